@@ -0,0 +1,28 @@
+use auth::Claims;
+use auth::JwtHandler;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+const SECRET: &[u8] = b"benchmark-secret-key-at-least-32-bytes";
+
+fn bench_jwt(c: &mut Criterion) {
+    let handler = JwtHandler::new(SECRET);
+    let claims = Claims::for_user(
+        "0b5c7b84-6f6e-4a3a-9d7e-1f1f6a2b3c4d",
+        "alice".to_string(),
+        24,
+    )
+    .with_roles(["admin", "moderator"]);
+    let token = handler.encode(&claims).unwrap();
+
+    let mut group = c.benchmark_group("jwt");
+    group.bench_function("encode", |b| b.iter(|| handler.encode(&claims).unwrap()));
+    group.bench_function("decode", |b| {
+        b.iter(|| handler.decode::<Claims>(&token).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_jwt);
+criterion_main!(benches);
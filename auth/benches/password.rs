@@ -0,0 +1,70 @@
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::PasswordHasher as Argon2PasswordHasher;
+use argon2::password_hash::SaltString;
+use argon2::Algorithm;
+use argon2::Argon2;
+use argon2::Params;
+use argon2::Version;
+use auth::PasswordHasher;
+use auth::SecretString;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+
+const PASSWORD: &str = "correct horse battery staple";
+
+/// Candidate Argon2id parameters as (memory KiB, iterations, parallelism).
+///
+/// The first entry matches `Argon2::default()`, which `PasswordHasher` uses.
+const PARAMS: &[(u32, u32, u32)] = &[
+    (19 * 1024, 2, 1),
+    (46 * 1024, 1, 1),
+    (64 * 1024, 3, 4),
+    (12 * 1024, 3, 1),
+];
+
+fn bench_password_hasher(c: &mut Criterion) {
+    let hasher = PasswordHasher::new();
+    let password = SecretString::from(PASSWORD);
+    let hash = hasher.hash(&password).unwrap();
+
+    let mut group = c.benchmark_group("password_hasher");
+    group.sample_size(20);
+    group.bench_function("hash", |b| b.iter(|| hasher.hash(&password).unwrap()));
+    group.bench_function("verify", |b| {
+        b.iter(|| hasher.verify(&password, &hash).unwrap())
+    });
+    group.finish();
+}
+
+fn bench_argon2_params(c: &mut Criterion) {
+    let salt = SaltString::generate(&mut OsRng);
+
+    let mut group = c.benchmark_group("argon2id_params");
+    group.sample_size(10);
+    for &(memory_kib, iterations, parallelism) in PARAMS {
+        let params = Params::new(memory_kib, iterations, parallelism, None).unwrap();
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!(
+                "m{}_t{}_p{}",
+                memory_kib, iterations, parallelism
+            )),
+            &argon2,
+            |b, argon2| {
+                b.iter(|| {
+                    argon2
+                        .hash_password(PASSWORD.as_bytes(), &salt)
+                        .unwrap()
+                        .to_string()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_password_hasher, bench_argon2_params);
+criterion_main!(benches);
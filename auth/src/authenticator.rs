@@ -1,9 +1,55 @@
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::SubsecRound;
+use chrono::Utc;
+use clock::Clock;
+use data_encoding::BASE64URL_NOPAD;
+use ring::digest::digest;
+use ring::digest::SHA256;
 use serde::Serialize;
+use uuid::Uuid;
 
+use crate::jwt::Claims;
 use crate::jwt::JwtError;
 use crate::jwt::JwtHandler;
+use crate::jwt::TokenType;
+use crate::jwt::ValidationCache;
+use crate::one_time::InMemoryOneTimeTokenStore;
+use crate::one_time::OneTimeToken;
+use crate::one_time::OneTimeTokenError;
+use crate::one_time::OneTimeTokenStore;
+use crate::password::LegacyHashVerifier;
 use crate::password::PasswordError;
 use crate::password::PasswordHasher;
+use crate::password::PasswordVerification;
+use crate::refresh::InMemoryRefreshTokenStore;
+use crate::refresh::RefreshTokenError;
+use crate::refresh::RefreshTokenFamily;
+use crate::refresh::RefreshTokenPolicy;
+use crate::refresh::RefreshTokenStore;
+use crate::refresh::Rotation;
+use crate::revocation::InMemoryRevocationStore;
+use crate::revocation::RevocationError;
+use crate::revocation::RevocationStore;
+use crate::secret::SecretBytes;
+use crate::secret::SecretString;
+use crate::session::DeviceInfo;
+use crate::session::InMemorySessionStore;
+use crate::session::Session;
+use crate::session::SessionError;
+use crate::session::SessionStore;
+
+/// Claim carrying the token family of a refresh token.
+const FAMILY_CLAIM: &str = "fam";
+
+/// Claim carrying the action a one-time token authorizes.
+const PURPOSE_CLAIM: &str = "purpose";
+
+/// Claim carrying the SHA-256 of the client a one-time token is bound to.
+const BINDING_CLAIM: &str = "bnd";
 
 /// Authentication coordinator combining password verification and JWT generation.
 ///
@@ -12,12 +58,37 @@ use crate::password::PasswordHasher;
 pub struct Authenticator {
     password_hasher: PasswordHasher,
     jwt_handler: JwtHandler,
+    // Stores not set by a builder are created in memory on first use, so that
+    // they tell the time by the clock set last
+    refresh_store: OnceLock<Arc<dyn RefreshTokenStore>>,
+    refresh_policy: RefreshTokenPolicy,
+    one_time_store: OnceLock<Arc<dyn OneTimeTokenStore>>,
+    session_store: OnceLock<Arc<dyn SessionStore>>,
+    revocation_store: OnceLock<Arc<dyn RevocationStore>>,
+    validation_cache: Option<ValidationCache>,
+    clock: Arc<dyn Clock>,
 }
 
 /// Result of successful authentication.
 pub struct AuthenticationResult {
     /// JWT access token
     pub access_token: String,
+    /// Current hash of the password, set when the stored hash is in a legacy
+    /// format and should be replaced by this one
+    pub rehashed_password: Option<String>,
+}
+
+/// Access token paired with the refresh token that can renew it.
+#[derive(Debug, Clone)]
+pub struct TokenPair {
+    /// Short-lived JWT access token
+    pub access_token: String,
+    /// Single-use JWT refresh token
+    pub refresh_token: String,
+    /// Expiration of the access token
+    pub access_expires_at: DateTime<Utc>,
+    /// Expiration of the refresh token family
+    pub refresh_expires_at: DateTime<Utc>,
 }
 
 /// Authentication operation errors.
@@ -33,21 +104,180 @@ pub enum AuthenticationError {
     JwtError(#[from] JwtError),
 }
 
+impl AuthenticationError {
+    /// Machine-readable code of the error, stable across releases.
+    ///
+    /// # Returns
+    /// `invalid_credentials`, `password_error`, or the code of the JWT error
+    pub fn code(&self) -> &'static str {
+        match self {
+            AuthenticationError::InvalidCredentials => "invalid_credentials",
+            AuthenticationError::PasswordError(_) => "password_error",
+            AuthenticationError::JwtError(e) => e.code(),
+        }
+    }
+}
+
 impl Authenticator {
     /// Create a new authenticator.
     ///
     /// # Arguments
-    /// * `jwt_secret` - Secret key for JWT signing
+    /// * `jwt_secret` - Secret key for JWT signing, wiped once the signing keys are derived
     ///
     /// # Returns
-    /// Configured Authenticator instance
-    pub fn new(jwt_secret: &[u8]) -> Self {
+    /// Configured Authenticator instance with in-memory refresh, one-time token, session
+    /// and revocation stores
+    pub fn new(jwt_secret: impl Into<SecretBytes>) -> Self {
         Self {
             password_hasher: PasswordHasher::new(),
             jwt_handler: JwtHandler::new(jwt_secret),
+            refresh_store: OnceLock::new(),
+            refresh_policy: RefreshTokenPolicy::default(),
+            one_time_store: OnceLock::new(),
+            session_store: OnceLock::new(),
+            revocation_store: OnceLock::new(),
+            validation_cache: None,
+            clock: clock::system(),
         }
     }
 
+    /// Use a shared store for refresh token families.
+    ///
+    /// Required when several instances must accept each other's refresh tokens.
+    ///
+    /// # Arguments
+    /// * `store` - Refresh token store
+    ///
+    /// # Returns
+    /// Authenticator using the given store
+    pub fn with_refresh_store(mut self, store: Arc<dyn RefreshTokenStore>) -> Self {
+        self.refresh_store = OnceLock::from(store);
+        self
+    }
+
+    /// Use a shared store for redeemed one-time tokens.
+    ///
+    /// Required when several instances must reject each other's redeemed tokens.
+    ///
+    /// # Arguments
+    /// * `store` - One-time token store
+    ///
+    /// # Returns
+    /// Authenticator using the given store
+    pub fn with_one_time_store(mut self, store: Arc<dyn OneTimeTokenStore>) -> Self {
+        self.one_time_store = OnceLock::from(store);
+        self
+    }
+
+    /// Use a shared store for sessions.
+    ///
+    /// Required when several instances must see each other's sessions and revocations.
+    ///
+    /// # Arguments
+    /// * `store` - Session store
+    ///
+    /// # Returns
+    /// Authenticator using the given store
+    pub fn with_session_store(mut self, store: Arc<dyn SessionStore>) -> Self {
+        self.session_store = OnceLock::from(store);
+        self
+    }
+
+    /// Use a shared store for revoked access tokens.
+    ///
+    /// Required when several instances must reject each other's logged out tokens.
+    ///
+    /// # Arguments
+    /// * `store` - Revocation store
+    ///
+    /// # Returns
+    /// Authenticator using the given store
+    pub fn with_revocation_store(mut self, store: Arc<dyn RevocationStore>) -> Self {
+        self.revocation_store = OnceLock::from(store);
+        self
+    }
+
+    /// Cache access tokens that passed validation.
+    ///
+    /// Tokens presented again skip the signature check until the cache TTL or
+    /// the token expires. Revocations are still checked on every request by
+    /// [`Authenticator::ensure_not_revoked`], which drops revoked tokens.
+    ///
+    /// # Arguments
+    /// * `capacity` - Maximum number of cached tokens
+    /// * `ttl` - Maximum time a token stays cached
+    ///
+    /// # Returns
+    /// Authenticator caching validated access tokens
+    pub fn with_validation_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.validation_cache =
+            Some(ValidationCache::new(capacity, ttl).with_clock(Arc::clone(&self.clock)));
+        self
+    }
+
+    /// Encrypt issued tokens so their claims are unreadable without the key.
+    ///
+    /// Signed-only tokens keep being accepted. See [`JwtHandler::with_encryption`].
+    ///
+    /// # Arguments
+    /// * `key` - 256-bit content encryption key
+    ///
+    /// # Returns
+    /// Authenticator issuing encrypted tokens
+    ///
+    /// # Errors
+    /// * `InvalidEncryptionKey` - Key is not 32 bytes long
+    pub fn with_token_encryption(mut self, key: impl Into<SecretBytes>) -> Result<Self, JwtError> {
+        self.jwt_handler = self.jwt_handler.with_encryption(key)?;
+        Ok(self)
+    }
+
+    /// Accept stored password hashes in a legacy format.
+    ///
+    /// See [`PasswordHasher::with_legacy_verifier`].
+    ///
+    /// # Arguments
+    /// * `verifier` - Verifier for the legacy format
+    ///
+    /// # Returns
+    /// Authenticator verifying passwords against legacy hashes too
+    pub fn with_legacy_verifier(mut self, verifier: Arc<dyn LegacyHashVerifier>) -> Self {
+        self.password_hasher = self.password_hasher.with_legacy_verifier(verifier);
+        self
+    }
+
+    /// Tell the time by another clock.
+    ///
+    /// Token and session lifetimes are stamped and checked with it, so tests
+    /// can expire them by advancing a fake clock. The system clock by default.
+    /// Stores set with the `with_*_store` builders keep their own clock.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock of the authenticator, its JWT handler and validation cache
+    ///
+    /// # Returns
+    /// Authenticator telling the time by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.jwt_handler = self.jwt_handler.with_clock(Arc::clone(&clock));
+        self.validation_cache = self
+            .validation_cache
+            .map(|cache| cache.with_clock(Arc::clone(&clock)));
+        self.clock = clock;
+        self
+    }
+
+    /// Override the token pair lifetimes.
+    ///
+    /// # Arguments
+    /// * `policy` - Access and refresh token lifetimes
+    ///
+    /// # Returns
+    /// Authenticator using the given policy
+    pub fn with_refresh_policy(mut self, policy: RefreshTokenPolicy) -> Self {
+        self.refresh_policy = policy;
+        self
+    }
+
     /// Hash a password for storage.
     ///
     /// # Arguments
@@ -58,7 +288,7 @@ impl Authenticator {
     ///
     /// # Errors
     /// * `PasswordError` - Hashing operation failed
-    pub fn hash_password(&self, password: &str) -> Result<String, PasswordError> {
+    pub fn hash_password(&self, password: &SecretString) -> Result<String, PasswordError> {
         self.password_hasher.hash(password)
     }
 
@@ -70,29 +300,32 @@ impl Authenticator {
     /// * `claims` - JWT claims to encode in token
     ///
     /// # Returns
-    /// AuthenticationResult with access token
+    /// AuthenticationResult with access token, and a replacement hash if the stored one is legacy
     ///
     /// # Errors
     /// * `InvalidCredentials` - Password does not match
-    /// * `PasswordError` - Password verification failed
+    /// * `PasswordError` - Password verification or rehashing failed
     /// * `JwtError` - Token generation failed
     pub fn authenticate<T: Serialize>(
         &self,
-        password: &str,
+        password: &SecretString,
         stored_hash: &str,
         claims: &T,
     ) -> Result<AuthenticationResult, AuthenticationError> {
         // Verify password
-        let is_valid = self.password_hasher.verify(password, stored_hash)?;
-
-        if !is_valid {
-            return Err(AuthenticationError::InvalidCredentials);
-        }
+        let rehashed_password = match self.password_hasher.verify(password, stored_hash)? {
+            PasswordVerification::Invalid => return Err(AuthenticationError::InvalidCredentials),
+            PasswordVerification::Valid => None,
+            PasswordVerification::NeedsRehash => Some(self.password_hasher.hash(password)?),
+        };
 
         // Generate JWT token
         let access_token = self.jwt_handler.encode(claims)?;
 
-        Ok(AuthenticationResult { access_token })
+        Ok(AuthenticationResult {
+            access_token,
+            rehashed_password,
+        })
     }
 
     /// Generate JWT token without password verification.
@@ -128,84 +361,1675 @@ impl Authenticator {
     ) -> Result<T, JwtError> {
         self.jwt_handler.decode(token)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::jwt::Claims;
+    /// Generate a short-lived service-to-service token.
+    ///
+    /// Machine tokens carry the calling service in `sub`/`azp`, a `service`
+    /// token type and the granted scopes, so they cannot be mistaken for
+    /// user tokens.
+    ///
+    /// # Arguments
+    /// * `service_name` - Name of the calling service
+    /// * `scopes` - Scopes granted to the service
+    /// * `ttl` - Time until token expires
+    ///
+    /// # Returns
+    /// JWT token string
+    ///
+    /// # Errors
+    /// * `JwtError` - Token generation failed
+    pub fn issue_service_token(
+        &self,
+        service_name: &str,
+        scopes: &[&str],
+        ttl: Duration,
+    ) -> Result<String, JwtError> {
+        // Stamped by the authenticator's clock, which validates the token
+        let now = self.clock.now();
+        let claims = Claims::for_service(service_name, scopes, ttl)
+            .with_issued_at(now.timestamp())
+            .with_expiration((now + ttl).timestamp());
+        self.jwt_handler.encode(&claims)
+    }
 
-    #[test]
-    fn test_authenticate_success() {
-        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+    /// Issue a token for an anonymous guest without an account.
+    ///
+    /// Guest tokens are user tokens with a generated `guest:` subject and the
+    /// `guest` claim set (see [`Claims::is_guest`]). Services decide what
+    /// guests may do; typically read-only access to public resources.
+    ///
+    /// # Arguments
+    /// * `ttl` - Time until token expires
+    ///
+    /// # Returns
+    /// JWT token string
+    ///
+    /// # Errors
+    /// * `JwtError` - Token generation failed
+    pub fn issue_guest_token(&self, ttl: Duration) -> Result<String, JwtError> {
+        let now = self.clock.now();
+        let claims = Claims::for_guest(ttl)
+            .with_issued_at(now.timestamp())
+            .with_expiration((now + ttl).timestamp());
+        self.jwt_handler.encode(&claims)
+    }
 
-        // Hash a password
-        let password = "my_password";
-        let hash = authenticator
-            .hash_password(password)
-            .expect("Failed to hash password");
+    /// Validate a token that may be used to access resources.
+    ///
+    /// Accepts user and service tokens but rejects refresh tokens, which may
+    /// only be exchanged through [`Authenticator::rotate_refresh`], one-time
+    /// tokens, which may only be redeemed for their purpose, and JWTs claiming
+    /// to be personal access tokens, which are never JWTs.
+    ///
+    /// # Arguments
+    /// * `token` - JWT token string
+    ///
+    /// # Returns
+    /// Decoded claims
+    ///
+    /// # Errors
+    /// * `UnexpectedTokenType` - Token is a refresh, one-time or personal token
+    /// * `JwtError` - Token validation or decoding failed
+    pub fn validate_access_token(&self, token: &str) -> Result<Claims, JwtError> {
+        let cache = self.validation_cache.as_ref();
+        if let Some(claims) = cache.and_then(|cache| cache.get(token)) {
+            return Ok(claims);
+        }
 
-        // Authenticate with correct password
-        let claims = Claims::new().with_subject("user123");
-        let result = authenticator
-            .authenticate(password, &hash, &claims)
-            .expect("Authentication failed");
+        let claims: Claims = self.jwt_handler.decode(token)?;
 
-        assert!(!result.access_token.is_empty());
+        match claims.token_type() {
+            TokenType::User | TokenType::Service => {}
+            actual @ (TokenType::Refresh | TokenType::OneTime | TokenType::Personal) => {
+                return Err(JwtError::UnexpectedTokenType {
+                    expected: TokenType::User,
+                    actual,
+                });
+            }
+        }
 
-        // Validate the token
-        let decoded: Claims = authenticator
-            .validate_token(&result.access_token)
-            .expect("Token validation failed");
-        assert_eq!(decoded.sub, Some("user123".to_string()));
+        if let Some(cache) = cache {
+            cache.insert(token, &claims);
+        }
+        Ok(claims)
     }
 
-    #[test]
-    fn test_authenticate_invalid_password() {
-        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+    /// Validate an access token presented by a device.
+    ///
+    /// Tokens bound with [`Claims::with_device_fingerprint`] are only accepted
+    /// from the device they were issued to; unbound tokens are accepted from any.
+    ///
+    /// # Arguments
+    /// * `token` - JWT token string
+    /// * `fingerprint` - Fingerprint of the presenting device, None if it sent no identifier
+    ///
+    /// # Returns
+    /// Decoded claims
+    ///
+    /// # Errors
+    /// * `DeviceMismatch` - Token is bound to another device, or the device is unknown
+    /// * `UnexpectedTokenType` - Token is a refresh or one-time token
+    /// * `JwtError` - Token validation or decoding failed
+    pub fn validate_token_for_device(
+        &self,
+        token: &str,
+        fingerprint: Option<&str>,
+    ) -> Result<Claims, JwtError> {
+        let claims = self.validate_access_token(token)?;
 
-        let password = "my_password";
-        let hash = authenticator
-            .hash_password(password)
-            .expect("Failed to hash password");
+        if let Some(bound) = claims.device_fingerprint() {
+            if fingerprint != Some(bound) {
+                return Err(JwtError::DeviceMismatch);
+            }
+        }
 
-        let claims = Claims::new().with_subject("user123");
+        Ok(claims)
+    }
 
-        // Try with wrong password
-        let result = authenticator.authenticate("wrong_password", &hash, &claims);
-        assert!(matches!(
-            result,
-            Err(AuthenticationError::InvalidCredentials)
-        ));
+    /// Fingerprint of a device for [`Claims::with_device_fingerprint`].
+    ///
+    /// Issuers and validators derive the fingerprint the same way, so the raw
+    /// device identifier never appears in a token.
+    ///
+    /// # Arguments
+    /// * `device_id` - Stable identifier the client sends for its device
+    ///
+    /// # Returns
+    /// Base64url-encoded SHA-256 of the identifier
+    pub fn device_fingerprint(device_id: &str) -> String {
+        binding_digest(device_id)
     }
 
-    #[test]
-    fn test_generate_and_validate_token() {
-        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+    /// Issue a signed, short-lived token authorizing a single action.
+    ///
+    /// # Arguments
+    /// * `subject` - Subject the action applies to (usually a user ID)
+    /// * `purpose` - Action the token authorizes (e.g. [`OneTimeToken::PASSWORD_RESET`])
+    /// * `ttl` - Time until token expires
+    ///
+    /// # Returns
+    /// JWT token string, suitable for embedding in a link
+    ///
+    /// # Errors
+    /// * `JwtError` - Token generation failed
+    pub fn issue_one_time_token(
+        &self,
+        subject: &str,
+        purpose: &str,
+        ttl: Duration,
+    ) -> Result<String, JwtError> {
+        let now = self.clock.now();
+        let claims = Claims::new()
+            .with_subject(subject)
+            .with_issued_at(now.timestamp())
+            .with_expiration((now + ttl).timestamp())
+            .with_token_type(TokenType::OneTime)
+            .with_claim(PURPOSE_CLAIM, purpose);
+
+        self.jwt_handler.encode(&claims)
+    }
 
+    /// Issue a one-time token that can only be redeemed by the same client.
+    ///
+    /// Only a digest of the binding is embedded, so the token does not
+    /// disclose it (e.g. the IP address a login link was requested from).
+    ///
+    /// # Arguments
+    /// * `subject` - Subject the action applies to (usually a user ID)
+    /// * `purpose` - Action the token authorizes (e.g. [`OneTimeToken::MAGIC_LINK`])
+    /// * `ttl` - Time until token expires
+    /// * `binding` - Client identifier that must be presented on redemption
+    ///
+    /// # Returns
+    /// JWT token string, suitable for embedding in a link
+    ///
+    /// # Errors
+    /// * `JwtError` - Token generation failed
+    pub fn issue_bound_one_time_token(
+        &self,
+        subject: &str,
+        purpose: &str,
+        ttl: Duration,
+        binding: &str,
+    ) -> Result<String, JwtError> {
+        let now = self.clock.now();
         let claims = Claims::new()
-            .with_subject("user123")
-            .with_issuer("test".to_string());
+            .with_subject(subject)
+            .with_issued_at(now.timestamp())
+            .with_expiration((now + ttl).timestamp())
+            .with_token_type(TokenType::OneTime)
+            .with_claim(PURPOSE_CLAIM, purpose)
+            .with_claim(BINDING_CLAIM, binding_digest(binding));
 
-        // Generate token
-        let token = authenticator
-            .generate_token(&claims)
-            .expect("Failed to generate token");
+        self.jwt_handler.encode(&claims)
+    }
 
-        // Validate token
-        let decoded: Claims = authenticator
-            .validate_token(&token)
-            .expect("Failed to validate token");
+    /// Redeem a one-time token for the given purpose.
+    ///
+    /// The token is marked as used before returning, so a second redemption
+    /// fails even if the action it authorized has not completed.
+    ///
+    /// # Arguments
+    /// * `token` - One-time token string
+    /// * `purpose` - Action the caller is about to perform
+    ///
+    /// # Returns
+    /// Redeemed token with its subject
+    ///
+    /// # Errors
+    /// * `WrongPurpose` - Token was issued for another action
+    /// * `AlreadyUsed` - Token was already redeemed
+    /// * `JwtError` - Token is invalid, expired or not a one-time token
+    /// * `StoreError` - Token could not be marked as used
+    pub async fn redeem_one_time_token(
+        &self,
+        token: &str,
+        purpose: &str,
+    ) -> Result<OneTimeToken, OneTimeTokenError> {
+        self.redeem(token, purpose, None).await
+    }
 
-        assert_eq!(decoded.sub, Some("user123".to_string()));
-        assert_eq!(decoded.iss, Some("test".to_string()));
+    /// Redeem a one-time token for the given purpose on behalf of a client.
+    ///
+    /// Tokens issued with [`Authenticator::issue_bound_one_time_token`] are only
+    /// accepted from the client they were bound to; a mismatch does not use up
+    /// the token. Unbound tokens are accepted from any client.
+    ///
+    /// # Arguments
+    /// * `token` - One-time token string
+    /// * `purpose` - Action the caller is about to perform
+    /// * `binding` - Identifier of the redeeming client
+    ///
+    /// # Returns
+    /// Redeemed token with its subject
+    ///
+    /// # Errors
+    /// * `BindingMismatch` - Token is bound to another client
+    /// * `WrongPurpose` - Token was issued for another action
+    /// * `AlreadyUsed` - Token was already redeemed
+    /// * `JwtError` - Token is invalid, expired or not a one-time token
+    /// * `StoreError` - Token could not be marked as used
+    pub async fn redeem_bound_one_time_token(
+        &self,
+        token: &str,
+        purpose: &str,
+        binding: &str,
+    ) -> Result<OneTimeToken, OneTimeTokenError> {
+        self.redeem(token, purpose, Some(binding)).await
     }
 
-    #[test]
-    fn test_validate_invalid_token() {
-        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+    async fn redeem(
+        &self,
+        token: &str,
+        purpose: &str,
+        binding: Option<&str>,
+    ) -> Result<OneTimeToken, OneTimeTokenError> {
+        let claims = self.validate_token_type(token, TokenType::OneTime)?;
 
-        let result = authenticator.validate_token::<Claims>("invalid.token.here");
-        assert!(result.is_err());
+        let actual: String = claims
+            .claim(PURPOSE_CLAIM)
+            .ok_or_else(|| JwtError::MissingClaim(PURPOSE_CLAIM.to_string()))?;
+        if actual != purpose {
+            return Err(OneTimeTokenError::WrongPurpose {
+                expected: purpose.to_string(),
+                actual,
+            });
+        }
+
+        if let Some(bound) = claims.claim::<String>(BINDING_CLAIM) {
+            if binding.map(binding_digest).as_deref() != Some(bound.as_str()) {
+                return Err(OneTimeTokenError::BindingMismatch);
+            }
+        }
+
+        let redeemed = OneTimeToken {
+            subject: claims
+                .sub
+                .clone()
+                .ok_or_else(|| JwtError::MissingClaim("sub".to_string()))?,
+            purpose: actual,
+            jti: claims
+                .jti()
+                .ok_or_else(|| JwtError::MissingClaim("jti".to_string()))?
+                .to_string(),
+            expires_at: claims
+                .exp
+                .and_then(|exp| DateTime::from_timestamp(exp, 0))
+                .ok_or_else(|| JwtError::MissingClaim("exp".to_string()))?,
+        };
+
+        if !self
+            .one_time_store()
+            .consume(&redeemed.jti, redeemed.expires_at)
+            .await?
+        {
+            return Err(OneTimeTokenError::AlreadyUsed);
+        }
+
+        Ok(redeemed)
+    }
+
+    /// Start a session for a user logging in on a device.
+    ///
+    /// Tokens issued for the session should carry its ID via
+    /// [`Claims::with_session_id`] and be checked with
+    /// [`Authenticator::validate_session_token`].
+    ///
+    /// # Arguments
+    /// * `user_id` - User logging in
+    /// * `device` - Client the user logs in from
+    /// * `ttl` - Time until the session expires
+    ///
+    /// # Returns
+    /// The new session
+    ///
+    /// # Errors
+    /// * `StoreError` - Session could not be stored
+    pub async fn start_session(
+        &self,
+        user_id: &str,
+        device: DeviceInfo,
+        ttl: Duration,
+    ) -> Result<Session, SessionError> {
+        let now = self.clock.now();
+        let session = Session {
+            id: Uuid::new_v4().to_string(),
+            user_id: user_id.to_string(),
+            device,
+            created_at: now,
+            expires_at: now + ttl,
+        };
+
+        self.session_store().create(session.clone()).await?;
+        Ok(session)
+    }
+
+    /// Validate an access token and the session it belongs to.
+    ///
+    /// # Arguments
+    /// * `token` - Access token carrying a `sid` claim
+    ///
+    /// # Returns
+    /// Decoded claims if the token is valid and its session active
+    ///
+    /// # Errors
+    /// * `JwtError` - Token is invalid, expired, not an access token, or has no `sid` claim
+    /// * `Revoked` - Session is unknown, revoked, or belongs to another user
+    /// * `Expired` - Session has expired
+    /// * `StoreError` - Session could not be read
+    pub async fn validate_session_token(&self, token: &str) -> Result<Claims, SessionError> {
+        let claims = self.validate_access_token(token)?;
+        let session_id = claims
+            .session_id()
+            .ok_or_else(|| JwtError::MissingClaim("sid".to_string()))?;
+
+        let session = self
+            .session_store()
+            .get(&session_id)
+            .await?
+            .filter(|session| claims.sub.as_deref() == Some(session.user_id.as_str()))
+            .ok_or(SessionError::Revoked)?;
+        if session.is_expired(self.clock.now()) {
+            return Err(SessionError::Expired);
+        }
+
+        Ok(claims)
+    }
+
+    /// List the active sessions of a user, most recent first.
+    ///
+    /// # Arguments
+    /// * `user_id` - User whose sessions to list
+    ///
+    /// # Returns
+    /// Sessions that are neither revoked nor expired
+    ///
+    /// # Errors
+    /// * `StoreError` - Sessions could not be read
+    pub async fn list_sessions(&self, user_id: &str) -> Result<Vec<Session>, SessionError> {
+        let now = self.clock.now();
+        let mut sessions: Vec<Session> = self
+            .session_store()
+            .list_for_user(user_id)
+            .await?
+            .into_iter()
+            .filter(|session| !session.is_expired(now))
+            .collect();
+        sessions.sort_by_key(|session| std::cmp::Reverse(session.created_at));
+
+        Ok(sessions)
+    }
+
+    /// Revoke a session, logging the device out.
+    ///
+    /// # Arguments
+    /// * `session_id` - Session to revoke
+    ///
+    /// # Errors
+    /// * `StoreError` - Session could not be revoked
+    pub async fn revoke_session(&self, session_id: &str) -> Result<(), SessionError> {
+        self.session_store().revoke(session_id).await
+    }
+
+    /// Revoke every session of a user except the current one ("log out other devices").
+    ///
+    /// # Arguments
+    /// * `user_id` - User whose sessions to revoke
+    /// * `current_session_id` - Session to keep
+    ///
+    /// # Returns
+    /// Number of revoked sessions
+    ///
+    /// # Errors
+    /// * `StoreError` - Sessions could not be revoked
+    pub async fn revoke_other_sessions(
+        &self,
+        user_id: &str,
+        current_session_id: &str,
+    ) -> Result<usize, SessionError> {
+        self.session_store()
+            .revoke_for_user(user_id, Some(current_session_id))
+            .await
+    }
+
+    /// Revoke every session of a user ("log out everywhere").
+    ///
+    /// # Arguments
+    /// * `user_id` - User whose sessions to revoke
+    ///
+    /// # Returns
+    /// Number of revoked sessions
+    ///
+    /// # Errors
+    /// * `StoreError` - Sessions could not be revoked
+    pub async fn revoke_all_sessions(&self, user_id: &str) -> Result<usize, SessionError> {
+        self.session_store().revoke_for_user(user_id, None).await
+    }
+
+    /// Revoke an access token until it expires, logging it out.
+    ///
+    /// # Arguments
+    /// * `claims` - Claims of the validated token to revoke
+    ///
+    /// # Errors
+    /// * `JwtError` - Token has no `jti` or `exp` claim
+    /// * `StoreError` - Revocation could not be stored
+    pub async fn revoke_access_token(&self, claims: &Claims) -> Result<(), RevocationError> {
+        let jti = claims
+            .jti()
+            .ok_or_else(|| JwtError::MissingClaim("jti".to_string()))?;
+        let expires_at = claims
+            .exp
+            .and_then(|exp| DateTime::from_timestamp(exp, 0))
+            .ok_or_else(|| JwtError::MissingClaim("exp".to_string()))?;
+
+        self.revocation_store().revoke(jti, expires_at).await?;
+
+        if let Some(cache) = &self.validation_cache {
+            cache.invalidate(jti);
+        }
+        Ok(())
+    }
+
+    /// Revoke every access token and refresh token family of a user ("log out everywhere").
+    ///
+    /// Tokens issued from now on, e.g. by the next login, stay valid.
+    ///
+    /// # Arguments
+    /// * `user_id` - Subject of the tokens to revoke
+    ///
+    /// # Errors
+    /// * `StoreError` - Families or revocation could not be stored
+    pub async fn revoke_user_tokens(&self, user_id: &str) -> Result<(), RevocationError> {
+        let now = self.clock.now();
+
+        self.refresh_store()
+            .revoke_user_families(user_id)
+            .await
+            .map_err(|e| RevocationError::StoreError(e.to_string()))?;
+        // No token issued before now outlives its family
+        self.revocation_store()
+            .revoke_user(user_id, now, now + self.refresh_policy.refresh_ttl)
+            .await
+    }
+
+    /// Check that a validated access token was not revoked.
+    ///
+    /// Tokens without a `jti` claim cannot be revoked one by one, only with
+    /// all other tokens of their user.
+    ///
+    /// # Arguments
+    /// * `claims` - Claims of the validated token
+    ///
+    /// # Errors
+    /// * `Revoked` - Token was revoked with [`Authenticator::revoke_access_token`]
+    ///   or [`Authenticator::revoke_user_tokens`]
+    /// * `StoreError` - Revocation could not be checked
+    pub async fn ensure_not_revoked(&self, claims: &Claims) -> Result<(), RevocationError> {
+        if let Some(jti) = claims.jti() {
+            if self.revocation_store().is_revoked(jti).await? {
+                // Revoked by another instance, whose cache was the only one cleared
+                if let Some(cache) = &self.validation_cache {
+                    cache.invalidate(jti);
+                }
+                return Err(RevocationError::Revoked);
+            }
+        }
+
+        if let (Some(user_id), Some(issued_at)) = (claims.sub.as_deref(), claims.iat) {
+            let revoked_before = self.revocation_store().user_revoked_before(user_id).await?;
+            // `iat` has whole seconds; tokens issued within the second of the revocation stay valid
+            if revoked_before.is_some_and(|cutoff| issued_at < cutoff.timestamp()) {
+                return Err(RevocationError::Revoked);
+            }
+        }
+        Ok(())
+    }
+
+    /// Issue an access token and the first refresh token of a new family.
+    ///
+    /// The access token carries `claims` with fresh `iat`, `exp` and `jti`;
+    /// the same claims are re-issued on every rotation of the family.
+    ///
+    /// # Arguments
+    /// * `claims` - Access token claims
+    ///
+    /// # Returns
+    /// TokenPair with access and refresh tokens
+    ///
+    /// # Errors
+    /// * `JwtError` - Token generation failed
+    /// * `StoreError` - Token family could not be stored
+    pub async fn issue_token_pair(&self, claims: &Claims) -> Result<TokenPair, RefreshTokenError> {
+        let now = self.clock.now();
+        let family = RefreshTokenFamily {
+            id: Uuid::new_v4().to_string(),
+            current_jti: Uuid::new_v4().to_string(),
+            claims: claims.clone(),
+            // Whole seconds, matching the `exp` claim it is later recovered from
+            expires_at: (now + self.refresh_policy.refresh_ttl).trunc_subsecs(0),
+        };
+
+        let pair = self.encode_token_pair(&family, now)?;
+        self.refresh_store().create_family(family).await?;
+
+        Ok(pair)
+    }
+
+    /// Exchange a refresh token for a new token pair.
+    ///
+    /// Each refresh token can be rotated once. Presenting a token that was
+    /// already rotated revokes its whole family, so neither the legitimate
+    /// client nor whoever replayed the token can refresh again.
+    ///
+    /// # Arguments
+    /// * `refresh_token` - Current refresh token of the family
+    ///
+    /// # Returns
+    /// TokenPair with a new access token and the next refresh token
+    ///
+    /// # Errors
+    /// * `ReuseDetected` - Token was already rotated; family is now revoked
+    /// * `Revoked` - Family is unknown, expired or revoked
+    /// * `JwtError` - Token is invalid, expired or not a refresh token
+    /// * `StoreError` - Token family could not be updated
+    pub async fn rotate_refresh(
+        &self,
+        refresh_token: &str,
+    ) -> Result<TokenPair, RefreshTokenError> {
+        let claims = self.validate_token_type(refresh_token, TokenType::Refresh)?;
+
+        let family_id: String = claims
+            .claim(FAMILY_CLAIM)
+            .ok_or_else(|| JwtError::MissingClaim(FAMILY_CLAIM.to_string()))?;
+        let presented_jti = claims
+            .jti()
+            .ok_or_else(|| JwtError::MissingClaim("jti".to_string()))?;
+        let expires_at = claims
+            .exp
+            .and_then(|exp| DateTime::from_timestamp(exp, 0))
+            .ok_or_else(|| JwtError::MissingClaim("exp".to_string()))?;
+
+        let next_jti = Uuid::new_v4().to_string();
+
+        match self
+            .refresh_store()
+            .rotate(&family_id, presented_jti, &next_jti)
+            .await?
+        {
+            Rotation::Rotated(access_claims) => {
+                let family = RefreshTokenFamily {
+                    id: family_id,
+                    current_jti: next_jti,
+                    claims: *access_claims,
+                    expires_at,
+                };
+                self.encode_token_pair(&family, self.clock.now())
+            }
+            Rotation::Reused => Err(RefreshTokenError::ReuseDetected),
+            Rotation::Revoked => Err(RefreshTokenError::Revoked),
+        }
+    }
+
+    fn encode_token_pair(
+        &self,
+        family: &RefreshTokenFamily,
+        now: DateTime<Utc>,
+    ) -> Result<TokenPair, RefreshTokenError> {
+        let access_expires_at = (now + self.refresh_policy.access_ttl).trunc_subsecs(0);
+
+        let mut access_claims = family.claims.clone();
+        access_claims.iat = Some(now.timestamp());
+        access_claims.exp = Some(access_expires_at.timestamp());
+        access_claims.jti = Some(Uuid::new_v4().to_string());
+
+        let mut refresh_claims = Claims::default()
+            .with_issued_at(now.timestamp())
+            .with_expiration(family.expires_at.timestamp())
+            .with_jti(&family.current_jti)
+            .with_token_type(TokenType::Refresh)
+            .with_claim(FAMILY_CLAIM, &family.id);
+        refresh_claims.sub = family.claims.sub.clone();
+
+        Ok(TokenPair {
+            access_token: self.jwt_handler.encode(&access_claims)?,
+            refresh_token: self.jwt_handler.encode(&refresh_claims)?,
+            access_expires_at,
+            refresh_expires_at: family.expires_at,
+        })
+    }
+
+    /// Validate a token issued to an end user.
+    ///
+    /// # Arguments
+    /// * `token` - JWT token string
+    ///
+    /// # Returns
+    /// Decoded claims
+    ///
+    /// # Errors
+    /// * `UnexpectedTokenType` - Token is a service token
+    /// * `JwtError` - Token validation or decoding failed
+    pub fn validate_user_token(&self, token: &str) -> Result<Claims, JwtError> {
+        self.validate_token_type(token, TokenType::User)
+    }
+
+    /// Validate a token issued to an internal service.
+    ///
+    /// # Arguments
+    /// * `token` - JWT token string
+    ///
+    /// # Returns
+    /// Decoded claims
+    ///
+    /// # Errors
+    /// * `UnexpectedTokenType` - Token is not a service token
+    /// * `MissingClaim` - Token has no `azp` claim
+    /// * `JwtError` - Token validation or decoding failed
+    pub fn validate_service_token(&self, token: &str) -> Result<Claims, JwtError> {
+        let claims = self.validate_token_type(token, TokenType::Service)?;
+
+        if claims.azp.is_none() {
+            return Err(JwtError::MissingClaim("azp".to_string()));
+        }
+
+        Ok(claims)
+    }
+
+    /// Validate a service token and check that it grants the given scopes.
+    ///
+    /// # Arguments
+    /// * `token` - JWT token string
+    /// * `required_scopes` - Scopes the call needs, all of which must be granted
+    ///
+    /// # Returns
+    /// Decoded claims
+    ///
+    /// # Errors
+    /// * `InsufficientScope` - A required scope is not granted (the first one missing)
+    /// * `UnexpectedTokenType` - Token is not a service token
+    /// * `MissingClaim` - Token has no `azp` claim
+    /// * `JwtError` - Token validation or decoding failed
+    pub fn validate_scoped_service_token(
+        &self,
+        token: &str,
+        required_scopes: &[&str],
+    ) -> Result<Claims, JwtError> {
+        let claims = self.validate_service_token(token)?;
+
+        if let Some(missing) = required_scopes
+            .iter()
+            .find(|scope| !claims.has_scope(scope))
+        {
+            return Err(JwtError::InsufficientScope(missing.to_string()));
+        }
+
+        Ok(claims)
+    }
+
+    fn refresh_store(&self) -> &dyn RefreshTokenStore {
+        self.refresh_store
+            .get_or_init(|| {
+                Arc::new(InMemoryRefreshTokenStore::new().with_clock(Arc::clone(&self.clock)))
+            })
+            .as_ref()
+    }
+
+    fn one_time_store(&self) -> &dyn OneTimeTokenStore {
+        self.one_time_store
+            .get_or_init(|| {
+                Arc::new(InMemoryOneTimeTokenStore::new().with_clock(Arc::clone(&self.clock)))
+            })
+            .as_ref()
+    }
+
+    fn session_store(&self) -> &dyn SessionStore {
+        self.session_store
+            .get_or_init(|| {
+                Arc::new(InMemorySessionStore::new().with_clock(Arc::clone(&self.clock)))
+            })
+            .as_ref()
+    }
+
+    fn revocation_store(&self) -> &dyn RevocationStore {
+        self.revocation_store
+            .get_or_init(|| {
+                Arc::new(InMemoryRevocationStore::new().with_clock(Arc::clone(&self.clock)))
+            })
+            .as_ref()
+    }
+
+    fn validate_token_type(&self, token: &str, expected: TokenType) -> Result<Claims, JwtError> {
+        let claims: Claims = self.jwt_handler.decode(token)?;
+
+        let actual = claims.token_type();
+        if actual != expected {
+            return Err(JwtError::UnexpectedTokenType { expected, actual });
+        }
+
+        Ok(claims)
+    }
+}
+
+/// Digest of a one-time token binding, as embedded in the token.
+fn binding_digest(binding: &str) -> String {
+    BASE64URL_NOPAD.encode(digest(&SHA256, binding.as_bytes()).as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authenticate_success() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        // Hash a password
+        let password = SecretString::from("my_password");
+        let hash = authenticator
+            .hash_password(&password)
+            .expect("Failed to hash password");
+
+        // Authenticate with correct password
+        let claims = Claims::new().with_subject("user123");
+        let result = authenticator
+            .authenticate(&password, &hash, &claims)
+            .expect("Authentication failed");
+
+        assert!(!result.access_token.is_empty());
+        assert!(result.rehashed_password.is_none());
+
+        // Validate the token
+        let decoded: Claims = authenticator
+            .validate_token(&result.access_token)
+            .expect("Token validation failed");
+        assert_eq!(decoded.sub, Some("user123".to_string()));
+    }
+
+    #[test]
+    fn test_authenticate_invalid_password() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let password = SecretString::from("my_password");
+        let hash = authenticator
+            .hash_password(&password)
+            .expect("Failed to hash password");
+
+        let claims = Claims::new().with_subject("user123");
+
+        // Try with wrong password
+        let result =
+            authenticator.authenticate(&SecretString::from("wrong_password"), &hash, &claims);
+        assert!(matches!(
+            result,
+            Err(AuthenticationError::InvalidCredentials)
+        ));
+    }
+
+    #[test]
+    fn test_validate_token_for_device() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+        let fingerprint = Authenticator::device_fingerprint("laptop-1");
+        let bound = authenticator
+            .generate_token(
+                &Claims::for_user("user123", "alice".to_string(), 1)
+                    .with_device_fingerprint(&fingerprint),
+            )
+            .unwrap();
+        let unbound = authenticator
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 1))
+            .unwrap();
+
+        let claims = authenticator
+            .validate_token_for_device(&bound, Some(&fingerprint))
+            .expect("Bound token rejected on its device");
+        assert_eq!(claims.device_fingerprint(), Some(fingerprint.as_str()));
+
+        let other_device = Authenticator::device_fingerprint("phone-2");
+        assert!(matches!(
+            authenticator.validate_token_for_device(&bound, Some(&other_device)),
+            Err(JwtError::DeviceMismatch)
+        ));
+        assert!(matches!(
+            authenticator.validate_token_for_device(&bound, None),
+            Err(JwtError::DeviceMismatch)
+        ));
+        assert!(authenticator
+            .validate_token_for_device(&unbound, None)
+            .is_ok());
+    }
+
+    #[cfg(feature = "bcrypt")]
+    #[test]
+    fn test_authenticate_legacy_hash_returns_rehash() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!")
+            .with_legacy_verifier(Arc::new(crate::password::BcryptVerifier::new()));
+
+        // bcrypt("password", cost 4)
+        let legacy_hash = "$2b$04$EhTEkfX4tso89VDRX7CiHu3GFr/XYiRZFwjCd.dfqEVEjiLz2sJ7G";
+        let password = SecretString::from("password");
+        let claims = Claims::new().with_subject("user123");
+        let result = authenticator
+            .authenticate(&password, legacy_hash, &claims)
+            .expect("Authentication failed");
+
+        let rehashed = result.rehashed_password.expect("Legacy hash not replaced");
+        assert!(PasswordHasher::is_supported_hash(&rehashed));
+        let result = authenticator
+            .authenticate(&password, &rehashed, &claims)
+            .expect("Authentication with new hash failed");
+        assert!(result.rehashed_password.is_none());
+    }
+
+    #[test]
+    fn test_generate_and_validate_token() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let claims = Claims::new()
+            .with_subject("user123")
+            .with_issuer("test".to_string());
+
+        // Generate token
+        let token = authenticator
+            .generate_token(&claims)
+            .expect("Failed to generate token");
+
+        // Validate token
+        let decoded: Claims = authenticator
+            .validate_token(&token)
+            .expect("Failed to validate token");
+
+        assert_eq!(decoded.sub, Some("user123".to_string()));
+        assert_eq!(decoded.iss, Some("test".to_string()));
+        assert_eq!(decoded.jti(), claims.jti());
+        assert_eq!(decoded.iat, claims.iat);
+    }
+
+    #[test]
+    fn test_validate_invalid_token() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let result = authenticator.validate_token::<Claims>("invalid.token.here");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_service_token_round_trip() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .issue_service_token("chat-service", &["users:read"], Duration::minutes(5))
+            .expect("Failed to generate service token");
+
+        let claims = authenticator
+            .validate_service_token(&token)
+            .expect("Failed to validate service token");
+
+        assert_eq!(claims.azp, Some("chat-service".to_string()));
+        assert!(claims.has_scope("users:read"));
+    }
+
+    #[test]
+    fn test_guest_token_round_trip() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .issue_guest_token(Duration::hours(1))
+            .expect("Failed to generate guest token");
+
+        let claims = authenticator
+            .validate_access_token(&token)
+            .expect("Failed to validate guest token");
+
+        assert!(claims.is_guest());
+        assert!(claims.sub.unwrap().starts_with("guest:"));
+        assert!(authenticator
+            .validate_service_token(&authenticator.issue_guest_token(Duration::hours(1)).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_service_token_rejected_as_user_token() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .issue_service_token("chat-service", &[], Duration::minutes(5))
+            .expect("Failed to generate service token");
+
+        let result = authenticator.validate_user_token(&token);
+        assert!(matches!(
+            result,
+            Err(JwtError::UnexpectedTokenType {
+                expected: TokenType::User,
+                actual: TokenType::Service,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_scoped_service_token() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .issue_service_token("chat-service", &["users:read"], Duration::minutes(5))
+            .expect("Failed to generate service token");
+
+        assert!(authenticator
+            .validate_scoped_service_token(&token, &["users:read"])
+            .is_ok());
+        assert!(matches!(
+            authenticator.validate_scoped_service_token(&token, &["users:read", "users:write"]),
+            Err(JwtError::InsufficientScope(scope)) if scope == "users:write"
+        ));
+
+        let user_token = authenticator
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 1))
+            .expect("Failed to generate token");
+        assert!(matches!(
+            authenticator.validate_scoped_service_token(&user_token, &[]),
+            Err(JwtError::UnexpectedTokenType { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_issue_token_pair() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let claims = Claims::for_user("user123", "alice".to_string(), 24);
+        let pair = authenticator
+            .issue_token_pair(&claims)
+            .await
+            .expect("Failed to issue token pair");
+
+        let access = authenticator
+            .validate_access_token(&pair.access_token)
+            .expect("Failed to validate access token");
+        assert_eq!(access.sub, Some("user123".to_string()));
+        assert_eq!(access.username(), Some("alice".to_string()));
+        assert_eq!(access.exp, Some(pair.access_expires_at.timestamp()));
+
+        let refresh: Claims = authenticator
+            .validate_token(&pair.refresh_token)
+            .expect("Failed to decode refresh token");
+        assert!(refresh.is_refresh());
+        assert_eq!(refresh.sub, Some("user123".to_string()));
+        assert!(refresh.has_claim(FAMILY_CLAIM));
+        assert!(refresh.username().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_rejected_as_access_token() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let pair = authenticator
+            .issue_token_pair(&Claims::new().with_subject("user123"))
+            .await
+            .expect("Failed to issue token pair");
+
+        assert!(matches!(
+            authenticator.validate_access_token(&pair.refresh_token),
+            Err(JwtError::UnexpectedTokenType {
+                actual: TokenType::Refresh,
+                ..
+            })
+        ));
+        assert!(authenticator
+            .validate_user_token(&pair.refresh_token)
+            .is_err());
+    }
+
+    #[test]
+    fn test_personal_token_jwt_rejected_as_access_token() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let claims = Claims::for_personal_token(
+            "user123",
+            "alice".to_string(),
+            "token123",
+            vec!["messages:write".to_string()],
+            None,
+        );
+        let token = authenticator
+            .generate_token(&claims)
+            .expect("Failed to generate token");
+
+        assert!(matches!(
+            authenticator.validate_access_token(&token),
+            Err(JwtError::UnexpectedTokenType {
+                actual: TokenType::Personal,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rotate_refresh_issues_new_pair() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let claims = Claims::new().with_subject("user123").with_roles(["admin"]);
+        let first = authenticator
+            .issue_token_pair(&claims)
+            .await
+            .expect("Failed to issue token pair");
+
+        let second = authenticator
+            .rotate_refresh(&first.refresh_token)
+            .await
+            .expect("Failed to rotate refresh token");
+        assert_ne!(second.refresh_token, first.refresh_token);
+        assert_eq!(second.refresh_expires_at, first.refresh_expires_at);
+
+        let access = authenticator
+            .validate_access_token(&second.access_token)
+            .expect("Failed to validate access token");
+        assert_eq!(access.sub, Some("user123".to_string()));
+        assert!(access.has_role("admin"));
+
+        let third = authenticator
+            .rotate_refresh(&second.refresh_token)
+            .await
+            .expect("Failed to rotate refresh token twice");
+        assert!(!third.access_token.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rotate_refresh_reuse_revokes_family() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let first = authenticator
+            .issue_token_pair(&Claims::new().with_subject("user123"))
+            .await
+            .expect("Failed to issue token pair");
+        let second = authenticator
+            .rotate_refresh(&first.refresh_token)
+            .await
+            .expect("Failed to rotate refresh token");
+
+        // Replaying the rotated token is detected...
+        let reused = authenticator.rotate_refresh(&first.refresh_token).await;
+        assert!(matches!(reused, Err(RefreshTokenError::ReuseDetected)));
+
+        // ...and the legitimate successor is revoked with it
+        let successor = authenticator.rotate_refresh(&second.refresh_token).await;
+        assert!(matches!(successor, Err(RefreshTokenError::Revoked)));
+    }
+
+    #[tokio::test]
+    async fn test_rotate_refresh_rejects_access_token() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let pair = authenticator
+            .issue_token_pair(&Claims::new().with_subject("user123"))
+            .await
+            .expect("Failed to issue token pair");
+
+        let result = authenticator.rotate_refresh(&pair.access_token).await;
+        assert!(matches!(
+            result,
+            Err(RefreshTokenError::JwtError(JwtError::UnexpectedTokenType {
+                expected: TokenType::Refresh,
+                ..
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rotate_refresh_unknown_family() {
+        let issuer = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+        let other = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let pair = issuer
+            .issue_token_pair(&Claims::new().with_subject("user123"))
+            .await
+            .expect("Failed to issue token pair");
+
+        // A separate in-memory store has never seen the family
+        let result = other.rotate_refresh(&pair.refresh_token).await;
+        assert!(matches!(result, Err(RefreshTokenError::Revoked)));
+    }
+
+    #[tokio::test]
+    async fn test_redeem_one_time_token_once() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .issue_one_time_token(
+                "user123",
+                OneTimeToken::PASSWORD_RESET,
+                Duration::minutes(30),
+            )
+            .expect("Failed to issue one-time token");
+
+        let redeemed = authenticator
+            .redeem_one_time_token(&token, OneTimeToken::PASSWORD_RESET)
+            .await
+            .expect("Failed to redeem one-time token");
+        assert_eq!(redeemed.subject, "user123");
+        assert_eq!(redeemed.purpose, OneTimeToken::PASSWORD_RESET);
+
+        let again = authenticator
+            .redeem_one_time_token(&token, OneTimeToken::PASSWORD_RESET)
+            .await;
+        assert!(matches!(again, Err(OneTimeTokenError::AlreadyUsed)));
+    }
+
+    #[tokio::test]
+    async fn test_redeem_one_time_token_wrong_purpose() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .issue_one_time_token(
+                "user123",
+                OneTimeToken::EMAIL_VERIFICATION,
+                Duration::hours(24),
+            )
+            .expect("Failed to issue one-time token");
+
+        let result = authenticator
+            .redeem_one_time_token(&token, OneTimeToken::PASSWORD_RESET)
+            .await;
+        assert!(matches!(
+            result,
+            Err(OneTimeTokenError::WrongPurpose { .. })
+        ));
+
+        // A rejected attempt does not use up the token
+        assert!(authenticator
+            .redeem_one_time_token(&token, OneTimeToken::EMAIL_VERIFICATION)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_redeem_one_time_token_expired() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .issue_one_time_token("user123", OneTimeToken::PASSWORD_RESET, Duration::hours(-1))
+            .expect("Failed to issue one-time token");
+
+        let result = authenticator
+            .redeem_one_time_token(&token, OneTimeToken::PASSWORD_RESET)
+            .await;
+        assert!(matches!(
+            result,
+            Err(OneTimeTokenError::JwtError(JwtError::TokenExpired))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_one_time_token_expires_on_fake_clock() {
+        let clock = Arc::new(clock::FakeClock::default());
+        let authenticator =
+            Authenticator::new(b"test_secret_key_at_least_32_bytes!").with_clock(clock.clone());
+
+        let token = authenticator
+            .issue_one_time_token(
+                "user123",
+                OneTimeToken::PASSWORD_RESET,
+                Duration::minutes(30),
+            )
+            .expect("Failed to issue one-time token");
+
+        clock.advance(Duration::hours(1));
+        let result = authenticator
+            .redeem_one_time_token(&token, OneTimeToken::PASSWORD_RESET)
+            .await;
+        assert!(matches!(
+            result,
+            Err(OneTimeTokenError::JwtError(JwtError::TokenExpired))
+        ));
+    }
+
+    #[test]
+    fn test_service_and_guest_tokens_issued_after_time_travel_are_valid() {
+        let clock = Arc::new(clock::FakeClock::default());
+        let authenticator =
+            Authenticator::new(b"test_secret_key_at_least_32_bytes!").with_clock(clock.clone());
+
+        clock.advance(Duration::days(1));
+        let service_token = authenticator
+            .issue_service_token("chat-service", &["users:read"], Duration::minutes(5))
+            .expect("Failed to issue service token");
+        let guest_token = authenticator
+            .issue_guest_token(Duration::minutes(5))
+            .expect("Failed to issue guest token");
+
+        let claims = authenticator
+            .validate_service_token(&service_token)
+            .expect("Service token issued after time travel was rejected");
+        assert_eq!(claims.iat, Some(clock.now().timestamp()));
+        assert!(authenticator.validate_access_token(&guest_token).is_ok());
+
+        clock.advance(Duration::minutes(10));
+        assert!(matches!(
+            authenticator.validate_service_token(&service_token),
+            Err(JwtError::TokenExpired)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_session_expires_on_fake_clock() {
+        let clock = Arc::new(clock::FakeClock::default());
+        let authenticator =
+            Authenticator::new(b"test_secret_key_at_least_32_bytes!").with_clock(clock.clone());
+
+        let session = authenticator
+            .start_session("user123", DeviceInfo::default(), Duration::days(30))
+            .await
+            .expect("Failed to start session");
+        let expires_at = (clock.now() + Duration::days(60)).timestamp();
+        let token = authenticator
+            .generate_token(
+                &Claims::for_user("user123", "alice".to_string(), 1)
+                    .with_expiration(expires_at)
+                    .with_session_id(&session.id),
+            )
+            .expect("Failed to generate token");
+
+        clock.advance(Duration::days(31));
+        assert!(matches!(
+            authenticator.validate_session_token(&token).await,
+            Err(SessionError::Expired)
+        ));
+        assert!(authenticator
+            .list_sessions("user123")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_one_time_token_is_not_interchangeable() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .issue_one_time_token(
+                "user123",
+                OneTimeToken::PASSWORD_RESET,
+                Duration::minutes(30),
+            )
+            .expect("Failed to issue one-time token");
+        assert!(matches!(
+            authenticator.validate_access_token(&token),
+            Err(JwtError::UnexpectedTokenType {
+                actual: TokenType::OneTime,
+                ..
+            })
+        ));
+
+        let access = authenticator
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 1))
+            .expect("Failed to generate token");
+        assert!(matches!(
+            authenticator
+                .redeem_one_time_token(&access, OneTimeToken::PASSWORD_RESET)
+                .await,
+            Err(OneTimeTokenError::JwtError(
+                JwtError::UnexpectedTokenType { .. }
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_bound_one_time_token_requires_same_client() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .issue_bound_one_time_token(
+                "user123",
+                OneTimeToken::MAGIC_LINK,
+                Duration::minutes(15),
+                "203.0.113.7",
+            )
+            .expect("Failed to issue one-time token");
+
+        // Unbound redemption and another client are rejected without using up the token
+        assert!(matches!(
+            authenticator
+                .redeem_one_time_token(&token, OneTimeToken::MAGIC_LINK)
+                .await,
+            Err(OneTimeTokenError::BindingMismatch)
+        ));
+        assert!(matches!(
+            authenticator
+                .redeem_bound_one_time_token(&token, OneTimeToken::MAGIC_LINK, "198.51.100.1")
+                .await,
+            Err(OneTimeTokenError::BindingMismatch)
+        ));
+
+        let redeemed = authenticator
+            .redeem_bound_one_time_token(&token, OneTimeToken::MAGIC_LINK, "203.0.113.7")
+            .await
+            .expect("Failed to redeem bound one-time token");
+        assert_eq!(redeemed.subject, "user123");
+    }
+
+    #[tokio::test]
+    async fn test_unbound_one_time_token_accepts_any_client() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .issue_one_time_token("user123", OneTimeToken::MAGIC_LINK, Duration::minutes(15))
+            .expect("Failed to issue one-time token");
+
+        assert!(authenticator
+            .redeem_bound_one_time_token(&token, OneTimeToken::MAGIC_LINK, "203.0.113.7")
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_session_token_rejected_after_revocation() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let session = authenticator
+            .start_session("user123", DeviceInfo::default(), Duration::days(30))
+            .await
+            .expect("Failed to start session");
+        let token = authenticator
+            .generate_token(
+                &Claims::for_user("user123", "alice".to_string(), 1).with_session_id(&session.id),
+            )
+            .expect("Failed to generate token");
+
+        let claims = authenticator
+            .validate_session_token(&token)
+            .await
+            .expect("Failed to validate session token");
+        assert_eq!(claims.session_id(), Some(session.id.clone()));
+
+        authenticator
+            .revoke_session(&session.id)
+            .await
+            .expect("Failed to revoke session");
+        assert!(matches!(
+            authenticator.validate_session_token(&token).await,
+            Err(SessionError::Revoked)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_session_token_requires_session() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let token = authenticator
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 1))
+            .expect("Failed to generate token");
+        assert!(matches!(
+            authenticator.validate_session_token(&token).await,
+            Err(SessionError::JwtError(JwtError::MissingClaim(_)))
+        ));
+
+        // A session of another user does not validate the token
+        let session = authenticator
+            .start_session("user456", DeviceInfo::default(), Duration::days(30))
+            .await
+            .expect("Failed to start session");
+        let token = authenticator
+            .generate_token(
+                &Claims::for_user("user123", "alice".to_string(), 1).with_session_id(&session.id),
+            )
+            .expect("Failed to generate token");
+        assert!(matches!(
+            authenticator.validate_session_token(&token).await,
+            Err(SessionError::Revoked)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_revoke_other_sessions_keeps_current() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let phone = authenticator
+            .start_session(
+                "user123",
+                DeviceInfo {
+                    name: Some("phone".to_string()),
+                    ..DeviceInfo::default()
+                },
+                Duration::days(30),
+            )
+            .await
+            .expect("Failed to start session");
+        let laptop = authenticator
+            .start_session("user123", DeviceInfo::default(), Duration::days(30))
+            .await
+            .expect("Failed to start session");
+        authenticator
+            .start_session("user456", DeviceInfo::default(), Duration::days(30))
+            .await
+            .expect("Failed to start session");
+        authenticator
+            .start_session("user123", DeviceInfo::default(), Duration::hours(-1))
+            .await
+            .expect("Failed to start session");
+
+        let sessions = authenticator
+            .list_sessions("user123")
+            .await
+            .expect("Failed to list sessions");
+        assert_eq!(sessions.len(), 2);
+
+        let revoked = authenticator
+            .revoke_other_sessions("user123", &laptop.id)
+            .await
+            .expect("Failed to revoke sessions");
+        assert!(revoked >= 1);
+
+        let sessions = authenticator
+            .list_sessions("user123")
+            .await
+            .expect("Failed to list sessions");
+        assert_eq!(sessions, vec![laptop]);
+        assert!(!sessions.contains(&phone));
+        assert_eq!(
+            authenticator.list_sessions("user456").await.unwrap().len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revoke_all_sessions() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        for user_id in ["user123", "user123", "user456"] {
+            authenticator
+                .start_session(user_id, DeviceInfo::default(), Duration::days(30))
+                .await
+                .expect("Failed to start session");
+        }
+
+        let revoked = authenticator
+            .revoke_all_sessions("user123")
+            .await
+            .expect("Failed to revoke sessions");
+        assert_eq!(revoked, 2);
+
+        assert!(authenticator
+            .list_sessions("user123")
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            authenticator.list_sessions("user456").await.unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_user_token_rejected_as_service_token() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+
+        let claims = Claims::for_user("user123", "alice".to_string(), 1);
+        let token = authenticator
+            .generate_token(&claims)
+            .expect("Failed to generate token");
+
+        assert!(authenticator.validate_user_token(&token).is_ok());
+        assert!(matches!(
+            authenticator.validate_service_token(&token),
+            Err(JwtError::UnexpectedTokenType { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_revoked_access_token_rejected() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+        let token = authenticator
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 1))
+            .expect("Failed to generate token");
+        let other = authenticator
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 1))
+            .expect("Failed to generate token");
+
+        let claims = authenticator
+            .validate_access_token(&token)
+            .expect("Token validation failed");
+        authenticator
+            .revoke_access_token(&claims)
+            .await
+            .expect("Failed to revoke token");
+
+        assert!(matches!(
+            authenticator.ensure_not_revoked(&claims).await,
+            Err(RevocationError::Revoked)
+        ));
+        let other_claims = authenticator
+            .validate_access_token(&other)
+            .expect("Token validation failed");
+        assert!(authenticator
+            .ensure_not_revoked(&other_claims)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_cached_token_revoked_by_other_instance_rejected() {
+        let store: Arc<dyn RevocationStore> = Arc::new(InMemoryRevocationStore::new());
+        let instance = |store: &Arc<dyn RevocationStore>| {
+            Authenticator::new(b"test_secret_key_at_least_32_bytes!")
+                .with_revocation_store(Arc::clone(store))
+                .with_validation_cache(100, Duration::minutes(5))
+        };
+        let (first, second) = (instance(&store), instance(&store));
+        let token = first
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 1))
+            .expect("Failed to generate token");
+        first
+            .validate_access_token(&token)
+            .expect("Token validation failed");
+
+        let claims = second
+            .validate_access_token(&token)
+            .expect("Token validation failed");
+        second
+            .revoke_access_token(&claims)
+            .await
+            .expect("Failed to revoke token");
+
+        // Still cached by the first instance, but the revocation check drops it
+        let cached = first
+            .validate_access_token(&token)
+            .expect("Token validation failed");
+        assert!(matches!(
+            first.ensure_not_revoked(&cached).await,
+            Err(RevocationError::Revoked)
+        ));
+        assert!(first
+            .validation_cache
+            .as_ref()
+            .is_some_and(|cache| cache.get(&token).is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_revocation_requires_jti() {
+        let authenticator = Authenticator::new(b"test_secret_key_at_least_32_bytes!");
+        let mut claims = Claims::for_user("user123", "alice".to_string(), 1);
+        claims.jti = None;
+
+        let result = authenticator.revoke_access_token(&claims).await;
+
+        assert!(matches!(
+            result,
+            Err(RevocationError::JwtError(JwtError::MissingClaim(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_revoke_user_tokens_ends_every_login_of_the_user() {
+        let clock = Arc::new(clock::FakeClock::default());
+        let authenticator =
+            Authenticator::new(b"test_secret_key_at_least_32_bytes!").with_clock(clock.clone());
+        let claims = Claims::for_user("user123", "alice".to_string(), 1);
+        let phone = authenticator
+            .issue_token_pair(&claims)
+            .await
+            .expect("Failed to issue token pair");
+        let other_user = authenticator
+            .issue_token_pair(&Claims::for_user("user456", "bob".to_string(), 1))
+            .await
+            .expect("Failed to issue token pair");
+
+        clock.advance(Duration::seconds(1));
+        authenticator
+            .revoke_user_tokens("user123")
+            .await
+            .expect("Failed to revoke tokens");
+
+        let phone_claims = authenticator
+            .validate_access_token(&phone.access_token)
+            .expect("Token validation failed");
+        assert!(matches!(
+            authenticator.ensure_not_revoked(&phone_claims).await,
+            Err(RevocationError::Revoked)
+        ));
+        assert!(matches!(
+            authenticator.rotate_refresh(&phone.refresh_token).await,
+            Err(RefreshTokenError::Revoked)
+        ));
+
+        // Other users and later logins are unaffected
+        let other_claims = authenticator
+            .validate_access_token(&other_user.access_token)
+            .expect("Token validation failed");
+        assert!(authenticator
+            .ensure_not_revoked(&other_claims)
+            .await
+            .is_ok());
+        clock.advance(Duration::seconds(1));
+        let next_login = authenticator
+            .issue_token_pair(&claims)
+            .await
+            .expect("Failed to issue token pair");
+        let next_claims = authenticator
+            .validate_access_token(&next_login.access_token)
+            .expect("Token validation failed");
+        assert!(authenticator.ensure_not_revoked(&next_claims).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_default_stores_expire_records_on_fake_clock() {
+        let clock = Arc::new(clock::FakeClock::default());
+        let authenticator =
+            Authenticator::new(b"test_secret_key_at_least_32_bytes!").with_clock(clock.clone());
+        // Outlives the 30 day refresh token families
+        let token = authenticator
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 60 * 24))
+            .expect("Failed to generate token");
+        let claims = authenticator
+            .validate_access_token(&token)
+            .expect("Token validation failed");
+
+        clock.advance(Duration::seconds(1));
+        authenticator
+            .revoke_user_tokens("user123")
+            .await
+            .expect("Failed to revoke tokens");
+        assert!(authenticator.ensure_not_revoked(&claims).await.is_err());
+
+        // The revocation is dropped once every refresh token family it covered expired
+        clock.advance(Duration::days(31));
+        assert!(authenticator.ensure_not_revoked(&claims).await.is_ok());
     }
 }
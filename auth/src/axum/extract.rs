@@ -0,0 +1,23 @@
+use ::axum::async_trait;
+use ::axum::extract::FromRequestParts;
+use ::axum::http::request::Parts;
+
+use super::rejection::AuthRejection;
+use crate::jwt::Claims;
+
+/// Extracts the claims validated by [`super::AuthLayer`].
+#[async_trait]
+impl<S> FromRequestParts<S> for Claims
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<Claims>()
+            .cloned()
+            .ok_or(AuthRejection::MissingLayer)
+    }
+}
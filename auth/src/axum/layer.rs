@@ -0,0 +1,368 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use ::axum::extract::Request;
+use ::axum::http::header::AUTHORIZATION;
+use ::axum::http::HeaderMap;
+use ::axum::response::IntoResponse;
+use ::axum::response::Response;
+use tower::Layer;
+use tower::Service;
+
+use super::rejection::AuthRejection;
+use crate::authenticator::Authenticator;
+use crate::jwt::Claims;
+use crate::jwt::JwtError;
+use crate::personal_token::PersonalToken;
+use crate::personal_token::PersonalTokenError;
+use crate::personal_token::PersonalTokenVerifier;
+use crate::revocation::RevocationError;
+
+/// Tower layer authenticating requests with a bearer access token.
+///
+/// Valid requests reach the inner service with their [`Claims`] in the request
+/// extensions (see the `Claims` extractor); others are answered with an
+/// [`AuthRejection`]. Refresh, one-time and revoked tokens are rejected.
+///
+/// Personal access tokens are only accepted once a verifier is set with
+/// [`AuthLayer::with_personal_tokens`].
+#[derive(Clone)]
+pub struct AuthLayer {
+    authenticator: Arc<Authenticator>,
+    personal_tokens: Option<Arc<dyn PersonalTokenVerifier>>,
+}
+
+impl AuthLayer {
+    /// Create a layer validating tokens with the given authenticator.
+    ///
+    /// # Arguments
+    /// * `authenticator` - Authenticator holding the signing key
+    ///
+    /// # Returns
+    /// AuthLayer instance
+    pub fn new(authenticator: Arc<Authenticator>) -> Self {
+        Self {
+            authenticator,
+            personal_tokens: None,
+        }
+    }
+
+    /// Also accept personal access tokens, verified with their issuer.
+    ///
+    /// # Arguments
+    /// * `verifier` - Lookup of personal access tokens
+    ///
+    /// # Returns
+    /// AuthLayer instance accepting personal access tokens
+    pub fn with_personal_tokens(mut self, verifier: Arc<dyn PersonalTokenVerifier>) -> Self {
+        self.personal_tokens = Some(verifier);
+        self
+    }
+}
+
+impl<S> Layer<S> for AuthLayer {
+    type Service = AuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuthService {
+            inner,
+            authenticator: Arc::clone(&self.authenticator),
+            personal_tokens: self.personal_tokens.clone(),
+        }
+    }
+}
+
+/// Service produced by [`AuthLayer`].
+#[derive(Clone)]
+pub struct AuthService<S> {
+    inner: S,
+    authenticator: Arc<Authenticator>,
+    personal_tokens: Option<Arc<dyn PersonalTokenVerifier>>,
+}
+
+impl<S> Service<Request> for AuthService<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request) -> Self::Future {
+        let authenticator = Arc::clone(&self.authenticator);
+        let personal_tokens = self.personal_tokens.clone();
+        // Use the service that was driven to readiness and leave a fresh clone behind
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            match authenticate(&authenticator, personal_tokens.as_deref(), req.headers()).await {
+                Ok(claims) => {
+                    req.extensions_mut().insert(claims);
+                    inner.call(req).await
+                }
+                Err(rejection) => Ok(rejection.into_response()),
+            }
+        })
+    }
+}
+
+async fn authenticate(
+    authenticator: &Authenticator,
+    personal_tokens: Option<&dyn PersonalTokenVerifier>,
+    headers: &HeaderMap,
+) -> Result<Claims, AuthRejection> {
+    let token = bearer_token(headers)?;
+
+    if let Some(verifier) = personal_tokens.filter(|_| PersonalToken::is_personal_token(token)) {
+        return verifier.verify(token).await.map_err(|e| match e {
+            PersonalTokenError::Invalid => AuthRejection::InvalidPersonalToken,
+            PersonalTokenError::Unavailable(_) => AuthRejection::PersonalTokenUnavailable,
+        });
+    }
+
+    let claims = authenticator
+        .validate_access_token(token)
+        .map_err(AuthRejection::InvalidToken)?;
+
+    if claims.sub.is_none() {
+        return Err(AuthRejection::InvalidClaims);
+    }
+
+    authenticator
+        .ensure_not_revoked(&claims)
+        .await
+        .map_err(|e| match e {
+            RevocationError::Revoked => AuthRejection::InvalidToken(JwtError::Revoked),
+            RevocationError::JwtError(e) => AuthRejection::InvalidToken(e),
+            RevocationError::StoreError(_) => AuthRejection::RevocationUnavailable,
+        })?;
+
+    Ok(claims)
+}
+
+fn bearer_token(headers: &HeaderMap) -> Result<&str, AuthRejection> {
+    let value = headers
+        .get(AUTHORIZATION)
+        .ok_or(AuthRejection::MissingHeader)?
+        .to_str()
+        .map_err(|_| AuthRejection::InvalidHeader)?;
+
+    value
+        .strip_prefix("Bearer ")
+        .ok_or(AuthRejection::InvalidScheme)
+}
+
+#[cfg(test)]
+mod tests {
+    use ::axum::body::Body;
+    use ::axum::http::StatusCode;
+    use ::axum::routing::get;
+    use ::axum::Router;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const SECRET: &[u8] = b"test_secret_key_at_least_32_bytes!";
+
+    fn app() -> Router {
+        let authenticator = Arc::new(Authenticator::new(SECRET));
+        Router::new()
+            .route(
+                "/me",
+                get(|claims: Claims| async move { claims.sub.unwrap_or_default() }),
+            )
+            .route_layer(AuthLayer::new(authenticator))
+    }
+
+    fn request(authorization: Option<&str>) -> Request {
+        let mut builder = Request::builder().uri("/me");
+        if let Some(value) = authorization {
+            builder = builder.header(AUTHORIZATION, value);
+        }
+        builder
+            .body(Body::empty())
+            .expect("Failed to build request")
+    }
+
+    #[tokio::test]
+    async fn test_valid_token_reaches_handler_with_claims() {
+        let token = Authenticator::new(SECRET)
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 1))
+            .expect("Failed to generate token");
+
+        let response = app()
+            .oneshot(request(Some(&format!("Bearer {}", token))))
+            .await
+            .expect("Request failed");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Failed to read body");
+        assert_eq!(&body[..], b"user123");
+    }
+
+    #[tokio::test]
+    async fn test_missing_or_malformed_header_rejected() {
+        for authorization in [None, Some("Basic dXNlcjpwYXNz"), Some("Bearer not-a-jwt")] {
+            let response = app()
+                .oneshot(request(authorization))
+                .await
+                .expect("Request failed");
+
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejection_carries_error_code() {
+        let response = app()
+            .oneshot(request(Some("Bearer not-a-jwt")))
+            .await
+            .expect("Request failed");
+
+        let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Failed to read body");
+        let body: serde_json::Value = serde_json::from_slice(&body).expect("Body is not JSON");
+        assert_eq!(body["code"], "malformed_token");
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_rejected() {
+        let authenticator = Authenticator::new(SECRET);
+        let pair = authenticator
+            .issue_token_pair(&Claims::for_user("user123", "alice".to_string(), 1))
+            .await
+            .expect("Failed to issue token pair");
+
+        let response = app()
+            .oneshot(request(Some(&format!("Bearer {}", pair.refresh_token))))
+            .await
+            .expect("Request failed");
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_revoked_token_rejected() {
+        let authenticator = Arc::new(Authenticator::new(SECRET));
+        let token = authenticator
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 1))
+            .expect("Failed to generate token");
+        let claims = authenticator
+            .validate_access_token(&token)
+            .expect("Token validation failed");
+        authenticator
+            .revoke_access_token(&claims)
+            .await
+            .expect("Failed to revoke token");
+        let app = Router::new()
+            .route("/me", get(|| async {}))
+            .route_layer(AuthLayer::new(authenticator));
+
+        let response = app
+            .oneshot(request(Some(&format!("Bearer {}", token))))
+            .await
+            .expect("Request failed");
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Failed to read body");
+        let body: serde_json::Value = serde_json::from_slice(&body).expect("Body is not JSON");
+        assert_eq!(body["code"], "token_revoked");
+    }
+
+    /// Verifier knowing a single personal access token
+    struct SingleTokenVerifier(String);
+
+    #[async_trait::async_trait]
+    impl PersonalTokenVerifier for SingleTokenVerifier {
+        async fn verify(&self, token: &str) -> Result<Claims, PersonalTokenError> {
+            if token != self.0 {
+                return Err(PersonalTokenError::Invalid);
+            }
+            Ok(Claims::for_personal_token(
+                "user123",
+                "alice".to_string(),
+                "token123",
+                vec!["messages:read".to_string()],
+                None,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_personal_token_verified_with_issuer() {
+        let generated = PersonalToken::generate();
+        let token = generated.token.expose_secret().to_string();
+        let verifier = Arc::new(SingleTokenVerifier(token.clone()));
+        let app = || {
+            Router::new()
+                .route(
+                    "/me",
+                    get(|claims: Claims| async move { claims.scopes.join(" ") }),
+                )
+                .route_layer(
+                    AuthLayer::new(Arc::new(Authenticator::new(SECRET)))
+                        .with_personal_tokens(verifier.clone()),
+                )
+        };
+
+        let response = app()
+            .oneshot(request(Some(&format!("Bearer {}", token))))
+            .await
+            .expect("Request failed");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Failed to read body");
+        assert_eq!(&body[..], b"messages:read");
+
+        let other = PersonalToken::generate();
+        let response = app()
+            .oneshot(request(Some(&format!(
+                "Bearer {}",
+                other.token.expose_secret()
+            ))))
+            .await
+            .expect("Request failed");
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_personal_token_rejected_without_verifier() {
+        let generated = PersonalToken::generate();
+
+        let response = app()
+            .oneshot(request(Some(&format!(
+                "Bearer {}",
+                generated.token.expose_secret()
+            ))))
+            .await
+            .expect("Request failed");
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_extractor_without_layer_is_server_error() {
+        let response = Router::new()
+            .route("/me", get(|_claims: Claims| async {}))
+            .oneshot(request(None))
+            .await
+            .expect("Request failed");
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,11 @@
+//! Axum integration: bearer token middleware and claims extractor.
+//!
+//! Enabled with the `axum` feature.
+
+pub mod extract;
+pub mod layer;
+pub mod rejection;
+
+pub use layer::AuthLayer;
+pub use layer::AuthService;
+pub use rejection::AuthRejection;
@@ -0,0 +1,104 @@
+use ::axum::http::StatusCode;
+use ::axum::response::IntoResponse;
+use ::axum::response::Response;
+use ::axum::Json;
+use serde_json::json;
+use thiserror::Error;
+
+use crate::jwt::JwtError;
+
+/// Reason a request failed bearer token authentication.
+///
+/// Renders as `{"error": "...", "code": "..."}` with the matching status code.
+#[derive(Debug, Clone, Error)]
+pub enum AuthRejection {
+    #[error("Missing Authorization header")]
+    MissingHeader,
+
+    #[error("Invalid Authorization header")]
+    InvalidHeader,
+
+    #[error("Invalid Authorization header format. Expected: Bearer <token>")]
+    InvalidScheme,
+
+    #[error("Invalid or expired token")]
+    InvalidToken(JwtError),
+
+    #[error("Invalid token format")]
+    InvalidClaims,
+
+    /// The route needs an account and the token belongs to an anonymous guest.
+    #[error("Guests are not allowed to access this resource")]
+    GuestNotAllowed,
+
+    /// The personal access token is unknown, expired or revoked.
+    #[error("Invalid or expired personal access token")]
+    InvalidPersonalToken,
+
+    /// The personal access token could not be checked with its issuer.
+    #[error("Personal access token could not be verified")]
+    PersonalTokenUnavailable,
+
+    /// The token is a personal access token without the scope the route needs.
+    #[error("Token lacks required scope: {0}")]
+    InsufficientScope(String),
+
+    /// Revocation of the token could not be checked.
+    #[error("Token revocation could not be checked")]
+    RevocationUnavailable,
+
+    /// The extractor was used on a route without [`super::AuthLayer`].
+    #[error("Authentication is not configured for this route")]
+    MissingLayer,
+}
+
+impl AuthRejection {
+    /// HTTP status code of the rejection.
+    ///
+    /// # Returns
+    /// `500` for a missing layer (a routing bug), `503` if revocation or a personal
+    /// token cannot be checked, `403` for guests and missing scopes, `401` otherwise
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            AuthRejection::MissingLayer => StatusCode::INTERNAL_SERVER_ERROR,
+            AuthRejection::RevocationUnavailable | AuthRejection::PersonalTokenUnavailable => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            AuthRejection::GuestNotAllowed | AuthRejection::InsufficientScope(_) => {
+                StatusCode::FORBIDDEN
+            }
+            _ => StatusCode::UNAUTHORIZED,
+        }
+    }
+
+    /// Machine-readable code of the rejection.
+    ///
+    /// # Returns
+    /// Code of the JWT error for rejected tokens, a fixed code otherwise
+    pub fn code(&self) -> &'static str {
+        match self {
+            AuthRejection::MissingHeader => "missing_token",
+            AuthRejection::InvalidHeader | AuthRejection::InvalidScheme => {
+                "invalid_authorization_header"
+            }
+            AuthRejection::InvalidToken(e) => e.code(),
+            AuthRejection::InvalidClaims => "invalid_claims",
+            AuthRejection::GuestNotAllowed => "guest_not_allowed",
+            AuthRejection::InvalidPersonalToken => "invalid_personal_token",
+            AuthRejection::PersonalTokenUnavailable => "personal_token_unavailable",
+            AuthRejection::InsufficientScope(_) => "insufficient_scope",
+            AuthRejection::RevocationUnavailable => "revocation_unavailable",
+            AuthRejection::MissingLayer => "auth_not_configured",
+        }
+    }
+}
+
+impl IntoResponse for AuthRejection {
+    fn into_response(self) -> Response {
+        (
+            self.status_code(),
+            Json(json!({ "error": self.to_string(), "code": self.code() })),
+        )
+            .into_response()
+    }
+}
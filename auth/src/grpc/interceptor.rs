@@ -0,0 +1,238 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Duration;
+use tonic::metadata::MetadataValue;
+use tonic::service::Interceptor;
+use tonic::Request;
+use tonic::Status;
+
+use crate::authenticator::Authenticator;
+
+/// Metadata key carrying the bearer token.
+const AUTHORIZATION: &str = "authorization";
+
+/// Lifetime of tokens minted by [`ServiceTokenInterceptor`].
+const SERVICE_TOKEN_TTL_SECONDS: i64 = 60;
+
+/// Server interceptor validating a bearer access token from request metadata.
+///
+/// Valid calls reach the service with their [`Claims`](crate::Claims) in the request
+/// extensions; others fail with `UNAUTHENTICATED`. Refresh and one-time
+/// tokens are rejected.
+///
+/// Once restricted with [`JwtInterceptor::with_trusted_services`], only service
+/// tokens of the trusted services are accepted; other valid tokens fail with
+/// `PERMISSION_DENIED`.
+#[derive(Clone)]
+pub struct JwtInterceptor {
+    authenticator: Arc<Authenticator>,
+    trusted_services: Option<Arc<HashSet<String>>>,
+}
+
+impl JwtInterceptor {
+    /// Create an interceptor validating tokens with the given authenticator.
+    ///
+    /// # Arguments
+    /// * `authenticator` - Authenticator holding the signing key
+    ///
+    /// # Returns
+    /// JwtInterceptor instance
+    pub fn new(authenticator: Arc<Authenticator>) -> Self {
+        Self {
+            authenticator,
+            trusted_services: None,
+        }
+    }
+
+    /// Only accept service tokens of the given services.
+    ///
+    /// # Arguments
+    /// * `services` - Names of the services allowed to call, as in their token subject
+    ///
+    /// # Returns
+    /// JwtInterceptor rejecting user tokens and other services
+    pub fn with_trusted_services<I, S>(mut self, services: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.trusted_services = Some(Arc::new(services.into_iter().map(Into::into).collect()));
+        self
+    }
+}
+
+impl Interceptor for JwtInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let value = request
+            .metadata()
+            .get(AUTHORIZATION)
+            .ok_or_else(|| Status::unauthenticated("Missing authorization metadata"))?
+            .to_str()
+            .map_err(|_| Status::unauthenticated("Invalid authorization metadata"))?;
+
+        let token = value.strip_prefix("Bearer ").ok_or_else(|| {
+            Status::unauthenticated("Invalid authorization format. Expected: Bearer <token>")
+        })?;
+
+        let claims = self
+            .authenticator
+            .validate_access_token(token)
+            .map_err(|e| {
+                Status::unauthenticated(format!("Invalid or expired token ({})", e.code()))
+            })?;
+
+        if let Some(trusted_services) = &self.trusted_services {
+            if !claims.is_service() {
+                return Err(Status::permission_denied("Service token required"));
+            }
+            let service = claims.sub.as_deref().unwrap_or_default();
+            if !trusted_services.contains(service) {
+                return Err(Status::permission_denied(format!(
+                    "Service '{}' is not a trusted caller",
+                    service
+                )));
+            }
+        }
+
+        request.extensions_mut().insert(claims);
+        Ok(request)
+    }
+}
+
+/// Client interceptor attaching a service token to every call.
+///
+/// A fresh short-lived token is minted per call, so long-lived clients never
+/// send an expired token.
+#[derive(Clone)]
+pub struct ServiceTokenInterceptor {
+    authenticator: Arc<Authenticator>,
+    service_name: String,
+    scopes: Vec<String>,
+}
+
+impl ServiceTokenInterceptor {
+    /// Create an interceptor authenticating as the given service.
+    ///
+    /// # Arguments
+    /// * `authenticator` - Authenticator holding the signing key
+    /// * `service_name` - Name of the calling service
+    /// * `scopes` - Scopes granted to the calls
+    ///
+    /// # Returns
+    /// ServiceTokenInterceptor instance
+    pub fn new(authenticator: Arc<Authenticator>, service_name: &str, scopes: &[&str]) -> Self {
+        Self {
+            authenticator,
+            service_name: service_name.to_string(),
+            scopes: scopes.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl Interceptor for ServiceTokenInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+        let token = self
+            .authenticator
+            .issue_service_token(
+                &self.service_name,
+                &scopes,
+                Duration::seconds(SERVICE_TOKEN_TTL_SECONDS),
+            )
+            .map_err(|e| Status::internal(format!("Failed to create service token: {}", e)))?;
+
+        let value = MetadataValue::try_from(format!("Bearer {}", token))
+            .map_err(|e| Status::internal(e.to_string()))?;
+        request.metadata_mut().insert(AUTHORIZATION, value);
+
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jwt::Claims;
+
+    const SECRET: &[u8] = b"test_secret_key_at_least_32_bytes!";
+
+    fn authenticator() -> Arc<Authenticator> {
+        Arc::new(Authenticator::new(SECRET))
+    }
+
+    #[test]
+    fn test_service_token_accepted_by_jwt_interceptor() {
+        let mut client =
+            ServiceTokenInterceptor::new(authenticator(), "chat-service", &["users:read"]);
+        let mut server = JwtInterceptor::new(authenticator());
+
+        let request = client.call(Request::new(())).unwrap();
+        let request = server.call(request).unwrap();
+
+        let claims = request.extensions().get::<Claims>().unwrap();
+        assert!(claims.is_service());
+        assert_eq!(claims.sub.as_deref(), Some("chat-service"));
+        assert!(claims.has_scope("users:read"));
+    }
+
+    #[test]
+    fn test_missing_or_invalid_token_unauthenticated() {
+        let mut server = JwtInterceptor::new(authenticator());
+
+        let status = server.call(Request::new(())).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        let mut request = Request::new(());
+        request.metadata_mut().insert(
+            AUTHORIZATION,
+            MetadataValue::from_static("Bearer not-a-jwt"),
+        );
+        let status = server.call(request).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn test_trusted_services_only_accept_their_service_tokens() {
+        let mut server =
+            JwtInterceptor::new(authenticator()).with_trusted_services(["chat-service"]);
+
+        let mut trusted = ServiceTokenInterceptor::new(authenticator(), "chat-service", &[]);
+        let request = trusted.call(Request::new(())).unwrap();
+        assert!(server.call(request).is_ok());
+
+        let mut untrusted = ServiceTokenInterceptor::new(authenticator(), "billing-service", &[]);
+        let request = untrusted.call(Request::new(())).unwrap();
+        let status = server.call(request).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[test]
+    fn test_trusted_services_reject_user_tokens() {
+        let authenticator = authenticator();
+        let token = authenticator
+            .generate_token(&Claims::for_user("user123", "alice".to_string(), 1))
+            .unwrap();
+        let mut server =
+            JwtInterceptor::new(Arc::clone(&authenticator)).with_trusted_services(["chat-service"]);
+
+        let mut request = Request::new(());
+        request.metadata_mut().insert(
+            AUTHORIZATION,
+            MetadataValue::try_from(format!("Bearer {}", token)).unwrap(),
+        );
+        let status = server.call(request).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[test]
+    fn test_token_signed_with_other_key_rejected() {
+        let other = Arc::new(Authenticator::new(b"another_secret_key_at_least_32_bytes"));
+        let mut client = ServiceTokenInterceptor::new(other, "chat-service", &[]);
+        let mut server = JwtInterceptor::new(authenticator());
+
+        let request = client.call(Request::new(())).unwrap();
+        let status = server.call(request).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+}
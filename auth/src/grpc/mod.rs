@@ -0,0 +1,8 @@
+//! Tonic integration: JWT interceptors for gRPC servers and clients.
+//!
+//! Enabled with the `grpc` feature.
+
+pub mod interceptor;
+
+pub use interceptor::JwtInterceptor;
+pub use interceptor::ServiceTokenInterceptor;
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use clock::Clock;
+use ring::digest::digest;
+use ring::digest::SHA256;
+
+use super::claims::Claims;
+
+/// Bounded cache of access tokens that passed validation.
+///
+/// Saves the signature check (and decryption) of tokens presented again and
+/// again, e.g. by clients polling the API. Tokens are keyed by their SHA-256,
+/// so the cache never holds usable credentials. An entry lives for the cache
+/// TTL or until the token expires, whichever comes first, and is dropped
+/// early once the token is revoked.
+pub struct ValidationCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<HashMap<Vec<u8>, CachedClaims>>,
+    clock: Arc<dyn Clock>,
+}
+
+struct CachedClaims {
+    claims: Claims,
+    expires_at: DateTime<Utc>,
+}
+
+impl ValidationCache {
+    /// Create an empty cache.
+    ///
+    /// # Arguments
+    /// * `capacity` - Maximum number of cached tokens
+    /// * `ttl` - Maximum time a token stays cached
+    ///
+    /// # Returns
+    /// ValidationCache instance
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            clock: clock::system(),
+        }
+    }
+
+    /// Expire entries by another clock.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock entries expire by, the system clock by default
+    ///
+    /// # Returns
+    /// ValidationCache expiring entries by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Claims of a token validated earlier.
+    ///
+    /// # Arguments
+    /// * `token` - JWT token string
+    ///
+    /// # Returns
+    /// Claims of the token, None if it is not cached or its entry expired
+    pub fn get(&self, token: &str) -> Option<Claims> {
+        // A poisoned cache is bypassed, tokens are then validated again
+        let entries = self.entries.lock().ok()?;
+
+        entries
+            .get(&key(token))
+            .filter(|entry| entry.expires_at > self.clock.now())
+            .map(|entry| entry.claims.clone())
+    }
+
+    /// Remember a validated token.
+    ///
+    /// Nothing is cached once the cache is full of live entries.
+    ///
+    /// # Arguments
+    /// * `token` - JWT token string
+    /// * `claims` - Claims the token was validated to
+    pub fn insert(&self, token: &str, claims: &Claims) {
+        let now = self.clock.now();
+        let mut expires_at = now + self.ttl;
+        if let Some(exp) = claims.exp.and_then(|exp| DateTime::from_timestamp(exp, 0)) {
+            expires_at = expires_at.min(exp);
+        }
+        if expires_at <= now {
+            return;
+        }
+
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+
+        if entries.len() >= self.capacity {
+            entries.retain(|_, entry| entry.expires_at > now);
+            if entries.len() >= self.capacity {
+                return;
+            }
+        }
+
+        entries.insert(
+            key(token),
+            CachedClaims {
+                claims: claims.clone(),
+                expires_at,
+            },
+        );
+    }
+
+    /// Drop a revoked token.
+    ///
+    /// # Arguments
+    /// * `jti` - Unique identifier of the token
+    pub fn invalidate(&self, jti: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|_, entry| entry.claims.jti() != Some(jti));
+        }
+    }
+}
+
+fn key(token: &str) -> Vec<u8> {
+    digest(&SHA256, token.as_bytes()).as_ref().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claims(jti: &str) -> Claims {
+        Claims::for_user("user123", "alice".to_string(), 1).with_jti(jti)
+    }
+
+    #[test]
+    fn test_cached_token_returns_claims() {
+        let cache = ValidationCache::new(10, Duration::minutes(5));
+        cache.insert("token", &claims("jti-1"));
+
+        let cached = cache.get("token").expect("Token should be cached");
+        assert_eq!(cached.jti(), Some("jti-1"));
+        assert!(cache.get("other").is_none());
+    }
+
+    #[test]
+    fn test_entry_does_not_outlive_token() {
+        let cache = ValidationCache::new(10, Duration::minutes(5));
+        let mut expired = claims("jti-1");
+        expired.exp = Some((Utc::now() - Duration::seconds(1)).timestamp());
+
+        cache.insert("token", &expired);
+
+        assert!(cache.get("token").is_none());
+    }
+
+    #[test]
+    fn test_full_cache_skips_new_tokens() {
+        let cache = ValidationCache::new(1, Duration::minutes(5));
+        cache.insert("first", &claims("jti-1"));
+        cache.insert("second", &claims("jti-2"));
+
+        assert!(cache.get("first").is_some());
+        assert!(cache.get("second").is_none());
+    }
+
+    #[test]
+    fn test_invalidate_drops_revoked_token() {
+        let cache = ValidationCache::new(10, Duration::minutes(5));
+        cache.insert("first", &claims("jti-1"));
+        cache.insert("second", &claims("jti-2"));
+
+        cache.invalidate("jti-1");
+
+        assert!(cache.get("first").is_none());
+        assert!(cache.get("second").is_some());
+    }
+}
@@ -1,15 +1,50 @@
 use std::collections::HashMap;
 
+use chrono::DateTime;
 use chrono::Duration;
 use chrono::Utc;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
+use uuid::Uuid;
+
+/// Claim carrying the fingerprint of the device a token is bound to.
+const DEVICE_FINGERPRINT_CLAIM: &str = "dfp";
+
+/// Claim marking tokens of anonymous guests.
+const GUEST_CLAIM: &str = "guest";
+
+/// Prefix of the generated subject of guest tokens.
+///
+/// Keeps guest subjects apart from user IDs, so a guest token never parses as
+/// the token of an account.
+pub const GUEST_SUBJECT_PREFIX: &str = "guest:";
+
+/// Kind of principal a token was issued to.
+///
+/// Distinguishes end-user tokens from machine tokens minted for
+/// service-to-service calls so that neither can be used in place of the other.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenType {
+    /// Token issued to an end user after login
+    User,
+    /// Short-lived machine token issued to an internal service
+    Service,
+    /// Refresh token, only exchangeable for a new token pair
+    Refresh,
+    /// Single-use action token (password reset, email verification, ...)
+    OneTime,
+    /// Personal access token of a user, limited to its scopes; never a JWT
+    Personal,
+}
 
 /// Generic JWT claims structure.
 ///
 /// Supports standard RFC 7519 claims plus custom fields via `extra` map.
-/// All standard fields are optional for maximum flexibility.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// All standard fields are optional for maximum flexibility; `Default` leaves
+/// every field empty while the constructors populate `jti` and `iat`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct Claims {
     /// Subject (user/entity identifier)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -39,15 +74,41 @@ pub struct Claims {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub jti: Option<String>,
 
+    /// Authorized party (service the token was issued to)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azp: Option<String>,
+
+    /// Token type (user or service); absent on legacy user tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<TokenType>,
+
+    /// Roles held by the subject (e.g. `admin`, `moderator`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+
+    /// Scopes granted to the token, as the space-delimited `scope` claim
+    #[serde(
+        rename = "scope",
+        alias = "scopes",
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        with = "scope_claim"
+    )]
+    pub scopes: Vec<String>,
+
     /// Additional custom fields (flattened into token)
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl Claims {
-    /// Create new empty claims.
+    /// Create new claims with a random `jti` and `iat` set to now.
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            iat: Some(Utc::now().timestamp()),
+            jti: Some(Uuid::new_v4().to_string()),
+            ..Self::default()
+        }
     }
 
     /// Create claims for user authentication with automatic expiration.
@@ -58,7 +119,7 @@ impl Claims {
     /// * `expiration_hours` - Hours until token expires
     ///
     /// # Returns
-    /// Claims with sub, exp, iat, and username set
+    /// Claims with sub, exp, iat, jti, and username set
     pub fn for_user(user_id: impl ToString, username: String, expiration_hours: i64) -> Self {
         let now = Utc::now();
         let expiration = now + Duration::hours(expiration_hours);
@@ -73,11 +134,95 @@ impl Claims {
             nbf: None,
             iss: None,
             aud: None,
-            jti: None,
+            jti: Some(Uuid::new_v4().to_string()),
+            azp: None,
+            token_type: Some(TokenType::User),
+            roles: Vec::new(),
+            scopes: Vec::new(),
             extra,
         }
     }
 
+    /// Create claims for a short-lived service-to-service token.
+    ///
+    /// # Arguments
+    /// * `service_name` - Name of the calling service (stored in `sub` and `azp`)
+    /// * `scopes` - Scopes granted to the service
+    /// * `ttl` - Time until token expires
+    ///
+    /// # Returns
+    /// Claims with sub, azp, token_type, scopes, exp, iat, and jti set
+    pub fn for_service(service_name: &str, scopes: &[&str], ttl: Duration) -> Self {
+        let now = Utc::now();
+        let expiration = now + ttl;
+
+        Self {
+            sub: Some(service_name.to_string()),
+            exp: Some(expiration.timestamp()),
+            iat: Some(now.timestamp()),
+            jti: Some(Uuid::new_v4().to_string()),
+            azp: Some(service_name.to_string()),
+            token_type: Some(TokenType::Service),
+            scopes: scopes.iter().map(|s| s.to_string()).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Create claims for a request authenticated with a personal access token.
+    ///
+    /// Personal access tokens are opaque; the service verifying one builds
+    /// these claims from the issuer's record of the token.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the token
+    /// * `username` - Username of the owner (stored in `extra.username`)
+    /// * `token_id` - Identifier of the token (stored in `jti`)
+    /// * `scopes` - Scopes granted to the token
+    /// * `expires_at` - Expiration of the token, None if it does not expire
+    ///
+    /// # Returns
+    /// Claims with sub, token_type, scopes, jti, iat, username and exp if set
+    pub fn for_personal_token(
+        user_id: impl ToString,
+        username: String,
+        token_id: impl ToString,
+        scopes: Vec<String>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            sub: Some(user_id.to_string()),
+            exp: expires_at.map(|expires_at| expires_at.timestamp()),
+            iat: Some(Utc::now().timestamp()),
+            jti: Some(token_id.to_string()),
+            token_type: Some(TokenType::Personal),
+            scopes,
+            ..Self::default()
+        }
+        .with_extra("username", username)
+    }
+
+    /// Create claims for an anonymous guest without an account.
+    ///
+    /// # Arguments
+    /// * `ttl` - Time until token expires
+    ///
+    /// # Returns
+    /// User claims with a generated `guest:` subject and the `guest` claim set
+    pub fn for_guest(ttl: Duration) -> Self {
+        let now = Utc::now();
+        let expiration = now + ttl;
+
+        Self {
+            sub: Some(format!("{}{}", GUEST_SUBJECT_PREFIX, Uuid::new_v4())),
+            exp: Some(expiration.timestamp()),
+            iat: Some(now.timestamp()),
+            jti: Some(Uuid::new_v4().to_string()),
+            token_type: Some(TokenType::User),
+            ..Self::default()
+        }
+        .with_extra(GUEST_CLAIM, true)
+    }
+
     /// Set subject.
     pub fn with_subject(mut self, sub: impl ToString) -> Self {
         self.sub = Some(sub.to_string());
@@ -96,6 +241,12 @@ impl Claims {
         self
     }
 
+    /// Set JWT ID.
+    pub fn with_jti(mut self, jti: impl ToString) -> Self {
+        self.jti = Some(jti.to_string());
+        self
+    }
+
     /// Set issuer.
     pub fn with_issuer(mut self, iss: String) -> Self {
         self.iss = Some(iss);
@@ -108,6 +259,32 @@ impl Claims {
         self
     }
 
+    /// Set token type.
+    pub fn with_token_type(mut self, token_type: TokenType) -> Self {
+        self.token_type = Some(token_type);
+        self
+    }
+
+    /// Set roles.
+    pub fn with_roles<I, S>(mut self, roles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        self.roles = roles.into_iter().map(|r| r.to_string()).collect();
+        self
+    }
+
+    /// Set scopes.
+    pub fn with_scopes<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        self.scopes = scopes.into_iter().map(|s| s.to_string()).collect();
+        self
+    }
+
     /// Add a custom field.
     pub fn with_extra(mut self, key: impl ToString, value: impl Serialize) -> Self {
         if let Ok(json_value) = serde_json::to_value(value) {
@@ -116,6 +293,31 @@ impl Claims {
         self
     }
 
+    /// Attach a service-specific claim (e.g. `tenant_id`).
+    ///
+    /// Alias of [`Claims::with_extra`]; the claim is flattened into the token payload.
+    pub fn with_claim(self, key: impl ToString, value: impl Serialize) -> Self {
+        self.with_extra(key, value)
+    }
+
+    /// Read a service-specific claim as a typed value.
+    ///
+    /// # Arguments
+    /// * `key` - Claim name
+    ///
+    /// # Returns
+    /// Claim value, or None if absent or not deserializable as `T`
+    pub fn claim<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.extra
+            .get(key)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Check if a service-specific claim is present.
+    pub fn has_claim(&self, key: &str) -> bool {
+        self.extra.contains_key(key)
+    }
+
     /// Get username from extra fields (convenience method).
     pub fn username(&self) -> Option<String> {
         self.extra
@@ -124,24 +326,129 @@ impl Claims {
             .map(|s| s.to_string())
     }
 
+    /// Set the session the token belongs to (`sid` claim).
+    pub fn with_session_id(self, session_id: impl ToString) -> Self {
+        self.with_extra("sid", session_id.to_string())
+    }
+
+    /// Get the session the token belongs to, if any (convenience method).
+    pub fn session_id(&self) -> Option<String> {
+        self.extra
+            .get("sid")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    }
+
+    /// Bind the token to a device (`dfp` claim).
+    ///
+    /// The fingerprint is an opaque hash of a stable device identifier, see
+    /// [`Authenticator::device_fingerprint`](crate::Authenticator::device_fingerprint).
+    pub fn with_device_fingerprint(self, fingerprint: impl ToString) -> Self {
+        self.with_extra(DEVICE_FINGERPRINT_CLAIM, fingerprint.to_string())
+    }
+
+    /// Get the fingerprint of the device the token is bound to, if any.
+    pub fn device_fingerprint(&self) -> Option<&str> {
+        self.extra
+            .get(DEVICE_FINGERPRINT_CLAIM)
+            .and_then(|v| v.as_str())
+    }
+
+    /// Check if the token was issued to an anonymous guest.
+    pub fn is_guest(&self) -> bool {
+        self.claim::<bool>(GUEST_CLAIM).unwrap_or(false)
+    }
+
+    /// Get the unique token identifier, if any.
+    pub fn jti(&self) -> Option<&str> {
+        self.jti.as_deref()
+    }
+
+    /// Get the issued-at time, if any.
+    pub fn issued_at(&self) -> Option<DateTime<Utc>> {
+        self.iat.and_then(|iat| DateTime::from_timestamp(iat, 0))
+    }
+
+    /// Get the token type, treating tokens without the claim as user tokens.
+    pub fn token_type(&self) -> TokenType {
+        self.token_type.unwrap_or(TokenType::User)
+    }
+
+    /// Check if the token was issued to an internal service.
+    pub fn is_service(&self) -> bool {
+        self.token_type() == TokenType::Service
+    }
+
+    /// Check if the token is a refresh token.
+    pub fn is_refresh(&self) -> bool {
+        self.token_type() == TokenType::Refresh
+    }
+
+    /// Check if the token is a personal access token.
+    pub fn is_personal(&self) -> bool {
+        self.token_type() == TokenType::Personal
+    }
+
+    /// Check if the token may be used for an action needing a scope.
+    ///
+    /// Personal access tokens are limited to their scopes; other tokens act
+    /// with the full rights of their subject.
+    pub fn allows_scope(&self, scope: &str) -> bool {
+        !self.is_personal() || self.has_scope(scope)
+    }
+
+    /// Check if the subject holds the given role.
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+
+    /// Check if the subject holds at least one of the given roles.
+    pub fn has_any_role(&self, roles: &[&str]) -> bool {
+        roles.iter().any(|role| self.has_role(role))
+    }
+
+    /// Check if the token grants the given scope.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+
+    /// Check if the token grants every one of the given scopes.
+    pub fn has_all_scopes(&self, scopes: &[&str]) -> bool {
+        scopes.iter().all(|scope| self.has_scope(scope))
+    }
+
     /// Check if token is expired.
     pub fn is_expired(&self, current_timestamp: i64) -> bool {
-        self.exp.map_or(false, |exp| exp < current_timestamp)
+        self.exp.is_some_and(|exp| exp < current_timestamp)
     }
 }
 
-impl Default for Claims {
-    fn default() -> Self {
-        Self {
-            sub: None,
-            exp: None,
-            iat: None,
-            nbf: None,
-            iss: None,
-            aud: None,
-            jti: None,
-            extra: HashMap::new(),
-        }
+/// Serde adapter for the RFC 8693 `scope` claim: one space-delimited string.
+///
+/// Arrays are still accepted, as written by earlier versions under `scopes`.
+mod scope_claim {
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serializer;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Scope {
+        Delimited(String),
+        List(Vec<String>),
+    }
+
+    pub fn serialize<S: Serializer>(scopes: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&scopes.join(" "))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<String>, D::Error> {
+        Ok(match Scope::deserialize(deserializer)? {
+            Scope::Delimited(scope) => scope.split_whitespace().map(str::to_string).collect(),
+            Scope::List(scopes) => scopes,
+        })
     }
 }
 
@@ -154,6 +461,27 @@ mod tests {
         let claims = Claims::new().with_subject("user123");
         assert_eq!(claims.sub, Some("user123".to_string()));
         assert!(claims.exp.is_none());
+        assert!(claims.iat.is_some());
+        assert!(Uuid::parse_str(claims.jti().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_jti_is_unique_per_token() {
+        let first = Claims::for_user("user123", "alice".to_string(), 24);
+        let second = Claims::for_user("user123", "alice".to_string(), 24);
+        let service = Claims::for_service("chat-service", &[], Duration::minutes(5));
+
+        assert!(first.jti().is_some());
+        assert!(service.jti().is_some());
+        assert_ne!(first.jti(), second.jti());
+        assert_eq!(first.issued_at().map(|iat| iat.timestamp()), first.iat);
+    }
+
+    #[test]
+    fn test_default_claims_are_empty() {
+        let claims = Claims::default();
+        assert!(claims.jti.is_none());
+        assert!(claims.iat.is_none());
     }
 
     #[test]
@@ -186,6 +514,124 @@ mod tests {
         assert_eq!(claims.extra.get("role").unwrap().as_str(), Some("admin"));
     }
 
+    #[test]
+    fn test_for_service() {
+        let claims = Claims::for_service("chat-service", &["users:read"], Duration::minutes(5));
+
+        assert_eq!(claims.sub, Some("chat-service".to_string()));
+        assert_eq!(claims.azp, Some("chat-service".to_string()));
+        assert!(claims.is_service());
+        assert!(claims.has_scope("users:read"));
+        assert!(!claims.has_scope("users:write"));
+        assert!(claims.username().is_none());
+
+        let exp = claims.exp.unwrap();
+        let iat = claims.iat.unwrap();
+        assert_eq!(exp - iat, 5 * 60);
+    }
+
+    #[test]
+    fn test_for_guest() {
+        let claims = Claims::for_guest(Duration::hours(1));
+
+        assert!(claims.is_guest());
+        assert!(claims
+            .sub
+            .as_ref()
+            .unwrap()
+            .starts_with(GUEST_SUBJECT_PREFIX));
+        assert_eq!(claims.token_type(), TokenType::User);
+        assert!(claims.username().is_none());
+        assert_eq!(claims.exp.unwrap() - claims.iat.unwrap(), 60 * 60);
+
+        assert!(!Claims::for_user("user123", "alice".to_string(), 1).is_guest());
+    }
+
+    #[test]
+    fn test_token_type_defaults_to_user() {
+        let claims: Claims = serde_json::from_str(r#"{"sub":"user123"}"#).unwrap();
+
+        assert_eq!(claims.token_type(), TokenType::User);
+        assert!(!claims.is_service());
+        assert!(claims.scopes.is_empty());
+    }
+
+    #[test]
+    fn test_roles_and_scopes() {
+        let claims = Claims::for_user("user123", "alice".to_string(), 24)
+            .with_roles(["admin", "moderator"])
+            .with_scopes(["channels:write", "messages:delete"]);
+
+        assert!(claims.has_role("admin"));
+        assert!(claims.has_role("moderator"));
+        assert!(!claims.has_role("owner"));
+        assert!(claims.has_any_role(&["owner", "moderator"]));
+        assert!(!claims.has_any_role(&["owner"]));
+        assert!(claims.has_all_scopes(&["channels:write", "messages:delete"]));
+        assert!(!claims.has_all_scopes(&["channels:write", "users:delete"]));
+    }
+
+    #[test]
+    fn test_personal_token_limited_to_its_scopes() {
+        let expires_at = Utc::now() + Duration::days(30);
+        let claims = Claims::for_personal_token(
+            "user123",
+            "alice".to_string(),
+            "token123",
+            vec!["messages:read".to_string()],
+            Some(expires_at),
+        );
+
+        assert!(claims.is_personal());
+        assert_eq!(claims.jti(), Some("token123"));
+        assert_eq!(claims.username(), Some("alice".to_string()));
+        assert_eq!(claims.exp, Some(expires_at.timestamp()));
+        assert!(claims.allows_scope("messages:read"));
+        assert!(!claims.allows_scope("messages:write"));
+
+        let user = Claims::for_user("user123", "alice".to_string(), 1);
+        assert!(user.allows_scope("messages:write"));
+    }
+
+    #[test]
+    fn test_roles_round_trip() {
+        let claims = Claims::new().with_subject("user123").with_roles(["admin"]);
+
+        let json = serde_json::to_value(&claims).unwrap();
+        assert_eq!(json["roles"], serde_json::json!(["admin"]));
+        assert!(json.get("scopes").is_none());
+
+        let decoded: Claims = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, claims);
+        assert!(decoded.extra.is_empty());
+    }
+
+    #[test]
+    fn test_custom_claims() {
+        let claims = Claims::for_user("user123", "alice".to_string(), 24)
+            .with_claim("tenant_id", "acme")
+            .with_claim("max_channels", 50);
+
+        assert!(claims.has_claim("tenant_id"));
+        assert_eq!(
+            claims.claim::<String>("tenant_id"),
+            Some("acme".to_string())
+        );
+        assert_eq!(claims.claim::<u32>("max_channels"), Some(50));
+        assert_eq!(claims.claim::<u32>("tenant_id"), None);
+        assert_eq!(claims.claim::<String>("missing"), None);
+
+        let json = serde_json::to_value(&claims).unwrap();
+        assert_eq!(json["tenant_id"], "acme");
+
+        let decoded: Claims = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            decoded.claim::<String>("tenant_id"),
+            Some("acme".to_string())
+        );
+        assert_eq!(decoded.username(), Some("alice".to_string()));
+    }
+
     #[test]
     fn test_is_expired() {
         let claims = Claims::new().with_expiration(1000);
@@ -200,4 +646,22 @@ mod tests {
         let claims = Claims::new();
         assert!(!claims.is_expired(9999999999)); // Never expires without exp
     }
+
+    #[test]
+    fn test_scope_claim_is_space_delimited() {
+        let claims = Claims::for_service(
+            "chat-service",
+            &["users:read", "users:watch"],
+            Duration::minutes(5),
+        );
+
+        let json = serde_json::to_value(&claims).unwrap();
+        assert_eq!(json["scope"], "users:read users:watch");
+        assert!(json.get("scopes").is_none());
+
+        let legacy: Claims =
+            serde_json::from_value(serde_json::json!({ "scopes": ["users:read"] })).unwrap();
+        assert!(legacy.has_scope("users:read"));
+        assert!(legacy.extra.is_empty());
+    }
 }
@@ -0,0 +1,181 @@
+use data_encoding::BASE64URL_NOPAD;
+use ring::aead::Aad;
+use ring::aead::LessSafeKey;
+use ring::aead::Nonce;
+use ring::aead::UnboundKey;
+use ring::aead::AES_256_GCM;
+use ring::aead::NONCE_LEN;
+use ring::rand::SecureRandom;
+use ring::rand::SystemRandom;
+use serde::Deserialize;
+
+use super::errors::JwtError;
+use crate::secret::SecretBytes;
+
+/// Protected header of every encrypted token: direct key agreement, AES-256-GCM
+/// content encryption, and a nested signed JWT as payload.
+const PROTECTED_HEADER: &str = r#"{"alg":"dir","enc":"A256GCM","cty":"JWT"}"#;
+
+/// Length of the content encryption key (256 bits).
+const KEY_LENGTH: usize = 32;
+
+#[derive(Deserialize)]
+struct JweHeader {
+    alg: String,
+    enc: String,
+}
+
+/// JWE (RFC 7516) compact serialization of signed tokens with A256GCM.
+///
+/// Tokens are signed first and the whole JWS is encrypted, so claims are
+/// only readable by holders of the encryption key.
+pub(crate) struct TokenEncryptor {
+    key: LessSafeKey,
+    rng: SystemRandom,
+}
+
+impl TokenEncryptor {
+    /// Create an encryptor from a 256-bit key.
+    ///
+    /// # Errors
+    /// * `InvalidEncryptionKey` - Key is not 32 bytes long
+    pub(crate) fn new(key: &SecretBytes) -> Result<Self, JwtError> {
+        if key.expose_secret().len() != KEY_LENGTH {
+            return Err(JwtError::InvalidEncryptionKey(format!(
+                "A256GCM requires a {} byte key, got {}",
+                KEY_LENGTH,
+                key.expose_secret().len()
+            )));
+        }
+
+        let key = UnboundKey::new(&AES_256_GCM, key.expose_secret())
+            .map_err(|_| JwtError::InvalidEncryptionKey("rejected by AES-256-GCM".to_string()))?;
+
+        Ok(Self {
+            key: LessSafeKey::new(key),
+            rng: SystemRandom::new(),
+        })
+    }
+
+    /// Whether a token uses the five-part JWE compact serialization.
+    pub(crate) fn is_encrypted(token: &str) -> bool {
+        token.split('.').count() == 5
+    }
+
+    /// Encrypt a signed token.
+    ///
+    /// # Errors
+    /// * `EncodingFailed` - No random IV could be generated or sealing failed
+    pub(crate) fn encrypt(&self, signed_token: &str) -> Result<String, JwtError> {
+        let mut iv = [0u8; NONCE_LEN];
+        self.rng
+            .fill(&mut iv)
+            .map_err(|_| JwtError::EncodingFailed("failed to generate IV".to_string()))?;
+
+        let header = BASE64URL_NOPAD.encode(PROTECTED_HEADER.as_bytes());
+        let mut content = signed_token.as_bytes().to_vec();
+        let tag = self
+            .key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(iv),
+                Aad::from(header.as_bytes()),
+                &mut content,
+            )
+            .map_err(|_| JwtError::EncodingFailed("token encryption failed".to_string()))?;
+
+        Ok(format!(
+            "{}..{}.{}.{}",
+            header,
+            BASE64URL_NOPAD.encode(&iv),
+            BASE64URL_NOPAD.encode(&content),
+            BASE64URL_NOPAD.encode(tag.as_ref())
+        ))
+    }
+
+    /// Decrypt a token back to the signed token it wraps.
+    ///
+    /// # Errors
+    /// * `Malformed` - Token is not a compact JWE
+    /// * `InvalidToken` - Token uses another algorithm or fails authentication
+    pub(crate) fn decrypt(&self, token: &str) -> Result<String, JwtError> {
+        let parts: Vec<&str> = token.split('.').collect();
+        let [header, encrypted_key, iv, ciphertext, tag] = parts.as_slice() else {
+            return Err(JwtError::Malformed("not a JWE token".to_string()));
+        };
+
+        let parsed: JweHeader = BASE64URL_NOPAD
+            .decode(header.as_bytes())
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| JwtError::Malformed("malformed JWE header".to_string()))?;
+        if parsed.alg != "dir" || parsed.enc != "A256GCM" || !encrypted_key.is_empty() {
+            return Err(JwtError::InvalidToken(format!(
+                "unsupported JWE algorithm {}/{}",
+                parsed.alg, parsed.enc
+            )));
+        }
+
+        let decode = |part: &str| {
+            BASE64URL_NOPAD
+                .decode(part.as_bytes())
+                .map_err(|e| JwtError::Malformed(format!("malformed JWE: {}", e)))
+        };
+        let iv: [u8; NONCE_LEN] = decode(iv)?
+            .try_into()
+            .map_err(|_| JwtError::Malformed("invalid JWE IV length".to_string()))?;
+        let mut content = decode(ciphertext)?;
+        content.extend_from_slice(&decode(tag)?);
+
+        let plaintext = self
+            .key
+            .open_in_place(
+                Nonce::assume_unique_for_key(iv),
+                Aad::from(header.as_bytes()),
+                &mut content,
+            )
+            .map_err(|_| JwtError::InvalidToken("token decryption failed".to_string()))?;
+
+        String::from_utf8(plaintext.to_vec())
+            .map_err(|_| JwtError::InvalidToken("encrypted payload is not a JWT".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encryptor(key: &[u8; 32]) -> TokenEncryptor {
+        TokenEncryptor::new(&SecretBytes::from(key)).unwrap()
+    }
+
+    #[test]
+    fn test_encrypt_round_trips() {
+        let encryptor = encryptor(&[7u8; 32]);
+
+        let token = encryptor.encrypt("header.payload.signature").unwrap();
+
+        assert!(TokenEncryptor::is_encrypted(&token));
+        assert!(!token.contains("payload"));
+        assert_eq!(
+            encryptor.decrypt(&token).unwrap(),
+            "header.payload.signature"
+        );
+    }
+
+    #[test]
+    fn test_decrypt_rejects_wrong_key_and_tampering() {
+        let token = encryptor(&[7u8; 32]).encrypt("a.b.c").unwrap();
+
+        assert!(encryptor(&[8u8; 32]).decrypt(&token).is_err());
+
+        let mut tampered: Vec<&str> = token.split('.').collect();
+        tampered[3] = "AAAA";
+        assert!(encryptor(&[7u8; 32]).decrypt(&tampered.join(".")).is_err());
+    }
+
+    #[test]
+    fn test_new_rejects_short_key() {
+        let result = TokenEncryptor::new(&SecretBytes::from(b"too short"));
+        assert!(matches!(result, Err(JwtError::InvalidEncryptionKey(_))));
+    }
+}
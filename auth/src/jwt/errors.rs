@@ -1,6 +1,11 @@
 use thiserror::Error;
 
+use super::claims::TokenType;
+
 /// Error type for JWT operations.
+///
+/// Use [`JwtError::code`] to report a failure to clients instead of
+/// matching on the message.
 #[derive(Debug, Clone, Error)]
 pub enum JwtError {
     #[error("Failed to encode token: {0}")]
@@ -12,9 +17,113 @@ pub enum JwtError {
     #[error("Token is expired")]
     TokenExpired,
 
+    /// The `nbf` claim lies in the future
+    #[error("Token is not valid yet")]
+    TokenNotYetValid,
+
+    /// The `aud` claim does not name this service
+    #[error("Token was issued for another audience")]
+    WrongAudience,
+
+    /// Token is well-formed and signed but was revoked before it expired
+    #[error("Token is revoked")]
+    Revoked,
+
+    /// Token is not a structurally valid JWT or JWE
+    #[error("Token is malformed: {0}")]
+    Malformed(String),
+
     #[error("Token is invalid: {0}")]
     InvalidToken(String),
 
+    #[error("Invalid encryption key: {0}")]
+    InvalidEncryptionKey(String),
+
     #[error("Missing required claim: {0}")]
     MissingClaim(String),
+
+    #[error("Token lacks required scope: {0}")]
+    InsufficientScope(String),
+
+    /// Token is bound to another device than the one presenting it
+    #[error("Token is bound to another device")]
+    DeviceMismatch,
+
+    #[error("Unexpected token type: expected {expected:?}, got {actual:?}")]
+    UnexpectedTokenType {
+        expected: TokenType,
+        actual: TokenType,
+    },
+}
+
+impl JwtError {
+    /// Machine-readable code of the error, stable across releases.
+    ///
+    /// # Returns
+    /// Snake case code such as `token_expired` or `malformed_token`
+    pub fn code(&self) -> &'static str {
+        match self {
+            JwtError::EncodingFailed(_) => "token_encoding_failed",
+            JwtError::DecodingFailed(_) | JwtError::InvalidToken(_) => "invalid_token",
+            JwtError::TokenExpired => "token_expired",
+            JwtError::TokenNotYetValid => "token_not_yet_valid",
+            JwtError::WrongAudience => "wrong_audience",
+            JwtError::Revoked => "token_revoked",
+            JwtError::Malformed(_) => "malformed_token",
+            JwtError::InvalidEncryptionKey(_) => "invalid_encryption_key",
+            JwtError::MissingClaim(_) => "missing_claim",
+            JwtError::InsufficientScope(_) => "insufficient_scope",
+            JwtError::DeviceMismatch => "device_mismatch",
+            JwtError::UnexpectedTokenType { .. } => "wrong_token_type",
+        }
+    }
+}
+
+impl From<jsonwebtoken::errors::Error> for JwtError {
+    fn from(err: jsonwebtoken::errors::Error) -> Self {
+        use jsonwebtoken::errors::ErrorKind;
+
+        match err.kind() {
+            ErrorKind::ExpiredSignature => JwtError::TokenExpired,
+            ErrorKind::ImmatureSignature => JwtError::TokenNotYetValid,
+            ErrorKind::InvalidAudience => JwtError::WrongAudience,
+            ErrorKind::InvalidToken
+            | ErrorKind::Base64(_)
+            | ErrorKind::Json(_)
+            | ErrorKind::Utf8(_) => JwtError::Malformed(err.to_string()),
+            ErrorKind::InvalidSignature
+            | ErrorKind::InvalidAlgorithm
+            | ErrorKind::InvalidIssuer
+            | ErrorKind::InvalidSubject
+            | ErrorKind::MissingRequiredClaim(_) => JwtError::InvalidToken(err.to_string()),
+            _ => JwtError::DecodingFailed(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_distinguish_token_failures() {
+        let codes = [
+            JwtError::TokenExpired.code(),
+            JwtError::TokenNotYetValid.code(),
+            JwtError::WrongAudience.code(),
+            JwtError::Revoked.code(),
+            JwtError::Malformed("bad".to_string()).code(),
+        ];
+
+        assert_eq!(
+            codes,
+            [
+                "token_expired",
+                "token_not_yet_valid",
+                "wrong_audience",
+                "token_revoked",
+                "malformed_token"
+            ]
+        );
+    }
 }
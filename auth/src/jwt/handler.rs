@@ -1,3 +1,6 @@
+use std::sync::Arc;
+
+use clock::Clock;
 use jsonwebtoken::decode;
 use jsonwebtoken::encode;
 use jsonwebtoken::Algorithm;
@@ -7,24 +10,31 @@ use jsonwebtoken::Header;
 use jsonwebtoken::Validation;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 
+use super::encryption::TokenEncryptor;
 use super::errors::JwtError;
+use crate::secret::SecretBytes;
 
 /// JWT token handler for encoding and decoding tokens.
 ///
 /// Generic over the claims type to allow services to define their own token payload.
-/// Uses HS256 (HMAC with SHA-256) algorithm by default.
+/// Uses HS256 (HMAC with SHA-256) algorithm by default. With
+/// [`JwtHandler::with_encryption`] tokens are additionally encrypted (JWE, A256GCM)
+/// so their claims cannot be read by whoever captures them.
 pub struct JwtHandler {
     encoding_key: EncodingKey,
     decoding_key: DecodingKey,
     algorithm: Algorithm,
+    encryptor: Option<TokenEncryptor>,
+    clock: Arc<dyn Clock>,
 }
 
 impl JwtHandler {
     /// Create a new JWT handler with a secret key.
     ///
     /// # Arguments
-    /// * `secret` - Secret key for signing tokens (should be stored securely)
+    /// * `secret` - Secret key for signing tokens, wiped once the keys are derived
     ///
     /// # Returns
     /// JwtHandler instance configured with HS256 algorithm
@@ -33,33 +43,91 @@ impl JwtHandler {
     /// - The secret should be at least 256 bits (32 bytes) for HS256
     /// - Store secrets in environment variables or secure vaults, never in code
     /// - Rotate secrets periodically
-    pub fn new(secret: &[u8]) -> Self {
+    /// - The derived keys keep their own copy of the secret for the handler's lifetime
+    pub fn new(secret: impl Into<SecretBytes>) -> Self {
+        let secret = secret.into();
         Self {
-            encoding_key: EncodingKey::from_secret(secret),
-            decoding_key: DecodingKey::from_secret(secret),
+            encoding_key: EncodingKey::from_secret(secret.expose_secret()),
+            decoding_key: DecodingKey::from_secret(secret.expose_secret()),
             algorithm: Algorithm::HS256,
+            encryptor: None,
+            clock: clock::system(),
         }
     }
 
+    /// Check `exp` and `nbf` against another clock.
+    ///
+    /// The system clock by default.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock deciding whether tokens have expired
+    ///
+    /// # Returns
+    /// JwtHandler validating token lifetimes with the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Encrypt issued tokens.
+    ///
+    /// Tokens are signed, then the signed token is encrypted as a compact JWE
+    /// (`dir`, `A256GCM`). Decoding keeps accepting signed-only tokens, so
+    /// encryption can be enabled without invalidating tokens already issued.
+    ///
+    /// # Arguments
+    /// * `key` - 256-bit content encryption key, distinct from the signing secret
+    ///
+    /// # Returns
+    /// JwtHandler that encrypts the tokens it encodes
+    ///
+    /// # Errors
+    /// * `InvalidEncryptionKey` - Key is not 32 bytes long
+    pub fn with_encryption(mut self, key: impl Into<SecretBytes>) -> Result<Self, JwtError> {
+        self.encryptor = Some(TokenEncryptor::new(&key.into())?);
+        Ok(self)
+    }
+
     /// Encode claims into a JWT token.
     ///
     /// # Arguments
     /// * `claims` - Claims to encode (must implement Serialize)
     ///
     /// # Returns
-    /// JWT token string
+    /// JWT token string, encrypted if the handler has an encryption key
     ///
     /// # Errors
-    /// * `EncodingFailed` - Token encoding failed
+    /// * `EncodingFailed` - Token encoding or encryption failed
     pub fn encode<T: Serialize>(&self, claims: &T) -> Result<String, JwtError> {
         let header = Header::new(self.algorithm);
 
-        encode(&header, claims, &self.encoding_key)
-            .map_err(|e| JwtError::EncodingFailed(e.to_string()))
+        let token = encode(&header, claims, &self.encoding_key)
+            .map_err(|e| JwtError::EncodingFailed(e.to_string()))?;
+
+        match &self.encryptor {
+            Some(encryptor) => encryptor.encrypt(&token),
+            None => Ok(token),
+        }
+    }
+
+    /// Unwrap an encrypted token to the signed token inside it.
+    fn signed_token(&self, token: &str) -> Result<String, JwtError> {
+        if !TokenEncryptor::is_encrypted(token) {
+            return Ok(token.to_string());
+        }
+
+        match &self.encryptor {
+            Some(encryptor) => encryptor.decrypt(token),
+            None => Err(JwtError::InvalidToken(
+                "encrypted token but no encryption key configured".to_string(),
+            )),
+        }
     }
 
     /// Decode and validate a JWT token.
     ///
+    /// Accepts signed-only and encrypted tokens.
+    ///
     /// # Arguments
     /// * `token` - JWT token string to decode
     ///
@@ -67,23 +135,38 @@ impl JwtHandler {
     /// Decoded claims
     ///
     /// # Errors
-    /// * `DecodingFailed` - Token decoding failed
     /// * `TokenExpired` - Token has expired (if exp claim is present)
-    /// * `InvalidToken` - Token signature is invalid or malformed
+    /// * `TokenNotYetValid` - Token is not valid before its nbf claim
+    /// * `WrongAudience` - Token was issued for another audience
+    /// * `Malformed` - Token is not a structurally valid JWT or JWE
+    /// * `InvalidToken` - Token signature is invalid or cannot be decrypted
+    /// * `DecodingFailed` - Token decoding failed for another reason
     pub fn decode<T: for<'de> Deserialize<'de>>(&self, token: &str) -> Result<T, JwtError> {
+        let token = self.signed_token(token)?;
         let mut validation = Validation::new(self.algorithm);
         // Allow tokens without 'exp' claim for flexibility
         validation.required_spec_claims.clear();
+        // The lifetime is checked against the handler's clock instead
+        validation.validate_exp = false;
 
-        let token_data = decode::<T>(token, &self.decoding_key, &validation).map_err(|e| {
-            if e.to_string().contains("ExpiredSignature") {
-                JwtError::TokenExpired
-            } else {
-                JwtError::DecodingFailed(e.to_string())
-            }
-        })?;
+        let token_data = decode::<Value>(&token, &self.decoding_key, &validation)?;
+        self.validate_lifetime(&token_data.claims, validation.leeway as i64)?;
 
-        Ok(token_data.claims)
+        serde_json::from_value(token_data.claims).map_err(|e| JwtError::Malformed(e.to_string()))
+    }
+
+    /// Reject tokens past their `exp` or before their `nbf`, give or take `leeway` seconds.
+    fn validate_lifetime(&self, claims: &Value, leeway: i64) -> Result<(), JwtError> {
+        let now = self.clock.now().timestamp();
+        let timestamp = |claim: &str| claims.get(claim).and_then(Value::as_f64).map(|t| t as i64);
+
+        if timestamp("exp").is_some_and(|exp| exp < now - leeway) {
+            return Err(JwtError::TokenExpired);
+        }
+        if timestamp("nbf").is_some_and(|nbf| nbf > now + leeway) {
+            return Err(JwtError::TokenNotYetValid);
+        }
+        Ok(())
     }
 
     /// Decode token without validation (for inspection only).
@@ -96,6 +179,7 @@ impl JwtHandler {
     ///
     /// # Errors
     /// * `DecodingFailed` - Token format is invalid
+    /// * `InvalidToken` - Token is encrypted and cannot be decrypted
     ///
     /// # Security Warning
     /// This does NOT validate the token signature. Only use for:
@@ -106,12 +190,12 @@ impl JwtHandler {
         &self,
         token: &str,
     ) -> Result<T, JwtError> {
+        let token = self.signed_token(token)?;
         let mut validation = Validation::new(self.algorithm);
         validation.insecure_disable_signature_validation();
         validation.required_spec_claims.clear();
 
-        let token_data = decode::<T>(token, &self.decoding_key, &validation)
-            .map_err(|e| JwtError::DecodingFailed(e.to_string()))?;
+        let token_data = decode::<T>(&token, &self.decoding_key, &validation)?;
 
         Ok(token_data.claims)
     }
@@ -150,7 +234,7 @@ mod tests {
         let handler = JwtHandler::new(b"my_secret_key_at_least_32_bytes_long!");
 
         let result = handler.decode::<TestClaims>("invalid.token.here");
-        assert!(result.is_err());
+        assert!(matches!(result, Err(JwtError::Malformed(_))));
     }
 
     #[test]
@@ -167,7 +251,54 @@ mod tests {
 
         // Try to decode with different secret
         let result = handler2.decode::<TestClaims>(&token);
-        assert!(result.is_err());
+        assert!(matches!(result, Err(JwtError::InvalidToken(_))));
+    }
+
+    #[test]
+    fn test_decode_reports_time_window_errors() {
+        let handler = JwtHandler::new(b"my_secret_key_at_least_32_bytes_long!");
+        let now = chrono::Utc::now().timestamp();
+
+        let expired = handler
+            .encode(&serde_json::json!({ "sub": "user123", "exp": now - 3600 }))
+            .expect("Failed to encode token");
+        assert!(matches!(
+            handler.decode::<serde_json::Value>(&expired),
+            Err(JwtError::TokenExpired)
+        ));
+
+        let not_yet_valid = handler
+            .encode(&serde_json::json!({ "sub": "user123", "nbf": now + 3600 }))
+            .expect("Failed to encode token");
+        assert!(matches!(
+            handler.decode::<serde_json::Value>(&not_yet_valid),
+            Err(JwtError::TokenNotYetValid)
+        ));
+    }
+
+    #[test]
+    fn test_decode_checks_lifetime_against_clock() {
+        let clock = Arc::new(clock::FakeClock::default());
+        let handler =
+            JwtHandler::new(b"my_secret_key_at_least_32_bytes_long!").with_clock(clock.clone());
+        let now = clock.now().timestamp();
+
+        let token = handler
+            .encode(&serde_json::json!({ "sub": "user123", "nbf": now + 600, "exp": now + 3600 }))
+            .expect("Failed to encode token");
+        assert!(matches!(
+            handler.decode::<serde_json::Value>(&token),
+            Err(JwtError::TokenNotYetValid)
+        ));
+
+        clock.advance(chrono::Duration::minutes(30));
+        assert!(handler.decode::<serde_json::Value>(&token).is_ok());
+
+        clock.advance(chrono::Duration::hours(1));
+        assert!(matches!(
+            handler.decode::<serde_json::Value>(&token),
+            Err(JwtError::TokenExpired)
+        ));
     }
 
     #[test]
@@ -189,4 +320,43 @@ mod tests {
         assert_eq!(decoded.sub, "user123");
         assert_eq!(decoded.role, "admin");
     }
+
+    #[test]
+    fn test_encrypted_tokens_hide_claims() {
+        let handler = JwtHandler::new(b"my_secret_key_at_least_32_bytes_long!")
+            .with_encryption(&[42u8; 32])
+            .expect("Failed to configure encryption");
+
+        let claims = TestClaims {
+            sub: "user123".to_string(),
+            role: "admin".to_string(),
+        };
+
+        let token = handler.encode(&claims).expect("Failed to encode token");
+        assert_eq!(token.split('.').count(), 5);
+
+        // Without the key the payload cannot be read
+        let plain = JwtHandler::new(b"my_secret_key_at_least_32_bytes_long!");
+        assert!(plain.decode_unverified::<TestClaims>(&token).is_err());
+
+        let decoded: TestClaims = handler.decode(&token).expect("Failed to decode token");
+        assert_eq!(decoded, claims);
+    }
+
+    #[test]
+    fn test_encrypting_handler_accepts_signed_only_tokens() {
+        let plain = JwtHandler::new(b"my_secret_key_at_least_32_bytes_long!");
+        let encrypting = JwtHandler::new(b"my_secret_key_at_least_32_bytes_long!")
+            .with_encryption(&[42u8; 32])
+            .expect("Failed to configure encryption");
+
+        let claims = TestClaims {
+            sub: "user123".to_string(),
+            role: "admin".to_string(),
+        };
+
+        let token = plain.encode(&claims).expect("Failed to encode token");
+        let decoded: TestClaims = encrypting.decode(&token).expect("Failed to decode token");
+        assert_eq!(decoded, claims);
+    }
 }
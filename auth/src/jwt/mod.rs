@@ -1,7 +1,12 @@
+pub mod cache;
 pub mod claims;
+mod encryption;
 pub mod errors;
 pub mod handler;
 
+pub use cache::ValidationCache;
 pub use claims::Claims;
+pub use claims::TokenType;
+pub use claims::GUEST_SUBJECT_PREFIX;
 pub use errors::JwtError;
 pub use handler::JwtHandler;
@@ -1,9 +1,21 @@
 //! Authentication utilities library
 //!
 //! Provides reusable authentication infrastructure for microservices:
-//! - Password hashing (Argon2id)
-//! - JWT token generation and validation
+//! - Password hashing (Argon2id), with verification of legacy bcrypt hashes (`bcrypt` feature)
+//! - Compromised password checks (Have I Been Pwned, `hibp` feature)
+//! - JWT token generation and validation, with optional JWE (A256GCM) encryption
+//!   and a cache of validated access tokens
+//! - Refresh token rotation with reuse detection, persisted in PostgreSQL (`postgres` feature)
+//! - Single-use action tokens (password reset, email verification), redeemed once across
+//!   instances in PostgreSQL (`postgres` feature)
+//! - Access token revocation (logout) by `jti`, shared between services in PostgreSQL (`postgres` feature)
+//! - Personal access tokens users mint for their own integrations
+//! - Revocable sessions per device ("log out other devices")
+//! - TOTP two-factor authentication codes
 //! - Authentication coordination
+//! - Zeroize-on-drop wrappers for secrets and passwords in transit
+//! - Axum bearer token layer and `Claims` extractor (`axum` feature)
+//! - Tonic interceptors for JWT-authenticated gRPC calls (`grpc` feature)
 //!
 //! Each service defines its own authentication traits and adapts these implementations.
 //! This avoids coupling services through shared domain logic while reducing code duplication.
@@ -12,12 +24,13 @@
 //!
 //! ## Password Hashing
 //! ```
-//! use auth::PasswordHasher;
+//! use auth::{PasswordHasher, SecretString};
 //!
 //! let hasher = PasswordHasher::new();
-//! let hash = hasher.hash("my_password").unwrap();
-//! let is_valid = hasher.verify("my_password", &hash).unwrap();
-//! assert!(is_valid);
+//! let password = SecretString::from("my_password");
+//! let hash = hasher.hash(&password).unwrap();
+//! let verification = hasher.verify(&password, &hash).unwrap();
+//! assert!(verification.is_valid());
 //! ```
 //!
 //! ## JWT Tokens
@@ -32,32 +45,184 @@
 //!
 //! ## Complete Authentication Flow
 //! ```
-//! use auth::{Authenticator, Claims};
+//! use auth::{Authenticator, Claims, SecretString};
 //!
 //! let auth = Authenticator::new(b"secret_key_at_least_32_bytes_long!");
 //!
 //! // Register: hash password
-//! let hash = auth.hash_password("password123").unwrap();
+//! let password = SecretString::from("password123");
+//! let hash = auth.hash_password(&password).unwrap();
 //!
 //! // Login: verify and generate token
 //! let claims = Claims::for_user("user123", "alice".to_string(), 24);
-//! let result = auth.authenticate("password123", &hash, &claims).unwrap();
+//! let result = auth.authenticate(&password, &hash, &claims).unwrap();
 //! println!("Token: {}", result.access_token);
 //!
 //! // Validate token
 //! let decoded: Claims = auth.validate_token(&result.access_token).unwrap();
 //! ```
+//!
+//! ## One-Time Action Tokens
+//! ```
+//! use auth::{Authenticator, OneTimeToken, OneTimeTokenError};
+//! use chrono::Duration;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let auth = Authenticator::new(b"secret_key_at_least_32_bytes_long!");
+//!
+//! let token = auth
+//!     .issue_one_time_token("user123", OneTimeToken::PASSWORD_RESET, Duration::minutes(30))
+//!     .unwrap();
+//!
+//! let redeemed = auth
+//!     .redeem_one_time_token(&token, OneTimeToken::PASSWORD_RESET)
+//!     .await
+//!     .unwrap();
+//! assert_eq!(redeemed.subject, "user123");
+//!
+//! // A second redemption is rejected
+//! let again = auth.redeem_one_time_token(&token, OneTimeToken::PASSWORD_RESET).await;
+//! assert!(matches!(again, Err(OneTimeTokenError::AlreadyUsed)));
+//! # }
+//! ```
+//!
+//! ## Sessions
+//! ```
+//! use auth::{Authenticator, Claims, DeviceInfo, SessionError};
+//! use chrono::Duration;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let auth = Authenticator::new(b"secret_key_at_least_32_bytes_long!");
+//!
+//! // Login on two devices
+//! let phone = auth.start_session("user123", DeviceInfo::default(), Duration::days(30)).await.unwrap();
+//! let laptop = auth.start_session("user123", DeviceInfo::default(), Duration::days(30)).await.unwrap();
+//! let claims = Claims::for_user("user123", "alice".to_string(), 1).with_session_id(&phone.id);
+//! let token = auth.generate_token(&claims).unwrap();
+//! assert!(auth.validate_session_token(&token).await.is_ok());
+//!
+//! // "Log out other devices" from the laptop
+//! assert_eq!(auth.revoke_other_sessions("user123", &laptop.id).await.unwrap(), 1);
+//! let revoked = auth.validate_session_token(&token).await;
+//! assert!(matches!(revoked, Err(SessionError::Revoked)));
+//! # }
+//! ```
+//!
+//! ## Logout
+//! ```
+//! use auth::{Authenticator, Claims, RevocationError};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let auth = Authenticator::new(b"secret_key_at_least_32_bytes_long!");
+//!
+//! let token = auth.generate_token(&Claims::for_user("user123", "alice".to_string(), 1)).unwrap();
+//! let claims = auth.validate_access_token(&token).unwrap();
+//! auth.revoke_access_token(&claims).await.unwrap();
+//!
+//! // The token is rejected until it expires
+//! let revoked = auth.ensure_not_revoked(&claims).await;
+//! assert!(matches!(revoked, Err(RevocationError::Revoked)));
+//! # }
+//! ```
+//!
+//! ## Two-Factor Authentication
+//! ```
+//! use auth::{Totp, TotpSecret};
+//!
+//! let totp = Totp::new("chat-rs");
+//!
+//! // Enrollment: store the secret, render the URI as a QR code
+//! let secret = TotpSecret::generate();
+//! let uri = totp.provisioning_uri(&secret, "alice");
+//! assert!(uri.starts_with("otpauth://totp/chat-rs:alice?"));
+//!
+//! // Verification: accept the code and remember its time step to prevent replay
+//! let code = totp.generate(&secret);
+//! assert!(totp.verify(&secret, &code).is_some());
+//! ```
+//!
+//! ## Refresh Token Rotation
+//! ```
+//! use auth::{Authenticator, Claims, RefreshTokenError};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let auth = Authenticator::new(b"secret_key_at_least_32_bytes_long!");
+//!
+//! let pair = auth.issue_token_pair(&Claims::new().with_subject("user123")).await.unwrap();
+//! let rotated = auth.rotate_refresh(&pair.refresh_token).await.unwrap();
+//!
+//! // Replaying a rotated refresh token revokes the whole family
+//! let reused = auth.rotate_refresh(&pair.refresh_token).await;
+//! assert!(matches!(reused, Err(RefreshTokenError::ReuseDetected)));
+//! assert!(auth.rotate_refresh(&rotated.refresh_token).await.is_err());
+//! # }
+//! ```
 
 pub mod authenticator;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod jwt;
+pub mod one_time;
 pub mod password;
+pub mod personal_token;
+pub mod refresh;
+pub mod revocation;
+pub mod secret;
+pub mod session;
+pub mod totp;
 
 // Re-export commonly used items
 pub use authenticator::AuthenticationError;
 pub use authenticator::AuthenticationResult;
 pub use authenticator::Authenticator;
+pub use authenticator::TokenPair;
 pub use jwt::Claims;
 pub use jwt::JwtError;
 pub use jwt::JwtHandler;
+pub use jwt::TokenType;
+pub use jwt::ValidationCache;
+pub use one_time::InMemoryOneTimeTokenStore;
+pub use one_time::OneTimeToken;
+pub use one_time::OneTimeTokenError;
+pub use one_time::OneTimeTokenStore;
+#[cfg(feature = "postgres")]
+pub use one_time::PostgresOneTimeTokenStore;
+#[cfg(feature = "bcrypt")]
+pub use password::BcryptVerifier;
+pub use password::CompromisedPasswordChecker;
+#[cfg(feature = "hibp")]
+pub use password::HibpPasswordChecker;
+pub use password::LegacyHashVerifier;
 pub use password::PasswordError;
 pub use password::PasswordHasher;
+pub use password::PasswordVerification;
+pub use personal_token::PersonalToken;
+pub use personal_token::PersonalTokenError;
+pub use personal_token::PersonalTokenVerifier;
+pub use refresh::InMemoryRefreshTokenStore;
+#[cfg(feature = "postgres")]
+pub use refresh::PostgresRefreshTokenStore;
+pub use refresh::RefreshTokenError;
+pub use refresh::RefreshTokenPolicy;
+pub use refresh::RefreshTokenStore;
+pub use revocation::InMemoryRevocationStore;
+#[cfg(feature = "postgres")]
+pub use revocation::PostgresRevocationStore;
+pub use revocation::RevocationError;
+pub use revocation::RevocationStore;
+pub use secret::SecretBytes;
+pub use secret::SecretString;
+pub use session::DeviceInfo;
+pub use session::InMemorySessionStore;
+pub use session::Session;
+pub use session::SessionError;
+pub use session::SessionStore;
+pub use totp::Totp;
+pub use totp::TotpError;
+pub use totp::TotpSecret;
@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+use crate::jwt::JwtError;
+
+/// Error type for one-time token operations.
+#[derive(Debug, Clone, Error)]
+pub enum OneTimeTokenError {
+    #[error("One-time token was already used")]
+    AlreadyUsed,
+
+    #[error("One-time token purpose mismatch: expected {expected}, got {actual}")]
+    WrongPurpose { expected: String, actual: String },
+
+    #[error("One-time token is bound to another client")]
+    BindingMismatch,
+
+    #[error("One-time token store error: {0}")]
+    StoreError(String),
+
+    #[error("JWT error: {0}")]
+    JwtError(#[from] JwtError),
+}
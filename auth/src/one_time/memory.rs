@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use clock::Clock;
+
+use super::errors::OneTimeTokenError;
+use super::store::OneTimeTokenStore;
+
+/// Process-local one-time token store.
+///
+/// Suitable for tests and single-instance deployments; used tokens become
+/// redeemable again after a restart and are not shared between replicas.
+pub struct InMemoryOneTimeTokenStore {
+    used: Mutex<HashMap<String, DateTime<Utc>>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl InMemoryOneTimeTokenStore {
+    /// Create an empty store.
+    ///
+    /// # Returns
+    /// InMemoryOneTimeTokenStore instance
+    pub fn new() -> Self {
+        Self {
+            used: Mutex::new(HashMap::new()),
+            clock: clock::system(),
+        }
+    }
+
+    /// Forget used tokens by another clock.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock records expire by, the system clock by default
+    ///
+    /// # Returns
+    /// InMemoryOneTimeTokenStore expiring records by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl Default for InMemoryOneTimeTokenStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl OneTimeTokenStore for InMemoryOneTimeTokenStore {
+    async fn consume(
+        &self,
+        jti: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, OneTimeTokenError> {
+        let mut used = self
+            .used
+            .lock()
+            .map_err(|e| OneTimeTokenError::StoreError(e.to_string()))?;
+
+        let now = self.clock.now();
+        used.retain(|_, expires_at| *expires_at > now);
+
+        if used.contains_key(jti) {
+            return Ok(false);
+        }
+
+        used.insert(jti.to_string(), expires_at);
+        Ok(true)
+    }
+}
@@ -0,0 +1,13 @@
+pub mod errors;
+pub mod memory;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+pub mod store;
+pub mod token;
+
+pub use errors::OneTimeTokenError;
+pub use memory::InMemoryOneTimeTokenStore;
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresOneTimeTokenStore;
+pub use store::OneTimeTokenStore;
+pub use token::OneTimeToken;
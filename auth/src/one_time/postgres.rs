@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use clock::Clock;
+use sqlx::PgPool;
+
+use super::errors::OneTimeTokenError;
+use super::store::OneTimeTokenStore;
+
+/// One-time token store shared by every instance reading the same database.
+///
+/// Expects the `redeemed_one_time_tokens` table created by the user-service
+/// migrations. Expired records are dropped on the next redemption.
+pub struct PostgresOneTimeTokenStore {
+    pool: PgPool,
+    clock: Arc<dyn Clock>,
+}
+
+impl PostgresOneTimeTokenStore {
+    /// Create a store on a connection pool.
+    ///
+    /// # Arguments
+    /// * `pool` - Pool of the database holding the redeemed tokens
+    ///
+    /// # Returns
+    /// PostgresOneTimeTokenStore instance
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            clock: clock::system(),
+        }
+    }
+
+    /// Forget used tokens by another clock.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock records expire by, the system clock by default
+    ///
+    /// # Returns
+    /// PostgresOneTimeTokenStore expiring records by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl OneTimeTokenStore for PostgresOneTimeTokenStore {
+    async fn consume(
+        &self,
+        jti: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, OneTimeTokenError> {
+        sqlx::query("DELETE FROM redeemed_one_time_tokens WHERE expires_at <= $1")
+            .bind(self.clock.now())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| OneTimeTokenError::StoreError(e.to_string()))?;
+
+        // Of two concurrent redemptions, only one inserts the row
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO redeemed_one_time_tokens (jti, expires_at)
+            VALUES ($1, $2)
+            ON CONFLICT (jti) DO NOTHING
+            "#,
+        )
+        .bind(jti)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| OneTimeTokenError::StoreError(e.to_string()))?;
+
+        Ok(inserted.rows_affected() == 1)
+    }
+}
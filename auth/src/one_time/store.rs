@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use super::errors::OneTimeTokenError;
+
+/// Record of redeemed one-time tokens.
+///
+/// Only tokens that were redeemed are stored, and only until they expire;
+/// after that the signature check rejects them on its own.
+#[async_trait]
+pub trait OneTimeTokenStore: Send + Sync + 'static {
+    /// Mark a token as used.
+    ///
+    /// Must be atomic so that two concurrent redemptions cannot both succeed.
+    ///
+    /// # Arguments
+    /// * `jti` - Unique identifier of the token
+    /// * `expires_at` - Expiration of the token, after which the record may be dropped
+    ///
+    /// # Returns
+    /// `true` if this is the first use of the token
+    ///
+    /// # Errors
+    /// * `StoreError` - Record could not be read or written
+    async fn consume(
+        &self,
+        jti: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, OneTimeTokenError>;
+}
@@ -0,0 +1,32 @@
+use chrono::DateTime;
+use chrono::Utc;
+
+/// Redeemed single-use action token (password reset, email verification, ...).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OneTimeToken {
+    /// Subject the action applies to (usually a user ID)
+    pub subject: String,
+    /// Action the token authorizes
+    pub purpose: String,
+    /// Unique token identifier
+    pub jti: String,
+    /// Time after which the token is no longer accepted
+    pub expires_at: DateTime<Utc>,
+}
+
+impl OneTimeToken {
+    /// Purpose of tokens sent in password reset links.
+    pub const PASSWORD_RESET: &'static str = "password_reset";
+
+    /// Purpose of tokens sent in email verification links.
+    pub const EMAIL_VERIFICATION: &'static str = "email_verification";
+
+    /// Purpose of tokens sent in password-less login links.
+    pub const MAGIC_LINK: &'static str = "magic_link";
+
+    /// Purpose of tokens sent in login links that create the account on redemption.
+    pub const MAGIC_LINK_SIGNUP: &'static str = "magic_link_signup";
+
+    /// Purpose of tokens confirming an email address to link it for login.
+    pub const ACCOUNT_LINK: &'static str = "account_link";
+}
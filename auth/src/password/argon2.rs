@@ -1,16 +1,30 @@
+use std::sync::Arc;
+
 use argon2::password_hash::rand_core::OsRng;
 use argon2::password_hash::PasswordHash;
 use argon2::password_hash::PasswordHasher as Argon2PasswordHasher;
 use argon2::password_hash::PasswordVerifier;
 use argon2::password_hash::SaltString;
+use argon2::Algorithm;
 use argon2::Argon2;
 
 use super::errors::PasswordError;
+use super::legacy::LegacyHashVerifier;
+use super::legacy::PasswordVerification;
+use crate::secret::SecretString;
 
 /// Password hashing implementation.
 ///
 /// Provides cryptographic password hashing (internally uses Argon2id).
-pub struct PasswordHasher;
+/// Plaintext passwords are taken as [`SecretString`] and Argon2's working
+/// memory is wiped after each hash, so neither lingers once a call returns.
+///
+/// New hashes are always Argon2id. Hashes in other formats are only verified,
+/// through the registered [`LegacyHashVerifier`]s.
+#[derive(Clone, Default)]
+pub struct PasswordHasher {
+    legacy_verifiers: Vec<Arc<dyn LegacyHashVerifier>>,
+}
 
 impl PasswordHasher {
     /// Create a new password hasher instance.
@@ -18,7 +32,19 @@ impl PasswordHasher {
     /// # Returns
     /// PasswordHasher instance configured with secure defaults
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Also verify passwords against hashes in a legacy format.
+    ///
+    /// # Arguments
+    /// * `verifier` - Verifier for the legacy format
+    ///
+    /// # Returns
+    /// PasswordHasher falling back to the verifier for hashes it supports
+    pub fn with_legacy_verifier(mut self, verifier: Arc<dyn LegacyHashVerifier>) -> Self {
+        self.legacy_verifiers.push(verifier);
+        self
     }
 
     /// Hash a plaintext password securely.
@@ -33,43 +59,95 @@ impl PasswordHasher {
     ///
     /// # Errors
     /// * `HashingFailed` - Password hashing operation failed
-    pub fn hash(&self, password: &str) -> Result<String, PasswordError> {
+    pub fn hash(&self, password: &SecretString) -> Result<String, PasswordError> {
         let salt = SaltString::generate(&mut OsRng);
         let argon2 = Argon2::default();
 
         argon2
-            .hash_password(password.as_bytes(), &salt)
+            .hash_password(password.expose_secret().as_bytes(), &salt)
             .map(|hash| hash.to_string())
             .map_err(|e| PasswordError::HashingFailed(e.to_string()))
     }
 
     /// Verify a password against a stored hash.
     ///
+    /// Hashes supported by a registered legacy verifier are checked by it, and
+    /// a match is reported as `NeedsRehash` so the caller can store
+    /// [`hash`](Self::hash) of the password instead.
+    ///
     /// # Arguments
     /// * `password` - Plaintext password to verify
-    /// * `hash` - Stored password hash in PHC string format
+    /// * `hash` - Stored password hash in PHC string or a legacy format
     ///
     /// # Returns
-    /// True if password matches, false otherwise
+    /// `Valid` or `NeedsRehash` if password matches, `Invalid` otherwise
     ///
     /// # Errors
     /// * `VerificationFailed` - Hash format is invalid or verification failed
-    pub fn verify(&self, password: &str, hash: &str) -> Result<bool, PasswordError> {
+    pub fn verify(
+        &self,
+        password: &SecretString,
+        hash: &str,
+    ) -> Result<PasswordVerification, PasswordError> {
+        if let Some(verifier) = self.legacy_verifier_for(hash) {
+            return Ok(if verifier.verify(password, hash)? {
+                PasswordVerification::NeedsRehash
+            } else {
+                PasswordVerification::Invalid
+            });
+        }
+
         let parsed_hash = PasswordHash::new(hash).map_err(|e| {
             PasswordError::VerificationFailed(format!("Invalid password hash: {}", e))
         })?;
 
         let argon2 = Argon2::default();
 
-        Ok(argon2
-            .verify_password(password.as_bytes(), &parsed_hash)
-            .is_ok())
+        Ok(
+            if argon2
+                .verify_password(password.expose_secret().as_bytes(), &parsed_hash)
+                .is_ok()
+            {
+                PasswordVerification::Valid
+            } else {
+                PasswordVerification::Invalid
+            },
+        )
+    }
+
+    /// Check whether a stored hash can be verified by this hasher instance.
+    ///
+    /// Unlike [`is_supported_hash`](Self::is_supported_hash), also accepts
+    /// hashes of the registered legacy formats.
+    ///
+    /// # Arguments
+    /// * `hash` - Password hash to check
+    ///
+    /// # Returns
+    /// True if the hash is a well-formed Argon2 PHC string or a supported legacy hash
+    pub fn accepts_hash(&self, hash: &str) -> bool {
+        Self::is_supported_hash(hash) || self.legacy_verifier_for(hash).is_some()
+    }
+
+    /// Check whether a stored hash is an Argon2 hash, verifiable without legacy verifiers.
+    ///
+    /// Used to vet pre-hashed passwords imported from another system.
+    ///
+    /// # Arguments
+    /// * `hash` - Password hash in PHC string format
+    ///
+    /// # Returns
+    /// True if the hash is a well-formed Argon2 PHC string
+    pub fn is_supported_hash(hash: &str) -> bool {
+        PasswordHash::new(hash)
+            .map(|parsed| Algorithm::try_from(parsed.algorithm).is_ok() && parsed.hash.is_some())
+            .unwrap_or(false)
     }
-}
 
-impl Default for PasswordHasher {
-    fn default() -> Self {
-        Self::new()
+    fn legacy_verifier_for(&self, hash: &str) -> Option<&Arc<dyn LegacyHashVerifier>> {
+        self.legacy_verifiers
+            .iter()
+            .find(|verifier| verifier.supports(hash))
     }
 }
 
@@ -80,26 +158,84 @@ mod tests {
     #[test]
     fn test_hash_and_verify() {
         let hasher = PasswordHasher::new();
-        let password = "my_secure_password";
+        let password = SecretString::from("my_secure_password");
 
         // Hash the password
-        let hash = hasher.hash(password).expect("Failed to hash password");
+        let hash = hasher.hash(&password).expect("Failed to hash password");
 
         // Verify correct password
-        assert!(hasher
-            .verify(password, &hash)
-            .expect("Failed to verify password"));
+        assert_eq!(
+            hasher
+                .verify(&password, &hash)
+                .expect("Failed to verify password"),
+            PasswordVerification::Valid
+        );
 
         // Verify incorrect password
-        assert!(!hasher
-            .verify("wrong_password", &hash)
-            .expect("Failed to verify password"));
+        assert_eq!(
+            hasher
+                .verify(&SecretString::from("wrong_password"), &hash)
+                .expect("Failed to verify password"),
+            PasswordVerification::Invalid
+        );
     }
 
     #[test]
     fn test_verify_invalid_hash() {
         let hasher = PasswordHasher::new();
-        let result = hasher.verify("password", "invalid_hash");
+        let result = hasher.verify(&SecretString::from("password"), "invalid_hash");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_is_supported_hash() {
+        let hasher = PasswordHasher::new();
+        let hash = hasher.hash(&SecretString::from("password")).unwrap();
+
+        assert!(PasswordHasher::is_supported_hash(&hash));
+        assert!(!PasswordHasher::is_supported_hash("invalid_hash"));
+        assert!(!PasswordHasher::is_supported_hash(
+            "$2b$12$R9h/cIPz0gi.URNNX3kh2OPST9/PgBkqquzi.Ss7KIUgO2t0jWMUW"
+        ));
+    }
+
+    struct PrefixVerifier;
+
+    impl LegacyHashVerifier for PrefixVerifier {
+        fn supports(&self, hash: &str) -> bool {
+            hash.starts_with("$plain$")
+        }
+
+        fn verify(&self, password: &SecretString, hash: &str) -> Result<bool, PasswordError> {
+            Ok(hash.strip_prefix("$plain$") == Some(password.expose_secret()))
+        }
+    }
+
+    #[test]
+    fn test_legacy_hash_verified_and_flagged_for_rehash() {
+        let hasher = PasswordHasher::new().with_legacy_verifier(Arc::new(PrefixVerifier));
+
+        assert!(hasher.accepts_hash("$plain$password"));
+        assert!(!PasswordHasher::new().accepts_hash("$plain$password"));
+        assert_eq!(
+            hasher
+                .verify(&SecretString::from("password"), "$plain$password")
+                .unwrap(),
+            PasswordVerification::NeedsRehash
+        );
+        assert_eq!(
+            hasher
+                .verify(&SecretString::from("wrong_password"), "$plain$password")
+                .unwrap(),
+            PasswordVerification::Invalid
+        );
+
+        let hash = hasher.hash(&SecretString::from("password")).unwrap();
+        assert_eq!(
+            hasher
+                .verify(&SecretString::from("password"), &hash)
+                .unwrap(),
+            PasswordVerification::Valid
+        );
+    }
 }
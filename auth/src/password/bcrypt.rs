@@ -0,0 +1,68 @@
+use super::errors::PasswordError;
+use super::legacy::LegacyHashVerifier;
+use crate::secret::SecretString;
+
+/// Prefixes of the bcrypt variants in modular crypt format.
+const BCRYPT_PREFIXES: [&str; 4] = ["$2a$", "$2b$", "$2x$", "$2y$"];
+
+/// Verifier for bcrypt hashes (`$2a$`, `$2b$`, `$2x$`, `$2y$`).
+///
+/// Passwords longer than 72 bytes are truncated before verification, as they
+/// were when the hash was created.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BcryptVerifier;
+
+impl BcryptVerifier {
+    /// Create a new bcrypt verifier.
+    ///
+    /// # Returns
+    /// BcryptVerifier instance
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl LegacyHashVerifier for BcryptVerifier {
+    fn supports(&self, hash: &str) -> bool {
+        BCRYPT_PREFIXES
+            .iter()
+            .any(|prefix| hash.starts_with(prefix))
+    }
+
+    fn verify(&self, password: &SecretString, hash: &str) -> Result<bool, PasswordError> {
+        bcrypt::verify(password.expose_secret(), hash)
+            .map_err(|e| PasswordError::VerificationFailed(format!("Invalid bcrypt hash: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // bcrypt("password", cost 4)
+    const HASH: &str = "$2b$04$EhTEkfX4tso89VDRX7CiHu3GFr/XYiRZFwjCd.dfqEVEjiLz2sJ7G";
+
+    #[test]
+    fn test_verify_bcrypt_hash() {
+        let verifier = BcryptVerifier::new();
+
+        assert!(verifier.supports(HASH));
+        assert!(verifier
+            .verify(&SecretString::from("password"), HASH)
+            .unwrap());
+        assert!(!verifier
+            .verify(&SecretString::from("wrong_password"), HASH)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_supports_only_bcrypt_hashes() {
+        let verifier = BcryptVerifier::new();
+
+        assert!(!verifier.supports("$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA"));
+        assert!(!verifier.supports("invalid_hash"));
+        assert!(verifier
+            .verify(&SecretString::from("password"), "$2b$04$short")
+            .is_err());
+    }
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use super::errors::PasswordError;
+
+/// Check whether a password appears in a known breach corpus.
+///
+/// Services call this before accepting a new password so that credentials
+/// already circulating in breach dumps are rejected at registration or change.
+#[async_trait]
+pub trait CompromisedPasswordChecker: Send + Sync + 'static {
+    /// Check a plaintext password against the breach corpus.
+    ///
+    /// # Arguments
+    /// * `password` - Plaintext password to check
+    ///
+    /// # Returns
+    /// `true` if the password is known to be compromised
+    ///
+    /// # Errors
+    /// * `BreachCheckFailed` - The breach corpus could not be queried
+    async fn is_compromised(&self, password: &str) -> Result<bool, PasswordError>;
+}
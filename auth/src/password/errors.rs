@@ -8,4 +8,7 @@ pub enum PasswordError {
 
     #[error("Password verification failed: {0}")]
     VerificationFailed(String),
+
+    #[error("Compromised password check failed: {0}")]
+    BreachCheckFailed(String),
 }
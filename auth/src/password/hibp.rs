@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use sha1::Digest;
+use sha1::Sha1;
+
+use super::compromised::CompromisedPasswordChecker;
+use super::errors::PasswordError;
+
+/// Default Have I Been Pwned range API endpoint.
+const DEFAULT_BASE_URL: &str = "https://api.pwnedpasswords.com";
+
+/// Length of the SHA-1 prefix sent to the range API.
+const PREFIX_LENGTH: usize = 5;
+
+/// Have I Been Pwned password checker using the k-anonymity range API.
+///
+/// Only the first five hex characters of the password's SHA-1 hash leave the
+/// process; the suffix is matched locally against the returned range.
+pub struct HibpPasswordChecker {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl HibpPasswordChecker {
+    /// Create a checker against the public HIBP API.
+    ///
+    /// # Returns
+    /// HibpPasswordChecker instance
+    pub fn new() -> Self {
+        Self::with_base_url(DEFAULT_BASE_URL)
+    }
+
+    /// Create a checker against a custom range API endpoint (mirror or test server).
+    ///
+    /// # Arguments
+    /// * `base_url` - Base URL serving `/range/{prefix}`
+    ///
+    /// # Returns
+    /// HibpPasswordChecker instance
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: base_url.into(),
+        }
+    }
+}
+
+impl Default for HibpPasswordChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CompromisedPasswordChecker for HibpPasswordChecker {
+    async fn is_compromised(&self, password: &str) -> Result<bool, PasswordError> {
+        let hash = sha1_hex(password);
+        let (prefix, suffix) = hash.split_at(PREFIX_LENGTH);
+
+        let body = self
+            .client
+            .get(format!("{}/range/{}", self.base_url, prefix))
+            // Padding hides the real range size from network observers
+            .header("Add-Padding", "true")
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| PasswordError::BreachCheckFailed(e.to_string()))?
+            .text()
+            .await
+            .map_err(|e| PasswordError::BreachCheckFailed(e.to_string()))?;
+
+        Ok(range_contains_suffix(&body, suffix))
+    }
+}
+
+/// Uppercase hex SHA-1 digest, as used by the range API.
+fn sha1_hex(password: &str) -> String {
+    Sha1::digest(password.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect()
+}
+
+/// Whether a range response (`SUFFIX:COUNT` per line) lists the suffix.
+///
+/// Padding entries have a count of zero and are ignored.
+fn range_contains_suffix(body: &str, suffix: &str) -> bool {
+    body.lines().any(|line| {
+        line.trim()
+            .split_once(':')
+            .is_some_and(|(candidate, count)| {
+                candidate.eq_ignore_ascii_case(suffix)
+                    && count.trim().parse::<u64>().is_ok_and(|count| count > 0)
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha1_hex() {
+        assert_eq!(
+            sha1_hex("password"),
+            "5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8"
+        );
+    }
+
+    #[test]
+    fn test_range_contains_suffix() {
+        let body = "0018A45C4D1DEF81644B54AB7F969B88D65:1\r\n\
+                    1E4C9B93F3F0682250B6CF8331B7EE68FD8:9545824\r\n\
+                    011053FD0102E94D6AE2F8B83D76FAF94F6:0";
+
+        assert!(range_contains_suffix(
+            body,
+            "1E4C9B93F3F0682250B6CF8331B7EE68FD8"
+        ));
+        assert!(!range_contains_suffix(
+            body,
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"
+        ));
+    }
+
+    #[test]
+    fn test_range_ignores_padding_entries() {
+        let body = "011053FD0102E94D6AE2F8B83D76FAF94F6:0";
+        assert!(!range_contains_suffix(
+            body,
+            "011053FD0102E94D6AE2F8B83D76FAF94F6"
+        ));
+    }
+}
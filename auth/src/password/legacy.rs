@@ -0,0 +1,52 @@
+use super::errors::PasswordError;
+use crate::secret::SecretString;
+
+/// Verify password hashes written by another system.
+///
+/// Registered on a [`PasswordHasher`](super::PasswordHasher) so that accounts
+/// imported with foreign hashes can still log in. A successful match through a
+/// legacy verifier asks the caller to replace the hash with a current one.
+pub trait LegacyHashVerifier: Send + Sync + 'static {
+    /// Check whether a stored hash is in a format this verifier understands.
+    ///
+    /// # Arguments
+    /// * `hash` - Stored password hash
+    ///
+    /// # Returns
+    /// True if [`verify`](Self::verify) can check passwords against the hash
+    fn supports(&self, hash: &str) -> bool;
+
+    /// Verify a password against a supported legacy hash.
+    ///
+    /// # Arguments
+    /// * `password` - Plaintext password to verify
+    /// * `hash` - Stored password hash, accepted by [`supports`](Self::supports)
+    ///
+    /// # Returns
+    /// True if password matches, false otherwise
+    ///
+    /// # Errors
+    /// * `VerificationFailed` - Hash is malformed or verification failed
+    fn verify(&self, password: &SecretString, hash: &str) -> Result<bool, PasswordError>;
+}
+
+/// Outcome of verifying a password against a stored hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordVerification {
+    /// Password does not match
+    Invalid,
+    /// Password matches a current hash
+    Valid,
+    /// Password matches a legacy hash that should be replaced by a current one
+    NeedsRehash,
+}
+
+impl PasswordVerification {
+    /// Whether the password matched, regardless of the hash format.
+    ///
+    /// # Returns
+    /// True for `Valid` and `NeedsRehash`
+    pub fn is_valid(&self) -> bool {
+        !matches!(self, PasswordVerification::Invalid)
+    }
+}
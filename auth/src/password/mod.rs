@@ -1,5 +1,18 @@
 pub mod argon2;
+#[cfg(feature = "bcrypt")]
+pub mod bcrypt;
+pub mod compromised;
 pub mod errors;
+#[cfg(feature = "hibp")]
+pub mod hibp;
+pub mod legacy;
 
 pub use argon2::PasswordHasher;
+#[cfg(feature = "bcrypt")]
+pub use bcrypt::BcryptVerifier;
+pub use compromised::CompromisedPasswordChecker;
 pub use errors::PasswordError;
+#[cfg(feature = "hibp")]
+pub use hibp::HibpPasswordChecker;
+pub use legacy::LegacyHashVerifier;
+pub use legacy::PasswordVerification;
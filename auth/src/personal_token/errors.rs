@@ -0,0 +1,12 @@
+use thiserror::Error;
+
+/// Error type for personal access token verification.
+#[derive(Debug, Clone, Error)]
+pub enum PersonalTokenError {
+    /// Token is unknown, expired or revoked, or its owner is no longer active
+    #[error("Personal access token is invalid")]
+    Invalid,
+
+    #[error("Personal access token could not be verified: {0}")]
+    Unavailable(String),
+}
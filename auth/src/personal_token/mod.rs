@@ -0,0 +1,7 @@
+pub mod errors;
+pub mod token;
+pub mod verifier;
+
+pub use errors::PersonalTokenError;
+pub use token::PersonalToken;
+pub use verifier::PersonalTokenVerifier;
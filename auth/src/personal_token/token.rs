@@ -0,0 +1,128 @@
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::rand_core::RngCore;
+use data_encoding::BASE64URL_NOPAD;
+use data_encoding::HEXLOWER;
+use ring::digest::digest;
+use ring::digest::SHA256;
+
+use crate::secret::SecretString;
+
+/// Length of the random part of generated tokens (256 bits).
+const SECRET_LENGTH: usize = 32;
+
+/// Newly generated personal access token.
+///
+/// Only `hash` is stored; `token` is shown to its owner once and cannot be
+/// recovered afterwards.
+#[derive(Debug, Clone)]
+pub struct PersonalToken {
+    /// Token to hand to the owner, starting with [`PersonalToken::PREFIX`]
+    pub token: SecretString,
+    /// Digest the token is looked up by, see [`PersonalToken::hash`]
+    pub hash: String,
+}
+
+impl PersonalToken {
+    /// Prefix of every personal access token.
+    ///
+    /// Tells them apart from JWTs without a lookup and lets secret scanners
+    /// spot leaked ones.
+    pub const PREFIX: &'static str = "chat_pat_";
+
+    /// Scope to read channels.
+    pub const CHANNELS_READ: &'static str = "channels:read";
+
+    /// Scope to create channels.
+    pub const CHANNELS_WRITE: &'static str = "channels:write";
+
+    /// Scope to read messages.
+    pub const MESSAGES_READ: &'static str = "messages:read";
+
+    /// Scope to send and delete messages.
+    pub const MESSAGES_WRITE: &'static str = "messages:write";
+
+    /// Every scope a personal access token can be granted.
+    pub const SCOPES: &'static [&'static str] = &[
+        Self::CHANNELS_READ,
+        Self::CHANNELS_WRITE,
+        Self::MESSAGES_READ,
+        Self::MESSAGES_WRITE,
+    ];
+
+    /// Generate a new random token.
+    ///
+    /// # Returns
+    /// Token with a 256-bit secret from the OS random number generator, and its hash
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; SECRET_LENGTH];
+        OsRng.fill_bytes(&mut bytes);
+        let token = format!("{}{}", Self::PREFIX, BASE64URL_NOPAD.encode(&bytes));
+        let hash = Self::hash(&token);
+
+        Self {
+            token: SecretString::new(token),
+            hash,
+        }
+    }
+
+    /// Digest of a token, as stored by the issuer.
+    ///
+    /// The secret has full entropy, so an unsalted SHA-256 is enough and
+    /// lets the issuer find the token by its digest.
+    ///
+    /// # Arguments
+    /// * `token` - Token including its prefix
+    ///
+    /// # Returns
+    /// Lowercase hex SHA-256 digest
+    pub fn hash(token: &str) -> String {
+        HEXLOWER.encode(digest(&SHA256, token.as_bytes()).as_ref())
+    }
+
+    /// Check if a bearer token is a personal access token.
+    ///
+    /// # Arguments
+    /// * `token` - Bearer token of a request
+    ///
+    /// # Returns
+    /// True if the token starts with [`PersonalToken::PREFIX`]
+    pub fn is_personal_token(token: &str) -> bool {
+        token.starts_with(Self::PREFIX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_tokens_are_unique_and_prefixed() {
+        let first = PersonalToken::generate();
+        let second = PersonalToken::generate();
+
+        assert!(PersonalToken::is_personal_token(
+            first.token.expose_secret()
+        ));
+        assert_ne!(first.token.expose_secret(), second.token.expose_secret());
+        assert_ne!(first.hash, second.hash);
+    }
+
+    #[test]
+    fn test_hash_matches_generated_token() {
+        let generated = PersonalToken::generate();
+
+        assert_eq!(
+            PersonalToken::hash(generated.token.expose_secret()),
+            generated.hash
+        );
+        assert_eq!(generated.hash.len(), 64);
+        assert!(!generated.hash.contains(generated.token.expose_secret()));
+    }
+
+    #[test]
+    fn test_jwt_is_not_a_personal_token() {
+        assert!(!PersonalToken::is_personal_token(
+            "eyJhbGciOiJIUzI1NiJ9.e30.sig"
+        ));
+    }
+}
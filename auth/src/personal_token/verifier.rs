@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+
+use super::errors::PersonalTokenError;
+use crate::jwt::Claims;
+
+/// Lookup of personal access tokens at the service that issued them.
+///
+/// Personal access tokens are opaque, so services accepting them ask the
+/// issuer on every request; a revoked token is rejected right away.
+#[async_trait]
+pub trait PersonalTokenVerifier: Send + Sync + 'static {
+    /// Resolve a personal access token to the claims it acts with.
+    ///
+    /// # Arguments
+    /// * `token` - Token presented by the caller, including its prefix
+    ///
+    /// # Returns
+    /// Claims built with [`Claims::for_personal_token`]
+    ///
+    /// # Errors
+    /// * `Invalid` - Token is unknown, expired or revoked
+    /// * `Unavailable` - Issuer could not be reached
+    async fn verify(&self, token: &str) -> Result<Claims, PersonalTokenError>;
+}
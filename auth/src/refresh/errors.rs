@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+use crate::jwt::JwtError;
+
+/// Error type for refresh token operations.
+#[derive(Debug, Clone, Error)]
+pub enum RefreshTokenError {
+    #[error("Refresh token was already used; token family revoked")]
+    ReuseDetected,
+
+    #[error("Refresh token family is revoked or unknown")]
+    Revoked,
+
+    #[error("Refresh token store error: {0}")]
+    StoreError(String),
+
+    #[error("JWT error: {0}")]
+    JwtError(#[from] JwtError),
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use clock::Clock;
+
+use super::errors::RefreshTokenError;
+use super::store::RefreshTokenFamily;
+use super::store::RefreshTokenStore;
+use super::store::Rotation;
+
+/// Process-local refresh token store.
+///
+/// Suitable for tests and single-instance deployments; families are lost on
+/// restart and are not shared between replicas.
+pub struct InMemoryRefreshTokenStore {
+    families: Mutex<HashMap<String, StoredFamily>>,
+    clock: Arc<dyn Clock>,
+}
+
+struct StoredFamily {
+    family: RefreshTokenFamily,
+    revoked: bool,
+}
+
+impl InMemoryRefreshTokenStore {
+    /// Create an empty store.
+    ///
+    /// # Returns
+    /// InMemoryRefreshTokenStore instance
+    pub fn new() -> Self {
+        Self {
+            families: Mutex::new(HashMap::new()),
+            clock: clock::system(),
+        }
+    }
+
+    /// Expire families by another clock.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock families expire by, the system clock by default
+    ///
+    /// # Returns
+    /// InMemoryRefreshTokenStore expiring families by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl Default for InMemoryRefreshTokenStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RefreshTokenStore for InMemoryRefreshTokenStore {
+    async fn create_family(&self, family: RefreshTokenFamily) -> Result<(), RefreshTokenError> {
+        let mut families = self
+            .families
+            .lock()
+            .map_err(|e| RefreshTokenError::StoreError(e.to_string()))?;
+
+        let now = self.clock.now();
+        families.retain(|_, stored| stored.family.expires_at > now);
+        families.insert(
+            family.id.clone(),
+            StoredFamily {
+                family,
+                revoked: false,
+            },
+        );
+
+        Ok(())
+    }
+
+    async fn rotate(
+        &self,
+        family_id: &str,
+        presented_jti: &str,
+        next_jti: &str,
+    ) -> Result<Rotation, RefreshTokenError> {
+        let mut families = self
+            .families
+            .lock()
+            .map_err(|e| RefreshTokenError::StoreError(e.to_string()))?;
+
+        let Some(stored) = families.get_mut(family_id) else {
+            return Ok(Rotation::Revoked);
+        };
+
+        if stored.revoked || stored.family.expires_at <= self.clock.now() {
+            return Ok(Rotation::Revoked);
+        }
+
+        if stored.family.current_jti != presented_jti {
+            stored.revoked = true;
+            return Ok(Rotation::Reused);
+        }
+
+        stored.family.current_jti = next_jti.to_string();
+        Ok(Rotation::Rotated(Box::new(stored.family.claims.clone())))
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> Result<(), RefreshTokenError> {
+        let mut families = self
+            .families
+            .lock()
+            .map_err(|e| RefreshTokenError::StoreError(e.to_string()))?;
+
+        if let Some(stored) = families.get_mut(family_id) {
+            stored.revoked = true;
+        }
+
+        Ok(())
+    }
+
+    async fn revoke_user_families(&self, user_id: &str) -> Result<usize, RefreshTokenError> {
+        let mut families = self
+            .families
+            .lock()
+            .map_err(|e| RefreshTokenError::StoreError(e.to_string()))?;
+
+        let mut revoked = 0;
+        for stored in families.values_mut() {
+            if !stored.revoked && stored.family.claims.sub.as_deref() == Some(user_id) {
+                stored.revoked = true;
+                revoked += 1;
+            }
+        }
+
+        Ok(revoked)
+    }
+}
@@ -0,0 +1,15 @@
+pub mod errors;
+pub mod memory;
+pub mod policy;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+pub mod store;
+
+pub use errors::RefreshTokenError;
+pub use memory::InMemoryRefreshTokenStore;
+pub use policy::RefreshTokenPolicy;
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresRefreshTokenStore;
+pub use store::RefreshTokenFamily;
+pub use store::RefreshTokenStore;
+pub use store::Rotation;
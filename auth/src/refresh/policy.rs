@@ -0,0 +1,34 @@
+use chrono::Duration;
+
+/// Lifetimes applied when issuing token pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefreshTokenPolicy {
+    /// Lifetime of each access token
+    pub access_ttl: Duration,
+    /// Absolute lifetime of a token family; rotation does not extend it
+    pub refresh_ttl: Duration,
+}
+
+impl RefreshTokenPolicy {
+    /// Create a policy with custom lifetimes.
+    ///
+    /// # Arguments
+    /// * `access_ttl` - Lifetime of each access token
+    /// * `refresh_ttl` - Absolute lifetime of a token family
+    ///
+    /// # Returns
+    /// RefreshTokenPolicy instance
+    pub fn new(access_ttl: Duration, refresh_ttl: Duration) -> Self {
+        Self {
+            access_ttl,
+            refresh_ttl,
+        }
+    }
+}
+
+impl Default for RefreshTokenPolicy {
+    /// 15 minute access tokens, 30 day token families.
+    fn default() -> Self {
+        Self::new(Duration::minutes(15), Duration::days(30))
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use clock::Clock;
+use sqlx::types::Json;
+use sqlx::PgPool;
+
+use super::errors::RefreshTokenError;
+use super::store::RefreshTokenFamily;
+use super::store::RefreshTokenStore;
+use super::store::Rotation;
+use crate::jwt::Claims;
+
+/// Refresh token store shared by every instance reading the same database.
+///
+/// Expects the `refresh_token_families` table created by the user-service
+/// migrations. Families survive restarts; expired ones are dropped whenever a
+/// new family is created.
+pub struct PostgresRefreshTokenStore {
+    pool: PgPool,
+    clock: Arc<dyn Clock>,
+}
+
+impl PostgresRefreshTokenStore {
+    /// Create a store on a connection pool.
+    ///
+    /// # Arguments
+    /// * `pool` - Pool of the database holding the refresh token families
+    ///
+    /// # Returns
+    /// PostgresRefreshTokenStore instance
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            clock: clock::system(),
+        }
+    }
+
+    /// Expire families by another clock.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock families expire by, the system clock by default
+    ///
+    /// # Returns
+    /// PostgresRefreshTokenStore expiring families by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl RefreshTokenStore for PostgresRefreshTokenStore {
+    async fn create_family(&self, family: RefreshTokenFamily) -> Result<(), RefreshTokenError> {
+        sqlx::query("DELETE FROM refresh_token_families WHERE expires_at <= $1")
+            .bind(self.clock.now())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RefreshTokenError::StoreError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO refresh_token_families (id, user_id, current_jti, claims, expires_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(&family.id)
+        .bind(family.claims.sub.as_deref())
+        .bind(&family.current_jti)
+        .bind(Json(&family.claims))
+        .bind(family.expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RefreshTokenError::StoreError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn rotate(
+        &self,
+        family_id: &str,
+        presented_jti: &str,
+        next_jti: &str,
+    ) -> Result<Rotation, RefreshTokenError> {
+        let now = self.clock.now();
+
+        // Compare-and-swap, so only one of two concurrent rotations succeeds
+        let rotated: Option<Json<Claims>> = sqlx::query_scalar(
+            r#"
+            UPDATE refresh_token_families
+            SET current_jti = $3
+            WHERE id = $1 AND current_jti = $2 AND NOT revoked AND expires_at > $4
+            RETURNING claims
+            "#,
+        )
+        .bind(family_id)
+        .bind(presented_jti)
+        .bind(next_jti)
+        .bind(now)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RefreshTokenError::StoreError(e.to_string()))?;
+
+        if let Some(Json(claims)) = rotated {
+            return Ok(Rotation::Rotated(Box::new(claims)));
+        }
+
+        // A live family whose current token is another one was presented a rotated token
+        let reused = sqlx::query(
+            r#"
+            UPDATE refresh_token_families
+            SET revoked = TRUE
+            WHERE id = $1 AND NOT revoked AND expires_at > $2
+            "#,
+        )
+        .bind(family_id)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RefreshTokenError::StoreError(e.to_string()))?;
+
+        if reused.rows_affected() > 0 {
+            Ok(Rotation::Reused)
+        } else {
+            Ok(Rotation::Revoked)
+        }
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> Result<(), RefreshTokenError> {
+        sqlx::query("UPDATE refresh_token_families SET revoked = TRUE WHERE id = $1")
+            .bind(family_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RefreshTokenError::StoreError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn revoke_user_families(&self, user_id: &str) -> Result<usize, RefreshTokenError> {
+        let revoked = sqlx::query(
+            "UPDATE refresh_token_families SET revoked = TRUE WHERE user_id = $1 AND NOT revoked",
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RefreshTokenError::StoreError(e.to_string()))?;
+
+        Ok(revoked.rows_affected() as usize)
+    }
+}
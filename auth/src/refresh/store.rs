@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use super::errors::RefreshTokenError;
+use crate::jwt::Claims;
+
+/// Chain of refresh tokens descending from a single login.
+///
+/// Only the most recently issued refresh token (`current_jti`) is valid;
+/// presenting any earlier one means the family has leaked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefreshTokenFamily {
+    /// Family identifier, carried in every refresh token of the family
+    pub id: String,
+    /// `jti` of the only refresh token that may be rotated next
+    pub current_jti: String,
+    /// Access token claims re-issued on every rotation
+    pub claims: Claims,
+    /// Time after which the family can no longer be rotated
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Outcome of a rotation attempt.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rotation {
+    /// Presented token was current; the family now points at the new `jti`
+    Rotated(Box<Claims>),
+    /// Presented token was already rotated; the family has been revoked
+    Reused,
+    /// Family is unknown, expired or revoked
+    Revoked,
+}
+
+/// Persistence for refresh token families.
+///
+/// Implementations must make [`RefreshTokenStore::rotate`] atomic so that two
+/// concurrent rotations of the same token cannot both succeed.
+#[async_trait]
+pub trait RefreshTokenStore: Send + Sync + 'static {
+    /// Store a new token family.
+    ///
+    /// # Arguments
+    /// * `family` - Family with its first refresh token as `current_jti`
+    ///
+    /// # Errors
+    /// * `StoreError` - Family could not be stored
+    async fn create_family(&self, family: RefreshTokenFamily) -> Result<(), RefreshTokenError>;
+
+    /// Swap the current refresh token of a family for a new one.
+    ///
+    /// If `presented_jti` is not the current token, the family must be revoked
+    /// before returning [`Rotation::Reused`].
+    ///
+    /// # Arguments
+    /// * `family_id` - Family of the presented token
+    /// * `presented_jti` - `jti` of the presented refresh token
+    /// * `next_jti` - `jti` of the refresh token about to be issued
+    ///
+    /// # Returns
+    /// Rotation outcome
+    ///
+    /// # Errors
+    /// * `StoreError` - Family could not be read or updated
+    async fn rotate(
+        &self,
+        family_id: &str,
+        presented_jti: &str,
+        next_jti: &str,
+    ) -> Result<Rotation, RefreshTokenError>;
+
+    /// Revoke a family so none of its refresh tokens can be rotated.
+    ///
+    /// # Arguments
+    /// * `family_id` - Family to revoke
+    ///
+    /// # Errors
+    /// * `StoreError` - Family could not be updated
+    async fn revoke_family(&self, family_id: &str) -> Result<(), RefreshTokenError>;
+
+    /// Revoke every family of a user, e.g. after a password change.
+    ///
+    /// # Arguments
+    /// * `user_id` - Subject of the families' claims
+    ///
+    /// # Returns
+    /// Number of revoked families
+    ///
+    /// # Errors
+    /// * `StoreError` - Families could not be updated
+    async fn revoke_user_families(&self, user_id: &str) -> Result<usize, RefreshTokenError>;
+}
@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+use crate::jwt::JwtError;
+
+/// Error type for access token revocation.
+#[derive(Debug, Clone, Error)]
+pub enum RevocationError {
+    #[error("Token is revoked")]
+    Revoked,
+
+    #[error("Revocation store error: {0}")]
+    StoreError(String),
+
+    #[error("JWT error: {0}")]
+    JwtError(#[from] JwtError),
+}
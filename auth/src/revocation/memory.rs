@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use clock::Clock;
+
+use super::errors::RevocationError;
+use super::store::RevocationStore;
+
+/// Process-local revocation store.
+///
+/// Suitable for tests and single-instance deployments; revoked tokens become
+/// valid again after a restart and are not shared between replicas.
+pub struct InMemoryRevocationStore {
+    revoked: Mutex<HashMap<String, DateTime<Utc>>>,
+    revoked_users: Mutex<HashMap<String, UserRevocation>>,
+    clock: Arc<dyn Clock>,
+}
+
+/// Tokens of a user issued before `issued_before`, revoked until `expires_at`
+struct UserRevocation {
+    issued_before: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl InMemoryRevocationStore {
+    /// Create an empty store.
+    ///
+    /// # Returns
+    /// InMemoryRevocationStore instance
+    pub fn new() -> Self {
+        Self {
+            revoked: Mutex::new(HashMap::new()),
+            revoked_users: Mutex::new(HashMap::new()),
+            clock: clock::system(),
+        }
+    }
+
+    /// Expire revocations by another clock.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock revocations expire by, the system clock by default
+    ///
+    /// # Returns
+    /// InMemoryRevocationStore expiring revocations by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl Default for InMemoryRevocationStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RevocationStore for InMemoryRevocationStore {
+    async fn revoke(&self, jti: &str, expires_at: DateTime<Utc>) -> Result<(), RevocationError> {
+        let mut revoked = self
+            .revoked
+            .lock()
+            .map_err(|e| RevocationError::StoreError(e.to_string()))?;
+
+        let now = self.clock.now();
+        revoked.retain(|_, expires_at| *expires_at > now);
+        revoked.insert(jti.to_string(), expires_at);
+        Ok(())
+    }
+
+    async fn is_revoked(&self, jti: &str) -> Result<bool, RevocationError> {
+        let revoked = self
+            .revoked
+            .lock()
+            .map_err(|e| RevocationError::StoreError(e.to_string()))?;
+
+        Ok(revoked
+            .get(jti)
+            .is_some_and(|expires_at| *expires_at > self.clock.now()))
+    }
+
+    async fn revoke_user(
+        &self,
+        user_id: &str,
+        issued_before: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), RevocationError> {
+        let mut revoked_users = self
+            .revoked_users
+            .lock()
+            .map_err(|e| RevocationError::StoreError(e.to_string()))?;
+
+        let now = self.clock.now();
+        revoked_users.retain(|_, revocation| revocation.expires_at > now);
+        revoked_users.insert(
+            user_id.to_string(),
+            UserRevocation {
+                issued_before,
+                expires_at,
+            },
+        );
+        Ok(())
+    }
+
+    async fn user_revoked_before(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<DateTime<Utc>>, RevocationError> {
+        let revoked_users = self
+            .revoked_users
+            .lock()
+            .map_err(|e| RevocationError::StoreError(e.to_string()))?;
+
+        Ok(revoked_users
+            .get(user_id)
+            .filter(|revocation| revocation.expires_at > self.clock.now())
+            .map(|revocation| revocation.issued_before))
+    }
+}
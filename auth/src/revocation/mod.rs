@@ -0,0 +1,11 @@
+pub mod errors;
+pub mod memory;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+pub mod store;
+
+pub use errors::RevocationError;
+pub use memory::InMemoryRevocationStore;
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresRevocationStore;
+pub use store::RevocationStore;
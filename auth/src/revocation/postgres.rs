@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use clock::Clock;
+use sqlx::PgPool;
+
+use super::errors::RevocationError;
+use super::store::RevocationStore;
+
+/// Revocation store shared by every service reading the same database.
+///
+/// Expects the `revoked_tokens` and `revoked_users` tables created by the
+/// user-service migrations. Expired records are ignored and dropped on the
+/// next revocation.
+pub struct PostgresRevocationStore {
+    pool: PgPool,
+    clock: Arc<dyn Clock>,
+}
+
+impl PostgresRevocationStore {
+    /// Create a store on a connection pool.
+    ///
+    /// # Arguments
+    /// * `pool` - Pool of the database holding the revocation tables
+    ///
+    /// # Returns
+    /// PostgresRevocationStore instance
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            clock: clock::system(),
+        }
+    }
+
+    /// Expire revocations by another clock.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock revocations expire by, the system clock by default
+    ///
+    /// # Returns
+    /// PostgresRevocationStore expiring revocations by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl RevocationStore for PostgresRevocationStore {
+    async fn revoke(&self, jti: &str, expires_at: DateTime<Utc>) -> Result<(), RevocationError> {
+        sqlx::query("DELETE FROM revoked_tokens WHERE expires_at <= $1")
+            .bind(self.clock.now())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RevocationError::StoreError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO revoked_tokens (jti, expires_at)
+            VALUES ($1, $2)
+            ON CONFLICT (jti) DO UPDATE SET
+                expires_at = GREATEST(revoked_tokens.expires_at, EXCLUDED.expires_at)
+            "#,
+        )
+        .bind(jti)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RevocationError::StoreError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn is_revoked(&self, jti: &str) -> Result<bool, RevocationError> {
+        sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM revoked_tokens WHERE jti = $1 AND expires_at > $2)",
+        )
+        .bind(jti)
+        .bind(self.clock.now())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| RevocationError::StoreError(e.to_string()))
+    }
+
+    async fn revoke_user(
+        &self,
+        user_id: &str,
+        issued_before: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), RevocationError> {
+        sqlx::query("DELETE FROM revoked_users WHERE expires_at <= $1")
+            .bind(self.clock.now())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RevocationError::StoreError(e.to_string()))?;
+
+        // A later revocation of another instance is never moved back
+        sqlx::query(
+            r#"
+            INSERT INTO revoked_users (user_id, issued_before, expires_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id) DO UPDATE SET
+                issued_before = GREATEST(revoked_users.issued_before, EXCLUDED.issued_before),
+                expires_at = GREATEST(revoked_users.expires_at, EXCLUDED.expires_at)
+            "#,
+        )
+        .bind(user_id)
+        .bind(issued_before)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RevocationError::StoreError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn user_revoked_before(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<DateTime<Utc>>, RevocationError> {
+        sqlx::query_scalar(
+            "SELECT issued_before FROM revoked_users WHERE user_id = $1 AND expires_at > $2",
+        )
+        .bind(user_id)
+        .bind(self.clock.now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RevocationError::StoreError(e.to_string()))
+    }
+}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use super::errors::RevocationError;
+
+/// Denylist of access tokens revoked before they expired.
+///
+/// Tokens are identified by their `jti` claim, or revoked all at once per
+/// user by issue time, and only kept until they expire; after that the
+/// signature check rejects them on its own.
+#[async_trait]
+pub trait RevocationStore: Send + Sync + 'static {
+    /// Add a token to the denylist.
+    ///
+    /// # Arguments
+    /// * `jti` - Unique identifier of the token
+    /// * `expires_at` - Expiration of the token, after which the record may be dropped
+    ///
+    /// # Errors
+    /// * `StoreError` - Record could not be written
+    async fn revoke(&self, jti: &str, expires_at: DateTime<Utc>) -> Result<(), RevocationError>;
+
+    /// Check if a token is on the denylist.
+    ///
+    /// # Arguments
+    /// * `jti` - Unique identifier of the token
+    ///
+    /// # Returns
+    /// `true` if the token was revoked
+    ///
+    /// # Errors
+    /// * `StoreError` - Record could not be read
+    async fn is_revoked(&self, jti: &str) -> Result<bool, RevocationError>;
+
+    /// Revoke every token of a user issued before a time.
+    ///
+    /// # Arguments
+    /// * `user_id` - Subject of the tokens
+    /// * `issued_before` - Tokens issued earlier are revoked
+    /// * `expires_at` - Time the last such token expires, after which the record may be dropped
+    ///
+    /// # Errors
+    /// * `StoreError` - Record could not be written
+    async fn revoke_user(
+        &self,
+        user_id: &str,
+        issued_before: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), RevocationError>;
+
+    /// Get the time before which every token of a user is revoked.
+    ///
+    /// # Arguments
+    /// * `user_id` - Subject of the tokens
+    ///
+    /// # Returns
+    /// Optional cutoff (None if the tokens of the user were never revoked together)
+    ///
+    /// # Errors
+    /// * `StoreError` - Record could not be read
+    async fn user_revoked_before(
+        &self,
+        user_id: &str,
+    ) -> Result<Option<DateTime<Utc>>, RevocationError>;
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Deserializer;
+use zeroize::Zeroizing;
+
+/// Secret key material that is wiped from memory when dropped.
+///
+/// `Debug` is redacted so secrets do not end up in logs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretBytes(Zeroizing<Vec<u8>>);
+
+impl SecretBytes {
+    /// Wrap secret bytes.
+    ///
+    /// # Arguments
+    /// * `bytes` - Secret bytes, wiped when the wrapper is dropped
+    ///
+    /// # Returns
+    /// SecretBytes owning the bytes
+    pub fn new(bytes: impl Into<Vec<u8>>) -> Self {
+        Self(Zeroizing::new(bytes.into()))
+    }
+
+    /// Get the plaintext bytes.
+    ///
+    /// Callers must not copy the result into memory that outlives the wrapper.
+    pub fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for SecretBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl From<&[u8]> for SecretBytes {
+    fn from(bytes: &[u8]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for SecretBytes {
+    fn from(bytes: &[u8; N]) -> Self {
+        Self::new(bytes.as_slice())
+    }
+}
+
+impl From<SecretString> for SecretBytes {
+    fn from(mut secret: SecretString) -> Self {
+        Self::new(std::mem::take(&mut *secret.0).into_bytes())
+    }
+}
+
+impl fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretBytes([REDACTED])")
+    }
+}
+
+/// Secret text, such as a password in transit, that is wiped from memory when dropped.
+///
+/// `Debug` is redacted so secrets do not end up in logs. Deserializes from a
+/// plain JSON string so request bodies can hold passwords directly.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    /// Wrap a secret string.
+    ///
+    /// # Arguments
+    /// * `secret` - Secret text, wiped when the wrapper is dropped
+    ///
+    /// # Returns
+    /// SecretString owning the text
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(Zeroizing::new(secret.into()))
+    }
+
+    /// Get the plaintext string.
+    ///
+    /// Callers must not copy the result into memory that outlives the wrapper.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretString([REDACTED])")
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let bytes = SecretBytes::from(b"hunter2-jwt-secret");
+        let string = SecretString::from("hunter2");
+
+        assert_eq!(format!("{:?}", bytes), "SecretBytes([REDACTED])");
+        assert_eq!(format!("{:?}", string), "SecretString([REDACTED])");
+    }
+
+    #[test]
+    fn test_expose_secret_returns_plaintext() {
+        assert_eq!(SecretBytes::from(b"key").expose_secret(), b"key");
+        assert_eq!(SecretString::from("hunter2").expose_secret(), "hunter2");
+        assert_eq!(
+            SecretBytes::from(SecretString::from("hunter2")).expose_secret(),
+            b"hunter2"
+        );
+    }
+
+    #[test]
+    fn test_secret_string_deserializes_from_json_string() {
+        let secret: SecretString = serde_json::from_str("\"hunter2\"").unwrap();
+        assert_eq!(secret.expose_secret(), "hunter2");
+    }
+}
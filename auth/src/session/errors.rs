@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+use crate::jwt::JwtError;
+
+/// Error type for session operations.
+#[derive(Debug, Clone, Error)]
+pub enum SessionError {
+    #[error("Session is revoked or unknown")]
+    Revoked,
+
+    #[error("Session has expired")]
+    Expired,
+
+    #[error("Session store error: {0}")]
+    StoreError(String),
+
+    #[error("JWT error: {0}")]
+    JwtError(#[from] JwtError),
+}
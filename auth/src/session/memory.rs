@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use clock::Clock;
+
+use super::errors::SessionError;
+use super::store::Session;
+use super::store::SessionStore;
+
+/// Process-local session store.
+///
+/// Suitable for tests and single-instance deployments; sessions are lost on
+/// restart and are not shared between replicas.
+pub struct InMemorySessionStore {
+    sessions: Mutex<HashMap<String, Session>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl InMemorySessionStore {
+    /// Create an empty store.
+    ///
+    /// # Returns
+    /// InMemorySessionStore instance
+    pub fn new() -> Self {
+        Self {
+            sessions: Mutex::new(HashMap::new()),
+            clock: clock::system(),
+        }
+    }
+
+    /// Drop expired sessions by another clock.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock sessions expire by, the system clock by default
+    ///
+    /// # Returns
+    /// InMemorySessionStore expiring sessions by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl Default for InMemorySessionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SessionStore for InMemorySessionStore {
+    async fn create(&self, session: Session) -> Result<(), SessionError> {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .map_err(|e| SessionError::StoreError(e.to_string()))?;
+
+        let now = self.clock.now();
+        sessions.retain(|_, session| !session.is_expired(now));
+        sessions.insert(session.id.clone(), session);
+
+        Ok(())
+    }
+
+    async fn get(&self, session_id: &str) -> Result<Option<Session>, SessionError> {
+        let sessions = self
+            .sessions
+            .lock()
+            .map_err(|e| SessionError::StoreError(e.to_string()))?;
+
+        Ok(sessions.get(session_id).cloned())
+    }
+
+    async fn list_for_user(&self, user_id: &str) -> Result<Vec<Session>, SessionError> {
+        let sessions = self
+            .sessions
+            .lock()
+            .map_err(|e| SessionError::StoreError(e.to_string()))?;
+
+        Ok(sessions
+            .values()
+            .filter(|session| session.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn revoke(&self, session_id: &str) -> Result<(), SessionError> {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .map_err(|e| SessionError::StoreError(e.to_string()))?;
+
+        sessions.remove(session_id);
+        Ok(())
+    }
+
+    async fn revoke_for_user(
+        &self,
+        user_id: &str,
+        except: Option<&str>,
+    ) -> Result<usize, SessionError> {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .map_err(|e| SessionError::StoreError(e.to_string()))?;
+
+        let before = sessions.len();
+        sessions.retain(|id, session| session.user_id != user_id || except == Some(id.as_str()));
+
+        Ok(before - sessions.len())
+    }
+}
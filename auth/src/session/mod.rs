@@ -0,0 +1,9 @@
+pub mod errors;
+pub mod memory;
+pub mod store;
+
+pub use errors::SessionError;
+pub use memory::InMemorySessionStore;
+pub use store::DeviceInfo;
+pub use store::Session;
+pub use store::SessionStore;
@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use super::errors::SessionError;
+
+/// Client a session was started from, as reported at login.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// `User-Agent` of the client
+    pub user_agent: Option<String>,
+    /// Network address of the client
+    pub ip_address: Option<String>,
+    /// Name the user gave the device, if any
+    pub name: Option<String>,
+}
+
+/// Login of a user on one device.
+///
+/// Access tokens carry the session ID, so revoking the session invalidates
+/// them before they expire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    /// Session identifier, carried in the `sid` claim
+    pub id: String,
+    /// User the session belongs to
+    pub user_id: String,
+    /// Client the session was started from
+    pub device: DeviceInfo,
+    pub created_at: DateTime<Utc>,
+    /// Time after which tokens of the session are rejected
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Session {
+    /// Whether the session has expired at `now`.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// Persistence for sessions.
+///
+/// Revoked sessions may simply be deleted; expired sessions may be dropped
+/// at any time.
+#[async_trait]
+pub trait SessionStore: Send + Sync + 'static {
+    /// Store a new session.
+    ///
+    /// # Arguments
+    /// * `session` - Session to store
+    ///
+    /// # Errors
+    /// * `StoreError` - Session could not be stored
+    async fn create(&self, session: Session) -> Result<(), SessionError>;
+
+    /// Look up a session that has not been revoked.
+    ///
+    /// # Arguments
+    /// * `session_id` - Session identifier
+    ///
+    /// # Returns
+    /// The session, or None if it is unknown or revoked
+    ///
+    /// # Errors
+    /// * `StoreError` - Session could not be read
+    async fn get(&self, session_id: &str) -> Result<Option<Session>, SessionError>;
+
+    /// List the sessions of a user that have not been revoked.
+    ///
+    /// # Arguments
+    /// * `user_id` - User whose sessions to list
+    ///
+    /// # Returns
+    /// Sessions of the user, in any order
+    ///
+    /// # Errors
+    /// * `StoreError` - Sessions could not be read
+    async fn list_for_user(&self, user_id: &str) -> Result<Vec<Session>, SessionError>;
+
+    /// Revoke a session.
+    ///
+    /// Revoking an unknown session is not an error.
+    ///
+    /// # Arguments
+    /// * `session_id` - Session to revoke
+    ///
+    /// # Errors
+    /// * `StoreError` - Session could not be revoked
+    async fn revoke(&self, session_id: &str) -> Result<(), SessionError>;
+
+    /// Revoke every session of a user, optionally keeping one.
+    ///
+    /// # Arguments
+    /// * `user_id` - User whose sessions to revoke
+    /// * `except` - Session to keep, usually the caller's own
+    ///
+    /// # Returns
+    /// Number of revoked sessions
+    ///
+    /// # Errors
+    /// * `StoreError` - Sessions could not be revoked
+    async fn revoke_for_user(
+        &self,
+        user_id: &str,
+        except: Option<&str>,
+    ) -> Result<usize, SessionError>;
+}
@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+/// Error type for TOTP operations.
+#[derive(Debug, Clone, Error)]
+pub enum TotpError {
+    #[error("Invalid TOTP secret: {0}")]
+    InvalidSecret(String),
+}
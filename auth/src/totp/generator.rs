@@ -0,0 +1,269 @@
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
+use sha1::Sha1;
+
+use super::secret::TotpSecret;
+
+/// Number of digits in generated codes.
+const DIGITS: u32 = 6;
+
+/// Default time step in seconds.
+const DEFAULT_PERIOD: u64 = 30;
+
+/// Default number of adjacent time steps accepted on each side (clock drift).
+const DEFAULT_SKEW: u64 = 1;
+
+/// Time-based one-time password generator and verifier (RFC 6238, HMAC-SHA1).
+///
+/// Uses the parameters every mainstream authenticator app supports:
+/// six digits and a 30 second time step.
+#[derive(Debug, Clone)]
+pub struct Totp {
+    issuer: String,
+    period: u64,
+    skew: u64,
+}
+
+impl Totp {
+    /// Create a TOTP instance for an issuer.
+    ///
+    /// # Arguments
+    /// * `issuer` - Service name shown in authenticator apps
+    ///
+    /// # Returns
+    /// Totp with 30 second steps, accepting one step of clock drift
+    pub fn new(issuer: impl Into<String>) -> Self {
+        Self {
+            issuer: issuer.into(),
+            period: DEFAULT_PERIOD,
+            skew: DEFAULT_SKEW,
+        }
+    }
+
+    /// Set the time step in seconds (must be greater than zero).
+    pub fn with_period(mut self, period: u64) -> Self {
+        self.period = period.max(1);
+        self
+    }
+
+    /// Set how many adjacent time steps are accepted on each side.
+    pub fn with_skew(mut self, skew: u64) -> Self {
+        self.skew = skew;
+        self
+    }
+
+    /// Generate the code for the current time.
+    ///
+    /// # Arguments
+    /// * `secret` - Shared secret
+    ///
+    /// # Returns
+    /// Six-digit code, zero-padded
+    pub fn generate(&self, secret: &TotpSecret) -> String {
+        self.generate_at(secret, Utc::now().timestamp())
+    }
+
+    /// Generate the code for a given time.
+    ///
+    /// # Arguments
+    /// * `secret` - Shared secret
+    /// * `timestamp` - Unix timestamp in seconds
+    ///
+    /// # Returns
+    /// Six-digit code, zero-padded
+    pub fn generate_at(&self, secret: &TotpSecret, timestamp: i64) -> String {
+        self.code_for_step(secret, self.time_step(timestamp))
+    }
+
+    /// Verify a code against the current time.
+    ///
+    /// # Arguments
+    /// * `secret` - Shared secret
+    /// * `code` - Code entered by the user
+    ///
+    /// # Returns
+    /// Matched time step, or None if the code is invalid
+    pub fn verify(&self, secret: &TotpSecret, code: &str) -> Option<u64> {
+        self.verify_at(secret, code, Utc::now().timestamp())
+    }
+
+    /// Verify a code against a given time, allowing for clock drift.
+    ///
+    /// A code stays valid for its whole window, so callers should store the
+    /// returned time step and reject codes whose step is not greater than the
+    /// last one accepted for the user, preventing replay.
+    ///
+    /// # Arguments
+    /// * `secret` - Shared secret
+    /// * `code` - Code entered by the user (surrounding whitespace ignored)
+    /// * `timestamp` - Unix timestamp in seconds
+    ///
+    /// # Returns
+    /// Matched time step, or None if the code is invalid
+    pub fn verify_at(&self, secret: &TotpSecret, code: &str, timestamp: i64) -> Option<u64> {
+        let code = code.trim();
+        if code.len() != DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let current = self.time_step(timestamp);
+        let first = current.saturating_sub(self.skew);
+        let last = current.saturating_add(self.skew);
+
+        // Check every step in the window so timing does not reveal which one matched
+        let mut matched = None;
+        for step in first..=last {
+            if constant_time_eq(self.code_for_step(secret, step).as_bytes(), code.as_bytes()) {
+                matched = Some(step);
+            }
+        }
+        matched
+    }
+
+    /// Build the `otpauth://` URI used for QR code enrollment.
+    ///
+    /// # Arguments
+    /// * `secret` - Shared secret
+    /// * `account_name` - Account label shown in authenticator apps (e.g. username or email)
+    ///
+    /// # Returns
+    /// Key URI in the format understood by authenticator apps
+    pub fn provisioning_uri(&self, secret: &TotpSecret, account_name: &str) -> String {
+        let issuer = percent_encode(&self.issuer);
+        format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            issuer,
+            percent_encode(account_name),
+            secret.to_base32(),
+            issuer,
+            DIGITS,
+            self.period
+        )
+    }
+
+    fn time_step(&self, timestamp: i64) -> u64 {
+        timestamp.max(0) as u64 / self.period
+    }
+
+    fn code_for_step(&self, secret: &TotpSecret, step: u64) -> String {
+        let mut mac = Hmac::<Sha1>::new_from_slice(secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(&step.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+
+        // Dynamic truncation (RFC 4226, section 5.3)
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            digest[offset] & 0x7f,
+            digest[offset + 1],
+            digest[offset + 2],
+            digest[offset + 3],
+        ]);
+
+        format!(
+            "{:0width$}",
+            binary % 10u32.pow(DIGITS),
+            width = DIGITS as usize
+        )
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rfc_secret() -> TotpSecret {
+        TotpSecret::from_bytes(b"12345678901234567890".to_vec())
+    }
+
+    #[test]
+    fn test_rfc6238_vectors() {
+        // RFC 6238 appendix B (SHA1), truncated to six digits
+        let totp = Totp::new("chat-rs");
+        let secret = rfc_secret();
+
+        assert_eq!(totp.generate_at(&secret, 59), "287082");
+        assert_eq!(totp.generate_at(&secret, 1_111_111_109), "081804");
+        assert_eq!(totp.generate_at(&secret, 1_234_567_890), "005924");
+        assert_eq!(totp.generate_at(&secret, 2_000_000_000), "279037");
+    }
+
+    #[test]
+    fn test_verify_accepts_adjacent_steps() {
+        let totp = Totp::new("chat-rs");
+        let secret = rfc_secret();
+        let code = totp.generate_at(&secret, 1_111_111_109);
+        let step = 1_111_111_109 / 30;
+
+        assert_eq!(totp.verify_at(&secret, &code, 1_111_111_109), Some(step));
+        assert_eq!(
+            totp.verify_at(&secret, &code, 1_111_111_109 + 30),
+            Some(step)
+        );
+        assert_eq!(
+            totp.verify_at(&secret, &code, 1_111_111_109 - 30),
+            Some(step)
+        );
+        assert_eq!(totp.verify_at(&secret, &code, 1_111_111_109 + 90), None);
+    }
+
+    #[test]
+    fn test_verify_without_skew() {
+        let totp = Totp::new("chat-rs").with_skew(0);
+        let secret = rfc_secret();
+        let code = totp.generate_at(&secret, 1_111_111_109);
+
+        assert!(totp.verify_at(&secret, &code, 1_111_111_109 + 30).is_none());
+    }
+
+    #[test]
+    fn test_verify_rejects_malformed_codes() {
+        let totp = Totp::new("chat-rs");
+        let secret = rfc_secret();
+
+        assert!(totp.verify_at(&secret, "", 59).is_none());
+        assert!(totp.verify_at(&secret, "28708", 59).is_none());
+        assert!(totp.verify_at(&secret, "2870821", 59).is_none());
+        assert!(totp.verify_at(&secret, "28708a", 59).is_none());
+        assert!(totp.verify_at(&secret, " 287082 ", 59).is_some());
+    }
+
+    #[test]
+    fn test_generate_matches_verify() {
+        let totp = Totp::new("chat-rs");
+        let secret = TotpSecret::generate();
+
+        let code = totp.generate(&secret);
+        assert!(totp.verify(&secret, &code).is_some());
+    }
+
+    #[test]
+    fn test_provisioning_uri() {
+        let totp = Totp::new("chat rs");
+        let secret = rfc_secret();
+
+        let uri = totp.provisioning_uri(&secret, "alice@example.com");
+        assert_eq!(
+            uri,
+            "otpauth://totp/chat%20rs:alice%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ\
+             &issuer=chat%20rs&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod errors;
+pub mod generator;
+pub mod secret;
+
+pub use errors::TotpError;
+pub use generator::Totp;
+pub use secret::TotpSecret;
@@ -0,0 +1,132 @@
+use std::fmt;
+
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::rand_core::RngCore;
+use data_encoding::BASE32_NOPAD;
+use zeroize::Zeroizing;
+
+use super::errors::TotpError;
+use crate::secret::SecretBytes;
+
+/// Length of generated secrets (160 bits, as recommended for HMAC-SHA1).
+const SECRET_LENGTH: usize = 20;
+
+/// Minimum accepted secret length (128 bits, RFC 4226).
+const MIN_SECRET_LENGTH: usize = 16;
+
+/// Shared TOTP secret between the service and the user's authenticator app.
+///
+/// `Debug` is redacted so secrets do not end up in logs, and the bytes are
+/// wiped from memory when the secret is dropped.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TotpSecret(SecretBytes);
+
+impl TotpSecret {
+    /// Generate a new random secret.
+    ///
+    /// # Returns
+    /// 160-bit secret from the OS random number generator
+    pub fn generate() -> Self {
+        let mut bytes = vec![0u8; SECRET_LENGTH];
+        OsRng.fill_bytes(&mut bytes);
+        Self(SecretBytes::new(bytes))
+    }
+
+    /// Parse a secret from its Base32 representation.
+    ///
+    /// Accepts lowercase input, spaces and `=` padding as typed or stored by users.
+    ///
+    /// # Arguments
+    /// * `encoded` - Base32-encoded secret
+    ///
+    /// # Returns
+    /// Decoded secret
+    ///
+    /// # Errors
+    /// * `InvalidSecret` - Input is not Base32 or shorter than 128 bits
+    pub fn from_base32(encoded: &str) -> Result<Self, TotpError> {
+        let normalized: Zeroizing<String> = encoded
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '=')
+            .map(|c| c.to_ascii_uppercase())
+            .collect::<String>()
+            .into();
+
+        let bytes = BASE32_NOPAD
+            .decode(normalized.as_bytes())
+            .map(SecretBytes::new)
+            .map_err(|e| TotpError::InvalidSecret(e.to_string()))?;
+
+        if bytes.expose_secret().len() < MIN_SECRET_LENGTH {
+            return Err(TotpError::InvalidSecret(format!(
+                "secret must be at least {} bytes, got {}",
+                MIN_SECRET_LENGTH,
+                bytes.expose_secret().len()
+            )));
+        }
+
+        Ok(Self(bytes))
+    }
+
+    /// Create a secret from raw bytes.
+    ///
+    /// # Arguments
+    /// * `bytes` - Raw secret bytes
+    ///
+    /// # Returns
+    /// Secret wrapping the bytes
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Self {
+        Self(SecretBytes::new(bytes))
+    }
+
+    /// Encode the secret as unpadded Base32, the format authenticator apps expect.
+    pub fn to_base32(&self) -> String {
+        BASE32_NOPAD.encode(self.0.expose_secret())
+    }
+
+    /// Get the raw secret bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.expose_secret()
+    }
+}
+
+impl fmt::Debug for TotpSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TotpSecret([REDACTED])")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_round_trips_through_base32() {
+        let secret = TotpSecret::generate();
+        assert_eq!(secret.as_bytes().len(), SECRET_LENGTH);
+
+        let encoded = secret.to_base32();
+        assert_eq!(TotpSecret::from_base32(&encoded).unwrap(), secret);
+        assert_ne!(TotpSecret::generate(), secret);
+    }
+
+    #[test]
+    fn test_from_base32_normalizes_input() {
+        let secret = TotpSecret::from_bytes(b"12345678901234567890".to_vec());
+        let typed = "gezd gnbv gy3t qojq gezd gnbv gy3t qojq";
+
+        assert_eq!(TotpSecret::from_base32(typed).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_from_base32_rejects_short_or_invalid_secrets() {
+        assert!(TotpSecret::from_base32("GEZDGNBV").is_err());
+        assert!(TotpSecret::from_base32("not base32!").is_err());
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let secret = TotpSecret::generate();
+        assert!(!format!("{:?}", secret).contains(&secret.to_base32()));
+    }
+}
@@ -0,0 +1,41 @@
+use std::process::Command;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+/// Expose git SHA, build timestamp, and enabled cargo features to the crate
+/// as `BUILD_GIT_SHA`, `BUILD_TIMESTAMP`, and `BUILD_FEATURES`.
+///
+/// Call from the `build.rs` of a service, then read the variables back with
+/// [`build_info!`](crate::build_info).
+pub fn emit_build_info() {
+    // Docker builds have no .git directory, so allow the SHA to be passed in
+    let git_sha = std::env::var("GIT_SHA").ok().or_else(|| {
+        Command::new("git")
+            .args(["rev-parse", "--short", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|sha| sha.trim().to_string())
+    });
+
+    let build_timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+
+    let mut features: Vec<String> = std::env::vars()
+        .filter_map(|(key, _)| key.strip_prefix("CARGO_FEATURE_").map(str::to_string))
+        .map(|feature| feature.to_lowercase().replace('_', "-"))
+        .collect();
+    features.sort();
+
+    println!(
+        "cargo:rustc-env=BUILD_GIT_SHA={}",
+        git_sha.unwrap_or_else(|| "unknown".to_string())
+    );
+    println!("cargo:rustc-env=BUILD_TIMESTAMP={}", build_timestamp);
+    println!("cargo:rustc-env=BUILD_FEATURES={}", features.join(","));
+    println!("cargo:rerun-if-env-changed=GIT_SHA");
+    println!("cargo:rerun-if-changed=../.git/HEAD");
+}
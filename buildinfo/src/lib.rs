@@ -0,0 +1,213 @@
+//! Build and runtime identity shared by the services.
+//!
+//! Each service calls [`emit_build_info`] from its `build.rs`, which hands the
+//! git SHA, build timestamp, and enabled features to the compiler, and then
+//! collects them at startup with [`build_info!`]:
+//!
+//! ```ignore
+//! // build.rs
+//! buildinfo::emit_build_info();
+//!
+//! // main.rs
+//! let build_info = buildinfo::build_info!("user-service", &config.redacted());
+//! build_info.log_startup();
+//! ```
+//!
+//! The configuration is digested as given, so secrets must be redacted first;
+//! [`redact_url_password`] covers connection URLs.
+
+mod emit;
+mod redact;
+
+pub use emit::emit_build_info;
+pub use redact::redact_url_password;
+pub use redact::REDACTED;
+
+use std::fmt::Display;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// Build and runtime identity of a running service instance.
+///
+/// Exposed on `/internal/version` and logged at startup so that incidents
+/// can be correlated with the exact build and configuration of an instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    pub service: &'static str,
+    pub version: &'static str,
+    pub git_sha: &'static str,
+    pub build_timestamp: Option<DateTime<Utc>>,
+    pub features: Vec<&'static str>,
+    pub config_digest: String,
+    /// Identity of this process, for services that run several instances
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+}
+
+/// Collect build information for the calling crate.
+///
+/// Reads the variables set by [`emit_build_info`] in the build script of the
+/// crate the macro is expanded in.
+///
+/// # Arguments
+/// * `service` - Name of the service
+/// * `config` - Active configuration, with secrets redacted
+///
+/// # Returns
+/// [`BuildInfo`] for this instance
+#[macro_export]
+macro_rules! build_info {
+    ($service:expr, $config:expr) => {
+        $crate::BuildInfo::new(
+            $service,
+            env!("CARGO_PKG_VERSION"),
+            env!("BUILD_GIT_SHA"),
+            env!("BUILD_TIMESTAMP"),
+            env!("BUILD_FEATURES"),
+            $config,
+        )
+    };
+}
+
+impl BuildInfo {
+    /// Create build information from the values emitted at build time.
+    ///
+    /// Prefer [`build_info!`], which reads them from the environment.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service
+    /// * `version` - Crate version
+    /// * `git_sha` - Short git SHA of the build
+    /// * `build_timestamp` - Unix timestamp of the build, in seconds
+    /// * `features` - Comma-separated cargo features enabled in the build
+    /// * `config` - Active configuration, with secrets redacted
+    ///
+    /// # Returns
+    /// BuildInfo for this instance
+    pub fn new(
+        service: &'static str,
+        version: &'static str,
+        git_sha: &'static str,
+        build_timestamp: &'static str,
+        features: &'static str,
+        config: &impl Serialize,
+    ) -> Self {
+        let build_timestamp = build_timestamp
+            .parse::<i64>()
+            .ok()
+            .and_then(|secs| DateTime::from_timestamp(secs, 0));
+
+        Self {
+            service,
+            version,
+            git_sha,
+            build_timestamp,
+            features: features
+                .split(',')
+                .filter(|feature| !feature.is_empty())
+                .collect(),
+            config_digest: config_digest(config),
+            instance_id: None,
+        }
+    }
+
+    /// Set the identity of this process.
+    ///
+    /// # Arguments
+    /// * `instance_id` - Identity generated at startup
+    ///
+    /// # Returns
+    /// BuildInfo carrying the instance identity
+    pub fn with_instance_id(mut self, instance_id: impl Display) -> Self {
+        self.instance_id = Some(instance_id.to_string());
+        self
+    }
+
+    /// Emit the structured startup event.
+    pub fn log_startup(&self) {
+        tracing::info!(
+            service = self.service,
+            version = self.version,
+            git_sha = self.git_sha,
+            build_timestamp = ?self.build_timestamp,
+            features = ?self.features,
+            config_digest = %self.config_digest,
+            instance_id = self.instance_id.as_deref(),
+            "Service starting"
+        );
+    }
+}
+
+/// SHA-256 of the configuration, hex encoded.
+fn config_digest(config: &impl Serialize) -> String {
+    let serialized = serde_json::to_vec(config).unwrap_or_default();
+
+    Sha256::digest(serialized)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct TestConfig {
+        port: u16,
+    }
+
+    #[test]
+    fn test_build_info_parses_build_values() {
+        let info = BuildInfo::new(
+            "test-service",
+            "1.2.3",
+            "abc1234",
+            "1792022400",
+            "bench,kafka",
+            &TestConfig { port: 8080 },
+        );
+
+        assert_eq!(info.build_timestamp.unwrap().timestamp(), 1_792_022_400);
+        assert_eq!(info.features, vec!["bench", "kafka"]);
+        assert_eq!(info.config_digest.len(), 64);
+        assert_eq!(info.instance_id, None);
+    }
+
+    #[test]
+    fn test_build_info_without_features_or_timestamp() {
+        let info = BuildInfo::new(
+            "test-service",
+            "1.2.3",
+            "unknown",
+            "",
+            "",
+            &TestConfig { port: 1 },
+        );
+
+        assert_eq!(info.build_timestamp, None);
+        assert!(info.features.is_empty());
+    }
+
+    #[test]
+    fn test_config_digest_changes_with_config() {
+        let first = BuildInfo::new("s", "1", "x", "0", "", &TestConfig { port: 1 });
+        let second = BuildInfo::new("s", "1", "x", "0", "", &TestConfig { port: 2 });
+
+        assert_ne!(first.config_digest, second.config_digest);
+    }
+
+    #[test]
+    fn test_instance_id_is_serialized_only_when_set() {
+        let info = BuildInfo::new("s", "1", "x", "0", "", &TestConfig { port: 1 });
+        let json = serde_json::to_value(&info).unwrap();
+        assert!(json.get("instance_id").is_none());
+
+        let json = serde_json::to_value(info.with_instance_id("instance-1")).unwrap();
+        assert_eq!(json["instance_id"], "instance-1");
+    }
+}
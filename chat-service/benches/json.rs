@@ -0,0 +1,129 @@
+//! Decoding throughput of the JSON hot paths.
+//!
+//! Compare backends by running the benchmark with and without the feature:
+//! `cargo bench --bench json` and `cargo bench --bench json --features simd-json`.
+//! The `serde_json` baseline is measured in both runs.
+
+use chat_service::domain::channel::models::ChannelId;
+use chat_service::domain::message::events::MessageSentEvent;
+use chat_service::fixtures;
+use chat_service::fixtures::MessageFixture;
+use chat_service::inbound::websocket::messages::ClientMessage;
+use chat_service::json;
+use chat_service::outbound::events::messages::ChatEventMessage;
+use chat_service::outbound::events::messages::MessageSentMessage;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BatchSize;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::Throughput;
+use uuid::Uuid;
+
+/// Kafka record of a sent message, as the consumer receives it.
+fn message_sent_payload(content_length: usize) -> Vec<u8> {
+    let message = MessageFixture::in_channel(ChannelId(Uuid::from_u128(1)))
+        .from_user(fixtures::user_id(1))
+        .with_content("x".repeat(content_length))
+        .at(fixtures::epoch())
+        .build();
+    let event =
+        ChatEventMessage::MessageSent(MessageSentMessage::from(&MessageSentEvent::new(&message)));
+    serde_json::to_vec(&event).unwrap()
+}
+
+fn bench_kafka_event_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("kafka_event_decode");
+    for content_length in [16, 256, 4000] {
+        let payload = message_sent_payload(content_length);
+        group.throughput(Throughput::Bytes(payload.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("serde_json", content_length),
+            &payload,
+            |b, payload| b.iter(|| serde_json::from_slice::<ChatEventMessage>(payload).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("configured", content_length),
+            &payload,
+            |b, payload| b.iter(|| json::from_slice::<ChatEventMessage>(payload).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+/// A busy channel: one consumer instance decoding a burst of records.
+fn bench_kafka_burst_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("kafka_burst_decode");
+    for records in [100usize, 1000] {
+        let payloads: Vec<Vec<u8>> = (0..records).map(|_| message_sent_payload(256)).collect();
+        group.throughput(Throughput::Elements(records as u64));
+        group.bench_with_input(
+            BenchmarkId::new("serde_json", records),
+            &payloads,
+            |b, payloads| {
+                b.iter(|| {
+                    for payload in payloads {
+                        serde_json::from_slice::<ChatEventMessage>(payload).unwrap();
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("configured", records),
+            &payloads,
+            |b, payloads| {
+                b.iter(|| {
+                    for payload in payloads {
+                        json::from_slice::<ChatEventMessage>(payload).unwrap();
+                    }
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_client_message_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("client_message_decode");
+    for content_length in [16, 256, 4000] {
+        let text = serde_json::json!({
+            "type": "send_message",
+            "content": "x".repeat(content_length),
+            "id": Uuid::now_v7(),
+        })
+        .to_string();
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("serde_json", content_length),
+            &text,
+            |b, text| {
+                b.iter_batched(
+                    || text.clone(),
+                    |text| serde_json::from_str::<ClientMessage>(&text).unwrap(),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("configured", content_length),
+            &text,
+            |b, text| {
+                // The handler owns the received text, so no copy is needed
+                b.iter_batched(
+                    || text.clone(),
+                    |text| json::from_string::<ClientMessage>(text).unwrap(),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_kafka_event_decode,
+    bench_kafka_burst_decode,
+    bench_client_message_decode
+);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+use chat_service::domain::channel::models::ChannelId;
+use chat_service::outbound::events::topic::TopicSharder;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use uuid::Uuid;
+
+fn bench_topic_sharder(c: &mut Criterion) {
+    let channel_ids: Vec<ChannelId> = (0..1024u128)
+        .map(|n| ChannelId(Uuid::from_u128(n)))
+        .collect();
+
+    let mut group = c.benchmark_group("topic_sharder");
+    for num_shards in [16, 256] {
+        let sharder = TopicSharder::new(num_shards, "chat.messages").unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("get_shard_for_channel", num_shards),
+            &sharder,
+            |b, sharder| {
+                let mut ids = channel_ids.iter().cycle();
+                b.iter(|| sharder.get_shard_for_channel(*ids.next().unwrap()))
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_topic_sharder);
+criterion_main!(benches);
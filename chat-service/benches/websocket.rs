@@ -0,0 +1,89 @@
+use axum::extract::ws::Message as WsMessage;
+use chat_service::domain::channel::models::ChannelId;
+use chat_service::domain::message::models::MessageId;
+use chat_service::fixtures;
+use chat_service::inbound::websocket::messages::ServerMessage;
+use chat_service::inbound::websocket::messages::WsMessageId;
+use chat_service::inbound::websocket::messages::WsUserId;
+use chat_service::inbound::websocket::registry::ConnectionRegistry;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::Throughput;
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+fn new_message(content_length: usize) -> ServerMessage {
+    ServerMessage::NewMessage {
+        id: WsMessageId::from(MessageId::new_time_based()),
+        user_id: WsUserId::from(fixtures::user_id(1)),
+        display_name: Some("Alice".to_string()),
+        avatar_url: None,
+        content: "x".repeat(content_length),
+        timestamp: fixtures::epoch(),
+        language: Some("en".to_string()),
+    }
+}
+
+fn bench_server_message_serialization(c: &mut Criterion) {
+    let mut group = c.benchmark_group("server_message_serialize");
+    for content_length in [16, 256, 4000] {
+        let message = new_message(content_length);
+        group.throughput(Throughput::Bytes(content_length as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(content_length),
+            &message,
+            |b, message| b.iter(|| serde_json::to_string(message).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+fn bench_broadcast_fan_out(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let payload = serde_json::to_string(&new_message(256)).unwrap();
+
+    let mut group = c.benchmark_group("registry_broadcast");
+    for subscribers in [1usize, 10, 100, 1000] {
+        let registry = ConnectionRegistry::new();
+        let channel_id = ChannelId(Uuid::from_u128(1));
+
+        // Drain every subscriber so unbounded queues do not grow across iterations
+        runtime.block_on(async {
+            for n in 0..subscribers {
+                let (sender, mut receiver) = mpsc::unbounded_channel();
+                registry
+                    .add_connection(
+                        Uuid::new_v4(),
+                        fixtures::user_id(n as u128),
+                        channel_id,
+                        sender,
+                    )
+                    .await
+                    .unwrap();
+                tokio::spawn(async move { while receiver.recv().await.is_some() {} });
+            }
+        });
+
+        group.throughput(Throughput::Elements(subscribers as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(subscribers),
+            &registry,
+            |b, registry| {
+                b.to_async(&runtime).iter(|| {
+                    registry.broadcast_to_channel(channel_id, WsMessage::Text(payload.clone()))
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_server_message_serialization,
+    bench_broadcast_fan_out
+);
+criterion_main!(benches);
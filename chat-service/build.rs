@@ -1,7 +1,12 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    buildinfo::emit_build_info();
+
     tonic_build::configure()
         .build_server(false)
         .build_client(true)
-        .compile(&["../proto/user.proto"], &["../proto"])?;
+        .compile(
+            &["../proto/user.proto", "../proto/user_events.proto"],
+            &["../proto"],
+        )?;
     Ok(())
 }
@@ -0,0 +1,14 @@
+-- Per-channel activity rollup, maintained as messages are sent
+CREATE TABLE IF NOT EXISTS channel_activity (
+    channel_id UUID PRIMARY KEY REFERENCES channels(id) ON DELETE CASCADE,
+    message_count BIGINT NOT NULL DEFAULT 0,
+    last_activity_at TIMESTAMPTZ NOT NULL
+);
+
+-- Distinct users who have posted in a channel
+CREATE TABLE IF NOT EXISTS channel_participants (
+    channel_id UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL,
+    first_seen_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (channel_id, user_id)
+);
@@ -0,0 +1,2 @@
+-- Public channels whose messages can be read without authentication through the embed API
+ALTER TABLE channels ADD COLUMN embeddable BOOLEAN NOT NULL DEFAULT FALSE;
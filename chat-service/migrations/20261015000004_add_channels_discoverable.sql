@@ -0,0 +1,4 @@
+-- Public channels listed in the unauthenticated public channel directory
+ALTER TABLE channels ADD COLUMN discoverable BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX idx_channels_discoverable ON channels(name) WHERE discoverable;
@@ -0,0 +1,7 @@
+-- Per-channel message count by detected content language
+CREATE TABLE IF NOT EXISTS channel_languages (
+    channel_id UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    language VARCHAR(3) NOT NULL,
+    message_count BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (channel_id, language)
+);
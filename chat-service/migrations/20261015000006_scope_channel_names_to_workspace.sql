@@ -0,0 +1,16 @@
+-- Scope channel names to a workspace instead of the whole deployment.
+-- Existing channels belong to the default (nil UUID) workspace.
+ALTER TABLE channels
+    ADD COLUMN workspace_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000';
+
+ALTER TABLE channels DROP CONSTRAINT channels_name_key;
+
+-- Public channel names are unique within the workspace
+CREATE UNIQUE INDEX channels_public_name_key
+    ON channels (workspace_id, name)
+    WHERE channel_type = 'public';
+
+-- Private channel names are unique per creator, so they disclose nothing to other users
+CREATE UNIQUE INDEX channels_private_name_key
+    ON channels (workspace_id, created_by, name)
+    WHERE channel_type = 'private';
@@ -0,0 +1,2 @@
+-- Channels where only the owner and moderators may post
+ALTER TABLE channels ADD COLUMN announcement_only BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,2 @@
+-- Set while the user is deactivated in user-service, see UserDeactivated/UserReactivated
+ALTER TABLE user_replica ADD COLUMN IF NOT EXISTS deactivated_at TIMESTAMPTZ;
@@ -0,0 +1,3 @@
+-- Profile shown instead of the bare username, copied from user-service
+ALTER TABLE user_replica ADD COLUMN IF NOT EXISTS display_name VARCHAR(64);
+ALTER TABLE user_replica ADD COLUMN IF NOT EXISTS avatar_url TEXT;
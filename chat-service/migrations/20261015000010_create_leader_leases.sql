@@ -0,0 +1,9 @@
+-- Leases of elected leaders, written by the leader of each election.
+-- Leadership is decided by an advisory lock; a lease only reports it.
+CREATE TABLE IF NOT EXISTS leader_leases (
+    name VARCHAR(255) PRIMARY KEY,
+    instance_id UUID NOT NULL,
+    elected_at TIMESTAMPTZ NOT NULL,
+    renewed_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
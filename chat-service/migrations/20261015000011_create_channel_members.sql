@@ -0,0 +1,14 @@
+-- Default public channels, joined automatically by new users of the workspace
+ALTER TABLE channels ADD COLUMN auto_join BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX idx_channels_auto_join ON channels(workspace_id) WHERE auto_join;
+
+-- Users who joined a channel, listed among the channels of the user
+CREATE TABLE IF NOT EXISTS channel_members (
+    channel_id UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL,
+    joined_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (channel_id, user_id)
+);
+
+CREATE INDEX idx_channel_members_user_id ON channel_members(user_id);
@@ -0,0 +1,26 @@
+-- RSS and Atom feeds whose entries are posted to channels
+CREATE TABLE IF NOT EXISTS channel_feeds (
+    id UUID PRIMARY KEY,
+    channel_id UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    created_by UUID NOT NULL,
+    max_posts_per_hour INTEGER NOT NULL,
+    last_polled_at TIMESTAMPTZ,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT channel_feeds_channel_id_url_key UNIQUE (channel_id, url)
+);
+
+CREATE INDEX idx_channel_feeds_last_polled_at ON channel_feeds(last_polled_at NULLS FIRST);
+
+-- Entries of a feed already posted, or skipped when the feed was registered
+CREATE TABLE IF NOT EXISTS channel_feed_entries (
+    feed_id UUID NOT NULL REFERENCES channel_feeds(id) ON DELETE CASCADE,
+    entry_key TEXT NOT NULL,
+    seen_at TIMESTAMPTZ NOT NULL,
+    posted_at TIMESTAMPTZ,
+    PRIMARY KEY (feed_id, entry_key)
+);
+
+CREATE INDEX idx_channel_feed_entries_posted_at ON channel_feed_entries(feed_id, posted_at)
+    WHERE posted_at IS NOT NULL;
@@ -0,0 +1,14 @@
+-- Reminders posted to channels by the reminder bot, deleted once posted
+CREATE TABLE IF NOT EXISTS channel_reminders (
+    id UUID PRIMARY KEY,
+    channel_id UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    created_by UUID NOT NULL,
+    content TEXT NOT NULL,
+    due_at TIMESTAMPTZ NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_channel_reminders_channel_id ON channel_reminders(channel_id, due_at);
+CREATE INDEX idx_channel_reminders_due_at ON channel_reminders(due_at);
@@ -0,0 +1,23 @@
+-- Polls asked in channels, closed by the poll bot once they expire
+CREATE TABLE IF NOT EXISTS channel_polls (
+    id UUID PRIMARY KEY,
+    channel_id UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    created_by UUID NOT NULL,
+    question TEXT NOT NULL,
+    options TEXT[] NOT NULL,
+    closes_at TIMESTAMPTZ NOT NULL,
+    closed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_channel_polls_channel_id ON channel_polls(channel_id);
+CREATE INDEX idx_channel_polls_open ON channel_polls(closes_at) WHERE closed_at IS NULL;
+
+-- One vote per user and poll
+CREATE TABLE IF NOT EXISTS channel_poll_votes (
+    poll_id UUID NOT NULL REFERENCES channel_polls(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL,
+    option_index INTEGER NOT NULL,
+    voted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (poll_id, user_id)
+);
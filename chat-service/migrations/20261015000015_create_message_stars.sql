@@ -0,0 +1,25 @@
+-- Stars users give to messages, one per user and message
+CREATE TABLE IF NOT EXISTS message_stars (
+    channel_id UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    message_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    starred_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (message_id, user_id)
+);
+
+CREATE INDEX idx_message_stars_channel_id ON message_stars(channel_id);
+
+-- Net stars each message received per UTC day, fed by the star events
+CREATE TABLE IF NOT EXISTS message_star_rollup (
+    channel_id UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    day DATE NOT NULL,
+    message_id UUID NOT NULL,
+    stars BIGINT NOT NULL,
+    PRIMARY KEY (channel_id, day, message_id)
+);
+
+-- Star events already counted into the rollup, so redelivered events count once
+CREATE TABLE IF NOT EXISTS message_star_rollup_events (
+    event_id TEXT PRIMARY KEY,
+    counted_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,2 @@
+-- Background jobs are tracked by user-service only; chat-service never created any
+DROP TABLE IF EXISTS jobs;
@@ -1,47 +1,101 @@
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
+use anyhow::anyhow;
 use anyhow::Error;
 use auth::Authenticator;
+use auth::PersonalTokenVerifier;
+use auth::PostgresRevocationStore;
 use chat_service::config::Config;
 use chat_service::domain::channel::service::ChannelService;
+use chat_service::domain::crash::ports::CrashReporter;
+use chat_service::domain::email::models::EmailGatewaySettings;
+use chat_service::domain::email::service::EmailGatewayService;
+use chat_service::domain::embed::service::EmbedService;
+use chat_service::domain::feed::models::FeedSettings;
+use chat_service::domain::feed::service::FeedService;
+use chat_service::domain::gateway::models::Region;
+use chat_service::domain::gateway::service::GatewayService;
+use chat_service::domain::leader::models::LeaderElectionSettings;
+use chat_service::domain::leader::service::LeaderElection;
+use chat_service::domain::lock::models::InstanceId;
+use chat_service::domain::message::models::MessageIdVersion;
 use chat_service::domain::message::service::MessageService;
+use chat_service::domain::poll::models::PollSettings;
+use chat_service::domain::poll::service::PollService;
+use chat_service::domain::reminder::models::ReminderSettings;
+use chat_service::domain::reminder::service::ReminderService;
+use chat_service::domain::star::service::StarService;
+use chat_service::domain::user::models::UserId;
 use chat_service::inbound::http::create_router;
+use chat_service::inbound::http::router::EmailGateway;
+use chat_service::inbound::metrics::install_recorder;
+use chat_service::inbound::panic::install_panic_hook;
+use chat_service::inbound::rate_limit::RateLimiter;
+use chat_service::inbound::websocket::registry::ConnectionLimits;
 use chat_service::inbound::websocket::registry::ConnectionRegistry;
+use chat_service::outbound::backup::FilesystemObjectStorage;
+use chat_service::outbound::crash::sentry::SentryCrashReporter;
+use chat_service::outbound::events::channel_publisher::KafkaChannelEventPublisher;
 use chat_service::outbound::events::consumer::KafkaEventConsumer;
 use chat_service::outbound::events::message_publisher::KafkaMessageEventPublisher;
+use chat_service::outbound::events::poll_publisher::KafkaPollEventPublisher;
 use chat_service::outbound::events::producer::KafkaEventProducer;
+use chat_service::outbound::events::replicator::KafkaEventReplicator;
+use chat_service::outbound::events::star_consumer::StarRollupConsumer;
+use chat_service::outbound::events::star_publisher::KafkaStarEventPublisher;
 use chat_service::outbound::events::user_consumer::UserEventsConsumer;
+use chat_service::outbound::feed::HttpFeedFetcher;
 use chat_service::outbound::grpc::user::GrpcUserServiceClient;
+use chat_service::outbound::language::stopwords::StopwordLanguageDetector;
+use chat_service::outbound::lock::postgres::PostgresAdvisoryLock;
 use chat_service::outbound::repositories::channel::PostgresChannelRepository;
+use chat_service::outbound::repositories::feed::PostgresFeedRepository;
+use chat_service::outbound::repositories::leader_lease::PostgresLeaderLeaseRepository;
 use chat_service::outbound::repositories::message::CassandraMessageRepository;
+use chat_service::outbound::repositories::poll::PostgresPollRepository;
+use chat_service::outbound::repositories::reminder::PostgresReminderRepository;
+use chat_service::outbound::repositories::star::PostgresStarRepository;
 use chat_service::outbound::repositories::user_replica::PostgresUserReplicaRepository;
+use chat_service::supervisor::RestartPolicy;
+use chat_service::supervisor::TaskSupervisor;
 use sqlx::postgres::PgPoolOptions;
-use tracing_subscriber::layer::SubscriberExt;
-use tracing_subscriber::util::SubscriberInitExt;
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "chat_service=debug,tower_http=debug".into()),
-        )
-        .with(tracing_subscriber::fmt::layer())
-        .init();
+    let config = Config::load()?;
+    logging::init("chat-service", &config.logging)?;
+    let instance_id = InstanceId::new();
+    let build_info = Arc::new(
+        buildinfo::build_info!("chat-service", &config.redacted()).with_instance_id(instance_id),
+    );
+    build_info.log_startup();
 
+    let crash_reporter = match &config.crash_reporting.sentry_dsn {
+        Some(dsn) => Some(Arc::new(SentryCrashReporter::new(
+            dsn,
+            config.crash_reporting.environment.clone(),
+            build_info.version,
+        )?) as Arc<dyn CrashReporter>),
+        None => None,
+    };
     tracing::info!(
-        service = "chat-service",
-        version = env!("CARGO_PKG_VERSION"),
-        "Service starting"
+        sentry = crash_reporter.is_some(),
+        "Crash reporting configured"
     );
-
-    let config = Config::load()?;
+    install_panic_hook(crash_reporter);
+    let metrics_handle = install_recorder()?;
 
     tracing::info!(
-        database_url = %config.database.url,
+        database_url = %config.redacted().database.url,
         cassandra_nodes = ?config.cassandra.nodes,
         cassandra_keyspace = %config.cassandra.keyspace,
         http_port = config.server.http_port,
+        read_only = config.server.read_only,
+        embed_requests_per_window = config.embed.requests_per_window,
+        max_connections = ?config.websocket.max_connections,
+        max_connections_per_channel = ?config.websocket.max_connections_per_channel,
         user_service_grpc_url = %config.user_service.grpc_url,
         kafka_brokers = %config.kafka.brokers,
         kafka_group_id = %config.kafka.group_id,
@@ -51,10 +105,12 @@ async fn main() -> Result<(), Error> {
 
     let pg_pool = PgPoolOptions::new()
         .max_connections(5)
-        .connect(&config.database.url)
+        .connect_with(config.database.connect_options()?)
         .await?;
     tracing::info!(
         max_connections = 5,
+        slow_query_threshold_ms = config.database.slow_query_threshold_ms,
+        statement_timeout_ms = config.database.statement_timeout_ms,
         database = "postgresql",
         "Database connection pool created"
     );
@@ -62,37 +118,249 @@ async fn main() -> Result<(), Error> {
     sqlx::migrate!("./migrations").run(&pg_pool).await?;
     tracing::info!(database = "postgresql", "Database migrations completed");
 
-    let authenticator = Arc::new(Authenticator::new(config.jwt.secret.as_bytes()));
-    let connection_registry = Arc::new(ConnectionRegistry::new());
-    let user_proxy = Arc::new(GrpcUserServiceClient::new(&config.user_service.grpc_url).await?);
+    // Tokens revoked by user-service, on logout or password change, are read from its database
+    let revocation_pool = PgPoolOptions::new()
+        .max_connections(5)
+        .connect(&config.jwt.revocation_database_url)
+        .await?;
+    let authenticator = Arc::new(
+        Authenticator::new(config.jwt.secret.as_bytes())
+            .with_revocation_store(Arc::new(PostgresRevocationStore::new(revocation_pool)))
+            .with_validation_cache(
+                config.jwt.validation_cache_size,
+                chrono::Duration::seconds(config.jwt.validation_cache_ttl_secs),
+            ),
+    );
+    let connection_registry = Arc::new(ConnectionRegistry::with_limits(ConnectionLimits {
+        max_connections: config.websocket.max_connections,
+        max_connections_per_channel: config.websocket.max_connections_per_channel,
+        retry_after_secs: config.websocket.retry_after_secs,
+        idle_timeout_secs: config.websocket.idle_timeout_secs,
+    }));
+    let user_proxy = Arc::new(
+        GrpcUserServiceClient::new(&config.user_service.grpc_url, Arc::clone(&authenticator))
+            .await?,
+    );
+    let personal_token_verifier: Arc<dyn PersonalTokenVerifier> = user_proxy.clone();
 
     let channel_repository = Arc::new(PostgresChannelRepository::new(pg_pool.clone()));
     let message_repository = Arc::new(CassandraMessageRepository::new(&config).await?);
-    let user_repository = Arc::new(PostgresUserReplicaRepository::new(pg_pool));
+    let user_repository = Arc::new(PostgresUserReplicaRepository::new(pg_pool.clone()));
+    let feed_repository = Arc::new(PostgresFeedRepository::new(pg_pool.clone()));
+    let reminder_repository = Arc::new(PostgresReminderRepository::new(pg_pool.clone()));
+    let poll_repository = Arc::new(PostgresPollRepository::new(pg_pool.clone()));
+    let star_repository = Arc::new(PostgresStarRepository::new(pg_pool.clone()));
+    let leader_election = Arc::new(LeaderElection::new(
+        "user_events_coordinator",
+        Arc::new(PostgresAdvisoryLock::new(pg_pool.clone(), instance_id)),
+        Arc::new(PostgresLeaderLeaseRepository::new(pg_pool)),
+        instance_id,
+        LeaderElectionSettings {
+            renew_interval: Duration::from_secs(config.leader_election.renew_interval_secs),
+            lease_ttl: Duration::from_secs(config.leader_election.lease_ttl_secs),
+            retry_interval: Duration::from_secs(config.leader_election.retry_interval_secs),
+        },
+    ));
+    let embed_service = Arc::new(EmbedService::new(
+        Arc::clone(&channel_repository),
+        Arc::clone(&message_repository),
+        Arc::clone(&user_repository),
+        config.embed.max_messages,
+    ));
+    let embed_rate_limiter = Arc::new(RateLimiter::new(
+        config.embed.requests_per_window,
+        Duration::from_secs(config.embed.window_secs),
+    ));
 
     let event_producer = Arc::new(KafkaEventProducer::new(&config)?);
-    let message_event_consumer =
-        KafkaEventConsumer::new(&config, Arc::clone(&connection_registry))?;
-    let user_events_consumer = UserEventsConsumer::new(&config, user_repository)?;
-    let message_event_publisher =
-        Arc::new(KafkaMessageEventPublisher::new(Arc::clone(&event_producer)));
-
-    let channel_service = Arc::new(ChannelService::new(Arc::clone(&channel_repository)));
-
-    let message_service = Arc::new(MessageService::new(
-        message_repository,
-        channel_repository,
-        user_proxy,
-        message_event_publisher,
+    let message_event_publisher = Arc::new(
+        KafkaMessageEventPublisher::new(Arc::clone(&event_producer))
+            .with_user_replica(user_repository.clone()),
+    );
+    let channel_event_publisher =
+        Arc::new(KafkaChannelEventPublisher::new(Arc::clone(&event_producer)));
+
+    let channel_service = Arc::new(ChannelService::new(
+        Arc::clone(&channel_repository),
+        channel_event_publisher,
     ));
 
+    let gateway_service = match &config.gateway {
+        Some(gateway) => {
+            let regions = gateway
+                .regions
+                .iter()
+                .map(|region| Region {
+                    name: region.name.clone(),
+                    websocket_url: region.websocket_url.clone(),
+                    countries: region.countries.clone(),
+                })
+                .collect();
+            tracing::info!(
+                default_region = %gateway.default_region,
+                regions = gateway.regions.len(),
+                "Gateway region selection enabled"
+            );
+            Some(Arc::new(GatewayService::new(
+                regions,
+                &gateway.default_region,
+            )?))
+        }
+        None => None,
+    };
+
+    let message_service = Arc::new(
+        MessageService::new(
+            Arc::clone(&message_repository),
+            channel_repository,
+            user_proxy,
+            message_event_publisher,
+            Arc::new(StopwordLanguageDetector::new()),
+        )
+        .with_tombstones(config.history.include_tombstones)
+        .with_id_version(if config.messages.uuid_v7_ids {
+            MessageIdVersion::V7
+        } else {
+            MessageIdVersion::TimeUuid
+        }),
+    );
+
+    let email_gateway = match &config.email_gateway {
+        Some(email_gateway) => {
+            let poster_id = UserId::from_string(&email_gateway.poster_user_id)
+                .map_err(|e| anyhow!("Invalid email gateway poster_user_id: {}", e))?;
+            tracing::info!(
+                domain = %email_gateway.domain,
+                storage_dir = %email_gateway.storage_dir,
+                "Email gateway enabled"
+            );
+            Some(EmailGateway {
+                service: Arc::new(EmailGatewayService::new(
+                    Arc::clone(&message_service),
+                    Arc::new(FilesystemObjectStorage::new(&email_gateway.storage_dir)),
+                    EmailGatewaySettings {
+                        domain: email_gateway.domain.clone(),
+                        address_secret: email_gateway.address_secret.clone(),
+                        poster_id,
+                        max_attachment_bytes: email_gateway.max_attachment_bytes,
+                    },
+                )),
+                webhook_secret: email_gateway.webhook_secret.clone(),
+                // Base64 grows attachments by a third, leave room for a few at the limit
+                body_limit: email_gateway.max_attachment_bytes.saturating_mul(4),
+            })
+        }
+        None => None,
+    };
+
+    let feed_service = match &config.feeds {
+        Some(feeds) => {
+            let bot_id = UserId::from_string(&feeds.bot_user_id)
+                .map_err(|e| anyhow!("Invalid feeds bot_user_id: {}", e))?;
+            tracing::info!(
+                poll_interval_secs = feeds.poll_interval_secs,
+                max_feeds_per_channel = feeds.max_feeds_per_channel,
+                "Feed bot enabled"
+            );
+            Some(Arc::new(FeedService::new(
+                feed_repository,
+                Arc::new(HttpFeedFetcher::new(
+                    Duration::from_secs(feeds.fetch_timeout_secs),
+                    feeds.max_feed_bytes,
+                )?),
+                Arc::clone(&message_service),
+                FeedSettings {
+                    bot_id,
+                    poll_interval: Duration::from_secs(feeds.poll_interval_secs),
+                    default_max_posts_per_hour: feeds.default_max_posts_per_hour,
+                    max_posts_per_hour: feeds.max_posts_per_hour,
+                    max_feeds_per_channel: feeds.max_feeds_per_channel,
+                },
+            )))
+        }
+        None => None,
+    };
+
+    let reminder_service = match &config.reminders {
+        Some(reminders) => {
+            let bot_id = UserId::from_string(&reminders.bot_user_id)
+                .map_err(|e| anyhow!("Invalid reminders bot_user_id: {}", e))?;
+            tracing::info!(
+                poll_interval_secs = reminders.poll_interval_secs,
+                max_pending_per_channel = reminders.max_pending_per_channel,
+                "Reminder bot enabled"
+            );
+            Some(Arc::new(ReminderService::new(
+                reminder_repository,
+                Arc::clone(&message_service),
+                ReminderSettings {
+                    bot_id,
+                    poll_interval: Duration::from_secs(reminders.poll_interval_secs),
+                    max_delay: chrono::Duration::days(reminders.max_delay_days),
+                    max_pending_per_channel: reminders.max_pending_per_channel,
+                },
+            )))
+        }
+        None => None,
+    };
+
+    let poll_service = match &config.polls {
+        Some(polls) => {
+            let bot_id = UserId::from_string(&polls.bot_user_id)
+                .map_err(|e| anyhow!("Invalid polls bot_user_id: {}", e))?;
+            tracing::info!(
+                poll_interval_secs = polls.poll_interval_secs,
+                max_options = polls.max_options,
+                "Polls enabled"
+            );
+            Some(Arc::new(PollService::new(
+                poll_repository,
+                Arc::new(KafkaPollEventPublisher::new(Arc::clone(&event_producer))),
+                Arc::clone(&message_service),
+                PollSettings {
+                    bot_id,
+                    poll_interval: Duration::from_secs(polls.poll_interval_secs),
+                    max_options: polls.max_options,
+                    max_duration: chrono::Duration::days(polls.max_duration_days),
+                },
+            )))
+        }
+        None => None,
+    };
+
+    let star_service = match &config.stars {
+        Some(stars) => {
+            tracing::info!(group_id = %stars.group_id, "Message stars enabled");
+            Some(Arc::new(StarService::new(
+                star_repository,
+                Arc::new(KafkaStarEventPublisher::new(Arc::clone(&event_producer))),
+                message_repository,
+            )))
+        }
+        None => None,
+    };
+
+    // Background tasks are rebuilt from scratch on every restart
+    let supervisor = Arc::new(TaskSupervisor::new(RestartPolicy::from(&config.supervisor)));
+    let task_config = Arc::new(config.clone());
+
     tracing::info!(
         consumer = "message_events",
         topics = "chat.messages.*",
         "Starting Kafka message event consumer"
     );
-    tokio::spawn(async move {
-        message_event_consumer.start_consuming().await;
+    let (consumer_config, consumer_registry, consumer_user_replica) = (
+        Arc::clone(&task_config),
+        Arc::clone(&connection_registry),
+        Arc::clone(&user_repository),
+    );
+    supervisor.supervise("message_events_consumer", move || {
+        let consumer = KafkaEventConsumer::new(&consumer_config, Arc::clone(&consumer_registry))
+            .map(|consumer| consumer.with_user_replica(consumer_user_replica.clone()));
+        async move {
+            consumer?.start_consuming().await;
+            Ok::<(), Error>(())
+        }
     });
 
     tracing::info!(
@@ -100,10 +368,129 @@ async fn main() -> Result<(), Error> {
         topic = %config.kafka.user_events.topic,
         "Starting Kafka user event consumer"
     );
-    tokio::spawn(async move {
-        user_events_consumer.start_consuming().await;
+    let (consumer_config, consumer_channel_service) =
+        (Arc::clone(&task_config), Arc::clone(&channel_service));
+    supervisor.supervise("user_events_consumer", move || {
+        let consumer = UserEventsConsumer::new(
+            &consumer_config,
+            Arc::clone(&user_repository),
+            Arc::clone(&consumer_channel_service),
+        );
+        async move {
+            consumer?.start_consuming().await;
+            Ok::<(), Error>(())
+        }
     });
 
+    if let Some(replication) = &config.kafka.replication {
+        tracing::info!(
+            region = %replication.region,
+            remote_brokers = %replication.remote_brokers,
+            "Starting Kafka event replicator"
+        );
+        let (replicator_config, replication) = (Arc::clone(&task_config), replication.clone());
+        supervisor.supervise("event_replicator", move || {
+            let replicator = KafkaEventReplicator::new(&replicator_config, &replication);
+            async move {
+                replicator?.start_replicating().await;
+                Ok::<(), Error>(())
+            }
+        });
+    }
+
+    // Star changes are counted once per group, so every instance may run the consumer
+    if let (Some(stars), Some(star_service)) = (&config.stars, &star_service) {
+        tracing::info!(
+            consumer = "star_rollup",
+            group_id = %stars.group_id,
+            "Starting Kafka star rollup consumer"
+        );
+        let (consumer_config, stars, consumer_star_service) = (
+            Arc::clone(&task_config),
+            stars.clone(),
+            Arc::clone(star_service),
+        );
+        supervisor.supervise("star_rollup_consumer", move || {
+            let consumer = StarRollupConsumer::new(
+                &consumer_config,
+                &stars,
+                Arc::clone(&consumer_star_service),
+            );
+            async move {
+                consumer?.start_consuming().await;
+                Ok::<(), Error>(())
+            }
+        });
+    }
+
+    // Coordinator tasks of the user-events consumer group run on the leader only,
+    // through `LeaderElection::while_leader`
+    tracing::info!(
+        election = leader_election.name(),
+        instance_id = %instance_id,
+        "Campaigning for coordinator leadership"
+    );
+    let campaign = Arc::clone(&leader_election);
+    supervisor.supervise("leader_election", move || {
+        let campaign = Arc::clone(&campaign);
+        async move { campaign.run().await }
+    });
+
+    // Feeds are polled by the leader only, so entries are not posted twice
+    if let Some(feed_service) = &feed_service {
+        tracing::info!("Starting feed poller");
+        let (poller, election) = (Arc::clone(feed_service), Arc::clone(&leader_election));
+        supervisor.supervise("feed_poller", move || {
+            let (poller, election) = (Arc::clone(&poller), Arc::clone(&election));
+            async move {
+                election
+                    .while_leader(|| async {
+                        poller.run().await;
+                        Ok(())
+                    })
+                    .await
+            }
+        });
+    }
+
+    // Reminders are posted by the leader only, so they are not posted twice
+    if let Some(reminder_service) = &reminder_service {
+        tracing::info!("Starting reminder scheduler");
+        let (scheduler, election) = (Arc::clone(reminder_service), Arc::clone(&leader_election));
+        supervisor.supervise("reminder_scheduler", move || {
+            let (scheduler, election) = (Arc::clone(&scheduler), Arc::clone(&election));
+            async move {
+                election
+                    .while_leader(|| async {
+                        scheduler.run().await;
+                        Ok(())
+                    })
+                    .await
+            }
+        });
+    }
+
+    // Polls are closed by the leader only, so their results are posted once
+    if let Some(poll_service) = &poll_service {
+        tracing::info!("Starting poll closer");
+        let (closer, election) = (Arc::clone(poll_service), Arc::clone(&leader_election));
+        supervisor.supervise("poll_closer", move || {
+            let (closer, election) = (Arc::clone(&closer), Arc::clone(&election));
+            async move {
+                election
+                    .while_leader(|| async {
+                        closer.run().await;
+                        Ok(())
+                    })
+                    .await
+            }
+        });
+    }
+
+    if config.server.read_only {
+        tracing::warn!("Running in read-only mode, writes will be rejected");
+    }
+
     let http_address = format!("0.0.0.0:{}", config.server.http_port);
     let listener = tokio::net::TcpListener::bind(&http_address).await?;
     tracing::info!(
@@ -116,11 +503,41 @@ async fn main() -> Result<(), Error> {
     let application = create_router(
         channel_service,
         message_service,
+        embed_service,
+        embed_rate_limiter,
         connection_registry,
         authenticator,
+        Some(personal_token_verifier),
+        build_info,
+        metrics_handle,
+        gateway_service,
+        email_gateway,
+        feed_service,
+        reminder_service,
+        poll_service,
+        star_service,
+        Arc::clone(&supervisor),
+        leader_election,
+        config.server.read_only,
+        config.server.trust_forwarded_for,
+        config.limits.json_body_bytes,
+        config.api.v1_deprecation(),
     );
 
-    axum::serve(listener, application).await?;
+    // A task failing over and over stops the server, so the process exits
+    // with an error and gets replaced
+    let escalation = Arc::clone(&supervisor);
+    axum::serve(
+        listener,
+        application.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(async move {
+        escalation.escalated().await;
+    })
+    .await?;
 
-    Ok(())
+    match supervisor.escalation() {
+        Some(reason) => Err(anyhow!(reason)),
+        None => Ok(()),
+    }
 }
@@ -1,15 +1,25 @@
 use std::env;
+use std::time::Duration;
 
+use buildinfo::redact_url_password;
+use buildinfo::REDACTED;
+use chrono::DateTime;
+use chrono::Utc;
 use config::Config as ConfigBuilder;
 use config::ConfigError;
 use config::Environment;
 use config::File;
+use envelope::Deprecation;
+use logging::LoggingConfig;
 use serde::Deserialize;
+use serde::Serialize;
+use sqlx::postgres::PgConnectOptions;
+use sqlx::ConnectOptions;
 
 /// Application configuration for chat-service.
 ///
 /// Loaded from configuration files with environment variable overrides.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Config {
     pub database: DatabaseConfig,
     pub cassandra: CassandraConfig,
@@ -17,29 +27,111 @@ pub struct Config {
     pub user_service: UserServiceConfig,
     pub kafka: KafkaConfig,
     pub jwt: JwtConfig,
+    #[serde(default)]
+    pub backup: BackupConfig,
+    #[serde(default)]
+    pub gateway: Option<GatewayConfig>,
+    #[serde(default)]
+    pub email_gateway: Option<EmailGatewayConfig>,
+    #[serde(default)]
+    pub feeds: Option<FeedsConfig>,
+    #[serde(default)]
+    pub reminders: Option<RemindersConfig>,
+    #[serde(default)]
+    pub polls: Option<PollsConfig>,
+    #[serde(default)]
+    pub stars: Option<StarsConfig>,
+    #[serde(default)]
+    pub websocket: WebsocketConfig,
+    #[serde(default)]
+    pub embed: EmbedConfig,
+    #[serde(default)]
+    pub history: HistoryConfig,
+    #[serde(default)]
+    pub messages: MessagesConfig,
+    #[serde(default)]
+    pub limits: LimitsConfig,
+    #[serde(default)]
+    pub api: ApiConfig,
+    #[serde(default)]
+    pub supervisor: SupervisorConfig,
+    #[serde(default)]
+    pub leader_election: LeaderElectionConfig,
+    #[serde(default)]
+    pub crash_reporting: CrashReportingConfig,
+    #[serde(default)]
+    pub logging: LoggingConfig,
 }
 
 /// PostgreSQL database configuration.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DatabaseConfig {
     pub url: String,
+    /// Statements running longer, in milliseconds, are logged as slow
+    #[serde(default = "default_slow_query_threshold_ms")]
+    pub slow_query_threshold_ms: u64,
+    /// Milliseconds Postgres lets a statement run before cancelling it, 0 for no limit
+    #[serde(default = "default_statement_timeout_ms")]
+    pub statement_timeout_ms: u64,
+}
+
+impl DatabaseConfig {
+    /// Connection options applying the statement timeout and slow query log.
+    ///
+    /// Slow statements are logged as a warning with their SQL text; bound
+    /// parameters are never part of it.
+    ///
+    /// # Errors
+    /// Returns an error if the URL cannot be parsed
+    pub fn connect_options(&self) -> Result<PgConnectOptions, sqlx::Error> {
+        Ok(self
+            .url
+            .parse::<PgConnectOptions>()?
+            .options([("statement_timeout", self.statement_timeout_ms)])
+            .log_slow_statements(
+                log::LevelFilter::Warn,
+                Duration::from_millis(self.slow_query_threshold_ms),
+            ))
+    }
 }
 
 /// Cassandra database configuration.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct CassandraConfig {
     pub nodes: Vec<String>,
     pub keyspace: String,
+    /// Statements running longer, in milliseconds, are logged as slow
+    #[serde(default = "default_slow_query_threshold_ms")]
+    pub slow_query_threshold_ms: u64,
+    /// Milliseconds the driver waits for a statement before failing it, 0 for no limit
+    #[serde(default = "default_statement_timeout_ms")]
+    pub request_timeout_ms: u64,
+}
+
+fn default_slow_query_threshold_ms() -> u64 {
+    500
+}
+
+fn default_statement_timeout_ms() -> u64 {
+    30_000
 }
 
 /// HTTP server configuration.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ServerConfig {
     pub http_port: u16,
+    /// Serve reads only: channel and history queries keep working, writes are
+    /// rejected, and Kafka consumers keep the replicas up to date. Used for
+    /// DR secondaries and during primary datastore maintenance.
+    #[serde(default)]
+    pub read_only: bool,
+    /// Take the client IP from the last `X-Forwarded-For` entry (only behind one trusted proxy)
+    #[serde(default)]
+    pub trust_forwarded_for: bool,
 }
 
 /// User-service gRPC client configuration.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct UserServiceConfig {
     pub grpc_url: String,
 }
@@ -47,29 +139,540 @@ pub struct UserServiceConfig {
 /// Kafka event broker configuration.
 ///
 /// Used for publishing message events and consuming user events.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct KafkaConfig {
     pub brokers: String,
     pub group_id: String,
     pub num_shards: u32,
+    #[serde(default)]
+    pub commit_mode: KafkaCommitMode,
     pub user_events: UserEventsConfig,
+    #[serde(default)]
+    pub replication: Option<ReplicationConfig>,
+}
+
+/// How Kafka consumers commit their offsets.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaCommitMode {
+    /// librdkafka commits periodically in the background; events handled
+    /// between the last commit and a restart are redelivered or skipped.
+    #[default]
+    Auto,
+    /// Each event's offset is committed synchronously right after it is handled,
+    /// so a restarted consumer resumes exactly after the last handled event.
+    Manual,
+}
+
+impl KafkaCommitMode {
+    /// Value for librdkafka's `enable.auto.commit` setting.
+    pub fn enable_auto_commit(&self) -> &'static str {
+        match self {
+            KafkaCommitMode::Auto => "true",
+            KafkaCommitMode::Manual => "false",
+        }
+    }
+}
+
+/// Cross-region event replication configuration.
+///
+/// When set, events produced in this region are tagged with its name and
+/// mirrored to the Kafka cluster of a remote region.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReplicationConfig {
+    /// Name of this region, written to the origin header of produced events
+    pub region: String,
+    /// Bootstrap servers of the remote region's Kafka cluster
+    pub remote_brokers: String,
+    /// Consumer group of the replicator
+    pub group_id: String,
 }
 
 /// User events Kafka consumer configuration.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct UserEventsConfig {
     pub topic: String,
     pub group_id: String,
 }
 
 /// JWT authentication configuration.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct JwtConfig {
     pub secret: String,
     pub expiration_hours: i64,
+    /// Database of user-service, holding the access tokens it revoked
+    pub revocation_database_url: String,
+    /// Access tokens cached after validation, 0 disables the cache
+    #[serde(default = "default_validation_cache_size")]
+    pub validation_cache_size: usize,
+    /// Maximum time a validated access token stays cached
+    #[serde(default = "default_validation_cache_ttl_secs")]
+    pub validation_cache_ttl_secs: i64,
+}
+
+fn default_validation_cache_size() -> usize {
+    10_000
+}
+
+fn default_validation_cache_ttl_secs() -> i64 {
+    60
+}
+
+/// Regions served by `GET /api/gateway` in multi-region deployments.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GatewayConfig {
+    /// Region returned when no client signal matches
+    pub default_region: String,
+    pub regions: Vec<GatewayRegionConfig>,
+}
+
+/// WebSocket endpoint of one region.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GatewayRegionConfig {
+    pub name: String,
+    pub websocket_url: String,
+    /// ISO 3166-1 alpha-2 country codes routed to this region by GeoIP
+    #[serde(default)]
+    pub countries: Vec<String>,
+}
+
+/// Inbound email gateway posting emails sent to channel addresses.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EmailGatewayConfig {
+    /// Domain of the channel addresses, routed to the mail provider
+    pub domain: String,
+    /// Key of the tags in channel addresses; changing it invalidates every address
+    pub address_secret: String,
+    /// Bearer token the mail provider authenticates its webhook calls with
+    pub webhook_secret: String,
+    /// User emails are posted as
+    pub poster_user_id: String,
+    /// Larger attachments are listed in the message but not stored
+    #[serde(default = "default_max_attachment_bytes")]
+    pub max_attachment_bytes: usize,
+    /// Directory backing the object storage of attachments
+    #[serde(default = "default_attachment_storage_dir")]
+    pub storage_dir: String,
+}
+
+fn default_max_attachment_bytes() -> usize {
+    10 * 1024 * 1024
+}
+
+fn default_attachment_storage_dir() -> String {
+    "attachments".to_string()
+}
+
+/// Feed bot posting RSS and Atom entries to the channels they are registered in.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FeedsConfig {
+    /// User feed entries are posted as
+    pub bot_user_id: String,
+    /// Seconds between two polls of a feed
+    #[serde(default = "default_feed_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// Entries posted per hour by feeds registered without a cap
+    #[serde(default = "default_feed_posts_per_hour")]
+    pub default_max_posts_per_hour: u32,
+    /// Highest cap channel owners may choose
+    #[serde(default = "default_feed_max_posts_per_hour")]
+    pub max_posts_per_hour: u32,
+    #[serde(default = "default_max_feeds_per_channel")]
+    pub max_feeds_per_channel: usize,
+    /// Seconds a feed download may take
+    #[serde(default = "default_feed_fetch_timeout_secs")]
+    pub fetch_timeout_secs: u64,
+    /// Larger feeds are not parsed
+    #[serde(default = "default_max_feed_bytes")]
+    pub max_feed_bytes: usize,
+}
+
+fn default_feed_poll_interval_secs() -> u64 {
+    300
+}
+
+fn default_feed_posts_per_hour() -> u32 {
+    10
+}
+
+fn default_feed_max_posts_per_hour() -> u32 {
+    60
+}
+
+fn default_max_feeds_per_channel() -> usize {
+    10
+}
+
+fn default_feed_fetch_timeout_secs() -> u64 {
+    10
+}
+
+fn default_max_feed_bytes() -> usize {
+    2 * 1024 * 1024
+}
+
+/// Reminder bot posting scheduled reminders to channels.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RemindersConfig {
+    /// User reminders are posted as
+    pub bot_user_id: String,
+    /// Seconds between two checks for due reminders
+    #[serde(default = "default_reminder_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// Longest time ahead a reminder may be scheduled
+    #[serde(default = "default_reminder_max_delay_days")]
+    pub max_delay_days: i64,
+    #[serde(default = "default_max_reminders_per_channel")]
+    pub max_pending_per_channel: usize,
+}
+
+fn default_reminder_poll_interval_secs() -> u64 {
+    15
+}
+
+fn default_reminder_max_delay_days() -> i64 {
+    365
+}
+
+fn default_max_reminders_per_channel() -> usize {
+    100
+}
+
+/// Polls asked in channels, and the bot posting their final results.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PollsConfig {
+    /// User final results are posted as
+    pub bot_user_id: String,
+    /// Seconds between two checks for expired polls
+    #[serde(default = "default_poll_close_interval_secs")]
+    pub poll_interval_secs: u64,
+    #[serde(default = "default_max_poll_options")]
+    pub max_options: usize,
+    /// Longest time a poll may stay open
+    #[serde(default = "default_max_poll_duration_days")]
+    pub max_duration_days: i64,
+}
+
+fn default_poll_close_interval_secs() -> u64 {
+    15
+}
+
+fn default_max_poll_options() -> usize {
+    10
+}
+
+fn default_max_poll_duration_days() -> i64 {
+    30
+}
+
+/// Message stars, and the consumer counting them into the top messages rollup.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StarsConfig {
+    /// Consumer group of the rollup, shared by every instance
+    pub group_id: String,
+}
+
+/// WebSocket connection capacity limits.
+///
+/// Connections beyond a limit are refused with `503` and `Retry-After` on
+/// upgrade instead of degrading every connection on the instance.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebsocketConfig {
+    /// Maximum connections on this instance, unbounded if unset
+    #[serde(default)]
+    pub max_connections: Option<usize>,
+    /// Maximum connections to one channel on this instance, unbounded if unset
+    #[serde(default)]
+    pub max_connections_per_channel: Option<usize>,
+    /// Seconds refused clients are told to wait before reconnecting
+    #[serde(default = "default_retry_after_secs")]
+    pub retry_after_secs: u64,
+    /// Seconds a client may send nothing before it is disconnected, never if unset
+    #[serde(default)]
+    pub idle_timeout_secs: Option<u64>,
+}
+
+impl Default for WebsocketConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: None,
+            max_connections_per_channel: None,
+            retry_after_secs: default_retry_after_secs(),
+            idle_timeout_secs: None,
+        }
+    }
+}
+
+fn default_retry_after_secs() -> u64 {
+    5
+}
+
+/// Unauthenticated read-only API for embeddable channels (`/embed`).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EmbedConfig {
+    /// Requests one client IP may make per window
+    #[serde(default = "default_embed_requests_per_window")]
+    pub requests_per_window: u32,
+    /// Length of the rate limit window in seconds
+    #[serde(default = "default_embed_window_secs")]
+    pub window_secs: u64,
+    /// Most messages returned per request
+    #[serde(default = "default_embed_max_messages")]
+    pub max_messages: i32,
+}
+
+impl Default for EmbedConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_window: default_embed_requests_per_window(),
+            window_secs: default_embed_window_secs(),
+            max_messages: default_embed_max_messages(),
+        }
+    }
+}
+
+fn default_embed_requests_per_window() -> u32 {
+    30
+}
+
+fn default_embed_window_secs() -> u64 {
+    60
+}
+
+fn default_embed_max_messages() -> i32 {
+    50
+}
+
+/// Channel history returned by `GET /api/channels/{id}/messages`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HistoryConfig {
+    /// Return deleted messages as `"type": "deleted"` entries instead of leaving gaps
+    #[serde(default = "default_include_tombstones")]
+    pub include_tombstones: bool,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            include_tombstones: default_include_tombstones(),
+        }
+    }
+}
+
+fn default_include_tombstones() -> bool {
+    true
+}
+
+/// Message storage configuration.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct MessagesConfig {
+    /// Give new messages UUIDv7 IDs instead of TimeUUIDs; history reads both
+    #[serde(default)]
+    pub uuid_v7_ids: bool,
+}
+
+/// Request body size limits, rejected with `413 Payload Too Large`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LimitsConfig {
+    /// Largest body of JSON routes
+    #[serde(default = "default_json_body_bytes")]
+    pub json_body_bytes: usize,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            json_body_bytes: default_json_body_bytes(),
+        }
+    }
+}
+
+fn default_json_body_bytes() -> usize {
+    1024 * 1024
+}
+
+/// Versioned HTTP API configuration.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ApiConfig {
+    /// When `/api` and `/api/v1` were deprecated in favour of `/api/v2`
+    #[serde(default = "default_v1_deprecated_at")]
+    pub v1_deprecated_at: DateTime<Utc>,
+    /// When `/api` and `/api/v1` stop being served, announced in the `Sunset` header
+    #[serde(default)]
+    pub v1_sunset: Option<DateTime<Utc>>,
+}
+
+impl ApiConfig {
+    /// Deprecation notice of `/api` and `/api/v1`.
+    pub fn v1_deprecation(&self) -> Deprecation {
+        let deprecation = Deprecation::new(self.v1_deprecated_at);
+        match self.v1_sunset {
+            Some(sunset) => deprecation.with_sunset(sunset),
+            None => deprecation,
+        }
+    }
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            v1_deprecated_at: default_v1_deprecated_at(),
+            v1_sunset: None,
+        }
+    }
+}
+
+fn default_v1_deprecated_at() -> DateTime<Utc> {
+    // 2026-10-15, when `/api/v2` was introduced
+    DateTime::from_timestamp(1_792_022_400, 0).unwrap_or_default()
+}
+
+/// Restarts of background tasks (Kafka consumers, replicator).
+///
+/// A task stopping more than `max_failures` times within the window shuts the
+/// process down, so the orchestrator replaces it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SupervisorConfig {
+    /// Wait before the first restart in milliseconds, doubled for every further failure
+    #[serde(default = "default_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+    /// Longest wait before a restart in milliseconds
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+    /// Failures of one task tolerated within the window
+    #[serde(default = "default_max_failures")]
+    pub max_failures: u32,
+    /// Length of the failure window in seconds
+    #[serde(default = "default_failure_window_secs")]
+    pub failure_window_secs: u64,
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff_ms: default_initial_backoff_ms(),
+            max_backoff_ms: default_max_backoff_ms(),
+            max_failures: default_max_failures(),
+            failure_window_secs: default_failure_window_secs(),
+        }
+    }
+}
+
+fn default_initial_backoff_ms() -> u64 {
+    500
+}
+
+fn default_max_backoff_ms() -> u64 {
+    30_000
+}
+
+fn default_max_failures() -> u32 {
+    5
+}
+
+fn default_failure_window_secs() -> u64 {
+    300
+}
+
+/// Election of the instance running coordinator tasks.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LeaderElectionConfig {
+    /// Seconds between two renewals of the leadership
+    #[serde(default = "default_renew_interval_secs")]
+    pub renew_interval_secs: u64,
+    /// Seconds a recorded lease stays valid without renewal
+    #[serde(default = "default_lease_ttl_secs")]
+    pub lease_ttl_secs: u64,
+    /// Seconds between two attempts of a follower to become leader
+    #[serde(default = "default_retry_interval_secs")]
+    pub retry_interval_secs: u64,
+}
+
+impl Default for LeaderElectionConfig {
+    fn default() -> Self {
+        Self {
+            renew_interval_secs: default_renew_interval_secs(),
+            lease_ttl_secs: default_lease_ttl_secs(),
+            retry_interval_secs: default_retry_interval_secs(),
+        }
+    }
+}
+
+fn default_renew_interval_secs() -> u64 {
+    5
+}
+
+fn default_lease_ttl_secs() -> u64 {
+    15
+}
+
+fn default_retry_interval_secs() -> u64 {
+    5
+}
+
+/// Reporting of panics to an error tracker.
+///
+/// Panics are always logged; they are also sent to Sentry when a DSN is set.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CrashReportingConfig {
+    /// Sentry project DSN, None to only log panics
+    #[serde(default)]
+    pub sentry_dsn: Option<String>,
+    /// Environment crashes are tagged with, e.g. `production`
+    #[serde(default)]
+    pub environment: Option<String>,
+}
+
+/// Disaster-recovery backup configuration (`chat-backup` tool).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BackupConfig {
+    /// Directory backing the object storage that dumps and manifests are written to
+    pub storage_dir: String,
+    /// Host `nodetool` connects to
+    pub nodetool_host: String,
+    /// Cassandra data directory, read when importing snapshots on restore
+    pub cassandra_data_dir: String,
+    /// Key manifests are signed (HMAC-SHA256) and verified with; required by `chat-backup`
+    #[serde(default)]
+    pub manifest_signing_key: Option<String>,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            storage_dir: "backups".to_string(),
+            nodetool_host: "localhost".to_string(),
+            cassandra_data_dir: "/var/lib/cassandra/data".to_string(),
+            manifest_signing_key: None,
+        }
+    }
 }
 
 impl Config {
+    /// Copy of the configuration with secrets replaced, safe to log or expose.
+    ///
+    /// # Returns
+    /// Configuration with the JWT secret, database passwords, Sentry DSN, backup
+    /// signing key and email gateway secrets redacted
+    pub fn redacted(&self) -> Self {
+        let mut config = self.clone();
+        config.jwt.secret = REDACTED.to_string();
+        config.database.url = redact_url_password(&config.database.url);
+        config.jwt.revocation_database_url =
+            redact_url_password(&config.jwt.revocation_database_url);
+        if config.crash_reporting.sentry_dsn.is_some() {
+            config.crash_reporting.sentry_dsn = Some(REDACTED.to_string());
+        }
+        if config.backup.manifest_signing_key.is_some() {
+            config.backup.manifest_signing_key = Some(REDACTED.to_string());
+        }
+        if let Some(email_gateway) = config.email_gateway.as_mut() {
+            email_gateway.address_secret = REDACTED.to_string();
+            email_gateway.webhook_secret = REDACTED.to_string();
+        }
+        config
+    }
+
     /// Load configuration from files with environment variable overrides.
     ///
     /// # Configuration Priority (highest to lowest)
@@ -0,0 +1,39 @@
+use thiserror::Error;
+
+/// Error for BackupId parsing failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum BackupIdError {
+    #[error("Invalid UUID format: {0}")]
+    InvalidFormat(String),
+}
+
+/// Top-level error for backup and restore operations
+#[derive(Debug, Clone, Error)]
+pub enum BackupError {
+    #[error("Invalid backup ID: {0}")]
+    InvalidBackupId(#[from] BackupIdError),
+
+    #[error("Backup not found: {0}")]
+    NotFound(String),
+
+    #[error("Database dump error: {0}")]
+    DumpError(String),
+
+    #[error("Snapshot error: {0}")]
+    SnapshotError(String),
+
+    #[error("Offset checkpoint error: {0}")]
+    CheckpointError(String),
+
+    #[error("Object storage error: {0}")]
+    StorageError(String),
+
+    #[error("Manifest error: {0}")]
+    ManifestError(String),
+
+    #[error("Manifest signature is missing or invalid: {0}")]
+    InvalidSignature(String),
+
+    #[error("Backup file was modified: {0}")]
+    IntegrityError(String),
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+
+use chrono::DateTime;
+use chrono::Utc;
+use sha2::Digest;
+use sha2::Sha256;
+use uuid::Uuid;
+
+use super::errors::BackupIdError;
+
+/// Description of a completed backup, sufficient to restore it.
+///
+/// Written last, so a backup without a manifest is incomplete and never restored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupManifest {
+    pub id: BackupId,
+    pub created_at: DateTime<Utc>,
+    pub postgres: PostgresDump,
+    pub cassandra: Vec<CassandraSnapshot>,
+    pub kafka: Vec<ConsumerGroupCheckpoint>,
+}
+
+/// Postgres dump stored in object storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostgresDump {
+    pub object_key: String,
+    pub size_bytes: u64,
+    /// Hex SHA-256 of the dump, proving it unmodified since the backup
+    pub sha256: String,
+}
+
+impl PostgresDump {
+    /// Describe a dump about to be stored under `object_key`.
+    ///
+    /// # Arguments
+    /// * `object_key` - Object storage key of the dump
+    /// * `dump` - Dump content
+    ///
+    /// # Returns
+    /// PostgresDump with the size and hash of the content
+    pub fn new(object_key: String, dump: &[u8]) -> Self {
+        Self {
+            object_key,
+            size_bytes: dump.len() as u64,
+            sha256: sha256_hex(dump),
+        }
+    }
+
+    /// Whether stored content is the dump this manifest describes.
+    ///
+    /// # Arguments
+    /// * `dump` - Content read back from object storage
+    ///
+    /// # Returns
+    /// True if the size and hash match
+    pub fn matches(&self, dump: &[u8]) -> bool {
+        dump.len() as u64 == self.size_bytes && sha256_hex(dump) == self.sha256
+    }
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Named Cassandra snapshot of one keyspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CassandraSnapshot {
+    pub keyspace: String,
+    pub tag: String,
+}
+
+/// Committed offsets of one Kafka consumer group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumerGroupCheckpoint {
+    pub group_id: String,
+    pub offsets: Vec<PartitionOffset>,
+}
+
+/// Committed offset of one topic partition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionOffset {
+    pub topic: String,
+    pub partition: i32,
+    pub offset: i64,
+}
+
+/// Backup unique identifier type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BackupId(pub Uuid);
+
+impl BackupId {
+    /// Generate a new random backup ID.
+    ///
+    /// # Returns
+    /// BackupId with random UUID v4
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Parse a backup ID from string.
+    ///
+    /// # Arguments
+    /// * `s` - UUID string to parse
+    ///
+    /// # Returns
+    /// Parsed BackupId
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a valid UUID
+    pub fn from_string(s: &str) -> Result<Self, BackupIdError> {
+        Uuid::parse_str(s)
+            .map(BackupId)
+            .map_err(|e| BackupIdError::InvalidFormat(e.to_string()))
+    }
+
+    /// Get a reference to the inner UUID.
+    ///
+    /// # Returns
+    /// Reference to the UUID value
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Object storage key of the Postgres dump of this backup.
+    pub fn postgres_dump_key(&self) -> String {
+        format!("backups/{}/postgres.dump", self.0)
+    }
+
+    /// Object storage key of the manifest of this backup.
+    pub fn manifest_key(&self) -> String {
+        format!("backups/{}/manifest.json", self.0)
+    }
+
+    /// Object storage key of the signature of the manifest of this backup.
+    pub fn manifest_signature_key(&self) -> String {
+        format!("backups/{}/manifest.sig", self.0)
+    }
+
+    /// Tag of the Cassandra snapshots taken for this backup.
+    pub fn snapshot_tag(&self) -> String {
+        format!("backup-{}", self.0)
+    }
+}
+
+impl Default for BackupId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for BackupId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,200 @@
+use async_trait::async_trait;
+
+use super::errors::BackupError;
+use super::models::BackupId;
+use super::models::BackupManifest;
+use super::models::ConsumerGroupCheckpoint;
+
+/// Port for disaster-recovery backups.
+#[async_trait]
+pub trait BackupServicePort: Send + Sync + 'static {
+    /// Back up Postgres, Cassandra and Kafka consumer offsets.
+    ///
+    /// # Returns
+    /// Manifest of the completed backup
+    ///
+    /// # Errors
+    /// * `CheckpointError` - Consumer offsets could not be read
+    /// * `DumpError` - Postgres dump failed
+    /// * `SnapshotError` - Cassandra snapshot failed
+    /// * `StorageError` - Dump or manifest could not be stored
+    async fn create_backup(&self) -> Result<BackupManifest, BackupError>;
+
+    /// Restore a backup by replaying its manifest.
+    ///
+    /// # Arguments
+    /// * `id` - Backup to restore
+    ///
+    /// # Returns
+    /// Manifest of the restored backup
+    ///
+    /// # Errors
+    /// * `NotFound` - No manifest exists for the backup
+    /// * `InvalidSignature` - Manifest was not signed with the configured key
+    /// * `IntegrityError` - Dump does not match the manifest
+    /// * `DumpError` - Postgres restore failed
+    /// * `SnapshotError` - Cassandra restore failed
+    /// * `CheckpointError` - Consumer offsets could not be committed
+    /// * `StorageError` - Dump or manifest could not be read
+    async fn restore_backup(&self, id: BackupId) -> Result<BackupManifest, BackupError>;
+
+    /// Prove a backup unmodified: check the manifest signature and the hash of each file.
+    ///
+    /// # Arguments
+    /// * `id` - Backup to verify
+    ///
+    /// # Returns
+    /// Manifest of the verified backup
+    ///
+    /// # Errors
+    /// * `NotFound` - No manifest exists for the backup
+    /// * `InvalidSignature` - Manifest was not signed with the configured key
+    /// * `IntegrityError` - A file is missing or does not match the manifest
+    /// * `StorageError` - Dump or manifest could not be read
+    async fn verify_backup(&self, id: BackupId) -> Result<BackupManifest, BackupError>;
+}
+
+/// Blob storage for backup artifacts.
+#[async_trait]
+pub trait ObjectStorage: Send + Sync + 'static {
+    /// Store an object, replacing any existing object with the same key.
+    ///
+    /// # Arguments
+    /// * `key` - Object key (slash-separated path)
+    /// * `data` - Object content
+    ///
+    /// # Errors
+    /// * `StorageError` - Object could not be written
+    async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), BackupError>;
+
+    /// Read an object.
+    ///
+    /// # Arguments
+    /// * `key` - Object key
+    ///
+    /// # Returns
+    /// Optional object content (None if not found)
+    ///
+    /// # Errors
+    /// * `StorageError` - Object could not be read
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BackupError>;
+}
+
+/// Persistence of backup manifests.
+#[async_trait]
+pub trait BackupManifestStore: Send + Sync + 'static {
+    /// Persist a manifest.
+    ///
+    /// # Arguments
+    /// * `manifest` - Manifest of a completed backup
+    ///
+    /// # Errors
+    /// * `StorageError` - Manifest could not be written
+    async fn save(&self, manifest: &BackupManifest) -> Result<(), BackupError>;
+
+    /// Retrieve the manifest of a backup.
+    ///
+    /// # Arguments
+    /// * `id` - Backup ID
+    ///
+    /// # Returns
+    /// Optional manifest (None if not found)
+    ///
+    /// # Errors
+    /// * `StorageError` - Manifest could not be read
+    /// * `ManifestError` - Manifest is malformed
+    /// * `InvalidSignature` - Manifest signature is missing or does not match
+    async fn find(&self, id: BackupId) -> Result<Option<BackupManifest>, BackupError>;
+}
+
+/// Signature of manifests, so a backup can be proven unmodified.
+pub trait ManifestSigner: Send + Sync + 'static {
+    /// Sign a serialized manifest.
+    ///
+    /// # Arguments
+    /// * `manifest` - Manifest bytes as stored
+    ///
+    /// # Returns
+    /// Signature, stored next to the manifest
+    fn sign(&self, manifest: &[u8]) -> String;
+
+    /// Check a signature produced by [`ManifestSigner::sign`].
+    ///
+    /// # Arguments
+    /// * `manifest` - Manifest bytes as stored
+    /// * `signature` - Stored signature
+    ///
+    /// # Returns
+    /// True if the signature was made over these bytes with the same key
+    fn verify(&self, manifest: &[u8], signature: &str) -> bool;
+}
+
+/// Consistent dump and restore of the Postgres database.
+#[async_trait]
+pub trait DatabaseDumper: Send + Sync + 'static {
+    /// Dump the database from a single transaction snapshot.
+    ///
+    /// # Returns
+    /// Dump content
+    ///
+    /// # Errors
+    /// * `DumpError` - Dump failed
+    async fn dump(&self) -> Result<Vec<u8>, BackupError>;
+
+    /// Replace the database content with a dump.
+    ///
+    /// # Arguments
+    /// * `dump` - Content produced by [`DatabaseDumper::dump`]
+    ///
+    /// # Errors
+    /// * `DumpError` - Restore failed
+    async fn restore(&self, dump: Vec<u8>) -> Result<(), BackupError>;
+}
+
+/// Cassandra snapshot trigger.
+#[async_trait]
+pub trait SnapshotTrigger: Send + Sync + 'static {
+    /// Take a named snapshot of a keyspace.
+    ///
+    /// # Arguments
+    /// * `keyspace` - Keyspace to snapshot
+    /// * `tag` - Snapshot name
+    ///
+    /// # Errors
+    /// * `SnapshotError` - Snapshot failed
+    async fn snapshot(&self, keyspace: &str, tag: &str) -> Result<(), BackupError>;
+
+    /// Load a named snapshot back into a keyspace.
+    ///
+    /// # Arguments
+    /// * `keyspace` - Keyspace to restore
+    /// * `tag` - Snapshot name
+    ///
+    /// # Errors
+    /// * `SnapshotError` - Snapshot is missing or could not be loaded
+    async fn restore(&self, keyspace: &str, tag: &str) -> Result<(), BackupError>;
+}
+
+/// Kafka consumer offset checkpointing.
+#[async_trait]
+pub trait OffsetCheckpointer: Send + Sync + 'static {
+    /// Read the committed offsets of the service's consumer groups.
+    ///
+    /// # Returns
+    /// Committed offsets per consumer group
+    ///
+    /// # Errors
+    /// * `CheckpointError` - Offsets could not be read
+    async fn checkpoint(&self) -> Result<Vec<ConsumerGroupCheckpoint>, BackupError>;
+
+    /// Commit checkpointed offsets back to their consumer groups.
+    ///
+    /// Consumers of the groups must be stopped, or the commit is overwritten.
+    ///
+    /// # Arguments
+    /// * `checkpoints` - Offsets produced by [`OffsetCheckpointer::checkpoint`]
+    ///
+    /// # Errors
+    /// * `CheckpointError` - Offsets could not be committed
+    async fn restore(&self, checkpoints: &[ConsumerGroupCheckpoint]) -> Result<(), BackupError>;
+}
@@ -0,0 +1,496 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use super::errors::BackupError;
+use super::models::BackupId;
+use super::models::BackupManifest;
+use super::models::CassandraSnapshot;
+use super::models::PostgresDump;
+use super::ports::BackupManifestStore;
+use super::ports::BackupServicePort;
+use super::ports::DatabaseDumper;
+use super::ports::ObjectStorage;
+use super::ports::OffsetCheckpointer;
+use super::ports::SnapshotTrigger;
+
+/// Domain service orchestrating disaster-recovery backups.
+///
+/// Concrete implementation of BackupServicePort with dependency injection.
+pub struct BackupService<DD, ST, OC, OS, MS>
+where
+    DD: DatabaseDumper,
+    ST: SnapshotTrigger,
+    OC: OffsetCheckpointer,
+    OS: ObjectStorage,
+    MS: BackupManifestStore,
+{
+    dumper: Arc<DD>,
+    snapshots: Arc<ST>,
+    offsets: Arc<OC>,
+    storage: Arc<OS>,
+    manifests: Arc<MS>,
+    keyspaces: Vec<String>,
+}
+
+impl<DD, ST, OC, OS, MS> BackupService<DD, ST, OC, OS, MS>
+where
+    DD: DatabaseDumper,
+    ST: SnapshotTrigger,
+    OC: OffsetCheckpointer,
+    OS: ObjectStorage,
+    MS: BackupManifestStore,
+{
+    /// Create a new backup service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `dumper` - Postgres dump implementation
+    /// * `snapshots` - Cassandra snapshot implementation
+    /// * `offsets` - Kafka offset checkpoint implementation
+    /// * `storage` - Object storage for dumps
+    /// * `manifests` - Manifest persistence implementation
+    /// * `keyspaces` - Cassandra keyspaces to snapshot
+    ///
+    /// # Returns
+    /// Configured backup service instance
+    pub fn new(
+        dumper: Arc<DD>,
+        snapshots: Arc<ST>,
+        offsets: Arc<OC>,
+        storage: Arc<OS>,
+        manifests: Arc<MS>,
+        keyspaces: Vec<String>,
+    ) -> Self {
+        Self {
+            dumper,
+            snapshots,
+            offsets,
+            storage,
+            manifests,
+            keyspaces,
+        }
+    }
+
+    async fn manifest(&self, id: BackupId) -> Result<BackupManifest, BackupError> {
+        self.manifests
+            .find(id)
+            .await?
+            .ok_or(BackupError::NotFound(id.to_string()))
+    }
+
+    /// Read back a dump, refusing it unless it is the one the manifest hashed.
+    async fn verified_dump(&self, postgres: &PostgresDump) -> Result<Vec<u8>, BackupError> {
+        let dump = self
+            .storage
+            .get(&postgres.object_key)
+            .await?
+            .ok_or_else(|| {
+                BackupError::IntegrityError(format!(
+                    "Postgres dump {} is missing",
+                    postgres.object_key
+                ))
+            })?;
+
+        if !postgres.matches(&dump) {
+            return Err(BackupError::IntegrityError(format!(
+                "Postgres dump {} does not match its hash",
+                postgres.object_key
+            )));
+        }
+
+        Ok(dump)
+    }
+}
+
+#[async_trait]
+impl<DD, ST, OC, OS, MS> BackupServicePort for BackupService<DD, ST, OC, OS, MS>
+where
+    DD: DatabaseDumper,
+    ST: SnapshotTrigger,
+    OC: OffsetCheckpointer,
+    OS: ObjectStorage,
+    MS: BackupManifestStore,
+{
+    async fn create_backup(&self) -> Result<BackupManifest, BackupError> {
+        let id = BackupId::new();
+        let created_at = Utc::now();
+        tracing::info!(backup_id = %id, "Backup started");
+
+        // Offsets are read before the data so that, after a restore, consumers
+        // re-handle events already reflected in the data (all handlers are
+        // idempotent upserts) instead of skipping events that are not.
+        let kafka = self.offsets.checkpoint().await?;
+
+        let dump = self.dumper.dump().await?;
+        let postgres = PostgresDump::new(id.postgres_dump_key(), &dump);
+        self.storage.put(&postgres.object_key, dump).await?;
+        tracing::info!(backup_id = %id, size_bytes = postgres.size_bytes, "Postgres dump stored");
+
+        let tag = id.snapshot_tag();
+        let mut cassandra = Vec::with_capacity(self.keyspaces.len());
+        for keyspace in &self.keyspaces {
+            self.snapshots.snapshot(keyspace, &tag).await?;
+            cassandra.push(CassandraSnapshot {
+                keyspace: keyspace.clone(),
+                tag: tag.clone(),
+            });
+        }
+        tracing::info!(backup_id = %id, keyspaces = cassandra.len(), "Cassandra snapshots taken");
+
+        let manifest = BackupManifest {
+            id,
+            created_at,
+            postgres,
+            cassandra,
+            kafka,
+        };
+        self.manifests.save(&manifest).await?;
+        tracing::info!(backup_id = %id, "Backup completed");
+
+        Ok(manifest)
+    }
+
+    async fn restore_backup(&self, id: BackupId) -> Result<BackupManifest, BackupError> {
+        let manifest = self.manifest(id).await?;
+        tracing::info!(backup_id = %id, created_at = %manifest.created_at, "Restore started");
+
+        let dump = self.verified_dump(&manifest.postgres).await?;
+        self.dumper.restore(dump).await?;
+        tracing::info!(backup_id = %id, "Postgres restored");
+
+        for snapshot in &manifest.cassandra {
+            self.snapshots
+                .restore(&snapshot.keyspace, &snapshot.tag)
+                .await?;
+        }
+        tracing::info!(backup_id = %id, "Cassandra restored");
+
+        self.offsets.restore(&manifest.kafka).await?;
+        tracing::info!(backup_id = %id, "Restore completed");
+
+        Ok(manifest)
+    }
+
+    async fn verify_backup(&self, id: BackupId) -> Result<BackupManifest, BackupError> {
+        let manifest = self.manifest(id).await?;
+        self.verified_dump(&manifest.postgres).await?;
+        tracing::info!(backup_id = %id, "Backup verified");
+
+        Ok(manifest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+    use mockall::predicate::*;
+
+    use super::*;
+    use crate::domain::backup::models::ConsumerGroupCheckpoint;
+    use crate::domain::backup::models::PartitionOffset;
+
+    mock! {
+        pub TestDumper {}
+
+        #[async_trait]
+        impl DatabaseDumper for TestDumper {
+            async fn dump(&self) -> Result<Vec<u8>, BackupError>;
+            async fn restore(&self, dump: Vec<u8>) -> Result<(), BackupError>;
+        }
+    }
+
+    mock! {
+        pub TestSnapshots {}
+
+        #[async_trait]
+        impl SnapshotTrigger for TestSnapshots {
+            async fn snapshot(&self, keyspace: &str, tag: &str) -> Result<(), BackupError>;
+            async fn restore(&self, keyspace: &str, tag: &str) -> Result<(), BackupError>;
+        }
+    }
+
+    mock! {
+        pub TestOffsets {}
+
+        #[async_trait]
+        impl OffsetCheckpointer for TestOffsets {
+            async fn checkpoint(&self) -> Result<Vec<ConsumerGroupCheckpoint>, BackupError>;
+            async fn restore(&self, checkpoints: &[ConsumerGroupCheckpoint]) -> Result<(), BackupError>;
+        }
+    }
+
+    mock! {
+        pub TestStorage {}
+
+        #[async_trait]
+        impl ObjectStorage for TestStorage {
+            async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), BackupError>;
+            async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BackupError>;
+        }
+    }
+
+    mock! {
+        pub TestManifests {}
+
+        #[async_trait]
+        impl BackupManifestStore for TestManifests {
+            async fn save(&self, manifest: &BackupManifest) -> Result<(), BackupError>;
+            async fn find(&self, id: BackupId) -> Result<Option<BackupManifest>, BackupError>;
+        }
+    }
+
+    type TestService = BackupService<
+        MockTestDumper,
+        MockTestSnapshots,
+        MockTestOffsets,
+        MockTestStorage,
+        MockTestManifests,
+    >;
+
+    fn service(
+        dumper: MockTestDumper,
+        snapshots: MockTestSnapshots,
+        offsets: MockTestOffsets,
+        storage: MockTestStorage,
+        manifests: MockTestManifests,
+    ) -> TestService {
+        BackupService::new(
+            Arc::new(dumper),
+            Arc::new(snapshots),
+            Arc::new(offsets),
+            Arc::new(storage),
+            Arc::new(manifests),
+            vec!["chat".to_string()],
+        )
+    }
+
+    fn checkpoint() -> Vec<ConsumerGroupCheckpoint> {
+        vec![ConsumerGroupCheckpoint {
+            group_id: "chat-service-user-events".to_string(),
+            offsets: vec![PartitionOffset {
+                topic: "user-events".to_string(),
+                partition: 0,
+                offset: 42,
+            }],
+        }]
+    }
+
+    fn manifest(id: BackupId) -> BackupManifest {
+        BackupManifest {
+            id,
+            created_at: Utc::now(),
+            postgres: PostgresDump::new(id.postgres_dump_key(), b"dump"),
+            cassandra: vec![CassandraSnapshot {
+                keyspace: "chat".to_string(),
+                tag: id.snapshot_tag(),
+            }],
+            kafka: checkpoint(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_backup_writes_manifest_last() {
+        let mut offsets = MockTestOffsets::new();
+        offsets
+            .expect_checkpoint()
+            .times(1)
+            .returning(|| Ok(checkpoint()));
+
+        let mut dumper = MockTestDumper::new();
+        dumper
+            .expect_dump()
+            .times(1)
+            .returning(|| Ok(b"dump".to_vec()));
+
+        let mut storage = MockTestStorage::new();
+        storage
+            .expect_put()
+            .withf(|key, data| key.ends_with("/postgres.dump") && data == b"dump")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut snapshots = MockTestSnapshots::new();
+        snapshots
+            .expect_snapshot()
+            .withf(|keyspace, tag| keyspace == "chat" && tag.starts_with("backup-"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut manifests = MockTestManifests::new();
+        manifests
+            .expect_save()
+            .withf(|manifest| {
+                manifest.postgres.size_bytes == 4
+                    && manifest.postgres.matches(b"dump")
+                    && manifest.cassandra.len() == 1
+                    && manifest.kafka[0].offsets[0].offset == 42
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let manifest = service(dumper, snapshots, offsets, storage, manifests)
+            .create_backup()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            manifest.postgres.object_key,
+            manifest.id.postgres_dump_key()
+        );
+        assert_eq!(manifest.cassandra[0].tag, manifest.id.snapshot_tag());
+    }
+
+    #[tokio::test]
+    async fn test_create_backup_without_manifest_when_snapshot_fails() {
+        let mut offsets = MockTestOffsets::new();
+        offsets.expect_checkpoint().returning(|| Ok(checkpoint()));
+
+        let mut dumper = MockTestDumper::new();
+        dumper.expect_dump().returning(|| Ok(b"dump".to_vec()));
+
+        let mut storage = MockTestStorage::new();
+        storage.expect_put().returning(|_, _| Ok(()));
+
+        let mut snapshots = MockTestSnapshots::new();
+        snapshots
+            .expect_snapshot()
+            .returning(|_, _| Err(BackupError::SnapshotError("nodetool failed".to_string())));
+
+        let mut manifests = MockTestManifests::new();
+        manifests.expect_save().times(0);
+
+        let result = service(dumper, snapshots, offsets, storage, manifests)
+            .create_backup()
+            .await;
+
+        assert!(matches!(result, Err(BackupError::SnapshotError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_restore_backup_replays_manifest() {
+        let id = BackupId::new();
+
+        let mut manifests = MockTestManifests::new();
+        manifests
+            .expect_find()
+            .with(eq(id))
+            .times(1)
+            .returning(|id| Ok(Some(manifest(id))));
+
+        let mut storage = MockTestStorage::new();
+        storage
+            .expect_get()
+            .with(eq(id.postgres_dump_key()))
+            .times(1)
+            .returning(|_| Ok(Some(b"dump".to_vec())));
+
+        let mut dumper = MockTestDumper::new();
+        dumper
+            .expect_restore()
+            .with(eq(b"dump".to_vec()))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut snapshots = MockTestSnapshots::new();
+        let tag = id.snapshot_tag();
+        snapshots
+            .expect_restore()
+            .withf(move |keyspace, t| keyspace == "chat" && t == tag)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut offsets = MockTestOffsets::new();
+        offsets
+            .expect_restore()
+            .withf(|checkpoints| checkpoints == checkpoint().as_slice())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let restored = service(dumper, snapshots, offsets, storage, manifests)
+            .restore_backup(id)
+            .await
+            .unwrap();
+
+        assert_eq!(restored.id, id);
+    }
+
+    #[tokio::test]
+    async fn test_restore_unknown_backup_not_found() {
+        let mut manifests = MockTestManifests::new();
+        manifests.expect_find().returning(|_| Ok(None));
+
+        let mut dumper = MockTestDumper::new();
+        dumper.expect_restore().times(0);
+
+        let result = service(
+            dumper,
+            MockTestSnapshots::new(),
+            MockTestOffsets::new(),
+            MockTestStorage::new(),
+            manifests,
+        )
+        .restore_backup(BackupId::new())
+        .await;
+
+        assert!(matches!(result, Err(BackupError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_restore_refuses_modified_dump() {
+        let mut manifests = MockTestManifests::new();
+        manifests
+            .expect_find()
+            .returning(|id| Ok(Some(manifest(id))));
+
+        let mut storage = MockTestStorage::new();
+        storage
+            .expect_get()
+            .returning(|_| Ok(Some(b"dumq".to_vec())));
+
+        let mut dumper = MockTestDumper::new();
+        dumper.expect_restore().times(0);
+
+        let result = service(
+            dumper,
+            MockTestSnapshots::new(),
+            MockTestOffsets::new(),
+            storage,
+            manifests,
+        )
+        .restore_backup(BackupId::new())
+        .await;
+
+        assert!(matches!(result, Err(BackupError::IntegrityError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_verify_backup_checks_dump_hash() {
+        let id = BackupId::new();
+
+        let mut manifests = MockTestManifests::new();
+        manifests
+            .expect_find()
+            .returning(|id| Ok(Some(manifest(id))));
+
+        let mut storage = MockTestStorage::new();
+        storage
+            .expect_get()
+            .with(eq(id.postgres_dump_key()))
+            .times(1)
+            .returning(|_| Ok(Some(b"dump".to_vec())));
+
+        let verified = service(
+            MockTestDumper::new(),
+            MockTestSnapshots::new(),
+            MockTestOffsets::new(),
+            storage,
+            manifests,
+        )
+        .verify_backup(id)
+        .await
+        .unwrap();
+
+        assert_eq!(verified.id, id);
+    }
+}
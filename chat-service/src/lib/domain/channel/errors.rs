@@ -9,6 +9,16 @@ use crate::UserId;
 pub enum ChannelIdError {
     #[error("Invalid UUID format: {0}")]
     InvalidFormat(String),
+
+    #[error("Channel ID must be a UUIDv7 or time-based UUID (v1), got version {0}")]
+    UnsupportedVersion(usize),
+}
+
+/// Error type for WorkspaceId parsing failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum WorkspaceIdError {
+    #[error("Invalid UUID format: {0}")]
+    InvalidFormat(String),
 }
 
 /// Error type for ChannelName validation failures
@@ -36,9 +46,15 @@ pub enum ChannelError {
     #[error("Channel not found: {0}")]
     NotFound(ChannelId),
 
+    #[error("Channel ID already exists: {0}")]
+    IdAlreadyExists(ChannelId),
+
     #[error("Channel name already exists: {0}")]
     NameAlreadyExists(String),
 
+    #[error("Channel {0} is not public")]
+    NotPublic(ChannelId),
+
     #[error("User {user_id} is not a member of channel {channel_id}")]
     NotMember {
         user_id: UserId,
@@ -10,6 +10,7 @@ use crate::domain::user::models::UserId;
 #[derive(Debug, Clone)]
 pub enum ChannelEvent {
     ChannelCreated(ChannelCreatedEvent),
+    ChannelUpdated(ChannelUpdatedEvent),
     UserJoinedChannel(UserJoinedChannelEvent),
     UserLeftChannel(UserLeftChannelEvent),
     ChannelDeleted(ChannelDeletedEvent),
@@ -23,6 +24,7 @@ impl ChannelEvent {
     pub fn event_id(&self) -> &str {
         match self {
             ChannelEvent::ChannelCreated(e) => &e.event_id,
+            ChannelEvent::ChannelUpdated(e) => &e.event_id,
             ChannelEvent::UserJoinedChannel(e) => &e.event_id,
             ChannelEvent::UserLeftChannel(e) => &e.event_id,
             ChannelEvent::ChannelDeleted(e) => &e.event_id,
@@ -36,6 +38,7 @@ impl ChannelEvent {
     pub fn event_type(&self) -> &str {
         match self {
             ChannelEvent::ChannelCreated(_) => "channel_created",
+            ChannelEvent::ChannelUpdated(_) => "channel_updated",
             ChannelEvent::UserJoinedChannel(_) => "user_joined_channel",
             ChannelEvent::UserLeftChannel(_) => "user_left_channel",
             ChannelEvent::ChannelDeleted(_) => "channel_deleted",
@@ -49,6 +52,7 @@ impl ChannelEvent {
     pub fn channel_id(&self) -> ChannelId {
         match self {
             ChannelEvent::ChannelCreated(e) => e.channel_id,
+            ChannelEvent::ChannelUpdated(e) => e.channel_id,
             ChannelEvent::UserJoinedChannel(e) => e.channel_id,
             ChannelEvent::UserLeftChannel(e) => e.channel_id,
             ChannelEvent::ChannelDeleted(e) => e.channel_id,
@@ -65,6 +69,7 @@ pub struct ChannelCreatedEvent {
     pub channel_id: ChannelId,
     pub channel_type: String,
     pub name: Option<String>,
+    pub description: Option<String>,
     pub created_by: UserId,
     pub timestamp: DateTime<Utc>,
 }
@@ -85,12 +90,46 @@ impl ChannelCreatedEvent {
             channel_id: channel.id(),
             channel_type: channel.channel_type().to_string(),
             name: channel.name().map(|n| n.as_str().to_string()),
+            description: channel.description().map(|d| d.to_string()),
             created_by: channel.created_by(),
             timestamp: Utc::now(),
         }
     }
 }
 
+/// Domain event published when a channel's name or description changes.
+///
+/// Contains snapshot of the mutable channel fields after the update.
+#[derive(Debug, Clone)]
+pub struct ChannelUpdatedEvent {
+    pub event_id: String,
+    pub channel_id: ChannelId,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ChannelUpdatedEvent {
+    /// Create a new ChannelUpdated event from a channel entity.
+    ///
+    /// Generates a unique event ID and extracts the updated channel data.
+    ///
+    /// # Arguments
+    /// * `channel` - Channel entity after the update
+    ///
+    /// # Returns
+    /// ChannelUpdatedEvent with unique event ID and channel snapshot
+    pub fn new(channel: &Channel) -> Self {
+        Self {
+            event_id: Uuid::new_v4().to_string(),
+            channel_id: channel.id(),
+            name: channel.name().map(|n| n.as_str().to_string()),
+            description: channel.description().map(|d| d.to_string()),
+            timestamp: Utc::now(),
+        }
+    }
+}
+
 /// Domain event published when a user joins a channel.
 ///
 /// For private channels and group membership tracking.
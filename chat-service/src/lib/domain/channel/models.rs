@@ -6,6 +6,9 @@ use uuid::Uuid;
 
 use crate::domain::channel::errors::ChannelIdError;
 use crate::domain::channel::errors::ChannelNameError;
+use crate::domain::channel::errors::WorkspaceIdError;
+use crate::domain::message::models::LanguageCode;
+use crate::domain::message::models::Sender;
 use crate::domain::user::models::UserId;
 
 /// Channel unique identifier value object.
@@ -37,6 +40,28 @@ impl ChannelId {
             .map_err(|e| ChannelIdError::InvalidFormat(e.to_string()))
     }
 
+    /// Parse a channel ID supplied by an external system.
+    ///
+    /// Only time-ordered UUIDs are accepted, so supplied IDs sort like
+    /// the creation times of the channels they refer to.
+    ///
+    /// # Arguments
+    /// * `s` - UUID string to parse
+    ///
+    /// # Returns
+    /// Parsed ChannelId
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a valid UUID
+    /// * `UnsupportedVersion` - UUID is neither v7 nor v1
+    pub fn from_supplied(s: &str) -> Result<Self, ChannelIdError> {
+        let id = Self::from_string(s)?;
+        match id.0.get_version_num() {
+            1 | 7 => Ok(id),
+            version => Err(ChannelIdError::UnsupportedVersion(version)),
+        }
+    }
+
     /// Get a reference to the inner UUID.
     ///
     /// # Returns
@@ -54,12 +79,58 @@ impl ChannelId {
     }
 }
 
+impl Default for ChannelId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl fmt::Display for ChannelId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
+/// Workspace identifier value object.
+///
+/// Workspaces separate the channels of different teams, so channel names only
+/// need to be unique within one. Single-tenant deployments put every channel
+/// in the default (nil UUID) workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WorkspaceId(pub Uuid);
+
+impl WorkspaceId {
+    /// Parse a workspace ID from string.
+    ///
+    /// # Arguments
+    /// * `s` - UUID string to parse
+    ///
+    /// # Returns
+    /// Parsed WorkspaceId
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a valid UUID
+    pub fn from_string(s: &str) -> Result<Self, WorkspaceIdError> {
+        Uuid::parse_str(s)
+            .map(WorkspaceId)
+            .map_err(|e| WorkspaceIdError::InvalidFormat(e.to_string()))
+    }
+
+    /// Get a reference to the inner UUID.
+    ///
+    /// # Returns
+    /// Reference to the UUID value
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl fmt::Display for WorkspaceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 /// Channel aggregate root with type-safe variants.
 #[derive(Debug, Clone)]
 pub enum Channel {
@@ -93,6 +164,18 @@ impl Channel {
         }
     }
 
+    /// Get the workspace the channel belongs to.
+    ///
+    /// # Returns
+    /// Workspace identifier
+    pub fn workspace_id(&self) -> WorkspaceId {
+        match self {
+            Channel::Public(c) => c.workspace_id,
+            Channel::Private(c) => c.workspace_id,
+            Channel::Direct(c) => c.workspace_id,
+        }
+    }
+
     /// Get the channel name if applicable.
     ///
     /// # Returns
@@ -129,6 +212,54 @@ impl Channel {
         }
     }
 
+    /// Check whether the channel may be read without authentication.
+    ///
+    /// # Returns
+    /// True for public channels flagged as embeddable
+    pub fn is_embeddable(&self) -> bool {
+        matches!(self, Channel::Public(c) if c.embeddable)
+    }
+
+    /// Check whether the channel is listed in the public channel directory.
+    ///
+    /// # Returns
+    /// True for public channels opted into public discovery
+    pub fn is_discoverable(&self) -> bool {
+        matches!(self, Channel::Public(c) if c.discoverable)
+    }
+
+    /// Check whether new users of the workspace join the channel automatically.
+    ///
+    /// # Returns
+    /// True for public channels marked as default channels
+    pub fn is_auto_join(&self) -> bool {
+        matches!(self, Channel::Public(c) if c.auto_join)
+    }
+
+    /// Check whether only the owner and moderators may post.
+    ///
+    /// # Returns
+    /// True for public and private channels in announcement mode
+    pub fn is_announcement_only(&self) -> bool {
+        match self {
+            Channel::Public(c) => c.announcement_only,
+            Channel::Private(c) => c.announcement_only,
+            Channel::Direct(_) => false,
+        }
+    }
+
+    /// Check whether a sender may post messages to the channel.
+    ///
+    /// # Arguments
+    /// * `sender` - User sending the message
+    ///
+    /// # Returns
+    /// True unless the channel is announcement-only and the sender is
+    /// neither its creator nor a moderator
+    pub fn may_post(&self, sender: &Sender) -> bool {
+        !self.is_announcement_only() || sender.moderator || sender.user_id == self.created_by()
+    }
+
     /// Get the channel description if applicable.
     ///
     /// # Returns
@@ -144,27 +275,41 @@ impl Channel {
 
 /// Public channel accessible to all users.
 ///
-/// Anyone can join and send messages.
+/// Anyone can join and send messages. Names are unique within the workspace.
 #[derive(Debug, Clone)]
 pub struct PublicChannel {
     pub id: ChannelId,
+    pub workspace_id: WorkspaceId,
     pub name: ChannelName,
     pub description: Option<String>,
     pub created_by: UserId,
     pub created_at: DateTime<Utc>,
+    /// Messages are readable without authentication through the embed API
+    pub embeddable: bool,
+    /// Listed in the unauthenticated public channel directory
+    pub discoverable: bool,
+    /// Only the creator and moderators may post
+    pub announcement_only: bool,
+    /// Default channel, joined automatically by new users of the workspace
+    pub auto_join: bool,
 }
 
 /// Private channel with restricted membership.
 ///
-/// Only invited members can access and send messages.
+/// Only invited members can access and send messages. Names are unique among
+/// the channels a user created in the workspace, so they reveal nothing about
+/// channels of others.
 #[derive(Debug, Clone)]
 pub struct PrivateChannel {
     pub id: ChannelId,
+    pub workspace_id: WorkspaceId,
     pub name: ChannelName,
     pub description: Option<String>,
     pub created_by: UserId,
     pub created_at: DateTime<Utc>,
     pub members: Vec<UserId>,
+    /// Only the creator and moderators may post
+    pub announcement_only: bool,
 }
 
 /// Direct message channel between exactly two users.
@@ -173,6 +318,7 @@ pub struct PrivateChannel {
 #[derive(Debug, Clone)]
 pub struct DirectChannel {
     pub id: ChannelId,
+    pub workspace_id: WorkspaceId,
     pub created_by: UserId,
     pub created_at: DateTime<Utc>,
     pub participants: [UserId; 2],
@@ -221,6 +367,71 @@ impl ChannelName {
     }
 }
 
+/// Activity rollup recorded for a channel as messages are sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChannelActivity {
+    pub message_count: u64,
+    pub participant_count: u64,
+    pub last_activity_at: Option<DateTime<Utc>>,
+    /// Messages per detected language, most used first
+    pub languages: Vec<LanguageCount>,
+}
+
+/// Number of messages of a channel detected as one language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageCount {
+    pub language: LanguageCode,
+    pub message_count: u64,
+}
+
+/// Computed channel statistics for rendering channel headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelStats {
+    pub message_count: u64,
+    pub member_count: u64,
+    pub last_activity_at: Option<DateTime<Utc>>,
+    /// Language distribution of the messages whose language could be detected
+    pub languages: Vec<LanguageCount>,
+}
+
+impl ChannelStats {
+    /// Combine a channel with its activity rollup.
+    ///
+    /// Direct channels always have two members; other channels count the
+    /// distinct users who have posted, since membership is open or not yet persisted.
+    ///
+    /// # Arguments
+    /// * `channel` - Channel the stats describe
+    /// * `activity` - Activity rollup for the channel
+    ///
+    /// # Returns
+    /// Channel statistics
+    pub fn new(channel: &Channel, activity: ChannelActivity) -> Self {
+        let member_count = match channel {
+            Channel::Direct(c) => c.participants.len() as u64,
+            Channel::Public(_) | Channel::Private(_) => activity.participant_count,
+        };
+
+        Self {
+            message_count: activity.message_count,
+            member_count,
+            last_activity_at: activity.last_activity_at,
+            languages: activity.languages,
+        }
+    }
+}
+
+/// Channel as listed in the public channel directory.
+///
+/// Only carries what is safe to show to anonymous visitors and crawlers.
+#[derive(Debug, Clone)]
+pub struct ChannelDirectoryEntry {
+    pub name: ChannelName,
+    pub description: Option<String>,
+    /// Distinct users who have posted in the channel
+    pub member_count: u64,
+}
+
 /// Channel type discriminator.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChannelType {
@@ -237,11 +448,15 @@ pub enum CreateChannelCommand {
     Public {
         name: ChannelName,
         description: Option<String>,
+        embeddable: bool,
+        discoverable: bool,
+        announcement_only: bool,
     },
     Private {
         name: ChannelName,
         description: Option<String>,
         members: Vec<UserId>,
+        announcement_only: bool,
     },
     Direct {
         participant_id: UserId,
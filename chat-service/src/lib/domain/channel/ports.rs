@@ -1,14 +1,22 @@
 use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
 
 use super::events::ChannelCreatedEvent;
 use super::events::ChannelDeletedEvent;
+use super::events::ChannelUpdatedEvent;
 use super::events::UserJoinedChannelEvent;
 use super::events::UserLeftChannelEvent;
 use super::models::Channel;
+use super::models::ChannelActivity;
+use super::models::ChannelDirectoryEntry;
 use super::models::ChannelId;
+use super::models::ChannelStats;
 use super::models::CreateChannelCommand;
+use super::models::WorkspaceId;
 use crate::domain::channel::errors::ChannelError;
 use crate::domain::errors::EventPublisherError;
+use crate::domain::message::models::LanguageCode;
 use crate::domain::user::models::UserId;
 
 /// Port for channel domain service operations.
@@ -19,17 +27,42 @@ pub trait ChannelServicePort: Send + Sync + 'static {
     /// # Arguments
     /// * `command` - Create channel command (Public, Private, or Direct)
     /// * `created_by` - User creating the channel
+    /// * `workspace_id` - Workspace the channel belongs to
     ///
     /// # Returns
     /// Created channel entity
     ///
     /// # Errors
-    /// * `NameAlreadyExists` - Channel name already taken (public/private only)
+    /// * `NameAlreadyExists` - Channel name already taken in the workspace (public/private only)
     /// * `DatabaseError` - Database operation failed
     async fn create_channel(
         &self,
         command: CreateChannelCommand,
         created_by: UserId,
+        workspace_id: WorkspaceId,
+    ) -> Result<Channel, ChannelError>;
+
+    /// Create a new channel under an ID supplied by an external system.
+    ///
+    /// # Arguments
+    /// * `id` - Channel ID chosen by the caller
+    /// * `command` - Create channel command (Public, Private, or Direct)
+    /// * `created_by` - User creating the channel
+    /// * `workspace_id` - Workspace the channel belongs to
+    ///
+    /// # Returns
+    /// Created channel entity
+    ///
+    /// # Errors
+    /// * `IdAlreadyExists` - A channel with the ID exists
+    /// * `NameAlreadyExists` - Channel name already taken in the workspace (public/private only)
+    /// * `DatabaseError` - Database operation failed
+    async fn create_channel_with_id(
+        &self,
+        id: ChannelId,
+        command: CreateChannelCommand,
+        created_by: UserId,
+        workspace_id: WorkspaceId,
     ) -> Result<Channel, ChannelError>;
 
     /// Retrieve channel by unique identifier.
@@ -45,6 +78,18 @@ pub trait ChannelServicePort: Send + Sync + 'static {
     /// * `DatabaseError` - Database operation failed
     async fn get_channel(&self, id: ChannelId) -> Result<Channel, ChannelError>;
 
+    /// Compute message and participant statistics for a channel.
+    ///
+    /// # Arguments
+    /// * `channel` - Channel to compute statistics for
+    ///
+    /// # Returns
+    /// Message count, member count, last activity time and language distribution
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn get_channel_stats(&self, channel: &Channel) -> Result<ChannelStats, ChannelError>;
+
     /// List all publicly accessible channels.
     ///
     /// # Returns
@@ -54,10 +99,19 @@ pub trait ChannelServicePort: Send + Sync + 'static {
     /// * `DatabaseError` - Database operation failed
     async fn list_public_channels(&self) -> Result<Vec<Channel>, ChannelError>;
 
+    /// List the channels opted into public discovery.
+    ///
+    /// # Returns
+    /// Directory entries ordered by channel name
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn list_channel_directory(&self) -> Result<Vec<ChannelDirectoryEntry>, ChannelError>;
+
     /// List channels accessible to a specific user.
     ///
-    /// Includes channels created by user, private channels where user is member,
-    /// and direct channels where user is participant.
+    /// Includes channels created by user and channels the user joined,
+    /// default channels among them.
     ///
     /// # Arguments
     /// * `user_id` - User ID to search for
@@ -68,6 +122,56 @@ pub trait ChannelServicePort: Send + Sync + 'static {
     /// # Errors
     /// * `DatabaseError` - Database operation failed
     async fn list_user_channels(&self, user_id: UserId) -> Result<Vec<Channel>, ChannelError>;
+
+    /// Mark a public channel as a default channel of its workspace, or unmark it.
+    ///
+    /// Users created afterwards join default channels automatically; existing
+    /// users are not added.
+    ///
+    /// # Arguments
+    /// * `id` - Channel ID to update
+    /// * `auto_join` - Whether new users join the channel
+    ///
+    /// # Returns
+    /// Updated channel
+    ///
+    /// # Errors
+    /// * `NotFound` - Channel does not exist
+    /// * `NotPublic` - Channel is private or direct
+    /// * `DatabaseError` - Database operation failed
+    async fn set_auto_join(&self, id: ChannelId, auto_join: bool) -> Result<Channel, ChannelError>;
+
+    /// Add a new user to the default channels of a workspace.
+    ///
+    /// Idempotent, so that redelivered user events add no duplicate memberships.
+    ///
+    /// # Arguments
+    /// * `user_id` - User joining the channels
+    /// * `workspace_id` - Workspace of the user
+    ///
+    /// # Returns
+    /// Channels the user joined, without those they were already a member of
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn join_default_channels(
+        &self,
+        user_id: UserId,
+        workspace_id: WorkspaceId,
+    ) -> Result<Vec<ChannelId>, ChannelError>;
+
+    /// Delete a channel and announce the deletion to downstream consumers.
+    ///
+    /// # Arguments
+    /// * `id` - Channel ID to delete
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `NotFound` - Channel does not exist
+    /// * `DatabaseError` - Database operation failed
+    async fn delete_channel(&self, id: ChannelId) -> Result<(), ChannelError>;
 }
 
 /// Repository port for channel persistence operations.
@@ -82,6 +186,7 @@ pub trait ChannelRepository: Send + Sync + 'static {
     /// Created channel with database-assigned metadata
     ///
     /// # Errors
+    /// * `IdAlreadyExists` - A channel with the ID exists
     /// * `NameAlreadyExists` - Channel name already taken
     /// * `DatabaseError` - Database operation failed
     async fn create(&self, channel: Channel) -> Result<Channel, ChannelError>;
@@ -107,10 +212,18 @@ pub trait ChannelRepository: Send + Sync + 'static {
     /// * `DatabaseError` - Database operation failed
     async fn find_public_channels(&self) -> Result<Vec<Channel>, ChannelError>;
 
+    /// List discoverable public channels with their member counts.
+    ///
+    /// # Returns
+    /// Directory entries ordered by channel name
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_directory_entries(&self) -> Result<Vec<ChannelDirectoryEntry>, ChannelError>;
+
     /// Find channels accessible to a specific user.
     ///
-    /// Includes channels created by user, private channels where user is member,
-    /// and direct channels where user is participant.
+    /// Includes channels created by user and channels the user joined.
     ///
     /// # Arguments
     /// * `user_id` - User ID to search for
@@ -122,6 +235,41 @@ pub trait ChannelRepository: Send + Sync + 'static {
     /// * `DatabaseError` - Database operation failed
     async fn find_by_user(&self, user_id: UserId) -> Result<Vec<Channel>, ChannelError>;
 
+    /// Set whether new users of the workspace join a channel automatically.
+    ///
+    /// # Arguments
+    /// * `id` - Channel ID to update
+    /// * `auto_join` - Whether new users join the channel
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `NotFound` - Channel does not exist
+    /// * `DatabaseError` - Database operation failed
+    async fn set_auto_join(&self, id: ChannelId, auto_join: bool) -> Result<(), ChannelError>;
+
+    /// Add a user to every default channel of a workspace.
+    ///
+    /// Memberships that already exist are left untouched.
+    ///
+    /// # Arguments
+    /// * `user_id` - User joining the channels
+    /// * `workspace_id` - Workspace whose default channels to join
+    /// * `joined_at` - Membership timestamp
+    ///
+    /// # Returns
+    /// Channels the user was added to
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn add_to_auto_join_channels(
+        &self,
+        user_id: UserId,
+        workspace_id: WorkspaceId,
+        joined_at: DateTime<Utc>,
+    ) -> Result<Vec<ChannelId>, ChannelError>;
+
     /// Remove channel permanently.
     ///
     /// # Arguments
@@ -134,6 +282,39 @@ pub trait ChannelRepository: Send + Sync + 'static {
     /// * `NotFound` - Channel does not exist
     /// * `DatabaseError` - Database operation failed
     async fn delete(&self, id: ChannelId) -> Result<(), ChannelError>;
+
+    /// Record a sent message in the channel's activity rollup.
+    ///
+    /// # Arguments
+    /// * `id` - Channel the message was sent to
+    /// * `user_id` - Author of the message
+    /// * `sent_at` - Message timestamp
+    /// * `language` - Detected language of the message, if any
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record_message_activity(
+        &self,
+        id: ChannelId,
+        user_id: UserId,
+        sent_at: DateTime<Utc>,
+        language: Option<LanguageCode>,
+    ) -> Result<(), ChannelError>;
+
+    /// Retrieve the activity rollup of a channel.
+    ///
+    /// # Arguments
+    /// * `id` - Channel ID to look up
+    ///
+    /// # Returns
+    /// Activity rollup, empty if no message has been sent yet
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_activity(&self, id: ChannelId) -> Result<ChannelActivity, ChannelError>;
 }
 
 /// Event publishing for channel domain events.
@@ -157,6 +338,24 @@ pub trait ChannelEventPublisher: Send + Sync + 'static {
         event: &ChannelCreatedEvent,
     ) -> Result<(), EventPublisherError>;
 
+    /// Publish channel update event.
+    ///
+    /// # Arguments
+    /// * `event` - ChannelUpdated event
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `SerializationFailed` - Event serialization failed
+    /// * `PublishFailed` - Failed to publish to broker
+    /// * `ConnectionFailed` - Broker connection failed
+    /// * `Timeout` - Publishing timed out
+    async fn publish_channel_updated(
+        &self,
+        event: &ChannelUpdatedEvent,
+    ) -> Result<(), EventPublisherError>;
+
     /// Publish user joined channel event.
     ///
     /// # Arguments
@@ -4,12 +4,20 @@ use async_trait::async_trait;
 use chrono::Utc;
 
 use super::errors::ChannelError;
+use super::events::ChannelCreatedEvent;
+use super::events::ChannelDeletedEvent;
+use super::events::ChannelUpdatedEvent;
+use super::events::UserJoinedChannelEvent;
 use super::models::Channel;
+use super::models::ChannelDirectoryEntry;
 use super::models::ChannelId;
+use super::models::ChannelStats;
 use super::models::CreateChannelCommand;
 use super::models::DirectChannel;
 use super::models::PrivateChannel;
 use super::models::PublicChannel;
+use super::models::WorkspaceId;
+use super::ports::ChannelEventPublisher;
 use super::ports::ChannelRepository;
 use super::ports::ChannelServicePort;
 use crate::domain::user::models::UserId;
@@ -17,62 +25,123 @@ use crate::domain::user::models::UserId;
 /// Concrete implementation of ChannelServicePort.
 ///
 /// Manages channel creation, retrieval, and deletion with eventual consistency.
-/// Generic over repository for testability.
-pub struct ChannelService<CR>
+/// Generic over repository and event publisher for testability.
+pub struct ChannelService<CR, EP>
 where
     CR: ChannelRepository,
+    EP: ChannelEventPublisher,
 {
     channel_repository: Arc<CR>,
+    event_publisher: Arc<EP>,
 }
 
-impl<CR> ChannelService<CR>
+impl<CR, EP> ChannelService<CR, EP>
 where
     CR: ChannelRepository,
+    EP: ChannelEventPublisher,
 {
-    pub fn new(channel_repository: Arc<CR>) -> Self {
-        Self { channel_repository }
+    /// Create a new channel service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `channel_repository` - Channel persistence implementation
+    /// * `event_publisher` - Channel event publisher implementation
+    ///
+    /// # Returns
+    /// Configured channel service instance
+    pub fn new(channel_repository: Arc<CR>, event_publisher: Arc<EP>) -> Self {
+        Self {
+            channel_repository,
+            event_publisher,
+        }
     }
-}
 
-#[async_trait]
-impl<CR> ChannelServicePort for ChannelService<CR>
-where
-    CR: ChannelRepository + 'static,
-{
-    async fn create_channel(
+    async fn create(
         &self,
+        id: ChannelId,
         command: CreateChannelCommand,
         created_by: UserId,
+        workspace_id: WorkspaceId,
     ) -> Result<Channel, ChannelError> {
         let channel = match command {
-            CreateChannelCommand::Public { name, description } => Channel::Public(PublicChannel {
-                id: ChannelId::new(),
+            CreateChannelCommand::Public {
+                name,
+                description,
+                embeddable,
+                discoverable,
+                announcement_only,
+            } => Channel::Public(PublicChannel {
+                id,
+                workspace_id,
                 name,
                 description,
                 created_by,
                 created_at: Utc::now(),
+                embeddable,
+                discoverable,
+                announcement_only,
+                auto_join: false,
             }),
             CreateChannelCommand::Private {
                 name,
                 description,
                 members,
+                announcement_only,
             } => Channel::Private(PrivateChannel {
-                id: ChannelId::new(),
+                id,
+                workspace_id,
                 name,
                 description,
                 created_by,
                 created_at: Utc::now(),
                 members,
+                announcement_only,
             }),
             CreateChannelCommand::Direct { participant_id } => Channel::Direct(DirectChannel {
-                id: ChannelId::new(),
+                id,
+                workspace_id,
                 created_by,
                 created_at: Utc::now(),
                 participants: [created_by, participant_id],
             }),
         };
 
-        self.channel_repository.create(channel).await
+        let saved_channel = self.channel_repository.create(channel).await?;
+
+        // Publish event (eventual consistency - channel already saved)
+        let event = ChannelCreatedEvent::new(&saved_channel);
+
+        if let Err(e) = self.event_publisher.publish_channel_created(&event).await {
+            tracing::error!("Failed to publish channel event: {}", e);
+        }
+
+        Ok(saved_channel)
+    }
+}
+
+#[async_trait]
+impl<CR, EP> ChannelServicePort for ChannelService<CR, EP>
+where
+    CR: ChannelRepository + 'static,
+    EP: ChannelEventPublisher + 'static,
+{
+    async fn create_channel(
+        &self,
+        command: CreateChannelCommand,
+        created_by: UserId,
+        workspace_id: WorkspaceId,
+    ) -> Result<Channel, ChannelError> {
+        self.create(ChannelId::new(), command, created_by, workspace_id)
+            .await
+    }
+
+    async fn create_channel_with_id(
+        &self,
+        id: ChannelId,
+        command: CreateChannelCommand,
+        created_by: UserId,
+        workspace_id: WorkspaceId,
+    ) -> Result<Channel, ChannelError> {
+        self.create(id, command, created_by, workspace_id).await
     }
 
     async fn get_channel(&self, id: ChannelId) -> Result<Channel, ChannelError> {
@@ -82,13 +151,80 @@ where
             .ok_or(ChannelError::NotFound(id))
     }
 
+    async fn get_channel_stats(&self, channel: &Channel) -> Result<ChannelStats, ChannelError> {
+        let activity = self.channel_repository.find_activity(channel.id()).await?;
+        Ok(ChannelStats::new(channel, activity))
+    }
+
     async fn list_public_channels(&self) -> Result<Vec<Channel>, ChannelError> {
         self.channel_repository.find_public_channels().await
     }
 
+    async fn list_channel_directory(&self) -> Result<Vec<ChannelDirectoryEntry>, ChannelError> {
+        self.channel_repository.find_directory_entries().await
+    }
+
     async fn list_user_channels(&self, user_id: UserId) -> Result<Vec<Channel>, ChannelError> {
         self.channel_repository.find_by_user(user_id).await
     }
+
+    async fn set_auto_join(&self, id: ChannelId, auto_join: bool) -> Result<Channel, ChannelError> {
+        let mut channel = self.get_channel(id).await?;
+        let Channel::Public(public) = &mut channel else {
+            return Err(ChannelError::NotPublic(id));
+        };
+
+        self.channel_repository.set_auto_join(id, auto_join).await?;
+        public.auto_join = auto_join;
+
+        let event = ChannelUpdatedEvent::new(&channel);
+
+        if let Err(e) = self.event_publisher.publish_channel_updated(&event).await {
+            tracing::error!("Failed to publish channel event: {}", e);
+        }
+
+        Ok(channel)
+    }
+
+    async fn join_default_channels(
+        &self,
+        user_id: UserId,
+        workspace_id: WorkspaceId,
+    ) -> Result<Vec<ChannelId>, ChannelError> {
+        let joined = self
+            .channel_repository
+            .add_to_auto_join_channels(user_id, workspace_id, Utc::now())
+            .await?;
+
+        // Only new memberships are announced, so redelivered events stay silent
+        for channel_id in &joined {
+            let event = UserJoinedChannelEvent::new(*channel_id, user_id);
+
+            if let Err(e) = self
+                .event_publisher
+                .publish_user_joined_channel(&event)
+                .await
+            {
+                tracing::error!("Failed to publish channel event: {}", e);
+            }
+        }
+
+        Ok(joined)
+    }
+
+    async fn delete_channel(&self, id: ChannelId) -> Result<(), ChannelError> {
+        let channel = self.get_channel(id).await?;
+        self.channel_repository.delete(channel.id()).await?;
+
+        // Publish event (eventual consistency - channel already deleted)
+        let event = ChannelDeletedEvent::new(channel.id());
+
+        if let Err(e) = self.event_publisher.publish_channel_deleted(&event).await {
+            tracing::error!("Failed to publish channel event: {}", e);
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -96,8 +232,17 @@ mod tests {
     use async_trait::async_trait;
     use mockall::mock;
     use mockall::predicate::*;
+    use uuid::Uuid;
 
     use super::*;
+    use crate::domain::channel::errors::ChannelIdError;
+    use crate::domain::channel::events::UserLeftChannelEvent;
+    use crate::domain::channel::models::ChannelActivity;
+    use crate::domain::channel::models::LanguageCount;
+    use crate::domain::errors::EventPublisherError;
+    use crate::domain::message::models::LanguageCode;
+    use crate::fixtures::user_id;
+    use crate::fixtures::ChannelFixture;
     use crate::ChannelName;
 
     mock! {
@@ -108,8 +253,37 @@ mod tests {
             async fn create(&self, channel: Channel) -> Result<Channel, ChannelError>;
             async fn find_by_id(&self, id: ChannelId) -> Result<Option<Channel>, ChannelError>;
             async fn find_public_channels(&self) -> Result<Vec<Channel>, ChannelError>;
+            async fn find_directory_entries(&self) -> Result<Vec<ChannelDirectoryEntry>, ChannelError>;
             async fn find_by_user(&self, user_id: UserId) -> Result<Vec<Channel>, ChannelError>;
+            async fn set_auto_join(&self, id: ChannelId, auto_join: bool) -> Result<(), ChannelError>;
+            async fn add_to_auto_join_channels(
+                &self,
+                user_id: UserId,
+                workspace_id: WorkspaceId,
+                joined_at: chrono::DateTime<Utc>,
+            ) -> Result<Vec<ChannelId>, ChannelError>;
             async fn delete(&self, id: ChannelId) -> Result<(), ChannelError>;
+            async fn record_message_activity(
+                &self,
+                id: ChannelId,
+                user_id: UserId,
+                sent_at: chrono::DateTime<Utc>,
+                language: Option<LanguageCode>,
+            ) -> Result<(), ChannelError>;
+            async fn find_activity(&self, id: ChannelId) -> Result<ChannelActivity, ChannelError>;
+        }
+    }
+
+    mock! {
+        pub TestChannelEventPublisher {}
+
+        #[async_trait]
+        impl ChannelEventPublisher for TestChannelEventPublisher {
+            async fn publish_channel_created(&self, event: &ChannelCreatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_channel_updated(&self, event: &ChannelUpdatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_joined_channel(&self, event: &UserJoinedChannelEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_left_channel(&self, event: &UserLeftChannelEvent) -> Result<(), EventPublisherError>;
+            async fn publish_channel_deleted(&self, event: &ChannelDeletedEvent) -> Result<(), EventPublisherError>;
         }
     }
 
@@ -118,6 +292,7 @@ mod tests {
         let mut channel_repository = MockTestChannelRepository::new();
 
         let creator_id = UserId::new();
+        let workspace_id = WorkspaceId(Uuid::from_u128(7));
 
         channel_repository
             .expect_create()
@@ -125,18 +300,34 @@ mod tests {
                 matches!(channel, Channel::Public(_))
                     && channel.name().unwrap().as_str() == "general"
                     && channel.created_by() == creator_id
+                    && channel.workspace_id() == workspace_id
+            })
+            .times(1)
+            .returning(Ok);
+
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+        event_publisher
+            .expect_publish_channel_created()
+            .withf(move |event| {
+                event.channel_type == "public"
+                    && event.name.as_deref() == Some("general")
+                    && event.description.as_deref() == Some("General discussion")
+                    && event.created_by == creator_id
             })
             .times(1)
-            .returning(|channel| Ok(channel));
+            .returning(|_| Ok(()));
 
-        let service = ChannelService::new(Arc::new(channel_repository));
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
 
         let req = CreateChannelCommand::Public {
             name: ChannelName::new("general".to_string()).unwrap(),
             description: Some("General discussion".to_string()),
+            embeddable: false,
+            discoverable: false,
+            announcement_only: false,
         };
 
-        let result = service.create_channel(req, creator_id).await;
+        let result = service.create_channel(req, creator_id, workspace_id).await;
         assert!(result.is_ok());
 
         let channel = result.unwrap();
@@ -161,17 +352,26 @@ mod tests {
                     && channel.created_by() == creator_id
             })
             .times(1)
-            .returning(|channel| Ok(channel));
+            .returning(Ok);
 
-        let service = ChannelService::new(Arc::new(channel_repository));
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+        event_publisher
+            .expect_publish_channel_created()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
 
         let req = CreateChannelCommand::Private {
             name: ChannelName::new("private-team".to_string()).unwrap(),
             description: Some("Team channel".to_string()),
             members: vec![member1_id, member2_id],
+            announcement_only: false,
         };
 
-        let result = service.create_channel(req, creator_id).await;
+        let result = service
+            .create_channel(req, creator_id, WorkspaceId::default())
+            .await;
         assert!(result.is_ok());
 
         let channel = result.unwrap();
@@ -192,15 +392,23 @@ mod tests {
                 matches!(channel, Channel::Direct(_)) && channel.created_by() == user1_id
             })
             .times(1)
-            .returning(|channel| Ok(channel));
+            .returning(Ok);
+
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+        event_publisher
+            .expect_publish_channel_created()
+            .times(1)
+            .returning(|_| Ok(()));
 
-        let service = ChannelService::new(Arc::new(channel_repository));
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
 
         let req = CreateChannelCommand::Direct {
             participant_id: user2_id,
         };
 
-        let result = service.create_channel(req, user1_id).await;
+        let result = service
+            .create_channel(req, user1_id, WorkspaceId::default())
+            .await;
         assert!(result.is_ok());
 
         let channel = result.unwrap();
@@ -215,13 +423,10 @@ mod tests {
         let creator_id = UserId::new();
         let channel_id = ChannelId::new();
 
-        let expected_channel = Channel::Public(PublicChannel {
-            id: channel_id,
-            name: ChannelName::new("general".to_string()).unwrap(),
-            description: None,
-            created_by: creator_id,
-            created_at: Utc::now(),
-        });
+        let expected_channel = ChannelFixture::public("general")
+            .with_id(channel_id)
+            .created_by(creator_id)
+            .build();
 
         let returned_channel = expected_channel.clone();
         channel_repository
@@ -230,7 +435,8 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(Some(returned_channel.clone())));
 
-        let service = ChannelService::new(Arc::new(channel_repository));
+        let event_publisher = MockTestChannelEventPublisher::new();
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
 
         let result = service.get_channel(channel_id).await;
         assert!(result.is_ok());
@@ -250,7 +456,8 @@ mod tests {
             .times(1)
             .returning(|_| Ok(None));
 
-        let service = ChannelService::new(Arc::new(channel_repository));
+        let event_publisher = MockTestChannelEventPublisher::new();
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
 
         let result = service.get_channel(non_existent_id).await;
 
@@ -258,6 +465,61 @@ mod tests {
         assert!(matches!(result.unwrap_err(), ChannelError::NotFound(_)));
     }
 
+    #[tokio::test]
+    async fn test_get_channel_stats_counts_posters() {
+        let mut channel_repository = MockTestChannelRepository::new();
+
+        let channel = ChannelFixture::public("general").build();
+        let channel_id = channel.id();
+        let last_activity_at = crate::fixtures::epoch();
+
+        channel_repository
+            .expect_find_activity()
+            .withf(move |id| *id == channel_id)
+            .times(1)
+            .returning(move |_| {
+                Ok(ChannelActivity {
+                    message_count: 42,
+                    participant_count: 3,
+                    last_activity_at: Some(last_activity_at),
+                    languages: vec![LanguageCount {
+                        language: LanguageCode::new("en").unwrap(),
+                        message_count: 40,
+                    }],
+                })
+            });
+
+        let event_publisher = MockTestChannelEventPublisher::new();
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        let stats = service.get_channel_stats(&channel).await.unwrap();
+        assert_eq!(stats.message_count, 42);
+        assert_eq!(stats.member_count, 3);
+        assert_eq!(stats.last_activity_at, Some(last_activity_at));
+        assert_eq!(stats.languages[0].language.as_str(), "en");
+        assert_eq!(stats.languages[0].message_count, 40);
+    }
+
+    #[tokio::test]
+    async fn test_get_channel_stats_direct_channel_without_messages() {
+        let mut channel_repository = MockTestChannelRepository::new();
+
+        let channel = ChannelFixture::direct(user_id(1), user_id(2)).build();
+
+        channel_repository
+            .expect_find_activity()
+            .times(1)
+            .returning(|_| Ok(ChannelActivity::default()));
+
+        let event_publisher = MockTestChannelEventPublisher::new();
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        let stats = service.get_channel_stats(&channel).await.unwrap();
+        assert_eq!(stats.message_count, 0);
+        assert_eq!(stats.member_count, 2);
+        assert!(stats.last_activity_at.is_none());
+    }
+
     #[tokio::test]
     async fn test_list_public_channels() {
         let mut channel_repository = MockTestChannelRepository::new();
@@ -265,27 +527,15 @@ mod tests {
         let creator_id = UserId::new();
 
         let expected_channels = vec![
-            Channel::Public(PublicChannel {
-                id: ChannelId::new(),
-                name: ChannelName::new("channel1".to_string()).unwrap(),
-                description: None,
-                created_by: creator_id,
-                created_at: Utc::now(),
-            }),
-            Channel::Public(PublicChannel {
-                id: ChannelId::new(),
-                name: ChannelName::new("channel2".to_string()).unwrap(),
-                description: None,
-                created_by: creator_id,
-                created_at: Utc::now(),
-            }),
-            Channel::Public(PublicChannel {
-                id: ChannelId::new(),
-                name: ChannelName::new("channel3".to_string()).unwrap(),
-                description: None,
-                created_by: creator_id,
-                created_at: Utc::now(),
-            }),
+            ChannelFixture::public("channel1")
+                .created_by(creator_id)
+                .build(),
+            ChannelFixture::public("channel2")
+                .created_by(creator_id)
+                .build(),
+            ChannelFixture::public("channel3")
+                .created_by(creator_id)
+                .build(),
         ];
 
         let returned_channels = expected_channels.clone();
@@ -294,7 +544,8 @@ mod tests {
             .times(1)
             .returning(move || Ok(returned_channels.clone()));
 
-        let service = ChannelService::new(Arc::new(channel_repository));
+        let event_publisher = MockTestChannelEventPublisher::new();
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
 
         let result = service.list_public_channels().await;
         assert!(result.is_ok());
@@ -304,6 +555,37 @@ mod tests {
         assert!(channels.iter().all(|c| matches!(c, Channel::Public(_))));
     }
 
+    #[tokio::test]
+    async fn test_create_public_channel_keeps_visibility_flags() {
+        let mut channel_repository = MockTestChannelRepository::new();
+        channel_repository
+            .expect_create()
+            .withf(|channel| channel.is_discoverable() && !channel.is_embeddable())
+            .times(1)
+            .returning(Ok);
+
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+        event_publisher
+            .expect_publish_channel_created()
+            .returning(|_| Ok(()));
+
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        let command = CreateChannelCommand::Public {
+            name: ChannelName::new("directory".to_string()).unwrap(),
+            description: None,
+            embeddable: false,
+            discoverable: true,
+            announcement_only: false,
+        };
+        let channel = service
+            .create_channel(command, user_id(1), WorkspaceId::default())
+            .await
+            .unwrap();
+
+        assert!(channel.is_discoverable());
+    }
+
     #[tokio::test]
     async fn test_list_user_channels() {
         let mut channel_repository = MockTestChannelRepository::new();
@@ -312,19 +594,10 @@ mod tests {
         let user2_id = UserId::new();
 
         let expected_channels = vec![
-            Channel::Public(PublicChannel {
-                id: ChannelId::new(),
-                name: ChannelName::new("user1-public".to_string()).unwrap(),
-                description: None,
-                created_by: user1_id,
-                created_at: Utc::now(),
-            }),
-            Channel::Direct(DirectChannel {
-                id: ChannelId::new(),
-                created_by: user1_id,
-                created_at: Utc::now(),
-                participants: [user1_id, user2_id],
-            }),
+            ChannelFixture::public("user1-public")
+                .created_by(user1_id)
+                .build(),
+            ChannelFixture::direct(user1_id, user2_id).build(),
         ];
 
         let returned_channels = expected_channels.clone();
@@ -334,7 +607,8 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(returned_channels.clone()));
 
-        let service = ChannelService::new(Arc::new(channel_repository));
+        let event_publisher = MockTestChannelEventPublisher::new();
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
 
         let result = service.list_user_channels(user1_id).await;
         assert!(result.is_ok());
@@ -343,6 +617,48 @@ mod tests {
         assert_eq!(channels.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_create_channel_with_supplied_id() {
+        let id = ChannelId(Uuid::now_v7());
+
+        let mut channel_repository = MockTestChannelRepository::new();
+        channel_repository
+            .expect_create()
+            .withf(move |channel| channel.id() == id)
+            .times(1)
+            .returning(Ok);
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+        event_publisher
+            .expect_publish_channel_created()
+            .withf(move |event| event.channel_id == id)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+        let command = CreateChannelCommand::Public {
+            name: ChannelName::new("imported".to_string()).unwrap(),
+            description: None,
+            embeddable: false,
+            discoverable: false,
+            announcement_only: false,
+        };
+        let channel = service
+            .create_channel_with_id(id, command, user_id(1), WorkspaceId::default())
+            .await
+            .unwrap();
+
+        assert_eq!(channel.id(), id);
+    }
+
+    #[test]
+    fn test_supplied_channel_id_must_be_time_ordered() {
+        assert!(ChannelId::from_supplied(&Uuid::now_v7().to_string()).is_ok());
+        assert!(matches!(
+            ChannelId::from_supplied(&Uuid::new_v4().to_string()),
+            Err(ChannelIdError::UnsupportedVersion(4))
+        ));
+    }
+
     #[tokio::test]
     async fn test_create_channel_invalid_name() {
         let mut channel_repository = MockTestChannelRepository::new();
@@ -352,19 +668,253 @@ mod tests {
         let invalid_name = ChannelName::new("".to_string());
         assert!(invalid_name.is_err(), "Empty channel name should fail");
 
-        channel_repository
-            .expect_create()
+        channel_repository.expect_create().times(1).returning(Ok);
+
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+        event_publisher
+            .expect_publish_channel_created()
             .times(1)
-            .returning(|channel| Ok(channel));
+            .returning(|_| Ok(()));
 
-        let service = ChannelService::new(Arc::new(channel_repository));
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
 
         let valid_name = ChannelName::new("valid-channel".to_string()).unwrap();
         let cmd = CreateChannelCommand::Public {
             name: valid_name,
             description: None,
+            embeddable: false,
+            discoverable: false,
+            announcement_only: false,
         };
-        let result = service.create_channel(cmd, creator_id).await;
+        let result = service
+            .create_channel(cmd, creator_id, WorkspaceId::default())
+            .await;
         assert!(result.is_ok(), "Valid channel name should succeed");
     }
+
+    #[tokio::test]
+    async fn test_create_channel_publishes_saved_channel() {
+        let mut channel_repository = MockTestChannelRepository::new();
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+
+        let creator_id = user_id(1);
+
+        channel_repository.expect_create().times(1).returning(Ok);
+
+        let published = Arc::new(std::sync::Mutex::new(None));
+        let captured = published.clone();
+        event_publisher
+            .expect_publish_channel_created()
+            .times(1)
+            .returning(move |event| {
+                *captured.lock().unwrap() = Some(event.clone());
+                Ok(())
+            });
+
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        let cmd = CreateChannelCommand::Direct {
+            participant_id: user_id(2),
+        };
+        let channel = service
+            .create_channel(cmd, creator_id, WorkspaceId::default())
+            .await
+            .unwrap();
+
+        let event = published.lock().unwrap().take().expect("event published");
+        assert_eq!(event.channel_id, channel.id());
+        assert_eq!(event.channel_type, "direct");
+        assert_eq!(event.created_by, creator_id);
+        assert!(event.name.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_create_channel_succeeds_when_publish_fails() {
+        let mut channel_repository = MockTestChannelRepository::new();
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+
+        channel_repository.expect_create().times(1).returning(Ok);
+
+        event_publisher
+            .expect_publish_channel_created()
+            .times(1)
+            .returning(|_| {
+                Err(EventPublisherError::PublishFailed(
+                    "broker down".to_string(),
+                ))
+            });
+
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        let cmd = CreateChannelCommand::Public {
+            name: ChannelName::new("general".to_string()).unwrap(),
+            description: None,
+            embeddable: false,
+            discoverable: false,
+            announcement_only: false,
+        };
+        let result = service
+            .create_channel(cmd, user_id(1), WorkspaceId::default())
+            .await;
+
+        assert!(result.is_ok(), "Channel is saved even if the event is lost");
+    }
+
+    #[tokio::test]
+    async fn test_create_channel_does_not_publish_on_repository_error() {
+        let mut channel_repository = MockTestChannelRepository::new();
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+
+        channel_repository
+            .expect_create()
+            .times(1)
+            .returning(|channel| {
+                Err(ChannelError::NameAlreadyExists(
+                    channel.name().unwrap().as_str().to_string(),
+                ))
+            });
+
+        event_publisher.expect_publish_channel_created().times(0);
+
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        let cmd = CreateChannelCommand::Public {
+            name: ChannelName::new("general".to_string()).unwrap(),
+            description: None,
+            embeddable: false,
+            discoverable: false,
+            announcement_only: false,
+        };
+        let result = service
+            .create_channel(cmd, user_id(1), WorkspaceId::default())
+            .await;
+
+        assert!(matches!(result, Err(ChannelError::NameAlreadyExists(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_channel_publishes_event() {
+        let mut channel_repository = MockTestChannelRepository::new();
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+
+        let channel = ChannelFixture::public("general").build();
+        let channel_id = channel.id();
+
+        channel_repository
+            .expect_find_by_id()
+            .with(eq(channel_id))
+            .times(1)
+            .returning(move |_| Ok(Some(channel.clone())));
+
+        channel_repository
+            .expect_delete()
+            .with(eq(channel_id))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        event_publisher
+            .expect_publish_channel_deleted()
+            .withf(move |event| event.channel_id == channel_id)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        assert!(service.delete_channel(channel_id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_channel_not_found() {
+        let mut channel_repository = MockTestChannelRepository::new();
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+
+        channel_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(|_| Ok(None));
+        channel_repository.expect_delete().times(0);
+        event_publisher.expect_publish_channel_deleted().times(0);
+
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        let result = service.delete_channel(ChannelId::new()).await;
+        assert!(matches!(result, Err(ChannelError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_set_auto_join_marks_public_channel() {
+        let mut channel_repository = MockTestChannelRepository::new();
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+
+        let channel = ChannelFixture::public("general").build();
+        let channel_id = channel.id();
+
+        channel_repository
+            .expect_find_by_id()
+            .with(eq(channel_id))
+            .times(1)
+            .returning(move |_| Ok(Some(channel.clone())));
+        channel_repository
+            .expect_set_auto_join()
+            .with(eq(channel_id), eq(true))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        event_publisher
+            .expect_publish_channel_updated()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        let channel = service.set_auto_join(channel_id, true).await.unwrap();
+        assert!(channel.is_auto_join());
+    }
+
+    #[tokio::test]
+    async fn test_set_auto_join_rejects_private_channel() {
+        let mut channel_repository = MockTestChannelRepository::new();
+
+        let channel = ChannelFixture::private("team").build();
+        let channel_id = channel.id();
+
+        channel_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(channel.clone())));
+        channel_repository.expect_set_auto_join().times(0);
+
+        let event_publisher = MockTestChannelEventPublisher::new();
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        let result = service.set_auto_join(channel_id, true).await;
+        assert!(matches!(result, Err(ChannelError::NotPublic(id)) if id == channel_id));
+    }
+
+    #[tokio::test]
+    async fn test_join_default_channels_announces_new_memberships() {
+        let mut channel_repository = MockTestChannelRepository::new();
+        let mut event_publisher = MockTestChannelEventPublisher::new();
+
+        let general = ChannelFixture::public("general").build().id();
+        let workspace_id = WorkspaceId::default();
+
+        channel_repository
+            .expect_add_to_auto_join_channels()
+            .withf(move |user, workspace, _| *user == user_id(2) && *workspace == workspace_id)
+            .times(1)
+            .returning(move |_, _, _| Ok(vec![general]));
+        event_publisher
+            .expect_publish_user_joined_channel()
+            .withf(move |event| event.channel_id == general && event.user_id == user_id(2))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = ChannelService::new(Arc::new(channel_repository), Arc::new(event_publisher));
+
+        let joined = service
+            .join_default_channels(user_id(2), workspace_id)
+            .await
+            .unwrap();
+        assert_eq!(joined, vec![general]);
+    }
 }
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// Error for crash reporting failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum CrashReportError {
+    #[error("Invalid crash reporting DSN: {0}")]
+    InvalidDsn(String),
+
+    #[error("Failed to deliver crash report: {0}")]
+    DeliveryFailed(String),
+}
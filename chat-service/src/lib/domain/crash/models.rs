@@ -0,0 +1,55 @@
+use std::any::Any;
+
+use chrono::DateTime;
+use chrono::Utc;
+use uuid::Uuid;
+
+/// A panic of the service, as logged and reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crash {
+    pub id: Uuid,
+    pub message: String,
+    /// Source location of the panic, as `file:line:column`
+    pub location: Option<String>,
+    /// Request being served when the panic happened
+    pub request_id: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl Crash {
+    /// Record a panic happening now.
+    ///
+    /// # Arguments
+    /// * `payload` - Value the panic was started with
+    /// * `location` - Source location of the panic
+    /// * `request_id` - Request being served, if any
+    ///
+    /// # Returns
+    /// Crash with a new ID
+    pub fn new(
+        payload: &(dyn Any + Send),
+        location: Option<String>,
+        request_id: Option<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            message: panic_message(payload),
+            location,
+            request_id,
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+/// Get the message of a panic payload.
+///
+/// `panic!` payloads are `&str` or `String`; anything else has no message.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
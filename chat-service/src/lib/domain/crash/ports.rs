@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use super::errors::CrashReportError;
+use super::models::Crash;
+
+/// Port for sending panics to a crash reporting service.
+#[async_trait]
+pub trait CrashReporter: Send + Sync + 'static {
+    /// Report a panic.
+    ///
+    /// # Arguments
+    /// * `crash` - Panic to report
+    ///
+    /// # Returns
+    /// Unit once the report was accepted
+    ///
+    /// # Errors
+    /// * `DeliveryFailed` - Reporting service unreachable or refused the report
+    async fn report(&self, crash: &Crash) -> Result<(), CrashReportError>;
+}
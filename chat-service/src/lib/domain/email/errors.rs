@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+use crate::domain::message::errors::MessageError;
+
+/// Top-level error for emails posted to channels
+#[derive(Debug, Error)]
+pub enum EmailError {
+    #[error("No recipient is a channel address")]
+    NoChannelAddress,
+
+    #[error("Attachment storage error: {0}")]
+    StorageError(String),
+
+    #[error("Failed to post email: {0}")]
+    Message(#[from] MessageError),
+}
@@ -0,0 +1,44 @@
+use crate::domain::message::models::Message;
+use crate::domain::user::models::UserId;
+
+/// Email received by the inbound gateway.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InboundEmail {
+    /// Envelope recipients, plain or as `Name <address>`
+    pub recipients: Vec<String>,
+    pub from: String,
+    pub subject: Option<String>,
+    /// Plain-text body
+    pub text: String,
+    pub attachments: Vec<EmailAttachment>,
+}
+
+/// File attached to an inbound email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailAttachment {
+    pub filename: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+/// Configuration of the email gateway.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailGatewaySettings {
+    /// Domain of the channel addresses, e.g. `in.chat.example.com`
+    pub domain: String,
+    /// Key of the tags that make channel addresses unguessable
+    pub address_secret: String,
+    /// User emails are posted as
+    pub poster_id: UserId,
+    /// Larger attachments are listed but not stored
+    pub max_attachment_bytes: usize,
+}
+
+/// Outcome of an email sent to channel addresses.
+#[derive(Debug, Clone)]
+pub struct EmailDelivery {
+    /// Messages posted, one per channel addressed
+    pub messages: Vec<Message>,
+    /// Object storage keys of the stored attachments
+    pub attachment_keys: Vec<String>,
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use super::errors::EmailError;
+use super::models::EmailDelivery;
+use super::models::InboundEmail;
+use crate::domain::channel::models::ChannelId;
+
+/// Port for posting emails to channels.
+#[async_trait]
+pub trait EmailGatewayServicePort: Send + Sync + 'static {
+    /// Email address posting to a channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel the address posts to
+    ///
+    /// # Returns
+    /// Address carrying the channel ID and a tag only the gateway can compute
+    fn channel_address(&self, channel_id: ChannelId) -> String;
+
+    /// Post an email to every channel it is addressed to.
+    ///
+    /// Attachments are stored once and listed in each message. Recipients
+    /// that are not valid channel addresses are ignored.
+    ///
+    /// # Arguments
+    /// * `email` - Email received from the mail provider
+    ///
+    /// # Returns
+    /// Posted messages and the keys of the stored attachments
+    ///
+    /// # Errors
+    /// * `NoChannelAddress` - No recipient is a valid channel address
+    /// * `StorageError` - An attachment could not be stored
+    /// * `Message` - No channel accepted the message
+    async fn receive(&self, email: InboundEmail) -> Result<EmailDelivery, EmailError>;
+}
@@ -0,0 +1,452 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use hmac::Hmac;
+use hmac::Mac;
+use sha2::Sha256;
+use uuid::Uuid;
+
+use super::errors::EmailError;
+use super::models::EmailDelivery;
+use super::models::EmailGatewaySettings;
+use super::models::InboundEmail;
+use super::ports::EmailGatewayServicePort;
+use crate::domain::backup::ports::ObjectStorage;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageContent;
+use crate::domain::message::models::Sender;
+use crate::domain::message::ports::MessageServicePort;
+
+/// Bytes of the HMAC kept in channel addresses, 48 bits against guessing.
+const TAG_BYTES: usize = 6;
+
+/// Domain service posting inbound emails to channels.
+///
+/// Channel addresses read `<channel id>.<tag>@<domain>`, where the tag is a
+/// truncated HMAC of the channel ID. Knowing a channel ID is therefore not
+/// enough to post to it, and addresses cannot be forged without the secret.
+pub struct EmailGatewayService<MS, OS>
+where
+    MS: MessageServicePort,
+    OS: ObjectStorage,
+{
+    message_service: Arc<MS>,
+    storage: Arc<OS>,
+    settings: EmailGatewaySettings,
+}
+
+impl<MS, OS> EmailGatewayService<MS, OS>
+where
+    MS: MessageServicePort,
+    OS: ObjectStorage,
+{
+    /// Create a new email gateway service.
+    ///
+    /// # Arguments
+    /// * `message_service` - Message service posting the emails
+    /// * `storage` - Object storage keeping attachments
+    /// * `settings` - Address domain, tag secret, poster and attachment limit
+    ///
+    /// # Returns
+    /// Configured email gateway service instance
+    pub fn new(message_service: Arc<MS>, storage: Arc<OS>, settings: EmailGatewaySettings) -> Self {
+        Self {
+            message_service,
+            storage,
+            settings,
+        }
+    }
+
+    fn tag(&self, channel_id: ChannelId) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.settings.address_secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(channel_id.to_string().as_bytes());
+
+        mac.finalize().into_bytes()[..TAG_BYTES]
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    /// Channel a recipient addresses, None unless its domain and tag match.
+    fn channel_of(&self, recipient: &str) -> Option<ChannelId> {
+        let (local, domain) = address_of(recipient).rsplit_once('@')?;
+        if !domain.eq_ignore_ascii_case(&self.settings.domain) {
+            return None;
+        }
+
+        let (id, tag) = local.rsplit_once('.')?;
+        let channel_id = ChannelId::from_string(id).ok()?;
+
+        constant_time_eq(
+            tag.to_ascii_lowercase().as_bytes(),
+            self.tag(channel_id).as_bytes(),
+        )
+        .then_some(channel_id)
+    }
+
+    /// Store the attachments within the size limit.
+    ///
+    /// # Returns
+    /// One line per attachment for the message, and the keys of the stored ones
+    async fn store_attachments(
+        &self,
+        email: &InboundEmail,
+    ) -> Result<(Vec<String>, Vec<String>), EmailError> {
+        let email_id = Uuid::new_v4();
+        let mut lines = Vec::with_capacity(email.attachments.len());
+        let mut keys = Vec::new();
+
+        for (index, attachment) in email.attachments.iter().enumerate() {
+            let size = attachment.data.len();
+            if size > self.settings.max_attachment_bytes {
+                lines.push(format!(
+                    "- {} ({} bytes, not stored: larger than {} bytes)",
+                    attachment.filename, size, self.settings.max_attachment_bytes
+                ));
+                continue;
+            }
+
+            let key = format!(
+                "email/{}/{}-{}",
+                email_id,
+                index,
+                sanitize_filename(&attachment.filename)
+            );
+            self.storage
+                .put(&key, attachment.data.clone())
+                .await
+                .map_err(|e| EmailError::StorageError(e.to_string()))?;
+
+            lines.push(format!(
+                "- {} ({} bytes, {}): {}",
+                attachment.filename, size, attachment.content_type, key
+            ));
+            keys.push(key);
+        }
+
+        Ok((lines, keys))
+    }
+}
+
+#[async_trait]
+impl<MS, OS> EmailGatewayServicePort for EmailGatewayService<MS, OS>
+where
+    MS: MessageServicePort,
+    OS: ObjectStorage,
+{
+    fn channel_address(&self, channel_id: ChannelId) -> String {
+        format!(
+            "{}.{}@{}",
+            channel_id,
+            self.tag(channel_id),
+            self.settings.domain
+        )
+    }
+
+    async fn receive(&self, email: InboundEmail) -> Result<EmailDelivery, EmailError> {
+        let mut channel_ids = Vec::new();
+        for channel_id in email
+            .recipients
+            .iter()
+            .filter_map(|recipient| self.channel_of(recipient))
+        {
+            if !channel_ids.contains(&channel_id) {
+                channel_ids.push(channel_id);
+            }
+        }
+        if channel_ids.is_empty() {
+            return Err(EmailError::NoChannelAddress);
+        }
+
+        let (attachment_lines, attachment_keys) = self.store_attachments(&email).await?;
+
+        let mut content = format!(
+            "Email from {}: {}",
+            email.from,
+            email.subject.as_deref().unwrap_or("(no subject)")
+        );
+        if !attachment_lines.is_empty() {
+            content.push_str("\n\nAttachments:\n");
+            content.push_str(&attachment_lines.join("\n"));
+        }
+        let text = email.text.trim();
+        if !text.is_empty() {
+            content.push_str("\n\n");
+            content.push_str(text);
+        }
+        let content =
+            MessageContent::new(truncate(content)).map_err(|e| EmailError::Message(e.into()))?;
+
+        let sender = Sender::member(self.settings.poster_id);
+        let mut messages = Vec::with_capacity(channel_ids.len());
+        let mut first_error = None;
+
+        for channel_id in channel_ids {
+            match self
+                .message_service
+                .send_message(channel_id, sender, content.clone())
+                .await
+            {
+                Ok(message) => messages.push(message),
+                Err(e) => {
+                    tracing::warn!("Failed to post email to channel {}: {}", channel_id, e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) if messages.is_empty() => Err(EmailError::Message(e)),
+            _ => Ok(EmailDelivery {
+                messages,
+                attachment_keys,
+            }),
+        }
+    }
+}
+
+/// Bare address of a recipient written `Name <address>`.
+fn address_of(recipient: &str) -> &str {
+    let address = match (recipient.rfind('<'), recipient.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &recipient[start + 1..end],
+        _ => recipient,
+    };
+    address.trim()
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Filename reduced to a single safe path component.
+fn sanitize_filename(filename: &str) -> String {
+    let sanitized: String = filename
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let sanitized = sanitized.trim_start_matches('.');
+
+    if sanitized.is_empty() {
+        "attachment".to_string()
+    } else {
+        sanitized.to_string()
+    }
+}
+
+/// Cut content to the longest message, on a character boundary.
+fn truncate(mut content: String) -> String {
+    if content.len() > MessageContent::MAX_LENGTH {
+        let mut end = MessageContent::MAX_LENGTH;
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
+        content.truncate(end);
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use chrono::Utc;
+    use mockall::mock;
+    use mockall::predicate::*;
+
+    use super::*;
+    use crate::domain::backup::errors::BackupError;
+    use crate::domain::email::models::EmailAttachment;
+    use crate::domain::message::errors::MessageError;
+    use crate::domain::message::models::HistoryEntry;
+    use crate::domain::message::models::Message;
+    use crate::domain::message::models::MessageId;
+    use crate::domain::message::models::MessageTombstone;
+    use crate::domain::message::ports::DeliveryReporter;
+    use crate::domain::user::models::UserId;
+
+    mock! {
+        pub TestMessageService {}
+
+        #[async_trait]
+        impl MessageServicePort for TestMessageService {
+            async fn send_message(
+                &self,
+                channel_id: ChannelId,
+                sender: Sender,
+                content: MessageContent,
+            ) -> Result<Message, MessageError>;
+            async fn send_message_with_id(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+                sender: Sender,
+                content: MessageContent,
+            ) -> Result<Message, MessageError>;
+            async fn send_message_reporting(
+                &self,
+                channel_id: ChannelId,
+                message_id: Option<MessageId>,
+                sender: Sender,
+                content: MessageContent,
+                reporter: Arc<dyn DeliveryReporter>,
+            ) -> Result<Message, MessageError>;
+            async fn get_channel_messages(
+                &self,
+                channel_id: ChannelId,
+                limit: i32,
+                before: Option<DateTime<Utc>>,
+            ) -> Result<Vec<HistoryEntry>, MessageError>;
+            async fn delete_message(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+                deleted_by: UserId,
+            ) -> Result<MessageTombstone, MessageError>;
+        }
+    }
+
+    mock! {
+        pub TestStorage {}
+
+        #[async_trait]
+        impl ObjectStorage for TestStorage {
+            async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), BackupError>;
+            async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BackupError>;
+        }
+    }
+
+    fn settings() -> EmailGatewaySettings {
+        EmailGatewaySettings {
+            domain: "in.chat.example.com".to_string(),
+            address_secret: "address-secret".to_string(),
+            poster_id: UserId::new(),
+            max_attachment_bytes: 16,
+        }
+    }
+
+    fn service(
+        message_service: MockTestMessageService,
+        storage: MockTestStorage,
+    ) -> EmailGatewayService<MockTestMessageService, MockTestStorage> {
+        EmailGatewayService::new(Arc::new(message_service), Arc::new(storage), settings())
+    }
+
+    fn email(recipients: Vec<String>) -> InboundEmail {
+        InboundEmail {
+            recipients,
+            from: "alice@example.com".to_string(),
+            subject: Some("Weekly report".to_string()),
+            text: "Numbers are up.".to_string(),
+            attachments: vec![],
+        }
+    }
+
+    fn posted(channel_id: ChannelId, sender: Sender, content: MessageContent) -> Message {
+        Message {
+            id: MessageId::new_time_based(),
+            channel_id,
+            user_id: sender.user_id,
+            content,
+            timestamp: Utc::now(),
+            language: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_email_to_channel_address_is_posted() {
+        let channel_id = ChannelId::new();
+        let mut message_service = MockTestMessageService::new();
+        message_service
+            .expect_send_message()
+            .with(eq(channel_id), always(), always())
+            .times(1)
+            .returning(|channel_id, sender, content| Ok(posted(channel_id, sender, content)));
+
+        let service = service(message_service, MockTestStorage::new());
+        let address = service.channel_address(channel_id);
+
+        let delivery = service
+            .receive(email(vec![format!("Team <{}>", address.to_uppercase())]))
+            .await
+            .unwrap();
+
+        assert_eq!(delivery.messages.len(), 1);
+        assert_eq!(
+            delivery.messages[0].content.as_str(),
+            "Email from alice@example.com: Weekly report\n\nNumbers are up."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forged_tag_is_ignored() {
+        let channel_id = ChannelId::new();
+        let mut message_service = MockTestMessageService::new();
+        message_service.expect_send_message().never();
+
+        let service = service(message_service, MockTestStorage::new());
+
+        let result = service
+            .receive(email(vec![
+                format!("{}.000000000000@in.chat.example.com", channel_id),
+                service
+                    .channel_address(channel_id)
+                    .replace("in.chat.example.com", "other.example.com"),
+            ]))
+            .await;
+
+        assert!(matches!(result, Err(EmailError::NoChannelAddress)));
+    }
+
+    #[tokio::test]
+    async fn test_attachments_are_stored_within_limit() {
+        let channel_id = ChannelId::new();
+        let mut message_service = MockTestMessageService::new();
+        message_service
+            .expect_send_message()
+            .times(1)
+            .returning(|channel_id, sender, content| Ok(posted(channel_id, sender, content)));
+
+        let mut storage = MockTestStorage::new();
+        storage
+            .expect_put()
+            .withf(|key, data| key.ends_with("/0-report_v1.csv") && data == b"a,b\n1,2\n")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let service = service(message_service, storage);
+        let mut email = email(vec![service.channel_address(channel_id)]);
+        email.attachments = vec![
+            EmailAttachment {
+                filename: "report v1.csv".to_string(),
+                content_type: "text/csv".to_string(),
+                data: b"a,b\n1,2\n".to_vec(),
+            },
+            EmailAttachment {
+                filename: "photo.jpg".to_string(),
+                content_type: "image/jpeg".to_string(),
+                data: vec![0; 64],
+            },
+        ];
+
+        let delivery = service.receive(email).await.unwrap();
+
+        assert_eq!(delivery.attachment_keys.len(), 1);
+        let content = delivery.messages[0].content.as_str();
+        assert!(content.contains(&delivery.attachment_keys[0]));
+        assert!(content.contains("photo.jpg (64 bytes, not stored: larger than 16 bytes)"));
+    }
+
+    #[test]
+    fn test_long_email_is_truncated_on_char_boundary() {
+        let content = truncate("é".repeat(MessageContent::MAX_LENGTH));
+
+        assert!(content.len() <= MessageContent::MAX_LENGTH);
+        assert!(MessageContent::new(content).is_ok());
+    }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+use crate::domain::channel::models::ChannelId;
+
+/// Error type for embed API operations
+#[derive(Debug, Error)]
+pub enum EmbedError {
+    /// Also returned for channels that exist but are not embeddable, so
+    /// anonymous callers cannot probe for private channels
+    #[error("Channel not found: {0}")]
+    ChannelNotFound(ChannelId),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
@@ -0,0 +1,17 @@
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::message::models::MessageContent;
+use crate::domain::message::models::MessageId;
+
+/// Message as shown to anonymous readers of an embedded channel.
+///
+/// Identifies the author by display name only, never by user ID.
+#[derive(Debug, Clone)]
+pub struct EmbeddedMessage {
+    pub id: MessageId,
+    pub content: MessageContent,
+    pub timestamp: DateTime<Utc>,
+    /// Author's display name or username, None if the author is not (yet) in the user replica
+    pub display_name: Option<String>,
+}
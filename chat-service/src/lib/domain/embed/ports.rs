@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::embed::errors::EmbedError;
+use crate::domain::embed::models::EmbeddedMessage;
+
+/// Port for the unauthenticated, read-only view of embeddable channels.
+#[async_trait]
+pub trait EmbedServicePort: Send + Sync + 'static {
+    /// Retrieve recent messages of an embeddable channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel to read
+    /// * `limit` - Maximum number of messages, capped by the service
+    /// * `before` - Optional timestamp to paginate backwards from
+    ///
+    /// # Returns
+    /// Messages with their authors' display names, newest first
+    ///
+    /// # Errors
+    /// * `ChannelNotFound` - Channel does not exist or is not embeddable
+    /// * `DatabaseError` - Database operation failed
+    async fn get_channel_messages(
+        &self,
+        channel_id: ChannelId,
+        limit: i32,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<Vec<EmbeddedMessage>, EmbedError>;
+}
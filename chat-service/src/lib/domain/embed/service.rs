@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use super::errors::EmbedError;
+use super::models::EmbeddedMessage;
+use super::ports::EmbedServicePort;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::channel::ports::ChannelRepository;
+use crate::domain::message::ports::MessageRepository;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserReplicaRepository;
+
+/// Concrete implementation of EmbedServicePort.
+///
+/// Serves embeddable public channels to anonymous readers. Display names come
+/// from the local user replica only: anonymous traffic never reaches user-service.
+pub struct EmbedService<CR, MR, UR>
+where
+    CR: ChannelRepository,
+    MR: MessageRepository,
+    UR: UserReplicaRepository,
+{
+    channel_repository: Arc<CR>,
+    message_repository: Arc<MR>,
+    user_replica: Arc<UR>,
+    max_messages: i32,
+}
+
+impl<CR, MR, UR> EmbedService<CR, MR, UR>
+where
+    CR: ChannelRepository,
+    MR: MessageRepository,
+    UR: UserReplicaRepository,
+{
+    /// Create a new embed service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `channel_repository` - Channel repository to check the embeddable flag
+    /// * `message_repository` - Message persistence implementation
+    /// * `user_replica` - User replica for display names
+    /// * `max_messages` - Most messages returned per request
+    ///
+    /// # Returns
+    /// Configured embed service instance
+    pub fn new(
+        channel_repository: Arc<CR>,
+        message_repository: Arc<MR>,
+        user_replica: Arc<UR>,
+        max_messages: i32,
+    ) -> Self {
+        Self {
+            channel_repository,
+            message_repository,
+            user_replica,
+            max_messages: max_messages.max(1),
+        }
+    }
+}
+
+#[async_trait]
+impl<CR, MR, UR> EmbedServicePort for EmbedService<CR, MR, UR>
+where
+    CR: ChannelRepository + 'static,
+    MR: MessageRepository + 'static,
+    UR: UserReplicaRepository + 'static,
+{
+    async fn get_channel_messages(
+        &self,
+        channel_id: ChannelId,
+        limit: i32,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<Vec<EmbeddedMessage>, EmbedError> {
+        let channel = self
+            .channel_repository
+            .find_by_id(channel_id)
+            .await
+            .map_err(|e| EmbedError::DatabaseError(e.to_string()))?;
+        if !channel.is_some_and(|channel| channel.is_embeddable()) {
+            return Err(EmbedError::ChannelNotFound(channel_id));
+        }
+
+        let messages = self
+            .message_repository
+            .find_by_channel(channel_id, limit.clamp(1, self.max_messages), before)
+            .await
+            .map_err(|e| EmbedError::DatabaseError(e.to_string()))?;
+
+        let author_ids: Vec<UserId> = messages
+            .iter()
+            .map(|message| message.user_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        // Missing names degrade the widget instead of failing it
+        let display_names: HashMap<UserId, _> = match self.user_replica.get_many(&author_ids).await
+        {
+            Ok(users) => users
+                .into_iter()
+                .map(|user| (user.id, user.shown_name().to_string()))
+                .collect(),
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load display names for channel {}: {}",
+                    channel_id,
+                    e
+                );
+                HashMap::new()
+            }
+        };
+
+        Ok(messages
+            .into_iter()
+            .map(|message| EmbeddedMessage {
+                id: message.id,
+                display_name: display_names.get(&message.user_id).cloned(),
+                content: message.content,
+                timestamp: message.timestamp,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::channel::errors::ChannelError;
+    use crate::domain::channel::models::Channel;
+    use crate::domain::channel::models::ChannelActivity;
+    use crate::domain::channel::models::ChannelDirectoryEntry;
+    use crate::domain::channel::models::WorkspaceId;
+    use crate::domain::message::errors::MessageError;
+    use crate::domain::message::models::HistoryEntry;
+    use crate::domain::message::models::LanguageCode;
+    use crate::domain::message::models::Message;
+    use crate::domain::message::models::MessageId;
+    use crate::domain::message::models::MessageTombstone;
+    use crate::domain::user::models::User;
+    use crate::domain::user::models::Username;
+    use crate::fixtures::epoch;
+    use crate::fixtures::user_id;
+    use crate::fixtures::ChannelFixture;
+    use crate::fixtures::MessageFixture;
+
+    mock! {
+        pub TestChannelRepository {}
+
+        #[async_trait]
+        impl ChannelRepository for TestChannelRepository {
+            async fn create(&self, channel: Channel) -> Result<Channel, ChannelError>;
+            async fn find_by_id(&self, id: ChannelId) -> Result<Option<Channel>, ChannelError>;
+            async fn find_public_channels(&self) -> Result<Vec<Channel>, ChannelError>;
+            async fn find_directory_entries(&self) -> Result<Vec<ChannelDirectoryEntry>, ChannelError>;
+            async fn find_by_user(&self, user_id: UserId) -> Result<Vec<Channel>, ChannelError>;
+            async fn set_auto_join(&self, id: ChannelId, auto_join: bool) -> Result<(), ChannelError>;
+            async fn add_to_auto_join_channels(
+                &self,
+                user_id: UserId,
+                workspace_id: WorkspaceId,
+                joined_at: DateTime<Utc>,
+            ) -> Result<Vec<ChannelId>, ChannelError>;
+            async fn delete(&self, id: ChannelId) -> Result<(), ChannelError>;
+            async fn record_message_activity(
+                &self,
+                id: ChannelId,
+                user_id: UserId,
+                sent_at: DateTime<Utc>,
+                language: Option<LanguageCode>,
+            ) -> Result<(), ChannelError>;
+            async fn find_activity(&self, id: ChannelId) -> Result<ChannelActivity, ChannelError>;
+        }
+    }
+
+    mock! {
+        pub TestMessageRepository {}
+
+        #[async_trait]
+        impl MessageRepository for TestMessageRepository {
+            async fn create(&self, message: Message) -> Result<Message, MessageError>;
+            async fn create_if_absent(&self, message: Message) -> Result<Message, MessageError>;
+            async fn find_by_channel(
+                &self,
+                channel_id: ChannelId,
+                limit: i32,
+                before: Option<DateTime<Utc>>,
+            ) -> Result<Vec<Message>, MessageError>;
+            async fn find_by_user(
+                &self,
+                user_id: UserId,
+                limit: i32,
+            ) -> Result<Vec<Message>, MessageError>;
+            async fn find_by_id(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+            ) -> Result<Option<Message>, MessageError>;
+            async fn find_history(
+                &self,
+                channel_id: ChannelId,
+                limit: i32,
+                before: Option<DateTime<Utc>>,
+            ) -> Result<Vec<HistoryEntry>, MessageError>;
+            async fn delete(
+                &self,
+                message: &Message,
+                tombstone: MessageTombstone,
+            ) -> Result<MessageTombstone, MessageError>;
+        }
+    }
+
+    mock! {
+        pub TestUserReplica {}
+
+        #[async_trait]
+        impl UserReplicaRepository for TestUserReplica {
+            async fn upsert(&self, user: User) -> Result<(), String>;
+            async fn delete(&self, user_id: UserId) -> Result<(), String>;
+            async fn set_deactivated(&self, user_id: UserId, deactivated_at: Option<DateTime<Utc>>) -> Result<(), String>;
+            async fn get(&self, user_id: UserId) -> Result<Option<User>, String>;
+            async fn get_many(&self, user_ids: &[UserId]) -> Result<Vec<User>, String>;
+        }
+    }
+
+    fn user(n: u128, username: &str) -> User {
+        User {
+            id: user_id(n),
+            username: Username::new(username.to_string()).unwrap(),
+            display_name: None,
+            avatar_url: None,
+            created_at: epoch(),
+            updated_at: epoch(),
+        }
+    }
+
+    fn service_for(
+        channel: Channel,
+        message_repository: MockTestMessageRepository,
+        user_replica: MockTestUserReplica,
+    ) -> EmbedService<MockTestChannelRepository, MockTestMessageRepository, MockTestUserReplica>
+    {
+        let mut channel_repository = MockTestChannelRepository::new();
+        channel_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(channel.clone())));
+
+        EmbedService::new(
+            Arc::new(channel_repository),
+            Arc::new(message_repository),
+            Arc::new(user_replica),
+            50,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_channel_messages_with_display_names() {
+        let channel = ChannelFixture::public("general").embeddable().build();
+        let channel_id = channel.id();
+
+        let mut message_repository = MockTestMessageRepository::new();
+        let messages = vec![
+            MessageFixture::in_channel(channel_id)
+                .from_user(user_id(1))
+                .build(),
+            MessageFixture::in_channel(channel_id)
+                .from_user(user_id(2))
+                .build(),
+            MessageFixture::in_channel(channel_id)
+                .from_user(user_id(3))
+                .build(),
+        ];
+        message_repository
+            .expect_find_by_channel()
+            .times(1)
+            .returning(move |_, _, _| Ok(messages.clone()));
+
+        let mut user_replica = MockTestUserReplica::new();
+        user_replica.expect_get_many().times(1).returning(|_| {
+            Ok(vec![
+                user(1, "alice"),
+                User {
+                    display_name: Some("Bob B.".to_string()),
+                    ..user(2, "bob")
+                },
+            ])
+        });
+
+        let service = service_for(channel, message_repository, user_replica);
+        let embedded = service
+            .get_channel_messages(channel_id, 20, None)
+            .await
+            .unwrap();
+
+        assert_eq!(embedded.len(), 3);
+        assert_eq!(embedded[0].display_name.as_deref(), Some("alice"));
+        assert_eq!(embedded[1].display_name.as_deref(), Some("Bob B."));
+        assert!(embedded[2].display_name.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_channel_messages_caps_limit() {
+        let channel = ChannelFixture::public("general").embeddable().build();
+        let channel_id = channel.id();
+
+        let mut message_repository = MockTestMessageRepository::new();
+        message_repository
+            .expect_find_by_channel()
+            .withf(|_, limit, _| *limit == 50)
+            .times(1)
+            .returning(|_, _, _| Ok(vec![]));
+        let mut user_replica = MockTestUserReplica::new();
+        user_replica.expect_get_many().returning(|_| Ok(vec![]));
+
+        let service = service_for(channel, message_repository, user_replica);
+        let result = service.get_channel_messages(channel_id, 10_000, None).await;
+
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_channel_messages_hides_channels_not_embeddable() {
+        for channel in [
+            ChannelFixture::public("general").build(),
+            ChannelFixture::private("staff").embeddable().build(),
+        ] {
+            let channel_id = channel.id();
+            let mut message_repository = MockTestMessageRepository::new();
+            message_repository.expect_find_by_channel().never();
+
+            let service = service_for(channel, message_repository, MockTestUserReplica::new());
+            let result = service.get_channel_messages(channel_id, 20, None).await;
+
+            assert!(matches!(result, Err(EmbedError::ChannelNotFound(id)) if id == channel_id));
+        }
+    }
+}
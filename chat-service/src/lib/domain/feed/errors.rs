@@ -0,0 +1,67 @@
+use thiserror::Error;
+
+use crate::domain::message::errors::MessageError;
+
+/// Error for FeedId parsing failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum FeedIdError {
+    #[error("Invalid UUID format: {0}")]
+    InvalidFormat(String),
+}
+
+/// Error for FeedUrl validation failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum FeedUrlError {
+    #[error("Feed URL must be http or https")]
+    UnsupportedScheme,
+
+    #[error("Feed URL too long: {actual} characters (max {max})")]
+    TooLong { max: usize, actual: usize },
+
+    #[error("Feed URL must point to a public host, not {0}")]
+    PrivateHost(String),
+}
+
+/// Error for failures fetching or parsing a feed
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum FeedFetchError {
+    #[error("Failed to fetch feed: {0}")]
+    Request(String),
+
+    #[error("Feed is larger than {0} bytes")]
+    TooLarge(usize),
+
+    #[error("Failed to parse feed: {0}")]
+    Parse(String),
+}
+
+/// Top-level error for all feed-related operations
+#[derive(Debug, Error)]
+pub enum FeedError {
+    #[error("Invalid feed ID: {0}")]
+    InvalidFeedId(#[from] FeedIdError),
+
+    #[error("Invalid feed URL: {0}")]
+    InvalidUrl(#[from] FeedUrlError),
+
+    #[error("Feed not found: {0}")]
+    NotFound(String),
+
+    #[error("Feed {0} is already registered in this channel")]
+    AlreadyRegistered(String),
+
+    #[error("Channel already has {0} feeds")]
+    TooManyFeeds(usize),
+
+    #[error("Posts per hour must be between 1 and {0}")]
+    InvalidPostRate(u32),
+
+    #[error(transparent)]
+    Fetch(#[from] FeedFetchError),
+
+    #[error("Failed to post feed entry: {0}")]
+    Message(#[from] MessageError),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
@@ -0,0 +1,219 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use super::errors::FeedError;
+use super::errors::FeedFetchError;
+use super::models::Feed;
+use super::models::FeedEntry;
+use super::models::FeedId;
+use super::models::FeedUrl;
+use super::models::RegisterFeedCommand;
+use crate::domain::channel::models::ChannelId;
+
+/// Port for the feeds posting to channels.
+#[async_trait]
+pub trait FeedServicePort: Send + Sync + 'static {
+    /// Register a feed in a channel.
+    ///
+    /// Entries already in the feed are not posted, only those published
+    /// after its first poll.
+    ///
+    /// # Arguments
+    /// * `command` - Channel, feed URL, owner and posting cap
+    ///
+    /// # Returns
+    /// Registered feed
+    ///
+    /// # Errors
+    /// * `InvalidPostRate` - Posting cap is zero or above the configured maximum
+    /// * `TooManyFeeds` - Channel has as many feeds as allowed
+    /// * `AlreadyRegistered` - Feed is already registered in the channel
+    /// * `DatabaseError` - Database operation failed
+    async fn register_feed(&self, command: RegisterFeedCommand) -> Result<Feed, FeedError>;
+
+    /// List the feeds registered in a channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel to list feeds of
+    ///
+    /// # Returns
+    /// Feeds of the channel, oldest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn list_feeds(&self, channel_id: ChannelId) -> Result<Vec<Feed>, FeedError>;
+
+    /// Remove a feed from a channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel the feed is registered in
+    /// * `feed_id` - Feed to remove
+    ///
+    /// # Returns
+    /// Removed feed
+    ///
+    /// # Errors
+    /// * `NotFound` - Feed is not registered in the channel
+    /// * `DatabaseError` - Database operation failed
+    async fn remove_feed(&self, channel_id: ChannelId, feed_id: FeedId) -> Result<Feed, FeedError>;
+
+    /// Poll the feeds due and post their new entries.
+    ///
+    /// A feed failing to be fetched or posted is recorded and retried at its
+    /// next poll, without failing the others.
+    ///
+    /// # Returns
+    /// Number of entries posted
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Due feeds could not be listed
+    async fn poll_due_feeds(&self) -> Result<usize, FeedError>;
+}
+
+/// Repository port for feed persistence operations.
+#[async_trait]
+pub trait FeedRepository: Send + Sync + 'static {
+    /// Persist a new feed.
+    ///
+    /// # Arguments
+    /// * `feed` - Feed to create
+    ///
+    /// # Returns
+    /// Created feed
+    ///
+    /// # Errors
+    /// * `AlreadyRegistered` - Feed URL is already registered in the channel
+    /// * `DatabaseError` - Database operation failed
+    async fn create(&self, feed: Feed) -> Result<Feed, FeedError>;
+
+    /// Retrieve the feeds of a channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel to query
+    ///
+    /// # Returns
+    /// Feeds of the channel, oldest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_by_channel(&self, channel_id: ChannelId) -> Result<Vec<Feed>, FeedError>;
+
+    /// Delete a feed and its entries.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel the feed is registered in
+    /// * `feed_id` - Feed to delete
+    ///
+    /// # Returns
+    /// Deleted feed, None if it is not registered in the channel
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn delete(
+        &self,
+        channel_id: ChannelId,
+        feed_id: FeedId,
+    ) -> Result<Option<Feed>, FeedError>;
+
+    /// Retrieve the feeds not polled since a time.
+    ///
+    /// # Arguments
+    /// * `polled_before` - Feeds last polled before this time, or never, are due
+    /// * `limit` - Maximum number of feeds to return
+    ///
+    /// # Returns
+    /// Due feeds, least recently polled first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_due(
+        &self,
+        polled_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Feed>, FeedError>;
+
+    /// Record the outcome of a poll.
+    ///
+    /// # Arguments
+    /// * `feed_id` - Feed polled
+    /// * `polled_at` - Time of the poll
+    /// * `error` - Why the poll failed, None if it succeeded
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record_poll(
+        &self,
+        feed_id: FeedId,
+        polled_at: DateTime<Utc>,
+        error: Option<String>,
+    ) -> Result<(), FeedError>;
+
+    /// Keep the entry keys not posted or skipped yet.
+    ///
+    /// # Arguments
+    /// * `feed_id` - Feed the entries belong to
+    /// * `entry_keys` - Keys of the fetched entries
+    ///
+    /// # Returns
+    /// Keys never marked seen, in the order given
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn filter_unseen(
+        &self,
+        feed_id: FeedId,
+        entry_keys: Vec<String>,
+    ) -> Result<Vec<String>, FeedError>;
+
+    /// Remember entries so they are never posted again.
+    ///
+    /// # Arguments
+    /// * `feed_id` - Feed the entries belong to
+    /// * `entry_keys` - Keys of the entries
+    /// * `posted_at` - Time the entries were posted, None if they were skipped
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn mark_seen(
+        &self,
+        feed_id: FeedId,
+        entry_keys: Vec<String>,
+        posted_at: Option<DateTime<Utc>>,
+    ) -> Result<(), FeedError>;
+
+    /// Count the entries of a feed posted since a time.
+    ///
+    /// # Arguments
+    /// * `feed_id` - Feed to count entries of
+    /// * `since` - Start of the window
+    ///
+    /// # Returns
+    /// Number of entries posted in the window
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn count_posted_since(
+        &self,
+        feed_id: FeedId,
+        since: DateTime<Utc>,
+    ) -> Result<u32, FeedError>;
+}
+
+/// Port for downloading and parsing feeds.
+#[async_trait]
+pub trait FeedFetcher: Send + Sync + 'static {
+    /// Fetch the entries of an RSS or Atom feed.
+    ///
+    /// # Arguments
+    /// * `url` - Feed URL
+    ///
+    /// # Returns
+    /// Entries in document order
+    ///
+    /// # Errors
+    /// * `Request` - Feed could not be downloaded
+    /// * `TooLarge` - Feed exceeds the configured size
+    /// * `Parse` - Document is neither RSS nor Atom
+    async fn fetch(&self, url: &FeedUrl) -> Result<Vec<FeedEntry>, FeedFetchError>;
+}
@@ -0,0 +1,672 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+use clock::Clock;
+
+use super::errors::FeedError;
+use super::models::Feed;
+use super::models::FeedEntry;
+use super::models::FeedId;
+use super::models::FeedSettings;
+use super::models::RegisterFeedCommand;
+use super::ports::FeedFetcher;
+use super::ports::FeedRepository;
+use super::ports::FeedServicePort;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageContent;
+use crate::domain::message::models::Sender;
+use crate::domain::message::ports::MessageServicePort;
+
+/// Feeds polled per batch.
+const POLL_BATCH_SIZE: i64 = 50;
+
+/// Longest summary excerpt shown under an entry link.
+const EXCERPT_CHARS: usize = 280;
+
+/// Domain service posting RSS and Atom feed entries to channels.
+///
+/// Entries are deduplicated by their ID, so an entry edited by its
+/// publisher is not posted twice. Entries over the hourly cap of a feed stay
+/// unseen and are posted by later polls.
+pub struct FeedService<FR, FF, MS>
+where
+    FR: FeedRepository,
+    FF: FeedFetcher,
+    MS: MessageServicePort,
+{
+    repository: Arc<FR>,
+    fetcher: Arc<FF>,
+    message_service: Arc<MS>,
+    settings: FeedSettings,
+    clock: Arc<dyn Clock>,
+}
+
+impl<FR, FF, MS> FeedService<FR, FF, MS>
+where
+    FR: FeedRepository,
+    FF: FeedFetcher,
+    MS: MessageServicePort,
+{
+    /// Create a new feed service.
+    ///
+    /// # Arguments
+    /// * `repository` - Feed persistence implementation
+    /// * `fetcher` - Downloads and parses feeds
+    /// * `message_service` - Message service posting the entries
+    /// * `settings` - Bot user, poll interval and posting caps
+    ///
+    /// # Returns
+    /// Configured feed service instance
+    pub fn new(
+        repository: Arc<FR>,
+        fetcher: Arc<FF>,
+        message_service: Arc<MS>,
+        settings: FeedSettings,
+    ) -> Self {
+        Self {
+            repository,
+            fetcher,
+            message_service,
+            settings,
+            clock: clock::system(),
+        }
+    }
+
+    /// Schedule polls and cap posts by another clock.
+    ///
+    /// The system clock by default.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock deciding when feeds are due and posts are counted
+    ///
+    /// # Returns
+    /// Service telling the time by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Poll feeds until the process exits.
+    ///
+    /// Every due feed is polled, then the worker waits `poll_interval` for
+    /// the next ones.
+    pub async fn run(&self) {
+        loop {
+            match self.poll_due_feeds().await {
+                Ok(0) => {}
+                Ok(posted) => tracing::debug!(posted, "Posted feed entries"),
+                Err(e) => tracing::error!("Failed to poll feeds: {}", e),
+            }
+            tokio::time::sleep(self.settings.poll_interval).await;
+        }
+    }
+
+    /// Fetch a feed and post its new entries, oldest first.
+    ///
+    /// # Returns
+    /// Number of entries posted
+    async fn poll_feed(&self, feed: &Feed) -> Result<usize, FeedError> {
+        let mut entries = self.fetcher.fetch(&feed.url).await?;
+
+        // Feeds list the newest entries first
+        entries.reverse();
+        entries.sort_by_key(|entry| entry.published_at);
+
+        let mut keys = HashSet::new();
+        entries.retain(|entry| keys.insert(entry.key()));
+
+        let unseen: HashSet<String> = self
+            .repository
+            .filter_unseen(feed.id, entries.iter().map(FeedEntry::key).collect())
+            .await?
+            .into_iter()
+            .collect();
+        entries.retain(|entry| unseen.contains(&entry.key()));
+
+        // Entries published before the feed was registered are not news
+        if feed.last_polled_at.is_none() {
+            self.repository
+                .mark_seen(feed.id, entries.iter().map(FeedEntry::key).collect(), None)
+                .await?;
+            return Ok(0);
+        }
+
+        let now = self.clock.now();
+        let posted_last_hour = self
+            .repository
+            .count_posted_since(feed.id, now - Duration::hours(1))
+            .await?;
+        let allowance = feed.max_posts_per_hour.saturating_sub(posted_last_hour) as usize;
+
+        let sender = Sender::member(self.settings.bot_id);
+        let mut posted = 0;
+        for entry in entries.into_iter().take(allowance) {
+            let Some(content) = entry_content(&entry) else {
+                self.repository
+                    .mark_seen(feed.id, vec![entry.key()], None)
+                    .await?;
+                continue;
+            };
+
+            self.message_service
+                .send_message(feed.channel_id, sender, content)
+                .await?;
+            self.repository
+                .mark_seen(feed.id, vec![entry.key()], Some(self.clock.now()))
+                .await?;
+            posted += 1;
+        }
+
+        Ok(posted)
+    }
+}
+
+#[async_trait]
+impl<FR, FF, MS> FeedServicePort for FeedService<FR, FF, MS>
+where
+    FR: FeedRepository,
+    FF: FeedFetcher,
+    MS: MessageServicePort,
+{
+    async fn register_feed(&self, command: RegisterFeedCommand) -> Result<Feed, FeedError> {
+        let max_posts_per_hour = command
+            .max_posts_per_hour
+            .unwrap_or(self.settings.default_max_posts_per_hour);
+        if max_posts_per_hour == 0 || max_posts_per_hour > self.settings.max_posts_per_hour {
+            return Err(FeedError::InvalidPostRate(self.settings.max_posts_per_hour));
+        }
+
+        let registered = self.repository.find_by_channel(command.channel_id).await?;
+        if registered.len() >= self.settings.max_feeds_per_channel {
+            return Err(FeedError::TooManyFeeds(self.settings.max_feeds_per_channel));
+        }
+
+        self.repository
+            .create(Feed {
+                id: FeedId::new(),
+                channel_id: command.channel_id,
+                url: command.url,
+                created_by: command.created_by,
+                max_posts_per_hour,
+                last_polled_at: None,
+                last_error: None,
+                created_at: self.clock.now(),
+            })
+            .await
+    }
+
+    async fn list_feeds(&self, channel_id: ChannelId) -> Result<Vec<Feed>, FeedError> {
+        self.repository.find_by_channel(channel_id).await
+    }
+
+    async fn remove_feed(&self, channel_id: ChannelId, feed_id: FeedId) -> Result<Feed, FeedError> {
+        self.repository
+            .delete(channel_id, feed_id)
+            .await?
+            .ok_or_else(|| FeedError::NotFound(feed_id.to_string()))
+    }
+
+    async fn poll_due_feeds(&self) -> Result<usize, FeedError> {
+        let poll_interval = Duration::from_std(self.settings.poll_interval)
+            .unwrap_or_else(|_| Duration::minutes(5));
+        let polled_before = self.clock.now() - poll_interval;
+
+        let mut posted = 0;
+        loop {
+            // Polled feeds are recorded, so each batch holds new ones
+            let due = self
+                .repository
+                .find_due(polled_before, POLL_BATCH_SIZE)
+                .await?;
+            let last_batch = (due.len() as i64) < POLL_BATCH_SIZE;
+
+            for feed in due {
+                let error = match self.poll_feed(&feed).await {
+                    Ok(count) => {
+                        posted += count;
+                        None
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to poll feed {} ({}): {}", feed.id, feed.url, e);
+                        Some(e.to_string())
+                    }
+                };
+                self.repository
+                    .record_poll(feed.id, self.clock.now(), error)
+                    .await?;
+            }
+
+            if last_batch {
+                return Ok(posted);
+            }
+        }
+    }
+}
+
+/// Message announcing an entry: its title, link and a summary excerpt.
+///
+/// # Returns
+/// Message content, None if the entry has neither title nor link
+fn entry_content(entry: &FeedEntry) -> Option<MessageContent> {
+    let title = entry.title.as_deref().map(collapse_whitespace);
+    let link = entry.link.as_deref().map(str::trim);
+
+    let mut content = match (title.as_deref(), link) {
+        (Some(title), Some(link)) if !title.is_empty() => format!("{}\n{}", title, link),
+        (Some(title), _) if !title.is_empty() => title.to_string(),
+        (_, Some(link)) if !link.is_empty() => link.to_string(),
+        _ => return None,
+    };
+
+    let excerpt = entry
+        .summary
+        .as_deref()
+        .map(|summary| excerpt(&strip_tags(summary)))
+        .unwrap_or_default();
+    if !excerpt.is_empty() {
+        content.push_str("\n\n> ");
+        content.push_str(&excerpt);
+    }
+
+    let content = match content.char_indices().nth(MessageContent::MAX_LENGTH) {
+        Some((end, _)) => content[..end].to_string(),
+        None => content,
+    };
+    MessageContent::new(content).ok()
+}
+
+/// Text of an HTML fragment, with the common entities decoded.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// First characters of a text, cut at a word boundary.
+fn excerpt(text: &str) -> String {
+    let text = collapse_whitespace(text);
+    match text.char_indices().nth(EXCERPT_CHARS) {
+        None => text,
+        Some((end, _)) => {
+            let cut = text[..end].rfind(' ').unwrap_or(end);
+            format!("{}…", &text[..cut])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::DateTime;
+    use chrono::Utc;
+    use mockall::mock;
+    use mockall::predicate::*;
+
+    use super::*;
+    use crate::domain::feed::errors::FeedFetchError;
+    use crate::domain::feed::models::FeedUrl;
+    use crate::domain::message::errors::MessageError;
+    use crate::domain::message::models::HistoryEntry;
+    use crate::domain::message::models::Message;
+    use crate::domain::message::models::MessageId;
+    use crate::domain::message::models::MessageTombstone;
+    use crate::domain::message::ports::DeliveryReporter;
+    use crate::domain::user::models::UserId;
+
+    mock! {
+        pub TestMessageService {}
+
+        #[async_trait]
+        impl MessageServicePort for TestMessageService {
+            async fn send_message(
+                &self,
+                channel_id: ChannelId,
+                sender: Sender,
+                content: MessageContent,
+            ) -> Result<Message, MessageError>;
+            async fn send_message_with_id(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+                sender: Sender,
+                content: MessageContent,
+            ) -> Result<Message, MessageError>;
+            async fn send_message_reporting(
+                &self,
+                channel_id: ChannelId,
+                message_id: Option<MessageId>,
+                sender: Sender,
+                content: MessageContent,
+                reporter: Arc<dyn DeliveryReporter>,
+            ) -> Result<Message, MessageError>;
+            async fn get_channel_messages(
+                &self,
+                channel_id: ChannelId,
+                limit: i32,
+                before: Option<DateTime<Utc>>,
+            ) -> Result<Vec<HistoryEntry>, MessageError>;
+            async fn delete_message(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+                deleted_by: UserId,
+            ) -> Result<MessageTombstone, MessageError>;
+        }
+    }
+
+    mock! {
+        pub TestFetcher {}
+
+        #[async_trait]
+        impl FeedFetcher for TestFetcher {
+            async fn fetch(&self, url: &FeedUrl) -> Result<Vec<FeedEntry>, FeedFetchError>;
+        }
+    }
+
+    /// In-memory repository holding one feed.
+    struct InMemoryFeedRepository {
+        feed: Mutex<Feed>,
+        seen: Mutex<Vec<(String, Option<DateTime<Utc>>)>>,
+    }
+
+    impl InMemoryFeedRepository {
+        fn new(feed: Feed) -> Self {
+            Self {
+                feed: Mutex::new(feed),
+                seen: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl FeedRepository for InMemoryFeedRepository {
+        async fn create(&self, feed: Feed) -> Result<Feed, FeedError> {
+            Ok(feed)
+        }
+
+        async fn find_by_channel(&self, channel_id: ChannelId) -> Result<Vec<Feed>, FeedError> {
+            let feed = self.feed.lock().unwrap().clone();
+            Ok(if feed.channel_id == channel_id {
+                vec![feed]
+            } else {
+                vec![]
+            })
+        }
+
+        async fn delete(
+            &self,
+            _channel_id: ChannelId,
+            _feed_id: FeedId,
+        ) -> Result<Option<Feed>, FeedError> {
+            Ok(None)
+        }
+
+        async fn find_due(
+            &self,
+            _polled_before: DateTime<Utc>,
+            _limit: i64,
+        ) -> Result<Vec<Feed>, FeedError> {
+            Ok(vec![self.feed.lock().unwrap().clone()])
+        }
+
+        async fn record_poll(
+            &self,
+            _feed_id: FeedId,
+            polled_at: DateTime<Utc>,
+            error: Option<String>,
+        ) -> Result<(), FeedError> {
+            let mut feed = self.feed.lock().unwrap();
+            feed.last_polled_at = Some(polled_at);
+            feed.last_error = error;
+            Ok(())
+        }
+
+        async fn filter_unseen(
+            &self,
+            _feed_id: FeedId,
+            entry_keys: Vec<String>,
+        ) -> Result<Vec<String>, FeedError> {
+            let seen = self.seen.lock().unwrap();
+            Ok(entry_keys
+                .into_iter()
+                .filter(|key| !seen.iter().any(|(seen_key, _)| seen_key == key))
+                .collect())
+        }
+
+        async fn mark_seen(
+            &self,
+            _feed_id: FeedId,
+            entry_keys: Vec<String>,
+            posted_at: Option<DateTime<Utc>>,
+        ) -> Result<(), FeedError> {
+            let mut seen = self.seen.lock().unwrap();
+            seen.extend(entry_keys.into_iter().map(|key| (key, posted_at)));
+            Ok(())
+        }
+
+        async fn count_posted_since(
+            &self,
+            _feed_id: FeedId,
+            since: DateTime<Utc>,
+        ) -> Result<u32, FeedError> {
+            let seen = self.seen.lock().unwrap();
+            Ok(seen
+                .iter()
+                .filter(|(_, posted_at)| posted_at.is_some_and(|at| at >= since))
+                .count() as u32)
+        }
+    }
+
+    fn settings() -> FeedSettings {
+        FeedSettings {
+            bot_id: UserId::new(),
+            poll_interval: std::time::Duration::from_secs(300),
+            default_max_posts_per_hour: 10,
+            max_posts_per_hour: 60,
+            max_feeds_per_channel: 1,
+        }
+    }
+
+    fn feed(max_posts_per_hour: u32, polled: bool) -> Feed {
+        Feed {
+            id: FeedId::new(),
+            channel_id: ChannelId::new(),
+            url: FeedUrl::new("https://example.com/feed.xml".to_string()).unwrap(),
+            created_by: UserId::new(),
+            max_posts_per_hour,
+            last_polled_at: polled.then(|| Utc::now() - Duration::hours(1)),
+            last_error: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn entry(id: &str, minutes_ago: i64) -> FeedEntry {
+        FeedEntry {
+            id: id.to_string(),
+            title: Some(format!("Post {}", id)),
+            link: Some(format!("https://example.com/{}", id)),
+            summary: Some("<p>First &amp; <b>foremost</b></p>".to_string()),
+            published_at: Some(Utc::now() - Duration::minutes(minutes_ago)),
+        }
+    }
+
+    fn fetcher(entries: Vec<FeedEntry>) -> MockTestFetcher {
+        let mut fetcher = MockTestFetcher::new();
+        fetcher
+            .expect_fetch()
+            .returning(move |_| Ok(entries.clone()));
+        fetcher
+    }
+
+    fn posted(channel_id: ChannelId, sender: Sender, content: MessageContent) -> Message {
+        Message {
+            id: MessageId::new_time_based(),
+            channel_id,
+            user_id: sender.user_id,
+            content,
+            timestamp: Utc::now(),
+            language: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_entries_are_posted_oldest_first_once() {
+        let feed = feed(10, true);
+        let channel_id = feed.channel_id;
+        let repository = Arc::new(InMemoryFeedRepository::new(feed));
+        let contents = Arc::new(Mutex::new(Vec::new()));
+
+        let mut message_service = MockTestMessageService::new();
+        let recorded = Arc::clone(&contents);
+        message_service
+            .expect_send_message()
+            .with(eq(channel_id), always(), always())
+            .times(2)
+            .returning(move |channel_id, sender, content| {
+                recorded.lock().unwrap().push(content.as_str().to_string());
+                Ok(posted(channel_id, sender, content))
+            });
+
+        let service = FeedService::new(
+            Arc::clone(&repository),
+            Arc::new(fetcher(vec![entry("b", 5), entry("a", 10), entry("a", 10)])),
+            Arc::new(message_service),
+            settings(),
+        );
+
+        assert_eq!(service.poll_due_feeds().await.unwrap(), 2);
+        assert_eq!(service.poll_due_feeds().await.unwrap(), 0);
+        assert_eq!(
+            *contents.lock().unwrap(),
+            vec![
+                "Post a\nhttps://example.com/a\n\n> First & foremost".to_string(),
+                "Post b\nhttps://example.com/b\n\n> First & foremost".to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_first_poll_skips_existing_entries() {
+        let repository = Arc::new(InMemoryFeedRepository::new(feed(10, false)));
+        let mut message_service = MockTestMessageService::new();
+        message_service.expect_send_message().never();
+
+        let service = FeedService::new(
+            Arc::clone(&repository),
+            Arc::new(fetcher(vec![entry("a", 10)])),
+            Arc::new(message_service),
+            settings(),
+        );
+
+        assert_eq!(service.poll_due_feeds().await.unwrap(), 0);
+        assert_eq!(repository.seen.lock().unwrap().len(), 1);
+        assert!(repository.feed.lock().unwrap().last_polled_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_entries_over_hourly_cap_wait_for_later_polls() {
+        let repository = Arc::new(InMemoryFeedRepository::new(feed(1, true)));
+        let mut message_service = MockTestMessageService::new();
+        message_service
+            .expect_send_message()
+            .times(1)
+            .returning(|channel_id, sender, content| Ok(posted(channel_id, sender, content)));
+
+        let service = FeedService::new(
+            Arc::clone(&repository),
+            Arc::new(fetcher(vec![entry("b", 5), entry("a", 10)])),
+            Arc::new(message_service),
+            settings(),
+        );
+
+        assert_eq!(service.poll_due_feeds().await.unwrap(), 1);
+        assert_eq!(service.poll_due_feeds().await.unwrap(), 0);
+
+        let unseen = repository
+            .filter_unseen(FeedId::new(), vec![entry("b", 5).key()])
+            .await
+            .unwrap();
+        assert_eq!(unseen.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_failure_is_recorded() {
+        let repository = Arc::new(InMemoryFeedRepository::new(feed(10, true)));
+        let mut fetcher = MockTestFetcher::new();
+        fetcher
+            .expect_fetch()
+            .returning(|_| Err(FeedFetchError::Parse("not a feed".to_string())));
+
+        let service = FeedService::new(
+            Arc::clone(&repository),
+            Arc::new(fetcher),
+            Arc::new(MockTestMessageService::new()),
+            settings(),
+        );
+
+        assert_eq!(service.poll_due_feeds().await.unwrap(), 0);
+        assert_eq!(
+            repository.feed.lock().unwrap().last_error.as_deref(),
+            Some("Failed to parse feed: not a feed")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_feed_enforces_limits() {
+        let existing = feed(10, true);
+        let channel_id = existing.channel_id;
+        let service = FeedService::new(
+            Arc::new(InMemoryFeedRepository::new(existing)),
+            Arc::new(MockTestFetcher::new()),
+            Arc::new(MockTestMessageService::new()),
+            settings(),
+        );
+        let command = |channel_id, max_posts_per_hour| RegisterFeedCommand {
+            channel_id,
+            url: FeedUrl::new("https://example.com/other.xml".to_string()).unwrap(),
+            created_by: UserId::new(),
+            max_posts_per_hour,
+        };
+
+        assert!(matches!(
+            service.register_feed(command(channel_id, None)).await,
+            Err(FeedError::TooManyFeeds(1))
+        ));
+        assert!(matches!(
+            service
+                .register_feed(command(ChannelId::new(), Some(61)))
+                .await,
+            Err(FeedError::InvalidPostRate(60))
+        ));
+        let feed = service
+            .register_feed(command(ChannelId::new(), None))
+            .await
+            .unwrap();
+        assert_eq!(feed.max_posts_per_hour, 10);
+        assert!(feed.last_polled_at.is_none());
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// Error for invalid gateway region configuration
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum GatewayError {
+    #[error("At least one region must be configured")]
+    NoRegions,
+
+    #[error("Default region {0} is not configured")]
+    UnknownDefaultRegion(String),
+}
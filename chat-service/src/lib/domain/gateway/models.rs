@@ -0,0 +1,54 @@
+use std::fmt;
+
+/// Deployment region clients can connect to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub name: String,
+    pub websocket_url: String,
+    /// ISO 3166-1 alpha-2 country codes served by this region
+    pub countries: Vec<String>,
+}
+
+/// Client signals used to pick a region.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GatewayHint {
+    /// Round-trip times in milliseconds the client measured per region
+    pub measured_rtt: Vec<(String, u32)>,
+    /// Region the client asked for
+    pub preferred_region: Option<String>,
+    /// Country the client connects from (GeoIP)
+    pub country: Option<String>,
+}
+
+/// Signal a region was selected by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionReason {
+    Latency,
+    Hint,
+    GeoIp,
+    Default,
+}
+
+impl SelectionReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SelectionReason::Latency => "latency",
+            SelectionReason::Hint => "hint",
+            SelectionReason::GeoIp => "geoip",
+            SelectionReason::Default => "default",
+        }
+    }
+}
+
+impl fmt::Display for SelectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Region chosen for a client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionSelection {
+    pub region: Region,
+    pub reason: SelectionReason,
+}
@@ -0,0 +1,14 @@
+use super::models::GatewayHint;
+use super::models::RegionSelection;
+
+/// Port for steering clients to a WebSocket endpoint.
+pub trait GatewayServicePort: Send + Sync + 'static {
+    /// Select the best region for a client.
+    ///
+    /// # Arguments
+    /// * `hint` - Client-measured latencies, preferred region and country
+    ///
+    /// # Returns
+    /// Selected region and the signal it was selected by
+    fn select_region(&self, hint: &GatewayHint) -> RegionSelection;
+}
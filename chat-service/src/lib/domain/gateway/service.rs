@@ -0,0 +1,171 @@
+use super::errors::GatewayError;
+use super::models::GatewayHint;
+use super::models::Region;
+use super::models::RegionSelection;
+use super::models::SelectionReason;
+use super::ports::GatewayServicePort;
+
+/// Domain service selecting the nearest region for WebSocket connections.
+///
+/// Signals are used in order of reliability: latencies measured by the
+/// client, then an explicit region hint, then the GeoIP country, then the
+/// default region. Unknown region names in hints are ignored.
+pub struct GatewayService {
+    regions: Vec<Region>,
+    default_index: usize,
+}
+
+impl GatewayService {
+    /// Create a gateway service for the configured regions.
+    ///
+    /// # Arguments
+    /// * `regions` - Regions clients can connect to
+    /// * `default_region` - Name of the region used when no signal matches
+    ///
+    /// # Returns
+    /// Configured gateway service instance
+    ///
+    /// # Errors
+    /// * `NoRegions` - No region is configured
+    /// * `UnknownDefaultRegion` - Default region is not among the regions
+    pub fn new(regions: Vec<Region>, default_region: &str) -> Result<Self, GatewayError> {
+        if regions.is_empty() {
+            return Err(GatewayError::NoRegions);
+        }
+
+        let default_index = regions
+            .iter()
+            .position(|region| region.name == default_region)
+            .ok_or_else(|| GatewayError::UnknownDefaultRegion(default_region.to_string()))?;
+
+        Ok(Self {
+            regions,
+            default_index,
+        })
+    }
+
+    fn find(&self, name: &str) -> Option<&Region> {
+        self.regions.iter().find(|region| region.name == name)
+    }
+
+    fn selection(region: &Region, reason: SelectionReason) -> RegionSelection {
+        RegionSelection {
+            region: region.clone(),
+            reason,
+        }
+    }
+}
+
+impl GatewayServicePort for GatewayService {
+    fn select_region(&self, hint: &GatewayHint) -> RegionSelection {
+        let fastest = hint
+            .measured_rtt
+            .iter()
+            .filter_map(|(name, rtt)| self.find(name).map(|region| (region, rtt)))
+            .min_by_key(|(_, rtt)| **rtt);
+        if let Some((region, _)) = fastest {
+            return Self::selection(region, SelectionReason::Latency);
+        }
+
+        if let Some(region) = hint
+            .preferred_region
+            .as_deref()
+            .and_then(|name| self.find(name))
+        {
+            return Self::selection(region, SelectionReason::Hint);
+        }
+
+        if let Some(country) = &hint.country {
+            let by_country = self.regions.iter().find(|region| {
+                region
+                    .countries
+                    .iter()
+                    .any(|code| code.eq_ignore_ascii_case(country))
+            });
+            if let Some(region) = by_country {
+                return Self::selection(region, SelectionReason::GeoIp);
+            }
+        }
+
+        Self::selection(&self.regions[self.default_index], SelectionReason::Default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(name: &str, countries: &[&str]) -> Region {
+        Region {
+            name: name.to_string(),
+            websocket_url: format!("wss://{}.chat.example.com", name),
+            countries: countries.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    fn service() -> GatewayService {
+        GatewayService::new(
+            vec![region("eu-west", &["DE", "FR"]), region("us-east", &["US"])],
+            "eu-west",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_new_rejects_unknown_default_region() {
+        assert_eq!(
+            GatewayService::new(vec![region("eu-west", &[])], "ap-south").err(),
+            Some(GatewayError::UnknownDefaultRegion("ap-south".to_string()))
+        );
+        assert_eq!(
+            GatewayService::new(vec![], "eu-west").err(),
+            Some(GatewayError::NoRegions)
+        );
+    }
+
+    #[test]
+    fn test_measured_latency_wins_over_other_signals() {
+        let hint = GatewayHint {
+            measured_rtt: vec![
+                ("eu-west".to_string(), 120),
+                ("us-east".to_string(), 35),
+                ("unknown".to_string(), 1),
+            ],
+            preferred_region: Some("eu-west".to_string()),
+            country: Some("DE".to_string()),
+        };
+
+        let selection = service().select_region(&hint);
+
+        assert_eq!(selection.region.name, "us-east");
+        assert_eq!(selection.reason, SelectionReason::Latency);
+    }
+
+    #[test]
+    fn test_hint_then_geoip_then_default() {
+        let service = service();
+
+        let hinted = service.select_region(&GatewayHint {
+            preferred_region: Some("us-east".to_string()),
+            country: Some("DE".to_string()),
+            ..GatewayHint::default()
+        });
+        assert_eq!(hinted.region.name, "us-east");
+        assert_eq!(hinted.reason, SelectionReason::Hint);
+
+        let located = service.select_region(&GatewayHint {
+            country: Some("us".to_string()),
+            ..GatewayHint::default()
+        });
+        assert_eq!(located.region.name, "us-east");
+        assert_eq!(located.reason, SelectionReason::GeoIp);
+
+        let fallback = service.select_region(&GatewayHint {
+            preferred_region: Some("unknown".to_string()),
+            country: Some("JP".to_string()),
+            ..GatewayHint::default()
+        });
+        assert_eq!(fallback.region.name, "eu-west");
+        assert_eq!(fallback.reason, SelectionReason::Default);
+    }
+}
@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+/// Error for leader lease operations
+#[derive(Debug, Clone, Error)]
+pub enum LeaderError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
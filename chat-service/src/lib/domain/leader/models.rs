@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Serialize;
+
+use crate::domain::lock::models::InstanceId;
+
+/// Lease of the leader of an election, as recorded for other instances to see.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LeaderLease {
+    /// Name of the election
+    pub name: String,
+    /// Instance holding the leadership
+    pub instance_id: InstanceId,
+    /// When the instance became leader
+    pub elected_at: DateTime<Utc>,
+    /// When the leader last confirmed it holds the leadership
+    pub renewed_at: DateTime<Utc>,
+    /// When the lease is considered abandoned if not renewed
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Timing of a leader election.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaderElectionSettings {
+    /// Time between two renewals of the leadership
+    pub renew_interval: Duration,
+    /// Validity of a recorded lease, a few renewal intervals
+    pub lease_ttl: Duration,
+    /// Time between two attempts of a follower to become leader
+    pub retry_interval: Duration,
+}
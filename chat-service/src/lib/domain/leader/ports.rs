@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+
+use super::errors::LeaderError;
+use super::models::LeaderLease;
+use crate::domain::lock::models::InstanceId;
+
+/// Port recording who leads each election.
+///
+/// Leadership itself is decided by a distributed lock; the recorded leases
+/// only tell other instances and operators which instance holds it.
+#[async_trait]
+pub trait LeaderLeaseRepository: Send + Sync + 'static {
+    /// Record or renew the lease of the current leader.
+    ///
+    /// # Arguments
+    /// * `lease` - Lease of the leader, replacing the election's previous lease
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record(&self, lease: &LeaderLease) -> Result<(), LeaderError>;
+
+    /// Find the unexpired lease of an election.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the election
+    ///
+    /// # Returns
+    /// Lease of the current leader, None if no instance leads
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find(&self, name: &str) -> Result<Option<LeaderLease>, LeaderError>;
+
+    /// Remove the lease of a leader stepping down.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the election
+    /// * `instance_id` - Leader stepping down; a lease of another instance is kept
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn clear(&self, name: &str, instance_id: &InstanceId) -> Result<(), LeaderError>;
+}
@@ -0,0 +1,406 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use chrono::Utc;
+use tokio::sync::watch;
+
+use super::errors::LeaderError;
+use super::models::LeaderElectionSettings;
+use super::models::LeaderLease;
+use super::ports::LeaderLeaseRepository;
+use crate::domain::lock::models::InstanceId;
+use crate::domain::lock::ports::DistributedLock;
+use crate::domain::lock::ports::LockGuard;
+
+/// Elects one instance of the deployment to run coordinator tasks.
+///
+/// Every instance campaigns with [`LeaderElection::run`]; the instance holding
+/// the election's distributed lock leads, renews its lease every
+/// `renew_interval` and steps down as soon as a renewal finds the lock lost.
+/// Followers retry every `retry_interval`, so one of them takes over once the
+/// backend frees the lock of a dead leader.
+pub struct LeaderElection<L, R>
+where
+    L: DistributedLock,
+    R: LeaderLeaseRepository,
+{
+    name: String,
+    lock: Arc<L>,
+    leases: Arc<R>,
+    instance_id: InstanceId,
+    settings: LeaderElectionSettings,
+    leadership: watch::Sender<bool>,
+}
+
+impl<L, R> LeaderElection<L, R>
+where
+    L: DistributedLock,
+    R: LeaderLeaseRepository,
+{
+    /// Create an election this instance takes part in.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the election, also the name of its lock
+    /// * `lock` - Locks shared by the instances of the deployment
+    /// * `leases` - Where the leader records its lease
+    /// * `instance_id` - Identity of this instance
+    /// * `settings` - Renewal and retry timing
+    ///
+    /// # Returns
+    /// Election this instance does not lead yet
+    pub fn new(
+        name: &str,
+        lock: Arc<L>,
+        leases: Arc<R>,
+        instance_id: InstanceId,
+        settings: LeaderElectionSettings,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            lock,
+            leases,
+            instance_id,
+            settings,
+            leadership: watch::Sender::new(false),
+        }
+    }
+
+    /// Get the name of the election.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the identity of this instance.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+
+    /// Check if this instance currently leads.
+    pub fn is_leader(&self) -> bool {
+        *self.leadership.borrow()
+    }
+
+    /// Get the lease of the current leader, whichever instance it is.
+    ///
+    /// # Returns
+    /// Lease of the leader, None while no instance leads
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Lease could not be read
+    pub async fn leader(&self) -> Result<Option<LeaderLease>, LeaderError> {
+        self.leases.find(&self.name).await
+    }
+
+    /// Campaign for the leadership, leading whenever elected.
+    ///
+    /// Lock backend failures are logged and retried, so the future only
+    /// completes if it is dropped, which also gives up the leadership.
+    pub async fn run(&self) -> anyhow::Result<()> {
+        loop {
+            match self.lock.try_acquire(&self.name).await {
+                Ok(Some(guard)) => self.lead(guard).await,
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(election = %self.name, error = %e, "Leader election attempt failed")
+                }
+            }
+            tokio::time::sleep(self.settings.retry_interval).await;
+        }
+    }
+
+    /// Run a coordinator task while this instance leads.
+    ///
+    /// The task starts on election and is cancelled when the leadership is
+    /// lost; a task completing keeps the instance leader without running it
+    /// again until its next term.
+    ///
+    /// # Arguments
+    /// * `task` - Builds the run of the task for each term
+    ///
+    /// # Errors
+    /// Error of the task
+    pub async fn while_leader<F, Fut>(&self, task: F) -> anyhow::Result<()>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = anyhow::Result<()>>,
+    {
+        let mut leadership = self.leadership.subscribe();
+        loop {
+            leadership.wait_for(|leader| *leader).await?;
+            let stopped = tokio::select! {
+                result = task() => {
+                    result?;
+                    false
+                }
+                _ = leadership.wait_for(|leader| !*leader) => true,
+            };
+            if stopped {
+                tracing::info!(election = %self.name, "Leadership lost, coordinator task stopped");
+            } else {
+                leadership.wait_for(|leader| !*leader).await?;
+            }
+        }
+    }
+
+    /// Hold the leadership until a renewal fails.
+    async fn lead(&self, mut guard: Box<dyn LockGuard>) {
+        let elected_at = Utc::now();
+        tracing::info!(election = %self.name, instance_id = %self.instance_id, "Elected leader");
+        let _term = Term::begin(&self.leadership);
+
+        let mut interval = tokio::time::interval(self.settings.renew_interval);
+        loop {
+            interval.tick().await;
+            if let Err(e) = guard.renew().await {
+                tracing::warn!(election = %self.name, error = %e, "Leadership lost, stepping down");
+                break;
+            }
+
+            let renewed_at = Utc::now();
+            let lease = LeaderLease {
+                name: self.name.clone(),
+                instance_id: self.instance_id,
+                elected_at,
+                renewed_at,
+                expires_at: renewed_at
+                    + chrono::Duration::from_std(self.settings.lease_ttl).unwrap_or_default(),
+            };
+            // The lock decides the leadership, the lease only reports it
+            if let Err(e) = self.leases.record(&lease).await {
+                tracing::warn!(election = %self.name, error = %e, "Failed to record leader lease");
+            }
+        }
+
+        if let Err(e) = self.leases.clear(&self.name, &self.instance_id).await {
+            tracing::warn!(election = %self.name, error = %e, "Failed to clear leader lease");
+        }
+        if let Err(e) = guard.release().await {
+            tracing::debug!(election = %self.name, error = %e, "Lost lock not released");
+        }
+    }
+}
+
+/// Leadership of this instance, given up when dropped, even if the campaign
+/// is cancelled mid-term.
+struct Term<'a> {
+    leadership: &'a watch::Sender<bool>,
+}
+
+impl<'a> Term<'a> {
+    fn begin(leadership: &'a watch::Sender<bool>) -> Self {
+        leadership.send_replace(true);
+        Self { leadership }
+    }
+}
+
+impl Drop for Term<'_> {
+    fn drop(&mut self) {
+        self.leadership.send_replace(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::atomic::Ordering;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::lock::errors::LockError;
+
+    mock! {
+        pub TestLock {}
+
+        #[async_trait]
+        impl DistributedLock for TestLock {
+            async fn try_acquire(&self, name: &str) -> Result<Option<Box<dyn LockGuard>>, LockError>;
+        }
+    }
+
+    /// Guard whose lock is lost once `lost` is set
+    struct TestGuard {
+        lost: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl LockGuard for TestGuard {
+        async fn renew(&mut self) -> Result<(), LockError> {
+            if self.lost.load(Ordering::SeqCst) {
+                return Err(LockError::Lost(
+                    "coordinator".to_string(),
+                    "gone".to_string(),
+                ));
+            }
+            Ok(())
+        }
+
+        async fn release(self: Box<Self>) -> Result<(), LockError> {
+            Ok(())
+        }
+    }
+
+    /// Lease repository keeping leases in memory
+    #[derive(Default)]
+    struct InMemoryLeases {
+        leases: Mutex<HashMap<String, LeaderLease>>,
+    }
+
+    #[async_trait]
+    impl LeaderLeaseRepository for InMemoryLeases {
+        async fn record(&self, lease: &LeaderLease) -> Result<(), LeaderError> {
+            self.leases
+                .lock()
+                .unwrap()
+                .insert(lease.name.clone(), lease.clone());
+            Ok(())
+        }
+
+        async fn find(&self, name: &str) -> Result<Option<LeaderLease>, LeaderError> {
+            Ok(self.leases.lock().unwrap().get(name).cloned())
+        }
+
+        async fn clear(&self, name: &str, instance_id: &InstanceId) -> Result<(), LeaderError> {
+            let mut leases = self.leases.lock().unwrap();
+            if leases.get(name).map(|lease| lease.instance_id) == Some(*instance_id) {
+                leases.remove(name);
+            }
+            Ok(())
+        }
+    }
+
+    fn settings() -> LeaderElectionSettings {
+        LeaderElectionSettings {
+            renew_interval: Duration::from_millis(5),
+            lease_ttl: Duration::from_millis(15),
+            retry_interval: Duration::from_millis(5),
+        }
+    }
+
+    async fn eventually(condition: impl Fn() -> bool) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !condition() {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_leader_steps_down_when_lock_is_lost() {
+        let lost = Arc::new(AtomicBool::new(false));
+        let attempts = Arc::new(AtomicU32::new(0));
+        let mut lock = MockTestLock::new();
+        let (guard_lost, lock_attempts) = (Arc::clone(&lost), Arc::clone(&attempts));
+        lock.expect_try_acquire()
+            .withf(|name| name == "coordinator")
+            .returning(move |_| {
+                // Elected once, then another instance holds the lock
+                if lock_attempts.fetch_add(1, Ordering::SeqCst) > 0 {
+                    return Ok(None);
+                }
+                Ok(Some(Box::new(TestGuard {
+                    lost: Arc::clone(&guard_lost),
+                }) as Box<dyn LockGuard>))
+            });
+        let leases = Arc::new(InMemoryLeases::default());
+        let instance_id = InstanceId::new();
+        let election = Arc::new(LeaderElection::new(
+            "coordinator",
+            Arc::new(lock),
+            Arc::clone(&leases),
+            instance_id,
+            settings(),
+        ));
+
+        let campaign = Arc::clone(&election);
+        let handle = tokio::spawn(async move { campaign.run().await });
+
+        eventually(|| election.is_leader()).await;
+        let lease = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Some(lease) = election.leader().await.unwrap() {
+                    return lease;
+                }
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(lease.instance_id, instance_id);
+        assert!(lease.expires_at > lease.renewed_at);
+
+        lost.store(true, Ordering::SeqCst);
+        eventually(|| !election.is_leader()).await;
+        assert!(election.leader().await.unwrap().is_none());
+        eventually(|| attempts.load(Ordering::SeqCst) > 1).await;
+        assert!(!election.is_leader());
+
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_coordinator_task_runs_only_while_leader() {
+        let lost = Arc::new(AtomicBool::new(false));
+        let mut lock = MockTestLock::new();
+        let guard_lost = Arc::clone(&lost);
+        lock.expect_try_acquire().returning(move |_| {
+            if guard_lost.load(Ordering::SeqCst) {
+                return Ok(None);
+            }
+            Ok(Some(Box::new(TestGuard {
+                lost: Arc::clone(&guard_lost),
+            }) as Box<dyn LockGuard>))
+        });
+        let election = Arc::new(LeaderElection::new(
+            "coordinator",
+            Arc::new(lock),
+            Arc::new(InMemoryLeases::default()),
+            InstanceId::new(),
+            settings(),
+        ));
+        let running = Arc::new(AtomicBool::new(false));
+
+        let coordinator = Arc::clone(&election);
+        let task_running = Arc::clone(&running);
+        let task = tokio::spawn(async move {
+            coordinator
+                .while_leader(|| {
+                    let running = Arc::clone(&task_running);
+                    async move {
+                        running.store(true, Ordering::SeqCst);
+                        // Flag reset when cancelled
+                        let _running = ResetOnDrop(running);
+                        std::future::pending::<anyhow::Result<()>>().await
+                    }
+                })
+                .await
+        });
+        assert!(!running.load(Ordering::SeqCst));
+
+        let campaign = Arc::clone(&election);
+        let handle = tokio::spawn(async move { campaign.run().await });
+        eventually(|| running.load(Ordering::SeqCst)).await;
+
+        lost.store(true, Ordering::SeqCst);
+        eventually(|| !running.load(Ordering::SeqCst)).await;
+        assert!(!election.is_leader());
+
+        handle.abort();
+        task.abort();
+    }
+
+    struct ResetOnDrop(Arc<AtomicBool>);
+
+    impl Drop for ResetOnDrop {
+        fn drop(&mut self) {
+            self.0.store(false, Ordering::SeqCst);
+        }
+    }
+}
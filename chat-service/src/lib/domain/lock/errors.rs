@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+/// Error for distributed lock operations
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum LockError {
+    #[error("Lock backend unavailable: {0}")]
+    Unavailable(String),
+
+    #[error("Lock {0} was lost: {1}")]
+    Lost(String, String),
+
+    #[error("Failed to release lock {0}: {1}")]
+    ReleaseFailed(String, String),
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Identity of a running chat-service instance.
+///
+/// Generated once at startup, so every process of a deployment, including
+/// restarts on the same host, is told apart in logs and lock ownership.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct InstanceId(Uuid);
+
+impl InstanceId {
+    /// Generate the identity of this instance.
+    ///
+    /// # Returns
+    /// InstanceId with random UUID v4
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Create an instance ID from a stored UUID.
+    ///
+    /// # Arguments
+    /// * `uuid` - UUID of the instance
+    ///
+    /// # Returns
+    /// InstanceId wrapping the UUID
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Get the underlying UUID.
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for InstanceId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for InstanceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+
+use super::errors::LockError;
+
+/// Port for locks shared by every instance of the deployment.
+///
+/// At most one instance holds a lock of a given name at a time. A lock is
+/// released with its guard, and by the backend when the holding instance dies.
+#[async_trait]
+pub trait DistributedLock: Send + Sync + 'static {
+    /// Take a lock if no instance holds it, without waiting.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the lock, e.g. the job it protects
+    ///
+    /// # Returns
+    /// Guard holding the lock, None if another instance holds it
+    ///
+    /// # Errors
+    /// * `Unavailable` - Lock backend cannot be reached
+    async fn try_acquire(&self, name: &str) -> Result<Option<Box<dyn LockGuard>>, LockError>;
+}
+
+/// Held distributed lock.
+///
+/// Dropping a guard without [`LockGuard::release`] still frees the lock, but
+/// only once the backend notices, e.g. when its connection is closed.
+#[async_trait]
+pub trait LockGuard: Send {
+    /// Check that the lock is still held.
+    ///
+    /// Called periodically by long-lived holders, such as an elected leader,
+    /// to notice a lock the backend freed behind their back.
+    ///
+    /// # Errors
+    /// * `Lost` - Lock is no longer held
+    async fn renew(&mut self) -> Result<(), LockError>;
+
+    /// Release the lock.
+    ///
+    /// # Errors
+    /// * `ReleaseFailed` - Backend failed to release the lock
+    async fn release(self: Box<Self>) -> Result<(), LockError>;
+}
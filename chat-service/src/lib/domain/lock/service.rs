@@ -0,0 +1,197 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::models::InstanceId;
+use super::ports::DistributedLock;
+
+/// Runs scheduled jobs on exactly one instance of the deployment.
+///
+/// Every instance schedules the same jobs; before each run, an instance takes
+/// the job's distributed lock and skips the run if another instance holds it.
+pub struct SingletonScheduler<L>
+where
+    L: DistributedLock,
+{
+    lock: Arc<L>,
+    instance_id: InstanceId,
+}
+
+impl<L> SingletonScheduler<L>
+where
+    L: DistributedLock,
+{
+    /// Create a scheduler for this instance.
+    ///
+    /// # Arguments
+    /// * `lock` - Locks shared by the instances of the deployment
+    /// * `instance_id` - Identity of this instance, logged with each run
+    ///
+    /// # Returns
+    /// Configured scheduler instance
+    pub fn new(lock: Arc<L>, instance_id: InstanceId) -> Self {
+        Self { lock, instance_id }
+    }
+
+    /// Run a job unless another instance is running it.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the job, also the name of its lock
+    /// * `job` - Run of the job
+    ///
+    /// # Returns
+    /// True if this instance ran the job, false if another instance holds its lock
+    ///
+    /// # Errors
+    /// Error of the lock backend or of the job
+    pub async fn run_once<Fut>(&self, name: &str, job: Fut) -> anyhow::Result<bool>
+    where
+        Fut: Future<Output = anyhow::Result<()>>,
+    {
+        let Some(guard) = self.lock.try_acquire(name).await? else {
+            tracing::debug!(job = name, "Job runs on another instance, skipping");
+            return Ok(false);
+        };
+
+        tracing::debug!(job = name, instance_id = %self.instance_id, "Running singleton job");
+        let result = job.await;
+        if let Err(e) = guard.release().await {
+            tracing::warn!(job = name, error = %e, "Failed to release job lock");
+        }
+
+        result.map(|()| true)
+    }
+
+    /// Run a job every `period` on whichever instance gets its lock first.
+    ///
+    /// Failed runs are logged and retried at the next period, so the future
+    /// only completes if it is dropped.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the job, also the name of its lock
+    /// * `period` - Time between two runs
+    /// * `job` - Builds each run of the job
+    pub async fn run_every<F, Fut>(
+        &self,
+        name: &str,
+        period: Duration,
+        job: F,
+    ) -> anyhow::Result<()>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = anyhow::Result<()>>,
+    {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            if let Err(e) = self.run_once(name, job()).await {
+                tracing::warn!(job = name, error = %e, "Singleton job failed");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::lock::errors::LockError;
+    use crate::domain::lock::ports::LockGuard;
+
+    mock! {
+        pub TestLock {}
+
+        #[async_trait]
+        impl DistributedLock for TestLock {
+            async fn try_acquire(&self, name: &str) -> Result<Option<Box<dyn LockGuard>>, LockError>;
+        }
+    }
+
+    struct TestGuard {
+        released: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl LockGuard for TestGuard {
+        async fn renew(&mut self) -> Result<(), LockError> {
+            Ok(())
+        }
+
+        async fn release(self: Box<Self>) -> Result<(), LockError> {
+            self.released.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_once_runs_job_while_holding_lock() {
+        let released = Arc::new(AtomicBool::new(false));
+        let mut lock = MockTestLock::new();
+        let guard_released = Arc::clone(&released);
+        lock.expect_try_acquire()
+            .withf(|name| name == "retention")
+            .times(1)
+            .returning(move |_| {
+                Ok(Some(Box::new(TestGuard {
+                    released: Arc::clone(&guard_released),
+                }) as Box<dyn LockGuard>))
+            });
+        let scheduler = SingletonScheduler::new(Arc::new(lock), InstanceId::new());
+
+        let ran = AtomicBool::new(false);
+        let result = scheduler
+            .run_once("retention", async {
+                ran.store(true, Ordering::SeqCst);
+                Ok(())
+            })
+            .await;
+
+        assert!(result.unwrap());
+        assert!(ran.load(Ordering::SeqCst));
+        assert!(released.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_run_once_skips_job_held_elsewhere() {
+        let mut lock = MockTestLock::new();
+        lock.expect_try_acquire().times(1).returning(|_| Ok(None));
+        let scheduler = SingletonScheduler::new(Arc::new(lock), InstanceId::new());
+
+        let ran = AtomicBool::new(false);
+        let result = scheduler
+            .run_once("retention", async {
+                ran.store(true, Ordering::SeqCst);
+                Ok(())
+            })
+            .await;
+
+        assert!(!result.unwrap());
+        assert!(!ran.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_run_once_releases_lock_of_failed_job() {
+        let released = Arc::new(AtomicBool::new(false));
+        let mut lock = MockTestLock::new();
+        let guard_released = Arc::clone(&released);
+        lock.expect_try_acquire().times(1).returning(move |_| {
+            Ok(Some(Box::new(TestGuard {
+                released: Arc::clone(&guard_released),
+            }) as Box<dyn LockGuard>))
+        });
+        let scheduler = SingletonScheduler::new(Arc::new(lock), InstanceId::new());
+
+        let result = scheduler
+            .run_once("retention", async { Err(anyhow::anyhow!("disk full")) })
+            .await;
+
+        assert!(result.is_err());
+        assert!(released.load(Ordering::SeqCst));
+    }
+}
@@ -11,6 +11,9 @@ use crate::UserId;
 pub enum MessageIdError {
     #[error("Invalid UUID format: {0}")]
     InvalidFormat(String),
+
+    #[error("Message ID must be a UUIDv7 or time-based UUID (v1), got version {0}")]
+    NotTimeBased(usize),
 }
 
 /// Error type for MessageContent validation failures
@@ -23,6 +26,13 @@ pub enum MessageContentError {
     TooLong { max: usize, actual: usize },
 }
 
+/// Error type for LanguageCode validation failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum LanguageCodeError {
+    #[error("Invalid language code: {0}")]
+    InvalidFormat(String),
+}
+
 /// Top-level error type for all message-related operations
 #[derive(Debug, Error)]
 pub enum MessageError {
@@ -48,6 +58,15 @@ pub enum MessageError {
     #[error("User not found: {0}")]
     UserNotFound(UserId),
 
+    #[error("Message ID already exists: {0}")]
+    IdAlreadyExists(MessageId),
+
+    #[error("Not allowed to delete message: {0}")]
+    DeleteForbidden(MessageId),
+
+    #[error("Only the owner and moderators may post in announcement channel {0}")]
+    AnnouncementOnly(ChannelId),
+
     // Infrastructure errors
     #[error("Database error: {0}")]
     DatabaseError(String),
@@ -56,6 +75,19 @@ pub enum MessageError {
     Unknown(String),
 }
 
+impl MessageError {
+    /// Machine-readable code of the error, for errors clients are expected to handle.
+    ///
+    /// # Returns
+    /// Stable code, None for errors without one
+    pub fn code(&self) -> Option<&'static str> {
+        match self {
+            MessageError::AnnouncementOnly(_) => Some("channel_announcement_only"),
+            _ => None,
+        }
+    }
+}
+
 impl From<anyhow::Error> for MessageError {
     fn from(err: anyhow::Error) -> Self {
         MessageError::Unknown(err.to_string())
@@ -60,6 +60,8 @@ pub struct MessageSentEvent {
     pub user_id: UserId,
     pub content: String,
     pub timestamp: DateTime<Utc>,
+    /// ISO 639 code of the detected content language
+    pub language: Option<String>,
 }
 
 impl MessageSentEvent {
@@ -80,6 +82,10 @@ impl MessageSentEvent {
             user_id: message.user_id,
             content: message.content.as_str().to_string(),
             timestamp: message.timestamp,
+            language: message
+                .language
+                .as_ref()
+                .map(|language| language.as_str().to_string()),
         }
     }
 }
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::fmt;
 
 use chrono::DateTime;
@@ -6,6 +7,7 @@ use uuid::Timestamp;
 use uuid::Uuid;
 
 use crate::domain::channel::models::ChannelId;
+use crate::domain::message::errors::LanguageCodeError;
 use crate::domain::message::errors::MessageContentError;
 use crate::domain::message::errors::MessageIdError;
 use crate::domain::user::models::UserId;
@@ -20,14 +22,118 @@ pub struct Message {
     pub user_id: UserId,
     pub content: MessageContent,
     pub timestamp: DateTime<Utc>,
+    /// Detected language of the content, None if detection was inconclusive
+    pub language: Option<LanguageCode>,
+}
+
+/// Tombstone left in channel history by a deleted message.
+///
+/// Keeps the position of the message so clients paging history can render a
+/// placeholder instead of a silent gap.
+#[derive(Debug, Clone)]
+pub struct MessageTombstone {
+    pub id: MessageId,
+    pub channel_id: ChannelId,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: UserId,
+}
+
+/// User sending a message, with the standing that decides where they may post.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sender {
+    pub user_id: UserId,
+    /// Moderators may post in announcement-only channels they do not own
+    pub moderator: bool,
+}
+
+impl Sender {
+    /// Create a sender without moderator rights.
+    ///
+    /// # Arguments
+    /// * `user_id` - User sending the message
+    ///
+    /// # Returns
+    /// Sender allowed to post where any member may
+    pub fn member(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            moderator: false,
+        }
+    }
+}
+
+/// Stage reached by a message on its way to the members of its channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DeliveryStatus {
+    /// Validated and allowed in the channel
+    Accepted,
+    /// Written to the message store, so it is part of channel history
+    Stored,
+    /// Acknowledged by the event broker, so it is broadcast to connected members
+    Published,
+}
+
+/// Entry of channel history.
+#[derive(Debug, Clone)]
+pub enum HistoryEntry {
+    /// Message as sent
+    Message(Message),
+    /// Tombstone of a deleted message
+    Deleted(MessageTombstone),
+}
+
+impl HistoryEntry {
+    /// Get the ID of the message the entry stands for.
+    ///
+    /// # Returns
+    /// Message identifier
+    pub fn id(&self) -> MessageId {
+        match self {
+            HistoryEntry::Message(message) => message.id,
+            HistoryEntry::Deleted(tombstone) => tombstone.id,
+        }
+    }
+
+    /// Check if the entry is the tombstone of a deleted message.
+    pub fn is_deleted(&self) -> bool {
+        matches!(self, HistoryEntry::Deleted(_))
+    }
 }
 
 /// Message unique identifier value object.
 ///
-/// Uses UUID v1 (TimeUUID) for Cassandra compatibility and time-based ordering.
+/// Either a UUID v1 (TimeUUID), as used by Cassandra's timeuuid type, or a
+/// UUIDv7, whose byte order is its time order. IDs of both versions order by
+/// their embedded time, see [`MessageIdVersion`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MessageId(pub Uuid);
 
+/// Version of newly generated message IDs.
+///
+/// Existing messages keep their IDs, so history mixes both versions once
+/// UUIDv7 generation is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageIdVersion {
+    /// UUID v1 (TimeUUID)
+    #[default]
+    TimeUuid,
+    /// UUIDv7, lexicographically sortable
+    V7,
+}
+
+impl MessageIdVersion {
+    /// Generate a new message ID of this version.
+    ///
+    /// # Returns
+    /// MessageId of this version for the current time
+    pub fn generate(self) -> MessageId {
+        match self {
+            MessageIdVersion::TimeUuid => MessageId::new_time_based(),
+            MessageIdVersion::V7 => MessageId::new_v7(),
+        }
+    }
+}
+
 impl MessageId {
     /// Generate a new time-based message ID.
     ///
@@ -42,6 +148,19 @@ impl MessageId {
         Self(Uuid::new_v1(timestamp, &node_id))
     }
 
+    /// Generate a new UUIDv7 message ID.
+    ///
+    /// # Returns
+    /// MessageId with a UUIDv7
+    pub fn new_v7() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Check if the ID is a UUIDv7 rather than a TimeUUID.
+    pub fn is_v7(&self) -> bool {
+        self.0.get_version_num() == 7
+    }
+
     /// Parse a message ID from string.
     ///
     /// # Arguments
@@ -58,6 +177,36 @@ impl MessageId {
             .map_err(|e| MessageIdError::InvalidFormat(e.to_string()))
     }
 
+    /// Parse a message ID supplied by an external system.
+    ///
+    /// Only time-ordered UUIDs (v1 and v7) keep history in order.
+    ///
+    /// # Arguments
+    /// * `s` - UUID string to parse
+    ///
+    /// # Returns
+    /// Parsed MessageId
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a valid UUID
+    /// * `NotTimeBased` - UUID is neither a v1 nor a v7 UUID
+    pub fn from_supplied(s: &str) -> Result<Self, MessageIdError> {
+        let id = Self::from_string(s)?;
+        match id.0.get_version_num() {
+            1 | 7 => Ok(id),
+            version => Err(MessageIdError::NotTimeBased(version)),
+        }
+    }
+
+    /// Get the time embedded in a time-based ID.
+    ///
+    /// # Returns
+    /// Creation time of the ID, None for IDs without a timestamp
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        let (seconds, nanos) = self.0.get_timestamp()?.to_unix();
+        DateTime::from_timestamp(i64::try_from(seconds).ok()?, nanos)
+    }
+
     /// Get a reference to the inner UUID.
     ///
     /// # Returns
@@ -75,6 +224,22 @@ impl MessageId {
     }
 }
 
+/// Orders by embedded time first, so that TimeUUIDs and UUIDv7s interleave
+/// chronologically; IDs without a timestamp sort first.
+impl Ord for MessageId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.timestamp()
+            .cmp(&other.timestamp())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl PartialOrd for MessageId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Display for MessageId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -88,7 +253,7 @@ impl fmt::Display for MessageId {
 pub struct MessageContent(String);
 
 impl MessageContent {
-    const MAX_LENGTH: usize = 4000;
+    pub const MAX_LENGTH: usize = 4000;
 
     /// Create a new validated message content.
     ///
@@ -123,3 +288,43 @@ impl MessageContent {
         &self.0
     }
 }
+
+/// Language code value object.
+///
+/// Lowercase ISO 639 code of two or three letters, e.g. "en" or "fil".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LanguageCode(String);
+
+impl LanguageCode {
+    /// Create a new validated language code.
+    ///
+    /// # Arguments
+    /// * `code` - ISO 639 code, case-insensitive
+    ///
+    /// # Returns
+    /// Lowercased LanguageCode value object
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - Code is not two or three ASCII letters
+    pub fn new(code: &str) -> Result<Self, LanguageCodeError> {
+        if (2..=3).contains(&code.len()) && code.bytes().all(|b| b.is_ascii_alphabetic()) {
+            Ok(Self(code.to_ascii_lowercase()))
+        } else {
+            Err(LanguageCodeError::InvalidFormat(code.to_string()))
+        }
+    }
+
+    /// Get the code as string slice.
+    ///
+    /// # Returns
+    /// Lowercase code string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
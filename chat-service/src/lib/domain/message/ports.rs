@@ -1,11 +1,19 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use chrono::DateTime;
 use chrono::Utc;
 
 use super::events::MessageDeletedEvent;
 use super::events::MessageSentEvent;
+use super::models::DeliveryStatus;
+use super::models::HistoryEntry;
+use super::models::LanguageCode;
 use super::models::Message;
 use super::models::MessageContent;
+use super::models::MessageId;
+use super::models::MessageTombstone;
+use super::models::Sender;
 use crate::domain::channel::models::ChannelId;
 use crate::domain::errors::EventPublisherError;
 use crate::domain::message::errors::MessageError;
@@ -21,7 +29,7 @@ pub trait MessageServicePort: Send + Sync + 'static {
     ///
     /// # Arguments
     /// * `channel_id` - Target channel ID
-    /// * `user_id` - Sender user ID
+    /// * `sender` - User sending the message
     /// * `content` - Validated message content
     ///
     /// # Returns
@@ -29,25 +37,89 @@ pub trait MessageServicePort: Send + Sync + 'static {
     ///
     /// # Errors
     /// * `ChannelNotFound` - Channel does not exist
+    /// * `AnnouncementOnly` - Channel is announcement-only and the sender is
+    ///   neither its owner nor a moderator
     /// * `DatabaseError` - Database operation failed
     async fn send_message(
         &self,
         channel_id: ChannelId,
-        user_id: UserId,
+        sender: Sender,
         content: MessageContent,
     ) -> Result<Message, MessageError>;
 
-    /// Retrieve messages from a channel with pagination.
+    /// Send a message under an ID supplied by an external system.
     ///
-    /// Returns messages in reverse chronological order (newest first).
+    /// The message is timestamped with the time embedded in the ID, so
+    /// imported messages keep their original position in history.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Target channel ID
+    /// * `message_id` - Time-based message ID chosen by the caller
+    /// * `sender` - User sending the message
+    /// * `content` - Validated message content
+    ///
+    /// # Returns
+    /// Created message entity
+    ///
+    /// # Errors
+    /// * `InvalidMessageId` - ID carries no timestamp
+    /// * `ChannelNotFound` - Channel does not exist
+    /// * `AnnouncementOnly` - Channel is announcement-only and the sender is
+    ///   neither its owner nor a moderator
+    /// * `IdAlreadyExists` - A message with the ID exists in the channel
+    /// * `DatabaseError` - Database operation failed
+    async fn send_message_with_id(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        sender: Sender,
+        content: MessageContent,
+    ) -> Result<Message, MessageError>;
+
+    /// Send a message, reporting each delivery stage as it is reached.
+    ///
+    /// A message sent again under an ID its sender already stored is not
+    /// stored twice but published again, so clients may retry until the
+    /// message is reported `Published`.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Target channel ID
+    /// * `message_id` - Time-based message ID chosen by the client, None to generate one
+    /// * `sender` - User sending the message
+    /// * `content` - Validated message content
+    /// * `reporter` - Receiver of the delivery stages
+    ///
+    /// # Returns
+    /// Stored message entity
+    ///
+    /// # Errors
+    /// * `InvalidMessageId` - ID carries no timestamp
+    /// * `ChannelNotFound` - Channel does not exist
+    /// * `AnnouncementOnly` - Channel is announcement-only and the sender is
+    ///   neither its owner nor a moderator
+    /// * `IdAlreadyExists` - Another user's message has the ID
+    /// * `DatabaseError` - Database operation failed
+    async fn send_message_reporting(
+        &self,
+        channel_id: ChannelId,
+        message_id: Option<MessageId>,
+        sender: Sender,
+        content: MessageContent,
+        reporter: Arc<dyn DeliveryReporter>,
+    ) -> Result<Message, MessageError>;
+
+    /// Retrieve the history of a channel with pagination.
+    ///
+    /// Returns entries in reverse chronological order (newest first). Deleted
+    /// messages appear as tombstones unless tombstones are disabled.
     ///
     /// # Arguments
     /// * `channel_id` - Channel ID to query
-    /// * `limit` - Maximum number of messages to return
-    /// * `before` - Optional timestamp cursor for pagination (fetch messages before this time)
+    /// * `limit` - Maximum number of entries to return
+    /// * `before` - Optional timestamp cursor for pagination (fetch entries before this time)
     ///
     /// # Returns
-    /// Vector of messages ordered by timestamp descending
+    /// Vector of history entries ordered by timestamp descending
     ///
     /// # Errors
     /// * `DatabaseError` - Database operation failed
@@ -56,7 +128,28 @@ pub trait MessageServicePort: Send + Sync + 'static {
         channel_id: ChannelId,
         limit: i32,
         before: Option<DateTime<Utc>>,
-    ) -> Result<Vec<Message>, MessageError>;
+    ) -> Result<Vec<HistoryEntry>, MessageError>;
+
+    /// Delete a message, leaving a tombstone in channel history.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel containing the message
+    /// * `message_id` - Message to delete
+    /// * `deleted_by` - User deleting the message
+    ///
+    /// # Returns
+    /// Tombstone replacing the message
+    ///
+    /// # Errors
+    /// * `NotFound` - Message does not exist in the channel or is already deleted
+    /// * `DeleteForbidden` - User is not the author of the message
+    /// * `DatabaseError` - Database operation failed
+    async fn delete_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        deleted_by: UserId,
+    ) -> Result<MessageTombstone, MessageError>;
 }
 
 /// Repository port for message persistence operations.
@@ -76,9 +169,23 @@ pub trait MessageRepository: Send + Sync + 'static {
     /// * `DatabaseError` - Database operation failed
     async fn create(&self, message: Message) -> Result<Message, MessageError>;
 
+    /// Persist a new message unless its ID is taken.
+    ///
+    /// # Arguments
+    /// * `message` - Message entity to create
+    ///
+    /// # Returns
+    /// Created message
+    ///
+    /// # Errors
+    /// * `IdAlreadyExists` - A message with the ID exists in the channel
+    /// * `DatabaseError` - Database operation failed
+    async fn create_if_absent(&self, message: Message) -> Result<Message, MessageError>;
+
     /// Retrieve messages from channel with pagination.
     ///
     /// Returns messages in reverse chronological order (newest first).
+    /// Deleted messages are skipped, so a page may hold fewer than `limit`.
     ///
     /// # Arguments
     /// * `channel_id` - Channel ID to query
@@ -112,6 +219,89 @@ pub trait MessageRepository: Send + Sync + 'static {
     /// * `DatabaseError` - Database operation failed
     async fn find_by_user(&self, user_id: UserId, limit: i32)
         -> Result<Vec<Message>, MessageError>;
+
+    /// Retrieve a message of a channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel containing the message
+    /// * `message_id` - Message ID to look up
+    ///
+    /// # Returns
+    /// Message, or None if it does not exist or was deleted
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_by_id(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<Option<Message>, MessageError>;
+
+    /// Retrieve the history of a channel, tombstones of deleted messages included.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel ID to query
+    /// * `limit` - Maximum number of entries to return
+    /// * `before` - Optional timestamp cursor for pagination (fetch entries before this time)
+    ///
+    /// # Returns
+    /// Vector of history entries ordered by timestamp descending
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_history(
+        &self,
+        channel_id: ChannelId,
+        limit: i32,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<Vec<HistoryEntry>, MessageError>;
+
+    /// Replace a message with its tombstone.
+    ///
+    /// The content is erased from channel history and the message removed
+    /// from the history of its author.
+    ///
+    /// # Arguments
+    /// * `message` - Message to delete
+    /// * `tombstone` - Tombstone taking its place
+    ///
+    /// # Returns
+    /// Stored tombstone
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn delete(
+        &self,
+        message: &Message,
+        tombstone: MessageTombstone,
+    ) -> Result<MessageTombstone, MessageError>;
+}
+
+/// Port for detecting the language of message content.
+///
+/// Runs inline when a message is sent, so implementations must be cheap and
+/// must not perform I/O.
+pub trait LanguageDetector: Send + Sync + 'static {
+    /// Detect the language of a message.
+    ///
+    /// # Arguments
+    /// * `content` - Message content to classify
+    ///
+    /// # Returns
+    /// Detected language, None if the content is too short or ambiguous
+    fn detect(&self, content: &str) -> Option<LanguageCode>;
+}
+
+/// Port receiving the delivery stages of a message being sent.
+///
+/// Called inline while the message is sent, so implementations must not block.
+pub trait DeliveryReporter: Send + Sync {
+    /// Report that a message reached a delivery stage.
+    ///
+    /// # Arguments
+    /// * `message_id` - Message being sent
+    /// * `status` - Stage reached
+    fn report(&self, message_id: MessageId, status: DeliveryStatus);
 }
 
 /// Event publishing for message domain events.
@@ -2,42 +2,59 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use chrono::Utc;
+use clock::Clock;
 
+use super::events::MessageDeletedEvent;
 use super::events::MessageSentEvent;
+use super::models::DeliveryStatus;
+use super::models::HistoryEntry;
 use super::models::Message;
 use super::models::MessageContent;
 use super::models::MessageId;
+use super::models::MessageIdVersion;
+use super::models::MessageTombstone;
+use super::models::Sender;
+use super::ports::DeliveryReporter;
+use super::ports::LanguageDetector;
 use super::ports::MessageEventPublisher;
 use super::ports::MessageRepository;
 use super::ports::MessageServicePort;
 use crate::domain::channel::models::ChannelId;
 use crate::domain::channel::ports::ChannelRepository;
 use crate::domain::message::errors::MessageError;
+use crate::domain::message::errors::MessageIdError;
 use crate::domain::user::models::UserId;
 use crate::domain::user::ports::UserServicePort;
 
 /// Concrete implementation of MessageServicePort.
 ///
 /// Manages message creation, retrieval, and event publishing with eventual consistency.
-pub struct MessageService<MR, CR, UC, EP>
+pub struct MessageService<MR, CR, UC, EP, LD>
 where
     MR: MessageRepository,
     CR: ChannelRepository,
     UC: UserServicePort,
     EP: MessageEventPublisher,
+    LD: LanguageDetector,
 {
     message_repository: Arc<MR>,
     channel_repository: Arc<CR>,
+    #[allow(dead_code)]
     user_proxy: Arc<UC>,
     event_publisher: Arc<EP>,
+    language_detector: Arc<LD>,
+    include_tombstones: bool,
+    id_version: MessageIdVersion,
+    clock: Arc<dyn Clock>,
 }
 
-impl<MR, CR, UC, EP> MessageService<MR, CR, UC, EP>
+impl<MR, CR, UC, EP, LD> MessageService<MR, CR, UC, EP, LD>
 where
     MR: MessageRepository,
     CR: ChannelRepository,
     UC: UserServicePort,
     EP: MessageEventPublisher,
+    LD: LanguageDetector,
 {
     /// Create a new message service with injected dependencies.
     ///
@@ -46,6 +63,7 @@ where
     /// * `channel_repository` - Channel repository for validation
     /// * `user_proxy` - User service client for future enrichment
     /// * `event_publisher` - Event publisher implementation
+    /// * `language_detector` - Detector tagging sent messages with their language
     ///
     /// # Returns
     /// Configured message service instance
@@ -54,60 +72,245 @@ where
         channel_repository: Arc<CR>,
         user_proxy: Arc<UC>,
         event_publisher: Arc<EP>,
+        language_detector: Arc<LD>,
     ) -> Self {
         Self {
             message_repository,
             channel_repository,
             user_proxy,
             event_publisher,
+            language_detector,
+            include_tombstones: true,
+            id_version: MessageIdVersion::default(),
+            clock: clock::system(),
+        }
+    }
+
+    /// Set whether channel history includes tombstones of deleted messages.
+    ///
+    /// Enabled by default. When disabled, deleted messages leave gaps.
+    ///
+    /// # Arguments
+    /// * `include_tombstones` - Return tombstones in channel history
+    ///
+    /// # Returns
+    /// Service with the history setting applied
+    pub fn with_tombstones(mut self, include_tombstones: bool) -> Self {
+        self.include_tombstones = include_tombstones;
+        self
+    }
+
+    /// Set the version of generated message IDs.
+    ///
+    /// TimeUUIDs by default. Messages sent earlier keep their IDs either way.
+    ///
+    /// # Arguments
+    /// * `id_version` - Version of IDs given to new messages
+    ///
+    /// # Returns
+    /// Service with the ID version applied
+    pub fn with_id_version(mut self, id_version: MessageIdVersion) -> Self {
+        self.id_version = id_version;
+        self
+    }
+
+    /// Stamp messages and deletions by another clock.
+    ///
+    /// The system clock by default. IDs of new messages keep coming from the
+    /// system time, so history stays in send order whatever the clock reads.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock giving sent messages their timestamp
+    ///
+    /// # Returns
+    /// Service telling the time by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    async fn ensure_may_post(
+        &self,
+        channel_id: ChannelId,
+        sender: &Sender,
+    ) -> Result<(), MessageError> {
+        let channel = self
+            .channel_repository
+            .find_by_id(channel_id)
+            .await
+            .map_err(|e| MessageError::DatabaseError(e.to_string()))?
+            .ok_or(MessageError::ChannelNotFound(channel_id))?;
+        if !channel.may_post(sender) {
+            return Err(MessageError::AnnouncementOnly(channel_id));
+        }
+        Ok(())
+    }
+
+    /// Record activity and publish the event of a saved message.
+    async fn after_send(&self, saved_message: &Message) {
+        self.record_activity(saved_message).await;
+        self.publish_sent(saved_message).await;
+    }
+
+    /// Update the channel activity rollup with a saved message.
+    async fn record_activity(&self, saved_message: &Message) {
+        let channel_id = saved_message.channel_id;
+
+        // Update the channel activity rollup (eventual consistency - message already saved)
+        if let Err(e) = self
+            .channel_repository
+            .record_message_activity(
+                channel_id,
+                saved_message.user_id,
+                saved_message.timestamp,
+                saved_message.language.clone(),
+            )
+            .await
+        {
+            tracing::warn!(
+                "Failed to record activity for channel {}: {}",
+                channel_id,
+                e
+            );
+        }
+    }
+
+    /// Publish the event of a saved message.
+    ///
+    /// # Returns
+    /// Whether the broker acknowledged the event
+    async fn publish_sent(&self, saved_message: &Message) -> bool {
+        // Event will be published to a topic/shard determined by implementation
+        let event = MessageSentEvent::new(saved_message);
+
+        if let Err(e) = self.event_publisher.publish_message_sent(&event).await {
+            tracing::error!("Failed to publish message event: {}", e);
+            false
+        } else {
+            tracing::debug!(
+                "Published message event for message {} in channel {}",
+                saved_message.id,
+                saved_message.channel_id
+            );
+            true
         }
     }
 }
 
 #[async_trait]
-impl<MR, CR, UC, EP> MessageServicePort for MessageService<MR, CR, UC, EP>
+impl<MR, CR, UC, EP, LD> MessageServicePort for MessageService<MR, CR, UC, EP, LD>
 where
     MR: MessageRepository + 'static,
     CR: ChannelRepository + 'static,
     UC: UserServicePort + 'static,
     EP: MessageEventPublisher + 'static,
+    LD: LanguageDetector + 'static,
 {
     async fn send_message(
         &self,
         channel_id: ChannelId,
-        user_id: UserId,
+        sender: Sender,
         content: MessageContent,
     ) -> Result<Message, MessageError> {
-        // Verify channel exists
-        self.channel_repository
-            .find_by_id(channel_id)
-            .await
-            .map_err(|e| MessageError::DatabaseError(e.to_string()))?
-            .ok_or(MessageError::ChannelNotFound(channel_id))?;
+        self.ensure_may_post(channel_id, &sender).await?;
 
         let message = Message {
-            id: MessageId::new_time_based(),
+            id: self.id_version.generate(),
             channel_id,
-            user_id,
-            content: content.clone(),
-            timestamp: Utc::now(),
+            user_id: sender.user_id,
+            language: self.language_detector.detect(content.as_str()),
+            content,
+            timestamp: self.clock.now(),
         };
 
         // Save message to database
         let saved_message = self.message_repository.create(message).await?;
+        self.after_send(&saved_message).await;
 
-        // Publish event
-        // Event will be published to a topic/shard determined by implementation
-        let event = MessageSentEvent::new(&saved_message);
+        Ok(saved_message)
+    }
 
-        if let Err(e) = self.event_publisher.publish_message_sent(&event).await {
-            tracing::error!("Failed to publish message event: {}", e);
-        } else {
-            tracing::debug!(
-                "Published message event for message {} in channel {}",
-                saved_message.id,
-                saved_message.channel_id
-            );
+    async fn send_message_with_id(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        sender: Sender,
+        content: MessageContent,
+    ) -> Result<Message, MessageError> {
+        let timestamp = message_id.timestamp().ok_or(MessageIdError::NotTimeBased(
+            message_id.as_uuid().get_version_num(),
+        ))?;
+        self.ensure_may_post(channel_id, &sender).await?;
+
+        let message = Message {
+            id: message_id,
+            channel_id,
+            user_id: sender.user_id,
+            language: self.language_detector.detect(content.as_str()),
+            content,
+            timestamp,
+        };
+
+        let saved_message = self.message_repository.create_if_absent(message).await?;
+        self.after_send(&saved_message).await;
+
+        Ok(saved_message)
+    }
+
+    async fn send_message_reporting(
+        &self,
+        channel_id: ChannelId,
+        message_id: Option<MessageId>,
+        sender: Sender,
+        content: MessageContent,
+        reporter: Arc<dyn DeliveryReporter>,
+    ) -> Result<Message, MessageError> {
+        let (id, timestamp) = match message_id {
+            Some(id) => (
+                id,
+                id.timestamp()
+                    .ok_or(MessageIdError::NotTimeBased(id.as_uuid().get_version_num()))?,
+            ),
+            None => (self.id_version.generate(), self.clock.now()),
+        };
+        self.ensure_may_post(channel_id, &sender).await?;
+        reporter.report(id, DeliveryStatus::Accepted);
+
+        let message = Message {
+            id,
+            channel_id,
+            user_id: sender.user_id,
+            language: self.language_detector.detect(content.as_str()),
+            content,
+            timestamp,
+        };
+
+        let saved_message = match message_id {
+            None => self.message_repository.create(message).await?,
+            Some(_) => match self.message_repository.create_if_absent(message).await {
+                // A retry of a message stored before: publish it again, since
+                // the first attempt may have failed after storing it
+                Err(MessageError::IdAlreadyExists(_)) => {
+                    let stored = self
+                        .message_repository
+                        .find_by_id(channel_id, id)
+                        .await?
+                        .filter(|stored| stored.user_id == sender.user_id)
+                        .ok_or(MessageError::IdAlreadyExists(id))?;
+                    reporter.report(id, DeliveryStatus::Stored);
+                    if self.publish_sent(&stored).await {
+                        reporter.report(id, DeliveryStatus::Published);
+                    }
+                    return Ok(stored);
+                }
+                result => result?,
+            },
+        };
+        reporter.report(id, DeliveryStatus::Stored);
+
+        self.record_activity(&saved_message).await;
+        if self.publish_sent(&saved_message).await {
+            reporter.report(id, DeliveryStatus::Published);
         }
 
         Ok(saved_message)
@@ -118,11 +321,54 @@ where
         channel_id: ChannelId,
         limit: i32,
         before: Option<chrono::DateTime<Utc>>,
-    ) -> Result<Vec<Message>, MessageError> {
+    ) -> Result<Vec<HistoryEntry>, MessageError> {
+        if !self.include_tombstones {
+            let messages = self
+                .message_repository
+                .find_by_channel(channel_id, limit, before)
+                .await?;
+            return Ok(messages.into_iter().map(HistoryEntry::Message).collect());
+        }
+
         self.message_repository
-            .find_by_channel(channel_id, limit, before)
+            .find_history(channel_id, limit, before)
             .await
     }
+
+    async fn delete_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        deleted_by: UserId,
+    ) -> Result<MessageTombstone, MessageError> {
+        let message = self
+            .message_repository
+            .find_by_id(channel_id, message_id)
+            .await?
+            .ok_or(MessageError::NotFound(message_id))?;
+        if message.user_id != deleted_by {
+            return Err(MessageError::DeleteForbidden(message_id));
+        }
+
+        let tombstone = MessageTombstone {
+            id: message_id,
+            channel_id,
+            deleted_at: self.clock.now(),
+            deleted_by,
+        };
+        let tombstone = self.message_repository.delete(&message, tombstone).await?;
+
+        // Publish event (eventual consistency - tombstone already saved)
+        let event = MessageDeletedEvent {
+            deleted_at: tombstone.deleted_at,
+            ..MessageDeletedEvent::new(message_id, channel_id)
+        };
+        if let Err(e) = self.event_publisher.publish_message_deleted(&event).await {
+            tracing::error!("Failed to publish message deletion event: {}", e);
+        }
+
+        Ok(tombstone)
+    }
 }
 
 #[cfg(test)]
@@ -134,11 +380,18 @@ mod tests {
     use super::*;
     use crate::domain::channel::errors::ChannelError;
     use crate::domain::channel::models::Channel;
-    use crate::domain::channel::models::ChannelName;
-    use crate::domain::channel::models::PublicChannel;
+    use crate::domain::channel::models::ChannelActivity;
+    use crate::domain::channel::models::ChannelDirectoryEntry;
+    use crate::domain::channel::models::WorkspaceId;
     use crate::domain::channel::ports::ChannelRepository;
-    use crate::domain::message::events::MessageDeletedEvent;
+    use crate::domain::message::models::LanguageCode;
     use crate::domain::user::models::User;
+    use crate::fixtures::epoch;
+    use crate::fixtures::message_id_at;
+    use crate::fixtures::message_id_v7_at;
+    use crate::fixtures::user_id;
+    use crate::fixtures::ChannelFixture;
+    use crate::fixtures::MessageFixture;
 
     mock! {
         pub TestMessageRepository {}
@@ -146,6 +399,7 @@ mod tests {
         #[async_trait]
         impl MessageRepository for TestMessageRepository {
             async fn create(&self, message: Message) -> Result<Message, MessageError>;
+            async fn create_if_absent(&self, message: Message) -> Result<Message, MessageError>;
             async fn find_by_channel(
                 &self,
                 channel_id: ChannelId,
@@ -157,6 +411,22 @@ mod tests {
                 user_id: UserId,
                 limit: i32,
             ) -> Result<Vec<Message>, MessageError>;
+            async fn find_by_id(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+            ) -> Result<Option<Message>, MessageError>;
+            async fn find_history(
+                &self,
+                channel_id: ChannelId,
+                limit: i32,
+                before: Option<chrono::DateTime<Utc>>,
+            ) -> Result<Vec<HistoryEntry>, MessageError>;
+            async fn delete(
+                &self,
+                message: &Message,
+                tombstone: MessageTombstone,
+            ) -> Result<MessageTombstone, MessageError>;
         }
     }
 
@@ -168,8 +438,24 @@ mod tests {
             async fn create(&self, channel: Channel) -> Result<Channel, ChannelError>;
             async fn find_by_id(&self, id: ChannelId) -> Result<Option<Channel>, ChannelError>;
             async fn find_public_channels(&self) -> Result<Vec<Channel>, ChannelError>;
+            async fn find_directory_entries(&self) -> Result<Vec<ChannelDirectoryEntry>, ChannelError>;
             async fn find_by_user(&self, user_id: UserId) -> Result<Vec<Channel>, ChannelError>;
+            async fn set_auto_join(&self, id: ChannelId, auto_join: bool) -> Result<(), ChannelError>;
+            async fn add_to_auto_join_channels(
+                &self,
+                user_id: UserId,
+                workspace_id: WorkspaceId,
+                joined_at: chrono::DateTime<Utc>,
+            ) -> Result<Vec<ChannelId>, ChannelError>;
             async fn delete(&self, id: ChannelId) -> Result<(), ChannelError>;
+            async fn record_message_activity(
+                &self,
+                id: ChannelId,
+                user_id: UserId,
+                sent_at: chrono::DateTime<Utc>,
+                language: Option<LanguageCode>,
+            ) -> Result<(), ChannelError>;
+            async fn find_activity(&self, id: ChannelId) -> Result<ChannelActivity, ChannelError>;
         }
     }
 
@@ -179,6 +465,7 @@ mod tests {
         #[async_trait]
         impl UserServicePort for TestUserService {
             async fn get_user(&self, user_id: UserId) -> Result<Option<User>, String>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, String>;
         }
     }
 
@@ -199,6 +486,22 @@ mod tests {
         }
     }
 
+    mock! {
+        pub TestLanguageDetector {}
+
+        impl LanguageDetector for TestLanguageDetector {
+            fn detect(&self, content: &str) -> Option<LanguageCode>;
+        }
+    }
+
+    fn language_detector(language: Option<&'static str>) -> MockTestLanguageDetector {
+        let mut detector = MockTestLanguageDetector::new();
+        detector
+            .expect_detect()
+            .returning(move |_| language.map(|code| LanguageCode::new(code).unwrap()));
+        detector
+    }
+
     #[tokio::test]
     async fn test_send_message_success() {
         let mut message_repository = MockTestMessageRepository::new();
@@ -210,13 +513,10 @@ mod tests {
         let channel_id = ChannelId::new();
 
         // Setup channel
-        let channel = Channel::Public(PublicChannel {
-            id: channel_id,
-            name: ChannelName::new("general".to_string()).unwrap(),
-            description: None,
-            created_by: user_id,
-            created_at: Utc::now(),
-        });
+        let channel = ChannelFixture::public("general")
+            .with_id(channel_id)
+            .created_by(user_id)
+            .build();
 
         let returned_channel = channel.clone();
         channel_repository
@@ -233,7 +533,13 @@ mod tests {
                     && message.content.as_str() == "Hello, world!"
             })
             .times(1)
-            .returning(|message| Ok(message));
+            .returning(Ok);
+
+        channel_repository
+            .expect_record_message_activity()
+            .withf(move |id, author, _, _| *id == channel_id && *author == user_id)
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
 
         // Expect event to be published
         event_publisher
@@ -246,11 +552,14 @@ mod tests {
             Arc::new(channel_repository),
             Arc::new(user_client),
             Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
         );
 
         let content = MessageContent::new("Hello, world!".to_string()).unwrap();
 
-        let result = service.send_message(channel_id, user_id, content).await;
+        let result = service
+            .send_message(channel_id, Sender::member(user_id), content)
+            .await;
         assert!(result.is_ok());
 
         let message = result.unwrap();
@@ -259,6 +568,421 @@ mod tests {
         assert_eq!(message.content.as_str(), "Hello, world!");
     }
 
+    #[tokio::test]
+    async fn test_send_message_with_id_uses_time_of_id() {
+        let channel = ChannelFixture::public("general").build();
+        let channel_id = channel.id();
+        let sent_at = epoch() + chrono::Duration::days(1);
+        let message_id = message_id_at(sent_at);
+
+        let mut channel_repository = MockTestChannelRepository::new();
+        channel_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(channel.clone())));
+        channel_repository
+            .expect_record_message_activity()
+            .withf(move |_, _, at, _| *at == sent_at)
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut message_repository = MockTestMessageRepository::new();
+        message_repository.expect_create().never();
+        message_repository
+            .expect_create_if_absent()
+            .withf(move |message| message.id == message_id && message.timestamp == sent_at)
+            .times(1)
+            .returning(Ok);
+
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_message_sent()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = MessageService::new(
+            Arc::new(message_repository),
+            Arc::new(channel_repository),
+            Arc::new(MockTestUserService::new()),
+            Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
+        );
+
+        let content = MessageContent::new("Imported".to_string()).unwrap();
+        let message = service
+            .send_message_with_id(channel_id, message_id, Sender::member(user_id(1)), content)
+            .await
+            .unwrap();
+
+        assert_eq!(message.id, message_id);
+        assert_eq!(message.timestamp, sent_at);
+    }
+
+    #[tokio::test]
+    async fn test_send_message_with_taken_id_conflicts() {
+        let channel = ChannelFixture::public("general").build();
+        let channel_id = channel.id();
+        let message_id = MessageId::new_time_based();
+
+        let mut channel_repository = MockTestChannelRepository::new();
+        channel_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(channel.clone())));
+        channel_repository.expect_record_message_activity().never();
+
+        let mut message_repository = MockTestMessageRepository::new();
+        message_repository
+            .expect_create_if_absent()
+            .returning(|message| Err(MessageError::IdAlreadyExists(message.id)));
+
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher.expect_publish_message_sent().never();
+
+        let service = MessageService::new(
+            Arc::new(message_repository),
+            Arc::new(channel_repository),
+            Arc::new(MockTestUserService::new()),
+            Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
+        );
+
+        let content = MessageContent::new("Imported".to_string()).unwrap();
+        let result = service
+            .send_message_with_id(channel_id, message_id, Sender::member(user_id(1)), content)
+            .await;
+
+        assert!(matches!(result, Err(MessageError::IdAlreadyExists(id)) if id == message_id));
+    }
+
+    /// Reporter recording the stages it is told about.
+    #[derive(Default)]
+    struct RecordingReporter(std::sync::Mutex<Vec<(MessageId, DeliveryStatus)>>);
+
+    impl DeliveryReporter for RecordingReporter {
+        fn report(&self, message_id: MessageId, status: DeliveryStatus) {
+            self.0.lock().unwrap().push((message_id, status));
+        }
+    }
+
+    impl RecordingReporter {
+        fn statuses(&self) -> Vec<DeliveryStatus> {
+            self.0
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(_, status)| *status)
+                .collect()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_message_reporting_reports_each_stage() {
+        let channel = ChannelFixture::public("general").build();
+        let channel_id = channel.id();
+        let message_id = MessageId::new_v7();
+
+        let mut channel_repository = MockTestChannelRepository::new();
+        channel_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(channel.clone())));
+        channel_repository
+            .expect_record_message_activity()
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut message_repository = MockTestMessageRepository::new();
+        message_repository
+            .expect_create_if_absent()
+            .withf(move |message| message.id == message_id)
+            .times(1)
+            .returning(Ok);
+
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_message_sent()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = MessageService::new(
+            Arc::new(message_repository),
+            Arc::new(channel_repository),
+            Arc::new(MockTestUserService::new()),
+            Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
+        );
+
+        let reporter = Arc::new(RecordingReporter::default());
+        let content = MessageContent::new("Hello".to_string()).unwrap();
+        service
+            .send_message_reporting(
+                channel_id,
+                Some(message_id),
+                Sender::member(user_id(1)),
+                content,
+                reporter.clone(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            reporter.0.lock().unwrap().as_slice(),
+            &[
+                (message_id, DeliveryStatus::Accepted),
+                (message_id, DeliveryStatus::Stored),
+                (message_id, DeliveryStatus::Published),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_message_reporting_stops_at_stored_when_publish_fails() {
+        let channel = ChannelFixture::public("general").build();
+        let channel_id = channel.id();
+
+        let mut channel_repository = MockTestChannelRepository::new();
+        channel_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(channel.clone())));
+        channel_repository
+            .expect_record_message_activity()
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut message_repository = MockTestMessageRepository::new();
+        message_repository.expect_create().times(1).returning(Ok);
+
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_message_sent()
+            .returning(|_| {
+                Err(crate::domain::errors::EventPublisherError::Timeout(
+                    "broker".to_string(),
+                ))
+            });
+
+        let service = MessageService::new(
+            Arc::new(message_repository),
+            Arc::new(channel_repository),
+            Arc::new(MockTestUserService::new()),
+            Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
+        );
+
+        let reporter = Arc::new(RecordingReporter::default());
+        let content = MessageContent::new("Hello".to_string()).unwrap();
+        service
+            .send_message_reporting(
+                channel_id,
+                None,
+                Sender::member(user_id(1)),
+                content,
+                reporter.clone(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            reporter.statuses(),
+            vec![DeliveryStatus::Accepted, DeliveryStatus::Stored]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_message_reporting_retry_publishes_stored_message() {
+        let channel = ChannelFixture::public("general").build();
+        let channel_id = channel.id();
+        let message_id = MessageId::new_v7();
+        let stored = MessageFixture::in_channel(channel_id)
+            .with_id(message_id)
+            .from_user(user_id(1))
+            .build();
+
+        let mut channel_repository = MockTestChannelRepository::new();
+        channel_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(channel.clone())));
+        // Activity was recorded on the first attempt
+        channel_repository.expect_record_message_activity().never();
+
+        let mut message_repository = MockTestMessageRepository::new();
+        message_repository
+            .expect_create_if_absent()
+            .returning(|message| Err(MessageError::IdAlreadyExists(message.id)));
+        message_repository
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(stored.clone())));
+
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_message_sent()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = MessageService::new(
+            Arc::new(message_repository),
+            Arc::new(channel_repository),
+            Arc::new(MockTestUserService::new()),
+            Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
+        );
+
+        let reporter = Arc::new(RecordingReporter::default());
+        let content = MessageContent::new("Hello".to_string()).unwrap();
+        let message = service
+            .send_message_reporting(
+                channel_id,
+                Some(message_id),
+                Sender::member(user_id(1)),
+                content,
+                reporter.clone(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(message.id, message_id);
+        assert_eq!(
+            reporter.statuses(),
+            vec![
+                DeliveryStatus::Accepted,
+                DeliveryStatus::Stored,
+                DeliveryStatus::Published
+            ]
+        );
+
+        // Someone else's message under the same ID is not theirs to retry
+        let reporter = Arc::new(RecordingReporter::default());
+        let content = MessageContent::new("Hello".to_string()).unwrap();
+        let result = service
+            .send_message_reporting(
+                channel_id,
+                Some(message_id),
+                Sender::member(user_id(2)),
+                content,
+                reporter.clone(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(MessageError::IdAlreadyExists(id)) if id == message_id));
+        assert_eq!(reporter.statuses(), vec![DeliveryStatus::Accepted]);
+    }
+
+    #[test]
+    fn test_supplied_message_id_must_be_time_based() {
+        let message_id = MessageId::new_time_based();
+        assert_eq!(
+            MessageId::from_supplied(&message_id.to_string()).unwrap(),
+            message_id
+        );
+        let message_id = MessageId::new_v7();
+        assert_eq!(
+            MessageId::from_supplied(&message_id.to_string()).unwrap(),
+            message_id
+        );
+        assert!(matches!(
+            MessageId::from_supplied(&uuid::Uuid::new_v4().to_string()),
+            Err(MessageIdError::NotTimeBased(4))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_message_generates_configured_id_version() {
+        let mut message_repository = MockTestMessageRepository::new();
+        let mut channel_repository = MockTestChannelRepository::new();
+        let mut event_publisher = MockTestEventPublisher::new();
+
+        let channel = ChannelFixture::public("general").build();
+        let channel_id = channel.id();
+
+        channel_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(channel.clone())));
+        message_repository
+            .expect_create()
+            .withf(|message| message.id.is_v7())
+            .times(1)
+            .returning(Ok);
+        channel_repository
+            .expect_record_message_activity()
+            .returning(|_, _, _, _| Ok(()));
+        event_publisher
+            .expect_publish_message_sent()
+            .returning(|_| Ok(()));
+
+        let service = MessageService::new(
+            Arc::new(message_repository),
+            Arc::new(channel_repository),
+            Arc::new(MockTestUserService::new()),
+            Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
+        )
+        .with_id_version(MessageIdVersion::V7);
+
+        let content = MessageContent::new("Hello".to_string()).unwrap();
+        let message = service
+            .send_message(channel_id, Sender::member(user_id(1)), content)
+            .await
+            .unwrap();
+
+        assert_eq!(message.id.as_uuid().get_version_num(), 7);
+    }
+
+    #[test]
+    fn test_message_ids_order_by_time_across_versions() {
+        let first = message_id_v7_at(epoch());
+        let second = message_id_at(epoch() + chrono::Duration::seconds(1));
+        let third = message_id_v7_at(epoch() + chrono::Duration::seconds(2));
+
+        let mut ids = vec![third, first, second];
+        ids.sort();
+
+        assert_eq!(ids, vec![first, second, third]);
+    }
+
+    #[tokio::test]
+    async fn test_send_message_records_detected_language() {
+        let mut message_repository = MockTestMessageRepository::new();
+        let mut channel_repository = MockTestChannelRepository::new();
+        let mut event_publisher = MockTestEventPublisher::new();
+
+        let channel = ChannelFixture::public("general").build();
+        let channel_id = channel.id();
+        let german = LanguageCode::new("de").unwrap();
+
+        channel_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(channel.clone())));
+        message_repository
+            .expect_create()
+            .withf(|message| message.language.as_ref().map(|l| l.as_str()) == Some("de"))
+            .times(1)
+            .returning(Ok);
+        let expected = german.clone();
+        channel_repository
+            .expect_record_message_activity()
+            .withf(move |_, _, _, language| language.as_ref() == Some(&expected))
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        event_publisher
+            .expect_publish_message_sent()
+            .withf(|event| event.language.as_deref() == Some("de"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = MessageService::new(
+            Arc::new(message_repository),
+            Arc::new(channel_repository),
+            Arc::new(MockTestUserService::new()),
+            Arc::new(event_publisher),
+            Arc::new(language_detector(Some("de"))),
+        );
+
+        let content = MessageContent::new("Guten Morgen, wie geht es dir?".to_string()).unwrap();
+        let message = service
+            .send_message(channel_id, Sender::member(user_id(1)), content)
+            .await
+            .unwrap();
+
+        assert_eq!(message.language, Some(german));
+    }
+
     #[tokio::test]
     async fn test_send_message_channel_not_found() {
         let message_repository = MockTestMessageRepository::new();
@@ -279,12 +1003,13 @@ mod tests {
             Arc::new(channel_repository),
             Arc::new(user_client),
             Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
         );
 
         let content = MessageContent::new("Hello".to_string()).unwrap();
 
         let result = service
-            .send_message(non_existent_channel, user_id, content)
+            .send_message(non_existent_channel, Sender::member(user_id), content)
             .await;
 
         assert!(result.is_err());
@@ -294,6 +1019,65 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_send_message_announcement_only_channel() {
+        let mut message_repository = MockTestMessageRepository::new();
+        let mut channel_repository = MockTestChannelRepository::new();
+        let mut event_publisher = MockTestEventPublisher::new();
+
+        let channel = ChannelFixture::public("announcements")
+            .created_by(user_id(1))
+            .announcement_only()
+            .build();
+        let channel_id = channel.id();
+
+        channel_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(channel.clone())));
+        channel_repository
+            .expect_record_message_activity()
+            .times(2)
+            .returning(|_, _, _, _| Ok(()));
+        message_repository
+            .expect_create()
+            .withf(|message| message.user_id != user_id(2))
+            .times(2)
+            .returning(Ok);
+        event_publisher
+            .expect_publish_message_sent()
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let service = MessageService::new(
+            Arc::new(message_repository),
+            Arc::new(channel_repository),
+            Arc::new(MockTestUserService::new()),
+            Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
+        );
+
+        let content = || MessageContent::new("Hello".to_string()).unwrap();
+
+        let result = service
+            .send_message(channel_id, Sender::member(user_id(2)), content())
+            .await;
+        let err = result.unwrap_err();
+        assert!(matches!(err, MessageError::AnnouncementOnly(id) if id == channel_id));
+        assert_eq!(err.code(), Some("channel_announcement_only"));
+
+        // The owner and moderators may still post
+        let moderator = Sender {
+            user_id: user_id(3),
+            moderator: true,
+        };
+        for sender in [Sender::member(user_id(1)), moderator] {
+            assert!(service
+                .send_message(channel_id, sender, content())
+                .await
+                .is_ok());
+        }
+    }
+
     #[tokio::test]
     async fn test_send_message_empty_content() {
         let mut message_repository = MockTestMessageRepository::new();
@@ -304,13 +1088,10 @@ mod tests {
         let user_id = UserId::new();
         let channel_id = ChannelId::new();
 
-        let channel = Channel::Public(PublicChannel {
-            id: channel_id,
-            name: ChannelName::new("general".to_string()).unwrap(),
-            description: None,
-            created_by: user_id,
-            created_at: Utc::now(),
-        });
+        let channel = ChannelFixture::public("general")
+            .with_id(channel_id)
+            .created_by(user_id)
+            .build();
 
         let returned_channel = channel.clone();
         channel_repository
@@ -318,10 +1099,12 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(Some(returned_channel.clone())));
 
-        message_repository
-            .expect_create()
+        message_repository.expect_create().times(1).returning(Ok);
+
+        channel_repository
+            .expect_record_message_activity()
             .times(1)
-            .returning(|message| Ok(message));
+            .returning(|_, _, _, _| Ok(()));
 
         event_publisher
             .expect_publish_message_sent()
@@ -333,6 +1116,7 @@ mod tests {
             Arc::new(channel_repository),
             Arc::new(user_client),
             Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
         );
 
         let empty_content = MessageContent::new("".to_string());
@@ -343,7 +1127,7 @@ mod tests {
 
         let valid_content = MessageContent::new("Valid message".to_string()).unwrap();
         let result = service
-            .send_message(channel_id, user_id, valid_content)
+            .send_message(channel_id, Sender::member(user_id), valid_content)
             .await;
         assert!(result.is_ok(), "Valid message should succeed");
     }
@@ -357,52 +1141,27 @@ mod tests {
         let user_id = UserId::new();
         let channel_id = ChannelId::new();
 
-        let expected_messages = vec![
-            Message {
-                id: MessageId::new_time_based(),
-                channel_id,
-                user_id,
-                content: MessageContent::new("Message 1".to_string()).unwrap(),
-                timestamp: Utc::now(),
-            },
-            Message {
-                id: MessageId::new_time_based(),
-                channel_id,
-                user_id,
-                content: MessageContent::new("Message 2".to_string()).unwrap(),
-                timestamp: Utc::now(),
-            },
-            Message {
-                id: MessageId::new_time_based(),
-                channel_id,
-                user_id,
-                content: MessageContent::new("Message 3".to_string()).unwrap(),
-                timestamp: Utc::now(),
-            },
-            Message {
-                id: MessageId::new_time_based(),
-                channel_id,
-                user_id,
-                content: MessageContent::new("Message 4".to_string()).unwrap(),
-                timestamp: Utc::now(),
-            },
-            Message {
-                id: MessageId::new_time_based(),
-                channel_id,
-                user_id,
-                content: MessageContent::new("Message 5".to_string()).unwrap(),
-                timestamp: Utc::now(),
-            },
-        ];
+        let mut history: Vec<HistoryEntry> = MessageFixture::in_channel(channel_id)
+            .from_user(user_id)
+            .build_many(4)
+            .into_iter()
+            .map(HistoryEntry::Message)
+            .collect();
+        history.push(HistoryEntry::Deleted(MessageTombstone {
+            id: MessageId::new_time_based(),
+            channel_id,
+            deleted_at: Utc::now(),
+            deleted_by: user_id,
+        }));
 
-        let returned_messages = expected_messages.clone();
         message_repository
-            .expect_find_by_channel()
+            .expect_find_history()
             .withf(move |ch_id, limit, before| {
                 *ch_id == channel_id && *limit == 10 && before.is_none()
             })
             .times(1)
-            .returning(move |_, _, _| Ok(returned_messages.clone()));
+            .returning(move |_, _, _| Ok(history.clone()));
+        message_repository.expect_find_by_channel().never();
 
         let event_publisher = MockTestEventPublisher::new();
         let service = MessageService::new(
@@ -410,6 +1169,7 @@ mod tests {
             Arc::new(channel_repository),
             Arc::new(user_client),
             Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
         );
 
         // Get messages
@@ -418,6 +1178,7 @@ mod tests {
 
         let messages = result.unwrap();
         assert_eq!(messages.len(), 5);
+        assert!(messages[4].is_deleted());
     }
 
     #[tokio::test]
@@ -429,29 +1190,9 @@ mod tests {
         let user_id = UserId::new();
         let channel_id = ChannelId::new();
 
-        let expected_messages = vec![
-            Message {
-                id: MessageId::new_time_based(),
-                channel_id,
-                user_id,
-                content: MessageContent::new("Message 1".to_string()).unwrap(),
-                timestamp: Utc::now(),
-            },
-            Message {
-                id: MessageId::new_time_based(),
-                channel_id,
-                user_id,
-                content: MessageContent::new("Message 2".to_string()).unwrap(),
-                timestamp: Utc::now(),
-            },
-            Message {
-                id: MessageId::new_time_based(),
-                channel_id,
-                user_id,
-                content: MessageContent::new("Message 3".to_string()).unwrap(),
-                timestamp: Utc::now(),
-            },
-        ];
+        let expected_messages = MessageFixture::in_channel(channel_id)
+            .from_user(user_id)
+            .build_many(3);
 
         let returned_messages = expected_messages.clone();
         message_repository
@@ -462,15 +1203,19 @@ mod tests {
             .times(1)
             .returning(move |_, _, _| Ok(returned_messages.clone()));
 
+        message_repository.expect_find_history().never();
+
         let event_publisher = MockTestEventPublisher::new();
         let service = MessageService::new(
             Arc::new(message_repository),
             Arc::new(channel_repository),
             Arc::new(user_client),
             Arc::new(event_publisher),
-        );
+            Arc::new(language_detector(None)),
+        )
+        .with_tombstones(false);
 
-        // Get messages with limit
+        // Get messages with limit, tombstones disabled
         let result = service.get_channel_messages(channel_id, 3, None).await;
         assert!(result.is_ok());
 
@@ -478,6 +1223,95 @@ mod tests {
         assert_eq!(messages.len(), 3);
     }
 
+    fn deletion_service(
+        message: Message,
+        event_publisher: MockTestEventPublisher,
+    ) -> MessageService<
+        MockTestMessageRepository,
+        MockTestChannelRepository,
+        MockTestUserService,
+        MockTestEventPublisher,
+        MockTestLanguageDetector,
+    > {
+        let mut message_repository = MockTestMessageRepository::new();
+        message_repository
+            .expect_find_by_id()
+            .returning(move |channel_id, message_id| {
+                Ok(Some(message.clone())
+                    .filter(|m| m.channel_id == channel_id && m.id == message_id))
+            });
+        message_repository
+            .expect_delete()
+            .returning(|_, tombstone| Ok(tombstone));
+
+        MessageService::new(
+            Arc::new(message_repository),
+            Arc::new(MockTestChannelRepository::new()),
+            Arc::new(MockTestUserService::new()),
+            Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_delete_message_leaves_tombstone() {
+        let channel_id = ChannelId::new();
+        let message = MessageFixture::in_channel(channel_id)
+            .from_user(user_id(1))
+            .build();
+        let message_id = message.id;
+
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_message_deleted()
+            .withf(move |event| event.message_id == message_id && event.channel_id == channel_id)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = deletion_service(message, event_publisher);
+        let tombstone = service
+            .delete_message(channel_id, message_id, user_id(1))
+            .await
+            .unwrap();
+
+        assert_eq!(tombstone.id, message_id);
+        assert_eq!(tombstone.deleted_by, user_id(1));
+    }
+
+    #[tokio::test]
+    async fn test_delete_message_of_other_user_forbidden() {
+        let channel_id = ChannelId::new();
+        let message = MessageFixture::in_channel(channel_id)
+            .from_user(user_id(1))
+            .build();
+        let message_id = message.id;
+
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher.expect_publish_message_deleted().never();
+
+        let service = deletion_service(message, event_publisher);
+        let result = service
+            .delete_message(channel_id, message_id, user_id(2))
+            .await;
+
+        assert!(matches!(result, Err(MessageError::DeleteForbidden(id)) if id == message_id));
+    }
+
+    #[tokio::test]
+    async fn test_delete_message_in_other_channel_not_found() {
+        let message = MessageFixture::in_channel(ChannelId::new())
+            .from_user(user_id(1))
+            .build();
+        let message_id = message.id;
+
+        let service = deletion_service(message, MockTestEventPublisher::new());
+        let result = service
+            .delete_message(ChannelId::new(), message_id, user_id(1))
+            .await;
+
+        assert!(matches!(result, Err(MessageError::NotFound(id)) if id == message_id));
+    }
+
     #[tokio::test]
     async fn test_send_message_content_too_long() {
         let mut message_repository = MockTestMessageRepository::new();
@@ -488,13 +1322,10 @@ mod tests {
         let user_id = UserId::new();
         let channel_id = ChannelId::new();
 
-        let channel = Channel::Public(PublicChannel {
-            id: channel_id,
-            name: ChannelName::new("general".to_string()).unwrap(),
-            description: None,
-            created_by: user_id,
-            created_at: Utc::now(),
-        });
+        let channel = ChannelFixture::public("general")
+            .with_id(channel_id)
+            .created_by(user_id)
+            .build();
 
         let returned_channel = channel.clone();
         channel_repository
@@ -502,10 +1333,12 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(Some(returned_channel.clone())));
 
-        message_repository
-            .expect_create()
+        message_repository.expect_create().times(1).returning(Ok);
+
+        channel_repository
+            .expect_record_message_activity()
             .times(1)
-            .returning(|message| Ok(message));
+            .returning(|_, _, _, _| Ok(()));
 
         // Expect event to be published for valid message
         event_publisher
@@ -518,6 +1351,7 @@ mod tests {
             Arc::new(channel_repository),
             Arc::new(user_client),
             Arc::new(event_publisher),
+            Arc::new(language_detector(None)),
         );
 
         // Test 1: Content that's too long should fail at newtype validation
@@ -532,7 +1366,7 @@ mod tests {
         let max_content = "a".repeat(4000);
         let valid_content = MessageContent::new(max_content).unwrap();
         let result = service
-            .send_message(channel_id, user_id, valid_content)
+            .send_message(channel_id, Sender::member(user_id), valid_content)
             .await;
         assert!(result.is_ok(), "Content at max length should succeed");
     }
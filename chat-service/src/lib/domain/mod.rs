@@ -1,5 +1,16 @@
+pub mod backup;
 pub mod channel;
+pub mod crash;
+pub mod email;
+pub mod embed;
 pub mod errors;
 pub mod events;
+pub mod feed;
+pub mod gateway;
+pub mod leader;
+pub mod lock;
 pub mod message;
+pub mod poll;
+pub mod reminder;
+pub mod star;
 pub mod user;
@@ -0,0 +1,47 @@
+use thiserror::Error;
+
+use crate::domain::message::errors::MessageError;
+
+/// Error for PollId parsing failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum PollIdError {
+    #[error("Invalid UUID format: {0}")]
+    InvalidFormat(String),
+}
+
+/// Top-level error for all poll-related operations
+#[derive(Debug, Error)]
+pub enum PollError {
+    #[error("Invalid poll ID: {0}")]
+    InvalidPollId(#[from] PollIdError),
+
+    #[error("Poll not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid poll question: {0}")]
+    InvalidQuestion(String),
+
+    #[error("Invalid poll options: {0}")]
+    InvalidOptions(String),
+
+    #[error("Poll closes in the past")]
+    ClosesInPast,
+
+    #[error("Poll closes more than {0} days from now")]
+    ClosesTooLate(i64),
+
+    #[error("Poll has no option {0}")]
+    UnknownOption(usize),
+
+    #[error("Poll is closed: {0}")]
+    Closed(String),
+
+    #[error("User already voted in poll {0}")]
+    AlreadyVoted(String),
+
+    #[error("Failed to post poll: {0}")]
+    Message(#[from] MessageError),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
@@ -0,0 +1,46 @@
+use chrono::DateTime;
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::models::PollId;
+use super::models::PollOptionTally;
+use super::models::PollResults;
+use crate::domain::channel::models::ChannelId;
+
+/// Domain event published when the results of a poll change.
+///
+/// Carries the full tally rather than the vote that changed it, so clients
+/// missing an update catch up with the next one.
+#[derive(Debug, Clone)]
+pub struct PollUpdatedEvent {
+    pub event_id: String,
+    pub poll_id: PollId,
+    pub channel_id: ChannelId,
+    pub question: String,
+    pub tallies: Vec<PollOptionTally>,
+    pub closes_at: DateTime<Utc>,
+    pub closed: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl PollUpdatedEvent {
+    /// Create an event reporting the current results of a poll.
+    ///
+    /// # Arguments
+    /// * `results` - Poll and its tally
+    ///
+    /// # Returns
+    /// PollUpdatedEvent with generated event ID and current timestamp
+    pub fn new(results: &PollResults) -> Self {
+        Self {
+            event_id: Uuid::new_v4().to_string(),
+            poll_id: results.poll.id,
+            channel_id: results.poll.channel_id,
+            question: results.poll.question.clone(),
+            tallies: results.tallies.clone(),
+            closes_at: results.poll.closes_at,
+            closed: results.poll.closed_at.is_some(),
+            timestamp: Utc::now(),
+        }
+    }
+}
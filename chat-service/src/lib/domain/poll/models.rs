@@ -0,0 +1,137 @@
+use std::fmt;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::Sender;
+use crate::domain::poll::errors::PollIdError;
+use crate::domain::user::models::UserId;
+
+/// Poll unique identifier value object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PollId(pub Uuid);
+
+impl PollId {
+    /// Generate a new random poll ID.
+    ///
+    /// # Returns
+    /// PollId with random UUID v4
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Parse a poll ID from string.
+    ///
+    /// # Arguments
+    /// * `s` - UUID string to parse
+    ///
+    /// # Returns
+    /// Parsed PollId
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a valid UUID
+    pub fn from_string(s: &str) -> Result<Self, PollIdError> {
+        Uuid::parse_str(s)
+            .map(PollId)
+            .map_err(|e| PollIdError::InvalidFormat(e.to_string()))
+    }
+
+    /// Get a reference to the inner UUID.
+    ///
+    /// # Returns
+    /// Reference to the UUID value
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for PollId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for PollId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Poll asked in a channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poll {
+    pub id: PollId,
+    pub channel_id: ChannelId,
+    pub created_by: UserId,
+    pub question: String,
+    /// Options voted for, by their index
+    pub options: Vec<String>,
+    pub closes_at: DateTime<Utc>,
+    /// Time the poll was closed, None while it is open
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Poll {
+    /// Check whether votes are still accepted.
+    ///
+    /// # Arguments
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// True if the poll is not closed and has not expired
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        self.closed_at.is_none() && now < self.closes_at
+    }
+}
+
+/// Votes cast for one option of a poll.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollOptionTally {
+    pub text: String,
+    pub votes: u64,
+}
+
+/// Poll along with the votes cast for each of its options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollResults {
+    pub poll: Poll,
+    /// Tally of each option, in the order of the poll's options
+    pub tallies: Vec<PollOptionTally>,
+}
+
+impl PollResults {
+    /// Count the votes cast in the poll.
+    ///
+    /// # Returns
+    /// Total of the votes of every option
+    pub fn total_votes(&self) -> u64 {
+        self.tallies.iter().map(|tally| tally.votes).sum()
+    }
+}
+
+/// Command to ask a poll in a channel.
+#[derive(Debug, Clone)]
+pub struct CreatePollCommand {
+    pub channel_id: ChannelId,
+    /// Author of the poll, who posts its announcement
+    pub sender: Sender,
+    pub question: String,
+    pub options: Vec<String>,
+    pub closes_at: DateTime<Utc>,
+}
+
+/// Configuration of polls and of the bot posting their results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollSettings {
+    /// User final results are posted as
+    pub bot_id: UserId,
+    /// Time between two checks for expired polls
+    pub poll_interval: Duration,
+    pub max_options: usize,
+    /// Longest time a poll may stay open
+    pub max_duration: chrono::Duration,
+}
@@ -0,0 +1,214 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use super::errors::PollError;
+use super::events::PollUpdatedEvent;
+use super::models::CreatePollCommand;
+use super::models::Poll;
+use super::models::PollId;
+use super::models::PollResults;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::errors::EventPublisherError;
+use crate::domain::user::models::UserId;
+
+/// Port for the polls asked in channels.
+#[async_trait]
+pub trait PollServicePort: Send + Sync + 'static {
+    /// Ask a poll in a channel, announced by a message of its author.
+    ///
+    /// # Arguments
+    /// * `command` - Channel, author, question, options and closing time
+    ///
+    /// # Returns
+    /// Created poll
+    ///
+    /// # Errors
+    /// * `InvalidQuestion` - Question is empty or too long
+    /// * `InvalidOptions` - Too few, too many, empty or duplicate options
+    /// * `ClosesInPast` - Closing time has passed
+    /// * `ClosesTooLate` - Closing time is further ahead than allowed
+    /// * `Message` - Announcement could not be posted
+    /// * `DatabaseError` - Database operation failed
+    async fn create_poll(&self, command: CreatePollCommand) -> Result<Poll, PollError>;
+
+    /// Retrieve the current results of a poll.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel the poll is asked in
+    /// * `poll_id` - Poll to retrieve
+    ///
+    /// # Returns
+    /// Poll and the votes cast for each option
+    ///
+    /// # Errors
+    /// * `NotFound` - Poll is not asked in the channel
+    /// * `DatabaseError` - Database operation failed
+    async fn get_results(
+        &self,
+        channel_id: ChannelId,
+        poll_id: PollId,
+    ) -> Result<PollResults, PollError>;
+
+    /// Vote for an option of a poll.
+    ///
+    /// Each user votes once per poll. The new results are broadcast to the
+    /// channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel the poll is asked in
+    /// * `poll_id` - Poll to vote in
+    /// * `user_id` - Voter
+    /// * `option` - Index of the chosen option
+    ///
+    /// # Returns
+    /// Results including the vote
+    ///
+    /// # Errors
+    /// * `NotFound` - Poll is not asked in the channel
+    /// * `UnknownOption` - Poll has no such option
+    /// * `Closed` - Poll no longer accepts votes
+    /// * `AlreadyVoted` - User already voted in the poll
+    /// * `DatabaseError` - Database operation failed
+    async fn vote(
+        &self,
+        channel_id: ChannelId,
+        poll_id: PollId,
+        user_id: UserId,
+        option: usize,
+    ) -> Result<PollResults, PollError>;
+
+    /// Close the polls that have expired and post their final results.
+    ///
+    /// # Returns
+    /// Number of polls closed
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Expired polls could not be listed or closed
+    async fn close_due_polls(&self) -> Result<usize, PollError>;
+}
+
+/// Repository port for poll persistence operations.
+#[async_trait]
+pub trait PollRepository: Send + Sync + 'static {
+    /// Persist a new poll.
+    ///
+    /// # Arguments
+    /// * `poll` - Poll to create
+    ///
+    /// # Returns
+    /// Created poll
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn create(&self, poll: Poll) -> Result<Poll, PollError>;
+
+    /// Delete a poll along with its votes.
+    ///
+    /// # Arguments
+    /// * `poll_id` - Poll to delete
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn delete(&self, poll_id: PollId) -> Result<(), PollError>;
+
+    /// Retrieve a poll of a channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel the poll is asked in
+    /// * `poll_id` - Poll to retrieve
+    ///
+    /// # Returns
+    /// Poll, None if the channel has no such poll
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_by_id(
+        &self,
+        channel_id: ChannelId,
+        poll_id: PollId,
+    ) -> Result<Option<Poll>, PollError>;
+
+    /// Record the vote of a user, unless they already voted in the poll.
+    ///
+    /// # Arguments
+    /// * `poll_id` - Poll voted in
+    /// * `user_id` - Voter
+    /// * `option` - Index of the chosen option
+    ///
+    /// # Returns
+    /// True if the vote was recorded, false if the user had already voted
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record_vote(
+        &self,
+        poll_id: PollId,
+        user_id: UserId,
+        option: usize,
+    ) -> Result<bool, PollError>;
+
+    /// Count the votes cast for each option of a poll.
+    ///
+    /// # Arguments
+    /// * `poll_id` - Poll to count
+    ///
+    /// # Returns
+    /// Option indexes with their number of votes, options without votes omitted
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn count_votes(&self, poll_id: PollId) -> Result<Vec<(usize, u64)>, PollError>;
+
+    /// Retrieve the open polls that expired at a time.
+    ///
+    /// # Arguments
+    /// * `closes_before` - Polls closing at or before this time are returned
+    /// * `limit` - Maximum number of polls to return
+    ///
+    /// # Returns
+    /// Expired polls, earliest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_expired(
+        &self,
+        closes_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Poll>, PollError>;
+
+    /// Mark a poll closed.
+    ///
+    /// # Arguments
+    /// * `poll_id` - Poll to close
+    /// * `closed_at` - Closing time
+    ///
+    /// # Returns
+    /// True if the poll was open, false if it had already been closed
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn close(&self, poll_id: PollId, closed_at: DateTime<Utc>) -> Result<bool, PollError>;
+}
+
+/// Port for broadcasting poll results to channel members.
+#[async_trait]
+pub trait PollEventPublisher: Send + Sync + 'static {
+    /// Publish the new results of a poll.
+    ///
+    /// # Arguments
+    /// * `event` - PollUpdated event
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `SerializationFailed` - Event serialization failed
+    /// * `PublishFailed` - Failed to publish to broker
+    /// * `ConnectionFailed` - Broker connection failed
+    /// * `Timeout` - Publishing timed out
+    async fn publish_poll_updated(
+        &self,
+        event: &PollUpdatedEvent,
+    ) -> Result<(), EventPublisherError>;
+}
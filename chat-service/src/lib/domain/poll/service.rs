@@ -0,0 +1,773 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use clock::Clock;
+
+use super::errors::PollError;
+use super::events::PollUpdatedEvent;
+use super::models::CreatePollCommand;
+use super::models::Poll;
+use super::models::PollId;
+use super::models::PollOptionTally;
+use super::models::PollResults;
+use super::models::PollSettings;
+use super::ports::PollEventPublisher;
+use super::ports::PollRepository;
+use super::ports::PollServicePort;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::errors::MessageContentError;
+use crate::domain::message::errors::MessageError;
+use crate::domain::message::models::MessageContent;
+use crate::domain::message::models::Sender;
+use crate::domain::message::ports::MessageServicePort;
+use crate::domain::user::models::UserId;
+
+/// Polls closed per batch.
+const CLOSE_BATCH_SIZE: i64 = 50;
+
+/// Longest question, in characters.
+const MAX_QUESTION_LENGTH: usize = 300;
+
+/// Longest option, in characters.
+const MAX_OPTION_LENGTH: usize = 100;
+
+/// Domain service for the polls asked in channels.
+///
+/// A poll is announced by a message of its author, so only users who may
+/// post in a channel may ask polls in it. Expired polls are closed by the
+/// poll bot, which posts the final results with moderator rights.
+pub struct PollService<PR, EP, MS>
+where
+    PR: PollRepository,
+    EP: PollEventPublisher,
+    MS: MessageServicePort,
+{
+    repository: Arc<PR>,
+    event_publisher: Arc<EP>,
+    message_service: Arc<MS>,
+    settings: PollSettings,
+    clock: Arc<dyn Clock>,
+}
+
+impl<PR, EP, MS> PollService<PR, EP, MS>
+where
+    PR: PollRepository,
+    EP: PollEventPublisher,
+    MS: MessageServicePort,
+{
+    /// Create a new poll service.
+    ///
+    /// # Arguments
+    /// * `repository` - Poll persistence implementation
+    /// * `event_publisher` - Publisher broadcasting poll results
+    /// * `message_service` - Message service posting announcements and results
+    /// * `settings` - Bot user, poll interval and poll limits
+    ///
+    /// # Returns
+    /// Configured poll service instance
+    pub fn new(
+        repository: Arc<PR>,
+        event_publisher: Arc<EP>,
+        message_service: Arc<MS>,
+        settings: PollSettings,
+    ) -> Self {
+        Self {
+            repository,
+            event_publisher,
+            message_service,
+            settings,
+            clock: clock::system(),
+        }
+    }
+
+    /// Open, close and stamp polls by another clock.
+    ///
+    /// The system clock by default.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock deciding when polls expire
+    ///
+    /// # Returns
+    /// Service telling the time by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Close expired polls until the process exits.
+    ///
+    /// Every expired poll is closed, then the worker waits `poll_interval`
+    /// for the next ones.
+    pub async fn run(&self) {
+        loop {
+            match self.close_due_polls().await {
+                Ok(0) => {}
+                Ok(closed) => tracing::debug!(closed, "Closed polls"),
+                Err(e) => tracing::error!("Failed to close polls: {}", e),
+            }
+            tokio::time::sleep(self.settings.poll_interval).await;
+        }
+    }
+
+    /// Count the votes of a poll.
+    async fn results(&self, poll: Poll) -> Result<PollResults, PollError> {
+        let counts = self.repository.count_votes(poll.id).await?;
+        let tallies = poll
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, text)| PollOptionTally {
+                text: text.clone(),
+                votes: counts
+                    .iter()
+                    .find(|(option, _)| *option == index)
+                    .map_or(0, |(_, votes)| *votes),
+            })
+            .collect();
+
+        Ok(PollResults { poll, tallies })
+    }
+
+    /// Broadcast the results of a poll to the channel.
+    ///
+    /// Clients can fetch the results again, so a failed broadcast does not
+    /// fail the change.
+    async fn publish_results(&self, results: &PollResults) {
+        if let Err(e) = self
+            .event_publisher
+            .publish_poll_updated(&PollUpdatedEvent::new(results))
+            .await
+        {
+            tracing::warn!(
+                "Failed to publish results of poll {}: {}",
+                results.poll.id,
+                e
+            );
+        }
+    }
+
+    /// Validate the question and options of a new poll.
+    fn validate(&self, command: &CreatePollCommand) -> Result<(), PollError> {
+        let question = command.question.trim();
+        if question.is_empty() {
+            return Err(PollError::InvalidQuestion(
+                "Question must not be empty".to_string(),
+            ));
+        }
+        if question.chars().count() > MAX_QUESTION_LENGTH {
+            return Err(PollError::InvalidQuestion(format!(
+                "Question must be at most {} characters",
+                MAX_QUESTION_LENGTH
+            )));
+        }
+
+        if command.options.len() < 2 || command.options.len() > self.settings.max_options {
+            return Err(PollError::InvalidOptions(format!(
+                "A poll has between 2 and {} options",
+                self.settings.max_options
+            )));
+        }
+        let mut seen = HashSet::new();
+        for option in &command.options {
+            let option = option.trim();
+            if option.is_empty() || option.chars().count() > MAX_OPTION_LENGTH {
+                return Err(PollError::InvalidOptions(format!(
+                    "Options must be between 1 and {} characters",
+                    MAX_OPTION_LENGTH
+                )));
+            }
+            if !seen.insert(option.to_lowercase()) {
+                return Err(PollError::InvalidOptions(format!(
+                    "Option is listed twice: {}",
+                    option
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<PR, EP, MS> PollServicePort for PollService<PR, EP, MS>
+where
+    PR: PollRepository,
+    EP: PollEventPublisher,
+    MS: MessageServicePort,
+{
+    async fn create_poll(&self, command: CreatePollCommand) -> Result<Poll, PollError> {
+        self.validate(&command)?;
+
+        let now = self.clock.now();
+        if command.closes_at <= now {
+            return Err(PollError::ClosesInPast);
+        }
+        if command.closes_at > now + self.settings.max_duration {
+            return Err(PollError::ClosesTooLate(
+                self.settings.max_duration.num_days(),
+            ));
+        }
+
+        let poll = Poll {
+            id: PollId::new(),
+            channel_id: command.channel_id,
+            created_by: command.sender.user_id,
+            question: command.question.trim().to_string(),
+            options: command
+                .options
+                .iter()
+                .map(|option| option.trim().to_string())
+                .collect(),
+            closes_at: command.closes_at,
+            closed_at: None,
+            created_at: now,
+        };
+        let content = announcement_content(&poll).map_err(MessageError::from)?;
+        let poll = self.repository.create(poll).await?;
+
+        // A poll nobody was told about is not kept
+        if let Err(e) = self
+            .message_service
+            .send_message(poll.channel_id, command.sender, content)
+            .await
+        {
+            self.repository.delete(poll.id).await?;
+            return Err(e.into());
+        }
+
+        Ok(poll)
+    }
+
+    async fn get_results(
+        &self,
+        channel_id: ChannelId,
+        poll_id: PollId,
+    ) -> Result<PollResults, PollError> {
+        let poll = self
+            .repository
+            .find_by_id(channel_id, poll_id)
+            .await?
+            .ok_or_else(|| PollError::NotFound(poll_id.to_string()))?;
+
+        self.results(poll).await
+    }
+
+    async fn vote(
+        &self,
+        channel_id: ChannelId,
+        poll_id: PollId,
+        user_id: UserId,
+        option: usize,
+    ) -> Result<PollResults, PollError> {
+        let poll = self
+            .repository
+            .find_by_id(channel_id, poll_id)
+            .await?
+            .ok_or_else(|| PollError::NotFound(poll_id.to_string()))?;
+        if option >= poll.options.len() {
+            return Err(PollError::UnknownOption(option));
+        }
+        if !poll.is_open(self.clock.now()) {
+            return Err(PollError::Closed(poll_id.to_string()));
+        }
+
+        if !self
+            .repository
+            .record_vote(poll_id, user_id, option)
+            .await?
+        {
+            return Err(PollError::AlreadyVoted(poll_id.to_string()));
+        }
+
+        let results = self.results(poll).await?;
+        self.publish_results(&results).await;
+        Ok(results)
+    }
+
+    async fn close_due_polls(&self) -> Result<usize, PollError> {
+        let sender = Sender {
+            user_id: self.settings.bot_id,
+            moderator: true,
+        };
+
+        let mut closed = 0;
+        loop {
+            // Closed polls are no longer expired, so each batch holds new ones
+            let expired = self
+                .repository
+                .find_expired(self.clock.now(), CLOSE_BATCH_SIZE)
+                .await?;
+            let last_batch = (expired.len() as i64) < CLOSE_BATCH_SIZE;
+
+            for mut poll in expired {
+                let closed_at = self.clock.now();
+                // Another closer got to the poll first
+                if !self.repository.close(poll.id, closed_at).await? {
+                    continue;
+                }
+                poll.closed_at = Some(closed_at);
+                closed += 1;
+
+                let results = self.results(poll).await?;
+                self.publish_results(&results).await;
+
+                // Results stay available from the poll, so they are not posted again
+                let posted = match results_content(&results) {
+                    Ok(content) => self
+                        .message_service
+                        .send_message(results.poll.channel_id, sender, content)
+                        .await
+                        .map(|_| ()),
+                    Err(e) => Err(e.into()),
+                };
+                if let Err(e) = posted {
+                    tracing::warn!(
+                        "Failed to post results of poll {} in channel {}: {}",
+                        results.poll.id,
+                        results.poll.channel_id,
+                        e
+                    );
+                }
+            }
+
+            if last_batch {
+                return Ok(closed);
+            }
+        }
+    }
+}
+
+/// Message announcing a poll, listing its options by their number.
+fn announcement_content(poll: &Poll) -> Result<MessageContent, MessageContentError> {
+    let options: Vec<String> = poll
+        .options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| format!("{}. {}", index + 1, option))
+        .collect();
+
+    MessageContent::new(format!(
+        "📊 Poll: {}\n{}\nVoting closes at {}.",
+        poll.question,
+        options.join("\n"),
+        poll.closes_at.format("%Y-%m-%d %H:%M UTC")
+    ))
+}
+
+/// Message with the final results of a poll.
+fn results_content(results: &PollResults) -> Result<MessageContent, MessageContentError> {
+    let total = results.total_votes();
+    let tallies: Vec<String> = results
+        .tallies
+        .iter()
+        .enumerate()
+        .map(|(index, tally)| {
+            format!(
+                "{}. {}: {} vote{}",
+                index + 1,
+                tally.text,
+                tally.votes,
+                if tally.votes == 1 { "" } else { "s" }
+            )
+        })
+        .collect();
+
+    MessageContent::new(format!(
+        "📊 Poll closed: {}\n{}\n{} vote{} in total.",
+        results.poll.question,
+        tallies.join("\n"),
+        total,
+        if total == 1 { "" } else { "s" }
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::DateTime;
+    use chrono::Duration;
+    use chrono::Utc;
+    use clock::FakeClock;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::errors::EventPublisherError;
+    use crate::domain::message::models::HistoryEntry;
+    use crate::domain::message::models::Message;
+    use crate::domain::message::models::MessageId;
+    use crate::domain::message::models::MessageTombstone;
+    use crate::domain::message::ports::DeliveryReporter;
+
+    mock! {
+        pub TestMessageService {}
+
+        #[async_trait]
+        impl MessageServicePort for TestMessageService {
+            async fn send_message(
+                &self,
+                channel_id: ChannelId,
+                sender: Sender,
+                content: MessageContent,
+            ) -> Result<Message, MessageError>;
+            async fn send_message_with_id(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+                sender: Sender,
+                content: MessageContent,
+            ) -> Result<Message, MessageError>;
+            async fn send_message_reporting(
+                &self,
+                channel_id: ChannelId,
+                message_id: Option<MessageId>,
+                sender: Sender,
+                content: MessageContent,
+                reporter: Arc<dyn DeliveryReporter>,
+            ) -> Result<Message, MessageError>;
+            async fn get_channel_messages(
+                &self,
+                channel_id: ChannelId,
+                limit: i32,
+                before: Option<DateTime<Utc>>,
+            ) -> Result<Vec<HistoryEntry>, MessageError>;
+            async fn delete_message(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+                deleted_by: UserId,
+            ) -> Result<MessageTombstone, MessageError>;
+        }
+    }
+
+    mock! {
+        pub TestPollEventPublisher {}
+
+        #[async_trait]
+        impl PollEventPublisher for TestPollEventPublisher {
+            async fn publish_poll_updated(
+                &self,
+                event: &PollUpdatedEvent,
+            ) -> Result<(), EventPublisherError>;
+        }
+    }
+
+    /// In-memory repository of polls and their votes.
+    #[derive(Default)]
+    struct InMemoryPollRepository {
+        polls: Mutex<Vec<Poll>>,
+        votes: Mutex<Vec<(PollId, UserId, usize)>>,
+    }
+
+    impl InMemoryPollRepository {
+        fn with_poll(poll: Poll) -> Self {
+            Self {
+                polls: Mutex::new(vec![poll]),
+                votes: Mutex::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl PollRepository for InMemoryPollRepository {
+        async fn create(&self, poll: Poll) -> Result<Poll, PollError> {
+            self.polls.lock().unwrap().push(poll.clone());
+            Ok(poll)
+        }
+
+        async fn delete(&self, poll_id: PollId) -> Result<(), PollError> {
+            self.polls.lock().unwrap().retain(|poll| poll.id != poll_id);
+            self.votes.lock().unwrap().retain(|vote| vote.0 != poll_id);
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            channel_id: ChannelId,
+            poll_id: PollId,
+        ) -> Result<Option<Poll>, PollError> {
+            let polls = self.polls.lock().unwrap();
+            Ok(polls
+                .iter()
+                .find(|poll| poll.id == poll_id && poll.channel_id == channel_id)
+                .cloned())
+        }
+
+        async fn record_vote(
+            &self,
+            poll_id: PollId,
+            user_id: UserId,
+            option: usize,
+        ) -> Result<bool, PollError> {
+            let mut votes = self.votes.lock().unwrap();
+            if votes
+                .iter()
+                .any(|vote| vote.0 == poll_id && vote.1 == user_id)
+            {
+                return Ok(false);
+            }
+            votes.push((poll_id, user_id, option));
+            Ok(true)
+        }
+
+        async fn count_votes(&self, poll_id: PollId) -> Result<Vec<(usize, u64)>, PollError> {
+            let votes = self.votes.lock().unwrap();
+            let mut counts: Vec<(usize, u64)> = Vec::new();
+            for vote in votes.iter().filter(|vote| vote.0 == poll_id) {
+                match counts.iter_mut().find(|(option, _)| *option == vote.2) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((vote.2, 1)),
+                }
+            }
+            Ok(counts)
+        }
+
+        async fn find_expired(
+            &self,
+            closes_before: DateTime<Utc>,
+            _limit: i64,
+        ) -> Result<Vec<Poll>, PollError> {
+            let polls = self.polls.lock().unwrap();
+            Ok(polls
+                .iter()
+                .filter(|poll| poll.closed_at.is_none() && poll.closes_at <= closes_before)
+                .cloned()
+                .collect())
+        }
+
+        async fn close(
+            &self,
+            poll_id: PollId,
+            closed_at: DateTime<Utc>,
+        ) -> Result<bool, PollError> {
+            let mut polls = self.polls.lock().unwrap();
+            match polls
+                .iter_mut()
+                .find(|poll| poll.id == poll_id && poll.closed_at.is_none())
+            {
+                Some(poll) => {
+                    poll.closed_at = Some(closed_at);
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+    }
+
+    fn settings() -> PollSettings {
+        PollSettings {
+            bot_id: UserId::new(),
+            poll_interval: std::time::Duration::from_secs(15),
+            max_options: 4,
+            max_duration: Duration::days(7),
+        }
+    }
+
+    fn poll(closes_in: Duration) -> Poll {
+        Poll {
+            id: PollId::new(),
+            channel_id: ChannelId::new(),
+            created_by: UserId::new(),
+            question: "Lunch?".to_string(),
+            options: vec!["Pizza".to_string(), "Sushi".to_string()],
+            closes_at: Utc::now() + closes_in,
+            closed_at: None,
+            created_at: Utc::now() - Duration::hours(1),
+        }
+    }
+
+    fn posted(channel_id: ChannelId, sender: Sender, content: MessageContent) -> Message {
+        Message {
+            id: MessageId::new_time_based(),
+            channel_id,
+            user_id: sender.user_id,
+            content,
+            timestamp: Utc::now(),
+            language: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_is_announced_by_its_author() {
+        let author = Sender {
+            user_id: UserId::new(),
+            moderator: false,
+        };
+        let repository = Arc::new(InMemoryPollRepository::default());
+
+        let mut message_service = MockTestMessageService::new();
+        message_service
+            .expect_send_message()
+            .withf(move |_, sender, content| {
+                *sender == author
+                    && content
+                        .as_str()
+                        .starts_with("📊 Poll: Lunch?\n1. Pizza\n2. Sushi\n")
+            })
+            .times(1)
+            .returning(|channel_id, sender, content| Ok(posted(channel_id, sender, content)));
+
+        let service = PollService::new(
+            Arc::clone(&repository),
+            Arc::new(MockTestPollEventPublisher::new()),
+            Arc::new(message_service),
+            settings(),
+        );
+
+        let command = CreatePollCommand {
+            channel_id: ChannelId::new(),
+            sender: author,
+            question: " Lunch? ".to_string(),
+            options: vec!["Pizza".to_string(), "Sushi".to_string()],
+            closes_at: Utc::now() + Duration::hours(1),
+        };
+        let duplicate = CreatePollCommand {
+            options: vec!["Pizza".to_string(), "pizza ".to_string()],
+            ..command.clone()
+        };
+
+        let poll = service.create_poll(command).await.unwrap();
+        assert_eq!(poll.question, "Lunch?");
+        assert!(matches!(
+            service.create_poll(duplicate).await,
+            Err(PollError::InvalidOptions(_))
+        ));
+        assert_eq!(repository.polls.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_users_vote_once_and_results_are_broadcast() {
+        let open = poll(Duration::hours(1));
+        let (channel_id, poll_id) = (open.channel_id, open.id);
+        let voter = UserId::new();
+
+        let mut event_publisher = MockTestPollEventPublisher::new();
+        event_publisher
+            .expect_publish_poll_updated()
+            .withf(move |event| event.poll_id == poll_id && !event.closed)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = PollService::new(
+            Arc::new(InMemoryPollRepository::with_poll(open)),
+            Arc::new(event_publisher),
+            Arc::new(MockTestMessageService::new()),
+            settings(),
+        );
+
+        let results = service.vote(channel_id, poll_id, voter, 1).await.unwrap();
+        assert_eq!(results.tallies[1].votes, 1);
+        assert_eq!(results.total_votes(), 1);
+
+        assert!(matches!(
+            service.vote(channel_id, poll_id, voter, 0).await,
+            Err(PollError::AlreadyVoted(_))
+        ));
+        assert!(matches!(
+            service.vote(channel_id, poll_id, UserId::new(), 2).await,
+            Err(PollError::UnknownOption(2))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_expired_poll_rejects_votes() {
+        let expired = poll(-Duration::minutes(1));
+        let (channel_id, poll_id) = (expired.channel_id, expired.id);
+
+        let service = PollService::new(
+            Arc::new(InMemoryPollRepository::with_poll(expired)),
+            Arc::new(MockTestPollEventPublisher::new()),
+            Arc::new(MockTestMessageService::new()),
+            settings(),
+        );
+
+        let result = service.vote(channel_id, poll_id, UserId::new(), 0).await;
+
+        assert!(matches!(result, Err(PollError::Closed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_expired_polls_are_closed_once_with_final_results() {
+        let settings = settings();
+        let bot_id = settings.bot_id;
+        let expired = poll(-Duration::minutes(1));
+        let poll_id = expired.id;
+        let repository = Arc::new(InMemoryPollRepository::with_poll(expired));
+        repository
+            .record_vote(poll_id, UserId::new(), 0)
+            .await
+            .unwrap();
+
+        let mut event_publisher = MockTestPollEventPublisher::new();
+        event_publisher
+            .expect_publish_poll_updated()
+            .withf(move |event| event.poll_id == poll_id && event.closed)
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut message_service = MockTestMessageService::new();
+        message_service
+            .expect_send_message()
+            .withf(move |_, sender, content| {
+                sender.user_id == bot_id
+                    && content.as_str()
+                        == "📊 Poll closed: Lunch?\n1. Pizza: 1 vote\n2. Sushi: 0 votes\n1 vote in total."
+            })
+            .times(1)
+            .returning(|channel_id, sender, content| Ok(posted(channel_id, sender, content)));
+
+        let service = PollService::new(
+            Arc::clone(&repository),
+            Arc::new(event_publisher),
+            Arc::new(message_service),
+            settings,
+        );
+
+        assert_eq!(service.close_due_polls().await.unwrap(), 1);
+        assert_eq!(service.close_due_polls().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_poll_closes_when_the_clock_passes_its_deadline() {
+        let clock = Arc::new(FakeClock::default());
+        let open = Poll {
+            closes_at: clock.now() + Duration::hours(1),
+            ..poll(Duration::hours(1))
+        };
+        let (channel_id, poll_id) = (open.channel_id, open.id);
+        let repository = Arc::new(InMemoryPollRepository::with_poll(open));
+
+        let mut event_publisher = MockTestPollEventPublisher::new();
+        event_publisher
+            .expect_publish_poll_updated()
+            .withf(move |event| event.poll_id == poll_id && event.closed)
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut message_service = MockTestMessageService::new();
+        message_service
+            .expect_send_message()
+            .times(1)
+            .returning(|channel_id, sender, content| Ok(posted(channel_id, sender, content)));
+
+        let service = PollService::new(
+            Arc::clone(&repository),
+            Arc::new(event_publisher),
+            Arc::new(message_service),
+            settings(),
+        )
+        .with_clock(clock.clone());
+
+        assert_eq!(service.close_due_polls().await.unwrap(), 0);
+
+        clock.advance(Duration::minutes(61));
+        assert_eq!(service.close_due_polls().await.unwrap(), 1);
+        assert_eq!(
+            repository.polls.lock().unwrap()[0].closed_at,
+            Some(clock.now())
+        );
+        assert!(matches!(
+            service.vote(channel_id, poll_id, UserId::new(), 0).await,
+            Err(PollError::Closed(_))
+        ));
+    }
+}
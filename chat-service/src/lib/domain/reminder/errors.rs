@@ -0,0 +1,35 @@
+use thiserror::Error;
+
+use crate::domain::message::errors::MessageError;
+
+/// Error for ReminderId parsing failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ReminderIdError {
+    #[error("Invalid UUID format: {0}")]
+    InvalidFormat(String),
+}
+
+/// Top-level error for all reminder-related operations
+#[derive(Debug, Error)]
+pub enum ReminderError {
+    #[error("Invalid reminder ID: {0}")]
+    InvalidReminderId(#[from] ReminderIdError),
+
+    #[error("Reminder not found: {0}")]
+    NotFound(String),
+
+    #[error("Reminder is due in the past")]
+    DueInPast,
+
+    #[error("Reminder is due more than {0} days from now")]
+    DueTooLate(i64),
+
+    #[error("Channel already has {0} pending reminders")]
+    TooManyReminders(usize),
+
+    #[error("Failed to post reminder: {0}")]
+    Message(#[from] MessageError),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageContent;
+use crate::domain::reminder::errors::ReminderIdError;
+use crate::domain::user::models::UserId;
+
+/// Reminder unique identifier value object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReminderId(pub Uuid);
+
+impl ReminderId {
+    /// Generate a new random reminder ID.
+    ///
+    /// # Returns
+    /// ReminderId with random UUID v4
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Parse a reminder ID from string.
+    ///
+    /// # Arguments
+    /// * `s` - UUID string to parse
+    ///
+    /// # Returns
+    /// Parsed ReminderId
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a valid UUID
+    pub fn from_string(s: &str) -> Result<Self, ReminderIdError> {
+        Uuid::parse_str(s)
+            .map(ReminderId)
+            .map_err(|e| ReminderIdError::InvalidFormat(e.to_string()))
+    }
+
+    /// Get a reference to the inner UUID.
+    ///
+    /// # Returns
+    /// Reference to the UUID value
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for ReminderId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ReminderId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Reminder waiting to be posted to a channel.
+///
+/// Reminders are deleted once posted, so every stored reminder is pending.
+#[derive(Debug, Clone)]
+pub struct Reminder {
+    pub id: ReminderId,
+    pub channel_id: ChannelId,
+    pub created_by: UserId,
+    pub content: MessageContent,
+    pub due_at: DateTime<Utc>,
+    /// Failed attempts to post the reminder
+    pub attempts: u32,
+    /// Error of the last attempt, None until an attempt fails
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Command to schedule a reminder in a channel.
+#[derive(Debug, Clone)]
+pub struct ScheduleReminderCommand {
+    pub channel_id: ChannelId,
+    pub created_by: UserId,
+    pub content: MessageContent,
+    pub due_at: DateTime<Utc>,
+}
+
+/// Configuration of the reminder bot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReminderSettings {
+    /// User reminders are posted as
+    pub bot_id: UserId,
+    /// Time between two checks for due reminders
+    pub poll_interval: Duration,
+    /// Longest time ahead a reminder may be scheduled
+    pub max_delay: chrono::Duration,
+    pub max_pending_per_channel: usize,
+}
@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use super::errors::ReminderError;
+use super::models::Reminder;
+use super::models::ReminderId;
+use super::models::ScheduleReminderCommand;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::user::models::UserId;
+
+/// Port for the reminders posted to channels.
+#[async_trait]
+pub trait ReminderServicePort: Send + Sync + 'static {
+    /// Schedule a reminder in a channel.
+    ///
+    /// # Arguments
+    /// * `command` - Channel, author, text and due time
+    ///
+    /// # Returns
+    /// Scheduled reminder
+    ///
+    /// # Errors
+    /// * `DueInPast` - Due time has passed
+    /// * `DueTooLate` - Due time is further ahead than allowed
+    /// * `TooManyReminders` - Channel has as many pending reminders as allowed
+    /// * `DatabaseError` - Database operation failed
+    async fn schedule_reminder(
+        &self,
+        command: ScheduleReminderCommand,
+    ) -> Result<Reminder, ReminderError>;
+
+    /// List the pending reminders of a channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel to list reminders of
+    ///
+    /// # Returns
+    /// Pending reminders, soonest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn list_reminders(&self, channel_id: ChannelId) -> Result<Vec<Reminder>, ReminderError>;
+
+    /// Cancel a pending reminder.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel the reminder is scheduled in
+    /// * `reminder_id` - Reminder to cancel
+    /// * `created_by` - Only cancel the reminder if this user scheduled it,
+    ///   None to cancel it whoever did
+    ///
+    /// # Returns
+    /// Cancelled reminder
+    ///
+    /// # Errors
+    /// * `NotFound` - Reminder is not pending in the channel, or was
+    ///   scheduled by someone else
+    /// * `DatabaseError` - Database operation failed
+    async fn cancel_reminder(
+        &self,
+        channel_id: ChannelId,
+        reminder_id: ReminderId,
+        created_by: Option<UserId>,
+    ) -> Result<Reminder, ReminderError>;
+
+    /// Post the reminders that are due.
+    ///
+    /// A reminder failing to be posted is retried later, and dropped after a
+    /// few attempts, without failing the others.
+    ///
+    /// # Returns
+    /// Number of reminders posted
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Due reminders could not be listed
+    async fn post_due_reminders(&self) -> Result<usize, ReminderError>;
+}
+
+/// Repository port for reminder persistence operations.
+#[async_trait]
+pub trait ReminderRepository: Send + Sync + 'static {
+    /// Persist a new reminder.
+    ///
+    /// # Arguments
+    /// * `reminder` - Reminder to create
+    ///
+    /// # Returns
+    /// Created reminder
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn create(&self, reminder: Reminder) -> Result<Reminder, ReminderError>;
+
+    /// Retrieve the reminders of a channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel to query
+    ///
+    /// # Returns
+    /// Reminders of the channel, soonest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_by_channel(&self, channel_id: ChannelId) -> Result<Vec<Reminder>, ReminderError>;
+
+    /// Delete a reminder.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel the reminder is scheduled in
+    /// * `reminder_id` - Reminder to delete
+    /// * `created_by` - Only delete the reminder if this user scheduled it
+    ///
+    /// # Returns
+    /// Deleted reminder, None if no reminder matched
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn delete(
+        &self,
+        channel_id: ChannelId,
+        reminder_id: ReminderId,
+        created_by: Option<UserId>,
+    ) -> Result<Option<Reminder>, ReminderError>;
+
+    /// Retrieve the reminders due at a time.
+    ///
+    /// # Arguments
+    /// * `due_before` - Reminders due at or before this time are returned
+    /// * `limit` - Maximum number of reminders to return
+    ///
+    /// # Returns
+    /// Due reminders, soonest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_due(
+        &self,
+        due_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Reminder>, ReminderError>;
+
+    /// Record a failed attempt to post a reminder and postpone it.
+    ///
+    /// # Arguments
+    /// * `reminder_id` - Reminder that failed to be posted
+    /// * `error` - Why the attempt failed
+    /// * `retry_at` - Time of the next attempt
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record_failure(
+        &self,
+        reminder_id: ReminderId,
+        error: String,
+        retry_at: DateTime<Utc>,
+    ) -> Result<(), ReminderError>;
+}
@@ -0,0 +1,543 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+use clock::Clock;
+
+use super::errors::ReminderError;
+use super::models::Reminder;
+use super::models::ReminderId;
+use super::models::ReminderSettings;
+use super::models::ScheduleReminderCommand;
+use super::ports::ReminderRepository;
+use super::ports::ReminderServicePort;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageContent;
+use crate::domain::message::models::Sender;
+use crate::domain::message::ports::MessageServicePort;
+use crate::domain::user::models::UserId;
+
+/// Reminders posted per batch.
+const POST_BATCH_SIZE: i64 = 50;
+
+/// Attempts to post a reminder before it is dropped.
+const MAX_POST_ATTEMPTS: u32 = 5;
+
+/// Delay before retrying a reminder, multiplied by its failed attempts.
+const RETRY_DELAY_SECS: i64 = 60;
+
+/// Domain service posting reminders to channels when they are due.
+///
+/// Reminders are posted by the reminder bot. Whoever schedules a reminder
+/// must be allowed to post in its channel, so the bot posts with moderator
+/// rights and is not turned away from announcement-only channels.
+pub struct ReminderService<RR, MS>
+where
+    RR: ReminderRepository,
+    MS: MessageServicePort,
+{
+    repository: Arc<RR>,
+    message_service: Arc<MS>,
+    settings: ReminderSettings,
+    clock: Arc<dyn Clock>,
+}
+
+impl<RR, MS> ReminderService<RR, MS>
+where
+    RR: ReminderRepository,
+    MS: MessageServicePort,
+{
+    /// Create a new reminder service.
+    ///
+    /// # Arguments
+    /// * `repository` - Reminder persistence implementation
+    /// * `message_service` - Message service posting the reminders
+    /// * `settings` - Bot user, poll interval and scheduling limits
+    ///
+    /// # Returns
+    /// Configured reminder service instance
+    pub fn new(repository: Arc<RR>, message_service: Arc<MS>, settings: ReminderSettings) -> Self {
+        Self {
+            repository,
+            message_service,
+            settings,
+            clock: clock::system(),
+        }
+    }
+
+    /// Decide which reminders are due by another clock.
+    ///
+    /// The system clock by default. A fake clock makes reminders due without
+    /// waiting for them.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock reminders are scheduled and posted by
+    ///
+    /// # Returns
+    /// Service telling the time by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Post due reminders until the process exits.
+    ///
+    /// Every due reminder is posted, then the worker waits `poll_interval`
+    /// for the next ones.
+    pub async fn run(&self) {
+        loop {
+            match self.post_due_reminders().await {
+                Ok(0) => {}
+                Ok(posted) => tracing::debug!(posted, "Posted reminders"),
+                Err(e) => tracing::error!("Failed to post reminders: {}", e),
+            }
+            tokio::time::sleep(self.settings.poll_interval).await;
+        }
+    }
+}
+
+#[async_trait]
+impl<RR, MS> ReminderServicePort for ReminderService<RR, MS>
+where
+    RR: ReminderRepository,
+    MS: MessageServicePort,
+{
+    async fn schedule_reminder(
+        &self,
+        command: ScheduleReminderCommand,
+    ) -> Result<Reminder, ReminderError> {
+        let now = self.clock.now();
+        if command.due_at <= now {
+            return Err(ReminderError::DueInPast);
+        }
+        if command.due_at > now + self.settings.max_delay {
+            return Err(ReminderError::DueTooLate(
+                self.settings.max_delay.num_days(),
+            ));
+        }
+
+        let pending = self.repository.find_by_channel(command.channel_id).await?;
+        if pending.len() >= self.settings.max_pending_per_channel {
+            return Err(ReminderError::TooManyReminders(
+                self.settings.max_pending_per_channel,
+            ));
+        }
+
+        self.repository
+            .create(Reminder {
+                id: ReminderId::new(),
+                channel_id: command.channel_id,
+                created_by: command.created_by,
+                content: command.content,
+                due_at: command.due_at,
+                attempts: 0,
+                last_error: None,
+                created_at: now,
+            })
+            .await
+    }
+
+    async fn list_reminders(&self, channel_id: ChannelId) -> Result<Vec<Reminder>, ReminderError> {
+        self.repository.find_by_channel(channel_id).await
+    }
+
+    async fn cancel_reminder(
+        &self,
+        channel_id: ChannelId,
+        reminder_id: ReminderId,
+        created_by: Option<UserId>,
+    ) -> Result<Reminder, ReminderError> {
+        self.repository
+            .delete(channel_id, reminder_id, created_by)
+            .await?
+            .ok_or_else(|| ReminderError::NotFound(reminder_id.to_string()))
+    }
+
+    async fn post_due_reminders(&self) -> Result<usize, ReminderError> {
+        let mut posted = 0;
+        loop {
+            // Posted reminders are deleted and failed ones postponed, so each
+            // batch holds new ones
+            let due = self
+                .repository
+                .find_due(self.clock.now(), POST_BATCH_SIZE)
+                .await?;
+            let last_batch = (due.len() as i64) < POST_BATCH_SIZE;
+
+            let sender = Sender {
+                user_id: self.settings.bot_id,
+                moderator: true,
+            };
+            for reminder in due {
+                let sent = self
+                    .message_service
+                    .send_message(reminder.channel_id, sender, reminder_content(&reminder))
+                    .await;
+                let attempts = reminder.attempts + 1;
+                match sent {
+                    Ok(_) => {
+                        self.repository
+                            .delete(reminder.channel_id, reminder.id, None)
+                            .await?;
+                        posted += 1;
+                    }
+                    Err(e) if attempts >= MAX_POST_ATTEMPTS => {
+                        tracing::warn!(
+                            "Dropping reminder {} in channel {} after {} attempts: {}",
+                            reminder.id,
+                            reminder.channel_id,
+                            attempts,
+                            e
+                        );
+                        self.repository
+                            .delete(reminder.channel_id, reminder.id, None)
+                            .await?;
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to post reminder {}: {}", reminder.id, e);
+                        let retry_at = self.clock.now()
+                            + Duration::seconds(RETRY_DELAY_SECS * i64::from(attempts));
+                        self.repository
+                            .record_failure(reminder.id, e.to_string(), retry_at)
+                            .await?;
+                    }
+                }
+            }
+
+            if last_batch {
+                return Ok(posted);
+            }
+        }
+    }
+}
+
+/// Message announcing a reminder, its text alone if the prefix does not fit.
+fn reminder_content(reminder: &Reminder) -> MessageContent {
+    MessageContent::new(format!("⏰ Reminder: {}", reminder.content.as_str()))
+        .unwrap_or_else(|_| reminder.content.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::DateTime;
+    use chrono::Utc;
+    use clock::FakeClock;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::message::errors::MessageError;
+    use crate::domain::message::models::HistoryEntry;
+    use crate::domain::message::models::Message;
+    use crate::domain::message::models::MessageId;
+    use crate::domain::message::models::MessageTombstone;
+    use crate::domain::message::ports::DeliveryReporter;
+
+    mock! {
+        pub TestMessageService {}
+
+        #[async_trait]
+        impl MessageServicePort for TestMessageService {
+            async fn send_message(
+                &self,
+                channel_id: ChannelId,
+                sender: Sender,
+                content: MessageContent,
+            ) -> Result<Message, MessageError>;
+            async fn send_message_with_id(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+                sender: Sender,
+                content: MessageContent,
+            ) -> Result<Message, MessageError>;
+            async fn send_message_reporting(
+                &self,
+                channel_id: ChannelId,
+                message_id: Option<MessageId>,
+                sender: Sender,
+                content: MessageContent,
+                reporter: Arc<dyn DeliveryReporter>,
+            ) -> Result<Message, MessageError>;
+            async fn get_channel_messages(
+                &self,
+                channel_id: ChannelId,
+                limit: i32,
+                before: Option<DateTime<Utc>>,
+            ) -> Result<Vec<HistoryEntry>, MessageError>;
+            async fn delete_message(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+                deleted_by: UserId,
+            ) -> Result<MessageTombstone, MessageError>;
+        }
+    }
+
+    /// In-memory repository of pending reminders.
+    struct InMemoryReminderRepository {
+        reminders: Mutex<Vec<Reminder>>,
+    }
+
+    impl InMemoryReminderRepository {
+        fn new(reminders: Vec<Reminder>) -> Self {
+            Self {
+                reminders: Mutex::new(reminders),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ReminderRepository for InMemoryReminderRepository {
+        async fn create(&self, reminder: Reminder) -> Result<Reminder, ReminderError> {
+            self.reminders.lock().unwrap().push(reminder.clone());
+            Ok(reminder)
+        }
+
+        async fn find_by_channel(
+            &self,
+            channel_id: ChannelId,
+        ) -> Result<Vec<Reminder>, ReminderError> {
+            let reminders = self.reminders.lock().unwrap();
+            Ok(reminders
+                .iter()
+                .filter(|reminder| reminder.channel_id == channel_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn delete(
+            &self,
+            channel_id: ChannelId,
+            reminder_id: ReminderId,
+            created_by: Option<UserId>,
+        ) -> Result<Option<Reminder>, ReminderError> {
+            let mut reminders = self.reminders.lock().unwrap();
+            let position = reminders.iter().position(|reminder| {
+                reminder.id == reminder_id
+                    && reminder.channel_id == channel_id
+                    && created_by.is_none_or(|user_id| reminder.created_by == user_id)
+            });
+            Ok(position.map(|position| reminders.remove(position)))
+        }
+
+        async fn find_due(
+            &self,
+            due_before: DateTime<Utc>,
+            _limit: i64,
+        ) -> Result<Vec<Reminder>, ReminderError> {
+            let reminders = self.reminders.lock().unwrap();
+            Ok(reminders
+                .iter()
+                .filter(|reminder| reminder.due_at <= due_before)
+                .cloned()
+                .collect())
+        }
+
+        async fn record_failure(
+            &self,
+            reminder_id: ReminderId,
+            error: String,
+            retry_at: DateTime<Utc>,
+        ) -> Result<(), ReminderError> {
+            let mut reminders = self.reminders.lock().unwrap();
+            if let Some(reminder) = reminders.iter_mut().find(|r| r.id == reminder_id) {
+                reminder.attempts += 1;
+                reminder.last_error = Some(error);
+                reminder.due_at = retry_at;
+            }
+            Ok(())
+        }
+    }
+
+    fn settings() -> ReminderSettings {
+        ReminderSettings {
+            bot_id: UserId::new(),
+            poll_interval: std::time::Duration::from_secs(15),
+            max_delay: Duration::days(30),
+            max_pending_per_channel: 1,
+        }
+    }
+
+    fn reminder(due_in: Duration, attempts: u32) -> Reminder {
+        Reminder {
+            id: ReminderId::new(),
+            channel_id: ChannelId::new(),
+            created_by: UserId::new(),
+            content: MessageContent::new("Stand-up".to_string()).unwrap(),
+            due_at: Utc::now() + due_in,
+            attempts,
+            last_error: None,
+            created_at: Utc::now() - Duration::hours(1),
+        }
+    }
+
+    fn posted(channel_id: ChannelId, sender: Sender, content: MessageContent) -> Message {
+        Message {
+            id: MessageId::new_time_based(),
+            channel_id,
+            user_id: sender.user_id,
+            content,
+            timestamp: Utc::now(),
+            language: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_due_reminders_are_posted_once_by_the_bot() {
+        let settings = settings();
+        let bot_id = settings.bot_id;
+        let due = reminder(-Duration::minutes(1), 0);
+        let channel_id = due.channel_id;
+        let repository = Arc::new(InMemoryReminderRepository::new(vec![
+            due,
+            reminder(Duration::hours(1), 0),
+        ]));
+
+        let mut message_service = MockTestMessageService::new();
+        message_service
+            .expect_send_message()
+            .withf(move |id, sender, content| {
+                *id == channel_id
+                    && sender.user_id == bot_id
+                    && content.as_str() == "⏰ Reminder: Stand-up"
+            })
+            .times(1)
+            .returning(|channel_id, sender, content| Ok(posted(channel_id, sender, content)));
+
+        let service =
+            ReminderService::new(Arc::clone(&repository), Arc::new(message_service), settings);
+
+        assert_eq!(service.post_due_reminders().await.unwrap(), 1);
+        assert_eq!(service.post_due_reminders().await.unwrap(), 0);
+        assert_eq!(repository.reminders.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reminder_is_posted_once_the_clock_reaches_it() {
+        let clock = Arc::new(FakeClock::default());
+        let repository = Arc::new(InMemoryReminderRepository::new(vec![]));
+
+        let mut message_service = MockTestMessageService::new();
+        message_service
+            .expect_send_message()
+            .times(1)
+            .returning(|channel_id, sender, content| Ok(posted(channel_id, sender, content)));
+
+        let service = ReminderService::new(
+            Arc::clone(&repository),
+            Arc::new(message_service),
+            settings(),
+        )
+        .with_clock(clock.clone());
+        service
+            .schedule_reminder(ScheduleReminderCommand {
+                channel_id: ChannelId::new(),
+                created_by: UserId::new(),
+                content: MessageContent::new("Ship it".to_string()).unwrap(),
+                due_at: clock.now() + Duration::days(3),
+            })
+            .await
+            .unwrap();
+
+        clock.advance(Duration::days(3) - Duration::seconds(1));
+        assert_eq!(service.post_due_reminders().await.unwrap(), 0);
+
+        clock.advance(Duration::seconds(1));
+        assert_eq!(service.post_due_reminders().await.unwrap(), 1);
+        assert!(repository.reminders.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_reminder_is_retried_then_dropped() {
+        let repository = Arc::new(InMemoryReminderRepository::new(vec![
+            reminder(-Duration::minutes(1), 0),
+            reminder(-Duration::minutes(1), MAX_POST_ATTEMPTS - 1),
+        ]));
+
+        let mut message_service = MockTestMessageService::new();
+        message_service
+            .expect_send_message()
+            .times(2)
+            .returning(|channel_id, _, _| Err(MessageError::ChannelNotFound(channel_id)));
+
+        let service = ReminderService::new(
+            Arc::clone(&repository),
+            Arc::new(message_service),
+            settings(),
+        );
+
+        assert_eq!(service.post_due_reminders().await.unwrap(), 0);
+
+        let reminders = repository.reminders.lock().unwrap();
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].attempts, 1);
+        assert!(reminders[0].due_at > Utc::now());
+        assert!(reminders[0].last_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_schedule_reminder_enforces_limits() {
+        let existing = reminder(Duration::hours(1), 0);
+        let channel_id = existing.channel_id;
+        let service = ReminderService::new(
+            Arc::new(InMemoryReminderRepository::new(vec![existing])),
+            Arc::new(MockTestMessageService::new()),
+            settings(),
+        );
+        let command = |channel_id, due_in| ScheduleReminderCommand {
+            channel_id,
+            created_by: UserId::new(),
+            content: MessageContent::new("Ship it".to_string()).unwrap(),
+            due_at: Utc::now() + due_in,
+        };
+
+        assert!(matches!(
+            service
+                .schedule_reminder(command(ChannelId::new(), -Duration::minutes(1)))
+                .await,
+            Err(ReminderError::DueInPast)
+        ));
+        assert!(matches!(
+            service
+                .schedule_reminder(command(ChannelId::new(), Duration::days(31)))
+                .await,
+            Err(ReminderError::DueTooLate(30))
+        ));
+        assert!(matches!(
+            service
+                .schedule_reminder(command(channel_id, Duration::hours(2)))
+                .await,
+            Err(ReminderError::TooManyReminders(1))
+        ));
+        let scheduled = service
+            .schedule_reminder(command(ChannelId::new(), Duration::hours(2)))
+            .await
+            .unwrap();
+        assert_eq!(scheduled.attempts, 0);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_reminder_scheduled_by_someone_else() {
+        let pending = reminder(Duration::hours(1), 0);
+        let (channel_id, reminder_id) = (pending.channel_id, pending.id);
+        let service = ReminderService::new(
+            Arc::new(InMemoryReminderRepository::new(vec![pending])),
+            Arc::new(MockTestMessageService::new()),
+            settings(),
+        );
+
+        assert!(matches!(
+            service
+                .cancel_reminder(channel_id, reminder_id, Some(UserId::new()))
+                .await,
+            Err(ReminderError::NotFound(_))
+        ));
+        let cancelled = service
+            .cancel_reminder(channel_id, reminder_id, None)
+            .await
+            .unwrap();
+        assert_eq!(cancelled.id, reminder_id);
+    }
+}
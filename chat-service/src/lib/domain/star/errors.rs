@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+use crate::domain::message::errors::MessageError;
+
+/// Top-level error for all star-related operations
+#[derive(Debug, Error)]
+pub enum StarError {
+    #[error("Unknown period '{0}', expected day, week, month or all")]
+    InvalidPeriod(String),
+
+    #[error("Failed to look up message: {0}")]
+    Message(#[from] MessageError),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
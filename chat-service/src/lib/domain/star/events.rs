@@ -0,0 +1,62 @@
+use chrono::DateTime;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageId;
+use crate::domain::user::models::UserId;
+
+/// Domain event published when a user stars or unstars a message.
+///
+/// Consumed by the star rollup, which counts the stars each message received
+/// per day.
+#[derive(Debug, Clone)]
+pub struct MessageStarChangedEvent {
+    pub event_id: String,
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
+    pub user_id: UserId,
+    /// True for a new star, false for a removed one
+    pub starred: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl MessageStarChangedEvent {
+    /// Create a new MessageStarChanged event.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel of the message
+    /// * `message_id` - Message starred or unstarred
+    /// * `user_id` - User who changed their star
+    /// * `starred` - True if the star was added, false if it was removed
+    ///
+    /// # Returns
+    /// MessageStarChangedEvent with generated event ID and current timestamp
+    pub fn new(
+        channel_id: ChannelId,
+        message_id: MessageId,
+        user_id: UserId,
+        starred: bool,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4().to_string(),
+            channel_id,
+            message_id,
+            user_id,
+            starred,
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// Change the event makes to the star count of its message.
+    ///
+    /// # Returns
+    /// 1 for a new star, -1 for a removed one
+    pub fn delta(&self) -> i64 {
+        if self.starred {
+            1
+        } else {
+            -1
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod errors;
+pub mod events;
+pub mod models;
+pub mod ports;
+pub mod service;
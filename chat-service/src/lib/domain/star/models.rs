@@ -0,0 +1,82 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::Utc;
+
+use crate::domain::message::models::Message;
+use crate::domain::star::errors::StarError;
+
+/// Time window the stars of top messages are counted over.
+///
+/// Stars are rolled up per UTC day, so a window starts at midnight of its
+/// first day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StarPeriod {
+    /// Today and yesterday
+    Day,
+    /// The last 7 days
+    #[default]
+    Week,
+    /// The last 30 days
+    Month,
+    /// Every star ever given
+    All,
+}
+
+impl StarPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StarPeriod::Day => "day",
+            StarPeriod::Week => "week",
+            StarPeriod::Month => "month",
+            StarPeriod::All => "all",
+        }
+    }
+
+    /// First day counted by the period.
+    ///
+    /// # Arguments
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// First day of the window, None if every day counts
+    pub fn first_day(&self, now: DateTime<Utc>) -> Option<NaiveDate> {
+        let length = match self {
+            StarPeriod::Day => Duration::days(1),
+            StarPeriod::Week => Duration::days(7),
+            StarPeriod::Month => Duration::days(30),
+            StarPeriod::All => return None,
+        };
+        Some((now - length).date_naive())
+    }
+}
+
+impl FromStr for StarPeriod {
+    type Err = StarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(StarPeriod::Day),
+            "week" => Ok(StarPeriod::Week),
+            "month" => Ok(StarPeriod::Month),
+            "all" => Ok(StarPeriod::All),
+            other => Err(StarError::InvalidPeriod(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for StarPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Message along with the stars it received over a period.
+#[derive(Debug, Clone)]
+pub struct TopMessage {
+    pub message: Message,
+    pub stars: u64,
+}
@@ -0,0 +1,190 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::Utc;
+
+use super::errors::StarError;
+use super::events::MessageStarChangedEvent;
+use super::models::StarPeriod;
+use super::models::TopMessage;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::errors::EventPublisherError;
+use crate::domain::message::models::MessageId;
+use crate::domain::user::models::UserId;
+
+/// Port for the stars users give to messages.
+#[async_trait]
+pub trait StarServicePort: Send + Sync + 'static {
+    /// Star a message, once per user.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel of the message
+    /// * `message_id` - Message to star
+    /// * `user_id` - User starring the message
+    ///
+    /// # Returns
+    /// True if the star is new, false if the user had already starred the message
+    ///
+    /// # Errors
+    /// * `Message` - Message does not exist in the channel or could not be read
+    /// * `DatabaseError` - Database operation failed
+    async fn star_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        user_id: UserId,
+    ) -> Result<bool, StarError>;
+
+    /// Remove the star of a user from a message.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel of the message
+    /// * `message_id` - Message to unstar
+    /// * `user_id` - User removing their star
+    ///
+    /// # Returns
+    /// True if the star was removed, false if the user had not starred the message
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn unstar_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        user_id: UserId,
+    ) -> Result<bool, StarError>;
+
+    /// List the most starred messages of a channel over a period.
+    ///
+    /// Counts come from the rollup, so stars given moments ago may not be
+    /// counted yet. Deleted messages are left out.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel to rank the messages of
+    /// * `period` - Window the stars are counted over
+    /// * `limit` - Maximum number of messages to return
+    ///
+    /// # Returns
+    /// Messages with their stars, most starred first
+    ///
+    /// # Errors
+    /// * `Message` - Messages could not be read
+    /// * `DatabaseError` - Database operation failed
+    async fn top_messages(
+        &self,
+        channel_id: ChannelId,
+        period: StarPeriod,
+        limit: u32,
+    ) -> Result<Vec<TopMessage>, StarError>;
+
+    /// Count a star change into the rollup.
+    ///
+    /// Events delivered more than once are counted once.
+    ///
+    /// # Arguments
+    /// * `event` - MessageStarChanged event from the event stream
+    ///
+    /// # Returns
+    /// True if the event was counted, false if it had been counted before
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn apply_star_change(&self, event: &MessageStarChangedEvent) -> Result<bool, StarError>;
+}
+
+/// Repository port for stars and their daily rollup.
+#[async_trait]
+pub trait StarRepository: Send + Sync + 'static {
+    /// Record the star of a user, unless they already starred the message.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel of the message
+    /// * `message_id` - Message starred
+    /// * `user_id` - User starring the message
+    /// * `starred_at` - Time of the star
+    ///
+    /// # Returns
+    /// True if the star was recorded, false if it already existed
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn add_star(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        user_id: UserId,
+        starred_at: DateTime<Utc>,
+    ) -> Result<bool, StarError>;
+
+    /// Remove the star of a user.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel of the message
+    /// * `message_id` - Message unstarred
+    /// * `user_id` - User removing their star
+    ///
+    /// # Returns
+    /// True if a star was removed, false if there was none
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn remove_star(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        user_id: UserId,
+    ) -> Result<bool, StarError>;
+
+    /// Add a star change to the count of its message on the day it happened.
+    ///
+    /// # Arguments
+    /// * `event` - Star change to count
+    ///
+    /// # Returns
+    /// True if the change was counted, false if its event ID was counted before
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record_change(&self, event: &MessageStarChangedEvent) -> Result<bool, StarError>;
+
+    /// Sum the daily counts of the messages of a channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel to rank the messages of
+    /// * `since` - First day counted, None to count every day
+    /// * `limit` - Maximum number of messages to return
+    ///
+    /// # Returns
+    /// Message IDs with a positive number of stars, most starred first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn top_messages(
+        &self,
+        channel_id: ChannelId,
+        since: Option<NaiveDate>,
+        limit: i64,
+    ) -> Result<Vec<(MessageId, u64)>, StarError>;
+}
+
+/// Port for publishing star changes to the event stream.
+#[async_trait]
+pub trait StarEventPublisher: Send + Sync + 'static {
+    /// Publish a star change.
+    ///
+    /// # Arguments
+    /// * `event` - MessageStarChanged event
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `SerializationFailed` - Event serialization failed
+    /// * `PublishFailed` - Failed to publish to broker
+    /// * `ConnectionFailed` - Broker connection failed
+    /// * `Timeout` - Publishing timed out
+    async fn publish_star_changed(
+        &self,
+        event: &MessageStarChangedEvent,
+    ) -> Result<(), EventPublisherError>;
+}
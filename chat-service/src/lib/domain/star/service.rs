@@ -0,0 +1,458 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use super::errors::StarError;
+use super::events::MessageStarChangedEvent;
+use super::models::StarPeriod;
+use super::models::TopMessage;
+use super::ports::StarEventPublisher;
+use super::ports::StarRepository;
+use super::ports::StarServicePort;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::errors::MessageError;
+use crate::domain::message::models::MessageId;
+use crate::domain::message::ports::MessageRepository;
+use crate::domain::user::models::UserId;
+
+/// Domain service for the stars users give to messages.
+///
+/// Stars are stored as they are given, and every change is published to the
+/// event stream. Top messages are ranked from a daily rollup fed by that
+/// stream, so ranking a channel never scans its stars.
+pub struct StarService<SR, EP, MR>
+where
+    SR: StarRepository,
+    EP: StarEventPublisher,
+    MR: MessageRepository,
+{
+    repository: Arc<SR>,
+    event_publisher: Arc<EP>,
+    message_repository: Arc<MR>,
+}
+
+impl<SR, EP, MR> StarService<SR, EP, MR>
+where
+    SR: StarRepository,
+    EP: StarEventPublisher,
+    MR: MessageRepository,
+{
+    /// Create a new star service.
+    ///
+    /// # Arguments
+    /// * `repository` - Star and rollup persistence implementation
+    /// * `event_publisher` - Publisher of star changes
+    /// * `message_repository` - Message persistence, to look up starred messages
+    ///
+    /// # Returns
+    /// Configured star service instance
+    pub fn new(repository: Arc<SR>, event_publisher: Arc<EP>, message_repository: Arc<MR>) -> Self {
+        Self {
+            repository,
+            event_publisher,
+            message_repository,
+        }
+    }
+
+    /// Publish a star change for the rollup.
+    ///
+    /// The star itself is stored, so a failed publish only leaves it out of
+    /// the top messages.
+    async fn publish_change(&self, event: MessageStarChangedEvent) {
+        if let Err(e) = self.event_publisher.publish_star_changed(&event).await {
+            tracing::error!(
+                "Failed to publish star change of message {}: {}",
+                event.message_id,
+                e
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl<SR, EP, MR> StarServicePort for StarService<SR, EP, MR>
+where
+    SR: StarRepository,
+    EP: StarEventPublisher,
+    MR: MessageRepository,
+{
+    async fn star_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        user_id: UserId,
+    ) -> Result<bool, StarError> {
+        if self
+            .message_repository
+            .find_by_id(channel_id, message_id)
+            .await?
+            .is_none()
+        {
+            return Err(MessageError::NotFound(message_id).into());
+        }
+
+        let starred = self
+            .repository
+            .add_star(channel_id, message_id, user_id, Utc::now())
+            .await?;
+        if starred {
+            self.publish_change(MessageStarChangedEvent::new(
+                channel_id, message_id, user_id, true,
+            ))
+            .await;
+        }
+
+        Ok(starred)
+    }
+
+    async fn unstar_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        user_id: UserId,
+    ) -> Result<bool, StarError> {
+        let unstarred = self
+            .repository
+            .remove_star(channel_id, message_id, user_id)
+            .await?;
+        if unstarred {
+            self.publish_change(MessageStarChangedEvent::new(
+                channel_id, message_id, user_id, false,
+            ))
+            .await;
+        }
+
+        Ok(unstarred)
+    }
+
+    async fn top_messages(
+        &self,
+        channel_id: ChannelId,
+        period: StarPeriod,
+        limit: u32,
+    ) -> Result<Vec<TopMessage>, StarError> {
+        let ranked = self
+            .repository
+            .top_messages(channel_id, period.first_day(Utc::now()), i64::from(limit))
+            .await?;
+
+        let mut top = Vec::with_capacity(ranked.len());
+        for (message_id, stars) in ranked {
+            // Deleted messages keep their stars but are no longer shown
+            if let Some(message) = self
+                .message_repository
+                .find_by_id(channel_id, message_id)
+                .await?
+            {
+                top.push(TopMessage { message, stars });
+            }
+        }
+
+        Ok(top)
+    }
+
+    async fn apply_star_change(&self, event: &MessageStarChangedEvent) -> Result<bool, StarError> {
+        let counted = self.repository.record_change(event).await?;
+        if !counted {
+            tracing::debug!(
+                event_id = %event.event_id,
+                "Skipping star change counted before"
+            );
+        }
+
+        Ok(counted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    use chrono::DateTime;
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use mockall::mock;
+    use mockall::predicate::*;
+
+    use super::*;
+    use crate::domain::errors::EventPublisherError;
+    use crate::domain::message::models::HistoryEntry;
+    use crate::domain::message::models::Message;
+    use crate::domain::message::models::MessageContent;
+    use crate::domain::message::models::MessageTombstone;
+
+    mock! {
+        pub TestMessageRepository {}
+
+        #[async_trait]
+        impl MessageRepository for TestMessageRepository {
+            async fn create(&self, message: Message) -> Result<Message, MessageError>;
+            async fn create_if_absent(&self, message: Message) -> Result<Message, MessageError>;
+            async fn find_by_channel(
+                &self,
+                channel_id: ChannelId,
+                limit: i32,
+                before: Option<DateTime<Utc>>,
+            ) -> Result<Vec<Message>, MessageError>;
+            async fn find_by_user(
+                &self,
+                user_id: UserId,
+                limit: i32,
+            ) -> Result<Vec<Message>, MessageError>;
+            async fn find_by_id(
+                &self,
+                channel_id: ChannelId,
+                message_id: MessageId,
+            ) -> Result<Option<Message>, MessageError>;
+            async fn find_history(
+                &self,
+                channel_id: ChannelId,
+                limit: i32,
+                before: Option<DateTime<Utc>>,
+            ) -> Result<Vec<HistoryEntry>, MessageError>;
+            async fn delete(
+                &self,
+                message: &Message,
+                tombstone: MessageTombstone,
+            ) -> Result<MessageTombstone, MessageError>;
+        }
+    }
+
+    mock! {
+        pub TestStarEventPublisher {}
+
+        #[async_trait]
+        impl StarEventPublisher for TestStarEventPublisher {
+            async fn publish_star_changed(
+                &self,
+                event: &MessageStarChangedEvent,
+            ) -> Result<(), EventPublisherError>;
+        }
+    }
+
+    /// In-memory repository of stars and of their daily rollup.
+    #[derive(Default)]
+    struct InMemoryStarRepository {
+        stars: Mutex<HashSet<(MessageId, UserId)>>,
+        counted_events: Mutex<HashSet<String>>,
+        rollup: Mutex<HashMap<(ChannelId, MessageId, NaiveDate), i64>>,
+    }
+
+    #[async_trait]
+    impl StarRepository for InMemoryStarRepository {
+        async fn add_star(
+            &self,
+            _channel_id: ChannelId,
+            message_id: MessageId,
+            user_id: UserId,
+            _starred_at: DateTime<Utc>,
+        ) -> Result<bool, StarError> {
+            Ok(self.stars.lock().unwrap().insert((message_id, user_id)))
+        }
+
+        async fn remove_star(
+            &self,
+            _channel_id: ChannelId,
+            message_id: MessageId,
+            user_id: UserId,
+        ) -> Result<bool, StarError> {
+            Ok(self.stars.lock().unwrap().remove(&(message_id, user_id)))
+        }
+
+        async fn record_change(&self, event: &MessageStarChangedEvent) -> Result<bool, StarError> {
+            if !self
+                .counted_events
+                .lock()
+                .unwrap()
+                .insert(event.event_id.clone())
+            {
+                return Ok(false);
+            }
+            let day = event.timestamp.date_naive();
+            *self
+                .rollup
+                .lock()
+                .unwrap()
+                .entry((event.channel_id, event.message_id, day))
+                .or_default() += event.delta();
+            Ok(true)
+        }
+
+        async fn top_messages(
+            &self,
+            channel_id: ChannelId,
+            since: Option<NaiveDate>,
+            limit: i64,
+        ) -> Result<Vec<(MessageId, u64)>, StarError> {
+            let mut totals: HashMap<MessageId, i64> = HashMap::new();
+            for ((channel, message_id, day), stars) in self.rollup.lock().unwrap().iter() {
+                if *channel == channel_id && since.is_none_or(|since| *day >= since) {
+                    *totals.entry(*message_id).or_default() += stars;
+                }
+            }
+            let mut top: Vec<(MessageId, u64)> = totals
+                .into_iter()
+                .filter(|(_, stars)| *stars > 0)
+                .map(|(message_id, stars)| (message_id, stars as u64))
+                .collect();
+            top.sort_by_key(|(_, stars)| std::cmp::Reverse(*stars));
+            top.truncate(limit as usize);
+            Ok(top)
+        }
+    }
+
+    fn message(channel_id: ChannelId, content: &str) -> Message {
+        Message {
+            id: MessageId::new_time_based(),
+            channel_id,
+            user_id: UserId::new(),
+            content: MessageContent::new(content.to_string()).unwrap(),
+            timestamp: Utc::now(),
+            language: None,
+        }
+    }
+
+    fn star_event(
+        channel_id: ChannelId,
+        message_id: MessageId,
+        starred: bool,
+        days_ago: i64,
+    ) -> MessageStarChangedEvent {
+        MessageStarChangedEvent {
+            timestamp: Utc::now() - Duration::days(days_ago),
+            ..MessageStarChangedEvent::new(channel_id, message_id, UserId::new(), starred)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_users_star_a_message_once() {
+        let channel_id = ChannelId::new();
+        let starred = message(channel_id, "Release is out");
+        let message_id = starred.id;
+        let user_id = UserId::new();
+
+        let mut message_repository = MockTestMessageRepository::new();
+        message_repository
+            .expect_find_by_id()
+            .with(eq(channel_id), eq(message_id))
+            .returning(move |_, _| Ok(Some(starred.clone())));
+        let mut event_publisher = MockTestStarEventPublisher::new();
+        event_publisher
+            .expect_publish_star_changed()
+            .withf(move |event| event.message_id == message_id && event.starred)
+            .times(1)
+            .returning(|_| Ok(()));
+        event_publisher
+            .expect_publish_star_changed()
+            .withf(move |event| event.message_id == message_id && !event.starred)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = StarService::new(
+            Arc::new(InMemoryStarRepository::default()),
+            Arc::new(event_publisher),
+            Arc::new(message_repository),
+        );
+
+        assert!(service
+            .star_message(channel_id, message_id, user_id)
+            .await
+            .unwrap());
+        assert!(!service
+            .star_message(channel_id, message_id, user_id)
+            .await
+            .unwrap());
+        assert!(service
+            .unstar_message(channel_id, message_id, user_id)
+            .await
+            .unwrap());
+        assert!(!service
+            .unstar_message(channel_id, message_id, user_id)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_starring_unknown_message_fails() {
+        let mut message_repository = MockTestMessageRepository::new();
+        message_repository
+            .expect_find_by_id()
+            .returning(|_, _| Ok(None));
+
+        let service = StarService::new(
+            Arc::new(InMemoryStarRepository::default()),
+            Arc::new(MockTestStarEventPublisher::new()),
+            Arc::new(message_repository),
+        );
+
+        let result = service
+            .star_message(ChannelId::new(), MessageId::new_time_based(), UserId::new())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(StarError::Message(MessageError::NotFound(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_top_messages_count_stars_of_the_period_once() {
+        let channel_id = ChannelId::new();
+        let recent = message(channel_id, "Recent highlight");
+        let old = message(channel_id, "Old highlight");
+        let deleted_id = MessageId::new_time_based();
+        let (recent_id, old_id) = (recent.id, old.id);
+
+        let mut message_repository = MockTestMessageRepository::new();
+        message_repository
+            .expect_find_by_id()
+            .returning(move |_, message_id| {
+                Ok([&recent, &old]
+                    .into_iter()
+                    .find(|message| message.id == message_id)
+                    .cloned())
+            });
+
+        let service = StarService::new(
+            Arc::new(InMemoryStarRepository::default()),
+            Arc::new(MockTestStarEventPublisher::new()),
+            Arc::new(message_repository),
+        );
+
+        let redelivered = star_event(channel_id, recent_id, true, 0);
+        for event in [
+            redelivered.clone(),
+            redelivered,
+            star_event(channel_id, recent_id, true, 1),
+            star_event(channel_id, old_id, true, 20),
+            star_event(channel_id, old_id, true, 20),
+            star_event(channel_id, old_id, true, 20),
+            star_event(channel_id, old_id, true, 20),
+            star_event(channel_id, old_id, false, 2),
+            star_event(channel_id, deleted_id, true, 0),
+        ] {
+            service.apply_star_change(&event).await.unwrap();
+        }
+
+        let week = service
+            .top_messages(channel_id, StarPeriod::Week, 10)
+            .await
+            .unwrap();
+        let ranked: Vec<(MessageId, u64)> =
+            week.iter().map(|top| (top.message.id, top.stars)).collect();
+        assert_eq!(ranked, vec![(recent_id, 2)]);
+
+        let month = service
+            .top_messages(channel_id, StarPeriod::Month, 10)
+            .await
+            .unwrap();
+        let ranked: Vec<(MessageId, u64)> = month
+            .iter()
+            .map(|top| (top.message.id, top.stars))
+            .collect();
+        assert_eq!(ranked, vec![(old_id, 3), (recent_id, 2)]);
+    }
+}
@@ -7,6 +7,12 @@ pub enum UserEvent {
     UserCreated(UserCreatedEvent),
     UserUpdated(UserUpdatedEvent),
     UserDeleted(UserDeletedEvent),
+    UserDeactivated(UserDeactivatedEvent),
+    UserReactivated(UserReactivatedEvent),
+    UserPasswordChanged(UserPasswordChangedEvent),
+    UserPasswordResetRequested(UserPasswordResetRequestedEvent),
+    UserPasswordReset(UserPasswordResetEvent),
+    UserAccountLocked(UserAccountLockedEvent),
 }
 
 impl UserEvent {
@@ -15,6 +21,12 @@ impl UserEvent {
             UserEvent::UserCreated(e) => &e.event_id,
             UserEvent::UserUpdated(e) => &e.event_id,
             UserEvent::UserDeleted(e) => &e.event_id,
+            UserEvent::UserDeactivated(e) => &e.event_id,
+            UserEvent::UserReactivated(e) => &e.event_id,
+            UserEvent::UserPasswordChanged(e) => &e.event_id,
+            UserEvent::UserPasswordResetRequested(e) => &e.event_id,
+            UserEvent::UserPasswordReset(e) => &e.event_id,
+            UserEvent::UserAccountLocked(e) => &e.event_id,
         }
     }
 
@@ -23,6 +35,12 @@ impl UserEvent {
             UserEvent::UserCreated(_) => "user_created",
             UserEvent::UserUpdated(_) => "user_updated",
             UserEvent::UserDeleted(_) => "user_deleted",
+            UserEvent::UserDeactivated(_) => "user_deactivated",
+            UserEvent::UserReactivated(_) => "user_reactivated",
+            UserEvent::UserPasswordChanged(_) => "user_password_changed",
+            UserEvent::UserPasswordResetRequested(_) => "user_password_reset_requested",
+            UserEvent::UserPasswordReset(_) => "user_password_reset",
+            UserEvent::UserAccountLocked(_) => "user_account_locked",
         }
     }
 
@@ -31,6 +49,12 @@ impl UserEvent {
             UserEvent::UserCreated(e) => &e.user_id,
             UserEvent::UserUpdated(e) => &e.user_id,
             UserEvent::UserDeleted(e) => &e.user_id,
+            UserEvent::UserDeactivated(e) => &e.user_id,
+            UserEvent::UserReactivated(e) => &e.user_id,
+            UserEvent::UserPasswordChanged(e) => &e.user_id,
+            UserEvent::UserPasswordResetRequested(e) => &e.user_id,
+            UserEvent::UserPasswordReset(e) => &e.user_id,
+            UserEvent::UserAccountLocked(e) => &e.user_id,
         }
     }
 }
@@ -52,6 +76,8 @@ pub struct UserUpdatedEvent {
     pub user_id: String,
     pub username: String,
     pub email: String,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -62,3 +88,54 @@ pub struct UserDeletedEvent {
     pub user_id: String,
     pub deleted_at: DateTime<Utc>,
 }
+
+/// Event published when a user is deactivated in user-service
+#[derive(Debug, Clone)]
+pub struct UserDeactivatedEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub deactivated_at: DateTime<Utc>,
+}
+
+/// Event published when a deactivated user is reactivated in user-service
+#[derive(Debug, Clone)]
+pub struct UserReactivatedEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub reactivated_at: DateTime<Utc>,
+}
+
+/// Event published when a user changes their password in user-service
+#[derive(Debug, Clone)]
+pub struct UserPasswordChangedEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Event published when a password reset link is sent to a user in user-service
+#[derive(Debug, Clone)]
+pub struct UserPasswordResetRequestedEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub requested_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Event published when a user resets their password in user-service
+#[derive(Debug, Clone)]
+pub struct UserPasswordResetEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub reset_at: DateTime<Utc>,
+}
+
+/// Event published when repeated failed logins lock a user's account in user-service
+#[derive(Debug, Clone)]
+pub struct UserAccountLockedEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub failed_attempts: u32,
+    pub locked_at: DateTime<Utc>,
+    pub locked_until: DateTime<Utc>,
+}
@@ -15,10 +15,25 @@ use crate::domain::user::errors::UsernameError;
 pub struct User {
     pub id: UserId,
     pub username: Username,
+    /// Name the user chose to be shown with
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl User {
+    /// Get the name to show for the user.
+    ///
+    /// # Returns
+    /// Display name, or the username when the user has none
+    pub fn shown_name(&self) -> &str {
+        self.display_name
+            .as_deref()
+            .unwrap_or(self.username.as_str())
+    }
+}
+
 /// User unique identifier value object.
 ///
 /// Wraps UUID v4 with type safety to prevent mixing with other IDs.
@@ -67,6 +82,12 @@ impl UserId {
     }
 }
 
+impl Default for UserId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl fmt::Display for UserId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -1,7 +1,11 @@
 use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
 
 use super::events::UserCreatedEvent;
+use super::events::UserDeactivatedEvent;
 use super::events::UserDeletedEvent;
+use super::events::UserReactivatedEvent;
 use super::events::UserUpdatedEvent;
 use crate::domain::user::models::User;
 use crate::domain::user::models::UserId;
@@ -20,6 +24,18 @@ pub trait UserServicePort: Send + Sync + 'static {
     /// # Errors
     /// Returns error string if gRPC call fails
     async fn get_user(&self, user_id: UserId) -> Result<Option<User>, String>;
+
+    /// Get multiple users by IDs from user-service.
+    ///
+    /// # Arguments
+    /// * `user_ids` - Slice of user IDs to retrieve
+    ///
+    /// # Returns
+    /// Vector of found users (missing IDs are skipped without error)
+    ///
+    /// # Errors
+    /// Returns error string if gRPC call fails
+    async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, String>;
 }
 
 /// Port for local user replica repository.
@@ -52,6 +68,23 @@ pub trait UserReplicaRepository: Send + Sync + 'static {
     /// Returns error string if database operation fails
     async fn delete(&self, user_id: UserId) -> Result<(), String>;
 
+    /// Record whether a user in the replica is deactivated.
+    ///
+    /// # Arguments
+    /// * `user_id` - User ID to update
+    /// * `deactivated_at` - When the user was deactivated, None once reactivated
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// Returns error string if database operation fails
+    async fn set_deactivated(
+        &self,
+        user_id: UserId,
+        deactivated_at: Option<DateTime<Utc>>,
+    ) -> Result<(), String>;
+
     /// Get user from replica by ID.
     ///
     /// # Arguments
@@ -130,4 +163,35 @@ pub trait UserEventConsumer: Send + Sync + 'static {
     /// * Database operation failed during cleanup
     /// * Invalid user ID in event
     async fn handle_user_deleted(&self, event: &UserDeletedEvent) -> Result<(), String>;
+
+    /// Handle user deactivation event.
+    ///
+    /// Marks the user deactivated in the local replica. Their channels and
+    /// messages are kept, since the account can be reactivated.
+    ///
+    /// # Arguments
+    /// * `event` - UserDeactivated event from user-service
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * Database operation failed
+    /// * Invalid user ID in event
+    async fn handle_user_deactivated(&self, event: &UserDeactivatedEvent) -> Result<(), String>;
+
+    /// Handle user reactivation event.
+    ///
+    /// Clears the deactivation of the user in the local replica.
+    ///
+    /// # Arguments
+    /// * `event` - UserReactivated event from user-service
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * Database operation failed
+    /// * Invalid user ID in event
+    async fn handle_user_reactivated(&self, event: &UserReactivatedEvent) -> Result<(), String>;
 }
@@ -0,0 +1,429 @@
+//! Deterministic builders for domain models.
+//!
+//! Available to unit tests and, with the `fixtures` feature, to integration tests.
+//! Every default is fixed (timestamps start at [`epoch`], IDs are derived from
+//! their inputs), so two runs of the same test build identical models.
+//!
+//! ```
+//! use chat_service::fixtures::ChannelFixture;
+//! use chat_service::fixtures::MessageFixture;
+//! use chat_service::fixtures::user_id;
+//!
+//! let channel = ChannelFixture::public("general").build();
+//! let message = MessageFixture::in_channel(channel.id())
+//!     .from_user(user_id(2))
+//!     .with_content("Hello")
+//!     .build();
+//!
+//! assert_eq!(message.channel_id, channel.id());
+//! ```
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use uuid::Timestamp;
+use uuid::Uuid;
+
+use crate::domain::channel::models::Channel;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::channel::models::ChannelName;
+use crate::domain::channel::models::DirectChannel;
+use crate::domain::channel::models::PrivateChannel;
+use crate::domain::channel::models::PublicChannel;
+use crate::domain::channel::models::WorkspaceId;
+use crate::domain::message::models::Message;
+use crate::domain::message::models::MessageContent;
+use crate::domain::message::models::MessageId;
+use crate::domain::user::models::UserId;
+
+/// 2024-01-01T00:00:00Z, the default timestamp of every fixture.
+const EPOCH_SECONDS: i64 = 1_704_067_200;
+
+/// Fixed point in time that fixture timestamps are based on.
+///
+/// # Returns
+/// 2024-01-01T00:00:00Z
+pub fn epoch() -> DateTime<Utc> {
+    DateTime::from_timestamp(EPOCH_SECONDS, 0).expect("fixture epoch is a valid timestamp")
+}
+
+/// Deterministic user ID for the n-th test user.
+///
+/// # Arguments
+/// * `n` - User number; the same number always yields the same ID
+///
+/// # Returns
+/// UserId wrapping `Uuid::from_u128(n)`
+pub fn user_id(n: u128) -> UserId {
+    UserId(Uuid::from_u128(n))
+}
+
+/// Time-based message ID for a given timestamp, stable across runs.
+///
+/// # Arguments
+/// * `timestamp` - Time encoded into the UUID v1
+///
+/// # Returns
+/// MessageId ordered like its timestamp
+pub fn message_id_at(timestamp: DateTime<Utc>) -> MessageId {
+    let uuid_timestamp = Timestamp::from_unix(
+        uuid::timestamp::context::NoContext,
+        timestamp.timestamp() as u64,
+        timestamp.timestamp_subsec_nanos(),
+    );
+    MessageId(Uuid::new_v1(uuid_timestamp, &[0u8; 6]))
+}
+
+/// UUIDv7 message ID for a given timestamp.
+///
+/// # Arguments
+/// * `timestamp` - Time encoded into the UUIDv7, at millisecond precision
+///
+/// # Returns
+/// MessageId ordered like its timestamp
+pub fn message_id_v7_at(timestamp: DateTime<Utc>) -> MessageId {
+    let uuid_timestamp = Timestamp::from_unix(
+        uuid::timestamp::context::NoContext,
+        timestamp.timestamp() as u64,
+        timestamp.timestamp_subsec_nanos(),
+    );
+    MessageId(Uuid::new_v7(uuid_timestamp))
+}
+
+/// Builder for [`Message`] with fixed defaults.
+///
+/// Defaults to [`user_id(1)`](user_id) as author, "Test message" as content
+/// and [`epoch`] as timestamp.
+#[derive(Debug, Clone)]
+pub struct MessageFixture {
+    id: Option<MessageId>,
+    channel_id: ChannelId,
+    user_id: UserId,
+    content: String,
+    timestamp: DateTime<Utc>,
+}
+
+impl MessageFixture {
+    /// Start building a message posted in the given channel.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel the message belongs to
+    ///
+    /// # Returns
+    /// Message fixture with default author, content, and timestamp
+    pub fn in_channel(channel_id: ChannelId) -> Self {
+        Self {
+            id: None,
+            channel_id,
+            user_id: user_id(1),
+            content: "Test message".to_string(),
+            timestamp: epoch(),
+        }
+    }
+
+    /// Set the author.
+    pub fn from_user(mut self, user_id: UserId) -> Self {
+        self.user_id = user_id;
+        self
+    }
+
+    /// Set the content.
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = content.into();
+        self
+    }
+
+    /// Set the timestamp.
+    pub fn at(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Set an explicit ID instead of deriving it from the timestamp.
+    pub fn with_id(mut self, id: MessageId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Build the message.
+    ///
+    /// # Returns
+    /// Message with ID derived from the timestamp unless set explicitly
+    ///
+    /// # Panics
+    /// Content is empty or longer than the message limit
+    pub fn build(self) -> Message {
+        Message {
+            id: self.id.unwrap_or_else(|| message_id_at(self.timestamp)),
+            channel_id: self.channel_id,
+            user_id: self.user_id,
+            content: MessageContent::new(self.content).expect("fixture content must be valid"),
+            timestamp: self.timestamp,
+            language: None,
+        }
+    }
+
+    /// Build `count` messages one second apart, numbered "Message 1" onwards.
+    ///
+    /// # Arguments
+    /// * `count` - Number of messages to build
+    ///
+    /// # Returns
+    /// Messages in chronological order, starting at the fixture timestamp
+    pub fn build_many(self, count: usize) -> Vec<Message> {
+        (0..count)
+            .map(|i| {
+                self.clone()
+                    .with_content(format!("Message {}", i + 1))
+                    .at(self.timestamp + Duration::seconds(i as i64))
+                    .build()
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum ChannelFixtureKind {
+    Public,
+    Private { members: Vec<UserId> },
+    Direct { participants: [UserId; 2] },
+}
+
+/// Builder for [`Channel`] with fixed defaults.
+///
+/// Defaults to [`user_id(1)`](user_id) as creator in the default workspace,
+/// no description and [`epoch`] as creation time. The ID is derived from the name (or
+/// participants for direct channels).
+#[derive(Debug, Clone)]
+pub struct ChannelFixture {
+    kind: ChannelFixtureKind,
+    id: Option<ChannelId>,
+    workspace_id: WorkspaceId,
+    name: String,
+    description: Option<String>,
+    created_by: UserId,
+    created_at: DateTime<Utc>,
+    embeddable: bool,
+    discoverable: bool,
+    announcement_only: bool,
+    auto_join: bool,
+}
+
+impl ChannelFixture {
+    /// Start building a public channel.
+    ///
+    /// # Arguments
+    /// * `name` - Channel name
+    ///
+    /// # Returns
+    /// Public channel fixture
+    pub fn public(name: impl Into<String>) -> Self {
+        Self::with_kind(ChannelFixtureKind::Public, name.into())
+    }
+
+    /// Start building a private channel without members.
+    ///
+    /// # Arguments
+    /// * `name` - Channel name
+    ///
+    /// # Returns
+    /// Private channel fixture
+    pub fn private(name: impl Into<String>) -> Self {
+        Self::with_kind(
+            ChannelFixtureKind::Private {
+                members: Vec::new(),
+            },
+            name.into(),
+        )
+    }
+
+    /// Start building a direct channel created by the first participant.
+    ///
+    /// # Arguments
+    /// * `creator` - Participant who opened the conversation
+    /// * `participant` - Other participant
+    ///
+    /// # Returns
+    /// Direct channel fixture
+    pub fn direct(creator: UserId, participant: UserId) -> Self {
+        let mut fixture = Self::with_kind(
+            ChannelFixtureKind::Direct {
+                participants: [creator, participant],
+            },
+            format!("direct:{}:{}", creator, participant),
+        );
+        fixture.created_by = creator;
+        fixture
+    }
+
+    fn with_kind(kind: ChannelFixtureKind, name: String) -> Self {
+        Self {
+            kind,
+            id: None,
+            workspace_id: WorkspaceId::default(),
+            name,
+            description: None,
+            created_by: user_id(1),
+            created_at: epoch(),
+            embeddable: false,
+            discoverable: false,
+            announcement_only: false,
+            auto_join: false,
+        }
+    }
+
+    /// Set an explicit ID instead of deriving it from the name.
+    pub fn with_id(mut self, id: ChannelId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Place the channel in a workspace other than the default one.
+    pub fn in_workspace(mut self, workspace_id: WorkspaceId) -> Self {
+        self.workspace_id = workspace_id;
+        self
+    }
+
+    /// Set the description (ignored for direct channels).
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Flag the channel as embeddable (ignored for private and direct channels).
+    pub fn embeddable(mut self) -> Self {
+        self.embeddable = true;
+        self
+    }
+
+    /// List the channel in the public directory (ignored for private and direct channels).
+    pub fn discoverable(mut self) -> Self {
+        self.discoverable = true;
+        self
+    }
+
+    /// Mark the channel as a default channel (ignored for private and direct channels).
+    pub fn auto_join(mut self) -> Self {
+        self.auto_join = true;
+        self
+    }
+
+    /// Let only the creator and moderators post (ignored for direct channels).
+    pub fn announcement_only(mut self) -> Self {
+        self.announcement_only = true;
+        self
+    }
+
+    /// Set the creator.
+    pub fn created_by(mut self, user_id: UserId) -> Self {
+        self.created_by = user_id;
+        self
+    }
+
+    /// Set the creation time.
+    pub fn at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// Set the members of a private channel (ignored for other kinds).
+    pub fn with_members(mut self, members: Vec<UserId>) -> Self {
+        if let ChannelFixtureKind::Private { members: current } = &mut self.kind {
+            *current = members;
+        }
+        self
+    }
+
+    /// Build the channel.
+    ///
+    /// # Returns
+    /// Channel of the requested kind
+    ///
+    /// # Panics
+    /// Name of a public or private channel is empty or too long
+    pub fn build(self) -> Channel {
+        let id = self
+            .id
+            .unwrap_or_else(|| ChannelId(Uuid::new_v5(&Uuid::NAMESPACE_OID, self.name.as_bytes())));
+
+        match self.kind {
+            ChannelFixtureKind::Public => Channel::Public(PublicChannel {
+                id,
+                workspace_id: self.workspace_id,
+                name: ChannelName::new(self.name).expect("fixture name must be valid"),
+                description: self.description,
+                created_by: self.created_by,
+                created_at: self.created_at,
+                embeddable: self.embeddable,
+                discoverable: self.discoverable,
+                announcement_only: self.announcement_only,
+                auto_join: self.auto_join,
+            }),
+            ChannelFixtureKind::Private { members } => Channel::Private(PrivateChannel {
+                id,
+                workspace_id: self.workspace_id,
+                name: ChannelName::new(self.name).expect("fixture name must be valid"),
+                description: self.description,
+                created_by: self.created_by,
+                created_at: self.created_at,
+                members,
+                announcement_only: self.announcement_only,
+            }),
+            ChannelFixtureKind::Direct { participants } => Channel::Direct(DirectChannel {
+                id,
+                workspace_id: self.workspace_id,
+                created_by: self.created_by,
+                created_at: self.created_at,
+                participants,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_fixture_is_deterministic() {
+        let channel_id = ChannelFixture::public("general").build().id();
+
+        let first = MessageFixture::in_channel(channel_id).build();
+        let second = MessageFixture::in_channel(channel_id).build();
+
+        assert_eq!(first.id, second.id);
+        assert_eq!(first.timestamp, epoch());
+        assert_eq!(first.user_id, user_id(1));
+        assert_eq!(first.content.as_str(), "Test message");
+    }
+
+    #[test]
+    fn test_build_many_orders_messages() {
+        let messages = MessageFixture::in_channel(ChannelId::default()).build_many(3);
+
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[2].content.as_str(), "Message 3");
+        assert!(messages[0].timestamp < messages[1].timestamp);
+        assert_ne!(messages[0].id, messages[1].id);
+    }
+
+    #[test]
+    fn test_channel_fixture_kinds() {
+        let public = ChannelFixture::public("general")
+            .with_description("General discussion")
+            .build();
+        let private = ChannelFixture::private("team")
+            .with_members(vec![user_id(2), user_id(3)])
+            .build();
+        let direct = ChannelFixture::direct(user_id(1), user_id(2)).build();
+
+        assert_eq!(public.channel_type(), "public");
+        assert_eq!(public.description(), Some("General discussion"));
+        assert_eq!(public.id(), ChannelFixture::public("general").build().id());
+        assert!(matches!(private, Channel::Private(ref c) if c.members.len() == 2));
+        assert!(
+            matches!(direct, Channel::Direct(ref c) if c.participants == [user_id(1), user_id(2)])
+        );
+        assert_ne!(public.id(), private.id());
+    }
+}
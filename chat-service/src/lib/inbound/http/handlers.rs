@@ -1,5 +1,13 @@
 pub mod channels;
+pub mod email;
+pub mod embed;
+pub mod feeds;
+pub mod gateway;
+pub mod internal;
 pub mod messages;
+pub mod polls;
+pub mod reminders;
+pub mod stars;
 
 // Re-export handlers for easy access
 use axum::http::StatusCode;
@@ -8,20 +16,71 @@ use axum::response::Response;
 use axum::Json;
 pub use channels::create_channel;
 pub use channels::get_channel;
+pub use channels::list_channel_directory;
 pub use channels::list_public_channels;
+pub use channels::list_user_channels;
+pub use channels::set_channel_auto_join;
 use chrono::DateTime;
 use chrono::Utc;
+pub use email::get_channel_email_address;
+pub use email::receive_email;
+pub use embed::get_embedded_messages;
+use envelope::Pagination;
+use envelope::ResponseData;
+use envelope::ResponseError;
+pub use feeds::list_channel_feeds;
+pub use feeds::register_channel_feed;
+pub use feeds::remove_channel_feed;
+pub use gateway::get_gateway;
+pub use internal::get_connections;
+pub use internal::get_leader;
+pub use internal::get_metrics;
+pub use internal::get_readiness;
+pub use internal::get_version;
+pub use messages::delete_message;
 pub use messages::get_channel_messages;
+pub use messages::send_message;
+pub use polls::create_channel_poll;
+pub use polls::get_channel_poll;
+pub use polls::vote_in_channel_poll;
+pub use reminders::cancel_channel_reminder;
+pub use reminders::list_channel_reminders;
+pub use reminders::schedule_channel_reminder;
 use serde::Deserialize;
 use serde::Serialize;
+pub use stars::get_top_messages;
+pub use stars::star_message;
+pub use stars::unstar_message;
 use thiserror::Error;
 
 use crate::domain::channel::errors::ChannelError;
 use crate::domain::channel::models::Channel;
+use crate::domain::channel::models::ChannelDirectoryEntry;
+use crate::domain::channel::models::ChannelStats;
+use crate::domain::email::errors::EmailError;
+use crate::domain::email::models::EmailDelivery;
+use crate::domain::embed::errors::EmbedError;
+use crate::domain::embed::models::EmbeddedMessage;
+use crate::domain::feed::errors::FeedError;
+use crate::domain::feed::models::Feed;
+use crate::domain::gateway::models::RegionSelection;
+use crate::domain::leader::errors::LeaderError;
 use crate::domain::message::errors::MessageError;
+use crate::domain::message::models::HistoryEntry;
 use crate::domain::message::models::Message;
+use crate::domain::message::models::MessageTombstone;
+use crate::domain::poll::errors::PollError;
+use crate::domain::poll::models::Poll;
+use crate::domain::poll::models::PollResults;
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::reminder::models::Reminder;
+use crate::domain::star::errors::StarError;
+use crate::domain::star::models::TopMessage;
 use crate::inbound::http::messages::ChannelIdMessage;
+use crate::inbound::http::messages::FeedIdMessage;
 use crate::inbound::http::messages::MessageIdMessage;
+use crate::inbound::http::messages::PollIdMessage;
+use crate::inbound::http::messages::ReminderIdMessage;
 use crate::inbound::http::messages::UserIdMessage;
 
 /// Standardized API success response
@@ -29,17 +88,35 @@ use crate::inbound::http::messages::UserIdMessage;
 pub struct ApiSuccess<T: Serialize> {
     #[serde(flatten)]
     pub data: T,
+    /// Position of a page, rendered by `/api/v2` only
+    #[serde(skip)]
+    pub pagination: Option<Pagination>,
 }
 
 impl<T: Serialize> ApiSuccess<T> {
     pub fn new(_status: StatusCode, data: T) -> Self {
-        Self { data }
+        Self {
+            data,
+            pagination: None,
+        }
+    }
+
+    /// Mark the response as a page of a list.
+    pub fn with_pagination(mut self, pagination: Pagination) -> Self {
+        self.pagination = Some(pagination);
+        self
     }
 }
 
 impl<T: Serialize> IntoResponse for ApiSuccess<T> {
     fn into_response(self) -> Response {
-        (StatusCode::OK, Json(self.data)).into_response()
+        let data = ResponseData::new(&self.data);
+        let mut response = (StatusCode::OK, Json(&data.0)).into_response();
+        response.extensions_mut().insert(data);
+        if let Some(pagination) = self.pagination {
+            response.extensions_mut().insert(pagination);
+        }
+        response
     }
 }
 
@@ -48,12 +125,28 @@ pub enum ApiError {
     #[error("Bad request: {0}")]
     BadRequest(String),
 
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    /// Forbidden for a reason clients are expected to handle, named by `code`
+    #[error("Forbidden: {message}")]
+    ForbiddenWithCode { message: String, code: &'static str },
+
     #[error("Not found: {0}")]
     NotFound(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error("Unprocessable entity: {0}")]
     UnprocessableEntity(String),
 
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+
     #[error("Internal server error: {0}")]
     InternalServerError(String),
 
@@ -65,17 +158,34 @@ impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
         let (status, message) = match &self {
             ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            ApiError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
+            ApiError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
+            ApiError::ForbiddenWithCode { message, .. } => (StatusCode::FORBIDDEN, message),
             ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg),
             ApiError::UnprocessableEntity(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg),
+            ApiError::PayloadTooLarge(msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg),
             ApiError::InternalServerError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             ApiError::ServiceUnavailable(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg),
         };
 
-        let body = Json(serde_json::json!({
-            "error": message
-        }));
+        let mut error = ResponseError::new(message.as_str());
+        let body = match &self {
+            ApiError::ForbiddenWithCode { code, .. } => {
+                error = error.with_code(*code);
+                Json(serde_json::json!({
+                    "error": message,
+                    "code": code
+                }))
+            }
+            _ => Json(serde_json::json!({
+                "error": message
+            })),
+        };
 
-        (status, body).into_response()
+        let mut response = (status, body).into_response();
+        response.extensions_mut().insert(error);
+        response
     }
 }
 
@@ -87,6 +197,10 @@ pub struct CreateChannelResponseData {
     pub description: Option<String>,
     pub created_by: UserIdMessage,
     pub created_at: DateTime<Utc>,
+    pub embeddable: bool,
+    pub discoverable: bool,
+    pub announcement_only: bool,
+    pub auto_join: bool,
 }
 
 impl From<&Channel> for CreateChannelResponseData {
@@ -102,6 +216,414 @@ impl From<&Channel> for CreateChannelResponseData {
             description: channel.description().map(|d| d.to_string()),
             created_by: channel.created_by().into(),
             created_at: channel.created_at(),
+            embeddable: channel.is_embeddable(),
+            discoverable: channel.is_discoverable(),
+            announcement_only: channel.is_announcement_only(),
+            auto_join: channel.is_auto_join(),
+        }
+    }
+}
+
+/// Channel listed in the public directory
+#[derive(Debug, Clone, Serialize)]
+pub struct ChannelDirectoryEntryData {
+    pub name: String,
+    pub description: Option<String>,
+    pub member_count: u64,
+}
+
+impl From<&ChannelDirectoryEntry> for ChannelDirectoryEntryData {
+    fn from(entry: &ChannelDirectoryEntry) -> Self {
+        Self {
+            name: entry.name.as_str().to_string(),
+            description: entry.description.clone(),
+            member_count: entry.member_count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChannelStatsData {
+    pub message_count: u64,
+    pub member_count: u64,
+    pub last_activity_at: Option<DateTime<Utc>>,
+    pub languages: Vec<LanguageCountData>,
+}
+
+/// Messages of a channel detected as one language
+#[derive(Debug, Clone, Serialize)]
+pub struct LanguageCountData {
+    pub language: String,
+    pub message_count: u64,
+}
+
+impl From<ChannelStats> for ChannelStatsData {
+    fn from(stats: ChannelStats) -> Self {
+        Self {
+            message_count: stats.message_count,
+            member_count: stats.member_count,
+            last_activity_at: stats.last_activity_at,
+            languages: stats
+                .languages
+                .into_iter()
+                .map(|count| LanguageCountData {
+                    language: count.language.as_str().to_string(),
+                    message_count: count.message_count,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GetChannelResponseData {
+    #[serde(flatten)]
+    pub channel: CreateChannelResponseData,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<ChannelStatsData>,
+}
+
+/// WebSocket endpoint selected for a client
+#[derive(Debug, Clone, Serialize)]
+pub struct GatewayResponseData {
+    pub region: String,
+    pub websocket_url: String,
+    pub reason: String,
+}
+
+impl From<RegionSelection> for GatewayResponseData {
+    fn from(selection: RegionSelection) -> Self {
+        Self {
+            region: selection.region.name,
+            websocket_url: selection.region.websocket_url,
+            reason: selection.reason.to_string(),
+        }
+    }
+}
+
+/// Request DTO of the mail provider webhook
+#[derive(Debug, Deserialize)]
+pub struct ReceiveEmailRequest {
+    /// Recipients, plain or as `Name <address>`
+    pub to: Vec<String>,
+    pub from: String,
+    #[serde(default)]
+    pub subject: Option<String>,
+    /// Plain-text body
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub attachments: Vec<EmailAttachmentRequest>,
+}
+
+/// File attached to an inbound email
+#[derive(Debug, Deserialize)]
+pub struct EmailAttachmentRequest {
+    pub filename: String,
+    #[serde(default = "default_attachment_content_type")]
+    pub content_type: String,
+    /// Base64-encoded file content
+    pub content: String,
+}
+
+fn default_attachment_content_type() -> String {
+    "application/octet-stream".to_string()
+}
+
+/// Messages posted for an inbound email
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailDeliveryResponseData {
+    pub message_ids: Vec<MessageIdMessage>,
+    pub channel_ids: Vec<ChannelIdMessage>,
+    pub attachment_keys: Vec<String>,
+}
+
+impl From<&EmailDelivery> for EmailDeliveryResponseData {
+    fn from(delivery: &EmailDelivery) -> Self {
+        Self {
+            message_ids: delivery
+                .messages
+                .iter()
+                .map(|message| message.id.into())
+                .collect(),
+            channel_ids: delivery
+                .messages
+                .iter()
+                .map(|message| message.channel_id.into())
+                .collect(),
+            attachment_keys: delivery.attachment_keys.clone(),
+        }
+    }
+}
+
+/// Address posting emails to a channel
+#[derive(Debug, Clone, Serialize)]
+pub struct ChannelEmailAddressResponseData {
+    pub channel_id: ChannelIdMessage,
+    pub address: String,
+}
+
+impl From<EmailError> for ApiError {
+    fn from(err: EmailError) -> Self {
+        match err {
+            EmailError::NoChannelAddress => ApiError::UnprocessableEntity(err.to_string()),
+            EmailError::StorageError(msg) => ApiError::InternalServerError(msg),
+            EmailError::Message(err) => err.into(),
+        }
+    }
+}
+
+/// Request DTO for registering a feed in a channel
+#[derive(Debug, Deserialize)]
+pub struct RegisterFeedRequest {
+    /// RSS or Atom feed URL
+    pub url: String,
+    /// Most entries posted per hour, the configured default if omitted
+    #[serde(default)]
+    pub max_posts_per_hour: Option<u32>,
+}
+
+/// Feed registered in a channel
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedResponseData {
+    pub id: FeedIdMessage,
+    pub channel_id: ChannelIdMessage,
+    pub url: String,
+    pub created_by: UserIdMessage,
+    pub max_posts_per_hour: u32,
+    pub last_polled_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&Feed> for FeedResponseData {
+    fn from(feed: &Feed) -> Self {
+        Self {
+            id: feed.id.into(),
+            channel_id: feed.channel_id.into(),
+            url: feed.url.to_string(),
+            created_by: feed.created_by.into(),
+            max_posts_per_hour: feed.max_posts_per_hour,
+            last_polled_at: feed.last_polled_at,
+            last_error: feed.last_error.clone(),
+            created_at: feed.created_at,
+        }
+    }
+}
+
+/// Feeds registered in a channel
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedListResponseData {
+    pub feeds: Vec<FeedResponseData>,
+}
+
+impl From<FeedError> for ApiError {
+    fn from(err: FeedError) -> Self {
+        match err {
+            FeedError::NotFound(id) => ApiError::NotFound(format!("Feed not found: {}", id)),
+            FeedError::InvalidFeedId(_) => ApiError::BadRequest(err.to_string()),
+            FeedError::AlreadyRegistered(_) => ApiError::Conflict(err.to_string()),
+            FeedError::InvalidUrl(_)
+            | FeedError::TooManyFeeds(_)
+            | FeedError::InvalidPostRate(_) => ApiError::UnprocessableEntity(err.to_string()),
+            FeedError::Message(err) => err.into(),
+            FeedError::Fetch(_) => ApiError::InternalServerError(err.to_string()),
+            FeedError::DatabaseError(msg) => ApiError::InternalServerError(msg),
+        }
+    }
+}
+
+/// Request DTO for asking a poll in a channel
+#[derive(Debug, Deserialize)]
+pub struct CreatePollRequest {
+    pub question: String,
+    /// Options voted for, numbered from 1 in the announcement
+    pub options: Vec<String>,
+    /// When voting closes and the final results are posted
+    pub closes_at: DateTime<Utc>,
+}
+
+/// Request DTO for voting in a poll
+#[derive(Debug, Deserialize)]
+pub struct VotePollRequest {
+    /// Index of the chosen option, from 0
+    pub option: usize,
+}
+
+/// Poll asked in a channel
+#[derive(Debug, Clone, Serialize)]
+pub struct PollResponseData {
+    pub id: PollIdMessage,
+    pub channel_id: ChannelIdMessage,
+    pub created_by: UserIdMessage,
+    pub question: String,
+    pub options: Vec<String>,
+    pub closes_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&Poll> for PollResponseData {
+    fn from(poll: &Poll) -> Self {
+        Self {
+            id: poll.id.into(),
+            channel_id: poll.channel_id.into(),
+            created_by: poll.created_by.into(),
+            question: poll.question.clone(),
+            options: poll.options.clone(),
+            closes_at: poll.closes_at,
+            closed_at: poll.closed_at,
+            created_at: poll.created_at,
+        }
+    }
+}
+
+/// Votes cast for one option of a poll
+#[derive(Debug, Clone, Serialize)]
+pub struct PollOptionResultData {
+    pub text: String,
+    pub votes: u64,
+}
+
+/// Poll with the votes cast for each of its options
+#[derive(Debug, Clone, Serialize)]
+pub struct PollResultsResponseData {
+    pub poll: PollResponseData,
+    pub results: Vec<PollOptionResultData>,
+    pub total_votes: u64,
+}
+
+impl From<&PollResults> for PollResultsResponseData {
+    fn from(results: &PollResults) -> Self {
+        Self {
+            poll: (&results.poll).into(),
+            results: results
+                .tallies
+                .iter()
+                .map(|tally| PollOptionResultData {
+                    text: tally.text.clone(),
+                    votes: tally.votes,
+                })
+                .collect(),
+            total_votes: results.total_votes(),
+        }
+    }
+}
+
+impl From<PollError> for ApiError {
+    fn from(err: PollError) -> Self {
+        match err {
+            PollError::NotFound(id) => ApiError::NotFound(format!("Poll not found: {}", id)),
+            PollError::InvalidPollId(_) => ApiError::BadRequest(err.to_string()),
+            PollError::AlreadyVoted(_) | PollError::Closed(_) => {
+                ApiError::Conflict(err.to_string())
+            }
+            PollError::InvalidQuestion(_)
+            | PollError::InvalidOptions(_)
+            | PollError::ClosesInPast
+            | PollError::ClosesTooLate(_)
+            | PollError::UnknownOption(_) => ApiError::UnprocessableEntity(err.to_string()),
+            PollError::Message(err) => err.into(),
+            PollError::DatabaseError(msg) => ApiError::InternalServerError(msg),
+        }
+    }
+}
+
+/// Request DTO for scheduling a reminder in a channel
+#[derive(Debug, Deserialize)]
+pub struct ScheduleReminderRequest {
+    /// Text of the reminder
+    pub content: String,
+    /// When the reminder is posted
+    pub due_at: DateTime<Utc>,
+}
+
+/// Reminder pending in a channel
+#[derive(Debug, Clone, Serialize)]
+pub struct ReminderResponseData {
+    pub id: ReminderIdMessage,
+    pub channel_id: ChannelIdMessage,
+    pub created_by: UserIdMessage,
+    pub content: String,
+    pub due_at: DateTime<Utc>,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&Reminder> for ReminderResponseData {
+    fn from(reminder: &Reminder) -> Self {
+        Self {
+            id: reminder.id.into(),
+            channel_id: reminder.channel_id.into(),
+            created_by: reminder.created_by.into(),
+            content: reminder.content.as_str().to_string(),
+            due_at: reminder.due_at,
+            attempts: reminder.attempts,
+            last_error: reminder.last_error.clone(),
+            created_at: reminder.created_at,
+        }
+    }
+}
+
+/// Reminders pending in a channel
+#[derive(Debug, Clone, Serialize)]
+pub struct ReminderListResponseData {
+    pub reminders: Vec<ReminderResponseData>,
+}
+
+impl From<ReminderError> for ApiError {
+    fn from(err: ReminderError) -> Self {
+        match err {
+            ReminderError::NotFound(id) => {
+                ApiError::NotFound(format!("Reminder not found: {}", id))
+            }
+            ReminderError::InvalidReminderId(_) => ApiError::BadRequest(err.to_string()),
+            ReminderError::DueInPast
+            | ReminderError::DueTooLate(_)
+            | ReminderError::TooManyReminders(_) => ApiError::UnprocessableEntity(err.to_string()),
+            ReminderError::Message(err) => err.into(),
+            ReminderError::DatabaseError(msg) => ApiError::InternalServerError(msg),
+        }
+    }
+}
+
+/// Star of the caller on a message
+#[derive(Debug, Clone, Serialize)]
+pub struct StarResponseData {
+    pub message_id: MessageIdMessage,
+    pub starred: bool,
+}
+
+/// Message along with the stars it received over the period
+#[derive(Debug, Clone, Serialize)]
+pub struct TopMessageData {
+    pub message: MessageResponseData,
+    pub stars: u64,
+}
+
+impl From<&TopMessage> for TopMessageData {
+    fn from(top: &TopMessage) -> Self {
+        Self {
+            message: (&top.message).into(),
+            stars: top.stars,
+        }
+    }
+}
+
+/// Most starred messages of a channel, most starred first
+#[derive(Debug, Clone, Serialize)]
+pub struct TopMessagesResponseData {
+    pub period: String,
+    pub messages: Vec<TopMessageData>,
+}
+
+impl From<StarError> for ApiError {
+    fn from(err: StarError) -> Self {
+        match err {
+            StarError::InvalidPeriod(_) => ApiError::BadRequest(err.to_string()),
+            StarError::Message(err) => err.into(),
+            StarError::DatabaseError(msg) => ApiError::InternalServerError(msg),
         }
     }
 }
@@ -113,7 +635,9 @@ impl From<ChannelError> for ApiError {
             ChannelError::NameAlreadyExists(name) => {
                 ApiError::UnprocessableEntity(format!("Channel name already exists: {}", name))
             }
-            ChannelError::InvalidChannelId(_)
+            ChannelError::IdAlreadyExists(_) => ApiError::Conflict(err.to_string()),
+            ChannelError::NotPublic(_)
+            | ChannelError::InvalidChannelId(_)
             | ChannelError::InvalidChannelName(_)
             | ChannelError::InvalidUserId(_) => ApiError::UnprocessableEntity(err.to_string()),
             ChannelError::UserServiceError(msg) => ApiError::ServiceUnavailable(msg),
@@ -138,6 +662,7 @@ pub struct MessageResponseData {
     pub user_id: UserIdMessage,
     pub content: String,
     pub timestamp: DateTime<Utc>,
+    pub language: Option<String>,
 }
 
 impl From<&Message> for MessageResponseData {
@@ -148,6 +673,78 @@ impl From<&Message> for MessageResponseData {
             user_id: message.user_id.into(),
             content: message.content.as_str().to_string(),
             timestamp: message.timestamp,
+            language: message
+                .language
+                .as_ref()
+                .map(|language| language.as_str().to_string()),
+        }
+    }
+}
+
+/// Tombstone of a deleted message, without its content
+#[derive(Debug, Clone, Serialize)]
+pub struct DeletedMessageResponseData {
+    pub id: MessageIdMessage,
+    pub channel_id: ChannelIdMessage,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: UserIdMessage,
+}
+
+impl From<&MessageTombstone> for DeletedMessageResponseData {
+    fn from(tombstone: &MessageTombstone) -> Self {
+        Self {
+            id: tombstone.id.into(),
+            channel_id: tombstone.channel_id.into(),
+            deleted_at: tombstone.deleted_at,
+            deleted_by: tombstone.deleted_by.into(),
+        }
+    }
+}
+
+/// Entry of channel history, tagged `"type": "message"` or `"type": "deleted"`
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HistoryEntryResponseData {
+    Message(MessageResponseData),
+    Deleted(DeletedMessageResponseData),
+}
+
+impl From<&HistoryEntry> for HistoryEntryResponseData {
+    fn from(entry: &HistoryEntry) -> Self {
+        match entry {
+            HistoryEntry::Message(message) => Self::Message(message.into()),
+            HistoryEntry::Deleted(tombstone) => Self::Deleted(tombstone.into()),
+        }
+    }
+}
+
+/// Message of an embedded channel, without the author's user ID
+#[derive(Debug, Clone, Serialize)]
+pub struct EmbeddedMessageResponseData {
+    pub id: MessageIdMessage,
+    pub display_name: Option<String>,
+    pub content: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl From<&EmbeddedMessage> for EmbeddedMessageResponseData {
+    fn from(message: &EmbeddedMessage) -> Self {
+        Self {
+            id: message.id.into(),
+            display_name: message.display_name.clone(),
+            content: message.content.as_str().to_string(),
+            timestamp: message.timestamp,
+        }
+    }
+}
+
+impl From<EmbedError> for ApiError {
+    fn from(err: EmbedError) -> Self {
+        match err {
+            EmbedError::ChannelNotFound(id) => {
+                ApiError::NotFound(format!("Channel not found: {}", id))
+            }
+            EmbedError::DatabaseError(msg) => ApiError::InternalServerError(msg),
         }
     }
 }
@@ -158,21 +755,53 @@ pub enum CreateChannelRequest {
     Public {
         name: String,
         description: Option<String>,
+        /// Allow reading messages without authentication through the embed API
+        #[serde(default)]
+        embeddable: bool,
+        /// List the channel in the unauthenticated public channel directory
+        #[serde(default)]
+        discoverable: bool,
+        /// Let only the creator and moderators post
+        #[serde(default)]
+        announcement_only: bool,
     },
     Private {
         name: String,
         description: Option<String>,
         members: Vec<String>, // UUID strings
+        /// Let only the creator and moderators post
+        #[serde(default)]
+        announcement_only: bool,
     },
     Direct {
         participant_id: String, // UUID string
     },
 }
 
+/// Request DTO for creating a channel, optionally under a supplied ID
+#[derive(Debug, Deserialize)]
+pub struct CreateChannelBody {
+    /// UUIDv7 or timeuuid chosen by a trusted importer or bot
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(flatten)]
+    pub channel: CreateChannelRequest,
+}
+
+/// Request DTO for marking a channel as a default channel
+#[derive(Debug, Deserialize)]
+pub struct SetChannelAutoJoinRequest {
+    /// Let new users of the workspace join the channel automatically
+    pub auto_join: bool,
+}
+
 /// Request DTO for sending a message
 #[derive(Debug, Deserialize)]
 pub struct SendMessageRequest {
     pub content: String,
+    /// Timeuuid chosen by a trusted importer or bot
+    #[serde(default)]
+    pub id: Option<String>,
 }
 
 impl From<MessageError> for ApiError {
@@ -183,6 +812,12 @@ impl From<MessageError> for ApiError {
                 ApiError::NotFound(format!("Channel not found: {}", id))
             }
             MessageError::UserNotFound(id) => ApiError::NotFound(format!("User not found: {}", id)),
+            MessageError::DeleteForbidden(_) => ApiError::Forbidden(err.to_string()),
+            MessageError::AnnouncementOnly(_) => ApiError::ForbiddenWithCode {
+                message: err.to_string(),
+                code: err.code().unwrap_or_default(),
+            },
+            MessageError::IdAlreadyExists(_) => ApiError::Conflict(err.to_string()),
             MessageError::InvalidMessageId(_)
             | MessageError::InvalidContent(_)
             | MessageError::InvalidChannelId(_)
@@ -193,3 +828,11 @@ impl From<MessageError> for ApiError {
         }
     }
 }
+
+impl From<LeaderError> for ApiError {
+    fn from(err: LeaderError) -> Self {
+        match err {
+            LeaderError::DatabaseError(msg) => ApiError::InternalServerError(msg),
+        }
+    }
+}
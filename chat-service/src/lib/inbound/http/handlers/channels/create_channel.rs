@@ -1,14 +1,15 @@
 use axum::extract::State;
 use axum::http::StatusCode;
-use axum::Extension;
 use axum::Json;
 
+use crate::domain::channel::models::ChannelId;
 use crate::domain::channel::models::ChannelName;
 use crate::domain::channel::models::CreateChannelCommand;
 use crate::domain::channel::ports::ChannelServicePort;
 use crate::domain::user::models::UserId;
 use crate::inbound::http::handlers::ApiError;
 use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::CreateChannelBody;
 use crate::inbound::http::handlers::CreateChannelRequest;
 use crate::inbound::http::handlers::CreateChannelResponseData;
 use crate::inbound::http::router::AppState;
@@ -16,23 +17,46 @@ use crate::inbound::middleware::AuthenticatedUser;
 
 pub async fn create_channel(
     State(state): State<AppState>,
-    Extension(auth_user): Extension<AuthenticatedUser>,
-    Json(req): Json<CreateChannelRequest>,
+    auth_user: AuthenticatedUser,
+    Json(body): Json<CreateChannelBody>,
 ) -> Result<ApiSuccess<CreateChannelResponseData>, ApiError> {
-    let command = match req {
-        CreateChannelRequest::Public { name, description } => {
+    let id = match body.id {
+        Some(_) if !auth_user.can_supply_ids() => {
+            return Err(ApiError::Forbidden(
+                "Only importers and bots may supply channel IDs".to_string(),
+            ))
+        }
+        Some(id) => Some(
+            ChannelId::from_supplied(&id)
+                .map_err(|e| ApiError::UnprocessableEntity(e.to_string()))?,
+        ),
+        None => None,
+    };
+
+    let command = match body.channel {
+        CreateChannelRequest::Public {
+            name,
+            description,
+            embeddable,
+            discoverable,
+            announcement_only,
+        } => {
             let channel_name =
                 ChannelName::new(name).map_err(|e| ApiError::UnprocessableEntity(e.to_string()))?;
 
             CreateChannelCommand::Public {
                 name: channel_name,
                 description,
+                embeddable,
+                discoverable,
+                announcement_only,
             }
         }
         CreateChannelRequest::Private {
             name,
             description,
             members,
+            announcement_only,
         } => {
             let channel_name =
                 ChannelName::new(name).map_err(|e| ApiError::UnprocessableEntity(e.to_string()))?;
@@ -47,6 +71,7 @@ pub async fn create_channel(
                 name: channel_name,
                 description,
                 members: member_ids,
+                announcement_only,
             }
         }
         CreateChannelRequest::Direct { participant_id } => {
@@ -58,10 +83,22 @@ pub async fn create_channel(
         }
     };
 
-    state
-        .channel_service
-        .create_channel(command, auth_user.user_id)
-        .await
+    let created = match id {
+        Some(id) => {
+            state
+                .channel_service
+                .create_channel_with_id(id, command, auth_user.user_id, auth_user.workspace_id)
+                .await
+        }
+        None => {
+            state
+                .channel_service
+                .create_channel(command, auth_user.user_id, auth_user.workspace_id)
+                .await
+        }
+    };
+
+    created
         .map_err(ApiError::from)
         .map(|ref channel| ApiSuccess::new(StatusCode::CREATED, channel.into()))
 }
@@ -1,25 +1,66 @@
 use axum::extract::Path;
+use axum::extract::Query;
 use axum::extract::State;
 use axum::http::StatusCode;
+use serde::Deserialize;
 
+use crate::domain::channel::errors::ChannelError;
 use crate::domain::channel::models::ChannelId;
 use crate::domain::channel::ports::ChannelServicePort;
 use crate::inbound::http::handlers::ApiError;
 use crate::inbound::http::handlers::ApiSuccess;
-use crate::inbound::http::handlers::CreateChannelResponseData;
+use crate::inbound::http::handlers::GetChannelResponseData;
 use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::Viewer;
+
+#[derive(Debug, Deserialize)]
+pub struct GetChannelQuery {
+    include: Option<String>, // Comma-separated, e.g. "stats"
+}
+
+impl GetChannelQuery {
+    fn includes(&self, field: &str) -> bool {
+        self.include
+            .as_deref()
+            .is_some_and(|include| include.split(',').any(|f| f.trim() == field))
+    }
+}
 
 pub async fn get_channel(
     State(state): State<AppState>,
+    viewer: Viewer,
     Path(channel_id): Path<String>,
-) -> Result<ApiSuccess<CreateChannelResponseData>, ApiError> {
+    Query(params): Query<GetChannelQuery>,
+) -> Result<ApiSuccess<GetChannelResponseData>, ApiError> {
     let channel_id =
         ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
 
-    state
+    let channel = state
         .channel_service
         .get_channel(channel_id)
         .await
-        .map_err(ApiError::from)
-        .map(|ref channel| ApiSuccess::new(StatusCode::OK, channel.into()))
+        .map_err(ApiError::from)?;
+    // Channels hidden from the viewer are indistinguishable from missing ones
+    if !viewer.can_read(&channel) {
+        return Err(ChannelError::NotFound(channel_id).into());
+    }
+
+    let stats = if params.includes("stats") {
+        let stats = state
+            .channel_service
+            .get_channel_stats(&channel)
+            .await
+            .map_err(ApiError::from)?;
+        Some(stats.into())
+    } else {
+        None
+    };
+
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        GetChannelResponseData {
+            channel: (&channel).into(),
+            stats,
+        },
+    ))
 }
@@ -0,0 +1,76 @@
+use axum::extract::State;
+use axum::http::header::CACHE_CONTROL;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::header::ETAG;
+use axum::http::header::IF_NONE_MATCH;
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::domain::channel::ports::ChannelServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ChannelDirectoryEntryData;
+use crate::inbound::http::router::AppState;
+
+/// Seconds browsers and shared caches may serve the directory without revalidating.
+const DIRECTORY_MAX_AGE_SECS: u64 = 300;
+
+/// Unauthenticated directory of channels opted into public discovery.
+///
+/// Responses carry an ETag derived from the body and are cacheable by
+/// shared caches; a matching `If-None-Match` gets `304 Not Modified`.
+pub async fn list_channel_directory(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let entries = state.channel_service.list_channel_directory().await?;
+    let entry_data: Vec<ChannelDirectoryEntryData> = entries.iter().map(|e| e.into()).collect();
+    let body = serde_json::to_vec(&entry_data)
+        .map_err(|e| ApiError::InternalServerError(e.to_string()))?;
+
+    let etag = format!("\"{}\"", body_digest(&body));
+    let cache_control = format!(
+        "public, max-age={}, stale-while-revalidate={}",
+        DIRECTORY_MAX_AGE_SECS,
+        DIRECTORY_MAX_AGE_SECS * 2
+    );
+
+    let not_modified = headers
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .map(|tag| tag.trim().trim_start_matches("W/"))
+                .any(|tag| tag == etag || tag == "*")
+        });
+    if not_modified {
+        return Ok((
+            StatusCode::NOT_MODIFIED,
+            [(ETAG, etag), (CACHE_CONTROL, cache_control)],
+        )
+            .into_response());
+    }
+
+    Ok((
+        StatusCode::OK,
+        [
+            (ETAG, etag),
+            (CACHE_CONTROL, cache_control),
+            (CONTENT_TYPE, "application/json".to_string()),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// SHA-256 of the response body, hex encoded.
+fn body_digest(body: &[u8]) -> String {
+    Sha256::digest(body)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
@@ -0,0 +1,26 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use crate::domain::channel::ports::ChannelServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::CreateChannelResponseData;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// List the channels the caller created or joined, default channels included.
+pub async fn list_user_channels(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<ApiSuccess<Vec<CreateChannelResponseData>>, ApiError> {
+    state
+        .channel_service
+        .list_user_channels(auth_user.user_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|channels| {
+            let channel_data: Vec<CreateChannelResponseData> =
+                channels.iter().map(|c| c.into()).collect();
+            ApiSuccess::new(StatusCode::OK, channel_data)
+        })
+}
@@ -1,7 +1,13 @@
 pub mod create_channel;
 pub mod get_channel;
+pub mod list_channel_directory;
 pub mod list_public_channels;
+pub mod list_user_channels;
+pub mod set_channel_auto_join;
 
 pub use create_channel::create_channel;
 pub use get_channel::get_channel;
+pub use list_channel_directory::list_channel_directory;
 pub use list_public_channels::list_public_channels;
+pub use list_user_channels::list_user_channels;
+pub use set_channel_auto_join::set_channel_auto_join;
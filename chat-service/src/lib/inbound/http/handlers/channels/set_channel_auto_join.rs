@@ -0,0 +1,40 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::channel::ports::ChannelServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::CreateChannelResponseData;
+use crate::inbound::http::handlers::SetChannelAutoJoinRequest;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Mark a public channel as a default channel, or unmark it (admins only).
+///
+/// Users created afterwards are added to default channels as their
+/// `user_created` event is consumed.
+pub async fn set_channel_auto_join(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(channel_id): Path<String>,
+    Json(body): Json<SetChannelAutoJoinRequest>,
+) -> Result<ApiSuccess<CreateChannelResponseData>, ApiError> {
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Only admins may manage default channels".to_string(),
+        ));
+    }
+
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    state
+        .channel_service
+        .set_auto_join(channel_id, body.auto_join)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref channel| ApiSuccess::new(StatusCode::OK, channel.into()))
+}
@@ -0,0 +1,49 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::channel::ports::ChannelServicePort;
+use crate::domain::email::ports::EmailGatewayServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::ChannelEmailAddressResponseData;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Email address posting to a channel (channel owner and admins only).
+///
+/// Anyone knowing the address may post to the channel, so it is handed out
+/// only to those who may decide to share it.
+pub async fn get_channel_email_address(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(channel_id): Path<String>,
+) -> Result<ApiSuccess<ChannelEmailAddressResponseData>, ApiError> {
+    let email_gateway = state
+        .email_gateway
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Email gateway is not configured".to_string()))?;
+
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let channel = state
+        .channel_service
+        .get_channel(channel_id)
+        .await
+        .map_err(ApiError::from)?;
+    if channel.created_by() != auth_user.user_id && !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Only the channel owner and admins may see its email address".to_string(),
+        ));
+    }
+
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        ChannelEmailAddressResponseData {
+            channel_id: channel_id.into(),
+            address: email_gateway.service.channel_address(channel_id),
+        },
+    ))
+}
@@ -0,0 +1,5 @@
+pub mod get_channel_email_address;
+pub mod receive_email;
+
+pub use get_channel_email_address::get_channel_email_address;
+pub use receive_email::receive_email;
@@ -0,0 +1,85 @@
+use axum::extract::State;
+use axum::http::header::AUTHORIZATION;
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
+use axum::Json;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::domain::email::models::EmailAttachment;
+use crate::domain::email::models::InboundEmail;
+use crate::domain::email::ports::EmailGatewayServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::EmailDeliveryResponseData;
+use crate::inbound::http::handlers::ReceiveEmailRequest;
+use crate::inbound::http::router::AppState;
+
+/// Webhook of the mail provider, posting an inbound email to the channels it
+/// is addressed to.
+///
+/// Authenticated with the configured webhook secret as bearer token. Emails
+/// addressed to no valid channel address are answered with `422`, which
+/// providers report to the sender as a bounce.
+pub async fn receive_email(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<ReceiveEmailRequest>,
+) -> Result<ApiSuccess<EmailDeliveryResponseData>, ApiError> {
+    let email_gateway = state
+        .email_gateway
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Email gateway is not configured".to_string()))?;
+
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| ApiError::Unauthorized("Missing webhook token".to_string()))?;
+    // Digests have a fixed length, so comparing them leaks nothing of the secret
+    if Sha256::digest(token.as_bytes()) != Sha256::digest(email_gateway.webhook_secret.as_bytes()) {
+        return Err(ApiError::Unauthorized("Invalid webhook token".to_string()));
+    }
+
+    let attachments = body
+        .attachments
+        .into_iter()
+        .map(|attachment| {
+            let data = STANDARD.decode(&attachment.content).map_err(|e| {
+                ApiError::BadRequest(format!(
+                    "Attachment {} is not valid base64: {}",
+                    attachment.filename, e
+                ))
+            })?;
+            Ok(EmailAttachment {
+                filename: attachment.filename,
+                content_type: attachment.content_type,
+                data,
+            })
+        })
+        .collect::<Result<Vec<_>, ApiError>>()?;
+
+    let email = InboundEmail {
+        recipients: body.to,
+        from: body.from,
+        subject: body.subject,
+        text: body.text,
+        attachments,
+    };
+
+    let delivery = email_gateway
+        .service
+        .receive(email)
+        .await
+        .map_err(ApiError::from)?;
+
+    tracing::info!(
+        "Email posted to {} channels with {} stored attachments",
+        delivery.messages.len(),
+        delivery.attachment_keys.len()
+    );
+
+    Ok(ApiSuccess::new(StatusCode::OK, (&delivery).into()))
+}
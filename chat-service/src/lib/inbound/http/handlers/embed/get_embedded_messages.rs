@@ -0,0 +1,44 @@
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Deserialize;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::embed::ports::EmbedServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::EmbeddedMessageResponseData;
+use crate::inbound::http::router::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct EmbedMessageQuery {
+    limit: Option<i32>,
+    before: Option<String>, // ISO 8601 timestamp
+}
+
+pub async fn get_embedded_messages(
+    State(state): State<AppState>,
+    Path(channel_id): Path<String>,
+    Query(params): Query<EmbedMessageQuery>,
+) -> Result<ApiSuccess<Vec<EmbeddedMessageResponseData>>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let limit = params.limit.unwrap_or(50);
+    let before = params
+        .before
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+        .map(|dt| dt.with_timezone(&chrono::Utc));
+
+    state
+        .embed_service
+        .get_channel_messages(channel_id, limit, before)
+        .await
+        .map_err(ApiError::from)
+        .map(|messages| {
+            let message_data: Vec<EmbeddedMessageResponseData> =
+                messages.iter().map(|m| m.into()).collect();
+            ApiSuccess::new(StatusCode::OK, message_data)
+        })
+}
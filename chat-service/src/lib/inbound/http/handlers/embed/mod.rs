@@ -0,0 +1,3 @@
+pub mod get_embedded_messages;
+
+pub use get_embedded_messages::get_embedded_messages;
@@ -0,0 +1,36 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::feed_service_for_owner;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::feed::ports::FeedServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::FeedListResponseData;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// List the feeds registered in a channel (channel owner and admins only).
+pub async fn list_channel_feeds(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(channel_id): Path<String>,
+) -> Result<ApiSuccess<FeedListResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let feed_service = feed_service_for_owner(&state, &auth_user, channel_id).await?;
+
+    feed_service
+        .list_feeds(channel_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|feeds| {
+            ApiSuccess::new(
+                StatusCode::OK,
+                FeedListResponseData {
+                    feeds: feeds.iter().map(Into::into).collect(),
+                },
+            )
+        })
+}
@@ -0,0 +1,45 @@
+pub mod list_channel_feeds;
+pub mod register_channel_feed;
+pub mod remove_channel_feed;
+
+pub use list_channel_feeds::list_channel_feeds;
+pub use register_channel_feed::register_channel_feed;
+pub use remove_channel_feed::remove_channel_feed;
+
+use std::sync::Arc;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::channel::ports::ChannelServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::router::AppFeedService;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Feed service of a channel the caller owns or administers.
+///
+/// # Errors
+/// * `NotFound` - Feeds are not configured, or the channel does not exist
+/// * `Forbidden` - Caller is neither the channel owner nor an admin
+async fn feed_service_for_owner(
+    state: &AppState,
+    auth_user: &AuthenticatedUser,
+    channel_id: ChannelId,
+) -> Result<Arc<AppFeedService>, ApiError> {
+    let feed_service = state
+        .feed_service
+        .clone()
+        .ok_or_else(|| ApiError::NotFound("Feeds are not configured".to_string()))?;
+
+    let channel = state
+        .channel_service
+        .get_channel(channel_id)
+        .await
+        .map_err(ApiError::from)?;
+    if channel.created_by() != auth_user.user_id && !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Only the channel owner and admins may manage its feeds".to_string(),
+        ));
+    }
+
+    Ok(feed_service)
+}
@@ -0,0 +1,43 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+
+use super::feed_service_for_owner;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::feed::models::FeedUrl;
+use crate::domain::feed::models::RegisterFeedCommand;
+use crate::domain::feed::ports::FeedServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::FeedResponseData;
+use crate::inbound::http::handlers::RegisterFeedRequest;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Register an RSS or Atom feed in a channel (channel owner and admins only).
+///
+/// Entries published after the first poll of the feed are posted to the
+/// channel by the feed bot, up to the hourly cap of the feed.
+pub async fn register_channel_feed(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(channel_id): Path<String>,
+    Json(body): Json<RegisterFeedRequest>,
+) -> Result<ApiSuccess<FeedResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let url = FeedUrl::new(body.url).map_err(|e| ApiError::UnprocessableEntity(e.to_string()))?;
+    let feed_service = feed_service_for_owner(&state, &auth_user, channel_id).await?;
+
+    feed_service
+        .register_feed(RegisterFeedCommand {
+            channel_id,
+            url,
+            created_by: auth_user.user_id,
+            max_posts_per_hour: body.max_posts_per_hour,
+        })
+        .await
+        .map_err(ApiError::from)
+        .map(|ref feed| ApiSuccess::new(StatusCode::CREATED, feed.into()))
+}
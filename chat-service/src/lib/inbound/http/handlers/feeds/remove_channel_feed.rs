@@ -0,0 +1,31 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::feed_service_for_owner;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::feed::models::FeedId;
+use crate::domain::feed::ports::FeedServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::FeedResponseData;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Remove a feed from a channel (channel owner and admins only).
+pub async fn remove_channel_feed(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path((channel_id, feed_id)): Path<(String, String)>,
+) -> Result<ApiSuccess<FeedResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let feed_id = FeedId::from_string(&feed_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let feed_service = feed_service_for_owner(&state, &auth_user, channel_id).await?;
+
+    feed_service
+        .remove_feed(channel_id, feed_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref feed| ApiSuccess::new(StatusCode::OK, feed.into()))
+}
@@ -0,0 +1,72 @@
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
+use serde::Deserialize;
+
+use crate::domain::gateway::models::GatewayHint;
+use crate::domain::gateway::ports::GatewayServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::GatewayResponseData;
+use crate::inbound::http::router::AppState;
+
+/// Country headers set by common CDNs and load balancers, in order of preference.
+const GEOIP_COUNTRY_HEADERS: [&str; 3] = [
+    "cf-ipcountry",
+    "cloudfront-viewer-country",
+    "x-country-code",
+];
+
+#[derive(Debug, Deserialize)]
+pub struct GetGatewayQuery {
+    region: Option<String>, // Preferred region name
+    rtt: Option<String>,    // Comma-separated `region:milliseconds`, e.g. "eu-west:35,us-east:120"
+}
+
+impl GetGatewayQuery {
+    fn measured_rtt(&self) -> Vec<(String, u32)> {
+        self.rtt
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|entry| {
+                let (region, rtt) = entry.trim().split_once(':')?;
+                Some((region.to_string(), rtt.parse().ok()?))
+            })
+            .collect()
+    }
+}
+
+pub async fn get_gateway(
+    State(state): State<AppState>,
+    Query(params): Query<GetGatewayQuery>,
+    headers: HeaderMap,
+) -> Result<ApiSuccess<GatewayResponseData>, ApiError> {
+    let gateway_service = state
+        .gateway_service
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Gateway is not configured".to_string()))?;
+
+    let country = GEOIP_COUNTRY_HEADERS
+        .iter()
+        .filter_map(|name| headers.get(*name)?.to_str().ok())
+        // Cloudflare reports unknown countries as XX
+        .find(|country| !country.is_empty() && *country != "XX")
+        .map(str::to_string);
+
+    let hint = GatewayHint {
+        measured_rtt: params.measured_rtt(),
+        preferred_region: params.region.clone(),
+        country,
+    };
+
+    let selection = gateway_service.select_region(&hint);
+    tracing::debug!(
+        region = %selection.region.name,
+        reason = %selection.reason,
+        "Gateway region selected"
+    );
+
+    Ok(ApiSuccess::new(StatusCode::OK, selection.into()))
+}
@@ -0,0 +1,3 @@
+pub mod get_gateway;
+
+pub use get_gateway::get_gateway;
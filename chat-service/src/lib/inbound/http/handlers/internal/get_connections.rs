@@ -0,0 +1,16 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::router::AppState;
+use crate::inbound::websocket::registry::RegistrySaturation;
+
+pub async fn get_connections(
+    State(state): State<AppState>,
+) -> Result<ApiSuccess<RegistrySaturation>, ApiError> {
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        state.connection_registry.saturation().await,
+    ))
+}
@@ -0,0 +1,38 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Serialize;
+
+use crate::domain::leader::models::LeaderLease;
+use crate::domain::lock::models::InstanceId;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::router::AppState;
+
+/// Report which instance leads the coordinator election.
+///
+/// Any instance answers: `leader` is the lease recorded by the current
+/// leader, null while the leadership is vacant, e.g. during a failover.
+pub async fn get_leader(State(state): State<AppState>) -> Result<ApiSuccess<LeaderData>, ApiError> {
+    let election = &state.leader_election;
+    let leader = election.leader().await?;
+
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        LeaderData {
+            election: election.name().to_string(),
+            instance_id: election.instance_id(),
+            is_leader: election.is_leader(),
+            leader,
+        },
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LeaderData {
+    pub election: String,
+    /// Instance answering the request
+    pub instance_id: InstanceId,
+    /// Whether the answering instance leads
+    pub is_leader: bool,
+    pub leader: Option<LeaderLease>,
+}
@@ -0,0 +1,13 @@
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+
+use crate::inbound::http::router::AppState;
+
+/// Metrics in the Prometheus text exposition format.
+pub async fn get_metrics(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(),
+    )
+}
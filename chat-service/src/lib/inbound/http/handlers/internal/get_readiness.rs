@@ -0,0 +1,37 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Serialize;
+
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::router::AppState;
+use crate::supervisor::TaskHealth;
+
+/// Report whether the instance is ready for traffic.
+///
+/// Answers `503` while a background task is down, e.g. a Kafka consumer
+/// waiting for its restart, since messages would not reach WebSockets.
+pub async fn get_readiness(
+    State(state): State<AppState>,
+) -> Result<ApiSuccess<ReadinessData>, ApiError> {
+    let ready = state.supervisor.is_healthy();
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    Ok(ApiSuccess::new(
+        status,
+        ReadinessData {
+            ready,
+            tasks: state.supervisor.health(),
+        },
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReadinessData {
+    pub ready: bool,
+    pub tasks: Vec<TaskHealth>,
+}
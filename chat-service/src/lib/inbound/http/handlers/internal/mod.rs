@@ -0,0 +1,11 @@
+pub mod get_connections;
+pub mod get_leader;
+pub mod get_metrics;
+pub mod get_readiness;
+pub mod get_version;
+
+pub use get_connections::get_connections;
+pub use get_leader::get_leader;
+pub use get_metrics::get_metrics;
+pub use get_readiness::get_readiness;
+pub use get_version::get_version;
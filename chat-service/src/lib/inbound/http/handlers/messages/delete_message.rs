@@ -0,0 +1,36 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageId;
+use crate::domain::message::ports::MessageServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::HistoryEntryResponseData;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Delete a message of the caller, leaving a tombstone in channel history.
+pub async fn delete_message(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path((channel_id, message_id)): Path<(String, String)>,
+) -> Result<ApiSuccess<HistoryEntryResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let message_id =
+        MessageId::from_string(&message_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    state
+        .message_service
+        .delete_message(channel_id, message_id, auth_user.user_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref tombstone| {
+            ApiSuccess::new(
+                StatusCode::OK,
+                HistoryEntryResponseData::Deleted(tombstone.into()),
+            )
+        })
+}
@@ -2,14 +2,18 @@ use axum::extract::Path;
 use axum::extract::Query;
 use axum::extract::State;
 use axum::http::StatusCode;
+use envelope::Pagination;
 use serde::Deserialize;
 
+use crate::domain::channel::errors::ChannelError;
 use crate::domain::channel::models::ChannelId;
+use crate::domain::channel::ports::ChannelServicePort;
 use crate::domain::message::ports::MessageServicePort;
 use crate::inbound::http::handlers::ApiError;
 use crate::inbound::http::handlers::ApiSuccess;
-use crate::inbound::http::handlers::MessageResponseData;
+use crate::inbound::http::handlers::HistoryEntryResponseData;
 use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::Viewer;
 
 #[derive(Debug, Deserialize)]
 pub struct MessageQuery {
@@ -19,12 +23,24 @@ pub struct MessageQuery {
 
 pub async fn get_channel_messages(
     State(state): State<AppState>,
+    viewer: Viewer,
     Path(channel_id): Path<String>,
     Query(params): Query<MessageQuery>,
-) -> Result<ApiSuccess<Vec<MessageResponseData>>, ApiError> {
+) -> Result<ApiSuccess<Vec<HistoryEntryResponseData>>, ApiError> {
     let channel_id =
         ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
 
+    if let Viewer::Guest = viewer {
+        let channel = state
+            .channel_service
+            .get_channel(channel_id)
+            .await
+            .map_err(ApiError::from)?;
+        if !viewer.can_read(&channel) {
+            return Err(ChannelError::NotFound(channel_id).into());
+        }
+    }
+
     let limit = params.limit.unwrap_or(50);
     let before = params
         .before
@@ -36,9 +52,18 @@ pub async fn get_channel_messages(
         .get_channel_messages(channel_id, limit, before)
         .await
         .map_err(ApiError::from)
-        .map(|messages| {
-            let message_data: Vec<MessageResponseData> =
-                messages.iter().map(|m| m.into()).collect();
-            ApiSuccess::new(StatusCode::OK, message_data)
+        .map(|entries| {
+            // A full page may have older entries; they are requested with `before`
+            let next_cursor = entries
+                .last()
+                .filter(|_| entries.len() >= usize::try_from(limit).unwrap_or_default())
+                .and_then(|oldest| oldest.id().timestamp())
+                .map(|timestamp| timestamp.to_rfc3339());
+            let entry_data: Vec<HistoryEntryResponseData> =
+                entries.iter().map(|e| e.into()).collect();
+            ApiSuccess::new(StatusCode::OK, entry_data).with_pagination(Pagination {
+                limit: u32::try_from(limit).unwrap_or_default(),
+                next_cursor,
+            })
         })
 }
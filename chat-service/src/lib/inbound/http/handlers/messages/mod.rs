@@ -1,3 +1,7 @@
+pub mod delete_message;
 pub mod get_channel_messages;
+pub mod send_message;
 
+pub use delete_message::delete_message;
 pub use get_channel_messages::get_channel_messages;
+pub use send_message::send_message;
@@ -0,0 +1,56 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageContent;
+use crate::domain::message::models::MessageId;
+use crate::domain::message::ports::MessageServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::MessageResponseData;
+use crate::inbound::http::handlers::SendMessageRequest;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Send a message over HTTP, for bots and importers without a WebSocket.
+///
+/// Importers and bots may supply the message ID to keep stable references
+/// to the message in their own systems.
+pub async fn send_message(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(channel_id): Path<String>,
+    Json(req): Json<SendMessageRequest>,
+) -> Result<ApiSuccess<MessageResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let content = MessageContent::new(req.content)
+        .map_err(|e| ApiError::UnprocessableEntity(e.to_string()))?;
+
+    let sent = match req.id {
+        Some(_) if !auth_user.can_supply_ids() => {
+            return Err(ApiError::Forbidden(
+                "Only importers and bots may supply message IDs".to_string(),
+            ))
+        }
+        Some(id) => {
+            let message_id = MessageId::from_supplied(&id)
+                .map_err(|e| ApiError::UnprocessableEntity(e.to_string()))?;
+            state
+                .message_service
+                .send_message_with_id(channel_id, message_id, auth_user.sender(), content)
+                .await
+        }
+        None => {
+            state
+                .message_service
+                .send_message(channel_id, auth_user.sender(), content)
+                .await
+        }
+    };
+
+    sent.map_err(ApiError::from)
+        .map(|ref message| ApiSuccess::new(StatusCode::CREATED, message.into()))
+}
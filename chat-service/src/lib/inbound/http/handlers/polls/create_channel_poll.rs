@@ -0,0 +1,42 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+
+use super::poll_service_for_channel;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::poll::models::CreatePollCommand;
+use crate::domain::poll::ports::PollServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::CreatePollRequest;
+use crate::inbound::http::handlers::PollResponseData;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Ask a poll in a channel, announced by a message of its author.
+///
+/// The announcement is an ordinary message, so only users who may post in
+/// the channel may ask polls in it.
+pub async fn create_channel_poll(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(channel_id): Path<String>,
+    Json(body): Json<CreatePollRequest>,
+) -> Result<ApiSuccess<PollResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let poll_service = poll_service_for_channel(&state, channel_id).await?;
+
+    poll_service
+        .create_poll(CreatePollCommand {
+            channel_id,
+            sender: auth_user.sender(),
+            question: body.question,
+            options: body.options,
+            closes_at: body.closes_at,
+        })
+        .await
+        .map_err(ApiError::from)
+        .map(|ref poll| ApiSuccess::new(StatusCode::CREATED, poll.into()))
+}
@@ -0,0 +1,29 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::poll_service_for_channel;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::poll::models::PollId;
+use crate::domain::poll::ports::PollServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::PollResultsResponseData;
+use crate::inbound::http::router::AppState;
+
+/// Get a poll with the votes cast so far, or its final results once closed.
+pub async fn get_channel_poll(
+    State(state): State<AppState>,
+    Path((channel_id, poll_id)): Path<(String, String)>,
+) -> Result<ApiSuccess<PollResultsResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let poll_id = PollId::from_string(&poll_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let poll_service = poll_service_for_channel(&state, channel_id).await?;
+
+    poll_service
+        .get_results(channel_id, poll_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref results| ApiSuccess::new(StatusCode::OK, results.into()))
+}
@@ -0,0 +1,37 @@
+pub mod create_channel_poll;
+pub mod get_channel_poll;
+pub mod vote_in_channel_poll;
+
+pub use create_channel_poll::create_channel_poll;
+pub use get_channel_poll::get_channel_poll;
+pub use vote_in_channel_poll::vote_in_channel_poll;
+
+use std::sync::Arc;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::channel::ports::ChannelServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::router::AppPollService;
+use crate::inbound::http::router::AppState;
+
+/// Poll service, once the channel the polls belong to is known to exist.
+///
+/// # Errors
+/// * `NotFound` - Polls are not configured, or the channel does not exist
+async fn poll_service_for_channel(
+    state: &AppState,
+    channel_id: ChannelId,
+) -> Result<Arc<AppPollService>, ApiError> {
+    let poll_service = state
+        .poll_service
+        .clone()
+        .ok_or_else(|| ApiError::NotFound("Polls are not configured".to_string()))?;
+
+    state
+        .channel_service
+        .get_channel(channel_id)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(poll_service)
+}
@@ -0,0 +1,37 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+
+use super::poll_service_for_channel;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::poll::models::PollId;
+use crate::domain::poll::ports::PollServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::PollResultsResponseData;
+use crate::inbound::http::handlers::VotePollRequest;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Vote for an option of an open poll.
+///
+/// Each user votes once per poll; the new results are pushed to the
+/// channel's WebSocket connections as a `poll_updated` message.
+pub async fn vote_in_channel_poll(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path((channel_id, poll_id)): Path<(String, String)>,
+    Json(body): Json<VotePollRequest>,
+) -> Result<ApiSuccess<PollResultsResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let poll_id = PollId::from_string(&poll_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let poll_service = poll_service_for_channel(&state, channel_id).await?;
+
+    poll_service
+        .vote(channel_id, poll_id, auth_user.user_id, body.option)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref results| ApiSuccess::new(StatusCode::OK, results.into()))
+}
@@ -0,0 +1,38 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::reminder_service_for_channel;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::reminder::models::ReminderId;
+use crate::domain::reminder::ports::ReminderServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::ReminderResponseData;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Cancel a pending reminder.
+///
+/// Users may cancel the reminders they scheduled; the channel owner and
+/// admins may cancel any.
+pub async fn cancel_channel_reminder(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path((channel_id, reminder_id)): Path<(String, String)>,
+) -> Result<ApiSuccess<ReminderResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let reminder_id =
+        ReminderId::from_string(&reminder_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let (reminder_service, channel) = reminder_service_for_channel(&state, channel_id).await?;
+
+    let manages_channel = channel.created_by() == auth_user.user_id || auth_user.is_admin();
+    let created_by = (!manages_channel).then_some(auth_user.user_id);
+
+    reminder_service
+        .cancel_reminder(channel_id, reminder_id, created_by)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref reminder| ApiSuccess::new(StatusCode::OK, reminder.into()))
+}
@@ -0,0 +1,34 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::reminder_service_for_channel;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::reminder::ports::ReminderServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::ReminderListResponseData;
+use crate::inbound::http::router::AppState;
+
+/// List the pending reminders of a channel, soonest first.
+pub async fn list_channel_reminders(
+    State(state): State<AppState>,
+    Path(channel_id): Path<String>,
+) -> Result<ApiSuccess<ReminderListResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let (reminder_service, _) = reminder_service_for_channel(&state, channel_id).await?;
+
+    reminder_service
+        .list_reminders(channel_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|reminders| {
+            ApiSuccess::new(
+                StatusCode::OK,
+                ReminderListResponseData {
+                    reminders: reminders.iter().map(Into::into).collect(),
+                },
+            )
+        })
+}
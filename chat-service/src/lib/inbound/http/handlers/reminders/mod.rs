@@ -0,0 +1,38 @@
+pub mod cancel_channel_reminder;
+pub mod list_channel_reminders;
+pub mod schedule_channel_reminder;
+
+pub use cancel_channel_reminder::cancel_channel_reminder;
+pub use list_channel_reminders::list_channel_reminders;
+pub use schedule_channel_reminder::schedule_channel_reminder;
+
+use std::sync::Arc;
+
+use crate::domain::channel::models::Channel;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::channel::ports::ChannelServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::router::AppReminderService;
+use crate::inbound::http::router::AppState;
+
+/// Reminder service, along with the channel the reminders belong to.
+///
+/// # Errors
+/// * `NotFound` - Reminders are not configured, or the channel does not exist
+async fn reminder_service_for_channel(
+    state: &AppState,
+    channel_id: ChannelId,
+) -> Result<(Arc<AppReminderService>, Channel), ApiError> {
+    let reminder_service = state
+        .reminder_service
+        .clone()
+        .ok_or_else(|| ApiError::NotFound("Reminders are not configured".to_string()))?;
+
+    let channel = state
+        .channel_service
+        .get_channel(channel_id)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok((reminder_service, channel))
+}
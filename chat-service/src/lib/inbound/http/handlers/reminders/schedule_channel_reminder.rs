@@ -0,0 +1,49 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+
+use super::reminder_service_for_channel;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageContent;
+use crate::domain::reminder::models::ScheduleReminderCommand;
+use crate::domain::reminder::ports::ReminderServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::ReminderResponseData;
+use crate::inbound::http::handlers::ScheduleReminderRequest;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Schedule a reminder, posted to the channel by the reminder bot when due.
+///
+/// Only users who may post in the channel may schedule reminders in it.
+pub async fn schedule_channel_reminder(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(channel_id): Path<String>,
+    Json(body): Json<ScheduleReminderRequest>,
+) -> Result<ApiSuccess<ReminderResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let content = MessageContent::new(body.content)
+        .map_err(|e| ApiError::UnprocessableEntity(e.to_string()))?;
+    let (reminder_service, channel) = reminder_service_for_channel(&state, channel_id).await?;
+    if !channel.may_post(&auth_user.sender()) {
+        return Err(ApiError::Forbidden(
+            "Only the owner and moderators may schedule reminders in an announcement channel"
+                .to_string(),
+        ));
+    }
+
+    reminder_service
+        .schedule_reminder(ScheduleReminderCommand {
+            channel_id,
+            created_by: auth_user.user_id,
+            content,
+            due_at: body.due_at,
+        })
+        .await
+        .map_err(ApiError::from)
+        .map(|ref reminder| ApiSuccess::new(StatusCode::CREATED, reminder.into()))
+}
@@ -0,0 +1,63 @@
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Deserialize;
+
+use super::star_service_for_owner;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::star::models::StarPeriod;
+use crate::domain::star::ports::StarServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::TopMessagesResponseData;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Messages listed when the query does not name a limit
+const DEFAULT_LIMIT: u32 = 10;
+/// Most messages a client may ask for
+const MAX_LIMIT: u32 = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct TopMessagesQuery {
+    /// `day`, `week` (default), `month` or `all`
+    period: Option<String>,
+    limit: Option<u32>,
+}
+
+/// List the most starred messages of a channel (channel owner and admins only).
+///
+/// Stars are counted from the rollup of star changes, so a star given
+/// moments ago may not be counted yet.
+pub async fn get_top_messages(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(channel_id): Path<String>,
+    Query(params): Query<TopMessagesQuery>,
+) -> Result<ApiSuccess<TopMessagesResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let period = params
+        .period
+        .as_deref()
+        .map(str::parse::<StarPeriod>)
+        .transpose()?
+        .unwrap_or_default();
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let star_service = star_service_for_owner(&state, &auth_user, channel_id).await?;
+
+    star_service
+        .top_messages(channel_id, period, limit)
+        .await
+        .map_err(ApiError::from)
+        .map(|messages| {
+            ApiSuccess::new(
+                StatusCode::OK,
+                TopMessagesResponseData {
+                    period: period.as_str().to_string(),
+                    messages: messages.iter().map(Into::into).collect(),
+                },
+            )
+        })
+}
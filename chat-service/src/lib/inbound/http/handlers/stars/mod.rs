@@ -0,0 +1,59 @@
+pub mod get_top_messages;
+pub mod star_message;
+pub mod unstar_message;
+
+pub use get_top_messages::get_top_messages;
+pub use star_message::star_message;
+pub use unstar_message::unstar_message;
+
+use std::sync::Arc;
+
+use crate::domain::channel::models::Channel;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::channel::ports::ChannelServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::router::AppStarService;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Star service, with the channel the starred messages belong to.
+///
+/// # Errors
+/// * `NotFound` - Stars are not configured, or the channel does not exist
+async fn star_service_for_channel(
+    state: &AppState,
+    channel_id: ChannelId,
+) -> Result<(Arc<AppStarService>, Channel), ApiError> {
+    let star_service = state
+        .star_service
+        .clone()
+        .ok_or_else(|| ApiError::NotFound("Stars are not configured".to_string()))?;
+
+    let channel = state
+        .channel_service
+        .get_channel(channel_id)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok((star_service, channel))
+}
+
+/// Star service, once the caller is known to own the channel or be an admin.
+///
+/// # Errors
+/// * `NotFound` - Stars are not configured, or the channel does not exist
+/// * `Forbidden` - Caller is neither the channel owner nor an admin
+async fn star_service_for_owner(
+    state: &AppState,
+    auth_user: &AuthenticatedUser,
+    channel_id: ChannelId,
+) -> Result<Arc<AppStarService>, ApiError> {
+    let (star_service, channel) = star_service_for_channel(state, channel_id).await?;
+    if channel.created_by() != auth_user.user_id && !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Only the channel owner and admins may see its top messages".to_string(),
+        ));
+    }
+
+    Ok(star_service)
+}
@@ -0,0 +1,39 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::star_service_for_channel;
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageId;
+use crate::domain::star::ports::StarServicePort;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::handlers::StarResponseData;
+use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::AuthenticatedUser;
+
+/// Remove the caller's star from a message, if they starred it.
+pub async fn unstar_message(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path((channel_id, message_id)): Path<(String, String)>,
+) -> Result<ApiSuccess<StarResponseData>, ApiError> {
+    let channel_id =
+        ChannelId::from_string(&channel_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let message_id =
+        MessageId::from_string(&message_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let (star_service, _) = star_service_for_channel(&state, channel_id).await?;
+
+    star_service
+        .unstar_message(channel_id, message_id, auth_user.user_id)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        StarResponseData {
+            message_id: message_id.into(),
+            starred: false,
+        },
+    ))
+}
@@ -9,8 +9,11 @@ use uuid::Uuid;
 use crate::domain::channel::errors::ChannelIdError;
 use crate::domain::channel::models::ChannelId;
 use crate::domain::channel::models::ChannelType;
+use crate::domain::feed::models::FeedId;
 use crate::domain::message::errors::MessageIdError;
 use crate::domain::message::models::MessageId;
+use crate::domain::poll::models::PollId;
+use crate::domain::reminder::models::ReminderId;
 use crate::domain::user::errors::UserIdError;
 use crate::domain::user::models::UserId;
 
@@ -54,6 +57,17 @@ impl ChannelIdMessage {
     }
 }
 
+/// Serializable wrapper for FeedId.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FeedIdMessage(pub Uuid);
+
+impl From<FeedId> for FeedIdMessage {
+    fn from(id: FeedId) -> Self {
+        Self(id.0)
+    }
+}
+
 /// Serializable wrapper for MessageId.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -94,6 +108,28 @@ impl MessageIdMessage {
     }
 }
 
+/// Serializable wrapper for PollId.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PollIdMessage(pub Uuid);
+
+impl From<PollId> for PollIdMessage {
+    fn from(id: PollId) -> Self {
+        Self(id.0)
+    }
+}
+
+/// Serializable wrapper for ReminderId.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReminderIdMessage(pub Uuid);
+
+impl From<ReminderId> for ReminderIdMessage {
+    fn from(id: ReminderId) -> Self {
+        Self(id.0)
+    }
+}
+
 /// Serializable wrapper for UserId.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
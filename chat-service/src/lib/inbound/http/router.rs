@@ -1,85 +1,371 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use auth::axum::AuthLayer;
 use auth::Authenticator;
+use auth::PersonalToken;
+use auth::PersonalTokenVerifier;
 use axum::body::Body;
+use axum::extract::DefaultBodyLimit;
 use axum::http::Request;
 use axum::http::Response;
 use axum::middleware;
+use axum::routing::delete;
 use axum::routing::get;
 use axum::routing::post;
+use axum::routing::put;
+use axum::routing::MethodRouter;
 use axum::Router;
+use buildinfo::BuildInfo;
+use envelope::versioned;
+use envelope::Deprecation;
+use metrics_exporter_prometheus::PrometheusHandle;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use tracing::Span;
 
+use super::handlers::cancel_channel_reminder;
 use super::handlers::create_channel;
+use super::handlers::create_channel_poll;
+use super::handlers::delete_message;
 use super::handlers::get_channel;
+use super::handlers::get_channel_email_address;
 use super::handlers::get_channel_messages;
+use super::handlers::get_channel_poll;
+use super::handlers::get_connections;
+use super::handlers::get_embedded_messages;
+use super::handlers::get_gateway;
+use super::handlers::get_leader;
+use super::handlers::get_metrics;
+use super::handlers::get_readiness;
+use super::handlers::get_top_messages;
+use super::handlers::get_version;
+use super::handlers::list_channel_directory;
+use super::handlers::list_channel_feeds;
+use super::handlers::list_channel_reminders;
 use super::handlers::list_public_channels;
+use super::handlers::list_user_channels;
+use super::handlers::receive_email;
+use super::handlers::register_channel_feed;
+use super::handlers::remove_channel_feed;
+use super::handlers::schedule_channel_reminder;
+use super::handlers::send_message;
+use super::handlers::set_channel_auto_join;
+use super::handlers::star_message;
+use super::handlers::unstar_message;
+use super::handlers::vote_in_channel_poll;
 use crate::domain::channel::service::ChannelService;
+use crate::domain::email::service::EmailGatewayService;
+use crate::domain::embed::service::EmbedService;
+use crate::domain::feed::service::FeedService;
+use crate::domain::gateway::service::GatewayService;
+use crate::domain::leader::service::LeaderElection;
 use crate::domain::message::service::MessageService;
-use crate::inbound::middleware as auth_middleware;
+use crate::domain::poll::service::PollService;
+use crate::domain::reminder::service::ReminderService;
+use crate::domain::star::service::StarService;
+use crate::inbound::middleware::assign_request_id;
+use crate::inbound::middleware::limit_body_size;
+use crate::inbound::middleware::limit_embed_requests;
+use crate::inbound::middleware::reject_writes_when_read_only;
+use crate::inbound::middleware::require_scope;
+use crate::inbound::panic::catch_panic_layer;
+use crate::inbound::rate_limit::RateLimiter;
 use crate::inbound::websocket::handler::websocket_handler;
 use crate::inbound::websocket::registry::ConnectionRegistry;
+use crate::outbound::backup::FilesystemObjectStorage;
+use crate::outbound::events::channel_publisher::KafkaChannelEventPublisher;
 use crate::outbound::events::message_publisher::KafkaMessageEventPublisher;
+use crate::outbound::events::poll_publisher::KafkaPollEventPublisher;
+use crate::outbound::events::star_publisher::KafkaStarEventPublisher;
+use crate::outbound::feed::HttpFeedFetcher;
 use crate::outbound::grpc::user::GrpcUserServiceClient;
+use crate::outbound::language::stopwords::StopwordLanguageDetector;
+use crate::outbound::lock::postgres::PostgresAdvisoryLock;
 use crate::outbound::repositories::channel::PostgresChannelRepository;
+use crate::outbound::repositories::feed::PostgresFeedRepository;
+use crate::outbound::repositories::leader_lease::PostgresLeaderLeaseRepository;
 use crate::outbound::repositories::message::CassandraMessageRepository;
+use crate::outbound::repositories::poll::PostgresPollRepository;
+use crate::outbound::repositories::reminder::PostgresReminderRepository;
+use crate::outbound::repositories::star::PostgresStarRepository;
+use crate::outbound::repositories::user_replica::PostgresUserReplicaRepository;
+use crate::supervisor::TaskSupervisor;
+
+pub type AppMessageService = MessageService<
+    CassandraMessageRepository,
+    PostgresChannelRepository,
+    GrpcUserServiceClient,
+    KafkaMessageEventPublisher,
+    StopwordLanguageDetector,
+>;
+
+pub type AppEmbedService = EmbedService<
+    PostgresChannelRepository,
+    CassandraMessageRepository,
+    PostgresUserReplicaRepository,
+>;
+
+pub type AppEmailGatewayService = EmailGatewayService<AppMessageService, FilesystemObjectStorage>;
+
+/// Inbound email gateway, set when `[email_gateway]` is configured.
+#[derive(Clone)]
+pub struct EmailGateway {
+    pub service: Arc<AppEmailGatewayService>,
+    /// Bearer token of the mail provider webhook
+    pub webhook_secret: String,
+    /// Largest webhook request accepted, attachments being base64-encoded
+    pub body_limit: usize,
+}
+
+/// Feed bot posting RSS and Atom entries to channels
+pub type AppFeedService = FeedService<PostgresFeedRepository, HttpFeedFetcher, AppMessageService>;
+
+/// Reminder bot posting scheduled reminders to channels
+pub type AppReminderService = ReminderService<PostgresReminderRepository, AppMessageService>;
+
+/// Polls asked in channels, closed by the poll bot
+pub type AppPollService =
+    PollService<PostgresPollRepository, KafkaPollEventPublisher, AppMessageService>;
+
+/// Message stars, ranked into top messages by the star rollup
+pub type AppStarService =
+    StarService<PostgresStarRepository, KafkaStarEventPublisher, CassandraMessageRepository>;
+
+/// Election of the instance running coordinator tasks, over PostgreSQL advisory locks
+pub type AppLeaderElection = LeaderElection<PostgresAdvisoryLock, PostgresLeaderLeaseRepository>;
 
 /// Unified application state for both HTTP and WebSocket handlers.
 ///
 /// Contains all service dependencies needed across the application.
 #[derive(Clone)]
 pub struct AppState {
-    pub channel_service: Arc<ChannelService<PostgresChannelRepository>>,
-    pub message_service: Arc<
-        MessageService<
-            CassandraMessageRepository,
-            PostgresChannelRepository,
-            GrpcUserServiceClient,
-            KafkaMessageEventPublisher,
-        >,
-    >,
+    pub channel_service: Arc<ChannelService<PostgresChannelRepository, KafkaChannelEventPublisher>>,
+    pub message_service: Arc<AppMessageService>,
+    pub embed_service: Arc<AppEmbedService>,
+    /// Per-client limit of the unauthenticated `/embed` routes
+    pub embed_rate_limiter: Arc<RateLimiter>,
     pub connection_registry: Arc<ConnectionRegistry>,
     pub authenticator: Arc<Authenticator>,
+    pub build_info: Arc<BuildInfo>,
+    /// Renders `/metrics`
+    pub metrics: PrometheusHandle,
+    /// Region selection for `GET /api/gateway`, only set in multi-region deployments
+    pub gateway_service: Option<Arc<GatewayService>>,
+    /// Email gateway for `POST /email/inbound`, only set when configured
+    pub email_gateway: Option<EmailGateway>,
+    /// Feeds of `/channels/:channel_id/feeds`, only set when `[feeds]` is configured
+    pub feed_service: Option<Arc<AppFeedService>>,
+    /// Reminders of `/channels/:channel_id/reminders`, only set when `[reminders]` is configured
+    pub reminder_service: Option<Arc<AppReminderService>>,
+    /// Polls of `/channels/:channel_id/polls`, only set when `[polls]` is configured
+    pub poll_service: Option<Arc<AppPollService>>,
+    /// Stars and top messages of channels, only set when `[stars]` is configured
+    pub star_service: Option<Arc<AppStarService>>,
+    /// Reject writes (see [`crate::config::ServerConfig::read_only`])
+    pub read_only: bool,
+    /// Take the client IP from `X-Forwarded-For`
+    pub trust_forwarded_for: bool,
+    /// Owner of the background tasks, reported by `/readyz`
+    pub supervisor: Arc<TaskSupervisor>,
+    /// Coordinator election, reported by `/internal/leader`
+    pub leader_election: Arc<AppLeaderElection>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_router(
-    channel_service: Arc<ChannelService<PostgresChannelRepository>>,
-    message_service: Arc<
-        MessageService<
-            CassandraMessageRepository,
-            PostgresChannelRepository,
-            GrpcUserServiceClient,
-            KafkaMessageEventPublisher,
-        >,
-    >,
+    channel_service: Arc<ChannelService<PostgresChannelRepository, KafkaChannelEventPublisher>>,
+    message_service: Arc<AppMessageService>,
+    embed_service: Arc<AppEmbedService>,
+    embed_rate_limiter: Arc<RateLimiter>,
     connection_registry: Arc<ConnectionRegistry>,
     authenticator: Arc<Authenticator>,
+    personal_token_verifier: Option<Arc<dyn PersonalTokenVerifier>>,
+    build_info: Arc<BuildInfo>,
+    metrics: PrometheusHandle,
+    gateway_service: Option<Arc<GatewayService>>,
+    email_gateway: Option<EmailGateway>,
+    feed_service: Option<Arc<AppFeedService>>,
+    reminder_service: Option<Arc<AppReminderService>>,
+    poll_service: Option<Arc<AppPollService>>,
+    star_service: Option<Arc<AppStarService>>,
+    supervisor: Arc<TaskSupervisor>,
+    leader_election: Arc<AppLeaderElection>,
+    read_only: bool,
+    trust_forwarded_for: bool,
+    json_body_limit: usize,
+    api_deprecation: Deprecation,
 ) -> Router {
     let state = AppState {
         channel_service,
         message_service,
+        embed_service,
+        embed_rate_limiter,
         connection_registry,
         authenticator,
+        build_info,
+        metrics,
+        gateway_service,
+        email_gateway,
+        feed_service,
+        reminder_service,
+        poll_service,
+        star_service,
+        read_only,
+        trust_forwarded_for,
+        supervisor,
+        leader_election,
     };
 
-    let api_routes = Router::new()
-        .route("/api/channels", post(create_channel))
-        .route("/api/channels/public", get(list_public_channels))
-        .route("/api/channels/:channel_id", get(get_channel))
+    // Personal access tokens are verified by user-service when configured
+    let mut auth_layer = AuthLayer::new(state.authenticator.clone());
+    if let Some(verifier) = personal_token_verifier {
+        auth_layer = auth_layer.with_personal_tokens(verifier);
+    }
+
+    // Served under every API version, see `envelope::versioned`
+    let mut api_routes = Router::new()
+        .route(
+            "/channels",
+            scoped(get(list_user_channels), PersonalToken::CHANNELS_READ)
+                .merge(scoped(post(create_channel), PersonalToken::CHANNELS_WRITE)),
+        )
+        .route(
+            "/channels/public",
+            scoped(get(list_public_channels), PersonalToken::CHANNELS_READ),
+        )
+        .route(
+            "/channels/:channel_id",
+            scoped(get(get_channel), PersonalToken::CHANNELS_READ),
+        )
+        .route(
+            "/channels/:channel_id/auto-join",
+            scoped(put(set_channel_auto_join), PersonalToken::CHANNELS_WRITE),
+        )
+        .route(
+            "/channels/:channel_id/messages",
+            scoped(get(get_channel_messages), PersonalToken::MESSAGES_READ)
+                .merge(scoped(post(send_message), PersonalToken::MESSAGES_WRITE)),
+        )
+        .route(
+            "/channels/:channel_id/messages/:message_id",
+            scoped(delete(delete_message), PersonalToken::MESSAGES_WRITE),
+        );
+    if state.gateway_service.is_some() {
+        api_routes = api_routes.route(
+            "/gateway",
+            scoped(get(get_gateway), PersonalToken::MESSAGES_READ),
+        );
+    }
+    if state.email_gateway.is_some() {
+        api_routes = api_routes.route(
+            "/channels/:channel_id/email-address",
+            scoped(get(get_channel_email_address), PersonalToken::CHANNELS_READ),
+        );
+    }
+    if state.feed_service.is_some() {
+        api_routes = api_routes
+            .route(
+                "/channels/:channel_id/feeds",
+                scoped(get(list_channel_feeds), PersonalToken::CHANNELS_READ).merge(scoped(
+                    post(register_channel_feed),
+                    PersonalToken::CHANNELS_WRITE,
+                )),
+            )
+            .route(
+                "/channels/:channel_id/feeds/:feed_id",
+                scoped(delete(remove_channel_feed), PersonalToken::CHANNELS_WRITE),
+            );
+    }
+    if state.reminder_service.is_some() {
+        api_routes = api_routes
+            .route(
+                "/channels/:channel_id/reminders",
+                scoped(get(list_channel_reminders), PersonalToken::MESSAGES_READ).merge(scoped(
+                    post(schedule_channel_reminder),
+                    PersonalToken::MESSAGES_WRITE,
+                )),
+            )
+            .route(
+                "/channels/:channel_id/reminders/:reminder_id",
+                scoped(
+                    delete(cancel_channel_reminder),
+                    PersonalToken::MESSAGES_WRITE,
+                ),
+            );
+    }
+    if state.poll_service.is_some() {
+        api_routes = api_routes
+            .route(
+                "/channels/:channel_id/polls",
+                scoped(post(create_channel_poll), PersonalToken::MESSAGES_WRITE),
+            )
+            .route(
+                "/channels/:channel_id/polls/:poll_id",
+                scoped(get(get_channel_poll), PersonalToken::MESSAGES_READ),
+            )
+            .route(
+                "/channels/:channel_id/polls/:poll_id/votes",
+                scoped(post(vote_in_channel_poll), PersonalToken::MESSAGES_WRITE),
+            );
+    }
+    if state.star_service.is_some() {
+        api_routes = api_routes
+            .route(
+                "/channels/:channel_id/messages/:message_id/star",
+                scoped(put(star_message), PersonalToken::MESSAGES_WRITE).merge(scoped(
+                    delete(unstar_message),
+                    PersonalToken::MESSAGES_WRITE,
+                )),
+            )
+            .route(
+                "/channels/:channel_id/top-messages",
+                scoped(get(get_top_messages), PersonalToken::MESSAGES_READ),
+            );
+    }
+    let api_routes = api_routes
+        .route_layer(middleware::from_fn_with_state(
+            state.read_only,
+            reject_writes_when_read_only,
+        ))
+        .route_layer(auth_layer)
+        .route_layer(DefaultBodyLimit::max(json_body_limit))
+        .route_layer(middleware::from_fn_with_state(
+            json_body_limit,
+            limit_body_size,
+        ));
+
+    let public_routes = Router::new().route("/public/channels", get(list_channel_directory));
+    // Caught inside the envelope so v2 clients get their usual error shape
+    let api_routes = api_routes.merge(public_routes).layer(catch_panic_layer());
+
+    let embed_routes = Router::new()
         .route(
-            "/api/channels/:channel_id/messages",
-            get(get_channel_messages),
+            "/embed/channels/:channel_id/messages",
+            get(get_embedded_messages),
         )
         .route_layer(middleware::from_fn_with_state(
-            state.authenticator.clone(),
-            auth_middleware::authenticate,
+            state.clone(),
+            limit_embed_requests,
         ));
 
+    // Authenticated by the webhook secret of the mail provider, not user tokens
+    let mut email_routes = Router::new();
+    if let Some(email_gateway) = &state.email_gateway {
+        email_routes = email_routes
+            .route("/email/inbound", post(receive_email))
+            .route_layer(DefaultBodyLimit::max(email_gateway.body_limit));
+    }
+
     let ws_routes = Router::new().route("/ws/channels/:channel_id", get(websocket_handler));
 
+    let internal_routes = Router::new()
+        .route("/internal/version", get(get_version))
+        .route("/internal/connections", get(get_connections))
+        .route("/internal/leader", get(get_leader))
+        .route("/metrics", get(get_metrics))
+        .route("/readyz", get(get_readiness));
+
     let trace_layer = TraceLayer::new_for_http()
         .make_span_with(|request: &Request<Body>| {
             tracing::info_span!(
@@ -108,9 +394,19 @@ pub fn create_router(
         );
 
     Router::new()
-        .merge(api_routes)
+        .merge(versioned(api_routes, api_deprecation))
+        .merge(embed_routes)
+        .merge(email_routes)
         .merge(ws_routes)
+        .merge(internal_routes)
+        .layer(catch_panic_layer())
+        .layer(middleware::from_fn(assign_request_id))
         .layer(trace_layer)
         .layer(CorsLayer::permissive())
         .with_state(state)
 }
+
+/// Restrict a route to personal access tokens granted `scope`.
+fn scoped(route: MethodRouter<AppState>, scope: &'static str) -> MethodRouter<AppState> {
+    route.route_layer(middleware::from_fn_with_state(scope, require_scope))
+}
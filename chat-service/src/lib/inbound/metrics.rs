@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use metrics_exporter_prometheus::BuildError;
+use metrics_exporter_prometheus::Matcher;
+use metrics_exporter_prometheus::PrometheusBuilder;
+use metrics_exporter_prometheus::PrometheusHandle;
+
+/// WebSocket connections closed, by close code.
+pub const WEBSOCKET_CLOSES_TOTAL: &str = "websocket_closes_total";
+/// Lifetime of WebSocket connections, from upgrade request to close, by close code.
+pub const WEBSOCKET_CONNECTION_DURATION_SECONDS: &str = "websocket_connection_duration_seconds";
+
+/// Histogram buckets of connection lifetimes, from 1 s to a day.
+const CONNECTION_DURATION_BUCKETS: &[f64] =
+    &[1.0, 5.0, 15.0, 60.0, 300.0, 900.0, 3600.0, 14400.0, 86400.0];
+
+/// Install the process-wide Prometheus recorder.
+///
+/// Must be called once, before any metric is recorded.
+///
+/// # Returns
+/// Handle rendering the metrics for `/metrics`
+///
+/// # Errors
+/// Returns error if a recorder is already installed
+pub fn install_recorder() -> Result<PrometheusHandle, BuildError> {
+    PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full(WEBSOCKET_CONNECTION_DURATION_SECONDS.to_string()),
+            CONNECTION_DURATION_BUCKETS,
+        )?
+        .install_recorder()
+}
+
+/// Count a closed WebSocket connection and record how long it lived.
+///
+/// # Arguments
+/// * `close_code` - Close code sent or received, or the one reported for a dropped connection
+/// * `duration` - Time since the upgrade request
+pub fn record_websocket_close(close_code: u16, duration: Duration) {
+    let code = close_code_label(close_code);
+    metrics::counter!(WEBSOCKET_CLOSES_TOTAL, "code" => code.clone()).increment(1);
+    metrics::histogram!(WEBSOCKET_CONNECTION_DURATION_SECONDS, "code" => code)
+        .record(duration.as_secs_f64());
+}
+
+/// Label of a close code, `other` outside the codes RFC 6455 defines.
+///
+/// Clients choose their close codes, so arbitrary codes would create a
+/// series each.
+fn close_code_label(close_code: u16) -> String {
+    if (1000..=1015).contains(&close_code) {
+        close_code.to_string()
+    } else {
+        "other".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_close_code_label_keeps_standard_codes_only() {
+        assert_eq!(close_code_label(1000), "1000");
+        assert_eq!(close_code_label(1006), "1006");
+        assert_eq!(close_code_label(1013), "1013");
+        assert_eq!(close_code_label(4001), "other");
+    }
+}
@@ -1,103 +1,356 @@
-use std::sync::Arc;
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::SocketAddr;
 
+use auth::axum::AuthRejection;
+use auth::Claims;
+use axum::async_trait;
+use axum::extract::ConnectInfo;
+use axum::extract::FromRequestParts;
 use axum::extract::Request;
 use axum::extract::State;
+use axum::http::header::RETRY_AFTER;
+use axum::http::request::Parts;
+use axum::http::HeaderValue;
 use axum::http::StatusCode;
 use axum::http::{self};
 use axum::middleware::Next;
 use axum::response::IntoResponse;
 use axum::response::Response;
 use axum::Json;
+use envelope::client_request_id;
+use envelope::forwarded_client_ip;
+use envelope::ResponseError;
+use envelope::REQUEST_ID_HEADER;
 use serde_json::json;
+use uuid::Uuid;
 
+use crate::domain::channel::models::Channel;
+use crate::domain::channel::models::WorkspaceId;
+use crate::domain::message::models::Sender;
 use crate::domain::user::models::UserId;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::router::AppState;
 
-/// Extension type to store authenticated user ID in request extensions
+/// Error message returned for writes while the service is read-only.
+pub const READ_ONLY_ERROR: &str = "Service is in read-only mode";
+
+/// Error message returned for embed requests over the rate limit.
+pub const RATE_LIMITED_ERROR: &str = "Too many requests";
+
+/// Roles allowed to supply their own message and channel IDs.
+pub const TRUSTED_ID_ROLES: &[&str] = &["importer", "bot"];
+
+/// Role allowed to post in announcement-only channels of others.
+pub const MODERATOR_ROLE: &str = "moderator";
+
+/// Role allowed to manage the default channels of a workspace.
+pub const ADMIN_ROLE: &str = "admin";
+
+/// Token claim naming the workspace of the caller.
+pub const WORKSPACE_ID_CLAIM: &str = "workspace_id";
+
+/// Authenticated caller of a route behind [`auth::axum::AuthLayer`]
+///
+/// Rejects tokens of anonymous guests with `403 Forbidden`.
 #[derive(Debug, Clone)]
 pub struct AuthenticatedUser {
     pub user_id: UserId,
     pub username: String,
+    pub workspace_id: WorkspaceId,
+    pub roles: Vec<String>,
 }
 
-fn extract_token_from_header(req: &Request) -> Result<&str, Response> {
-    let auth_header = req
-        .headers()
-        .get(http::header::AUTHORIZATION)
-        .ok_or_else(|| {
-            (
-                StatusCode::UNAUTHORIZED,
-                Json(json!({
-                    "error": "Missing Authorization header"
-                })),
-            )
-                .into_response()
+impl AuthenticatedUser {
+    /// Check if the caller may supply its own message and channel IDs.
+    ///
+    /// # Returns
+    /// True for importers and bots, see [`TRUSTED_ID_ROLES`]
+    pub fn can_supply_ids(&self) -> bool {
+        self.roles
+            .iter()
+            .any(|role| TRUSTED_ID_ROLES.contains(&role.as_str()))
+    }
+
+    /// Check if the caller is an administrator.
+    ///
+    /// # Returns
+    /// True if the caller has the [`ADMIN_ROLE`]
+    pub fn is_admin(&self) -> bool {
+        self.roles.iter().any(|role| role == ADMIN_ROLE)
+    }
+
+    /// Describe the caller as the sender of a message.
+    ///
+    /// # Returns
+    /// Sender with moderator rights if the caller has the [`MODERATOR_ROLE`]
+    pub fn sender(&self) -> Sender {
+        Sender {
+            user_id: self.user_id,
+            moderator: self.roles.iter().any(|role| role == MODERATOR_ROLE),
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AuthenticatedUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        if claims.is_guest() {
+            return Err(AuthRejection::GuestNotAllowed);
+        }
+
+        let user_id_str = claims.sub.as_ref().ok_or_else(|| {
+            tracing::error!("Missing 'sub' claim in token");
+            AuthRejection::InvalidClaims
         })?;
 
-    let auth_str = auth_header.to_str().map_err(|_| {
-        (
-            StatusCode::UNAUTHORIZED,
-            Json(json!({
-                "error": "Invalid Authorization header"
-            })),
-        )
-            .into_response()
-    })?;
+        let user_id = UserId::from_string(user_id_str).map_err(|e| {
+            tracing::error!("Failed to parse user ID from token: {}", e);
+            AuthRejection::InvalidClaims
+        })?;
+
+        let username = claims.username().unwrap_or_else(|| "unknown".to_string());
+
+        // Tokens without a workspace belong to the default workspace
+        let workspace_id = match claims.claim::<String>(WORKSPACE_ID_CLAIM) {
+            Some(workspace_id) => WorkspaceId::from_string(&workspace_id).map_err(|e| {
+                tracing::error!("Failed to parse workspace ID from token: {}", e);
+                AuthRejection::InvalidClaims
+            })?,
+            None if claims.has_claim(WORKSPACE_ID_CLAIM) => {
+                tracing::error!("Non-string 'workspace_id' claim in token");
+                return Err(AuthRejection::InvalidClaims);
+            }
+            None => WorkspaceId::default(),
+        };
+
+        Ok(AuthenticatedUser {
+            user_id,
+            username,
+            workspace_id,
+            roles: claims.roles.clone(),
+        })
+    }
+}
+
+/// Caller of a read route behind [`auth::axum::AuthLayer`]
+///
+/// Unlike [`AuthenticatedUser`], accepts anonymous guests, who may only read
+/// public channels.
+#[derive(Debug, Clone)]
+pub enum Viewer {
+    User(AuthenticatedUser),
+    Guest,
+}
+
+impl Viewer {
+    /// Check if the viewer may read a channel.
+    ///
+    /// # Arguments
+    /// * `channel` - Channel to read
+    ///
+    /// # Returns
+    /// True for users, and for guests if the channel is public
+    pub fn can_read(&self, channel: &Channel) -> bool {
+        match self {
+            Viewer::User(_) => true,
+            Viewer::Guest => matches!(channel, Channel::Public(_)),
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Viewer
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        if claims.is_guest() {
+            return Ok(Viewer::Guest);
+        }
+
+        AuthenticatedUser::from_request_parts(parts, state)
+            .await
+            .map(Viewer::User)
+    }
+}
+
+/// Middleware rejecting state-changing requests while the service is read-only.
+///
+/// Safe methods (GET, HEAD, OPTIONS) pass through; everything else gets
+/// `503 Service Unavailable` so clients retry against the primary later.
+pub async fn reject_writes_when_read_only(
+    State(read_only): State<bool>,
+    req: Request,
+    next: Next,
+) -> Result<Response, Response> {
+    let is_safe = matches!(
+        *req.method(),
+        http::Method::GET | http::Method::HEAD | http::Method::OPTIONS
+    );
 
-    if !auth_str.starts_with("Bearer ") {
+    if read_only && !is_safe {
+        tracing::debug!(method = %req.method(), uri = %req.uri(), "Write rejected in read-only mode");
         return Err((
-            StatusCode::UNAUTHORIZED,
-            Json(json!({
-                "error": "Invalid Authorization header format. Expected: Bearer <token>"
-            })),
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({"error": READ_ONLY_ERROR})),
         )
             .into_response());
     }
 
-    Ok(auth_str.trim_start_matches("Bearer "))
+    Ok(next.run(req).await)
+}
+
+/// Middleware rejecting personal access tokens without `scope` with `403 Forbidden`.
+///
+/// Session tokens are not scoped and always pass, see [`Claims::allows_scope`].
+pub async fn require_scope(
+    State(scope): State<&'static str>,
+    claims: Claims,
+    req: Request,
+    next: Next,
+) -> Result<Response, AuthRejection> {
+    if !claims.allows_scope(scope) {
+        tracing::debug!(scope, uri = %req.uri(), "Personal access token lacks scope");
+        return Err(AuthRejection::InsufficientScope(scope.to_string()));
+    }
+
+    Ok(next.run(req).await)
+}
+
+/// Middleware rejecting request bodies over `limit` bytes with `413 Payload Too Large`.
+///
+/// A declared `Content-Length` over the limit is rejected before the handler
+/// runs. Other bodies are cut off while streaming by the
+/// [`axum::extract::DefaultBodyLimit`] installed next to this middleware, whose
+/// plain text rejection is replaced by a structured [`ApiError`].
+pub async fn limit_body_size(State(limit): State<usize>, req: Request, next: Next) -> Response {
+    let declared_length = req
+        .headers()
+        .get(http::header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if declared_length.is_some_and(|length| length > limit) {
+        return payload_too_large(limit);
+    }
+
+    let response = next.run(req).await;
+    if response.status() == StatusCode::PAYLOAD_TOO_LARGE
+        && response.extensions().get::<ResponseError>().is_none()
+    {
+        return payload_too_large(limit);
+    }
+    response
+}
+
+fn payload_too_large(limit: usize) -> Response {
+    ApiError::PayloadTooLarge(format!("Request body exceeds the limit of {} bytes", limit))
+        .into_response()
+}
+
+/// Network address of the client, if known
+///
+/// Taken from the right-most `X-Forwarded-For` entry, written by the proxy,
+/// when `server.trust_forwarded_for` is set, otherwise from the peer address
+/// of the connection.
+#[derive(Debug, Clone, Copy)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+#[async_trait]
+impl FromRequestParts<AppState> for ClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let forwarded = state
+            .trust_forwarded_for
+            .then(|| forwarded_client_ip(&parts.headers))
+            .flatten();
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(address)| address.ip());
+
+        Ok(ClientIp(forwarded.or(peer)))
+    }
 }
 
-/// Middleware to validate JWT tokens for protected routes
-pub async fn authenticate(
-    State(authenticator): State<Arc<auth::Authenticator>>,
-    mut req: Request,
+/// Middleware limiting unauthenticated embed requests per client IP.
+///
+/// Requests over the limit get `429 Too Many Requests` with `Retry-After`.
+/// Clients without a known address share one budget.
+pub async fn limit_embed_requests(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    req: Request,
     next: Next,
 ) -> Result<Response, Response> {
-    let token = extract_token_from_header(&req)?;
-
-    // Validate token and extract claims
-    let claims: auth::Claims = authenticator.validate_token(token).map_err(|e| {
-        tracing::warn!("JWT validation failed: {}", e);
-        (
-            StatusCode::UNAUTHORIZED,
-            Json(json!({"error": "Invalid or expired token"})),
-        )
-            .into_response()
-    })?;
-
-    // Extract user ID from claims
-    let user_id_str = claims.sub.as_ref().ok_or_else(|| {
-        tracing::error!("Missing 'sub' claim in token");
-        (
-            StatusCode::UNAUTHORIZED,
-            Json(json!({"error": "Invalid token format"})),
-        )
-            .into_response()
-    })?;
-
-    let user_id = UserId::from_string(user_id_str).map_err(|e| {
-        tracing::error!("Failed to parse user ID from token: {}", e);
-        (
-            StatusCode::UNAUTHORIZED,
-            Json(json!({"error": "Invalid token format"})),
+    let client = client_ip.unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+
+    if let Err(retry_after) = state.embed_rate_limiter.check(client) {
+        tracing::debug!(client = %client, uri = %req.uri(), "Embed request rate limited");
+        return Err((
+            StatusCode::TOO_MANY_REQUESTS,
+            [(RETRY_AFTER, retry_after.as_secs().max(1).to_string())],
+            Json(json!({"error": RATE_LIMITED_ERROR})),
         )
-            .into_response()
-    })?;
+            .into_response());
+    }
 
-    let username = claims.username().unwrap_or_else(|| "unknown".to_string());
+    Ok(next.run(req).await)
+}
 
-    // Add authenticated user info to request extensions
-    req.extensions_mut()
-        .insert(AuthenticatedUser { user_id, username });
+tokio::task_local! {
+    /// ID of the request the current task is serving
+    static REQUEST_ID: String;
+}
 
-    Ok(next.run(req).await)
+/// Middleware giving every request an ID.
+///
+/// The ID is taken from `X-Request-Id` when the client sent a usable one and
+/// generated otherwise. It is set on the request, so the API envelope reuses
+/// it, echoed in the response and available through [`current_request_id`]
+/// while the request is served.
+pub async fn assign_request_id(mut req: Request, next: Next) -> Response {
+    let request_id = client_request_id(req.headers())
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let header = HeaderValue::from_str(&request_id).ok();
+    if let Some(value) = &header {
+        req.headers_mut().insert(REQUEST_ID_HEADER, value.clone());
+    }
+
+    let mut response = with_request_id(request_id, next.run(req)).await;
+    if let Some(value) = header {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+/// Run `future` as part of serving the request `request_id`.
+pub async fn with_request_id<F: Future>(request_id: String, future: F) -> F::Output {
+    REQUEST_ID.scope(request_id, future).await
+}
+
+/// Get the ID of the request the current task is serving.
+///
+/// # Returns
+/// Request ID, None outside of requests, e.g. in background tasks
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
 }
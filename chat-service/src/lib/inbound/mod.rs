@@ -1,3 +1,6 @@
 pub mod http;
+pub mod metrics;
 pub mod middleware;
+pub mod panic;
+pub mod rate_limit;
 pub mod websocket;
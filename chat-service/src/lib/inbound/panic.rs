@@ -0,0 +1,129 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::Json;
+use serde_json::json;
+use tower_http::catch_panic::CatchPanicLayer;
+
+use crate::domain::crash::models::Crash;
+use crate::domain::crash::ports::CrashReporter;
+use crate::inbound::middleware::current_request_id;
+
+/// Error message returned for requests whose handler panicked.
+pub const INTERNAL_ERROR: &str = "Internal server error";
+
+/// Error code returned for requests whose handler panicked.
+pub const INTERNAL_ERROR_CODE: &str = "internal_error";
+
+/// Builds the response of a request whose handler panicked
+pub type PanicResponder = fn(Box<dyn Any + Send + 'static>) -> Response;
+
+/// Install the panic hook of the process.
+///
+/// Every panic, in handlers, WebSocket connections and background tasks
+/// alike, is logged as a structured event naming the request it broke and,
+/// when crash reporting is configured, sent to `reporter`.
+///
+/// # Arguments
+/// * `reporter` - Where crashes are reported, None to only log them
+pub fn install_panic_hook(reporter: Option<Arc<dyn CrashReporter>>) {
+    std::panic::set_hook(Box::new(move |info| {
+        let crash = Crash::new(
+            info.payload(),
+            info.location().map(ToString::to_string),
+            current_request_id(),
+        );
+        tracing::error!(
+            crash_id = %crash.id,
+            request_id = crash.request_id.as_deref(),
+            location = crash.location.as_deref(),
+            message = %crash.message,
+            "Panic"
+        );
+
+        // Panics outside the runtime, e.g. during startup, are only logged
+        let Some(reporter) = reporter.clone() else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        runtime.spawn(async move {
+            if let Err(e) = reporter.report(&crash).await {
+                tracing::warn!(crash_id = %crash.id, error = %e, "Failed to report crash");
+            }
+        });
+    }));
+}
+
+/// Layer answering requests whose handler panicked with `500 Internal Server Error`.
+///
+/// The panic itself is logged and reported by the hook of
+/// [`install_panic_hook`]; the response only names the request ID to quote.
+pub fn catch_panic_layer() -> CatchPanicLayer<PanicResponder> {
+    CatchPanicLayer::custom(panic_response as PanicResponder)
+}
+
+fn panic_response(_payload: Box<dyn Any + Send + 'static>) -> Response {
+    let mut body = json!({"error": INTERNAL_ERROR, "code": INTERNAL_ERROR_CODE});
+    if let Some(request_id) = current_request_id() {
+        body["request_id"] = json!(request_id);
+    }
+    (StatusCode::INTERNAL_SERVER_ERROR, Json(body)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::middleware;
+    use axum::routing::get;
+    use axum::Router;
+    use envelope::REQUEST_ID_HEADER;
+
+    use super::*;
+    use crate::inbound::middleware::assign_request_id;
+
+    #[tokio::test]
+    async fn test_handler_panic_becomes_internal_error() {
+        let app = Router::new()
+            .route(
+                "/boom",
+                get(|| async {
+                    if true {
+                        panic!("handler failed");
+                    }
+                    "unreachable"
+                }),
+            )
+            .route("/ok", get(|| async { "ok" }))
+            .layer(catch_panic_layer())
+            .layer(middleware::from_fn(assign_request_id));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        let client = reqwest::Client::new();
+
+        let response = client
+            .get(format!("http://{}/boom", address))
+            .header(REQUEST_ID_HEADER, "req-42")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "req-42");
+        let body: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(body["code"], INTERNAL_ERROR_CODE);
+        assert_eq!(body["request_id"], "req-42");
+
+        // The server keeps serving after the panic
+        let response = client
+            .get(format!("http://{}/ok", address))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key(REQUEST_ID_HEADER));
+    }
+}
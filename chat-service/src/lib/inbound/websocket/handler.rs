@@ -1,30 +1,70 @@
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use auth::axum::AuthRejection;
+use auth::Authenticator;
+use auth::JwtError;
+use auth::RevocationError;
+use axum::extract::ws::CloseFrame;
 use axum::extract::ws::Message as WebSocketMessage;
 use axum::extract::ws::WebSocket;
 use axum::extract::Path;
 use axum::extract::Query;
 use axum::extract::State;
 use axum::extract::WebSocketUpgrade;
+use axum::http::header::RETRY_AFTER;
+use axum::http::HeaderMap;
 use axum::response::IntoResponse;
 use axum::response::Response;
+use envelope::REQUEST_ID_HEADER;
+use futures::FutureExt;
 use futures::SinkExt;
 use futures::StreamExt;
 use serde::Deserialize;
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
+use super::lifecycle::ConnectionLifecycle;
+use super::lifecycle::ABNORMAL_CLOSE_CODE;
+use super::lifecycle::IDLE_TIMEOUT_CLOSE_CODE;
+use super::lifecycle::NO_STATUS_CLOSE_CODE;
 use super::messages::ClientMessage;
 use super::messages::ServerMessage;
 use super::messages::WsChannelId;
+use super::messages::WsMessageId;
 use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::DeliveryStatus;
 use crate::domain::message::models::MessageContent;
+use crate::domain::message::models::MessageId;
+use crate::domain::message::models::Sender;
+use crate::domain::message::ports::DeliveryReporter;
 use crate::domain::message::ports::MessageServicePort;
 use crate::domain::user::models::UserId;
 use crate::inbound::http::router::AppState;
+use crate::inbound::middleware::with_request_id;
+use crate::inbound::middleware::MODERATOR_ROLE;
+use crate::inbound::middleware::READ_ONLY_ERROR;
+use crate::inbound::panic::INTERNAL_ERROR;
+use crate::inbound::panic::INTERNAL_ERROR_CODE;
+use crate::json;
+
+/// Close code sent when a connection is refused for capacity (RFC 6455 "Try Again Later").
+pub const TRY_AGAIN_LATER_CLOSE_CODE: u16 = 1013;
+
+/// Longest wait for the close frame of an idle connection to be sent.
+const CLOSE_FLUSH_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Header carrying the client's device identifier on the upgrade request.
+pub const DEVICE_ID_HEADER: &str = "x-device-id";
 
 /// WebSocket query parameters
 #[derive(Debug, Deserialize)]
 pub struct WebsocketParameters {
     pub token: String,
+    /// Device identifier, for browsers that cannot set headers on the upgrade request
+    pub device_id: Option<String>,
 }
 
 /// WebSocket upgrade handler
@@ -32,21 +72,48 @@ pub async fn websocket_handler(
     ws: WebSocketUpgrade,
     Path(channel_id): Path<String>,
     Query(params): Query<WebsocketParameters>,
+    headers: HeaderMap,
     State(state): State<AppState>,
 ) -> Response {
+    let started_at = Instant::now();
+
+    // Tokens bound to a device are only accepted from that device
+    let fingerprint = headers
+        .get(DEVICE_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .or(params.device_id.as_deref())
+        .map(Authenticator::device_fingerprint);
+
     // Validate JWT token and extract user ID
-    let claims: auth::Claims = match state.authenticator.validate_token(&params.token) {
+    let claims = match state
+        .authenticator
+        .validate_token_for_device(&params.token, fingerprint.as_deref())
+    {
         Ok(claims) => claims,
         Err(e) => {
             tracing::error!("Invalid JWT token: {}", e);
             return axum::http::Response::builder()
                 .status(axum::http::StatusCode::UNAUTHORIZED)
-                .body(axum::body::Body::from("Invalid or expired token"))
+                .body(axum::body::Body::from(format!(
+                    "Invalid or expired token ({})",
+                    e.code()
+                )))
                 .unwrap()
                 .into_response();
         }
     };
 
+    // Logged out tokens are refused like on the HTTP routes behind `AuthLayer`
+    if let Err(e) = state.authenticator.ensure_not_revoked(&claims).await {
+        tracing::warn!("Refused WebSocket upgrade: {}", e);
+        return match e {
+            RevocationError::Revoked => AuthRejection::InvalidToken(JwtError::Revoked),
+            RevocationError::JwtError(e) => AuthRejection::InvalidToken(e),
+            RevocationError::StoreError(_) => AuthRejection::RevocationUnavailable,
+        }
+        .into_response();
+    }
+
     // Extract user ID from claims
     let user_id_str = match claims.sub.as_ref() {
         Some(id) => id,
@@ -84,24 +151,117 @@ pub async fn websocket_handler(
         }
     };
 
-    ws.on_upgrade(move |socket| handle_socket(socket, channel_id, user_id, state))
+    if let Err(e) = state.connection_registry.check_capacity(channel_id).await {
+        return axum::http::Response::builder()
+            .status(axum::http::StatusCode::SERVICE_UNAVAILABLE)
+            .header(
+                RETRY_AFTER,
+                state.connection_registry.limits().retry_after_secs,
+            )
+            .body(axum::body::Body::from(e.to_string()))
+            .unwrap()
+            .into_response();
+    }
+
+    let sender = Sender {
+        user_id,
+        moderator: claims.roles.iter().any(|role| role == MODERATOR_ROLE),
+    };
+    let lifecycle = ConnectionLifecycle::authenticated(user_id, channel_id, started_at);
+
+    // Set by the request ID middleware, panics of the connection are logged with it
+    let request_id = headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    ws.on_upgrade(move |socket| {
+        handle_socket(socket, channel_id, sender, request_id, lifecycle, state)
+    })
 }
 
-/// Handle an individual WebSocket connection
-async fn handle_socket(socket: WebSocket, channel_id: ChannelId, user_id: UserId, state: AppState) {
-    let connection_id = Uuid::new_v4();
+/// Error processing a client message, reported back to the client.
+#[derive(Debug)]
+struct ClientMessageError {
+    message: String,
+    /// Machine-readable code, for errors clients are expected to handle
+    code: Option<&'static str>,
+    /// ID of the sent message the error is about
+    message_id: Option<MessageId>,
+}
 
-    // Split the socket into sender and receiver
-    let (mut sender, mut receiver) = socket.split();
+impl From<String> for ClientMessageError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            code: None,
+            message_id: None,
+        }
+    }
+}
+
+/// Why a connection stopped receiving client messages.
+enum ReceiveEnd {
+    /// The client closed the connection or it dropped, with the close code
+    Closed(u16),
+    /// The client sent nothing for this long
+    IdleTimeout(Duration),
+}
+
+/// Reports the delivery stages of sent messages to the sending connection.
+struct AckReporter {
+    tx: mpsc::UnboundedSender<WebSocketMessage>,
+}
+
+impl DeliveryReporter for AckReporter {
+    fn report(&self, message_id: MessageId, status: DeliveryStatus) {
+        let ack_msg = ServerMessage::MessageAck {
+            id: WsMessageId::from(message_id),
+            status: status.into(),
+        };
+        if let Ok(json) = serde_json::to_string(&ack_msg) {
+            let _ = self.tx.send(WebSocketMessage::Text(json));
+        }
+    }
+}
+
+/// Handle an individual WebSocket connection
+async fn handle_socket(
+    mut socket: WebSocket,
+    channel_id: ChannelId,
+    sender: Sender,
+    request_id: String,
+    lifecycle: ConnectionLifecycle,
+    state: AppState,
+) {
+    lifecycle.connected();
+    let connection_id = lifecycle.connection_id();
+    let user_id = sender.user_id;
 
     // Create a channel for outgoing messages
     let (tx, mut rx) = mpsc::unbounded_channel::<WebSocketMessage>();
 
-    // Add connection to manager
-    state
+    // Add connection to manager, closing the socket if the registry filled up
+    // since the upgrade was accepted
+    if let Err(e) = state
         .connection_registry
         .add_connection(connection_id, user_id, channel_id, tx.clone())
-        .await;
+        .await
+    {
+        let _ = socket
+            .send(WebSocketMessage::Close(Some(CloseFrame {
+                code: TRY_AGAIN_LATER_CLOSE_CODE,
+                reason: e.to_string().into(),
+            })))
+            .await;
+        lifecycle.closed(TRY_AGAIN_LATER_CLOSE_CODE);
+        return;
+    }
+    lifecycle.subscribed();
+
+    // Split the socket into sender and receiver
+    let (mut ws_sender, mut receiver) = socket.split();
 
     // Send connection confirmation using type-safe message
     let connected_msg = ServerMessage::Connected {
@@ -111,10 +271,11 @@ async fn handle_socket(socket: WebSocket, channel_id: ChannelId, user_id: UserId
         let _ = tx.send(WebSocketMessage::Text(json));
     }
 
-    // Task to send messages to the WebSocket
+    // Task to send messages to the WebSocket, until the connection is closed
     let mut send_task = tokio::spawn(async move {
         while let Some(msg) = rx.recv().await {
-            if sender.send(msg).await.is_err() {
+            let closing = matches!(msg, WebSocketMessage::Close(_));
+            if ws_sender.send(msg).await.is_err() || closing {
                 break;
             }
         }
@@ -122,35 +283,95 @@ async fn handle_socket(socket: WebSocket, channel_id: ChannelId, user_id: UserId
 
     // Task to receive messages from the WebSocket
     let message_service = state.message_service.clone();
+    let read_only = state.read_only;
     let tx_clone = tx.clone();
+    let idle_timeout = state
+        .connection_registry
+        .limits()
+        .idle_timeout_secs
+        .map(Duration::from_secs);
 
-    let mut recv_task = tokio::spawn(async move {
-        while let Some(Ok(msg)) = receiver.next().await {
-            if let Err(e) = process_client_message(
+    let mut recv_task = tokio::spawn(with_request_id(request_id, async move {
+        let mut close_code = ABNORMAL_CLOSE_CODE;
+        loop {
+            let next = match idle_timeout {
+                Some(idle_timeout) => {
+                    match tokio::time::timeout(idle_timeout, receiver.next()).await {
+                        Ok(next) => next,
+                        Err(_) => {
+                            let _ = tx_clone.send(WebSocketMessage::Close(Some(CloseFrame {
+                                code: IDLE_TIMEOUT_CLOSE_CODE,
+                                reason: "Idle timeout".into(),
+                            })));
+                            return ReceiveEnd::IdleTimeout(idle_timeout);
+                        }
+                    }
+                }
+                None => receiver.next().await,
+            };
+            let Some(Ok(msg)) = next else {
+                return ReceiveEnd::Closed(close_code);
+            };
+            if let WebSocketMessage::Close(frame) = &msg {
+                close_code = frame
+                    .as_ref()
+                    .map_or(NO_STATUS_CLOSE_CODE, |frame| frame.code);
+            }
+
+            // A panic fails the message, not the whole connection
+            let result = AssertUnwindSafe(process_client_message(
                 msg,
                 channel_id,
-                user_id,
+                sender,
                 message_service.as_ref(),
+                read_only,
                 &tx_clone,
-            )
+            ))
+            .catch_unwind()
             .await
-            {
-                tracing::error!("Error processing message: {}", e);
+            .unwrap_or_else(|_| {
+                Err(ClientMessageError {
+                    message: INTERNAL_ERROR.to_string(),
+                    code: Some(INTERNAL_ERROR_CODE),
+                    message_id: None,
+                })
+            });
+
+            if let Err(e) = result {
+                tracing::error!("Error processing message: {}", e.message);
                 let error_msg = ServerMessage::Error {
-                    message: e.to_string(),
+                    message: e.message,
+                    id: e.message_id.map(WsMessageId::from),
+                    code: e.code.map(str::to_string),
                 };
                 if let Ok(json) = serde_json::to_string(&error_msg) {
                     let _ = tx_clone.send(WebSocketMessage::Text(json));
                 }
             }
         }
-    });
+    }));
 
     // Wait for either task to finish
-    tokio::select! {
-        _ = (&mut send_task) => recv_task.abort(),
-        _ = (&mut recv_task) => send_task.abort(),
-    }
+    let close_code = tokio::select! {
+        _ = (&mut send_task) => {
+            recv_task.abort();
+            ABNORMAL_CLOSE_CODE
+        }
+        end = (&mut recv_task) => {
+            let close_code = match end {
+                Ok(ReceiveEnd::Closed(close_code)) => close_code,
+                Ok(ReceiveEnd::IdleTimeout(idle_timeout)) => {
+                    lifecycle.idle_timeout(idle_timeout);
+                    // Let the close frame go out before the connection is dropped
+                    let _ = tokio::time::timeout(CLOSE_FLUSH_TIMEOUT, &mut send_task).await;
+                    IDLE_TIMEOUT_CLOSE_CODE
+                }
+                Err(_) => ABNORMAL_CLOSE_CODE,
+            };
+            send_task.abort();
+            close_code
+        }
+    };
 
     // Remove connection from manager
     state
@@ -158,32 +379,39 @@ async fn handle_socket(socket: WebSocket, channel_id: ChannelId, user_id: UserId
         .remove_connection(connection_id)
         .await;
 
-    tracing::info!(
-        "WebSocket connection closed: {} (user: {}, channel: {})",
-        connection_id,
-        user_id,
-        channel_id
-    );
+    lifecycle.closed(close_code);
 }
 
 /// Process a message received from a client
 async fn process_client_message(
     msg: WebSocketMessage,
     channel_id: ChannelId,
-    user_id: UserId,
+    sender: Sender,
     message_service: &dyn MessageServicePort,
+    read_only: bool,
     tx: &tokio::sync::mpsc::UnboundedSender<WebSocketMessage>,
-) -> Result<(), String> {
+) -> Result<(), ClientMessageError> {
     match msg {
         WebSocketMessage::Text(text) => {
-            let client_msg: ClientMessage = serde_json::from_str(&text)
-                .map_err(|e| format!("Failed to parse message: {}", e))?;
+            let client_msg: ClientMessage =
+                json::from_string(text).map_err(|e| format!("Failed to parse message: {}", e))?;
 
             match client_msg {
-                ClientMessage::SendMessage { content } => {
+                ClientMessage::SendMessage { content, id } => {
+                    let message_id = id.map(MessageId::from);
+                    let with_id = |message: String| ClientMessageError {
+                        message,
+                        code: None,
+                        message_id,
+                    };
+
+                    if read_only {
+                        return Err(with_id(READ_ONLY_ERROR.to_string()));
+                    }
+
                     // Convert String → MessageContent (domain newtype)
                     let message_content = MessageContent::new(content)
-                        .map_err(|e| format!("Invalid message content: {}", e))?;
+                        .map_err(|e| with_id(format!("Invalid message content: {}", e)))?;
 
                     // Save message to database and publish to Kafka
                     // The MessageService will:
@@ -191,10 +419,20 @@ async fn process_client_message(
                     // 2. Publish MessageSentEvent to Kafka (sharded by channel_id)
                     // 3. KafkaEventConsumer on ALL instances will receive the event
                     // 4. Each instance broadcasts to its local WebSocket connections
+                    // Each stage reached is acknowledged to this connection
                     let message = message_service
-                        .send_message(channel_id, user_id, message_content)
+                        .send_message_reporting(
+                            channel_id,
+                            message_id,
+                            sender,
+                            message_content,
+                            Arc::new(AckReporter { tx: tx.clone() }),
+                        )
                         .await
-                        .map_err(|e| format!("Failed to send message: {}", e))?;
+                        .map_err(|e| ClientMessageError {
+                            code: e.code(),
+                            ..with_id(format!("Failed to send message: {}", e))
+                        })?;
 
                     tracing::debug!(
                         "Message {} saved and published to Kafka for channel {}",
@@ -223,6 +461,6 @@ async fn process_client_message(
             // Axum handles ping/pong automatically
             Ok(())
         }
-        WebSocketMessage::Binary(_) => Err("Binary messages not supported".to_string()),
+        WebSocketMessage::Binary(_) => Err("Binary messages not supported".to_string().into()),
     }
 }
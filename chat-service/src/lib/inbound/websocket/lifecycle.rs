@@ -0,0 +1,118 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use uuid::Uuid;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::user::models::UserId;
+use crate::inbound::metrics::record_websocket_close;
+
+/// Close code sent to connections closed for idling (RFC 6455 "Going Away").
+pub const IDLE_TIMEOUT_CLOSE_CODE: u16 = 1001;
+/// Close code reported for a close frame without a code (RFC 6455 "No Status Received").
+pub const NO_STATUS_CLOSE_CODE: u16 = 1005;
+/// Close code reported for a connection dropped without a close frame (RFC 6455 "Abnormal Closure").
+pub const ABNORMAL_CLOSE_CODE: u16 = 1006;
+
+/// Lifecycle of one WebSocket connection.
+///
+/// Each stage is logged as a structured event with a `lifecycle` field
+/// (`authenticated`, `connected`, `subscribed`, `idle_timeout`, `closed`) and
+/// the milliseconds since the upgrade request arrived. Closing also records
+/// the close code and lifetime in metrics.
+#[derive(Debug)]
+pub struct ConnectionLifecycle {
+    connection_id: Uuid,
+    user_id: UserId,
+    channel_id: ChannelId,
+    started_at: Instant,
+}
+
+impl ConnectionLifecycle {
+    /// Start the lifecycle of a connection whose token was accepted.
+    ///
+    /// # Arguments
+    /// * `user_id` - User the token was issued to
+    /// * `channel_id` - Channel the connection subscribes to
+    /// * `started_at` - When the upgrade request arrived
+    pub fn authenticated(user_id: UserId, channel_id: ChannelId, started_at: Instant) -> Self {
+        let lifecycle = Self {
+            connection_id: Uuid::new_v4(),
+            user_id,
+            channel_id,
+            started_at,
+        };
+        tracing::info!(
+            lifecycle = "authenticated",
+            connection_id = %lifecycle.connection_id,
+            user_id = %user_id,
+            channel_id = %channel_id,
+            elapsed_ms = lifecycle.elapsed_ms(),
+            "WebSocket authenticated"
+        );
+        lifecycle
+    }
+
+    pub fn connection_id(&self) -> Uuid {
+        self.connection_id
+    }
+
+    /// The WebSocket handshake completed.
+    pub fn connected(&self) {
+        tracing::info!(
+            lifecycle = "connected",
+            connection_id = %self.connection_id,
+            user_id = %self.user_id,
+            channel_id = %self.channel_id,
+            elapsed_ms = self.elapsed_ms(),
+            "WebSocket connected"
+        );
+    }
+
+    /// The connection was registered and receives the channel's messages.
+    pub fn subscribed(&self) {
+        tracing::info!(
+            lifecycle = "subscribed",
+            connection_id = %self.connection_id,
+            user_id = %self.user_id,
+            channel_id = %self.channel_id,
+            elapsed_ms = self.elapsed_ms(),
+            "WebSocket subscribed"
+        );
+    }
+
+    /// The client sent nothing for `idle_timeout` and is being closed.
+    pub fn idle_timeout(&self, idle_timeout: Duration) {
+        tracing::info!(
+            lifecycle = "idle_timeout",
+            connection_id = %self.connection_id,
+            user_id = %self.user_id,
+            channel_id = %self.channel_id,
+            idle_ms = idle_timeout.as_millis() as u64,
+            elapsed_ms = self.elapsed_ms(),
+            "WebSocket idle timeout"
+        );
+    }
+
+    /// The connection is closed, ending the lifecycle.
+    ///
+    /// # Arguments
+    /// * `close_code` - Close code sent or received, [`ABNORMAL_CLOSE_CODE`] if the connection dropped
+    pub fn closed(self, close_code: u16) {
+        let duration = self.started_at.elapsed();
+        tracing::info!(
+            lifecycle = "closed",
+            connection_id = %self.connection_id,
+            user_id = %self.user_id,
+            channel_id = %self.channel_id,
+            close_code,
+            duration_ms = duration.as_millis() as u64,
+            "WebSocket closed"
+        );
+        record_websocket_close(close_code, duration);
+    }
+
+    fn elapsed_ms(&self) -> u64 {
+        self.started_at.elapsed().as_millis() as u64
+    }
+}
@@ -9,7 +9,9 @@ use serde::Serialize;
 use uuid::Uuid;
 
 use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::DeliveryStatus;
 use crate::domain::message::models::MessageId;
+use crate::domain::poll::models::PollId;
 use crate::domain::user::models::UserId;
 
 /// Serializable wrapper for MessageId in WebSocket messages.
@@ -63,12 +65,55 @@ impl From<WsChannelId> for ChannelId {
     }
 }
 
+/// Serializable wrapper for PollId in WebSocket messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WsPollId(Uuid);
+
+impl From<PollId> for WsPollId {
+    fn from(id: PollId) -> Self {
+        Self(id.0)
+    }
+}
+
+/// Votes cast for one option of a poll.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WsPollOption {
+    pub text: String,
+    pub votes: u64,
+}
+
+/// Serializable delivery stage of a sent message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WsDeliveryStatus {
+    Accepted,
+    Stored,
+    Published,
+}
+
+impl From<DeliveryStatus> for WsDeliveryStatus {
+    fn from(status: DeliveryStatus) -> Self {
+        match status {
+            DeliveryStatus::Accepted => Self::Accepted,
+            DeliveryStatus::Stored => Self::Stored,
+            DeliveryStatus::Published => Self::Published,
+        }
+    }
+}
+
 /// WebSocket message types from client.
 #[derive(Debug, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ClientMessage {
     /// Send a message to the channel.
-    SendMessage { content: String },
+    SendMessage {
+        content: String,
+        /// Time-based ID chosen by the client, reused on retries so the
+        /// message is stored once
+        #[serde(default)]
+        id: Option<WsMessageId>,
+    },
     /// Ping to keep connection alive.
     Ping,
 }
@@ -83,11 +128,42 @@ pub enum ServerMessage {
     NewMessage {
         id: WsMessageId,
         user_id: WsUserId,
+        /// Author's display name or username, omitted if the author is unknown
+        #[serde(skip_serializing_if = "Option::is_none")]
+        display_name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        avatar_url: Option<String>,
         content: String,
         timestamp: DateTime<Utc>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
+    },
+    /// Delivery stage reached by a message the client sent.
+    MessageAck {
+        id: WsMessageId,
+        status: WsDeliveryStatus,
     },
     /// Error message.
-    Error { message: String },
+    Error {
+        message: String,
+        /// ID of the sent message the error is about
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<WsMessageId>,
+        /// Machine-readable code, for errors clients are expected to handle
+        #[serde(skip_serializing_if = "Option::is_none")]
+        code: Option<String>,
+    },
+    /// Results of a poll in the channel changed.
+    PollUpdated {
+        poll_id: WsPollId,
+        question: String,
+        /// Options with their votes, in the order they are numbered
+        options: Vec<WsPollOption>,
+        total_votes: u64,
+        closes_at: DateTime<Utc>,
+        /// True once the poll no longer accepts votes
+        closed: bool,
+    },
     /// Pong response to ping.
     Pong,
     /// Connection established confirmation.
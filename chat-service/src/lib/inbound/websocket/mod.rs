@@ -1,3 +1,4 @@
 pub mod handler;
+pub mod lifecycle;
 pub mod messages;
 pub mod registry;
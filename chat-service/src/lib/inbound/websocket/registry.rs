@@ -1,7 +1,11 @@
 use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use axum::extract::ws::Message as WsMessage;
+use serde::Serialize;
+use thiserror::Error;
 use tokio::sync::mpsc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
@@ -17,6 +21,56 @@ pub struct Connection {
     pub sender: mpsc::UnboundedSender<WsMessage>,
 }
 
+/// Capacity limits enforced when connections are added.
+///
+/// `None` leaves the corresponding dimension unbounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionLimits {
+    /// Maximum number of connections on this instance
+    pub max_connections: Option<usize>,
+    /// Maximum number of connections to a single channel on this instance
+    pub max_connections_per_channel: Option<usize>,
+    /// Seconds refused clients are told to wait before reconnecting
+    pub retry_after_secs: u64,
+    /// Seconds a client may send nothing before it is disconnected
+    pub idle_timeout_secs: Option<u64>,
+}
+
+impl Default for ConnectionLimits {
+    fn default() -> Self {
+        Self {
+            max_connections: None,
+            max_connections_per_channel: None,
+            retry_after_secs: 5,
+            idle_timeout_secs: None,
+        }
+    }
+}
+
+/// Connection refused because a capacity limit is reached.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CapacityError {
+    #[error("Instance connection limit reached ({limit})")]
+    InstanceFull { limit: usize },
+
+    #[error("Channel connection limit reached ({limit})")]
+    ChannelFull { channel_id: ChannelId, limit: usize },
+}
+
+/// Point-in-time view of how close the registry is to its limits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RegistrySaturation {
+    pub connections: usize,
+    pub max_connections: Option<usize>,
+    /// Connections of the channel with the most connections
+    pub busiest_channel_connections: usize,
+    pub max_connections_per_channel: Option<usize>,
+    /// Connections refused by the instance limit since startup
+    pub refused_instance_limit: u64,
+    /// Connections refused by the per-channel limit since startup
+    pub refused_channel_limit: u64,
+}
+
 /// Manages all active WebSocket connections
 #[derive(Debug, Clone)]
 pub struct ConnectionRegistry {
@@ -24,40 +78,83 @@ pub struct ConnectionRegistry {
     connections: Arc<RwLock<HashMap<Uuid, Connection>>>,
     /// Map of channel_id -> Vec<connection_id> for efficient broadcasting
     channel_connections: Arc<RwLock<HashMap<ChannelId, Vec<Uuid>>>>,
+    limits: ConnectionLimits,
+    refused_instance_limit: Arc<AtomicU64>,
+    refused_channel_limit: Arc<AtomicU64>,
 }
 
 impl ConnectionRegistry {
     pub fn new() -> Self {
+        Self::with_limits(ConnectionLimits::default())
+    }
+
+    /// Create a registry that refuses connections beyond the given limits.
+    ///
+    /// # Arguments
+    /// * `limits` - Per-instance and per-channel maximums
+    ///
+    /// # Returns
+    /// Empty registry enforcing the limits
+    pub fn with_limits(limits: ConnectionLimits) -> Self {
         Self {
             connections: Arc::new(RwLock::new(HashMap::new())),
             channel_connections: Arc::new(RwLock::new(HashMap::new())),
+            limits,
+            refused_instance_limit: Arc::new(AtomicU64::new(0)),
+            refused_channel_limit: Arc::new(AtomicU64::new(0)),
         }
     }
 
+    /// Limits enforced by this registry
+    pub fn limits(&self) -> ConnectionLimits {
+        self.limits
+    }
+
+    /// Check whether a new connection to a channel would be accepted.
+    ///
+    /// Lets the upgrade handler refuse before the WebSocket handshake;
+    /// [`Self::add_connection`] enforces the limits again atomically.
+    ///
+    /// # Arguments
+    /// * `channel_id` - Channel the client wants to connect to
+    ///
+    /// # Errors
+    /// * `InstanceFull` - The instance limit is reached
+    /// * `ChannelFull` - The channel limit is reached
+    pub async fn check_capacity(&self, channel_id: ChannelId) -> Result<(), CapacityError> {
+        let channel_conns = self.channel_connections.read().await;
+        let connections = self.connections.read().await;
+        self.enforce_limits(&channel_conns, connections.len(), channel_id)
+    }
+
     /// Add a new connection
+    ///
+    /// # Errors
+    /// * `InstanceFull` - The instance limit is reached
+    /// * `ChannelFull` - The channel limit is reached
     pub async fn add_connection(
         &self,
         connection_id: Uuid,
         user_id: UserId,
         channel_id: ChannelId,
         sender: mpsc::UnboundedSender<WsMessage>,
-    ) {
+    ) -> Result<(), CapacityError> {
         let connection = Connection {
             user_id,
             channel_id,
             sender,
         };
 
+        // Hold both locks so concurrent additions cannot exceed the limits
+        let mut channel_conns = self.channel_connections.write().await;
+        let mut connections = self.connections.write().await;
+        self.enforce_limits(&channel_conns, connections.len(), channel_id)?;
+
         // Add to connections map
-        self.connections
-            .write()
-            .await
-            .insert(connection_id, connection);
+        connections.insert(connection_id, connection);
 
         // Add to channel connections
-        self.channel_connections
-            .write()
-            .await
+        channel_conns
             .entry(channel_id)
             .or_insert_with(Vec::new)
             .push(connection_id);
@@ -68,6 +165,60 @@ impl ConnectionRegistry {
             user_id,
             channel_id
         );
+
+        Ok(())
+    }
+
+    fn enforce_limits(
+        &self,
+        channel_conns: &HashMap<ChannelId, Vec<Uuid>>,
+        total: usize,
+        channel_id: ChannelId,
+    ) -> Result<(), CapacityError> {
+        let channel_count = channel_conns.get(&channel_id).map_or(0, Vec::len);
+
+        let refusal = match self.limits {
+            ConnectionLimits {
+                max_connections: Some(limit),
+                ..
+            } if total >= limit => CapacityError::InstanceFull { limit },
+            ConnectionLimits {
+                max_connections_per_channel: Some(limit),
+                ..
+            } if channel_count >= limit => CapacityError::ChannelFull { channel_id, limit },
+            _ => return Ok(()),
+        };
+
+        let counter = match refusal {
+            CapacityError::InstanceFull { .. } => &self.refused_instance_limit,
+            CapacityError::ChannelFull { .. } => &self.refused_channel_limit,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        tracing::warn!(
+            channel_id = %channel_id,
+            connections = total,
+            channel_connections = channel_count,
+            "Connection refused: {}",
+            refusal
+        );
+
+        Err(refusal)
+    }
+
+    /// Current connection counts against the configured limits
+    pub async fn saturation(&self) -> RegistrySaturation {
+        let channel_conns = self.channel_connections.read().await;
+        let connections = self.connections.read().await;
+
+        RegistrySaturation {
+            connections: connections.len(),
+            max_connections: self.limits.max_connections,
+            busiest_channel_connections: channel_conns.values().map(Vec::len).max().unwrap_or(0),
+            max_connections_per_channel: self.limits.max_connections_per_channel,
+            refused_instance_limit: self.refused_instance_limit.load(Ordering::Relaxed),
+            refused_channel_limit: self.refused_channel_limit.load(Ordering::Relaxed),
+        }
     }
 
     /// Remove a connection
@@ -146,3 +297,68 @@ impl Default for ConnectionRegistry {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limited(max_connections: Option<usize>, per_channel: Option<usize>) -> ConnectionRegistry {
+        ConnectionRegistry::with_limits(ConnectionLimits {
+            max_connections,
+            max_connections_per_channel: per_channel,
+            ..ConnectionLimits::default()
+        })
+    }
+
+    async fn connect(
+        registry: &ConnectionRegistry,
+        channel_id: ChannelId,
+    ) -> Result<(), CapacityError> {
+        let (sender, _receiver) = mpsc::unbounded_channel();
+        registry
+            .add_connection(Uuid::new_v4(), UserId::new(), channel_id, sender)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_instance_limit_refuses_connections() {
+        let registry = limited(Some(2), None);
+
+        connect(&registry, ChannelId::new()).await.unwrap();
+        connect(&registry, ChannelId::new()).await.unwrap();
+        let refused = connect(&registry, ChannelId::new()).await;
+
+        assert_eq!(refused, Err(CapacityError::InstanceFull { limit: 2 }));
+        assert_eq!(registry.get_total_connections().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_channel_limit_only_applies_to_full_channel() {
+        let registry = limited(None, Some(1));
+        let busy = ChannelId::new();
+
+        connect(&registry, busy).await.unwrap();
+        assert!(matches!(
+            registry.check_capacity(busy).await,
+            Err(CapacityError::ChannelFull { limit: 1, .. })
+        ));
+        assert!(connect(&registry, ChannelId::new()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_saturation_reports_counts_and_refusals() {
+        let registry = limited(Some(3), Some(2));
+        let channel_id = ChannelId::new();
+
+        connect(&registry, channel_id).await.unwrap();
+        connect(&registry, channel_id).await.unwrap();
+        let _ = connect(&registry, channel_id).await;
+
+        let saturation = registry.saturation().await;
+        assert_eq!(saturation.connections, 2);
+        assert_eq!(saturation.busiest_channel_connections, 2);
+        assert_eq!(saturation.max_connections, Some(3));
+        assert_eq!(saturation.refused_channel_limit, 1);
+        assert_eq!(saturation.refused_instance_limit, 0);
+    }
+}
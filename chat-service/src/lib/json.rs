@@ -0,0 +1,82 @@
+//! JSON decoding on the hot paths: Kafka chat events and WebSocket client messages.
+//!
+//! Backed by serde_json, or by simd-json with the `simd-json` feature. Both
+//! decode the same serde types, so the feature only changes throughput.
+
+use serde::de::DeserializeOwned;
+
+/// Error decoding a JSON document.
+#[cfg(not(feature = "simd-json"))]
+pub type JsonError = serde_json::Error;
+
+/// Error decoding a JSON document.
+#[cfg(feature = "simd-json")]
+pub type JsonError = simd_json::Error;
+
+/// Decode a value from borrowed JSON bytes.
+///
+/// simd-json parses in place, so it decodes a copy of the bytes.
+///
+/// # Arguments
+/// * `bytes` - JSON document
+///
+/// # Errors
+/// Returns error if the document is not valid JSON for `T`
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, JsonError> {
+    #[cfg(feature = "simd-json")]
+    {
+        simd_json::serde::from_slice(&mut bytes.to_vec())
+    }
+    #[cfg(not(feature = "simd-json"))]
+    {
+        serde_json::from_slice(bytes)
+    }
+}
+
+/// Decode a value from an owned JSON string, without copying it.
+///
+/// # Arguments
+/// * `text` - JSON document
+///
+/// # Errors
+/// Returns error if the document is not valid JSON for `T`
+pub fn from_string<T: DeserializeOwned>(text: String) -> Result<T, JsonError> {
+    #[cfg(feature = "simd-json")]
+    {
+        simd_json::serde::from_slice(&mut text.into_bytes())
+    }
+    #[cfg(not(feature = "simd-json"))]
+    {
+        serde_json::from_str(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum Event {
+        Sent { content: String },
+    }
+
+    #[test]
+    fn test_from_slice_and_from_string_decode_same_value() {
+        let json = r#"{"type":"sent","content":"héllo \"world\""}"#;
+        let expected = Event::Sent {
+            content: "héllo \"world\"".to_string(),
+        };
+
+        assert_eq!(from_slice::<Event>(json.as_bytes()).unwrap(), expected);
+        assert_eq!(from_string::<Event>(json.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_invalid_document_rejected() {
+        assert!(from_slice::<Event>(br#"{"type":"sent"}"#).is_err());
+        assert!(from_string::<Event>("not json".to_string()).is_err());
+    }
+}
@@ -1,7 +1,11 @@
 pub mod config;
 pub mod domain;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
 pub mod inbound;
+pub mod json;
 pub mod outbound;
+pub mod supervisor;
 
 // Re-export commonly used types
 pub use domain::channel::models::*;
@@ -13,4 +17,9 @@ pub use domain::user::models::UserId;
 // Include the generated proto code
 pub mod proto {
     tonic::include_proto!("user");
+
+    /// Protobuf encoding of the user events consumed from Kafka
+    pub mod events {
+        tonic::include_proto!("user_events");
+    }
 }
@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use tokio::process::Command;
+
+use crate::domain::backup::errors::BackupError;
+use crate::domain::backup::ports::SnapshotTrigger;
+
+/// Cassandra snapshot trigger shelling out to `nodetool`.
+///
+/// Snapshots stay on the node's disk. Restoring imports the snapshot's
+/// SSTables with `nodetool import`, which reads them from the node's data
+/// directory, so the tool must run on (or share the data volume with) the node.
+pub struct NodetoolSnapshotTrigger {
+    host: String,
+    data_dir: PathBuf,
+}
+
+impl NodetoolSnapshotTrigger {
+    pub fn new(host: impl Into<String>, data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            host: host.into(),
+            data_dir: data_dir.into(),
+        }
+    }
+
+    async fn nodetool(&self, args: &[&str]) -> Result<(), BackupError> {
+        let output = Command::new("nodetool")
+            .arg("-h")
+            .arg(&self.host)
+            .args(args)
+            .output()
+            .await
+            .map_err(|e| BackupError::SnapshotError(format!("Failed to run nodetool: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(BackupError::SnapshotError(format!(
+                "nodetool {} exited with {}: {}",
+                args.first().unwrap_or(&""),
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Snapshot directories of a keyspace, as (table, directory) pairs.
+    async fn snapshot_dirs(
+        &self,
+        keyspace: &str,
+        tag: &str,
+    ) -> Result<Vec<(String, PathBuf)>, BackupError> {
+        let keyspace_dir = self.data_dir.join(keyspace);
+        let mut entries = tokio::fs::read_dir(&keyspace_dir).await.map_err(|e| {
+            BackupError::SnapshotError(format!("{}: {}", keyspace_dir.display(), e))
+        })?;
+
+        let mut dirs = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| BackupError::SnapshotError(e.to_string()))?
+        {
+            // Table directories are named `<table>-<table id>`
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let Some((table, _)) = name.rsplit_once('-') else {
+                continue;
+            };
+
+            let snapshot_dir = entry.path().join("snapshots").join(tag);
+            if tokio::fs::try_exists(&snapshot_dir).await.unwrap_or(false) {
+                dirs.push((table.to_string(), snapshot_dir));
+            }
+        }
+
+        Ok(dirs)
+    }
+}
+
+#[async_trait]
+impl SnapshotTrigger for NodetoolSnapshotTrigger {
+    async fn snapshot(&self, keyspace: &str, tag: &str) -> Result<(), BackupError> {
+        self.nodetool(&["snapshot", "-t", tag, "--", keyspace])
+            .await
+    }
+
+    async fn restore(&self, keyspace: &str, tag: &str) -> Result<(), BackupError> {
+        let dirs = self.snapshot_dirs(keyspace, tag).await?;
+        if dirs.is_empty() {
+            return Err(BackupError::SnapshotError(format!(
+                "Snapshot {} of keyspace {} not found",
+                tag, keyspace
+            )));
+        }
+
+        for (table, dir) in dirs {
+            let dir = dir.to_string_lossy();
+            self.nodetool(&["import", "--copy-data", keyspace, &table, &dir])
+                .await?;
+            tracing::info!(keyspace = %keyspace, table = %table, "Cassandra table restored");
+        }
+
+        Ok(())
+    }
+}
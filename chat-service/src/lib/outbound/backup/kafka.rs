@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rdkafka::consumer::BaseConsumer;
+use rdkafka::consumer::CommitMode;
+use rdkafka::consumer::Consumer;
+use rdkafka::ClientConfig;
+use rdkafka::Offset;
+use rdkafka::TopicPartitionList;
+
+use crate::config::Config;
+use crate::domain::backup::errors::BackupError;
+use crate::domain::backup::models::ConsumerGroupCheckpoint;
+use crate::domain::backup::models::PartitionOffset;
+use crate::domain::backup::ports::OffsetCheckpointer;
+use crate::outbound::events::topic::TopicSharder;
+
+const KAFKA_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Checkpoints the committed offsets of chat-service's consumer groups.
+pub struct KafkaOffsetCheckpointer {
+    brokers: String,
+    groups: Vec<(String, Vec<String>)>,
+}
+
+impl KafkaOffsetCheckpointer {
+    /// Create a checkpointer for the message and user event consumer groups.
+    ///
+    /// # Arguments
+    /// * `config` - Application configuration
+    ///
+    /// # Returns
+    /// KafkaOffsetCheckpointer instance
+    ///
+    /// # Errors
+    /// Returns error if the shard configuration is invalid
+    pub fn new(config: &Config) -> Result<Self, anyhow::Error> {
+        let sharder = TopicSharder::new(config.kafka.num_shards, "chat.messages")?;
+
+        Ok(Self {
+            brokers: config.kafka.brokers.clone(),
+            groups: vec![
+                (config.kafka.group_id.clone(), sharder.get_all_shards()),
+                (
+                    config.kafka.user_events.group_id.clone(),
+                    vec![config.kafka.user_events.topic.clone()],
+                ),
+            ],
+        })
+    }
+
+    fn consumer(&self, group_id: &str) -> Result<BaseConsumer, BackupError> {
+        ClientConfig::new()
+            .set("bootstrap.servers", &self.brokers)
+            .set("group.id", group_id)
+            .set("enable.auto.commit", "false")
+            .create()
+            .map_err(|e| BackupError::CheckpointError(e.to_string()))
+    }
+
+    fn checkpoint_group(
+        &self,
+        group_id: &str,
+        topics: &[String],
+    ) -> Result<ConsumerGroupCheckpoint, BackupError> {
+        let consumer = self.consumer(group_id)?;
+
+        let mut partitions = TopicPartitionList::new();
+        for topic in topics {
+            let metadata = consumer
+                .fetch_metadata(Some(topic), KAFKA_TIMEOUT)
+                .map_err(|e| BackupError::CheckpointError(e.to_string()))?;
+            for partition in metadata.topics().iter().flat_map(|t| t.partitions()) {
+                partitions.add_partition(topic, partition.id());
+            }
+        }
+
+        let committed = consumer
+            .committed_offsets(partitions, KAFKA_TIMEOUT)
+            .map_err(|e| BackupError::CheckpointError(e.to_string()))?;
+
+        // Partitions without a committed offset are left out and fall back to
+        // `auto.offset.reset` after a restore, as they would today
+        let offsets = committed
+            .elements()
+            .iter()
+            .filter_map(|element| match element.offset() {
+                Offset::Offset(offset) => Some(PartitionOffset {
+                    topic: element.topic().to_string(),
+                    partition: element.partition(),
+                    offset,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(ConsumerGroupCheckpoint {
+            group_id: group_id.to_string(),
+            offsets,
+        })
+    }
+
+    fn restore_group(&self, checkpoint: &ConsumerGroupCheckpoint) -> Result<(), BackupError> {
+        if checkpoint.offsets.is_empty() {
+            return Ok(());
+        }
+
+        let mut partitions = TopicPartitionList::new();
+        for offset in &checkpoint.offsets {
+            partitions
+                .add_partition_offset(
+                    &offset.topic,
+                    offset.partition,
+                    Offset::Offset(offset.offset),
+                )
+                .map_err(|e| BackupError::CheckpointError(e.to_string()))?;
+        }
+
+        self.consumer(&checkpoint.group_id)?
+            .commit(&partitions, CommitMode::Sync)
+            .map_err(|e| BackupError::CheckpointError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl OffsetCheckpointer for KafkaOffsetCheckpointer {
+    async fn checkpoint(&self) -> Result<Vec<ConsumerGroupCheckpoint>, BackupError> {
+        // librdkafka calls block, keep them off the async executor threads
+        tokio::task::block_in_place(|| {
+            self.groups
+                .iter()
+                .map(|(group_id, topics)| self.checkpoint_group(group_id, topics))
+                .collect()
+        })
+    }
+
+    async fn restore(&self, checkpoints: &[ConsumerGroupCheckpoint]) -> Result<(), BackupError> {
+        tokio::task::block_in_place(|| {
+            checkpoints
+                .iter()
+                .try_for_each(|checkpoint| self.restore_group(checkpoint))
+        })
+    }
+}
@@ -0,0 +1,277 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::backup::errors::BackupError;
+use crate::domain::backup::models::BackupId;
+use crate::domain::backup::models::BackupManifest;
+use crate::domain::backup::models::CassandraSnapshot;
+use crate::domain::backup::models::ConsumerGroupCheckpoint;
+use crate::domain::backup::models::PartitionOffset;
+use crate::domain::backup::models::PostgresDump;
+use crate::domain::backup::ports::BackupManifestStore;
+use crate::domain::backup::ports::ManifestSigner;
+use crate::domain::backup::ports::ObjectStorage;
+
+/// Manifest store writing signed JSON manifests next to the backup artifacts.
+///
+/// The signature of `manifest.json` is stored as `manifest.sig`; a manifest
+/// without a valid signature is never returned.
+pub struct ObjectStorageManifestStore<OS, SG>
+where
+    OS: ObjectStorage,
+    SG: ManifestSigner,
+{
+    storage: Arc<OS>,
+    signer: Arc<SG>,
+}
+
+impl<OS, SG> ObjectStorageManifestStore<OS, SG>
+where
+    OS: ObjectStorage,
+    SG: ManifestSigner,
+{
+    pub fn new(storage: Arc<OS>, signer: Arc<SG>) -> Self {
+        Self { storage, signer }
+    }
+}
+
+#[async_trait]
+impl<OS, SG> BackupManifestStore for ObjectStorageManifestStore<OS, SG>
+where
+    OS: ObjectStorage,
+    SG: ManifestSigner,
+{
+    async fn save(&self, manifest: &BackupManifest) -> Result<(), BackupError> {
+        let json = serde_json::to_vec_pretty(&ManifestDocument::from(manifest))
+            .map_err(|e| BackupError::ManifestError(e.to_string()))?;
+        let signature = self.signer.sign(&json);
+
+        // The manifest marks the backup complete, so it is written after its signature
+        self.storage
+            .put(
+                &manifest.id.manifest_signature_key(),
+                signature.into_bytes(),
+            )
+            .await?;
+        self.storage.put(&manifest.id.manifest_key(), json).await
+    }
+
+    async fn find(&self, id: BackupId) -> Result<Option<BackupManifest>, BackupError> {
+        let Some(json) = self.storage.get(&id.manifest_key()).await? else {
+            return Ok(None);
+        };
+
+        let signature = self
+            .storage
+            .get(&id.manifest_signature_key())
+            .await?
+            .ok_or_else(|| BackupError::InvalidSignature(id.to_string()))?;
+        if !self
+            .signer
+            .verify(&json, String::from_utf8_lossy(&signature).trim())
+        {
+            return Err(BackupError::InvalidSignature(id.to_string()));
+        }
+
+        let document: ManifestDocument =
+            serde_json::from_slice(&json).map_err(|e| BackupError::ManifestError(e.to_string()))?;
+
+        Ok(Some(document.into()))
+    }
+}
+
+/// On-disk manifest format.
+#[derive(Debug, Serialize, Deserialize)]
+struct ManifestDocument {
+    id: Uuid,
+    created_at: DateTime<Utc>,
+    postgres: PostgresDocument,
+    cassandra: Vec<SnapshotDocument>,
+    kafka: Vec<CheckpointDocument>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PostgresDocument {
+    object_key: String,
+    size_bytes: u64,
+    sha256: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotDocument {
+    keyspace: String,
+    tag: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CheckpointDocument {
+    group_id: String,
+    offsets: Vec<OffsetDocument>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OffsetDocument {
+    topic: String,
+    partition: i32,
+    offset: i64,
+}
+
+impl From<&BackupManifest> for ManifestDocument {
+    fn from(manifest: &BackupManifest) -> Self {
+        Self {
+            id: *manifest.id.as_uuid(),
+            created_at: manifest.created_at,
+            postgres: PostgresDocument {
+                object_key: manifest.postgres.object_key.clone(),
+                size_bytes: manifest.postgres.size_bytes,
+                sha256: manifest.postgres.sha256.clone(),
+            },
+            cassandra: manifest
+                .cassandra
+                .iter()
+                .map(|snapshot| SnapshotDocument {
+                    keyspace: snapshot.keyspace.clone(),
+                    tag: snapshot.tag.clone(),
+                })
+                .collect(),
+            kafka: manifest
+                .kafka
+                .iter()
+                .map(|checkpoint| CheckpointDocument {
+                    group_id: checkpoint.group_id.clone(),
+                    offsets: checkpoint
+                        .offsets
+                        .iter()
+                        .map(|offset| OffsetDocument {
+                            topic: offset.topic.clone(),
+                            partition: offset.partition,
+                            offset: offset.offset,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<ManifestDocument> for BackupManifest {
+    fn from(document: ManifestDocument) -> Self {
+        Self {
+            id: BackupId(document.id),
+            created_at: document.created_at,
+            postgres: PostgresDump {
+                object_key: document.postgres.object_key,
+                size_bytes: document.postgres.size_bytes,
+                sha256: document.postgres.sha256,
+            },
+            cassandra: document
+                .cassandra
+                .into_iter()
+                .map(|snapshot| CassandraSnapshot {
+                    keyspace: snapshot.keyspace,
+                    tag: snapshot.tag,
+                })
+                .collect(),
+            kafka: document
+                .kafka
+                .into_iter()
+                .map(|checkpoint| ConsumerGroupCheckpoint {
+                    group_id: checkpoint.group_id,
+                    offsets: checkpoint
+                        .offsets
+                        .into_iter()
+                        .map(|offset| PartitionOffset {
+                            topic: offset.topic,
+                            partition: offset.partition,
+                            offset: offset.offset,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::outbound::backup::HmacManifestSigner;
+
+    #[derive(Default)]
+    struct MemoryStorage {
+        objects: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl ObjectStorage for MemoryStorage {
+        async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), BackupError> {
+            self.objects.lock().unwrap().insert(key.to_string(), data);
+            Ok(())
+        }
+
+        async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BackupError> {
+            Ok(self.objects.lock().unwrap().get(key).cloned())
+        }
+    }
+
+    fn manifest() -> BackupManifest {
+        let id = BackupId::new();
+        BackupManifest {
+            id,
+            created_at: Utc::now(),
+            postgres: PostgresDump::new(id.postgres_dump_key(), b"dump"),
+            cassandra: vec![],
+            kafka: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signed_manifest_round_trips() {
+        let storage = Arc::new(MemoryStorage::default());
+        let store = ObjectStorageManifestStore::new(
+            Arc::clone(&storage),
+            Arc::new(HmacManifestSigner::new("backup-key")),
+        );
+        let manifest = manifest();
+
+        store.save(&manifest).await.unwrap();
+
+        assert_eq!(store.find(manifest.id).await.unwrap(), Some(manifest));
+    }
+
+    #[tokio::test]
+    async fn test_modified_manifest_is_refused() {
+        let storage = Arc::new(MemoryStorage::default());
+        let store = ObjectStorageManifestStore::new(
+            Arc::clone(&storage),
+            Arc::new(HmacManifestSigner::new("backup-key")),
+        );
+        let manifest = manifest();
+        store.save(&manifest).await.unwrap();
+
+        let key = manifest.id.manifest_key();
+        let json = String::from_utf8(storage.get(&key).await.unwrap().unwrap()).unwrap();
+        let tampered = json.replace("\"size_bytes\": 4", "\"size_bytes\": 5");
+        storage.put(&key, tampered.into_bytes()).await.unwrap();
+
+        let result = store.find(manifest.id).await;
+        assert!(matches!(result, Err(BackupError::InvalidSignature(_))));
+
+        let other_key = ObjectStorageManifestStore::new(
+            Arc::clone(&storage),
+            Arc::new(HmacManifestSigner::new("other-key")),
+        );
+        storage.put(&key, json.into_bytes()).await.unwrap();
+        let result = other_key.find(manifest.id).await;
+        assert!(matches!(result, Err(BackupError::InvalidSignature(_))));
+    }
+}
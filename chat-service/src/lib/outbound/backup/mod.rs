@@ -0,0 +1,13 @@
+pub mod cassandra;
+pub mod kafka;
+pub mod manifest;
+pub mod postgres;
+pub mod signer;
+pub mod storage;
+
+pub use cassandra::NodetoolSnapshotTrigger;
+pub use kafka::KafkaOffsetCheckpointer;
+pub use manifest::ObjectStorageManifestStore;
+pub use postgres::PgDumpDumper;
+pub use signer::HmacManifestSigner;
+pub use storage::FilesystemObjectStorage;
@@ -0,0 +1,84 @@
+use std::process::Stdio;
+
+use async_trait::async_trait;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::domain::backup::errors::BackupError;
+use crate::domain::backup::ports::DatabaseDumper;
+
+/// Postgres dumper shelling out to `pg_dump` / `pg_restore`.
+pub struct PgDumpDumper {
+    database_url: String,
+}
+
+impl PgDumpDumper {
+    pub fn new(database_url: impl Into<String>) -> Self {
+        Self {
+            database_url: database_url.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl DatabaseDumper for PgDumpDumper {
+    async fn dump(&self) -> Result<Vec<u8>, BackupError> {
+        // --serializable-deferrable waits for a snapshot free of serialization
+        // anomalies, so the dump is consistent without blocking writers
+        let output = Command::new("pg_dump")
+            .arg("--format=custom")
+            .arg("--serializable-deferrable")
+            .arg("--dbname")
+            .arg(&self.database_url)
+            .output()
+            .await
+            .map_err(|e| BackupError::DumpError(format!("Failed to run pg_dump: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(BackupError::DumpError(format!(
+                "pg_dump exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(output.stdout)
+    }
+
+    async fn restore(&self, dump: Vec<u8>) -> Result<(), BackupError> {
+        let mut child = Command::new("pg_restore")
+            .arg("--clean")
+            .arg("--if-exists")
+            .arg("--single-transaction")
+            .arg("--no-owner")
+            .arg("--dbname")
+            .arg(&self.database_url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| BackupError::DumpError(format!("Failed to run pg_restore: {}", e)))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(&dump)
+                .await
+                .map_err(|e| BackupError::DumpError(e.to_string()))?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .await
+            .map_err(|e| BackupError::DumpError(e.to_string()))?;
+
+        if !output.status.success() {
+            return Err(BackupError::DumpError(format!(
+                "pg_restore exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use hmac::Hmac;
+use hmac::Mac;
+use sha2::Sha256;
+
+use crate::domain::backup::ports::ManifestSigner;
+
+/// Manifest signer computing an HMAC-SHA256 with a configured key.
+///
+/// Anyone holding the key can both sign and verify, so keep it out of the
+/// backup storage.
+pub struct HmacManifestSigner {
+    key: Vec<u8>,
+}
+
+impl HmacManifestSigner {
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self { key: key.into() }
+    }
+
+    fn mac(&self, manifest: &[u8]) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(manifest);
+        mac
+    }
+}
+
+impl ManifestSigner for HmacManifestSigner {
+    fn sign(&self, manifest: &[u8]) -> String {
+        self.mac(manifest)
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    fn verify(&self, manifest: &[u8], signature: &str) -> bool {
+        let Some(bytes) = decode_hex(signature) else {
+            return false;
+        };
+
+        // Constant-time comparison
+        self.mac(manifest).verify_slice(&bytes).is_ok()
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature_verifies_only_same_manifest_and_key() {
+        let signer = HmacManifestSigner::new("backup-key");
+        let signature = signer.sign(b"{\"id\":1}");
+
+        assert!(signer.verify(b"{\"id\":1}", &signature));
+        assert!(!signer.verify(b"{\"id\":2}", &signature));
+        assert!(!HmacManifestSigner::new("other-key").verify(b"{\"id\":1}", &signature));
+        assert!(!signer.verify(b"{\"id\":1}", "not-hex"));
+    }
+}
@@ -0,0 +1,70 @@
+use std::io::ErrorKind;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use crate::domain::backup::errors::BackupError;
+use crate::domain::backup::ports::ObjectStorage;
+
+/// Object storage backed by a local (or mounted) directory.
+///
+/// Keys map to paths below the root directory.
+pub struct FilesystemObjectStorage {
+    root: PathBuf,
+}
+
+impl FilesystemObjectStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_for(&self, key: &str) -> Result<PathBuf, BackupError> {
+        let relative = Path::new(key);
+        let is_plain = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+
+        if key.is_empty() || !is_plain {
+            return Err(BackupError::StorageError(format!(
+                "Invalid object key: {}",
+                key
+            )));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl ObjectStorage for FilesystemObjectStorage {
+    async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), BackupError> {
+        let path = self.path_for(key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| BackupError::StorageError(e.to_string()))?;
+        }
+
+        // Write to a temporary file first so readers never see a partial object
+        let partial = path.with_extension("partial");
+        tokio::fs::write(&partial, data)
+            .await
+            .map_err(|e| BackupError::StorageError(e.to_string()))?;
+        tokio::fs::rename(&partial, &path)
+            .await
+            .map_err(|e| BackupError::StorageError(e.to_string()))
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BackupError> {
+        let path = self.path_for(key)?;
+
+        match tokio::fs::read(&path).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(BackupError::StorageError(e.to_string())),
+        }
+    }
+}
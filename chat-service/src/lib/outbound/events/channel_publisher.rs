@@ -0,0 +1,91 @@
+/// Kafka adapter implementing ChannelEventPublisher port.
+///
+/// Publishes channel change data capture events to the `chat.channels` topic.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::messages::ChannelEventMessage;
+use super::producer::KafkaEventProducer;
+use crate::domain::channel::events::ChannelCreatedEvent;
+use crate::domain::channel::events::ChannelDeletedEvent;
+use crate::domain::channel::events::ChannelUpdatedEvent;
+use crate::domain::channel::events::UserJoinedChannelEvent;
+use crate::domain::channel::events::UserLeftChannelEvent;
+use crate::domain::channel::ports::ChannelEventPublisher;
+use crate::domain::errors::EventPublisherError;
+
+/// Topic carrying every channel change, keyed by channel ID.
+pub const CHANNEL_EVENTS_TOPIC: &str = "chat.channels";
+
+/// Kafka implementation of ChannelEventPublisher.
+///
+/// Unlike message events, channel events are not sharded: downstream search
+/// indexing and analytics consume a single topic, and keying by channel ID
+/// keeps each channel's changes ordered.
+pub struct KafkaChannelEventPublisher {
+    producer: Arc<KafkaEventProducer>,
+}
+
+impl KafkaChannelEventPublisher {
+    /// Create a new Kafka channel event publisher.
+    ///
+    /// # Arguments
+    /// * `producer` - Kafka event producer for publishing events
+    ///
+    /// # Returns
+    /// Configured publisher instance
+    pub fn new(producer: Arc<KafkaEventProducer>) -> Self {
+        Self { producer }
+    }
+
+    async fn publish(&self, envelope: ChannelEventMessage) -> Result<(), EventPublisherError> {
+        self.producer
+            .publish_to_topic(CHANNEL_EVENTS_TOPIC, envelope.channel_id(), &envelope)
+            .await
+            .map_err(|e| EventPublisherError::PublishFailed(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl ChannelEventPublisher for KafkaChannelEventPublisher {
+    async fn publish_channel_created(
+        &self,
+        event: &ChannelCreatedEvent,
+    ) -> Result<(), EventPublisherError> {
+        self.publish(ChannelEventMessage::ChannelCreated(event.into()))
+            .await
+    }
+
+    async fn publish_channel_updated(
+        &self,
+        event: &ChannelUpdatedEvent,
+    ) -> Result<(), EventPublisherError> {
+        self.publish(ChannelEventMessage::ChannelUpdated(event.into()))
+            .await
+    }
+
+    async fn publish_user_joined_channel(
+        &self,
+        event: &UserJoinedChannelEvent,
+    ) -> Result<(), EventPublisherError> {
+        self.publish(ChannelEventMessage::ChannelMembershipChanged(event.into()))
+            .await
+    }
+
+    async fn publish_user_left_channel(
+        &self,
+        event: &UserLeftChannelEvent,
+    ) -> Result<(), EventPublisherError> {
+        self.publish(ChannelEventMessage::ChannelMembershipChanged(event.into()))
+            .await
+    }
+
+    async fn publish_channel_deleted(
+        &self,
+        event: &ChannelDeletedEvent,
+    ) -> Result<(), EventPublisherError> {
+        self.publish(ChannelEventMessage::ChannelDeleted(event.into()))
+            .await
+    }
+}
@@ -1,18 +1,30 @@
 use std::sync::Arc;
 
 use futures::StreamExt;
+use rdkafka::consumer::CommitMode;
 use rdkafka::consumer::Consumer;
 use rdkafka::consumer::StreamConsumer;
 use rdkafka::error::KafkaError;
+use rdkafka::message::BorrowedMessage;
 use rdkafka::ClientConfig;
 use rdkafka::Message;
+use serde_json::value::RawValue;
 use thiserror::Error;
 
 use super::messages::ChatEventMessage;
+use super::messages::PollUpdatedMessage;
+use super::messages::RenderedEventMessage;
 use super::topic::TopicSharder;
 use crate::config::Config;
+use crate::config::KafkaCommitMode;
 use crate::domain::channel::models::ChannelId;
+use crate::domain::poll::models::PollId;
+use crate::domain::user::ports::UserReplicaRepository;
+use crate::inbound::websocket::messages::ServerMessage;
+use crate::inbound::websocket::messages::WsPollId;
+use crate::inbound::websocket::messages::WsPollOption;
 use crate::inbound::websocket::registry::ConnectionRegistry;
+use crate::json;
 
 #[derive(Debug, Error)]
 enum MessageProcessingError {
@@ -26,7 +38,7 @@ enum MessageProcessingError {
     Utf8Error(#[from] std::str::Utf8Error),
 
     #[error("Failed to deserialize event: {0}")]
-    DeserializationError(#[from] serde_json::Error),
+    DeserializationError(#[from] json::JsonError),
 
     #[error("Failed to handle event: {0}")]
     HandlingError(String),
@@ -40,6 +52,8 @@ enum MessageProcessingError {
 pub struct KafkaEventConsumer {
     consumer: StreamConsumer,
     connection_manager: Arc<ConnectionRegistry>,
+    commit_mode: KafkaCommitMode,
+    user_replica: Option<Arc<dyn UserReplicaRepository>>,
 }
 
 impl KafkaEventConsumer {
@@ -53,16 +67,20 @@ impl KafkaEventConsumer {
         connection_manager: Arc<ConnectionRegistry>,
     ) -> Result<Self, anyhow::Error> {
         tracing::info!(
-            "Initializing Kafka consumer with brokers: {}, group_id: {}, shards: {}",
+            "Initializing Kafka consumer with brokers: {}, group_id: {}, shards: {}, commit_mode: {:?}",
             &config.kafka.brokers,
             &config.kafka.group_id,
-            &config.kafka.num_shards
+            &config.kafka.num_shards,
+            &config.kafka.commit_mode
         );
 
         let consumer: StreamConsumer = ClientConfig::new()
             .set("bootstrap.servers", &config.kafka.brokers)
             .set("group.id", &config.kafka.group_id)
-            .set("enable.auto.commit", "true")
+            .set(
+                "enable.auto.commit",
+                config.kafka.commit_mode.enable_auto_commit(),
+            )
             .set("auto.commit.interval.ms", "5000")
             .set("auto.offset.reset", "latest") // Only consume new messages
             .set("session.timeout.ms", "30000")
@@ -88,9 +106,22 @@ impl KafkaEventConsumer {
         Ok(Self {
             consumer,
             connection_manager,
+            commit_mode: config.kafka.commit_mode,
+            user_replica: None,
         })
     }
 
+    /// Show authors of broadcast messages with their profile.
+    ///
+    /// Without a replica, clients only get the author's user ID.
+    ///
+    /// # Arguments
+    /// * `user_replica` - Replica the authors' display names and avatars are read from
+    pub fn with_user_replica(mut self, user_replica: Arc<dyn UserReplicaRepository>) -> Self {
+        self.user_replica = Some(user_replica);
+        self
+    }
+
     /// Start consuming events from Kafka
     ///
     /// This is a long-running task that should be spawned in a separate tokio task
@@ -114,14 +145,45 @@ impl KafkaEventConsumer {
     }
 
     /// Process a single Kafka message
+    ///
+    /// In manual commit mode the offset is committed once the event has been handled,
+    /// including events that fail to decode so they are not redelivered forever.
+    /// The broadcast and the commit happen in the same poll, so aborting the consumer
+    /// task cannot leave a broadcast event uncommitted.
     async fn process_message(
         &self,
-        result: Result<rdkafka::message::BorrowedMessage<'_>, KafkaError>,
+        result: Result<BorrowedMessage<'_>, KafkaError>,
     ) -> Result<(), MessageProcessingError> {
         let message = result?;
+        let outcome = self.handle_message(&message).await;
+
+        if self.commit_mode == KafkaCommitMode::Manual {
+            self.consumer.commit_message(&message, CommitMode::Sync)?;
+        }
+
+        outcome
+    }
+
+    /// Decode and handle the payload of a single Kafka message
+    async fn handle_message(
+        &self,
+        message: &BorrowedMessage<'_>,
+    ) -> Result<(), MessageProcessingError> {
         let payload = message.payload().ok_or(MessageProcessingError::NoPayload)?;
         let json_str = std::str::from_utf8(payload)?;
-        let event = serde_json::from_str::<ChatEventMessage>(json_str)?;
+
+        // Sent messages rendered by the producer are forwarded without decoding the event
+        if let Ok(RenderedEventMessage {
+            event_type: "message_sent",
+            channel_id,
+            rendered: Some(rendered),
+        }) = serde_json::from_str::<RenderedEventMessage>(json_str)
+        {
+            self.forward_rendered_message(channel_id, rendered).await;
+            return Ok(());
+        }
+
+        let event = json::from_slice::<ChatEventMessage>(payload)?;
 
         tracing::trace!(
             "Received event: {} ({})",
@@ -161,9 +223,57 @@ impl KafkaEventConsumer {
                 );
                 Ok(())
             }
+            ChatEventMessage::PollUpdated(poll_event) => {
+                self.broadcast_poll_update(poll_event).await;
+                Ok(())
+            }
+            ChatEventMessage::MessageStarChanged(star_event) => {
+                tracing::debug!(
+                    "User {} changed their star on message {}",
+                    star_event.user_id,
+                    star_event.message_id
+                );
+                Ok(())
+            }
         }
     }
 
+    /// Forward a rendered message to all connected clients in the channel (if any)
+    async fn forward_rendered_message(&self, channel_id: &str, rendered: &RawValue) {
+        let channel_id = match ChannelId::from_string(channel_id) {
+            Ok(id) => id,
+            Err(e) => {
+                tracing::error!("Invalid channel_id in event: {}", e);
+                return;
+            }
+        };
+
+        let conn_count = self
+            .connection_manager
+            .get_channel_connection_count(channel_id)
+            .await;
+        if conn_count == 0 {
+            tracing::trace!(
+                "No active connections for channel {} on this instance, skipping broadcast",
+                channel_id
+            );
+            return;
+        }
+
+        tracing::debug!(
+            "Forwarding rendered message to {} connections in channel {} on this instance",
+            conn_count,
+            channel_id
+        );
+
+        self.connection_manager
+            .broadcast_to_channel(
+                channel_id,
+                axum::extract::ws::Message::Text(rendered.get().to_string()),
+            )
+            .await;
+    }
+
     /// Broadcast a message to all connected clients in the channel (if any)
     ///
     /// This method implements client-side filtering:
@@ -198,7 +308,6 @@ impl KafkaEventConsumer {
         // We have connections - broadcast the message using type-safe ServerMessage enum
         use crate::domain::message::models::MessageId;
         use crate::domain::user::models::UserId;
-        use crate::inbound::websocket::messages::ServerMessage;
         use crate::inbound::websocket::messages::WsMessageId;
         use crate::inbound::websocket::messages::WsUserId;
 
@@ -219,12 +328,24 @@ impl KafkaEventConsumer {
             }
         };
 
+        // A missing profile only leaves the message without the author's name
+        let author = match &self.user_replica {
+            Some(user_replica) => user_replica.get(user_id).await.unwrap_or_else(|e| {
+                tracing::warn!("Failed to load author {} of message: {}", user_id, e);
+                None
+            }),
+            None => None,
+        };
+
         // Create type-safe server message
         let server_message = ServerMessage::NewMessage {
             id: WsMessageId::from(message_id),
             user_id: WsUserId::from(user_id),
+            display_name: author.as_ref().map(|user| user.shown_name().to_string()),
+            avatar_url: author.and_then(|user| user.avatar_url),
             content: event.content,
             timestamp: event.timestamp,
+            language: event.language,
         };
 
         let ws_message = match serde_json::to_string(&server_message) {
@@ -246,4 +367,59 @@ impl KafkaEventConsumer {
             .broadcast_to_channel(channel_id, ws_message)
             .await;
     }
+
+    /// Broadcast new poll results to all connected clients in the channel (if any)
+    async fn broadcast_poll_update(&self, event: PollUpdatedMessage) {
+        let channel_id = match ChannelId::from_string(&event.channel_id) {
+            Ok(id) => id,
+            Err(e) => {
+                tracing::error!("Invalid channel_id in event: {}", e);
+                return;
+            }
+        };
+        let poll_id = match PollId::from_string(&event.poll_id) {
+            Ok(id) => id,
+            Err(e) => {
+                tracing::error!("Invalid poll_id in event: {}", e);
+                return;
+            }
+        };
+
+        if self
+            .connection_manager
+            .get_channel_connection_count(channel_id)
+            .await
+            == 0
+        {
+            return;
+        }
+
+        let server_message = ServerMessage::PollUpdated {
+            poll_id: WsPollId::from(poll_id),
+            question: event.question,
+            total_votes: event.options.iter().map(|option| option.votes).sum(),
+            options: event
+                .options
+                .into_iter()
+                .map(|option| WsPollOption {
+                    text: option.text,
+                    votes: option.votes,
+                })
+                .collect(),
+            closes_at: event.closes_at,
+            closed: event.closed,
+        };
+
+        let ws_message = match serde_json::to_string(&server_message) {
+            Ok(json) => axum::extract::ws::Message::Text(json),
+            Err(e) => {
+                tracing::error!("Failed to serialize server message: {}", e);
+                return;
+            }
+        };
+
+        self.connection_manager
+            .broadcast_to_channel(channel_id, ws_message)
+            .await;
+    }
 }
@@ -13,12 +13,19 @@ use crate::domain::errors::EventPublisherError;
 use crate::domain::message::events::MessageDeletedEvent;
 use crate::domain::message::events::MessageSentEvent;
 use crate::domain::message::ports::MessageEventPublisher;
+use crate::domain::user::ports::UserReplicaRepository;
+use crate::inbound::websocket::messages::ServerMessage;
+use crate::inbound::websocket::messages::WsMessageId;
+use crate::inbound::websocket::messages::WsUserId;
 
 /// Kafka implementation of MessageEventPublisher.
 ///
 /// Publishes message domain events to Kafka topics using the event producer.
+/// Sent messages carry their rendered WebSocket payload, which consumers
+/// forward as is.
 pub struct KafkaMessageEventPublisher {
     producer: Arc<KafkaEventProducer>,
+    user_replica: Option<Arc<dyn UserReplicaRepository>>,
 }
 
 impl KafkaMessageEventPublisher {
@@ -30,7 +37,51 @@ impl KafkaMessageEventPublisher {
     /// # Returns
     /// Configured publisher instance
     pub fn new(producer: Arc<KafkaEventProducer>) -> Self {
-        Self { producer }
+        Self {
+            producer,
+            user_replica: None,
+        }
+    }
+
+    /// Render sent messages with the profile of their author.
+    ///
+    /// Without a replica, rendered payloads only carry the author's user ID.
+    ///
+    /// # Arguments
+    /// * `user_replica` - Replica the authors' display names and avatars are read from
+    pub fn with_user_replica(mut self, user_replica: Arc<dyn UserReplicaRepository>) -> Self {
+        self.user_replica = Some(user_replica);
+        self
+    }
+
+    /// Render the WebSocket payload of a sent message.
+    ///
+    /// # Returns
+    /// Payload clients receive, None if it could not be rendered and consumers
+    /// should render it themselves
+    async fn render_message_sent(&self, event: &MessageSentEvent) -> Option<serde_json::Value> {
+        // A missing profile only leaves the message without the author's name
+        let author = match &self.user_replica {
+            Some(user_replica) => user_replica.get(event.user_id).await.unwrap_or_else(|e| {
+                tracing::warn!("Failed to load author {} of message: {}", event.user_id, e);
+                None
+            }),
+            None => None,
+        };
+
+        let server_message = ServerMessage::NewMessage {
+            id: WsMessageId::from(event.message_id),
+            user_id: WsUserId::from(event.user_id),
+            display_name: author.as_ref().map(|user| user.shown_name().to_string()),
+            avatar_url: author.and_then(|user| user.avatar_url),
+            content: event.content.clone(),
+            timestamp: event.timestamp,
+            language: event.language.clone(),
+        };
+
+        serde_json::to_value(&server_message)
+            .inspect_err(|e| tracing::warn!("Failed to render message {}: {}", event.message_id, e))
+            .ok()
     }
 }
 
@@ -40,7 +91,10 @@ impl MessageEventPublisher for KafkaMessageEventPublisher {
         &self,
         event: &MessageSentEvent,
     ) -> Result<(), EventPublisherError> {
-        let message = MessageSentMessage::from(event);
+        let message = MessageSentMessage {
+            rendered: self.render_message_sent(event).await,
+            ..MessageSentMessage::from(event)
+        };
         let envelope = ChatEventMessage::MessageSent(message);
 
         self.producer
@@ -6,17 +6,30 @@ use chrono::DateTime;
 use chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::value::RawValue;
 
 use crate::domain::channel::events::ChannelCreatedEvent;
 use crate::domain::channel::events::ChannelDeletedEvent;
+use crate::domain::channel::events::ChannelUpdatedEvent;
 use crate::domain::channel::events::UserJoinedChannelEvent;
 use crate::domain::channel::events::UserLeftChannelEvent;
+use crate::domain::channel::models::ChannelId;
 use crate::domain::message::events::MessageDeletedEvent;
 use crate::domain::message::events::MessageSentEvent;
+use crate::domain::message::models::MessageId;
+use crate::domain::poll::events::PollUpdatedEvent;
+use crate::domain::star::events::MessageStarChangedEvent;
+use crate::domain::user::events::UserAccountLockedEvent;
 use crate::domain::user::events::UserCreatedEvent;
+use crate::domain::user::events::UserDeactivatedEvent;
 use crate::domain::user::events::UserDeletedEvent;
 use crate::domain::user::events::UserEvent;
+use crate::domain::user::events::UserPasswordChangedEvent;
+use crate::domain::user::events::UserPasswordResetEvent;
+use crate::domain::user::events::UserPasswordResetRequestedEvent;
+use crate::domain::user::events::UserReactivatedEvent;
 use crate::domain::user::events::UserUpdatedEvent;
+use crate::domain::user::models::UserId;
 
 /// Serializable envelope for all chat-service events
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +39,8 @@ pub enum ChatEventMessage {
     ChannelCreated(ChannelCreatedMessage),
     UserJoinedChannel(UserJoinedChannelMessage),
     UserLeftChannel(UserLeftChannelMessage),
+    PollUpdated(PollUpdatedMessage),
+    MessageStarChanged(MessageStarChangedMessage),
 }
 
 impl ChatEventMessage {
@@ -35,6 +50,8 @@ impl ChatEventMessage {
             ChatEventMessage::ChannelCreated(e) => &e.event_id,
             ChatEventMessage::UserJoinedChannel(e) => &e.event_id,
             ChatEventMessage::UserLeftChannel(e) => &e.event_id,
+            ChatEventMessage::PollUpdated(e) => &e.event_id,
+            ChatEventMessage::MessageStarChanged(e) => &e.event_id,
         }
     }
 
@@ -44,6 +61,8 @@ impl ChatEventMessage {
             ChatEventMessage::ChannelCreated(_) => "channel_created",
             ChatEventMessage::UserJoinedChannel(_) => "user_joined_channel",
             ChatEventMessage::UserLeftChannel(_) => "user_left_channel",
+            ChatEventMessage::PollUpdated(_) => "poll_updated",
+            ChatEventMessage::MessageStarChanged(_) => "message_star_changed",
         }
     }
 }
@@ -57,6 +76,12 @@ pub struct MessageSentMessage {
     pub user_id: String,
     pub content: String,
     pub timestamp: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// WebSocket payload of the message, rendered once by the producer so
+    /// consumers can forward it to clients without decoding the event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rendered: Option<serde_json::Value>,
 }
 
 impl From<&MessageSentEvent> for MessageSentMessage {
@@ -68,10 +93,23 @@ impl From<&MessageSentEvent> for MessageSentMessage {
             user_id: event.user_id.to_string(),
             content: event.content.clone(),
             timestamp: event.timestamp,
+            language: event.language.clone(),
+            rendered: None,
         }
     }
 }
 
+/// Fields of a chat event needed to forward its rendered WebSocket payload.
+///
+/// Borrowed from the Kafka record: the rest of the event is skipped, not decoded.
+#[derive(Debug, Deserialize)]
+pub struct RenderedEventMessage<'a> {
+    pub event_type: &'a str,
+    pub channel_id: &'a str,
+    #[serde(borrow, default)]
+    pub rendered: Option<&'a RawValue>,
+}
+
 /// Serializable message for MessageDeleted event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageDeletedMessage {
@@ -92,6 +130,87 @@ impl From<&MessageDeletedEvent> for MessageDeletedMessage {
     }
 }
 
+/// Serializable message for PollUpdated event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollUpdatedMessage {
+    pub event_id: String,
+    pub poll_id: String,
+    pub channel_id: String,
+    pub question: String,
+    pub options: Vec<PollOptionTallyMessage>,
+    pub closes_at: DateTime<Utc>,
+    pub closed: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Serializable tally of one poll option
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollOptionTallyMessage {
+    pub text: String,
+    pub votes: u64,
+}
+
+impl From<&PollUpdatedEvent> for PollUpdatedMessage {
+    fn from(event: &PollUpdatedEvent) -> Self {
+        Self {
+            event_id: event.event_id.clone(),
+            poll_id: event.poll_id.to_string(),
+            channel_id: event.channel_id.to_string(),
+            question: event.question.clone(),
+            options: event
+                .tallies
+                .iter()
+                .map(|tally| PollOptionTallyMessage {
+                    text: tally.text.clone(),
+                    votes: tally.votes,
+                })
+                .collect(),
+            closes_at: event.closes_at,
+            closed: event.closed,
+            timestamp: event.timestamp,
+        }
+    }
+}
+
+/// Serializable message for MessageStarChanged event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageStarChangedMessage {
+    pub event_id: String,
+    pub channel_id: String,
+    pub message_id: String,
+    pub user_id: String,
+    pub starred: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl From<&MessageStarChangedEvent> for MessageStarChangedMessage {
+    fn from(event: &MessageStarChangedEvent) -> Self {
+        Self {
+            event_id: event.event_id.clone(),
+            channel_id: event.channel_id.to_string(),
+            message_id: event.message_id.to_string(),
+            user_id: event.user_id.to_string(),
+            starred: event.starred,
+            timestamp: event.timestamp,
+        }
+    }
+}
+
+impl TryFrom<MessageStarChangedMessage> for MessageStarChangedEvent {
+    type Error = String;
+
+    fn try_from(message: MessageStarChangedMessage) -> Result<Self, Self::Error> {
+        Ok(MessageStarChangedEvent {
+            event_id: message.event_id,
+            channel_id: ChannelId::from_string(&message.channel_id).map_err(|e| e.to_string())?,
+            message_id: MessageId::from_string(&message.message_id).map_err(|e| e.to_string())?,
+            user_id: UserId::from_string(&message.user_id).map_err(|e| e.to_string())?,
+            starred: message.starred,
+            timestamp: message.timestamp,
+        })
+    }
+}
+
 /// Serializable message for ChannelCreated event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChannelCreatedMessage {
@@ -99,6 +218,8 @@ pub struct ChannelCreatedMessage {
     pub channel_id: String,
     pub channel_type: String,
     pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
     pub created_by: String,
     pub timestamp: DateTime<Utc>,
 }
@@ -110,12 +231,35 @@ impl From<&ChannelCreatedEvent> for ChannelCreatedMessage {
             channel_id: event.channel_id.to_string(),
             channel_type: event.channel_type.clone(),
             name: event.name.clone(),
+            description: event.description.clone(),
             created_by: event.created_by.to_string(),
             timestamp: event.timestamp,
         }
     }
 }
 
+/// Serializable message for ChannelUpdated event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelUpdatedMessage {
+    pub event_id: String,
+    pub channel_id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl From<&ChannelUpdatedEvent> for ChannelUpdatedMessage {
+    fn from(event: &ChannelUpdatedEvent) -> Self {
+        Self {
+            event_id: event.event_id.clone(),
+            channel_id: event.channel_id.to_string(),
+            name: event.name.clone(),
+            description: event.description.clone(),
+            timestamp: event.timestamp,
+        }
+    }
+}
+
 /// Serializable message for ChannelDeleted event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChannelDeletedMessage {
@@ -174,6 +318,81 @@ impl From<&UserLeftChannelEvent> for UserLeftChannelMessage {
     }
 }
 
+/// Serializable envelope for the `chat.channels` change data capture topic.
+///
+/// Every change to a channel is keyed by channel ID, so consumers see the
+/// changes of one channel in order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event_type", rename_all = "snake_case")]
+pub enum ChannelEventMessage {
+    ChannelCreated(ChannelCreatedMessage),
+    ChannelUpdated(ChannelUpdatedMessage),
+    ChannelDeleted(ChannelDeletedMessage),
+    ChannelMembershipChanged(ChannelMembershipChangedMessage),
+}
+
+impl ChannelEventMessage {
+    pub fn event_id(&self) -> &str {
+        match self {
+            ChannelEventMessage::ChannelCreated(e) => &e.event_id,
+            ChannelEventMessage::ChannelUpdated(e) => &e.event_id,
+            ChannelEventMessage::ChannelDeleted(e) => &e.event_id,
+            ChannelEventMessage::ChannelMembershipChanged(e) => &e.event_id,
+        }
+    }
+
+    pub fn channel_id(&self) -> &str {
+        match self {
+            ChannelEventMessage::ChannelCreated(e) => &e.channel_id,
+            ChannelEventMessage::ChannelUpdated(e) => &e.channel_id,
+            ChannelEventMessage::ChannelDeleted(e) => &e.channel_id,
+            ChannelEventMessage::ChannelMembershipChanged(e) => &e.channel_id,
+        }
+    }
+}
+
+/// Direction of a channel membership change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MembershipChange {
+    Joined,
+    Left,
+}
+
+/// Serializable message for UserJoinedChannel and UserLeftChannel events on the CDC topic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelMembershipChangedMessage {
+    pub event_id: String,
+    pub channel_id: String,
+    pub user_id: String,
+    pub change: MembershipChange,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl From<&UserJoinedChannelEvent> for ChannelMembershipChangedMessage {
+    fn from(event: &UserJoinedChannelEvent) -> Self {
+        Self {
+            event_id: event.event_id.clone(),
+            channel_id: event.channel_id.to_string(),
+            user_id: event.user_id.to_string(),
+            change: MembershipChange::Joined,
+            timestamp: event.timestamp,
+        }
+    }
+}
+
+impl From<&UserLeftChannelEvent> for ChannelMembershipChangedMessage {
+    fn from(event: &UserLeftChannelEvent) -> Self {
+        Self {
+            event_id: event.event_id.clone(),
+            channel_id: event.channel_id.to_string(),
+            user_id: event.user_id.to_string(),
+            change: MembershipChange::Left,
+            timestamp: event.timestamp,
+        }
+    }
+}
+
 /// Serializable envelope for user-service events
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "event_type", rename_all = "snake_case")]
@@ -181,6 +400,12 @@ pub enum UserEventMessage {
     UserCreated(UserCreatedMessage),
     UserUpdated(UserUpdatedMessage),
     UserDeleted(UserDeletedMessage),
+    UserDeactivated(UserDeactivatedMessage),
+    UserReactivated(UserReactivatedMessage),
+    UserPasswordChanged(UserPasswordChangedMessage),
+    UserPasswordResetRequested(UserPasswordResetRequestedMessage),
+    UserPasswordReset(UserPasswordResetMessage),
+    UserAccountLocked(UserAccountLockedMessage),
 }
 
 impl TryFrom<UserEventMessage> for UserEvent {
@@ -200,6 +425,8 @@ impl TryFrom<UserEventMessage> for UserEvent {
                 user_id: m.user_id,
                 username: m.username,
                 email: m.email,
+                display_name: m.display_name,
+                avatar_url: m.avatar_url,
                 updated_at: m.updated_at,
             })),
             UserEventMessage::UserDeleted(m) => Ok(UserEvent::UserDeleted(UserDeletedEvent {
@@ -207,6 +434,51 @@ impl TryFrom<UserEventMessage> for UserEvent {
                 user_id: m.user_id,
                 deleted_at: m.deleted_at,
             })),
+            UserEventMessage::UserDeactivated(m) => {
+                Ok(UserEvent::UserDeactivated(UserDeactivatedEvent {
+                    event_id: m.event_id,
+                    user_id: m.user_id,
+                    deactivated_at: m.deactivated_at,
+                }))
+            }
+            UserEventMessage::UserReactivated(m) => {
+                Ok(UserEvent::UserReactivated(UserReactivatedEvent {
+                    event_id: m.event_id,
+                    user_id: m.user_id,
+                    reactivated_at: m.reactivated_at,
+                }))
+            }
+            UserEventMessage::UserPasswordChanged(m) => {
+                Ok(UserEvent::UserPasswordChanged(UserPasswordChangedEvent {
+                    event_id: m.event_id,
+                    user_id: m.user_id,
+                    changed_at: m.changed_at,
+                }))
+            }
+            UserEventMessage::UserPasswordResetRequested(m) => Ok(
+                UserEvent::UserPasswordResetRequested(UserPasswordResetRequestedEvent {
+                    event_id: m.event_id,
+                    user_id: m.user_id,
+                    requested_at: m.requested_at,
+                    expires_at: m.expires_at,
+                }),
+            ),
+            UserEventMessage::UserPasswordReset(m) => {
+                Ok(UserEvent::UserPasswordReset(UserPasswordResetEvent {
+                    event_id: m.event_id,
+                    user_id: m.user_id,
+                    reset_at: m.reset_at,
+                }))
+            }
+            UserEventMessage::UserAccountLocked(m) => {
+                Ok(UserEvent::UserAccountLocked(UserAccountLockedEvent {
+                    event_id: m.event_id,
+                    user_id: m.user_id,
+                    failed_attempts: m.failed_attempts,
+                    locked_at: m.locked_at,
+                    locked_until: m.locked_until,
+                }))
+            }
         }
     }
 }
@@ -228,6 +500,11 @@ pub struct UserUpdatedMessage {
     pub user_id: String,
     pub username: String,
     pub email: String,
+    /// Missing in events published before profiles existed
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -238,3 +515,54 @@ pub struct UserDeletedMessage {
     pub user_id: String,
     pub deleted_at: DateTime<Utc>,
 }
+
+/// Serializable message for UserDeactivated event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserDeactivatedMessage {
+    pub event_id: String,
+    pub user_id: String,
+    pub deactivated_at: DateTime<Utc>,
+}
+
+/// Serializable message for UserReactivated event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserReactivatedMessage {
+    pub event_id: String,
+    pub user_id: String,
+    pub reactivated_at: DateTime<Utc>,
+}
+
+/// Serializable message for UserPasswordChanged event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPasswordChangedMessage {
+    pub event_id: String,
+    pub user_id: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Serializable message for UserPasswordResetRequested event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPasswordResetRequestedMessage {
+    pub event_id: String,
+    pub user_id: String,
+    pub requested_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Serializable message for UserPasswordReset event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPasswordResetMessage {
+    pub event_id: String,
+    pub user_id: String,
+    pub reset_at: DateTime<Utc>,
+}
+
+/// Serializable message for UserAccountLocked event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAccountLockedMessage {
+    pub event_id: String,
+    pub user_id: String,
+    pub failed_attempts: u32,
+    pub locked_at: DateTime<Utc>,
+    pub locked_until: DateTime<Utc>,
+}
@@ -1,6 +1,12 @@
+pub mod channel_publisher;
 pub mod consumer;
 pub mod message_publisher;
 pub mod messages;
+pub mod poll_publisher;
 pub mod producer;
+pub mod replicator;
+pub mod star_consumer;
+pub mod star_publisher;
 pub mod topic;
 pub mod user_consumer;
+pub mod user_protobuf;
@@ -0,0 +1,49 @@
+/// Kafka adapter implementing PollEventPublisher port.
+///
+/// Publishes poll results to the sharded chat message topics, so every
+/// instance forwards them to the channel's WebSocket connections.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::messages::ChatEventMessage;
+use super::messages::PollUpdatedMessage;
+use super::producer::KafkaEventProducer;
+use crate::domain::errors::EventPublisherError;
+use crate::domain::poll::events::PollUpdatedEvent;
+use crate::domain::poll::ports::PollEventPublisher;
+
+/// Kafka implementation of PollEventPublisher.
+///
+/// Poll updates are keyed by poll ID, so the updates of a poll stay ordered.
+pub struct KafkaPollEventPublisher {
+    producer: Arc<KafkaEventProducer>,
+}
+
+impl KafkaPollEventPublisher {
+    /// Create a new Kafka poll event publisher.
+    ///
+    /// # Arguments
+    /// * `producer` - Kafka event producer for publishing events
+    ///
+    /// # Returns
+    /// Configured publisher instance
+    pub fn new(producer: Arc<KafkaEventProducer>) -> Self {
+        Self { producer }
+    }
+}
+
+#[async_trait]
+impl PollEventPublisher for KafkaPollEventPublisher {
+    async fn publish_poll_updated(
+        &self,
+        event: &PollUpdatedEvent,
+    ) -> Result<(), EventPublisherError> {
+        let envelope = ChatEventMessage::PollUpdated(PollUpdatedMessage::from(event));
+
+        self.producer
+            .publish_event(event.channel_id, &event.poll_id.to_string(), &envelope)
+            .await
+            .map_err(|e| EventPublisherError::PublishFailed(e.to_string()))
+    }
+}
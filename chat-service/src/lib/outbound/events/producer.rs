@@ -2,12 +2,16 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use rdkafka::config::ClientConfig;
+use rdkafka::message::Header;
+use rdkafka::message::OwnedHeaders;
 use rdkafka::producer::FutureProducer;
 use rdkafka::producer::FutureRecord;
 use rdkafka::util::Timeout;
 use serde::Serialize;
 use thiserror::Error;
+use tracing::instrument;
 
+use super::replicator::ORIGIN_REGION_HEADER;
 use super::topic::TopicSharder;
 use crate::config::Config;
 use crate::domain::channel::models::ChannelId;
@@ -25,6 +29,7 @@ pub struct KafkaEventProducer {
     producer: FutureProducer,
     timeout: Duration,
     sharder: Arc<TopicSharder>,
+    region: Option<String>,
 }
 
 impl KafkaEventProducer {
@@ -59,6 +64,11 @@ impl KafkaEventProducer {
             producer,
             timeout: Duration::from_secs(5),
             sharder,
+            region: config
+                .kafka
+                .replication
+                .as_ref()
+                .map(|replication| replication.region.clone()),
         })
     }
 
@@ -72,9 +82,6 @@ impl KafkaEventProducer {
         key: &str,
         event: &T,
     ) -> Result<(), KafkaProducerError> {
-        let payload = serde_json::to_string(event)
-            .map_err(|e| KafkaProducerError::SerializationError(e.to_string()))?;
-
         let topic = self.sharder.get_shard_for_channel(channel_id);
 
         tracing::debug!(
@@ -84,15 +91,7 @@ impl KafkaEventProducer {
             key
         );
 
-        let record = FutureRecord::to(&topic).key(key).payload(&payload);
-
-        self.producer
-            .send(record, Timeout::After(self.timeout))
-            .await
-            .map_err(|(err, _)| {
-                tracing::error!("Failed to send message to Kafka: {}", err);
-                KafkaProducerError::SendError(err.to_string())
-            })?;
+        self.publish_to_topic(&topic, key, event).await?;
 
         tracing::debug!(
             "Event published successfully to topic '{}' for channel {}",
@@ -101,4 +100,39 @@ impl KafkaEventProducer {
         );
         Ok(())
     }
+
+    /// Publish a domain event to a fixed, unsharded topic
+    ///
+    /// Ordering is guaranteed per key, since Kafka assigns equal keys to the same partition.
+    /// With replication enabled the record carries the origin region header.
+    #[instrument(
+        name = "kafka_producer.publish",
+        skip_all,
+        fields(messaging.system = "kafka", messaging.destination = %topic),
+        err(level = "warn")
+    )]
+    pub async fn publish_to_topic<T: Serialize>(
+        &self,
+        topic: &str,
+        key: &str,
+        event: &T,
+    ) -> Result<(), KafkaProducerError> {
+        let payload = serde_json::to_string(event)
+            .map_err(|e| KafkaProducerError::SerializationError(e.to_string()))?;
+
+        let mut record = FutureRecord::to(topic).key(key).payload(&payload);
+        if let Some(region) = &self.region {
+            record = record.headers(OwnedHeaders::new().insert(Header {
+                key: ORIGIN_REGION_HEADER,
+                value: Some(region),
+            }));
+        }
+
+        self.producer
+            .send(record, Timeout::After(self.timeout))
+            .await
+            .map_err(|(err, _)| KafkaProducerError::SendError(err.to_string()))?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,194 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use rdkafka::consumer::CommitMode;
+use rdkafka::consumer::Consumer;
+use rdkafka::consumer::StreamConsumer;
+use rdkafka::error::KafkaError;
+use rdkafka::message::BorrowedMessage;
+use rdkafka::message::Header;
+use rdkafka::message::Headers;
+use rdkafka::message::OwnedHeaders;
+use rdkafka::producer::FutureProducer;
+use rdkafka::producer::FutureRecord;
+use rdkafka::util::Timeout;
+use rdkafka::ClientConfig;
+use rdkafka::Message;
+
+use super::topic::TopicSharder;
+use crate::config::Config;
+use crate::config::ReplicationConfig;
+
+/// Header naming the region an event was first produced in.
+pub const ORIGIN_REGION_HEADER: &str = "origin-region";
+
+/// Mirrors local message and user events to a remote region's Kafka cluster.
+///
+/// Only events that originated in this region are forwarded: events that
+/// arrived through replication carry another region's origin header and are
+/// skipped, so two regions mirroring to each other never loop. Offsets are
+/// committed after the remote write, so events are mirrored at least once.
+pub struct KafkaEventReplicator {
+    consumer: StreamConsumer,
+    producer: FutureProducer,
+    region: String,
+    timeout: Duration,
+}
+
+impl KafkaEventReplicator {
+    /// Create a replicator subscribed to the message shards and user events topic.
+    ///
+    /// # Arguments
+    /// * `config` - Application configuration
+    /// * `replication` - Replication settings of this region
+    ///
+    /// # Errors
+    /// Returns error if a Kafka client cannot be created or subscribed
+    pub fn new(config: &Config, replication: &ReplicationConfig) -> Result<Self, anyhow::Error> {
+        tracing::info!(
+            region = %replication.region,
+            remote_brokers = %replication.remote_brokers,
+            group_id = %replication.group_id,
+            "Initializing Kafka event replicator"
+        );
+
+        let consumer: StreamConsumer = ClientConfig::new()
+            .set("bootstrap.servers", &config.kafka.brokers)
+            .set("group.id", &replication.group_id)
+            .set("enable.auto.commit", "false")
+            .set("auto.offset.reset", "latest")
+            .set("session.timeout.ms", "30000")
+            .set("enable.partition.eof", "false")
+            .create()?;
+
+        let producer: FutureProducer = ClientConfig::new()
+            .set("bootstrap.servers", &replication.remote_brokers)
+            .set("message.timeout.ms", "30000")
+            .set("compression.type", "gzip")
+            .create()?;
+
+        let sharder = TopicSharder::new(config.kafka.num_shards, "chat.messages")?;
+        let mut topics = sharder.get_all_shards();
+        topics.push(config.kafka.user_events.topic.clone());
+
+        let topic_refs: Vec<&str> = topics.iter().map(|s| s.as_str()).collect();
+        consumer.subscribe(&topic_refs)?;
+
+        Ok(Self {
+            consumer,
+            producer,
+            region: replication.region.clone(),
+            timeout: Duration::from_secs(30),
+        })
+    }
+
+    /// Start mirroring events
+    ///
+    /// This is a long-running task that should be spawned in a separate tokio task
+    pub async fn start_replicating(self) {
+        tracing::info!(region = %self.region, "Starting Kafka event replicator loop");
+
+        let mut message_stream = self.consumer.stream();
+
+        while let Some(result) = message_stream.next().await {
+            if let Err(e) = self.replicate(result).await {
+                tracing::error!("Error replicating event: {}", e);
+                // Leave the offset uncommitted and back off; the event is
+                // redelivered after a rebalance or restart
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            }
+        }
+
+        tracing::warn!("Kafka event replicator loop ended");
+    }
+
+    async fn replicate(
+        &self,
+        result: Result<BorrowedMessage<'_>, KafkaError>,
+    ) -> Result<(), KafkaError> {
+        let message = result?;
+
+        let origin = origin_region(&message);
+        if should_replicate(origin.as_deref(), &self.region) {
+            self.forward(&message).await?;
+        } else {
+            tracing::trace!(
+                topic = message.topic(),
+                origin = ?origin,
+                "Skipping replicated event"
+            );
+        }
+
+        self.consumer.commit_message(&message, CommitMode::Async)
+    }
+
+    async fn forward(&self, message: &BorrowedMessage<'_>) -> Result<(), KafkaError> {
+        // Events produced before replication was enabled (or by services that
+        // do not tag them) are local by definition
+        let headers = match message.headers() {
+            Some(headers) if origin_region(message).is_some() => headers.detach(),
+            Some(headers) => headers.detach().insert(Header {
+                key: ORIGIN_REGION_HEADER,
+                value: Some(&self.region),
+            }),
+            None => OwnedHeaders::new().insert(Header {
+                key: ORIGIN_REGION_HEADER,
+                value: Some(&self.region),
+            }),
+        };
+
+        let mut record: FutureRecord<'_, [u8], [u8]> =
+            FutureRecord::to(message.topic()).headers(headers);
+        if let Some(key) = message.key() {
+            record = record.key(key);
+        }
+        if let Some(payload) = message.payload() {
+            record = record.payload(payload);
+        }
+
+        self.producer
+            .send(record, Timeout::After(self.timeout))
+            .await
+            .map(|_| ())
+            .map_err(|(err, _)| err)
+    }
+}
+
+/// Origin region of a consumed event, if tagged.
+fn origin_region(message: &BorrowedMessage<'_>) -> Option<String> {
+    message.headers().and_then(|headers| {
+        headers
+            .iter()
+            .find(|header| header.key == ORIGIN_REGION_HEADER)
+            .and_then(|header| header.value)
+            .map(|value| String::from_utf8_lossy(value).into_owned())
+    })
+}
+
+/// Whether an event should be mirrored to the remote region.
+///
+/// # Arguments
+/// * `origin` - Origin region header of the event, if any
+/// * `local_region` - Region this replicator runs in
+///
+/// # Returns
+/// `true` for untagged events and events that originated locally
+fn should_replicate(origin: Option<&str>, local_region: &str) -> bool {
+    origin.is_none_or(|origin| origin == local_region)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_replicate_local_and_untagged_events() {
+        assert!(should_replicate(None, "eu-west"));
+        assert!(should_replicate(Some("eu-west"), "eu-west"));
+    }
+
+    #[test]
+    fn test_should_not_replicate_events_from_other_regions() {
+        assert!(!should_replicate(Some("us-east"), "eu-west"));
+    }
+}
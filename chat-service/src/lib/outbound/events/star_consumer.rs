@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use futures::StreamExt;
+use rdkafka::consumer::CommitMode;
+use rdkafka::consumer::Consumer;
+use rdkafka::consumer::StreamConsumer;
+use rdkafka::error::KafkaError;
+use rdkafka::message::BorrowedMessage;
+use rdkafka::ClientConfig;
+use rdkafka::Message;
+use thiserror::Error;
+
+use super::messages::ChatEventMessage;
+use super::topic::TopicSharder;
+use crate::config::Config;
+use crate::config::KafkaCommitMode;
+use crate::config::StarsConfig;
+use crate::domain::star::events::MessageStarChangedEvent;
+use crate::domain::star::ports::StarServicePort;
+use crate::json;
+
+#[derive(Debug, Error)]
+enum MessageProcessingError {
+    #[error("Kafka consumer error: {0}")]
+    KafkaError(#[from] KafkaError),
+
+    #[error("Message has no payload")]
+    NoPayload,
+
+    #[error("Failed to deserialize event: {0}")]
+    DeserializationError(#[from] json::JsonError),
+
+    #[error("Failed to handle event: {0}")]
+    HandlingError(String),
+}
+
+/// Kafka consumer counting star changes into the star rollup
+///
+/// Reads every shard of the chat message topics in its own consumer group, so
+/// each star change is counted by one instance only. Other chat events are skipped.
+pub struct StarRollupConsumer<SS: StarServicePort> {
+    consumer: StreamConsumer,
+    star_service: Arc<SS>,
+    commit_mode: KafkaCommitMode,
+}
+
+impl<SS: StarServicePort> StarRollupConsumer<SS> {
+    /// Create a new star rollup consumer
+    ///
+    /// # Arguments
+    /// * `config` - Application configuration
+    /// * `stars` - Star settings naming the rollup consumer group
+    /// * `star_service` - Star service counting the changes
+    pub fn new(
+        config: &Config,
+        stars: &StarsConfig,
+        star_service: Arc<SS>,
+    ) -> Result<Self, anyhow::Error> {
+        tracing::info!(
+            "Initializing star rollup consumer: brokers={}, group_id={}, shards={}",
+            &config.kafka.brokers,
+            &stars.group_id,
+            &config.kafka.num_shards
+        );
+
+        let consumer: StreamConsumer = ClientConfig::new()
+            .set("bootstrap.servers", &config.kafka.brokers)
+            .set("group.id", &stars.group_id)
+            .set(
+                "enable.auto.commit",
+                config.kafka.commit_mode.enable_auto_commit(),
+            )
+            .set("auto.commit.interval.ms", "5000")
+            .set("auto.offset.reset", "earliest") // Count every star change retained
+            .set("session.timeout.ms", "30000")
+            .set("enable.partition.eof", "false")
+            .create()?;
+
+        let sharder = TopicSharder::new(config.kafka.num_shards, "chat.messages")?;
+        let topics = sharder.get_all_shards();
+        let topic_refs: Vec<&str> = topics.iter().map(|s| s.as_str()).collect();
+        consumer.subscribe(&topic_refs)?;
+
+        tracing::info!(
+            "Star rollup consumer initialized and subscribed to {} topic shards",
+            topics.len()
+        );
+
+        Ok(Self {
+            consumer,
+            star_service,
+            commit_mode: config.kafka.commit_mode,
+        })
+    }
+
+    /// Start counting star changes from Kafka
+    ///
+    /// This is a long-running task that should be spawned in a separate tokio task
+    pub async fn start_consuming(self) {
+        tracing::info!("Starting star rollup consumer loop");
+
+        let mut message_stream = self.consumer.stream();
+
+        while let Some(result) = message_stream.next().await {
+            if let Err(error) = self.process_message(result).await {
+                tracing::error!("Error processing star change: {}", error);
+
+                // Add backoff on Kafka errors to avoid tight error loops
+                if matches!(error, MessageProcessingError::KafkaError(_)) {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+                }
+            }
+        }
+
+        tracing::warn!("Star rollup consumer loop ended");
+    }
+
+    /// Process a single Kafka message
+    ///
+    /// In manual commit mode the offset is committed once the event has been handled.
+    async fn process_message(
+        &self,
+        result: Result<BorrowedMessage<'_>, KafkaError>,
+    ) -> Result<(), MessageProcessingError> {
+        let message = result?;
+        let outcome = self.handle_message(&message).await;
+
+        if self.commit_mode == KafkaCommitMode::Manual {
+            self.consumer.commit_message(&message, CommitMode::Sync)?;
+        }
+
+        outcome
+    }
+
+    /// Decode a chat event and count it if it is a star change
+    async fn handle_message(
+        &self,
+        message: &BorrowedMessage<'_>,
+    ) -> Result<(), MessageProcessingError> {
+        let payload = message.payload().ok_or(MessageProcessingError::NoPayload)?;
+
+        let ChatEventMessage::MessageStarChanged(star_message) =
+            json::from_slice::<ChatEventMessage>(payload)?
+        else {
+            return Ok(());
+        };
+
+        let event = MessageStarChangedEvent::try_from(star_message)
+            .map_err(MessageProcessingError::HandlingError)?;
+
+        self.star_service
+            .apply_star_change(&event)
+            .await
+            .map_err(|e| MessageProcessingError::HandlingError(e.to_string()))?;
+
+        Ok(())
+    }
+}
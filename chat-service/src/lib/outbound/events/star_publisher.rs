@@ -0,0 +1,49 @@
+/// Kafka adapter implementing StarEventPublisher port.
+///
+/// Publishes star changes to the sharded chat message topics, where the star
+/// rollup consumer counts them.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::messages::ChatEventMessage;
+use super::messages::MessageStarChangedMessage;
+use super::producer::KafkaEventProducer;
+use crate::domain::errors::EventPublisherError;
+use crate::domain::star::events::MessageStarChangedEvent;
+use crate::domain::star::ports::StarEventPublisher;
+
+/// Kafka implementation of StarEventPublisher.
+///
+/// Star changes are keyed by message ID, so the changes of a message stay ordered.
+pub struct KafkaStarEventPublisher {
+    producer: Arc<KafkaEventProducer>,
+}
+
+impl KafkaStarEventPublisher {
+    /// Create a new Kafka star event publisher.
+    ///
+    /// # Arguments
+    /// * `producer` - Kafka event producer for publishing events
+    ///
+    /// # Returns
+    /// Configured publisher instance
+    pub fn new(producer: Arc<KafkaEventProducer>) -> Self {
+        Self { producer }
+    }
+}
+
+#[async_trait]
+impl StarEventPublisher for KafkaStarEventPublisher {
+    async fn publish_star_changed(
+        &self,
+        event: &MessageStarChangedEvent,
+    ) -> Result<(), EventPublisherError> {
+        let envelope = ChatEventMessage::MessageStarChanged(MessageStarChangedMessage::from(event));
+
+        self.producer
+            .publish_event(event.channel_id, &event.message_id.to_string(), &envelope)
+            .await
+            .map_err(|e| EventPublisherError::PublishFailed(e.to_string()))
+    }
+}
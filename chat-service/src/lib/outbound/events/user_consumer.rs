@@ -2,23 +2,35 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use futures::StreamExt;
+use prost::Message as _;
+use rdkafka::consumer::CommitMode;
 use rdkafka::consumer::Consumer;
 use rdkafka::consumer::StreamConsumer;
 use rdkafka::error::KafkaError;
+use rdkafka::message::BorrowedMessage;
+use rdkafka::message::Headers;
 use rdkafka::ClientConfig;
 use rdkafka::Message;
 use thiserror::Error;
 
 use super::messages::UserEventMessage;
+use super::user_protobuf::CONTENT_TYPE_HEADER;
+use super::user_protobuf::PROTOBUF_CONTENT_TYPE;
 use crate::config::Config;
+use crate::config::KafkaCommitMode;
+use crate::domain::channel::models::WorkspaceId;
+use crate::domain::channel::ports::ChannelServicePort;
 use crate::domain::user::events::UserCreatedEvent;
+use crate::domain::user::events::UserDeactivatedEvent;
 use crate::domain::user::events::UserDeletedEvent;
 use crate::domain::user::events::UserEvent;
+use crate::domain::user::events::UserReactivatedEvent;
 use crate::domain::user::events::UserUpdatedEvent;
 use crate::domain::user::models::User;
 use crate::domain::user::models::UserId;
 use crate::domain::user::models::Username;
 use crate::domain::user::ports::UserReplicaRepository;
+use crate::proto::events as proto;
 
 #[derive(Debug, Error)]
 enum MessageProcessingError {
@@ -34,26 +46,48 @@ enum MessageProcessingError {
     #[error("Failed to deserialize event: {0}")]
     DeserializationError(#[from] serde_json::Error),
 
+    #[error("Failed to decode protobuf event: {0}")]
+    ProtobufError(#[from] prost::DecodeError),
+
     #[error("Failed to handle event: {0}")]
     HandlingError(String),
 }
 
+/// Content type of a consumed user event, if tagged.
+fn content_type(message: &BorrowedMessage<'_>) -> Option<String> {
+    message.headers().and_then(|headers| {
+        headers
+            .iter()
+            .find(|header| header.key == CONTENT_TYPE_HEADER)
+            .and_then(|header| header.value)
+            .map(|value| String::from_utf8_lossy(value).into_owned())
+    })
+}
+
 /// Kafka consumer for user events from user-service
 ///
 /// This consumer maintains a local denormalized copy of user data
-/// by subscribing to user-events topic and updating the user_replica table
-pub struct UserEventsConsumer<R: UserReplicaRepository> {
+/// by subscribing to user-events topic and updating the user_replica table.
+/// New users also join the default channels of the default workspace.
+pub struct UserEventsConsumer<R: UserReplicaRepository, CS: ChannelServicePort> {
     consumer: StreamConsumer,
     user_replica_repository: Arc<R>,
+    channel_service: Arc<CS>,
+    commit_mode: KafkaCommitMode,
 }
 
-impl<R: UserReplicaRepository> UserEventsConsumer<R> {
+impl<R: UserReplicaRepository, CS: ChannelServicePort> UserEventsConsumer<R, CS> {
     /// Create a new user events consumer
     ///
     /// # Arguments
     /// * `config` - Application configuration
     /// * `user_replica_repository` - Repository for updating local user replica
-    pub fn new(config: &Config, user_replica_repository: Arc<R>) -> Result<Self, anyhow::Error> {
+    /// * `channel_service` - Channel service adding new users to default channels
+    pub fn new(
+        config: &Config,
+        user_replica_repository: Arc<R>,
+        channel_service: Arc<CS>,
+    ) -> Result<Self, anyhow::Error> {
         tracing::info!(
             "Initializing user events consumer: brokers={}, group_id={}, topic={}",
             &config.kafka.brokers,
@@ -64,7 +98,10 @@ impl<R: UserReplicaRepository> UserEventsConsumer<R> {
         let consumer: StreamConsumer = ClientConfig::new()
             .set("bootstrap.servers", &config.kafka.brokers)
             .set("group.id", &config.kafka.group_id)
-            .set("enable.auto.commit", "true")
+            .set(
+                "enable.auto.commit",
+                config.kafka.commit_mode.enable_auto_commit(),
+            )
             .set("auto.commit.interval.ms", "5000")
             .set("auto.offset.reset", "earliest") // Process all user events from beginning
             .set("session.timeout.ms", "30000")
@@ -82,6 +119,8 @@ impl<R: UserReplicaRepository> UserEventsConsumer<R> {
         Ok(Self {
             consumer,
             user_replica_repository,
+            channel_service,
+            commit_mode: config.kafka.commit_mode,
         })
     }
 
@@ -108,14 +147,35 @@ impl<R: UserReplicaRepository> UserEventsConsumer<R> {
     }
 
     /// Process a single Kafka message
+    ///
+    /// In manual commit mode the offset is committed once the event has been handled.
     async fn process_message(
         &self,
-        result: Result<rdkafka::message::BorrowedMessage<'_>, KafkaError>,
+        result: Result<BorrowedMessage<'_>, KafkaError>,
     ) -> Result<(), MessageProcessingError> {
         let message = result?;
+        let outcome = self.handle_message(&message).await;
+
+        if self.commit_mode == KafkaCommitMode::Manual {
+            self.consumer.commit_message(&message, CommitMode::Sync)?;
+        }
+
+        outcome
+    }
+
+    /// Decode and handle the payload of a single Kafka message
+    async fn handle_message(
+        &self,
+        message: &BorrowedMessage<'_>,
+    ) -> Result<(), MessageProcessingError> {
         let payload = message.payload().ok_or(MessageProcessingError::NoPayload)?;
-        let json_string = std::str::from_utf8(payload)?;
-        let event_message = serde_json::from_str::<UserEventMessage>(json_string)?;
+        // Records without a content type predate protobuf encoding and are JSON
+        let event_message = if content_type(message).as_deref() == Some(PROTOBUF_CONTENT_TYPE) {
+            UserEventMessage::try_from(proto::UserEvent::decode(payload)?)
+                .map_err(MessageProcessingError::HandlingError)?
+        } else {
+            serde_json::from_str::<UserEventMessage>(std::str::from_utf8(payload)?)?
+        };
 
         // Convert infrastructure message to domain event
         let event = UserEvent::try_from(event_message)
@@ -138,10 +198,38 @@ impl<R: UserReplicaRepository> UserEventsConsumer<R> {
             UserEvent::UserCreated(created_event) => self.handle_user_created(created_event).await,
             UserEvent::UserUpdated(updated_event) => self.handle_user_updated(updated_event).await,
             UserEvent::UserDeleted(deleted_event) => self.handle_user_deleted(deleted_event).await,
+            UserEvent::UserDeactivated(deactivated_event) => {
+                self.handle_user_deactivated(deactivated_event).await
+            }
+            UserEvent::UserReactivated(reactivated_event) => {
+                self.handle_user_reactivated(reactivated_event).await
+            }
+            // The replica holds no credentials, and user-service revoked the tokens issued
+            // before the change in the revocation store this service reads
+            UserEvent::UserPasswordChanged(changed_event) => {
+                tracing::debug!("Ignoring password change of user {}", changed_event.user_id);
+                Ok(())
+            }
+            UserEvent::UserPasswordResetRequested(requested_event) => {
+                tracing::debug!(
+                    "Ignoring password reset request of user {}",
+                    requested_event.user_id
+                );
+                Ok(())
+            }
+            UserEvent::UserPasswordReset(reset_event) => {
+                tracing::debug!("Ignoring password reset of user {}", reset_event.user_id);
+                Ok(())
+            }
+            // Lockouts only refuse password logins, the user stays active
+            UserEvent::UserAccountLocked(locked_event) => {
+                tracing::debug!("Ignoring account lockout of user {}", locked_event.user_id);
+                Ok(())
+            }
         }
     }
 
-    /// Handle UserCreated event - insert user into replica
+    /// Handle UserCreated event - insert user into replica and join default channels
     async fn handle_user_created(&self, event: UserCreatedEvent) -> Result<(), String> {
         tracing::info!("Handling UserCreated event for user {}", event.user_id);
 
@@ -154,6 +242,8 @@ impl<R: UserReplicaRepository> UserEventsConsumer<R> {
         let user = User {
             id: user_id,
             username,
+            display_name: None,
+            avatar_url: None,
             created_at: event.created_at,
             updated_at: event.created_at, // Same as created_at for new users
         };
@@ -166,6 +256,21 @@ impl<R: UserReplicaRepository> UserEventsConsumer<R> {
             event.username
         );
 
+        // Users carry no workspace yet, so they belong to the default one
+        let joined = self
+            .channel_service
+            .join_default_channels(user_id, WorkspaceId::default())
+            .await
+            .map_err(|error| format!("Failed to join default channels: {}", error))?;
+
+        if !joined.is_empty() {
+            tracing::info!(
+                "User {} joined {} default channels",
+                event.user_id,
+                joined.len()
+            );
+        }
+
         Ok(())
     }
 
@@ -195,6 +300,8 @@ impl<R: UserReplicaRepository> UserEventsConsumer<R> {
         let user = User {
             id: user_id,
             username,
+            display_name: event.display_name.clone(),
+            avatar_url: event.avatar_url.clone(),
             created_at,
             updated_at: event.updated_at,
         };
@@ -223,4 +330,36 @@ impl<R: UserReplicaRepository> UserEventsConsumer<R> {
 
         Ok(())
     }
+
+    /// Handle UserDeactivated event - mark user deactivated in replica
+    async fn handle_user_deactivated(&self, event: UserDeactivatedEvent) -> Result<(), String> {
+        tracing::info!("Handling UserDeactivated event for user {}", event.user_id);
+
+        let user_id = UserId::from_string(&event.user_id)
+            .map_err(|error| format!("Invalid user_id in UserDeactivated event: {}", error))?;
+
+        self.user_replica_repository
+            .set_deactivated(user_id, Some(event.deactivated_at))
+            .await?;
+
+        tracing::info!("User {} deactivated in replica", event.user_id);
+
+        Ok(())
+    }
+
+    /// Handle UserReactivated event - clear user deactivation in replica
+    async fn handle_user_reactivated(&self, event: UserReactivatedEvent) -> Result<(), String> {
+        tracing::info!("Handling UserReactivated event for user {}", event.user_id);
+
+        let user_id = UserId::from_string(&event.user_id)
+            .map_err(|error| format!("Invalid user_id in UserReactivated event: {}", error))?;
+
+        self.user_replica_repository
+            .set_deactivated(user_id, None)
+            .await?;
+
+        tracing::info!("User {} reactivated in replica", event.user_id);
+
+        Ok(())
+    }
 }
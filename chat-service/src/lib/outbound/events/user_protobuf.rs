@@ -0,0 +1,137 @@
+use chrono::DateTime;
+use chrono::Utc;
+
+use super::messages::UserAccountLockedMessage;
+use super::messages::UserCreatedMessage;
+use super::messages::UserDeactivatedMessage;
+use super::messages::UserDeletedMessage;
+use super::messages::UserEventMessage;
+use super::messages::UserPasswordChangedMessage;
+use super::messages::UserPasswordResetMessage;
+use super::messages::UserPasswordResetRequestedMessage;
+use super::messages::UserReactivatedMessage;
+use super::messages::UserUpdatedMessage;
+use crate::proto::events as proto;
+use crate::proto::events::user_event::Event;
+
+/// Record header naming the encoding of a user event
+pub const CONTENT_TYPE_HEADER: &str = "content-type";
+/// Content type of user events encoded as `proto/user_events.proto`
+pub const PROTOBUF_CONTENT_TYPE: &str = "application/x-protobuf";
+
+fn timestamp(millis: i64) -> Result<DateTime<Utc>, String> {
+    DateTime::from_timestamp_millis(millis).ok_or_else(|| format!("Invalid timestamp: {}", millis))
+}
+
+impl TryFrom<proto::UserEvent> for UserEventMessage {
+    type Error = String;
+
+    fn try_from(event: proto::UserEvent) -> Result<Self, Self::Error> {
+        match event.event.ok_or("Missing user event")? {
+            Event::UserCreated(e) => Ok(UserEventMessage::UserCreated(UserCreatedMessage {
+                event_id: e.event_id,
+                user_id: e.user_id,
+                username: e.username,
+                email: e.email,
+                created_at: timestamp(e.created_at)?,
+            })),
+            Event::UserUpdated(e) => Ok(UserEventMessage::UserUpdated(UserUpdatedMessage {
+                event_id: e.event_id,
+                user_id: e.user_id,
+                username: e.username,
+                email: e.email,
+                display_name: e.display_name,
+                avatar_url: e.avatar_url,
+                updated_at: timestamp(e.updated_at)?,
+            })),
+            Event::UserDeleted(e) => Ok(UserEventMessage::UserDeleted(UserDeletedMessage {
+                event_id: e.event_id,
+                user_id: e.user_id,
+                deleted_at: timestamp(e.deleted_at)?,
+            })),
+            Event::UserDeactivated(e) => {
+                Ok(UserEventMessage::UserDeactivated(UserDeactivatedMessage {
+                    event_id: e.event_id,
+                    user_id: e.user_id,
+                    deactivated_at: timestamp(e.deactivated_at)?,
+                }))
+            }
+            Event::UserReactivated(e) => {
+                Ok(UserEventMessage::UserReactivated(UserReactivatedMessage {
+                    event_id: e.event_id,
+                    user_id: e.user_id,
+                    reactivated_at: timestamp(e.reactivated_at)?,
+                }))
+            }
+            Event::UserPasswordChanged(e) => Ok(UserEventMessage::UserPasswordChanged(
+                UserPasswordChangedMessage {
+                    event_id: e.event_id,
+                    user_id: e.user_id,
+                    changed_at: timestamp(e.changed_at)?,
+                },
+            )),
+            Event::UserPasswordResetRequested(e) => Ok(
+                UserEventMessage::UserPasswordResetRequested(UserPasswordResetRequestedMessage {
+                    event_id: e.event_id,
+                    user_id: e.user_id,
+                    requested_at: timestamp(e.requested_at)?,
+                    expires_at: timestamp(e.expires_at)?,
+                }),
+            ),
+            Event::UserPasswordReset(e) => Ok(UserEventMessage::UserPasswordReset(
+                UserPasswordResetMessage {
+                    event_id: e.event_id,
+                    user_id: e.user_id,
+                    reset_at: timestamp(e.reset_at)?,
+                },
+            )),
+            Event::UserAccountLocked(e) => Ok(UserEventMessage::UserAccountLocked(
+                UserAccountLockedMessage {
+                    event_id: e.event_id,
+                    user_id: e.user_id,
+                    failed_attempts: e.failed_attempts,
+                    locked_at: timestamp(e.locked_at)?,
+                    locked_until: timestamp(e.locked_until)?,
+                },
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_updated_converts_with_profile_fields() {
+        let event = proto::UserEvent {
+            schema_version: 2,
+            event: Some(Event::UserUpdated(proto::UserUpdated {
+                event_id: "event-1".to_string(),
+                user_id: "user-1".to_string(),
+                username: "alice".to_string(),
+                email: "alice@example.com".to_string(),
+                display_name: Some("Alice".to_string()),
+                avatar_url: None,
+                bio: None,
+                updated_at: 1_792_022_400_123,
+            })),
+        };
+
+        let Ok(UserEventMessage::UserUpdated(updated)) = UserEventMessage::try_from(event) else {
+            panic!("Expected a user_updated message");
+        };
+        assert_eq!(updated.display_name.as_deref(), Some("Alice"));
+        assert_eq!(updated.updated_at.timestamp_millis(), 1_792_022_400_123);
+    }
+
+    #[test]
+    fn test_event_without_payload_is_rejected() {
+        let event = proto::UserEvent {
+            schema_version: 2,
+            event: None,
+        };
+
+        assert!(UserEventMessage::try_from(event).is_err());
+    }
+}
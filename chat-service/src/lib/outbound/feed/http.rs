@@ -0,0 +1,183 @@
+use std::net::IpAddr;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::dns::Addrs;
+use reqwest::dns::Name;
+use reqwest::dns::Resolve;
+use reqwest::dns::Resolving;
+use reqwest::redirect;
+use reqwest::Url;
+
+use super::parser::parse_feed;
+use crate::domain::feed::errors::FeedFetchError;
+use crate::domain::feed::models::is_public_address;
+use crate::domain::feed::models::FeedEntry;
+use crate::domain::feed::models::FeedUrl;
+use crate::domain::feed::ports::FeedFetcher;
+
+/// Redirects followed before a download is abandoned.
+const MAX_REDIRECTS: usize = 5;
+
+/// Feed fetcher downloading feeds over HTTP.
+///
+/// Downloads are cut off past `max_bytes`, so a hostile feed cannot exhaust
+/// the memory of the poller. Feeds are only fetched from public addresses:
+/// hostnames resolving to a private address are refused, and so are
+/// redirects to private IP literals, so channel owners cannot reach the
+/// cluster through their feeds.
+pub struct HttpFeedFetcher {
+    client: reqwest::Client,
+    max_bytes: usize,
+}
+
+impl HttpFeedFetcher {
+    /// Create a fetcher.
+    ///
+    /// # Arguments
+    /// * `timeout` - Longest time a download may take
+    /// * `max_bytes` - Largest feed accepted
+    ///
+    /// # Returns
+    /// HttpFeedFetcher instance
+    ///
+    /// # Errors
+    /// * `Request` - HTTP client could not be built
+    pub fn new(timeout: Duration, max_bytes: usize) -> Result<Self, FeedFetchError> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .dns_resolver(Arc::new(PublicResolver))
+            .redirect(redirect::Policy::custom(|attempt| {
+                if attempt.previous().len() >= MAX_REDIRECTS {
+                    attempt.error("too many redirects")
+                } else if let Some(ip) = private_ip_literal(attempt.url()) {
+                    attempt.error(format!("redirect to private address {}", ip))
+                } else {
+                    attempt.follow()
+                }
+            }))
+            .user_agent(concat!("chat-service/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| FeedFetchError::Request(e.to_string()))?;
+
+        Ok(Self { client, max_bytes })
+    }
+}
+
+/// IP literal host of a URL that is not a public address.
+///
+/// IP literals never reach the resolver, so they are checked here.
+fn private_ip_literal(url: &Url) -> Option<IpAddr> {
+    let host = url.host_str()?;
+    let ip: IpAddr = host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse()
+        .ok()?;
+    (!is_public_address(ip)).then_some(ip)
+}
+
+/// Resolver refusing hostnames with a private address.
+///
+/// Checking the addresses connected to, rather than the URL, also covers
+/// redirects and DNS records changed after the feed was registered.
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            if let Some(addr) = addrs.iter().find(|addr| !is_public_address(addr.ip())) {
+                return Err(format!("{} resolves to private address {}", host, addr.ip()).into());
+            }
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for HttpFeedFetcher {
+    async fn fetch(&self, url: &FeedUrl) -> Result<Vec<FeedEntry>, FeedFetchError> {
+        // Feeds registered before private hosts were refused are stored as is
+        if let Some(ip) = Url::parse(url.as_str())
+            .ok()
+            .as_ref()
+            .and_then(private_ip_literal)
+        {
+            return Err(FeedFetchError::Request(format!(
+                "refusing private address {}",
+                ip
+            )));
+        }
+
+        let mut response = self
+            .client
+            .get(url.as_str())
+            .header(
+                "Accept",
+                "application/rss+xml, application/atom+xml, application/xml;q=0.9, */*;q=0.8",
+            )
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| FeedFetchError::Request(e.to_string()))?;
+
+        if response
+            .content_length()
+            .is_some_and(|length| length > self.max_bytes as u64)
+        {
+            return Err(FeedFetchError::TooLarge(self.max_bytes));
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| FeedFetchError::Request(e.to_string()))?
+        {
+            if body.len() + chunk.len() > self.max_bytes {
+                return Err(FeedFetchError::TooLarge(self.max_bytes));
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        let xml = String::from_utf8_lossy(&body);
+        parse_feed(&xml)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolver_refuses_private_addresses() {
+        let name: Name = "localhost".parse().unwrap();
+        assert!(PublicResolver.resolve(name).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_refuses_stored_private_literal() {
+        let fetcher = HttpFeedFetcher::new(Duration::from_secs(1), 1024).unwrap();
+        let url = FeedUrl::from_stored("http://169.254.169.254/latest/meta-data/".to_string());
+
+        assert!(matches!(
+            fetcher.fetch(&url).await,
+            Err(FeedFetchError::Request(_))
+        ));
+    }
+
+    #[test]
+    fn test_private_ip_literal() {
+        let private = |url: &str| private_ip_literal(&Url::parse(url).unwrap());
+        assert!(private("http://127.0.0.1:8080/").is_some());
+        assert!(private("http://[fd12::1]/").is_some());
+        assert!(private("http://93.184.216.34/").is_none());
+        assert!(private("http://example.com/").is_none());
+    }
+}
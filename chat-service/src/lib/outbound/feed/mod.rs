@@ -0,0 +1,4 @@
+pub mod http;
+pub mod parser;
+
+pub use http::HttpFeedFetcher;
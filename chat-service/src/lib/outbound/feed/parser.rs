@@ -0,0 +1,255 @@
+use chrono::DateTime;
+use chrono::Utc;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::domain::feed::errors::FeedFetchError;
+use crate::domain::feed::models::FeedEntry;
+
+/// Element of an entry whose text is being read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Id,
+    Title,
+    Link,
+    Summary,
+    Published,
+}
+
+impl Field {
+    fn of(name: &[u8]) -> Option<Self> {
+        match name {
+            b"guid" | b"id" => Some(Field::Id),
+            b"title" => Some(Field::Title),
+            b"link" => Some(Field::Link),
+            b"description" | b"summary" | b"content" => Some(Field::Summary),
+            b"pubDate" | b"published" | b"updated" | b"date" => Some(Field::Published),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct EntryBuilder {
+    id: Option<String>,
+    title: Option<String>,
+    link: Option<String>,
+    summary: Option<String>,
+    published_at: Option<DateTime<Utc>>,
+}
+
+impl EntryBuilder {
+    fn set(&mut self, field: Field, text: String) {
+        let text = text.trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        match field {
+            Field::Id => self.id = Some(text),
+            Field::Title => self.title = Some(text),
+            Field::Link => {
+                self.link.get_or_insert(text);
+            }
+            // Atom entries may carry both, the summary is the shorter one
+            Field::Summary => {
+                self.summary.get_or_insert(text);
+            }
+            Field::Published => {
+                if self.published_at.is_none() {
+                    self.published_at = parse_date(&text);
+                }
+            }
+        }
+    }
+
+    /// Atom links are attributes, the alternate one points to the entry.
+    fn set_atom_link(&mut self, element: &BytesStart) {
+        let attribute = |name: &str| {
+            element
+                .try_get_attribute(name)
+                .ok()
+                .flatten()
+                .and_then(|attribute| attribute.unescape_value().ok())
+                .map(|value| value.into_owned())
+        };
+
+        if let Some(href) = attribute("href") {
+            match attribute("rel").as_deref() {
+                None | Some("alternate") => self.link = Some(href),
+                _ => {}
+            }
+        }
+    }
+
+    fn build(self) -> Option<FeedEntry> {
+        let id = self.id.clone().or_else(|| self.link.clone())?;
+        Some(FeedEntry {
+            id,
+            title: self.title,
+            link: self.link,
+            summary: self.summary,
+            published_at: self.published_at,
+        })
+    }
+}
+
+/// Parse the entries of an RSS 2.0, RSS 1.0 or Atom document.
+///
+/// Entries with neither an ID nor a link cannot be deduplicated and are left out.
+///
+/// # Arguments
+/// * `xml` - Feed document
+///
+/// # Returns
+/// Entries in document order
+///
+/// # Errors
+/// * `Parse` - Document is not well-formed XML
+pub fn parse_feed(xml: &str) -> Result<Vec<FeedEntry>, FeedFetchError> {
+    let mut reader = Reader::from_str(xml);
+    let mut entries = Vec::new();
+    let mut entry: Option<EntryBuilder> = None;
+    let mut field: Option<(Field, Vec<u8>)> = None;
+    let mut text = String::new();
+
+    loop {
+        match reader
+            .read_event()
+            .map_err(|e| FeedFetchError::Parse(e.to_string()))?
+        {
+            Event::Start(element) => {
+                let name = element.local_name().as_ref().to_vec();
+                if matches!(name.as_slice(), b"item" | b"entry") {
+                    entry = Some(EntryBuilder::default());
+                } else if field.is_none() {
+                    if let Some(builder) = entry.as_mut() {
+                        if name == b"link" {
+                            builder.set_atom_link(&element);
+                        }
+                        if let Some(kind) = Field::of(&name) {
+                            field = Some((kind, name));
+                            text.clear();
+                        }
+                    }
+                }
+            }
+            Event::Empty(element) if field.is_none() => {
+                if let Some(builder) = entry.as_mut() {
+                    if element.local_name().as_ref() == b"link" {
+                        builder.set_atom_link(&element);
+                    }
+                }
+            }
+            Event::Text(content) if field.is_some() => {
+                let content = content
+                    .unescape()
+                    .map_err(|e| FeedFetchError::Parse(e.to_string()))?;
+                text.push_str(&content);
+            }
+            Event::CData(content) if field.is_some() => {
+                text.push_str(&String::from_utf8_lossy(&content.into_inner()));
+            }
+            Event::End(element) => {
+                let name = element.local_name();
+                if let Some((kind, open)) = &field {
+                    if open.as_slice() == name.as_ref() {
+                        if let Some(builder) = entry.as_mut() {
+                            builder.set(*kind, std::mem::take(&mut text));
+                        }
+                        field = None;
+                    }
+                } else if matches!(name.as_ref(), b"item" | b"entry") {
+                    entries.extend(entry.take().and_then(EntryBuilder::build));
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Date of an RSS (RFC 2822) or Atom (RFC 3339) entry.
+fn parse_date(text: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(text)
+        .or_else(|_| DateTime::parse_from_rfc2822(text))
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rss() {
+        let entries = parse_feed(
+            r#"<?xml version="1.0"?>
+            <rss version="2.0"><channel>
+              <title>Blog</title>
+              <link>https://example.com/</link>
+              <item>
+                <title>Release &amp; notes</title>
+                <link>https://example.com/release</link>
+                <guid isPermaLink="false">release-1</guid>
+                <description><![CDATA[<p>Fixes</p>]]></description>
+                <pubDate>Thu, 15 Oct 2026 09:30:00 +0000</pubDate>
+              </item>
+              <item><title>No link or guid</title></item>
+            </channel></rss>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![FeedEntry {
+                id: "release-1".to_string(),
+                title: Some("Release & notes".to_string()),
+                link: Some("https://example.com/release".to_string()),
+                summary: Some("<p>Fixes</p>".to_string()),
+                published_at: Some("2026-10-15T09:30:00Z".parse().unwrap()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_atom() {
+        let entries = parse_feed(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom">
+              <title>Blog</title>
+              <link href="https://example.com/"/>
+              <entry>
+                <id>tag:example.com,2026:1</id>
+                <title type="text">Hello</title>
+                <link rel="edit" href="https://example.com/edit/1"/>
+                <link rel="alternate" href="https://example.com/1"/>
+                <updated>2026-10-15T09:30:00Z</updated>
+                <summary>Short</summary>
+                <content type="html">Long</content>
+              </entry>
+            </feed>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![FeedEntry {
+                id: "tag:example.com,2026:1".to_string(),
+                title: Some("Hello".to_string()),
+                link: Some("https://example.com/1".to_string()),
+                summary: Some("Short".to_string()),
+                published_at: Some("2026-10-15T09:30:00Z".parse().unwrap()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_malformed_document_is_rejected() {
+        assert!(matches!(
+            parse_feed("<rss><channel><item></channel>"),
+            Err(FeedFetchError::Parse(_))
+        ));
+    }
+}
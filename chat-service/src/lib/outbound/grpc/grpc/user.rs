@@ -47,6 +47,8 @@ impl UserServicePort for GrpcUserServiceClient {
                 Ok(Some(User {
                     id: user_id,
                     username,
+                    display_name: None,
+                    avatar_url: None,
                     created_at: Default::default(),
                     updated_at: Default::default(),
                 }))
@@ -1,26 +1,75 @@
+use std::sync::Arc;
+
 use anyhow::Error;
+use auth::grpc::ServiceTokenInterceptor;
+use auth::Authenticator;
+use auth::Claims;
+use auth::PersonalTokenError;
+use auth::PersonalTokenVerifier;
+use chrono::DateTime;
+use chrono::Utc;
+use tonic::service::interceptor::InterceptedService;
 use tonic::transport::Channel;
+use tracing::instrument;
 
 use crate::domain::user::models::User;
 use crate::domain::user::models::UserId;
 use crate::domain::user::models::Username;
 use crate::domain::user::ports::UserServicePort;
 use crate::proto::user_service_client::UserServiceClient;
+use crate::proto::verify_personal_token_response;
 use crate::proto::GetUserRequest;
+use crate::proto::GetUsersByIdsRequest;
+use crate::proto::VerifyPersonalTokenRequest;
+
+/// Service name chat-service authenticates as towards user-service.
+const SERVICE_NAME: &str = "chat-service";
+
+/// Service the spans of calls to user-service name as their peer.
+const PEER_SERVICE: &str = "user-service";
+
+/// Scope granting read access to user profiles.
+const USERS_READ_SCOPE: &str = "users:read";
+
+/// Scope granting verification of personal access tokens.
+const PERSONAL_TOKENS_VERIFY_SCOPE: &str = "personal_tokens:verify";
+
+/// Most users user-service looks up in one `GetUsersByIds` call.
+const MAX_USERS_PER_CALL: usize = 100;
 
 pub struct GrpcUserServiceClient {
-    client: UserServiceClient<Channel>,
+    client: UserServiceClient<InterceptedService<Channel, ServiceTokenInterceptor>>,
 }
 
 impl GrpcUserServiceClient {
-    pub async fn new(url: &str) -> Result<Self, Error> {
-        let client = UserServiceClient::connect(url.to_string()).await?;
+    /// Connect to user-service, authenticating calls with a service token.
+    ///
+    /// # Arguments
+    /// * `url` - gRPC endpoint of user-service
+    /// * `authenticator` - Authenticator sharing user-service's signing key
+    ///
+    /// # Errors
+    /// Returns error if the connection cannot be established
+    pub async fn new(url: &str, authenticator: Arc<Authenticator>) -> Result<Self, Error> {
+        let channel = Channel::from_shared(url.to_string())?.connect().await?;
+        let interceptor = ServiceTokenInterceptor::new(
+            authenticator,
+            SERVICE_NAME,
+            &[USERS_READ_SCOPE, PERSONAL_TOKENS_VERIFY_SCOPE],
+        );
+        let client = UserServiceClient::with_interceptor(channel, interceptor);
         Ok(Self { client })
     }
 }
 
 #[async_trait::async_trait]
 impl UserServicePort for GrpcUserServiceClient {
+    #[instrument(
+        name = "user_service.get_user",
+        skip_all,
+        fields(peer.service = PEER_SERVICE, rpc.system = "grpc", rpc.method = "GetUser"),
+        err(level = "warn")
+    )]
     async fn get_user(&self, user_id: UserId) -> Result<Option<User>, String> {
         let request = tonic::Request::new(GetUserRequest {
             user_id: user_id.to_string(),
@@ -36,23 +85,106 @@ impl UserServicePort for GrpcUserServiceClient {
 
         match result.result {
             Some(crate::proto::get_user_response::Result::User(user)) => {
-                let user_id =
-                    UserId::from_string(&user.id).map_err(|e| format!("Invalid user ID: {}", e))?;
+                user_from_proto(user).map(Some)
+            }
+            Some(crate::proto::get_user_response::Result::Error(err)) => Err(err),
+            None => Ok(None),
+        }
+    }
 
-                let username = Username::new(user.username)
-                    .map_err(|e| format!("Invalid username from gRPC: {}", e))?;
+    #[instrument(
+        name = "user_service.get_users_by_ids",
+        skip_all,
+        fields(
+            peer.service = PEER_SERVICE,
+            rpc.system = "grpc",
+            rpc.method = "GetUsersByIds"
+        ),
+        err(level = "warn")
+    )]
+    async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, String> {
+        let mut users = Vec::with_capacity(user_ids.len());
 
-                //@TODO remove created_at, updated_at
+        for batch in user_ids.chunks(MAX_USERS_PER_CALL) {
+            let request = tonic::Request::new(GetUsersByIdsRequest {
+                user_ids: batch.iter().map(ToString::to_string).collect(),
+            });
 
-                Ok(Some(User {
-                    id: user_id,
-                    username,
-                    created_at: Default::default(),
-                    updated_at: Default::default(),
-                }))
+            let mut client = self.client.clone();
+            let response = client
+                .get_users_by_ids(request)
+                .await
+                .map_err(|e| format!("gRPC error: {}", e))?;
+
+            for user in response.into_inner().users {
+                users.push(user_from_proto(user)?);
+            }
+        }
+
+        Ok(users)
+    }
+}
+
+fn user_from_proto(user: crate::proto::User) -> Result<User, String> {
+    let user_id = UserId::from_string(&user.id).map_err(|e| format!("Invalid user ID: {}", e))?;
+
+    let username =
+        Username::new(user.username).map_err(|e| format!("Invalid username from gRPC: {}", e))?;
+
+    //@TODO remove created_at, updated_at
+
+    Ok(User {
+        id: user_id,
+        username,
+        display_name: None,
+        avatar_url: None,
+        created_at: Default::default(),
+        updated_at: Default::default(),
+    })
+}
+
+#[async_trait::async_trait]
+impl PersonalTokenVerifier for GrpcUserServiceClient {
+    #[instrument(
+        name = "user_service.verify_personal_token",
+        skip_all,
+        fields(
+            peer.service = PEER_SERVICE,
+            rpc.system = "grpc",
+            rpc.method = "VerifyPersonalToken"
+        ),
+        err(level = "warn")
+    )]
+    async fn verify(&self, token: &str) -> Result<Claims, PersonalTokenError> {
+        let request = tonic::Request::new(VerifyPersonalTokenRequest {
+            token: token.to_string(),
+        });
+
+        let mut client = self.client.clone();
+        let response = client
+            .verify_personal_token(request)
+            .await
+            .map_err(|e| PersonalTokenError::Unavailable(format!("gRPC error: {}", e)))?;
+
+        match response.into_inner().result {
+            Some(verify_personal_token_response::Result::Token(token)) => {
+                let expires_at = DateTime::parse_from_rfc3339(&token.expires_at)
+                    .map_err(|e| {
+                        PersonalTokenError::Unavailable(format!("Invalid expiry from gRPC: {}", e))
+                    })?
+                    .with_timezone(&Utc);
+
+                Ok(Claims::for_personal_token(
+                    token.user_id,
+                    token.username,
+                    token.id,
+                    token.scopes,
+                    Some(expires_at),
+                ))
+            }
+            Some(verify_personal_token_response::Result::Error(_)) | None => {
+                Err(PersonalTokenError::Invalid)
             }
-            Some(crate::proto::get_user_response::Result::Error(err)) => Err(err),
-            None => Ok(None),
         }
     }
 }
@@ -0,0 +1,211 @@
+use crate::domain::message::models::LanguageCode;
+use crate::domain::message::ports::LanguageDetector;
+
+/// Stopword hits required before a Latin-script language is reported.
+const MIN_STOPWORD_HITS: usize = 2;
+
+/// Languages marked by their own characters within a script shared with another language.
+const SHARED_SCRIPTS: &[(&str, &str)] = &[("ja", "zh"), ("uk", "ru")];
+
+/// Frequent function words of the supported Latin-script languages.
+const STOPWORDS: &[(&str, &[&str])] = &[
+    (
+        "en",
+        &[
+            "the", "and", "is", "are", "was", "to", "of", "in", "that", "it", "you", "this", "for",
+            "with", "have", "not", "what", "be", "on", "i'm", "don't",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der", "die", "das", "und", "ist", "nicht", "ich", "du", "wir", "ein", "eine", "zu",
+            "mit", "auf", "auch", "es", "sie", "sind", "wie", "geht", "dir",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "et", "est", "je", "tu", "nous", "vous", "une", "des", "pas", "que",
+            "qui", "dans", "pour", "avec", "sur", "ce", "c'est", "très",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "los", "las", "y", "es", "yo", "tú", "una", "que", "por", "para", "con", "pero",
+            "está", "muy", "qué", "como", "del", "hola", "gracias", "también",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "il", "gli", "e", "è", "io", "sono", "una", "che", "non", "per", "con", "ma", "come",
+            "della", "questo", "ciao", "grazie", "anche", "molto", "sei", "hai",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "o", "os", "as", "e", "é", "eu", "você", "uma", "que", "não", "para", "com", "mas",
+            "muito", "obrigado", "também", "isso", "está", "tem", "do", "da",
+        ],
+    ),
+    (
+        "nl",
+        &[
+            "de", "het", "een", "en", "is", "ik", "jij", "je", "wij", "niet", "dat", "van", "met",
+            "op", "voor", "maar", "ook", "zijn", "hoe", "gaat", "dank",
+        ],
+    ),
+];
+
+/// Language detector based on writing systems and stopword counts.
+///
+/// Non-Latin scripts identify their language directly. Latin-script content
+/// is attributed to the language whose stopwords occur most often, and left
+/// undetected when too few occur or two languages tie.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StopwordLanguageDetector;
+
+impl StopwordLanguageDetector {
+    /// Create a new detector.
+    ///
+    /// # Returns
+    /// Detector for the built-in scripts and stopword lists
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn detect_script(content: &str) -> Option<&'static str> {
+        let mut letters = 0;
+        let mut counts: Vec<(&'static str, usize)> = Vec::new();
+        for c in content.chars().filter(|c| c.is_alphabetic()) {
+            letters += 1;
+            if let Some(language) = script_language(c) {
+                match counts.iter_mut().find(|(code, _)| *code == language) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((language, 1)),
+                }
+            }
+        }
+
+        // Characters only one language uses decide between languages sharing a script
+        for (specific, shared) in SHARED_SCRIPTS {
+            if counts.iter().any(|(code, _)| code == specific) {
+                let total: usize = counts
+                    .iter()
+                    .filter(|(code, _)| code == specific || code == shared)
+                    .map(|(_, count)| count)
+                    .sum();
+                return (total * 2 > letters).then_some(*specific);
+            }
+        }
+
+        counts
+            .into_iter()
+            .max_by_key(|(_, count)| *count)
+            .filter(|(_, count)| count * 2 > letters)
+            .map(|(code, _)| code)
+    }
+
+    fn detect_stopwords(content: &str) -> Option<&'static str> {
+        let words: Vec<String> = content
+            .split(|c: char| !(c.is_alphabetic() || c == '\''))
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+            .collect();
+
+        let mut scores: Vec<(&'static str, usize)> = STOPWORDS
+            .iter()
+            .map(|(code, stopwords)| {
+                let hits = words
+                    .iter()
+                    .filter(|word| stopwords.contains(&word.as_str()))
+                    .count();
+                (*code, hits)
+            })
+            .collect();
+        scores.sort_by_key(|(_, hits)| std::cmp::Reverse(*hits));
+
+        match scores.as_slice() {
+            [(code, best), (_, runner_up), ..]
+                if *best >= MIN_STOPWORD_HITS && best > runner_up =>
+            {
+                Some(code)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl LanguageDetector for StopwordLanguageDetector {
+    fn detect(&self, content: &str) -> Option<LanguageCode> {
+        let code = Self::detect_script(content).or_else(|| Self::detect_stopwords(content))?;
+        LanguageCode::new(code).ok()
+    }
+}
+
+/// Language implied by a character of a single-language script.
+fn script_language(c: char) -> Option<&'static str> {
+    match c {
+        '\u{0370}'..='\u{03FF}' => Some("el"),
+        'і' | 'ї' | 'є' | 'ґ' | 'І' | 'Ї' | 'Є' | 'Ґ' => Some("uk"),
+        '\u{0400}'..='\u{04FF}' => Some("ru"),
+        '\u{0590}'..='\u{05FF}' => Some("he"),
+        '\u{0600}'..='\u{06FF}' => Some("ar"),
+        '\u{0900}'..='\u{097F}' => Some("hi"),
+        '\u{0E00}'..='\u{0E7F}' => Some("th"),
+        '\u{3040}'..='\u{30FF}' => Some("ja"),
+        '\u{4E00}'..='\u{9FFF}' => Some("zh"),
+        '\u{AC00}'..='\u{D7AF}' | '\u{1100}'..='\u{11FF}' => Some("ko"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(content: &str) -> Option<String> {
+        StopwordLanguageDetector::new()
+            .detect(content)
+            .map(|code| code.as_str().to_string())
+    }
+
+    #[test]
+    fn test_detects_latin_script_languages_by_stopwords() {
+        assert_eq!(
+            detect("Is this the right channel for the release notes?").as_deref(),
+            Some("en")
+        );
+        assert_eq!(
+            detect("Guten Morgen, wie geht es dir und der Familie?").as_deref(),
+            Some("de")
+        );
+        assert_eq!(
+            detect("Je pense que c'est une très bonne idée").as_deref(),
+            Some("fr")
+        );
+        assert_eq!(
+            detect("Hola, gracias por la ayuda con el servidor").as_deref(),
+            Some("es")
+        );
+    }
+
+    #[test]
+    fn test_detects_languages_by_script() {
+        assert_eq!(detect("Привет, как дела?").as_deref(), Some("ru"));
+        assert_eq!(detect("Привіт, як справи? Це їжа").as_deref(), Some("uk"));
+        assert_eq!(detect("今日はいい天気ですね").as_deref(), Some("ja"));
+        assert_eq!(detect("你好，今天天气很好").as_deref(), Some("zh"));
+        assert_eq!(detect("안녕하세요 반갑습니다").as_deref(), Some("ko"));
+    }
+
+    #[test]
+    fn test_short_or_ambiguous_content_is_undetected() {
+        assert_eq!(detect("ok"), None);
+        assert_eq!(detect("lgtm 👍"), None);
+        assert_eq!(detect("https://example.com/build/1234"), None);
+    }
+}
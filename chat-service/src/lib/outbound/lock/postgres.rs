@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use sha2::Digest;
+use sha2::Sha256;
+use sqlx::pool::PoolConnection;
+use sqlx::PgPool;
+use sqlx::Postgres;
+
+use crate::domain::lock::errors::LockError;
+use crate::domain::lock::models::InstanceId;
+use crate::domain::lock::ports::DistributedLock;
+use crate::domain::lock::ports::LockGuard;
+
+/// Distributed lock backed by PostgreSQL session-level advisory locks.
+///
+/// A held lock keeps one pool connection checked out. If the instance dies,
+/// its connection closes and PostgreSQL frees the lock.
+pub struct PostgresAdvisoryLock {
+    pool: PgPool,
+    instance_id: InstanceId,
+}
+
+impl PostgresAdvisoryLock {
+    /// Create an advisory lock backend.
+    ///
+    /// # Arguments
+    /// * `pool` - PostgreSQL connection pool shared by every instance's database
+    /// * `instance_id` - Identity of this instance, logged as lock holder
+    ///
+    /// # Returns
+    /// Configured lock backend
+    pub fn new(pool: PgPool, instance_id: InstanceId) -> Self {
+        Self { pool, instance_id }
+    }
+}
+
+#[async_trait]
+impl DistributedLock for PostgresAdvisoryLock {
+    async fn try_acquire(&self, name: &str) -> Result<Option<Box<dyn LockGuard>>, LockError> {
+        let key = lock_key(name);
+        let mut connection = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| LockError::Unavailable(e.to_string()))?;
+
+        let acquired: bool = sqlx::query_scalar("SELECT pg_try_advisory_lock($1)")
+            .bind(key)
+            .fetch_one(&mut *connection)
+            .await
+            .map_err(|e| LockError::Unavailable(e.to_string()))?;
+        if !acquired {
+            return Ok(None);
+        }
+
+        tracing::debug!(lock = name, key, instance_id = %self.instance_id, "Advisory lock acquired");
+        Ok(Some(Box::new(PostgresLockGuard {
+            name: name.to_string(),
+            key,
+            connection: Some(connection),
+        })))
+    }
+}
+
+/// Advisory lock held on a checked out connection.
+struct PostgresLockGuard {
+    name: String,
+    key: i64,
+    /// Taken on release, so dropping knows whether the lock is still held
+    connection: Option<PoolConnection<Postgres>>,
+}
+
+#[async_trait]
+impl LockGuard for PostgresLockGuard {
+    async fn renew(&mut self) -> Result<(), LockError> {
+        let Some(connection) = self.connection.as_mut() else {
+            return Err(LockError::Lost(
+                self.name.clone(),
+                "lock was released".to_string(),
+            ));
+        };
+
+        // A bigint advisory key is split into classid (high half) and objid (low half)
+        let held: bool = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM pg_locks
+                WHERE locktype = 'advisory'
+                  AND pid = pg_backend_pid()
+                  AND granted
+                  AND objsubid = 1
+                  AND ((classid::bigint << 32) | objid::bigint) = $1
+            )
+            "#,
+        )
+        .bind(self.key)
+        .fetch_one(&mut **connection)
+        .await
+        .map_err(|e| LockError::Lost(self.name.clone(), e.to_string()))?;
+        if !held {
+            return Err(LockError::Lost(
+                self.name.clone(),
+                "session no longer holds the lock".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn release(mut self: Box<Self>) -> Result<(), LockError> {
+        let Some(mut connection) = self.connection.take() else {
+            return Ok(());
+        };
+
+        let released: bool = sqlx::query_scalar("SELECT pg_advisory_unlock($1)")
+            .bind(self.key)
+            .fetch_one(&mut *connection)
+            .await
+            .map_err(|e| LockError::ReleaseFailed(self.name.clone(), e.to_string()))?;
+        if !released {
+            return Err(LockError::ReleaseFailed(
+                self.name.clone(),
+                "lock was not held by this session".to_string(),
+            ));
+        }
+
+        tracing::debug!(lock = %self.name, key = self.key, "Advisory lock released");
+        Ok(())
+    }
+}
+
+impl Drop for PostgresLockGuard {
+    fn drop(&mut self) {
+        // Returned to the pool, the connection would keep holding the lock;
+        // closing it makes PostgreSQL release the lock instead
+        if let Some(connection) = self.connection.take() {
+            tracing::warn!(lock = %self.name, "Advisory lock dropped without release, closing its connection");
+            drop(connection.detach());
+        }
+    }
+}
+
+/// Map a lock name to the 64-bit key of its advisory lock.
+///
+/// Derived from a SHA-256 digest so keys are stable across instances and builds.
+fn lock_key(name: &str) -> i64 {
+    let digest = Sha256::digest(name.as_bytes());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    i64::from_be_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_key_is_stable_and_distinct() {
+        // Instances running different builds must agree on the key
+        assert_eq!(lock_key("retention"), -8204994315909486879);
+        assert_ne!(lock_key("retention"), lock_key("outbox_relay"));
+    }
+}
@@ -1,3 +1,8 @@
+pub mod backup;
+pub mod crash;
 pub mod events;
+pub mod feed;
 pub mod grpc;
+pub mod language;
+pub mod lock;
 pub mod repositories;
@@ -1,17 +1,27 @@
 use async_trait::async_trait;
+use sqlx::postgres::PgRow;
 use sqlx::PgPool;
 use sqlx::Row;
+use tracing::instrument;
 
 use crate::domain::channel::errors::ChannelError;
 use crate::domain::channel::models::Channel;
+use crate::domain::channel::models::ChannelActivity;
+use crate::domain::channel::models::ChannelDirectoryEntry;
 use crate::domain::channel::models::ChannelId;
 use crate::domain::channel::models::ChannelName;
 use crate::domain::channel::models::DirectChannel;
+use crate::domain::channel::models::LanguageCount;
 use crate::domain::channel::models::PrivateChannel;
 use crate::domain::channel::models::PublicChannel;
+use crate::domain::channel::models::WorkspaceId;
 use crate::domain::channel::ports::ChannelRepository;
+use crate::domain::message::models::LanguageCode;
 use crate::domain::user::models::UserId;
 
+/// Unique indexes scoping channel names to their workspace and channel type.
+const NAME_CONSTRAINTS: &[&str] = &["channels_public_name_key", "channels_private_name_key"];
+
 pub struct PostgresChannelRepository {
     pool: PgPool,
 }
@@ -21,43 +31,53 @@ impl PostgresChannelRepository {
         Self { pool }
     }
 
-    fn row_to_channel(
-        id: uuid::Uuid,
-        name: Option<String>,
-        description: Option<String>,
-        created_by: uuid::Uuid,
-        created_at: chrono::DateTime<chrono::Utc>,
-        channel_type: String,
-    ) -> Result<Channel, ChannelError> {
-        let channel_id = ChannelId(id);
-        let user_id = UserId(created_by);
+    fn row_to_channel(row: &PgRow) -> Result<Channel, ChannelError> {
+        let channel_id = ChannelId(row.get("id"));
+        let workspace_id = WorkspaceId(row.get("workspace_id"));
+        let user_id = UserId(row.get("created_by"));
+        let name: Option<String> = row.get("name");
+        let description: Option<String> = row.get("description");
+        let created_at: chrono::DateTime<chrono::Utc> = row.get("created_at");
+        let channel_type: String = row.get("channel_type");
+        let embeddable: bool = row.get("embeddable");
+        let discoverable: bool = row.get("discoverable");
+        let announcement_only: bool = row.get("announcement_only");
+        let auto_join: bool = row.get("auto_join");
 
         match channel_type.as_str() {
             "public" => {
                 let channel_name = ChannelName::new(name.unwrap_or_default())?;
                 Ok(Channel::Public(PublicChannel {
                     id: channel_id,
+                    workspace_id,
                     name: channel_name,
                     description,
                     created_by: user_id,
                     created_at,
+                    embeddable,
+                    discoverable,
+                    announcement_only,
+                    auto_join,
                 }))
             }
             "private" => {
                 let channel_name = ChannelName::new(name.unwrap_or_default())?;
                 Ok(Channel::Private(PrivateChannel {
                     id: channel_id,
+                    workspace_id,
                     name: channel_name,
                     description,
                     created_by: user_id,
                     created_at,
                     members: vec![], // TODO: Load members from a separate table
+                    announcement_only,
                 }))
             }
             "direct" => {
                 // TODO: Load actual participants from a separate table
                 Ok(Channel::Direct(DirectChannel {
                     id: channel_id,
+                    workspace_id,
                     created_by: user_id,
                     created_at,
                     participants: [user_id, user_id], // Placeholder
@@ -67,10 +87,15 @@ impl PostgresChannelRepository {
                 let channel_name = ChannelName::new(name.unwrap_or_default())?;
                 Ok(Channel::Public(PublicChannel {
                     id: channel_id,
+                    workspace_id,
                     name: channel_name,
                     description,
                     created_by: user_id,
                     created_at,
+                    embeddable,
+                    discoverable,
+                    announcement_only,
+                    auto_join,
                 }))
             }
         }
@@ -79,30 +104,46 @@ impl PostgresChannelRepository {
 
 #[async_trait]
 impl ChannelRepository for PostgresChannelRepository {
+    #[instrument(
+        name = "channel_repository.create",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
     async fn create(&self, channel: Channel) -> Result<Channel, ChannelError> {
         let name = channel.name().map(|n| n.as_str());
 
         sqlx::query(
             r#"
-            INSERT INTO channels (id, name, description, created_by, created_at, channel_type)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            INSERT INTO channels (id, workspace_id, name, description, created_by, created_at, channel_type, embeddable, discoverable, announcement_only, auto_join)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             "#,
         )
         .bind(channel.id().0)
+        .bind(channel.workspace_id().0)
         .bind(name)
         .bind(channel.description())
         .bind(channel.created_by().0)
         .bind(channel.created_at())
         .bind(channel.channel_type())
+        .bind(channel.is_embeddable())
+        .bind(channel.is_discoverable())
+        .bind(channel.is_announcement_only())
+        .bind(channel.is_auto_join())
         .execute(&self.pool)
         .await
         .map_err(|e| {
             if let Some(db_err) = e.as_database_error() {
-                if db_err.is_unique_violation() {
-                    if db_err.constraint() == Some("channels_name_key") {
-                        if let Some(name) = name {
-                            return ChannelError::NameAlreadyExists(name.to_string());
-                        }
+                if db_err.is_unique_violation() && db_err.constraint() == Some("channels_pkey") {
+                    return ChannelError::IdAlreadyExists(channel.id());
+                }
+                if db_err.is_unique_violation()
+                    && db_err
+                        .constraint()
+                        .is_some_and(|constraint| NAME_CONSTRAINTS.contains(&constraint))
+                {
+                    if let Some(name) = name {
+                        return ChannelError::NameAlreadyExists(name.to_string());
                     }
                 }
             }
@@ -112,10 +153,16 @@ impl ChannelRepository for PostgresChannelRepository {
         Ok(channel)
     }
 
+    #[instrument(
+        name = "channel_repository.find_by_id",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
     async fn find_by_id(&self, id: ChannelId) -> Result<Option<Channel>, ChannelError> {
         let row = sqlx::query(
             r#"
-            SELECT id, name, description, created_by, created_at, channel_type
+            SELECT id, workspace_id, name, description, created_by, created_at, channel_type, embeddable, discoverable, announcement_only, auto_join
             FROM channels
             WHERE id = $1
             "#,
@@ -126,22 +173,21 @@ impl ChannelRepository for PostgresChannelRepository {
         .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
 
         match row {
-            Some(r) => Ok(Some(Self::row_to_channel(
-                r.get("id"),
-                r.get("name"),
-                r.get("description"),
-                r.get("created_by"),
-                r.get("created_at"),
-                r.get("channel_type"),
-            )?)),
+            Some(r) => Ok(Some(Self::row_to_channel(&r)?)),
             None => Ok(None),
         }
     }
 
+    #[instrument(
+        name = "channel_repository.find_public_channels",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
     async fn find_public_channels(&self) -> Result<Vec<Channel>, ChannelError> {
         let rows = sqlx::query(
             r#"
-            SELECT id, name, description, created_by, created_at, channel_type
+            SELECT id, workspace_id, name, description, created_by, created_at, channel_type, embeddable, discoverable, announcement_only, auto_join
             FROM channels
             WHERE channel_type = 'public'
             ORDER BY created_at DESC
@@ -151,26 +197,57 @@ impl ChannelRepository for PostgresChannelRepository {
         .await
         .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
 
+        rows.into_iter().map(|r| Self::row_to_channel(&r)).collect()
+    }
+
+    #[instrument(
+        name = "channel_repository.find_directory_entries",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn find_directory_entries(&self) -> Result<Vec<ChannelDirectoryEntry>, ChannelError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                c.name,
+                c.description,
+                (SELECT COUNT(*) FROM channel_participants p WHERE p.channel_id = c.id) AS member_count
+            FROM channels c
+            WHERE c.channel_type = 'public' AND c.discoverable
+            ORDER BY c.name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
+
         rows.into_iter()
             .map(|r| {
-                Self::row_to_channel(
-                    r.get("id"),
-                    r.get("name"),
-                    r.get("description"),
-                    r.get("created_by"),
-                    r.get("created_at"),
-                    r.get("channel_type"),
-                )
+                let name: Option<String> = r.get("name");
+                let member_count: i64 = r.get("member_count");
+                Ok(ChannelDirectoryEntry {
+                    name: ChannelName::new(name.unwrap_or_default())?,
+                    description: r.get("description"),
+                    member_count: member_count.max(0) as u64,
+                })
             })
             .collect()
     }
 
+    #[instrument(
+        name = "channel_repository.find_by_user",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
     async fn find_by_user(&self, user_id: UserId) -> Result<Vec<Channel>, ChannelError> {
         let rows = sqlx::query(
             r#"
-            SELECT id, name, description, created_by, created_at, channel_type
+            SELECT id, workspace_id, name, description, created_by, created_at, channel_type, embeddable, discoverable, announcement_only, auto_join
             FROM channels
             WHERE created_by = $1
+               OR id IN (SELECT channel_id FROM channel_members WHERE user_id = $1)
             ORDER BY created_at DESC
             "#,
         )
@@ -179,20 +256,77 @@ impl ChannelRepository for PostgresChannelRepository {
         .await
         .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
 
-        rows.into_iter()
-            .map(|r| {
-                Self::row_to_channel(
-                    r.get("id"),
-                    r.get("name"),
-                    r.get("description"),
-                    r.get("created_by"),
-                    r.get("created_at"),
-                    r.get("channel_type"),
-                )
-            })
-            .collect()
+        rows.into_iter().map(|r| Self::row_to_channel(&r)).collect()
+    }
+
+    #[instrument(
+        name = "channel_repository.set_auto_join",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn set_auto_join(&self, id: ChannelId, auto_join: bool) -> Result<(), ChannelError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE channels
+            SET auto_join = $2
+            WHERE id = $1
+            "#,
+        )
+        .bind(id.as_uuid())
+        .bind(auto_join)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(ChannelError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(
+        name = "channel_repository.add_to_auto_join_channels",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn add_to_auto_join_channels(
+        &self,
+        user_id: UserId,
+        workspace_id: WorkspaceId,
+        joined_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<ChannelId>, ChannelError> {
+        let rows = sqlx::query(
+            r#"
+            INSERT INTO channel_members (channel_id, user_id, joined_at)
+            SELECT id, $1, $3
+            FROM channels
+            WHERE workspace_id = $2 AND channel_type = 'public' AND auto_join
+            ON CONFLICT (channel_id, user_id) DO NOTHING
+            RETURNING channel_id
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .bind(workspace_id.as_uuid())
+        .bind(joined_at)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| ChannelId(row.get("channel_id")))
+            .collect())
     }
 
+    #[instrument(
+        name = "channel_repository.delete",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
     async fn delete(&self, id: ChannelId) -> Result<(), ChannelError> {
         sqlx::query(
             r#"
@@ -207,4 +341,135 @@ impl ChannelRepository for PostgresChannelRepository {
 
         Ok(())
     }
+
+    #[instrument(
+        name = "channel_repository.record_message_activity",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn record_message_activity(
+        &self,
+        id: ChannelId,
+        user_id: UserId,
+        sent_at: chrono::DateTime<chrono::Utc>,
+        language: Option<LanguageCode>,
+    ) -> Result<(), ChannelError> {
+        let mut transaction = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO channel_activity (channel_id, message_count, last_activity_at)
+            VALUES ($1, 1, $2)
+            ON CONFLICT (channel_id) DO UPDATE
+            SET message_count = channel_activity.message_count + 1,
+                last_activity_at = GREATEST(channel_activity.last_activity_at, EXCLUDED.last_activity_at)
+            "#,
+        )
+        .bind(id.as_uuid())
+        .bind(sent_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO channel_participants (channel_id, user_id, first_seen_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (channel_id, user_id) DO NOTHING
+            "#,
+        )
+        .bind(id.as_uuid())
+        .bind(user_id.as_uuid())
+        .bind(sent_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
+
+        if let Some(language) = language {
+            sqlx::query(
+                r#"
+                INSERT INTO channel_languages (channel_id, language, message_count)
+                VALUES ($1, $2, 1)
+                ON CONFLICT (channel_id, language) DO UPDATE
+                SET message_count = channel_languages.message_count + 1
+                "#,
+            )
+            .bind(id.as_uuid())
+            .bind(language.as_str())
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
+        }
+
+        transaction
+            .commit()
+            .await
+            .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(
+        name = "channel_repository.find_activity",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn find_activity(&self, id: ChannelId) -> Result<ChannelActivity, ChannelError> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                a.message_count,
+                a.last_activity_at,
+                (SELECT COUNT(*) FROM channel_participants p WHERE p.channel_id = $1) AS participant_count
+            FROM (SELECT $1::uuid AS channel_id) c
+            LEFT JOIN channel_activity a ON a.channel_id = c.channel_id
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
+
+        let message_count: Option<i64> = row.get("message_count");
+        let participant_count: i64 = row.get("participant_count");
+
+        let language_rows = sqlx::query(
+            r#"
+            SELECT language, message_count
+            FROM channel_languages
+            WHERE channel_id = $1
+            ORDER BY message_count DESC, language
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ChannelError::DatabaseError(e.to_string()))?;
+
+        let languages = language_rows
+            .iter()
+            .map(|row| {
+                let language: String = row.get("language");
+                let message_count: i64 = row.get("message_count");
+                Ok(LanguageCount {
+                    language: LanguageCode::new(&language)
+                        .map_err(|e| ChannelError::DatabaseError(e.to_string()))?,
+                    message_count: message_count.max(0) as u64,
+                })
+            })
+            .collect::<Result<Vec<_>, ChannelError>>()?;
+
+        Ok(ChannelActivity {
+            message_count: message_count.unwrap_or(0).max(0) as u64,
+            participant_count: participant_count.max(0) as u64,
+            last_activity_at: row.get("last_activity_at"),
+            languages,
+        })
+    }
 }
@@ -0,0 +1,284 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use sqlx::postgres::PgRow;
+use sqlx::PgPool;
+use sqlx::Row;
+use tracing::instrument;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::feed::errors::FeedError;
+use crate::domain::feed::models::Feed;
+use crate::domain::feed::models::FeedId;
+use crate::domain::feed::models::FeedUrl;
+use crate::domain::feed::ports::FeedRepository;
+use crate::domain::user::models::UserId;
+
+const CHANNEL_URL_CONSTRAINT: &str = "channel_feeds_channel_id_url_key";
+
+pub struct PostgresFeedRepository {
+    pool: PgPool,
+}
+
+impl PostgresFeedRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_feed(row: &PgRow) -> Result<Feed, FeedError> {
+        Ok(Feed {
+            id: FeedId(row.get("id")),
+            channel_id: ChannelId(row.get("channel_id")),
+            url: FeedUrl::from_stored(row.get("url")),
+            created_by: UserId(row.get("created_by")),
+            max_posts_per_hour: row.get::<i32, _>("max_posts_per_hour").max(0) as u32,
+            last_polled_at: row.get("last_polled_at"),
+            last_error: row.get("last_error"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl FeedRepository for PostgresFeedRepository {
+    #[instrument(
+        name = "feed_repository.create",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn create(&self, feed: Feed) -> Result<Feed, FeedError> {
+        sqlx::query(
+            r#"
+            INSERT INTO channel_feeds (id, channel_id, url, created_by, max_posts_per_hour, last_polled_at, last_error, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(feed.id.as_uuid())
+        .bind(feed.channel_id.as_uuid())
+        .bind(feed.url.as_str())
+        .bind(feed.created_by.as_uuid())
+        .bind(feed.max_posts_per_hour as i32)
+        .bind(feed.last_polled_at)
+        .bind(&feed.last_error)
+        .bind(feed.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if let Some(db_err) = e.as_database_error() {
+                if db_err.is_unique_violation()
+                    && db_err.constraint() == Some(CHANNEL_URL_CONSTRAINT)
+                {
+                    return FeedError::AlreadyRegistered(feed.url.to_string());
+                }
+            }
+            FeedError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(feed)
+    }
+
+    #[instrument(
+        name = "feed_repository.find_by_channel",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn find_by_channel(&self, channel_id: ChannelId) -> Result<Vec<Feed>, FeedError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, channel_id, url, created_by, max_posts_per_hour, last_polled_at, last_error, created_at
+            FROM channel_feeds
+            WHERE channel_id = $1
+            ORDER BY created_at
+            "#,
+        )
+        .bind(channel_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| FeedError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(Self::row_to_feed).collect()
+    }
+
+    #[instrument(
+        name = "feed_repository.delete",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn delete(
+        &self,
+        channel_id: ChannelId,
+        feed_id: FeedId,
+    ) -> Result<Option<Feed>, FeedError> {
+        let row = sqlx::query(
+            r#"
+            DELETE FROM channel_feeds
+            WHERE id = $1 AND channel_id = $2
+            RETURNING id, channel_id, url, created_by, max_posts_per_hour, last_polled_at, last_error, created_at
+            "#,
+        )
+        .bind(feed_id.as_uuid())
+        .bind(channel_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| FeedError::DatabaseError(e.to_string()))?;
+
+        row.as_ref().map(Self::row_to_feed).transpose()
+    }
+
+    #[instrument(
+        name = "feed_repository.find_due",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn find_due(
+        &self,
+        polled_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Feed>, FeedError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, channel_id, url, created_by, max_posts_per_hour, last_polled_at, last_error, created_at
+            FROM channel_feeds
+            WHERE last_polled_at IS NULL OR last_polled_at < $1
+            ORDER BY last_polled_at NULLS FIRST
+            LIMIT $2
+            "#,
+        )
+        .bind(polled_before)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| FeedError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(Self::row_to_feed).collect()
+    }
+
+    #[instrument(
+        name = "feed_repository.record_poll",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn record_poll(
+        &self,
+        feed_id: FeedId,
+        polled_at: DateTime<Utc>,
+        error: Option<String>,
+    ) -> Result<(), FeedError> {
+        sqlx::query(
+            r#"
+            UPDATE channel_feeds
+            SET last_polled_at = $2, last_error = $3
+            WHERE id = $1
+            "#,
+        )
+        .bind(feed_id.as_uuid())
+        .bind(polled_at)
+        .bind(error)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| FeedError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(
+        name = "feed_repository.filter_unseen",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn filter_unseen(
+        &self,
+        feed_id: FeedId,
+        entry_keys: Vec<String>,
+    ) -> Result<Vec<String>, FeedError> {
+        if entry_keys.is_empty() {
+            return Ok(entry_keys);
+        }
+
+        let rows = sqlx::query(
+            r#"
+            SELECT entry_key
+            FROM channel_feed_entries
+            WHERE feed_id = $1 AND entry_key = ANY($2)
+            "#,
+        )
+        .bind(feed_id.as_uuid())
+        .bind(&entry_keys)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| FeedError::DatabaseError(e.to_string()))?;
+
+        let seen: Vec<String> = rows.iter().map(|row| row.get("entry_key")).collect();
+        Ok(entry_keys
+            .into_iter()
+            .filter(|key| !seen.contains(key))
+            .collect())
+    }
+
+    #[instrument(
+        name = "feed_repository.mark_seen",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn mark_seen(
+        &self,
+        feed_id: FeedId,
+        entry_keys: Vec<String>,
+        posted_at: Option<DateTime<Utc>>,
+    ) -> Result<(), FeedError> {
+        if entry_keys.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO channel_feed_entries (feed_id, entry_key, seen_at, posted_at)
+            SELECT $1, entry_key, NOW(), $3
+            FROM UNNEST($2::TEXT[]) AS entry_key
+            ON CONFLICT (feed_id, entry_key) DO NOTHING
+            "#,
+        )
+        .bind(feed_id.as_uuid())
+        .bind(&entry_keys)
+        .bind(posted_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| FeedError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(
+        name = "feed_repository.count_posted_since",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn count_posted_since(
+        &self,
+        feed_id: FeedId,
+        since: DateTime<Utc>,
+    ) -> Result<u32, FeedError> {
+        let row = sqlx::query(
+            r#"
+            SELECT COUNT(*) AS posted
+            FROM channel_feed_entries
+            WHERE feed_id = $1 AND posted_at >= $2
+            "#,
+        )
+        .bind(feed_id.as_uuid())
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| FeedError::DatabaseError(e.to_string()))?;
+
+        Ok(row.get::<i64, _>("posted").max(0) as u32)
+    }
+}
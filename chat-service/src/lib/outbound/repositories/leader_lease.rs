@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use sqlx::Row;
+use tracing::instrument;
+
+use crate::domain::leader::errors::LeaderError;
+use crate::domain::leader::models::LeaderLease;
+use crate::domain::leader::ports::LeaderLeaseRepository;
+use crate::domain::lock::models::InstanceId;
+
+pub struct PostgresLeaderLeaseRepository {
+    pool: PgPool,
+}
+
+impl PostgresLeaderLeaseRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LeaderLeaseRepository for PostgresLeaderLeaseRepository {
+    #[instrument(
+        name = "leader_lease_repository.record",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn record(&self, lease: &LeaderLease) -> Result<(), LeaderError> {
+        sqlx::query(
+            r#"
+            INSERT INTO leader_leases (name, instance_id, elected_at, renewed_at, expires_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (name)
+            DO UPDATE SET
+                instance_id = EXCLUDED.instance_id,
+                elected_at = EXCLUDED.elected_at,
+                renewed_at = EXCLUDED.renewed_at,
+                expires_at = EXCLUDED.expires_at
+            "#,
+        )
+        .bind(&lease.name)
+        .bind(lease.instance_id.as_uuid())
+        .bind(lease.elected_at)
+        .bind(lease.renewed_at)
+        .bind(lease.expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| LeaderError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(
+        name = "leader_lease_repository.find",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn find(&self, name: &str) -> Result<Option<LeaderLease>, LeaderError> {
+        let row = sqlx::query(
+            r#"
+            SELECT name, instance_id, elected_at, renewed_at, expires_at
+            FROM leader_leases
+            WHERE name = $1 AND expires_at > NOW()
+            "#,
+        )
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| LeaderError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(|r| LeaderLease {
+            name: r.get("name"),
+            instance_id: InstanceId::from_uuid(r.get("instance_id")),
+            elected_at: r.get("elected_at"),
+            renewed_at: r.get("renewed_at"),
+            expires_at: r.get("expires_at"),
+        }))
+    }
+
+    #[instrument(
+        name = "leader_lease_repository.clear",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn clear(&self, name: &str, instance_id: &InstanceId) -> Result<(), LeaderError> {
+        sqlx::query(
+            r#"
+            DELETE FROM leader_leases
+            WHERE name = $1 AND instance_id = $2
+            "#,
+        )
+        .bind(name)
+        .bind(instance_id.as_uuid())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| LeaderError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
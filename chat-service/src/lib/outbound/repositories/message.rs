@@ -1,30 +1,52 @@
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use async_trait::async_trait;
 use chrono::DateTime;
 use chrono::Utc;
+use scylla::frame::response::result::CqlValue;
 use scylla::frame::value::CqlTimeuuid;
+use scylla::serialize::row::SerializeRow;
+use scylla::statement::query::Query;
+use scylla::transport::errors::QueryError;
+use scylla::ExecutionProfile;
+use scylla::QueryResult;
 use scylla::Session;
 use scylla::SessionBuilder;
+use tracing::instrument;
 use uuid::Uuid;
 
 use crate::config::Config;
 use crate::domain::channel::models::ChannelId;
 use crate::domain::message::errors::MessageError;
+use crate::domain::message::models::HistoryEntry;
+use crate::domain::message::models::LanguageCode;
 use crate::domain::message::models::Message;
 use crate::domain::message::models::MessageContent;
 use crate::domain::message::models::MessageId;
+use crate::domain::message::models::MessageTombstone;
 use crate::domain::message::ports::MessageRepository;
 use crate::domain::user::models::UserId;
+use crate::outbound::repositories::slow_query::SlowQueryLog;
 
 pub struct CassandraMessageRepository {
     session: Arc<Session>,
+    slow_queries: SlowQueryLog,
 }
 
 impl CassandraMessageRepository {
     pub async fn new(config: &Config) -> Result<Self, anyhow::Error> {
+        // Every statement of the session fails once it runs past the timeout
+        let request_timeout = Some(config.cassandra.request_timeout_ms)
+            .filter(|timeout_ms| *timeout_ms > 0)
+            .map(Duration::from_millis);
+        let profile = ExecutionProfile::builder()
+            .request_timeout(request_timeout)
+            .build();
         let session = SessionBuilder::new()
             .known_nodes(&config.cassandra.nodes)
+            .default_execution_profile_handle(profile.into_handle())
             .build()
             .await?;
 
@@ -47,179 +69,596 @@ impl CassandraMessageRepository {
             .use_keyspace(&config.cassandra.keyspace, false)
             .await?;
 
-        // Create a messages_by_channel table
-        session
-            .query(
-                "CREATE TABLE IF NOT EXISTS messages_by_channel (
-                    channel_id uuid,
-                    message_id timeuuid,
-                    user_id uuid,
-                    content text,
-                    timestamp timestamp,
-                    PRIMARY KEY (channel_id, message_id)
-                ) WITH CLUSTERING ORDER BY (message_id DESC)",
-                &[],
+        // TimeUUID and UUIDv7 messages live in separate tables, as timeuuid only accepts v1
+        for (tables, id_type) in [(&TIMEUUID_TABLES, "timeuuid"), (&V7_TABLES, "uuid")] {
+            session
+                .query(
+                    format!(
+                        "CREATE TABLE IF NOT EXISTS {} (
+                            channel_id uuid,
+                            message_id {},
+                            user_id uuid,
+                            content text,
+                            timestamp timestamp,
+                            language text,
+                            deleted_at timestamp,
+                            deleted_by uuid,
+                            PRIMARY KEY (channel_id, message_id)
+                        ) WITH CLUSTERING ORDER BY (message_id DESC)",
+                        tables.by_channel, id_type
+                    ),
+                    &[],
+                )
+                .await?;
+
+            session
+                .query(
+                    format!(
+                        "CREATE TABLE IF NOT EXISTS {} (
+                            user_id uuid,
+                            message_id {},
+                            channel_id uuid,
+                            content text,
+                            timestamp timestamp,
+                            language text,
+                            PRIMARY KEY (user_id, message_id)
+                        ) WITH CLUSTERING ORDER BY (message_id DESC)",
+                        tables.by_user, id_type
+                    ),
+                    &[],
+                )
+                .await?;
+        }
+
+        // Tables created before language detection lack the column
+        for table in ["messages_by_channel", "messages_by_user"] {
+            add_column_if_missing(
+                &session,
+                &config.cassandra.keyspace,
+                table,
+                "language",
+                "text",
             )
             .await?;
-
-        // Create a messages_by_user table
-        session
-            .query(
-                "CREATE TABLE IF NOT EXISTS messages_by_user (
-                    user_id uuid,
-                    message_id timeuuid,
-                    channel_id uuid,
-                    content text,
-                    timestamp timestamp,
-                    PRIMARY KEY (user_id, message_id)
-                ) WITH CLUSTERING ORDER BY (message_id DESC)",
-                &[],
+        }
+        // Tables created before message deletion lack the tombstone columns
+        for (column, column_type) in [("deleted_at", "timestamp"), ("deleted_by", "uuid")] {
+            add_column_if_missing(
+                &session,
+                &config.cassandra.keyspace,
+                "messages_by_channel",
+                column,
+                column_type,
             )
             .await?;
+        }
 
         Ok(Self {
             session: Arc::new(session),
+            slow_queries: SlowQueryLog::new(
+                "cassandra",
+                Duration::from_millis(config.cassandra.slow_query_threshold_ms),
+            ),
         })
     }
-}
 
-#[async_trait]
-impl MessageRepository for CassandraMessageRepository {
-    async fn create(&self, message: Message) -> Result<Message, MessageError> {
-        // Convert domain Uuid to CqlTimeuuid for Cassandra
-        let message_id_timeuuid = CqlTimeuuid::from(*message.id.as_uuid());
+    /// Run a statement, logging it when it is slower than the slow query threshold.
+    async fn query(
+        &self,
+        statement: impl Into<Query>,
+        values: impl SerializeRow,
+    ) -> Result<QueryResult, QueryError> {
+        let statement = statement.into();
+        let started = Instant::now();
+        let result = self.session.query(statement.clone(), values).await;
+        self.slow_queries
+            .record(&statement.contents, started.elapsed());
+        result
+    }
+}
 
-        // Insert into messages_by_channel (denormalized)
-        self.session
+async fn add_column_if_missing(
+    session: &Session,
+    keyspace: &str,
+    table: &str,
+    column: &str,
+    column_type: &str,
+) -> Result<(), anyhow::Error> {
+    let existing = session
+        .query(
+            "SELECT column_name FROM system_schema.columns
+             WHERE keyspace_name = ? AND table_name = ? AND column_name = ?",
+            (keyspace, table, column),
+        )
+        .await?;
+    if existing.rows_num()? == 0 {
+        session
             .query(
-                "INSERT INTO messages_by_channel (channel_id, message_id, user_id, content, timestamp)
-                 VALUES (?, ?, ?, ?, ?)",
-                (
-                    message.channel_id.as_uuid(),
-                    message_id_timeuuid,
-                    message.user_id.as_uuid(),
-                    message.content.as_str(),
-                    message.timestamp,
-                ),
+                format!("ALTER TABLE {} ADD {} {}", table, column, column_type),
+                &[],
             )
-            .await
-            .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
+            .await?;
+    }
+    Ok(())
+}
 
-        // Insert into messages_by_user (denormalized)
-        self.session
-            .query(
-                "INSERT INTO messages_by_user (user_id, message_id, channel_id, content, timestamp)
-                 VALUES (?, ?, ?, ?, ?)",
-                (
-                    message.user_id.as_uuid(),
-                    message_id_timeuuid,
-                    message.channel_id.as_uuid(),
-                    message.content.as_str(),
-                    message.timestamp,
-                ),
-            )
-            .await
-            .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
+/// Tables holding the messages of one ID version.
+struct MessageTables {
+    by_channel: &'static str,
+    by_user: &'static str,
+    v7: bool,
+}
 
-        Ok(message)
+/// Messages with TimeUUID IDs, in `timeuuid` clustering columns
+const TIMEUUID_TABLES: MessageTables = MessageTables {
+    by_channel: "messages_by_channel",
+    by_user: "messages_by_user",
+    v7: false,
+};
+
+/// Messages with UUIDv7 IDs, in `uuid` clustering columns
+const V7_TABLES: MessageTables = MessageTables {
+    by_channel: "messages_by_channel_v7",
+    by_user: "messages_by_user_v7",
+    v7: true,
+};
+
+/// Every message table pair; reads merge all of them.
+const ALL_TABLES: [&MessageTables; 2] = [&TIMEUUID_TABLES, &V7_TABLES];
+
+fn tables_for(message_id: MessageId) -> &'static MessageTables {
+    if message_id.is_v7() {
+        &V7_TABLES
+    } else {
+        &TIMEUUID_TABLES
     }
+}
 
-    async fn find_by_channel(
+/// Bind a message ID with the column type of its table.
+fn id_value(message_id: MessageId) -> CqlValue {
+    if message_id.is_v7() {
+        CqlValue::Uuid(message_id.into_uuid())
+    } else {
+        CqlValue::Timeuuid(CqlTimeuuid::from(message_id.into_uuid()))
+    }
+}
+
+fn id_from_value(value: CqlValue) -> Result<MessageId, MessageError> {
+    match value {
+        CqlValue::Uuid(uuid) => Ok(MessageId(uuid)),
+        CqlValue::Timeuuid(timeuuid) => Ok(MessageId(timeuuid.into())),
+        other => Err(MessageError::DatabaseError(format!(
+            "Unexpected message ID value: {:?}",
+            other
+        ))),
+    }
+}
+
+/// Greatest UUIDv7 of the millisecond of `time`, the UUIDv7 counterpart of `maxTimeuuid`.
+fn max_v7_at(time: DateTime<Utc>) -> Uuid {
+    let millis = time.timestamp_millis() as u64;
+    Uuid::from_u64_pair((millis << 16) | 0x7fff, 0xbfff_ffff_ffff_ffff)
+}
+
+/// Merge the newest entries of several tables into one newest-first page.
+fn newest_first<T>(mut entries: Vec<T>, id: impl Fn(&T) -> MessageId, limit: i32) -> Vec<T> {
+    entries.sort_by_key(|entry| std::cmp::Reverse(id(entry)));
+    entries.truncate(usize::try_from(limit).unwrap_or_default());
+    entries
+}
+
+fn parse_language(language: Option<String>) -> Option<LanguageCode> {
+    language.and_then(|code| LanguageCode::new(&code).ok())
+}
+
+/// Row of `messages_by_channel`, content erased and deletion columns set for tombstones.
+type ChannelRow = (
+    Uuid,
+    CqlValue,
+    Uuid,
+    Option<String>,
+    DateTime<Utc>,
+    Option<String>,
+    Option<DateTime<Utc>>,
+    Option<Uuid>,
+);
+
+const CHANNEL_COLUMNS: &str =
+    "channel_id, message_id, user_id, content, timestamp, language, deleted_at, deleted_by";
+
+fn row_to_entry(row: ChannelRow) -> Result<HistoryEntry, MessageError> {
+    let (channel_id, message_id, user_id, content, timestamp, language, deleted_at, deleted_by) =
+        row;
+    let message_id = id_from_value(message_id)?;
+
+    if let Some(deleted_at) = deleted_at {
+        return Ok(HistoryEntry::Deleted(MessageTombstone {
+            id: message_id,
+            channel_id: ChannelId(channel_id),
+            deleted_at,
+            deleted_by: UserId(deleted_by.unwrap_or(user_id)),
+        }));
+    }
+
+    Ok(HistoryEntry::Message(Message {
+        id: message_id,
+        channel_id: ChannelId(channel_id),
+        user_id: UserId(user_id),
+        content: MessageContent::new(content.unwrap_or_default())?,
+        timestamp,
+        language: parse_language(language),
+    }))
+}
+
+impl CassandraMessageRepository {
+    async fn query_channel(
         &self,
         channel_id: ChannelId,
         limit: i32,
         before: Option<DateTime<Utc>>,
-    ) -> Result<Vec<Message>, MessageError> {
-        let query = if let Some(before_time) = before {
-            self.session
-                .query(
-                    "SELECT channel_id, message_id, user_id, content, timestamp
-                     FROM messages_by_channel
-                     WHERE channel_id = ? AND message_id < maxTimeuuid(?)
-                     LIMIT ?",
+    ) -> Result<Vec<HistoryEntry>, MessageError> {
+        let mut entries = Vec::new();
+        for tables in ALL_TABLES {
+            entries.extend(
+                self.query_channel_table(tables, channel_id, limit, before)
+                    .await?,
+            );
+        }
+
+        Ok(newest_first(entries, HistoryEntry::id, limit))
+    }
+
+    async fn query_channel_table(
+        &self,
+        tables: &MessageTables,
+        channel_id: ChannelId,
+        limit: i32,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<Vec<HistoryEntry>, MessageError> {
+        let query = match before {
+            Some(before_time) if tables.v7 => {
+                self.query(
+                    format!(
+                        "SELECT {} FROM {}
+                             WHERE channel_id = ? AND message_id < ?
+                             LIMIT ?",
+                        CHANNEL_COLUMNS, tables.by_channel
+                    ),
+                    (channel_id.as_uuid(), max_v7_at(before_time), limit),
+                )
+                .await
+            }
+            Some(before_time) => {
+                self.query(
+                    format!(
+                        "SELECT {} FROM {}
+                             WHERE channel_id = ? AND message_id < maxTimeuuid(?)
+                             LIMIT ?",
+                        CHANNEL_COLUMNS, tables.by_channel
+                    ),
                     (channel_id.as_uuid(), before_time, limit),
                 )
                 .await
-        } else {
-            self.session
-                .query(
-                    "SELECT channel_id, message_id, user_id, content, timestamp
-                     FROM messages_by_channel
-                     WHERE channel_id = ?
-                     LIMIT ?",
+            }
+            None => {
+                self.query(
+                    format!(
+                        "SELECT {} FROM {}
+                             WHERE channel_id = ?
+                             LIMIT ?",
+                        CHANNEL_COLUMNS, tables.by_channel
+                    ),
                     (channel_id.as_uuid(), limit),
                 )
                 .await
+            }
         };
 
         let rows = query.map_err(|e| MessageError::DatabaseError(e.to_string()))?;
 
+        rows.rows
+            .unwrap_or_default()
+            .into_iter()
+            .map(|row| {
+                row.into_typed::<ChannelRow>()
+                    .map_err(|e| MessageError::DatabaseError(e.to_string()))
+                    .and_then(row_to_entry)
+            })
+            .collect()
+    }
+
+    async fn query_user_table(
+        &self,
+        tables: &MessageTables,
+        user_id: UserId,
+        limit: i32,
+    ) -> Result<Vec<Message>, MessageError> {
+        let rows = self
+            .query(
+                format!(
+                    "SELECT user_id, message_id, channel_id, content, timestamp, language
+                     FROM {}
+                     WHERE user_id = ?
+                     LIMIT ?",
+                    tables.by_user
+                ),
+                (user_id.as_uuid(), limit),
+            )
+            .await
+            .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
+
         let mut messages = Vec::new();
         if let Some(rows) = rows.rows {
             for row in rows {
-                let (channel_id, message_id_timeuuid, user_id, content, timestamp): (
-                    Uuid,
-                    CqlTimeuuid,
-                    Uuid,
-                    String,
-                    DateTime<Utc>,
-                ) = row
-                    .into_typed::<(Uuid, CqlTimeuuid, Uuid, String, DateTime<Utc>)>()
+                let (user_id, message_id, channel_id, content, timestamp, language) = row
+                    .into_typed::<(Uuid, CqlValue, Uuid, String, DateTime<Utc>, Option<String>)>()
                     .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
 
                 messages.push(Message {
-                    id: MessageId(message_id_timeuuid.into()),
+                    id: id_from_value(message_id)?,
                     channel_id: ChannelId(channel_id),
                     user_id: UserId(user_id),
                     content: MessageContent::new(content)?,
                     timestamp,
+                    language: parse_language(language),
                 });
             }
         }
 
         Ok(messages)
     }
+}
+
+#[async_trait]
+impl MessageRepository for CassandraMessageRepository {
+    #[instrument(
+        name = "message_repository.create",
+        skip_all,
+        fields(db.system = "cassandra"),
+        err(level = "warn")
+    )]
+    async fn create(&self, message: Message) -> Result<Message, MessageError> {
+        let tables = tables_for(message.id);
+        let message_id = id_value(message.id);
+
+        // Insert into messages_by_channel (denormalized)
+        self.query(
+            format!(
+                "INSERT INTO {} (channel_id, message_id, user_id, content, timestamp, language)
+                     VALUES (?, ?, ?, ?, ?, ?)",
+                tables.by_channel
+            ),
+            (
+                message.channel_id.as_uuid(),
+                &message_id,
+                message.user_id.as_uuid(),
+                message.content.as_str(),
+                message.timestamp,
+                message.language.as_ref().map(|language| language.as_str()),
+            ),
+        )
+        .await
+        .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
+
+        // Insert into messages_by_user (denormalized)
+        self.query(
+            format!(
+                "INSERT INTO {} (user_id, message_id, channel_id, content, timestamp, language)
+                     VALUES (?, ?, ?, ?, ?, ?)",
+                tables.by_user
+            ),
+            (
+                message.user_id.as_uuid(),
+                &message_id,
+                message.channel_id.as_uuid(),
+                message.content.as_str(),
+                message.timestamp,
+                message.language.as_ref().map(|language| language.as_str()),
+            ),
+        )
+        .await
+        .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
+
+        Ok(message)
+    }
+
+    #[instrument(
+        name = "message_repository.create_if_absent",
+        skip_all,
+        fields(db.system = "cassandra"),
+        err(level = "warn")
+    )]
+    async fn create_if_absent(&self, message: Message) -> Result<Message, MessageError> {
+        let tables = tables_for(message.id);
+        let message_id = id_value(message.id);
+
+        // Lightweight transaction: the ID is claimed in channel history first
+        let result = self
+            .query(
+                format!(
+                    "INSERT INTO {} (channel_id, message_id, user_id, content, timestamp, language)
+                     VALUES (?, ?, ?, ?, ?, ?)
+                     IF NOT EXISTS",
+                    tables.by_channel
+                ),
+                (
+                    message.channel_id.as_uuid(),
+                    &message_id,
+                    message.user_id.as_uuid(),
+                    message.content.as_str(),
+                    message.timestamp,
+                    message.language.as_ref().map(|language| language.as_str()),
+                ),
+            )
+            .await
+            .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
+
+        let applied = result
+            .first_row()
+            .ok()
+            .and_then(|row| row.columns.into_iter().next().flatten())
+            .and_then(|applied| applied.as_boolean())
+            .ok_or_else(|| {
+                MessageError::DatabaseError("Missing [applied] column in LWT result".to_string())
+            })?;
+        if !applied {
+            return Err(MessageError::IdAlreadyExists(message.id));
+        }
+
+        self.query(
+            format!(
+                "INSERT INTO {} (user_id, message_id, channel_id, content, timestamp, language)
+                     VALUES (?, ?, ?, ?, ?, ?)",
+                tables.by_user
+            ),
+            (
+                message.user_id.as_uuid(),
+                &message_id,
+                message.channel_id.as_uuid(),
+                message.content.as_str(),
+                message.timestamp,
+                message.language.as_ref().map(|language| language.as_str()),
+            ),
+        )
+        .await
+        .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
+
+        Ok(message)
+    }
+
+    #[instrument(
+        name = "message_repository.find_by_channel",
+        skip_all,
+        fields(db.system = "cassandra"),
+        err(level = "warn")
+    )]
+    async fn find_by_channel(
+        &self,
+        channel_id: ChannelId,
+        limit: i32,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Message>, MessageError> {
+        let entries = self.query_channel(channel_id, limit, before).await?;
+
+        Ok(entries
+            .into_iter()
+            .filter_map(|entry| match entry {
+                HistoryEntry::Message(message) => Some(message),
+                HistoryEntry::Deleted(_) => None,
+            })
+            .collect())
+    }
 
+    #[instrument(
+        name = "message_repository.find_by_user",
+        skip_all,
+        fields(db.system = "cassandra"),
+        err(level = "warn")
+    )]
     async fn find_by_user(
         &self,
         user_id: UserId,
         limit: i32,
     ) -> Result<Vec<Message>, MessageError> {
+        let mut messages = Vec::new();
+        for tables in ALL_TABLES {
+            messages.extend(self.query_user_table(tables, user_id, limit).await?);
+        }
+
+        Ok(newest_first(messages, |message| message.id, limit))
+    }
+
+    #[instrument(
+        name = "message_repository.find_by_id",
+        skip_all,
+        fields(db.system = "cassandra"),
+        err(level = "warn")
+    )]
+    async fn find_by_id(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<Option<Message>, MessageError> {
         let rows = self
-            .session
             .query(
-                "SELECT user_id, message_id, channel_id, content, timestamp
-                 FROM messages_by_user
-                 WHERE user_id = ?
-                 LIMIT ?",
-                (user_id.as_uuid(), limit),
+                format!(
+                    "SELECT {} FROM {}
+                     WHERE channel_id = ? AND message_id = ?",
+                    CHANNEL_COLUMNS,
+                    tables_for(message_id).by_channel
+                ),
+                (channel_id.as_uuid(), id_value(message_id)),
             )
             .await
             .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
 
-        let mut messages = Vec::new();
-        if let Some(rows) = rows.rows {
-            for row in rows {
-                let (user_id, message_id_timeuuid, channel_id, content, timestamp): (
-                    Uuid,
-                    CqlTimeuuid,
-                    Uuid,
-                    String,
-                    DateTime<Utc>,
-                ) = row
-                    .into_typed::<(Uuid, CqlTimeuuid, Uuid, String, DateTime<Utc>)>()
-                    .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
+        let row = rows
+            .maybe_first_row_typed::<ChannelRow>()
+            .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
 
-                messages.push(Message {
-                    id: MessageId(message_id_timeuuid.into()),
-                    channel_id: ChannelId(channel_id),
-                    user_id: UserId(user_id),
-                    content: MessageContent::new(content)?,
-                    timestamp,
-                });
-            }
+        match row.map(row_to_entry).transpose()? {
+            Some(HistoryEntry::Message(message)) => Ok(Some(message)),
+            Some(HistoryEntry::Deleted(_)) | None => Ok(None),
         }
+    }
 
-        Ok(messages)
+    #[instrument(
+        name = "message_repository.find_history",
+        skip_all,
+        fields(db.system = "cassandra"),
+        err(level = "warn")
+    )]
+    async fn find_history(
+        &self,
+        channel_id: ChannelId,
+        limit: i32,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<Vec<HistoryEntry>, MessageError> {
+        self.query_channel(channel_id, limit, before).await
+    }
+
+    #[instrument(
+        name = "message_repository.delete",
+        skip_all,
+        fields(db.system = "cassandra"),
+        err(level = "warn")
+    )]
+    async fn delete(
+        &self,
+        message: &Message,
+        tombstone: MessageTombstone,
+    ) -> Result<MessageTombstone, MessageError> {
+        let tables = tables_for(message.id);
+        let message_id = id_value(message.id);
+
+        // Keep the row in channel history as a tombstone, without the content
+        self.query(
+            format!(
+                "UPDATE {}
+                     SET content = null, language = null, deleted_at = ?, deleted_by = ?
+                     WHERE channel_id = ? AND message_id = ?",
+                tables.by_channel
+            ),
+            (
+                tombstone.deleted_at,
+                tombstone.deleted_by.as_uuid(),
+                message.channel_id.as_uuid(),
+                &message_id,
+            ),
+        )
+        .await
+        .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
+
+        self.query(
+            format!(
+                "DELETE FROM {} WHERE user_id = ? AND message_id = ?",
+                tables.by_user
+            ),
+            (message.user_id.as_uuid(), &message_id),
+        )
+        .await
+        .map_err(|e| MessageError::DatabaseError(e.to_string()))?;
+
+        Ok(tombstone)
     }
 }
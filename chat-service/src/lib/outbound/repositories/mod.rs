@@ -1,7 +1,18 @@
 pub mod channel;
+pub mod feed;
+pub mod leader_lease;
 pub mod message;
+pub mod poll;
+pub mod reminder;
+pub mod slow_query;
+pub mod star;
 pub mod user_replica;
 
 pub use channel::PostgresChannelRepository;
+pub use feed::PostgresFeedRepository;
+pub use leader_lease::PostgresLeaderLeaseRepository;
 pub use message::CassandraMessageRepository;
+pub use poll::PostgresPollRepository;
+pub use reminder::PostgresReminderRepository;
+pub use star::PostgresStarRepository;
 pub use user_replica::PostgresUserReplicaRepository;
@@ -0,0 +1,224 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use sqlx::postgres::PgRow;
+use sqlx::PgPool;
+use sqlx::Row;
+use tracing::instrument;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::poll::errors::PollError;
+use crate::domain::poll::models::Poll;
+use crate::domain::poll::models::PollId;
+use crate::domain::poll::ports::PollRepository;
+use crate::domain::user::models::UserId;
+
+pub struct PostgresPollRepository {
+    pool: PgPool,
+}
+
+impl PostgresPollRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_poll(row: &PgRow) -> Poll {
+        Poll {
+            id: PollId(row.get("id")),
+            channel_id: ChannelId(row.get("channel_id")),
+            created_by: UserId(row.get("created_by")),
+            question: row.get("question"),
+            options: row.get("options"),
+            closes_at: row.get("closes_at"),
+            closed_at: row.get("closed_at"),
+            created_at: row.get("created_at"),
+        }
+    }
+}
+
+#[async_trait]
+impl PollRepository for PostgresPollRepository {
+    #[instrument(
+        name = "poll_repository.create",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn create(&self, poll: Poll) -> Result<Poll, PollError> {
+        sqlx::query(
+            r#"
+            INSERT INTO channel_polls (id, channel_id, created_by, question, options, closes_at, closed_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(poll.id.as_uuid())
+        .bind(poll.channel_id.as_uuid())
+        .bind(poll.created_by.as_uuid())
+        .bind(&poll.question)
+        .bind(&poll.options)
+        .bind(poll.closes_at)
+        .bind(poll.closed_at)
+        .bind(poll.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| PollError::DatabaseError(e.to_string()))?;
+
+        Ok(poll)
+    }
+
+    #[instrument(
+        name = "poll_repository.delete",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn delete(&self, poll_id: PollId) -> Result<(), PollError> {
+        sqlx::query("DELETE FROM channel_polls WHERE id = $1")
+            .bind(poll_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| PollError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(
+        name = "poll_repository.find_by_id",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn find_by_id(
+        &self,
+        channel_id: ChannelId,
+        poll_id: PollId,
+    ) -> Result<Option<Poll>, PollError> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, channel_id, created_by, question, options, closes_at, closed_at, created_at
+            FROM channel_polls
+            WHERE id = $1 AND channel_id = $2
+            "#,
+        )
+        .bind(poll_id.as_uuid())
+        .bind(channel_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| PollError::DatabaseError(e.to_string()))?;
+
+        Ok(row.as_ref().map(Self::row_to_poll))
+    }
+
+    #[instrument(
+        name = "poll_repository.record_vote",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn record_vote(
+        &self,
+        poll_id: PollId,
+        user_id: UserId,
+        option: usize,
+    ) -> Result<bool, PollError> {
+        // The primary key keeps the first vote of each user
+        let result = sqlx::query(
+            r#"
+            INSERT INTO channel_poll_votes (poll_id, user_id, option_index)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (poll_id, user_id) DO NOTHING
+            "#,
+        )
+        .bind(poll_id.as_uuid())
+        .bind(user_id.as_uuid())
+        .bind(option as i32)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| PollError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    #[instrument(
+        name = "poll_repository.count_votes",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn count_votes(&self, poll_id: PollId) -> Result<Vec<(usize, u64)>, PollError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT option_index, COUNT(*) AS votes
+            FROM channel_poll_votes
+            WHERE poll_id = $1
+            GROUP BY option_index
+            "#,
+        )
+        .bind(poll_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| PollError::DatabaseError(e.to_string()))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                (
+                    row.get::<i32, _>("option_index").max(0) as usize,
+                    row.get::<i64, _>("votes").max(0) as u64,
+                )
+            })
+            .collect())
+    }
+
+    #[instrument(
+        name = "poll_repository.find_expired",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn find_expired(
+        &self,
+        closes_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Poll>, PollError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, channel_id, created_by, question, options, closes_at, closed_at, created_at
+            FROM channel_polls
+            WHERE closed_at IS NULL AND closes_at <= $1
+            ORDER BY closes_at
+            LIMIT $2
+            "#,
+        )
+        .bind(closes_before)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| PollError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.iter().map(Self::row_to_poll).collect())
+    }
+
+    #[instrument(
+        name = "poll_repository.close",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn close(&self, poll_id: PollId, closed_at: DateTime<Utc>) -> Result<bool, PollError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE channel_polls
+            SET closed_at = $2
+            WHERE id = $1 AND closed_at IS NULL
+            "#,
+        )
+        .bind(poll_id.as_uuid())
+        .bind(closed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| PollError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
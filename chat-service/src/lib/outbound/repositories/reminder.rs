@@ -0,0 +1,180 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use sqlx::postgres::PgRow;
+use sqlx::PgPool;
+use sqlx::Row;
+use tracing::instrument;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageContent;
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::reminder::models::Reminder;
+use crate::domain::reminder::models::ReminderId;
+use crate::domain::reminder::ports::ReminderRepository;
+use crate::domain::user::models::UserId;
+
+pub struct PostgresReminderRepository {
+    pool: PgPool,
+}
+
+impl PostgresReminderRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_reminder(row: &PgRow) -> Result<Reminder, ReminderError> {
+        Ok(Reminder {
+            id: ReminderId(row.get("id")),
+            channel_id: ChannelId(row.get("channel_id")),
+            created_by: UserId(row.get("created_by")),
+            content: MessageContent::new(row.get("content"))
+                .map_err(|e| ReminderError::DatabaseError(e.to_string()))?,
+            due_at: row.get("due_at"),
+            attempts: row.get::<i32, _>("attempts").max(0) as u32,
+            last_error: row.get("last_error"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl ReminderRepository for PostgresReminderRepository {
+    #[instrument(
+        name = "reminder_repository.create",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn create(&self, reminder: Reminder) -> Result<Reminder, ReminderError> {
+        sqlx::query(
+            r#"
+            INSERT INTO channel_reminders (id, channel_id, created_by, content, due_at, attempts, last_error, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(reminder.id.as_uuid())
+        .bind(reminder.channel_id.as_uuid())
+        .bind(reminder.created_by.as_uuid())
+        .bind(reminder.content.as_str())
+        .bind(reminder.due_at)
+        .bind(reminder.attempts as i32)
+        .bind(&reminder.last_error)
+        .bind(reminder.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ReminderError::DatabaseError(e.to_string()))?;
+
+        Ok(reminder)
+    }
+
+    #[instrument(
+        name = "reminder_repository.find_by_channel",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn find_by_channel(&self, channel_id: ChannelId) -> Result<Vec<Reminder>, ReminderError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, channel_id, created_by, content, due_at, attempts, last_error, created_at
+            FROM channel_reminders
+            WHERE channel_id = $1
+            ORDER BY due_at
+            "#,
+        )
+        .bind(channel_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ReminderError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(Self::row_to_reminder).collect()
+    }
+
+    #[instrument(
+        name = "reminder_repository.delete",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn delete(
+        &self,
+        channel_id: ChannelId,
+        reminder_id: ReminderId,
+        created_by: Option<UserId>,
+    ) -> Result<Option<Reminder>, ReminderError> {
+        let row = sqlx::query(
+            r#"
+            DELETE FROM channel_reminders
+            WHERE id = $1 AND channel_id = $2 AND ($3::UUID IS NULL OR created_by = $3)
+            RETURNING id, channel_id, created_by, content, due_at, attempts, last_error, created_at
+            "#,
+        )
+        .bind(reminder_id.as_uuid())
+        .bind(channel_id.as_uuid())
+        .bind(created_by.map(|user_id| user_id.0))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ReminderError::DatabaseError(e.to_string()))?;
+
+        row.as_ref().map(Self::row_to_reminder).transpose()
+    }
+
+    #[instrument(
+        name = "reminder_repository.find_due",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn find_due(
+        &self,
+        due_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Reminder>, ReminderError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, channel_id, created_by, content, due_at, attempts, last_error, created_at
+            FROM channel_reminders
+            WHERE due_at <= $1
+            ORDER BY due_at
+            LIMIT $2
+            "#,
+        )
+        .bind(due_before)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ReminderError::DatabaseError(e.to_string()))?;
+
+        rows.iter().map(Self::row_to_reminder).collect()
+    }
+
+    #[instrument(
+        name = "reminder_repository.record_failure",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn record_failure(
+        &self,
+        reminder_id: ReminderId,
+        error: String,
+        retry_at: DateTime<Utc>,
+    ) -> Result<(), ReminderError> {
+        sqlx::query(
+            r#"
+            UPDATE channel_reminders
+            SET attempts = attempts + 1, last_error = $2, due_at = $3
+            WHERE id = $1
+            "#,
+        )
+        .bind(reminder_id.as_uuid())
+        .bind(error)
+        .bind(retry_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ReminderError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
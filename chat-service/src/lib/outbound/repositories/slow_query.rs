@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+/// Logs statements that take longer than a threshold.
+///
+/// Only the statement text is logged. Values are bound separately and never
+/// appear in it; string and number literals written into the text are
+/// replaced with `?` as well.
+#[derive(Debug, Clone, Copy)]
+pub struct SlowQueryLog {
+    db_system: &'static str,
+    threshold: Duration,
+}
+
+impl SlowQueryLog {
+    /// # Arguments
+    /// * `db_system` - Database the statements run against, e.g. `cassandra`
+    /// * `threshold` - Statements taking at least this long are logged
+    pub fn new(db_system: &'static str, threshold: Duration) -> Self {
+        Self {
+            db_system,
+            threshold,
+        }
+    }
+
+    /// Log `statement` as a warning if it took `elapsed` or longer than the threshold.
+    pub fn record(&self, statement: &str, elapsed: Duration) {
+        if elapsed < self.threshold {
+            return;
+        }
+        tracing::warn!(
+            db.system = self.db_system,
+            db.statement = %redact_statement(statement),
+            elapsed_ms = elapsed.as_millis() as u64,
+            threshold_ms = self.threshold.as_millis() as u64,
+            "Slow query"
+        );
+    }
+}
+
+/// Collapse whitespace and replace string and number literals with `?`.
+fn redact_statement(statement: &str) -> String {
+    let mut redacted = String::with_capacity(statement.len());
+    let mut chars = statement.chars().peekable();
+    // Digits continuing an identifier such as `messages_by_channel_v7` are kept
+    let mut in_word = false;
+
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            // A doubled quote escapes a quote inside the literal
+            while let Some(c) = chars.next() {
+                if c == '\'' && chars.next_if_eq(&'\'').is_none() {
+                    break;
+                }
+            }
+            redacted.push('?');
+            in_word = false;
+        } else if c.is_ascii_digit() && !in_word {
+            while chars.next_if(|c| c.is_ascii_digit() || *c == '.').is_some() {}
+            redacted.push('?');
+        } else if c.is_whitespace() {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            if !redacted.is_empty() && chars.peek().is_some() {
+                redacted.push(' ');
+            }
+            in_word = false;
+        } else {
+            redacted.push(c);
+            in_word = c.is_alphanumeric() || c == '_';
+        }
+    }
+
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_statement_keeps_placeholders_and_identifiers() {
+        let statement = "SELECT user_id, content
+                         FROM messages_by_user_v7
+                         WHERE user_id = ?
+                         LIMIT ?";
+
+        assert_eq!(
+            redact_statement(statement),
+            "SELECT user_id, content FROM messages_by_user_v7 WHERE user_id = ? LIMIT ?"
+        );
+    }
+
+    #[test]
+    fn test_redact_statement_replaces_literals() {
+        let statement = "UPDATE messages SET content = 'it''s secret' WHERE id = 42 LIMIT 1.5";
+
+        assert_eq!(
+            redact_statement(statement),
+            "UPDATE messages SET content = ? WHERE id = ? LIMIT ?"
+        );
+    }
+}
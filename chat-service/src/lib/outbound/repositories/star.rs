@@ -0,0 +1,177 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::Utc;
+use sqlx::PgPool;
+use sqlx::Row;
+use tracing::instrument;
+
+use crate::domain::channel::models::ChannelId;
+use crate::domain::message::models::MessageId;
+use crate::domain::star::errors::StarError;
+use crate::domain::star::events::MessageStarChangedEvent;
+use crate::domain::star::ports::StarRepository;
+use crate::domain::user::models::UserId;
+
+pub struct PostgresStarRepository {
+    pool: PgPool,
+}
+
+impl PostgresStarRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StarRepository for PostgresStarRepository {
+    #[instrument(
+        name = "star_repository.add_star",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn add_star(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        user_id: UserId,
+        starred_at: DateTime<Utc>,
+    ) -> Result<bool, StarError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO message_stars (channel_id, message_id, user_id, starred_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (message_id, user_id) DO NOTHING
+            "#,
+        )
+        .bind(channel_id.as_uuid())
+        .bind(message_id.as_uuid())
+        .bind(user_id.as_uuid())
+        .bind(starred_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| StarError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    #[instrument(
+        name = "star_repository.remove_star",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn remove_star(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        user_id: UserId,
+    ) -> Result<bool, StarError> {
+        let result = sqlx::query(
+            "DELETE FROM message_stars WHERE channel_id = $1 AND message_id = $2 AND user_id = $3",
+        )
+        .bind(channel_id.as_uuid())
+        .bind(message_id.as_uuid())
+        .bind(user_id.as_uuid())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| StarError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    #[instrument(
+        name = "star_repository.record_change",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn record_change(&self, event: &MessageStarChangedEvent) -> Result<bool, StarError> {
+        let mut transaction = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| StarError::DatabaseError(e.to_string()))?;
+
+        // Claimed in the same transaction as the count, so a failed count is retried
+        let claimed = sqlx::query(
+            r#"
+            INSERT INTO message_star_rollup_events (event_id)
+            VALUES ($1)
+            ON CONFLICT (event_id) DO NOTHING
+            "#,
+        )
+        .bind(&event.event_id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StarError::DatabaseError(e.to_string()))?;
+        if claimed.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO message_star_rollup (channel_id, day, message_id, stars)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (channel_id, day, message_id) DO UPDATE
+            SET stars = message_star_rollup.stars + EXCLUDED.stars
+            "#,
+        )
+        .bind(event.channel_id.as_uuid())
+        .bind(event.timestamp.date_naive())
+        .bind(event.message_id.as_uuid())
+        .bind(event.delta())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StarError::DatabaseError(e.to_string()))?;
+
+        transaction
+            .commit()
+            .await
+            .map_err(|e| StarError::DatabaseError(e.to_string()))?;
+
+        Ok(true)
+    }
+
+    #[instrument(
+        name = "star_repository.top_messages",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn top_messages(
+        &self,
+        channel_id: ChannelId,
+        since: Option<NaiveDate>,
+        limit: i64,
+    ) -> Result<Vec<(MessageId, u64)>, StarError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT message_id, SUM(stars)::BIGINT AS stars
+            FROM message_star_rollup
+            WHERE channel_id = $1 AND ($2::DATE IS NULL OR day >= $2)
+            GROUP BY message_id
+            HAVING SUM(stars) > 0
+            ORDER BY stars DESC, message_id DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(channel_id.as_uuid())
+        .bind(since)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| StarError::DatabaseError(e.to_string()))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                (
+                    MessageId(row.get("message_id")),
+                    row.get::<i64, _>("stars").max(0) as u64,
+                )
+            })
+            .collect())
+    }
+}
@@ -1,5 +1,8 @@
 use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
 use sqlx::PgPool;
+use tracing::instrument;
 
 use crate::domain::user::models::User;
 use crate::domain::user::models::UserId;
@@ -29,19 +32,29 @@ impl PostgresUserReplicaRepository {
 
 #[async_trait]
 impl UserReplicaRepository for PostgresUserReplicaRepository {
+    #[instrument(
+        name = "user_replica_repository.upsert",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
     async fn upsert(&self, user: User) -> Result<(), String> {
         sqlx::query!(
             r#"
-            INSERT INTO user_replica (id, username, created_at, updated_at, synced_at)
-            VALUES ($1, $2, $3, $4, NOW())
+            INSERT INTO user_replica (id, username, display_name, avatar_url, created_at, updated_at, synced_at)
+            VALUES ($1, $2, $3, $4, $5, $6, NOW())
             ON CONFLICT (id)
             DO UPDATE SET
                 username = EXCLUDED.username,
+                display_name = EXCLUDED.display_name,
+                avatar_url = EXCLUDED.avatar_url,
                 updated_at = EXCLUDED.updated_at,
                 synced_at = NOW()
             "#,
             user.id.as_uuid(),
             user.username.as_str(),
+            user.display_name.as_deref(),
+            user.avatar_url.as_deref(),
             user.created_at,
             user.updated_at,
         )
@@ -53,6 +66,12 @@ impl UserReplicaRepository for PostgresUserReplicaRepository {
         Ok(())
     }
 
+    #[instrument(
+        name = "user_replica_repository.delete",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
     async fn delete(&self, user_id: UserId) -> Result<(), String> {
         let result = sqlx::query!(
             r#"
@@ -74,10 +93,49 @@ impl UserReplicaRepository for PostgresUserReplicaRepository {
         Ok(())
     }
 
+    #[instrument(
+        name = "user_replica_repository.set_deactivated",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
+    async fn set_deactivated(
+        &self,
+        user_id: UserId,
+        deactivated_at: Option<DateTime<Utc>>,
+    ) -> Result<(), String> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE user_replica
+            SET deactivated_at = $2, synced_at = NOW()
+            WHERE id = $1
+            "#,
+            user_id.as_uuid(),
+            deactivated_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to update user deactivation in replica: {}", e))?;
+
+        if result.rows_affected() == 0 {
+            tracing::warn!("User {} not found in replica for deactivation", user_id);
+        } else {
+            tracing::debug!("User {} deactivation updated in replica", user_id);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(
+        name = "user_replica_repository.get",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
     async fn get(&self, user_id: UserId) -> Result<Option<User>, String> {
         let record = sqlx::query!(
             r#"
-            SELECT id, username, created_at, updated_at
+            SELECT id, username, display_name, avatar_url, created_at, updated_at
             FROM user_replica
             WHERE id = $1
             "#,
@@ -93,18 +151,26 @@ impl UserReplicaRepository for PostgresUserReplicaRepository {
             User {
                 id: UserId(r.id),
                 username,
+                display_name: r.display_name,
+                avatar_url: r.avatar_url,
                 created_at: r.created_at,
                 updated_at: r.updated_at,
             }
         }))
     }
 
+    #[instrument(
+        name = "user_replica_repository.get_many",
+        skip_all,
+        fields(db.system = "postgresql"),
+        err(level = "warn")
+    )]
     async fn get_many(&self, user_ids: &[UserId]) -> Result<Vec<User>, String> {
         let uuids: Vec<uuid::Uuid> = user_ids.iter().map(|id| *id.as_uuid()).collect();
 
         let records = sqlx::query!(
             r#"
-            SELECT id, username, created_at, updated_at
+            SELECT id, username, display_name, avatar_url, created_at, updated_at
             FROM user_replica
             WHERE id = ANY($1)
             "#,
@@ -122,6 +188,8 @@ impl UserReplicaRepository for PostgresUserReplicaRepository {
                 User {
                     id: UserId(r.id),
                     username,
+                    display_name: r.display_name,
+                    avatar_url: r.avatar_url,
                     created_at: r.created_at,
                     updated_at: r.updated_at,
                 }
@@ -0,0 +1,313 @@
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::config::SupervisorConfig;
+use crate::domain::crash::models::panic_message;
+
+/// How a supervisor restarts the tasks it owns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartPolicy {
+    /// Wait before the first restart, doubled for every further failure in the window
+    pub initial_backoff: Duration,
+    /// Longest wait before a restart
+    pub max_backoff: Duration,
+    /// Failures of one task tolerated within `failure_window`
+    pub max_failures: u32,
+    /// Time after which a failure no longer counts
+    pub failure_window: Duration,
+}
+
+impl RestartPolicy {
+    /// Wait before restarting a task with `failures` recent failures.
+    fn backoff(&self, failures: u32) -> Duration {
+        let factor = 2u32.saturating_pow(failures.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl From<&SupervisorConfig> for RestartPolicy {
+    fn from(config: &SupervisorConfig) -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(config.initial_backoff_ms),
+            max_backoff: Duration::from_millis(config.max_backoff_ms),
+            max_failures: config.max_failures,
+            failure_window: Duration::from_secs(config.failure_window_secs),
+        }
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self::from(&SupervisorConfig::default())
+    }
+}
+
+/// State of a supervised task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskState {
+    Running,
+    /// Stopped and waiting for its restart
+    Restarting,
+    /// Stopped too often, the process is shutting down
+    Failed,
+}
+
+/// Health of a supervised task, as reported by `/readyz`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TaskHealth {
+    pub name: &'static str,
+    pub state: TaskState,
+    /// Restarts since the process started
+    pub restarts: u32,
+    /// Why the task last stopped
+    pub last_error: Option<String>,
+}
+
+/// Owner of the background tasks of the process.
+///
+/// Tasks that panic, fail or return are restarted with exponential backoff.
+/// A task stopping more than `max_failures` times within the failure window
+/// escalates, see [`TaskSupervisor::escalated`].
+pub struct TaskSupervisor {
+    policy: RestartPolicy,
+    health: Arc<Mutex<BTreeMap<&'static str, TaskHealth>>>,
+    escalation: watch::Sender<Option<String>>,
+}
+
+impl TaskSupervisor {
+    /// Create a supervisor without tasks.
+    ///
+    /// # Arguments
+    /// * `policy` - How tasks are restarted
+    ///
+    /// # Returns
+    /// Supervisor ready to take tasks
+    pub fn new(policy: RestartPolicy) -> Self {
+        Self {
+            policy,
+            health: Arc::new(Mutex::new(BTreeMap::new())),
+            escalation: watch::Sender::new(None),
+        }
+    }
+
+    /// Spawn a task and keep it running.
+    ///
+    /// `task` is called again for every restart, so it must build everything
+    /// the task consumes, e.g. a fresh Kafka consumer.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the task in logs and health reports
+    /// * `task` - Builds the future running the task
+    pub fn supervise<F, Fut>(&self, name: &'static str, task: F)
+    where
+        F: Fn() -> Fut + Send + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        let policy = self.policy;
+        let health = Arc::clone(&self.health);
+        let escalation = self.escalation.clone();
+        update(&health, name, |task| task.state = TaskState::Running);
+
+        tokio::spawn(async move {
+            let mut failures = VecDeque::new();
+            loop {
+                let error = match tokio::spawn(task()).await {
+                    Ok(Ok(())) => "Task returned".to_string(),
+                    Ok(Err(e)) => format!("Task failed: {}", e),
+                    Err(e) if e.is_panic() => {
+                        format!("Task panicked: {}", panic_message(&*e.into_panic()))
+                    }
+                    Err(e) => format!("Task cancelled: {}", e),
+                };
+
+                let now = Instant::now();
+                failures.retain(|at| now.duration_since(*at) < policy.failure_window);
+                failures.push_back(now);
+                let recent_failures = u32::try_from(failures.len()).unwrap_or(u32::MAX);
+
+                if recent_failures > policy.max_failures {
+                    tracing::error!(
+                        task = name,
+                        error = %error,
+                        failures = recent_failures,
+                        "Background task keeps failing, shutting down"
+                    );
+                    update(&health, name, |task| {
+                        task.state = TaskState::Failed;
+                        task.last_error = Some(error.clone());
+                    });
+                    escalation.send_replace(Some(format!(
+                        "Task {} stopped {} times within {:?}: {}",
+                        name, recent_failures, policy.failure_window, error
+                    )));
+                    return;
+                }
+
+                let backoff = policy.backoff(recent_failures);
+                tracing::warn!(
+                    task = name,
+                    error = %error,
+                    backoff_ms = backoff.as_millis(),
+                    "Background task stopped, restarting"
+                );
+                update(&health, name, |task| {
+                    task.state = TaskState::Restarting;
+                    task.last_error = Some(error.clone());
+                });
+                tokio::time::sleep(backoff).await;
+                update(&health, name, |task| {
+                    task.state = TaskState::Running;
+                    task.restarts += 1;
+                });
+            }
+        });
+    }
+
+    /// Get the health of every supervised task.
+    ///
+    /// # Returns
+    /// Health of the tasks ordered by name
+    pub fn health(&self) -> Vec<TaskHealth> {
+        self.health
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Check if every supervised task is running.
+    pub fn is_healthy(&self) -> bool {
+        self.health()
+            .iter()
+            .all(|task| task.state == TaskState::Running)
+    }
+
+    /// Get why the supervisor gave up, if it did.
+    ///
+    /// # Returns
+    /// Reason of the escalation, None while tasks are kept running
+    pub fn escalation(&self) -> Option<String> {
+        self.escalation.borrow().clone()
+    }
+
+    /// Wait until a task has failed too often for the process to carry on.
+    ///
+    /// # Returns
+    /// Why the supervisor gave up
+    pub async fn escalated(&self) -> String {
+        let mut escalation = self.escalation.subscribe();
+        // The sender lives as long as self, so waiting cannot fail
+        let reason = escalation
+            .wait_for(Option::is_some)
+            .await
+            .map(|reason| reason.clone())
+            .unwrap_or_default();
+        reason.unwrap_or_default()
+    }
+}
+
+fn update(
+    health: &Mutex<BTreeMap<&'static str, TaskHealth>>,
+    name: &'static str,
+    change: impl FnOnce(&mut TaskHealth),
+) {
+    let mut health = health.lock().unwrap_or_else(|e| e.into_inner());
+    let task = health.entry(name).or_insert_with(|| TaskHealth {
+        name,
+        state: TaskState::Running,
+        restarts: 0,
+        last_error: None,
+    });
+    change(task);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU32;
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+
+    fn policy(max_failures: u32) -> RestartPolicy {
+        RestartPolicy {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(4),
+            max_failures,
+            failure_window: Duration::from_secs(60),
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let policy = policy(10);
+        assert_eq!(policy.backoff(1), Duration::from_millis(1));
+        assert_eq!(policy.backoff(2), Duration::from_millis(2));
+        assert_eq!(policy.backoff(3), Duration::from_millis(4));
+        assert_eq!(policy.backoff(40), Duration::from_millis(4));
+    }
+
+    #[tokio::test]
+    async fn test_restarts_panicking_task() {
+        let supervisor = TaskSupervisor::new(policy(5));
+        let runs = Arc::new(AtomicU32::new(0));
+
+        let task_runs = Arc::clone(&runs);
+        supervisor.supervise("flaky", move || {
+            let runs = Arc::clone(&task_runs);
+            async move {
+                if runs.fetch_add(1, Ordering::SeqCst) == 0 {
+                    panic!("first run");
+                }
+                std::future::pending::<anyhow::Result<()>>().await
+            }
+        });
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while runs.load(Ordering::SeqCst) < 2 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        let health = supervisor.health();
+        assert_eq!(health.len(), 1);
+        assert_eq!(health[0].state, TaskState::Running);
+        assert_eq!(health[0].restarts, 1);
+        assert_eq!(
+            health[0].last_error.as_deref(),
+            Some("Task panicked: first run")
+        );
+        assert!(supervisor.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_escalates_after_repeated_failures() {
+        let supervisor = TaskSupervisor::new(policy(2));
+
+        supervisor.supervise("broken", || async {
+            Err::<(), _>(anyhow::anyhow!("no broker"))
+        });
+
+        let reason = tokio::time::timeout(Duration::from_secs(5), supervisor.escalated())
+            .await
+            .unwrap();
+
+        assert!(reason.contains("broken"));
+        assert!(reason.contains("no broker"));
+        assert_eq!(supervisor.health()[0].state, TaskState::Failed);
+        assert!(!supervisor.is_healthy());
+    }
+}
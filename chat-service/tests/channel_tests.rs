@@ -1,5 +1,14 @@
 mod common;
 
+use chat_service::domain::channel::models::ChannelId;
+use chat_service::domain::channel::models::WorkspaceId;
+use chat_service::domain::channel::ports::ChannelRepository;
+use chat_service::domain::message::models::LanguageCode;
+use chat_service::domain::user::models::UserId;
+use chat_service::outbound::repositories::channel::PostgresChannelRepository;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
 use common::TestApp;
 use reqwest::StatusCode;
 use serde_json::json;
@@ -31,6 +40,47 @@ async fn test_create_public_channel_success() {
     assert!(body["created_at"].is_string());
 }
 
+#[tokio::test]
+async fn test_api_versions_share_handlers() {
+    let app = TestApp::spawn().await;
+    let (token, _user_id) = app.create_test_token();
+
+    let response = app
+        .post_authenticated("/api/v1/channels", &token)
+        .json(&json!({ "channel_type": "public", "name": "versioned" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()["deprecation"]
+        .to_str()
+        .unwrap()
+        .starts_with('@'));
+    assert_eq!(
+        response.headers()["link"],
+        "</api/v2>; rel=\"successor-version\""
+    );
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let channel_id = body["id"].as_str().unwrap();
+
+    let response = app
+        .get_authenticated(&format!("/api/channels/{}", channel_id), &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert!(response.headers().contains_key("deprecation"));
+
+    let response = app
+        .get_authenticated(&format!("/api/v2/channels/{}", channel_id), &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!response.headers().contains_key("deprecation"));
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["id"], channel_id);
+}
+
 #[tokio::test]
 async fn test_create_public_channel_without_description() {
     let app = TestApp::spawn().await;
@@ -180,6 +230,121 @@ async fn test_create_channel_with_duplicate_name() {
     assert!(body["error"].as_str().unwrap().contains("already exists"));
 }
 
+#[tokio::test]
+async fn test_channel_names_are_scoped_to_workspace() {
+    let app = TestApp::spawn().await;
+    let team_a = app.create_token_in_workspace(uuid::Uuid::new_v4());
+    let team_b = app.create_token_in_workspace(uuid::Uuid::new_v4());
+
+    for token in [&team_a, &team_b] {
+        let response = app
+            .post_authenticated("/api/channels", token)
+            .json(&json!({
+                "channel_type": "public",
+                "name": "general"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = app
+        .post_authenticated("/api/channels", &team_a)
+        .json(&json!({
+            "channel_type": "public",
+            "name": "general"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_private_channel_names_are_scoped_to_creator() {
+    let app = TestApp::spawn().await;
+
+    for _ in 0..2 {
+        let (token, _user_id) = app.create_test_token();
+        let response = app
+            .post_authenticated("/api/channels", &token)
+            .json(&json!({
+                "channel_type": "private",
+                "name": "notes"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn test_guest_reads_public_channels_only() {
+    let app = TestApp::spawn().await;
+    let (token, _user_id) = app.create_test_token();
+    let guest_token = app.create_guest_token();
+
+    let mut channel_ids = Vec::new();
+    for (channel_type, name) in [("public", "lobby"), ("private", "staff")] {
+        let response = app
+            .post_authenticated("/api/channels", &token)
+            .json(&json!({
+                "channel_type": channel_type,
+                "name": name
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+        channel_ids.push(body["id"].as_str().unwrap().to_string());
+    }
+
+    let response = app
+        .get_authenticated(&format!("/api/channels/{}", channel_ids[0]), &guest_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    for path in [
+        format!("/api/channels/{}", channel_ids[1]),
+        format!("/api/channels/{}/messages", channel_ids[1]),
+    ] {
+        let response = app
+            .get_authenticated(&path, &guest_token)
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
+
+#[tokio::test]
+async fn test_guest_cannot_create_channel() {
+    let app = TestApp::spawn().await;
+    let guest_token = app.create_guest_token();
+
+    let response = app
+        .post_authenticated("/api/channels", &guest_token)
+        .json(&json!({
+            "channel_type": "public",
+            "name": "guest-room"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["code"], "guest_not_allowed");
+}
+
 #[tokio::test]
 async fn test_get_channel_by_id() {
     let app = TestApp::spawn().await;
@@ -218,6 +383,100 @@ async fn test_get_channel_by_id() {
     assert_eq!(body["description"], "Test channel");
 }
 
+#[tokio::test]
+async fn test_get_channel_with_stats() {
+    let app = TestApp::spawn().await;
+    let (token, user_id) = app.create_test_token();
+
+    let create_response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({
+            "channel_type": "public",
+            "name": "stats-channel"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let channel_id = create_body["id"].as_str().unwrap().to_string();
+
+    // Stats are omitted unless requested
+    let response = app
+        .get_authenticated(&format!("/api/channels/{}", channel_id), &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body.get("stats").is_none());
+
+    // A channel without messages has empty stats
+    let response = app
+        .get_authenticated(
+            &format!("/api/channels/{}?include=stats", channel_id),
+            &token,
+        )
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["id"], channel_id.as_str());
+    assert_eq!(body["stats"]["message_count"], 0);
+    assert_eq!(body["stats"]["member_count"], 0);
+    assert!(body["stats"]["last_activity_at"].is_null());
+
+    // Record activity as the message service does on send
+    let repository = PostgresChannelRepository::new(app.db.pg_pool.clone());
+    let channel_id_value = ChannelId::from_string(&channel_id).unwrap();
+    let other_user = UserId(uuid::Uuid::new_v4());
+    let sent_at = Utc::now();
+    for (author, offset, language) in [
+        (UserId(user_id), 2, Some("en")),
+        (UserId(user_id), 1, None),
+        (other_user, 0, Some("en")),
+    ] {
+        repository
+            .record_message_activity(
+                channel_id_value,
+                author,
+                sent_at - Duration::seconds(offset),
+                language.map(|code| LanguageCode::new(code).unwrap()),
+            )
+            .await
+            .expect("Failed to record activity");
+    }
+
+    let response = app
+        .get_authenticated(
+            &format!("/api/channels/{}?include=stats", channel_id),
+            &token,
+        )
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["name"], "stats-channel");
+    assert_eq!(body["stats"]["message_count"], 3);
+    assert_eq!(body["stats"]["member_count"], 2);
+    assert_eq!(
+        body["stats"]["languages"],
+        json!([{ "language": "en", "message_count": 2 }])
+    );
+    let last_activity_at: DateTime<Utc> = body["stats"]["last_activity_at"]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(
+        last_activity_at.timestamp_micros(),
+        sent_at.timestamp_micros()
+    );
+}
+
 #[tokio::test]
 async fn test_get_channel_not_found() {
     let app = TestApp::spawn().await;
@@ -393,3 +652,236 @@ async fn test_full_channel_workflow() {
     let found = channels.iter().any(|c| c["id"] == channel_id);
     assert!(found);
 }
+
+#[tokio::test]
+async fn test_read_only_mode_rejects_writes_and_serves_reads() {
+    let app = TestApp::spawn_with_read_only(true).await;
+    let (token, _user_id) = app.create_test_token();
+
+    let create_response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({
+            "channel_type": "public",
+            "name": "read-only-channel"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(create_response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(create_body["error"], "Service is in read-only mode");
+
+    let list_response = app
+        .get_authenticated("/api/channels/public", &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(list_response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_embed_messages_only_for_embeddable_channels() {
+    let app = TestApp::spawn().await;
+    let (token, _user_id) = app.create_test_token();
+
+    let mut channel_ids = Vec::new();
+    for (name, embeddable) in [("widget", true), ("lobby", false)] {
+        let response = app
+            .post_authenticated("/api/channels", &token)
+            .json(&json!({
+                "channel_type": "public",
+                "name": name,
+                "embeddable": embeddable
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+        assert_eq!(body["embeddable"], embeddable);
+        channel_ids.push(body["id"].as_str().unwrap().to_string());
+    }
+
+    // No Authorization header: the embed API is public
+    let response = app
+        .get(&format!("/embed/channels/{}/messages", channel_ids[0]))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body.as_array().unwrap().is_empty());
+
+    let response = app
+        .get(&format!("/embed/channels/{}/messages", channel_ids[1]))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_public_channel_directory_lists_discoverable_channels() {
+    let app = TestApp::spawn().await;
+    let (token, _user_id) = app.create_test_token();
+
+    for (name, discoverable) in [("community", true), ("unlisted", false)] {
+        app.post_authenticated("/api/channels", &token)
+            .json(&json!({
+                "channel_type": "public",
+                "name": name,
+                "description": "Say hello",
+                "discoverable": discoverable
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+    }
+
+    // No Authorization header: the directory is public
+    let response = app
+        .get("/api/public/channels")
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers()["etag"].to_str().unwrap().to_string();
+    assert!(response.headers()["cache-control"]
+        .to_str()
+        .unwrap()
+        .starts_with("public, max-age="));
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(
+        body,
+        json!([{"name": "community", "description": "Say hello", "member_count": 0}])
+    );
+
+    let response = app
+        .get("/api/public/channels")
+        .header("if-none-match", &etag)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[tokio::test]
+async fn test_importer_supplies_channel_id() {
+    let app = TestApp::spawn().await;
+    let token = app.create_token_with_role("importer");
+    let id = uuid::Uuid::now_v7();
+
+    let response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({ "id": id, "channel_type": "public", "name": "imported" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["id"], id.to_string());
+
+    // Reusing the ID conflicts even with another name
+    let response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({ "id": id, "channel_type": "public", "name": "imported-again" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_supplied_channel_id_requires_trusted_role() {
+    let app = TestApp::spawn().await;
+    let (token, _user_id) = app.create_test_token();
+
+    let response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({ "id": uuid::Uuid::now_v7(), "channel_type": "public", "name": "mine" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_supplied_channel_id_must_be_time_ordered() {
+    let app = TestApp::spawn().await;
+    let token = app.create_token_with_role("bot");
+
+    let response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({ "id": uuid::Uuid::new_v4(), "channel_type": "public", "name": "random" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_new_users_join_default_channels() {
+    let app = TestApp::spawn().await;
+    let (token, _user_id) = app.create_test_token();
+    let admin_token = app.create_token_with_role("admin");
+
+    let response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({ "channel_type": "public", "name": "welcome" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let channel_id = body["id"].as_str().unwrap().to_string();
+    let auto_join_path = format!("/api/channels/{}/auto-join", channel_id);
+
+    // Only admins manage default channels
+    let response = app
+        .put_authenticated(&auto_join_path, &token)
+        .json(&json!({ "auto_join": true }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .put_authenticated(&auto_join_path, &admin_token)
+        .json(&json!({ "auto_join": true }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["auto_join"], true);
+
+    // What the user events consumer does for a new user, twice as on redelivery
+    let repository = PostgresChannelRepository::new(app.db.pg_pool.clone());
+    let new_user = UserId(uuid::Uuid::new_v4());
+    let joined = repository
+        .add_to_auto_join_channels(new_user, WorkspaceId::default(), Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(joined.len(), 1);
+    let joined = repository
+        .add_to_auto_join_channels(new_user, WorkspaceId::default(), Utc::now())
+        .await
+        .unwrap();
+    assert!(joined.is_empty());
+
+    let new_user_token = app.create_token_for_user(new_user.0, "newcomer");
+    let response = app
+        .get_authenticated("/api/channels", &new_user_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let channels = body.as_array().unwrap();
+    assert_eq!(channels.len(), 1);
+    assert_eq!(channels[0]["id"], channel_id);
+}
@@ -1,5 +1,6 @@
 mod common;
 
+use clock::Clock;
 use common::TestApp;
 use reqwest::StatusCode;
 use serde_json::json;
@@ -272,3 +273,362 @@ async fn test_message_retrieval_workflow() {
         .expect("Failed to execute request");
     assert_eq!(before_response.status(), StatusCode::OK);
 }
+
+#[tokio::test]
+async fn test_deleted_message_leaves_tombstone_in_history() {
+    let app = TestApp::spawn().await;
+    let (token, user_id) = app.create_test_token();
+    let (other_token, _other_user_id) = app.create_test_token();
+
+    let create_response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({
+            "channel_type": "public",
+            "name": "test-tombstones"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let channel_id: uuid::Uuid = create_body["id"].as_str().unwrap().parse().unwrap();
+
+    let message_id = app.insert_message(channel_id, user_id, "oops").await;
+    let path = format!("/api/channels/{}/messages/{}", channel_id, message_id);
+
+    // Only the author may delete the message
+    let response = app
+        .delete_authenticated(&path, &other_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .delete_authenticated(&path, &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .get_authenticated(&format!("/api/channels/{}/messages", channel_id), &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["type"], "deleted");
+    assert_eq!(entries[0]["id"], message_id.to_string());
+    assert_eq!(entries[0]["deleted_by"], user_id.to_string());
+    assert!(entries[0].get("content").is_none());
+
+    // Deleting again finds no message
+    let response = app
+        .delete_authenticated(&path, &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_send_message_over_http() {
+    let app = TestApp::spawn().await;
+    let (token, user_id) = app.create_test_token();
+
+    let create_response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({ "channel_type": "public", "name": "http-send" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let channel: serde_json::Value = create_response.json().await.unwrap();
+    let channel_id = channel["id"].as_str().unwrap();
+    let path = format!("/api/channels/{}/messages", channel_id);
+
+    let response = app
+        .post_authenticated(&path, &token)
+        .json(&json!({ "content": "hello" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["content"], "hello");
+    assert_eq!(body["user_id"], user_id.to_string());
+
+    // Regular users may not supply message IDs
+    let response = app
+        .post_authenticated(&path, &token)
+        .json(&json!({ "content": "hello", "id": "6fa459ea-ee8a-11d0-8000-000000000000" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_time_travel_stamps_messages_and_expires_tokens() {
+    let app = TestApp::spawn().await;
+    let (token, _user_id) = app.create_test_token();
+
+    let create_response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({ "channel_type": "public", "name": "time-travel" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let channel: serde_json::Value = create_response.json().await.unwrap();
+    let path = format!("/api/channels/{}/messages", channel["id"].as_str().unwrap());
+
+    app.clock.advance(chrono::Duration::hours(23));
+    let response = app
+        .post_authenticated(&path, &token)
+        .json(&json!({ "content": "still here" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let timestamp: chrono::DateTime<chrono::Utc> =
+        body["timestamp"].as_str().unwrap().parse().unwrap();
+    assert_eq!(timestamp, app.clock.now());
+
+    // Test tokens live for 24 hours
+    app.clock.advance(chrono::Duration::hours(2));
+    let response = app
+        .post_authenticated(&path, &token)
+        .json(&json!({ "content": "too late" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_announcement_only_channel_rejects_members() {
+    let app = TestApp::spawn().await;
+    let (owner_token, _) = app.create_test_token();
+
+    let create_response = app
+        .post_authenticated("/api/channels", &owner_token)
+        .json(&json!({
+            "channel_type": "public",
+            "name": "announcements",
+            "announcement_only": true
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let channel: serde_json::Value = create_response.json().await.unwrap();
+    assert_eq!(channel["announcement_only"], true);
+    let path = format!("/api/channels/{}/messages", channel["id"].as_str().unwrap());
+
+    let (member_token, _) = app.create_test_token();
+    let response = app
+        .post_authenticated(&path, &member_token)
+        .json(&json!({ "content": "hello" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["code"], "channel_announcement_only");
+
+    for token in [owner_token, app.create_token_with_role("moderator")] {
+        let response = app
+            .post_authenticated(&path, &token)
+            .json(&json!({ "content": "news" }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn test_importer_supplies_message_id() {
+    let app = TestApp::spawn().await;
+    let token = app.create_token_with_role("importer");
+
+    let create_response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({ "channel_type": "public", "name": "imported-history" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let channel: serde_json::Value = create_response.json().await.unwrap();
+    let channel_id = channel["id"].as_str().unwrap();
+    let path = format!("/api/channels/{}/messages", channel_id);
+    let message_id = uuid::Uuid::now_v1(&[1, 2, 3, 4, 5, 6]);
+
+    let response = app
+        .post_authenticated(&path, &token)
+        .json(&json!({ "content": "from the archive", "id": message_id }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["id"], message_id.to_string());
+
+    let response = app
+        .post_authenticated(&path, &token)
+        .json(&json!({ "content": "duplicate", "id": message_id }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // Message IDs must be time-ordered
+    let response = app
+        .post_authenticated(&path, &token)
+        .json(&json!({ "content": "random", "id": uuid::Uuid::new_v4() }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_history_merges_timeuuid_and_v7_messages() {
+    let app = TestApp::spawn().await;
+    let token = app.create_token_with_role("importer");
+
+    let create_response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({ "channel_type": "public", "name": "mixed-ids" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let channel: serde_json::Value = create_response.json().await.unwrap();
+    let channel_id = channel["id"].as_str().unwrap();
+    let path = format!("/api/channels/{}/messages", channel_id);
+
+    let seconds = chrono::Utc::now().timestamp() as u64;
+    let at = |offset: u64| uuid::Timestamp::from_unix(uuid::NoContext, seconds - 60 + offset, 0);
+    let ids = [
+        uuid::Uuid::new_v1(at(0), &[1, 2, 3, 4, 5, 6]),
+        uuid::Uuid::new_v7(at(1)),
+        uuid::Uuid::new_v1(at(2), &[1, 2, 3, 4, 5, 6]),
+    ];
+    for (n, id) in ids.iter().enumerate() {
+        let response = app
+            .post_authenticated(&path, &token)
+            .json(&json!({ "content": format!("message {}", n), "id": id }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = app
+        .get_authenticated(&path, &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let history: Vec<&str> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["id"].as_str().unwrap())
+        .collect();
+    let expected: Vec<String> = ids.iter().rev().map(|id| id.to_string()).collect();
+    assert_eq!(history, expected);
+
+    // Paging before the newest message continues across both ID versions
+    let before = chrono::DateTime::from_timestamp((seconds - 60 + 1) as i64, 500_000_000).unwrap();
+    let response = app
+        .get_authenticated(&path, &token)
+        .query(&[("before", before.to_rfc3339())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let page: Vec<&str> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(page, vec![ids[1].to_string(), ids[0].to_string()]);
+}
+
+#[tokio::test]
+async fn test_v2_wraps_message_page_in_envelope() {
+    let app = TestApp::spawn().await;
+    let (token, _) = app.create_test_token();
+
+    let create_response = app
+        .post_authenticated("/api/v2/channels", &token)
+        .json(&json!({ "channel_type": "public", "name": "enveloped" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let channel: serde_json::Value = create_response.json().await.unwrap();
+    let channel_id = channel["data"]["id"].as_str().unwrap();
+    assert!(channel["request_id"].is_string());
+    let path = format!("/api/v2/channels/{}/messages", channel_id);
+
+    for content in ["first", "second"] {
+        app.post_authenticated(&path, &token)
+            .json(&json!({ "content": content }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+    }
+
+    let response = app
+        .get_authenticated(&format!("{}?limit=1", path), &token)
+        .header("X-Request-Id", "req-456")
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-request-id"], "req-456");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"][0]["content"], "second");
+    assert_eq!(body["pagination"]["limit"], 1);
+    assert!(body["pagination"]["next_cursor"].is_string());
+    assert_eq!(body["request_id"], "req-456");
+
+    let response = app
+        .get_authenticated("/api/v2/channels/not-a-uuid/messages", &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body["error"]["message"].is_string());
+}
+
+#[tokio::test]
+async fn test_oversized_message_is_rejected() {
+    let app = TestApp::spawn().await;
+    let (token, _) = app.create_test_token();
+
+    let create_response = app
+        .post_authenticated("/api/channels", &token)
+        .json(&json!({ "channel_type": "public", "name": "limits" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let channel: serde_json::Value = create_response.json().await.unwrap();
+    let channel_id = channel["id"].as_str().unwrap();
+
+    let response = app
+        .post_authenticated(&format!("/api/channels/{}/messages", channel_id), &token)
+        .json(&json!({ "content": "x".repeat(2 * 1024 * 1024) }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body["error"].as_str().unwrap().contains("limit"));
+}
@@ -0,0 +1,142 @@
+mod common;
+
+use common::sign_up_and_log_in;
+use common::user_service_url;
+use common::TestApp;
+use reqwest::StatusCode;
+use serde_json::json;
+
+/// Mint a personal access token on user-service.
+///
+/// # Returns
+/// ID of the token and the token itself
+async fn create_personal_token(
+    client: &reqwest::Client,
+    access_token: &str,
+    scopes: &[&str],
+) -> (String, String) {
+    let response = client
+        .post(format!("{}/api/account/tokens", user_service_url()))
+        .bearer_auth(access_token)
+        .json(&json!({
+            "name": "integration",
+            "scopes": scopes,
+            "expires_in_days": 1
+        }))
+        .send()
+        .await
+        .expect("Failed to create personal token");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let token_id = body["data"]["id"].as_str().unwrap().to_string();
+    let token = body["data"]["token"].as_str().unwrap().to_string();
+
+    (token_id, token)
+}
+
+/// Create a public channel as the owner of `access_token`.
+///
+/// # Returns
+/// ID of the channel
+async fn create_channel(app: &TestApp, access_token: &str) -> String {
+    let response = app
+        .post_authenticated("/api/channels", access_token)
+        .json(&json!({
+            "name": format!("pat-{}", &uuid::Uuid::new_v4().simple().to_string()[..8]),
+            "channel_type": "public"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    body["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_read_scoped_personal_token_cannot_send_messages() {
+    let app = TestApp::spawn_with_personal_tokens().await;
+    let client = reqwest::Client::new();
+    let (_user_id, access_token) = sign_up_and_log_in(&client).await;
+    let channel_id = create_channel(&app, &access_token).await;
+    let (_token_id, personal_token) =
+        create_personal_token(&client, &access_token, &["messages:read"]).await;
+    let path = format!("/api/channels/{}/messages", channel_id);
+
+    let response = app
+        .post_authenticated(&path, &personal_token)
+        .json(&json!({ "content": "Hello from a script" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["code"], "insufficient_scope");
+
+    // Reading is within the token's scope
+    let response = app
+        .get_authenticated(&path, &personal_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_write_scoped_personal_token_sends_messages() {
+    let app = TestApp::spawn_with_personal_tokens().await;
+    let client = reqwest::Client::new();
+    let (_user_id, access_token) = sign_up_and_log_in(&client).await;
+    let channel_id = create_channel(&app, &access_token).await;
+    let (_token_id, personal_token) =
+        create_personal_token(&client, &access_token, &["messages:write"]).await;
+
+    let response = app
+        .post_authenticated(
+            &format!("/api/channels/{}/messages", channel_id),
+            &personal_token,
+        )
+        .json(&json!({ "content": "Hello from a script" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_revoked_personal_token_is_rejected() {
+    let app = TestApp::spawn_with_personal_tokens().await;
+    let client = reqwest::Client::new();
+    let (_user_id, access_token) = sign_up_and_log_in(&client).await;
+    let (token_id, personal_token) =
+        create_personal_token(&client, &access_token, &["channels:read"]).await;
+
+    let response = app
+        .get_authenticated("/api/channels", &personal_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .delete(format!(
+            "{}/api/account/tokens/{}",
+            user_service_url(),
+            token_id
+        ))
+        .bearer_auth(&access_token)
+        .send()
+        .await
+        .expect("Failed to revoke personal token");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // chat-service asks user-service on every request, so revocation is immediate
+    let response = app
+        .get_authenticated("/api/channels", &personal_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["code"], "invalid_personal_token");
+}
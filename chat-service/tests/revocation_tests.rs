@@ -0,0 +1,73 @@
+mod common;
+
+use common::sign_up_and_log_in;
+use common::user_service_url;
+use common::TestApp;
+use reqwest::StatusCode;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_logout_through_user_service_rejects_token() {
+    let app = TestApp::spawn().await;
+    let client = reqwest::Client::new();
+    let (_user_id, token) = sign_up_and_log_in(&client).await;
+
+    let response = app
+        .get_authenticated("/api/channels", &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .post(format!("{}/api/auth/logout", user_service_url()))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .expect("Failed to log out");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // chat-service reads the revocation user-service stored
+    let response = app
+        .get_authenticated("/api/channels", &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["code"], "token_revoked");
+}
+
+#[tokio::test]
+async fn test_password_change_through_user_service_rejects_earlier_tokens() {
+    let app = TestApp::spawn().await;
+    let client = reqwest::Client::new();
+    let (user_id, token) = sign_up_and_log_in(&client).await;
+    // Tokens issued within the second of the change stay valid
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+
+    let response = client
+        .post(format!(
+            "{}/api/users/{}/password",
+            user_service_url(),
+            user_id
+        ))
+        .bearer_auth(&token)
+        .json(&json!({
+            "current_password": "pass_word!",
+            "new_password": "new_pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to change password");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .get_authenticated("/api/channels", &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["code"], "token_revoked");
+}
@@ -18,6 +18,8 @@ async fn test_upsert_new_user() {
     let user = User {
         id: user_id,
         username: Username::new("john_doe".to_string()).expect("Invalid username"),
+        display_name: None,
+        avatar_url: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
@@ -50,6 +52,8 @@ async fn test_upsert_existing_user() {
     let user = User {
         id: user_id,
         username: Username::new("john_doe".to_string()).expect("Invalid username"),
+        display_name: None,
+        avatar_url: None,
         created_at,
         updated_at: created_at,
     };
@@ -63,6 +67,8 @@ async fn test_upsert_existing_user() {
     let updated_user = User {
         id: user_id,
         username: Username::new("john_updated".to_string()).expect("Invalid username"),
+        display_name: Some("John Doe".to_string()),
+        avatar_url: Some("https://cdn.example.com/john.png".to_string()),
         created_at,
         updated_at: Utc::now(),
     };
@@ -78,6 +84,8 @@ async fn test_upsert_existing_user() {
         .expect("User not found");
 
     assert_eq!(retrieved_user.username.as_str(), "john_updated");
+    assert_eq!(retrieved_user.display_name.as_deref(), Some("John Doe"));
+    assert_eq!(retrieved_user.shown_name(), "John Doe");
 }
 
 #[tokio::test]
@@ -91,6 +99,8 @@ async fn test_delete_user() {
     let user = User {
         id: user_id,
         username: Username::new("john_doe".to_string()).expect("Invalid username"),
+        display_name: None,
+        avatar_url: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
@@ -141,6 +151,8 @@ async fn test_get_many_users() {
     let user_1 = User {
         id: user_id_1,
         username: Username::new("user1".to_string()).expect("Invalid username"),
+        display_name: None,
+        avatar_url: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
@@ -148,6 +160,8 @@ async fn test_get_many_users() {
     let user_2 = User {
         id: user_id_2,
         username: Username::new("user2".to_string()).expect("Invalid username"),
+        display_name: None,
+        avatar_url: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
@@ -155,6 +169,8 @@ async fn test_get_many_users() {
     let user_3 = User {
         id: user_id_3,
         username: Username::new("user3".to_string()).expect("Invalid username"),
+        display_name: None,
+        avatar_url: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
@@ -197,6 +213,8 @@ async fn test_get_many_partial_match() {
     let user_1 = User {
         id: user_id_1,
         username: Username::new("user1".to_string()).expect("Invalid username"),
+        display_name: None,
+        avatar_url: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
@@ -228,6 +246,8 @@ async fn test_upsert_preserves_unique_constraints() {
     let user_1 = User {
         id: user_id_1,
         username: Username::new("john_doe".to_string()).expect("Invalid username"),
+        display_name: None,
+        avatar_url: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
@@ -242,6 +262,8 @@ async fn test_upsert_preserves_unique_constraints() {
     let user_2 = User {
         id: user_id_2,
         username: Username::new("john_doe".to_string()).expect("Invalid username"), // Duplicate username
+        display_name: None,
+        avatar_url: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
@@ -0,0 +1,163 @@
+mod common;
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common::TestApp;
+use futures::SinkExt;
+use futures::StreamExt;
+use tokio::task::JoinSet;
+use tokio::time::timeout;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Read a `u64` soak parameter from the environment.
+fn env_or(name: &str, default: u64) -> u64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Resident set size of this process in KiB (Linux only).
+fn rss_kib() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find(|line| line.starts_with("VmRSS:"))?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()
+}
+
+/// How a soak client leaves the channel.
+#[derive(Debug, Clone, Copy)]
+enum Departure {
+    /// Send a close frame and wait for the server to acknowledge it
+    Graceful,
+    /// Drop the TCP stream without a close frame
+    Abrupt,
+    /// Exchange a ping/pong first, then close gracefully
+    Chatty,
+}
+
+/// Connect one client, wait for the `connected` frame, then leave.
+async fn churn_client(url: String, departure: Departure) -> Result<(), String> {
+    let (mut socket, _) = connect_async(&url)
+        .await
+        .map_err(|e| format!("connect failed: {}", e))?;
+
+    let connected = timeout(Duration::from_secs(5), socket.next())
+        .await
+        .map_err(|_| "timed out waiting for connected frame".to_string())?;
+    match connected {
+        Some(Ok(Message::Text(text))) if text.contains("\"connected\"") => {}
+        other => return Err(format!("unexpected first frame: {:?}", other)),
+    }
+
+    match departure {
+        Departure::Graceful => {
+            let _ = socket.close(None).await;
+        }
+        Departure::Abrupt => {
+            drop(socket);
+        }
+        Departure::Chatty => {
+            socket
+                .send(Message::Text(r#"{"type":"ping"}"#.to_string()))
+                .await
+                .map_err(|e| format!("ping failed: {}", e))?;
+            let _ = timeout(Duration::from_secs(5), socket.next()).await;
+            let _ = socket.close(None).await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Wait until the registry has no connections left, returning the final count.
+async fn wait_for_drain(app: &TestApp, deadline: Duration) -> usize {
+    let start = Instant::now();
+    loop {
+        let remaining = app.connection_registry.get_total_connections().await;
+        if remaining == 0 || start.elapsed() > deadline {
+            return remaining;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+/// Continuously connect and disconnect WebSocket clients, sampling registry size and RSS.
+///
+/// Detects connection leaks such as senders left in the registry after abnormal closes.
+/// Tunable through `SOAK_DURATION_SECS`, `SOAK_WAVE_SIZE`, and `SOAK_MAX_RSS_GROWTH_MIB`.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+#[ignore = "long-running soak test, run with `cargo test --test websocket_soak_tests -- --ignored`"]
+async fn test_websocket_connection_churn_soak() {
+    let duration = Duration::from_secs(env_or("SOAK_DURATION_SECS", 60));
+    let wave_size = env_or("SOAK_WAVE_SIZE", 500) as usize;
+    let max_rss_growth_kib = env_or("SOAK_MAX_RSS_GROWTH_MIB", 64) * 1024;
+
+    let app = TestApp::spawn().await;
+    let (token, _) = app.create_test_token();
+    let channel_id = uuid::Uuid::new_v4();
+    let url = app.websocket_url(channel_id, &token);
+
+    let departures = [Departure::Graceful, Departure::Abrupt, Departure::Chatty];
+    let started = Instant::now();
+    let mut baseline_rss = None;
+    let mut waves = 0usize;
+    let mut total_clients = 0usize;
+    let mut failures = 0usize;
+
+    while started.elapsed() < duration {
+        let mut clients = JoinSet::new();
+        for i in 0..wave_size {
+            clients.spawn(churn_client(url.clone(), departures[i % departures.len()]));
+        }
+        while let Some(result) = clients.join_next().await {
+            if !matches!(result, Ok(Ok(()))) {
+                failures += 1;
+            }
+        }
+
+        waves += 1;
+        total_clients += wave_size;
+
+        let registered = wait_for_drain(&app, Duration::from_secs(5)).await;
+        let rss = rss_kib();
+        println!(
+            "wave={} clients={} registered_after_drain={} rss_kib={:?} failures={}",
+            waves, total_clients, registered, rss, failures
+        );
+        assert_eq!(
+            registered, 0,
+            "connections leaked in registry after wave {}",
+            waves
+        );
+
+        // Skip the first wave so allocator and runtime warm-up is not counted as growth
+        if waves == 1 {
+            baseline_rss = rss;
+        }
+    }
+
+    assert!(
+        failures * 100 <= total_clients,
+        "more than 1% of soak clients failed: {} of {}",
+        failures,
+        total_clients
+    );
+
+    if let (Some(baseline), Some(current)) = (baseline_rss, rss_kib()) {
+        let growth = current.saturating_sub(baseline);
+        assert!(
+            growth <= max_rss_growth_kib,
+            "RSS grew by {} KiB over {} waves (limit {} KiB)",
+            growth,
+            waves,
+            max_rss_growth_kib
+        );
+    }
+}
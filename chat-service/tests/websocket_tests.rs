@@ -0,0 +1,42 @@
+mod common;
+
+use common::TestApp;
+use reqwest::StatusCode;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Error as WebSocketError;
+
+#[tokio::test]
+async fn test_logged_out_token_cannot_open_websocket() {
+    let app = TestApp::spawn().await;
+    let (token, _user_id) = app.create_test_token();
+    let url = app.websocket_url(uuid::Uuid::new_v4(), &token);
+
+    let (socket, _) = connect_async(&url).await.expect("Failed to open WebSocket");
+    drop(socket);
+
+    // Logging out revokes the access token
+    let claims = app
+        .authenticator
+        .validate_access_token(&token)
+        .expect("Failed to validate token");
+    app.authenticator
+        .revoke_access_token(&claims)
+        .await
+        .expect("Failed to revoke token");
+
+    match connect_async(&url).await {
+        Err(WebSocketError::Http(response)) => {
+            assert_eq!(
+                response.status().as_u16(),
+                StatusCode::UNAUTHORIZED.as_u16()
+            );
+            let body: serde_json::Value =
+                serde_json::from_slice(response.body().as_deref().unwrap_or_default()).unwrap();
+            assert_eq!(body["code"], "token_revoked");
+        }
+        other => panic!(
+            "Expected the upgrade to be refused, got {:?}",
+            other.map(|_| ())
+        ),
+    }
+}
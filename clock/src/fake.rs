@@ -0,0 +1,83 @@
+use std::sync::Mutex;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+
+use crate::Clock;
+
+/// Clock that only moves when told to.
+///
+/// Every reader sharing it sees the same time, so one fake clock handed to
+/// all services of a test travels through time for all of them at once.
+#[derive(Debug)]
+pub struct FakeClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl FakeClock {
+    /// Create a clock stopped at `now`.
+    ///
+    /// # Arguments
+    /// * `now` - Time the clock reads until moved
+    ///
+    /// # Returns
+    /// FakeClock instance
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Move the clock forward, or back with a negative duration.
+    ///
+    /// # Arguments
+    /// * `duration` - Time to travel
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+
+    /// Stop the clock at another time.
+    ///
+    /// # Arguments
+    /// * `now` - Time the clock reads from now on
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap() = now;
+    }
+}
+
+impl Default for FakeClock {
+    /// Clock stopped at the current system time, to the second.
+    fn default() -> Self {
+        let now = Utc::now();
+        Self::new(DateTime::from_timestamp(now.timestamp(), 0).unwrap_or(now))
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fake_clock_stands_still_until_moved() {
+        let start = DateTime::from_timestamp(1_792_022_400, 0).unwrap();
+        let clock = FakeClock::new(start);
+        assert_eq!(clock.now(), start);
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::days(2));
+        assert_eq!(clock.now(), start + Duration::days(2));
+
+        clock.advance(-Duration::hours(1));
+        assert_eq!(clock.now(), start + Duration::days(2) - Duration::hours(1));
+
+        clock.set(start);
+        assert_eq!(clock.now(), start);
+    }
+}
@@ -0,0 +1,61 @@
+//! Source of the current time shared by the services.
+//!
+//! Code that stamps or compares times takes an `Arc<dyn Clock>` instead of
+//! calling `Utc::now()`, so tests can control time. Services run on
+//! [`SystemClock`]; tests swap in a [`FakeClock`] (`test-util` feature) and
+//! move it forward to expire tokens or make scheduled work due without
+//! sleeping:
+//!
+//! ```
+//! # #[cfg(feature = "test-util")]
+//! # {
+//! use std::sync::Arc;
+//!
+//! use chrono::Duration;
+//! use clock::Clock;
+//! use clock::FakeClock;
+//!
+//! let fake = Arc::new(FakeClock::default());
+//! let clock: Arc<dyn Clock> = fake.clone();
+//!
+//! let start = clock.now();
+//! fake.advance(Duration::hours(1));
+//! assert_eq!(clock.now() - start, Duration::hours(1));
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// Source of the current time.
+pub trait Clock: Debug + Send + Sync {
+    /// Current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock reading the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Shared system clock, the default of every service.
+///
+/// # Returns
+/// [`SystemClock`] behind an `Arc<dyn Clock>`
+pub fn system() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+#[cfg(feature = "test-util")]
+mod fake;
+
+#[cfg(feature = "test-util")]
+pub use fake::FakeClock;
@@ -0,0 +1,89 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Body of a successful response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Envelope<T> {
+    pub data: T,
+    /// Position in a paged list, absent for single resources
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+    pub request_id: String,
+}
+
+/// Body of an error response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorEnvelope {
+    pub error: ErrorBody,
+    pub request_id: String,
+}
+
+/// Description of a failed request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorBody {
+    /// Human-readable description
+    pub message: String,
+    /// Machine-readable code, for errors clients are expected to handle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+}
+
+/// Position of a page in a list.
+///
+/// Attached to a response as an extension by handlers of paged lists.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pagination {
+    /// Maximum number of items per page
+    pub limit: u32,
+    /// Cursor requesting the next page, None on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Payload of a successful response, attached as a response extension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseData(pub serde_json::Value);
+
+impl ResponseData {
+    /// Capture the payload of a response.
+    ///
+    /// # Arguments
+    /// * `data` - Payload as rendered by the service
+    ///
+    /// # Returns
+    /// ResponseData holding the JSON form of the payload, `null` if it cannot be serialized
+    pub fn new<T: Serialize>(data: &T) -> Self {
+        Self(serde_json::to_value(data).unwrap_or_default())
+    }
+}
+
+/// Error of a failed response, attached as a response extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseError(pub ErrorBody);
+
+impl ResponseError {
+    /// Describe the error of a response.
+    ///
+    /// # Arguments
+    /// * `message` - Human-readable description
+    ///
+    /// # Returns
+    /// ResponseError without a code
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(ErrorBody {
+            message: message.into(),
+            code: None,
+        })
+    }
+
+    /// Attach a machine-readable code to the error.
+    ///
+    /// # Arguments
+    /// * `code` - Stable code clients can match on
+    ///
+    /// # Returns
+    /// ResponseError carrying the code
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.0.code = Some(code.into());
+        self
+    }
+}
@@ -0,0 +1,68 @@
+use std::net::IpAddr;
+
+use axum::http::HeaderMap;
+
+/// Header listing the addresses a request was forwarded for, one per proxy.
+pub const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// Get the client address recorded by the proxy in front of the service.
+///
+/// Every proxy appends the address it received the request from to
+/// [`FORWARDED_FOR_HEADER`], so only the right-most entry was written by the
+/// proxy the service trusts. Entries left of it were sent by the client and
+/// can be forged. Only use this behind exactly one trusted proxy.
+///
+/// # Arguments
+/// * `headers` - Request headers
+///
+/// # Returns
+/// Right-most forwarded address, None if absent or not an IP address
+pub fn forwarded_client_ip(headers: &HeaderMap) -> Option<IpAddr> {
+    headers
+        .get_all(FORWARDED_FOR_HEADER)
+        .iter()
+        .next_back()
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.rsplit(',').next())
+        .and_then(|last| last.trim().parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    fn headers(values: &[&'static str]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for value in values {
+            headers.append(FORWARDED_FOR_HEADER, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn test_right_most_entry_is_the_client() {
+        // The client forged the first entry; the proxy appended the real address
+        let forged = headers(&["10.0.0.1, 203.0.113.7"]);
+        assert_eq!(
+            forwarded_client_ip(&forged),
+            Some("203.0.113.7".parse().unwrap())
+        );
+
+        let repeated = headers(&["10.0.0.1", "2001:db8::7"]);
+        assert_eq!(
+            forwarded_client_ip(&repeated),
+            Some("2001:db8::7".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_missing_or_invalid_entry_is_unknown() {
+        assert_eq!(forwarded_client_ip(&HeaderMap::new()), None);
+        assert_eq!(
+            forwarded_client_ip(&headers(&["203.0.113.7, unknown"])),
+            None
+        );
+    }
+}
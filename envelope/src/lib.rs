@@ -0,0 +1,58 @@
+//! Response envelope shared by the HTTP APIs of all services.
+//!
+//! [`versioned`] serves the API routes of a service under every version with
+//! shared handlers: `/api` and `/api/v1` answer with the handlers' own bodies
+//! and deprecation headers, `/api/v2` with the same shape everywhere:
+//! - Success: `{"data": ..., "pagination": {...}, "request_id": "..."}`,
+//!   `pagination` only for paged lists
+//! - Error: `{"error": {"message": "...", "code": "..."}, "request_id": "..."}`
+//!
+//! Handlers keep returning their service's own response types; those attach
+//! their payload to the response as [`ResponseData`] or [`ResponseError`],
+//! and the [`envelope`] middleware renders it. The request ID is taken from
+//! the `X-Request-Id` header, or generated, and echoed in the response header.
+//! [`forwarded_client_ip`] reads the client address a trusted proxy recorded in
+//! `X-Forwarded-For`.
+//!
+//! # Examples
+//!
+//! ```
+//! use axum::http::StatusCode;
+//! use axum::response::IntoResponse;
+//! use axum::response::Response;
+//! use axum::routing::get;
+//! use axum::Json;
+//! use axum::Router;
+//! use envelope::ResponseData;
+//!
+//! async fn hello() -> Response {
+//!     let data = serde_json::json!({ "greeting": "hello" });
+//!     let mut response = (StatusCode::OK, Json(data.clone())).into_response();
+//!     response.extensions_mut().insert(ResponseData(data));
+//!     response
+//! }
+//!
+//! let api = Router::new().route("/hello", get(hello));
+//! let deprecation = envelope::Deprecation::new(std::time::UNIX_EPOCH);
+//! let app: Router = envelope::versioned(api, deprecation);
+//! ```
+
+pub mod body;
+pub mod forwarded;
+pub mod middleware;
+pub mod version;
+
+pub use body::Envelope;
+pub use body::ErrorBody;
+pub use body::ErrorEnvelope;
+pub use body::Pagination;
+pub use body::ResponseData;
+pub use body::ResponseError;
+pub use forwarded::forwarded_client_ip;
+pub use forwarded::FORWARDED_FOR_HEADER;
+pub use middleware::client_request_id;
+pub use middleware::envelope;
+pub use middleware::REQUEST_ID_HEADER;
+pub use version::versioned;
+pub use version::ApiVersion;
+pub use version::Deprecation;
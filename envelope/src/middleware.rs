@@ -0,0 +1,269 @@
+use axum::body::to_bytes;
+use axum::body::Body;
+use axum::extract::Request;
+use axum::http::header::CONTENT_LENGTH;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::HeaderMap;
+use axum::http::HeaderValue;
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::Response;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::body::Envelope;
+use crate::body::ErrorBody;
+use crate::body::ErrorEnvelope;
+use crate::body::Pagination;
+use crate::body::ResponseData;
+use crate::body::ResponseError;
+
+/// Header carrying the ID of a request, from the client or generated.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longest client-supplied request ID that is kept
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// Largest error body read to recover its message
+const MAX_ERROR_BODY_BYTES: usize = 64 * 1024;
+
+/// Middleware rendering responses in the shared envelope.
+///
+/// Responses carrying [`ResponseData`] or [`ResponseError`] are rendered from
+/// it. Other error responses (e.g. authentication rejections) are rendered from
+/// an `{"error": "...", "code": "..."}` body if they have one, from their status
+/// otherwise. Other successful responses pass through unchanged.
+pub async fn envelope(request: Request, next: Next) -> Response {
+    let request_id = client_request_id(request.headers())
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let response = next.run(request).await;
+    let (mut parts, body) = response.into_parts();
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        parts.headers.insert(REQUEST_ID_HEADER, value);
+    }
+
+    let rendered = if let Some(ResponseData(data)) = parts.extensions.remove::<ResponseData>() {
+        to_json(&Envelope {
+            data,
+            pagination: parts.extensions.remove::<Pagination>(),
+            request_id,
+        })
+    } else if let Some(ResponseError(error)) = parts.extensions.remove::<ResponseError>() {
+        to_json(&ErrorEnvelope { error, request_id })
+    } else if parts.status.is_client_error() || parts.status.is_server_error() {
+        let error = error_from_body(parts.status, body).await;
+        to_json(&ErrorEnvelope { error, request_id })
+    } else {
+        return Response::from_parts(parts, body);
+    };
+
+    // Bodiless statuses stay bodiless
+    if parts.status == StatusCode::NO_CONTENT {
+        return Response::from_parts(parts, Body::empty());
+    }
+
+    parts.headers.remove(CONTENT_LENGTH);
+    parts
+        .headers
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    Response::from_parts(parts, Body::from(rendered))
+}
+
+/// Get the request ID a client sent in [`REQUEST_ID_HEADER`], if it is usable.
+///
+/// IDs must be printable ASCII without spaces and at most 128 bytes long.
+pub fn client_request_id(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_request_id(value))
+}
+
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LENGTH
+        && value.bytes().all(|byte| byte.is_ascii_graphic())
+}
+
+fn to_json<T: Serialize>(value: &T) -> Vec<u8> {
+    serde_json::to_vec(value).unwrap_or_default()
+}
+
+/// Recover the message of an error response rendered without [`ResponseError`].
+async fn error_from_body(status: StatusCode, body: Body) -> ErrorBody {
+    let fallback = || ErrorBody {
+        message: status
+            .canonical_reason()
+            .unwrap_or("Request failed")
+            .to_string(),
+        code: None,
+    };
+
+    let Ok(bytes) = to_bytes(body, MAX_ERROR_BODY_BYTES).await else {
+        return fallback();
+    };
+    let Ok(value) = serde_json::from_slice::<serde_json::Value>(&bytes) else {
+        return fallback();
+    };
+
+    match value.get("error").and_then(|error| error.as_str()) {
+        Some(message) => ErrorBody {
+            message: message.to_string(),
+            code: value
+                .get("code")
+                .and_then(|code| code.as_str())
+                .map(str::to_string),
+        },
+        None => fallback(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+    use axum::response::IntoResponse;
+    use axum::routing::get;
+    use axum::Json;
+    use axum::Router;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/item",
+                get(|| async {
+                    let data = json!({ "id": 1 });
+                    let mut response = Json(data.clone()).into_response();
+                    response.extensions_mut().insert(ResponseData(data));
+                    response
+                }),
+            )
+            .route(
+                "/items",
+                get(|| async {
+                    let data = json!([{ "id": 1 }]);
+                    let mut response = Json(data.clone()).into_response();
+                    response.extensions_mut().insert(ResponseData(data));
+                    response.extensions_mut().insert(Pagination {
+                        limit: 1,
+                        next_cursor: Some("1".to_string()),
+                    });
+                    response
+                }),
+            )
+            .route(
+                "/missing",
+                get(|| async {
+                    let mut response = StatusCode::NOT_FOUND.into_response();
+                    response
+                        .extensions_mut()
+                        .insert(ResponseError::new("Item not found"));
+                    response
+                }),
+            )
+            .route(
+                "/rejected",
+                get(|| async {
+                    (
+                        StatusCode::UNAUTHORIZED,
+                        Json(json!({ "error": "Token is revoked", "code": "token_revoked" })),
+                    )
+                }),
+            )
+            .route("/plain", get(|| async { "plain" }))
+            .layer(axum::middleware::from_fn(envelope))
+    }
+
+    async fn call(path: &str, headers: HeaderMap) -> (StatusCode, HeaderMap, Vec<u8>) {
+        let mut request = Request::builder().uri(path);
+        for (name, value) in &headers {
+            request = request.header(name, value);
+        }
+        let response = app()
+            .oneshot(
+                request
+                    .body(Body::empty())
+                    .expect("Failed to build request"),
+            )
+            .await
+            .expect("Request failed");
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Failed to read body");
+        (status, headers, body.to_vec())
+    }
+
+    fn json_body(body: &[u8]) -> serde_json::Value {
+        serde_json::from_slice(body).expect("Body is not JSON")
+    }
+
+    #[tokio::test]
+    async fn test_data_is_enveloped_with_request_id() {
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static("req-123"));
+
+        let (status, headers, body) = call("/item", headers).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers[REQUEST_ID_HEADER], "req-123");
+        assert_eq!(
+            json_body(&body),
+            json!({ "data": { "id": 1 }, "request_id": "req-123" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_id_generated_when_missing() {
+        let (_, headers, body) = call("/item", HeaderMap::new()).await;
+
+        let request_id = headers[REQUEST_ID_HEADER].to_str().unwrap();
+        assert!(Uuid::parse_str(request_id).is_ok());
+        assert_eq!(json_body(&body)["request_id"], request_id);
+    }
+
+    #[tokio::test]
+    async fn test_pagination_included_for_lists() {
+        let (_, _, body) = call("/items", HeaderMap::new()).await;
+
+        let body = json_body(&body);
+        assert_eq!(body["data"], json!([{ "id": 1 }]));
+        assert_eq!(
+            body["pagination"],
+            json!({ "limit": 1, "next_cursor": "1" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_enveloped() {
+        let (status, _, body) = call("/missing", HeaderMap::new()).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(
+            json_body(&body)["error"],
+            json!({ "message": "Item not found" })
+        );
+
+        let (status, _, body) = call("/rejected", HeaderMap::new()).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            json_body(&body)["error"],
+            json!({ "message": "Token is revoked", "code": "token_revoked" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_other_responses_pass_through() {
+        let (status, headers, body) = call("/plain", HeaderMap::new()).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(headers.contains_key(REQUEST_ID_HEADER));
+        assert_eq!(body, b"plain");
+    }
+}
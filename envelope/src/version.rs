@@ -0,0 +1,234 @@
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use axum::extract::Request;
+use axum::extract::State;
+use axum::http::header::LINK;
+use axum::http::HeaderName;
+use axum::http::HeaderValue;
+use axum::middleware::from_fn;
+use axum::middleware::from_fn_with_state;
+use axum::middleware::Next;
+use axum::response::Response;
+use axum::Router;
+
+use crate::middleware::envelope;
+
+/// Header marking a deprecated API (RFC 9745)
+pub const DEPRECATION_HEADER: HeaderName = HeaderName::from_static("deprecation");
+
+/// Header announcing when a deprecated API stops being served (RFC 8594)
+pub const SUNSET_HEADER: HeaderName = HeaderName::from_static("sunset");
+
+/// Versions of the public HTTP APIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiVersion {
+    /// Service-specific response bodies, also served unversioned under `/api`
+    V1,
+    /// Response bodies in the shared envelope
+    V2,
+}
+
+impl ApiVersion {
+    /// Version new clients should use
+    pub const LATEST: ApiVersion = ApiVersion::V2;
+
+    /// Path prefix the version is served under.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "/api/v1",
+            ApiVersion::V2 => "/api/v2",
+        }
+    }
+}
+
+/// Deprecation notice sent with every response of an old API version.
+#[derive(Debug, Clone)]
+pub struct Deprecation {
+    deprecated_at: SystemTime,
+    sunset: Option<SystemTime>,
+}
+
+impl Deprecation {
+    /// Create a notice for a version deprecated since `deprecated_at`.
+    ///
+    /// # Arguments
+    /// * `deprecated_at` - When the version was deprecated
+    ///
+    /// # Returns
+    /// Notice without a sunset date
+    pub fn new(deprecated_at: impl Into<SystemTime>) -> Self {
+        Self {
+            deprecated_at: deprecated_at.into(),
+            sunset: None,
+        }
+    }
+
+    /// Announce when the version stops being served.
+    ///
+    /// # Arguments
+    /// * `sunset` - When the version is removed
+    ///
+    /// # Returns
+    /// Notice also sending a `Sunset` header
+    pub fn with_sunset(mut self, sunset: impl Into<SystemTime>) -> Self {
+        self.sunset = Some(sunset.into());
+        self
+    }
+
+    fn deprecation_value(&self) -> Option<HeaderValue> {
+        let seconds = self
+            .deprecated_at
+            .duration_since(UNIX_EPOCH)
+            .ok()?
+            .as_secs();
+        HeaderValue::from_str(&format!("@{}", seconds)).ok()
+    }
+
+    fn sunset_value(&self) -> Option<HeaderValue> {
+        let sunset = self.sunset?;
+        HeaderValue::from_str(&httpdate::fmt_http_date(sunset)).ok()
+    }
+}
+
+/// Serve the API routes of a service under every version.
+///
+/// Handlers are shared by all versions; only the rendering of their responses
+/// differs. `/api` and `/api/v1` answer with the handlers' own bodies plus the
+/// deprecation headers, `/api/v2` in the shared envelope.
+///
+/// # Arguments
+/// * `api` - Routes relative to the version prefix (e.g. `/users`)
+/// * `deprecation` - Notice sent by the deprecated versions
+///
+/// # Returns
+/// Router serving `/api`, `/api/v1` and `/api/v2`
+pub fn versioned<S>(api: Router<S>, deprecation: Deprecation) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let v1 = api
+        .clone()
+        .layer(from_fn_with_state(deprecation, deprecated));
+    let v2 = api.layer(from_fn(envelope));
+
+    Router::new()
+        .nest("/api", v1.clone())
+        .nest(ApiVersion::V1.prefix(), v1)
+        .nest(ApiVersion::V2.prefix(), v2)
+}
+
+/// Middleware adding the `Deprecation`, `Sunset` and successor `Link` headers.
+pub async fn deprecated(
+    State(deprecation): State<Deprecation>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+
+    if let Some(value) = deprecation.deprecation_value() {
+        headers.insert(DEPRECATION_HEADER, value);
+    }
+    if let Some(value) = deprecation.sunset_value() {
+        headers.insert(SUNSET_HEADER, value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&format!(
+        "<{}>; rel=\"successor-version\"",
+        ApiVersion::LATEST.prefix()
+    )) {
+        headers.append(LINK, value);
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::body::to_bytes;
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use axum::routing::get;
+    use axum::Json;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::body::ResponseData;
+
+    fn app(deprecation: Deprecation) -> Router {
+        let api = Router::new().route(
+            "/item",
+            get(|| async {
+                let data = json!({ "id": 1 });
+                let mut response = Json(data.clone()).into_response();
+                response.extensions_mut().insert(ResponseData(data));
+                response
+            }),
+        );
+        versioned(api, deprecation)
+    }
+
+    async fn call(app: Router, path: &str) -> Response {
+        app.oneshot(
+            Request::builder()
+                .uri(path)
+                .body(Body::empty())
+                .expect("Failed to build request"),
+        )
+        .await
+        .expect("Request failed")
+    }
+
+    async fn json_body(response: Response) -> serde_json::Value {
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Failed to read body");
+        serde_json::from_slice(&body).expect("Body is not JSON")
+    }
+
+    #[tokio::test]
+    async fn test_old_versions_are_deprecated() {
+        let deprecated_at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let sunset = UNIX_EPOCH + Duration::from_secs(1_800_000_000);
+        let app = app(Deprecation::new(deprecated_at).with_sunset(sunset));
+
+        for path in ["/api/item", "/api/v1/item"] {
+            let response = call(app.clone(), path).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()[DEPRECATION_HEADER], "@1700000000");
+            assert_eq!(
+                response.headers()[SUNSET_HEADER],
+                "Fri, 15 Jan 2027 08:00:00 GMT"
+            );
+            assert_eq!(
+                response.headers()[LINK],
+                "</api/v2>; rel=\"successor-version\""
+            );
+            assert_eq!(json_body(response).await, json!({ "id": 1 }));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_latest_version_is_enveloped_without_deprecation() {
+        let app = app(Deprecation::new(UNIX_EPOCH));
+
+        let response = call(app, "/api/v2/item").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(DEPRECATION_HEADER).is_none());
+        assert!(response.headers().get(SUNSET_HEADER).is_none());
+        assert_eq!(json_body(response).await["data"], json!({ "id": 1 }));
+    }
+
+    #[tokio::test]
+    async fn test_sunset_is_optional() {
+        let app = app(Deprecation::new(UNIX_EPOCH));
+
+        let response = call(app, "/api/v1/item").await;
+        assert_eq!(response.headers()[DEPRECATION_HEADER], "@0");
+        assert!(response.headers().get(SUNSET_HEADER).is_none());
+    }
+}
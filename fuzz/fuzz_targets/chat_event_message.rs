@@ -0,0 +1,26 @@
+//! Kafka payloads on the chat.messages topics, parsed like the event consumer does.
+#![no_main]
+
+use chat_service::domain::channel::models::ChannelId;
+use chat_service::domain::message::models::MessageId;
+use chat_service::domain::user::models::UserId;
+use chat_service::outbound::events::messages::ChatEventMessage;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(event) = serde_json::from_str::<ChatEventMessage>(text) else {
+        return;
+    };
+
+    let _ = event.event_id();
+    let _ = event.event_type();
+
+    if let ChatEventMessage::MessageSent(message) = event {
+        let _ = ChannelId::from_string(&message.channel_id);
+        let _ = MessageId::from_string(&message.message_id);
+        let _ = UserId::from_string(&message.user_id);
+    }
+});
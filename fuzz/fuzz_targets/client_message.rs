@@ -0,0 +1,17 @@
+//! WebSocket frames sent by clients, parsed like the connection handler does.
+#![no_main]
+
+use chat_service::domain::message::models::MessageContent;
+use chat_service::inbound::websocket::messages::ClientMessage;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(ClientMessage::SendMessage { content }) = serde_json::from_str::<ClientMessage>(text)
+    {
+        let _ = MessageContent::new(content);
+    }
+});
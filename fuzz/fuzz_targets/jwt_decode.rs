@@ -0,0 +1,21 @@
+//! Bearer tokens from untrusted clients, validated like the HTTP and WebSocket layers do.
+#![no_main]
+
+use auth::Authenticator;
+use auth::Claims;
+use auth::JwtHandler;
+use libfuzzer_sys::fuzz_target;
+
+const SECRET: &[u8] = b"fuzz-secret-key-at-least-32-bytes-long";
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(token) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    let _ = JwtHandler::new(SECRET).decode::<Claims>(token);
+
+    let authenticator = Authenticator::new(SECRET);
+    let _ = authenticator.validate_user_token(token);
+    let _ = authenticator.validate_service_token(token);
+});
@@ -0,0 +1,34 @@
+//! Kafka payloads from user-service, parsed like the user events consumer does.
+#![no_main]
+
+use chat_service::domain::user::events::UserEvent;
+use chat_service::domain::user::models::UserId;
+use chat_service::domain::user::models::Username;
+use chat_service::outbound::events::messages::UserEventMessage;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(message) = serde_json::from_str::<UserEventMessage>(text) else {
+        return;
+    };
+    let Ok(event) = UserEvent::try_from(message) else {
+        return;
+    };
+
+    match event {
+        UserEvent::UserCreated(e) => {
+            let _ = UserId::from_string(&e.user_id);
+            let _ = Username::new(e.username);
+        }
+        UserEvent::UserUpdated(e) => {
+            let _ = UserId::from_string(&e.user_id);
+            let _ = Username::new(e.username);
+        }
+        UserEvent::UserDeleted(e) => {
+            let _ = UserId::from_string(&e.user_id);
+        }
+    }
+});
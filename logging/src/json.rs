@@ -0,0 +1,246 @@
+//! JSON log lines.
+//!
+//! Each event is written as one object:
+//! `{"timestamp": "...", "level": "INFO", "target": "...", "message": "...",
+//! "fields": {...}, "spans": [{"name": "...", ...}]}`, `fields` and `spans`
+//! only when the event has fields or is inside spans.
+
+use std::fmt;
+
+use chrono::SecondsFormat;
+use chrono::Utc;
+use serde_json::Map;
+use serde_json::Value;
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::Event;
+use tracing::Subscriber;
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::FmtContext;
+use tracing_subscriber::fmt::FormatEvent;
+use tracing_subscriber::fmt::FormatFields;
+use tracing_subscriber::fmt::FormattedFields;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Formats events as JSON lines.
+///
+/// Span fields are only included when recorded with [`JsonFields`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonFormat;
+
+impl<S, N> FormatEvent<S, N> for JsonFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        let mut fields = Map::new();
+        event.record(&mut JsonVisitor(&mut fields));
+
+        let mut line = Map::new();
+        line.insert(
+            "timestamp".to_string(),
+            Utc::now()
+                .to_rfc3339_opts(SecondsFormat::Millis, true)
+                .into(),
+        );
+        line.insert("level".to_string(), metadata.level().as_str().into());
+        line.insert("target".to_string(), metadata.target().into());
+        if let Some(message) = fields.remove("message") {
+            line.insert("message".to_string(), message);
+        }
+        if !fields.is_empty() {
+            line.insert("fields".to_string(), Value::Object(fields));
+        }
+
+        let spans: Vec<Value> = ctx
+            .event_scope()
+            .into_iter()
+            .flat_map(|scope| scope.from_root())
+            .map(|span| {
+                let mut object = Map::new();
+                object.insert("name".to_string(), span.name().into());
+                if let Some(formatted) = span.extensions().get::<FormattedFields<N>>() {
+                    if let Ok(Value::Object(fields)) = serde_json::from_str(formatted) {
+                        object.extend(fields);
+                    }
+                }
+                Value::Object(object)
+            })
+            .collect();
+        if !spans.is_empty() {
+            line.insert("spans".to_string(), Value::Array(spans));
+        }
+
+        writeln!(writer, "{}", Value::Object(line))
+    }
+}
+
+/// Records span fields as a JSON object, read back by [`JsonFormat`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonFields;
+
+impl<'writer> FormatFields<'writer> for JsonFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        mut writer: Writer<'writer>,
+        fields: R,
+    ) -> fmt::Result {
+        let mut object = Map::new();
+        fields.record(&mut JsonVisitor(&mut object));
+        write!(writer, "{}", Value::Object(object))
+    }
+
+    fn add_fields(
+        &self,
+        current: &'writer mut FormattedFields<Self>,
+        fields: &tracing::span::Record<'_>,
+    ) -> fmt::Result {
+        // Fields recorded after the span was created are merged into its object
+        let mut object = match serde_json::from_str(current) {
+            Ok(Value::Object(object)) => object,
+            _ => Map::new(),
+        };
+        fields.record(&mut JsonVisitor(&mut object));
+        current.fields = Value::Object(object).to_string();
+        Ok(())
+    }
+}
+
+/// Collects the fields of an event or span into a JSON object.
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl Visit for JsonVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+        self.0
+            .insert(field.name().to_string(), value.to_string().into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use tracing_subscriber::fmt::MakeWriter;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+
+    /// Writer keeping everything written in memory
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(bytes);
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Buffer;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_event_is_written_as_json_line() {
+        let buffer = Buffer::default();
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(buffer.clone())
+                .event_format(JsonFormat)
+                .fmt_fields(JsonFields),
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!(
+                "http_request",
+                method = "GET",
+                status = tracing::field::Empty
+            );
+            let _entered = span.enter();
+            span.record("status", 200);
+            tracing::warn!(user = "alice", attempts = 3, "Login failed");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output.lines().count(), 1);
+        let line: Value = serde_json::from_str(output.trim_end()).unwrap();
+        assert_eq!(line["level"], "WARN");
+        assert_eq!(line["target"], module_path!());
+        assert_eq!(line["message"], "Login failed");
+        assert_eq!(line["fields"]["user"], "alice");
+        assert_eq!(line["fields"]["attempts"], 3);
+        assert_eq!(line["spans"][0]["name"], "http_request");
+        assert_eq!(line["spans"][0]["method"], "GET");
+        assert_eq!(line["spans"][0]["status"], 200);
+        assert!(line["timestamp"].as_str().unwrap().ends_with('Z'));
+    }
+
+    #[test]
+    fn test_closed_span_is_written_with_its_duration() {
+        let buffer = Buffer::default();
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(buffer.clone())
+                .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+                .event_format(JsonFormat)
+                .fmt_fields(JsonFields),
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span =
+                tracing::info_span!("channel_repository.find_by_id", db.system = "postgresql");
+            let _entered = span.enter();
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output.lines().count(), 1);
+        let line: Value = serde_json::from_str(output.trim_end()).unwrap();
+        assert_eq!(line["message"], "close");
+        assert!(line["fields"]["time.busy"].is_string());
+        assert_eq!(line["spans"][0]["name"], "channel_repository.find_by_id");
+        assert_eq!(line["spans"][0]["db.system"], "postgresql");
+    }
+}
@@ -0,0 +1,281 @@
+//! Logging setup shared by the binaries of all services.
+//!
+//! Each service embeds a [`LoggingConfig`] in its configuration, under
+//! `[logging]`, and calls [`init`] once at startup:
+//!
+//! ```toml
+//! [logging]
+//! format = "json"          # or "pretty", the default
+//! level = "info"           # level of every target not listed below
+//! file = "logs/service.log"
+//! span_timings = true      # log each span with its duration when it closes
+//!
+//! [logging.targets]
+//! tower_http = "debug"
+//!
+//! [logging.syslog]
+//! address = "/dev/log"     # or "host:514" for a remote collector over UDP
+//! ```
+//!
+//! Events always go to stdout, and also to the file and syslog outputs when
+//! set. `RUST_LOG`, when set, replaces the configured level and targets.
+
+pub mod json;
+pub mod syslog;
+
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::Subscriber;
+use tracing_subscriber::fmt;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::Layer;
+
+use crate::json::JsonFields;
+use crate::json::JsonFormat;
+use crate::syslog::SyslogWriter;
+
+/// Errors raised while setting up logging.
+#[derive(Debug, thiserror::Error)]
+pub enum LoggingError {
+    #[error("Invalid log filter '{0}': {1}")]
+    InvalidFilter(String, String),
+
+    #[error("Failed to open log file {0}: {1}")]
+    File(PathBuf, std::io::Error),
+
+    #[error("Failed to connect to syslog at {0}: {1}")]
+    Syslog(String, std::io::Error),
+
+    #[error("Logging already initialized: {0}")]
+    AlreadyInitialized(String),
+}
+
+/// Layout of log lines.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines, colored on stdout
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log collectors
+    Json,
+}
+
+/// Logging configuration of a service.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LoggingConfig {
+    #[serde(default)]
+    pub format: LogFormat,
+    /// Level of the targets without an override, e.g. `info`
+    #[serde(default = "default_level")]
+    pub level: String,
+    /// Level per target (module path prefix), e.g. `tower_http = "debug"`
+    #[serde(default)]
+    pub targets: BTreeMap<String, String>,
+    /// File events are also appended to
+    #[serde(default)]
+    pub file: Option<PathBuf>,
+    /// Syslog daemon events are also sent to
+    #[serde(default)]
+    pub syslog: Option<SyslogConfig>,
+    /// Log spans, such as repository calls, with their duration when they close
+    #[serde(default)]
+    pub span_timings: bool,
+}
+
+fn default_level() -> String {
+    "info".to_string()
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::default(),
+            level: default_level(),
+            targets: BTreeMap::new(),
+            file: None,
+            syslog: None,
+            span_timings: false,
+        }
+    }
+}
+
+impl LoggingConfig {
+    /// Get the filter directives of the configured level and targets.
+    ///
+    /// # Returns
+    /// Directives in `RUST_LOG` syntax, e.g. `info,tower_http=debug`
+    pub fn directives(&self) -> String {
+        std::iter::once(self.level.clone())
+            .chain(
+                self.targets
+                    .iter()
+                    .map(|(target, level)| format!("{}={}", target, level)),
+            )
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Syslog output.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SyslogConfig {
+    /// Unix socket path of the local daemon, or `host:port` of a UDP collector
+    #[serde(default = "default_syslog_address")]
+    pub address: String,
+}
+
+fn default_syslog_address() -> String {
+    "/dev/log".to_string()
+}
+
+impl Default for SyslogConfig {
+    fn default() -> Self {
+        Self {
+            address: default_syslog_address(),
+        }
+    }
+}
+
+/// Install the global tracing subscriber of a binary.
+///
+/// # Arguments
+/// * `application` - Name events are tagged with in syslog, e.g. `chat-service`
+/// * `config` - Format, filter and outputs
+///
+/// # Errors
+/// * `InvalidFilter` - Level or target override is not a valid directive
+/// * `File` - Log file could not be opened
+/// * `Syslog` - Syslog address could not be reached
+/// * `AlreadyInitialized` - A global subscriber is already installed
+pub fn init(application: &str, config: &LoggingConfig) -> Result<(), LoggingError> {
+    let filter = match EnvFilter::try_from_default_env() {
+        Ok(filter) => filter,
+        Err(_) => {
+            let directives = config.directives();
+            EnvFilter::try_new(&directives)
+                .map_err(|e| LoggingError::InvalidFilter(directives, e.to_string()))?
+        }
+    };
+
+    let span_events = if config.span_timings {
+        FmtSpan::CLOSE
+    } else {
+        FmtSpan::NONE
+    };
+
+    let mut outputs = vec![output(
+        config.format,
+        span_events.clone(),
+        std::io::stdout,
+        true,
+    )];
+    if let Some(path) = &config.file {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent).map_err(|e| LoggingError::File(path.clone(), e))?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| LoggingError::File(path.clone(), e))?;
+        outputs.push(output(
+            config.format,
+            span_events.clone(),
+            Mutex::new(file),
+            false,
+        ));
+    }
+    if let Some(syslog) = &config.syslog {
+        let writer = SyslogWriter::connect(&syslog.address, application)
+            .map_err(|e| LoggingError::Syslog(syslog.address.clone(), e))?;
+        outputs.push(output(config.format, span_events, writer, false));
+    }
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(outputs)
+        .try_init()
+        .map_err(|e| LoggingError::AlreadyInitialized(e.to_string()))
+}
+
+/// Build the layer formatting events, and the span events asked for, to one output.
+fn output<S, W>(
+    format: LogFormat,
+    span_events: FmtSpan,
+    writer: W,
+    ansi: bool,
+) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    match format {
+        LogFormat::Pretty => fmt::layer()
+            .with_writer(writer)
+            .with_ansi(ansi)
+            .with_span_events(span_events)
+            .boxed(),
+        LogFormat::Json => fmt::layer()
+            .with_writer(writer)
+            .with_span_events(span_events)
+            .with_ansi(false)
+            .event_format(JsonFormat)
+            .fmt_fields(JsonFields)
+            .boxed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directives_list_level_then_targets() {
+        let mut config = LoggingConfig::default();
+        assert_eq!(config.directives(), "info");
+
+        config.level = "warn".to_string();
+        config
+            .targets
+            .insert("tower_http".to_string(), "debug".to_string());
+        config
+            .targets
+            .insert("chat_service".to_string(), "trace".to_string());
+
+        assert_eq!(
+            config.directives(),
+            "warn,chat_service=trace,tower_http=debug"
+        );
+        assert!(EnvFilter::try_new(config.directives()).is_ok());
+    }
+
+    #[test]
+    fn test_config_defaults() {
+        let config: LoggingConfig = serde_json::from_value(serde_json::json!({
+            "format": "json",
+            "syslog": {}
+        }))
+        .unwrap();
+
+        assert_eq!(config.format, LogFormat::Json);
+        assert_eq!(config.level, "info");
+        assert!(config.targets.is_empty());
+        assert!(config.file.is_none());
+        assert!(!config.span_timings);
+        assert_eq!(config.syslog.unwrap().address, "/dev/log");
+    }
+}
@@ -0,0 +1,179 @@
+//! Syslog output (RFC 3164 messages over datagrams).
+
+use std::io;
+use std::net::SocketAddr;
+use std::net::ToSocketAddrs;
+use std::net::UdpSocket;
+use std::os::unix::net::UnixDatagram;
+
+use tracing::Level;
+use tracing::Metadata;
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Facility services log under (`daemon`)
+const FACILITY: u8 = 3;
+
+/// Sends each formatted event to a syslog daemon as one message.
+///
+/// Messages are `<PRI>application[pid]: line`; the severity of the priority
+/// follows the level of the event.
+pub struct SyslogWriter {
+    transport: Transport,
+    application: String,
+}
+
+enum Transport {
+    Unix(UnixDatagram),
+    Udp(UdpSocket),
+}
+
+impl SyslogWriter {
+    /// Connect to a syslog daemon.
+    ///
+    /// # Arguments
+    /// * `address` - Unix socket path (e.g. `/dev/log`) or `host:port` of a UDP collector
+    /// * `application` - Name messages are tagged with
+    ///
+    /// # Errors
+    /// Socket could not be created or the address not resolved
+    pub fn connect(address: &str, application: &str) -> io::Result<Self> {
+        let transport = if address.starts_with('/') {
+            let socket = UnixDatagram::unbound()?;
+            socket.connect(address)?;
+            Transport::Unix(socket)
+        } else {
+            let target = address.to_socket_addrs()?.next().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "Address resolved to nothing")
+            })?;
+            let local: SocketAddr = match target {
+                SocketAddr::V4(_) => ([0, 0, 0, 0], 0).into(),
+                SocketAddr::V6(_) => ([0u16; 8], 0).into(),
+            };
+            let socket = UdpSocket::bind(local)?;
+            socket.connect(target)?;
+            Transport::Udp(socket)
+        };
+
+        Ok(Self {
+            transport,
+            application: application.to_string(),
+        })
+    }
+
+    /// Frame a formatted event as a syslog message.
+    fn message(&self, severity: u8, line: &[u8]) -> Vec<u8> {
+        let mut message = format!(
+            "<{}>{}[{}]: ",
+            FACILITY * 8 + severity,
+            self.application,
+            std::process::id()
+        )
+        .into_bytes();
+        message.extend_from_slice(line.strip_suffix(b"\n").unwrap_or(line));
+        message
+    }
+
+    fn send(&self, severity: u8, line: &[u8]) {
+        let message = self.message(severity, line);
+        // Logging must not fail the caller; a daemon that is down loses events
+        let _ = match &self.transport {
+            Transport::Unix(socket) => socket.send(&message),
+            Transport::Udp(socket) => socket.send(&message),
+        };
+    }
+}
+
+impl<'a> MakeWriter<'a> for SyslogWriter {
+    type Writer = SyslogMessage<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        SyslogMessage::new(self, severity(&Level::INFO))
+    }
+
+    fn make_writer_for(&'a self, metadata: &Metadata<'_>) -> Self::Writer {
+        SyslogMessage::new(self, severity(metadata.level()))
+    }
+}
+
+/// Buffers one formatted event, sent to the daemon when dropped.
+pub struct SyslogMessage<'a> {
+    writer: &'a SyslogWriter,
+    severity: u8,
+    buffer: Vec<u8>,
+}
+
+impl<'a> SyslogMessage<'a> {
+    fn new(writer: &'a SyslogWriter, severity: u8) -> Self {
+        Self {
+            writer,
+            severity,
+            buffer: Vec::new(),
+        }
+    }
+}
+
+impl io::Write for SyslogMessage<'_> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for SyslogMessage<'_> {
+    fn drop(&mut self) {
+        if !self.buffer.is_empty() {
+            self.writer.send(self.severity, &self.buffer);
+        }
+    }
+}
+
+/// Syslog severity of a tracing level.
+fn severity(level: &Level) -> u8 {
+    match *level {
+        Level::ERROR => 3,
+        Level::WARN => 4,
+        Level::INFO => 6,
+        Level::DEBUG | Level::TRACE => 7,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_event_is_sent_as_syslog_message() {
+        let collector = UdpSocket::bind("127.0.0.1:0").unwrap();
+        collector
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let address = collector.local_addr().unwrap().to_string();
+        let writer = SyslogWriter::connect(&address, "chat-service").unwrap();
+
+        {
+            let mut message = SyslogMessage::new(&writer, severity(&Level::ERROR));
+            message.write_all(b"Server error\n").unwrap();
+        }
+
+        let mut datagram = [0u8; 1024];
+        let length = collector.recv(&mut datagram).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&datagram[..length]),
+            format!("<27>chat-service[{}]: Server error", std::process::id())
+        );
+    }
+
+    #[test]
+    fn test_severity_follows_level() {
+        assert_eq!(severity(&Level::WARN), 4);
+        assert_eq!(severity(&Level::INFO), 6);
+        assert_eq!(severity(&Level::TRACE), 7);
+    }
+}
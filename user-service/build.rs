@@ -1,9 +1,16 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Generate gRPC code from proto files
+    buildinfo::emit_build_info();
+
+    // Generate gRPC code and event messages from proto files, and the descriptors served by reflection
+    let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
     tonic_build::configure()
         .build_server(true)
         .build_client(false)
-        .compile(&["../proto/user.proto"], &["../proto"])?;
+        .file_descriptor_set_path(out_dir.join("user_descriptor.bin"))
+        .compile(
+            &["../proto/user.proto", "../proto/user_events.proto"],
+            &["../proto"],
+        )?;
 
     Ok(())
 }
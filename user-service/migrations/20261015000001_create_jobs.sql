@@ -0,0 +1,16 @@
+-- Background jobs for long-running operations (exports, imports, bulk deletes)
+CREATE TABLE IF NOT EXISTS jobs (
+    id UUID PRIMARY KEY,
+    kind VARCHAR(64) NOT NULL,
+    owner_id UUID NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'pending',
+    progress SMALLINT NOT NULL DEFAULT 0,
+    result_url TEXT,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT jobs_status_check CHECK (status IN ('pending', 'running', 'succeeded', 'failed')),
+    CONSTRAINT jobs_progress_check CHECK (progress BETWEEN 0 AND 100)
+);
+
+CREATE INDEX idx_jobs_owner_id ON jobs(owner_id);
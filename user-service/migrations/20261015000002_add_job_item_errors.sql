@@ -0,0 +1,2 @@
+-- Per-item failures of bulk jobs (e.g. rejected rows of a user import)
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS item_errors JSONB NOT NULL DEFAULT '[]'::jsonb;
@@ -0,0 +1,13 @@
+-- WebAuthn credentials (passkeys) registered by users
+CREATE TABLE IF NOT EXISTS passkeys (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    credential_id BYTEA NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    credential JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    last_used_at TIMESTAMPTZ,
+    CONSTRAINT passkeys_credential_id_key UNIQUE (credential_id)
+);
+
+CREATE INDEX idx_passkeys_user_id ON passkeys(user_id);
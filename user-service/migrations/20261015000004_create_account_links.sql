@@ -0,0 +1,26 @@
+-- Password and magic link logins enabled per account (passkeys are linked by existing)
+CREATE TABLE IF NOT EXISTS account_links (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    method VARCHAR(32) NOT NULL,
+    linked_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (user_id, method)
+);
+
+-- Every existing account logs in by password
+INSERT INTO account_links (user_id, method, linked_at)
+SELECT id, 'password', created_at FROM users
+ON CONFLICT DO NOTHING;
+
+-- New accounts start with password login, whichever path created them
+CREATE OR REPLACE FUNCTION link_password_login() RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO account_links (user_id, method, linked_at)
+    VALUES (NEW.id, 'password', NEW.created_at)
+    ON CONFLICT DO NOTHING;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER users_link_password_login
+    AFTER INSERT ON users
+    FOR EACH ROW EXECUTE FUNCTION link_password_login();
@@ -0,0 +1,4 @@
+-- Deactivated and deleted users keep their row; lookups only see active ones
+ALTER TABLE users ADD COLUMN IF NOT EXISTS status TEXT NOT NULL DEFAULT 'active'
+    CHECK (status IN ('active', 'deactivated', 'deleted'));
+ALTER TABLE users ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
@@ -0,0 +1,15 @@
+-- Roles that can be granted in users.roles
+CREATE TABLE IF NOT EXISTS roles (
+    name TEXT PRIMARY KEY,
+    description TEXT NOT NULL
+);
+INSERT INTO roles (name, description)
+VALUES ('admin', 'Manages users and accounts through /api/admin')
+ON CONFLICT (name) DO NOTHING;
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS roles TEXT[] NOT NULL DEFAULT '{}';
+
+-- Admins lock accounts; locked users are hidden from lookups like deactivated ones
+ALTER TABLE users DROP CONSTRAINT IF EXISTS users_status_check;
+ALTER TABLE users ADD CONSTRAINT users_status_check
+    CHECK (status IN ('active', 'deactivated', 'locked', 'deleted'));
@@ -0,0 +1,9 @@
+-- Failed password logins since the last successful one; reaching the limit locks the subject.
+-- Subjects are `user:<id>` or `identifier:<username or email>`, so that identifiers of no
+-- account lock like accounts do and a lock does not reveal which accounts exist
+CREATE TABLE IF NOT EXISTS login_attempts (
+    subject TEXT PRIMARY KEY,
+    failed_attempts INTEGER NOT NULL,
+    last_failed_at TIMESTAMPTZ NOT NULL,
+    locked_until TIMESTAMPTZ
+);
@@ -0,0 +1,14 @@
+-- Personal access tokens users mint for their own scripts and integrations; only a digest of each token is kept
+CREATE TABLE IF NOT EXISTS personal_tokens (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(64) NOT NULL,
+    scopes TEXT[] NOT NULL,
+    token_hash TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    last_used_at TIMESTAMPTZ,
+    CONSTRAINT personal_tokens_token_hash_key UNIQUE (token_hash)
+);
+
+CREATE INDEX idx_personal_tokens_user_id ON personal_tokens(user_id);
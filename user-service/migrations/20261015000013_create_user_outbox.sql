@@ -0,0 +1,14 @@
+-- User events waiting to be published to Kafka, written in the transaction of the change they describe
+CREATE TABLE IF NOT EXISTS user_outbox (
+    sequence BIGSERIAL PRIMARY KEY,
+    message_key TEXT NOT NULL,
+    event_type VARCHAR(64) NOT NULL,
+    payload JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    last_error TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_outbox_message_key ON user_outbox(message_key, sequence);
+CREATE INDEX IF NOT EXISTS idx_user_outbox_next_attempt_at ON user_outbox(next_attempt_at);
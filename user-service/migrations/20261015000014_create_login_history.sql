@@ -0,0 +1,13 @@
+-- Successful logins of each user, shown on the account's recent activity page
+ALTER TABLE users ADD COLUMN IF NOT EXISTS last_login_at TIMESTAMPTZ;
+
+CREATE TABLE IF NOT EXISTS login_history (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    method VARCHAR(32) NOT NULL,
+    logged_in_at TIMESTAMPTZ NOT NULL,
+    ip_address TEXT,
+    user_agent TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_login_history_user_id_logged_in_at ON login_history(user_id, logged_in_at DESC);
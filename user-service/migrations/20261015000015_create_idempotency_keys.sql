@@ -0,0 +1,12 @@
+-- Requests made under an Idempotency-Key header, answered again when retried
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    operation VARCHAR(64) NOT NULL,
+    key VARCHAR(255) NOT NULL,
+    fingerprint VARCHAR(64) NOT NULL,
+    -- Created resource, NULL while the first request is in progress
+    resource_id UUID,
+    created_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (operation, key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_idempotency_keys_created_at ON idempotency_keys(created_at);
@@ -0,0 +1,15 @@
+-- Security-relevant actions, reviewed by admins. Entries are never updated and
+-- outlive the users they name, so users are not referenced by foreign key
+CREATE TABLE IF NOT EXISTS audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    action VARCHAR(64) NOT NULL,
+    actor_id UUID,
+    target_id UUID,
+    ip_address TEXT,
+    detail TEXT,
+    occurred_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_occurred_at ON audit_log(occurred_at DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_log_actor_id ON audit_log(actor_id, occurred_at DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_log_target_id ON audit_log(target_id, occurred_at DESC);
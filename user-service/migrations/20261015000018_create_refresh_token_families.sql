@@ -0,0 +1,12 @@
+-- Refresh token families, one per login; only current_jti may be rotated next
+CREATE TABLE IF NOT EXISTS refresh_token_families (
+    id TEXT PRIMARY KEY,
+    user_id TEXT,
+    current_jti TEXT NOT NULL,
+    claims JSONB NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX IF NOT EXISTS idx_refresh_token_families_user_id ON refresh_token_families(user_id);
+CREATE INDEX IF NOT EXISTS idx_refresh_token_families_expires_at ON refresh_token_families(expires_at);
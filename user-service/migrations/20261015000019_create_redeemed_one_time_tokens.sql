@@ -0,0 +1,7 @@
+-- One-time tokens (password reset, email verification, magic links) already redeemed
+CREATE TABLE IF NOT EXISTS redeemed_one_time_tokens (
+    jti TEXT PRIMARY KEY,
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_redeemed_one_time_tokens_expires_at ON redeemed_one_time_tokens(expires_at);
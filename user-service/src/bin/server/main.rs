@@ -1,38 +1,88 @@
+use std::net::SocketAddr;
 use std::sync::Arc;
 
+use auth::grpc::JwtInterceptor;
 use auth::Authenticator;
-use sqlx::postgres::PgPoolOptions;
+use auth::BcryptVerifier;
+use auth::HibpPasswordChecker;
+use auth::PostgresOneTimeTokenStore;
+use auth::PostgresRefreshTokenStore;
+use auth::PostgresRevocationStore;
+use auth::RefreshTokenPolicy;
+use auth::SecretString;
+use chrono::Duration;
 use tonic::transport::Server;
-use tracing_subscriber::layer::SubscriberExt;
-use tracing_subscriber::util::SubscriberInitExt;
+use user_service::config::CaptchaProvider;
 use user_service::config::Config;
+use user_service::domain::account_link::service::AccountLinkService;
+use user_service::domain::audit::service::AuditLogService;
+use user_service::domain::avatar::models::AvatarSettings;
+use user_service::domain::avatar::service::AvatarService;
+use user_service::domain::email_verification::models::EmailVerificationSettings;
+use user_service::domain::email_verification::service::EmailVerificationService;
+use user_service::domain::event_replay::service::EventReplayService;
+use user_service::domain::import::service::UserImportService;
+use user_service::domain::job::service::JobService;
+use user_service::domain::lockout::models::LockoutSettings;
+use user_service::domain::lockout::service::LockoutService;
+use user_service::domain::login_history::service::LoginHistoryService;
+use user_service::domain::magic_link::models::MagicLinkSettings;
+use user_service::domain::magic_link::service::MagicLinkService;
+use user_service::domain::outbox::models::OutboxSettings;
+use user_service::domain::outbox::service::OutboxRelay;
+use user_service::domain::passkey::models::PasskeySettings;
+use user_service::domain::passkey::service::PasskeyService;
+use user_service::domain::password_reset::models::PasswordResetSettings;
+use user_service::domain::password_reset::service::PasswordResetService;
+use user_service::domain::personal_token::models::PersonalTokenSettings;
+use user_service::domain::personal_token::service::PersonalTokenService;
+use user_service::domain::signup::models::SignupSettings;
+use user_service::domain::signup::service::SignupService;
 use user_service::domain::user::service::UserService;
+use user_service::inbound::grpc::health::report_health;
+use user_service::inbound::grpc::health::HEALTH_CHECK_INTERVAL;
 use user_service::inbound::grpc::UserGrpcService;
+use user_service::inbound::health::DependencyCheck;
+use user_service::inbound::health::HealthChecks;
 use user_service::inbound::http::router::create_router;
+use user_service::inbound::metrics::install_recorder;
+use user_service::inbound::rate_limit::MagicLinkRateLimiter;
+use user_service::inbound::rate_limit::RateLimiter;
+use user_service::outbound::captcha::SiteverifyCaptchaVerifier;
+use user_service::outbound::events::BroadcastPublisher;
 use user_service::outbound::events::KafkaEventProducer;
+use user_service::outbound::mail::WebhookEmailSender;
+use user_service::outbound::mail::WebhookLoginLinkSender;
+use user_service::outbound::repositories::InMemoryCeremonyStore;
+use user_service::outbound::repositories::PostgresAccountLinkRepository;
+use user_service::outbound::repositories::PostgresAuditLogger;
+use user_service::outbound::repositories::PostgresHealthCheck;
+use user_service::outbound::repositories::PostgresIdempotencyRepository;
+use user_service::outbound::repositories::PostgresJobRepository;
+use user_service::outbound::repositories::PostgresLoginAttemptRepository;
+use user_service::outbound::repositories::PostgresLoginHistoryRepository;
+use user_service::outbound::repositories::PostgresOutboxRepository;
+use user_service::outbound::repositories::PostgresPasskeyRepository;
+use user_service::outbound::repositories::PostgresPersonalTokenRepository;
 use user_service::outbound::repositories::PostgresUserRepository;
+use user_service::outbound::storage::S3ObjectStorage;
+use user_service::outbound::storage::S3Settings;
 use user_service::proto::user_service_server::UserServiceServer;
+use user_service::proto::FILE_DESCRIPTOR_SET;
+use webauthn_rs::prelude::Url;
+use webauthn_rs::WebauthnBuilder;
 
 #[tokio::main]
 async fn main() -> Result<(), anyhow::Error> {
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "user_service=debug,tower_http=debug".into()),
-        )
-        .with(tracing_subscriber::fmt::layer())
-        .init();
-
-    tracing::info!(
-        service = "user-service",
-        version = env!("CARGO_PKG_VERSION"),
-        "Service starting"
-    );
-
     let config = Config::load()?;
+    logging::init("user-service", &config.logging)?;
+    let build_info = Arc::new(buildinfo::build_info!("user-service", &config.redacted()));
+    build_info.log_startup();
+    // Installed before anything records a metric
+    let metrics_handle = install_recorder()?;
 
     tracing::info!(
-        database_url = %config.database.url,
+        database_url = %config.redacted().database.url,
         http_port = config.server.http_port,
         grpc_port = config.server.grpc_port,
         kafka_brokers = %config.kafka.brokers,
@@ -40,12 +90,16 @@ async fn main() -> Result<(), anyhow::Error> {
         "Configuration loaded"
     );
 
-    let pg_pool = PgPoolOptions::new()
-        .max_connections(5)
+    let pg_pool = config
+        .database
+        .pool_options()
         .connect(&config.database.url)
         .await?;
     tracing::info!(
-        max_connections = 5,
+        max_connections = config.database.max_connections,
+        min_connections = config.database.min_connections,
+        acquire_timeout_secs = config.database.acquire_timeout_secs,
+        statement_timeout_ms = config.database.statement_timeout_ms,
         database = "postgresql",
         "Database connection pool created"
     );
@@ -53,11 +107,224 @@ async fn main() -> Result<(), anyhow::Error> {
     sqlx::migrate!("./migrations").run(&pg_pool).await?;
     tracing::info!(database = "postgresql", "Database migrations completed");
 
-    let authenticator = Arc::new(Authenticator::new(config.jwt.secret.as_bytes()));
-    let user_repository = Arc::new(PostgresUserRepository::new(pg_pool));
+    // Users imported from the previous system still have bcrypt hashes. Revocations are
+    // kept in the database, where chat-service reads them too; refresh token families and
+    // redeemed one-time tokens there survive restarts and are shared between replicas
+    let authenticator = Arc::new(
+        Authenticator::new(config.jwt.secret.as_bytes())
+            .with_legacy_verifier(Arc::new(BcryptVerifier::new()))
+            .with_revocation_store(Arc::new(PostgresRevocationStore::new(pg_pool.clone())))
+            .with_refresh_store(Arc::new(PostgresRefreshTokenStore::new(pg_pool.clone())))
+            .with_one_time_store(Arc::new(PostgresOneTimeTokenStore::new(pg_pool.clone())))
+            .with_refresh_policy(RefreshTokenPolicy::new(
+                Duration::hours(config.jwt.expiration_hours),
+                Duration::days(config.jwt.refresh_expiration_days),
+            ))
+            .with_validation_cache(
+                config.jwt.validation_cache_size,
+                Duration::seconds(config.jwt.validation_cache_ttl_secs),
+            ),
+    );
+    let user_repository = Arc::new(PostgresUserRepository::new(pg_pool.clone()));
+    let job_repository = Arc::new(PostgresJobRepository::new(pg_pool.clone()));
+    let passkey_repository = Arc::new(PostgresPasskeyRepository::new(pg_pool.clone()));
+    let account_link_repository = Arc::new(PostgresAccountLinkRepository::new(pg_pool.clone()));
+    let login_attempt_repository = Arc::new(PostgresLoginAttemptRepository::new(pg_pool.clone()));
+    let login_history_repository = Arc::new(PostgresLoginHistoryRepository::new(pg_pool.clone()));
+    let personal_token_repository = Arc::new(PostgresPersonalTokenRepository::new(pg_pool.clone()));
+    let outbox_repository = Arc::new(PostgresOutboxRepository::new(pg_pool.clone()));
     let event_producer = Arc::new(KafkaEventProducer::new(&config)?);
+    // Relayed events also reach gRPC watchers once Kafka accepted them
+    let user_change_publisher = Arc::new(BroadcastPublisher::new(Arc::clone(&event_producer)));
+
+    let outbox_relay = OutboxRelay::new(
+        outbox_repository,
+        Arc::clone(&user_change_publisher),
+        OutboxSettings {
+            batch_size: config.outbox.batch_size,
+            poll_interval: std::time::Duration::from_millis(config.outbox.poll_interval_ms),
+            claim_timeout: chrono::Duration::seconds(config.outbox.claim_timeout_secs),
+            retry_base_delay: chrono::Duration::milliseconds(config.outbox.retry_base_delay_ms),
+            retry_max_delay: chrono::Duration::seconds(config.outbox.retry_max_delay_secs),
+        },
+    );
+    tracing::info!(
+        batch_size = config.outbox.batch_size,
+        poll_interval_ms = config.outbox.poll_interval_ms,
+        "Outbox relay started"
+    );
+    tokio::spawn(async move { outbox_relay.run().await });
+
+    let mut user_service = UserService::new(user_repository);
+    if config.password.check_compromised {
+        user_service = user_service.with_password_checker(Arc::new(HibpPasswordChecker::new()));
+        tracing::info!(checker = "hibp", "Compromised password checks enabled");
+    }
+    let user_service = Arc::new(user_service);
+    let captcha = &config.signup.captcha;
+    let captcha_verifier = captcha
+        .enabled
+        .then(|| {
+            tracing::info!(provider = ?captcha.provider, "CAPTCHA required for signups");
+            let secret = SecretString::new(captcha.secret.clone());
+            match captcha.provider {
+                CaptchaProvider::Turnstile => SiteverifyCaptchaVerifier::turnstile(secret),
+                CaptchaProvider::HCaptcha => SiteverifyCaptchaVerifier::hcaptcha(secret),
+            }
+            .map(Arc::new)
+        })
+        .transpose()?;
+    let signup_service = Arc::new(SignupService::new(
+        Arc::clone(&user_service),
+        captcha_verifier,
+        Arc::new(PostgresIdempotencyRepository::new(pg_pool.clone())),
+        SignupSettings::new(&config.signup.blocked_email_domains)
+            .with_idempotency_ttl(Duration::seconds(config.signup.idempotency_ttl_secs)),
+    ));
+    let signup_rate_limiter = Arc::new(RateLimiter::new(
+        config.signup.requests_per_window,
+        std::time::Duration::from_secs(config.signup.window_secs),
+    ));
+    let magic_link_rate_limiter = Arc::new(MagicLinkRateLimiter::new(
+        config.magic_link.requests_per_window,
+        config.magic_link.requests_per_email,
+        std::time::Duration::from_secs(config.magic_link.window_secs),
+    ));
+    let job_service = Arc::new(JobService::new(job_repository));
+    let import_service = Arc::new(UserImportService::new(
+        Arc::clone(&user_service),
+        Arc::clone(&job_service),
+    ));
+    let account_link_service = Arc::new(AccountLinkService::new(
+        Arc::clone(&user_service),
+        Arc::clone(&account_link_repository),
+        Arc::clone(&passkey_repository),
+    ));
+    let email_verification_service = Arc::new(EmailVerificationService::new(
+        Arc::clone(&user_service),
+        Arc::new(WebhookEmailSender::new(
+            config.email_verification.mail_webhook_url.clone(),
+        )),
+        Arc::clone(&authenticator),
+        EmailVerificationSettings {
+            link_url: config.email_verification.link_url.clone(),
+            ttl: chrono::Duration::hours(config.email_verification.expiration_hours),
+        },
+    ));
+
+    let password_reset_service = Arc::new(PasswordResetService::new(
+        Arc::clone(&user_service),
+        Arc::new(WebhookEmailSender::new(
+            config.password_reset.mail_webhook_url.clone(),
+        )),
+        Arc::clone(&event_producer),
+        Arc::clone(&authenticator),
+        PasswordResetSettings {
+            link_url: config.password_reset.link_url.clone(),
+            ttl: chrono::Duration::minutes(config.password_reset.expiration_minutes),
+        },
+    ));
+
+    let lockout_service = Arc::new(LockoutService::new(
+        login_attempt_repository,
+        Arc::clone(&event_producer),
+        LockoutSettings {
+            max_failed_attempts: config.lockout.max_failed_attempts,
+            lock_duration: chrono::Duration::minutes(config.lockout.duration_minutes),
+        },
+    ));
+    let login_history_service = Arc::new(LoginHistoryService::new(login_history_repository));
+
+    let personal_token_service = Arc::new(PersonalTokenService::new(
+        Arc::clone(&user_service),
+        personal_token_repository,
+        PersonalTokenSettings {
+            default_lifetime: chrono::Duration::days(config.personal_tokens.default_lifetime_days),
+            max_lifetime: chrono::Duration::days(config.personal_tokens.max_lifetime_days),
+        },
+    ));
+
+    let magic_link_service = config.magic_link.enabled.then(|| {
+        tracing::info!(
+            bind_ip = config.magic_link.bind_ip,
+            expiration_minutes = config.magic_link.expiration_minutes,
+            mail_relay = config.magic_link.mail_webhook_url.is_some(),
+            "Magic link login enabled"
+        );
+        Arc::new(MagicLinkService::new(
+            Arc::clone(&user_service),
+            Arc::new(WebhookLoginLinkSender::new(
+                config.magic_link.mail_webhook_url.clone(),
+            )),
+            account_link_repository,
+            Arc::clone(&authenticator),
+            MagicLinkSettings {
+                base_url: config.magic_link.base_url.clone(),
+                verification_url: config.magic_link.verification_url.clone(),
+                ttl: chrono::Duration::minutes(config.magic_link.expiration_minutes),
+                bind_ip: config.magic_link.bind_ip,
+                access_token_hours: config.jwt.expiration_hours,
+            },
+        ))
+    });
+
+    let avatar_service = config.avatar.enabled.then(|| {
+        let storage = &config.avatar.storage;
+        tracing::info!(
+            endpoint = %storage.endpoint,
+            bucket = %storage.bucket,
+            max_bytes = config.avatar.max_bytes,
+            "Avatar uploads enabled"
+        );
+        Arc::new(AvatarService::new(
+            Arc::clone(&user_service),
+            Arc::new(S3ObjectStorage::new(S3Settings {
+                endpoint: storage.endpoint.clone(),
+                bucket: storage.bucket.clone(),
+                region: storage.region.clone(),
+                access_key_id: storage.access_key_id.clone(),
+                secret_access_key: storage.secret_access_key.clone(),
+                public_url: storage.public_url.clone(),
+            })),
+            AvatarSettings {
+                max_bytes: config.avatar.max_bytes,
+            },
+        ))
+    });
+
+    let event_replay_service = Arc::new(EventReplayService::new(
+        Arc::clone(&user_service),
+        Arc::clone(&event_producer),
+    ));
+
+    let audit_log_service = Arc::new(AuditLogService::new(Arc::new(PostgresAuditLogger::new(
+        pg_pool.clone(),
+    ))));
 
-    let user_service = Arc::new(UserService::new(user_repository, event_producer));
+    let passkey_service = if config.passkey.enabled {
+        let rp_origin = Url::parse(&config.passkey.rp_origin)?;
+        let webauthn = WebauthnBuilder::new(&config.passkey.rp_id, &rp_origin)?
+            .rp_name(&config.passkey.rp_name)
+            .build()?;
+        tracing::info!(
+            rp_id = %config.passkey.rp_id,
+            rp_origin = %rp_origin,
+            "Passkey login enabled"
+        );
+        Some(Arc::new(PasskeyService::new(
+            Arc::clone(&user_service),
+            passkey_repository,
+            Arc::new(InMemoryCeremonyStore::new()),
+            Arc::new(webauthn),
+            Arc::clone(&authenticator),
+            PasskeySettings {
+                ceremony_ttl: chrono::Duration::seconds(config.passkey.ceremony_timeout_seconds),
+                access_token_hours: config.jwt.expiration_hours,
+            },
+        )))
+    } else {
+        None
+    };
 
     let http_address = format!("0.0.0.0:{}", config.server.http_port);
     let http_listener = tokio::net::TcpListener::bind(&http_address).await?;
@@ -68,16 +335,53 @@ async fn main() -> Result<(), anyhow::Error> {
         "Http server listening"
     );
 
+    // Shared by `/readyz` and the gRPC health service
+    let dependency_checks: Vec<Arc<dyn DependencyCheck>> =
+        vec![Arc::new(PostgresHealthCheck::new(pg_pool)), event_producer];
+    let health_checks = Arc::new(HealthChecks::new(dependency_checks));
+
     let http_application = create_router(
         Arc::clone(&user_service),
+        signup_service,
+        signup_rate_limiter,
+        magic_link_rate_limiter,
+        job_service,
+        import_service,
+        magic_link_service,
+        passkey_service,
+        account_link_service,
+        email_verification_service,
+        password_reset_service,
+        lockout_service,
+        login_history_service,
+        Arc::clone(&personal_token_service),
+        avatar_service,
+        event_replay_service,
+        audit_log_service,
         Arc::clone(&authenticator),
         config.jwt.expiration_hours,
+        build_info,
+        Arc::clone(&health_checks),
+        metrics_handle,
+        config.server.trust_forwarded_for,
+        config.limits.json_body_bytes,
+        config.limits.upload_body_bytes,
+        config.api.v1_deprecation(),
     );
-    let http_server =
-        tokio::spawn(async move { axum::serve(http_listener, http_application).await });
+    let http_server = tokio::spawn(async move {
+        axum::serve(
+            http_listener,
+            http_application.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+    });
 
     let grpc_address = format!("0.0.0.0:{}", config.server.grpc_port).parse()?;
-    let grpc_service = UserGrpcService::new(Arc::clone(&user_service));
+    let grpc_service = UserGrpcService::new(
+        Arc::clone(&user_service),
+        personal_token_service,
+        user_change_publisher,
+    );
     tracing::info!(
         address = %grpc_address,
         port = config.server.grpc_port,
@@ -85,9 +389,26 @@ async fn main() -> Result<(), anyhow::Error> {
         "gRpc server listening"
     );
 
+    // Probes and grpcurl reach health and reflection without a token
+    let (health_reporter, health_service) = tonic_health::server::health_reporter();
+    tokio::spawn(report_health(
+        health_reporter,
+        health_checks,
+        HEALTH_CHECK_INTERVAL,
+    ));
+    let reflection_service = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+        .build()?;
+
     let grpc_server = tokio::spawn(async move {
         Server::builder()
-            .add_service(UserServiceServer::new(grpc_service))
+            .add_service(health_service)
+            .add_service(reflection_service)
+            .add_service(UserServiceServer::with_interceptor(
+                grpc_service,
+                JwtInterceptor::new(authenticator)
+                    .with_trusted_services(config.grpc.trusted_callers.clone()),
+            ))
             .serve(grpc_address)
             .await
     });
@@ -1,43 +1,729 @@
 use std::env;
+use std::time::Duration;
 
+use buildinfo::redact_url_password;
+use buildinfo::REDACTED;
+use chrono::DateTime;
+use chrono::Utc;
 use config::Config as ConfigBuilder;
 use config::ConfigError;
 use config::Environment;
 use config::File;
+use envelope::Deprecation;
+use logging::LoggingConfig;
 use serde::Deserialize;
+use serde::Serialize;
+use sqlx::postgres::PgPoolOptions;
+use sqlx::Executor;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Config {
     pub database: DatabaseConfig,
     pub server: ServerConfig,
     pub jwt: JwtConfig,
     pub kafka: KafkaConfig,
+    #[serde(default)]
+    pub password: PasswordConfig,
+    #[serde(default)]
+    pub magic_link: MagicLinkConfig,
+    #[serde(default)]
+    pub passkey: PasskeyConfig,
+    #[serde(default)]
+    pub email_verification: EmailVerificationConfig,
+    #[serde(default)]
+    pub password_reset: PasswordResetConfig,
+    #[serde(default)]
+    pub lockout: LockoutConfig,
+    #[serde(default)]
+    pub personal_tokens: PersonalTokenConfig,
+    #[serde(default)]
+    pub outbox: OutboxConfig,
+    #[serde(default)]
+    pub grpc: GrpcConfig,
+    #[serde(default)]
+    pub signup: SignupConfig,
+    #[serde(default)]
+    pub avatar: AvatarConfig,
+    #[serde(default)]
+    pub limits: LimitsConfig,
+    #[serde(default)]
+    pub api: ApiConfig,
+    #[serde(default)]
+    pub logging: LoggingConfig,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DatabaseConfig {
     pub url: String,
+    /// Most connections the pool opens
+    #[serde(default = "default_database_max_connections")]
+    pub max_connections: u32,
+    /// Connections the pool keeps open while idle
+    #[serde(default)]
+    pub min_connections: u32,
+    /// Seconds a query waits for a free connection before failing
+    #[serde(default = "default_database_acquire_timeout_secs")]
+    pub acquire_timeout_secs: u64,
+    /// Milliseconds Postgres lets a statement run before cancelling it, 0 for no limit
+    #[serde(default)]
+    pub statement_timeout_ms: u64,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+impl DatabaseConfig {
+    /// Pool options sized and timed from this configuration.
+    ///
+    /// # Returns
+    /// Pool options setting the statement timeout on every new connection
+    pub fn pool_options(&self) -> PgPoolOptions {
+        let statement_timeout_ms = self.statement_timeout_ms;
+        PgPoolOptions::new()
+            .max_connections(self.max_connections)
+            .min_connections(self.min_connections)
+            .acquire_timeout(Duration::from_secs(self.acquire_timeout_secs))
+            .after_connect(move |connection, _| {
+                Box::pin(async move {
+                    connection
+                        .execute(
+                            format!("SET statement_timeout = {}", statement_timeout_ms).as_str(),
+                        )
+                        .await?;
+                    Ok(())
+                })
+            })
+    }
+
+    /// Check the pool settings are usable.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::Message` naming the first setting out of range
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_connections == 0 {
+            return Err(ConfigError::Message(
+                "database.max_connections must be at least 1".to_string(),
+            ));
+        }
+        if self.min_connections > self.max_connections {
+            return Err(ConfigError::Message(format!(
+                "database.min_connections ({}) must not exceed database.max_connections ({})",
+                self.min_connections, self.max_connections
+            )));
+        }
+        if self.acquire_timeout_secs == 0 {
+            return Err(ConfigError::Message(
+                "database.acquire_timeout_secs must be at least 1".to_string(),
+            ));
+        }
+        if self.statement_timeout_ms > MAX_STATEMENT_TIMEOUT_MS {
+            return Err(ConfigError::Message(format!(
+                "database.statement_timeout_ms must not exceed {}",
+                MAX_STATEMENT_TIMEOUT_MS
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Largest `statement_timeout` Postgres accepts, in milliseconds
+const MAX_STATEMENT_TIMEOUT_MS: u64 = i32::MAX as u64;
+
+fn default_database_max_connections() -> u32 {
+    5
+}
+
+fn default_database_acquire_timeout_secs() -> u64 {
+    30
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ServerConfig {
     pub http_port: u16,
     pub grpc_port: u16,
+    /// Take the client IP from the last `X-Forwarded-For` entry (only behind one trusted proxy)
+    #[serde(default)]
+    pub trust_forwarded_for: bool,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct JwtConfig {
     pub secret: String,
+    /// Lifetime of access tokens
     pub expiration_hours: i64,
+    /// Absolute lifetime of a login's refresh tokens; refreshing does not extend it
+    #[serde(default = "default_refresh_expiration_days")]
+    pub refresh_expiration_days: i64,
+    /// Access tokens cached after validation, 0 disables the cache
+    #[serde(default = "default_validation_cache_size")]
+    pub validation_cache_size: usize,
+    /// Maximum time a validated access token stays cached
+    #[serde(default = "default_validation_cache_ttl_secs")]
+    pub validation_cache_ttl_secs: i64,
+}
+
+fn default_refresh_expiration_days() -> i64 {
+    30
 }
 
-#[derive(Debug, Deserialize, Clone)]
+fn default_validation_cache_size() -> usize {
+    10_000
+}
+
+fn default_validation_cache_ttl_secs() -> i64 {
+    60
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct KafkaConfig {
     pub brokers: String,
     pub topic: String,
+    /// Encoding of the events published to the topic
+    #[serde(default)]
+    pub encoding: EventEncoding,
+}
+
+/// Wire encoding of published user events.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EventEncoding {
+    /// `UserEventMessage` as JSON
+    #[default]
+    Json,
+    /// `proto/user_events.proto`, smaller and checked against the schema
+    Protobuf,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct PasswordConfig {
+    /// Reject passwords found in the Have I Been Pwned breach corpus
+    #[serde(default)]
+    pub check_compromised: bool,
+}
+
+/// Password-less login by emailed link.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MagicLinkConfig {
+    /// Serve `POST /api/auth/magic-link` and its callback
+    #[serde(default)]
+    pub enabled: bool,
+    /// Page the emailed link opens; it posts the `token` query parameter to the callback
+    #[serde(default = "default_magic_link_base_url")]
+    pub base_url: String,
+    /// Page a link confirming an address for magic link login opens; it
+    /// posts the `token` query parameter to `/api/account/methods/magic-link/verify`
+    #[serde(default = "default_magic_link_verification_url")]
+    pub verification_url: String,
+    /// Minutes until a link expires
+    #[serde(default = "default_magic_link_expiration_minutes")]
+    pub expiration_minutes: i64,
+    /// Only accept a link from the IP address it was requested from
+    #[serde(default = "default_magic_link_bind_ip")]
+    pub bind_ip: bool,
+    /// Mail relay the link emails are posted to, logged instead if unset
+    #[serde(default)]
+    pub mail_webhook_url: Option<String>,
+    /// Link requests one client IP may make per window
+    #[serde(default = "default_magic_link_requests_per_window")]
+    pub requests_per_window: u32,
+    /// Link requests for one email address per window, from any client
+    #[serde(default = "default_magic_link_requests_per_email")]
+    pub requests_per_email: u32,
+    /// Length of the rate limit windows in seconds
+    #[serde(default = "default_magic_link_window_secs")]
+    pub window_secs: u64,
+}
+
+impl Default for MagicLinkConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            base_url: default_magic_link_base_url(),
+            verification_url: default_magic_link_verification_url(),
+            expiration_minutes: default_magic_link_expiration_minutes(),
+            bind_ip: default_magic_link_bind_ip(),
+            mail_webhook_url: None,
+            requests_per_window: default_magic_link_requests_per_window(),
+            requests_per_email: default_magic_link_requests_per_email(),
+            window_secs: default_magic_link_window_secs(),
+        }
+    }
+}
+
+fn default_magic_link_base_url() -> String {
+    "http://localhost:3000/login/magic".to_string()
+}
+
+fn default_magic_link_verification_url() -> String {
+    "http://localhost:3000/account/magic-link".to_string()
+}
+
+fn default_magic_link_expiration_minutes() -> i64 {
+    15
+}
+
+fn default_magic_link_bind_ip() -> bool {
+    true
+}
+
+fn default_magic_link_requests_per_window() -> u32 {
+    10
+}
+
+fn default_magic_link_requests_per_email() -> u32 {
+    3
+}
+
+fn default_magic_link_window_secs() -> u64 {
+    3600
+}
+
+/// WebAuthn passkey registration and login.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PasskeyConfig {
+    /// Serve the `/api/passkeys` and `/api/auth/passkey` routes
+    #[serde(default)]
+    pub enabled: bool,
+    /// Relying party ID, the domain passkeys are scoped to
+    #[serde(default = "default_passkey_rp_id")]
+    pub rp_id: String,
+    /// Origin of the web client performing the ceremonies
+    #[serde(default = "default_passkey_rp_origin")]
+    pub rp_origin: String,
+    /// Name shown by authenticators when creating a passkey
+    #[serde(default = "default_passkey_rp_name")]
+    pub rp_name: String,
+    /// Seconds a started ceremony may take to finish
+    #[serde(default = "default_passkey_ceremony_timeout_seconds")]
+    pub ceremony_timeout_seconds: i64,
+}
+
+impl Default for PasskeyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rp_id: default_passkey_rp_id(),
+            rp_origin: default_passkey_rp_origin(),
+            rp_name: default_passkey_rp_name(),
+            ceremony_timeout_seconds: default_passkey_ceremony_timeout_seconds(),
+        }
+    }
+}
+
+/// Confirmation of users' email addresses by emailed link.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EmailVerificationConfig {
+    /// Public URL of `GET /api/auth/verify`; the link appends the `token` query parameter
+    #[serde(default = "default_email_verification_link_url")]
+    pub link_url: String,
+    /// Hours until a link expires
+    #[serde(default = "default_email_verification_expiration_hours")]
+    pub expiration_hours: i64,
+    /// Mail relay the emails are posted to, logged instead if unset
+    #[serde(default)]
+    pub mail_webhook_url: Option<String>,
+}
+
+impl Default for EmailVerificationConfig {
+    fn default() -> Self {
+        Self {
+            link_url: default_email_verification_link_url(),
+            expiration_hours: default_email_verification_expiration_hours(),
+            mail_webhook_url: None,
+        }
+    }
+}
+
+fn default_email_verification_link_url() -> String {
+    "http://localhost:3001/api/auth/verify".to_string()
+}
+
+fn default_email_verification_expiration_hours() -> i64 {
+    24
+}
+
+/// Password resets by emailed link.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PasswordResetConfig {
+    /// Page asking for the new password, which posts it with the link's `token`
+    /// query parameter to `POST /api/auth/password-reset/confirm`
+    #[serde(default = "default_password_reset_link_url")]
+    pub link_url: String,
+    /// Minutes until a link expires
+    #[serde(default = "default_password_reset_expiration_minutes")]
+    pub expiration_minutes: i64,
+    /// Mail relay the emails are posted to, logged instead if unset
+    #[serde(default)]
+    pub mail_webhook_url: Option<String>,
+}
+
+impl Default for PasswordResetConfig {
+    fn default() -> Self {
+        Self {
+            link_url: default_password_reset_link_url(),
+            expiration_minutes: default_password_reset_expiration_minutes(),
+            mail_webhook_url: None,
+        }
+    }
+}
+
+fn default_password_reset_link_url() -> String {
+    "http://localhost:3000/reset-password".to_string()
+}
+
+fn default_password_reset_expiration_minutes() -> i64 {
+    30
+}
+
+/// Lockout of accounts after failed password logins, answered with `423 Locked`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LockoutConfig {
+    /// Failed logins in a row that lock the account
+    #[serde(default = "default_lockout_max_failed_attempts")]
+    pub max_failed_attempts: u32,
+    /// Minutes the account stays locked
+    #[serde(default = "default_lockout_duration_minutes")]
+    pub duration_minutes: i64,
+}
+
+impl Default for LockoutConfig {
+    fn default() -> Self {
+        Self {
+            max_failed_attempts: default_lockout_max_failed_attempts(),
+            duration_minutes: default_lockout_duration_minutes(),
+        }
+    }
+}
+
+fn default_lockout_max_failed_attempts() -> u32 {
+    5
+}
+
+fn default_lockout_duration_minutes() -> i64 {
+    15
+}
+
+/// Personal access tokens users mint for their own integrations.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PersonalTokenConfig {
+    /// Days until tokens minted without an expiry expire
+    #[serde(default = "default_personal_token_default_lifetime_days")]
+    pub default_lifetime_days: i64,
+    /// Longest lifetime, in days, a token may be minted with
+    #[serde(default = "default_personal_token_max_lifetime_days")]
+    pub max_lifetime_days: i64,
+}
+
+impl Default for PersonalTokenConfig {
+    fn default() -> Self {
+        Self {
+            default_lifetime_days: default_personal_token_default_lifetime_days(),
+            max_lifetime_days: default_personal_token_max_lifetime_days(),
+        }
+    }
+}
+
+fn default_personal_token_default_lifetime_days() -> i64 {
+    30
+}
+
+fn default_personal_token_max_lifetime_days() -> i64 {
+    365
+}
+
+/// Callers of the internal gRPC API.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GrpcConfig {
+    /// Services whose service tokens are accepted, by token subject
+    #[serde(default = "default_grpc_trusted_callers")]
+    pub trusted_callers: Vec<String>,
+}
+
+impl Default for GrpcConfig {
+    fn default() -> Self {
+        Self {
+            trusted_callers: default_grpc_trusted_callers(),
+        }
+    }
+}
+
+fn default_grpc_trusted_callers() -> Vec<String> {
+    vec!["chat-service".to_string()]
+}
+
+/// Relay publishing the user events stored in the outbox to Kafka.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OutboxConfig {
+    /// Messages published per round
+    #[serde(default = "default_outbox_batch_size")]
+    pub batch_size: u32,
+    /// Pause, in milliseconds, between polls of a drained outbox
+    #[serde(default = "default_outbox_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+    /// Seconds a claimed message is hidden from other relays
+    #[serde(default = "default_outbox_claim_timeout_secs")]
+    pub claim_timeout_secs: i64,
+    /// Delay, in milliseconds, before the first retry of a failed message
+    #[serde(default = "default_outbox_retry_base_delay_ms")]
+    pub retry_base_delay_ms: i64,
+    /// Longest delay, in seconds, between retries
+    #[serde(default = "default_outbox_retry_max_delay_secs")]
+    pub retry_max_delay_secs: i64,
+}
+
+impl Default for OutboxConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: default_outbox_batch_size(),
+            poll_interval_ms: default_outbox_poll_interval_ms(),
+            claim_timeout_secs: default_outbox_claim_timeout_secs(),
+            retry_base_delay_ms: default_outbox_retry_base_delay_ms(),
+            retry_max_delay_secs: default_outbox_retry_max_delay_secs(),
+        }
+    }
+}
+
+fn default_outbox_batch_size() -> u32 {
+    100
+}
+
+fn default_outbox_poll_interval_ms() -> u64 {
+    500
+}
+
+fn default_outbox_claim_timeout_secs() -> i64 {
+    30
+}
+
+fn default_outbox_retry_base_delay_ms() -> i64 {
+    1000
+}
+
+fn default_outbox_retry_max_delay_secs() -> i64 {
+    300
+}
+
+/// Public registration through `POST /api/users`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SignupConfig {
+    /// Signups one client IP may attempt per window
+    #[serde(default = "default_signup_requests_per_window")]
+    pub requests_per_window: u32,
+    /// Length of the rate limit window in seconds
+    #[serde(default = "default_signup_window_secs")]
+    pub window_secs: u64,
+    /// Email domains, subdomains included, rejected as disposable
+    #[serde(default = "default_signup_blocked_email_domains")]
+    pub blocked_email_domains: Vec<String>,
+    /// Seconds retries under an `Idempotency-Key` get the outcome of the first signup
+    #[serde(default = "default_signup_idempotency_ttl_secs")]
+    pub idempotency_ttl_secs: i64,
+    #[serde(default)]
+    pub captcha: CaptchaConfig,
+}
+
+impl Default for SignupConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_window: default_signup_requests_per_window(),
+            window_secs: default_signup_window_secs(),
+            blocked_email_domains: default_signup_blocked_email_domains(),
+            idempotency_ttl_secs: default_signup_idempotency_ttl_secs(),
+            captcha: CaptchaConfig::default(),
+        }
+    }
+}
+
+fn default_signup_requests_per_window() -> u32 {
+    5
+}
+
+fn default_signup_window_secs() -> u64 {
+    3600
+}
+
+fn default_signup_idempotency_ttl_secs() -> i64 {
+    86400
+}
+
+fn default_signup_blocked_email_domains() -> Vec<String> {
+    [
+        "10minutemail.com",
+        "dispostable.com",
+        "guerrillamail.com",
+        "mailinator.com",
+        "maildrop.cc",
+        "sharklasers.com",
+        "temp-mail.org",
+        "trashmail.com",
+        "yopmail.com",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+}
+
+/// CAPTCHA required with every signup.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CaptchaConfig {
+    /// Reject signups without a CAPTCHA response accepted by the provider
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub provider: CaptchaProvider,
+    /// Secret key of the site at the provider
+    #[serde(default)]
+    pub secret: String,
+}
+
+/// Provider checking CAPTCHA responses.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptchaProvider {
+    /// Cloudflare Turnstile
+    #[default]
+    Turnstile,
+    /// hCaptcha
+    HCaptcha,
+}
+
+/// Avatar uploads to an S3-compatible bucket.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AvatarConfig {
+    /// Serve `PUT /api/users/{id}/avatar`
+    #[serde(default)]
+    pub enabled: bool,
+    /// Largest accepted image, in bytes
+    #[serde(default = "default_avatar_max_bytes")]
+    pub max_bytes: usize,
+    #[serde(default)]
+    pub storage: ObjectStorageConfig,
+}
+
+impl Default for AvatarConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_bytes: default_avatar_max_bytes(),
+            storage: ObjectStorageConfig::default(),
+        }
+    }
+}
+
+fn default_avatar_max_bytes() -> usize {
+    2 * 1024 * 1024
+}
+
+/// S3-compatible bucket (AWS S3, MinIO, R2, ...).
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct ObjectStorageConfig {
+    /// Endpoint of the S3 API, e.g. `https://s3.eu-west-1.amazonaws.com`
+    #[serde(default)]
+    pub endpoint: String,
+    #[serde(default)]
+    pub bucket: String,
+    #[serde(default)]
+    pub region: String,
+    #[serde(default)]
+    pub access_key_id: String,
+    #[serde(default)]
+    pub secret_access_key: String,
+    /// HTTPS base URL objects are served from, such as a CDN, `{endpoint}/{bucket}` if unset
+    #[serde(default)]
+    pub public_url: Option<String>,
+}
+
+/// Request body size limits per route class, rejected with `413 Payload Too Large`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LimitsConfig {
+    /// Largest body of JSON routes
+    #[serde(default = "default_json_body_bytes")]
+    pub json_body_bytes: usize,
+    /// Largest body of upload routes, such as user imports and avatars
+    #[serde(default = "default_upload_body_bytes")]
+    pub upload_body_bytes: usize,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            json_body_bytes: default_json_body_bytes(),
+            upload_body_bytes: default_upload_body_bytes(),
+        }
+    }
+}
+
+fn default_json_body_bytes() -> usize {
+    1024 * 1024
+}
+
+fn default_upload_body_bytes() -> usize {
+    16 * 1024 * 1024
+}
+
+/// Versioned HTTP API configuration.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ApiConfig {
+    /// When `/api` and `/api/v1` were deprecated in favour of `/api/v2`
+    #[serde(default = "default_v1_deprecated_at")]
+    pub v1_deprecated_at: DateTime<Utc>,
+    /// When `/api` and `/api/v1` stop being served, announced in the `Sunset` header
+    #[serde(default)]
+    pub v1_sunset: Option<DateTime<Utc>>,
+}
+
+impl ApiConfig {
+    /// Deprecation notice of `/api` and `/api/v1`.
+    pub fn v1_deprecation(&self) -> Deprecation {
+        let deprecation = Deprecation::new(self.v1_deprecated_at);
+        match self.v1_sunset {
+            Some(sunset) => deprecation.with_sunset(sunset),
+            None => deprecation,
+        }
+    }
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            v1_deprecated_at: default_v1_deprecated_at(),
+            v1_sunset: None,
+        }
+    }
+}
+
+fn default_v1_deprecated_at() -> DateTime<Utc> {
+    // 2026-10-15, when `/api/v2` was introduced
+    DateTime::from_timestamp(1_792_022_400, 0).unwrap_or_default()
+}
+
+fn default_passkey_rp_id() -> String {
+    "localhost".to_string()
+}
+
+fn default_passkey_rp_origin() -> String {
+    "http://localhost:3000".to_string()
+}
+
+fn default_passkey_rp_name() -> String {
+    "chat-rs".to_string()
+}
+
+fn default_passkey_ceremony_timeout_seconds() -> i64 {
+    300
 }
 
 impl Config {
+    /// Copy of the configuration with secrets replaced, safe to log or expose.
+    ///
+    /// # Returns
+    /// Configuration with the JWT secret, database password and storage key redacted
+    pub fn redacted(&self) -> Self {
+        let mut config = self.clone();
+        config.jwt.secret = REDACTED.to_string();
+        config.signup.captcha.secret = REDACTED.to_string();
+        config.avatar.storage.secret_access_key = REDACTED.to_string();
+        config.database.url = redact_url_password(&config.database.url);
+        config
+    }
+
     /// Load configuration from files with environment variable overrides
     ///
     /// Priority (highest to lowest):
@@ -58,7 +744,62 @@ impl Config {
             .build()?;
 
         let config: Config = configuration.try_deserialize()?;
+        config.database.validate()?;
 
         Ok(config)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn database_config() -> DatabaseConfig {
+        DatabaseConfig {
+            url: "postgresql://localhost:5432/db".to_string(),
+            max_connections: 10,
+            min_connections: 2,
+            acquire_timeout_secs: 5,
+            statement_timeout_ms: 10_000,
+        }
+    }
+
+    #[test]
+    fn test_database_config_accepts_valid_pool_settings() {
+        assert!(database_config().validate().is_ok());
+    }
+
+    #[test]
+    fn test_database_config_rejects_empty_pool() {
+        let config = DatabaseConfig {
+            max_connections: 0,
+            min_connections: 0,
+            ..database_config()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_database_config_rejects_min_above_max() {
+        let config = DatabaseConfig {
+            min_connections: 11,
+            ..database_config()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_database_config_rejects_out_of_range_timeouts() {
+        let no_acquire_timeout = DatabaseConfig {
+            acquire_timeout_secs: 0,
+            ..database_config()
+        };
+        assert!(no_acquire_timeout.validate().is_err());
+
+        let statement_timeout_too_long = DatabaseConfig {
+            statement_timeout_ms: MAX_STATEMENT_TIMEOUT_MS + 1,
+            ..database_config()
+        };
+        assert!(statement_timeout_too_long.validate().is_err());
+    }
+}
@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+use crate::domain::passkey::errors::PasskeyError;
+use crate::domain::user::errors::UserError;
+
+/// Top-level error for account linking operations
+#[derive(Debug, Clone, Error)]
+pub enum AccountLinkError {
+    #[error("Invalid login method: {0}")]
+    InvalidMethod(String),
+
+    #[error("Login method is not linked: {0}")]
+    NotLinked(String),
+
+    #[error("Login method is already linked: {0}")]
+    AlreadyLinked(String),
+
+    #[error("The last login method of an account cannot be removed")]
+    LastMethod,
+
+    #[error(transparent)]
+    User(#[from] UserError),
+
+    #[error(transparent)]
+    Passkey(#[from] PasskeyError),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
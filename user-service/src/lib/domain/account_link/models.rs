@@ -0,0 +1,133 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::account_link::errors::AccountLinkError;
+use crate::domain::passkey::models::PasskeyCredential;
+use crate::domain::passkey::models::PasskeyId;
+use crate::domain::user::models::UserId;
+
+/// Way of logging in to an account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuthMethodKind {
+    Password,
+    MagicLink,
+    Passkey,
+}
+
+impl AuthMethodKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthMethodKind::Password => "password",
+            AuthMethodKind::MagicLink => "magic_link",
+            AuthMethodKind::Passkey => "passkey",
+        }
+    }
+}
+
+impl FromStr for AuthMethodKind {
+    type Err = AccountLinkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "password" => Ok(AuthMethodKind::Password),
+            "magic_link" => Ok(AuthMethodKind::MagicLink),
+            "passkey" => Ok(AuthMethodKind::Passkey),
+            _ => Err(AccountLinkError::InvalidMethod(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for AuthMethodKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Identifier of a linked login method.
+///
+/// Written as `password`, `magic_link`, or the ID of a passkey, since an
+/// account can hold several passkeys but only one of the other methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMethodId {
+    Password,
+    MagicLink,
+    Passkey(PasskeyId),
+}
+
+impl AuthMethodId {
+    pub fn kind(&self) -> AuthMethodKind {
+        match self {
+            AuthMethodId::Password => AuthMethodKind::Password,
+            AuthMethodId::MagicLink => AuthMethodKind::MagicLink,
+            AuthMethodId::Passkey(_) => AuthMethodKind::Passkey,
+        }
+    }
+}
+
+impl FromStr for AuthMethodId {
+    type Err = AccountLinkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<AuthMethodKind>() {
+            Ok(AuthMethodKind::Password) => Ok(AuthMethodId::Password),
+            Ok(AuthMethodKind::MagicLink) => Ok(AuthMethodId::MagicLink),
+            _ => PasskeyId::from_string(s)
+                .map(AuthMethodId::Passkey)
+                .map_err(|_| AccountLinkError::InvalidMethod(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for AuthMethodId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthMethodId::Password | AuthMethodId::MagicLink => self.kind().fmt(f),
+            AuthMethodId::Passkey(id) => id.fmt(f),
+        }
+    }
+}
+
+/// Password or magic link login enabled for an account.
+///
+/// Passkeys are linked by registering them and not recorded here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountLink {
+    pub user_id: UserId,
+    pub method: AuthMethodId,
+    pub linked_at: DateTime<Utc>,
+}
+
+/// Login method attached to an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthMethod {
+    pub id: AuthMethodId,
+    /// Name of a passkey
+    pub name: Option<String>,
+    pub linked_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<&AccountLink> for AuthMethod {
+    fn from(link: &AccountLink) -> Self {
+        Self {
+            id: link.method,
+            name: None,
+            linked_at: link.linked_at,
+            last_used_at: None,
+        }
+    }
+}
+
+impl From<&PasskeyCredential> for AuthMethod {
+    fn from(passkey: &PasskeyCredential) -> Self {
+        Self {
+            id: AuthMethodId::Passkey(passkey.id),
+            name: Some(passkey.name.as_str().to_string()),
+            linked_at: passkey.created_at,
+            last_used_at: passkey.last_used_at,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use auth::SecretString;
+
+use crate::domain::account_link::errors::AccountLinkError;
+use crate::domain::account_link::models::AccountLink;
+use crate::domain::account_link::models::AuthMethod;
+use crate::domain::account_link::models::AuthMethodId;
+use crate::domain::account_link::models::AuthMethodKind;
+use crate::domain::user::models::UserId;
+
+/// Port for managing the login methods attached to an account.
+///
+/// Magic link logins are linked through `MagicLinkServicePort` and passkeys
+/// through `PasskeyServicePort`, whose ceremonies verify them.
+#[async_trait]
+pub trait AccountLinkServicePort: Send + Sync + 'static {
+    /// List the login methods of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the methods
+    ///
+    /// # Returns
+    /// Password and magic link first, then passkeys oldest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn list_methods(&self, user_id: &UserId) -> Result<Vec<AuthMethod>, AccountLinkError>;
+
+    /// Set a password for an account that has none and link password login.
+    ///
+    /// # Arguments
+    /// * `user_id` - User linking the password
+    /// * `password` - New plaintext password
+    ///
+    /// # Returns
+    /// Linked password method
+    ///
+    /// # Errors
+    /// * `AlreadyLinked` - Account already has a password
+    /// * `User` - User does not exist or the password is rejected
+    /// * `DatabaseError` - Database operation failed
+    async fn link_password(
+        &self,
+        user_id: &UserId,
+        password: SecretString,
+    ) -> Result<AuthMethod, AccountLinkError>;
+
+    /// Remove a login method, keeping at least one.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the method
+    /// * `method` - Method to remove
+    ///
+    /// # Errors
+    /// * `NotLinked` - User has no such method
+    /// * `LastMethod` - Method is the only one left
+    /// * `DatabaseError` - Database operation failed
+    async fn remove_method(
+        &self,
+        user_id: &UserId,
+        method: &AuthMethodId,
+    ) -> Result<(), AccountLinkError>;
+
+    /// Whether a user may log in with a kind of method.
+    ///
+    /// # Arguments
+    /// * `user_id` - User logging in
+    /// * `kind` - Kind of login method
+    ///
+    /// # Returns
+    /// True if at least one method of this kind is linked
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn is_linked(
+        &self,
+        user_id: &UserId,
+        kind: AuthMethodKind,
+    ) -> Result<bool, AccountLinkError>;
+}
+
+/// Persistence operations for password and magic link account links.
+#[async_trait]
+pub trait AccountLinkRepository: Send + Sync + 'static {
+    /// Retrieve the links of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the links
+    ///
+    /// # Returns
+    /// Links, password first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<AccountLink>, AccountLinkError>;
+
+    /// Persist a link unless it already exists.
+    ///
+    /// # Arguments
+    /// * `link` - Link to store
+    ///
+    /// # Returns
+    /// Whether the link was added
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn link(&self, link: &AccountLink) -> Result<bool, AccountLinkError>;
+
+    /// Delete a link.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the link
+    /// * `method` - Linked method
+    ///
+    /// # Returns
+    /// Whether a link was deleted
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn unlink(
+        &self,
+        user_id: &UserId,
+        method: &AuthMethodId,
+    ) -> Result<bool, AccountLinkError>;
+
+    /// Check whether a link exists.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the link
+    /// * `method` - Linked method
+    ///
+    /// # Returns
+    /// True if the link exists
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn is_linked(
+        &self,
+        user_id: &UserId,
+        method: &AuthMethodId,
+    ) -> Result<bool, AccountLinkError>;
+}
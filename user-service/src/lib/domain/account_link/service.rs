@@ -0,0 +1,386 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use auth::SecretString;
+use chrono::Utc;
+
+use crate::domain::account_link::errors::AccountLinkError;
+use crate::domain::account_link::models::AccountLink;
+use crate::domain::account_link::models::AuthMethod;
+use crate::domain::account_link::models::AuthMethodId;
+use crate::domain::account_link::models::AuthMethodKind;
+use crate::domain::account_link::ports::AccountLinkRepository;
+use crate::domain::account_link::ports::AccountLinkServicePort;
+use crate::domain::passkey::ports::PasskeyRepository;
+use crate::domain::user::models::UpdateUserCommand;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserServicePort;
+
+/// Domain service implementation for account linking.
+///
+/// Concrete implementation of AccountLinkServicePort with dependency injection.
+pub struct AccountLinkService<US, AR, PR>
+where
+    US: UserServicePort,
+    AR: AccountLinkRepository,
+    PR: PasskeyRepository,
+{
+    user_service: Arc<US>,
+    repository: Arc<AR>,
+    passkeys: Arc<PR>,
+}
+
+impl<US, AR, PR> AccountLinkService<US, AR, PR>
+where
+    US: UserServicePort,
+    AR: AccountLinkRepository,
+    PR: PasskeyRepository,
+{
+    /// Create a new account link service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `user_service` - Service updating passwords
+    /// * `repository` - Account link persistence implementation
+    /// * `passkeys` - Passkey persistence implementation
+    ///
+    /// # Returns
+    /// Configured account link service instance
+    pub fn new(user_service: Arc<US>, repository: Arc<AR>, passkeys: Arc<PR>) -> Self {
+        Self {
+            user_service,
+            repository,
+            passkeys,
+        }
+    }
+}
+
+#[async_trait]
+impl<US, AR, PR> AccountLinkServicePort for AccountLinkService<US, AR, PR>
+where
+    US: UserServicePort,
+    AR: AccountLinkRepository,
+    PR: PasskeyRepository,
+{
+    async fn list_methods(&self, user_id: &UserId) -> Result<Vec<AuthMethod>, AccountLinkError> {
+        let links = self.repository.list_for_user(user_id).await?;
+        let passkeys = self.passkeys.list_for_user(user_id).await?;
+
+        Ok(links
+            .iter()
+            .map(AuthMethod::from)
+            .chain(passkeys.iter().map(AuthMethod::from))
+            .collect())
+    }
+
+    async fn link_password(
+        &self,
+        user_id: &UserId,
+        password: SecretString,
+    ) -> Result<AuthMethod, AccountLinkError> {
+        if self
+            .repository
+            .is_linked(user_id, &AuthMethodId::Password)
+            .await?
+        {
+            return Err(AccountLinkError::AlreadyLinked(
+                AuthMethodKind::Password.to_string(),
+            ));
+        }
+
+        self.user_service
+            .update_user(
+                user_id,
+                UpdateUserCommand {
+                    username: None,
+                    email: None,
+                    password: Some(password),
+                    display_name: None,
+                    avatar_url: None,
+                    bio: None,
+                },
+            )
+            .await?;
+
+        let link = AccountLink {
+            user_id: *user_id,
+            method: AuthMethodId::Password,
+            linked_at: Utc::now(),
+        };
+        self.repository.link(&link).await?;
+
+        tracing::info!(user_id = %user_id, "Password login linked");
+        Ok((&link).into())
+    }
+
+    async fn remove_method(
+        &self,
+        user_id: &UserId,
+        method: &AuthMethodId,
+    ) -> Result<(), AccountLinkError> {
+        // Check-then-delete: two concurrent removals can still leave an
+        // account without methods, recoverable by a magic link or reset.
+        let methods = self.list_methods(user_id).await?;
+        if !methods.iter().any(|linked| linked.id == *method) {
+            return Err(AccountLinkError::NotLinked(method.to_string()));
+        }
+        if methods.len() <= 1 {
+            return Err(AccountLinkError::LastMethod);
+        }
+
+        let removed = match method {
+            AuthMethodId::Password | AuthMethodId::MagicLink => {
+                self.repository.unlink(user_id, method).await?
+            }
+            AuthMethodId::Passkey(passkey_id) => self.passkeys.delete(user_id, passkey_id).await?,
+        };
+        if !removed {
+            return Err(AccountLinkError::NotLinked(method.to_string()));
+        }
+
+        tracing::info!(user_id = %user_id, method = %method.kind(), "Login method removed");
+        Ok(())
+    }
+
+    async fn is_linked(
+        &self,
+        user_id: &UserId,
+        kind: AuthMethodKind,
+    ) -> Result<bool, AccountLinkError> {
+        match kind {
+            AuthMethodKind::Password => {
+                self.repository
+                    .is_linked(user_id, &AuthMethodId::Password)
+                    .await
+            }
+            AuthMethodKind::MagicLink => {
+                self.repository
+                    .is_linked(user_id, &AuthMethodId::MagicLink)
+                    .await
+            }
+            AuthMethodKind::Passkey => Ok(!self.passkeys.list_for_user(user_id).await?.is_empty()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+    use mockall::predicate::*;
+
+    use super::*;
+    use crate::domain::passkey::errors::PasskeyError;
+    use crate::domain::passkey::models::PasskeyCredential;
+    use crate::domain::passkey::models::PasskeyId;
+    use crate::domain::user::errors::UserError;
+    use crate::domain::user::models::CreateUserCommand;
+    use crate::domain::user::models::EmailAddress;
+    use crate::domain::user::models::ImportUserCommand;
+    use crate::domain::user::models::LoginIdentifier;
+    use crate::domain::user::models::User;
+    use crate::domain::user::models::UserCursor;
+    use crate::domain::user::models::UserPage;
+    use crate::domain::user::models::UserSearchQuery;
+    use crate::domain::user::models::UserStatus;
+    use crate::domain::user::models::Username;
+
+    mock! {
+        pub TestUserService {}
+
+        #[async_trait]
+        impl UserServicePort for TestUserService {
+            async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
+            async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+            async fn get_user(&self, id: &UserId) -> Result<User, UserError>;
+            async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
+            async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+            async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
+            async fn list_users(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<UserPage, UserError>;
+            async fn search_users(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
+            async fn update_user(&self, id: &UserId, command: UpdateUserCommand) -> Result<User, UserError>;
+            async fn change_password(&self, id: &UserId, current_password: SecretString, new_password: SecretString) -> Result<(), UserError>;
+            async fn reset_password(&self, id: &UserId, new_password: SecretString) -> Result<(), UserError>;
+            async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+            async fn replace_password_hash(&self, id: &UserId, password_hash: String) -> Result<(), UserError>;
+            async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    mock! {
+        pub TestAccountLinkRepository {}
+
+        #[async_trait]
+        impl AccountLinkRepository for TestAccountLinkRepository {
+            async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<AccountLink>, AccountLinkError>;
+            async fn link(&self, link: &AccountLink) -> Result<bool, AccountLinkError>;
+            async fn unlink(&self, user_id: &UserId, method: &AuthMethodId) -> Result<bool, AccountLinkError>;
+            async fn is_linked(&self, user_id: &UserId, method: &AuthMethodId) -> Result<bool, AccountLinkError>;
+        }
+    }
+
+    mock! {
+        pub TestPasskeyRepository {}
+
+        #[async_trait]
+        impl PasskeyRepository for TestPasskeyRepository {
+            async fn create(&self, credential: PasskeyCredential) -> Result<PasskeyCredential, PasskeyError>;
+            async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<PasskeyCredential>, PasskeyError>;
+            async fn update(&self, credential: &PasskeyCredential) -> Result<(), PasskeyError>;
+            async fn delete(&self, user_id: &UserId, id: &PasskeyId) -> Result<bool, PasskeyError>;
+        }
+    }
+
+    fn link(user_id: UserId, method: AuthMethodId) -> AccountLink {
+        AccountLink {
+            user_id,
+            method,
+            linked_at: Utc::now(),
+        }
+    }
+
+    fn service(
+        user_service: MockTestUserService,
+        repository: MockTestAccountLinkRepository,
+        passkeys: MockTestPasskeyRepository,
+    ) -> AccountLinkService<
+        MockTestUserService,
+        MockTestAccountLinkRepository,
+        MockTestPasskeyRepository,
+    > {
+        AccountLinkService::new(
+            Arc::new(user_service),
+            Arc::new(repository),
+            Arc::new(passkeys),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_remove_last_method_is_refused() {
+        let user_id = UserId::new();
+
+        let mut repository = MockTestAccountLinkRepository::new();
+        repository
+            .expect_list_for_user()
+            .returning(move |_| Ok(vec![link(user_id, AuthMethodId::Password)]));
+        repository.expect_unlink().never();
+        let mut passkeys = MockTestPasskeyRepository::new();
+        passkeys.expect_list_for_user().returning(|_| Ok(vec![]));
+
+        let service = service(MockTestUserService::new(), repository, passkeys);
+
+        let result = service
+            .remove_method(&user_id, &AuthMethodId::Password)
+            .await;
+        assert!(matches!(result, Err(AccountLinkError::LastMethod)));
+    }
+
+    #[tokio::test]
+    async fn test_remove_method_with_another_linked() {
+        let user_id = UserId::new();
+
+        let mut repository = MockTestAccountLinkRepository::new();
+        repository.expect_list_for_user().returning(move |_| {
+            Ok(vec![
+                link(user_id, AuthMethodId::Password),
+                link(user_id, AuthMethodId::MagicLink),
+            ])
+        });
+        repository
+            .expect_unlink()
+            .with(eq(user_id), eq(AuthMethodId::Password))
+            .times(1)
+            .returning(|_, _| Ok(true));
+        let mut passkeys = MockTestPasskeyRepository::new();
+        passkeys.expect_list_for_user().returning(|_| Ok(vec![]));
+
+        let service = service(MockTestUserService::new(), repository, passkeys);
+
+        assert!(service
+            .remove_method(&user_id, &AuthMethodId::Password)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_remove_unlinked_passkey() {
+        let user_id = UserId::new();
+
+        let mut repository = MockTestAccountLinkRepository::new();
+        repository.expect_list_for_user().returning(move |_| {
+            Ok(vec![
+                link(user_id, AuthMethodId::Password),
+                link(user_id, AuthMethodId::MagicLink),
+            ])
+        });
+        let mut passkeys = MockTestPasskeyRepository::new();
+        passkeys.expect_list_for_user().returning(|_| Ok(vec![]));
+        passkeys.expect_delete().never();
+
+        let service = service(MockTestUserService::new(), repository, passkeys);
+
+        let result = service
+            .remove_method(&user_id, &AuthMethodId::Passkey(PasskeyId::new()))
+            .await;
+        assert!(matches!(result, Err(AccountLinkError::NotLinked(_))));
+    }
+
+    #[tokio::test]
+    async fn test_link_password_when_already_linked() {
+        let mut user_service = MockTestUserService::new();
+        user_service.expect_update_user().never();
+        let mut repository = MockTestAccountLinkRepository::new();
+        repository.expect_is_linked().returning(|_, _| Ok(true));
+        repository.expect_link().never();
+
+        let service = service(user_service, repository, MockTestPasskeyRepository::new());
+
+        let result = service
+            .link_password(&UserId::new(), SecretString::new("Correct-Horse-7"))
+            .await;
+        assert!(matches!(result, Err(AccountLinkError::AlreadyLinked(_))));
+    }
+
+    #[tokio::test]
+    async fn test_link_password_sets_password() {
+        let user_id = UserId::new();
+
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_update_user()
+            .withf(move |id, command| *id == user_id && command.password.is_some())
+            .times(1)
+            .returning(|id, _| {
+                Ok(User {
+                    id: *id,
+                    username: Username::new("alice".to_string()).unwrap(),
+                    email: EmailAddress::new("alice@example.com".to_string()).unwrap(),
+                    password_hash: "$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA".to_string(),
+                    email_verified: false,
+                    display_name: None,
+                    avatar_url: None,
+                    bio: None,
+                    roles: Vec::new(),
+                    created_at: Utc::now(),
+                })
+            });
+        let mut repository = MockTestAccountLinkRepository::new();
+        repository.expect_is_linked().returning(|_, _| Ok(false));
+        repository
+            .expect_link()
+            .withf(move |link| link.user_id == user_id && link.method == AuthMethodId::Password)
+            .times(1)
+            .returning(|_| Ok(true));
+
+        let service = service(user_service, repository, MockTestPasskeyRepository::new());
+
+        let method = service
+            .link_password(&user_id, SecretString::new("Correct-Horse-7"))
+            .await
+            .unwrap();
+        assert_eq!(method.id, AuthMethodId::Password);
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// Top-level error for audit log operations
+#[derive(Debug, Clone, Error)]
+pub enum AuditError {
+    #[error("Unknown audit action: {0}")]
+    InvalidAction(String),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
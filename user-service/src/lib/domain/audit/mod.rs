@@ -0,0 +1,4 @@
+pub mod errors;
+pub mod models;
+pub mod ports;
+pub mod service;
@@ -0,0 +1,145 @@
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::audit::errors::AuditError;
+use crate::domain::user::models::UserId;
+
+/// Security-relevant action recorded in the audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuditAction {
+    Login,
+    LoginFailed,
+    PasswordChanged,
+    PasswordReset,
+    EmailChanged,
+    UserDeleted,
+    /// Admin forced a password reset
+    PasswordResetForced,
+    UserLocked,
+    UserUnlocked,
+    UsersImported,
+    EventsReplayed,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Login => "login",
+            AuditAction::LoginFailed => "login_failed",
+            AuditAction::PasswordChanged => "password_changed",
+            AuditAction::PasswordReset => "password_reset",
+            AuditAction::EmailChanged => "email_changed",
+            AuditAction::UserDeleted => "user_deleted",
+            AuditAction::PasswordResetForced => "password_reset_forced",
+            AuditAction::UserLocked => "user_locked",
+            AuditAction::UserUnlocked => "user_unlocked",
+            AuditAction::UsersImported => "users_imported",
+            AuditAction::EventsReplayed => "events_replayed",
+        }
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = AuditError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "login" => Ok(AuditAction::Login),
+            "login_failed" => Ok(AuditAction::LoginFailed),
+            "password_changed" => Ok(AuditAction::PasswordChanged),
+            "password_reset" => Ok(AuditAction::PasswordReset),
+            "email_changed" => Ok(AuditAction::EmailChanged),
+            "user_deleted" => Ok(AuditAction::UserDeleted),
+            "password_reset_forced" => Ok(AuditAction::PasswordResetForced),
+            "user_locked" => Ok(AuditAction::UserLocked),
+            "user_unlocked" => Ok(AuditAction::UserUnlocked),
+            "users_imported" => Ok(AuditAction::UsersImported),
+            "events_replayed" => Ok(AuditAction::EventsReplayed),
+            _ => Err(AuditError::InvalidAction(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Entry of the audit log.
+///
+/// Entries name users by ID only and are kept when the users are deleted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    pub action: AuditAction,
+    /// User who performed the action, None if it was not authenticated
+    pub actor_id: Option<UserId>,
+    /// User the action was performed on
+    pub target_id: Option<UserId>,
+    pub ip_address: Option<IpAddr>,
+    /// What the action changed, cut to [`AuditEntry::MAX_DETAIL_LENGTH`] characters
+    pub detail: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl AuditEntry {
+    /// Longest detail kept, in characters
+    pub const MAX_DETAIL_LENGTH: usize = 1024;
+
+    /// Create an entry for an action happening now.
+    ///
+    /// # Arguments
+    /// * `action` - Action performed
+    ///
+    /// # Returns
+    /// Entry without actor, target, address or detail
+    pub fn new(action: AuditAction) -> Self {
+        Self {
+            action,
+            actor_id: None,
+            target_id: None,
+            ip_address: None,
+            detail: None,
+            occurred_at: Utc::now(),
+        }
+    }
+
+    /// Name the user who performed the action.
+    pub fn by(mut self, actor_id: UserId) -> Self {
+        self.actor_id = Some(actor_id);
+        self
+    }
+
+    /// Name the user the action was performed on.
+    pub fn on(mut self, target_id: UserId) -> Self {
+        self.target_id = Some(target_id);
+        self
+    }
+
+    /// Record the address of the client, if known.
+    pub fn from_ip(mut self, ip_address: Option<IpAddr>) -> Self {
+        self.ip_address = ip_address;
+        self
+    }
+
+    /// Describe what the action changed.
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+/// Filters of an audit log search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditQuery {
+    pub actor_id: Option<UserId>,
+    pub target_id: Option<UserId>,
+    pub action: Option<AuditAction>,
+    /// Only entries strictly older than this time, for paging
+    pub before: Option<DateTime<Utc>>,
+    pub limit: u32,
+}
@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+
+use crate::domain::audit::errors::AuditError;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::audit::models::AuditQuery;
+
+/// Port for recording security-relevant actions and reviewing them.
+#[async_trait]
+pub trait AuditLogServicePort: Send + Sync + 'static {
+    /// Record an action in the audit log.
+    ///
+    /// # Arguments
+    /// * `entry` - Action, users involved and client address
+    ///
+    /// # Returns
+    /// Stored entry
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record(&self, entry: AuditEntry) -> Result<AuditEntry, AuditError>;
+
+    /// Search the audit log.
+    ///
+    /// # Arguments
+    /// * `query` - Filters, paging cursor and most entries returned
+    ///
+    /// # Returns
+    /// Matching entries, newest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn search(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditError>;
+}
+
+/// Append-only store of audit log entries.
+#[async_trait]
+pub trait AuditLogger: Send + Sync + 'static {
+    /// Append an entry to the audit log.
+    ///
+    /// # Arguments
+    /// * `entry` - Entry to store
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn log(&self, entry: &AuditEntry) -> Result<(), AuditError>;
+
+    /// Retrieve the entries matching a query.
+    ///
+    /// # Arguments
+    /// * `query` - Filters, paging cursor and most entries returned
+    ///
+    /// # Returns
+    /// Matching entries, newest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditError>;
+}
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::audit::errors::AuditError;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::audit::models::AuditQuery;
+use crate::domain::audit::ports::AuditLogServicePort;
+use crate::domain::audit::ports::AuditLogger;
+
+/// Domain service implementation for the audit log.
+///
+/// Concrete implementation of AuditLogServicePort with dependency injection.
+pub struct AuditLogService<AL>
+where
+    AL: AuditLogger,
+{
+    logger: Arc<AL>,
+}
+
+impl<AL> AuditLogService<AL>
+where
+    AL: AuditLogger,
+{
+    /// Create a new audit log service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `logger` - Audit log persistence implementation
+    ///
+    /// # Returns
+    /// Configured audit log service instance
+    pub fn new(logger: Arc<AL>) -> Self {
+        Self { logger }
+    }
+}
+
+#[async_trait]
+impl<AL> AuditLogServicePort for AuditLogService<AL>
+where
+    AL: AuditLogger,
+{
+    async fn record(&self, entry: AuditEntry) -> Result<AuditEntry, AuditError> {
+        let entry = AuditEntry {
+            detail: entry
+                .detail
+                .map(|detail| {
+                    detail
+                        .chars()
+                        .take(AuditEntry::MAX_DETAIL_LENGTH)
+                        .collect::<String>()
+                })
+                .filter(|detail| !detail.is_empty()),
+            ..entry
+        };
+        self.logger.log(&entry).await?;
+
+        tracing::info!(
+            action = entry.action.as_str(),
+            actor_id = ?entry.actor_id,
+            target_id = ?entry.target_id,
+            "Audited action"
+        );
+        Ok(entry)
+    }
+
+    async fn search(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditError> {
+        self.logger.find(query).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::Duration;
+
+    use super::*;
+    use crate::domain::audit::models::AuditAction;
+    use crate::domain::user::models::UserId;
+
+    /// Audit log kept in memory, oldest entry first
+    #[derive(Default)]
+    struct InMemoryAuditLogger {
+        entries: Mutex<Vec<AuditEntry>>,
+    }
+
+    #[async_trait]
+    impl AuditLogger for InMemoryAuditLogger {
+        async fn log(&self, entry: &AuditEntry) -> Result<(), AuditError> {
+            self.entries.lock().unwrap().push(entry.clone());
+            Ok(())
+        }
+
+        async fn find(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditError> {
+            let entries = self.entries.lock().unwrap();
+            Ok(entries
+                .iter()
+                .rev()
+                .filter(|entry| query.actor_id.is_none_or(|id| entry.actor_id == Some(id)))
+                .filter(|entry| query.target_id.is_none_or(|id| entry.target_id == Some(id)))
+                .filter(|entry| query.action.is_none_or(|action| entry.action == action))
+                .filter(|entry| query.before.is_none_or(|before| entry.occurred_at < before))
+                .take(query.limit as usize)
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recorded_detail_is_cut() {
+        let service = AuditLogService::new(Arc::new(InMemoryAuditLogger::default()));
+
+        let long = service
+            .record(
+                AuditEntry::new(AuditAction::EmailChanged)
+                    .with_detail("x".repeat(AuditEntry::MAX_DETAIL_LENGTH + 1)),
+            )
+            .await
+            .unwrap();
+        let empty = service
+            .record(AuditEntry::new(AuditAction::EmailChanged).with_detail(""))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            long.detail.as_ref().map(String::len),
+            Some(AuditEntry::MAX_DETAIL_LENGTH)
+        );
+        assert_eq!(empty.detail, None);
+    }
+
+    #[tokio::test]
+    async fn test_search_filters_newest_first() {
+        let service = AuditLogService::new(Arc::new(InMemoryAuditLogger::default()));
+        let (admin, user) = (UserId::new(), UserId::new());
+
+        let locked = service
+            .record(AuditEntry::new(AuditAction::UserLocked).by(admin).on(user))
+            .await
+            .unwrap();
+        service
+            .record(AuditEntry::new(AuditAction::Login).by(admin))
+            .await
+            .unwrap();
+        let unlocked = service
+            .record(
+                AuditEntry::new(AuditAction::UserUnlocked)
+                    .by(admin)
+                    .on(user),
+            )
+            .await
+            .unwrap();
+
+        let on_user = service
+            .search(&AuditQuery {
+                actor_id: None,
+                target_id: Some(user),
+                action: None,
+                before: None,
+                limit: 10,
+            })
+            .await
+            .unwrap();
+        assert_eq!(on_user, vec![unlocked.clone(), locked.clone()]);
+
+        let earlier = service
+            .search(&AuditQuery {
+                actor_id: Some(admin),
+                target_id: None,
+                action: Some(AuditAction::UserLocked),
+                before: Some(unlocked.occurred_at + Duration::seconds(1)),
+                limit: 10,
+            })
+            .await
+            .unwrap();
+        assert_eq!(earlier, vec![locked]);
+    }
+}
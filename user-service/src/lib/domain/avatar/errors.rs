@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+use crate::domain::avatar::models::ImageType;
+use crate::domain::storage::errors::StorageError;
+use crate::domain::user::errors::UserError;
+
+/// Top-level error for avatar uploads
+#[derive(Debug, Clone, Error)]
+pub enum AvatarError {
+    #[error("Avatar image is empty")]
+    Empty,
+
+    #[error("Avatar image too large (max {max} bytes, got {actual})")]
+    TooLarge { max: usize, actual: usize },
+
+    #[error(
+        "Unsupported avatar type '{0}', expected image/png, image/jpeg, image/gif or image/webp"
+    )]
+    UnsupportedType(String),
+
+    #[error("Avatar content is not a valid {} image", .0.content_type())]
+    ContentMismatch(ImageType),
+
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+
+    #[error(transparent)]
+    User(#[from] UserError),
+}
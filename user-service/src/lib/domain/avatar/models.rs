@@ -0,0 +1,88 @@
+/// Image formats accepted as avatars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageType {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+}
+
+impl ImageType {
+    /// Get the image type of a media type.
+    ///
+    /// # Arguments
+    /// * `content_type` - Media type, parameters are ignored
+    ///
+    /// # Returns
+    /// Image type, None for media types other than the accepted images
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime_type = content_type.split(';').next().unwrap_or_default().trim();
+        match mime_type.to_ascii_lowercase().as_str() {
+            "image/png" => Some(ImageType::Png),
+            "image/jpeg" | "image/jpg" => Some(ImageType::Jpeg),
+            "image/gif" => Some(ImageType::Gif),
+            "image/webp" => Some(ImageType::Webp),
+            _ => None,
+        }
+    }
+
+    /// Check if `bytes` start with the file signature of this image type.
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        match self {
+            ImageType::Png => bytes.starts_with(b"\x89PNG\r\n\x1a\n"),
+            ImageType::Jpeg => bytes.starts_with(&[0xff, 0xd8, 0xff]),
+            ImageType::Gif => bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a"),
+            ImageType::Webp => {
+                bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP"
+            }
+        }
+    }
+
+    /// Media type the image is served with.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ImageType::Png => "image/png",
+            ImageType::Jpeg => "image/jpeg",
+            ImageType::Gif => "image/gif",
+            ImageType::Webp => "image/webp",
+        }
+    }
+
+    /// File extension of stored images.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageType::Png => "png",
+            ImageType::Jpeg => "jpg",
+            ImageType::Gif => "gif",
+            ImageType::Webp => "webp",
+        }
+    }
+}
+
+/// Limits of uploaded avatars.
+#[derive(Debug, Clone)]
+pub struct AvatarSettings {
+    /// Largest accepted image, in bytes
+    pub max_bytes: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_type_checks_signature() {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        let webp = b"RIFF\x24\0\0\0WEBPVP8 ";
+
+        assert_eq!(
+            ImageType::from_content_type("image/PNG; charset=binary"),
+            Some(ImageType::Png)
+        );
+        assert!(ImageType::Png.matches(png));
+        assert!(!ImageType::Jpeg.matches(png));
+        assert!(ImageType::Webp.matches(webp));
+        assert!(!ImageType::Webp.matches(b"RIFF"));
+        assert_eq!(ImageType::from_content_type("image/svg+xml"), None);
+    }
+}
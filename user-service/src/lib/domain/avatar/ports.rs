@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::domain::avatar::errors::AvatarError;
+use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
+
+/// Port for uploading users' avatar images.
+#[async_trait]
+pub trait AvatarServicePort: Send + Sync + 'static {
+    /// Store an avatar image and set it as the user's avatar.
+    ///
+    /// # Arguments
+    /// * `user_id` - User whose avatar to replace
+    /// * `content_type` - Declared media type of the image
+    /// * `bytes` - Content of the image
+    ///
+    /// # Returns
+    /// The user with the URL of the stored image as `avatar_url`
+    ///
+    /// # Errors
+    /// * `Empty` - Image has no content
+    /// * `TooLarge` - Image exceeds the configured size
+    /// * `UnsupportedType` - Media type is not an accepted image type
+    /// * `ContentMismatch` - Content is not an image of the declared type
+    /// * `Storage` - Image could not be stored
+    /// * `User` - User does not exist or could not be updated
+    async fn upload_avatar(
+        &self,
+        user_id: &UserId,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<User, AvatarError>;
+}
@@ -0,0 +1,297 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::domain::avatar::errors::AvatarError;
+use crate::domain::avatar::models::AvatarSettings;
+use crate::domain::avatar::models::ImageType;
+use crate::domain::avatar::ports::AvatarServicePort;
+use crate::domain::storage::ports::ObjectStorage;
+use crate::domain::user::errors::UserError;
+use crate::domain::user::models::AvatarUrl;
+use crate::domain::user::models::UpdateUserCommand;
+use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserServicePort;
+
+/// Domain service implementation for avatar uploads.
+///
+/// Concrete implementation of AvatarServicePort with dependency injection.
+pub struct AvatarService<US, OS>
+where
+    US: UserServicePort,
+    OS: ObjectStorage,
+{
+    user_service: Arc<US>,
+    storage: Arc<OS>,
+    settings: AvatarSettings,
+}
+
+impl<US, OS> AvatarService<US, OS>
+where
+    US: UserServicePort,
+    OS: ObjectStorage,
+{
+    /// Create a new avatar service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `user_service` - Service looking up and updating users
+    /// * `storage` - Storage serving the images
+    /// * `settings` - Size limit of images
+    ///
+    /// # Returns
+    /// Configured avatar service instance
+    pub fn new(user_service: Arc<US>, storage: Arc<OS>, settings: AvatarSettings) -> Self {
+        Self {
+            user_service,
+            storage,
+            settings,
+        }
+    }
+
+    fn validate(&self, content_type: &str, bytes: &[u8]) -> Result<ImageType, AvatarError> {
+        let image_type = ImageType::from_content_type(content_type)
+            .ok_or_else(|| AvatarError::UnsupportedType(content_type.to_string()))?;
+        if bytes.is_empty() {
+            return Err(AvatarError::Empty);
+        }
+        if bytes.len() > self.settings.max_bytes {
+            return Err(AvatarError::TooLarge {
+                max: self.settings.max_bytes,
+                actual: bytes.len(),
+            });
+        }
+        // Browsers sniff content, so a mislabelled file could be served as something else
+        if !image_type.matches(bytes) {
+            return Err(AvatarError::ContentMismatch(image_type));
+        }
+        Ok(image_type)
+    }
+}
+
+#[async_trait]
+impl<US, OS> AvatarServicePort for AvatarService<US, OS>
+where
+    US: UserServicePort,
+    OS: ObjectStorage,
+{
+    async fn upload_avatar(
+        &self,
+        user_id: &UserId,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<User, AvatarError> {
+        let image_type = self.validate(content_type, &bytes)?;
+        let user = self.user_service.get_user(user_id).await?;
+
+        // Named after the content, so a new avatar never hits a cached old one
+        let digest = Sha256::digest(&bytes)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        let key = format!("avatars/{}/{}.{}", user.id, digest, image_type.extension());
+        let url = self
+            .storage
+            .put_object(&key, image_type.content_type(), bytes)
+            .await?;
+        let avatar_url = AvatarUrl::new(&url).map_err(UserError::from)?;
+
+        let user = self
+            .user_service
+            .update_user(
+                &user.id,
+                UpdateUserCommand {
+                    username: None,
+                    email: None,
+                    password: None,
+                    display_name: None,
+                    avatar_url: Some(Some(avatar_url)),
+                    bio: None,
+                },
+            )
+            .await?;
+
+        tracing::info!(user_id = %user.id, key = %key, "Avatar uploaded");
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use auth::SecretString;
+    use chrono::Utc;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::user::models::CreateUserCommand;
+    use crate::domain::user::models::EmailAddress;
+    use crate::domain::user::models::ImportUserCommand;
+    use crate::domain::user::models::LoginIdentifier;
+    use crate::domain::user::models::UserCursor;
+    use crate::domain::user::models::UserPage;
+    use crate::domain::user::models::UserSearchQuery;
+    use crate::domain::user::models::UserStatus;
+    use crate::domain::user::models::Username;
+
+    mock! {
+        pub TestUserService {}
+
+        #[async_trait]
+        impl UserServicePort for TestUserService {
+            async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
+            async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+            async fn get_user(&self, id: &UserId) -> Result<User, UserError>;
+            async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
+            async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+            async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
+            async fn list_users(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<UserPage, UserError>;
+            async fn search_users(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
+            async fn update_user(&self, id: &UserId, command: UpdateUserCommand) -> Result<User, UserError>;
+            async fn change_password(&self, id: &UserId, current_password: SecretString, new_password: SecretString) -> Result<(), UserError>;
+            async fn reset_password(&self, id: &UserId, new_password: SecretString) -> Result<(), UserError>;
+            async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+            async fn replace_password_hash(&self, id: &UserId, password_hash: String) -> Result<(), UserError>;
+            async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    /// Storage keeping the keys and media types of stored objects
+    #[derive(Default)]
+    struct RecordingStorage {
+        stored: Mutex<Vec<(String, String)>>,
+    }
+
+    #[async_trait]
+    impl ObjectStorage for RecordingStorage {
+        async fn put_object(
+            &self,
+            key: &str,
+            content_type: &str,
+            _bytes: Vec<u8>,
+        ) -> Result<String, StorageError> {
+            self.stored
+                .lock()
+                .unwrap()
+                .push((key.to_string(), content_type.to_string()));
+            Ok(format!("https://cdn.example.com/{}", key))
+        }
+    }
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    fn alice() -> User {
+        User {
+            id: UserId::new(),
+            username: Username::new("alice".to_string()).unwrap(),
+            email: EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            password_hash: "$argon2id$hash".to_string(),
+            email_verified: true,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn service(
+        user_service: MockTestUserService,
+        storage: Arc<RecordingStorage>,
+    ) -> AvatarService<MockTestUserService, RecordingStorage> {
+        AvatarService::new(
+            Arc::new(user_service),
+            storage,
+            AvatarSettings { max_bytes: 64 },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_upload_avatar_stores_image_and_sets_url() {
+        let user = alice();
+        let user_id = user.id;
+        let mut user_service = MockTestUserService::new();
+        let returned = user.clone();
+        user_service
+            .expect_get_user()
+            .times(1)
+            .returning(move |_| Ok(returned.clone()));
+        user_service
+            .expect_update_user()
+            .withf(move |id, command| {
+                *id == user_id
+                    && command.username.is_none()
+                    && matches!(&command.avatar_url, Some(Some(url))
+                        if url.as_str().starts_with("https://cdn.example.com/avatars/"))
+            })
+            .times(1)
+            .returning(move |_, command| {
+                Ok(User {
+                    avatar_url: command.avatar_url.flatten(),
+                    ..user.clone()
+                })
+            });
+
+        let storage = Arc::new(RecordingStorage::default());
+        let service = service(user_service, Arc::clone(&storage));
+
+        let updated = service
+            .upload_avatar(&user_id, "image/png", PNG.to_vec())
+            .await
+            .unwrap();
+
+        let stored = storage.stored.lock().unwrap();
+        assert_eq!(stored.len(), 1);
+        assert!(stored[0].0.starts_with(&format!("avatars/{}/", user_id)));
+        assert!(stored[0].0.ends_with(".png"));
+        assert_eq!(stored[0].1, "image/png");
+        assert_eq!(
+            updated.avatar_url.unwrap().as_str(),
+            format!("https://cdn.example.com/{}", stored[0].0)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_avatar_rejects_invalid_images() {
+        let mut user_service = MockTestUserService::new();
+        user_service.expect_get_user().times(0);
+        user_service.expect_update_user().times(0);
+        let storage = Arc::new(RecordingStorage::default());
+        let service = service(user_service, Arc::clone(&storage));
+        let user_id = UserId::new();
+
+        let result = service
+            .upload_avatar(&user_id, "image/svg+xml", b"<svg/>".to_vec())
+            .await;
+        assert!(matches!(result, Err(AvatarError::UnsupportedType(_))));
+
+        let result = service
+            .upload_avatar(&user_id, "image/jpeg", PNG.to_vec())
+            .await;
+        assert!(matches!(
+            result,
+            Err(AvatarError::ContentMismatch(ImageType::Jpeg))
+        ));
+
+        let result = service
+            .upload_avatar(&user_id, "image/png", [PNG, &[0; 64]].concat())
+            .await;
+        assert!(matches!(result, Err(AvatarError::TooLarge { max: 64, .. })));
+
+        let result = service
+            .upload_avatar(&user_id, "image/png", Vec::new())
+            .await;
+        assert!(matches!(result, Err(AvatarError::Empty)));
+
+        assert!(storage.stored.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+/// Error delivering an email
+#[derive(Debug, Clone, Error)]
+pub enum EmailError {
+    #[error("Email could not be delivered: {0}")]
+    DeliveryFailed(String),
+}
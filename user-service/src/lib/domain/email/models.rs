@@ -0,0 +1,9 @@
+use crate::domain::user::models::EmailAddress;
+
+/// Plain-text email to deliver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub to: EmailAddress,
+    pub subject: String,
+    pub text: String,
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::email::errors::EmailError;
+use crate::domain::email::models::EmailMessage;
+
+/// Port delivering emails, so the mail transport can be swapped.
+#[async_trait]
+pub trait EmailSender: Send + Sync + 'static {
+    /// Send an email.
+    ///
+    /// # Arguments
+    /// * `message` - Recipient, subject and body
+    ///
+    /// # Errors
+    /// * `DeliveryFailed` - Email could not be sent
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailError>;
+}
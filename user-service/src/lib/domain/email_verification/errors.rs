@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+use crate::domain::email::errors::EmailError;
+use crate::domain::user::errors::UserError;
+
+/// Top-level error for email verification operations
+#[derive(Debug, Clone, Error)]
+pub enum EmailVerificationError {
+    #[error("Verification link is invalid or expired")]
+    InvalidLink,
+
+    #[error("Verification link was already used")]
+    AlreadyUsed,
+
+    #[error("Email address is already verified")]
+    AlreadyVerified,
+
+    #[error("Token error: {0}")]
+    TokenError(String),
+
+    #[error(transparent)]
+    Email(#[from] EmailError),
+
+    #[error(transparent)]
+    User(#[from] UserError),
+}
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::email_verification::errors::EmailVerificationError;
+use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
+
+/// Port for confirming that users own their email address.
+#[async_trait]
+pub trait EmailVerificationServicePort: Send + Sync + 'static {
+    /// Email a single-use verification link to the user's address.
+    ///
+    /// # Arguments
+    /// * `user_id` - User whose address to verify
+    ///
+    /// # Returns
+    /// Expiration of the link
+    ///
+    /// # Errors
+    /// * `AlreadyVerified` - Address is already verified
+    /// * `User` - User does not exist
+    /// * `TokenError` - Link token could not be issued
+    /// * `Email` - Email could not be sent
+    async fn request_verification(
+        &self,
+        user_id: &UserId,
+    ) -> Result<DateTime<Utc>, EmailVerificationError>;
+
+    /// Mark the address a verification link was sent to as verified.
+    ///
+    /// # Arguments
+    /// * `token` - Token of the verification link
+    ///
+    /// # Returns
+    /// The verified user
+    ///
+    /// # Errors
+    /// * `InvalidLink` - Token is invalid, expired, its user no longer exists
+    ///   or changed address since the link was sent
+    /// * `AlreadyUsed` - Link was already redeemed
+    /// * `TokenError` - Link could not be redeemed
+    /// * `User` - User could not be updated
+    async fn verify_email(&self, token: &str) -> Result<User, EmailVerificationError>;
+}
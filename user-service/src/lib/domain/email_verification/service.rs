@@ -0,0 +1,357 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use auth::Authenticator;
+use auth::Claims;
+use auth::OneTimeToken;
+use auth::OneTimeTokenError;
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::email::models::EmailMessage;
+use crate::domain::email::ports::EmailSender;
+use crate::domain::email_verification::errors::EmailVerificationError;
+use crate::domain::email_verification::models::EmailVerificationSettings;
+use crate::domain::email_verification::ports::EmailVerificationServicePort;
+use crate::domain::user::errors::UserError;
+use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserServicePort;
+
+/// Domain service implementation for email verification.
+///
+/// Concrete implementation of EmailVerificationServicePort with dependency injection.
+pub struct EmailVerificationService<US, ES>
+where
+    US: UserServicePort,
+    ES: EmailSender,
+{
+    user_service: Arc<US>,
+    sender: Arc<ES>,
+    authenticator: Arc<Authenticator>,
+    settings: EmailVerificationSettings,
+}
+
+impl<US, ES> EmailVerificationService<US, ES>
+where
+    US: UserServicePort,
+    ES: EmailSender,
+{
+    /// Create a new email verification service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `user_service` - Service looking up and updating users
+    /// * `sender` - Mail transport
+    /// * `authenticator` - Issuer of link tokens
+    /// * `settings` - Link URL and expiration
+    ///
+    /// # Returns
+    /// Configured email verification service instance
+    pub fn new(
+        user_service: Arc<US>,
+        sender: Arc<ES>,
+        authenticator: Arc<Authenticator>,
+        settings: EmailVerificationSettings,
+    ) -> Self {
+        Self {
+            user_service,
+            sender,
+            authenticator,
+            settings,
+        }
+    }
+
+    fn link_url(&self, token: &str) -> String {
+        let separator = if self.settings.link_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        format!("{}{}token={}", self.settings.link_url, separator, token)
+    }
+}
+
+#[async_trait]
+impl<US, ES> EmailVerificationServicePort for EmailVerificationService<US, ES>
+where
+    US: UserServicePort,
+    ES: EmailSender,
+{
+    async fn request_verification(
+        &self,
+        user_id: &UserId,
+    ) -> Result<DateTime<Utc>, EmailVerificationError> {
+        let user = self.user_service.get_user(user_id).await?;
+        if user.email_verified {
+            return Err(EmailVerificationError::AlreadyVerified);
+        }
+
+        // Bound to the address, so a link stops working once the address changes
+        let token = self
+            .authenticator
+            .issue_bound_one_time_token(
+                &user.id.to_string(),
+                OneTimeToken::EMAIL_VERIFICATION,
+                self.settings.ttl,
+                user.email.as_str(),
+            )
+            .map_err(|e| EmailVerificationError::TokenError(e.to_string()))?;
+        let expires_at = Utc::now() + self.settings.ttl;
+
+        let message = EmailMessage {
+            to: user.email.clone(),
+            subject: "Confirm your email address".to_string(),
+            text: format!(
+                "Hi {},\n\nOpen this link to confirm your email address: {}\n\n\
+                 It expires at {}. If you did not ask for this, you can ignore this email.",
+                user.username.as_str(),
+                self.link_url(&token),
+                expires_at.to_rfc2822()
+            ),
+        };
+        self.sender.send(&message).await?;
+
+        tracing::info!(user_id = %user.id, "Email verification link sent");
+        Ok(expires_at)
+    }
+
+    async fn verify_email(&self, token: &str) -> Result<User, EmailVerificationError> {
+        // The subject is read before redeeming to find the address the token is bound to
+        let claims: Claims = self
+            .authenticator
+            .validate_token(token)
+            .map_err(|_| EmailVerificationError::InvalidLink)?;
+        let user_id = claims
+            .sub
+            .as_deref()
+            .and_then(|subject| UserId::from_string(subject).ok())
+            .ok_or(EmailVerificationError::InvalidLink)?;
+        let user = self
+            .user_service
+            .get_user(&user_id)
+            .await
+            .map_err(|e| match e {
+                UserError::NotFound(_) => EmailVerificationError::InvalidLink,
+                e => e.into(),
+            })?;
+
+        self.authenticator
+            .redeem_bound_one_time_token(
+                token,
+                OneTimeToken::EMAIL_VERIFICATION,
+                user.email.as_str(),
+            )
+            .await
+            .map_err(|e| match e {
+                OneTimeTokenError::AlreadyUsed => EmailVerificationError::AlreadyUsed,
+                OneTimeTokenError::StoreError(e) => EmailVerificationError::TokenError(e),
+                _ => EmailVerificationError::InvalidLink,
+            })?;
+
+        if user.email_verified {
+            return Ok(user);
+        }
+        let user = self.user_service.mark_email_verified(&user.id).await?;
+
+        tracing::info!(user_id = %user.id, "Email address verified");
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use auth::SecretString;
+    use chrono::Duration;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::email::errors::EmailError;
+    use crate::domain::user::models::CreateUserCommand;
+    use crate::domain::user::models::EmailAddress;
+    use crate::domain::user::models::ImportUserCommand;
+    use crate::domain::user::models::LoginIdentifier;
+    use crate::domain::user::models::UpdateUserCommand;
+    use crate::domain::user::models::UserCursor;
+    use crate::domain::user::models::UserPage;
+    use crate::domain::user::models::UserSearchQuery;
+    use crate::domain::user::models::UserStatus;
+    use crate::domain::user::models::Username;
+
+    mock! {
+        pub TestUserService {}
+
+        #[async_trait]
+        impl UserServicePort for TestUserService {
+            async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
+            async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+            async fn get_user(&self, id: &UserId) -> Result<User, UserError>;
+            async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
+            async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+            async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
+            async fn list_users(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<UserPage, UserError>;
+            async fn search_users(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
+            async fn update_user(&self, id: &UserId, command: UpdateUserCommand) -> Result<User, UserError>;
+            async fn change_password(&self, id: &UserId, current_password: SecretString, new_password: SecretString) -> Result<(), UserError>;
+            async fn reset_password(&self, id: &UserId, new_password: SecretString) -> Result<(), UserError>;
+            async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+            async fn replace_password_hash(&self, id: &UserId, password_hash: String) -> Result<(), UserError>;
+            async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    /// Sender keeping the sent emails
+    #[derive(Default)]
+    struct RecordingSender {
+        sent: Mutex<Vec<EmailMessage>>,
+    }
+
+    #[async_trait]
+    impl EmailSender for RecordingSender {
+        async fn send(&self, message: &EmailMessage) -> Result<(), EmailError> {
+            self.sent.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    fn alice(email: &str) -> User {
+        User {
+            id: UserId::new(),
+            username: Username::new("alice".to_string()).unwrap(),
+            email: EmailAddress::new(email.to_string()).unwrap(),
+            password_hash: "$argon2id$hash".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn service(
+        user_service: MockTestUserService,
+        sender: Arc<RecordingSender>,
+    ) -> EmailVerificationService<MockTestUserService, RecordingSender> {
+        EmailVerificationService::new(
+            Arc::new(user_service),
+            sender,
+            Arc::new(Authenticator::new(b"test_secret_key_at_least_32_bytes!")),
+            EmailVerificationSettings {
+                link_url: "http://localhost/api/auth/verify".to_string(),
+                ttl: Duration::hours(24),
+            },
+        )
+    }
+
+    fn sent_token(sender: &RecordingSender) -> String {
+        let sent = sender.sent.lock().unwrap();
+        let text = &sent.last().expect("No email sent").text;
+        let start = text.find("token=").expect("No token in email") + "token=".len();
+        text[start..].split_whitespace().next().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_verify_email_with_sent_link() {
+        let user = alice("alice@example.com");
+        let user_id = user.id;
+        let mut user_service = MockTestUserService::new();
+        let returned = user.clone();
+        user_service
+            .expect_get_user()
+            .times(3)
+            .returning(move |_| Ok(returned.clone()));
+        let verified = User {
+            email_verified: true,
+            ..user.clone()
+        };
+        user_service
+            .expect_mark_email_verified()
+            .withf(move |id| *id == user_id)
+            .times(1)
+            .returning(move |_| Ok(verified.clone()));
+
+        let sender = Arc::new(RecordingSender::default());
+        let service = service(user_service, Arc::clone(&sender));
+
+        service.request_verification(&user_id).await.unwrap();
+        assert_eq!(
+            sender.sent.lock().unwrap()[0].to.as_str(),
+            "alice@example.com"
+        );
+
+        let token = sent_token(&sender);
+        let verified = service.verify_email(&token).await.unwrap();
+        assert!(verified.email_verified);
+
+        let result = service.verify_email(&token).await;
+        assert!(matches!(result, Err(EmailVerificationError::AlreadyUsed)));
+    }
+
+    #[tokio::test]
+    async fn test_link_stops_working_after_email_change() {
+        let user = alice("alice@example.com");
+        let user_id = user.id;
+        let changed = User {
+            email: EmailAddress::new("alice@example.org".to_string()).unwrap(),
+            ..user.clone()
+        };
+        let mut user_service = MockTestUserService::new();
+        let mut calls = 0;
+        user_service.expect_get_user().times(2).returning(move |_| {
+            calls += 1;
+            Ok(if calls == 1 {
+                user.clone()
+            } else {
+                changed.clone()
+            })
+        });
+        user_service.expect_mark_email_verified().times(0);
+
+        let sender = Arc::new(RecordingSender::default());
+        let service = service(user_service, Arc::clone(&sender));
+
+        service.request_verification(&user_id).await.unwrap();
+        let result = service.verify_email(&sent_token(&sender)).await;
+        assert!(matches!(result, Err(EmailVerificationError::InvalidLink)));
+    }
+
+    #[tokio::test]
+    async fn test_request_verification_already_verified() {
+        let user = User {
+            email_verified: true,
+            ..alice("alice@example.com")
+        };
+        let user_id = user.id;
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user()
+            .returning(move |_| Ok(user.clone()));
+
+        let sender = Arc::new(RecordingSender::default());
+        let service = service(user_service, Arc::clone(&sender));
+
+        let result = service.request_verification(&user_id).await;
+        assert!(matches!(
+            result,
+            Err(EmailVerificationError::AlreadyVerified)
+        ));
+        assert!(sender.sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_verify_email_rejects_other_tokens() {
+        let user_service = MockTestUserService::new();
+        let sender = Arc::new(RecordingSender::default());
+        let service = service(user_service, sender);
+
+        let result = service.verify_email("not-a-token").await;
+        assert!(matches!(result, Err(EmailVerificationError::InvalidLink)));
+    }
+}
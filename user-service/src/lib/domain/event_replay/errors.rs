@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use crate::domain::user::errors::EventPublisherError;
+use crate::domain::user::errors::UserError;
+use crate::domain::user::models::UserCursor;
+
+/// Top-level error for event replays
+#[derive(Debug, Error)]
+pub enum EventReplayError {
+    #[error("Replay range is empty: since is after until")]
+    EmptyRange,
+
+    #[error("Failed to list users: {0}")]
+    User(#[from] UserError),
+
+    /// Users before the failed one were republished, the replay resumes after them
+    #[error("Failed to republish user {user_id}: {source}")]
+    PublishFailed {
+        user_id: String,
+        resume_after: Option<UserCursor>,
+        source: EventPublisherError,
+    },
+}
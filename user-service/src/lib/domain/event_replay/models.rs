@@ -0,0 +1,31 @@
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::user::models::UserCursor;
+use crate::domain::user::models::UserStatus;
+
+/// Page of users whose `UserCreated` events are published again.
+///
+/// Users are replayed newest first, like the admin user listing.
+#[derive(Debug, Clone)]
+pub struct ReplayRequest {
+    /// Status of the replayed users
+    pub status: UserStatus,
+    /// Oldest creation time replayed, inclusive
+    pub since: Option<DateTime<Utc>>,
+    /// Newest creation time replayed, inclusive
+    pub until: Option<DateTime<Utc>>,
+    /// Maximum number of users replayed
+    pub limit: u32,
+    /// Cursor returned by the previous page, None for the first page
+    pub after: Option<UserCursor>,
+}
+
+/// Outcome of a replayed page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayPage {
+    /// Number of events published
+    pub replayed: usize,
+    /// Cursor of the following page, None once the range is replayed
+    pub next_cursor: Option<UserCursor>,
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+
+use super::errors::EventReplayError;
+use super::models::ReplayPage;
+use super::models::ReplayRequest;
+
+/// Port for republishing user events to the event topic.
+///
+/// Lets a new consumer, such as the user replica of chat-service, bootstrap
+/// its state from scratch.
+#[async_trait]
+pub trait EventReplayServicePort: Send + Sync + 'static {
+    /// Publish the `UserCreated` events of a page of users again.
+    ///
+    /// Replayed events carry new event IDs and the current username and
+    /// email address of each user.
+    ///
+    /// # Arguments
+    /// * `request` - Status, creation time range, page size and cursor
+    ///
+    /// # Returns
+    /// Number of events published and the cursor of the next page
+    ///
+    /// # Errors
+    /// * `EmptyRange` - `since` is after `until`
+    /// * `User` - Users could not be listed
+    /// * `PublishFailed` - An event could not be published
+    async fn replay_user_created(
+        &self,
+        request: ReplayRequest,
+    ) -> Result<ReplayPage, EventReplayError>;
+}
@@ -0,0 +1,340 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::event_replay::errors::EventReplayError;
+use crate::domain::event_replay::models::ReplayPage;
+use crate::domain::event_replay::models::ReplayRequest;
+use crate::domain::event_replay::ports::EventReplayServicePort;
+use crate::domain::user::events::UserCreatedEvent;
+use crate::domain::user::models::UserCursor;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::EventPublisher;
+use crate::domain::user::ports::UserServicePort;
+
+/// Domain service implementation for event replays.
+///
+/// Events are published straight to the broker rather than through the
+/// outbox: a replay describes no change, and a failed page is simply
+/// requested again from the cursor reported by the error.
+pub struct EventReplayService<US, EP>
+where
+    US: UserServicePort,
+    EP: EventPublisher,
+{
+    user_service: Arc<US>,
+    event_publisher: Arc<EP>,
+}
+
+impl<US, EP> EventReplayService<US, EP>
+where
+    US: UserServicePort,
+    EP: EventPublisher,
+{
+    /// Create a new event replay service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `user_service` - Service listing the replayed users
+    /// * `event_publisher` - Publisher of the replayed events
+    ///
+    /// # Returns
+    /// Configured event replay service instance
+    pub fn new(user_service: Arc<US>, event_publisher: Arc<EP>) -> Self {
+        Self {
+            user_service,
+            event_publisher,
+        }
+    }
+}
+
+#[async_trait]
+impl<US, EP> EventReplayServicePort for EventReplayService<US, EP>
+where
+    US: UserServicePort,
+    EP: EventPublisher,
+{
+    async fn replay_user_created(
+        &self,
+        request: ReplayRequest,
+    ) -> Result<ReplayPage, EventReplayError> {
+        if let (Some(since), Some(until)) = (request.since, request.until) {
+            if since > until {
+                return Err(EventReplayError::EmptyRange);
+            }
+        }
+
+        // Users are listed newest first, so the range starts at `until`; the
+        // largest ID keeps users created at that very instant
+        let after = request.after.or_else(|| {
+            request.until.map(|until| UserCursor {
+                created_at: until,
+                id: UserId(Uuid::max()),
+            })
+        });
+
+        let page = self
+            .user_service
+            .list_users(request.status, request.limit, after)
+            .await?;
+
+        let mut replayed = 0;
+        let mut resume_after = after;
+        for user in &page.users {
+            if request.since.is_some_and(|since| user.created_at < since) {
+                return Ok(ReplayPage {
+                    replayed,
+                    next_cursor: None,
+                });
+            }
+
+            self.event_publisher
+                .publish_user_created(&UserCreatedEvent::new(user))
+                .await
+                .map_err(|source| EventReplayError::PublishFailed {
+                    user_id: user.id.to_string(),
+                    resume_after,
+                    source,
+                })?;
+            replayed += 1;
+            resume_after = Some(UserCursor::after(user));
+        }
+
+        tracing::info!(
+            replayed,
+            status = request.status.as_str(),
+            "Replayed UserCreated events"
+        );
+
+        Ok(ReplayPage {
+            replayed,
+            next_cursor: page.next_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use auth::SecretString;
+    use chrono::DateTime;
+    use chrono::Duration;
+    use chrono::Utc;
+    use mockall::mock;
+    use mockall::predicate::*;
+
+    use super::*;
+    use crate::domain::user::errors::EventPublisherError;
+    use crate::domain::user::errors::UserError;
+    use crate::domain::user::events::UserAccountLockedEvent;
+    use crate::domain::user::events::UserDeactivatedEvent;
+    use crate::domain::user::events::UserDeletedEvent;
+    use crate::domain::user::events::UserPasswordChangedEvent;
+    use crate::domain::user::events::UserPasswordResetEvent;
+    use crate::domain::user::events::UserPasswordResetRequestedEvent;
+    use crate::domain::user::events::UserReactivatedEvent;
+    use crate::domain::user::events::UserUpdatedEvent;
+    use crate::domain::user::models::CreateUserCommand;
+    use crate::domain::user::models::EmailAddress;
+    use crate::domain::user::models::ImportUserCommand;
+    use crate::domain::user::models::LoginIdentifier;
+    use crate::domain::user::models::UpdateUserCommand;
+    use crate::domain::user::models::User;
+    use crate::domain::user::models::UserPage;
+    use crate::domain::user::models::UserSearchQuery;
+    use crate::domain::user::models::UserStatus;
+    use crate::domain::user::models::Username;
+
+    mock! {
+        pub TestUserService {}
+
+        #[async_trait]
+        impl UserServicePort for TestUserService {
+            async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
+            async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+            async fn get_user(&self, id: &UserId) -> Result<User, UserError>;
+            async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
+            async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+            async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
+            async fn list_users(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<UserPage, UserError>;
+            async fn search_users(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
+            async fn update_user(&self, id: &UserId, command: UpdateUserCommand) -> Result<User, UserError>;
+            async fn change_password(&self, id: &UserId, current_password: SecretString, new_password: SecretString) -> Result<(), UserError>;
+            async fn reset_password(&self, id: &UserId, new_password: SecretString) -> Result<(), UserError>;
+            async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+            async fn replace_password_hash(&self, id: &UserId, password_hash: String) -> Result<(), UserError>;
+            async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    mock! {
+        pub TestEventPublisher {}
+
+        #[async_trait]
+        impl EventPublisher for TestEventPublisher {
+            async fn publish_user_created(&self, event: &UserCreatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_updated(&self, event: &UserUpdatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_deleted(&self, event: &UserDeletedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_deactivated(&self, event: &UserDeactivatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_reactivated(&self, event: &UserReactivatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_password_changed(&self, event: &UserPasswordChangedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_password_reset_requested(&self, event: &UserPasswordResetRequestedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_password_reset(&self, event: &UserPasswordResetEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_account_locked(&self, event: &UserAccountLockedEvent) -> Result<(), EventPublisherError>;
+        }
+    }
+
+    fn user(username: &str, created_at: DateTime<Utc>) -> User {
+        User {
+            id: UserId::new(),
+            username: Username::new(username.to_string()).unwrap(),
+            email: EmailAddress::new(format!("{}@example.com", username)).unwrap(),
+            password_hash: "$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at,
+        }
+    }
+
+    fn request(since: Option<DateTime<Utc>>, until: Option<DateTime<Utc>>) -> ReplayRequest {
+        ReplayRequest {
+            status: UserStatus::Active,
+            since,
+            until,
+            limit: 2,
+            after: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_returns_cursor_of_next_page() {
+        let now = Utc::now();
+        let users = vec![user("alice", now), user("bob", now - Duration::minutes(1))];
+        let next_cursor = UserCursor::after(&users[1]);
+
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_list_users()
+            .with(eq(UserStatus::Active), eq(2), eq(None))
+            .times(1)
+            .returning(move |_, _, _| {
+                Ok(UserPage {
+                    users: users.clone(),
+                    next_cursor: Some(next_cursor),
+                })
+            });
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_created()
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let service = EventReplayService::new(Arc::new(user_service), Arc::new(event_publisher));
+
+        let page = service
+            .replay_user_created(request(None, None))
+            .await
+            .unwrap();
+
+        assert_eq!(page.replayed, 2);
+        assert_eq!(page.next_cursor, Some(next_cursor));
+    }
+
+    #[tokio::test]
+    async fn test_replay_stops_at_start_of_range() {
+        let now = Utc::now();
+        let until = now - Duration::minutes(1);
+        let since = now - Duration::minutes(10);
+        let users = vec![
+            user("alice", until),
+            user("bob", since - Duration::seconds(1)),
+        ];
+
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_list_users()
+            .withf(move |_, _, after| after.is_some_and(|cursor| cursor.created_at == until))
+            .times(1)
+            .returning(move |_, _, _| {
+                Ok(UserPage {
+                    users: users.clone(),
+                    next_cursor: Some(UserCursor::after(&users[1])),
+                })
+            });
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_created()
+            .withf(|event| event.username == "alice")
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = EventReplayService::new(Arc::new(user_service), Arc::new(event_publisher));
+
+        let page = service
+            .replay_user_created(request(Some(since), Some(until)))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            page,
+            ReplayPage {
+                replayed: 1,
+                next_cursor: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_publish_reports_resume_cursor() {
+        let now = Utc::now();
+        let users = vec![user("alice", now), user("bob", now - Duration::minutes(1))];
+        let resume_cursor = UserCursor::after(&users[0]);
+
+        let mut user_service = MockTestUserService::new();
+        user_service.expect_list_users().returning(move |_, _, _| {
+            Ok(UserPage {
+                users: users.clone(),
+                next_cursor: None,
+            })
+        });
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_created()
+            .returning(|event| match event.username.as_str() {
+                "alice" => Ok(()),
+                _ => Err(EventPublisherError::Timeout("broker".to_string())),
+            });
+
+        let service = EventReplayService::new(Arc::new(user_service), Arc::new(event_publisher));
+
+        let result = service.replay_user_created(request(None, None)).await;
+
+        assert!(matches!(
+            result,
+            Err(EventReplayError::PublishFailed { resume_after, .. })
+                if resume_after == Some(resume_cursor)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_inverted_range_is_rejected() {
+        let now = Utc::now();
+        let service = EventReplayService::new(
+            Arc::new(MockTestUserService::new()),
+            Arc::new(MockTestEventPublisher::new()),
+        );
+
+        let result = service
+            .replay_user_created(request(Some(now), Some(now - Duration::minutes(1))))
+            .await;
+
+        assert!(matches!(result, Err(EventReplayError::EmptyRange)));
+    }
+}
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+/// Error type for IdempotencyKey validation failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum IdempotencyKeyError {
+    #[error("Idempotency key is empty")]
+    Empty,
+
+    #[error("Idempotency key too long: maximum {max} characters, got {actual}")]
+    TooLong { max: usize, actual: usize },
+
+    #[error("Idempotency key may only contain printable ASCII characters")]
+    InvalidCharacters,
+}
+
+/// Top-level error for idempotency record operations
+#[derive(Debug, Clone, Error)]
+pub enum IdempotencyError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
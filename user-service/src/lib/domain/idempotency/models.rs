@@ -0,0 +1,87 @@
+use chrono::DateTime;
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::errors::IdempotencyKeyError;
+
+/// Key a client sends with a request it may retry, e.g. a UUID.
+///
+/// Requests sent again under the same key get the outcome of the first one
+/// instead of being applied twice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    const MAX_LENGTH: usize = 255;
+
+    /// Create a new valid idempotency key.
+    ///
+    /// # Arguments
+    /// * `key` - Raw key from the `Idempotency-Key` header
+    ///
+    /// # Returns
+    /// Validated IdempotencyKey value object
+    ///
+    /// # Errors
+    /// * `Empty` - Key is empty
+    /// * `TooLong` - Key longer than 255 characters
+    /// * `InvalidCharacters` - Key contains non-printable or non-ASCII characters
+    pub fn new(key: String) -> Result<Self, IdempotencyKeyError> {
+        let length = key.len();
+        if length == 0 {
+            Err(IdempotencyKeyError::Empty)
+        } else if length > Self::MAX_LENGTH {
+            Err(IdempotencyKeyError::TooLong {
+                max: Self::MAX_LENGTH,
+                actual: length,
+            })
+        } else if !key.chars().all(|c| c.is_ascii_graphic()) {
+            Err(IdempotencyKeyError::InvalidCharacters)
+        } else {
+            Ok(Self(key))
+        }
+    }
+
+    /// Get key as string slice.
+    ///
+    /// # Returns
+    /// Key string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Request made under an idempotency key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyRecord {
+    /// Digest of the request, to tell retries from other requests reusing the key
+    pub fingerprint: String,
+    /// Resource the request created, None while the request is in progress
+    pub resource_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idempotency_key_validation() {
+        assert!(IdempotencyKey::new("5f0c9a52-6a3e-4b1d-9a0e-1f2b3c4d5e6f".to_string()).is_ok());
+        assert_eq!(
+            IdempotencyKey::new(String::new()),
+            Err(IdempotencyKeyError::Empty)
+        );
+        assert_eq!(
+            IdempotencyKey::new("a".repeat(256)),
+            Err(IdempotencyKeyError::TooLong {
+                max: 255,
+                actual: 256
+            })
+        );
+        assert_eq!(
+            IdempotencyKey::new("retry 1".to_string()),
+            Err(IdempotencyKeyError::InvalidCharacters)
+        );
+    }
+}
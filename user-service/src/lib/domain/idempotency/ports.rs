@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::errors::IdempotencyError;
+use super::models::IdempotencyKey;
+use super::models::IdempotencyRecord;
+
+/// Repository port for idempotency records.
+///
+/// Records are scoped by operation, so one key may be used with different
+/// endpoints.
+#[async_trait]
+pub trait IdempotencyRepository: Send + Sync + 'static {
+    /// Claim a key for a request, unless a live record already holds it.
+    ///
+    /// Records created before `expired_before` are removed first, for every key.
+    ///
+    /// # Arguments
+    /// * `operation` - Operation the key is used with, e.g. `create_user`
+    /// * `key` - Key sent by the client
+    /// * `fingerprint` - Digest of the request
+    /// * `expired_before` - Creation time before which records are expired
+    ///
+    /// # Returns
+    /// None if the key was claimed for this request, otherwise the record holding it
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn claim(
+        &self,
+        operation: &str,
+        key: &IdempotencyKey,
+        fingerprint: &str,
+        expired_before: DateTime<Utc>,
+    ) -> Result<Option<IdempotencyRecord>, IdempotencyError>;
+
+    /// Record the resource created by the request holding a key.
+    ///
+    /// # Arguments
+    /// * `operation` - Operation the key is used with
+    /// * `key` - Key claimed for the request
+    /// * `resource_id` - ID of the created resource
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn complete(
+        &self,
+        operation: &str,
+        key: &IdempotencyKey,
+        resource_id: Uuid,
+    ) -> Result<(), IdempotencyError>;
+
+    /// Release a key whose request failed, so that it can be retried.
+    ///
+    /// # Arguments
+    /// * `operation` - Operation the key is used with
+    /// * `key` - Key claimed for the request
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn release(&self, operation: &str, key: &IdempotencyKey) -> Result<(), IdempotencyError>;
+}
@@ -0,0 +1,15 @@
+use crate::domain::user::models::ImportUserCommand;
+
+/// Job kind of bulk user imports.
+pub const USER_IMPORT_JOB_KIND: &str = "user_import";
+
+/// One row of a bulk user import.
+///
+/// Rows that could not be parsed are kept with their error so they are
+/// reported alongside rows rejected by the user service.
+#[derive(Debug)]
+pub struct ImportRow {
+    /// 1-based line number of the row in the uploaded file
+    pub number: usize,
+    pub command: Result<ImportUserCommand, String>,
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use crate::domain::import::models::ImportRow;
+use crate::domain::job::errors::JobError;
+use crate::domain::job::models::Job;
+use crate::domain::user::models::UserId;
+
+/// Port for bulk user imports.
+#[async_trait]
+pub trait UserImportServicePort: Send + Sync + 'static {
+    /// Start importing users in the background.
+    ///
+    /// Each row is created like a registration, emitting `UserCreated`. Rows
+    /// that fail are recorded as item errors of the job, which still succeeds.
+    ///
+    /// # Arguments
+    /// * `owner_id` - Administrator who requested the import
+    /// * `rows` - Parsed rows of the uploaded file
+    ///
+    /// # Returns
+    /// Pending job tracking the import
+    ///
+    /// # Errors
+    /// * `DatabaseError` - The job could not be created
+    async fn start_import(&self, owner_id: &UserId, rows: Vec<ImportRow>) -> Result<Job, JobError>;
+}
@@ -0,0 +1,301 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::import::models::ImportRow;
+use crate::domain::import::models::USER_IMPORT_JOB_KIND;
+use crate::domain::import::ports::UserImportServicePort;
+use crate::domain::job::errors::JobError;
+use crate::domain::job::models::Job;
+use crate::domain::job::models::JobId;
+use crate::domain::job::models::JobItemError;
+use crate::domain::job::models::JobKind;
+use crate::domain::job::models::JobProgress;
+use crate::domain::job::ports::JobServicePort;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserServicePort;
+
+/// Number of rows between two progress reports.
+const PROGRESS_INTERVAL: usize = 100;
+
+/// Domain service implementation for bulk user imports.
+///
+/// Concrete implementation of UserImportServicePort with dependency injection.
+pub struct UserImportService<US, JS>
+where
+    US: UserServicePort,
+    JS: JobServicePort,
+{
+    user_service: Arc<US>,
+    job_service: Arc<JS>,
+}
+
+impl<US, JS> UserImportService<US, JS>
+where
+    US: UserServicePort,
+    JS: JobServicePort,
+{
+    /// Create a new import service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `user_service` - Service creating the imported users
+    /// * `job_service` - Service tracking import progress
+    ///
+    /// # Returns
+    /// Configured import service instance
+    pub fn new(user_service: Arc<US>, job_service: Arc<JS>) -> Self {
+        Self {
+            user_service,
+            job_service,
+        }
+    }
+
+    async fn run_import(
+        user_service: &US,
+        job_service: &JS,
+        job_id: &JobId,
+        rows: Vec<ImportRow>,
+    ) -> Result<Job, JobError> {
+        let total = rows.len();
+        let mut item_errors = Vec::new();
+
+        for (index, row) in rows.into_iter().enumerate() {
+            let result = match row.command {
+                Ok(command) => user_service
+                    .import_user(command)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| e.to_string()),
+                Err(message) => Err(message),
+            };
+
+            if let Err(message) = result {
+                item_errors.push(JobItemError {
+                    item: row.number,
+                    message,
+                });
+            }
+
+            let processed = index + 1;
+            if processed % PROGRESS_INTERVAL == 0 && processed < total {
+                let progress = JobProgress::new((processed * 100 / total) as i16)?;
+                job_service.report_progress(job_id, progress).await?;
+            }
+        }
+
+        tracing::info!(
+            job_id = %job_id,
+            rows = total,
+            failed = item_errors.len(),
+            "User import finished"
+        );
+
+        if !item_errors.is_empty() {
+            job_service.report_item_errors(job_id, item_errors).await?;
+        }
+        job_service.complete_job(job_id, None).await
+    }
+}
+
+#[async_trait]
+impl<US, JS> UserImportServicePort for UserImportService<US, JS>
+where
+    US: UserServicePort,
+    JS: JobServicePort,
+{
+    async fn start_import(&self, owner_id: &UserId, rows: Vec<ImportRow>) -> Result<Job, JobError> {
+        let job = self
+            .job_service
+            .create_job(JobKind::new(USER_IMPORT_JOB_KIND), owner_id)
+            .await?;
+
+        let user_service = Arc::clone(&self.user_service);
+        let job_service = Arc::clone(&self.job_service);
+        let job_id = job.id;
+        tokio::spawn(async move {
+            let result =
+                Self::run_import(user_service.as_ref(), job_service.as_ref(), &job_id, rows).await;
+            if let Err(e) = result {
+                tracing::error!(job_id = %job_id, "User import failed: {}", e);
+                if let Err(e) = job_service.fail_job(&job_id, e.to_string()).await {
+                    tracing::error!(job_id = %job_id, "Failed to mark import job as failed: {}", e);
+                }
+            }
+        });
+
+        Ok(job)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use auth::SecretString;
+    use mockall::mock;
+    use mockall::predicate::*;
+
+    use super::*;
+    use crate::domain::user::models::CreateUserCommand;
+    use crate::domain::user::models::EmailAddress;
+    use crate::domain::user::models::ImportUserCommand;
+    use crate::domain::user::models::LoginIdentifier;
+    use crate::domain::user::models::UpdateUserCommand;
+    use crate::domain::user::models::User;
+    use crate::domain::user::models::UserCursor;
+    use crate::domain::user::models::UserPage;
+    use crate::domain::user::models::UserSearchQuery;
+    use crate::domain::user::models::UserStatus;
+    use crate::domain::user::models::Username;
+    use crate::user::errors::UserError;
+
+    mock! {
+        pub TestUserService {}
+
+        #[async_trait]
+        impl UserServicePort for TestUserService {
+            async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
+            async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+            async fn get_user(&self, id: &UserId) -> Result<User, UserError>;
+            async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
+            async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+            async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
+            async fn list_users(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<UserPage, UserError>;
+            async fn search_users(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
+            async fn update_user(&self, id: &UserId, command: UpdateUserCommand) -> Result<User, UserError>;
+            async fn change_password(&self, id: &UserId, current_password: SecretString, new_password: SecretString) -> Result<(), UserError>;
+            async fn reset_password(&self, id: &UserId, new_password: SecretString) -> Result<(), UserError>;
+            async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+            async fn replace_password_hash(&self, id: &UserId, password_hash: String) -> Result<(), UserError>;
+            async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    mock! {
+        pub TestJobService {}
+
+        #[async_trait]
+        impl JobServicePort for TestJobService {
+            async fn create_job(&self, kind: JobKind, owner_id: &UserId) -> Result<Job, JobError>;
+            async fn get_job(&self, id: &JobId, requester_id: &UserId) -> Result<Job, JobError>;
+            async fn report_progress(&self, id: &JobId, progress: JobProgress) -> Result<Job, JobError>;
+            async fn report_item_errors(&self, id: &JobId, errors: Vec<JobItemError>) -> Result<Job, JobError>;
+            async fn complete_job(&self, id: &JobId, result_url: Option<String>) -> Result<Job, JobError>;
+            async fn fail_job(&self, id: &JobId, error: String) -> Result<Job, JobError>;
+        }
+    }
+
+    fn row(number: usize, username: &str) -> ImportRow {
+        ImportRow {
+            number,
+            command: Ok(ImportUserCommand {
+                username: Username::new(username.to_string()).unwrap(),
+                email: EmailAddress::new(format!("{}@example.com", username)).unwrap(),
+                password_hash: None,
+            }),
+        }
+    }
+
+    fn imported(command: ImportUserCommand) -> User {
+        User {
+            id: UserId::new(),
+            username: command.username,
+            email: command.email,
+            password_hash: "$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_import_reports_failed_rows() {
+        let owner_id = UserId::new();
+        let job = Job::new(JobKind::new(USER_IMPORT_JOB_KIND), owner_id);
+        let job_id = job.id;
+
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_import_user()
+            .times(2)
+            .returning(|command| match command.username.as_str() {
+                "taken" => Err(UserError::UsernameAlreadyExists("taken".to_string())),
+                _ => Ok(imported(command)),
+            });
+
+        let mut job_service = MockTestJobService::new();
+        let failed_job = job.clone();
+        job_service
+            .expect_report_item_errors()
+            .withf(|_, errors| {
+                errors.iter().map(|e| e.item).collect::<Vec<_>>() == vec![2, 3]
+                    && errors[0].message == "invalid email"
+            })
+            .times(1)
+            .returning(move |_, _| Ok(failed_job.clone()));
+        job_service
+            .expect_complete_job()
+            .with(eq(job_id), eq(None))
+            .times(1)
+            .returning(move |_, _| Ok(job.clone()));
+
+        let rows = vec![
+            row(1, "alice"),
+            ImportRow {
+                number: 2,
+                command: Err("invalid email".to_string()),
+            },
+            row(3, "taken"),
+        ];
+
+        let result = UserImportService::<MockTestUserService, MockTestJobService>::run_import(
+            &user_service,
+            &job_service,
+            &job_id,
+            rows,
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_run_import_reports_progress() {
+        let job = Job::new(JobKind::new(USER_IMPORT_JOB_KIND), UserId::new());
+        let job_id = job.id;
+
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_import_user()
+            .times(250)
+            .returning(|command| Ok(imported(command)));
+
+        let mut job_service = MockTestJobService::new();
+        let progress_job = job.clone();
+        job_service
+            .expect_report_progress()
+            .times(2)
+            .returning(move |_, _| Ok(progress_job.clone()));
+        job_service.expect_report_item_errors().never();
+        job_service
+            .expect_complete_job()
+            .times(1)
+            .returning(move |_, _| Ok(job.clone()));
+
+        let rows = (1..=250).map(|n| row(n, &format!("user{}", n))).collect();
+
+        let result = UserImportService::<MockTestUserService, MockTestJobService>::run_import(
+            &user_service,
+            &job_service,
+            &job_id,
+            rows,
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+/// Error for JobId parsing failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum JobIdError {
+    #[error("Invalid UUID format: {0}")]
+    InvalidFormat(String),
+}
+
+/// Top-level error for all job-related operations
+#[derive(Debug, Clone, Error)]
+pub enum JobError {
+    #[error("Invalid job ID: {0}")]
+    InvalidJobId(#[from] JobIdError),
+
+    #[error("Job not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid job status: {0}")]
+    InvalidStatus(String),
+
+    #[error("Invalid job progress: {0} (must be between 0 and 100)")]
+    InvalidProgress(i16),
+
+    #[error("Job {id} is already {status}")]
+    AlreadyFinished { id: String, status: String },
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+
+    #[error("Unknown error: {0}")]
+    Unknown(String),
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::job::errors::JobError;
+use crate::domain::job::errors::JobIdError;
+use crate::domain::user::models::UserId;
+
+/// Background job aggregate entity.
+///
+/// Tracks the lifecycle of a long-running operation (export, import, bulk
+/// delete, ...) so clients can poll its status instead of holding a request open.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub id: JobId,
+    pub kind: JobKind,
+    pub owner_id: UserId,
+    pub status: JobStatus,
+    pub progress: JobProgress,
+    pub result_url: Option<String>,
+    pub error: Option<String>,
+    /// Items a bulk job could not process; the job itself may still succeed
+    pub item_errors: Vec<JobItemError>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Job {
+    /// Create a new pending job.
+    ///
+    /// # Arguments
+    /// * `kind` - Kind of operation the job performs
+    /// * `owner_id` - User who requested the operation
+    ///
+    /// # Returns
+    /// Job in `Pending` status with zero progress
+    pub fn new(kind: JobKind, owner_id: UserId) -> Self {
+        let now = Utc::now();
+        Self {
+            id: JobId::new(),
+            kind,
+            owner_id,
+            status: JobStatus::Pending,
+            progress: JobProgress::default(),
+            result_url: None,
+            error: None,
+            item_errors: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Record progress, moving a pending job to `Running`.
+    ///
+    /// # Arguments
+    /// * `progress` - Completion percentage
+    ///
+    /// # Errors
+    /// * `AlreadyFinished` - Job has already succeeded or failed
+    pub fn report_progress(&mut self, progress: JobProgress) -> Result<(), JobError> {
+        self.ensure_not_finished()?;
+        self.status = JobStatus::Running;
+        self.progress = progress;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Record items a bulk job could not process.
+    ///
+    /// # Arguments
+    /// * `errors` - Failed items to append
+    ///
+    /// # Errors
+    /// * `AlreadyFinished` - Job has already succeeded or failed
+    pub fn record_item_errors(&mut self, errors: Vec<JobItemError>) -> Result<(), JobError> {
+        self.ensure_not_finished()?;
+        self.item_errors.extend(errors);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Mark the job as succeeded.
+    ///
+    /// # Arguments
+    /// * `result_url` - Optional link to the job output
+    ///
+    /// # Errors
+    /// * `AlreadyFinished` - Job has already succeeded or failed
+    pub fn succeed(&mut self, result_url: Option<String>) -> Result<(), JobError> {
+        self.ensure_not_finished()?;
+        self.status = JobStatus::Succeeded;
+        self.progress = JobProgress::complete();
+        self.result_url = result_url;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Mark the job as failed.
+    ///
+    /// # Arguments
+    /// * `error` - Human-readable failure reason
+    ///
+    /// # Errors
+    /// * `AlreadyFinished` - Job has already succeeded or failed
+    pub fn fail(&mut self, error: String) -> Result<(), JobError> {
+        self.ensure_not_finished()?;
+        self.status = JobStatus::Failed;
+        self.error = Some(error);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    fn ensure_not_finished(&self) -> Result<(), JobError> {
+        if self.status.is_finished() {
+            return Err(JobError::AlreadyFinished {
+                id: self.id.to_string(),
+                status: self.status.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Failure of a single item (e.g. an import row) within a bulk job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobItemError {
+    /// 1-based position of the item in the job input
+    pub item: usize,
+    pub message: String,
+}
+
+/// Job unique identifier type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JobId(pub Uuid);
+
+impl JobId {
+    /// Generate a new random job ID.
+    ///
+    /// # Returns
+    /// JobId with random UUID v4
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Parse a job ID from string.
+    ///
+    /// # Arguments
+    /// * `s` - UUID string to parse
+    ///
+    /// # Returns
+    /// Parsed JobId
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a valid UUID
+    pub fn from_string(s: &str) -> Result<Self, JobIdError> {
+        Uuid::parse_str(s)
+            .map(JobId)
+            .map_err(|e| JobIdError::InvalidFormat(e.to_string()))
+    }
+}
+
+impl Default for JobId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for JobId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Kind of operation a job performs (e.g. `user_export`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobKind(String);
+
+impl JobKind {
+    /// Create a job kind.
+    ///
+    /// # Arguments
+    /// * `kind` - Snake-case operation name
+    ///
+    /// # Returns
+    /// JobKind wrapping the name
+    pub fn new(kind: impl Into<String>) -> Self {
+        Self(kind.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for JobKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Lifecycle state of a job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Pending => "pending",
+            JobStatus::Running => "running",
+            JobStatus::Succeeded => "succeeded",
+            JobStatus::Failed => "failed",
+        }
+    }
+
+    /// Whether the job has reached a terminal state.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, JobStatus::Succeeded | JobStatus::Failed)
+    }
+}
+
+impl FromStr for JobStatus {
+    type Err = JobError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(JobStatus::Pending),
+            "running" => Ok(JobStatus::Running),
+            "succeeded" => Ok(JobStatus::Succeeded),
+            "failed" => Ok(JobStatus::Failed),
+            other => Err(JobError::InvalidStatus(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Job completion percentage value type
+///
+/// Ensures progress is between 0 and 100 inclusive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JobProgress(i16);
+
+impl JobProgress {
+    const MAX: i16 = 100;
+
+    /// Create a validated progress value.
+    ///
+    /// # Arguments
+    /// * `percent` - Completion percentage
+    ///
+    /// # Returns
+    /// Validated JobProgress
+    ///
+    /// # Errors
+    /// * `InvalidProgress` - Value is outside 0..=100
+    pub fn new(percent: i16) -> Result<Self, JobError> {
+        if !(0..=Self::MAX).contains(&percent) {
+            return Err(JobError::InvalidProgress(percent));
+        }
+        Ok(Self(percent))
+    }
+
+    /// Progress of a completed job.
+    pub fn complete() -> Self {
+        Self(Self::MAX)
+    }
+
+    pub fn value(&self) -> i16 {
+        self.0
+    }
+}
@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+
+use crate::domain::job::errors::JobError;
+use crate::domain::job::models::Job;
+use crate::domain::job::models::JobId;
+use crate::domain::job::models::JobItemError;
+use crate::domain::job::models::JobKind;
+use crate::domain::job::models::JobProgress;
+use crate::domain::user::models::UserId;
+
+/// Port for background job operations.
+///
+/// Endpoints that start long-running work create a job, hand its ID back to the
+/// client, and report progress through this port as the work proceeds.
+#[async_trait]
+pub trait JobServicePort: Send + Sync + 'static {
+    /// Register a new pending job.
+    ///
+    /// # Arguments
+    /// * `kind` - Kind of operation the job performs
+    /// * `owner_id` - User who requested the operation
+    ///
+    /// # Returns
+    /// Created job entity
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn create_job(&self, kind: JobKind, owner_id: &UserId) -> Result<Job, JobError>;
+
+    /// Retrieve a job visible to the requesting user.
+    ///
+    /// # Arguments
+    /// * `id` - Job ID
+    /// * `requester_id` - User asking for the job
+    ///
+    /// # Returns
+    /// Job entity
+    ///
+    /// # Errors
+    /// * `NotFound` - Job does not exist or belongs to another user
+    /// * `DatabaseError` - Database operation failed
+    async fn get_job(&self, id: &JobId, requester_id: &UserId) -> Result<Job, JobError>;
+
+    /// Record progress of a running job.
+    ///
+    /// # Arguments
+    /// * `id` - Job ID
+    /// * `progress` - Completion percentage
+    ///
+    /// # Returns
+    /// Updated job entity
+    ///
+    /// # Errors
+    /// * `NotFound` - Job does not exist
+    /// * `AlreadyFinished` - Job has already succeeded or failed
+    /// * `DatabaseError` - Database operation failed
+    async fn report_progress(&self, id: &JobId, progress: JobProgress) -> Result<Job, JobError>;
+
+    /// Record items a bulk job could not process.
+    ///
+    /// # Arguments
+    /// * `id` - Job ID
+    /// * `errors` - Failed items to append
+    ///
+    /// # Returns
+    /// Updated job entity
+    ///
+    /// # Errors
+    /// * `NotFound` - Job does not exist
+    /// * `AlreadyFinished` - Job has already succeeded or failed
+    /// * `DatabaseError` - Database operation failed
+    async fn report_item_errors(
+        &self,
+        id: &JobId,
+        errors: Vec<JobItemError>,
+    ) -> Result<Job, JobError>;
+
+    /// Mark a job as succeeded.
+    ///
+    /// # Arguments
+    /// * `id` - Job ID
+    /// * `result_url` - Optional link to the job output
+    ///
+    /// # Returns
+    /// Updated job entity
+    ///
+    /// # Errors
+    /// * `NotFound` - Job does not exist
+    /// * `AlreadyFinished` - Job has already succeeded or failed
+    /// * `DatabaseError` - Database operation failed
+    async fn complete_job(&self, id: &JobId, result_url: Option<String>) -> Result<Job, JobError>;
+
+    /// Mark a job as failed.
+    ///
+    /// # Arguments
+    /// * `id` - Job ID
+    /// * `error` - Human-readable failure reason
+    ///
+    /// # Returns
+    /// Updated job entity
+    ///
+    /// # Errors
+    /// * `NotFound` - Job does not exist
+    /// * `AlreadyFinished` - Job has already succeeded or failed
+    /// * `DatabaseError` - Database operation failed
+    async fn fail_job(&self, id: &JobId, error: String) -> Result<Job, JobError>;
+}
+
+/// Persistence operations for job aggregate.
+#[async_trait]
+pub trait JobRepository: Send + Sync + 'static {
+    /// Persist new job to storage.
+    ///
+    /// # Arguments
+    /// * `job` - Job entity to create
+    ///
+    /// # Returns
+    /// Created job entity
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn create(&self, job: Job) -> Result<Job, JobError>;
+
+    /// Retrieve job by identifier.
+    ///
+    /// # Arguments
+    /// * `id` - Job ID
+    ///
+    /// # Returns
+    /// Optional job entity (None if not found)
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_by_id(&self, id: &JobId) -> Result<Option<Job>, JobError>;
+
+    /// Update status, progress, and outcome of an existing job.
+    ///
+    /// # Arguments
+    /// * `job` - Job entity with updated fields
+    ///
+    /// # Returns
+    /// Updated job entity
+    ///
+    /// # Errors
+    /// * `NotFound` - Job does not exist
+    /// * `DatabaseError` - Database operation failed
+    async fn update(&self, job: Job) -> Result<Job, JobError>;
+}
@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::job::errors::JobError;
+use crate::domain::job::models::Job;
+use crate::domain::job::models::JobId;
+use crate::domain::job::models::JobItemError;
+use crate::domain::job::models::JobKind;
+use crate::domain::job::models::JobProgress;
+use crate::domain::job::ports::JobRepository;
+use crate::domain::job::ports::JobServicePort;
+use crate::domain::user::models::UserId;
+
+/// Domain service implementation for background job tracking.
+///
+/// Concrete implementation of JobServicePort with dependency injection.
+pub struct JobService<JR>
+where
+    JR: JobRepository,
+{
+    repository: Arc<JR>,
+}
+
+impl<JR> JobService<JR>
+where
+    JR: JobRepository,
+{
+    /// Create a new job service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `repository` - Job persistence implementation
+    ///
+    /// # Returns
+    /// Configured job service instance
+    pub fn new(repository: Arc<JR>) -> Self {
+        Self { repository }
+    }
+
+    async fn find_job(&self, id: &JobId) -> Result<Job, JobError> {
+        self.repository
+            .find_by_id(id)
+            .await?
+            .ok_or(JobError::NotFound(id.to_string()))
+    }
+}
+
+#[async_trait]
+impl<JR> JobServicePort for JobService<JR>
+where
+    JR: JobRepository,
+{
+    async fn create_job(&self, kind: JobKind, owner_id: &UserId) -> Result<Job, JobError> {
+        let job = Job::new(kind, *owner_id);
+        tracing::info!(job_id = %job.id, kind = %job.kind, "Job created");
+        self.repository.create(job).await
+    }
+
+    async fn get_job(&self, id: &JobId, requester_id: &UserId) -> Result<Job, JobError> {
+        let job = self.find_job(id).await?;
+
+        // Do not reveal the existence of other users' jobs
+        if job.owner_id != *requester_id {
+            return Err(JobError::NotFound(id.to_string()));
+        }
+
+        Ok(job)
+    }
+
+    async fn report_progress(&self, id: &JobId, progress: JobProgress) -> Result<Job, JobError> {
+        let mut job = self.find_job(id).await?;
+        job.report_progress(progress)?;
+        self.repository.update(job).await
+    }
+
+    async fn report_item_errors(
+        &self,
+        id: &JobId,
+        errors: Vec<JobItemError>,
+    ) -> Result<Job, JobError> {
+        let mut job = self.find_job(id).await?;
+        job.record_item_errors(errors)?;
+        self.repository.update(job).await
+    }
+
+    async fn complete_job(&self, id: &JobId, result_url: Option<String>) -> Result<Job, JobError> {
+        let mut job = self.find_job(id).await?;
+        job.succeed(result_url)?;
+        tracing::info!(job_id = %job.id, kind = %job.kind, "Job succeeded");
+        self.repository.update(job).await
+    }
+
+    async fn fail_job(&self, id: &JobId, error: String) -> Result<Job, JobError> {
+        let mut job = self.find_job(id).await?;
+        job.fail(error)?;
+        tracing::warn!(job_id = %job.id, kind = %job.kind, "Job failed");
+        self.repository.update(job).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+    use mockall::predicate::*;
+
+    use super::*;
+    use crate::domain::job::models::JobStatus;
+
+    mock! {
+        pub TestJobRepository {}
+
+        #[async_trait]
+        impl JobRepository for TestJobRepository {
+            async fn create(&self, job: Job) -> Result<Job, JobError>;
+            async fn find_by_id(&self, id: &JobId) -> Result<Option<Job>, JobError>;
+            async fn update(&self, job: Job) -> Result<Job, JobError>;
+        }
+    }
+
+    fn pending_job(owner_id: UserId) -> Job {
+        Job::new(JobKind::new("user_export"), owner_id)
+    }
+
+    #[tokio::test]
+    async fn test_create_job_is_pending() {
+        let mut repository = MockTestJobRepository::new();
+        repository.expect_create().times(1).returning(Ok);
+
+        let service = JobService::new(Arc::new(repository));
+        let owner_id = UserId::new();
+
+        let job = service
+            .create_job(JobKind::new("user_export"), &owner_id)
+            .await
+            .unwrap();
+
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(job.progress.value(), 0);
+        assert_eq!(job.owner_id, owner_id);
+    }
+
+    #[tokio::test]
+    async fn test_get_job_of_other_user_is_not_found() {
+        let owner_id = UserId::new();
+        let job = pending_job(owner_id);
+        let job_id = job.id;
+
+        let mut repository = MockTestJobRepository::new();
+        repository
+            .expect_find_by_id()
+            .with(eq(job_id))
+            .times(1)
+            .returning(move |_| Ok(Some(job.clone())));
+
+        let service = JobService::new(Arc::new(repository));
+
+        let result = service.get_job(&job_id, &UserId::new()).await;
+        assert!(matches!(result, Err(JobError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_job_not_found() {
+        let mut repository = MockTestJobRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(|_| Ok(None));
+
+        let service = JobService::new(Arc::new(repository));
+
+        let result = service.get_job(&JobId::new(), &UserId::new()).await;
+        assert!(matches!(result, Err(JobError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_report_progress_marks_running() {
+        let job = pending_job(UserId::new());
+        let job_id = job.id;
+
+        let mut repository = MockTestJobRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(job.clone())));
+        repository.expect_update().times(1).returning(Ok);
+
+        let service = JobService::new(Arc::new(repository));
+
+        let job = service
+            .report_progress(&job_id, JobProgress::new(40).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(job.status, JobStatus::Running);
+        assert_eq!(job.progress.value(), 40);
+    }
+
+    #[tokio::test]
+    async fn test_complete_job_sets_result() {
+        let job = pending_job(UserId::new());
+        let job_id = job.id;
+
+        let mut repository = MockTestJobRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(job.clone())));
+        repository.expect_update().times(1).returning(Ok);
+
+        let service = JobService::new(Arc::new(repository));
+
+        let job = service
+            .complete_job(&job_id, Some("/exports/1.json".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(job.status, JobStatus::Succeeded);
+        assert_eq!(job.progress.value(), 100);
+        assert_eq!(job.result_url, Some("/exports/1.json".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_report_item_errors_appends() {
+        let mut job = pending_job(UserId::new());
+        job.record_item_errors(vec![JobItemError {
+            item: 1,
+            message: "first".to_string(),
+        }])
+        .unwrap();
+        let job_id = job.id;
+
+        let mut repository = MockTestJobRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(job.clone())));
+        repository.expect_update().times(1).returning(Ok);
+
+        let service = JobService::new(Arc::new(repository));
+
+        let job = service
+            .report_item_errors(
+                &job_id,
+                vec![JobItemError {
+                    item: 3,
+                    message: "third".to_string(),
+                }],
+            )
+            .await
+            .unwrap();
+
+        let items: Vec<usize> = job.item_errors.iter().map(|e| e.item).collect();
+        assert_eq!(items, vec![1, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_finished_job_cannot_be_updated() {
+        let mut job = pending_job(UserId::new());
+        job.fail("boom".to_string()).unwrap();
+        let job_id = job.id;
+
+        let mut repository = MockTestJobRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(job.clone())));
+        repository.expect_update().never();
+
+        let service = JobService::new(Arc::new(repository));
+
+        let result = service.complete_job(&job_id, None).await;
+        assert!(matches!(result, Err(JobError::AlreadyFinished { .. })));
+    }
+
+    #[test]
+    fn test_job_progress_bounds() {
+        assert!(JobProgress::new(0).is_ok());
+        assert!(JobProgress::new(100).is_ok());
+        assert!(matches!(
+            JobProgress::new(101),
+            Err(JobError::InvalidProgress(101))
+        ));
+        assert!(matches!(
+            JobProgress::new(-1),
+            Err(JobError::InvalidProgress(-1))
+        ));
+    }
+}
@@ -0,0 +1,13 @@
+use chrono::DateTime;
+use chrono::Utc;
+use thiserror::Error;
+
+/// Top-level error for login lockout operations
+#[derive(Debug, Clone, Error)]
+pub enum LockoutError {
+    #[error("Account is locked after too many failed logins, try again after {0}")]
+    Locked(DateTime<Utc>),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
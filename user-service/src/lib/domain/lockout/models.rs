@@ -0,0 +1,50 @@
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+
+use crate::domain::user::models::UserId;
+
+/// Settings of the lockout after failed password logins.
+#[derive(Debug, Clone)]
+pub struct LockoutSettings {
+    /// Failed logins in a row that lock the account
+    pub max_failed_attempts: u32,
+    /// Time the account stays locked
+    pub lock_duration: Duration,
+}
+
+/// Account a password login is attempted on.
+///
+/// Identifiers of no account are counted and locked exactly like users, so that
+/// a lock does not reveal which accounts exist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoginSubject {
+    /// Existing user, whichever identifier they logged in with
+    User(UserId),
+    /// Username or email address of no account
+    Unknown(String),
+}
+
+impl LoginSubject {
+    /// Key the failed logins of the subject are counted under.
+    ///
+    /// # Returns
+    /// `user:<id>` for users, `identifier:<identifier>` (lowercase) otherwise
+    pub fn key(&self) -> String {
+        match self {
+            Self::User(user_id) => format!("user:{}", user_id),
+            Self::Unknown(identifier) => format!("identifier:{}", identifier.to_lowercase()),
+        }
+    }
+}
+
+/// Failed password logins of a subject since its last successful login.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginAttempts {
+    /// Key of the subject, see [`LoginSubject::key`]
+    pub key: String,
+    /// Failed logins in a row, restarted once a lock has expired
+    pub failed_attempts: u32,
+    /// End of the current lock, None while the account is not locked
+    pub locked_until: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::lockout::errors::LockoutError;
+use crate::domain::lockout::models::LoginAttempts;
+use crate::domain::lockout::models::LoginSubject;
+
+/// Port for locking accounts after repeated failed password logins.
+#[async_trait]
+pub trait LockoutServicePort: Send + Sync + 'static {
+    /// Refuse logins to a locked account.
+    ///
+    /// # Arguments
+    /// * `subject` - Account logged in to
+    ///
+    /// # Errors
+    /// * `Locked` - Account is locked, carrying the end of the lock
+    /// * `DatabaseError` - Database operation failed
+    async fn ensure_unlocked(&self, subject: &LoginSubject) -> Result<(), LockoutError>;
+
+    /// Count a failed login, locking the account once the limit is reached.
+    ///
+    /// Publishes a UserAccountLocked event when the account of a user gets locked.
+    ///
+    /// # Arguments
+    /// * `subject` - Account whose login failed
+    ///
+    /// # Returns
+    /// Failed logins of the account, with the lock if this failure locked it
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record_failure(&self, subject: &LoginSubject) -> Result<LoginAttempts, LockoutError>;
+
+    /// Forget the failed logins of an account after a successful one.
+    ///
+    /// # Arguments
+    /// * `subject` - Account logged in to
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record_success(&self, subject: &LoginSubject) -> Result<(), LockoutError>;
+}
+
+/// Persistence of failed login attempts.
+#[async_trait]
+pub trait LoginAttemptRepository: Send + Sync + 'static {
+    /// Retrieve the failed logins of a subject.
+    ///
+    /// # Arguments
+    /// * `key` - Key of the subject to look up
+    ///
+    /// # Returns
+    /// Failed logins, None if the subject has none
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find(&self, key: &str) -> Result<Option<LoginAttempts>, LockoutError>;
+
+    /// Count one more failed login, atomically.
+    ///
+    /// A lock that expired before `at` is cleared and the count restarts.
+    ///
+    /// # Arguments
+    /// * `key` - Key of the subject whose login failed
+    /// * `at` - Time of the failure
+    ///
+    /// # Returns
+    /// Failed logins including this one
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record_failure(
+        &self,
+        key: &str,
+        at: DateTime<Utc>,
+    ) -> Result<LoginAttempts, LockoutError>;
+
+    /// Lock an account that is not locked yet.
+    ///
+    /// # Arguments
+    /// * `key` - Key of the subject to lock
+    /// * `until` - End of the lock
+    ///
+    /// # Returns
+    /// True if this call locked the account, false if it already was
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<bool, LockoutError>;
+
+    /// Forget the failed logins and lock of a subject.
+    ///
+    /// # Arguments
+    /// * `key` - Key of the subject to clear
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn clear(&self, key: &str) -> Result<(), LockoutError>;
+}
@@ -0,0 +1,343 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use clock::Clock;
+
+use crate::domain::lockout::errors::LockoutError;
+use crate::domain::lockout::models::LockoutSettings;
+use crate::domain::lockout::models::LoginAttempts;
+use crate::domain::lockout::models::LoginSubject;
+use crate::domain::lockout::ports::LockoutServicePort;
+use crate::domain::lockout::ports::LoginAttemptRepository;
+use crate::domain::user::events::UserAccountLockedEvent;
+use crate::domain::user::ports::EventPublisher;
+
+/// Domain service implementation for login lockouts.
+///
+/// Concrete implementation of LockoutServicePort with dependency injection.
+pub struct LockoutService<LR, EP>
+where
+    LR: LoginAttemptRepository,
+    EP: EventPublisher,
+{
+    repository: Arc<LR>,
+    event_publisher: Arc<EP>,
+    settings: LockoutSettings,
+    clock: Arc<dyn Clock>,
+}
+
+impl<LR, EP> LockoutService<LR, EP>
+where
+    LR: LoginAttemptRepository,
+    EP: EventPublisher,
+{
+    /// Create a new lockout service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `repository` - Login attempt persistence implementation
+    /// * `event_publisher` - Publisher of lockout events
+    /// * `settings` - Failure limit and lock duration
+    ///
+    /// # Returns
+    /// Configured lockout service instance
+    pub fn new(repository: Arc<LR>, event_publisher: Arc<EP>, settings: LockoutSettings) -> Self {
+        Self {
+            repository,
+            event_publisher,
+            settings,
+            clock: clock::system(),
+        }
+    }
+
+    /// Lock and unlock accounts by another clock.
+    ///
+    /// The system clock by default. A fake clock lifts locks without waiting
+    /// for them to expire.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock failures are recorded and locks expire by
+    ///
+    /// # Returns
+    /// Service telling the time by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl<LR, EP> LockoutServicePort for LockoutService<LR, EP>
+where
+    LR: LoginAttemptRepository,
+    EP: EventPublisher,
+{
+    async fn ensure_unlocked(&self, subject: &LoginSubject) -> Result<(), LockoutError> {
+        let locked_until = self
+            .repository
+            .find(&subject.key())
+            .await?
+            .and_then(|attempts| attempts.locked_until)
+            .filter(|until| *until > self.clock.now());
+        match locked_until {
+            Some(until) => Err(LockoutError::Locked(until)),
+            None => Ok(()),
+        }
+    }
+
+    async fn record_failure(&self, subject: &LoginSubject) -> Result<LoginAttempts, LockoutError> {
+        let now = self.clock.now();
+        let key = subject.key();
+        let mut attempts = self.repository.record_failure(&key, now).await?;
+        if attempts.failed_attempts < self.settings.max_failed_attempts
+            || attempts.locked_until.is_some()
+        {
+            return Ok(attempts);
+        }
+
+        let locked_until = now + self.settings.lock_duration;
+        // Concurrent failures can reach the limit together; one of them locks.
+        // Only users have an account to announce the lock of.
+        if self.repository.lock(&key, locked_until).await? {
+            if let LoginSubject::User(user_id) = subject {
+                tracing::warn!(
+                    user_id = %user_id,
+                    failed_attempts = attempts.failed_attempts,
+                    locked_until = %locked_until,
+                    "Account locked after failed logins"
+                );
+                let event = UserAccountLockedEvent::new(
+                    user_id.to_string(),
+                    attempts.failed_attempts,
+                    locked_until,
+                );
+                if let Err(e) = self
+                    .event_publisher
+                    .publish_user_account_locked(&event)
+                    .await
+                {
+                    tracing::warn!(
+                        "Failed to publish UserAccountLocked event for user {}: {}",
+                        user_id,
+                        e
+                    );
+                }
+            }
+        }
+        attempts.locked_until = Some(locked_until);
+
+        Ok(attempts)
+    }
+
+    async fn record_success(&self, subject: &LoginSubject) -> Result<(), LockoutError> {
+        self.repository.clear(&subject.key()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use chrono::DateTime;
+    use chrono::Duration;
+    use chrono::Utc;
+    use clock::FakeClock;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::user::errors::EventPublisherError;
+    use crate::domain::user::events::UserCreatedEvent;
+    use crate::domain::user::events::UserDeactivatedEvent;
+    use crate::domain::user::events::UserDeletedEvent;
+    use crate::domain::user::events::UserPasswordChangedEvent;
+    use crate::domain::user::events::UserPasswordResetEvent;
+    use crate::domain::user::events::UserPasswordResetRequestedEvent;
+    use crate::domain::user::events::UserReactivatedEvent;
+    use crate::domain::user::events::UserUpdatedEvent;
+    use crate::domain::user::models::UserId;
+
+    mock! {
+        pub TestEventPublisher {}
+
+        #[async_trait]
+        impl EventPublisher for TestEventPublisher {
+            async fn publish_user_created(&self, event: &UserCreatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_updated(&self, event: &UserUpdatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_deleted(&self, event: &UserDeletedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_deactivated(&self, event: &UserDeactivatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_reactivated(&self, event: &UserReactivatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_password_changed(&self, event: &UserPasswordChangedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_password_reset_requested(&self, event: &UserPasswordResetRequestedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_password_reset(&self, event: &UserPasswordResetEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_account_locked(&self, event: &UserAccountLockedEvent) -> Result<(), EventPublisherError>;
+        }
+    }
+
+    /// Login attempt repository keeping attempts in memory
+    #[derive(Default)]
+    struct InMemoryAttempts {
+        attempts: Mutex<HashMap<String, LoginAttempts>>,
+    }
+
+    #[async_trait]
+    impl LoginAttemptRepository for InMemoryAttempts {
+        async fn find(&self, key: &str) -> Result<Option<LoginAttempts>, LockoutError> {
+            Ok(self.attempts.lock().unwrap().get(key).cloned())
+        }
+
+        async fn record_failure(
+            &self,
+            key: &str,
+            at: DateTime<Utc>,
+        ) -> Result<LoginAttempts, LockoutError> {
+            let mut attempts = self.attempts.lock().unwrap();
+            let entry = attempts.entry(key.to_string()).or_insert(LoginAttempts {
+                key: key.to_string(),
+                failed_attempts: 0,
+                locked_until: None,
+            });
+            if entry.locked_until.is_some_and(|until| until <= at) {
+                entry.failed_attempts = 0;
+                entry.locked_until = None;
+            }
+            entry.failed_attempts += 1;
+            Ok(entry.clone())
+        }
+
+        async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<bool, LockoutError> {
+            let mut attempts = self.attempts.lock().unwrap();
+            match attempts.get_mut(key) {
+                Some(entry) if entry.locked_until.is_none() => {
+                    entry.locked_until = Some(until);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn clear(&self, key: &str) -> Result<(), LockoutError> {
+            self.attempts.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    fn service(
+        repository: Arc<InMemoryAttempts>,
+        event_publisher: MockTestEventPublisher,
+    ) -> LockoutService<InMemoryAttempts, MockTestEventPublisher> {
+        LockoutService::new(
+            repository,
+            Arc::new(event_publisher),
+            LockoutSettings {
+                max_failed_attempts: 3,
+                lock_duration: Duration::minutes(15),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_account_locked_after_max_failures() {
+        let user_id = UserId::new();
+        let subject = LoginSubject::User(user_id);
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_account_locked()
+            .withf(move |event| event.user_id == user_id.to_string() && event.failed_attempts == 3)
+            .times(1)
+            .returning(|_| Ok(()));
+        let service = service(Arc::new(InMemoryAttempts::default()), event_publisher);
+
+        for _ in 0..2 {
+            let attempts = service.record_failure(&subject).await.unwrap();
+            assert!(attempts.locked_until.is_none());
+            service.ensure_unlocked(&subject).await.unwrap();
+        }
+        let attempts = service.record_failure(&subject).await.unwrap();
+
+        let locked_until = attempts.locked_until.unwrap();
+        assert!(locked_until > Utc::now() + Duration::minutes(14));
+        assert!(matches!(
+            service.ensure_unlocked(&subject).await,
+            Err(LockoutError::Locked(until)) if until == locked_until
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_lock_lifts_on_fake_clock() {
+        let subject = LoginSubject::User(UserId::new());
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_account_locked()
+            .returning(|_| Ok(()));
+        let clock = Arc::new(FakeClock::default());
+        let service = service(Arc::new(InMemoryAttempts::default()), event_publisher)
+            .with_clock(clock.clone());
+
+        for _ in 0..3 {
+            service.record_failure(&subject).await.unwrap();
+        }
+        assert!(service.ensure_unlocked(&subject).await.is_err());
+
+        clock.advance(Duration::minutes(16));
+        service.ensure_unlocked(&subject).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_success_clears_failures() {
+        let subject = LoginSubject::User(UserId::new());
+        let service = service(
+            Arc::new(InMemoryAttempts::default()),
+            MockTestEventPublisher::new(),
+        );
+
+        service.record_failure(&subject).await.unwrap();
+        service.record_failure(&subject).await.unwrap();
+        service.record_success(&subject).await.unwrap();
+        let attempts = service.record_failure(&subject).await.unwrap();
+
+        assert_eq!(attempts.failed_attempts, 1);
+        assert!(attempts.locked_until.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expired_lock_restarts_count() {
+        let subject = LoginSubject::User(UserId::new());
+        let repository = Arc::new(InMemoryAttempts::default());
+        repository.attempts.lock().unwrap().insert(
+            subject.key(),
+            LoginAttempts {
+                key: subject.key(),
+                failed_attempts: 3,
+                locked_until: Some(Utc::now() - Duration::minutes(1)),
+            },
+        );
+        let service = service(Arc::clone(&repository), MockTestEventPublisher::new());
+
+        service.ensure_unlocked(&subject).await.unwrap();
+        let attempts = service.record_failure(&subject).await.unwrap();
+
+        assert_eq!(attempts.failed_attempts, 1);
+        assert!(attempts.locked_until.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_identifier_locked_like_user() {
+        let subject = LoginSubject::Unknown("Nobody@example.com".to_string());
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_account_locked()
+            .times(0);
+        let service = service(Arc::new(InMemoryAttempts::default()), event_publisher);
+
+        for _ in 0..3 {
+            service.record_failure(&subject).await.unwrap();
+        }
+
+        // Any capitalization of the identifier is the same subject
+        let same = LoginSubject::Unknown("nobody@example.com".to_string());
+        assert!(matches!(
+            service.ensure_unlocked(&same).await,
+            Err(LockoutError::Locked(_))
+        ));
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// Top-level error for login history operations
+#[derive(Debug, Clone, Error)]
+pub enum LoginHistoryError {
+    #[error("User not found: {0}")]
+    UserNotFound(String),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
@@ -0,0 +1,41 @@
+use std::net::IpAddr;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::account_link::models::AuthMethodKind;
+use crate::domain::user::models::UserId;
+
+/// Client a login came from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoginContext {
+    pub ip_address: Option<IpAddr>,
+    /// `User-Agent` header sent with the login
+    pub user_agent: Option<String>,
+}
+
+/// Successful login of a user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginRecord {
+    pub user_id: UserId,
+    /// Method the user proved their identity with
+    pub method: AuthMethodKind,
+    pub logged_in_at: DateTime<Utc>,
+    pub ip_address: Option<IpAddr>,
+    /// User agent, cut to [`LoginRecord::MAX_USER_AGENT_LENGTH`] characters
+    pub user_agent: Option<String>,
+}
+
+impl LoginRecord {
+    /// Longest user agent kept, in characters
+    pub const MAX_USER_AGENT_LENGTH: usize = 512;
+}
+
+/// Recent logins of a user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginHistory {
+    /// Time of the last successful login, None if the user never logged in
+    pub last_login_at: Option<DateTime<Utc>>,
+    /// Logins, newest first
+    pub logins: Vec<LoginRecord>,
+}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+
+use crate::domain::account_link::models::AuthMethodKind;
+use crate::domain::login_history::errors::LoginHistoryError;
+use crate::domain::login_history::models::LoginContext;
+use crate::domain::login_history::models::LoginHistory;
+use crate::domain::login_history::models::LoginRecord;
+use crate::domain::user::models::UserId;
+
+/// Port for recording successful logins and showing them to their user.
+#[async_trait]
+pub trait LoginHistoryServicePort: Send + Sync + 'static {
+    /// Record a successful login and make it the last login of the user.
+    ///
+    /// # Arguments
+    /// * `user_id` - User who logged in
+    /// * `method` - Method the user logged in with
+    /// * `context` - Address and user agent of the client
+    ///
+    /// # Returns
+    /// Stored login
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record_login(
+        &self,
+        user_id: &UserId,
+        method: AuthMethodKind,
+        context: LoginContext,
+    ) -> Result<LoginRecord, LoginHistoryError>;
+
+    /// Retrieve the recent logins of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - User whose logins to list
+    /// * `limit` - Most logins returned
+    ///
+    /// # Returns
+    /// Last login time and the latest logins, newest first
+    ///
+    /// # Errors
+    /// * `UserNotFound` - User does not exist
+    /// * `DatabaseError` - Database operation failed
+    async fn login_history(
+        &self,
+        user_id: &UserId,
+        limit: u32,
+    ) -> Result<LoginHistory, LoginHistoryError>;
+}
+
+/// Persistence of successful logins.
+#[async_trait]
+pub trait LoginHistoryRepository: Send + Sync + 'static {
+    /// Store a login and set it as the last login of its user, atomically.
+    ///
+    /// # Arguments
+    /// * `record` - Login to store
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn record(&self, record: &LoginRecord) -> Result<(), LoginHistoryError>;
+
+    /// Retrieve the last login time and the latest logins of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - User to look up
+    /// * `limit` - Most logins returned
+    ///
+    /// # Returns
+    /// Logins newest first, None if the user does not exist
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_history(
+        &self,
+        user_id: &UserId,
+        limit: u32,
+    ) -> Result<Option<LoginHistory>, LoginHistoryError>;
+}
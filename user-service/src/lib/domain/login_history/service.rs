@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::account_link::models::AuthMethodKind;
+use crate::domain::login_history::errors::LoginHistoryError;
+use crate::domain::login_history::models::LoginContext;
+use crate::domain::login_history::models::LoginHistory;
+use crate::domain::login_history::models::LoginRecord;
+use crate::domain::login_history::ports::LoginHistoryRepository;
+use crate::domain::login_history::ports::LoginHistoryServicePort;
+use crate::domain::user::models::UserId;
+
+/// Domain service implementation for the login history.
+///
+/// Concrete implementation of LoginHistoryServicePort with dependency injection.
+pub struct LoginHistoryService<LR>
+where
+    LR: LoginHistoryRepository,
+{
+    repository: Arc<LR>,
+}
+
+impl<LR> LoginHistoryService<LR>
+where
+    LR: LoginHistoryRepository,
+{
+    /// Create a new login history service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `repository` - Login history persistence implementation
+    ///
+    /// # Returns
+    /// Configured login history service instance
+    pub fn new(repository: Arc<LR>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<LR> LoginHistoryServicePort for LoginHistoryService<LR>
+where
+    LR: LoginHistoryRepository,
+{
+    async fn record_login(
+        &self,
+        user_id: &UserId,
+        method: AuthMethodKind,
+        context: LoginContext,
+    ) -> Result<LoginRecord, LoginHistoryError> {
+        let record = LoginRecord {
+            user_id: *user_id,
+            method,
+            logged_in_at: Utc::now(),
+            ip_address: context.ip_address,
+            user_agent: context
+                .user_agent
+                .map(|agent| {
+                    agent
+                        .chars()
+                        .take(LoginRecord::MAX_USER_AGENT_LENGTH)
+                        .collect::<String>()
+                })
+                .filter(|agent| !agent.is_empty()),
+        };
+        self.repository.record(&record).await?;
+        Ok(record)
+    }
+
+    async fn login_history(
+        &self,
+        user_id: &UserId,
+        limit: u32,
+    ) -> Result<LoginHistory, LoginHistoryError> {
+        self.repository
+            .find_history(user_id, limit)
+            .await?
+            .ok_or_else(|| LoginHistoryError::UserNotFound(user_id.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::net::IpAddr;
+    use std::net::Ipv4Addr;
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Login history of known users kept in memory
+    #[derive(Default)]
+    struct InMemoryLoginHistory {
+        logins: Mutex<HashMap<UserId, Vec<LoginRecord>>>,
+    }
+
+    impl InMemoryLoginHistory {
+        fn with_user(user_id: UserId) -> Self {
+            let history = Self::default();
+            history.logins.lock().unwrap().insert(user_id, Vec::new());
+            history
+        }
+    }
+
+    #[async_trait]
+    impl LoginHistoryRepository for InMemoryLoginHistory {
+        async fn record(&self, record: &LoginRecord) -> Result<(), LoginHistoryError> {
+            self.logins
+                .lock()
+                .unwrap()
+                .entry(record.user_id)
+                .or_default()
+                .push(record.clone());
+            Ok(())
+        }
+
+        async fn find_history(
+            &self,
+            user_id: &UserId,
+            limit: u32,
+        ) -> Result<Option<LoginHistory>, LoginHistoryError> {
+            Ok(self.logins.lock().unwrap().get(user_id).map(|logins| {
+                let logins: Vec<LoginRecord> =
+                    logins.iter().rev().take(limit as usize).cloned().collect();
+                LoginHistory {
+                    last_login_at: logins.first().map(|login| login.logged_in_at),
+                    logins,
+                }
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recorded_logins_listed_newest_first() {
+        let user_id = UserId::new();
+        let service = LoginHistoryService::new(Arc::new(InMemoryLoginHistory::with_user(user_id)));
+        let ip_address = Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)));
+
+        service
+            .record_login(&user_id, AuthMethodKind::Password, LoginContext::default())
+            .await
+            .unwrap();
+        let last = service
+            .record_login(
+                &user_id,
+                AuthMethodKind::Passkey,
+                LoginContext {
+                    ip_address,
+                    user_agent: Some("x".repeat(LoginRecord::MAX_USER_AGENT_LENGTH + 1)),
+                },
+            )
+            .await
+            .unwrap();
+
+        let history = service.login_history(&user_id, 10).await.unwrap();
+        assert_eq!(history.last_login_at, Some(last.logged_in_at));
+        assert_eq!(history.logins.len(), 2);
+        assert_eq!(history.logins[0].method, AuthMethodKind::Passkey);
+        assert_eq!(history.logins[0].ip_address, ip_address);
+        assert_eq!(
+            history.logins[0].user_agent.as_ref().map(String::len),
+            Some(LoginRecord::MAX_USER_AGENT_LENGTH)
+        );
+        assert_eq!(history.logins[1].method, AuthMethodKind::Password);
+    }
+
+    #[tokio::test]
+    async fn test_login_history_of_unknown_user() {
+        let service = LoginHistoryService::new(Arc::new(InMemoryLoginHistory::default()));
+
+        let result = service.login_history(&UserId::new(), 10).await;
+
+        assert!(matches!(result, Err(LoginHistoryError::UserNotFound(_))));
+    }
+}
@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+use crate::domain::account_link::errors::AccountLinkError;
+use crate::domain::user::errors::UserError;
+
+/// Top-level error for password-less login operations
+#[derive(Debug, Clone, Error)]
+pub enum MagicLinkError {
+    #[error("Login link is invalid or expired")]
+    InvalidLink,
+
+    #[error("Login link was already used")]
+    AlreadyUsed,
+
+    #[error("Login link was requested from another network address")]
+    ClientMismatch,
+
+    #[error("Magic link login is already linked")]
+    AlreadyLinked,
+
+    #[error("Login link could not be delivered: {0}")]
+    DeliveryFailed(String),
+
+    #[error("Token error: {0}")]
+    TokenError(String),
+
+    #[error(transparent)]
+    User(#[from] UserError),
+
+    #[error(transparent)]
+    AccountLink(#[from] AccountLinkError),
+}
@@ -0,0 +1,84 @@
+use std::net::IpAddr;
+
+use auth::TokenPair;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+
+use crate::domain::user::models::EmailAddress;
+use crate::domain::user::models::User;
+use crate::domain::user::models::Username;
+
+/// Request for a password-less login link.
+#[derive(Debug, Clone)]
+pub struct RequestMagicLinkCommand {
+    pub email: EmailAddress,
+    /// Username of the account to create if no user has this email
+    pub username: Option<Username>,
+    /// Address the request came from, which the link is bound to
+    pub client_ip: Option<IpAddr>,
+}
+
+/// Account a sign-up link creates when it is redeemed.
+///
+/// Nothing is stored until then, so requesting a link for someone else's
+/// address cannot reserve it or the username.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MagicLinkSignup {
+    pub username: Username,
+    pub email: EmailAddress,
+}
+
+impl MagicLinkSignup {
+    /// Encode the account as the subject of a sign-up link token.
+    ///
+    /// # Returns
+    /// Username and email address separated by `:`, which usernames cannot contain
+    pub fn to_subject(&self) -> String {
+        format!("{}:{}", self.username.as_str(), self.email.as_str())
+    }
+
+    /// Decode the account from the subject of a sign-up link token.
+    ///
+    /// # Arguments
+    /// * `subject` - Subject written by [`MagicLinkSignup::to_subject`]
+    ///
+    /// # Returns
+    /// The account, or None if the subject is malformed
+    pub fn from_subject(subject: &str) -> Option<Self> {
+        let (username, email) = subject.split_once(':')?;
+        Some(Self {
+            username: Username::new(username.to_string()).ok()?,
+            email: EmailAddress::new(email.to_string()).ok()?,
+        })
+    }
+}
+
+/// Settings of issued login links.
+#[derive(Debug, Clone)]
+pub struct MagicLinkSettings {
+    /// Page the link opens, with the token appended as `token` query parameter
+    pub base_url: String,
+    /// Page a link confirming the email address for magic link login opens
+    pub verification_url: String,
+    /// Time until a link expires
+    pub ttl: Duration,
+    /// Only accept a link from the address it was requested from
+    pub bind_ip: bool,
+    /// Lifetime of access tokens issued on login
+    pub access_token_hours: i64,
+}
+
+/// Login link to deliver to a user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MagicLink {
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Session started by redeeming a login link.
+#[derive(Debug, Clone)]
+pub struct MagicLinkLogin {
+    pub user: User,
+    pub tokens: TokenPair,
+}
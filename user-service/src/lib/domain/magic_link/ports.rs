@@ -0,0 +1,145 @@
+use std::net::IpAddr;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::account_link::models::AccountLink;
+use crate::domain::magic_link::errors::MagicLinkError;
+use crate::domain::magic_link::models::MagicLink;
+use crate::domain::magic_link::models::MagicLinkLogin;
+use crate::domain::magic_link::models::MagicLinkSignup;
+use crate::domain::magic_link::models::RequestMagicLinkCommand;
+use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
+
+/// Port for password-less login by emailed link.
+#[async_trait]
+pub trait MagicLinkServicePort: Send + Sync + 'static {
+    /// Email a single-use login link to the owner of an address.
+    ///
+    /// Unknown addresses, and accounts without magic link login linked,
+    /// succeed without sending anything; with a username an unknown address
+    /// is sent a sign-up link instead, which creates the account once
+    /// redeemed. The response therefore does not reveal which addresses are
+    /// registered.
+    ///
+    /// # Arguments
+    /// * `command` - Email address, optional sign-up username and client address
+    ///
+    /// # Returns
+    /// Expiration of the link
+    ///
+    /// # Errors
+    /// * `User` - Lookup failed or the sign-up username is taken
+    /// * `TokenError` - Link token could not be issued
+    /// * `DeliveryFailed` - Email could not be sent
+    async fn request_link(
+        &self,
+        command: RequestMagicLinkCommand,
+    ) -> Result<DateTime<Utc>, MagicLinkError>;
+
+    /// Exchange a login link token for an access and refresh token pair.
+    ///
+    /// A sign-up link creates its account first, with magic link login as
+    /// its only login method.
+    ///
+    /// # Arguments
+    /// * `token` - Token of the login link
+    /// * `client_ip` - Address of the client redeeming the link
+    ///
+    /// # Returns
+    /// Logged-in user with the issued token pair
+    ///
+    /// # Errors
+    /// * `InvalidLink` - Token is invalid, expired, or its user no longer exists
+    ///   or unlinked magic link login
+    /// * `AlreadyUsed` - Link was already redeemed
+    /// * `ClientMismatch` - Link is bound to another address
+    /// * `User` - Sign-up failed (e.g. the address or username was taken since)
+    /// * `TokenError` - Token pair could not be issued
+    async fn redeem_link(
+        &self,
+        token: &str,
+        client_ip: Option<IpAddr>,
+    ) -> Result<MagicLinkLogin, MagicLinkError>;
+
+    /// Email a link confirming the user's address to link magic link login.
+    ///
+    /// # Arguments
+    /// * `user_id` - Logged-in user linking magic link login
+    ///
+    /// # Returns
+    /// Expiration of the link
+    ///
+    /// # Errors
+    /// * `AlreadyLinked` - Magic link login is already linked
+    /// * `User` - User does not exist
+    /// * `TokenError` - Link token could not be issued
+    /// * `DeliveryFailed` - Email could not be sent
+    async fn request_link_verification(
+        &self,
+        user_id: &UserId,
+    ) -> Result<DateTime<Utc>, MagicLinkError>;
+
+    /// Link magic link login with the token of a confirmation link.
+    ///
+    /// # Arguments
+    /// * `user_id` - Logged-in user the link was sent to
+    /// * `token` - Token of the confirmation link
+    ///
+    /// # Returns
+    /// The new account link
+    ///
+    /// # Errors
+    /// * `InvalidLink` - Token is invalid, expired, or was sent to another user
+    /// * `AlreadyUsed` - Link was already redeemed
+    /// * `AccountLink` - Link could not be stored
+    async fn verify_link(
+        &self,
+        user_id: &UserId,
+        token: &str,
+    ) -> Result<AccountLink, MagicLinkError>;
+}
+
+/// Port delivering login links to users.
+#[async_trait]
+pub trait LoginLinkSender: Send + Sync + 'static {
+    /// Send a login link to the user's email address.
+    ///
+    /// # Arguments
+    /// * `user` - Recipient
+    /// * `link` - Login link and its expiration
+    ///
+    /// # Errors
+    /// * `DeliveryFailed` - Email could not be sent
+    async fn send_login_link(&self, user: &User, link: &MagicLink) -> Result<(), MagicLinkError>;
+
+    /// Send a link creating an account to the address it is for.
+    ///
+    /// # Arguments
+    /// * `signup` - Account to create, with the recipient address
+    /// * `link` - Sign-up link and its expiration
+    ///
+    /// # Errors
+    /// * `DeliveryFailed` - Email could not be sent
+    async fn send_signup_link(
+        &self,
+        signup: &MagicLinkSignup,
+        link: &MagicLink,
+    ) -> Result<(), MagicLinkError>;
+
+    /// Send a link confirming the user's email address for magic link login.
+    ///
+    /// # Arguments
+    /// * `user` - Recipient
+    /// * `link` - Confirmation link and its expiration
+    ///
+    /// # Errors
+    /// * `DeliveryFailed` - Email could not be sent
+    async fn send_link_verification(
+        &self,
+        user: &User,
+        link: &MagicLink,
+    ) -> Result<(), MagicLinkError>;
+}
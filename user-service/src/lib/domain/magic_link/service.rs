@@ -0,0 +1,756 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use auth::Authenticator;
+use auth::OneTimeToken;
+use auth::OneTimeTokenError;
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::account_link::models::AccountLink;
+use crate::domain::account_link::models::AuthMethodId;
+use crate::domain::account_link::ports::AccountLinkRepository;
+use crate::domain::magic_link::errors::MagicLinkError;
+use crate::domain::magic_link::models::MagicLink;
+use crate::domain::magic_link::models::MagicLinkLogin;
+use crate::domain::magic_link::models::MagicLinkSettings;
+use crate::domain::magic_link::models::MagicLinkSignup;
+use crate::domain::magic_link::models::RequestMagicLinkCommand;
+use crate::domain::magic_link::ports::LoginLinkSender;
+use crate::domain::magic_link::ports::MagicLinkServicePort;
+use crate::domain::user::errors::UserError;
+use crate::domain::user::models::ImportUserCommand;
+use crate::domain::user::models::Role;
+use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserServicePort;
+
+/// Who a requested link is sent to.
+enum Recipient {
+    /// Existing account with magic link login linked
+    User(User),
+    /// Unknown address, with the account its link creates
+    SignUp(MagicLinkSignup),
+}
+
+/// Domain service implementation for password-less login.
+///
+/// Concrete implementation of MagicLinkServicePort with dependency injection.
+pub struct MagicLinkService<US, LS, AR>
+where
+    US: UserServicePort,
+    LS: LoginLinkSender,
+    AR: AccountLinkRepository,
+{
+    user_service: Arc<US>,
+    sender: Arc<LS>,
+    account_links: Arc<AR>,
+    authenticator: Arc<Authenticator>,
+    settings: MagicLinkSettings,
+}
+
+impl<US, LS, AR> MagicLinkService<US, LS, AR>
+where
+    US: UserServicePort,
+    LS: LoginLinkSender,
+    AR: AccountLinkRepository,
+{
+    /// Create a new magic link service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `user_service` - Service looking up and signing up users
+    /// * `sender` - Delivery of login links
+    /// * `account_links` - Accounts with magic link login linked
+    /// * `authenticator` - Issuer of link and session tokens
+    /// * `settings` - Link expiration, IP binding and session lifetime
+    ///
+    /// # Returns
+    /// Configured magic link service instance
+    pub fn new(
+        user_service: Arc<US>,
+        sender: Arc<LS>,
+        account_links: Arc<AR>,
+        authenticator: Arc<Authenticator>,
+        settings: MagicLinkSettings,
+    ) -> Self {
+        Self {
+            user_service,
+            sender,
+            account_links,
+            authenticator,
+            settings,
+        }
+    }
+
+    async fn find_recipient(
+        &self,
+        command: RequestMagicLinkCommand,
+    ) -> Result<Option<Recipient>, MagicLinkError> {
+        match self.user_service.get_user_by_email(&command.email).await {
+            Ok(user) => {
+                if self.is_linked(&user.id).await? {
+                    Ok(Some(Recipient::User(user)))
+                } else {
+                    tracing::debug!(user_id = %user.id, "Magic link login is not linked");
+                    Ok(None)
+                }
+            }
+            Err(UserError::NotFoundByEmail(_)) => match command.username {
+                Some(username) => {
+                    // Checked again on redemption, but a taken name is better reported now
+                    match self.user_service.get_user_by_username(&username).await {
+                        Ok(_) => Err(UserError::UsernameAlreadyExists(
+                            username.as_str().to_string(),
+                        )
+                        .into()),
+                        Err(UserError::NotFoundByUsername(_)) => {
+                            Ok(Some(Recipient::SignUp(MagicLinkSignup {
+                                username,
+                                email: command.email,
+                            })))
+                        }
+                        Err(e) => Err(e.into()),
+                    }
+                }
+                None => Ok(None),
+            },
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn sign_up(&self, signup: MagicLinkSignup) -> Result<User, MagicLinkError> {
+        let user = self
+            .user_service
+            .import_user(ImportUserCommand {
+                username: signup.username,
+                email: signup.email,
+                password_hash: None,
+            })
+            .await?;
+        // Nobody knows the generated password, so the link is the account's
+        // only login method
+        self.account_links
+            .link(&AccountLink {
+                user_id: user.id,
+                method: AuthMethodId::MagicLink,
+                linked_at: Utc::now(),
+            })
+            .await?;
+        self.account_links
+            .unlink(&user.id, &AuthMethodId::Password)
+            .await?;
+
+        tracing::info!(user_id = %user.id, "User signed up by login link");
+        Ok(user)
+    }
+
+    async fn linked_user(&self, subject: &str) -> Result<User, MagicLinkError> {
+        let user_id = UserId::from_string(subject).map_err(|_| MagicLinkError::InvalidLink)?;
+        let user = self
+            .user_service
+            .get_user(&user_id)
+            .await
+            .map_err(|e| match e {
+                UserError::NotFound(_) => MagicLinkError::InvalidLink,
+                e => e.into(),
+            })?;
+        // Magic link login may have been removed since the link was sent
+        if !self.is_linked(&user.id).await? {
+            return Err(MagicLinkError::InvalidLink);
+        }
+        Ok(user)
+    }
+
+    fn issue_link_token(
+        &self,
+        subject: &str,
+        purpose: &str,
+        client_ip: Option<IpAddr>,
+    ) -> Result<String, MagicLinkError> {
+        match client_ip.filter(|_| self.settings.bind_ip) {
+            Some(ip) => self.authenticator.issue_bound_one_time_token(
+                subject,
+                purpose,
+                self.settings.ttl,
+                &ip.to_string(),
+            ),
+            None => self
+                .authenticator
+                .issue_one_time_token(subject, purpose, self.settings.ttl),
+        }
+        .map_err(|e| MagicLinkError::TokenError(e.to_string()))
+    }
+
+    async fn redeem_link_token(
+        &self,
+        token: &str,
+        purpose: &str,
+        client_ip: Option<IpAddr>,
+    ) -> Result<OneTimeToken, OneTimeTokenError> {
+        match client_ip {
+            Some(ip) => {
+                self.authenticator
+                    .redeem_bound_one_time_token(token, purpose, &ip.to_string())
+                    .await
+            }
+            None => {
+                self.authenticator
+                    .redeem_one_time_token(token, purpose)
+                    .await
+            }
+        }
+    }
+
+    fn link_error(e: OneTimeTokenError) -> MagicLinkError {
+        match e {
+            OneTimeTokenError::AlreadyUsed => MagicLinkError::AlreadyUsed,
+            OneTimeTokenError::BindingMismatch => MagicLinkError::ClientMismatch,
+            OneTimeTokenError::WrongPurpose { .. } | OneTimeTokenError::JwtError(_) => {
+                MagicLinkError::InvalidLink
+            }
+            OneTimeTokenError::StoreError(e) => MagicLinkError::TokenError(e),
+        }
+    }
+
+    async fn is_linked(&self, user_id: &UserId) -> Result<bool, MagicLinkError> {
+        Ok(self
+            .account_links
+            .is_linked(user_id, &AuthMethodId::MagicLink)
+            .await?)
+    }
+
+    fn link_url(base_url: &str, token: &str) -> String {
+        let separator = if base_url.contains('?') { '&' } else { '?' };
+        format!("{}{}token={}", base_url, separator, token)
+    }
+}
+
+#[async_trait]
+impl<US, LS, AR> MagicLinkServicePort for MagicLinkService<US, LS, AR>
+where
+    US: UserServicePort,
+    LS: LoginLinkSender,
+    AR: AccountLinkRepository,
+{
+    async fn request_link(
+        &self,
+        command: RequestMagicLinkCommand,
+    ) -> Result<DateTime<Utc>, MagicLinkError> {
+        let expires_at = Utc::now() + self.settings.ttl;
+        let client_ip = command.client_ip;
+
+        match self.find_recipient(command).await? {
+            Some(Recipient::User(user)) => {
+                let token = self.issue_link_token(
+                    &user.id.to_string(),
+                    OneTimeToken::MAGIC_LINK,
+                    client_ip,
+                )?;
+                let link = MagicLink {
+                    url: Self::link_url(&self.settings.base_url, &token),
+                    expires_at,
+                };
+                self.sender.send_login_link(&user, &link).await?;
+                tracing::info!(user_id = %user.id, "Login link sent");
+            }
+            Some(Recipient::SignUp(signup)) => {
+                let token = self.issue_link_token(
+                    &signup.to_subject(),
+                    OneTimeToken::MAGIC_LINK_SIGNUP,
+                    client_ip,
+                )?;
+                let link = MagicLink {
+                    url: Self::link_url(&self.settings.base_url, &token),
+                    expires_at,
+                };
+                self.sender.send_signup_link(&signup, &link).await?;
+                tracing::info!("Sign-up link sent");
+            }
+            None => tracing::debug!("Login link requested for unknown email"),
+        }
+
+        Ok(expires_at)
+    }
+
+    async fn redeem_link(
+        &self,
+        token: &str,
+        client_ip: Option<IpAddr>,
+    ) -> Result<MagicLinkLogin, MagicLinkError> {
+        let user = match self
+            .redeem_link_token(token, OneTimeToken::MAGIC_LINK, client_ip)
+            .await
+        {
+            Ok(redeemed) => self.linked_user(&redeemed.subject).await?,
+            Err(OneTimeTokenError::WrongPurpose { actual, .. })
+                if actual == OneTimeToken::MAGIC_LINK_SIGNUP =>
+            {
+                let redeemed = self
+                    .redeem_link_token(token, OneTimeToken::MAGIC_LINK_SIGNUP, client_ip)
+                    .await
+                    .map_err(Self::link_error)?;
+                let signup = MagicLinkSignup::from_subject(&redeemed.subject)
+                    .ok_or(MagicLinkError::InvalidLink)?;
+                self.sign_up(signup).await?
+            }
+            Err(e) => return Err(Self::link_error(e)),
+        };
+
+        let claims = auth::Claims::for_user(
+            user.id,
+            user.username.as_str().to_string(),
+            self.settings.access_token_hours,
+        )
+        .with_roles(user.roles.iter().map(Role::as_str));
+        let tokens = self
+            .authenticator
+            .issue_token_pair(&claims)
+            .await
+            .map_err(|e| MagicLinkError::TokenError(e.to_string()))?;
+
+        tracing::info!(user_id = %user.id, "User logged in by login link");
+        Ok(MagicLinkLogin { user, tokens })
+    }
+
+    async fn request_link_verification(
+        &self,
+        user_id: &UserId,
+    ) -> Result<DateTime<Utc>, MagicLinkError> {
+        if self.is_linked(user_id).await? {
+            return Err(MagicLinkError::AlreadyLinked);
+        }
+        let user = self.user_service.get_user(user_id).await?;
+
+        let token = self
+            .authenticator
+            .issue_one_time_token(
+                &user.id.to_string(),
+                OneTimeToken::ACCOUNT_LINK,
+                self.settings.ttl,
+            )
+            .map_err(|e| MagicLinkError::TokenError(e.to_string()))?;
+
+        let link = MagicLink {
+            url: Self::link_url(&self.settings.verification_url, &token),
+            expires_at: Utc::now() + self.settings.ttl,
+        };
+        self.sender.send_link_verification(&user, &link).await?;
+
+        tracing::info!(user_id = %user.id, "Magic link login confirmation sent");
+        Ok(link.expires_at)
+    }
+
+    async fn verify_link(
+        &self,
+        user_id: &UserId,
+        token: &str,
+    ) -> Result<AccountLink, MagicLinkError> {
+        let redeemed = self
+            .authenticator
+            .redeem_one_time_token(token, OneTimeToken::ACCOUNT_LINK)
+            .await
+            .map_err(|e| match e {
+                OneTimeTokenError::AlreadyUsed => MagicLinkError::AlreadyUsed,
+                OneTimeTokenError::StoreError(e) => MagicLinkError::TokenError(e),
+                _ => MagicLinkError::InvalidLink,
+            })?;
+        if redeemed.subject != user_id.to_string() {
+            return Err(MagicLinkError::InvalidLink);
+        }
+
+        let link = AccountLink {
+            user_id: *user_id,
+            method: AuthMethodId::MagicLink,
+            linked_at: Utc::now(),
+        };
+        if !self.account_links.link(&link).await? {
+            return Err(MagicLinkError::AlreadyLinked);
+        }
+
+        tracing::info!(user_id = %user_id, "Magic link login linked");
+        Ok(link)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use auth::SecretString;
+    use chrono::Duration;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::account_link::errors::AccountLinkError;
+    use crate::domain::magic_link::models::MagicLink;
+    use crate::domain::user::models::CreateUserCommand;
+    use crate::domain::user::models::EmailAddress;
+    use crate::domain::user::models::LoginIdentifier;
+    use crate::domain::user::models::UpdateUserCommand;
+    use crate::domain::user::models::UserCursor;
+    use crate::domain::user::models::UserPage;
+    use crate::domain::user::models::UserSearchQuery;
+    use crate::domain::user::models::UserStatus;
+    use crate::domain::user::models::Username;
+
+    mock! {
+        pub TestUserService {}
+
+        #[async_trait]
+        impl UserServicePort for TestUserService {
+            async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
+            async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+            async fn get_user(&self, id: &UserId) -> Result<User, UserError>;
+            async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
+            async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+            async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
+            async fn list_users(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<UserPage, UserError>;
+            async fn search_users(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
+            async fn update_user(&self, id: &UserId, command: UpdateUserCommand) -> Result<User, UserError>;
+            async fn change_password(&self, id: &UserId, current_password: SecretString, new_password: SecretString) -> Result<(), UserError>;
+            async fn reset_password(&self, id: &UserId, new_password: SecretString) -> Result<(), UserError>;
+            async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+            async fn replace_password_hash(&self, id: &UserId, password_hash: String) -> Result<(), UserError>;
+            async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    mock! {
+        pub TestAccountLinkRepository {}
+
+        #[async_trait]
+        impl AccountLinkRepository for TestAccountLinkRepository {
+            async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<AccountLink>, AccountLinkError>;
+            async fn link(&self, link: &AccountLink) -> Result<bool, AccountLinkError>;
+            async fn unlink(&self, user_id: &UserId, method: &AuthMethodId) -> Result<bool, AccountLinkError>;
+            async fn is_linked(&self, user_id: &UserId, method: &AuthMethodId) -> Result<bool, AccountLinkError>;
+        }
+    }
+
+    /// Sender keeping the last link instead of emailing it
+    #[derive(Default)]
+    struct CapturingSender {
+        links: Mutex<Vec<MagicLink>>,
+    }
+
+    #[async_trait]
+    impl LoginLinkSender for CapturingSender {
+        async fn send_login_link(
+            &self,
+            _user: &User,
+            link: &MagicLink,
+        ) -> Result<(), MagicLinkError> {
+            self.links.lock().unwrap().push(link.clone());
+            Ok(())
+        }
+
+        async fn send_signup_link(
+            &self,
+            _signup: &MagicLinkSignup,
+            link: &MagicLink,
+        ) -> Result<(), MagicLinkError> {
+            self.links.lock().unwrap().push(link.clone());
+            Ok(())
+        }
+
+        async fn send_link_verification(
+            &self,
+            user: &User,
+            link: &MagicLink,
+        ) -> Result<(), MagicLinkError> {
+            self.send_login_link(user, link).await
+        }
+    }
+
+    fn linked(is_linked: bool) -> MockTestAccountLinkRepository {
+        let mut account_links = MockTestAccountLinkRepository::new();
+        account_links
+            .expect_is_linked()
+            .returning(move |_, _| Ok(is_linked));
+        account_links
+    }
+
+    fn alice() -> User {
+        User {
+            id: UserId::new(),
+            username: Username::new("alice".to_string()).unwrap(),
+            email: EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            password_hash: "$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn service(
+        user_service: MockTestUserService,
+        sender: Arc<CapturingSender>,
+        account_links: MockTestAccountLinkRepository,
+    ) -> MagicLinkService<MockTestUserService, CapturingSender, MockTestAccountLinkRepository> {
+        MagicLinkService::new(
+            Arc::new(user_service),
+            sender,
+            Arc::new(account_links),
+            Arc::new(Authenticator::new(b"test_secret_key_at_least_32_bytes!")),
+            MagicLinkSettings {
+                base_url: "https://chat.example.com/login/magic".to_string(),
+                verification_url: "https://chat.example.com/account/magic-link".to_string(),
+                ttl: Duration::minutes(15),
+                bind_ip: true,
+                access_token_hours: 1,
+            },
+        )
+    }
+
+    fn command(client_ip: &str) -> RequestMagicLinkCommand {
+        RequestMagicLinkCommand {
+            email: EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            username: None,
+            client_ip: Some(client_ip.parse().unwrap()),
+        }
+    }
+
+    fn token_of(link: &MagicLink) -> String {
+        link.url
+            .split_once("?token=")
+            .map(|(_, token)| token.to_string())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_request_and_redeem_link_once() {
+        let user = alice();
+        let user_id = user.id;
+
+        let mut user_service = MockTestUserService::new();
+        let found = user.clone();
+        user_service
+            .expect_get_user_by_email()
+            .times(1)
+            .returning(move |_| Ok(found.clone()));
+        user_service
+            .expect_get_user()
+            .withf(move |id| *id == user_id)
+            .times(1)
+            .returning(move |_| Ok(user.clone()));
+
+        let sender = Arc::new(CapturingSender::default());
+        let service = service(user_service, Arc::clone(&sender), linked(true));
+
+        service.request_link(command("203.0.113.7")).await.unwrap();
+        let token = token_of(&sender.links.lock().unwrap()[0]);
+
+        let login = service
+            .redeem_link(&token, Some("203.0.113.7".parse().unwrap()))
+            .await
+            .unwrap();
+        assert_eq!(login.user.id, user_id);
+        assert!(!login.tokens.refresh_token.is_empty());
+
+        let again = service
+            .redeem_link(&token, Some("203.0.113.7".parse().unwrap()))
+            .await;
+        assert!(matches!(again, Err(MagicLinkError::AlreadyUsed)));
+    }
+
+    #[tokio::test]
+    async fn test_redeem_link_from_other_address_is_rejected() {
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user_by_email()
+            .returning(|_| Ok(alice()));
+        user_service.expect_get_user().never();
+
+        let sender = Arc::new(CapturingSender::default());
+        let service = service(user_service, Arc::clone(&sender), linked(true));
+
+        service.request_link(command("203.0.113.7")).await.unwrap();
+        let token = token_of(&sender.links.lock().unwrap()[0]);
+
+        let result = service
+            .redeem_link(&token, Some("198.51.100.1".parse().unwrap()))
+            .await;
+        assert!(matches!(result, Err(MagicLinkError::ClientMismatch)));
+    }
+
+    #[tokio::test]
+    async fn test_request_link_for_unknown_email() {
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user_by_email()
+            .times(2)
+            .returning(|email| Err(UserError::NotFoundByEmail(email.as_str().to_string())));
+        user_service
+            .expect_get_user_by_username()
+            .times(1)
+            .returning(|username| {
+                Err(UserError::NotFoundByUsername(username.as_str().to_string()))
+            });
+        user_service.expect_import_user().never();
+
+        let sender = Arc::new(CapturingSender::default());
+        let service = service(
+            user_service,
+            Arc::clone(&sender),
+            MockTestAccountLinkRepository::new(),
+        );
+
+        // Without a username nothing is sent, but the caller cannot tell
+        assert!(service.request_link(command("203.0.113.7")).await.is_ok());
+        assert!(sender.links.lock().unwrap().is_empty());
+
+        // With a username a sign-up link is sent, but no account created yet
+        let sign_up = RequestMagicLinkCommand {
+            username: Some(Username::new("alice".to_string()).unwrap()),
+            ..command("203.0.113.7")
+        };
+        assert!(service.request_link(sign_up).await.is_ok());
+        assert_eq!(sender.links.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_redeem_signup_link_creates_account() {
+        let user = alice();
+        let user_id = user.id;
+
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user_by_email()
+            .returning(|email| Err(UserError::NotFoundByEmail(email.as_str().to_string())));
+        user_service
+            .expect_get_user_by_username()
+            .returning(|username| {
+                Err(UserError::NotFoundByUsername(username.as_str().to_string()))
+            });
+        user_service
+            .expect_import_user()
+            .withf(|command| {
+                command.username.as_str() == "alice"
+                    && command.email.as_str() == "alice@example.com"
+                    && command.password_hash.is_none()
+            })
+            .times(1)
+            .returning(move |_| Ok(user.clone()));
+        let mut account_links = MockTestAccountLinkRepository::new();
+        account_links
+            .expect_link()
+            .withf(move |link| link.user_id == user_id && link.method == AuthMethodId::MagicLink)
+            .times(1)
+            .returning(|_| Ok(true));
+        account_links
+            .expect_unlink()
+            .withf(|_, method| *method == AuthMethodId::Password)
+            .times(1)
+            .returning(|_, _| Ok(true));
+
+        let sender = Arc::new(CapturingSender::default());
+        let service = service(user_service, Arc::clone(&sender), account_links);
+
+        let sign_up = RequestMagicLinkCommand {
+            username: Some(Username::new("alice".to_string()).unwrap()),
+            ..command("203.0.113.7")
+        };
+        service.request_link(sign_up).await.unwrap();
+        let token = token_of(&sender.links.lock().unwrap()[0]);
+
+        let result = service
+            .redeem_link(&token, Some("198.51.100.1".parse().unwrap()))
+            .await;
+        assert!(matches!(result, Err(MagicLinkError::ClientMismatch)));
+
+        let login = service
+            .redeem_link(&token, Some("203.0.113.7".parse().unwrap()))
+            .await
+            .unwrap();
+        assert_eq!(login.user.id, user_id);
+
+        let again = service
+            .redeem_link(&token, Some("203.0.113.7".parse().unwrap()))
+            .await;
+        assert!(matches!(again, Err(MagicLinkError::AlreadyUsed)));
+    }
+
+    #[tokio::test]
+    async fn test_request_signup_link_with_taken_username() {
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user_by_email()
+            .returning(|email| Err(UserError::NotFoundByEmail(email.as_str().to_string())));
+        user_service
+            .expect_get_user_by_username()
+            .returning(|_| Ok(alice()));
+
+        let sender = Arc::new(CapturingSender::default());
+        let service = service(
+            user_service,
+            Arc::clone(&sender),
+            MockTestAccountLinkRepository::new(),
+        );
+
+        let sign_up = RequestMagicLinkCommand {
+            email: EmailAddress::new("other@example.com".to_string()).unwrap(),
+            username: Some(Username::new("alice".to_string()).unwrap()),
+            ..command("203.0.113.7")
+        };
+        let result = service.request_link(sign_up).await;
+        assert!(matches!(
+            result,
+            Err(MagicLinkError::User(UserError::UsernameAlreadyExists(_)))
+        ));
+        assert!(sender.links.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_request_link_without_magic_link_linked() {
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user_by_email()
+            .returning(|_| Ok(alice()));
+
+        let sender = Arc::new(CapturingSender::default());
+        let service = service(user_service, Arc::clone(&sender), linked(false));
+
+        assert!(service.request_link(command("203.0.113.7")).await.is_ok());
+        assert!(sender.links.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_verify_link_for_own_account_only() {
+        let user = alice();
+        let user_id = user.id;
+
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user()
+            .returning(move |_| Ok(user.clone()));
+        let mut account_links = linked(false);
+        account_links
+            .expect_link()
+            .withf(move |link| link.user_id == user_id && link.method == AuthMethodId::MagicLink)
+            .times(1)
+            .returning(|_| Ok(true));
+
+        let sender = Arc::new(CapturingSender::default());
+        let service = service(user_service, Arc::clone(&sender), account_links);
+
+        service.request_link_verification(&user_id).await.unwrap();
+        service.request_link_verification(&user_id).await.unwrap();
+        let first = token_of(&sender.links.lock().unwrap()[0]);
+        let second = token_of(&sender.links.lock().unwrap()[1]);
+
+        let result = service.verify_link(&UserId::new(), &first).await;
+        assert!(matches!(result, Err(MagicLinkError::InvalidLink)));
+
+        let link = service.verify_link(&user_id, &second).await.unwrap();
+        assert_eq!(link.method, AuthMethodId::MagicLink);
+    }
+}
@@ -1 +1,19 @@
+pub mod account_link;
+pub mod audit;
+pub mod avatar;
+pub mod email;
+pub mod email_verification;
+pub mod event_replay;
+pub mod idempotency;
+pub mod import;
+pub mod job;
+pub mod lockout;
+pub mod login_history;
+pub mod magic_link;
+pub mod outbox;
+pub mod passkey;
+pub mod password_reset;
+pub mod personal_token;
+pub mod signup;
+pub mod storage;
 pub mod user;
@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+/// Top-level error for outbox operations
+#[derive(Debug, Clone, Error)]
+pub enum OutboxError {
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
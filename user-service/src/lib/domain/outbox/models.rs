@@ -0,0 +1,46 @@
+use chrono::Duration;
+
+/// Event stored in the outbox, waiting to be published.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxMessage {
+    /// Position of the message in the outbox, increasing in commit order
+    pub sequence: i64,
+    /// Partition key, the ID of the user the event is about
+    pub key: String,
+    /// Serialized event
+    pub payload: String,
+    /// Failed publish attempts so far
+    pub attempts: u32,
+}
+
+/// Settings of the outbox relay.
+#[derive(Debug, Clone)]
+pub struct OutboxSettings {
+    /// Messages claimed per round
+    pub batch_size: u32,
+    /// Pause between rounds once the outbox is drained
+    pub poll_interval: std::time::Duration,
+    /// Time a claimed message is hidden from other relays while it is published
+    pub claim_timeout: Duration,
+    /// Delay before the first retry of a message, doubled on each further failure
+    pub retry_base_delay: Duration,
+    /// Longest delay between retries
+    pub retry_max_delay: Duration,
+}
+
+impl OutboxSettings {
+    /// Compute the delay before the next attempt to publish a message.
+    ///
+    /// # Arguments
+    /// * `attempts` - Failed attempts so far, including the last one
+    ///
+    /// # Returns
+    /// Exponential backoff from `retry_base_delay`, capped at `retry_max_delay`
+    pub fn retry_delay(&self, attempts: u32) -> Duration {
+        let factor = 2_i32.saturating_pow(attempts.saturating_sub(1).min(30));
+        self.retry_base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.retry_max_delay)
+            .min(self.retry_max_delay)
+    }
+}
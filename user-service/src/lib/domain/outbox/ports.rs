@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::outbox::errors::OutboxError;
+use crate::domain::outbox::models::OutboxMessage;
+use crate::domain::user::errors::EventPublisherError;
+
+/// Persistence operations for the event outbox.
+///
+/// Events are added by the repositories of the changes they describe, in the
+/// same transaction; this port only drains them.
+#[async_trait]
+pub trait OutboxRepository: Send + Sync + 'static {
+    /// Claim the oldest messages due for publishing.
+    ///
+    /// Only the oldest message of each key is claimed, so the messages of a
+    /// key are published in order. Claimed messages are not due again until
+    /// `claimed_until`, which keeps concurrent relays from claiming them.
+    ///
+    /// # Arguments
+    /// * `now` - Current time
+    /// * `claimed_until` - End of the claim
+    /// * `limit` - Maximum number of messages to claim
+    ///
+    /// # Returns
+    /// Claimed messages, oldest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        claimed_until: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<OutboxMessage>, OutboxError>;
+
+    /// Remove a published message.
+    ///
+    /// # Arguments
+    /// * `sequence` - Message to remove
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn remove(&self, sequence: i64) -> Result<(), OutboxError>;
+
+    /// Record a failed attempt to publish a message.
+    ///
+    /// # Arguments
+    /// * `sequence` - Message that could not be published
+    /// * `attempts` - Failed attempts so far
+    /// * `retry_at` - Time the message is due again
+    /// * `error` - Reason of the failure
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn schedule_retry(
+        &self,
+        sequence: i64,
+        attempts: u32,
+        retry_at: DateTime<Utc>,
+        error: &str,
+    ) -> Result<(), OutboxError>;
+}
+
+/// Publishing of serialized outbox messages to the event broker.
+#[async_trait]
+pub trait MessagePublisher: Send + Sync + 'static {
+    /// Publish a message.
+    ///
+    /// # Arguments
+    /// * `key` - Partition key of the message
+    /// * `payload` - Serialized event
+    ///
+    /// # Errors
+    /// * `PublishFailed` - Failed to publish to broker
+    /// * `ConnectionFailed` - Broker connection failed
+    /// * `Timeout` - Publishing timed out
+    async fn publish(&self, key: &str, payload: &str) -> Result<(), EventPublisherError>;
+}
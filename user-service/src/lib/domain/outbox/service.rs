@@ -0,0 +1,285 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::outbox::errors::OutboxError;
+use crate::domain::outbox::models::OutboxSettings;
+use crate::domain::outbox::ports::MessagePublisher;
+use crate::domain::outbox::ports::OutboxRepository;
+
+/// Background relay publishing the events of the outbox to the event broker.
+///
+/// Messages are removed once published and retried with exponential backoff
+/// otherwise, so every committed event is published at least once.
+pub struct OutboxRelay<OR, MP>
+where
+    OR: OutboxRepository,
+    MP: MessagePublisher,
+{
+    repository: Arc<OR>,
+    publisher: Arc<MP>,
+    settings: OutboxSettings,
+}
+
+impl<OR, MP> OutboxRelay<OR, MP>
+where
+    OR: OutboxRepository,
+    MP: MessagePublisher,
+{
+    /// Create a new outbox relay with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `repository` - Outbox persistence implementation
+    /// * `publisher` - Publisher of the relayed messages
+    /// * `settings` - Batch size, polling and retry settings
+    ///
+    /// # Returns
+    /// Configured outbox relay instance
+    pub fn new(repository: Arc<OR>, publisher: Arc<MP>, settings: OutboxSettings) -> Self {
+        Self {
+            repository,
+            publisher,
+            settings,
+        }
+    }
+
+    /// Publish one batch of due messages.
+    ///
+    /// # Returns
+    /// Number of messages published
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Messages could not be claimed or updated
+    pub async fn relay_due(&self) -> Result<usize, OutboxError> {
+        let now = Utc::now();
+        let messages = self
+            .repository
+            .claim_due(
+                now,
+                now + self.settings.claim_timeout,
+                self.settings.batch_size,
+            )
+            .await?;
+
+        let mut published = 0;
+        for message in messages {
+            match self.publisher.publish(&message.key, &message.payload).await {
+                Ok(()) => {
+                    self.repository.remove(message.sequence).await?;
+                    published += 1;
+                }
+                Err(e) => {
+                    let attempts = message.attempts + 1;
+                    let retry_at = Utc::now() + self.settings.retry_delay(attempts);
+                    tracing::warn!(
+                        sequence = message.sequence,
+                        attempts,
+                        retry_at = %retry_at,
+                        "Failed to relay outbox message: {}",
+                        e
+                    );
+                    self.repository
+                        .schedule_retry(message.sequence, attempts, retry_at, &e.to_string())
+                        .await?;
+                }
+            }
+        }
+
+        Ok(published)
+    }
+
+    /// Relay messages until the process exits.
+    ///
+    /// Batches follow each other while messages get published, then the
+    /// relay waits `poll_interval` for new ones.
+    pub async fn run(&self) {
+        loop {
+            match self.relay_due().await {
+                Ok(0) => {}
+                Ok(published) => {
+                    tracing::debug!(published, "Relayed outbox messages");
+                    continue;
+                }
+                Err(e) => tracing::error!("Failed to relay outbox messages: {}", e),
+            }
+            tokio::time::sleep(self.settings.poll_interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use chrono::Duration;
+
+    use super::*;
+    use crate::domain::outbox::models::OutboxMessage;
+    use crate::domain::user::errors::EventPublisherError;
+
+    /// Outbox keeping messages in memory, with their next attempt time
+    #[derive(Default)]
+    struct InMemoryOutbox {
+        messages: Mutex<Vec<(OutboxMessage, DateTime<Utc>)>>,
+    }
+
+    impl InMemoryOutbox {
+        fn with_messages(keys: &[&str]) -> Self {
+            let messages = keys
+                .iter()
+                .enumerate()
+                .map(|(i, key)| {
+                    let message = OutboxMessage {
+                        sequence: i as i64 + 1,
+                        key: key.to_string(),
+                        payload: format!("{{\"n\":{}}}", i + 1),
+                        attempts: 0,
+                    };
+                    (message, Utc::now() - Duration::seconds(1))
+                })
+                .collect();
+            Self {
+                messages: Mutex::new(messages),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OutboxRepository for InMemoryOutbox {
+        async fn claim_due(
+            &self,
+            now: DateTime<Utc>,
+            claimed_until: DateTime<Utc>,
+            limit: u32,
+        ) -> Result<Vec<OutboxMessage>, OutboxError> {
+            let mut messages = self.messages.lock().unwrap();
+            let mut seen_keys = Vec::new();
+            let mut claimed = Vec::new();
+            for (message, due_at) in messages.iter_mut() {
+                let is_head = !seen_keys.contains(&message.key);
+                seen_keys.push(message.key.clone());
+                if is_head && *due_at <= now && claimed.len() < limit as usize {
+                    *due_at = claimed_until;
+                    claimed.push(message.clone());
+                }
+            }
+            Ok(claimed)
+        }
+
+        async fn remove(&self, sequence: i64) -> Result<(), OutboxError> {
+            self.messages
+                .lock()
+                .unwrap()
+                .retain(|(message, _)| message.sequence != sequence);
+            Ok(())
+        }
+
+        async fn schedule_retry(
+            &self,
+            sequence: i64,
+            attempts: u32,
+            retry_at: DateTime<Utc>,
+            _error: &str,
+        ) -> Result<(), OutboxError> {
+            let mut messages = self.messages.lock().unwrap();
+            if let Some((message, due_at)) = messages
+                .iter_mut()
+                .find(|(message, _)| message.sequence == sequence)
+            {
+                message.attempts = attempts;
+                *due_at = retry_at;
+            }
+            Ok(())
+        }
+    }
+
+    /// Publisher recording messages, failing while `fail` is set
+    #[derive(Default)]
+    struct RecordingPublisher {
+        published: Mutex<Vec<(String, String)>>,
+        fail: Mutex<bool>,
+    }
+
+    #[async_trait]
+    impl MessagePublisher for RecordingPublisher {
+        async fn publish(&self, key: &str, payload: &str) -> Result<(), EventPublisherError> {
+            if *self.fail.lock().unwrap() {
+                return Err(EventPublisherError::PublishFailed(
+                    "broker down".to_string(),
+                ));
+            }
+            self.published
+                .lock()
+                .unwrap()
+                .push((key.to_string(), payload.to_string()));
+            Ok(())
+        }
+    }
+
+    fn settings() -> OutboxSettings {
+        OutboxSettings {
+            batch_size: 10,
+            poll_interval: std::time::Duration::from_millis(10),
+            claim_timeout: Duration::seconds(30),
+            retry_base_delay: Duration::seconds(1),
+            retry_max_delay: Duration::minutes(5),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_relay_publishes_messages_of_a_key_in_order() {
+        let repository = Arc::new(InMemoryOutbox::with_messages(&["alice", "bob", "alice"]));
+        let publisher = Arc::new(RecordingPublisher::default());
+        let relay = OutboxRelay::new(Arc::clone(&repository), Arc::clone(&publisher), settings());
+
+        assert_eq!(relay.relay_due().await.unwrap(), 2);
+        assert_eq!(relay.relay_due().await.unwrap(), 1);
+        assert_eq!(relay.relay_due().await.unwrap(), 0);
+
+        let published = publisher.published.lock().unwrap().clone();
+        let alice: Vec<&str> = published
+            .iter()
+            .filter(|(key, _)| key == "alice")
+            .map(|(_, payload)| payload.as_str())
+            .collect();
+        assert_eq!(alice, vec!["{\"n\":1}", "{\"n\":3}"]);
+        assert!(repository.messages.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_publish_is_retried_later() {
+        let repository = Arc::new(InMemoryOutbox::with_messages(&["alice"]));
+        let publisher = Arc::new(RecordingPublisher::default());
+        *publisher.fail.lock().unwrap() = true;
+        let relay = OutboxRelay::new(Arc::clone(&repository), Arc::clone(&publisher), settings());
+
+        assert_eq!(relay.relay_due().await.unwrap(), 0);
+
+        {
+            let messages = repository.messages.lock().unwrap();
+            let (message, due_at) = &messages[0];
+            assert_eq!(message.attempts, 1);
+            assert!(*due_at > Utc::now());
+        }
+        // Not due before its backoff has passed, even once the broker is back
+        *publisher.fail.lock().unwrap() = false;
+        assert_eq!(relay.relay_due().await.unwrap(), 0);
+
+        repository.messages.lock().unwrap()[0].1 = Utc::now() - Duration::seconds(1);
+        assert_eq!(relay.relay_due().await.unwrap(), 1);
+        assert_eq!(publisher.published.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_max() {
+        let settings = settings();
+
+        assert_eq!(settings.retry_delay(1), Duration::seconds(1));
+        assert_eq!(settings.retry_delay(2), Duration::seconds(2));
+        assert_eq!(settings.retry_delay(4), Duration::seconds(8));
+        assert_eq!(settings.retry_delay(20), Duration::minutes(5));
+        assert_eq!(settings.retry_delay(u32::MAX), Duration::minutes(5));
+    }
+}
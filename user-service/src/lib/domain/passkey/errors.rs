@@ -0,0 +1,44 @@
+use thiserror::Error;
+
+use crate::domain::user::errors::UserError;
+
+/// Error for PasskeyId and CeremonyId parsing failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum PasskeyIdError {
+    #[error("Invalid UUID format: {0}")]
+    InvalidFormat(String),
+}
+
+/// Top-level error for all passkey operations
+#[derive(Debug, Clone, Error)]
+pub enum PasskeyError {
+    #[error("Invalid ID: {0}")]
+    InvalidId(#[from] PasskeyIdError),
+
+    #[error("Invalid passkey name: {0}")]
+    InvalidName(String),
+
+    #[error("Passkey not found: {0}")]
+    NotFound(String),
+
+    #[error("Passkey is already registered")]
+    AlreadyRegistered,
+
+    #[error("No passkey is registered for this user")]
+    NoCredentials,
+
+    #[error("Passkey ceremony is unknown or expired")]
+    CeremonyNotFound,
+
+    #[error("Passkey verification failed: {0}")]
+    VerificationFailed(String),
+
+    #[error("Token error: {0}")]
+    TokenError(String),
+
+    #[error(transparent)]
+    User(#[from] UserError),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
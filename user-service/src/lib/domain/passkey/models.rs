@@ -0,0 +1,198 @@
+use std::fmt;
+
+use auth::TokenPair;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use uuid::Uuid;
+use webauthn_rs::prelude::CreationChallengeResponse;
+use webauthn_rs::prelude::Passkey;
+use webauthn_rs::prelude::PasskeyAuthentication;
+use webauthn_rs::prelude::PasskeyRegistration;
+use webauthn_rs::prelude::RequestChallengeResponse;
+
+use crate::domain::passkey::errors::PasskeyError;
+use crate::domain::passkey::errors::PasskeyIdError;
+use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
+
+/// Maximum length of a passkey name.
+pub const PASSKEY_NAME_MAX_LENGTH: usize = 64;
+
+/// WebAuthn credential registered by a user.
+#[derive(Debug, Clone)]
+pub struct PasskeyCredential {
+    pub id: PasskeyId,
+    pub user_id: UserId,
+    pub name: PasskeyName,
+    /// Public key and signature counter verified on each login
+    pub passkey: Passkey,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// Passkey unique identifier type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PasskeyId(pub Uuid);
+
+impl PasskeyId {
+    /// Generate a new random passkey ID.
+    ///
+    /// # Returns
+    /// PasskeyId with random UUID v4
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Parse a passkey ID from string.
+    ///
+    /// # Arguments
+    /// * `s` - UUID string to parse
+    ///
+    /// # Returns
+    /// Parsed PasskeyId
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a valid UUID
+    pub fn from_string(s: &str) -> Result<Self, PasskeyIdError> {
+        Uuid::parse_str(s)
+            .map(PasskeyId)
+            .map_err(|e| PasskeyIdError::InvalidFormat(e.to_string()))
+    }
+}
+
+impl Default for PasskeyId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for PasskeyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Name a user gave a passkey to tell their devices apart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasskeyName(String);
+
+impl PasskeyName {
+    /// Create a validated passkey name.
+    ///
+    /// # Arguments
+    /// * `name` - Name, trimmed before validation
+    ///
+    /// # Returns
+    /// Validated PasskeyName
+    ///
+    /// # Errors
+    /// * `InvalidName` - Name is empty or longer than 64 characters
+    pub fn new(name: String) -> Result<Self, PasskeyError> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > PASSKEY_NAME_MAX_LENGTH {
+            return Err(PasskeyError::InvalidName(format!(
+                "must be 1 to {} characters",
+                PASSKEY_NAME_MAX_LENGTH
+            )));
+        }
+        Ok(Self(name.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for PasskeyName {
+    fn default() -> Self {
+        Self("Passkey".to_string())
+    }
+}
+
+/// Identifier of a registration or login ceremony in progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CeremonyId(pub Uuid);
+
+impl CeremonyId {
+    /// Generate a new random ceremony ID.
+    ///
+    /// # Returns
+    /// CeremonyId with random UUID v4
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Parse a ceremony ID from string.
+    ///
+    /// # Arguments
+    /// * `s` - UUID string to parse
+    ///
+    /// # Returns
+    /// Parsed CeremonyId
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a valid UUID
+    pub fn from_string(s: &str) -> Result<Self, PasskeyIdError> {
+        Uuid::parse_str(s)
+            .map(CeremonyId)
+            .map_err(|e| PasskeyIdError::InvalidFormat(e.to_string()))
+    }
+}
+
+impl Default for CeremonyId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for CeremonyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Server-side state of a ceremony between its start and finish requests.
+#[derive(Debug, Clone)]
+pub enum CeremonyState {
+    Registration(PasskeyRegistration),
+    Login(PasskeyAuthentication),
+}
+
+/// Ceremony started for a user, awaiting the authenticator's response.
+#[derive(Debug, Clone)]
+pub struct PendingCeremony {
+    pub user_id: UserId,
+    pub state: CeremonyState,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Options for `navigator.credentials.create()` of a started registration.
+#[derive(Debug, Clone)]
+pub struct RegistrationChallenge {
+    pub ceremony_id: CeremonyId,
+    pub options: CreationChallengeResponse,
+}
+
+/// Options for `navigator.credentials.get()` of a started login.
+#[derive(Debug, Clone)]
+pub struct LoginChallenge {
+    pub ceremony_id: CeremonyId,
+    pub options: RequestChallengeResponse,
+}
+
+/// Session started by a passkey login.
+#[derive(Debug, Clone)]
+pub struct PasskeyLogin {
+    pub user: User,
+    pub tokens: TokenPair,
+}
+
+/// Settings of passkey ceremonies and sessions.
+#[derive(Debug, Clone)]
+pub struct PasskeySettings {
+    /// Time a started ceremony may take to finish
+    pub ceremony_ttl: Duration,
+    /// Lifetime of access tokens issued on login
+    pub access_token_hours: i64,
+}
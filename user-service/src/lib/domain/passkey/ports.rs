@@ -0,0 +1,195 @@
+use async_trait::async_trait;
+use webauthn_rs::prelude::PublicKeyCredential;
+use webauthn_rs::prelude::RegisterPublicKeyCredential;
+
+use crate::domain::passkey::errors::PasskeyError;
+use crate::domain::passkey::models::CeremonyId;
+use crate::domain::passkey::models::LoginChallenge;
+use crate::domain::passkey::models::PasskeyCredential;
+use crate::domain::passkey::models::PasskeyId;
+use crate::domain::passkey::models::PasskeyLogin;
+use crate::domain::passkey::models::PasskeyName;
+use crate::domain::passkey::models::PendingCeremony;
+use crate::domain::passkey::models::RegistrationChallenge;
+use crate::domain::user::models::UserId;
+use crate::domain::user::models::Username;
+
+/// Port for passkey (WebAuthn) registration, login and listing.
+///
+/// Passkeys are removed through `AccountLinkServicePort`, which keeps an
+/// account from losing its last login method.
+#[async_trait]
+pub trait PasskeyServicePort: Send + Sync + 'static {
+    /// Start registering a passkey for a logged-in user.
+    ///
+    /// # Arguments
+    /// * `user_id` - User registering the passkey
+    ///
+    /// # Returns
+    /// Ceremony ID and options for `navigator.credentials.create()`
+    ///
+    /// # Errors
+    /// * `User` - User does not exist
+    /// * `VerificationFailed` - Challenge could not be generated
+    /// * `DatabaseError` - Existing passkeys could not be read
+    async fn start_registration(
+        &self,
+        user_id: &UserId,
+    ) -> Result<RegistrationChallenge, PasskeyError>;
+
+    /// Verify the authenticator's response and store the new passkey.
+    ///
+    /// # Arguments
+    /// * `user_id` - User who started the ceremony
+    /// * `ceremony_id` - Ceremony returned by `start_registration`
+    /// * `name` - Name of the passkey
+    /// * `credential` - Response of `navigator.credentials.create()`
+    ///
+    /// # Returns
+    /// Stored passkey
+    ///
+    /// # Errors
+    /// * `CeremonyNotFound` - Ceremony is unknown, expired, or another user's
+    /// * `VerificationFailed` - Response does not match the challenge
+    /// * `AlreadyRegistered` - Credential is already registered
+    /// * `DatabaseError` - Passkey could not be stored
+    async fn finish_registration(
+        &self,
+        user_id: &UserId,
+        ceremony_id: &CeremonyId,
+        name: PasskeyName,
+        credential: RegisterPublicKeyCredential,
+    ) -> Result<PasskeyCredential, PasskeyError>;
+
+    /// Start logging in with one of a user's passkeys.
+    ///
+    /// # Arguments
+    /// * `username` - User logging in
+    ///
+    /// # Returns
+    /// Ceremony ID and options for `navigator.credentials.get()`
+    ///
+    /// # Errors
+    /// * `NoCredentials` - User is unknown or has no passkey
+    /// * `VerificationFailed` - Challenge could not be generated
+    /// * `DatabaseError` - Passkeys could not be read
+    async fn start_login(&self, username: &Username) -> Result<LoginChallenge, PasskeyError>;
+
+    /// Verify the authenticator's assertion and issue a token pair.
+    ///
+    /// # Arguments
+    /// * `ceremony_id` - Ceremony returned by `start_login`
+    /// * `credential` - Response of `navigator.credentials.get()`
+    ///
+    /// # Returns
+    /// Logged-in user with the issued token pair
+    ///
+    /// # Errors
+    /// * `CeremonyNotFound` - Ceremony is unknown or expired
+    /// * `VerificationFailed` - Assertion is invalid or uses an unknown passkey
+    /// * `TokenError` - Token pair could not be issued
+    /// * `DatabaseError` - Passkey could not be updated
+    async fn finish_login(
+        &self,
+        ceremony_id: &CeremonyId,
+        credential: PublicKeyCredential,
+    ) -> Result<PasskeyLogin, PasskeyError>;
+
+    /// List the passkeys of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the passkeys
+    ///
+    /// # Returns
+    /// Passkeys, oldest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn list_passkeys(&self, user_id: &UserId)
+        -> Result<Vec<PasskeyCredential>, PasskeyError>;
+}
+
+/// Persistence operations for passkeys.
+#[async_trait]
+pub trait PasskeyRepository: Send + Sync + 'static {
+    /// Persist a new passkey.
+    ///
+    /// # Arguments
+    /// * `credential` - Passkey to store
+    ///
+    /// # Returns
+    /// Stored passkey
+    ///
+    /// # Errors
+    /// * `AlreadyRegistered` - Credential ID already exists
+    /// * `DatabaseError` - Database operation failed
+    async fn create(
+        &self,
+        credential: PasskeyCredential,
+    ) -> Result<PasskeyCredential, PasskeyError>;
+
+    /// Retrieve the passkeys of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the passkeys
+    ///
+    /// # Returns
+    /// Passkeys, oldest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn list_for_user(&self, user_id: &UserId)
+        -> Result<Vec<PasskeyCredential>, PasskeyError>;
+
+    /// Save the signature counter and last use of a passkey.
+    ///
+    /// # Arguments
+    /// * `credential` - Passkey with updated state
+    ///
+    /// # Errors
+    /// * `NotFound` - Passkey does not exist
+    /// * `DatabaseError` - Database operation failed
+    async fn update(&self, credential: &PasskeyCredential) -> Result<(), PasskeyError>;
+
+    /// Delete a passkey of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the passkey
+    /// * `id` - Passkey to delete
+    ///
+    /// # Returns
+    /// Whether a passkey was deleted
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn delete(&self, user_id: &UserId, id: &PasskeyId) -> Result<bool, PasskeyError>;
+}
+
+/// Storage of ceremonies between their start and finish requests.
+///
+/// Every instance that may receive the finish request must see the
+/// ceremony, so multi-instance deployments need a shared store.
+#[async_trait]
+pub trait CeremonyStore: Send + Sync + 'static {
+    /// Store a started ceremony.
+    ///
+    /// # Arguments
+    /// * `id` - Ceremony identifier
+    /// * `ceremony` - Ceremony state
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Ceremony could not be stored
+    async fn insert(&self, id: CeremonyId, ceremony: PendingCeremony) -> Result<(), PasskeyError>;
+
+    /// Remove and return a ceremony, so it can only be finished once.
+    ///
+    /// # Arguments
+    /// * `id` - Ceremony identifier
+    ///
+    /// # Returns
+    /// The ceremony, or None if unknown or already taken
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Ceremony could not be read
+    async fn take(&self, id: &CeremonyId) -> Result<Option<PendingCeremony>, PasskeyError>;
+}
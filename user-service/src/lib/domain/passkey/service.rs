@@ -0,0 +1,520 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use auth::Authenticator;
+use chrono::Utc;
+use webauthn_rs::prelude::PublicKeyCredential;
+use webauthn_rs::prelude::RegisterPublicKeyCredential;
+use webauthn_rs::Webauthn;
+
+use crate::domain::passkey::errors::PasskeyError;
+use crate::domain::passkey::models::CeremonyId;
+use crate::domain::passkey::models::CeremonyState;
+use crate::domain::passkey::models::LoginChallenge;
+use crate::domain::passkey::models::PasskeyCredential;
+use crate::domain::passkey::models::PasskeyId;
+use crate::domain::passkey::models::PasskeyLogin;
+use crate::domain::passkey::models::PasskeyName;
+use crate::domain::passkey::models::PasskeySettings;
+use crate::domain::passkey::models::PendingCeremony;
+use crate::domain::passkey::models::RegistrationChallenge;
+use crate::domain::passkey::ports::CeremonyStore;
+use crate::domain::passkey::ports::PasskeyRepository;
+use crate::domain::passkey::ports::PasskeyServicePort;
+use crate::domain::user::errors::UserError;
+use crate::domain::user::models::Role;
+use crate::domain::user::models::UserId;
+use crate::domain::user::models::Username;
+use crate::domain::user::ports::UserServicePort;
+
+/// Domain service implementation for passkey operations.
+///
+/// Concrete implementation of PasskeyServicePort with dependency injection.
+pub struct PasskeyService<US, PR>
+where
+    US: UserServicePort,
+    PR: PasskeyRepository,
+{
+    user_service: Arc<US>,
+    repository: Arc<PR>,
+    ceremonies: Arc<dyn CeremonyStore>,
+    webauthn: Arc<Webauthn>,
+    authenticator: Arc<Authenticator>,
+    settings: PasskeySettings,
+}
+
+impl<US, PR> PasskeyService<US, PR>
+where
+    US: UserServicePort,
+    PR: PasskeyRepository,
+{
+    /// Create a new passkey service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `user_service` - Service looking up users
+    /// * `repository` - Passkey persistence implementation
+    /// * `ceremonies` - Storage of ceremonies in progress
+    /// * `webauthn` - Relying party verifying authenticator responses
+    /// * `authenticator` - Issuer of session tokens
+    /// * `settings` - Ceremony timeout and session lifetime
+    ///
+    /// # Returns
+    /// Configured passkey service instance
+    pub fn new(
+        user_service: Arc<US>,
+        repository: Arc<PR>,
+        ceremonies: Arc<dyn CeremonyStore>,
+        webauthn: Arc<Webauthn>,
+        authenticator: Arc<Authenticator>,
+        settings: PasskeySettings,
+    ) -> Self {
+        Self {
+            user_service,
+            repository,
+            ceremonies,
+            webauthn,
+            authenticator,
+            settings,
+        }
+    }
+
+    async fn start_ceremony(
+        &self,
+        user_id: UserId,
+        state: CeremonyState,
+    ) -> Result<CeremonyId, PasskeyError> {
+        let ceremony_id = CeremonyId::new();
+        let ceremony = PendingCeremony {
+            user_id,
+            state,
+            expires_at: Utc::now() + self.settings.ceremony_ttl,
+        };
+        self.ceremonies.insert(ceremony_id, ceremony).await?;
+        Ok(ceremony_id)
+    }
+
+    async fn take_ceremony(&self, id: &CeremonyId) -> Result<PendingCeremony, PasskeyError> {
+        self.ceremonies
+            .take(id)
+            .await?
+            .filter(|ceremony| ceremony.expires_at > Utc::now())
+            .ok_or(PasskeyError::CeremonyNotFound)
+    }
+}
+
+#[async_trait]
+impl<US, PR> PasskeyServicePort for PasskeyService<US, PR>
+where
+    US: UserServicePort,
+    PR: PasskeyRepository,
+{
+    async fn start_registration(
+        &self,
+        user_id: &UserId,
+    ) -> Result<RegistrationChallenge, PasskeyError> {
+        let user = self.user_service.get_user(user_id).await?;
+        let existing = self.repository.list_for_user(user_id).await?;
+        let exclude_credentials = existing
+            .iter()
+            .map(|credential| credential.passkey.cred_id().clone())
+            .collect();
+
+        let (options, state) = self
+            .webauthn
+            .start_passkey_registration(
+                user.id.0,
+                user.username.as_str(),
+                user.username.as_str(),
+                Some(exclude_credentials),
+            )
+            .map_err(|e| PasskeyError::VerificationFailed(e.to_string()))?;
+
+        let ceremony_id = self
+            .start_ceremony(user.id, CeremonyState::Registration(state))
+            .await?;
+
+        Ok(RegistrationChallenge {
+            ceremony_id,
+            options,
+        })
+    }
+
+    async fn finish_registration(
+        &self,
+        user_id: &UserId,
+        ceremony_id: &CeremonyId,
+        name: PasskeyName,
+        credential: RegisterPublicKeyCredential,
+    ) -> Result<PasskeyCredential, PasskeyError> {
+        let ceremony = self.take_ceremony(ceremony_id).await?;
+        let CeremonyState::Registration(state) = ceremony.state else {
+            return Err(PasskeyError::CeremonyNotFound);
+        };
+        if ceremony.user_id != *user_id {
+            return Err(PasskeyError::CeremonyNotFound);
+        }
+
+        let passkey = self
+            .webauthn
+            .finish_passkey_registration(&credential, &state)
+            .map_err(|e| PasskeyError::VerificationFailed(e.to_string()))?;
+
+        let created = self
+            .repository
+            .create(PasskeyCredential {
+                id: PasskeyId::new(),
+                user_id: *user_id,
+                name,
+                passkey,
+                created_at: Utc::now(),
+                last_used_at: None,
+            })
+            .await?;
+
+        tracing::info!(user_id = %user_id, passkey_id = %created.id, "Passkey registered");
+        Ok(created)
+    }
+
+    async fn start_login(&self, username: &Username) -> Result<LoginChallenge, PasskeyError> {
+        // Unknown users look like users without passkeys
+        let user = match self.user_service.get_user_by_username(username).await {
+            Ok(user) => user,
+            Err(UserError::NotFoundByUsername(_)) => return Err(PasskeyError::NoCredentials),
+            Err(e) => return Err(e.into()),
+        };
+
+        let passkeys: Vec<_> = self
+            .repository
+            .list_for_user(&user.id)
+            .await?
+            .into_iter()
+            .map(|credential| credential.passkey)
+            .collect();
+        if passkeys.is_empty() {
+            return Err(PasskeyError::NoCredentials);
+        }
+
+        let (options, state) = self
+            .webauthn
+            .start_passkey_authentication(&passkeys)
+            .map_err(|e| PasskeyError::VerificationFailed(e.to_string()))?;
+
+        let ceremony_id = self
+            .start_ceremony(user.id, CeremonyState::Login(state))
+            .await?;
+
+        Ok(LoginChallenge {
+            ceremony_id,
+            options,
+        })
+    }
+
+    async fn finish_login(
+        &self,
+        ceremony_id: &CeremonyId,
+        credential: PublicKeyCredential,
+    ) -> Result<PasskeyLogin, PasskeyError> {
+        let ceremony = self.take_ceremony(ceremony_id).await?;
+        let CeremonyState::Login(state) = ceremony.state else {
+            return Err(PasskeyError::CeremonyNotFound);
+        };
+
+        let result = self
+            .webauthn
+            .finish_passkey_authentication(&credential, &state)
+            .map_err(|e| PasskeyError::VerificationFailed(e.to_string()))?;
+
+        let mut stored = self
+            .repository
+            .list_for_user(&ceremony.user_id)
+            .await?
+            .into_iter()
+            .find(|credential| credential.passkey.cred_id() == result.cred_id())
+            .ok_or_else(|| PasskeyError::VerificationFailed("passkey was removed".to_string()))?;
+        stored.passkey.update_credential(&result);
+        stored.last_used_at = Some(Utc::now());
+        self.repository.update(&stored).await?;
+
+        let user = self.user_service.get_user(&ceremony.user_id).await?;
+        let claims = auth::Claims::for_user(
+            user.id,
+            user.username.as_str().to_string(),
+            self.settings.access_token_hours,
+        )
+        .with_roles(user.roles.iter().map(Role::as_str));
+        let tokens = self
+            .authenticator
+            .issue_token_pair(&claims)
+            .await
+            .map_err(|e| PasskeyError::TokenError(e.to_string()))?;
+
+        tracing::info!(user_id = %user.id, passkey_id = %stored.id, "User logged in with passkey");
+        Ok(PasskeyLogin { user, tokens })
+    }
+
+    async fn list_passkeys(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PasskeyCredential>, PasskeyError> {
+        self.repository.list_for_user(user_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use auth::SecretString;
+    use mockall::mock;
+    use mockall::predicate::*;
+    use webauthn_rs::prelude::Url;
+    use webauthn_rs::WebauthnBuilder;
+
+    use super::*;
+    use crate::domain::user::models::CreateUserCommand;
+    use crate::domain::user::models::EmailAddress;
+    use crate::domain::user::models::ImportUserCommand;
+    use crate::domain::user::models::LoginIdentifier;
+    use crate::domain::user::models::UpdateUserCommand;
+    use crate::domain::user::models::User;
+    use crate::domain::user::models::UserCursor;
+    use crate::domain::user::models::UserPage;
+    use crate::domain::user::models::UserSearchQuery;
+    use crate::domain::user::models::UserStatus;
+
+    mock! {
+        pub TestUserService {}
+
+        #[async_trait]
+        impl UserServicePort for TestUserService {
+            async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
+            async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+            async fn get_user(&self, id: &UserId) -> Result<User, UserError>;
+            async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
+            async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+            async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
+            async fn list_users(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<UserPage, UserError>;
+            async fn search_users(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
+            async fn update_user(&self, id: &UserId, command: UpdateUserCommand) -> Result<User, UserError>;
+            async fn change_password(&self, id: &UserId, current_password: SecretString, new_password: SecretString) -> Result<(), UserError>;
+            async fn reset_password(&self, id: &UserId, new_password: SecretString) -> Result<(), UserError>;
+            async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+            async fn replace_password_hash(&self, id: &UserId, password_hash: String) -> Result<(), UserError>;
+            async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    mock! {
+        pub TestPasskeyRepository {}
+
+        #[async_trait]
+        impl PasskeyRepository for TestPasskeyRepository {
+            async fn create(&self, credential: PasskeyCredential) -> Result<PasskeyCredential, PasskeyError>;
+            async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<PasskeyCredential>, PasskeyError>;
+            async fn update(&self, credential: &PasskeyCredential) -> Result<(), PasskeyError>;
+            async fn delete(&self, user_id: &UserId, id: &PasskeyId) -> Result<bool, PasskeyError>;
+        }
+    }
+
+    mock! {
+        pub TestCeremonyStore {}
+
+        #[async_trait]
+        impl CeremonyStore for TestCeremonyStore {
+            async fn insert(&self, id: CeremonyId, ceremony: PendingCeremony) -> Result<(), PasskeyError>;
+            async fn take(&self, id: &CeremonyId) -> Result<Option<PendingCeremony>, PasskeyError>;
+        }
+    }
+
+    fn webauthn() -> Arc<Webauthn> {
+        let origin = Url::parse("http://localhost:3000").unwrap();
+        Arc::new(
+            WebauthnBuilder::new("localhost", &origin)
+                .unwrap()
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn alice() -> User {
+        User {
+            id: UserId::new(),
+            username: Username::new("alice".to_string()).unwrap(),
+            email: EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            password_hash: "$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn service(
+        user_service: MockTestUserService,
+        repository: MockTestPasskeyRepository,
+        ceremonies: MockTestCeremonyStore,
+    ) -> PasskeyService<MockTestUserService, MockTestPasskeyRepository> {
+        PasskeyService::new(
+            Arc::new(user_service),
+            Arc::new(repository),
+            Arc::new(ceremonies),
+            webauthn(),
+            Arc::new(Authenticator::new(b"test_secret_key_at_least_32_bytes!")),
+            PasskeySettings {
+                ceremony_ttl: chrono::Duration::minutes(5),
+                access_token_hours: 1,
+            },
+        )
+    }
+
+    fn registration_response() -> RegisterPublicKeyCredential {
+        serde_json::from_value(serde_json::json!({
+            "id": "AAAA",
+            "rawId": "AAAA",
+            "response": {
+                "attestationObject": "AAAA",
+                "clientDataJSON": "AAAA"
+            },
+            "type": "public-key",
+            "extensions": {}
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_start_registration_stores_ceremony() {
+        let user = alice();
+        let user_id = user.id;
+
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user()
+            .with(eq(user_id))
+            .times(1)
+            .returning(move |_| Ok(user.clone()));
+
+        let mut repository = MockTestPasskeyRepository::new();
+        repository
+            .expect_list_for_user()
+            .times(1)
+            .returning(|_| Ok(Vec::new()));
+
+        let mut ceremonies = MockTestCeremonyStore::new();
+        ceremonies
+            .expect_insert()
+            .withf(move |_, ceremony| {
+                ceremony.user_id == user_id
+                    && matches!(ceremony.state, CeremonyState::Registration(_))
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let challenge = service(user_service, repository, ceremonies)
+            .start_registration(&user_id)
+            .await
+            .unwrap();
+        assert_eq!(challenge.options.public_key.user.name, "alice");
+    }
+
+    #[tokio::test]
+    async fn test_finish_registration_rejects_other_users_ceremony() {
+        let (_, state) = webauthn()
+            .start_passkey_registration(uuid::Uuid::new_v4(), "bob", "bob", None)
+            .unwrap();
+
+        let mut ceremonies = MockTestCeremonyStore::new();
+        ceremonies.expect_take().times(1).returning(move |_| {
+            Ok(Some(PendingCeremony {
+                user_id: UserId::new(),
+                state: CeremonyState::Registration(state.clone()),
+                expires_at: Utc::now() + chrono::Duration::minutes(5),
+            }))
+        });
+        let mut repository = MockTestPasskeyRepository::new();
+        repository.expect_create().never();
+
+        let result = service(MockTestUserService::new(), repository, ceremonies)
+            .finish_registration(
+                &UserId::new(),
+                &CeremonyId::new(),
+                PasskeyName::default(),
+                registration_response(),
+            )
+            .await;
+        assert!(matches!(result, Err(PasskeyError::CeremonyNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_finish_registration_rejects_expired_ceremony() {
+        let user_id = UserId::new();
+        let (_, state) = webauthn()
+            .start_passkey_registration(user_id.0, "alice", "alice", None)
+            .unwrap();
+
+        let mut ceremonies = MockTestCeremonyStore::new();
+        ceremonies.expect_take().times(1).returning(move |_| {
+            Ok(Some(PendingCeremony {
+                user_id,
+                state: CeremonyState::Registration(state.clone()),
+                expires_at: Utc::now() - chrono::Duration::seconds(1),
+            }))
+        });
+
+        let result = service(
+            MockTestUserService::new(),
+            MockTestPasskeyRepository::new(),
+            ceremonies,
+        )
+        .finish_registration(
+            &user_id,
+            &CeremonyId::new(),
+            PasskeyName::default(),
+            registration_response(),
+        )
+        .await;
+        assert!(matches!(result, Err(PasskeyError::CeremonyNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_start_login_without_passkeys() {
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user_by_username()
+            .returning(|username| match username.as_str() {
+                "alice" => Ok(alice()),
+                other => Err(UserError::NotFoundByUsername(other.to_string())),
+            });
+
+        let mut repository = MockTestPasskeyRepository::new();
+        repository
+            .expect_list_for_user()
+            .times(1)
+            .returning(|_| Ok(Vec::new()));
+
+        let service = service(user_service, repository, MockTestCeremonyStore::new());
+
+        for username in ["alice", "nobody"] {
+            let result = service
+                .start_login(&Username::new(username.to_string()).unwrap())
+                .await;
+            assert!(matches!(result, Err(PasskeyError::NoCredentials)));
+        }
+    }
+
+    #[test]
+    fn test_passkey_name_validation() {
+        assert_eq!(
+            PasskeyName::new("  YubiKey  ".to_string())
+                .unwrap()
+                .as_str(),
+            "YubiKey"
+        );
+        assert!(PasskeyName::new("   ".to_string()).is_err());
+        assert!(PasskeyName::new("x".repeat(65)).is_err());
+    }
+}
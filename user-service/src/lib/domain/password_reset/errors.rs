@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use crate::domain::email::errors::EmailError;
+use crate::domain::user::errors::UserError;
+
+/// Top-level error for password reset operations
+#[derive(Debug, Clone, Error)]
+pub enum PasswordResetError {
+    #[error("Password reset link is invalid or expired")]
+    InvalidLink,
+
+    #[error("Password reset link was already used")]
+    AlreadyUsed,
+
+    #[error("Token error: {0}")]
+    TokenError(String),
+
+    #[error(transparent)]
+    Email(#[from] EmailError),
+
+    #[error(transparent)]
+    User(#[from] UserError),
+}
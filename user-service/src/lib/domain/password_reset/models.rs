@@ -0,0 +1,10 @@
+use chrono::Duration;
+
+/// Settings of issued password reset links.
+#[derive(Debug, Clone)]
+pub struct PasswordResetSettings {
+    /// Page the link opens, with the token appended as `token` query parameter
+    pub link_url: String,
+    /// Time until a link expires
+    pub ttl: Duration,
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use auth::SecretString;
+
+use crate::domain::password_reset::errors::PasswordResetError;
+use crate::domain::user::models::EmailAddress;
+use crate::domain::user::models::UserId;
+
+/// Port for letting users who forgot their password set a new one.
+#[async_trait]
+pub trait PasswordResetServicePort: Send + Sync + 'static {
+    /// Email a single-use password reset link to the owner of an address.
+    ///
+    /// Succeeds without sending anything when no user has the address, so
+    /// that callers cannot probe which addresses are registered.
+    ///
+    /// # Arguments
+    /// * `email` - Address the user signed up with
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `User` - User lookup failed
+    /// * `TokenError` - Link token could not be issued
+    /// * `Email` - Email could not be sent
+    async fn request_reset(&self, email: &EmailAddress) -> Result<(), PasswordResetError>;
+
+    /// Make a user choose a new password, on behalf of an admin.
+    ///
+    /// Replaces the password with one nobody knows, ends all sessions of the
+    /// user and emails a reset link.
+    ///
+    /// # Arguments
+    /// * `user_id` - User whose password is reset
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `User` - User does not exist or could not be updated
+    /// * `TokenError` - Link token could not be issued
+    /// * `Email` - Email could not be sent
+    async fn force_reset(&self, user_id: &UserId) -> Result<(), PasswordResetError>;
+
+    /// Set a new password with the token of a reset link.
+    ///
+    /// Ends all sessions of the user. The link is used up even when the new
+    /// password is then rejected.
+    ///
+    /// # Arguments
+    /// * `token` - Token of the reset link
+    /// * `new_password` - Password to set
+    ///
+    /// # Returns
+    /// ID of the user whose password was reset
+    ///
+    /// # Errors
+    /// * `InvalidLink` - Token is invalid, expired, its user no longer exists
+    ///   or changed password since the link was sent
+    /// * `AlreadyUsed` - Link was already redeemed
+    /// * `TokenError` - Link could not be redeemed
+    /// * `User` - New password is compromised or could not be stored
+    async fn confirm_reset(
+        &self,
+        token: &str,
+        new_password: SecretString,
+    ) -> Result<UserId, PasswordResetError>;
+}
@@ -0,0 +1,544 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use auth::Authenticator;
+use auth::Claims;
+use auth::OneTimeToken;
+use auth::OneTimeTokenError;
+use auth::SecretString;
+use chrono::Utc;
+
+use crate::domain::email::models::EmailMessage;
+use crate::domain::email::ports::EmailSender;
+use crate::domain::password_reset::errors::PasswordResetError;
+use crate::domain::password_reset::models::PasswordResetSettings;
+use crate::domain::password_reset::ports::PasswordResetServicePort;
+use crate::domain::user::errors::UserError;
+use crate::domain::user::events::UserPasswordResetEvent;
+use crate::domain::user::events::UserPasswordResetRequestedEvent;
+use crate::domain::user::models::EmailAddress;
+use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::EventPublisher;
+use crate::domain::user::ports::UserServicePort;
+
+/// Domain service implementation for password resets.
+///
+/// Concrete implementation of PasswordResetServicePort with dependency injection.
+pub struct PasswordResetService<US, ES, EP>
+where
+    US: UserServicePort,
+    ES: EmailSender,
+    EP: EventPublisher,
+{
+    user_service: Arc<US>,
+    sender: Arc<ES>,
+    event_publisher: Arc<EP>,
+    authenticator: Arc<Authenticator>,
+    settings: PasswordResetSettings,
+}
+
+impl<US, ES, EP> PasswordResetService<US, ES, EP>
+where
+    US: UserServicePort,
+    ES: EmailSender,
+    EP: EventPublisher,
+{
+    /// Create a new password reset service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `user_service` - Service looking up and updating users
+    /// * `sender` - Mail transport
+    /// * `event_publisher` - Publisher of reset audit events
+    /// * `authenticator` - Issuer of link tokens and owner of sessions
+    /// * `settings` - Link URL and expiration
+    ///
+    /// # Returns
+    /// Configured password reset service instance
+    pub fn new(
+        user_service: Arc<US>,
+        sender: Arc<ES>,
+        event_publisher: Arc<EP>,
+        authenticator: Arc<Authenticator>,
+        settings: PasswordResetSettings,
+    ) -> Self {
+        Self {
+            user_service,
+            sender,
+            event_publisher,
+            authenticator,
+            settings,
+        }
+    }
+
+    fn link_url(&self, token: &str) -> String {
+        let separator = if self.settings.link_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        format!("{}{}token={}", self.settings.link_url, separator, token)
+    }
+
+    /// Email a reset link bound to the current password hash of a user.
+    async fn send_link(
+        &self,
+        user: &User,
+        call_to_action: &str,
+        notice: &str,
+    ) -> Result<(), PasswordResetError> {
+        // Bound to the password hash, so a link stops working once the password changes
+        let token = self
+            .authenticator
+            .issue_bound_one_time_token(
+                &user.id.to_string(),
+                OneTimeToken::PASSWORD_RESET,
+                self.settings.ttl,
+                &user.password_hash,
+            )
+            .map_err(|e| PasswordResetError::TokenError(e.to_string()))?;
+        let expires_at = Utc::now() + self.settings.ttl;
+
+        let message = EmailMessage {
+            to: user.email.clone(),
+            subject: "Reset your password".to_string(),
+            text: format!(
+                "Hi {},\n\n{}: {}\n\nIt expires at {}. {}",
+                user.username.as_str(),
+                call_to_action,
+                self.link_url(&token),
+                expires_at.to_rfc2822(),
+                notice
+            ),
+        };
+        self.sender.send(&message).await?;
+
+        let event = UserPasswordResetRequestedEvent::new(user.id.to_string(), expires_at);
+        if let Err(e) = &self
+            .event_publisher
+            .publish_user_password_reset_requested(&event)
+            .await
+        {
+            tracing::error!(
+                "Failed to publish UserPasswordResetRequested event for user {}: {}",
+                user.id,
+                e
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<US, ES, EP> PasswordResetServicePort for PasswordResetService<US, ES, EP>
+where
+    US: UserServicePort,
+    ES: EmailSender,
+    EP: EventPublisher,
+{
+    async fn request_reset(&self, email: &EmailAddress) -> Result<(), PasswordResetError> {
+        let user = match self.user_service.get_user_by_email(email).await {
+            Ok(user) => user,
+            Err(UserError::NotFoundByEmail(_)) => {
+                tracing::info!("Password reset requested for unknown address");
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        self.send_link(
+            &user,
+            "Open this link to choose a new password",
+            "If you did not ask for this, you can ignore this email.",
+        )
+        .await?;
+
+        tracing::info!(user_id = %user.id, "Password reset link sent");
+        Ok(())
+    }
+
+    async fn force_reset(&self, user_id: &UserId) -> Result<(), PasswordResetError> {
+        let mut user = self.user_service.get_user(user_id).await?;
+
+        // Nobody knows this password, so only the reset link opens the account
+        user.password_hash = auth::PasswordHasher::new()
+            .hash(&SecretString::new(format!(
+                "{}{}",
+                uuid::Uuid::new_v4(),
+                uuid::Uuid::new_v4()
+            )))
+            .map_err(|e| UserError::Unknown(format!("Password hashing failed: {}", e)))?;
+        self.user_service
+            .replace_password_hash(&user.id, user.password_hash.clone())
+            .await?;
+
+        if let Err(e) = self
+            .authenticator
+            .revoke_all_sessions(&user.id.to_string())
+            .await
+        {
+            tracing::warn!("Failed to revoke sessions of user {}: {}", user.id, e);
+        }
+
+        self.send_link(
+            &user,
+            "An administrator reset your password. Open this link to choose a new one",
+            "Your previous password no longer works and you were signed out everywhere.",
+        )
+        .await?;
+
+        tracing::info!(user_id = %user.id, "Password reset forced");
+        Ok(())
+    }
+
+    async fn confirm_reset(
+        &self,
+        token: &str,
+        new_password: SecretString,
+    ) -> Result<UserId, PasswordResetError> {
+        // The subject is read before redeeming to find the hash the token is bound to
+        let claims: Claims = self
+            .authenticator
+            .validate_token(token)
+            .map_err(|_| PasswordResetError::InvalidLink)?;
+        let user_id = claims
+            .sub
+            .as_deref()
+            .and_then(|subject| UserId::from_string(subject).ok())
+            .ok_or(PasswordResetError::InvalidLink)?;
+        let user = self
+            .user_service
+            .get_user(&user_id)
+            .await
+            .map_err(|e| match e {
+                UserError::NotFound(_) => PasswordResetError::InvalidLink,
+                e => e.into(),
+            })?;
+
+        self.authenticator
+            .redeem_bound_one_time_token(token, OneTimeToken::PASSWORD_RESET, &user.password_hash)
+            .await
+            .map_err(|e| match e {
+                OneTimeTokenError::AlreadyUsed => PasswordResetError::AlreadyUsed,
+                OneTimeTokenError::StoreError(e) => PasswordResetError::TokenError(e),
+                _ => PasswordResetError::InvalidLink,
+            })?;
+
+        self.user_service
+            .reset_password(&user.id, new_password)
+            .await?;
+
+        if let Err(e) = self
+            .authenticator
+            .revoke_all_sessions(&user.id.to_string())
+            .await
+        {
+            tracing::warn!("Failed to revoke sessions of user {}: {}", user.id, e);
+        }
+
+        let event = UserPasswordResetEvent::new(user.id.to_string());
+        if let Err(e) = &self
+            .event_publisher
+            .publish_user_password_reset(&event)
+            .await
+        {
+            tracing::error!(
+                "Failed to publish UserPasswordReset event for user {}: {}",
+                user.id,
+                e
+            );
+        }
+
+        tracing::info!(user_id = %user.id, "Password reset");
+        Ok(user.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::Duration;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::email::errors::EmailError;
+    use crate::domain::user::errors::EventPublisherError;
+    use crate::domain::user::events::UserAccountLockedEvent;
+    use crate::domain::user::events::UserCreatedEvent;
+    use crate::domain::user::events::UserDeactivatedEvent;
+    use crate::domain::user::events::UserDeletedEvent;
+    use crate::domain::user::events::UserPasswordChangedEvent;
+    use crate::domain::user::events::UserReactivatedEvent;
+    use crate::domain::user::events::UserUpdatedEvent;
+    use crate::domain::user::models::CreateUserCommand;
+    use crate::domain::user::models::ImportUserCommand;
+    use crate::domain::user::models::LoginIdentifier;
+    use crate::domain::user::models::UpdateUserCommand;
+    use crate::domain::user::models::User;
+    use crate::domain::user::models::UserCursor;
+    use crate::domain::user::models::UserPage;
+    use crate::domain::user::models::UserSearchQuery;
+    use crate::domain::user::models::UserStatus;
+    use crate::domain::user::models::Username;
+
+    mock! {
+        pub TestUserService {}
+
+        #[async_trait]
+        impl UserServicePort for TestUserService {
+            async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
+            async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+            async fn get_user(&self, id: &UserId) -> Result<User, UserError>;
+            async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
+            async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+            async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
+            async fn list_users(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<UserPage, UserError>;
+            async fn search_users(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
+            async fn update_user(&self, id: &UserId, command: UpdateUserCommand) -> Result<User, UserError>;
+            async fn change_password(&self, id: &UserId, current_password: SecretString, new_password: SecretString) -> Result<(), UserError>;
+            async fn reset_password(&self, id: &UserId, new_password: SecretString) -> Result<(), UserError>;
+            async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+            async fn replace_password_hash(&self, id: &UserId, password_hash: String) -> Result<(), UserError>;
+            async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    mock! {
+        pub TestEventPublisher {}
+
+        #[async_trait]
+        impl EventPublisher for TestEventPublisher {
+            async fn publish_user_created(&self, event: &UserCreatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_updated(&self, event: &UserUpdatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_deleted(&self, event: &UserDeletedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_deactivated(&self, event: &UserDeactivatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_reactivated(&self, event: &UserReactivatedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_password_changed(&self, event: &UserPasswordChangedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_password_reset_requested(&self, event: &UserPasswordResetRequestedEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_password_reset(&self, event: &UserPasswordResetEvent) -> Result<(), EventPublisherError>;
+            async fn publish_user_account_locked(&self, event: &UserAccountLockedEvent) -> Result<(), EventPublisherError>;
+        }
+    }
+
+    /// Sender keeping the sent emails
+    #[derive(Default)]
+    struct RecordingSender {
+        sent: Mutex<Vec<EmailMessage>>,
+    }
+
+    #[async_trait]
+    impl EmailSender for RecordingSender {
+        async fn send(&self, message: &EmailMessage) -> Result<(), EmailError> {
+            self.sent.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    fn alice() -> User {
+        User {
+            id: UserId::new(),
+            username: Username::new("alice".to_string()).unwrap(),
+            email: EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            password_hash: "$argon2id$hash".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn service(
+        user_service: MockTestUserService,
+        event_publisher: MockTestEventPublisher,
+        sender: Arc<RecordingSender>,
+    ) -> PasswordResetService<MockTestUserService, RecordingSender, MockTestEventPublisher> {
+        PasswordResetService::new(
+            Arc::new(user_service),
+            sender,
+            Arc::new(event_publisher),
+            Arc::new(Authenticator::new(b"test_secret_key_at_least_32_bytes!")),
+            PasswordResetSettings {
+                link_url: "http://localhost/reset-password".to_string(),
+                ttl: Duration::minutes(30),
+            },
+        )
+    }
+
+    fn sent_token(sender: &RecordingSender) -> String {
+        let sent = sender.sent.lock().unwrap();
+        let text = &sent.last().expect("No email sent").text;
+        let start = text.find("token=").expect("No token in email") + "token=".len();
+        text[start..].split_whitespace().next().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_with_sent_link() {
+        let user = alice();
+        let user_id = user.id;
+        let mut user_service = MockTestUserService::new();
+        let returned = user.clone();
+        user_service
+            .expect_get_user_by_email()
+            .times(1)
+            .returning(move |_| Ok(returned.clone()));
+        user_service
+            .expect_get_user()
+            .times(2)
+            .returning(move |_| Ok(user.clone()));
+        user_service
+            .expect_reset_password()
+            .withf(move |id, password| *id == user_id && password.expose_secret() == "new_pass")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_password_reset_requested()
+            .withf(move |event| event.user_id == user_id.to_string())
+            .times(1)
+            .returning(|_| Ok(()));
+        event_publisher
+            .expect_publish_user_password_reset()
+            .withf(move |event| event.user_id == user_id.to_string())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let sender = Arc::new(RecordingSender::default());
+        let service = service(user_service, event_publisher, Arc::clone(&sender));
+
+        let email = EmailAddress::new("alice@example.com".to_string()).unwrap();
+        service.request_reset(&email).await.unwrap();
+
+        let token = sent_token(&sender);
+        service
+            .confirm_reset(&token, SecretString::from("new_pass"))
+            .await
+            .unwrap();
+
+        let result = service
+            .confirm_reset(&token, SecretString::from("other_pass"))
+            .await;
+        assert!(matches!(result, Err(PasswordResetError::AlreadyUsed)));
+    }
+
+    #[tokio::test]
+    async fn test_request_reset_unknown_email_sends_nothing() {
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user_by_email()
+            .returning(|email| Err(UserError::NotFoundByEmail(email.as_str().to_string())));
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_password_reset_requested()
+            .times(0);
+
+        let sender = Arc::new(RecordingSender::default());
+        let service = service(user_service, event_publisher, Arc::clone(&sender));
+
+        let email = EmailAddress::new("nobody@example.com".to_string()).unwrap();
+        assert!(service.request_reset(&email).await.is_ok());
+        assert!(sender.sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_link_stops_working_after_password_change() {
+        let user = alice();
+        let changed = User {
+            password_hash: "$argon2id$other".to_string(),
+            ..user.clone()
+        };
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user_by_email()
+            .returning(move |_| Ok(user.clone()));
+        user_service
+            .expect_get_user()
+            .returning(move |_| Ok(changed.clone()));
+        user_service.expect_reset_password().times(0);
+
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_password_reset_requested()
+            .returning(|_| Ok(()));
+        event_publisher
+            .expect_publish_user_password_reset()
+            .times(0);
+
+        let sender = Arc::new(RecordingSender::default());
+        let service = service(user_service, event_publisher, Arc::clone(&sender));
+
+        let email = EmailAddress::new("alice@example.com".to_string()).unwrap();
+        service.request_reset(&email).await.unwrap();
+
+        let result = service
+            .confirm_reset(&sent_token(&sender), SecretString::from("new_pass"))
+            .await;
+        assert!(matches!(result, Err(PasswordResetError::InvalidLink)));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_reset_rejects_other_tokens() {
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_password_reset()
+            .times(0);
+        let service = service(
+            MockTestUserService::new(),
+            event_publisher,
+            Arc::new(RecordingSender::default()),
+        );
+
+        let result = service
+            .confirm_reset("not-a-token", SecretString::from("new_pass"))
+            .await;
+        assert!(matches!(result, Err(PasswordResetError::InvalidLink)));
+    }
+
+    #[tokio::test]
+    async fn test_force_reset_replaces_password_and_sends_link() {
+        let user = alice();
+        let user_id = user.id;
+        let old_hash = user.password_hash.clone();
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user()
+            .withf(move |id| *id == user_id)
+            .times(1)
+            .returning(move |_| Ok(user.clone()));
+        user_service
+            .expect_replace_password_hash()
+            .withf(move |id, hash| {
+                *id == user_id && hash.starts_with("$argon2") && *hash != old_hash
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut event_publisher = MockTestEventPublisher::new();
+        event_publisher
+            .expect_publish_user_password_reset_requested()
+            .withf(move |event| event.user_id == user_id.to_string())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let sender = Arc::new(RecordingSender::default());
+        let service = service(user_service, event_publisher, Arc::clone(&sender));
+
+        service.force_reset(&user_id).await.unwrap();
+
+        let sent = sender.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to.as_str(), "alice@example.com");
+        assert!(sent[0]
+            .text
+            .contains("An administrator reset your password"));
+    }
+}
@@ -0,0 +1,39 @@
+use thiserror::Error;
+
+use crate::domain::user::errors::UserError;
+
+/// Error for PersonalTokenId parsing failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum PersonalTokenIdError {
+    #[error("Invalid UUID format: {0}")]
+    InvalidFormat(String),
+}
+
+/// Top-level error for all personal access token operations
+#[derive(Debug, Clone, Error)]
+pub enum PersonalTokenError {
+    #[error("Invalid ID: {0}")]
+    InvalidId(#[from] PersonalTokenIdError),
+
+    #[error("Invalid token name: {0}")]
+    InvalidName(String),
+
+    #[error("Invalid token scopes: {0}")]
+    InvalidScopes(String),
+
+    #[error("Invalid token lifetime: {0}")]
+    InvalidLifetime(String),
+
+    #[error("Personal access token not found: {0}")]
+    NotFound(String),
+
+    /// Presented token is unknown or expired, or its owner is not active
+    #[error("Personal access token is invalid")]
+    Invalid,
+
+    #[error(transparent)]
+    User(#[from] UserError),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
@@ -0,0 +1,187 @@
+use std::fmt;
+
+use auth::SecretString;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::personal_token::errors::PersonalTokenError;
+use crate::domain::personal_token::errors::PersonalTokenIdError;
+use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
+
+/// Maximum length of a personal access token name.
+pub const PERSONAL_TOKEN_NAME_MAX_LENGTH: usize = 64;
+
+/// Personal access token a user minted for a script or integration.
+///
+/// The token itself is never stored, only its digest.
+#[derive(Debug, Clone)]
+pub struct PersonalToken {
+    pub id: PersonalTokenId,
+    pub user_id: UserId,
+    pub name: PersonalTokenName,
+    pub scopes: PersonalTokenScopes,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl PersonalToken {
+    /// Check if the token is past its expiration.
+    ///
+    /// # Arguments
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// True once `expires_at` is reached
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// Personal access token unique identifier type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PersonalTokenId(pub Uuid);
+
+impl PersonalTokenId {
+    /// Generate a new random token ID.
+    ///
+    /// # Returns
+    /// PersonalTokenId with random UUID v4
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Parse a token ID from string.
+    ///
+    /// # Arguments
+    /// * `s` - UUID string to parse
+    ///
+    /// # Returns
+    /// Parsed PersonalTokenId
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a valid UUID
+    pub fn from_string(s: &str) -> Result<Self, PersonalTokenIdError> {
+        Uuid::parse_str(s)
+            .map(PersonalTokenId)
+            .map_err(|e| PersonalTokenIdError::InvalidFormat(e.to_string()))
+    }
+}
+
+impl Default for PersonalTokenId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for PersonalTokenId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Name a user gave a token to recall the integration using it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonalTokenName(String);
+
+impl PersonalTokenName {
+    /// Create a validated token name.
+    ///
+    /// # Arguments
+    /// * `name` - Name, trimmed before validation
+    ///
+    /// # Returns
+    /// Validated PersonalTokenName
+    ///
+    /// # Errors
+    /// * `InvalidName` - Name is empty or longer than 64 characters
+    pub fn new(name: String) -> Result<Self, PersonalTokenError> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > PERSONAL_TOKEN_NAME_MAX_LENGTH {
+            return Err(PersonalTokenError::InvalidName(format!(
+                "must be 1 to {} characters",
+                PERSONAL_TOKEN_NAME_MAX_LENGTH
+            )));
+        }
+        Ok(Self(name.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Scopes granted to a token, see [`auth::PersonalToken::SCOPES`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonalTokenScopes(Vec<String>);
+
+impl PersonalTokenScopes {
+    /// Create a validated set of scopes.
+    ///
+    /// # Arguments
+    /// * `scopes` - Requested scopes, sorted and deduplicated
+    ///
+    /// # Returns
+    /// Validated PersonalTokenScopes
+    ///
+    /// # Errors
+    /// * `InvalidScopes` - No scope is requested, or one is unknown
+    pub fn new(mut scopes: Vec<String>) -> Result<Self, PersonalTokenError> {
+        if scopes.is_empty() {
+            return Err(PersonalTokenError::InvalidScopes(
+                "at least one scope is required".to_string(),
+            ));
+        }
+        if let Some(unknown) = scopes
+            .iter()
+            .find(|scope| !auth::PersonalToken::SCOPES.contains(&scope.as_str()))
+        {
+            return Err(PersonalTokenError::InvalidScopes(format!(
+                "unknown scope '{}'",
+                unknown
+            )));
+        }
+        scopes.sort();
+        scopes.dedup();
+        Ok(Self(scopes))
+    }
+
+    pub fn as_slice(&self) -> &[String] {
+        &self.0
+    }
+}
+
+/// Command to mint a personal access token.
+#[derive(Debug, Clone)]
+pub struct CreatePersonalTokenCommand {
+    pub name: PersonalTokenName,
+    pub scopes: PersonalTokenScopes,
+    /// Time until the token expires, None for the configured default
+    pub lifetime: Option<Duration>,
+}
+
+/// Token minted by `create_token`, with the secret shown to its owner once.
+#[derive(Debug, Clone)]
+pub struct IssuedPersonalToken {
+    pub token: PersonalToken,
+    pub secret: SecretString,
+}
+
+/// Token presented to another service, with its active owner.
+#[derive(Debug, Clone)]
+pub struct VerifiedPersonalToken {
+    pub token: PersonalToken,
+    pub user: User,
+}
+
+/// Settings of personal access tokens.
+#[derive(Debug, Clone)]
+pub struct PersonalTokenSettings {
+    /// Lifetime of tokens minted without one
+    pub default_lifetime: Duration,
+    /// Longest lifetime a token may be minted with
+    pub max_lifetime: Duration,
+}
@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::domain::personal_token::errors::PersonalTokenError;
+use crate::domain::personal_token::models::CreatePersonalTokenCommand;
+use crate::domain::personal_token::models::IssuedPersonalToken;
+use crate::domain::personal_token::models::PersonalToken;
+use crate::domain::personal_token::models::PersonalTokenId;
+use crate::domain::personal_token::models::VerifiedPersonalToken;
+use crate::domain::user::models::UserId;
+
+/// Port for minting, listing, revoking and verifying personal access tokens.
+#[async_trait]
+pub trait PersonalTokenServicePort: Send + Sync + 'static {
+    /// Mint a personal access token for a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the token
+    /// * `command` - Name, scopes and lifetime of the token
+    ///
+    /// # Returns
+    /// Stored token with its secret, which cannot be recovered later
+    ///
+    /// # Errors
+    /// * `InvalidLifetime` - Lifetime is not positive or over the configured maximum
+    /// * `User` - User does not exist
+    /// * `DatabaseError` - Token could not be stored
+    async fn create_token(
+        &self,
+        user_id: &UserId,
+        command: CreatePersonalTokenCommand,
+    ) -> Result<IssuedPersonalToken, PersonalTokenError>;
+
+    /// List the personal access tokens of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the tokens
+    ///
+    /// # Returns
+    /// Tokens, expired ones included, oldest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn list_tokens(&self, user_id: &UserId)
+        -> Result<Vec<PersonalToken>, PersonalTokenError>;
+
+    /// Revoke a personal access token of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the token
+    /// * `id` - Token to revoke
+    ///
+    /// # Errors
+    /// * `NotFound` - User has no such token
+    /// * `DatabaseError` - Database operation failed
+    async fn revoke_token(
+        &self,
+        user_id: &UserId,
+        id: &PersonalTokenId,
+    ) -> Result<(), PersonalTokenError>;
+
+    /// Resolve a token presented to another service and record its use.
+    ///
+    /// # Arguments
+    /// * `secret` - Token as presented by the caller
+    ///
+    /// # Returns
+    /// Token with its owner
+    ///
+    /// # Errors
+    /// * `Invalid` - Token is unknown, revoked or expired, or its owner is not active
+    /// * `User` - Owner could not be looked up
+    /// * `DatabaseError` - Database operation failed
+    async fn verify_token(&self, secret: &str)
+        -> Result<VerifiedPersonalToken, PersonalTokenError>;
+}
+
+/// Persistence operations for personal access tokens.
+#[async_trait]
+pub trait PersonalTokenRepository: Send + Sync + 'static {
+    /// Persist a new token.
+    ///
+    /// # Arguments
+    /// * `token` - Token to store
+    /// * `token_hash` - Digest of the token's secret
+    ///
+    /// # Returns
+    /// Stored token
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn create(
+        &self,
+        token: PersonalToken,
+        token_hash: &str,
+    ) -> Result<PersonalToken, PersonalTokenError>;
+
+    /// Retrieve the tokens of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the tokens
+    ///
+    /// # Returns
+    /// Tokens, oldest first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn list_for_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PersonalToken>, PersonalTokenError>;
+
+    /// Retrieve a token by the digest of its secret.
+    ///
+    /// # Arguments
+    /// * `token_hash` - Digest of the token's secret
+    ///
+    /// # Returns
+    /// The token, or None if no token has this digest
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PersonalToken>, PersonalTokenError>;
+
+    /// Record the last use of a token.
+    ///
+    /// # Arguments
+    /// * `id` - Token that was used
+    /// * `at` - Time of use
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn touch(
+        &self,
+        id: &PersonalTokenId,
+        at: DateTime<Utc>,
+    ) -> Result<(), PersonalTokenError>;
+
+    /// Delete a token of a user.
+    ///
+    /// # Arguments
+    /// * `user_id` - Owner of the token
+    /// * `id` - Token to delete
+    ///
+    /// # Returns
+    /// Whether a token was deleted
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn delete(
+        &self,
+        user_id: &UserId,
+        id: &PersonalTokenId,
+    ) -> Result<bool, PersonalTokenError>;
+}
@@ -0,0 +1,491 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+use clock::Clock;
+
+use crate::domain::personal_token::errors::PersonalTokenError;
+use crate::domain::personal_token::models::CreatePersonalTokenCommand;
+use crate::domain::personal_token::models::IssuedPersonalToken;
+use crate::domain::personal_token::models::PersonalToken;
+use crate::domain::personal_token::models::PersonalTokenId;
+use crate::domain::personal_token::models::PersonalTokenSettings;
+use crate::domain::personal_token::models::VerifiedPersonalToken;
+use crate::domain::personal_token::ports::PersonalTokenRepository;
+use crate::domain::personal_token::ports::PersonalTokenServicePort;
+use crate::domain::user::errors::UserError;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserServicePort;
+
+/// Domain service implementation for personal access tokens.
+///
+/// Concrete implementation of PersonalTokenServicePort with dependency injection.
+pub struct PersonalTokenService<US, PR>
+where
+    US: UserServicePort,
+    PR: PersonalTokenRepository,
+{
+    user_service: Arc<US>,
+    repository: Arc<PR>,
+    settings: PersonalTokenSettings,
+    clock: Arc<dyn Clock>,
+}
+
+impl<US, PR> PersonalTokenService<US, PR>
+where
+    US: UserServicePort,
+    PR: PersonalTokenRepository,
+{
+    /// Create a new personal access token service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `user_service` - Service looking up token owners
+    /// * `repository` - Token persistence implementation
+    /// * `settings` - Default and maximum token lifetime
+    ///
+    /// # Returns
+    /// Configured personal access token service instance
+    pub fn new(
+        user_service: Arc<US>,
+        repository: Arc<PR>,
+        settings: PersonalTokenSettings,
+    ) -> Self {
+        Self {
+            user_service,
+            repository,
+            settings,
+            clock: clock::system(),
+        }
+    }
+
+    /// Stamp and expire tokens by another clock.
+    ///
+    /// The system clock by default. A fake clock expires tokens without
+    /// waiting for them.
+    ///
+    /// # Arguments
+    /// * `clock` - Clock tokens are created, used and expired by
+    ///
+    /// # Returns
+    /// Service telling the time by the clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl<US, PR> PersonalTokenServicePort for PersonalTokenService<US, PR>
+where
+    US: UserServicePort,
+    PR: PersonalTokenRepository,
+{
+    async fn create_token(
+        &self,
+        user_id: &UserId,
+        command: CreatePersonalTokenCommand,
+    ) -> Result<IssuedPersonalToken, PersonalTokenError> {
+        let lifetime = command.lifetime.unwrap_or(self.settings.default_lifetime);
+        if lifetime <= Duration::zero() || lifetime > self.settings.max_lifetime {
+            return Err(PersonalTokenError::InvalidLifetime(format!(
+                "must be between 1 and {} days",
+                self.settings.max_lifetime.num_days()
+            )));
+        }
+
+        let user = self.user_service.get_user(user_id).await?;
+
+        let generated = auth::PersonalToken::generate();
+        let now = self.clock.now();
+        let token = self
+            .repository
+            .create(
+                PersonalToken {
+                    id: PersonalTokenId::new(),
+                    user_id: user.id,
+                    name: command.name,
+                    scopes: command.scopes,
+                    created_at: now,
+                    expires_at: now + lifetime,
+                    last_used_at: None,
+                },
+                &generated.hash,
+            )
+            .await?;
+
+        tracing::info!(
+            user_id = %user.id,
+            token_id = %token.id,
+            scopes = ?token.scopes.as_slice(),
+            "Personal access token created"
+        );
+        Ok(IssuedPersonalToken {
+            token,
+            secret: generated.token,
+        })
+    }
+
+    async fn list_tokens(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PersonalToken>, PersonalTokenError> {
+        self.repository.list_for_user(user_id).await
+    }
+
+    async fn revoke_token(
+        &self,
+        user_id: &UserId,
+        id: &PersonalTokenId,
+    ) -> Result<(), PersonalTokenError> {
+        if !self.repository.delete(user_id, id).await? {
+            return Err(PersonalTokenError::NotFound(id.to_string()));
+        }
+
+        tracing::info!(user_id = %user_id, token_id = %id, "Personal access token revoked");
+        Ok(())
+    }
+
+    async fn verify_token(
+        &self,
+        secret: &str,
+    ) -> Result<VerifiedPersonalToken, PersonalTokenError> {
+        if !auth::PersonalToken::is_personal_token(secret) {
+            return Err(PersonalTokenError::Invalid);
+        }
+
+        let now = self.clock.now();
+        let token = self
+            .repository
+            .find_by_hash(&auth::PersonalToken::hash(secret))
+            .await?
+            .filter(|token| !token.is_expired(now))
+            .ok_or(PersonalTokenError::Invalid)?;
+
+        // Tokens of deactivated, locked and deleted users stop working with them
+        let user = match self.user_service.get_user(&token.user_id).await {
+            Ok(user) => user,
+            Err(UserError::NotFound(_)) => return Err(PersonalTokenError::Invalid),
+            Err(e) => return Err(e.into()),
+        };
+
+        if let Err(e) = self.repository.touch(&token.id, now).await {
+            tracing::warn!(
+                "Failed to record use of personal access token {}: {}",
+                token.id,
+                e
+            );
+        }
+
+        Ok(VerifiedPersonalToken { token, user })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use auth::SecretString;
+    use chrono::DateTime;
+    use chrono::Utc;
+    use clock::FakeClock;
+    use mockall::mock;
+    use mockall::predicate::*;
+
+    use super::*;
+    use crate::domain::personal_token::models::PersonalTokenName;
+    use crate::domain::personal_token::models::PersonalTokenScopes;
+    use crate::domain::user::models::CreateUserCommand;
+    use crate::domain::user::models::EmailAddress;
+    use crate::domain::user::models::ImportUserCommand;
+    use crate::domain::user::models::LoginIdentifier;
+    use crate::domain::user::models::UpdateUserCommand;
+    use crate::domain::user::models::User;
+    use crate::domain::user::models::UserCursor;
+    use crate::domain::user::models::UserPage;
+    use crate::domain::user::models::UserSearchQuery;
+    use crate::domain::user::models::UserStatus;
+    use crate::domain::user::models::Username;
+
+    mock! {
+        pub TestUserService {}
+
+        #[async_trait]
+        impl UserServicePort for TestUserService {
+            async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
+            async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+            async fn get_user(&self, id: &UserId) -> Result<User, UserError>;
+            async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
+            async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+            async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
+            async fn list_users(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<UserPage, UserError>;
+            async fn search_users(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
+            async fn update_user(&self, id: &UserId, command: UpdateUserCommand) -> Result<User, UserError>;
+            async fn change_password(&self, id: &UserId, current_password: SecretString, new_password: SecretString) -> Result<(), UserError>;
+            async fn reset_password(&self, id: &UserId, new_password: SecretString) -> Result<(), UserError>;
+            async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+            async fn replace_password_hash(&self, id: &UserId, password_hash: String) -> Result<(), UserError>;
+            async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    /// Token repository keeping tokens and their digests in memory
+    #[derive(Default)]
+    struct InMemoryTokens {
+        tokens: Mutex<HashMap<String, PersonalToken>>,
+    }
+
+    #[async_trait]
+    impl PersonalTokenRepository for InMemoryTokens {
+        async fn create(
+            &self,
+            token: PersonalToken,
+            token_hash: &str,
+        ) -> Result<PersonalToken, PersonalTokenError> {
+            self.tokens
+                .lock()
+                .unwrap()
+                .insert(token_hash.to_string(), token.clone());
+            Ok(token)
+        }
+
+        async fn list_for_user(
+            &self,
+            user_id: &UserId,
+        ) -> Result<Vec<PersonalToken>, PersonalTokenError> {
+            let mut tokens: Vec<_> = self
+                .tokens
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|token| token.user_id == *user_id)
+                .cloned()
+                .collect();
+            tokens.sort_by_key(|token| token.created_at);
+            Ok(tokens)
+        }
+
+        async fn find_by_hash(
+            &self,
+            token_hash: &str,
+        ) -> Result<Option<PersonalToken>, PersonalTokenError> {
+            Ok(self.tokens.lock().unwrap().get(token_hash).cloned())
+        }
+
+        async fn touch(
+            &self,
+            id: &PersonalTokenId,
+            at: DateTime<Utc>,
+        ) -> Result<(), PersonalTokenError> {
+            if let Some(token) = self
+                .tokens
+                .lock()
+                .unwrap()
+                .values_mut()
+                .find(|token| token.id == *id)
+            {
+                token.last_used_at = Some(at);
+            }
+            Ok(())
+        }
+
+        async fn delete(
+            &self,
+            user_id: &UserId,
+            id: &PersonalTokenId,
+        ) -> Result<bool, PersonalTokenError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let before = tokens.len();
+            tokens.retain(|_, token| !(token.id == *id && token.user_id == *user_id));
+            Ok(tokens.len() < before)
+        }
+    }
+
+    fn alice() -> User {
+        User {
+            id: UserId::new(),
+            username: Username::new("alice".to_string()).unwrap(),
+            email: EmailAddress::new("alice@example.com".to_string()).unwrap(),
+            password_hash: "$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn service(
+        user_service: MockTestUserService,
+        repository: Arc<InMemoryTokens>,
+    ) -> PersonalTokenService<MockTestUserService, InMemoryTokens> {
+        PersonalTokenService::new(
+            Arc::new(user_service),
+            repository,
+            PersonalTokenSettings {
+                default_lifetime: Duration::days(30),
+                max_lifetime: Duration::days(365),
+            },
+        )
+    }
+
+    fn command(lifetime: Option<Duration>) -> CreatePersonalTokenCommand {
+        CreatePersonalTokenCommand {
+            name: PersonalTokenName::new("deploy bot".to_string()).unwrap(),
+            scopes: PersonalTokenScopes::new(vec![
+                "messages:write".to_string(),
+                "messages:read".to_string(),
+            ])
+            .unwrap(),
+            lifetime,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_created_token_verifies_until_revoked() {
+        let user = alice();
+        let user_id = user.id;
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user()
+            .with(eq(user_id))
+            .returning(move |_| Ok(user.clone()));
+        let repository = Arc::new(InMemoryTokens::default());
+        let service = service(user_service, Arc::clone(&repository));
+
+        let issued = service.create_token(&user_id, command(None)).await.unwrap();
+        let secret = issued.secret.expose_secret().to_string();
+
+        assert!(issued.token.expires_at > Utc::now() + Duration::days(29));
+        assert!(!repository.tokens.lock().unwrap().contains_key(&secret));
+
+        let verified = service.verify_token(&secret).await.unwrap();
+        assert_eq!(verified.user.id, user_id);
+        assert_eq!(
+            verified.token.scopes.as_slice(),
+            ["messages:read", "messages:write"]
+        );
+        let listed = service.list_tokens(&user_id).await.unwrap();
+        assert!(listed[0].last_used_at.is_some());
+
+        service
+            .revoke_token(&user_id, &issued.token.id)
+            .await
+            .unwrap();
+        assert!(matches!(
+            service.verify_token(&secret).await,
+            Err(PersonalTokenError::Invalid)
+        ));
+        assert!(matches!(
+            service.revoke_token(&user_id, &issued.token.id).await,
+            Err(PersonalTokenError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_lifetime_over_maximum_rejected() {
+        let service = service(
+            MockTestUserService::new(),
+            Arc::new(InMemoryTokens::default()),
+        );
+
+        let result = service
+            .create_token(&UserId::new(), command(Some(Duration::days(366))))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PersonalTokenError::InvalidLifetime(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_expired_token_and_inactive_owner_rejected() {
+        let user_id = UserId::new();
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user()
+            .with(eq(user_id))
+            .returning(|id| Err(UserError::NotFound(id.to_string())));
+        let repository = Arc::new(InMemoryTokens::default());
+        let expired = auth::PersonalToken::generate();
+        let active = auth::PersonalToken::generate();
+        for (generated, expires_at) in [
+            (&expired, Utc::now() - Duration::minutes(1)),
+            (&active, Utc::now() + Duration::days(1)),
+        ] {
+            repository
+                .create(
+                    PersonalToken {
+                        id: PersonalTokenId::new(),
+                        user_id,
+                        name: PersonalTokenName::new("script".to_string()).unwrap(),
+                        scopes: PersonalTokenScopes::new(vec!["channels:read".to_string()])
+                            .unwrap(),
+                        created_at: Utc::now() - Duration::days(30),
+                        expires_at,
+                        last_used_at: None,
+                    },
+                    &generated.hash,
+                )
+                .await
+                .unwrap();
+        }
+        let service = service(user_service, repository);
+
+        for generated in [&expired, &active] {
+            assert!(matches!(
+                service.verify_token(generated.token.expose_secret()).await,
+                Err(PersonalTokenError::Invalid)
+            ));
+        }
+        assert!(matches!(
+            service.verify_token("not-a-personal-token").await,
+            Err(PersonalTokenError::Invalid)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_token_expires_on_fake_clock() {
+        let user = alice();
+        let user_id = user.id;
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_get_user()
+            .returning(move |_| Ok(user.clone()));
+        let clock = Arc::new(FakeClock::default());
+        let service =
+            service(user_service, Arc::new(InMemoryTokens::default())).with_clock(clock.clone());
+
+        let issued = service
+            .create_token(&user_id, command(Some(Duration::days(7))))
+            .await
+            .unwrap();
+        let secret = issued.secret.expose_secret().to_string();
+        service.verify_token(&secret).await.unwrap();
+
+        clock.advance(Duration::days(8));
+        assert!(matches!(
+            service.verify_token(&secret).await,
+            Err(PersonalTokenError::Invalid)
+        ));
+    }
+
+    #[test]
+    fn test_unknown_scope_rejected() {
+        assert!(matches!(
+            PersonalTokenScopes::new(vec!["users:delete".to_string()]),
+            Err(PersonalTokenError::InvalidScopes(_))
+        ));
+        assert!(matches!(
+            PersonalTokenScopes::new(Vec::new()),
+            Err(PersonalTokenError::InvalidScopes(_))
+        ));
+    }
+}
@@ -0,0 +1,39 @@
+use thiserror::Error;
+
+use crate::domain::idempotency::errors::IdempotencyError;
+use crate::domain::user::errors::UserError;
+
+/// Error verifying a CAPTCHA response
+#[derive(Debug, Clone, Error)]
+pub enum CaptchaError {
+    #[error("CAPTCHA provider could not be reached: {0}")]
+    Unavailable(String),
+}
+
+/// Top-level error for public signups
+#[derive(Debug, Clone, Error)]
+pub enum SignupError {
+    #[error("A CAPTCHA response is required")]
+    CaptchaRequired,
+
+    #[error("CAPTCHA response was rejected")]
+    CaptchaRejected,
+
+    #[error("Email addresses at {0} are not accepted")]
+    BlockedEmailDomain(String),
+
+    #[error("Idempotency key was already used with a different signup")]
+    IdempotencyKeyReused,
+
+    #[error("A signup with this idempotency key is still in progress")]
+    SignupInProgress,
+
+    #[error(transparent)]
+    Captcha(#[from] CaptchaError),
+
+    #[error(transparent)]
+    Idempotency(#[from] IdempotencyError),
+
+    #[error(transparent)]
+    User(#[from] UserError),
+}
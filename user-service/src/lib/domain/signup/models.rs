@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+use std::net::IpAddr;
+
+use chrono::Duration;
+
+use crate::domain::idempotency::models::IdempotencyKey;
+use crate::domain::user::models::CreateUserCommand;
+use crate::domain::user::models::EmailAddress;
+
+/// Request to open an account through public registration.
+#[derive(Debug)]
+pub struct SignupCommand {
+    pub user: CreateUserCommand,
+    /// Response token of the CAPTCHA widget solved by the client
+    pub captcha_response: Option<String>,
+    /// Address the request came from, passed on to the CAPTCHA provider
+    pub client_ip: Option<IpAddr>,
+    /// Key of a signup the client may retry, see [`IdempotencyKey`]
+    pub idempotency_key: Option<IdempotencyKey>,
+}
+
+/// Settings of public registration.
+#[derive(Debug, Clone)]
+pub struct SignupSettings {
+    /// Lowercase email domains rejected as disposable, their subdomains included
+    pub blocked_email_domains: HashSet<String>,
+    /// How long retries under an idempotency key get the outcome of the first signup
+    pub idempotency_ttl: Duration,
+}
+
+impl Default for SignupSettings {
+    fn default() -> Self {
+        Self::new(std::iter::empty::<&str>())
+    }
+}
+
+impl SignupSettings {
+    /// Create settings blocking a list of email domains.
+    ///
+    /// # Arguments
+    /// * `blocked_email_domains` - Domains to reject, in any case
+    ///
+    /// # Returns
+    /// SignupSettings with the domains normalized to lowercase, keeping
+    /// idempotency keys for a day
+    pub fn new<I, S>(blocked_email_domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            blocked_email_domains: blocked_email_domains
+                .into_iter()
+                .map(|domain| domain.as_ref().trim().to_lowercase())
+                .filter(|domain| !domain.is_empty())
+                .collect(),
+            idempotency_ttl: Duration::hours(24),
+        }
+    }
+
+    /// Keep idempotency keys for another duration.
+    ///
+    /// # Arguments
+    /// * `ttl` - How long retries get the outcome of the first signup
+    pub fn with_idempotency_ttl(mut self, ttl: Duration) -> Self {
+        self.idempotency_ttl = ttl;
+        self
+    }
+
+    /// Find the blocked domain an email address belongs to.
+    ///
+    /// # Arguments
+    /// * `email` - Address to check
+    ///
+    /// # Returns
+    /// The blocked domain, or None if the address is accepted
+    pub fn blocked_domain(&self, email: &EmailAddress) -> Option<&str> {
+        let domain = email.domain().to_lowercase();
+        let mut candidate = domain.as_str();
+        loop {
+            if let Some(blocked) = self.blocked_email_domains.get(candidate) {
+                return Some(blocked);
+            }
+            candidate = candidate.split_once('.')?.1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn email(address: &str) -> EmailAddress {
+        EmailAddress::new(address.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_blocked_domain_matches_subdomains_in_any_case() {
+        let settings = SignupSettings::new(["Mailinator.com", " "]);
+
+        assert_eq!(
+            settings.blocked_domain(&email("bot@mailinator.com")),
+            Some("mailinator.com")
+        );
+        assert_eq!(
+            settings.blocked_domain(&email("bot@eu.MAILINATOR.com")),
+            Some("mailinator.com")
+        );
+        assert_eq!(settings.blocked_domain(&email("alice@example.com")), None);
+        assert_eq!(
+            settings.blocked_domain(&email("alice@notmailinator.com")),
+            None
+        );
+    }
+}
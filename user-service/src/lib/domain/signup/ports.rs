@@ -0,0 +1,75 @@
+use std::net::IpAddr;
+
+use async_trait::async_trait;
+
+use crate::domain::signup::errors::CaptchaError;
+use crate::domain::signup::errors::SignupError;
+use crate::domain::signup::models::SignupCommand;
+use crate::domain::user::models::EmailAddress;
+use crate::domain::user::models::User;
+
+/// Port for opening accounts through public registration.
+#[async_trait]
+pub trait SignupServicePort: Send + Sync + 'static {
+    /// Create an account for an anonymous client.
+    ///
+    /// A signup retried under its idempotency key returns the user created by
+    /// the first attempt, without checking the CAPTCHA again.
+    ///
+    /// # Arguments
+    /// * `command` - Account to create, with the CAPTCHA response of the client
+    ///
+    /// # Returns
+    /// Created user
+    ///
+    /// # Errors
+    /// * `BlockedEmailDomain` - Email address is at a disposable domain
+    /// * `CaptchaRequired` - CAPTCHA is enabled and no response was sent
+    /// * `CaptchaRejected` - CAPTCHA provider rejected the response
+    /// * `Captcha` - CAPTCHA provider could not be reached
+    /// * `IdempotencyKeyReused` - Key was used with another username or email
+    /// * `SignupInProgress` - First signup under the key has not finished
+    /// * `Idempotency` - Key could not be claimed
+    /// * `User` - User could not be created
+    async fn sign_up(&self, command: SignupCommand) -> Result<User, SignupError>;
+
+    /// Check that an anonymous client may open an account, without opening it.
+    ///
+    /// Applies the checks of [`SignupServicePort::sign_up`] to accounts opened
+    /// another way, such as by a magic link sign-up.
+    ///
+    /// # Arguments
+    /// * `email` - Email address of the account
+    /// * `captcha_response` - CAPTCHA response of the client, if it sent one
+    /// * `client_ip` - Address of the client, if known
+    ///
+    /// # Errors
+    /// * `BlockedEmailDomain` - Email address is at a disposable domain
+    /// * `CaptchaRequired` - CAPTCHA is enabled and no response was sent
+    /// * `CaptchaRejected` - CAPTCHA provider rejected the response
+    /// * `Captcha` - CAPTCHA provider could not be reached
+    async fn screen_signup(
+        &self,
+        email: &EmailAddress,
+        captcha_response: Option<String>,
+        client_ip: Option<IpAddr>,
+    ) -> Result<(), SignupError>;
+}
+
+/// Port checking CAPTCHA responses with a CAPTCHA provider.
+#[async_trait]
+pub trait CaptchaVerifier: Send + Sync + 'static {
+    /// Check the response of a solved CAPTCHA.
+    ///
+    /// # Arguments
+    /// * `response` - Response token from the client
+    /// * `client_ip` - Address of the client, if known
+    ///
+    /// # Returns
+    /// Whether the provider accepted the response
+    ///
+    /// # Errors
+    /// * `Unavailable` - Provider could not be reached or answered unexpectedly
+    async fn verify(&self, response: &str, client_ip: Option<IpAddr>)
+        -> Result<bool, CaptchaError>;
+}
@@ -0,0 +1,500 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::domain::idempotency::ports::IdempotencyRepository;
+use crate::domain::signup::errors::SignupError;
+use crate::domain::signup::models::SignupCommand;
+use crate::domain::signup::models::SignupSettings;
+use crate::domain::signup::ports::CaptchaVerifier;
+use crate::domain::signup::ports::SignupServicePort;
+use crate::domain::user::models::CreateUserCommand;
+use crate::domain::user::models::EmailAddress;
+use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserServicePort;
+
+/// Operation signup idempotency keys are recorded under.
+const CREATE_USER_OPERATION: &str = "create_user";
+
+/// Domain service implementation for public registration.
+///
+/// Concrete implementation of SignupServicePort with dependency injection.
+pub struct SignupService<US, CV, IR>
+where
+    US: UserServicePort,
+    CV: CaptchaVerifier,
+    IR: IdempotencyRepository,
+{
+    user_service: Arc<US>,
+    captcha_verifier: Option<Arc<CV>>,
+    idempotency_repository: Arc<IR>,
+    settings: SignupSettings,
+}
+
+impl<US, CV, IR> SignupService<US, CV, IR>
+where
+    US: UserServicePort,
+    CV: CaptchaVerifier,
+    IR: IdempotencyRepository,
+{
+    /// Create a new signup service with injected dependencies.
+    ///
+    /// # Arguments
+    /// * `user_service` - Service creating the accounts
+    /// * `captcha_verifier` - Provider checking CAPTCHA responses, or None to accept signups without one
+    /// * `idempotency_repository` - Records of signups made under an idempotency key
+    /// * `settings` - Blocked email domains and idempotency key lifetime
+    ///
+    /// # Returns
+    /// Configured signup service instance
+    pub fn new(
+        user_service: Arc<US>,
+        captcha_verifier: Option<Arc<CV>>,
+        idempotency_repository: Arc<IR>,
+        settings: SignupSettings,
+    ) -> Self {
+        Self {
+            user_service,
+            captcha_verifier,
+            idempotency_repository,
+            settings,
+        }
+    }
+
+    /// Check the CAPTCHA response and create the user.
+    async fn create_user(&self, command: SignupCommand) -> Result<User, SignupError> {
+        self.check_captcha(command.captcha_response.as_deref(), command.client_ip)
+            .await?;
+
+        Ok(self.user_service.create_user(command.user).await?)
+    }
+
+    /// Refuse email addresses at blocked domains.
+    fn check_email_domain(&self, email: &EmailAddress) -> Result<(), SignupError> {
+        if let Some(domain) = self.settings.blocked_domain(email) {
+            tracing::info!(domain = %domain, "Signup with blocked email domain refused");
+            return Err(SignupError::BlockedEmailDomain(domain.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Check the CAPTCHA response of the client, if CAPTCHA is enabled.
+    async fn check_captcha(
+        &self,
+        captcha_response: Option<&str>,
+        client_ip: Option<IpAddr>,
+    ) -> Result<(), SignupError> {
+        if let Some(captcha_verifier) = &self.captcha_verifier {
+            let response = captcha_response
+                .filter(|response| !response.is_empty())
+                .ok_or(SignupError::CaptchaRequired)?;
+            if !captcha_verifier.verify(response, client_ip).await? {
+                tracing::info!(client_ip = ?client_ip, "Signup with rejected CAPTCHA refused");
+                return Err(SignupError::CaptchaRejected);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<US, CV, IR> SignupServicePort for SignupService<US, CV, IR>
+where
+    US: UserServicePort,
+    CV: CaptchaVerifier,
+    IR: IdempotencyRepository,
+{
+    async fn sign_up(&self, command: SignupCommand) -> Result<User, SignupError> {
+        self.check_email_domain(&command.user.email)?;
+
+        let Some(key) = command.idempotency_key.clone() else {
+            return self.create_user(command).await;
+        };
+
+        // Claimed before the CAPTCHA check, whose responses cannot be verified twice
+        let fingerprint = fingerprint(&command.user);
+        let expired_before = Utc::now() - self.settings.idempotency_ttl;
+        if let Some(record) = self
+            .idempotency_repository
+            .claim(CREATE_USER_OPERATION, &key, &fingerprint, expired_before)
+            .await?
+        {
+            if record.fingerprint != fingerprint {
+                return Err(SignupError::IdempotencyKeyReused);
+            }
+            let user_id = record.resource_id.ok_or(SignupError::SignupInProgress)?;
+            tracing::info!(user_id = %user_id, "Retried signup answered with the user it created");
+            return Ok(self.user_service.get_user(&UserId(user_id)).await?);
+        }
+
+        match self.create_user(command).await {
+            Ok(user) => {
+                // The user exists either way, retries then get `SignupInProgress` until the key expires
+                if let Err(e) = self
+                    .idempotency_repository
+                    .complete(CREATE_USER_OPERATION, &key, user.id.0)
+                    .await
+                {
+                    tracing::warn!(user_id = %user.id, error = %e, "Failed to record signup under its idempotency key");
+                }
+                Ok(user)
+            }
+            Err(err) => {
+                if let Err(e) = self
+                    .idempotency_repository
+                    .release(CREATE_USER_OPERATION, &key)
+                    .await
+                {
+                    tracing::warn!(error = %e, "Failed to release idempotency key of a failed signup");
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn screen_signup(
+        &self,
+        email: &EmailAddress,
+        captcha_response: Option<String>,
+        client_ip: Option<IpAddr>,
+    ) -> Result<(), SignupError> {
+        self.check_email_domain(email)?;
+        self.check_captcha(captcha_response.as_deref(), client_ip)
+            .await
+    }
+}
+
+/// Digest of the account a signup creates, telling retries from other signups.
+///
+/// The password is left out, so that no digest of it is stored.
+fn fingerprint(command: &CreateUserCommand) -> String {
+    Sha256::digest(format!(
+        "{}\n{}",
+        command.username.as_str(),
+        command.email.as_str()
+    ))
+    .iter()
+    .map(|byte| format!("{:02x}", byte))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use auth::SecretString;
+    use chrono::Utc;
+    use mockall::mock;
+
+    use super::*;
+    use crate::domain::idempotency::errors::IdempotencyError;
+    use crate::domain::idempotency::models::IdempotencyKey;
+    use crate::domain::idempotency::models::IdempotencyRecord;
+    use crate::domain::signup::errors::CaptchaError;
+    use crate::domain::user::errors::UserError;
+    use crate::domain::user::models::EmailAddress;
+    use crate::domain::user::models::ImportUserCommand;
+    use crate::domain::user::models::LoginIdentifier;
+    use crate::domain::user::models::UpdateUserCommand;
+    use crate::domain::user::models::UserCursor;
+    use crate::domain::user::models::UserPage;
+    use crate::domain::user::models::UserSearchQuery;
+    use crate::domain::user::models::UserStatus;
+    use crate::domain::user::models::Username;
+
+    mock! {
+        pub TestUserService {}
+
+        #[async_trait]
+        impl UserServicePort for TestUserService {
+            async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
+            async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+            async fn get_user(&self, id: &UserId) -> Result<User, UserError>;
+            async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
+            async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+            async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+            async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
+            async fn list_users(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<UserPage, UserError>;
+            async fn search_users(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
+            async fn update_user(&self, id: &UserId, command: UpdateUserCommand) -> Result<User, UserError>;
+            async fn change_password(&self, id: &UserId, current_password: SecretString, new_password: SecretString) -> Result<(), UserError>;
+            async fn reset_password(&self, id: &UserId, new_password: SecretString) -> Result<(), UserError>;
+            async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+            async fn replace_password_hash(&self, id: &UserId, password_hash: String) -> Result<(), UserError>;
+            async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+            async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    mock! {
+        pub TestCaptchaVerifier {}
+
+        #[async_trait]
+        impl CaptchaVerifier for TestCaptchaVerifier {
+            async fn verify(&self, response: &str, client_ip: Option<IpAddr>) -> Result<bool, CaptchaError>;
+        }
+    }
+
+    mock! {
+        pub TestIdempotencyRepository {}
+
+        #[async_trait]
+        impl IdempotencyRepository for TestIdempotencyRepository {
+            async fn claim(&self, operation: &str, key: &IdempotencyKey, fingerprint: &str, expired_before: chrono::DateTime<Utc>) -> Result<Option<IdempotencyRecord>, IdempotencyError>;
+            async fn complete(&self, operation: &str, key: &IdempotencyKey, resource_id: uuid::Uuid) -> Result<(), IdempotencyError>;
+            async fn release(&self, operation: &str, key: &IdempotencyKey) -> Result<(), IdempotencyError>;
+        }
+    }
+
+    fn command(email: &str, captcha_response: Option<&str>) -> SignupCommand {
+        SignupCommand {
+            user: CreateUserCommand::new(
+                Username::new("alice".to_string()).unwrap(),
+                EmailAddress::new(email.to_string()).unwrap(),
+                SecretString::new("Correct-Horse-7"),
+            ),
+            captcha_response: captcha_response.map(str::to_string),
+            client_ip: None,
+            idempotency_key: None,
+        }
+    }
+
+    fn retried(email: &str) -> SignupCommand {
+        SignupCommand {
+            idempotency_key: Some(IdempotencyKey::new("signup-1".to_string()).unwrap()),
+            ..command(email, None)
+        }
+    }
+
+    fn created(command: CreateUserCommand) -> User {
+        User {
+            id: UserId::new(),
+            username: command.username,
+            email: command.email,
+            password_hash: "$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        }
+    }
+
+    type TestSignupService =
+        SignupService<MockTestUserService, MockTestCaptchaVerifier, MockTestIdempotencyRepository>;
+
+    fn service(
+        user_service: MockTestUserService,
+        captcha_verifier: Option<MockTestCaptchaVerifier>,
+    ) -> TestSignupService {
+        service_with_keys(
+            user_service,
+            captcha_verifier,
+            MockTestIdempotencyRepository::new(),
+        )
+    }
+
+    fn service_with_keys(
+        user_service: MockTestUserService,
+        captcha_verifier: Option<MockTestCaptchaVerifier>,
+        idempotency_repository: MockTestIdempotencyRepository,
+    ) -> TestSignupService {
+        SignupService::new(
+            Arc::new(user_service),
+            captcha_verifier.map(Arc::new),
+            Arc::new(idempotency_repository),
+            SignupSettings::new(["mailinator.com"]),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_sign_up_with_accepted_captcha() {
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_create_user()
+            .times(1)
+            .returning(|command| Ok(created(command)));
+        let mut captcha_verifier = MockTestCaptchaVerifier::new();
+        captcha_verifier
+            .expect_verify()
+            .withf(|response, _| response == "solved")
+            .returning(|_, _| Ok(true));
+        let service = service(user_service, Some(captcha_verifier));
+
+        let user = service
+            .sign_up(command("alice@example.com", Some("solved")))
+            .await
+            .unwrap();
+
+        assert_eq!(user.email.as_str(), "alice@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_sign_up_without_or_with_rejected_captcha() {
+        let mut captcha_verifier = MockTestCaptchaVerifier::new();
+        captcha_verifier.expect_verify().returning(|_, _| Ok(false));
+        let service = service(MockTestUserService::new(), Some(captcha_verifier));
+
+        assert!(matches!(
+            service.sign_up(command("alice@example.com", None)).await,
+            Err(SignupError::CaptchaRequired)
+        ));
+        assert!(matches!(
+            service
+                .sign_up(command("alice@example.com", Some("guessed")))
+                .await,
+            Err(SignupError::CaptchaRejected)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sign_up_with_blocked_email_domain() {
+        let service = service(MockTestUserService::new(), None);
+
+        let result = service.sign_up(command("alice@mailinator.com", None)).await;
+
+        assert!(matches!(
+            result,
+            Err(SignupError::BlockedEmailDomain(domain)) if domain == "mailinator.com"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_screen_signup_without_creating_user() {
+        let mut user_service = MockTestUserService::new();
+        user_service.expect_create_user().never();
+        let mut captcha_verifier = MockTestCaptchaVerifier::new();
+        captcha_verifier
+            .expect_verify()
+            .returning(|response, _| Ok(response == "solved"));
+        let service = service(user_service, Some(captcha_verifier));
+        let email = |address: &str| EmailAddress::new(address.to_string()).unwrap();
+
+        assert!(matches!(
+            service
+                .screen_signup(
+                    &email("alice@mailinator.com"),
+                    Some("solved".to_string()),
+                    None
+                )
+                .await,
+            Err(SignupError::BlockedEmailDomain(_))
+        ));
+        assert!(matches!(
+            service
+                .screen_signup(&email("alice@example.com"), None, None)
+                .await,
+            Err(SignupError::CaptchaRequired)
+        ));
+        assert!(matches!(
+            service
+                .screen_signup(
+                    &email("alice@example.com"),
+                    Some("guessed".to_string()),
+                    None
+                )
+                .await,
+            Err(SignupError::CaptchaRejected)
+        ));
+        assert!(service
+            .screen_signup(
+                &email("alice@example.com"),
+                Some("solved".to_string()),
+                None
+            )
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_retried_signup_returns_created_user() {
+        let first = retried("alice@example.com");
+        let user = created(CreateUserCommand::new(
+            first.user.username.clone(),
+            first.user.email.clone(),
+            SecretString::new("Correct-Horse-7"),
+        ));
+        let user_id = user.id;
+        let fingerprint = fingerprint(&first.user);
+
+        let mut user_service = MockTestUserService::new();
+        user_service.expect_create_user().never();
+        user_service
+            .expect_get_user()
+            .withf(move |id| *id == user_id)
+            .returning(move |_| Ok(user.clone()));
+        let mut idempotency_repository = MockTestIdempotencyRepository::new();
+        idempotency_repository
+            .expect_claim()
+            .withf(|operation, key, _, _| operation == "create_user" && key.as_str() == "signup-1")
+            .returning(move |_, _, _, _| {
+                Ok(Some(IdempotencyRecord {
+                    fingerprint: fingerprint.clone(),
+                    resource_id: Some(user_id.0),
+                    created_at: Utc::now(),
+                }))
+            });
+        let service = service_with_keys(user_service, None, idempotency_repository);
+
+        let user = service.sign_up(first).await.unwrap();
+
+        assert_eq!(user.id, user_id);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_reused_or_in_progress() {
+        let fingerprint = fingerprint(&retried("alice@example.com").user);
+        let mut idempotency_repository = MockTestIdempotencyRepository::new();
+        idempotency_repository
+            .expect_claim()
+            .returning(move |_, _, _, _| {
+                Ok(Some(IdempotencyRecord {
+                    fingerprint: fingerprint.clone(),
+                    resource_id: None,
+                    created_at: Utc::now(),
+                }))
+            });
+        let service = service_with_keys(MockTestUserService::new(), None, idempotency_repository);
+
+        assert!(matches!(
+            service.sign_up(retried("alice@example.com")).await,
+            Err(SignupError::SignupInProgress)
+        ));
+        assert!(matches!(
+            service.sign_up(retried("mallory@example.com")).await,
+            Err(SignupError::IdempotencyKeyReused)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failed_signup_releases_idempotency_key() {
+        let mut user_service = MockTestUserService::new();
+        user_service
+            .expect_create_user()
+            .returning(|_| Err(UserError::UsernameAlreadyExists("alice".to_string())));
+        let mut idempotency_repository = MockTestIdempotencyRepository::new();
+        idempotency_repository
+            .expect_claim()
+            .returning(|_, _, _, _| Ok(None));
+        idempotency_repository.expect_complete().never();
+        idempotency_repository
+            .expect_release()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let service = service_with_keys(user_service, None, idempotency_repository);
+
+        assert!(matches!(
+            service.sign_up(retried("alice@example.com")).await,
+            Err(SignupError::User(UserError::UsernameAlreadyExists(_)))
+        ));
+    }
+}
@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+/// Error storing an object
+#[derive(Debug, Clone, Error)]
+pub enum StorageError {
+    #[error("Object could not be stored: {0}")]
+    UploadFailed(String),
+}
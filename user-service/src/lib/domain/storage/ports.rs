@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use crate::domain::storage::errors::StorageError;
+
+/// Port storing publicly served files, so the storage backend can be swapped.
+#[async_trait]
+pub trait ObjectStorage: Send + Sync + 'static {
+    /// Store an object, replacing any object with the same key.
+    ///
+    /// # Arguments
+    /// * `key` - Path of the object within the storage
+    /// * `content_type` - Media type the object is served with
+    /// * `bytes` - Content of the object
+    ///
+    /// # Returns
+    /// Public URL the object is served from
+    ///
+    /// # Errors
+    /// * `UploadFailed` - Object could not be stored
+    async fn put_object(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<String, StorageError>;
+}
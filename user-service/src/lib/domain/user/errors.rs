@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::domain::user::models::UserStatus;
+
 /// Error for UserId parsing failures
 #[derive(Debug, Clone, Error, PartialEq, Eq)]
 pub enum UserIdError {
@@ -29,6 +31,56 @@ pub enum EmailError {
     InvalidFormat(String),
 }
 
+/// Error for DisplayName validation failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum DisplayNameError {
+    #[error("Display name is empty")]
+    Empty,
+
+    #[error("Display name too long: maximum {max} characters, got {actual}")]
+    TooLong { max: usize, actual: usize },
+
+    #[error("Display name contains control characters")]
+    InvalidCharacters,
+}
+
+/// Error for AvatarUrl validation failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum AvatarUrlError {
+    #[error("Invalid avatar URL: {0}")]
+    InvalidFormat(String),
+
+    #[error("Avatar URL must use https, got {0}")]
+    InsecureScheme(String),
+
+    #[error("Avatar URL too long: maximum {max} bytes, got {actual}")]
+    TooLong { max: usize, actual: usize },
+}
+
+/// Error for Bio validation failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum BioError {
+    #[error("Bio too long: maximum {max} characters, got {actual}")]
+    TooLong { max: usize, actual: usize },
+}
+
+/// Error for UserCursor parsing failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum UserCursorError {
+    #[error("Invalid cursor: {0}")]
+    InvalidFormat(String),
+}
+
+/// Error for UserSearchQuery validation failures
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum UserSearchQueryError {
+    #[error("Search query is empty")]
+    Empty,
+
+    #[error("Search query too long: maximum {max} characters, got {actual}")]
+    TooLong { max: usize, actual: usize },
+}
+
 /// Error for password operations
 #[derive(Debug, Clone, Error)]
 pub enum PasswordError {
@@ -37,6 +89,9 @@ pub enum PasswordError {
 
     #[error("Password verification failed: {0}")]
     VerificationFailed(String),
+
+    #[error("Unsupported password hash format")]
+    UnsupportedHash,
 }
 
 /// Error for event publishing operations
@@ -68,6 +123,15 @@ pub enum UserError {
     #[error("Invalid email: {0}")]
     InvalidEmail(#[from] EmailError),
 
+    #[error("Invalid display name: {0}")]
+    InvalidDisplayName(#[from] DisplayNameError),
+
+    #[error("Invalid avatar URL: {0}")]
+    InvalidAvatarUrl(#[from] AvatarUrlError),
+
+    #[error("Invalid bio: {0}")]
+    InvalidBio(#[from] BioError),
+
     #[error("Password error: {0}")]
     Password(#[from] PasswordError),
 
@@ -78,15 +142,24 @@ pub enum UserError {
     #[error("User not found with username: {0}")]
     NotFoundByUsername(String),
 
+    #[error("User not found with email: {0}")]
+    NotFoundByEmail(String),
+
     #[error("Username already exists: {0}")]
     UsernameAlreadyExists(String),
 
     #[error("Email already exists: {0}")]
     EmailAlreadyExists(String),
 
+    #[error("User {id} is already {status}")]
+    StatusUnchanged { id: String, status: UserStatus },
+
     #[error("Invalid credentials")]
     InvalidCredentials,
 
+    #[error("Password has appeared in a data breach, please choose a different one")]
+    CompromisedPassword,
+
     // Infrastructure errors
     #[error("Database error: {0}")]
     DatabaseError(String),
@@ -10,6 +10,12 @@ pub enum UserEvent {
     UserCreated(UserCreatedEvent),
     UserUpdated(UserUpdatedEvent),
     UserDeleted(UserDeletedEvent),
+    UserDeactivated(UserDeactivatedEvent),
+    UserReactivated(UserReactivatedEvent),
+    UserPasswordChanged(UserPasswordChangedEvent),
+    UserPasswordResetRequested(UserPasswordResetRequestedEvent),
+    UserPasswordReset(UserPasswordResetEvent),
+    UserAccountLocked(UserAccountLockedEvent),
 }
 
 impl UserEvent {
@@ -22,18 +28,33 @@ impl UserEvent {
             UserEvent::UserCreated(e) => &e.event_id,
             UserEvent::UserUpdated(e) => &e.event_id,
             UserEvent::UserDeleted(e) => &e.event_id,
+            UserEvent::UserDeactivated(e) => &e.event_id,
+            UserEvent::UserReactivated(e) => &e.event_id,
+            UserEvent::UserPasswordChanged(e) => &e.event_id,
+            UserEvent::UserPasswordResetRequested(e) => &e.event_id,
+            UserEvent::UserPasswordReset(e) => &e.event_id,
+            UserEvent::UserAccountLocked(e) => &e.event_id,
         }
     }
 
     /// Get the event type name.
     ///
     /// # Returns
-    /// Event type string ("user_created", "user_updated", or "user_deleted")
+    /// Event type string ("user_created", "user_updated", "user_deleted",
+    /// "user_deactivated", "user_reactivated", "user_password_changed",
+    /// "user_password_reset_requested", "user_password_reset" or
+    /// "user_account_locked")
     pub fn event_type(&self) -> &str {
         match self {
             UserEvent::UserCreated(_) => "user_created",
             UserEvent::UserUpdated(_) => "user_updated",
             UserEvent::UserDeleted(_) => "user_deleted",
+            UserEvent::UserDeactivated(_) => "user_deactivated",
+            UserEvent::UserReactivated(_) => "user_reactivated",
+            UserEvent::UserPasswordChanged(_) => "user_password_changed",
+            UserEvent::UserPasswordResetRequested(_) => "user_password_reset_requested",
+            UserEvent::UserPasswordReset(_) => "user_password_reset",
+            UserEvent::UserAccountLocked(_) => "user_account_locked",
         }
     }
 
@@ -46,6 +67,12 @@ impl UserEvent {
             UserEvent::UserCreated(e) => &e.user_id,
             UserEvent::UserUpdated(e) => &e.user_id,
             UserEvent::UserDeleted(e) => &e.user_id,
+            UserEvent::UserDeactivated(e) => &e.user_id,
+            UserEvent::UserReactivated(e) => &e.user_id,
+            UserEvent::UserPasswordChanged(e) => &e.user_id,
+            UserEvent::UserPasswordResetRequested(e) => &e.user_id,
+            UserEvent::UserPasswordReset(e) => &e.user_id,
+            UserEvent::UserAccountLocked(e) => &e.user_id,
         }
     }
 }
@@ -92,6 +119,9 @@ pub struct UserUpdatedEvent {
     pub user_id: String,
     pub username: String,
     pub email: String,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -111,6 +141,12 @@ impl UserUpdatedEvent {
             user_id: user.id.to_string(),
             username: user.username.as_str().to_string(),
             email: user.email.as_str().to_string(),
+            display_name: user
+                .display_name
+                .as_ref()
+                .map(|name| name.as_str().to_string()),
+            avatar_url: user.avatar_url.as_ref().map(|url| url.as_str().to_string()),
+            bio: user.bio.as_ref().map(|bio| bio.as_str().to_string()),
             updated_at: Utc::now(),
         }
     }
@@ -144,3 +180,187 @@ impl UserDeletedEvent {
         }
     }
 }
+
+/// Domain event published when a user deactivates their account.
+///
+/// The account is kept and can be reactivated; until then it is hidden from
+/// lookups and cannot sign in.
+#[derive(Debug, Clone)]
+pub struct UserDeactivatedEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub deactivated_at: DateTime<Utc>,
+}
+
+impl UserDeactivatedEvent {
+    /// Create a new UserDeactivated event.
+    ///
+    /// Generates a unique event ID and captures current timestamp.
+    ///
+    /// # Arguments
+    /// * `user_id` - ID of the deactivated user
+    ///
+    /// # Returns
+    /// UserDeactivatedEvent with unique event ID and deactivation timestamp
+    pub fn new(user_id: String) -> Self {
+        Self {
+            event_id: Uuid::new_v4().to_string(),
+            user_id,
+            deactivated_at: Utc::now(),
+        }
+    }
+}
+
+/// Domain event published when a deactivated account is reactivated.
+#[derive(Debug, Clone)]
+pub struct UserReactivatedEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub reactivated_at: DateTime<Utc>,
+}
+
+impl UserReactivatedEvent {
+    /// Create a new UserReactivated event.
+    ///
+    /// Generates a unique event ID and captures current timestamp.
+    ///
+    /// # Arguments
+    /// * `user_id` - ID of the reactivated user
+    ///
+    /// # Returns
+    /// UserReactivatedEvent with unique event ID and reactivation timestamp
+    pub fn new(user_id: String) -> Self {
+        Self {
+            event_id: Uuid::new_v4().to_string(),
+            user_id,
+            reactivated_at: Utc::now(),
+        }
+    }
+}
+
+/// Domain event published when a user changes their password.
+///
+/// Carries no credentials; consumers use it to end the user's other sessions.
+#[derive(Debug, Clone)]
+pub struct UserPasswordChangedEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl UserPasswordChangedEvent {
+    /// Create a new UserPasswordChanged event.
+    ///
+    /// Generates a unique event ID and captures current timestamp.
+    ///
+    /// # Arguments
+    /// * `user_id` - ID of the user whose password changed
+    ///
+    /// # Returns
+    /// UserPasswordChangedEvent with unique event ID and change timestamp
+    pub fn new(user_id: String) -> Self {
+        Self {
+            event_id: Uuid::new_v4().to_string(),
+            user_id,
+            changed_at: Utc::now(),
+        }
+    }
+}
+
+/// Domain event published when a password reset link is sent to a user.
+///
+/// Lets security tooling spot reset attempts the user did not make.
+#[derive(Debug, Clone)]
+pub struct UserPasswordResetRequestedEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub requested_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl UserPasswordResetRequestedEvent {
+    /// Create a new UserPasswordResetRequested event.
+    ///
+    /// Generates a unique event ID and captures current timestamp.
+    ///
+    /// # Arguments
+    /// * `user_id` - ID of the user the reset link was sent to
+    /// * `expires_at` - Expiration of the reset link
+    ///
+    /// # Returns
+    /// UserPasswordResetRequestedEvent with unique event ID and request timestamp
+    pub fn new(user_id: String, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            event_id: Uuid::new_v4().to_string(),
+            user_id,
+            requested_at: Utc::now(),
+            expires_at,
+        }
+    }
+}
+
+/// Domain event published when a user sets a new password by a reset link.
+///
+/// Carries no credentials; like UserPasswordChanged, consumers use it to end
+/// the user's sessions.
+#[derive(Debug, Clone)]
+pub struct UserPasswordResetEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub reset_at: DateTime<Utc>,
+}
+
+impl UserPasswordResetEvent {
+    /// Create a new UserPasswordReset event.
+    ///
+    /// Generates a unique event ID and captures current timestamp.
+    ///
+    /// # Arguments
+    /// * `user_id` - ID of the user whose password was reset
+    ///
+    /// # Returns
+    /// UserPasswordResetEvent with unique event ID and reset timestamp
+    pub fn new(user_id: String) -> Self {
+        Self {
+            event_id: Uuid::new_v4().to_string(),
+            user_id,
+            reset_at: Utc::now(),
+        }
+    }
+}
+
+/// Domain event published when repeated failed logins lock a user's account.
+///
+/// The lock ends by itself at `locked_until`; consumers can use the event to
+/// alert the user or security monitoring.
+#[derive(Debug, Clone)]
+pub struct UserAccountLockedEvent {
+    pub event_id: String,
+    pub user_id: String,
+    pub failed_attempts: u32,
+    pub locked_at: DateTime<Utc>,
+    pub locked_until: DateTime<Utc>,
+}
+
+impl UserAccountLockedEvent {
+    /// Create a new UserAccountLocked event.
+    ///
+    /// Generates a unique event ID and captures current timestamp.
+    ///
+    /// # Arguments
+    /// * `user_id` - ID of the locked user
+    /// * `failed_attempts` - Failed logins in a row that locked the account
+    /// * `locked_until` - End of the lock
+    ///
+    /// # Returns
+    /// UserAccountLockedEvent with unique event ID and lock timestamp
+    pub fn new(user_id: String, failed_attempts: u32, locked_until: DateTime<Utc>) -> Self {
+        Self {
+            event_id: Uuid::new_v4().to_string(),
+            user_id,
+            failed_attempts,
+            locked_at: Utc::now(),
+            locked_until,
+        }
+    }
+}
@@ -1,12 +1,19 @@
 use std::fmt;
 use std::str::FromStr;
 
+use auth::SecretString;
 use chrono::DateTime;
 use chrono::Utc;
 use uuid::Uuid;
 
+use crate::user::errors::AvatarUrlError;
+use crate::user::errors::BioError;
+use crate::user::errors::DisplayNameError;
 use crate::user::errors::EmailError;
+use crate::user::errors::UserCursorError;
+use crate::user::errors::UserError;
 use crate::user::errors::UserIdError;
+use crate::user::errors::UserSearchQueryError;
 use crate::user::errors::UsernameError;
 
 /// User aggregate entity.
@@ -18,9 +25,30 @@ pub struct User {
     pub username: Username,
     pub email: EmailAddress,
     pub password_hash: String,
+    /// Whether the owner confirmed `email` by a verification link
+    pub email_verified: bool,
+    /// Name shown instead of the username, if the user chose one
+    pub display_name: Option<DisplayName>,
+    pub avatar_url: Option<AvatarUrl>,
+    pub bio: Option<Bio>,
+    /// Roles granted to the user, embedded in the tokens issued to it
+    pub roles: Vec<Role>,
     pub created_at: DateTime<Utc>,
 }
 
+impl User {
+    /// Check if the user holds a role.
+    ///
+    /// # Arguments
+    /// * `role` - Role to look for
+    ///
+    /// # Returns
+    /// True if the role was granted to the user
+    pub fn has_role(&self, role: Role) -> bool {
+        self.roles.contains(&role)
+    }
+}
+
 /// User unique identifier type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct UserId(pub Uuid);
@@ -51,6 +79,12 @@ impl UserId {
     }
 }
 
+impl Default for UserId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl fmt::Display for UserId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -160,6 +194,392 @@ impl EmailAddress {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Get the domain part of the email.
+    ///
+    /// # Returns
+    /// Text after the last `@`
+    pub fn domain(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+}
+
+/// Identifier a user logs in with: their username or their email address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoginIdentifier {
+    Username(Username),
+    Email(EmailAddress),
+}
+
+impl LoginIdentifier {
+    /// Parse a login identifier.
+    ///
+    /// Text with an `@` is taken as an email address, since usernames cannot contain one.
+    ///
+    /// # Arguments
+    /// * `identifier` - Raw username or email address
+    ///
+    /// # Returns
+    /// Validated LoginIdentifier
+    ///
+    /// # Errors
+    /// * `InvalidUsername` - Neither a valid username nor an email address
+    /// * `InvalidEmail` - Contains an `@` but is not a valid email address
+    pub fn parse(identifier: String) -> Result<Self, UserError> {
+        if identifier.contains('@') {
+            Ok(Self::Email(EmailAddress::new(identifier)?))
+        } else {
+            Ok(Self::Username(Username::new(identifier)?))
+        }
+    }
+
+    /// Get the identifier as a string slice.
+    ///
+    /// # Returns
+    /// String slice of the username or email address
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Username(username) => username.as_str(),
+            Self::Email(email) => email.as_str(),
+        }
+    }
+}
+
+/// Display name type
+///
+/// Ensures the name is trimmed, 1-64 characters and free of control characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayName(String);
+
+impl DisplayName {
+    const MAX_LENGTH: usize = 64;
+
+    /// Create a new valid display name.
+    ///
+    /// # Arguments
+    /// * `name` - Raw display name, surrounding whitespace is dropped
+    ///
+    /// # Returns
+    /// Validated DisplayName value object
+    ///
+    /// # Errors
+    /// * `Empty` - Name is blank
+    /// * `TooLong` - Name longer than 64 characters
+    /// * `InvalidCharacters` - Name contains control characters
+    pub fn new(name: &str) -> Result<Self, DisplayNameError> {
+        let name = name.trim();
+        let length = name.chars().count();
+        if length == 0 {
+            Err(DisplayNameError::Empty)
+        } else if length > Self::MAX_LENGTH {
+            Err(DisplayNameError::TooLong {
+                max: Self::MAX_LENGTH,
+                actual: length,
+            })
+        } else if name.chars().any(char::is_control) {
+            Err(DisplayNameError::InvalidCharacters)
+        } else {
+            Ok(Self(name.to_string()))
+        }
+    }
+
+    /// Get display name as string slice.
+    ///
+    /// # Returns
+    /// Display name string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Avatar URL type
+///
+/// Ensures the URL is absolute, uses HTTPS and is at most 2048 bytes long.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvatarUrl(String);
+
+impl AvatarUrl {
+    const MAX_LENGTH: usize = 2048;
+
+    /// Create a new valid avatar URL.
+    ///
+    /// # Arguments
+    /// * `url` - Raw URL string
+    ///
+    /// # Returns
+    /// Validated AvatarUrl value object
+    ///
+    /// # Errors
+    /// * `TooLong` - URL longer than 2048 bytes
+    /// * `InvalidFormat` - Not an absolute URL
+    /// * `InsecureScheme` - Scheme other than `https`
+    pub fn new(url: &str) -> Result<Self, AvatarUrlError> {
+        let url = url.trim();
+        if url.len() > Self::MAX_LENGTH {
+            return Err(AvatarUrlError::TooLong {
+                max: Self::MAX_LENGTH,
+                actual: url.len(),
+            });
+        }
+        let parsed =
+            reqwest::Url::parse(url).map_err(|e| AvatarUrlError::InvalidFormat(e.to_string()))?;
+        if parsed.scheme() != "https" {
+            return Err(AvatarUrlError::InsecureScheme(parsed.scheme().to_string()));
+        }
+
+        Ok(Self(url.to_string()))
+    }
+
+    /// Get avatar URL as string slice.
+    ///
+    /// # Returns
+    /// URL string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Profile biography type
+///
+/// Ensures the text is trimmed and at most 500 characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bio(String);
+
+impl Bio {
+    const MAX_LENGTH: usize = 500;
+
+    /// Create a new valid biography.
+    ///
+    /// # Arguments
+    /// * `bio` - Raw biography text, surrounding whitespace is dropped
+    ///
+    /// # Returns
+    /// Validated Bio value object
+    ///
+    /// # Errors
+    /// * `TooLong` - Text longer than 500 characters
+    pub fn new(bio: &str) -> Result<Self, BioError> {
+        let bio = bio.trim();
+        let length = bio.chars().count();
+        if length > Self::MAX_LENGTH {
+            Err(BioError::TooLong {
+                max: Self::MAX_LENGTH,
+                actual: length,
+            })
+        } else {
+            Ok(Self(bio.to_string()))
+        }
+    }
+
+    /// Get biography as string slice.
+    ///
+    /// # Returns
+    /// Biography string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Lifecycle state of an account.
+///
+/// Only active users are returned by lookups. Deactivated accounts can be
+/// reactivated and locked ones unlocked by an admin; deleted ones are kept for
+/// retention but never come back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStatus {
+    Active,
+    Deactivated,
+    /// Blocked by an admin
+    Locked,
+    Deleted,
+}
+
+impl UserStatus {
+    /// Get the status as stored in the `users.status` column.
+    ///
+    /// # Returns
+    /// Status name ("active", "deactivated", "locked" or "deleted")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserStatus::Active => "active",
+            UserStatus::Deactivated => "deactivated",
+            UserStatus::Locked => "locked",
+            UserStatus::Deleted => "deleted",
+        }
+    }
+
+    /// Parse a status stored in the `users.status` column.
+    ///
+    /// # Arguments
+    /// * `status` - Status name
+    ///
+    /// # Returns
+    /// Matching status, None for an unknown name
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "active" => Some(UserStatus::Active),
+            "deactivated" => Some(UserStatus::Deactivated),
+            "locked" => Some(UserStatus::Locked),
+            "deleted" => Some(UserStatus::Deleted),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for UserStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Role granting privileges beyond those of a regular user.
+///
+/// Roles are stored in the `users.roles` column and embedded in the `roles`
+/// claim of issued tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// Manages users and accounts through `/api/admin`
+    Admin,
+}
+
+impl Role {
+    /// Get the role as stored in `users.roles` and the `roles` claim.
+    ///
+    /// # Returns
+    /// Role name
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+        }
+    }
+
+    /// Parse a stored role name.
+    ///
+    /// # Arguments
+    /// * `role` - Role name
+    ///
+    /// # Returns
+    /// Matching role, None for an unknown name
+    pub fn parse(role: &str) -> Option<Self> {
+        match role {
+            "admin" => Some(Role::Admin),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Position in the user listing, right after the last user of a page.
+///
+/// Users are listed newest first. The cursor carries the creation time and ID
+/// of the last listed user, so the next page continues after it even when
+/// users sign up in between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: UserId,
+}
+
+impl UserCursor {
+    /// Create the cursor continuing after a user.
+    ///
+    /// # Arguments
+    /// * `user` - Last user of a page
+    ///
+    /// # Returns
+    /// Cursor of the next page
+    pub fn after(user: &User) -> Self {
+        Self {
+            created_at: user.created_at,
+            id: user.id,
+        }
+    }
+
+    /// Parse a cursor previously rendered with `Display`.
+    ///
+    /// # Arguments
+    /// * `s` - Cursor string
+    ///
+    /// # Returns
+    /// Parsed UserCursor
+    ///
+    /// # Errors
+    /// * `InvalidFormat` - String is not a cursor
+    pub fn parse(s: &str) -> Result<Self, UserCursorError> {
+        let invalid = || UserCursorError::InvalidFormat(s.to_string());
+        let (micros, id) = s.split_once('_').ok_or_else(invalid)?;
+        let created_at = micros
+            .parse()
+            .ok()
+            .and_then(DateTime::from_timestamp_micros)
+            .ok_or_else(invalid)?;
+        let id = UserId::from_string(id).map_err(|_| invalid())?;
+
+        Ok(Self { created_at, id })
+    }
+}
+
+impl fmt::Display for UserCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.created_at.timestamp_micros(), self.id)
+    }
+}
+
+/// Page of the user listing.
+#[derive(Debug, Clone)]
+pub struct UserPage {
+    pub users: Vec<User>,
+    /// Cursor of the following page, None on the last page
+    pub next_cursor: Option<UserCursor>,
+}
+
+/// Text searched for in usernames.
+///
+/// Trimmed, non-empty and at most 64 characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserSearchQuery(String);
+
+impl UserSearchQuery {
+    const MAX_LENGTH: usize = 64;
+
+    /// Create a new validated search query.
+    ///
+    /// # Arguments
+    /// * `query` - Raw search text
+    ///
+    /// # Returns
+    /// Trimmed UserSearchQuery value object
+    ///
+    /// # Errors
+    /// * `Empty` - Query is blank
+    /// * `TooLong` - Query longer than 64 characters
+    pub fn new(query: &str) -> Result<Self, UserSearchQueryError> {
+        let query = query.trim();
+        let length = query.chars().count();
+        if length == 0 {
+            Err(UserSearchQueryError::Empty)
+        } else if length > Self::MAX_LENGTH {
+            Err(UserSearchQueryError::TooLong {
+                max: Self::MAX_LENGTH,
+                actual: length,
+            })
+        } else {
+            Ok(Self(query.to_string()))
+        }
+    }
+
+    /// Get the query as string slice.
+    ///
+    /// # Returns
+    /// Query string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 /// Command to create a new user with domain types
@@ -167,7 +587,7 @@ impl EmailAddress {
 pub struct CreateUserCommand {
     pub username: Username,
     pub email: EmailAddress,
-    pub password: String,
+    pub password: SecretString,
 }
 
 impl CreateUserCommand {
@@ -180,7 +600,7 @@ impl CreateUserCommand {
     ///
     /// # Returns
     /// CreateUserCommand with validated fields
-    pub fn new(username: Username, email: EmailAddress, password: String) -> Self {
+    pub fn new(username: Username, email: EmailAddress, password: SecretString) -> Self {
         Self {
             username,
             email,
@@ -189,6 +609,16 @@ impl CreateUserCommand {
     }
 }
 
+/// Command to import a user migrated from another system.
+#[derive(Debug)]
+pub struct ImportUserCommand {
+    pub username: Username,
+    pub email: EmailAddress,
+    /// Password hash carried over from the source system, in PHC string format.
+    /// Users imported without one must reset their password before logging in.
+    pub password_hash: Option<String>,
+}
+
 /// Command to update an existing user with optional validated fields.
 ///
 /// All fields are optional to support partial updates.
@@ -197,5 +627,11 @@ impl CreateUserCommand {
 pub struct UpdateUserCommand {
     pub username: Option<Username>,
     pub email: Option<EmailAddress>,
-    pub password: Option<String>,
+    pub password: Option<SecretString>,
+    /// `Some(None)` removes the display name
+    pub display_name: Option<Option<DisplayName>>,
+    /// `Some(None)` removes the avatar
+    pub avatar_url: Option<Option<AvatarUrl>>,
+    /// `Some(None)` removes the biography
+    pub bio: Option<Option<Bio>>,
 }
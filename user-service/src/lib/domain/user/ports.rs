@@ -1,12 +1,27 @@
 use async_trait::async_trait;
+use auth::SecretString;
 
+use crate::domain::user::events::UserAccountLockedEvent;
 use crate::domain::user::events::UserCreatedEvent;
+use crate::domain::user::events::UserDeactivatedEvent;
 use crate::domain::user::events::UserDeletedEvent;
+use crate::domain::user::events::UserEvent;
+use crate::domain::user::events::UserPasswordChangedEvent;
+use crate::domain::user::events::UserPasswordResetEvent;
+use crate::domain::user::events::UserPasswordResetRequestedEvent;
+use crate::domain::user::events::UserReactivatedEvent;
 use crate::domain::user::events::UserUpdatedEvent;
 use crate::domain::user::models::CreateUserCommand;
+use crate::domain::user::models::EmailAddress;
+use crate::domain::user::models::ImportUserCommand;
+use crate::domain::user::models::LoginIdentifier;
 use crate::domain::user::models::UpdateUserCommand;
 use crate::domain::user::models::User;
+use crate::domain::user::models::UserCursor;
 use crate::domain::user::models::UserId;
+use crate::domain::user::models::UserPage;
+use crate::domain::user::models::UserSearchQuery;
+use crate::domain::user::models::UserStatus;
 use crate::user::errors::EventPublisherError;
 use crate::user::errors::UserError;
 use crate::user::models::Username;
@@ -28,6 +43,25 @@ pub trait UserServicePort: Send + Sync + 'static {
     /// * `DatabaseError` - Database operation failed
     async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError>;
 
+    /// Create a user migrated from another system.
+    ///
+    /// Keeps the source system's password hash when it is supported, so
+    /// users can log in with their existing password. Publishes `UserCreated`
+    /// like a regular registration.
+    ///
+    /// # Arguments
+    /// * `command` - Validated command with an optional pre-hashed password
+    ///
+    /// # Returns
+    /// Created user entity
+    ///
+    /// # Errors
+    /// * `Password(UnsupportedHash)` - Password hash is neither Argon2 nor bcrypt
+    /// * `UsernameAlreadyExists` - Username is already taken
+    /// * `EmailAlreadyExists` - Email is already registered
+    /// * `DatabaseError` - Database operation failed
+    async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError>;
+
     /// Retrieve user by unique identifier.
     ///
     /// # Arguments
@@ -54,6 +88,33 @@ pub trait UserServicePort: Send + Sync + 'static {
     /// * `DatabaseError` - Database operation failed
     async fn get_user_by_username(&self, username: &Username) -> Result<User, UserError>;
 
+    /// Retrieve user by unique email address.
+    ///
+    /// # Arguments
+    /// * `email` - Email address to search for
+    ///
+    /// # Returns
+    /// User entity
+    ///
+    /// # Errors
+    /// * `NotFoundByEmail` - No user with this email address
+    /// * `DatabaseError` - Database operation failed
+    async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError>;
+
+    /// Retrieve the user a login identifier names.
+    ///
+    /// # Arguments
+    /// * `identifier` - Username or email address
+    ///
+    /// # Returns
+    /// User entity
+    ///
+    /// # Errors
+    /// * `NotFoundByUsername` - No user with this username
+    /// * `NotFoundByEmail` - No user with this email address
+    /// * `DatabaseError` - Database operation failed
+    async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError>;
+
     /// Retrieve multiple users by identifiers.
     ///
     /// # Arguments
@@ -66,6 +127,45 @@ pub trait UserServicePort: Send + Sync + 'static {
     /// * `DatabaseError` - Database operation failed
     async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError>;
 
+    /// List users with a status newest first, one page at a time.
+    ///
+    /// # Arguments
+    /// * `status` - Status of the listed users, `Active` outside of admin tools
+    /// * `limit` - Maximum number of users on the page
+    /// * `after` - Cursor of the page to return, None for the first page
+    ///
+    /// # Returns
+    /// Page of users with the cursor of the following page, if any
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn list_users(
+        &self,
+        status: UserStatus,
+        limit: u32,
+        after: Option<UserCursor>,
+    ) -> Result<UserPage, UserError>;
+
+    /// Search users by username.
+    ///
+    /// Usernames and email addresses starting with the query rank first,
+    /// followed by usernames similar to it, for @mention autocomplete.
+    ///
+    /// # Arguments
+    /// * `query` - Text to search for
+    /// * `limit` - Maximum number of users to return
+    ///
+    /// # Returns
+    /// Matching users, best match first
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn search_users(
+        &self,
+        query: &UserSearchQuery,
+        limit: u32,
+    ) -> Result<Vec<User>, UserError>;
+
     /// Update existing user with optional fields.
     ///
     /// # Arguments
@@ -83,8 +183,152 @@ pub trait UserServicePort: Send + Sync + 'static {
     async fn update_user(&self, id: &UserId, command: UpdateUserCommand)
         -> Result<User, UserError>;
 
+    /// Change a user's password after re-checking the current one.
+    ///
+    /// Publishes a UserPasswordChanged event so that consumers can end the
+    /// user's other sessions.
+    ///
+    /// # Arguments
+    /// * `id` - User ID to update
+    /// * `current_password` - Password the user has now
+    /// * `new_password` - Password to replace it with
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `NotFound` - User does not exist
+    /// * `InvalidCredentials` - Current password does not match
+    /// * `CompromisedPassword` - New password appears in a breach corpus
+    /// * `DatabaseError` - Database operation failed
+    async fn change_password(
+        &self,
+        id: &UserId,
+        current_password: SecretString,
+        new_password: SecretString,
+    ) -> Result<(), UserError>;
+
+    /// Set a new password for a user who proved access by other means.
+    ///
+    /// Publishes no event; the password reset flow reports resets itself.
+    ///
+    /// # Arguments
+    /// * `id` - User ID to update
+    /// * `new_password` - Password to set
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `NotFound` - User does not exist
+    /// * `CompromisedPassword` - New password appears in a breach corpus
+    /// * `DatabaseError` - Database operation failed
+    async fn reset_password(
+        &self,
+        id: &UserId,
+        new_password: SecretString,
+    ) -> Result<(), UserError>;
+
+    /// Mark a user's current email address as verified.
+    ///
+    /// Publishes no event, since the user's public data does not change.
+    ///
+    /// # Arguments
+    /// * `id` - User ID to update
+    ///
+    /// # Returns
+    /// Updated user entity
+    ///
+    /// # Errors
+    /// * `NotFound` - User does not exist
+    /// * `DatabaseError` - Database operation failed
+    async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError>;
+
+    /// Replace a user's stored password hash without changing the password.
+    ///
+    /// Used after login to upgrade imported legacy hashes. Publishes no event,
+    /// since nothing visible about the user changes.
+    ///
+    /// # Arguments
+    /// * `id` - User ID to update
+    /// * `password_hash` - New hash of the user's current password
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `NotFound` - User does not exist
+    /// * `DatabaseError` - Database operation failed
+    async fn replace_password_hash(
+        &self,
+        id: &UserId,
+        password_hash: String,
+    ) -> Result<(), UserError>;
+
+    /// Deactivate an active user.
+    ///
+    /// The account is hidden from lookups and cannot sign in until it is
+    /// reactivated.
+    ///
+    /// # Arguments
+    /// * `id` - User ID to deactivate
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `NotFound` - User does not exist or is deleted
+    /// * `StatusUnchanged` - User is already deactivated
+    /// * `DatabaseError` - Database operation failed
+    async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+
+    /// Reactivate a deactivated user.
+    ///
+    /// # Arguments
+    /// * `id` - User ID to reactivate
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `NotFound` - User does not exist or is deleted
+    /// * `StatusUnchanged` - User is already active
+    /// * `DatabaseError` - Database operation failed
+    async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError>;
+
+    /// Lock an active user, blocking the account until an admin unlocks it.
+    ///
+    /// # Arguments
+    /// * `id` - User ID to lock
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `NotFound` - User does not exist or is deleted
+    /// * `StatusUnchanged` - User is not active
+    /// * `DatabaseError` - Database operation failed
+    async fn lock_user(&self, id: &UserId) -> Result<(), UserError>;
+
+    /// Unlock a locked user.
+    ///
+    /// # Arguments
+    /// * `id` - User ID to unlock
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `NotFound` - User does not exist or is deleted
+    /// * `StatusUnchanged` - User is not locked
+    /// * `DatabaseError` - Database operation failed
+    async fn unlock_user(&self, id: &UserId) -> Result<(), UserError>;
+
     /// Delete existing user.
     ///
+    /// The user is soft deleted: the row is kept for retention, but the
+    /// account is gone for good.
+    ///
     /// # Arguments
     /// * `id` - User ID to delete
     ///
@@ -92,18 +336,25 @@ pub trait UserServicePort: Send + Sync + 'static {
     /// Unit on success
     ///
     /// # Errors
-    /// * `NotFound` - User does not exist
+    /// * `NotFound` - User does not exist or is already deleted
     /// * `DatabaseError` - Database operation failed
     async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
 }
 
 /// Persistence operations for user aggregate.
+///
+/// Lookups and updates only see active users; deactivated and deleted users
+/// are reached through `update_status` and `delete` alone.
+///
+/// Mutations store their event in the outbox in the same transaction, so the
+/// event is published by the outbox relay if and only if the change is committed.
 #[async_trait]
 pub trait UserRepository: Send + Sync + 'static {
     /// Persist new user to storage.
     ///
     /// # Arguments
     /// * `user` - User entity to create
+    /// * `event` - Event to store in the outbox with the user
     ///
     /// # Returns
     /// Created user entity
@@ -112,7 +363,7 @@ pub trait UserRepository: Send + Sync + 'static {
     /// * `UsernameAlreadyExists` - Username is already taken
     /// * `EmailAlreadyExists` - Email is already registered
     /// * `DatabaseError` - Database operation failed
-    async fn create(&self, user: User) -> Result<User, UserError>;
+    async fn create(&self, user: User, event: UserEvent) -> Result<User, UserError>;
 
     /// Retrieve user by identifier.
     ///
@@ -150,14 +401,38 @@ pub trait UserRepository: Send + Sync + 'static {
     /// * `DatabaseError` - Database operation failed
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, UserError>;
 
-    /// Retrieve all users from storage.
+    /// Retrieve users with a status newest first, continuing after a cursor.
+    ///
+    /// # Arguments
+    /// * `status` - Status of the users to return
+    /// * `limit` - Maximum number of users to return
+    /// * `after` - Position to continue after, None to start with the newest user
     ///
     /// # Returns
-    /// Vector of all users
+    /// Vector of users ordered by creation time (and ID) descending
     ///
     /// # Errors
     /// * `DatabaseError` - Database operation failed
-    async fn list_all(&self) -> Result<Vec<User>, UserError>;
+    async fn list_page(
+        &self,
+        status: UserStatus,
+        limit: u32,
+        after: Option<UserCursor>,
+    ) -> Result<Vec<User>, UserError>;
+
+    /// Retrieve users whose username matches a query.
+    ///
+    /// # Arguments
+    /// * `query` - Text to search for
+    /// * `limit` - Maximum number of users to return
+    ///
+    /// # Returns
+    /// Users with a username starting with the query, then users with a
+    /// username similar to it, each group by similarity descending
+    ///
+    /// # Errors
+    /// * `DatabaseError` - Database operation failed
+    async fn search(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
 
     /// Retrieve multiple users by identifiers.
     ///
@@ -175,6 +450,8 @@ pub trait UserRepository: Send + Sync + 'static {
     ///
     /// # Arguments
     /// * `user` - User entity with updated fields
+    /// * `event` - Event to store in the outbox with the update, None for changes
+    ///   downstream consumers do not see
     ///
     /// # Returns
     /// Updated user entity
@@ -184,20 +461,47 @@ pub trait UserRepository: Send + Sync + 'static {
     /// * `UsernameAlreadyExists` - New username is already taken
     /// * `EmailAlreadyExists` - New email is already registered
     /// * `DatabaseError` - Database operation failed
-    async fn update(&self, user: User) -> Result<User, UserError>;
+    async fn update(&self, user: User, event: Option<UserEvent>) -> Result<User, UserError>;
+
+    /// Move a user from one status to another.
+    ///
+    /// # Arguments
+    /// * `id` - User ID to update
+    /// * `from` - Status the user must have
+    /// * `to` - New status of the user
+    /// * `event` - Event to store in the outbox with the status change
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `NotFound` - User does not exist or is deleted
+    /// * `StatusUnchanged` - User exists but does not have status `from`,
+    ///   carrying its current status
+    /// * `DatabaseError` - Database operation failed
+    async fn update_status(
+        &self,
+        id: &UserId,
+        from: UserStatus,
+        to: UserStatus,
+        event: UserEvent,
+    ) -> Result<(), UserError>;
 
-    /// Remove user from storage.
+    /// Mark user as deleted.
+    ///
+    /// The row is kept with its deletion time, but lookups no longer return it.
     ///
     /// # Arguments
     /// * `id` - User ID to delete
+    /// * `event` - Event to store in the outbox with the deletion
     ///
     /// # Returns
     /// Unit on success
     ///
     /// # Errors
-    /// * `NotFound` - User does not exist
+    /// * `NotFound` - User does not exist or is already deleted
     /// * `DatabaseError` - Database operation failed
-    async fn delete(&self, id: &UserId) -> Result<(), UserError>;
+    async fn delete(&self, id: &UserId, event: UserEvent) -> Result<(), UserError>;
 }
 
 /// Event publishing for domain events.
@@ -256,4 +560,112 @@ pub trait EventPublisher: Send + Sync + 'static {
         &self,
         event: &UserDeletedEvent,
     ) -> Result<(), EventPublisherError>;
+
+    /// Publish user deactivation event.
+    ///
+    /// # Arguments
+    /// * `event` - UserDeactivated event
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `SerializationFailed` - Event serialization failed
+    /// * `PublishFailed` - Failed to publish to broker
+    /// * `ConnectionFailed` - Broker connection failed
+    /// * `Timeout` - Publishing timed out
+    async fn publish_user_deactivated(
+        &self,
+        event: &UserDeactivatedEvent,
+    ) -> Result<(), EventPublisherError>;
+
+    /// Publish user reactivation event.
+    ///
+    /// # Arguments
+    /// * `event` - UserReactivated event
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `SerializationFailed` - Event serialization failed
+    /// * `PublishFailed` - Failed to publish to broker
+    /// * `ConnectionFailed` - Broker connection failed
+    /// * `Timeout` - Publishing timed out
+    async fn publish_user_reactivated(
+        &self,
+        event: &UserReactivatedEvent,
+    ) -> Result<(), EventPublisherError>;
+
+    /// Publish password change event.
+    ///
+    /// # Arguments
+    /// * `event` - UserPasswordChanged event
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `SerializationFailed` - Event serialization failed
+    /// * `PublishFailed` - Failed to publish to broker
+    /// * `ConnectionFailed` - Broker connection failed
+    /// * `Timeout` - Publishing timed out
+    async fn publish_user_password_changed(
+        &self,
+        event: &UserPasswordChangedEvent,
+    ) -> Result<(), EventPublisherError>;
+
+    /// Publish password reset request event.
+    ///
+    /// # Arguments
+    /// * `event` - UserPasswordResetRequested event
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `SerializationFailed` - Event serialization failed
+    /// * `PublishFailed` - Failed to publish to broker
+    /// * `ConnectionFailed` - Broker connection failed
+    /// * `Timeout` - Publishing timed out
+    async fn publish_user_password_reset_requested(
+        &self,
+        event: &UserPasswordResetRequestedEvent,
+    ) -> Result<(), EventPublisherError>;
+
+    /// Publish password reset event.
+    ///
+    /// # Arguments
+    /// * `event` - UserPasswordReset event
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `SerializationFailed` - Event serialization failed
+    /// * `PublishFailed` - Failed to publish to broker
+    /// * `ConnectionFailed` - Broker connection failed
+    /// * `Timeout` - Publishing timed out
+    async fn publish_user_password_reset(
+        &self,
+        event: &UserPasswordResetEvent,
+    ) -> Result<(), EventPublisherError>;
+
+    /// Publish account lockout event.
+    ///
+    /// # Arguments
+    /// * `event` - UserAccountLocked event
+    ///
+    /// # Returns
+    /// Unit on success
+    ///
+    /// # Errors
+    /// * `SerializationFailed` - Event serialization failed
+    /// * `PublishFailed` - Failed to publish to broker
+    /// * `ConnectionFailed` - Broker connection failed
+    /// * `Timeout` - Publishing timed out
+    async fn publish_user_account_locked(
+        &self,
+        event: &UserAccountLockedEvent,
+    ) -> Result<(), EventPublisherError>;
 }
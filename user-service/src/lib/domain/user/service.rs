@@ -1,89 +1,166 @@
 use std::sync::Arc;
+use std::time::Instant;
 
 use async_trait::async_trait;
+use auth::CompromisedPasswordChecker;
+use auth::SecretString;
 use chrono::Utc;
 
 use crate::domain::user::events::UserCreatedEvent;
+use crate::domain::user::events::UserDeactivatedEvent;
 use crate::domain::user::events::UserDeletedEvent;
+use crate::domain::user::events::UserEvent;
+use crate::domain::user::events::UserPasswordChangedEvent;
+use crate::domain::user::events::UserReactivatedEvent;
 use crate::domain::user::events::UserUpdatedEvent;
 use crate::domain::user::models::CreateUserCommand;
+use crate::domain::user::models::EmailAddress;
+use crate::domain::user::models::ImportUserCommand;
+use crate::domain::user::models::LoginIdentifier;
 use crate::domain::user::models::UpdateUserCommand;
 use crate::domain::user::models::User;
+use crate::domain::user::models::UserCursor;
 use crate::domain::user::models::UserId;
+use crate::domain::user::models::UserPage;
+use crate::domain::user::models::UserSearchQuery;
+use crate::domain::user::models::UserStatus;
 use crate::domain::user::models::Username;
+use crate::user::errors::PasswordError;
 use crate::user::errors::UserError;
-use crate::user::ports::EventPublisher;
 use crate::user::ports::UserRepository;
 use crate::user::ports::UserServicePort;
 
+/// Time spent hashing new passwords.
+pub const PASSWORD_HASH_DURATION_SECONDS: &str = "password_hash_duration_seconds";
+
 /// Domain service implementation for user operations.
 ///
 /// Concrete implementation of UserServicePort with dependency injection.
-pub struct UserService<UR, EP>
+/// Events are stored in the outbox by the repository together with the
+/// change, and published by [`crate::domain::outbox::service::OutboxRelay`].
+pub struct UserService<UR>
 where
     UR: UserRepository,
-    EP: EventPublisher,
 {
     repository: Arc<UR>,
-    event_publisher: Arc<EP>,
     password_hasher: auth::PasswordHasher,
+    password_checker: Option<Arc<dyn CompromisedPasswordChecker>>,
 }
 
-impl<UR, EP> UserService<UR, EP>
+impl<UR> UserService<UR>
 where
     UR: UserRepository,
-    EP: EventPublisher,
 {
     /// Create a new user service with injected dependencies.
     ///
     /// # Arguments
     /// * `repository` - User persistence implementation
-    /// * `event_publisher` - Domain event publishing implementation
     ///
     /// # Returns
     /// Configured user service instance
-    pub fn new(repository: Arc<UR>, event_publisher: Arc<EP>) -> Self {
+    pub fn new(repository: Arc<UR>) -> Self {
         Self {
             repository,
-            event_publisher,
-            password_hasher: auth::PasswordHasher::new(),
+            password_hasher: auth::PasswordHasher::new()
+                .with_legacy_verifier(Arc::new(auth::BcryptVerifier::new())),
+            password_checker: None,
         }
     }
-}
 
-#[async_trait]
-impl<UR, EP> UserServicePort for UserService<UR, EP>
-where
-    UR: UserRepository,
-    EP: EventPublisher,
-{
-    async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError> {
-        // Hash password using auth library
-        let password_hash = self
-            .password_hasher
-            .hash(&command.password)
-            .map_err(|e| UserError::Unknown(format!("Password hashing failed: {}", e)))?;
+    /// Reject passwords found in a breach corpus on create and update.
+    ///
+    /// # Arguments
+    /// * `password_checker` - Compromised password checker implementation
+    ///
+    /// # Returns
+    /// User service that checks new passwords before hashing them
+    pub fn with_password_checker(
+        mut self,
+        password_checker: Arc<dyn CompromisedPasswordChecker>,
+    ) -> Self {
+        self.password_checker = Some(password_checker);
+        self
+    }
 
+    /// Fail if the password is known to be compromised.
+    ///
+    /// Checker outages are logged and the password is accepted, so that an
+    /// unavailable breach corpus does not block registrations.
+    async fn insert_user(
+        &self,
+        username: Username,
+        email: EmailAddress,
+        password_hash: String,
+    ) -> Result<User, UserError> {
         let user = User {
             id: UserId::new(),
-            username: command.username,
-            email: command.email,
+            username,
+            email,
             password_hash,
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
             created_at: Utc::now(),
         };
 
-        let created_user = self.repository.create(user).await?;
+        let event = UserEvent::UserCreated(UserCreatedEvent::new(&user));
+        self.repository.create(user, event).await
+    }
+
+    fn hash_password(&self, password: &SecretString) -> Result<String, UserError> {
+        let start = Instant::now();
+        let result = self.password_hasher.hash(password);
+        metrics::histogram!(PASSWORD_HASH_DURATION_SECONDS).record(start.elapsed().as_secs_f64());
+
+        result.map_err(|e| UserError::Unknown(format!("Password hashing failed: {}", e)))
+    }
+
+    async fn ensure_not_compromised(&self, password: &SecretString) -> Result<(), UserError> {
+        let Some(checker) = &self.password_checker else {
+            return Ok(());
+        };
 
-        let event = UserCreatedEvent::new(&created_user);
-        if let Err(e) = &self.event_publisher.publish_user_created(&event).await {
-            tracing::error!(
-                "Failed to publish UserCreated event for user {}: {}",
-                created_user.id,
-                e
-            );
+        match checker.is_compromised(password.expose_secret()).await {
+            Ok(true) => Err(UserError::CompromisedPassword),
+            Ok(false) => Ok(()),
+            Err(e) => {
+                tracing::warn!("Compromised password check unavailable: {}", e);
+                Ok(())
+            }
         }
+    }
+}
+
+#[async_trait]
+impl<UR> UserServicePort for UserService<UR>
+where
+    UR: UserRepository,
+{
+    async fn create_user(&self, command: CreateUserCommand) -> Result<User, UserError> {
+        self.ensure_not_compromised(&command.password).await?;
 
-        Ok(created_user)
+        let password_hash = self.hash_password(&command.password)?;
+
+        self.insert_user(command.username, command.email, password_hash)
+            .await
+    }
+
+    async fn import_user(&self, command: ImportUserCommand) -> Result<User, UserError> {
+        let password_hash = match command.password_hash {
+            Some(hash) if self.password_hasher.accepts_hash(&hash) => hash,
+            Some(_) => return Err(PasswordError::UnsupportedHash.into()),
+            // Nobody knows this password, so the account is locked until reset
+            None => self.hash_password(&SecretString::new(format!(
+                "{}{}",
+                uuid::Uuid::new_v4(),
+                uuid::Uuid::new_v4()
+            )))?,
+        };
+
+        self.insert_user(command.username, command.email, password_hash)
+            .await
     }
 
     async fn get_user(&self, id: &UserId) -> Result<User, UserError> {
@@ -100,10 +177,53 @@ where
             .ok_or(UserError::NotFoundByUsername(username.to_string()))
     }
 
+    async fn get_user_by_email(&self, email: &EmailAddress) -> Result<User, UserError> {
+        self.repository
+            .find_by_email(email.as_str())
+            .await?
+            .ok_or(UserError::NotFoundByEmail(email.as_str().to_string()))
+    }
+
+    async fn get_user_by_login(&self, identifier: &LoginIdentifier) -> Result<User, UserError> {
+        match identifier {
+            LoginIdentifier::Username(username) => self.get_user_by_username(username).await,
+            LoginIdentifier::Email(email) => self.get_user_by_email(email).await,
+        }
+    }
+
     async fn get_users_by_ids(&self, user_ids: &[UserId]) -> Result<Vec<User>, UserError> {
         self.repository.find_by_ids(user_ids).await
     }
 
+    async fn list_users(
+        &self,
+        status: UserStatus,
+        limit: u32,
+        after: Option<UserCursor>,
+    ) -> Result<UserPage, UserError> {
+        // One extra user tells whether another page follows
+        let mut users = self
+            .repository
+            .list_page(status, limit.saturating_add(1), after)
+            .await?;
+        let next_cursor = if users.len() > limit as usize {
+            users.truncate(limit as usize);
+            users.last().map(UserCursor::after)
+        } else {
+            None
+        };
+
+        Ok(UserPage { users, next_cursor })
+    }
+
+    async fn search_users(
+        &self,
+        query: &UserSearchQuery,
+        limit: u32,
+    ) -> Result<Vec<User>, UserError> {
+        self.repository.search(query, limit).await
+    }
+
     async fn update_user(
         &self,
         id: &UserId,
@@ -120,40 +240,140 @@ where
         }
 
         if let Some(new_email) = command.email {
+            // A new address has to be verified again
+            if new_email != user.email {
+                user.email_verified = false;
+            }
             user.email = new_email;
         }
 
-        if let Some(new_password) = command.password {
-            user.password_hash = self
-                .password_hasher
-                .hash(&new_password)
-                .map_err(|e| UserError::Unknown(format!("Password hashing failed: {}", e)))?;
+        if let Some(display_name) = command.display_name {
+            user.display_name = display_name;
         }
 
-        let updated_user = self.repository.update(user).await?;
+        if let Some(avatar_url) = command.avatar_url {
+            user.avatar_url = avatar_url;
+        }
+
+        if let Some(bio) = command.bio {
+            user.bio = bio;
+        }
 
-        let event = UserUpdatedEvent::new(&updated_user);
-        if let Err(e) = &self.event_publisher.publish_user_updated(&event).await {
-            tracing::error!(
-                "Failed to publish UserUpdated event for user {}: {}",
-                updated_user.id,
-                e
-            );
+        if let Some(new_password) = command.password {
+            self.ensure_not_compromised(&new_password).await?;
+            user.password_hash = self.hash_password(&new_password)?;
         }
 
-        Ok(updated_user)
+        let event = UserEvent::UserUpdated(UserUpdatedEvent::new(&user));
+        self.repository.update(user, Some(event)).await
     }
 
-    async fn delete_user(&self, id: &UserId) -> Result<(), UserError> {
-        self.repository.delete(id).await?;
+    async fn change_password(
+        &self,
+        id: &UserId,
+        current_password: SecretString,
+        new_password: SecretString,
+    ) -> Result<(), UserError> {
+        let mut user = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(UserError::NotFound(id.to_string()))?;
 
-        let event = UserDeletedEvent::new(id.to_string());
-        if let Err(e) = &self.event_publisher.publish_user_deleted(&event).await {
-            tracing::error!("Failed to publish UserDeleted event for user {}: {}", id, e);
+        let verification = self
+            .password_hasher
+            .verify(&current_password, &user.password_hash)
+            .map_err(|e| PasswordError::VerificationFailed(e.to_string()))?;
+        if !verification.is_valid() {
+            return Err(UserError::InvalidCredentials);
         }
 
+        self.ensure_not_compromised(&new_password).await?;
+        user.password_hash = self.hash_password(&new_password)?;
+        let event = UserEvent::UserPasswordChanged(UserPasswordChangedEvent::new(id.to_string()));
+        self.repository.update(user, Some(event)).await?;
+
+        Ok(())
+    }
+
+    async fn reset_password(
+        &self,
+        id: &UserId,
+        new_password: SecretString,
+    ) -> Result<(), UserError> {
+        let mut user = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(UserError::NotFound(id.to_string()))?;
+
+        self.ensure_not_compromised(&new_password).await?;
+        user.password_hash = self.hash_password(&new_password)?;
+        self.repository.update(user, None).await?;
+
         Ok(())
     }
+
+    async fn mark_email_verified(&self, id: &UserId) -> Result<User, UserError> {
+        let mut user = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(UserError::NotFound(id.to_string()))?;
+
+        user.email_verified = true;
+        self.repository.update(user, None).await
+    }
+
+    async fn replace_password_hash(
+        &self,
+        id: &UserId,
+        password_hash: String,
+    ) -> Result<(), UserError> {
+        let mut user = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or(UserError::NotFound(id.to_string()))?;
+
+        user.password_hash = password_hash;
+        self.repository.update(user, None).await?;
+        Ok(())
+    }
+
+    async fn deactivate_user(&self, id: &UserId) -> Result<(), UserError> {
+        let event = UserEvent::UserDeactivated(UserDeactivatedEvent::new(id.to_string()));
+        self.repository
+            .update_status(id, UserStatus::Active, UserStatus::Deactivated, event)
+            .await
+    }
+
+    async fn reactivate_user(&self, id: &UserId) -> Result<(), UserError> {
+        let event = UserEvent::UserReactivated(UserReactivatedEvent::new(id.to_string()));
+        self.repository
+            .update_status(id, UserStatus::Deactivated, UserStatus::Active, event)
+            .await
+    }
+
+    async fn lock_user(&self, id: &UserId) -> Result<(), UserError> {
+        // Replicas only tell whether a user can be seen, so a lock is a deactivation to them
+        let event = UserEvent::UserDeactivated(UserDeactivatedEvent::new(id.to_string()));
+        self.repository
+            .update_status(id, UserStatus::Active, UserStatus::Locked, event)
+            .await
+    }
+
+    async fn unlock_user(&self, id: &UserId) -> Result<(), UserError> {
+        let event = UserEvent::UserReactivated(UserReactivatedEvent::new(id.to_string()));
+        self.repository
+            .update_status(id, UserStatus::Locked, UserStatus::Active, event)
+            .await
+    }
+
+    async fn delete_user(&self, id: &UserId) -> Result<(), UserError> {
+        let event = UserEvent::UserDeleted(UserDeletedEvent::new(id.to_string()));
+        self.repository.delete(id, event).await
+    }
 }
 
 #[cfg(test)]
@@ -161,10 +381,12 @@ mod tests {
     use mockall::mock;
     use mockall::predicate::*;
 
+    use auth::PasswordError;
+
     use super::*;
-    use crate::domain::user::models::EmailAddress;
-    use crate::domain::user::models::Username;
-    use crate::user::errors::EventPublisherError;
+    use crate::domain::user::models::AvatarUrl;
+    use crate::domain::user::models::Bio;
+    use crate::domain::user::models::DisplayName;
 
     // Define mocks in the test module using mockall
     mock! {
@@ -172,55 +394,50 @@ mod tests {
 
         #[async_trait]
         impl UserRepository for TestUserRepository {
-            async fn create(&self, user: User) -> Result<User, UserError>;
+            async fn create(&self, user: User, event: UserEvent) -> Result<User, UserError>;
             async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, UserError>;
             async fn find_by_username(&self, username: &Username) -> Result<Option<User>, UserError>;
             async fn find_by_email(&self, email: &str) -> Result<Option<User>, UserError>;
-            async fn list_all(&self) -> Result<Vec<User>, UserError>;
+            async fn list_page(&self, status: UserStatus, limit: u32, after: Option<UserCursor>) -> Result<Vec<User>, UserError>;
+            async fn search(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError>;
             async fn find_by_ids(&self, ids: &[UserId]) -> Result<Vec<User>, UserError>;
-            async fn update(&self, user: User) -> Result<User, UserError>;
-            async fn delete(&self, id: &UserId) -> Result<(), UserError>;
+            async fn update(&self, user: User, event: Option<UserEvent>) -> Result<User, UserError>;
+            async fn update_status(&self, id: &UserId, from: UserStatus, to: UserStatus, event: UserEvent) -> Result<(), UserError>;
+            async fn delete(&self, id: &UserId, event: UserEvent) -> Result<(), UserError>;
         }
     }
 
     mock! {
-        pub TestEventPublisher {}
+        pub TestPasswordChecker {}
 
         #[async_trait]
-        impl EventPublisher for TestEventPublisher {
-            async fn publish_user_created(&self, event: &UserCreatedEvent) -> Result<(), EventPublisherError>;
-            async fn publish_user_updated(&self, event: &UserUpdatedEvent) -> Result<(), EventPublisherError>;
-            async fn publish_user_deleted(&self, event: &UserDeletedEvent) -> Result<(), EventPublisherError>;
+        impl CompromisedPasswordChecker for TestPasswordChecker {
+            async fn is_compromised(&self, password: &str) -> Result<bool, PasswordError>;
         }
     }
 
     #[tokio::test]
     async fn test_create_user_success() {
         let mut repository = MockTestUserRepository::new();
-        let mut event_publisher = MockTestEventPublisher::new();
 
         // Set up mock expectations
         repository
             .expect_create()
-            .withf(|user| {
+            .withf(|user, event| {
                 user.username.as_str() == "testuser"
                     && user.email.as_str() == "test@example.com"
                     && user.password_hash.starts_with("$argon2")
+                    && matches!(event, UserEvent::UserCreated(e) if e.user_id == user.id.to_string())
             })
             .times(1)
-            .returning(|user| Ok(user));
-
-        event_publisher
-            .expect_publish_user_created()
-            .times(1)
-            .returning(|_| Ok(()));
+            .returning(|user, _| Ok(user));
 
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let command = CreateUserCommand {
             username: Username::new("testuser".to_string()).unwrap(),
             email: EmailAddress::new("test@example.com".to_string()).unwrap(),
-            password: "password123".to_string(),
+            password: SecretString::from("password123"),
         };
 
         let result = service.create_user(command).await;
@@ -233,25 +450,170 @@ mod tests {
         assert!(user.password_hash.starts_with("$argon2"));
     }
 
+    #[tokio::test]
+    async fn test_import_user_keeps_supported_hash() {
+        let existing_hash = auth::PasswordHasher::new()
+            .hash(&SecretString::from("password123"))
+            .unwrap();
+        let expected_hash = existing_hash.clone();
+
+        let mut repository = MockTestUserRepository::new();
+        repository
+            .expect_create()
+            .withf(move |user, _| user.password_hash == expected_hash)
+            .times(1)
+            .returning(|user, _| Ok(user));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let command = ImportUserCommand {
+            username: Username::new("migrated".to_string()).unwrap(),
+            email: EmailAddress::new("migrated@example.com".to_string()).unwrap(),
+            password_hash: Some(existing_hash),
+        };
+
+        assert!(service.import_user(command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_import_user_rejects_unsupported_hash() {
+        let mut repository = MockTestUserRepository::new();
+        repository.expect_create().never();
+
+        let service = UserService::new(Arc::new(repository));
+
+        let command = ImportUserCommand {
+            username: Username::new("migrated".to_string()).unwrap(),
+            email: EmailAddress::new("migrated@example.com".to_string()).unwrap(),
+            password_hash: Some("md5:5f4dcc3b5aa765d61d8327deb882cf99".to_string()),
+        };
+
+        let result = service.import_user(command).await;
+        assert!(matches!(
+            result,
+            Err(UserError::Password(
+                crate::user::errors::PasswordError::UnsupportedHash
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_import_user_without_hash_is_locked() {
+        let mut repository = MockTestUserRepository::new();
+        repository
+            .expect_create()
+            .withf(|user, _| user.password_hash.starts_with("$argon2"))
+            .times(1)
+            .returning(|user, _| Ok(user));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let command = ImportUserCommand {
+            username: Username::new("migrated".to_string()).unwrap(),
+            email: EmailAddress::new("migrated@example.com".to_string()).unwrap(),
+            password_hash: None,
+        };
+
+        let user = service.import_user(command).await.unwrap();
+        assert!(!auth::PasswordHasher::new()
+            .verify(&SecretString::from(""), &user.password_hash)
+            .unwrap()
+            .is_valid());
+    }
+
+    #[tokio::test]
+    async fn test_import_user_keeps_bcrypt_hash() {
+        // bcrypt("password", cost 4)
+        const BCRYPT_HASH: &str = "$2b$04$EhTEkfX4tso89VDRX7CiHu3GFr/XYiRZFwjCd.dfqEVEjiLz2sJ7G";
+
+        let mut repository = MockTestUserRepository::new();
+        repository
+            .expect_create()
+            .withf(|user, _| user.password_hash == BCRYPT_HASH)
+            .times(1)
+            .returning(|user, _| Ok(user));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let command = ImportUserCommand {
+            username: Username::new("migrated".to_string()).unwrap(),
+            email: EmailAddress::new("migrated@example.com".to_string()).unwrap(),
+            password_hash: Some(BCRYPT_HASH.to_string()),
+        };
+
+        assert!(service.import_user(command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_user_compromised_password() {
+        let mut repository = MockTestUserRepository::new();
+        let mut password_checker = MockTestPasswordChecker::new();
+
+        password_checker
+            .expect_is_compromised()
+            .with(eq("password123"))
+            .times(1)
+            .returning(|_| Ok(true));
+
+        repository.expect_create().times(0);
+
+        let service = UserService::new(Arc::new(repository))
+            .with_password_checker(Arc::new(password_checker));
+
+        let command = CreateUserCommand {
+            username: Username::new("testuser".to_string()).unwrap(),
+            email: EmailAddress::new("test@example.com".to_string()).unwrap(),
+            password: SecretString::from("password123"),
+        };
+
+        let result = service.create_user(command).await;
+        assert!(matches!(result, Err(UserError::CompromisedPassword)));
+    }
+
+    #[tokio::test]
+    async fn test_create_user_password_check_unavailable() {
+        let mut repository = MockTestUserRepository::new();
+        let mut password_checker = MockTestPasswordChecker::new();
+
+        password_checker
+            .expect_is_compromised()
+            .times(1)
+            .returning(|_| Err(PasswordError::BreachCheckFailed("timeout".to_string())));
+
+        repository
+            .expect_create()
+            .times(1)
+            .returning(|user, _| Ok(user));
+
+        let service = UserService::new(Arc::new(repository))
+            .with_password_checker(Arc::new(password_checker));
+
+        let command = CreateUserCommand {
+            username: Username::new("testuser".to_string()).unwrap(),
+            email: EmailAddress::new("test@example.com".to_string()).unwrap(),
+            password: SecretString::from("password123"),
+        };
+
+        let result = service.create_user(command).await;
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_create_user_duplicate_username() {
         let mut repository = MockTestUserRepository::new();
-        let mut event_publisher = MockTestEventPublisher::new();
 
-        repository.expect_create().times(1).returning(|user| {
+        repository.expect_create().times(1).returning(|user, _| {
             Err(UserError::UsernameAlreadyExists(
                 user.username.as_str().to_string(),
             ))
         });
 
-        event_publisher.expect_publish_user_created().times(0);
-
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let command = CreateUserCommand {
             username: Username::new("testuser".to_string()).unwrap(),
             email: EmailAddress::new("test2@example.com".to_string()).unwrap(),
-            password: "password456".to_string(),
+            password: SecretString::from("password456"),
         };
 
         let result = service.create_user(command).await;
@@ -265,22 +627,19 @@ mod tests {
     #[tokio::test]
     async fn test_create_user_duplicate_email() {
         let mut repository = MockTestUserRepository::new();
-        let mut event_publisher = MockTestEventPublisher::new();
 
-        repository.expect_create().times(1).returning(|user| {
+        repository.expect_create().times(1).returning(|user, _| {
             Err(UserError::EmailAlreadyExists(
                 user.email.as_str().to_string(),
             ))
         });
 
-        event_publisher.expect_publish_user_created().times(0);
-
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let command = CreateUserCommand {
             username: Username::new("user2".to_string()).unwrap(),
             email: EmailAddress::new("test@example.com".to_string()).unwrap(),
-            password: "password456".to_string(),
+            password: SecretString::from("password456"),
         };
 
         let result = service.create_user(command).await;
@@ -294,7 +653,6 @@ mod tests {
     #[tokio::test]
     async fn test_get_user_success() {
         let mut repository = MockTestUserRepository::new();
-        let event_publisher = MockTestEventPublisher::new();
 
         let user_id = UserId::new();
         let expected_user = User {
@@ -302,6 +660,11 @@ mod tests {
             username: Username::new("testuser".to_string()).unwrap(),
             email: EmailAddress::new("test@example.com".to_string()).unwrap(),
             password_hash: "$argon2id$test_hash".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
             created_at: Utc::now(),
         };
 
@@ -312,7 +675,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(Some(returned_user.clone())));
 
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let result = service.get_user(&user_id).await;
         assert!(result.is_ok());
@@ -325,14 +688,13 @@ mod tests {
     #[tokio::test]
     async fn test_get_user_not_found() {
         let mut repository = MockTestUserRepository::new();
-        let event_publisher = MockTestEventPublisher::new();
 
         repository
             .expect_find_by_id()
             .times(1)
             .returning(|_| Ok(None));
 
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let non_existent_id = UserId::new();
         let result = service.get_user(&non_existent_id).await;
@@ -344,7 +706,6 @@ mod tests {
     #[tokio::test]
     async fn test_get_user_by_username_success() {
         let mut repository = MockTestUserRepository::new();
-        let event_publisher = MockTestEventPublisher::new();
 
         let username = Username::new("testuser".to_string()).unwrap();
         let expected_user = User {
@@ -352,6 +713,11 @@ mod tests {
             username: username.clone(),
             email: EmailAddress::new("test@example.com".to_string()).unwrap(),
             password_hash: "$argon2id$test_hash".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
             created_at: Utc::now(),
         };
 
@@ -363,7 +729,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(Some(returned_user.clone())));
 
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let result = service.get_user_by_username(&username).await;
         assert!(result.is_ok());
@@ -375,14 +741,13 @@ mod tests {
     #[tokio::test]
     async fn test_get_user_by_username_not_found() {
         let mut repository = MockTestUserRepository::new();
-        let event_publisher = MockTestEventPublisher::new();
 
         repository
             .expect_find_by_username()
             .times(1)
             .returning(|_| Ok(None));
 
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let username = Username::new("nonexistent".to_string()).unwrap();
         let result = service.get_user_by_username(&username).await;
@@ -393,10 +758,62 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_get_user_by_login_with_email() {
+        let mut repository = MockTestUserRepository::new();
+
+        let email = EmailAddress::new("test@example.com".to_string()).unwrap();
+        let expected_user = User {
+            id: UserId::new(),
+            username: Username::new("testuser".to_string()).unwrap(),
+            email: email.clone(),
+            password_hash: "$argon2id$test_hash".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        };
+
+        let returned_user = expected_user.clone();
+        repository
+            .expect_find_by_email()
+            .withf(|e| e == "test@example.com")
+            .times(1)
+            .returning(move |_| Ok(Some(returned_user.clone())));
+        repository.expect_find_by_username().times(0);
+
+        let service = UserService::new(Arc::new(repository));
+
+        let identifier = LoginIdentifier::parse("test@example.com".to_string()).unwrap();
+        let user = service.get_user_by_login(&identifier).await.unwrap();
+        assert_eq!(user.username.as_str(), "testuser");
+    }
+
+    #[tokio::test]
+    async fn test_get_user_by_login_with_username_not_found() {
+        let mut repository = MockTestUserRepository::new();
+
+        repository
+            .expect_find_by_username()
+            .times(1)
+            .returning(|_| Ok(None));
+        repository.expect_find_by_email().times(0);
+
+        let service = UserService::new(Arc::new(repository));
+
+        let identifier = LoginIdentifier::parse("nonexistent".to_string()).unwrap();
+        let result = service.get_user_by_login(&identifier).await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UserError::NotFoundByUsername(_)
+        ));
+    }
+
     #[tokio::test]
     async fn test_get_users_by_ids() {
         let mut repository = MockTestUserRepository::new();
-        let event_publisher = MockTestEventPublisher::new();
 
         let user_ids: Vec<UserId> = vec![UserId::new(), UserId::new(), UserId::new()];
         let expected_users: Vec<User> = user_ids
@@ -407,6 +824,11 @@ mod tests {
                 username: Username::new(format!("user{}", i + 1)).unwrap(),
                 email: EmailAddress::new(format!("user{}@example.com", i + 1)).unwrap(),
                 password_hash: "$argon2id$test_hash".to_string(),
+                email_verified: false,
+                display_name: None,
+                avatar_url: None,
+                bio: None,
+                roles: Vec::new(),
                 created_at: Utc::now(),
             })
             .collect();
@@ -417,7 +839,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(returned_users.clone()));
 
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let result = service.get_users_by_ids(&user_ids).await;
         assert!(result.is_ok());
@@ -429,7 +851,6 @@ mod tests {
     #[tokio::test]
     async fn test_get_users_by_ids_partial_match() {
         let mut repository = MockTestUserRepository::new();
-        let event_publisher = MockTestEventPublisher::new();
 
         let existing_user_id = UserId::new();
         let existing_user = User {
@@ -437,6 +858,11 @@ mod tests {
             username: Username::new("user1".to_string()).unwrap(),
             email: EmailAddress::new("user1@example.com".to_string()).unwrap(),
             password_hash: "$argon2id$test_hash".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
             created_at: Utc::now(),
         };
 
@@ -446,7 +872,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(vec![returned_user.clone()]));
 
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
         let ids = vec![existing_user_id, UserId::new()];
         let result = service.get_users_by_ids(&ids).await;
 
@@ -456,10 +882,84 @@ mod tests {
         assert_eq!(users[0].id, existing_user_id);
     }
 
+    #[tokio::test]
+    async fn test_list_users_sets_next_cursor() {
+        let mut repository = MockTestUserRepository::new();
+
+        let users: Vec<User> = (0..3)
+            .map(|i| User {
+                id: UserId::new(),
+                username: Username::new(format!("user{}", i + 1)).unwrap(),
+                email: EmailAddress::new(format!("user{}@example.com", i + 1)).unwrap(),
+                password_hash: "$argon2id$test_hash".to_string(),
+                email_verified: false,
+                display_name: None,
+                avatar_url: None,
+                bio: None,
+                roles: Vec::new(),
+                created_at: Utc::now() - chrono::Duration::minutes(i),
+            })
+            .collect();
+
+        let returned_users = users.clone();
+        repository
+            .expect_list_page()
+            .withf(|status, limit, after| {
+                *status == UserStatus::Active && *limit == 3 && after.is_none()
+            })
+            .times(1)
+            .returning(move |_, _, _| Ok(returned_users.clone()));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let page = service
+            .list_users(UserStatus::Active, 2, None)
+            .await
+            .unwrap();
+        assert_eq!(page.users.len(), 2);
+        assert_eq!(page.next_cursor, Some(UserCursor::after(&users[1])));
+    }
+
+    #[tokio::test]
+    async fn test_list_users_last_page() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user = User {
+            id: UserId::new(),
+            username: Username::new("user1".to_string()).unwrap(),
+            email: EmailAddress::new("user1@example.com".to_string()).unwrap(),
+            password_hash: "$argon2id$test_hash".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        };
+        let cursor = UserCursor::after(&user);
+
+        let returned_user = user.clone();
+        repository
+            .expect_list_page()
+            .withf(move |status, limit, after| {
+                *status == UserStatus::Locked && *limit == 3 && *after == Some(cursor)
+            })
+            .times(1)
+            .returning(move |_, _, _| Ok(vec![returned_user.clone()]));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let page = service
+            .list_users(UserStatus::Locked, 2, Some(cursor))
+            .await
+            .unwrap();
+        assert_eq!(page.users.len(), 1);
+        assert_eq!(page.next_cursor, None);
+    }
+
     #[tokio::test]
     async fn test_update_user_success() {
         let mut repository = MockTestUserRepository::new();
-        let mut event_publisher = MockTestEventPublisher::new();
 
         let user_id = UserId::new();
         let existing_user = User {
@@ -467,6 +967,11 @@ mod tests {
             username: Username::new("olduser".to_string()).unwrap(),
             email: EmailAddress::new("old@example.com".to_string()).unwrap(),
             password_hash: "$argon2id$old_hash".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
             created_at: Utc::now(),
         };
 
@@ -481,25 +986,24 @@ mod tests {
         // Mock update to return updated user
         repository
             .expect_update()
-            .withf(|user| {
+            .withf(|user, event| {
                 user.username.as_str() == "newuser"
                     && user.email.as_str() == "new@example.com"
                     && user.password_hash.starts_with("$argon2")
+                    && matches!(event, Some(UserEvent::UserUpdated(_)))
             })
             .times(1)
-            .returning(|user| Ok(user));
+            .returning(|user, _| Ok(user));
 
-        event_publisher
-            .expect_publish_user_updated()
-            .times(1)
-            .returning(|_| Ok(()));
-
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let command = UpdateUserCommand {
             username: Some(Username::new("newuser".to_string()).unwrap()),
             email: Some(EmailAddress::new("new@example.com".to_string()).unwrap()),
-            password: Some("newpassword".to_string()),
+            password: Some(SecretString::from("newpassword")),
+            display_name: None,
+            avatar_url: None,
+            bio: None,
         };
 
         let result = service.update_user(&user_id, command).await;
@@ -510,23 +1014,301 @@ mod tests {
         assert_eq!(updated_user.email.as_str(), "new@example.com");
     }
 
+    #[tokio::test]
+    async fn test_update_user_email_resets_verification() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user_id = UserId::new();
+        let existing_user = User {
+            id: user_id,
+            username: Username::new("nicola".to_string()).unwrap(),
+            email: EmailAddress::new("old@example.com".to_string()).unwrap(),
+            password_hash: "$argon2id$old_hash".to_string(),
+            email_verified: true,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        };
+
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(existing_user.clone())));
+        repository
+            .expect_update()
+            .withf(|user, _| user.email.as_str() == "new@example.com" && !user.email_verified)
+            .times(1)
+            .returning(|user, _| Ok(user));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let command = UpdateUserCommand {
+            username: None,
+            email: Some(EmailAddress::new("new@example.com".to_string()).unwrap()),
+            password: None,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+        };
+
+        let updated_user = service.update_user(&user_id, command).await.unwrap();
+        assert!(!updated_user.email_verified);
+    }
+
+    #[tokio::test]
+    async fn test_update_user_profile() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user_id = UserId::new();
+        let existing_user = User {
+            id: user_id,
+            username: Username::new("nicola".to_string()).unwrap(),
+            email: EmailAddress::new("nicola@example.com".to_string()).unwrap(),
+            password_hash: "$argon2id$hash".to_string(),
+            email_verified: true,
+            display_name: None,
+            avatar_url: None,
+            bio: Some(Bio::new("Old bio").unwrap()),
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        };
+
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(existing_user.clone())));
+        repository
+            .expect_update()
+            .withf(|_, event| {
+                matches!(event, Some(UserEvent::UserUpdated(e)) if
+                    e.display_name.as_deref() == Some("Nicola")
+                        && e.avatar_url.as_deref() == Some("https://cdn.example.com/nicola.png")
+                        && e.bio.is_none())
+            })
+            .times(1)
+            .returning(|user, _| Ok(user));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let command = UpdateUserCommand {
+            username: None,
+            email: None,
+            password: None,
+            display_name: Some(Some(DisplayName::new("Nicola").unwrap())),
+            avatar_url: Some(Some(
+                AvatarUrl::new("https://cdn.example.com/nicola.png").unwrap(),
+            )),
+            bio: Some(None),
+        };
+
+        let updated_user = service.update_user(&user_id, command).await.unwrap();
+        assert_eq!(
+            updated_user.display_name.as_ref().map(DisplayName::as_str),
+            Some("Nicola")
+        );
+        assert!(updated_user.avatar_url.is_some());
+        assert!(updated_user.bio.is_none());
+        assert_eq!(updated_user.username.as_str(), "nicola");
+    }
+
+    #[tokio::test]
+    async fn test_update_user_compromised_password() {
+        let mut repository = MockTestUserRepository::new();
+        let mut password_checker = MockTestPasswordChecker::new();
+
+        let user_id = UserId::new();
+        let existing_user = User {
+            id: user_id,
+            username: Username::new("olduser".to_string()).unwrap(),
+            email: EmailAddress::new("old@example.com".to_string()).unwrap(),
+            password_hash: "$argon2id$old_hash".to_string(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        };
+
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(existing_user.clone())));
+        repository.expect_update().times(0);
+
+        password_checker
+            .expect_is_compromised()
+            .with(eq("qwerty123"))
+            .times(1)
+            .returning(|_| Ok(true));
+
+        let service = UserService::new(Arc::new(repository))
+            .with_password_checker(Arc::new(password_checker));
+
+        let command = UpdateUserCommand {
+            username: None,
+            email: None,
+            password: Some(SecretString::from("qwerty123")),
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+        };
+
+        let result = service.update_user(&user_id, command).await;
+        assert!(matches!(result, Err(UserError::CompromisedPassword)));
+    }
+
+    #[tokio::test]
+    async fn test_change_password_success() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user_id = UserId::new();
+        let existing_user = User {
+            id: user_id,
+            username: Username::new("nicola".to_string()).unwrap(),
+            email: EmailAddress::new("nicola@example.com".to_string()).unwrap(),
+            password_hash: auth::PasswordHasher::new()
+                .hash(&SecretString::from("old_password"))
+                .unwrap(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        };
+
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(existing_user.clone())));
+        repository
+            .expect_update()
+            .withf(move |user, event| {
+                auth::PasswordHasher::new()
+                    .verify(&SecretString::from("new_password"), &user.password_hash)
+                    .unwrap()
+                    .is_valid()
+                    && matches!(event, Some(UserEvent::UserPasswordChanged(e)) if e.user_id == user_id.to_string())
+            })
+            .times(1)
+            .returning(|user, _| Ok(user));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let result = service
+            .change_password(
+                &user_id,
+                SecretString::from("old_password"),
+                SecretString::from("new_password"),
+            )
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_change_password_wrong_current_password() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user_id = UserId::new();
+        let existing_user = User {
+            id: user_id,
+            username: Username::new("nicola".to_string()).unwrap(),
+            email: EmailAddress::new("nicola@example.com".to_string()).unwrap(),
+            password_hash: auth::PasswordHasher::new()
+                .hash(&SecretString::from("old_password"))
+                .unwrap(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        };
+
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(existing_user.clone())));
+        repository.expect_update().times(0);
+
+        let service = UserService::new(Arc::new(repository));
+
+        let result = service
+            .change_password(
+                &user_id,
+                SecretString::from("guessed_password"),
+                SecretString::from("new_password"),
+            )
+            .await;
+        assert!(matches!(result, Err(UserError::InvalidCredentials)));
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_replaces_hash_without_event() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user_id = UserId::new();
+        let existing_user = User {
+            id: user_id,
+            username: Username::new("nicola".to_string()).unwrap(),
+            email: EmailAddress::new("nicola@example.com".to_string()).unwrap(),
+            password_hash: auth::PasswordHasher::new()
+                .hash(&SecretString::from("forgotten_password"))
+                .unwrap(),
+            email_verified: false,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
+            roles: Vec::new(),
+            created_at: Utc::now(),
+        };
+
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(Some(existing_user.clone())));
+        repository
+            .expect_update()
+            .withf(|user, event| {
+                auth::PasswordHasher::new()
+                    .verify(&SecretString::from("new_password"), &user.password_hash)
+                    .unwrap()
+                    .is_valid()
+                    && event.is_none()
+            })
+            .times(1)
+            .returning(|user, _| Ok(user));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let result = service
+            .reset_password(&user_id, SecretString::from("new_password"))
+            .await;
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_update_user_not_found() {
         let mut repository = MockTestUserRepository::new();
-        let event_publisher = MockTestEventPublisher::new();
 
         repository
             .expect_find_by_id()
             .times(1)
             .returning(|_| Ok(None));
 
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let user_id = UserId::new();
         let command = UpdateUserCommand {
             username: Some(Username::new("newuser".to_string()).unwrap()),
             email: None,
             password: None,
+            display_name: None,
+            avatar_url: None,
+            bio: None,
         };
 
         let result = service.update_user(&user_id, command).await;
@@ -537,22 +1319,19 @@ mod tests {
     #[tokio::test]
     async fn test_delete_user_success() {
         let mut repository = MockTestUserRepository::new();
-        let mut event_publisher = MockTestEventPublisher::new();
 
         let user_id = UserId::new();
 
         repository
             .expect_delete()
-            .withf(move |id| *id == user_id)
-            .times(1)
-            .returning(|_| Ok(()));
-
-        event_publisher
-            .expect_publish_user_deleted()
+            .withf(move |id, event| {
+                *id == user_id
+                    && matches!(event, UserEvent::UserDeleted(e) if e.user_id == user_id.to_string())
+            })
             .times(1)
-            .returning(|_| Ok(()));
+            .returning(|_, _| Ok(()));
 
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let result = service.delete_user(&user_id).await;
         assert!(result.is_ok());
@@ -561,19 +1340,144 @@ mod tests {
     #[tokio::test]
     async fn test_delete_user_not_found() {
         let mut repository = MockTestUserRepository::new();
-        let event_publisher = MockTestEventPublisher::new();
 
         let user_id = UserId::new();
 
         repository
             .expect_delete()
             .times(1)
-            .returning(move |_| Err(UserError::NotFound(user_id.to_string())));
+            .returning(move |_, _| Err(UserError::NotFound(user_id.to_string())));
 
-        let service = UserService::new(Arc::new(repository), Arc::new(event_publisher));
+        let service = UserService::new(Arc::new(repository));
 
         let result = service.delete_user(&user_id).await;
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), UserError::NotFound(_)));
     }
+
+    #[tokio::test]
+    async fn test_deactivate_user_success() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user_id = UserId::new();
+
+        repository
+            .expect_update_status()
+            .withf(move |id, from, to, event| {
+                *id == user_id
+                    && *from == UserStatus::Active
+                    && *to == UserStatus::Deactivated
+                    && matches!(event, UserEvent::UserDeactivated(e) if e.user_id == user_id.to_string())
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let result = service.deactivate_user(&user_id).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_deactivate_user_already_deactivated() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user_id = UserId::new();
+
+        repository
+            .expect_update_status()
+            .times(1)
+            .returning(move |_, _, to, _| {
+                Err(UserError::StatusUnchanged {
+                    id: user_id.to_string(),
+                    status: to,
+                })
+            });
+
+        let service = UserService::new(Arc::new(repository));
+
+        let result = service.deactivate_user(&user_id).await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UserError::StatusUnchanged {
+                status: UserStatus::Deactivated,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reactivate_user_success() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user_id = UserId::new();
+
+        repository
+            .expect_update_status()
+            .withf(move |id, from, to, event| {
+                *id == user_id
+                    && *from == UserStatus::Deactivated
+                    && *to == UserStatus::Active
+                    && matches!(event, UserEvent::UserReactivated(e) if e.user_id == user_id.to_string())
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let result = service.reactivate_user(&user_id).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_lock_user_success() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user_id = UserId::new();
+
+        repository
+            .expect_update_status()
+            .withf(move |id, from, to, event| {
+                *id == user_id
+                    && *from == UserStatus::Active
+                    && *to == UserStatus::Locked
+                    && matches!(event, UserEvent::UserDeactivated(e) if e.user_id == user_id.to_string())
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let service = UserService::new(Arc::new(repository));
+
+        let result = service.lock_user(&user_id).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unlock_user_not_locked() {
+        let mut repository = MockTestUserRepository::new();
+
+        let user_id = UserId::new();
+
+        repository
+            .expect_update_status()
+            .withf(|_, from, to, _| *from == UserStatus::Locked && *to == UserStatus::Active)
+            .times(1)
+            .returning(move |_, _, _, _| {
+                Err(UserError::StatusUnchanged {
+                    id: user_id.to_string(),
+                    status: UserStatus::Deactivated,
+                })
+            });
+
+        let service = UserService::new(Arc::new(repository));
+
+        let result = service.unlock_user(&user_id).await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UserError::StatusUnchanged {
+                status: UserStatus::Deactivated,
+                ..
+            }
+        ));
+    }
 }
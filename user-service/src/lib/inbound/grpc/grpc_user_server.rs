@@ -1,34 +1,98 @@
 use std::sync::Arc;
 
+use auth::Claims;
 use tonic::Request;
 use tonic::Response;
 use tonic::Status;
 
 use super::handlers::get_user;
+use super::handlers::get_users_by_ids;
+use super::handlers::verify_personal_token;
+use super::handlers::watch_users;
+use super::handlers::watch_users::UserChangeStream;
 use crate::domain::user::service::UserService;
+use crate::inbound::http::router::AppPersonalTokenService;
+use crate::outbound::events::BroadcastPublisher;
 use crate::outbound::events::KafkaEventProducer;
 use crate::outbound::repositories::PostgresUserRepository;
 use crate::proto::user_service_server::UserService as UserServiceProto;
 use crate::proto::GetUserRequest;
 use crate::proto::GetUserResponse;
+use crate::proto::GetUsersByIdsRequest;
+use crate::proto::GetUsersByIdsResponse;
+use crate::proto::VerifyPersonalTokenRequest;
+use crate::proto::VerifyPersonalTokenResponse;
+use crate::proto::WatchUsersRequest;
 
 pub struct UserGrpcService {
-    service: Arc<UserService<PostgresUserRepository, KafkaEventProducer>>,
+    service: Arc<UserService<PostgresUserRepository>>,
+    personal_token_service: Arc<AppPersonalTokenService>,
+    event_publisher: Arc<BroadcastPublisher<KafkaEventProducer>>,
 }
 
 impl UserGrpcService {
-    pub fn new(service: Arc<UserService<PostgresUserRepository, KafkaEventProducer>>) -> Self {
-        Self { service }
+    pub fn new(
+        service: Arc<UserService<PostgresUserRepository>>,
+        personal_token_service: Arc<AppPersonalTokenService>,
+        event_publisher: Arc<BroadcastPublisher<KafkaEventProducer>>,
+    ) -> Self {
+        Self {
+            service,
+            personal_token_service,
+            event_publisher,
+        }
     }
 }
 
 #[tonic::async_trait]
 impl UserServiceProto for UserGrpcService {
+    type WatchUsersStream = UserChangeStream;
+
     async fn get_user(
         &self,
         request: Request<GetUserRequest>,
     ) -> Result<Response<GetUserResponse>, Status> {
-        let response = get_user::get_user(self.service.clone(), request.into_inner()).await?;
+        let claims = request.extensions().get::<Claims>().cloned();
+        let response =
+            get_user::get_user(self.service.clone(), claims.as_ref(), request.into_inner()).await?;
+        Ok(Response::new(response))
+    }
+
+    async fn get_users_by_ids(
+        &self,
+        request: Request<GetUsersByIdsRequest>,
+    ) -> Result<Response<GetUsersByIdsResponse>, Status> {
+        let claims = request.extensions().get::<Claims>().cloned();
+        let response = get_users_by_ids::get_users_by_ids(
+            self.service.clone(),
+            claims.as_ref(),
+            request.into_inner(),
+        )
+        .await?;
+        Ok(Response::new(response))
+    }
+
+    async fn verify_personal_token(
+        &self,
+        request: Request<VerifyPersonalTokenRequest>,
+    ) -> Result<Response<VerifyPersonalTokenResponse>, Status> {
+        let claims = request.extensions().get::<Claims>().cloned();
+        let response = verify_personal_token::verify_personal_token(
+            self.personal_token_service.clone(),
+            claims.as_ref(),
+            request.into_inner(),
+        )
+        .await?;
         Ok(Response::new(response))
     }
+
+    async fn watch_users(
+        &self,
+        request: Request<WatchUsersRequest>,
+    ) -> Result<Response<Self::WatchUsersStream>, Status> {
+        let claims = request.extensions().get::<Claims>().cloned();
+        let changes =
+            watch_users::watch_users(self.event_publisher.clone(), claims.as_ref()).await?;
+        Ok(Response::new(changes))
+    }
 }
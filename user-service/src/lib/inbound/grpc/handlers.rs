@@ -1,6 +1,12 @@
+use auth::Claims;
+use tonic::Status;
+
 use crate::domain::user::models::User;
 
 pub mod get_user;
+pub mod get_users_by_ids;
+pub mod verify_personal_token;
+pub mod watch_users;
 
 impl From<User> for crate::proto::User {
     fn from(user: User) -> Self {
@@ -12,3 +18,18 @@ impl From<User> for crate::proto::User {
         }
     }
 }
+
+/// Check that the caller is a service granted the scope.
+///
+/// # Errors
+/// * `PERMISSION_DENIED` - Caller is not a service, or lacks the scope
+#[allow(clippy::result_large_err)] // Status is what every gRPC handler returns
+fn require_service_scope(claims: Option<&Claims>, scope: &str) -> Result<(), Status> {
+    if claims.is_some_and(|claims| claims.is_service() && claims.has_scope(scope)) {
+        return Ok(());
+    }
+    Err(Status::permission_denied(format!(
+        "Service token with scope '{}' required",
+        scope
+    )))
+}
@@ -1,20 +1,27 @@
 use std::sync::Arc;
 
+use auth::Claims;
 use tonic::Status;
 
+use super::require_service_scope;
 use crate::domain::user::models::UserId;
 use crate::domain::user::ports::UserServicePort;
 use crate::domain::user::service::UserService;
-use crate::outbound::events::KafkaEventProducer;
 use crate::outbound::repositories::user::PostgresUserRepository;
 use crate::proto::GetUserRequest;
 use crate::proto::GetUserResponse;
 use crate::proto::User as ProtoUser;
 
+/// Scope services need to look users up.
+pub const USERS_READ_SCOPE: &str = "users:read";
+
 pub async fn get_user(
-    service: Arc<UserService<PostgresUserRepository, KafkaEventProducer>>,
+    service: Arc<UserService<PostgresUserRepository>>,
+    claims: Option<&Claims>,
     request: GetUserRequest,
 ) -> Result<GetUserResponse, Status> {
+    require_service_scope(claims, USERS_READ_SCOPE)?;
+
     let user_id = UserId::from_string(&request.user_id)
         .map_err(|e| Status::invalid_argument(format!("Invalid user ID: {}", e)))?;
 
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use auth::Claims;
+use tonic::Status;
+
+use super::get_user::USERS_READ_SCOPE;
+use super::require_service_scope;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserServicePort;
+use crate::domain::user::service::UserService;
+use crate::outbound::repositories::user::PostgresUserRepository;
+use crate::proto::GetUsersByIdsRequest;
+use crate::proto::GetUsersByIdsResponse;
+
+/// Most users looked up in one call.
+pub const MAX_USERS_PER_CALL: usize = 100;
+
+pub async fn get_users_by_ids(
+    service: Arc<UserService<PostgresUserRepository>>,
+    claims: Option<&Claims>,
+    request: GetUsersByIdsRequest,
+) -> Result<GetUsersByIdsResponse, Status> {
+    require_service_scope(claims, USERS_READ_SCOPE)?;
+
+    if request.user_ids.len() > MAX_USERS_PER_CALL {
+        return Err(Status::invalid_argument(format!(
+            "At most {} user IDs per call",
+            MAX_USERS_PER_CALL
+        )));
+    }
+
+    let user_ids = request
+        .user_ids
+        .iter()
+        .map(|id| UserId::from_string(id))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| Status::invalid_argument(format!("Invalid user ID: {}", e)))?;
+
+    let users = service
+        .get_users_by_ids(&user_ids)
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?;
+
+    Ok(GetUsersByIdsResponse {
+        users: users.into_iter().map(Into::into).collect(),
+    })
+}
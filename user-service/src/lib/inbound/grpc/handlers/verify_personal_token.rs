@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use auth::Claims;
+use tonic::Status;
+
+use super::require_service_scope;
+use crate::domain::personal_token::errors::PersonalTokenError;
+use crate::domain::personal_token::ports::PersonalTokenServicePort;
+use crate::inbound::http::router::AppPersonalTokenService;
+use crate::proto::PersonalToken as ProtoPersonalToken;
+use crate::proto::VerifyPersonalTokenRequest;
+use crate::proto::VerifyPersonalTokenResponse;
+
+/// Scope services need to verify personal access tokens.
+pub const PERSONAL_TOKENS_VERIFY_SCOPE: &str = "personal_tokens:verify";
+
+pub async fn verify_personal_token(
+    service: Arc<AppPersonalTokenService>,
+    claims: Option<&Claims>,
+    request: VerifyPersonalTokenRequest,
+) -> Result<VerifyPersonalTokenResponse, Status> {
+    // Only services may look tokens up, users go through the HTTP API
+    require_service_scope(claims, PERSONAL_TOKENS_VERIFY_SCOPE)?;
+
+    match service.verify_token(&request.token).await {
+        Ok(verified) => Ok(VerifyPersonalTokenResponse {
+            result: Some(crate::proto::verify_personal_token_response::Result::Token(
+                ProtoPersonalToken {
+                    id: verified.token.id.to_string(),
+                    user_id: verified.user.id.to_string(),
+                    username: verified.user.username.as_str().to_string(),
+                    scopes: verified.token.scopes.as_slice().to_vec(),
+                    expires_at: verified.token.expires_at.to_rfc3339(),
+                },
+            )),
+        }),
+        Err(e @ PersonalTokenError::Invalid) => Ok(VerifyPersonalTokenResponse {
+            result: Some(crate::proto::verify_personal_token_response::Result::Error(
+                e.to_string(),
+            )),
+        }),
+        Err(e) => {
+            tracing::error!("Failed to verify personal access token: {}", e);
+            Err(Status::internal(
+                "Personal access token could not be verified",
+            ))
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use auth::Claims;
+use futures::stream;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use tokio::sync::broadcast::error::RecvError;
+use tonic::Status;
+
+use super::require_service_scope;
+use crate::outbound::events::messages::UserEventMessage;
+use crate::outbound::events::BroadcastPublisher;
+use crate::outbound::events::KafkaEventProducer;
+use crate::proto::user_change::Change;
+use crate::proto::UserChange;
+use crate::proto::UserCreated;
+use crate::proto::UserDeactivated;
+use crate::proto::UserDeleted;
+use crate::proto::UserReactivated;
+use crate::proto::UserUpdated;
+
+/// Scope services need to watch user changes.
+pub const USERS_WATCH_SCOPE: &str = "users:watch";
+
+/// Stream of user changes sent to a watcher
+pub type UserChangeStream = BoxStream<'static, Result<UserChange, Status>>;
+
+pub async fn watch_users(
+    publisher: Arc<BroadcastPublisher<KafkaEventProducer>>,
+    claims: Option<&Claims>,
+) -> Result<UserChangeStream, Status> {
+    // Changes carry email addresses, so only services may watch them
+    require_service_scope(claims, USERS_WATCH_SCOPE)?;
+
+    let receiver = publisher.subscribe();
+    let changes = stream::unfold(Some(receiver), |receiver| async move {
+        let mut receiver = receiver?;
+        loop {
+            match receiver.recv().await {
+                Ok(message) => {
+                    if let Some(change) = user_change(&message) {
+                        return Some((Ok(change), Some(receiver)));
+                    }
+                }
+                // Ending the stream tells the watcher to resynchronize
+                Err(RecvError::Lagged(missed)) => {
+                    let status = Status::resource_exhausted(format!(
+                        "Watcher fell behind by {} user changes",
+                        missed
+                    ));
+                    return Some((Err(status), None));
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    Ok(changes.boxed())
+}
+
+/// Describe a published event as a user change.
+///
+/// Credential and lockout events are not shared with other services.
+fn user_change(message: &UserEventMessage) -> Option<UserChange> {
+    let (event_id, user_id, occurred_at, change) = match message {
+        UserEventMessage::UserCreated(m) => (
+            &m.event_id,
+            &m.user_id,
+            m.created_at,
+            Change::Created(UserCreated {
+                username: m.username.clone(),
+                email: m.email.clone(),
+            }),
+        ),
+        UserEventMessage::UserUpdated(m) => (
+            &m.event_id,
+            &m.user_id,
+            m.updated_at,
+            Change::Updated(UserUpdated {
+                username: m.username.clone(),
+                email: m.email.clone(),
+                display_name: m.display_name.clone(),
+                avatar_url: m.avatar_url.clone(),
+                bio: m.bio.clone(),
+            }),
+        ),
+        UserEventMessage::UserDeleted(m) => (
+            &m.event_id,
+            &m.user_id,
+            m.deleted_at,
+            Change::Deleted(UserDeleted {}),
+        ),
+        UserEventMessage::UserDeactivated(m) => (
+            &m.event_id,
+            &m.user_id,
+            m.deactivated_at,
+            Change::Deactivated(UserDeactivated {}),
+        ),
+        UserEventMessage::UserReactivated(m) => (
+            &m.event_id,
+            &m.user_id,
+            m.reactivated_at,
+            Change::Reactivated(UserReactivated {}),
+        ),
+        UserEventMessage::UserPasswordChanged(_)
+        | UserEventMessage::UserPasswordResetRequested(_)
+        | UserEventMessage::UserPasswordReset(_)
+        | UserEventMessage::UserAccountLocked(_) => return None,
+    };
+
+    Some(UserChange {
+        event_id: event_id.clone(),
+        user_id: user_id.clone(),
+        occurred_at: occurred_at.to_rfc3339(),
+        change: Some(change),
+    })
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tonic::server::NamedService;
+use tonic_health::server::HealthReporter;
+use tonic_health::ServingStatus;
+
+use super::UserGrpcService;
+use crate::inbound::health::HealthChecks;
+use crate::proto::user_service_server::UserServiceServer;
+
+/// Time between two rounds of dependency checks.
+pub const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Keep the gRPC health status in line with the dependencies.
+///
+/// Both the user service and the server as a whole (empty service name) are
+/// reported `NOT_SERVING` while a dependency check fails. This is a
+/// long-running task that should be spawned in a separate tokio task.
+///
+/// # Arguments
+/// * `reporter` - Reporter of the health service registered on the gRPC server
+/// * `checks` - Dependency checks, shared with the HTTP readiness probe
+/// * `interval` - Time between two rounds of checks
+pub async fn report_health(
+    mut reporter: HealthReporter,
+    checks: Arc<HealthChecks>,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    let mut serving = None;
+
+    loop {
+        ticker.tick().await;
+
+        // Failures are logged every round, so a lasting outage stays visible
+        let healthy = checks.check_all().await.iter().all(|status| status.healthy);
+
+        if serving == Some(healthy) {
+            continue;
+        }
+        serving = Some(healthy);
+
+        let status = if healthy {
+            ServingStatus::Serving
+        } else {
+            ServingStatus::NotServing
+        };
+        tracing::info!(serving = healthy, "gRPC health status changed");
+        reporter
+            .set_service_status(
+                <UserServiceServer<UserGrpcService> as NamedService>::NAME,
+                status,
+            )
+            .await;
+        reporter.set_service_status("", status).await;
+    }
+}
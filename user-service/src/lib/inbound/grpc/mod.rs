@@ -1,4 +1,5 @@
 mod grpc_user_server;
 mod handlers;
+pub mod health;
 
 pub use grpc_user_server::UserGrpcService;
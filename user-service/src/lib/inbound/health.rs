@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::future::join_all;
+
+/// Dependency the health of the user service follows.
+#[async_trait]
+pub trait DependencyCheck: Send + Sync + 'static {
+    /// Name of the dependency in logs and readiness reports.
+    fn name(&self) -> &'static str;
+
+    /// Check that the dependency is reachable.
+    ///
+    /// # Errors
+    /// Returns error string describing why the dependency is unreachable
+    async fn check(&self) -> Result<(), String>;
+}
+
+/// Outcome of one dependency check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyStatus {
+    pub name: &'static str,
+    pub healthy: bool,
+}
+
+/// Dependency checks shared by the HTTP readiness probe and the gRPC health service.
+pub struct HealthChecks {
+    checks: Vec<Arc<dyn DependencyCheck>>,
+}
+
+impl HealthChecks {
+    pub fn new(checks: Vec<Arc<dyn DependencyCheck>>) -> Self {
+        Self { checks }
+    }
+
+    /// Run every dependency check concurrently.
+    ///
+    /// Failures are logged with their cause, which the returned statuses leave
+    /// out so that probes answered without a token do not expose internals.
+    ///
+    /// # Returns
+    /// Status of each dependency, in the order the checks were registered
+    pub async fn check_all(&self) -> Vec<DependencyStatus> {
+        let results = join_all(self.checks.iter().map(|check| check.check())).await;
+
+        self.checks
+            .iter()
+            .zip(results)
+            .map(|(check, result)| {
+                if let Err(e) = &result {
+                    tracing::warn!(dependency = check.name(), error = %e, "Health check failed");
+                }
+                DependencyStatus {
+                    name: check.name(),
+                    healthy: result.is_ok(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StaticCheck {
+        name: &'static str,
+        healthy: bool,
+    }
+
+    #[async_trait]
+    impl DependencyCheck for StaticCheck {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn check(&self) -> Result<(), String> {
+            if self.healthy {
+                Ok(())
+            } else {
+                Err("connection refused".to_string())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_all_reports_each_dependency() {
+        let checks = HealthChecks::new(vec![
+            Arc::new(StaticCheck {
+                name: "postgresql",
+                healthy: true,
+            }),
+            Arc::new(StaticCheck {
+                name: "kafka",
+                healthy: false,
+            }),
+        ]);
+
+        let statuses = checks.check_all().await;
+
+        assert_eq!(
+            statuses,
+            vec![
+                DependencyStatus {
+                    name: "postgresql",
+                    healthy: true,
+                },
+                DependencyStatus {
+                    name: "kafka",
+                    healthy: false,
+                },
+            ]
+        );
+    }
+}
@@ -2,15 +2,73 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::response::Response;
 use axum::Json;
+use envelope::ResponseData;
+use envelope::ResponseError;
 use serde::Serialize;
 
+use crate::domain::account_link::errors::AccountLinkError;
+use crate::domain::audit::errors::AuditError;
+use crate::domain::avatar::errors::AvatarError;
+use crate::domain::email_verification::errors::EmailVerificationError;
+use crate::domain::event_replay::errors::EventReplayError;
+use crate::domain::job::errors::JobError;
+use crate::domain::lockout::errors::LockoutError;
+use crate::domain::login_history::errors::LoginHistoryError;
+use crate::domain::magic_link::errors::MagicLinkError;
+use crate::domain::passkey::errors::PasskeyError;
+use crate::domain::password_reset::errors::PasswordResetError;
+use crate::domain::personal_token::errors::PersonalTokenError;
+use crate::domain::signup::errors::SignupError;
+use crate::user::errors::PasswordError;
 use crate::user::errors::UserError;
 
 pub mod authenticate;
+pub mod change_password;
+pub mod check_liveness;
+pub mod check_readiness;
+pub mod confirm_password_reset;
+pub mod create_personal_token;
 pub mod create_user;
+pub mod deactivate_user;
+pub mod delete_passkey;
 pub mod delete_user;
+pub mod export_user;
+pub mod finish_passkey_login;
+pub mod finish_passkey_registration;
+pub mod force_password_reset;
+pub mod get_job;
+pub mod get_metrics;
 pub mod get_user;
+pub mod get_version;
+pub mod import_users;
+pub mod link_password;
+pub mod list_admin_users;
+pub mod list_audit_log;
+pub mod list_auth_methods;
+pub mod list_logins;
+pub mod list_passkeys;
+pub mod list_personal_tokens;
+pub mod list_users;
+pub mod lock_user;
+pub mod logout;
+pub mod reactivate_user;
+pub mod redeem_magic_link;
+pub mod refresh_token;
+pub mod remove_auth_method;
+pub mod replay_user_events;
+pub mod request_email_verification;
+pub mod request_magic_link;
+pub mod request_magic_link_verification;
+pub mod request_password_reset;
+pub mod revoke_personal_token;
+pub mod search_users;
+pub mod start_passkey_login;
+pub mod start_passkey_registration;
+pub mod unlock_user;
 pub mod update_user;
+pub mod upload_avatar;
+pub mod verify_email;
+pub mod verify_magic_link;
 
 #[derive(Debug, Clone)]
 pub struct ApiSuccess<T: Serialize + PartialEq>(StatusCode, Json<ApiResponseBody<T>>);
@@ -32,7 +90,10 @@ impl<T: Serialize + PartialEq> ApiSuccess<T> {
 
 impl<T: Serialize + PartialEq> IntoResponse for ApiSuccess<T> {
     fn into_response(self) -> Response {
-        (self.0, self.1).into_response()
+        let data = ResponseData::new(&self.1 .0.data);
+        let mut response = (self.0, self.1).into_response();
+        response.extensions_mut().insert(data);
+        response
     }
 }
 
@@ -44,6 +105,10 @@ pub enum ApiError {
     NotFound(String),
     Conflict(String),
     Unauthorized(String),
+    Forbidden(String),
+    Locked(String),
+    PayloadTooLarge(String),
+    TooManyRequests(String),
 }
 
 impl From<anyhow::Error> for ApiError {
@@ -61,31 +126,230 @@ impl IntoResponse for ApiError {
             ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
             ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg),
             ApiError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
+            ApiError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
+            ApiError::Locked(msg) => (StatusCode::LOCKED, msg),
+            ApiError::PayloadTooLarge(msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg),
+            ApiError::TooManyRequests(msg) => (StatusCode::TOO_MANY_REQUESTS, msg),
         };
 
-        (status, Json(ApiResponseBody::new_error(status, message))).into_response()
+        let error = ResponseError::new(message.as_str());
+        let mut response =
+            (status, Json(ApiResponseBody::new_error(status, message))).into_response();
+        response.extensions_mut().insert(error);
+        response
     }
 }
 
 impl From<UserError> for ApiError {
     fn from(err: UserError) -> Self {
         match err {
-            UserError::NotFound(_) | UserError::NotFoundByUsername(_) => {
-                ApiError::NotFound(err.to_string())
-            }
-            UserError::UsernameAlreadyExists(_) | UserError::EmailAlreadyExists(_) => {
-                ApiError::Conflict(err.to_string())
-            }
+            UserError::NotFound(_)
+            | UserError::NotFoundByUsername(_)
+            | UserError::NotFoundByEmail(_) => ApiError::NotFound(err.to_string()),
+            UserError::UsernameAlreadyExists(_)
+            | UserError::EmailAlreadyExists(_)
+            | UserError::StatusUnchanged { .. } => ApiError::Conflict(err.to_string()),
             UserError::InvalidCredentials => ApiError::Unauthorized(err.to_string()),
             UserError::InvalidUsername(_)
             | UserError::InvalidEmail(_)
-            | UserError::InvalidUserId(_) => ApiError::UnprocessableEntity(err.to_string()),
+            | UserError::InvalidDisplayName(_)
+            | UserError::InvalidAvatarUrl(_)
+            | UserError::InvalidBio(_)
+            | UserError::InvalidUserId(_)
+            | UserError::CompromisedPassword
+            | UserError::Password(PasswordError::UnsupportedHash) => {
+                ApiError::UnprocessableEntity(err.to_string())
+            }
             UserError::Password(_) | UserError::DatabaseError(_) | UserError::Unknown(_) => {
                 ApiError::InternalServerError(err.to_string())
             }
         }
     }
 }
+impl From<JobError> for ApiError {
+    fn from(err: JobError) -> Self {
+        match err {
+            JobError::NotFound(_) => ApiError::NotFound(err.to_string()),
+            JobError::InvalidJobId(_) => ApiError::BadRequest(err.to_string()),
+            JobError::AlreadyFinished { .. } => ApiError::Conflict(err.to_string()),
+            JobError::InvalidStatus(_)
+            | JobError::InvalidProgress(_)
+            | JobError::DatabaseError(_)
+            | JobError::Unknown(_) => ApiError::InternalServerError(err.to_string()),
+        }
+    }
+}
+
+impl From<AvatarError> for ApiError {
+    fn from(err: AvatarError) -> Self {
+        match err {
+            AvatarError::Empty
+            | AvatarError::UnsupportedType(_)
+            | AvatarError::ContentMismatch(_) => ApiError::UnprocessableEntity(err.to_string()),
+            AvatarError::TooLarge { .. } => ApiError::PayloadTooLarge(err.to_string()),
+            AvatarError::User(err) => err.into(),
+            AvatarError::Storage(_) => ApiError::InternalServerError(err.to_string()),
+        }
+    }
+}
+
+impl From<MagicLinkError> for ApiError {
+    fn from(err: MagicLinkError) -> Self {
+        match err {
+            MagicLinkError::InvalidLink
+            | MagicLinkError::AlreadyUsed
+            | MagicLinkError::ClientMismatch => ApiError::Unauthorized(err.to_string()),
+            MagicLinkError::AlreadyLinked => ApiError::Conflict(err.to_string()),
+            MagicLinkError::User(err) => err.into(),
+            MagicLinkError::AccountLink(err) => err.into(),
+            MagicLinkError::DeliveryFailed(_) | MagicLinkError::TokenError(_) => {
+                ApiError::InternalServerError(err.to_string())
+            }
+        }
+    }
+}
+
+impl From<EmailVerificationError> for ApiError {
+    fn from(err: EmailVerificationError) -> Self {
+        match err {
+            EmailVerificationError::InvalidLink | EmailVerificationError::AlreadyUsed => {
+                ApiError::Unauthorized(err.to_string())
+            }
+            EmailVerificationError::AlreadyVerified => ApiError::Conflict(err.to_string()),
+            EmailVerificationError::User(err) => err.into(),
+            EmailVerificationError::Email(_) | EmailVerificationError::TokenError(_) => {
+                ApiError::InternalServerError(err.to_string())
+            }
+        }
+    }
+}
+
+impl From<PasswordResetError> for ApiError {
+    fn from(err: PasswordResetError) -> Self {
+        match err {
+            PasswordResetError::InvalidLink | PasswordResetError::AlreadyUsed => {
+                ApiError::Unauthorized(err.to_string())
+            }
+            PasswordResetError::User(err) => err.into(),
+            PasswordResetError::Email(_) | PasswordResetError::TokenError(_) => {
+                ApiError::InternalServerError(err.to_string())
+            }
+        }
+    }
+}
+
+impl From<LockoutError> for ApiError {
+    fn from(err: LockoutError) -> Self {
+        match err {
+            // The end of the lock is left out, so that every locked login answers alike
+            LockoutError::Locked(_) => ApiError::Locked(
+                "Account is locked after too many failed logins, try again later".to_string(),
+            ),
+            LockoutError::DatabaseError(_) => ApiError::InternalServerError(err.to_string()),
+        }
+    }
+}
+
+impl From<LoginHistoryError> for ApiError {
+    fn from(err: LoginHistoryError) -> Self {
+        match err {
+            LoginHistoryError::UserNotFound(_) => ApiError::NotFound(err.to_string()),
+            LoginHistoryError::DatabaseError(_) => ApiError::InternalServerError(err.to_string()),
+        }
+    }
+}
+
+impl From<AuditError> for ApiError {
+    fn from(err: AuditError) -> Self {
+        match err {
+            AuditError::InvalidAction(_) => ApiError::BadRequest(err.to_string()),
+            AuditError::DatabaseError(_) => ApiError::InternalServerError(err.to_string()),
+        }
+    }
+}
+
+impl From<PasskeyError> for ApiError {
+    fn from(err: PasskeyError) -> Self {
+        match err {
+            PasskeyError::InvalidId(_) => ApiError::BadRequest(err.to_string()),
+            PasskeyError::InvalidName(_) => ApiError::UnprocessableEntity(err.to_string()),
+            PasskeyError::NotFound(_) => ApiError::NotFound(err.to_string()),
+            PasskeyError::AlreadyRegistered => ApiError::Conflict(err.to_string()),
+            PasskeyError::NoCredentials
+            | PasskeyError::CeremonyNotFound
+            | PasskeyError::VerificationFailed(_) => ApiError::Unauthorized(err.to_string()),
+            PasskeyError::User(err) => err.into(),
+            PasskeyError::TokenError(_) | PasskeyError::DatabaseError(_) => {
+                ApiError::InternalServerError(err.to_string())
+            }
+        }
+    }
+}
+
+impl From<PersonalTokenError> for ApiError {
+    fn from(err: PersonalTokenError) -> Self {
+        match err {
+            PersonalTokenError::InvalidId(_) => ApiError::BadRequest(err.to_string()),
+            PersonalTokenError::InvalidName(_)
+            | PersonalTokenError::InvalidScopes(_)
+            | PersonalTokenError::InvalidLifetime(_) => {
+                ApiError::UnprocessableEntity(err.to_string())
+            }
+            PersonalTokenError::NotFound(_) => ApiError::NotFound(err.to_string()),
+            PersonalTokenError::Invalid => ApiError::Unauthorized(err.to_string()),
+            PersonalTokenError::User(err) => err.into(),
+            PersonalTokenError::DatabaseError(_) => ApiError::InternalServerError(err.to_string()),
+        }
+    }
+}
+
+impl From<SignupError> for ApiError {
+    fn from(err: SignupError) -> Self {
+        match err {
+            SignupError::CaptchaRequired => ApiError::BadRequest(err.to_string()),
+            SignupError::CaptchaRejected => ApiError::Forbidden(err.to_string()),
+            SignupError::BlockedEmailDomain(_) => ApiError::UnprocessableEntity(err.to_string()),
+            SignupError::IdempotencyKeyReused => ApiError::UnprocessableEntity(err.to_string()),
+            SignupError::SignupInProgress => ApiError::Conflict(err.to_string()),
+            SignupError::User(err) => err.into(),
+            SignupError::Captcha(_) | SignupError::Idempotency(_) => {
+                ApiError::InternalServerError(err.to_string())
+            }
+        }
+    }
+}
+
+impl From<AccountLinkError> for ApiError {
+    fn from(err: AccountLinkError) -> Self {
+        match err {
+            AccountLinkError::InvalidMethod(_) => ApiError::BadRequest(err.to_string()),
+            AccountLinkError::NotLinked(_) => ApiError::NotFound(err.to_string()),
+            AccountLinkError::AlreadyLinked(_) | AccountLinkError::LastMethod => {
+                ApiError::Conflict(err.to_string())
+            }
+            AccountLinkError::User(err) => err.into(),
+            AccountLinkError::Passkey(err) => err.into(),
+            AccountLinkError::DatabaseError(_) => ApiError::InternalServerError(err.to_string()),
+        }
+    }
+}
+
+impl From<EventReplayError> for ApiError {
+    fn from(err: EventReplayError) -> Self {
+        match err {
+            EventReplayError::EmptyRange => ApiError::UnprocessableEntity(err.to_string()),
+            EventReplayError::User(err) => err.into(),
+            EventReplayError::PublishFailed { resume_after, .. } => {
+                let resume = match resume_after {
+                    Some(cursor) => format!("resume with cursor {}", cursor),
+                    None => "retry the page".to_string(),
+                };
+                ApiError::InternalServerError(format!("{}; {}", err, resume))
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ApiResponseBody<T: Serialize + PartialEq> {
     status_code: u16,
@@ -1,3 +1,7 @@
+use std::net::IpAddr;
+
+use auth::SecretString;
+use auth::TokenPair;
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::Json;
@@ -6,76 +10,214 @@ use chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::list_audit_log::record_audit;
 use super::ApiError;
 use super::ApiSuccess;
+use crate::domain::account_link::models::AuthMethodKind;
+use crate::domain::account_link::ports::AccountLinkServicePort;
+use crate::domain::audit::models::AuditAction;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::lockout::errors::LockoutError;
+use crate::domain::lockout::models::LoginSubject;
+use crate::domain::lockout::ports::LockoutServicePort;
+use crate::domain::login_history::models::LoginContext;
+use crate::domain::login_history::ports::LoginHistoryServicePort;
+use crate::domain::user::models::Role;
 use crate::domain::user::models::User;
+use crate::domain::user::models::UserId;
 use crate::domain::user::ports::UserServicePort;
+use crate::inbound::http::middleware::ClientIp;
+use crate::inbound::http::middleware::UserAgent;
 use crate::inbound::http::router::AppState;
+use crate::inbound::metrics::count_login;
+use crate::inbound::metrics::LoginOutcome;
 use crate::user::errors::UserError;
-use crate::user::models::Username;
+use crate::user::models::LoginIdentifier;
 
+/// Log in with username or email address and password.
+///
+/// An identifier containing `@` is looked up as an email address, anything
+/// else as a username. Unknown accounts are answered exactly like a wrong
+/// password.
+///
+/// Issues an access and refresh token pair; `POST /api/auth/refresh` exchanges
+/// the refresh token for a new pair when the access token expires. Repeated
+/// wrong passwords lock the account for a while, answered with `423 Locked`.
+/// Unknown identifiers are locked the same way, so that a lock does not reveal
+/// which accounts exist.
+/// Successful logins are recorded in the login history of the user.
 pub async fn authenticate(
     State(state): State<AppState>,
+    ClientIp(ip_address): ClientIp,
+    UserAgent(user_agent): UserAgent,
     Json(body): Json<AuthenticateRequestBody>,
-) -> Result<ApiSuccess<AuthenticateResponseData>, ApiError> {
-    // Parse and validate username
-    let username = Username::new(body.username)
+) -> Result<ApiSuccess<TokenPairResponseData>, ApiError> {
+    let result = authenticate_password(&state, ip_address, user_agent, body).await;
+
+    // Successes are counted with the login history, see `record_login`
+    if matches!(result, Err(ApiError::Unauthorized(_) | ApiError::Locked(_))) {
+        count_login(AuthMethodKind::Password, LoginOutcome::Failure);
+    }
+
+    result
+}
+
+async fn authenticate_password(
+    state: &AppState,
+    ip_address: Option<IpAddr>,
+    user_agent: Option<String>,
+    body: AuthenticateRequestBody,
+) -> Result<ApiSuccess<TokenPairResponseData>, ApiError> {
+    // Malformed and unknown identifiers fail like a wrong password
+    let identifier = LoginIdentifier::parse(body.identifier)
         .map_err(|_| ApiError::Unauthorized("Invalid credentials".to_string()))?;
 
-    // Get user from database
-    let user = state
-        .user_service
-        .get_user_by_username(&username)
-        .await
-        .map_err(|e| match e {
-            UserError::NotFoundByUsername(_) => {
-                ApiError::Unauthorized("Invalid credentials".to_string())
-            }
-            _ => ApiError::from(e),
-        })?;
+    let user = match state.user_service.get_user_by_login(&identifier).await {
+        Ok(user) => user,
+        Err(UserError::NotFoundByUsername(_) | UserError::NotFoundByEmail(_)) => {
+            let subject = LoginSubject::Unknown(identifier.as_str().to_string());
+            state.lockout_service.ensure_unlocked(&subject).await?;
+            // Hash anyway so that response time does not reveal unknown accounts
+            let _ = state.authenticator.hash_password(&body.password);
+            return Err(failed_login(state, &subject).await);
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let subject = LoginSubject::User(user.id);
+
+    state.lockout_service.ensure_unlocked(&subject).await?;
 
     // Create JWT claims (from auth library)
     let claims = auth::Claims::for_user(
-        user.id.clone(),
+        user.id,
         user.username.as_str().to_string(),
         state.jwt_expiration_hours,
-    );
+    )
+    .with_roles(user.roles.iter().map(Role::as_str));
 
     // Verify password and generate token
-    let result = state
+    let verified = state
         .authenticator
-        .authenticate(&body.password, &user.password_hash, &claims)
-        .map_err(|e| match e {
-            auth::AuthenticationError::InvalidCredentials => {
-                ApiError::Unauthorized("Invalid credentials".to_string())
-            }
-            auth::AuthenticationError::PasswordError(err) => {
-                ApiError::InternalServerError(format!("Password verification failed: {}", err))
-            }
-            auth::AuthenticationError::JwtError(err) => {
-                ApiError::InternalServerError(format!("Token generation failed: {}", err))
-            }
-        })?;
+        .authenticate(&body.password, &user.password_hash, &claims);
+    let result = match verified {
+        Ok(result) => result,
+        Err(auth::AuthenticationError::InvalidCredentials) => {
+            record_audit(
+                state,
+                AuditEntry::new(AuditAction::LoginFailed)
+                    .on(user.id)
+                    .from_ip(ip_address),
+            )
+            .await;
+            return Err(failed_login(state, &subject).await);
+        }
+        Err(auth::AuthenticationError::PasswordError(err)) => {
+            return Err(ApiError::InternalServerError(format!(
+                "Password verification failed: {}",
+                err
+            )));
+        }
+        Err(auth::AuthenticationError::JwtError(err)) => {
+            return Err(ApiError::InternalServerError(format!(
+                "Token generation failed: {}",
+                err
+            )));
+        }
+    };
+
+    // Checked after the password so that timing does not reveal the link
+    if !state
+        .account_link_service
+        .is_linked(&user.id, AuthMethodKind::Password)
+        .await?
+    {
+        return Err(ApiError::Unauthorized("Invalid credentials".to_string()));
+    }
+
+    if let Err(e) = state.lockout_service.record_success(&subject).await {
+        tracing::warn!("Failed to clear failed logins of user {}: {}", user.id, e);
+    }
+
+    // Imported legacy hashes are upgraded on the first successful login
+    if let Some(password_hash) = result.rehashed_password {
+        if let Err(e) = state
+            .user_service
+            .replace_password_hash(&user.id, password_hash)
+            .await
+        {
+            tracing::warn!("Failed to upgrade password hash of user {}: {}", user.id, e);
+        }
+    }
+
+    let tokens = state
+        .authenticator
+        .issue_token_pair(&claims)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Token generation failed: {}", e)))?;
+
+    record_login(
+        state,
+        &user.id,
+        AuthMethodKind::Password,
+        LoginContext {
+            ip_address,
+            user_agent,
+        },
+    )
+    .await;
 
     Ok(ApiSuccess::new(
         StatusCode::OK,
-        AuthenticateResponseData {
-            user: (&user).into(),
-            token: result.access_token,
-        },
+        TokenPairResponseData::new(&user, tokens),
     ))
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub struct AuthenticateRequestBody {
-    username: String,
-    password: String,
+/// Count a failed login; the failure reaching the limit is already answered as locked.
+async fn failed_login(state: &AppState, subject: &LoginSubject) -> ApiError {
+    match state.lockout_service.record_failure(subject).await {
+        Ok(attempts) => match attempts.locked_until {
+            Some(until) => LockoutError::Locked(until).into(),
+            None => ApiError::Unauthorized("Invalid credentials".to_string()),
+        },
+        Err(e) => e.into(),
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct AuthenticateResponseData {
-    pub user: UserData,
-    pub token: String,
+/// Record a successful login in the login history of the user and the audit log.
+///
+/// A failure is logged rather than returned, so that it does not fail the login.
+pub(super) async fn record_login(
+    state: &AppState,
+    user_id: &UserId,
+    method: AuthMethodKind,
+    context: LoginContext,
+) {
+    count_login(method, LoginOutcome::Success);
+
+    record_audit(
+        state,
+        AuditEntry::new(AuditAction::Login)
+            .by(*user_id)
+            .on(*user_id)
+            .from_ip(context.ip_address)
+            .with_detail(method.as_str()),
+    )
+    .await;
+
+    if let Err(e) = state
+        .login_history_service
+        .record_login(user_id, method, context)
+        .await
+    {
+        tracing::warn!("Failed to record login of user {}: {}", user_id, e);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AuthenticateRequestBody {
+    #[serde(alias = "username", alias = "email")]
+    identifier: String,
+    password: SecretString,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
@@ -96,3 +238,25 @@ impl From<&User> for UserData {
         }
     }
 }
+
+/// Logged-in user with an access and refresh token pair
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TokenPairResponseData {
+    pub user: UserData,
+    pub token: String,
+    pub refresh_token: String,
+    pub expires_at: DateTime<Utc>,
+    pub refresh_expires_at: DateTime<Utc>,
+}
+
+impl TokenPairResponseData {
+    pub fn new(user: &User, tokens: TokenPair) -> Self {
+        Self {
+            user: user.into(),
+            token: tokens.access_token,
+            refresh_token: tokens.refresh_token,
+            expires_at: tokens.access_expires_at,
+            refresh_expires_at: tokens.refresh_expires_at,
+        }
+    }
+}
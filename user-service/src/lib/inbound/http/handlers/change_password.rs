@@ -0,0 +1,74 @@
+use auth::SecretString;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+
+use crate::domain::audit::models::AuditAction;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::user::models::UserId;
+use crate::inbound::http::handlers::list_audit_log::record_audit;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::middleware::ClientIp;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+use crate::user::ports::UserServicePort;
+
+/// Change the caller's password, re-checking the current one.
+///
+/// Every access token and refresh token of the caller, including the one of
+/// this request, is revoked: each device logs in again with the new password.
+pub async fn change_password(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    ClientIp(ip_address): ClientIp,
+    Path(id): Path<String>,
+    Json(body): Json<ChangePasswordRequestBody>,
+) -> Result<ApiSuccess<()>, ApiError> {
+    let user_id = UserId::from_string(&id).map_err(UserError::from)?;
+    if user_id != auth_user.user_id {
+        return Err(ApiError::Forbidden(
+            "Only the account owner can change its password".to_string(),
+        ));
+    }
+
+    state
+        .user_service
+        .change_password(&user_id, body.current_password, body.new_password)
+        .await
+        .map_err(|e| match e {
+            UserError::InvalidCredentials => {
+                ApiError::Forbidden("Current password is incorrect".to_string())
+            }
+            e => ApiError::from(e),
+        })?;
+
+    record_audit(
+        &state,
+        AuditEntry::new(AuditAction::PasswordChanged)
+            .by(user_id)
+            .on(user_id)
+            .from_ip(ip_address),
+    )
+    .await;
+
+    if let Err(e) = state
+        .authenticator
+        .revoke_user_tokens(&user_id.to_string())
+        .await
+    {
+        tracing::warn!("Failed to revoke tokens of user {}: {}", user_id, e);
+    }
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+/// The body of a change password request (raw JSON)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ChangePasswordRequestBody {
+    current_password: SecretString,
+    new_password: SecretString,
+}
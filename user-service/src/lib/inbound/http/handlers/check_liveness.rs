@@ -0,0 +1,14 @@
+use axum::http::StatusCode;
+use serde::Serialize;
+
+use super::ApiSuccess;
+
+/// Liveness probe: the process answers HTTP, dependencies are not checked.
+pub async fn check_liveness() -> ApiSuccess<LivenessResponseData> {
+    ApiSuccess::new(StatusCode::OK, LivenessResponseData { status: "ok" })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LivenessResponseData {
+    pub status: &'static str,
+}
@@ -0,0 +1,55 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Serialize;
+
+use super::ApiSuccess;
+use crate::inbound::health::DependencyStatus;
+use crate::inbound::http::router::AppState;
+
+/// Readiness probe: answers `503 Service Unavailable` while a dependency is unreachable.
+pub async fn check_readiness(State(state): State<AppState>) -> ApiSuccess<ReadinessResponseData> {
+    let statuses = state.health_checks.check_all().await;
+    let ready = statuses.iter().all(|status| status.healthy);
+
+    let code = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    ApiSuccess::new(
+        code,
+        ReadinessResponseData {
+            status: status_label(ready),
+            dependencies: statuses.iter().map(Into::into).collect(),
+        },
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReadinessResponseData {
+    pub status: &'static str,
+    pub dependencies: Vec<DependencyResponseData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DependencyResponseData {
+    pub name: &'static str,
+    pub status: &'static str,
+}
+
+impl From<&DependencyStatus> for DependencyResponseData {
+    fn from(status: &DependencyStatus) -> Self {
+        Self {
+            name: status.name,
+            status: status_label(status.healthy),
+        }
+    }
+}
+
+fn status_label(healthy: bool) -> &'static str {
+    if healthy {
+        "ok"
+    } else {
+        "unavailable"
+    }
+}
@@ -0,0 +1,45 @@
+use auth::SecretString;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+
+use super::list_audit_log::record_audit;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::audit::models::AuditAction;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::password_reset::ports::PasswordResetServicePort;
+use crate::inbound::http::middleware::ClientIp;
+use crate::inbound::http::router::AppState;
+
+/// Set a new password with the token of a password reset link.
+///
+/// All sessions of the user end; they log in again with the new password.
+pub async fn confirm_password_reset(
+    State(state): State<AppState>,
+    ClientIp(ip_address): ClientIp,
+    Json(body): Json<ConfirmPasswordResetRequestBody>,
+) -> Result<ApiSuccess<()>, ApiError> {
+    let user_id = state
+        .password_reset_service
+        .confirm_reset(&body.token, body.new_password)
+        .await?;
+
+    record_audit(
+        &state,
+        AuditEntry::new(AuditAction::PasswordReset)
+            .on(user_id)
+            .from_ip(ip_address),
+    )
+    .await;
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+/// The body of a password reset confirmation (raw JSON)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ConfirmPasswordResetRequestBody {
+    token: String,
+    new_password: SecretString,
+}
@@ -0,0 +1,64 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::Duration;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::list_personal_tokens::PersonalTokenData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::personal_token::models::CreatePersonalTokenCommand;
+use crate::domain::personal_token::models::PersonalTokenName;
+use crate::domain::personal_token::models::PersonalTokenScopes;
+use crate::domain::personal_token::ports::PersonalTokenServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// Mint a personal access token for the caller.
+///
+/// The token is only part of this response; it cannot be retrieved later.
+pub async fn create_personal_token(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Json(body): Json<CreatePersonalTokenRequestBody>,
+) -> Result<ApiSuccess<CreatePersonalTokenResponseData>, ApiError> {
+    let command = CreatePersonalTokenCommand {
+        name: PersonalTokenName::new(body.name)?,
+        scopes: PersonalTokenScopes::new(body.scopes)?,
+        lifetime: body.expires_in_days.map(|days| Duration::days(days.into())),
+    };
+
+    state
+        .personal_token_service
+        .create_token(&auth_user.user_id, command)
+        .await
+        .map_err(ApiError::from)
+        .map(|issued| {
+            ApiSuccess::new(
+                StatusCode::CREATED,
+                CreatePersonalTokenResponseData {
+                    token: issued.secret.expose_secret().to_string(),
+                    personal_token: (&issued.token).into(),
+                },
+            )
+        })
+}
+
+/// The body of a personal access token creation request (raw JSON)
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreatePersonalTokenRequestBody {
+    name: String,
+    scopes: Vec<String>,
+    /// Days until the token expires, the configured default if absent
+    #[serde(default)]
+    expires_in_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreatePersonalTokenResponseData {
+    /// Token to send as `Authorization: Bearer <token>`, shown only once
+    pub token: String,
+    #[serde(flatten)]
+    pub personal_token: PersonalTokenData,
+}
@@ -1,4 +1,7 @@
+use auth::SecretString;
 use axum::extract::State;
+use axum::http::header::HeaderName;
+use axum::http::HeaderMap;
 use axum::http::StatusCode;
 use axum::Json;
 use chrono::DateTime;
@@ -9,22 +12,54 @@ use thiserror::Error;
 
 use super::ApiError;
 use super::ApiSuccess;
+use crate::domain::idempotency::models::IdempotencyKey;
+use crate::domain::signup::models::SignupCommand;
+use crate::domain::signup::ports::SignupServicePort;
 use crate::domain::user::models::CreateUserCommand;
 use crate::domain::user::models::EmailAddress;
 use crate::domain::user::models::User;
 use crate::domain::user::models::Username;
-use crate::domain::user::ports::UserServicePort;
+use crate::inbound::http::middleware::ClientIp;
 use crate::inbound::http::router::AppState;
 use crate::user::errors::EmailError;
 use crate::user::errors::UsernameError;
 
+/// Header of the key that makes a signup safe to retry
+const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Open an account through public registration.
+///
+/// Rate limited per client IP, see [`crate::inbound::http::middleware::limit_signups`].
+/// A signup retried with the `Idempotency-Key` of a successful one gets the
+/// same user back instead of a duplicate username error.
 pub async fn create_user(
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    headers: HeaderMap,
     Json(body): Json<CreateUserRequest>,
 ) -> Result<ApiSuccess<CreateUserResponseData>, ApiError> {
+    let idempotency_key = headers
+        .get(IDEMPOTENCY_KEY)
+        .map(|value| {
+            let value = value
+                .to_str()
+                .map_err(|_| ApiError::BadRequest("Invalid Idempotency-Key header".to_string()))?;
+            IdempotencyKey::new(value.to_string())
+                .map_err(|e| ApiError::BadRequest(format!("Invalid Idempotency-Key header: {}", e)))
+        })
+        .transpose()?;
+
+    let captcha_response = body.captcha_response.clone();
+    let command = SignupCommand {
+        user: body.try_into_command()?,
+        captcha_response,
+        client_ip,
+        idempotency_key,
+    };
+
     state
-        .user_service
-        .create_user(body.try_into_command()?)
+        .signup_service
+        .sign_up(command)
         .await
         .map_err(ApiError::from)
         .map(|ref user| ApiSuccess::new(StatusCode::CREATED, user.into()))
@@ -35,7 +70,10 @@ pub async fn create_user(
 pub struct CreateUserRequest {
     username: String,
     email_address: String,
-    password: String,
+    password: SecretString,
+    /// Response token of the CAPTCHA widget, required when CAPTCHA is enabled
+    #[serde(default)]
+    captcha_response: Option<String>,
 }
 
 #[derive(Debug, Clone, Error)]
@@ -0,0 +1,37 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use crate::domain::user::models::UserId;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+use crate::user::ports::UserServicePort;
+
+/// Deactivate an account, signing it out everywhere.
+///
+/// Owners deactivate their own account, admins any account. Only admins can
+/// reactivate it.
+pub async fn deactivate_user(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<ApiSuccess<()>, ApiError> {
+    let user_id = UserId::from_string(&id).map_err(UserError::from)?;
+
+    state.user_service.deactivate_user(&user_id).await?;
+
+    if let Err(e) = state
+        .authenticator
+        .revoke_all_sessions(&user_id.to_string())
+        .await
+    {
+        tracing::warn!(
+            "Failed to revoke sessions of deactivated user {}: {}",
+            user_id,
+            e
+        );
+    }
+
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, ()))
+}
@@ -0,0 +1,28 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::account_link::models::AuthMethodId;
+use crate::domain::account_link::ports::AccountLinkServicePort;
+use crate::domain::passkey::errors::PasskeyError;
+use crate::domain::passkey::models::PasskeyId;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// Remove one of the caller's passkeys, unless it is their last login method.
+pub async fn delete_passkey(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<ApiSuccess<()>, ApiError> {
+    let passkey_id = PasskeyId::from_string(&id).map_err(PasskeyError::from)?;
+
+    state
+        .account_link_service
+        .remove_method(&auth_user.user_id, &AuthMethodId::Passkey(passkey_id))
+        .await
+        .map_err(ApiError::from)
+        .map(|_| ApiSuccess::new(StatusCode::NO_CONTENT, ()))
+}
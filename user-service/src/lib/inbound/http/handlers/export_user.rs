@@ -0,0 +1,72 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Serialize;
+
+use super::get_user::GetUserResponseData;
+use super::list_auth_methods::AuthMethodData;
+use super::list_logins::ListLoginsResponseData;
+use super::list_personal_tokens::PersonalTokenData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::account_link::ports::AccountLinkServicePort;
+use crate::domain::login_history::ports::LoginHistoryServicePort;
+use crate::domain::personal_token::ports::PersonalTokenServicePort;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+
+/// Most logins included in an export
+const MAX_EXPORTED_LOGINS: u32 = 10_000;
+
+/// Export the personal data held about an account, for data access requests.
+///
+/// Gathers the profile, the login history, the linked login methods and the
+/// personal access tokens into one document. Token secrets and password
+/// hashes are never exported. Available to the account owner and to admins.
+pub async fn export_user(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<ApiSuccess<ExportUserResponseData>, ApiError> {
+    let user_id = UserId::from_string(&id).map_err(UserError::from)?;
+
+    let user = state.user_service.get_user(&user_id).await?;
+    let logins = state
+        .login_history_service
+        .login_history(&user_id, MAX_EXPORTED_LOGINS)
+        .await?;
+    let auth_methods = state.account_link_service.list_methods(&user_id).await?;
+    let personal_tokens = state.personal_token_service.list_tokens(&user_id).await?;
+
+    tracing::info!(requested_by = %auth_user.user_id, user_id = %user_id, "Exported user data");
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        ExportUserResponseData {
+            exported_at: Utc::now(),
+            profile: (&user).into(),
+            roles: user
+                .roles
+                .iter()
+                .map(|role| role.as_str().to_string())
+                .collect(),
+            logins: (&logins).into(),
+            auth_methods: auth_methods.iter().map(Into::into).collect(),
+            personal_tokens: personal_tokens.iter().map(Into::into).collect(),
+        },
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExportUserResponseData {
+    pub exported_at: DateTime<Utc>,
+    pub profile: GetUserResponseData,
+    pub roles: Vec<String>,
+    pub logins: ListLoginsResponseData,
+    pub auth_methods: Vec<AuthMethodData>,
+    pub personal_tokens: Vec<PersonalTokenData>,
+}
@@ -0,0 +1,60 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+use webauthn_rs::prelude::PublicKeyCredential;
+
+use super::authenticate::record_login;
+use super::authenticate::TokenPairResponseData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::account_link::models::AuthMethodKind;
+use crate::domain::login_history::models::LoginContext;
+use crate::domain::passkey::errors::PasskeyError;
+use crate::domain::passkey::models::CeremonyId;
+use crate::domain::passkey::ports::PasskeyServicePort;
+use crate::inbound::http::middleware::ClientIp;
+use crate::inbound::http::middleware::UserAgent;
+use crate::inbound::http::router::AppState;
+
+/// Verify the result of `navigator.credentials.get()` and issue a token pair.
+pub async fn finish_passkey_login(
+    State(state): State<AppState>,
+    ClientIp(ip_address): ClientIp,
+    UserAgent(user_agent): UserAgent,
+    Json(body): Json<FinishPasskeyLoginRequestBody>,
+) -> Result<ApiSuccess<TokenPairResponseData>, ApiError> {
+    let passkey_service = state
+        .passkey_service
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Passkeys are not enabled".to_string()))?;
+
+    let ceremony_id = CeremonyId::from_string(&body.ceremony_id).map_err(PasskeyError::from)?;
+
+    let login = passkey_service
+        .finish_login(&ceremony_id, body.credential)
+        .await?;
+
+    record_login(
+        &state,
+        &login.user.id,
+        AuthMethodKind::Passkey,
+        LoginContext {
+            ip_address,
+            user_agent,
+        },
+    )
+    .await;
+
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        TokenPairResponseData::new(&login.user, login.tokens),
+    ))
+}
+
+/// The body of a passkey login (raw JSON)
+#[derive(Debug, Clone, Deserialize)]
+pub struct FinishPasskeyLoginRequestBody {
+    ceremony_id: String,
+    credential: PublicKeyCredential,
+}
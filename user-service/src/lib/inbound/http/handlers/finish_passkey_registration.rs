@@ -0,0 +1,49 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+use webauthn_rs::prelude::RegisterPublicKeyCredential;
+
+use super::list_passkeys::PasskeyData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::passkey::errors::PasskeyError;
+use crate::domain::passkey::models::CeremonyId;
+use crate::domain::passkey::models::PasskeyName;
+use crate::domain::passkey::ports::PasskeyServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// Verify the result of `navigator.credentials.create()` and store the passkey.
+pub async fn finish_passkey_registration(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Json(body): Json<FinishPasskeyRegistrationRequestBody>,
+) -> Result<ApiSuccess<PasskeyData>, ApiError> {
+    let passkey_service = state
+        .passkey_service
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Passkeys are not enabled".to_string()))?;
+
+    let ceremony_id = CeremonyId::from_string(&body.ceremony_id).map_err(PasskeyError::from)?;
+    let name = body
+        .name
+        .map(PasskeyName::new)
+        .transpose()?
+        .unwrap_or_default();
+
+    passkey_service
+        .finish_registration(&auth_user.user_id, &ceremony_id, name, body.credential)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref passkey| ApiSuccess::new(StatusCode::CREATED, passkey.into()))
+}
+
+/// The body of a passkey registration (raw JSON)
+#[derive(Debug, Clone, Deserialize)]
+pub struct FinishPasskeyRegistrationRequestBody {
+    ceremony_id: String,
+    #[serde(default)]
+    name: Option<String>,
+    credential: RegisterPublicKeyCredential,
+}
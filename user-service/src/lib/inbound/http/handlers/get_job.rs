@@ -0,0 +1,71 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Serialize;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::job::models::Job;
+use crate::domain::job::models::JobId;
+use crate::domain::job::ports::JobServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+pub async fn get_job(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(job_id): Path<String>,
+) -> Result<ApiSuccess<JobResponseData>, ApiError> {
+    let job_id = JobId::from_string(&job_id).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    state
+        .job_service
+        .get_job(&job_id, &auth_user.user_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref job| ApiSuccess::new(StatusCode::OK, job.into()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JobResponseData {
+    pub id: String,
+    pub kind: String,
+    pub status: String,
+    pub progress: i16,
+    pub result_url: Option<String>,
+    pub error: Option<String>,
+    pub item_errors: Vec<JobItemErrorData>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JobItemErrorData {
+    pub item: usize,
+    pub message: String,
+}
+
+impl From<&Job> for JobResponseData {
+    fn from(job: &Job) -> Self {
+        Self {
+            id: job.id.to_string(),
+            kind: job.kind.to_string(),
+            status: job.status.to_string(),
+            progress: job.progress.value(),
+            result_url: job.result_url.clone(),
+            error: job.error.clone(),
+            item_errors: job
+                .item_errors
+                .iter()
+                .map(|e| JobItemErrorData {
+                    item: e.item,
+                    message: e.message.clone(),
+                })
+                .collect(),
+            created_at: job.created_at,
+            updated_at: job.updated_at,
+        }
+    }
+}
@@ -31,6 +31,10 @@ pub struct GetUserResponseData {
     pub id: String,
     pub username: String,
     pub email: String,
+    pub email_verified: bool,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -40,6 +44,40 @@ impl From<&User> for GetUserResponseData {
             id: user.id.to_string(),
             username: user.username.as_str().to_string(),
             email: user.email.as_str().to_string(),
+            email_verified: user.email_verified,
+            display_name: user
+                .display_name
+                .as_ref()
+                .map(|name| name.as_str().to_string()),
+            avatar_url: user.avatar_url.as_ref().map(|url| url.as_str().to_string()),
+            bio: user.bio.as_ref().map(|bio| bio.as_str().to_string()),
+            created_at: user.created_at,
+        }
+    }
+}
+
+/// Profile fields any signed-in user may see of another, without contact details.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PublicUserResponseData {
+    pub id: String,
+    pub username: String,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&User> for PublicUserResponseData {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id.to_string(),
+            username: user.username.as_str().to_string(),
+            display_name: user
+                .display_name
+                .as_ref()
+                .map(|name| name.as_str().to_string()),
+            avatar_url: user.avatar_url.as_ref().map(|url| url.as_str().to_string()),
+            bio: user.bio.as_ref().map(|bio| bio.as_str().to_string()),
             created_at: user.created_at,
         }
     }
@@ -0,0 +1,14 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use buildinfo::BuildInfo;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::inbound::http::router::AppState;
+
+pub async fn get_version(State(state): State<AppState>) -> Result<ApiSuccess<BuildInfo>, ApiError> {
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        state.build_info.as_ref().clone(),
+    ))
+}
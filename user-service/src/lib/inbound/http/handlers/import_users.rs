@@ -0,0 +1,354 @@
+use axum::extract::FromRequest;
+use axum::extract::Multipart;
+use axum::extract::Request;
+use axum::extract::State;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::StatusCode;
+use serde::Deserialize;
+
+use super::get_job::JobResponseData;
+use super::list_audit_log::record_audit;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::audit::models::AuditAction;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::import::models::ImportRow;
+use crate::domain::import::ports::UserImportServicePort;
+use crate::domain::user::models::EmailAddress;
+use crate::domain::user::models::ImportUserCommand;
+use crate::domain::user::models::Username;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::middleware::ClientIp;
+use crate::inbound::http::router::AppState;
+
+/// Form field holding the file of a `multipart/form-data` import
+const IMPORT_FILE_FIELD: &str = "file";
+
+/// Start a bulk user import from a CSV or NDJSON upload.
+///
+/// CSV needs a header row with `username`, `email` and optionally
+/// `password_hash` columns; NDJSON has one such object per line. The file is
+/// either the request body or the `file` part of a `multipart/form-data` form.
+/// The import runs as a job whose per-row errors are reported by
+/// `GET /api/jobs/{id}`.
+pub async fn import_users(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    ClientIp(ip_address): ClientIp,
+    request: Request,
+) -> Result<ApiSuccess<JobResponseData>, ApiError> {
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Importing users requires the admin role".to_string(),
+        ));
+    }
+
+    let upload = ImportUpload::read(request).await?;
+    let rows = match upload.format() {
+        Some(ImportFormat::Csv) => parse_csv(&upload.body)?,
+        Some(ImportFormat::Ndjson) => parse_ndjson(&upload.body),
+        None => {
+            return Err(ApiError::BadRequest(format!(
+                "Unsupported import content type '{}', expected text/csv or application/x-ndjson",
+                upload.content_type
+            )))
+        }
+    };
+
+    if rows.is_empty() {
+        return Err(ApiError::UnprocessableEntity(
+            "Import contains no users".to_string(),
+        ));
+    }
+
+    tracing::info!(
+        requested_by = %auth_user.user_id,
+        rows = rows.len(),
+        "User import requested"
+    );
+
+    let row_count = rows.len();
+    let job = state
+        .import_service
+        .start_import(&auth_user.user_id, rows)
+        .await?;
+
+    record_audit(
+        &state,
+        AuditEntry::new(AuditAction::UsersImported)
+            .by(auth_user.user_id)
+            .from_ip(ip_address)
+            .with_detail(format!("{} rows in job {}", row_count, job.id)),
+    )
+    .await;
+
+    Ok(ApiSuccess::new(StatusCode::ACCEPTED, (&job).into()))
+}
+
+/// An uploaded import file
+struct ImportUpload {
+    content_type: String,
+    file_name: Option<String>,
+    body: String,
+}
+
+impl ImportUpload {
+    /// Read the upload from the request body.
+    ///
+    /// A `multipart/form-data` form is streamed part by part, buffering only
+    /// the `file` part. Either way the body limit of the route applies.
+    async fn read(request: Request) -> Result<Self, ApiError> {
+        let content_type = request
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        if mime_type(&content_type) != "multipart/form-data" {
+            let body = String::from_request(request, &())
+                .await
+                .map_err(|e| body_error(e.status(), e.body_text()))?;
+            return Ok(Self {
+                content_type,
+                file_name: None,
+                body,
+            });
+        }
+
+        let mut multipart = Multipart::from_request(request, &())
+            .await
+            .map_err(|e| body_error(e.status(), e.body_text()))?;
+        while let Some(mut field) = multipart
+            .next_field()
+            .await
+            .map_err(|e| body_error(e.status(), e.body_text()))?
+        {
+            if field.name() != Some(IMPORT_FILE_FIELD) {
+                continue;
+            }
+
+            let content_type = field.content_type().unwrap_or_default().to_string();
+            let file_name = field.file_name().map(str::to_string);
+            let mut bytes = Vec::new();
+            while let Some(chunk) = field
+                .chunk()
+                .await
+                .map_err(|e| body_error(e.status(), e.body_text()))?
+            {
+                bytes.extend_from_slice(&chunk);
+            }
+            let body = String::from_utf8(bytes)
+                .map_err(|_| ApiError::BadRequest("Import file is not valid UTF-8".to_string()))?;
+
+            return Ok(Self {
+                content_type,
+                file_name,
+                body,
+            });
+        }
+
+        Err(ApiError::BadRequest(format!(
+            "Multipart import has no '{}' part",
+            IMPORT_FILE_FIELD
+        )))
+    }
+
+    fn format(&self) -> Option<ImportFormat> {
+        ImportFormat::from_content_type(&self.content_type).or_else(|| {
+            self.file_name
+                .as_deref()
+                .and_then(ImportFormat::from_file_name)
+        })
+    }
+}
+
+/// Map a rejected upload body, keeping `413 Payload Too Large` apart.
+fn body_error(status: StatusCode, message: String) -> ApiError {
+    if status == StatusCode::PAYLOAD_TOO_LARGE {
+        ApiError::PayloadTooLarge("Import exceeds the upload size limit".to_string())
+    } else {
+        ApiError::BadRequest(message)
+    }
+}
+
+fn mime_type(content_type: &str) -> &str {
+    content_type.split(';').next().unwrap_or_default().trim()
+}
+
+enum ImportFormat {
+    Csv,
+    Ndjson,
+}
+
+impl ImportFormat {
+    fn from_content_type(content_type: &str) -> Option<Self> {
+        match mime_type(content_type) {
+            "text/csv" => Some(ImportFormat::Csv),
+            "application/x-ndjson" | "application/ndjson" | "application/jsonl" => {
+                Some(ImportFormat::Ndjson)
+            }
+            _ => None,
+        }
+    }
+
+    fn from_file_name(file_name: &str) -> Option<Self> {
+        match file_name.rsplit_once('.')?.1 {
+            "csv" => Some(ImportFormat::Csv),
+            "ndjson" | "jsonl" => Some(ImportFormat::Ndjson),
+            _ => None,
+        }
+    }
+}
+
+/// One user of an NDJSON import (raw JSON)
+#[derive(Debug, Deserialize)]
+struct ImportUserRecord {
+    username: String,
+    email: String,
+    #[serde(default)]
+    password_hash: Option<String>,
+}
+
+impl ImportUserRecord {
+    fn try_into_command(self) -> Result<ImportUserCommand, String> {
+        let username =
+            Username::new(self.username).map_err(|e| format!("Invalid username: {}", e))?;
+        let email = EmailAddress::new(self.email).map_err(|e| format!("Invalid email: {}", e))?;
+        let password_hash = self.password_hash.filter(|hash| !hash.is_empty());
+
+        Ok(ImportUserCommand {
+            username,
+            email,
+            password_hash,
+        })
+    }
+}
+
+fn parse_ndjson(body: &str) -> Vec<ImportRow> {
+    body.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| ImportRow {
+            number: index + 1,
+            command: serde_json::from_str::<ImportUserRecord>(line)
+                .map_err(|e| format!("Invalid JSON: {}", e))
+                .and_then(ImportUserRecord::try_into_command),
+        })
+        .collect()
+}
+
+fn parse_csv(body: &str) -> Result<Vec<ImportRow>, ApiError> {
+    let mut lines = body
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    let Some((_, header)) = lines.next() else {
+        return Ok(Vec::new());
+    };
+    let columns = split_csv_line(header)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid CSV header: {}", e)))?;
+    let position = |name: &str| columns.iter().position(|column| column.trim() == name);
+    let (Some(username_column), Some(email_column)) = (position("username"), position("email"))
+    else {
+        return Err(ApiError::BadRequest(
+            "CSV header must contain username and email columns".to_string(),
+        ));
+    };
+    let password_hash_column = position("password_hash");
+
+    Ok(lines
+        .map(|(index, line)| ImportRow {
+            number: index + 1,
+            command: split_csv_line(line).and_then(|fields| {
+                let field = |column: usize| fields.get(column).cloned().unwrap_or_default();
+                ImportUserRecord {
+                    username: field(username_column),
+                    email: field(email_column),
+                    password_hash: password_hash_column.map(field),
+                }
+                .try_into_command()
+            }),
+        })
+        .collect())
+}
+
+/// Split a CSV line into fields, honouring double-quoted fields (RFC 4180).
+fn split_csv_line(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_reports_invalid_rows() {
+        let body = "username,email,password_hash\n\
+                    alice,alice@example.com,\n\
+                    \"bob\",\"bob@example.com\",\"$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA\"\n\
+                    carol,not-an-email,\n";
+
+        let rows = parse_csv(body).unwrap();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].number, 2);
+        assert!(matches!(&rows[0].command, Ok(c) if c.password_hash.is_none()));
+        assert!(
+            matches!(&rows[1].command, Ok(c) if c.password_hash.as_deref()
+            == Some("$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA"))
+        );
+        assert!(matches!(&rows[2].command, Err(e) if e.starts_with("Invalid email")));
+    }
+
+    #[test]
+    fn test_parse_csv_requires_columns() {
+        assert!(parse_csv("name,mail\nalice,alice@example.com").is_err());
+    }
+
+    #[test]
+    fn test_import_format_falls_back_to_file_name() {
+        let upload = ImportUpload {
+            content_type: "application/octet-stream".to_string(),
+            file_name: Some("users.jsonl".to_string()),
+            body: String::new(),
+        };
+
+        assert!(matches!(upload.format(), Some(ImportFormat::Ndjson)));
+    }
+
+    #[test]
+    fn test_parse_ndjson_keeps_line_numbers() {
+        let body = "{\"username\":\"alice\",\"email\":\"alice@example.com\"}\n\n{not json}\n";
+
+        let rows = parse_ndjson(body);
+
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].command.is_ok());
+        assert_eq!(rows[1].number, 3);
+        assert!(matches!(&rows[1].command, Err(e) if e.starts_with("Invalid JSON")));
+    }
+}
@@ -0,0 +1,32 @@
+use auth::SecretString;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+
+use super::list_auth_methods::AuthMethodData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::account_link::ports::AccountLinkServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// Add password login to an account that has none (e.g. signed up by link).
+pub async fn link_password(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Json(body): Json<LinkPasswordRequestBody>,
+) -> Result<ApiSuccess<AuthMethodData>, ApiError> {
+    state
+        .account_link_service
+        .link_password(&auth_user.user_id, body.password)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref method| ApiSuccess::new(StatusCode::CREATED, method.into()))
+}
+
+/// The body of a password link request (raw JSON)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct LinkPasswordRequestBody {
+    password: SecretString,
+}
@@ -0,0 +1,92 @@
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::get_user::GetUserResponseData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::user::models::UserCursor;
+use crate::domain::user::models::UserPage;
+use crate::domain::user::models::UserStatus;
+use crate::domain::user::ports::UserServicePort;
+use crate::inbound::http::router::AppState;
+
+/// Page size when the query does not name one
+const DEFAULT_LIMIT: u32 = 50;
+/// Largest page size a client may ask for
+const MAX_LIMIT: u32 = 100;
+
+/// List users with a status, newest first, one page at a time.
+///
+/// Unlike `GET /api/users`, admins also see deactivated, locked and deleted
+/// accounts, along with the roles of each user.
+pub async fn list_admin_users(
+    State(state): State<AppState>,
+    Query(query): Query<ListAdminUsersQuery>,
+) -> Result<ApiSuccess<ListAdminUsersResponseData>, ApiError> {
+    let status = match query.status.as_deref() {
+        Some(status) => UserStatus::parse(status)
+            .ok_or_else(|| ApiError::BadRequest(format!("Unknown user status: {}", status)))?,
+        None => UserStatus::Active,
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let after = query
+        .cursor
+        .as_deref()
+        .map(UserCursor::parse)
+        .transpose()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    state
+        .user_service
+        .list_users(status, limit, after)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref page| ApiSuccess::new(StatusCode::OK, (page, status).into()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ListAdminUsersQuery {
+    /// `active` (default), `deactivated`, `locked` or `deleted`
+    status: Option<String>,
+    limit: Option<u32>,
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AdminUserData {
+    #[serde(flatten)]
+    pub user: GetUserResponseData,
+    pub status: String,
+    pub roles: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListAdminUsersResponseData {
+    pub items: Vec<AdminUserData>,
+    /// Cursor of the next page, None on the last page
+    pub next_cursor: Option<String>,
+}
+
+impl From<(&UserPage, UserStatus)> for ListAdminUsersResponseData {
+    fn from((page, status): (&UserPage, UserStatus)) -> Self {
+        Self {
+            items: page
+                .users
+                .iter()
+                .map(|user| AdminUserData {
+                    user: user.into(),
+                    status: status.as_str().to_string(),
+                    roles: user
+                        .roles
+                        .iter()
+                        .map(|role| role.as_str().to_string())
+                        .collect(),
+                })
+                .collect(),
+            next_cursor: page.next_cursor.as_ref().map(ToString::to_string),
+        }
+    }
+}
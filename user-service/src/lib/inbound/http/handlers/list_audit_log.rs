@@ -0,0 +1,125 @@
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::StatusCode;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::audit::models::AuditAction;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::audit::models::AuditQuery;
+use crate::domain::audit::ports::AuditLogServicePort;
+use crate::domain::user::models::UserId;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+
+/// Entries returned when the query does not name a limit
+const DEFAULT_LIMIT: u32 = 50;
+/// Most entries a client may ask for
+const MAX_LIMIT: u32 = 200;
+
+/// Search the audit log, newest entries first, one page at a time.
+///
+/// Entries can be narrowed to the user who acted, the user acted on and the
+/// action. The next page is requested with `before` set to `next_before`.
+pub async fn list_audit_log(
+    State(state): State<AppState>,
+    Query(query): Query<ListAuditLogQuery>,
+) -> Result<ApiSuccess<ListAuditLogResponseData>, ApiError> {
+    let actor_id = query
+        .actor_id
+        .as_deref()
+        .map(UserId::from_string)
+        .transpose()
+        .map_err(UserError::from)?;
+    let target_id = query
+        .target_id
+        .as_deref()
+        .map(UserId::from_string)
+        .transpose()
+        .map_err(UserError::from)?;
+    let action = query
+        .action
+        .as_deref()
+        .map(str::parse::<AuditAction>)
+        .transpose()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let entries = state
+        .audit_log_service
+        .search(&AuditQuery {
+            actor_id,
+            target_id,
+            action,
+            before: query.before,
+            limit,
+        })
+        .await?;
+
+    // A full page may be followed by older entries
+    let next_before = match entries.last() {
+        Some(last) if entries.len() == limit as usize => Some(last.occurred_at),
+        _ => None,
+    };
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        ListAuditLogResponseData {
+            items: entries.iter().map(Into::into).collect(),
+            next_before,
+        },
+    ))
+}
+
+/// Record a security-relevant action in the audit log.
+///
+/// A failure is logged rather than returned, so that it does not fail the
+/// action, which has already happened.
+pub(super) async fn record_audit(state: &AppState, entry: AuditEntry) {
+    let action = entry.action;
+    if let Err(e) = state.audit_log_service.record(entry).await {
+        tracing::warn!("Failed to record {} in the audit log: {}", action, e);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ListAuditLogQuery {
+    actor_id: Option<String>,
+    target_id: Option<String>,
+    action: Option<String>,
+    before: Option<DateTime<Utc>>,
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListAuditLogResponseData {
+    pub items: Vec<AuditEntryData>,
+    /// `before` of the next page, None on the last page
+    pub next_before: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuditEntryData {
+    pub action: String,
+    pub actor_id: Option<String>,
+    pub target_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub detail: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl From<&AuditEntry> for AuditEntryData {
+    fn from(entry: &AuditEntry) -> Self {
+        Self {
+            action: entry.action.as_str().to_string(),
+            actor_id: entry.actor_id.as_ref().map(ToString::to_string),
+            target_id: entry.target_id.as_ref().map(ToString::to_string),
+            ip_address: entry.ip_address.map(|ip| ip.to_string()),
+            detail: entry.detail.clone(),
+            occurred_at: entry.occurred_at,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Serialize;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::account_link::models::AuthMethod;
+use crate::domain::account_link::ports::AccountLinkServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// List the login methods linked to the caller's account.
+pub async fn list_auth_methods(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<ApiSuccess<Vec<AuthMethodData>>, ApiError> {
+    state
+        .account_link_service
+        .list_methods(&auth_user.user_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|methods| ApiSuccess::new(StatusCode::OK, methods.iter().map(Into::into).collect()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuthMethodData {
+    /// `password`, `magic_link`, or the passkey ID
+    pub id: String,
+    pub kind: String,
+    pub name: Option<String>,
+    pub linked_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<&AuthMethod> for AuthMethodData {
+    fn from(method: &AuthMethod) -> Self {
+        Self {
+            id: method.id.to_string(),
+            kind: method.id.kind().to_string(),
+            name: method.name.clone(),
+            linked_at: method.linked_at,
+            last_used_at: method.last_used_at,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::StatusCode;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::login_history::models::LoginHistory;
+use crate::domain::login_history::models::LoginRecord;
+use crate::domain::login_history::ports::LoginHistoryServicePort;
+use crate::domain::user::models::UserId;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+
+/// Logins returned when the query does not name a limit
+const DEFAULT_LIMIT: u32 = 20;
+/// Most logins a client may ask for
+const MAX_LIMIT: u32 = 100;
+
+/// List the recent logins of an account, newest first.
+///
+/// Visible to the account owner and to admins.
+pub async fn list_logins(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<ListLoginsQuery>,
+) -> Result<ApiSuccess<ListLoginsResponseData>, ApiError> {
+    let user_id = UserId::from_string(&id).map_err(UserError::from)?;
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    state
+        .login_history_service
+        .login_history(&user_id, limit)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref history| ApiSuccess::new(StatusCode::OK, history.into()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ListLoginsQuery {
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListLoginsResponseData {
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub items: Vec<LoginData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LoginData {
+    pub method: String,
+    pub logged_in_at: DateTime<Utc>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl From<&LoginHistory> for ListLoginsResponseData {
+    fn from(history: &LoginHistory) -> Self {
+        Self {
+            last_login_at: history.last_login_at,
+            items: history.logins.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<&LoginRecord> for LoginData {
+    fn from(login: &LoginRecord) -> Self {
+        Self {
+            method: login.method.as_str().to_string(),
+            logged_in_at: login.logged_in_at,
+            ip_address: login.ip_address.map(|ip| ip.to_string()),
+            user_agent: login.user_agent.clone(),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Serialize;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::passkey::models::PasskeyCredential;
+use crate::domain::passkey::ports::PasskeyServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// List the caller's passkeys, oldest first.
+pub async fn list_passkeys(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<ApiSuccess<Vec<PasskeyData>>, ApiError> {
+    let passkey_service = state
+        .passkey_service
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Passkeys are not enabled".to_string()))?;
+
+    passkey_service
+        .list_passkeys(&auth_user.user_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|passkeys| ApiSuccess::new(StatusCode::OK, passkeys.iter().map(Into::into).collect()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PasskeyData {
+    pub id: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<&PasskeyCredential> for PasskeyData {
+    fn from(passkey: &PasskeyCredential) -> Self {
+        Self {
+            id: passkey.id.to_string(),
+            name: passkey.name.as_str().to_string(),
+            created_at: passkey.created_at,
+            last_used_at: passkey.last_used_at,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Serialize;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::personal_token::models::PersonalToken;
+use crate::domain::personal_token::ports::PersonalTokenServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// List the caller's personal access tokens, oldest first.
+pub async fn list_personal_tokens(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<ApiSuccess<Vec<PersonalTokenData>>, ApiError> {
+    state
+        .personal_token_service
+        .list_tokens(&auth_user.user_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|tokens| ApiSuccess::new(StatusCode::OK, tokens.iter().map(Into::into).collect()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PersonalTokenData {
+    pub id: String,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<&PersonalToken> for PersonalTokenData {
+    fn from(token: &PersonalToken) -> Self {
+        Self {
+            id: token.id.to_string(),
+            name: token.name.as_str().to_string(),
+            scopes: token.scopes.as_slice().to_vec(),
+            created_at: token.created_at,
+            expires_at: token.expires_at,
+            last_used_at: token.last_used_at,
+        }
+    }
+}
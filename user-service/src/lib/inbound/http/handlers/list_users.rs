@@ -0,0 +1,66 @@
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::get_user::PublicUserResponseData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::user::models::UserCursor;
+use crate::domain::user::models::UserPage;
+use crate::domain::user::models::UserStatus;
+use crate::domain::user::ports::UserServicePort;
+use crate::inbound::http::router::AppState;
+
+/// Page size when the query does not name one
+const DEFAULT_LIMIT: u32 = 50;
+/// Largest page size a client may ask for
+const MAX_LIMIT: u32 = 100;
+
+/// List users, newest first, one page at a time.
+///
+/// The `next_cursor` of a page is passed back as `cursor` to get the next one.
+/// Items carry public profile fields only; admins see email addresses
+/// through `GET /api/admin/users`.
+pub async fn list_users(
+    State(state): State<AppState>,
+    Query(query): Query<ListUsersQuery>,
+) -> Result<ApiSuccess<ListUsersResponseData>, ApiError> {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let after = query
+        .cursor
+        .as_deref()
+        .map(UserCursor::parse)
+        .transpose()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    state
+        .user_service
+        .list_users(UserStatus::Active, limit, after)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref page| ApiSuccess::new(StatusCode::OK, page.into()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ListUsersQuery {
+    limit: Option<u32>,
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListUsersResponseData {
+    pub items: Vec<PublicUserResponseData>,
+    /// Cursor of the next page, None on the last page
+    pub next_cursor: Option<String>,
+}
+
+impl From<&UserPage> for ListUsersResponseData {
+    fn from(page: &UserPage) -> Self {
+        Self {
+            items: page.users.iter().map(Into::into).collect(),
+            next_cursor: page.next_cursor.as_ref().map(ToString::to_string),
+        }
+    }
+}
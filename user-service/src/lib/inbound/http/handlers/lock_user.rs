@@ -0,0 +1,59 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use crate::domain::audit::models::AuditAction;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::user::models::UserId;
+use crate::inbound::http::handlers::list_audit_log::record_audit;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::middleware::ClientIp;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+use crate::user::ports::UserServicePort;
+
+/// Lock an account, signing it out everywhere.
+///
+/// Locked users cannot sign in until an admin unlocks them. Admins cannot lock
+/// their own account.
+pub async fn lock_user(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    ClientIp(ip_address): ClientIp,
+    Path(id): Path<String>,
+) -> Result<ApiSuccess<()>, ApiError> {
+    let user_id = UserId::from_string(&id).map_err(UserError::from)?;
+    if user_id == auth_user.user_id {
+        return Err(ApiError::Conflict(
+            "Admins cannot lock their own account".to_string(),
+        ));
+    }
+
+    state.user_service.lock_user(&user_id).await?;
+
+    if let Err(e) = state
+        .authenticator
+        .revoke_all_sessions(&user_id.to_string())
+        .await
+    {
+        tracing::warn!(
+            "Failed to revoke sessions of locked user {}: {}",
+            user_id,
+            e
+        );
+    }
+
+    record_audit(
+        &state,
+        AuditEntry::new(AuditAction::UserLocked)
+            .by(auth_user.user_id)
+            .on(user_id)
+            .from_ip(ip_address),
+    )
+    .await;
+
+    tracing::info!(admin_id = %auth_user.user_id, user_id = %user_id, "Admin locked account");
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, ()))
+}
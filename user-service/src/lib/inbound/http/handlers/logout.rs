@@ -0,0 +1,48 @@
+use auth::Claims;
+use auth::RevocationError;
+use axum::extract::State;
+use axum::http::header::HeaderName;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::inbound::http::router::AppState;
+
+/// Response header telling browsers to drop cookies stored for this origin
+const CLEAR_SITE_DATA: HeaderName = HeaderName::from_static("clear-site-data");
+
+/// Log out the presented access token.
+///
+/// The token is revoked until it expires, together with its session if it
+/// belongs to one, and browsers are told to clear any session cookie.
+pub async fn logout(
+    State(state): State<AppState>,
+    claims: Claims,
+) -> Result<impl IntoResponse, ApiError> {
+    state
+        .authenticator
+        .revoke_access_token(&claims)
+        .await
+        .map_err(|e| match e {
+            RevocationError::JwtError(e) => {
+                ApiError::BadRequest(format!("Token cannot be revoked: {}", e))
+            }
+            e => ApiError::InternalServerError(format!("Failed to revoke token: {}", e)),
+        })?;
+
+    if let Some(session_id) = claims.session_id() {
+        state
+            .authenticator
+            .revoke_session(&session_id)
+            .await
+            .map_err(|e| {
+                ApiError::InternalServerError(format!("Failed to revoke session: {}", e))
+            })?;
+    }
+
+    Ok((
+        [(CLEAR_SITE_DATA, "\"cookies\"")],
+        ApiSuccess::new(StatusCode::NO_CONTENT, ()),
+    ))
+}
@@ -0,0 +1,28 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use crate::domain::user::models::UserId;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+use crate::user::ports::UserServicePort;
+
+/// Reactivate a deactivated account.
+///
+/// Deactivated users cannot sign in, so only admins reactivate accounts; the
+/// route sits behind the admin role check.
+pub async fn reactivate_user(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<ApiSuccess<()>, ApiError> {
+    let user_id = UserId::from_string(&id).map_err(UserError::from)?;
+
+    state
+        .user_service
+        .reactivate_user(&user_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|_| ApiSuccess::new(StatusCode::NO_CONTENT, ()))
+}
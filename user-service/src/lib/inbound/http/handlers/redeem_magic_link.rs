@@ -0,0 +1,57 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+
+use super::authenticate::record_login;
+use super::authenticate::TokenPairResponseData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::account_link::models::AuthMethodKind;
+use crate::domain::login_history::models::LoginContext;
+use crate::domain::magic_link::ports::MagicLinkServicePort;
+use crate::inbound::http::middleware::ClientIp;
+use crate::inbound::http::middleware::UserAgent;
+use crate::inbound::http::router::AppState;
+
+/// Exchange the token of a login link for an access and refresh token pair.
+///
+/// A POST rather than the link target itself, so that mail scanners
+/// prefetching the link cannot use it up.
+pub async fn redeem_magic_link(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    UserAgent(user_agent): UserAgent,
+    Json(body): Json<RedeemMagicLinkRequestBody>,
+) -> Result<ApiSuccess<TokenPairResponseData>, ApiError> {
+    let magic_link_service = state
+        .magic_link_service
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Magic link login is not enabled".to_string()))?;
+
+    let login = magic_link_service
+        .redeem_link(&body.token, client_ip)
+        .await?;
+
+    record_login(
+        &state,
+        &login.user.id,
+        AuthMethodKind::MagicLink,
+        LoginContext {
+            ip_address: client_ip,
+            user_agent,
+        },
+    )
+    .await;
+
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        TokenPairResponseData::new(&login.user, login.tokens),
+    ))
+}
+
+/// The body of a login link redemption (raw JSON)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RedeemMagicLinkRequestBody {
+    token: String,
+}
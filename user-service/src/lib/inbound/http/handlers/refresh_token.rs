@@ -0,0 +1,64 @@
+use auth::RefreshTokenError;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+
+use super::authenticate::TokenPairResponseData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::user::models::UserId;
+use crate::domain::user::ports::UserServicePort;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+
+/// Exchange a refresh token for a new access and refresh token pair.
+///
+/// Each refresh token works once; replaying a used one logs out every token
+/// of the same login.
+pub async fn refresh_token(
+    State(state): State<AppState>,
+    Json(body): Json<RefreshTokenRequestBody>,
+) -> Result<ApiSuccess<TokenPairResponseData>, ApiError> {
+    let tokens = state
+        .authenticator
+        .rotate_refresh(&body.refresh_token)
+        .await
+        .map_err(|e| match e {
+            RefreshTokenError::StoreError(_) => {
+                ApiError::InternalServerError(format!("Failed to refresh token: {}", e))
+            }
+            e => ApiError::Unauthorized(e.to_string()),
+        })?;
+
+    let claims = state
+        .authenticator
+        .validate_access_token(&tokens.access_token)
+        .map_err(|e| ApiError::InternalServerError(format!("Token generation failed: {}", e)))?;
+    let user_id = claims
+        .sub
+        .as_deref()
+        .and_then(|sub| UserId::from_string(sub).ok())
+        .ok_or_else(|| ApiError::Unauthorized("Invalid refresh token".to_string()))?;
+
+    // Deleted users cannot refresh their way back in
+    let user = state
+        .user_service
+        .get_user(&user_id)
+        .await
+        .map_err(|e| match e {
+            UserError::NotFound(_) => ApiError::Unauthorized("Invalid refresh token".to_string()),
+            e => ApiError::from(e),
+        })?;
+
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        TokenPairResponseData::new(&user, tokens),
+    ))
+}
+
+/// The body of a token refresh request (raw JSON)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RefreshTokenRequestBody {
+    refresh_token: String,
+}
@@ -0,0 +1,28 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::account_link::models::AuthMethodId;
+use crate::domain::account_link::ports::AccountLinkServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// Remove a login method from the caller's account.
+///
+/// Refused with 409 Conflict for the last remaining method.
+pub async fn remove_auth_method(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<ApiSuccess<()>, ApiError> {
+    let method = id.parse::<AuthMethodId>()?;
+
+    state
+        .account_link_service
+        .remove_method(&auth_user.user_id, &method)
+        .await
+        .map_err(ApiError::from)
+        .map(|_| ApiSuccess::new(StatusCode::NO_CONTENT, ()))
+}
@@ -0,0 +1,103 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::domain::audit::models::AuditAction;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::event_replay::models::ReplayPage;
+use crate::domain::event_replay::models::ReplayRequest;
+use crate::domain::event_replay::ports::EventReplayServicePort;
+use crate::domain::user::models::UserCursor;
+use crate::domain::user::models::UserStatus;
+use crate::inbound::http::handlers::list_audit_log::record_audit;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::middleware::ClientIp;
+use crate::inbound::http::router::AppState;
+
+/// Page size when the body does not name one
+const DEFAULT_LIMIT: u32 = 500;
+/// Largest page size a client may ask for
+const MAX_LIMIT: u32 = 1000;
+
+/// Publish the `UserCreated` events of a page of users again.
+///
+/// Lets a new consumer bootstrap from the event topic. Callers replay the
+/// whole range by sending `next_cursor` back until it is null.
+pub async fn replay_user_events(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    ClientIp(ip_address): ClientIp,
+    Json(body): Json<ReplayUserEventsRequestBody>,
+) -> Result<ApiSuccess<ReplayUserEventsResponseData>, ApiError> {
+    let status = match body.status.as_deref() {
+        Some(status) => UserStatus::parse(status)
+            .ok_or_else(|| ApiError::BadRequest(format!("Unknown user status: {}", status)))?,
+        None => UserStatus::Active,
+    };
+    let after = body
+        .cursor
+        .as_deref()
+        .map(UserCursor::parse)
+        .transpose()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let request = ReplayRequest {
+        status,
+        since: body.since,
+        until: body.until,
+        limit: body.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+        after,
+    };
+
+    let page = state
+        .event_replay_service
+        .replay_user_created(request)
+        .await?;
+
+    record_audit(
+        &state,
+        AuditEntry::new(AuditAction::EventsReplayed)
+            .by(auth_user.user_id)
+            .from_ip(ip_address)
+            .with_detail(format!(
+                "{} {} users replayed",
+                page.replayed,
+                status.as_str()
+            )),
+    )
+    .await;
+
+    tracing::info!(admin_id = %auth_user.user_id, replayed = page.replayed, "Admin replayed user events");
+    Ok(ApiSuccess::new(StatusCode::OK, (&page).into()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReplayUserEventsRequestBody {
+    /// `active` (default), `deactivated`, `locked` or `deleted`
+    status: Option<String>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    limit: Option<u32>,
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReplayUserEventsResponseData {
+    pub replayed: usize,
+    /// Cursor of the next page, None once the range is replayed
+    pub next_cursor: Option<String>,
+}
+
+impl From<&ReplayPage> for ReplayUserEventsResponseData {
+    fn from(page: &ReplayPage) -> Self {
+        Self {
+            replayed: page.replayed,
+            next_cursor: page.next_cursor.as_ref().map(ToString::to_string),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Serialize;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::email_verification::ports::EmailVerificationServicePort;
+use crate::domain::user::models::UserId;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+
+/// Email the caller a link confirming their email address.
+pub async fn request_email_verification(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<ApiSuccess<RequestEmailVerificationResponseData>, ApiError> {
+    let user_id = UserId::from_string(&id).map_err(UserError::from)?;
+    if user_id != auth_user.user_id {
+        return Err(ApiError::Forbidden(
+            "Only the account owner can verify its email address".to_string(),
+        ));
+    }
+
+    state
+        .email_verification_service
+        .request_verification(&user_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|expires_at| {
+            ApiSuccess::new(
+                StatusCode::ACCEPTED,
+                RequestEmailVerificationResponseData { expires_at },
+            )
+        })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RequestEmailVerificationResponseData {
+    /// Expiration of the emailed link
+    pub expires_at: DateTime<Utc>,
+}
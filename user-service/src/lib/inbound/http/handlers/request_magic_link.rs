@@ -0,0 +1,102 @@
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::Json;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::magic_link::models::RequestMagicLinkCommand;
+use crate::domain::magic_link::ports::MagicLinkServicePort;
+use crate::domain::signup::ports::SignupServicePort;
+use crate::inbound::http::middleware::too_many_requests;
+use crate::inbound::http::middleware::ClientIp;
+use crate::inbound::http::router::AppState;
+use crate::user::models::EmailAddress;
+use crate::user::models::Username;
+
+/// Email a single-use login link.
+///
+/// Responds the same whether or not the address is registered; with a
+/// `username`, an unknown address is sent a link creating the account.
+/// Requests are limited per client IP and per email address, over which
+/// they get `429 Too Many Requests` with `Retry-After`. Requests with a
+/// `username` are also held to the checks of `POST /api/users`: the signup
+/// rate limit, blocked email domains and the CAPTCHA.
+pub async fn request_magic_link(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<RequestMagicLinkRequestBody>,
+) -> Result<ApiSuccess<RequestMagicLinkResponseData>, Response> {
+    let magic_link_service = state.magic_link_service.as_ref().ok_or_else(|| {
+        ApiError::NotFound("Magic link login is not enabled".to_string()).into_response()
+    })?;
+
+    let command = RequestMagicLinkCommand {
+        email: EmailAddress::new(body.email)
+            .map_err(|e| ApiError::UnprocessableEntity(e.to_string()).into_response())?,
+        username: body
+            .username
+            .map(Username::new)
+            .transpose()
+            .map_err(|e| ApiError::UnprocessableEntity(e.to_string()).into_response())?,
+        client_ip,
+    };
+
+    // Clients without a known address share one budget
+    let client = client_ip.unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    if let Err(retry_after) = state
+        .magic_link_rate_limiter
+        .check(client, command.email.as_str())
+    {
+        tracing::info!(client = %client, "Login link request rate limited");
+        return Err(too_many_requests("login link requests", retry_after));
+    }
+
+    // Checked whether or not the address is registered, so the answer does not tell
+    if command.username.is_some() {
+        if let Err(retry_after) = state.signup_rate_limiter.check(client) {
+            tracing::info!(client = %client, "Signup rate limited");
+            return Err(too_many_requests("signups", retry_after));
+        }
+        state
+            .signup_service
+            .screen_signup(&command.email, body.captcha_response, client_ip)
+            .await
+            .map_err(|e| ApiError::from(e).into_response())?;
+    }
+
+    magic_link_service
+        .request_link(command)
+        .await
+        .map_err(|e| ApiError::from(e).into_response())
+        .map(|expires_at| {
+            ApiSuccess::new(
+                StatusCode::ACCEPTED,
+                RequestMagicLinkResponseData { expires_at },
+            )
+        })
+}
+
+/// The body of a login link request (raw JSON)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RequestMagicLinkRequestBody {
+    email: String,
+    #[serde(default)]
+    username: Option<String>,
+    /// Response token of the CAPTCHA widget, required with a `username` when CAPTCHA is enabled
+    #[serde(default)]
+    captcha_response: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RequestMagicLinkResponseData {
+    pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,31 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::request_magic_link::RequestMagicLinkResponseData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::magic_link::ports::MagicLinkServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// Email the caller a link confirming their address for magic link login.
+pub async fn request_magic_link_verification(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<ApiSuccess<RequestMagicLinkResponseData>, ApiError> {
+    let magic_link_service = state
+        .magic_link_service
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Magic link login is not enabled".to_string()))?;
+
+    magic_link_service
+        .request_link_verification(&auth_user.user_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|expires_at| {
+            ApiSuccess::new(
+                StatusCode::ACCEPTED,
+                RequestMagicLinkResponseData { expires_at },
+            )
+        })
+}
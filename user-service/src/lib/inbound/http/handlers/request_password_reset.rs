@@ -0,0 +1,34 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::password_reset::ports::PasswordResetServicePort;
+use crate::inbound::http::router::AppState;
+use crate::user::models::EmailAddress;
+
+/// Email a single-use password reset link.
+///
+/// Responds the same whether or not the address is registered.
+pub async fn request_password_reset(
+    State(state): State<AppState>,
+    Json(body): Json<RequestPasswordResetRequestBody>,
+) -> Result<ApiSuccess<()>, ApiError> {
+    let email =
+        EmailAddress::new(body.email).map_err(|e| ApiError::UnprocessableEntity(e.to_string()))?;
+
+    state
+        .password_reset_service
+        .request_reset(&email)
+        .await
+        .map_err(ApiError::from)
+        .map(|_| ApiSuccess::new(StatusCode::ACCEPTED, ()))
+}
+
+/// The body of a password reset request (raw JSON)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RequestPasswordResetRequestBody {
+    email: String,
+}
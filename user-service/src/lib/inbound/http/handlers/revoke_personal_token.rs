@@ -0,0 +1,27 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::personal_token::errors::PersonalTokenError;
+use crate::domain::personal_token::models::PersonalTokenId;
+use crate::domain::personal_token::ports::PersonalTokenServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// Revoke one of the caller's personal access tokens.
+pub async fn revoke_personal_token(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<ApiSuccess<()>, ApiError> {
+    let token_id = PersonalTokenId::from_string(&id).map_err(PersonalTokenError::from)?;
+
+    state
+        .personal_token_service
+        .revoke_token(&auth_user.user_id, &token_id)
+        .await
+        .map_err(ApiError::from)
+        .map(|_| ApiSuccess::new(StatusCode::NO_CONTENT, ()))
+}
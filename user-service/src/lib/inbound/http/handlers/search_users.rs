@@ -0,0 +1,43 @@
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Deserialize;
+
+use super::get_user::PublicUserResponseData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::user::models::UserSearchQuery;
+use crate::domain::user::ports::UserServicePort;
+use crate::inbound::http::router::AppState;
+
+/// Number of results when the query does not name one
+const DEFAULT_LIMIT: u32 = 10;
+/// Largest number of results a client may ask for
+const MAX_LIMIT: u32 = 50;
+
+/// Search users by username, best match first.
+///
+/// Meant for @mention autocomplete and pickers: prefixes match as typed and
+/// misspelled usernames still find similar ones. Results carry public
+/// profile fields only, so searching never reveals email addresses.
+pub async fn search_users(
+    State(state): State<AppState>,
+    Query(query): Query<SearchUsersQuery>,
+) -> Result<ApiSuccess<Vec<PublicUserResponseData>>, ApiError> {
+    let search_query =
+        UserSearchQuery::new(&query.q).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    state
+        .user_service
+        .search_users(&search_query, limit)
+        .await
+        .map_err(ApiError::from)
+        .map(|users| ApiSuccess::new(StatusCode::OK, users.iter().map(Into::into).collect()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SearchUsersQuery {
+    q: String,
+    limit: Option<u32>,
+}
@@ -0,0 +1,41 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+
+use super::start_passkey_registration::PasskeyChallengeResponseData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::passkey::ports::PasskeyServicePort;
+use crate::inbound::http::router::AppState;
+use crate::user::models::Username;
+
+/// Start logging in with a passkey.
+///
+/// The returned options are passed to `navigator.credentials.get()` and its
+/// result posted to `/api/auth/passkey/finish`.
+pub async fn start_passkey_login(
+    State(state): State<AppState>,
+    Json(body): Json<StartPasskeyLoginRequestBody>,
+) -> Result<ApiSuccess<PasskeyChallengeResponseData>, ApiError> {
+    let passkey_service = state
+        .passkey_service
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Passkeys are not enabled".to_string()))?;
+
+    let username = Username::new(body.username)
+        .map_err(|_| ApiError::Unauthorized("Invalid credentials".to_string()))?;
+
+    let challenge = passkey_service.start_login(&username).await?;
+
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        PasskeyChallengeResponseData::new(&challenge.ceremony_id, &challenge.options)?,
+    ))
+}
+
+/// The body of a passkey login start (raw JSON)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct StartPasskeyLoginRequestBody {
+    username: String,
+}
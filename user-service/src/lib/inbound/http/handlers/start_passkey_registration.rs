@@ -0,0 +1,53 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Serialize;
+
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::passkey::models::CeremonyId;
+use crate::domain::passkey::ports::PasskeyServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// Start registering a passkey for the caller.
+///
+/// The returned options are passed to `navigator.credentials.create()` and
+/// its result posted to `/api/passkeys/registration/finish`.
+pub async fn start_passkey_registration(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<ApiSuccess<PasskeyChallengeResponseData>, ApiError> {
+    let passkey_service = state
+        .passkey_service
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Passkeys are not enabled".to_string()))?;
+
+    let challenge = passkey_service
+        .start_registration(&auth_user.user_id)
+        .await?;
+
+    Ok(ApiSuccess::new(
+        StatusCode::OK,
+        PasskeyChallengeResponseData::new(&challenge.ceremony_id, &challenge.options)?,
+    ))
+}
+
+/// Ceremony ID with the WebAuthn options for the browser
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PasskeyChallengeResponseData {
+    pub ceremony_id: String,
+    pub options: serde_json::Value,
+}
+
+impl PasskeyChallengeResponseData {
+    pub fn new<T: Serialize>(ceremony_id: &CeremonyId, options: &T) -> Result<Self, ApiError> {
+        let options = serde_json::to_value(options).map_err(|e| {
+            ApiError::InternalServerError(format!("Failed to serialize WebAuthn options: {}", e))
+        })?;
+
+        Ok(Self {
+            ceremony_id: ceremony_id.to_string(),
+            options,
+        })
+    }
+}
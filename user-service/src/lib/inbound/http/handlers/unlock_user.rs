@@ -0,0 +1,39 @@
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use crate::domain::audit::models::AuditAction;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::user::models::UserId;
+use crate::inbound::http::handlers::list_audit_log::record_audit;
+use crate::inbound::http::handlers::ApiError;
+use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::middleware::ClientIp;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+use crate::user::ports::UserServicePort;
+
+/// Unlock an account locked by an admin.
+pub async fn unlock_user(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    ClientIp(ip_address): ClientIp,
+    Path(id): Path<String>,
+) -> Result<ApiSuccess<()>, ApiError> {
+    let user_id = UserId::from_string(&id).map_err(UserError::from)?;
+
+    state.user_service.unlock_user(&user_id).await?;
+
+    record_audit(
+        &state,
+        AuditEntry::new(AuditAction::UserUnlocked)
+            .by(auth_user.user_id)
+            .on(user_id)
+            .from_ip(ip_address),
+    )
+    .await;
+
+    tracing::info!(admin_id = %auth_user.user_id, user_id = %user_id, "Admin unlocked account");
+    Ok(ApiSuccess::new(StatusCode::NO_CONTENT, ()))
+}
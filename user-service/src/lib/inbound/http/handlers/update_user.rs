@@ -1,3 +1,4 @@
+use auth::SecretString;
 use axum::extract::Path;
 use axum::extract::State;
 use axum::http::StatusCode;
@@ -5,13 +6,21 @@ use axum::Json;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::domain::audit::models::AuditAction;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::user::models::AvatarUrl;
+use crate::domain::user::models::Bio;
+use crate::domain::user::models::DisplayName;
 use crate::domain::user::models::EmailAddress;
 use crate::domain::user::models::UpdateUserCommand;
 use crate::domain::user::models::User;
 use crate::domain::user::models::UserId;
 use crate::domain::user::models::Username;
+use crate::inbound::http::handlers::list_audit_log::record_audit;
 use crate::inbound::http::handlers::ApiError;
 use crate::inbound::http::handlers::ApiSuccess;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::middleware::ClientIp;
 use crate::inbound::http::router::AppState;
 use crate::user::errors::UserError;
 use crate::user::ports::UserServicePort;
@@ -21,7 +30,14 @@ use crate::user::ports::UserServicePort;
 pub struct UpdateUserRequest {
     pub username: Option<String>,
     pub email: Option<String>,
-    pub password: Option<String>,
+    /// Rejected; passwords change through `POST /api/users/{id}/password`
+    pub password: Option<SecretString>,
+    /// Empty string removes the display name
+    pub display_name: Option<String>,
+    /// Empty string removes the avatar
+    pub avatar_url: Option<String>,
+    /// Empty string removes the biography
+    pub bio: Option<String>,
 }
 
 impl UpdateUserRequest {
@@ -31,20 +47,46 @@ impl UpdateUserRequest {
 
         let email = self.email.map(EmailAddress::new).transpose()?;
 
+        let display_name = self
+            .display_name
+            .map(|name| optional(&name, DisplayName::new))
+            .transpose()?;
+        let avatar_url = self
+            .avatar_url
+            .map(|url| optional(&url, AvatarUrl::new))
+            .transpose()?;
+        let bio = self.bio.map(|bio| optional(&bio, Bio::new)).transpose()?;
+
         Ok(UpdateUserCommand {
             username,
             email,
-            password: self.password,
+            password: None,
+            display_name,
+            avatar_url,
+            bio,
         })
     }
 }
 
+/// Parse an optional profile field, where a blank value removes it.
+fn optional<T, E>(value: &str, parse: impl FnOnce(&str) -> Result<T, E>) -> Result<Option<T>, E> {
+    if value.trim().is_empty() {
+        Ok(None)
+    } else {
+        parse(value).map(Some)
+    }
+}
+
 /// Response body for user operations
 #[derive(Debug, Serialize, PartialEq)]
 pub struct UserResponse {
     pub id: String,
     pub username: String,
     pub email: String,
+    pub email_verified: bool,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub bio: Option<String>,
     pub created_at: String,
 }
 
@@ -54,24 +96,53 @@ impl From<User> for UserResponse {
             id: user.id.to_string(),
             username: user.username.as_str().to_string(),
             email: user.email.as_str().to_string(),
+            email_verified: user.email_verified,
+            display_name: user.display_name.map(|name| name.as_str().to_string()),
+            avatar_url: user.avatar_url.map(|url| url.as_str().to_string()),
+            bio: user.bio.map(|bio| bio.as_str().to_string()),
             created_at: user.created_at.to_rfc3339(),
         }
     }
 }
 
+/// Update the profile of a user.
+///
+/// A change of email address is recorded in the audit log.
 pub async fn update_user(
     State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    ClientIp(ip_address): ClientIp,
     Path(id): Path<String>,
     Json(req): Json<UpdateUserRequest>,
 ) -> Result<ApiSuccess<UserResponse>, ApiError> {
     // Parse user ID and request at HTTP boundary - errors automatically converted
     let user_id = UserId::from_string(&id).map_err(UserError::from)?;
+    if req.password.is_some() {
+        return Err(ApiError::UnprocessableEntity(format!(
+            "Password cannot be updated here, use POST /api/users/{}/password",
+            user_id
+        )));
+    }
     let command = req.try_into_command()?;
 
-    state
-        .user_service
-        .update_user(&user_id, command)
-        .await
-        .map_err(ApiError::from)
-        .map(|user| ApiSuccess::new(StatusCode::OK, user.into()))
+    // Read before the update to tell a new address from a repeated one
+    let previous_email = match &command.email {
+        Some(_) => Some(state.user_service.get_user(&user_id).await?.email),
+        None => None,
+    };
+
+    let user = state.user_service.update_user(&user_id, command).await?;
+
+    if previous_email.is_some_and(|email| email != user.email) {
+        record_audit(
+            &state,
+            AuditEntry::new(AuditAction::EmailChanged)
+                .by(auth_user.user_id)
+                .on(user_id)
+                .from_ip(ip_address),
+        )
+        .await;
+    }
+
+    Ok(ApiSuccess::new(StatusCode::OK, user.into()))
 }
@@ -0,0 +1,80 @@
+use axum::extract::FromRequest;
+use axum::extract::Multipart;
+use axum::extract::Path;
+use axum::extract::Request;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use super::update_user::UserResponse;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::avatar::ports::AvatarServicePort;
+use crate::domain::user::models::UserId;
+use crate::inbound::http::router::AppState;
+use crate::user::errors::UserError;
+
+/// Form field holding the image of a `multipart/form-data` avatar upload
+const AVATAR_FILE_FIELD: &str = "file";
+
+/// Replace a user's avatar with an uploaded image.
+///
+/// The image is the `file` part of a `multipart/form-data` form, a PNG, JPEG,
+/// GIF or WebP image named by the part's content type. Owners replace their
+/// own avatar, admins any avatar.
+pub async fn upload_avatar(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    request: Request,
+) -> Result<ApiSuccess<UserResponse>, ApiError> {
+    let user_id = UserId::from_string(&id).map_err(UserError::from)?;
+    let avatar_service = state
+        .avatar_service
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Avatar uploads are not enabled".to_string()))?;
+
+    let mut multipart = Multipart::from_request(request, &())
+        .await
+        .map_err(|e| body_error(e.status(), e.body_text()))?;
+    while let Some(mut field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| body_error(e.status(), e.body_text()))?
+    {
+        if field.name() != Some(AVATAR_FILE_FIELD) {
+            continue;
+        }
+
+        let content_type = field
+            .content_type()
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        let mut bytes = Vec::new();
+        while let Some(chunk) = field
+            .chunk()
+            .await
+            .map_err(|e| body_error(e.status(), e.body_text()))?
+        {
+            bytes.extend_from_slice(&chunk);
+        }
+
+        return avatar_service
+            .upload_avatar(&user_id, &content_type, bytes)
+            .await
+            .map_err(ApiError::from)
+            .map(|user| ApiSuccess::new(StatusCode::OK, user.into()));
+    }
+
+    Err(ApiError::BadRequest(format!(
+        "Multipart avatar upload has no '{}' part",
+        AVATAR_FILE_FIELD
+    )))
+}
+
+/// Map a rejected upload body, keeping `413 Payload Too Large` apart.
+fn body_error(status: StatusCode, message: String) -> ApiError {
+    if status == StatusCode::PAYLOAD_TOO_LARGE {
+        ApiError::PayloadTooLarge("Avatar exceeds the upload size limit".to_string())
+    } else {
+        ApiError::BadRequest(message)
+    }
+}
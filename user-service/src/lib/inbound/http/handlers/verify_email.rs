@@ -0,0 +1,32 @@
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Deserialize;
+
+use super::get_user::GetUserResponseData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::email_verification::ports::EmailVerificationServicePort;
+use crate::inbound::http::router::AppState;
+
+/// Confirm an email address with the token of a verification link.
+///
+/// The link target itself: a mail scanner prefetching it can only see the
+/// link in the owner's mailbox, which is what the link proves.
+pub async fn verify_email(
+    State(state): State<AppState>,
+    Query(query): Query<VerifyEmailQuery>,
+) -> Result<ApiSuccess<GetUserResponseData>, ApiError> {
+    state
+        .email_verification_service
+        .verify_email(&query.token)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref user| ApiSuccess::new(StatusCode::OK, user.into()))
+}
+
+/// The query of a verification link
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct VerifyEmailQuery {
+    token: String,
+}
@@ -0,0 +1,41 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Deserialize;
+
+use super::list_auth_methods::AuthMethodData;
+use super::ApiError;
+use super::ApiSuccess;
+use crate::domain::account_link::models::AuthMethod;
+use crate::domain::magic_link::ports::MagicLinkServicePort;
+use crate::inbound::http::middleware::AuthenticatedUser;
+use crate::inbound::http::router::AppState;
+
+/// Link magic link login with the token of a confirmation link.
+pub async fn verify_magic_link(
+    State(state): State<AppState>,
+    auth_user: AuthenticatedUser,
+    Json(body): Json<VerifyMagicLinkRequestBody>,
+) -> Result<ApiSuccess<AuthMethodData>, ApiError> {
+    let magic_link_service = state
+        .magic_link_service
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Magic link login is not enabled".to_string()))?;
+
+    magic_link_service
+        .verify_link(&auth_user.user_id, &body.token)
+        .await
+        .map_err(ApiError::from)
+        .map(|ref link| {
+            ApiSuccess::new(
+                StatusCode::CREATED,
+                AuthMethodData::from(&AuthMethod::from(link)),
+            )
+        })
+}
+
+/// The body of a magic link confirmation (raw JSON)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct VerifyMagicLinkRequestBody {
+    token: String,
+}
@@ -1,110 +1,425 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use auth::axum::AuthRejection;
+use auth::Claims;
+use axum::async_trait;
+use axum::extract::ConnectInfo;
+use axum::extract::FromRequestParts;
+use axum::extract::Path;
 use axum::extract::Request;
 use axum::extract::State;
+use axum::http::header::CONTENT_LENGTH;
+use axum::http::header::RETRY_AFTER;
+use axum::http::header::USER_AGENT;
+use axum::http::request::Parts;
+use axum::http::HeaderValue;
 use axum::http::StatusCode;
-use axum::http::{self};
 use axum::middleware::Next;
 use axum::response::IntoResponse;
 use axum::response::Response;
-use axum::Json;
-use serde_json::json;
+use envelope::forwarded_client_ip;
+use envelope::ResponseError;
 
+use super::handlers::ApiError;
+use crate::domain::user::models::Role;
 use crate::domain::user::models::UserId;
 use crate::inbound::http::router::AppState;
 
-/// Extension type to store authenticated user ID in request extensions
+/// Authenticated caller of a route behind [`auth::axum::AuthLayer`]
 #[derive(Debug, Clone)]
 pub struct AuthenticatedUser {
     pub user_id: UserId,
     pub username: String,
+    pub roles: Vec<String>,
+}
+
+impl AuthenticatedUser {
+    /// Role required for `/api/admin` routes
+    pub const ADMIN_ROLE: &'static str = Role::Admin.as_str();
+
+    /// Whether the token of the caller carries a role.
+    pub fn has_role(&self, role: Role) -> bool {
+        self.roles.iter().any(|name| name == role.as_str())
+    }
+
+    /// Whether the caller holds the admin role.
+    pub fn is_admin(&self) -> bool {
+        self.has_role(Role::Admin)
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AuthenticatedUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        let user_id_str = claims.sub.as_ref().ok_or_else(|| {
+            tracing::error!("Missing 'sub' claim in token");
+            AuthRejection::InvalidClaims
+        })?;
+
+        let user_id = UserId::from_string(user_id_str).map_err(|e| {
+            tracing::error!("Failed to parse user ID from token: {}", e);
+            AuthRejection::InvalidClaims
+        })?;
+
+        let username = claims.username().unwrap_or_else(|| "unknown".to_string());
+
+        Ok(AuthenticatedUser {
+            user_id,
+            username,
+            roles: claims.roles,
+        })
+    }
+}
+
+/// Network address of the client, if known
+///
+/// Taken from the right-most `X-Forwarded-For` entry, written by the proxy,
+/// when `server.trust_forwarded_for` is set, otherwise from the peer address
+/// of the connection.
+#[derive(Debug, Clone, Copy)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+#[async_trait]
+impl FromRequestParts<AppState> for ClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let forwarded = state
+            .trust_forwarded_for
+            .then(|| forwarded_client_ip(&parts.headers))
+            .flatten();
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(address)| address.ip());
+
+        Ok(ClientIp(forwarded.or(peer)))
+    }
 }
 
-/// Middleware that validates JWT tokens and adds user info to request extensions
-pub async fn authenticate(
+/// Reject callers whose token does not carry `role` with `403 Forbidden`.
+///
+/// Installed with [`axum::middleware::from_fn_with_state`] inside an
+/// [`auth::axum::AuthLayer`], which rejects unauthenticated requests first.
+pub async fn require_role(
+    State(role): State<Role>,
+    auth_user: AuthenticatedUser,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !auth_user.has_role(role) {
+        tracing::warn!(
+            user_id = %auth_user.user_id,
+            role = %role,
+            uri = %request.uri(),
+            "Request without required role refused"
+        );
+        return ApiError::Forbidden(format!("This resource requires the {} role", role))
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Reject callers acting on another account than their own with `403 Forbidden`.
+///
+/// Guards the `/users/:user_id` routes that change or reveal an account:
+/// the subject of the token must be the `user_id` of the path, unless the
+/// caller holds the admin role. Paths without a valid user ID are left to
+/// the handler to reject. Installed with [`axum::middleware::from_fn`]
+/// inside an [`auth::axum::AuthLayer`].
+pub async fn require_self_or_admin(
+    auth_user: AuthenticatedUser,
+    Path(params): Path<HashMap<String, String>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let target = params
+        .get("user_id")
+        .and_then(|id| UserId::from_string(id).ok());
+    if target.is_some_and(|user_id| user_id != auth_user.user_id) && !auth_user.is_admin() {
+        tracing::warn!(
+            user_id = %auth_user.user_id,
+            uri = %request.uri(),
+            "Request on another account refused"
+        );
+        return ApiError::Forbidden("Only the account owner or an admin can do this".to_string())
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+/// `User-Agent` header of the request, if present and valid text
+#[derive(Debug, Clone)]
+pub struct UserAgent(pub Option<String>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for UserAgent
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(UserAgent(
+            parts
+                .headers
+                .get(USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+        ))
+    }
+}
+
+/// Middleware limiting signups per client IP.
+///
+/// Requests over the limit get `429 Too Many Requests` with `Retry-After`.
+/// Clients without a known address share one budget.
+pub async fn limit_signups(
     State(state): State<AppState>,
-    mut req: Request,
+    ClientIp(client_ip): ClientIp,
+    request: Request,
     next: Next,
-) -> Result<Response, Response> {
-    // Extract token from Authorization header
-    let token = extract_token_from_header(&req)?;
-
-    // Validate token and extract claims (from auth library)
-    let claims: auth::Claims = state.authenticator.validate_token(token).map_err(|e| {
-        tracing::warn!("JWT validation failed: {}", e);
-        (
-            StatusCode::UNAUTHORIZED,
-            Json(json!({
-                "error": "Invalid or expired token"
-            })),
-        )
-            .into_response()
-    })?;
-
-    // Extract user ID from claims
-    let user_id_str = claims.sub.as_ref().ok_or_else(|| {
-        tracing::error!("Missing 'sub' claim in token");
-        (
-            StatusCode::UNAUTHORIZED,
-            Json(json!({
-                "error": "Invalid token format"
-            })),
-        )
-            .into_response()
-    })?;
-
-    let user_id = UserId::from_string(user_id_str).map_err(|e| {
-        tracing::error!("Failed to parse user ID from token: {}", e);
-        (
-            StatusCode::UNAUTHORIZED,
-            Json(json!({
-                "error": "Invalid token format"
-            })),
-        )
-            .into_response()
-    })?;
-
-    // Extract username from claims
-    let username = claims.username().unwrap_or_else(|| "unknown".to_string());
-
-    // Add authenticated user info to request extensions
-    req.extensions_mut()
-        .insert(AuthenticatedUser { user_id, username });
-
-    Ok(next.run(req).await)
+) -> Response {
+    let client = client_ip.unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+
+    if let Err(retry_after) = state.signup_rate_limiter.check(client) {
+        tracing::info!(client = %client, "Signup rate limited");
+        return too_many_requests("signups", retry_after);
+    }
+
+    next.run(request).await
 }
 
-fn extract_token_from_header(req: &Request) -> Result<&str, Response> {
-    let auth_header = req
+/// `429 Too Many Requests` with `Retry-After`, in whole seconds and at least one.
+///
+/// # Arguments
+/// * `what` - Requests that were limited, as in "Too many signups"
+/// * `retry_after` - Time until the limit resets
+///
+/// # Returns
+/// Error response telling the client when to retry
+pub fn too_many_requests(what: &str, retry_after: Duration) -> Response {
+    let retry_after_secs = retry_after.as_secs().max(1);
+    let mut response = ApiError::TooManyRequests(format!(
+        "Too many {}, retry in {} seconds",
+        what, retry_after_secs
+    ))
+    .into_response();
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from(retry_after_secs));
+    response
+}
+
+/// Reject request bodies over `limit` bytes with `413 Payload Too Large`.
+///
+/// A declared `Content-Length` over the limit is rejected before the handler
+/// runs. Other bodies are cut off while streaming by the
+/// [`axum::extract::DefaultBodyLimit`] installed next to this middleware, whose
+/// plain text rejection is replaced by a structured [`ApiError`].
+pub async fn limit_body_size(State(limit): State<usize>, request: Request, next: Next) -> Response {
+    let declared_length = request
         .headers()
-        .get(http::header::AUTHORIZATION)
-        .ok_or_else(|| {
-            (
-                StatusCode::UNAUTHORIZED,
-                Json(json!({
-                    "error": "Missing Authorization header"
-                })),
-            )
-                .into_response()
-        })?;
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if declared_length.is_some_and(|length| length > limit) {
+        return payload_too_large(limit);
+    }
+
+    let response = next.run(request).await;
+    if response.status() == StatusCode::PAYLOAD_TOO_LARGE
+        && response.extensions().get::<ResponseError>().is_none()
+    {
+        return payload_too_large(limit);
+    }
+    response
+}
+
+fn payload_too_large(limit: usize) -> Response {
+    ApiError::PayloadTooLarge(format!("Request body exceeds the limit of {} bytes", limit))
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::DefaultBodyLimit;
+    use axum::routing::post;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const LIMIT: usize = 8;
+
+    fn app() -> Router {
+        Router::new()
+            .route("/", post(|body: String| async move { body }))
+            .route_layer(DefaultBodyLimit::max(LIMIT))
+            .route_layer(axum::middleware::from_fn_with_state(LIMIT, limit_body_size))
+    }
+
+    async fn message(response: Response) -> String {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        body["data"]["message"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_limit_body_size_rejects_declared_length() {
+        let request = Request::post("/")
+            .header(CONTENT_LENGTH, LIMIT + 1)
+            .body(Body::from("x".repeat(LIMIT + 1)))
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(
+            message(response).await,
+            "Request body exceeds the limit of 8 bytes"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_limit_body_size_rejects_undeclared_length() {
+        let request = Request::post("/").body(Body::from("123456789")).unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert!(message(response).await.contains("limit"));
+    }
+
+    fn admin_app() -> Router {
+        Router::new()
+            .route("/", post(|| async { "ok" }))
+            .route_layer(axum::middleware::from_fn_with_state(
+                Role::Admin,
+                require_role,
+            ))
+    }
+
+    fn request_with_roles(roles: &[&str]) -> Request {
+        let claims = Claims::for_user(UserId::new(), "alice".to_string(), 1).with_roles(roles);
+        let mut request = Request::post("/").body(Body::empty()).unwrap();
+        request.extensions_mut().insert(claims);
+        request
+    }
+
+    #[tokio::test]
+    async fn test_require_role_refuses_caller_without_role() {
+        let response = admin_app()
+            .oneshot(request_with_roles(&["moderator"]))
+            .await
+            .unwrap();
 
-    let auth_str = auth_header.to_str().map_err(|_| {
-        (
-            StatusCode::UNAUTHORIZED,
-            Json(json!({
-                "error": "Invalid Authorization header"
-            })),
-        )
-            .into_response()
-    })?;
-
-    if !auth_str.starts_with("Bearer ") {
-        return Err((
-            StatusCode::UNAUTHORIZED,
-            Json(json!({
-                "error": "Invalid Authorization header format. Expected: Bearer <token>"
-            })),
-        )
-            .into_response());
-    }
-
-    Ok(auth_str.trim_start_matches("Bearer "))
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(
+            message(response).await,
+            "This resource requires the admin role"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_require_role_passes_caller_with_role() {
+        let response = admin_app()
+            .oneshot(request_with_roles(&["admin"]))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    fn account_app() -> Router {
+        Router::new()
+            .route("/users/:user_id", post(|| async { "ok" }))
+            .route_layer(axum::middleware::from_fn(require_self_or_admin))
+    }
+
+    fn account_request(claims: Claims, user_id: &str) -> Request {
+        let mut request = Request::post(format!("/users/{}", user_id))
+            .body(Body::empty())
+            .unwrap();
+        request.extensions_mut().insert(claims);
+        request
+    }
+
+    #[tokio::test]
+    async fn test_require_self_or_admin_refuses_other_account() {
+        let claims = Claims::for_user(UserId::new(), "alice".to_string(), 1);
+        let other = UserId::new().to_string();
+
+        let response = account_app()
+            .oneshot(account_request(claims, &other))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(
+            message(response).await,
+            "Only the account owner or an admin can do this"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_require_self_or_admin_refuses_moderator_on_other_account() {
+        let claims =
+            Claims::for_user(UserId::new(), "alice".to_string(), 1).with_roles(["moderator"]);
+        let other = UserId::new().to_string();
+
+        let response = account_app()
+            .oneshot(account_request(claims, &other))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_require_self_or_admin_passes_owner_and_admin() {
+        let owner = UserId::new();
+        let claims = Claims::for_user(owner, "alice".to_string(), 1);
+        let response = account_app()
+            .oneshot(account_request(claims, &owner.to_string()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let claims = Claims::for_user(UserId::new(), "root".to_string(), 1).with_roles(["admin"]);
+        let response = account_app()
+            .oneshot(account_request(claims, &owner.to_string()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_limit_body_size_passes_small_body() {
+        let request = Request::post("/").body(Body::from("12345678")).unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
 }
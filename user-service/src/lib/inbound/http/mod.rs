@@ -1,5 +1,6 @@
 mod handlers;
 mod middleware;
+pub mod openapi;
 pub mod router;
 
 pub use middleware::AuthenticatedUser;
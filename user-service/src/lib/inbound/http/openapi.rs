@@ -0,0 +1,41 @@
+use serde_json::Value;
+use utoipa_swagger_ui::SwaggerUi;
+
+/// OpenAPI contract of the HTTP API, maintained next to the code in `openapi/`.
+const OPENAPI_CONTRACT: &str = include_str!("../../../../../openapi/user-service.yaml");
+
+/// Path of the contract converted to JSON.
+pub const OPENAPI_JSON_PATH: &str = "/api/openapi.json";
+
+/// Path of the Swagger UI browsing the contract.
+pub const SWAGGER_UI_PATH: &str = "/api/docs";
+
+/// Serve the OpenAPI contract as JSON, and Swagger UI on top of it.
+///
+/// # Panics
+/// Panics if the embedded contract is not valid YAML, which the tests rule out
+pub fn openapi_routes() -> SwaggerUi {
+    let document = openapi_document().expect("Embedded OpenAPI contract is not valid YAML");
+
+    SwaggerUi::new(SWAGGER_UI_PATH).external_url_unchecked(OPENAPI_JSON_PATH, document)
+}
+
+/// Parse the embedded OpenAPI contract.
+fn openapi_document() -> Result<Value, serde_yaml::Error> {
+    serde_yaml::from_str(OPENAPI_CONTRACT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contract_parses_as_openapi_3() {
+        let document = openapi_document().expect("Contract should parse");
+
+        assert!(document["openapi"]
+            .as_str()
+            .is_some_and(|version| version.starts_with("3.")));
+        assert!(document["paths"]["/api/users"].is_object());
+    }
+}
@@ -1,8 +1,10 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use auth::axum::AuthLayer;
 use auth::Authenticator;
 use axum::body::Body;
+use axum::extract::DefaultBodyLimit;
 use axum::http::Request;
 use axum::http::Response;
 use axum::middleware;
@@ -10,51 +12,340 @@ use axum::routing::delete;
 use axum::routing::get;
 use axum::routing::patch;
 use axum::routing::post;
+use axum::routing::put;
 use axum::Router;
+use buildinfo::BuildInfo;
+use envelope::versioned;
+use envelope::Deprecation;
+use metrics_exporter_prometheus::PrometheusHandle;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use tracing::Span;
 
 use super::handlers::authenticate::authenticate;
+use super::handlers::change_password::change_password;
+use super::handlers::check_liveness::check_liveness;
+use super::handlers::check_readiness::check_readiness;
+use super::handlers::confirm_password_reset::confirm_password_reset;
+use super::handlers::create_personal_token::create_personal_token;
 use super::handlers::create_user::create_user;
+use super::handlers::deactivate_user::deactivate_user;
+use super::handlers::delete_passkey::delete_passkey;
 use super::handlers::delete_user::delete_user;
+use super::handlers::export_user::export_user;
+use super::handlers::finish_passkey_login::finish_passkey_login;
+use super::handlers::finish_passkey_registration::finish_passkey_registration;
+use super::handlers::force_password_reset::force_password_reset;
+use super::handlers::get_job::get_job;
+use super::handlers::get_metrics::get_metrics;
 use super::handlers::get_user::get_user;
+use super::handlers::get_version::get_version;
+use super::handlers::import_users::import_users;
+use super::handlers::link_password::link_password;
+use super::handlers::list_admin_users::list_admin_users;
+use super::handlers::list_audit_log::list_audit_log;
+use super::handlers::list_auth_methods::list_auth_methods;
+use super::handlers::list_logins::list_logins;
+use super::handlers::list_passkeys::list_passkeys;
+use super::handlers::list_personal_tokens::list_personal_tokens;
+use super::handlers::list_users::list_users;
+use super::handlers::lock_user::lock_user;
+use super::handlers::logout::logout;
+use super::handlers::reactivate_user::reactivate_user;
+use super::handlers::redeem_magic_link::redeem_magic_link;
+use super::handlers::refresh_token::refresh_token;
+use super::handlers::remove_auth_method::remove_auth_method;
+use super::handlers::replay_user_events::replay_user_events;
+use super::handlers::request_email_verification::request_email_verification;
+use super::handlers::request_magic_link::request_magic_link;
+use super::handlers::request_magic_link_verification::request_magic_link_verification;
+use super::handlers::request_password_reset::request_password_reset;
+use super::handlers::revoke_personal_token::revoke_personal_token;
+use super::handlers::search_users::search_users;
+use super::handlers::start_passkey_login::start_passkey_login;
+use super::handlers::start_passkey_registration::start_passkey_registration;
+use super::handlers::unlock_user::unlock_user;
 use super::handlers::update_user::update_user;
-use super::middleware::authenticate as auth_middleware;
+use super::handlers::upload_avatar::upload_avatar;
+use super::handlers::verify_email::verify_email;
+use super::handlers::verify_magic_link::verify_magic_link;
+use super::middleware::limit_body_size;
+use super::middleware::limit_signups;
+use super::middleware::require_role;
+use super::middleware::require_self_or_admin;
+use super::openapi::openapi_routes;
+use crate::domain::account_link::service::AccountLinkService;
+use crate::domain::audit::service::AuditLogService;
+use crate::domain::avatar::service::AvatarService;
+use crate::domain::email_verification::service::EmailVerificationService;
+use crate::domain::event_replay::service::EventReplayService;
+use crate::domain::import::service::UserImportService;
+use crate::domain::job::service::JobService;
+use crate::domain::lockout::service::LockoutService;
+use crate::domain::login_history::service::LoginHistoryService;
+use crate::domain::magic_link::service::MagicLinkService;
+use crate::domain::passkey::service::PasskeyService;
+use crate::domain::password_reset::service::PasswordResetService;
+use crate::domain::personal_token::service::PersonalTokenService;
+use crate::domain::signup::service::SignupService;
+use crate::domain::user::models::Role;
 use crate::domain::user::service::UserService;
+use crate::inbound::health::HealthChecks;
+use crate::inbound::metrics::track_http_metrics;
+use crate::inbound::rate_limit::MagicLinkRateLimiter;
+use crate::inbound::rate_limit::RateLimiter;
+use crate::outbound::captcha::SiteverifyCaptchaVerifier;
 use crate::outbound::events::KafkaEventProducer;
+use crate::outbound::mail::WebhookEmailSender;
+use crate::outbound::mail::WebhookLoginLinkSender;
+use crate::outbound::repositories::account_link::PostgresAccountLinkRepository;
+use crate::outbound::repositories::audit::PostgresAuditLogger;
+use crate::outbound::repositories::idempotency::PostgresIdempotencyRepository;
+use crate::outbound::repositories::job::PostgresJobRepository;
+use crate::outbound::repositories::login_attempt::PostgresLoginAttemptRepository;
+use crate::outbound::repositories::login_history::PostgresLoginHistoryRepository;
+use crate::outbound::repositories::passkey::PostgresPasskeyRepository;
+use crate::outbound::repositories::personal_token::PostgresPersonalTokenRepository;
 use crate::outbound::repositories::user::PostgresUserRepository;
+use crate::outbound::storage::S3ObjectStorage;
+
+/// User service wired to Postgres, its events relayed to Kafka through the outbox
+pub type AppUserService = UserService<PostgresUserRepository>;
+/// Signup service checking CAPTCHAs with hCaptcha or Turnstile
+pub type AppSignupService =
+    SignupService<AppUserService, SiteverifyCaptchaVerifier, PostgresIdempotencyRepository>;
+/// Magic link service sending links through the mail relay
+pub type AppMagicLinkService =
+    MagicLinkService<AppUserService, WebhookLoginLinkSender, PostgresAccountLinkRepository>;
+/// Passkey service storing credentials in Postgres
+pub type AppPasskeyService = PasskeyService<AppUserService, PostgresPasskeyRepository>;
+/// Account link service over the Postgres link and passkey tables
+pub type AppAccountLinkService =
+    AccountLinkService<AppUserService, PostgresAccountLinkRepository, PostgresPasskeyRepository>;
+/// Email verification service sending links through the mail relay
+pub type AppEmailVerificationService = EmailVerificationService<AppUserService, WebhookEmailSender>;
+/// Password reset service sending links through the mail relay
+pub type AppPasswordResetService =
+    PasswordResetService<AppUserService, WebhookEmailSender, KafkaEventProducer>;
+/// Lockout service counting failed logins in Postgres
+pub type AppLockoutService = LockoutService<PostgresLoginAttemptRepository, KafkaEventProducer>;
+/// Audit log service appending entries to Postgres
+pub type AppAuditLogService = AuditLogService<PostgresAuditLogger>;
+/// Login history service storing logins in Postgres
+pub type AppLoginHistoryService = LoginHistoryService<PostgresLoginHistoryRepository>;
+/// Personal access token service storing token digests in Postgres
+pub type AppPersonalTokenService =
+    PersonalTokenService<AppUserService, PostgresPersonalTokenRepository>;
+/// Event replay service publishing straight to Kafka
+pub type AppEventReplayService = EventReplayService<AppUserService, KafkaEventProducer>;
+/// Avatar service storing images in an S3-compatible bucket
+pub type AppAvatarService = AvatarService<AppUserService, S3ObjectStorage>;
 
 #[derive(Clone)]
 pub struct AppState {
-    pub user_service: Arc<UserService<PostgresUserRepository, KafkaEventProducer>>,
+    pub user_service: Arc<AppUserService>,
+    pub signup_service: Arc<AppSignupService>,
+    /// Per-client limit of `POST /users`
+    pub signup_rate_limiter: Arc<RateLimiter>,
+    pub magic_link_rate_limiter: Arc<MagicLinkRateLimiter>,
+    pub job_service: Arc<JobService<PostgresJobRepository>>,
+    pub import_service: Arc<UserImportService<AppUserService, JobService<PostgresJobRepository>>>,
+    pub magic_link_service: Option<Arc<AppMagicLinkService>>,
+    pub passkey_service: Option<Arc<AppPasskeyService>>,
+    pub account_link_service: Arc<AppAccountLinkService>,
+    pub email_verification_service: Arc<AppEmailVerificationService>,
+    pub password_reset_service: Arc<AppPasswordResetService>,
+    pub lockout_service: Arc<AppLockoutService>,
+    pub login_history_service: Arc<AppLoginHistoryService>,
+    pub personal_token_service: Arc<AppPersonalTokenService>,
+    pub avatar_service: Option<Arc<AppAvatarService>>,
+    pub event_replay_service: Arc<AppEventReplayService>,
+    pub audit_log_service: Arc<AppAuditLogService>,
     pub authenticator: Arc<Authenticator>,
     pub jwt_expiration_hours: i64,
+    pub build_info: Arc<BuildInfo>,
+    /// Dependencies checked by `/readyz`
+    pub health_checks: Arc<HealthChecks>,
+    /// Renders `/metrics`
+    pub metrics: PrometheusHandle,
+    pub trust_forwarded_for: bool,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_router(
-    user_service: Arc<UserService<PostgresUserRepository, KafkaEventProducer>>,
+    user_service: Arc<AppUserService>,
+    signup_service: Arc<AppSignupService>,
+    signup_rate_limiter: Arc<RateLimiter>,
+    magic_link_rate_limiter: Arc<MagicLinkRateLimiter>,
+    job_service: Arc<JobService<PostgresJobRepository>>,
+    import_service: Arc<UserImportService<AppUserService, JobService<PostgresJobRepository>>>,
+    magic_link_service: Option<Arc<AppMagicLinkService>>,
+    passkey_service: Option<Arc<AppPasskeyService>>,
+    account_link_service: Arc<AppAccountLinkService>,
+    email_verification_service: Arc<AppEmailVerificationService>,
+    password_reset_service: Arc<AppPasswordResetService>,
+    lockout_service: Arc<AppLockoutService>,
+    login_history_service: Arc<AppLoginHistoryService>,
+    personal_token_service: Arc<AppPersonalTokenService>,
+    avatar_service: Option<Arc<AppAvatarService>>,
+    event_replay_service: Arc<AppEventReplayService>,
+    audit_log_service: Arc<AppAuditLogService>,
     authenticator: Arc<Authenticator>,
     jwt_expiration_hours: i64,
+    build_info: Arc<BuildInfo>,
+    health_checks: Arc<HealthChecks>,
+    metrics: PrometheusHandle,
+    trust_forwarded_for: bool,
+    json_body_limit: usize,
+    upload_body_limit: usize,
+    api_deprecation: Deprecation,
 ) -> Router {
     let state = AppState {
         user_service,
+        signup_service,
+        signup_rate_limiter,
+        magic_link_rate_limiter,
+        job_service,
+        import_service,
+        magic_link_service,
+        passkey_service,
+        account_link_service,
+        email_verification_service,
+        password_reset_service,
+        lockout_service,
+        login_history_service,
+        personal_token_service,
+        avatar_service,
+        event_replay_service,
+        audit_log_service,
         authenticator,
         jwt_expiration_hours,
+        build_info,
+        health_checks,
+        metrics,
+        trust_forwarded_for,
     };
 
-    let public_routes = Router::new()
-        .route("/api/auth/login", post(authenticate))
-        .route("/api/users", post(create_user));
+    // Served under every API version, see `envelope::versioned`
+    let mut public_routes = Router::new()
+        .route("/auth/login", post(authenticate))
+        .route("/auth/refresh", post(refresh_token))
+        .route("/auth/verify", get(verify_email))
+        .route("/auth/password-reset/request", post(request_password_reset))
+        .route("/auth/password-reset/confirm", post(confirm_password_reset))
+        .route(
+            "/users",
+            post(create_user)
+                .route_layer(middleware::from_fn_with_state(state.clone(), limit_signups)),
+        );
+    if state.magic_link_service.is_some() {
+        public_routes = public_routes
+            .route("/auth/magic-link", post(request_magic_link))
+            .route("/auth/magic-link/callback", post(redeem_magic_link));
+    }
+    if state.passkey_service.is_some() {
+        public_routes = public_routes
+            .route("/auth/passkey/start", post(start_passkey_login))
+            .route("/auth/passkey/finish", post(finish_passkey_login));
+    }
+
+    let mut protected_routes = Router::new()
+        .route("/users", get(list_users))
+        .route("/users/search", get(search_users))
+        .route("/users/:user_id", get(get_user))
+        .route("/auth/logout", post(logout))
+        .route("/jobs/:job_id", get(get_job))
+        .route("/account/methods", get(list_auth_methods))
+        .route("/account/methods/password", post(link_password))
+        .route("/account/methods/:method_id", delete(remove_auth_method))
+        .route(
+            "/account/tokens",
+            get(list_personal_tokens).post(create_personal_token),
+        )
+        .route("/account/tokens/:token_id", delete(revoke_personal_token));
+    if state.magic_link_service.is_some() {
+        protected_routes = protected_routes
+            .route(
+                "/account/methods/magic-link",
+                post(request_magic_link_verification),
+            )
+            .route(
+                "/account/methods/magic-link/verify",
+                post(verify_magic_link),
+            );
+    }
+    if state.passkey_service.is_some() {
+        protected_routes = protected_routes
+            .route("/passkeys", get(list_passkeys))
+            .route("/passkeys/:passkey_id", delete(delete_passkey))
+            .route(
+                "/passkeys/registration/start",
+                post(start_passkey_registration),
+            )
+            .route(
+                "/passkeys/registration/finish",
+                post(finish_passkey_registration),
+            );
+    }
+    let protected_routes =
+        protected_routes.route_layer(AuthLayer::new(state.authenticator.clone()));
+
+    // Routes acting on one account, for its owner and admins only
+    let account_routes = Router::new()
+        .route("/users/:user_id", patch(update_user).delete(delete_user))
+        .route("/users/:user_id/deactivate", post(deactivate_user))
+        .route("/users/:user_id/password", post(change_password))
+        .route("/users/:user_id/logins", get(list_logins))
+        .route("/users/:user_id/export", get(export_user))
+        .route(
+            "/users/:user_id/verification",
+            post(request_email_verification),
+        )
+        .route_layer(middleware::from_fn(require_self_or_admin))
+        .route_layer(AuthLayer::new(state.authenticator.clone()));
 
-    let protected_routes = Router::new()
-        .route("/api/users/:user_id", get(get_user))
-        .route("/api/users/:user_id", patch(update_user))
-        .route("/api/users/:user_id", delete(delete_user))
-        .route_layer(middleware::from_fn_with_state(
-            state.clone(),
-            auth_middleware,
-        ));
+    // The role check runs after `AuthLayer`, which wraps it
+    let admin_routes = Router::new()
+        .route("/admin/users", get(list_admin_users))
+        .route(
+            "/admin/users/:user_id/password-reset",
+            post(force_password_reset),
+        )
+        .route("/admin/users/:user_id/lock", post(lock_user))
+        .route("/admin/users/:user_id/unlock", post(unlock_user))
+        .route("/users/:user_id/reactivate", post(reactivate_user))
+        .route("/admin/events/replay", post(replay_user_events))
+        .route("/admin/audit-log", get(list_audit_log))
+        .route_layer(middleware::from_fn_with_state(Role::Admin, require_role))
+        .route_layer(AuthLayer::new(state.authenticator.clone()));
+
+    let json_routes = with_body_limit(
+        public_routes
+            .merge(protected_routes)
+            .merge(account_routes)
+            .merge(admin_routes),
+        json_body_limit,
+    );
+
+    let mut upload_routes = Router::new().route("/admin/users/import", post(import_users));
+    if state.avatar_service.is_some() {
+        upload_routes = upload_routes.route(
+            "/users/:user_id/avatar",
+            put(upload_avatar).route_layer(middleware::from_fn(require_self_or_admin)),
+        );
+    }
+    let upload_routes = upload_routes.route_layer(AuthLayer::new(state.authenticator.clone()));
+    let upload_routes = with_body_limit(upload_routes, upload_body_limit);
+
+    let api_routes = json_routes.merge(upload_routes);
+
+    let internal_routes = Router::new().route("/internal/version", get(get_version));
+
+    // Probed by orchestrators and scraped by Prometheus without a token, outside API versioning
+    let probe_routes = Router::new()
+        .route("/healthz", get(check_liveness))
+        .route("/readyz", get(check_readiness))
+        .route("/metrics", get(get_metrics));
 
     let trace_layer = TraceLayer::new_for_http()
         .make_span_with(|request: &Request<Body>| {
@@ -84,9 +375,19 @@ pub fn create_router(
         );
 
     Router::new()
-        .merge(public_routes)
-        .merge(protected_routes)
+        .merge(versioned(api_routes, api_deprecation))
+        .merge(internal_routes)
+        .merge(probe_routes)
+        .merge(openapi_routes())
+        .route_layer(middleware::from_fn(track_http_metrics))
         .layer(trace_layer)
         .layer(CorsLayer::permissive())
         .with_state(state)
 }
+
+/// Cap the request bodies of `routes` at `limit` bytes.
+fn with_body_limit(routes: Router<AppState>, limit: usize) -> Router<AppState> {
+    routes
+        .route_layer(DefaultBodyLimit::max(limit))
+        .route_layer(middleware::from_fn_with_state(limit, limit_body_size))
+}
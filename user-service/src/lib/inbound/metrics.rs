@@ -0,0 +1,92 @@
+use std::time::Instant;
+
+use axum::extract::MatchedPath;
+use axum::extract::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use metrics_exporter_prometheus::BuildError;
+use metrics_exporter_prometheus::Matcher;
+use metrics_exporter_prometheus::PrometheusBuilder;
+use metrics_exporter_prometheus::PrometheusHandle;
+
+use crate::domain::account_link::models::AuthMethodKind;
+
+/// HTTP requests served, by method, route and status.
+pub const HTTP_REQUESTS_TOTAL: &str = "http_requests_total";
+/// Latency of HTTP requests, by method, route and status.
+pub const HTTP_REQUEST_DURATION_SECONDS: &str = "http_request_duration_seconds";
+/// Login attempts, by method and outcome.
+pub const LOGINS_TOTAL: &str = "user_logins_total";
+
+/// Histogram buckets of every `*_seconds` metric, from 1 ms to 10 s.
+const LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Outcome of a login attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginOutcome {
+    Success,
+    Failure,
+}
+
+impl LoginOutcome {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LoginOutcome::Success => "success",
+            LoginOutcome::Failure => "failure",
+        }
+    }
+}
+
+/// Install the process-wide Prometheus recorder.
+///
+/// Must be called once, before any metric is recorded.
+///
+/// # Returns
+/// Handle rendering the metrics for `/metrics`
+///
+/// # Errors
+/// Returns error if a recorder is already installed
+pub fn install_recorder() -> Result<PrometheusHandle, BuildError> {
+    PrometheusBuilder::new()
+        .set_buckets_for_metric(Matcher::Suffix("_seconds".to_string()), LATENCY_BUCKETS)?
+        .install_recorder()
+}
+
+/// Count and time every routed HTTP request.
+///
+/// Requests are labelled with the route template rather than the path, so that
+/// IDs in paths do not create a series per resource.
+pub async fn track_http_metrics(request: Request, next: Next) -> Response {
+    let start = Instant::now();
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let response = next.run(request).await;
+
+    let labels = [
+        ("method", method),
+        ("route", route),
+        ("status", response.status().as_u16().to_string()),
+    ];
+    metrics::counter!(HTTP_REQUESTS_TOTAL, &labels).increment(1);
+    metrics::histogram!(HTTP_REQUEST_DURATION_SECONDS, &labels)
+        .record(start.elapsed().as_secs_f64());
+
+    response
+}
+
+/// Count a login attempt.
+///
+/// # Arguments
+/// * `method` - Login method the user tried
+/// * `outcome` - Whether the user got in
+pub fn count_login(method: AuthMethodKind, outcome: LoginOutcome) {
+    metrics::counter!(LOGINS_TOTAL, "method" => method.as_str(), "outcome" => outcome.as_str())
+        .increment(1);
+}
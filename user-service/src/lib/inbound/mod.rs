@@ -1,2 +1,5 @@
 pub mod grpc;
+pub mod health;
 pub mod http;
+pub mod metrics;
+pub mod rate_limit;
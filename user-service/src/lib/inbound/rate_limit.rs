@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Number of tracked clients above which expired windows are pruned.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Fixed-window request limiter keyed by client IP, or by another key.
+///
+/// State is kept per instance, so the effective limit of a client spread
+/// over several instances is a multiple of the configured one.
+pub struct RateLimiter<K = IpAddr> {
+    max_requests: u32,
+    window: Duration,
+    windows: Mutex<HashMap<K, Window>>,
+}
+
+struct Window {
+    started_at: Instant,
+    requests: u32,
+}
+
+impl<K: Eq + Hash> RateLimiter<K> {
+    /// Create a limiter allowing `max_requests` per client in every window.
+    ///
+    /// # Arguments
+    /// * `max_requests` - Requests allowed per client and window
+    /// * `window` - Length of a window
+    ///
+    /// # Returns
+    /// Limiter without tracked clients
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        Self {
+            max_requests,
+            window,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Count a request of a client against its window.
+    ///
+    /// # Arguments
+    /// * `client` - Address of the client, or other key requests are counted by
+    ///
+    /// # Returns
+    /// Ok if the request is allowed, otherwise the time until the window resets
+    pub fn check(&self, client: K) -> Result<(), Duration> {
+        self.check_at(client, Instant::now())
+    }
+
+    fn check_at(&self, client: K, now: Instant) -> Result<(), Duration> {
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+
+        if windows.len() >= PRUNE_THRESHOLD {
+            windows.retain(|_, window| now.duration_since(window.started_at) < self.window);
+        }
+
+        let window = windows.entry(client).or_insert(Window {
+            started_at: now,
+            requests: 0,
+        });
+        let elapsed = now.duration_since(window.started_at);
+        if elapsed >= self.window {
+            window.started_at = now;
+            window.requests = 0;
+        }
+
+        if window.requests >= self.max_requests {
+            return Err(self
+                .window
+                .saturating_sub(now.duration_since(window.started_at)));
+        }
+        window.requests += 1;
+        Ok(())
+    }
+}
+
+/// Limits of login link requests, per client IP and per email address.
+///
+/// The address limit keeps clients spread over many IPs from flooding one
+/// mailbox with links.
+pub struct MagicLinkRateLimiter {
+    per_client: RateLimiter,
+    per_email: RateLimiter<String>,
+}
+
+impl MagicLinkRateLimiter {
+    /// Create a limiter for login link requests.
+    ///
+    /// # Arguments
+    /// * `per_client` - Requests allowed per client IP and window
+    /// * `per_email` - Requests allowed per email address and window
+    /// * `window` - Length of a window
+    ///
+    /// # Returns
+    /// Limiter without tracked clients or addresses
+    pub fn new(per_client: u32, per_email: u32, window: Duration) -> Self {
+        Self {
+            per_client: RateLimiter::new(per_client, window),
+            per_email: RateLimiter::new(per_email, window),
+        }
+    }
+
+    /// Count a link request against the windows of its client and address.
+    ///
+    /// # Arguments
+    /// * `client` - Address of the client
+    /// * `email` - Email address the link is requested for
+    ///
+    /// # Returns
+    /// Ok if the request is allowed, otherwise the time until the exhausted window resets
+    pub fn check(&self, client: IpAddr, email: &str) -> Result<(), Duration> {
+        self.per_client.check(client)?;
+        self.per_email.check(email.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+
+    const CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+    const OTHER_CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2));
+
+    #[test]
+    fn test_rejects_requests_over_limit_until_window_resets() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert!(limiter.check_at(CLIENT, start).is_ok());
+        assert!(limiter.check_at(CLIENT, start).is_ok());
+        assert_eq!(
+            limiter.check_at(CLIENT, start + Duration::from_secs(20)),
+            Err(Duration::from_secs(40))
+        );
+        assert!(limiter.check_at(OTHER_CLIENT, start).is_ok());
+
+        assert!(limiter
+            .check_at(CLIENT, start + Duration::from_secs(60))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_limits_link_requests_per_email_across_clients() {
+        let limiter = MagicLinkRateLimiter::new(10, 2, Duration::from_secs(60));
+
+        assert!(limiter.check(CLIENT, "alice@example.com").is_ok());
+        assert!(limiter.check(OTHER_CLIENT, "Alice@Example.com").is_ok());
+        assert!(limiter.check(OTHER_CLIENT, "alice@example.com").is_err());
+        assert!(limiter.check(CLIENT, "bob@example.com").is_ok());
+    }
+}
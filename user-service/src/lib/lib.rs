@@ -8,4 +8,12 @@ pub use outbound::repositories;
 
 pub mod proto {
     tonic::include_proto!("user");
+
+    /// Descriptors of the user proto, served by gRPC reflection
+    pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("user_descriptor");
+
+    /// Protobuf encoding of the user events published to Kafka
+    pub mod events {
+        tonic::include_proto!("user_events");
+    }
 }
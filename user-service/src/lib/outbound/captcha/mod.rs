@@ -0,0 +1,3 @@
+pub mod siteverify;
+
+pub use siteverify::SiteverifyCaptchaVerifier;
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+use crate::domain::outbox::ports::MessagePublisher;
+use crate::outbound::events::messages::UserEventMessage;
+use crate::user::errors::EventPublisherError;
+
+/// Messages kept for watchers that have not received them yet.
+const WATCH_BUFFER_SIZE: usize = 1024;
+
+/// Publisher handing published messages to in-process watchers as well.
+///
+/// Wraps the broker publisher of the outbox relay. A message reaches the
+/// watchers once the broker has accepted it, so they see the same events
+/// in the same order as Kafka consumers.
+pub struct BroadcastPublisher<MP: MessagePublisher> {
+    publisher: Arc<MP>,
+    sender: broadcast::Sender<Arc<UserEventMessage>>,
+}
+
+impl<MP: MessagePublisher> BroadcastPublisher<MP> {
+    /// Create a broadcasting publisher.
+    ///
+    /// # Arguments
+    /// * `publisher` - Broker publisher messages are published to first
+    pub fn new(publisher: Arc<MP>) -> Self {
+        let (sender, _) = broadcast::channel(WATCH_BUFFER_SIZE);
+        Self { publisher, sender }
+    }
+
+    /// Watch the messages published from now on.
+    ///
+    /// # Returns
+    /// Receiver of the messages, lagging once it falls more than the buffer behind
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<UserEventMessage>> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl<MP: MessagePublisher> MessagePublisher for BroadcastPublisher<MP> {
+    async fn publish(&self, key: &str, payload: &str) -> Result<(), EventPublisherError> {
+        self.publisher.publish(key, payload).await?;
+
+        match UserEventMessage::from_json(payload) {
+            // Sending fails only when nobody watches
+            Ok(message) => {
+                let _ = self.sender.send(Arc::new(message));
+            }
+            Err(e) => tracing::warn!("Failed to decode outbox message for watchers: {}", e),
+        }
+
+        Ok(())
+    }
+}
@@ -2,25 +2,112 @@ use chrono::DateTime;
 use chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
 
+use crate::domain::user::events::UserAccountLockedEvent;
 use crate::domain::user::events::UserCreatedEvent;
+use crate::domain::user::events::UserDeactivatedEvent;
 use crate::domain::user::events::UserDeletedEvent;
+use crate::domain::user::events::UserEvent;
+use crate::domain::user::events::UserPasswordChangedEvent;
+use crate::domain::user::events::UserPasswordResetEvent;
+use crate::domain::user::events::UserPasswordResetRequestedEvent;
+use crate::domain::user::events::UserReactivatedEvent;
 use crate::domain::user::events::UserUpdatedEvent;
 
+/// Schema version of the payloads this service publishes.
+///
+/// Bump it when an event's shape changes and add the step from the previous
+/// version to [`upgrade`], so stored and in-flight payloads keep decoding.
+/// - 1: unversioned payloads, `user_updated` without profile fields
+/// - 2: `schema_version` on every payload, `user_updated` with profile fields
+pub const SCHEMA_VERSION: u32 = 2;
+
 /// Serializable envelope for all user-related events.
 ///
 /// Infrastructure representation for event publishing (Kafka, etc.).
+/// Decode payloads with [`UserEventMessage::from_json`] so older schema
+/// versions are upgraded first.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "event_type", rename_all = "snake_case")]
 pub enum UserEventMessage {
     UserCreated(UserCreatedMessage),
     UserUpdated(UserUpdatedMessage),
     UserDeleted(UserDeletedMessage),
+    UserDeactivated(UserDeactivatedMessage),
+    UserReactivated(UserReactivatedMessage),
+    UserPasswordChanged(UserPasswordChangedMessage),
+    UserPasswordResetRequested(UserPasswordResetRequestedMessage),
+    UserPasswordReset(UserPasswordResetMessage),
+    UserAccountLocked(UserAccountLockedMessage),
+}
+
+impl UserEventMessage {
+    /// Decode a payload of any schema version up to [`SCHEMA_VERSION`].
+    ///
+    /// Payloads without a `schema_version` are version 1. Older payloads are
+    /// upgraded one version at a time, so the message always has the current
+    /// shape and version.
+    ///
+    /// # Errors
+    /// Returns an error if the payload is not JSON or does not match an event
+    pub fn from_json(payload: &str) -> Result<Self, serde_json::Error> {
+        let mut value: Value = serde_json::from_str(payload)?;
+        if let Value::Object(fields) = &mut value {
+            let mut version = fields
+                .get("schema_version")
+                .and_then(Value::as_u64)
+                .map_or(1, |version| version as u32);
+            while version < SCHEMA_VERSION {
+                upgrade(fields, version);
+                version += 1;
+            }
+            fields.insert("schema_version".to_string(), version.into());
+        }
+
+        serde_json::from_value(value)
+    }
+}
+
+/// Rewrite a payload of schema `version` into the shape of `version + 1`.
+fn upgrade(fields: &mut Map<String, Value>, version: u32) {
+    let event_type = fields.get("event_type").and_then(Value::as_str);
+    if version == 1 && event_type == Some("user_updated") {
+        for profile_field in ["display_name", "avatar_url", "bio"] {
+            fields
+                .entry(profile_field.to_string())
+                .or_insert(Value::Null);
+        }
+    }
+}
+
+/// Version of payloads published before schemas were versioned
+fn schema_v1() -> u32 {
+    1
+}
+
+impl From<UserEvent> for UserEventMessage {
+    fn from(event: UserEvent) -> Self {
+        match event {
+            UserEvent::UserCreated(e) => e.into(),
+            UserEvent::UserUpdated(e) => e.into(),
+            UserEvent::UserDeleted(e) => e.into(),
+            UserEvent::UserDeactivated(e) => e.into(),
+            UserEvent::UserReactivated(e) => e.into(),
+            UserEvent::UserPasswordChanged(e) => e.into(),
+            UserEvent::UserPasswordResetRequested(e) => e.into(),
+            UserEvent::UserPasswordReset(e) => e.into(),
+            UserEvent::UserAccountLocked(e) => e.into(),
+        }
+    }
 }
 
 /// Serializable message for UserCreated domain event.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserCreatedMessage {
+    #[serde(default = "schema_v1")]
+    pub schema_version: u32,
     pub event_id: String,
     pub user_id: String,
     pub username: String,
@@ -31,6 +118,7 @@ pub struct UserCreatedMessage {
 impl From<&UserCreatedEvent> for UserCreatedMessage {
     fn from(event: &UserCreatedEvent) -> Self {
         Self {
+            schema_version: SCHEMA_VERSION,
             event_id: event.event_id.clone(),
             user_id: event.user_id.clone(),
             username: event.username.clone(),
@@ -49,20 +137,32 @@ impl From<UserCreatedEvent> for UserEventMessage {
 /// Serializable message for UserUpdated domain event.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserUpdatedMessage {
+    #[serde(default = "schema_v1")]
+    pub schema_version: u32,
     pub event_id: String,
     pub user_id: String,
     pub username: String,
     pub email: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+    #[serde(default)]
+    pub bio: Option<String>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl From<&UserUpdatedEvent> for UserUpdatedMessage {
     fn from(event: &UserUpdatedEvent) -> Self {
         Self {
+            schema_version: SCHEMA_VERSION,
             event_id: event.event_id.clone(),
             user_id: event.user_id.clone(),
             username: event.username.clone(),
             email: event.email.clone(),
+            display_name: event.display_name.clone(),
+            avatar_url: event.avatar_url.clone(),
+            bio: event.bio.clone(),
             updated_at: event.updated_at,
         }
     }
@@ -77,6 +177,8 @@ impl From<UserUpdatedEvent> for UserEventMessage {
 /// Serializable message for UserDeleted domain event.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserDeletedMessage {
+    #[serde(default = "schema_v1")]
+    pub schema_version: u32,
     pub event_id: String,
     pub user_id: String,
     pub deleted_at: DateTime<Utc>,
@@ -85,6 +187,7 @@ pub struct UserDeletedMessage {
 impl From<&UserDeletedEvent> for UserDeletedMessage {
     fn from(event: &UserDeletedEvent) -> Self {
         Self {
+            schema_version: SCHEMA_VERSION,
             event_id: event.event_id.clone(),
             user_id: event.user_id.clone(),
             deleted_at: event.deleted_at,
@@ -97,3 +200,224 @@ impl From<UserDeletedEvent> for UserEventMessage {
         UserEventMessage::UserDeleted(UserDeletedMessage::from(&event))
     }
 }
+
+/// Serializable message for UserDeactivated domain event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserDeactivatedMessage {
+    #[serde(default = "schema_v1")]
+    pub schema_version: u32,
+    pub event_id: String,
+    pub user_id: String,
+    pub deactivated_at: DateTime<Utc>,
+}
+
+impl From<&UserDeactivatedEvent> for UserDeactivatedMessage {
+    fn from(event: &UserDeactivatedEvent) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            event_id: event.event_id.clone(),
+            user_id: event.user_id.clone(),
+            deactivated_at: event.deactivated_at,
+        }
+    }
+}
+
+impl From<UserDeactivatedEvent> for UserEventMessage {
+    fn from(event: UserDeactivatedEvent) -> Self {
+        UserEventMessage::UserDeactivated(UserDeactivatedMessage::from(&event))
+    }
+}
+
+/// Serializable message for UserReactivated domain event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserReactivatedMessage {
+    #[serde(default = "schema_v1")]
+    pub schema_version: u32,
+    pub event_id: String,
+    pub user_id: String,
+    pub reactivated_at: DateTime<Utc>,
+}
+
+impl From<&UserReactivatedEvent> for UserReactivatedMessage {
+    fn from(event: &UserReactivatedEvent) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            event_id: event.event_id.clone(),
+            user_id: event.user_id.clone(),
+            reactivated_at: event.reactivated_at,
+        }
+    }
+}
+
+impl From<UserReactivatedEvent> for UserEventMessage {
+    fn from(event: UserReactivatedEvent) -> Self {
+        UserEventMessage::UserReactivated(UserReactivatedMessage::from(&event))
+    }
+}
+
+/// Serializable message for UserPasswordChanged domain event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPasswordChangedMessage {
+    #[serde(default = "schema_v1")]
+    pub schema_version: u32,
+    pub event_id: String,
+    pub user_id: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl From<&UserPasswordChangedEvent> for UserPasswordChangedMessage {
+    fn from(event: &UserPasswordChangedEvent) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            event_id: event.event_id.clone(),
+            user_id: event.user_id.clone(),
+            changed_at: event.changed_at,
+        }
+    }
+}
+
+impl From<UserPasswordChangedEvent> for UserEventMessage {
+    fn from(event: UserPasswordChangedEvent) -> Self {
+        UserEventMessage::UserPasswordChanged(UserPasswordChangedMessage::from(&event))
+    }
+}
+
+/// Serializable message for UserPasswordResetRequested domain event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPasswordResetRequestedMessage {
+    #[serde(default = "schema_v1")]
+    pub schema_version: u32,
+    pub event_id: String,
+    pub user_id: String,
+    pub requested_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<&UserPasswordResetRequestedEvent> for UserPasswordResetRequestedMessage {
+    fn from(event: &UserPasswordResetRequestedEvent) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            event_id: event.event_id.clone(),
+            user_id: event.user_id.clone(),
+            requested_at: event.requested_at,
+            expires_at: event.expires_at,
+        }
+    }
+}
+
+impl From<UserPasswordResetRequestedEvent> for UserEventMessage {
+    fn from(event: UserPasswordResetRequestedEvent) -> Self {
+        UserEventMessage::UserPasswordResetRequested(UserPasswordResetRequestedMessage::from(
+            &event,
+        ))
+    }
+}
+
+/// Serializable message for UserPasswordReset domain event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPasswordResetMessage {
+    #[serde(default = "schema_v1")]
+    pub schema_version: u32,
+    pub event_id: String,
+    pub user_id: String,
+    pub reset_at: DateTime<Utc>,
+}
+
+impl From<&UserPasswordResetEvent> for UserPasswordResetMessage {
+    fn from(event: &UserPasswordResetEvent) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            event_id: event.event_id.clone(),
+            user_id: event.user_id.clone(),
+            reset_at: event.reset_at,
+        }
+    }
+}
+
+impl From<UserPasswordResetEvent> for UserEventMessage {
+    fn from(event: UserPasswordResetEvent) -> Self {
+        UserEventMessage::UserPasswordReset(UserPasswordResetMessage::from(&event))
+    }
+}
+
+/// Serializable message for UserAccountLocked domain event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAccountLockedMessage {
+    #[serde(default = "schema_v1")]
+    pub schema_version: u32,
+    pub event_id: String,
+    pub user_id: String,
+    pub failed_attempts: u32,
+    pub locked_at: DateTime<Utc>,
+    pub locked_until: DateTime<Utc>,
+}
+
+impl From<&UserAccountLockedEvent> for UserAccountLockedMessage {
+    fn from(event: &UserAccountLockedEvent) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            event_id: event.event_id.clone(),
+            user_id: event.user_id.clone(),
+            failed_attempts: event.failed_attempts,
+            locked_at: event.locked_at,
+            locked_until: event.locked_until,
+        }
+    }
+}
+
+impl From<UserAccountLockedEvent> for UserEventMessage {
+    fn from(event: UserAccountLockedEvent) -> Self {
+        UserEventMessage::UserAccountLocked(UserAccountLockedMessage::from(&event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_json_upgrades_v1_payload() {
+        let payload = r#"{
+            "event_type": "user_updated",
+            "event_id": "event-1",
+            "user_id": "user-1",
+            "username": "alice",
+            "email": "alice@example.com",
+            "updated_at": "2026-10-15T12:00:00Z"
+        }"#;
+
+        let message = UserEventMessage::from_json(payload).unwrap();
+
+        let UserEventMessage::UserUpdated(updated) = message else {
+            panic!("Expected a user_updated message");
+        };
+        assert_eq!(updated.schema_version, SCHEMA_VERSION);
+        assert_eq!(updated.username, "alice");
+        assert_eq!(updated.display_name, None);
+    }
+
+    #[test]
+    fn test_from_json_reads_current_payload() {
+        let event = UserDeletedEvent {
+            event_id: "event-1".to_string(),
+            user_id: "user-1".to_string(),
+            deleted_at: Utc::now(),
+        };
+        let payload = serde_json::to_string(&UserEventMessage::from(event)).unwrap();
+
+        let message = UserEventMessage::from_json(&payload).unwrap();
+
+        let UserEventMessage::UserDeleted(deleted) = message else {
+            panic!("Expected a user_deleted message");
+        };
+        assert_eq!(deleted.schema_version, SCHEMA_VERSION);
+        assert_eq!(deleted.user_id, "user-1");
+    }
+
+    #[test]
+    fn test_from_json_rejects_unknown_event() {
+        let payload = r#"{"event_type": "user_renamed", "schema_version": 2}"#;
+
+        assert!(UserEventMessage::from_json(payload).is_err());
+    }
+}
@@ -1,4 +1,7 @@
+pub mod broadcast;
 pub mod messages;
 pub mod producer;
+pub mod protobuf;
 
+pub use broadcast::BroadcastPublisher;
 pub use producer::KafkaEventProducer;
@@ -1,18 +1,33 @@
+use std::borrow::Cow;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use rdkafka::config::ClientConfig;
+use rdkafka::message::Header;
+use rdkafka::message::OwnedHeaders;
 use rdkafka::producer::FutureProducer;
 use rdkafka::producer::FutureRecord;
+use rdkafka::producer::Producer;
 use rdkafka::util::Timeout;
 use serde::Serialize;
 use thiserror::Error;
 
 use crate::config::Config;
+use crate::config::EventEncoding;
+use crate::domain::outbox::ports::MessagePublisher;
+use crate::domain::user::events::UserAccountLockedEvent;
 use crate::domain::user::events::UserCreatedEvent;
+use crate::domain::user::events::UserDeactivatedEvent;
 use crate::domain::user::events::UserDeletedEvent;
+use crate::domain::user::events::UserPasswordChangedEvent;
+use crate::domain::user::events::UserPasswordResetEvent;
+use crate::domain::user::events::UserPasswordResetRequestedEvent;
+use crate::domain::user::events::UserReactivatedEvent;
 use crate::domain::user::events::UserUpdatedEvent;
+use crate::inbound::health::DependencyCheck;
 use crate::outbound::events::messages::UserEventMessage;
+use crate::outbound::events::protobuf;
+use crate::outbound::events::protobuf::PROTOBUF_CONTENT_TYPE;
 use crate::user::errors::EventPublisherError;
 use crate::user::ports::EventPublisher;
 
@@ -36,10 +51,22 @@ impl From<KafkaProducerError> for EventPublisherError {
     }
 }
 
+/// Events sent to Kafka, by topic and outcome.
+pub const KAFKA_PUBLISH_TOTAL: &str = "kafka_publish_total";
+
+/// Record header naming the encoding of the payload
+pub const CONTENT_TYPE_HEADER: &str = "content-type";
+/// Content type of JSON-encoded events
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Time the health check waits for broker metadata.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub struct KafkaEventProducer {
     producer: FutureProducer,
     topic: String,
     timeout: Duration,
+    encoding: EventEncoding,
 }
 
 impl KafkaEventProducer {
@@ -55,9 +82,10 @@ impl KafkaEventProducer {
     /// - `retry.backoff.ms=100`: Backoff between retry attempts
     pub fn new(config: &Config) -> Result<Self, anyhow::Error> {
         tracing::info!(
-            "Initializing Kafka producer for user events: brokers={}, topic={}",
+            "Initializing Kafka producer for user events: brokers={}, topic={}, encoding={:?}",
             &config.kafka.brokers,
-            &config.kafka.topic
+            &config.kafka.topic,
+            config.kafka.encoding
         );
 
         let producer: FutureProducer = ClientConfig::new()
@@ -80,6 +108,7 @@ impl KafkaEventProducer {
             producer,
             topic: config.kafka.topic.to_string(),
             timeout: Duration::from_secs(30),
+            encoding: config.kafka.encoding,
         })
     }
 
@@ -95,20 +124,39 @@ impl KafkaEventProducer {
         let payload = serde_json::to_string(event)
             .map_err(|e| KafkaProducerError::SerializationError(e.to_string()))?;
 
+        self.send(user_id, &payload).await
+    }
+
+    /// Send a serialized event to Kafka, keyed by the user it is about
+    ///
+    /// The JSON payload is re-encoded when the producer publishes protobuf.
+    async fn send(&self, user_id: &str, payload: &str) -> Result<(), KafkaProducerError> {
         tracing::debug!(
             "Publishing event to topic '{}' (user_id: {})",
             self.topic,
             user_id
         );
 
+        let (payload, content_type) = self.encode(payload)?;
         let record = FutureRecord::to(&self.topic)
             .key(user_id) // Partition by user_id for ordering
-            .payload(&payload);
+            .payload(payload.as_ref())
+            .headers(OwnedHeaders::new().insert(Header {
+                key: CONTENT_TYPE_HEADER,
+                value: Some(content_type),
+            }));
 
         // Send to Kafka - producer will handle retries automatically with at-least-once semantics
-        self.producer
+        let result = self
+            .producer
             .send(record, Timeout::After(self.timeout))
-            .await
+            .await;
+
+        let outcome = if result.is_ok() { "success" } else { "error" };
+        metrics::counter!(KAFKA_PUBLISH_TOTAL, "topic" => self.topic.clone(), "outcome" => outcome)
+            .increment(1);
+
+        result
             .map(|_| {
                 tracing::debug!(
                     "Event published successfully to topic '{}' for user {}",
@@ -126,6 +174,32 @@ impl KafkaEventProducer {
     }
 }
 
+impl KafkaEventProducer {
+    /// Encode a JSON event payload in the configured encoding.
+    ///
+    /// # Returns
+    /// Encoded payload and its content type
+    ///
+    /// # Errors
+    /// Returns `SerializationError` if the payload is not a user event
+    fn encode<'a>(
+        &self,
+        payload: &'a str,
+    ) -> Result<(Cow<'a, [u8]>, &'static str), KafkaProducerError> {
+        match self.encoding {
+            EventEncoding::Json => Ok((Cow::Borrowed(payload.as_bytes()), JSON_CONTENT_TYPE)),
+            EventEncoding::Protobuf => {
+                let message = UserEventMessage::from_json(payload)
+                    .map_err(|e| KafkaProducerError::SerializationError(e.to_string()))?;
+                Ok((
+                    Cow::Owned(protobuf::encode(&message)),
+                    PROTOBUF_CONTENT_TYPE,
+                ))
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl EventPublisher for KafkaEventProducer {
     async fn publish_user_created(
@@ -179,4 +253,135 @@ impl EventPublisher for KafkaEventProducer {
             e.into()
         })
     }
+
+    async fn publish_user_deactivated(
+        &self,
+        event: &UserDeactivatedEvent,
+    ) -> Result<(), EventPublisherError> {
+        // Convert domain event to serializable message
+        let message: UserEventMessage = event.clone().into();
+
+        self.publish(&event.user_id, &message).await.map_err(|e| {
+            tracing::error!(
+                "Failed to publish UserDeactivated event for user {}: {}",
+                event.user_id,
+                e
+            );
+            e.into()
+        })
+    }
+
+    async fn publish_user_reactivated(
+        &self,
+        event: &UserReactivatedEvent,
+    ) -> Result<(), EventPublisherError> {
+        // Convert domain event to serializable message
+        let message: UserEventMessage = event.clone().into();
+
+        self.publish(&event.user_id, &message).await.map_err(|e| {
+            tracing::error!(
+                "Failed to publish UserReactivated event for user {}: {}",
+                event.user_id,
+                e
+            );
+            e.into()
+        })
+    }
+
+    async fn publish_user_password_changed(
+        &self,
+        event: &UserPasswordChangedEvent,
+    ) -> Result<(), EventPublisherError> {
+        // Convert domain event to serializable message
+        let message: UserEventMessage = event.clone().into();
+
+        self.publish(&event.user_id, &message).await.map_err(|e| {
+            tracing::error!(
+                "Failed to publish UserPasswordChanged event for user {}: {}",
+                event.user_id,
+                e
+            );
+            e.into()
+        })
+    }
+
+    async fn publish_user_password_reset_requested(
+        &self,
+        event: &UserPasswordResetRequestedEvent,
+    ) -> Result<(), EventPublisherError> {
+        // Convert domain event to serializable message
+        let message: UserEventMessage = event.clone().into();
+
+        self.publish(&event.user_id, &message).await.map_err(|e| {
+            tracing::error!(
+                "Failed to publish UserPasswordResetRequested event for user {}: {}",
+                event.user_id,
+                e
+            );
+            e.into()
+        })
+    }
+
+    async fn publish_user_password_reset(
+        &self,
+        event: &UserPasswordResetEvent,
+    ) -> Result<(), EventPublisherError> {
+        // Convert domain event to serializable message
+        let message: UserEventMessage = event.clone().into();
+
+        self.publish(&event.user_id, &message).await.map_err(|e| {
+            tracing::error!(
+                "Failed to publish UserPasswordReset event for user {}: {}",
+                event.user_id,
+                e
+            );
+            e.into()
+        })
+    }
+
+    async fn publish_user_account_locked(
+        &self,
+        event: &UserAccountLockedEvent,
+    ) -> Result<(), EventPublisherError> {
+        // Convert domain event to serializable message
+        let message: UserEventMessage = event.clone().into();
+
+        self.publish(&event.user_id, &message).await.map_err(|e| {
+            tracing::error!(
+                "Failed to publish UserAccountLocked event for user {}: {}",
+                event.user_id,
+                e
+            );
+            e.into()
+        })
+    }
+}
+
+#[async_trait]
+impl MessagePublisher for KafkaEventProducer {
+    async fn publish(&self, key: &str, payload: &str) -> Result<(), EventPublisherError> {
+        self.send(key, payload).await.map_err(Into::into)
+    }
+}
+
+#[async_trait]
+impl DependencyCheck for KafkaEventProducer {
+    fn name(&self) -> &'static str {
+        "kafka"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        let (producer, topic) = (self.producer.clone(), self.topic.clone());
+
+        // Fetching metadata blocks until the brokers answer
+        tokio::task::spawn_blocking(move || {
+            producer
+                .client()
+                .fetch_metadata(Some(&topic), Timeout::After(HEALTH_CHECK_TIMEOUT))
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
 }
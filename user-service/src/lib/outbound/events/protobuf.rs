@@ -0,0 +1,163 @@
+use chrono::DateTime;
+use chrono::Utc;
+use prost::Message;
+
+use crate::outbound::events::messages::UserEventMessage;
+use crate::proto::events as proto;
+use crate::proto::events::user_event::Event;
+
+/// Content type of protobuf-encoded events, in the `content-type` record header
+pub const PROTOBUF_CONTENT_TYPE: &str = "application/x-protobuf";
+
+/// Encode a user event as `proto/user_events.proto`.
+///
+/// # Arguments
+/// * `message` - Event to encode
+///
+/// # Returns
+/// Protobuf bytes of the event
+pub fn encode(message: &UserEventMessage) -> Vec<u8> {
+    proto::UserEvent::from(message).encode_to_vec()
+}
+
+fn millis(timestamp: DateTime<Utc>) -> i64 {
+    timestamp.timestamp_millis()
+}
+
+impl From<&UserEventMessage> for proto::UserEvent {
+    fn from(message: &UserEventMessage) -> Self {
+        let (schema_version, event) = match message {
+            UserEventMessage::UserCreated(m) => (
+                m.schema_version,
+                Event::UserCreated(proto::UserCreated {
+                    event_id: m.event_id.clone(),
+                    user_id: m.user_id.clone(),
+                    username: m.username.clone(),
+                    email: m.email.clone(),
+                    created_at: millis(m.created_at),
+                }),
+            ),
+            UserEventMessage::UserUpdated(m) => (
+                m.schema_version,
+                Event::UserUpdated(proto::UserUpdated {
+                    event_id: m.event_id.clone(),
+                    user_id: m.user_id.clone(),
+                    username: m.username.clone(),
+                    email: m.email.clone(),
+                    display_name: m.display_name.clone(),
+                    avatar_url: m.avatar_url.clone(),
+                    bio: m.bio.clone(),
+                    updated_at: millis(m.updated_at),
+                }),
+            ),
+            UserEventMessage::UserDeleted(m) => (
+                m.schema_version,
+                Event::UserDeleted(proto::UserDeleted {
+                    event_id: m.event_id.clone(),
+                    user_id: m.user_id.clone(),
+                    deleted_at: millis(m.deleted_at),
+                }),
+            ),
+            UserEventMessage::UserDeactivated(m) => (
+                m.schema_version,
+                Event::UserDeactivated(proto::UserDeactivated {
+                    event_id: m.event_id.clone(),
+                    user_id: m.user_id.clone(),
+                    deactivated_at: millis(m.deactivated_at),
+                }),
+            ),
+            UserEventMessage::UserReactivated(m) => (
+                m.schema_version,
+                Event::UserReactivated(proto::UserReactivated {
+                    event_id: m.event_id.clone(),
+                    user_id: m.user_id.clone(),
+                    reactivated_at: millis(m.reactivated_at),
+                }),
+            ),
+            UserEventMessage::UserPasswordChanged(m) => (
+                m.schema_version,
+                Event::UserPasswordChanged(proto::UserPasswordChanged {
+                    event_id: m.event_id.clone(),
+                    user_id: m.user_id.clone(),
+                    changed_at: millis(m.changed_at),
+                }),
+            ),
+            UserEventMessage::UserPasswordResetRequested(m) => (
+                m.schema_version,
+                Event::UserPasswordResetRequested(proto::UserPasswordResetRequested {
+                    event_id: m.event_id.clone(),
+                    user_id: m.user_id.clone(),
+                    requested_at: millis(m.requested_at),
+                    expires_at: millis(m.expires_at),
+                }),
+            ),
+            UserEventMessage::UserPasswordReset(m) => (
+                m.schema_version,
+                Event::UserPasswordReset(proto::UserPasswordReset {
+                    event_id: m.event_id.clone(),
+                    user_id: m.user_id.clone(),
+                    reset_at: millis(m.reset_at),
+                }),
+            ),
+            UserEventMessage::UserAccountLocked(m) => (
+                m.schema_version,
+                Event::UserAccountLocked(proto::UserAccountLocked {
+                    event_id: m.event_id.clone(),
+                    user_id: m.user_id.clone(),
+                    failed_attempts: m.failed_attempts,
+                    locked_at: millis(m.locked_at),
+                    locked_until: millis(m.locked_until),
+                }),
+            ),
+        };
+
+        Self {
+            schema_version,
+            event: Some(event),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::user::events::UserAccountLockedEvent;
+    use crate::outbound::events::messages::SCHEMA_VERSION;
+
+    #[test]
+    fn test_encode_round_trips_through_proto() {
+        let locked_at = DateTime::from_timestamp_millis(1_792_022_400_123).unwrap();
+        let message = UserEventMessage::from(UserAccountLockedEvent {
+            event_id: "event-1".to_string(),
+            user_id: "user-1".to_string(),
+            failed_attempts: 5,
+            locked_at,
+            locked_until: locked_at + chrono::Duration::minutes(15),
+        });
+
+        let decoded = proto::UserEvent::decode(encode(&message).as_slice()).unwrap();
+
+        assert_eq!(decoded.schema_version, SCHEMA_VERSION);
+        let Some(Event::UserAccountLocked(locked)) = decoded.event else {
+            panic!("Expected a user_account_locked event");
+        };
+        assert_eq!(locked.user_id, "user-1");
+        assert_eq!(locked.failed_attempts, 5);
+        assert_eq!(locked.locked_at, 1_792_022_400_123);
+    }
+
+    #[test]
+    fn test_encode_is_smaller_than_json() {
+        let message = UserEventMessage::from(UserAccountLockedEvent {
+            event_id: "event-1".to_string(),
+            user_id: "user-1".to_string(),
+            failed_attempts: 5,
+            locked_at: Utc::now(),
+            locked_until: Utc::now(),
+        });
+
+        let json = serde_json::to_vec(&message).unwrap();
+
+        assert!(encode(&message).len() < json.len());
+    }
+}
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+
+use crate::domain::email::models::EmailMessage;
+use crate::domain::email::ports::EmailSender;
+use crate::domain::magic_link::errors::MagicLinkError;
+use crate::domain::magic_link::models::MagicLink;
+use crate::domain::magic_link::models::MagicLinkSignup;
+use crate::domain::magic_link::ports::LoginLinkSender;
+use crate::domain::user::models::EmailAddress;
+use crate::domain::user::models::User;
+use crate::outbound::mail::webhook::WebhookEmailSender;
+
+/// Login link sender posting emails as JSON to an HTTP mail relay.
+///
+/// Without a relay URL the link is logged instead, which is only suitable
+/// for local development since anyone reading the logs can log in.
+pub struct WebhookLoginLinkSender {
+    sender: WebhookEmailSender,
+}
+
+impl WebhookLoginLinkSender {
+    /// Create a sender.
+    ///
+    /// # Arguments
+    /// * `webhook_url` - Mail relay accepting `{to, subject, text}`, or None to log links
+    ///
+    /// # Returns
+    /// WebhookLoginLinkSender instance
+    pub fn new(webhook_url: Option<String>) -> Self {
+        Self {
+            sender: WebhookEmailSender::new(webhook_url),
+        }
+    }
+
+    async fn send(
+        &self,
+        to: &EmailAddress,
+        subject: &str,
+        text: String,
+    ) -> Result<(), MagicLinkError> {
+        let message = EmailMessage {
+            to: to.clone(),
+            subject: subject.to_string(),
+            text,
+        };
+
+        self.sender
+            .send(&message)
+            .await
+            .map_err(|e| MagicLinkError::DeliveryFailed(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl LoginLinkSender for WebhookLoginLinkSender {
+    async fn send_login_link(&self, user: &User, link: &MagicLink) -> Result<(), MagicLinkError> {
+        let text = format!(
+            "Hi {},\n\nUse this link to log in: {}\n\nIt can be used once and expires at {}. \
+             If you did not request it, you can ignore this email.",
+            user.username.as_str(),
+            link.url,
+            link.expires_at.to_rfc2822()
+        );
+        self.send(&user.email, "Your login link", text).await
+    }
+
+    async fn send_signup_link(
+        &self,
+        signup: &MagicLinkSignup,
+        link: &MagicLink,
+    ) -> Result<(), MagicLinkError> {
+        let text = format!(
+            "Hi {},\n\nUse this link to create your account and log in: {}\n\n\
+             It can be used once and expires at {}. If you did not sign up, you can ignore \
+             this email and no account will be created.",
+            signup.username.as_str(),
+            link.url,
+            link.expires_at.to_rfc2822()
+        );
+        self.send(&signup.email, "Finish signing up", text).await
+    }
+
+    async fn send_link_verification(
+        &self,
+        user: &User,
+        link: &MagicLink,
+    ) -> Result<(), MagicLinkError> {
+        let text = format!(
+            "Hi {},\n\nOpen this link to log in with links sent to this address: {}\n\n\
+             It expires at {}. If you did not ask for this, you can ignore this email.",
+            user.username.as_str(),
+            link.url,
+            link.expires_at.to_rfc2822()
+        );
+        self.send(&user.email, "Confirm login by email link", text)
+            .await
+    }
+}
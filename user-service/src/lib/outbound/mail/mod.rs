@@ -0,0 +1,5 @@
+pub mod login_link;
+pub mod webhook;
+
+pub use login_link::WebhookLoginLinkSender;
+pub use webhook::WebhookEmailSender;
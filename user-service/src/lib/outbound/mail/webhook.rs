@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::domain::email::errors::EmailError;
+use crate::domain::email::models::EmailMessage;
+use crate::domain::email::ports::EmailSender;
+
+/// Email posted to the mail relay.
+#[derive(Debug, Serialize)]
+struct MailMessage<'a> {
+    to: &'a str,
+    subject: &'a str,
+    text: &'a str,
+}
+
+/// Email sender posting emails as JSON to an HTTP mail relay.
+///
+/// Without a relay URL the email is logged instead, which is only suitable
+/// for local development since emails may carry login or verification links.
+pub struct WebhookEmailSender {
+    client: reqwest::Client,
+    webhook_url: Option<String>,
+}
+
+impl WebhookEmailSender {
+    /// Create a sender.
+    ///
+    /// # Arguments
+    /// * `webhook_url` - Mail relay accepting `{to, subject, text}`, or None to log emails
+    ///
+    /// # Returns
+    /// WebhookEmailSender instance
+    pub fn new(webhook_url: Option<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            webhook_url,
+        }
+    }
+}
+
+#[async_trait]
+impl EmailSender for WebhookEmailSender {
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailError> {
+        let Some(webhook_url) = &self.webhook_url else {
+            tracing::warn!(
+                to = %message.to.as_str(),
+                subject = %message.subject,
+                text = %message.text,
+                "No mail relay configured, email logged instead of sent"
+            );
+            return Ok(());
+        };
+
+        let body = MailMessage {
+            to: message.to.as_str(),
+            subject: &message.subject,
+            text: &message.text,
+        };
+
+        self.client
+            .post(webhook_url)
+            .json(&body)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| EmailError::DeliveryFailed(e.to_string()))?;
+
+        Ok(())
+    }
+}
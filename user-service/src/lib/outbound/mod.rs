@@ -1,2 +1,5 @@
+pub mod captcha;
 pub mod events;
+pub mod mail;
 pub mod repositories;
+pub mod storage;
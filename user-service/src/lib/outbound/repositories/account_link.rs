@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::account_link::errors::AccountLinkError;
+use crate::domain::account_link::models::AccountLink;
+use crate::domain::account_link::models::AuthMethodId;
+use crate::domain::account_link::ports::AccountLinkRepository;
+use crate::domain::user::models::UserId;
+
+pub struct PostgresAccountLinkRepository {
+    pool: PgPool,
+}
+
+impl PostgresAccountLinkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AccountLinkRepository for PostgresAccountLinkRepository {
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<AccountLink>, AccountLinkError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT user_id, method, linked_at
+            FROM account_links
+            WHERE user_id = $1
+            ORDER BY method DESC
+            "#,
+            user_id.0,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AccountLinkError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|r| {
+                Ok(AccountLink {
+                    user_id: UserId(r.user_id),
+                    method: r.method.parse()?,
+                    linked_at: r.linked_at,
+                })
+            })
+            .collect()
+    }
+
+    async fn link(&self, link: &AccountLink) -> Result<bool, AccountLinkError> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO account_links (user_id, method, linked_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT DO NOTHING
+            "#,
+            link.user_id.0,
+            link.method.to_string(),
+            link.linked_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AccountLinkError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn unlink(
+        &self,
+        user_id: &UserId,
+        method: &AuthMethodId,
+    ) -> Result<bool, AccountLinkError> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM account_links
+            WHERE user_id = $1 AND method = $2
+            "#,
+            user_id.0,
+            method.to_string()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AccountLinkError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn is_linked(
+        &self,
+        user_id: &UserId,
+        method: &AuthMethodId,
+    ) -> Result<bool, AccountLinkError> {
+        let linked = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(SELECT 1 FROM account_links WHERE user_id = $1 AND method = $2) AS "linked!"
+            "#,
+            user_id.0,
+            method.to_string()
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AccountLinkError::DatabaseError(e.to_string()))?;
+
+        Ok(linked)
+    }
+}
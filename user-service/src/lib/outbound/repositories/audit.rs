@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::audit::errors::AuditError;
+use crate::domain::audit::models::AuditAction;
+use crate::domain::audit::models::AuditEntry;
+use crate::domain::audit::models::AuditQuery;
+use crate::domain::audit::ports::AuditLogger;
+use crate::domain::user::models::UserId;
+
+pub struct PostgresAuditLogger {
+    pool: PgPool,
+}
+
+impl PostgresAuditLogger {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditLogger for PostgresAuditLogger {
+    async fn log(&self, entry: &AuditEntry) -> Result<(), AuditError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO audit_log (action, actor_id, target_id, ip_address, detail, occurred_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            entry.action.as_str(),
+            entry.actor_id.map(|id| id.0),
+            entry.target_id.map(|id| id.0),
+            entry.ip_address.map(|ip| ip.to_string()),
+            entry.detail,
+            entry.occurred_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuditError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT action, actor_id, target_id, ip_address, detail, occurred_at
+            FROM audit_log
+            WHERE ($1::UUID IS NULL OR actor_id = $1)
+              AND ($2::UUID IS NULL OR target_id = $2)
+              AND ($3::TEXT IS NULL OR action = $3)
+              AND ($4::TIMESTAMPTZ IS NULL OR occurred_at < $4)
+            ORDER BY occurred_at DESC, id DESC
+            LIMIT $5
+            "#,
+            query.actor_id.map(|id| id.0),
+            query.target_id.map(|id| id.0),
+            query.action.map(|action| action.as_str()),
+            query.before,
+            i64::from(query.limit)
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AuditError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|r| {
+                Ok(AuditEntry {
+                    action: r
+                        .action
+                        .parse::<AuditAction>()
+                        .map_err(|e| AuditError::DatabaseError(e.to_string()))?,
+                    actor_id: r.actor_id.map(UserId),
+                    target_id: r.target_id.map(UserId),
+                    // Written from an `IpAddr`, so anything else is dropped
+                    ip_address: r.ip_address.and_then(|ip| ip.parse().ok()),
+                    detail: r.detail,
+                    occurred_at: r.occurred_at,
+                })
+            })
+            .collect()
+    }
+}
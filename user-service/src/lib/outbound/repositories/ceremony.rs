@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::passkey::errors::PasskeyError;
+use crate::domain::passkey::models::CeremonyId;
+use crate::domain::passkey::models::PendingCeremony;
+use crate::domain::passkey::ports::CeremonyStore;
+
+/// Process-local passkey ceremony store.
+///
+/// Suitable for single-instance deployments, or behind a load balancer with
+/// sticky sessions; ceremonies in progress are lost on restart.
+#[derive(Default)]
+pub struct InMemoryCeremonyStore {
+    ceremonies: Mutex<HashMap<CeremonyId, PendingCeremony>>,
+}
+
+impl InMemoryCeremonyStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CeremonyStore for InMemoryCeremonyStore {
+    async fn insert(&self, id: CeremonyId, ceremony: PendingCeremony) -> Result<(), PasskeyError> {
+        let mut ceremonies = self
+            .ceremonies
+            .lock()
+            .map_err(|e| PasskeyError::DatabaseError(e.to_string()))?;
+
+        let now = Utc::now();
+        ceremonies.retain(|_, ceremony| ceremony.expires_at > now);
+        ceremonies.insert(id, ceremony);
+
+        Ok(())
+    }
+
+    async fn take(&self, id: &CeremonyId) -> Result<Option<PendingCeremony>, PasskeyError> {
+        let mut ceremonies = self
+            .ceremonies
+            .lock()
+            .map_err(|e| PasskeyError::DatabaseError(e.to_string()))?;
+
+        Ok(ceremonies.remove(id))
+    }
+}
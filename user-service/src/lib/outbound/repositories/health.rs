@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::inbound::health::DependencyCheck;
+
+/// Check that PostgreSQL accepts queries.
+pub struct PostgresHealthCheck {
+    pool: PgPool,
+}
+
+impl PostgresHealthCheck {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DependencyCheck for PostgresHealthCheck {
+    fn name(&self) -> &'static str {
+        "postgresql"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
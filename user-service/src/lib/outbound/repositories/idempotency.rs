@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::idempotency::errors::IdempotencyError;
+use crate::domain::idempotency::models::IdempotencyKey;
+use crate::domain::idempotency::models::IdempotencyRecord;
+use crate::domain::idempotency::ports::IdempotencyRepository;
+
+pub struct PostgresIdempotencyRepository {
+    pool: PgPool,
+}
+
+impl PostgresIdempotencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IdempotencyRepository for PostgresIdempotencyRepository {
+    async fn claim(
+        &self,
+        operation: &str,
+        key: &IdempotencyKey,
+        fingerprint: &str,
+        expired_before: DateTime<Utc>,
+    ) -> Result<Option<IdempotencyRecord>, IdempotencyError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| IdempotencyError::DatabaseError(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM idempotency_keys
+            WHERE created_at < $1
+            "#,
+            expired_before
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| IdempotencyError::DatabaseError(e.to_string()))?;
+
+        // The no-op update locks and returns the record holding the key;
+        // `xmax = 0` only holds for a row this statement inserted
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO idempotency_keys (operation, key, fingerprint, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (operation, key) DO UPDATE SET operation = EXCLUDED.operation
+            RETURNING (xmax = 0) AS "claimed!", fingerprint, resource_id, created_at
+            "#,
+            operation,
+            key.as_str(),
+            fingerprint,
+            Utc::now()
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| IdempotencyError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| IdempotencyError::DatabaseError(e.to_string()))?;
+
+        Ok((!row.claimed).then_some(IdempotencyRecord {
+            fingerprint: row.fingerprint,
+            resource_id: row.resource_id,
+            created_at: row.created_at,
+        }))
+    }
+
+    async fn complete(
+        &self,
+        operation: &str,
+        key: &IdempotencyKey,
+        resource_id: Uuid,
+    ) -> Result<(), IdempotencyError> {
+        sqlx::query!(
+            r#"
+            UPDATE idempotency_keys
+            SET resource_id = $3
+            WHERE operation = $1 AND key = $2
+            "#,
+            operation,
+            key.as_str(),
+            resource_id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| IdempotencyError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn release(&self, operation: &str, key: &IdempotencyKey) -> Result<(), IdempotencyError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM idempotency_keys
+            WHERE operation = $1 AND key = $2 AND resource_id IS NULL
+            "#,
+            operation,
+            key.as_str()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| IdempotencyError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlx::PgPool;
+
+use crate::domain::job::errors::JobError;
+use crate::domain::job::models::Job;
+use crate::domain::job::models::JobId;
+use crate::domain::job::models::JobItemError;
+use crate::domain::job::models::JobKind;
+use crate::domain::job::models::JobProgress;
+use crate::domain::job::ports::JobRepository;
+use crate::domain::user::models::UserId;
+
+pub struct PostgresJobRepository {
+    pool: PgPool,
+}
+
+impl PostgresJobRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// JSON representation of a [`JobItemError`] in the `item_errors` column.
+#[derive(Serialize, Deserialize)]
+struct JobItemErrorRecord {
+    item: usize,
+    message: String,
+}
+
+fn item_errors_to_json(errors: &[JobItemError]) -> Result<serde_json::Value, JobError> {
+    let records: Vec<JobItemErrorRecord> = errors
+        .iter()
+        .map(|e| JobItemErrorRecord {
+            item: e.item,
+            message: e.message.clone(),
+        })
+        .collect();
+    serde_json::to_value(records).map_err(|e| JobError::DatabaseError(e.to_string()))
+}
+
+fn item_errors_from_json(value: serde_json::Value) -> Result<Vec<JobItemError>, JobError> {
+    let records: Vec<JobItemErrorRecord> =
+        serde_json::from_value(value).map_err(|e| JobError::DatabaseError(e.to_string()))?;
+    Ok(records
+        .into_iter()
+        .map(|r| JobItemError {
+            item: r.item,
+            message: r.message,
+        })
+        .collect())
+}
+
+#[async_trait]
+impl JobRepository for PostgresJobRepository {
+    async fn create(&self, job: Job) -> Result<Job, JobError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO jobs (id, kind, owner_id, status, progress, result_url, error, item_errors, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            job.id.0,
+            job.kind.as_str(),
+            job.owner_id.0,
+            job.status.as_str(),
+            job.progress.value(),
+            job.result_url,
+            job.error,
+            item_errors_to_json(&job.item_errors)?,
+            job.created_at,
+            job.updated_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| JobError::DatabaseError(e.to_string()))?;
+
+        Ok(job)
+    }
+
+    async fn find_by_id(&self, id: &JobId) -> Result<Option<Job>, JobError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, kind, owner_id, status, progress, result_url, error, item_errors, created_at, updated_at
+            FROM jobs
+            WHERE id = $1
+            "#,
+            id.0,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| JobError::DatabaseError(e.to_string()))?;
+
+        match row {
+            Some(r) => Ok(Some(Job {
+                id: JobId(r.id),
+                kind: JobKind::new(r.kind),
+                owner_id: UserId(r.owner_id),
+                status: r.status.parse()?,
+                progress: JobProgress::new(r.progress)?,
+                result_url: r.result_url,
+                error: r.error,
+                item_errors: item_errors_from_json(r.item_errors)?,
+                created_at: r.created_at,
+                updated_at: r.updated_at,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    async fn update(&self, job: Job) -> Result<Job, JobError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE jobs
+            SET status = $2, progress = $3, result_url = $4, error = $5, item_errors = $6, updated_at = $7
+            WHERE id = $1
+            "#,
+            job.id.0,
+            job.status.as_str(),
+            job.progress.value(),
+            job.result_url,
+            job.error,
+            item_errors_to_json(&job.item_errors)?,
+            job.updated_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| JobError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(JobError::NotFound(job.id.to_string()));
+        }
+
+        Ok(job)
+    }
+}
@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use sqlx::PgPool;
+
+use crate::domain::lockout::errors::LockoutError;
+use crate::domain::lockout::models::LoginAttempts;
+use crate::domain::lockout::ports::LoginAttemptRepository;
+
+pub struct PostgresLoginAttemptRepository {
+    pool: PgPool,
+}
+
+impl PostgresLoginAttemptRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepository for PostgresLoginAttemptRepository {
+    async fn find(&self, key: &str) -> Result<Option<LoginAttempts>, LockoutError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT subject, failed_attempts, locked_until
+            FROM login_attempts
+            WHERE subject = $1
+            "#,
+            key,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| LockoutError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(|r| LoginAttempts {
+            key: r.subject,
+            failed_attempts: u32::try_from(r.failed_attempts).unwrap_or_default(),
+            locked_until: r.locked_until,
+        }))
+    }
+
+    async fn record_failure(
+        &self,
+        key: &str,
+        at: DateTime<Utc>,
+    ) -> Result<LoginAttempts, LockoutError> {
+        // An expired lock is cleared and the count restarts with this failure
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO login_attempts (subject, failed_attempts, last_failed_at)
+            VALUES ($1, 1, $2)
+            ON CONFLICT (subject) DO UPDATE SET
+                failed_attempts = CASE
+                    WHEN login_attempts.locked_until <= EXCLUDED.last_failed_at THEN 1
+                    ELSE login_attempts.failed_attempts + 1
+                END,
+                locked_until = CASE
+                    WHEN login_attempts.locked_until <= EXCLUDED.last_failed_at THEN NULL
+                    ELSE login_attempts.locked_until
+                END,
+                last_failed_at = EXCLUDED.last_failed_at
+            RETURNING subject, failed_attempts, locked_until
+            "#,
+            key,
+            at
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| LockoutError::DatabaseError(e.to_string()))?;
+
+        Ok(LoginAttempts {
+            key: row.subject,
+            failed_attempts: u32::try_from(row.failed_attempts).unwrap_or_default(),
+            locked_until: row.locked_until,
+        })
+    }
+
+    async fn lock(&self, key: &str, until: DateTime<Utc>) -> Result<bool, LockoutError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE login_attempts
+            SET locked_until = $2
+            WHERE subject = $1 AND locked_until IS NULL
+            "#,
+            key,
+            until
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| LockoutError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), LockoutError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM login_attempts
+            WHERE subject = $1
+            "#,
+            key,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| LockoutError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::account_link::models::AuthMethodKind;
+use crate::domain::login_history::errors::LoginHistoryError;
+use crate::domain::login_history::models::LoginHistory;
+use crate::domain::login_history::models::LoginRecord;
+use crate::domain::login_history::ports::LoginHistoryRepository;
+use crate::domain::user::models::UserId;
+
+pub struct PostgresLoginHistoryRepository {
+    pool: PgPool,
+}
+
+impl PostgresLoginHistoryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoginHistoryRepository for PostgresLoginHistoryRepository {
+    async fn record(&self, record: &LoginRecord) -> Result<(), LoginHistoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| LoginHistoryError::DatabaseError(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO login_history (user_id, method, logged_in_at, ip_address, user_agent)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            record.user_id.0,
+            record.method.as_str(),
+            record.logged_in_at,
+            record.ip_address.map(|ip| ip.to_string()),
+            record.user_agent
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| LoginHistoryError::DatabaseError(e.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET last_login_at = $2
+            WHERE id = $1
+            "#,
+            record.user_id.0,
+            record.logged_in_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| LoginHistoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| LoginHistoryError::DatabaseError(e.to_string()))
+    }
+
+    async fn find_history(
+        &self,
+        user_id: &UserId,
+        limit: u32,
+    ) -> Result<Option<LoginHistory>, LoginHistoryError> {
+        let Some(user) = sqlx::query!(
+            r#"
+            SELECT last_login_at
+            FROM users
+            WHERE id = $1 AND status <> 'deleted'
+            "#,
+            user_id.0,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| LoginHistoryError::DatabaseError(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT method, logged_in_at, ip_address, user_agent
+            FROM login_history
+            WHERE user_id = $1
+            ORDER BY logged_in_at DESC, id DESC
+            LIMIT $2
+            "#,
+            user_id.0,
+            i64::from(limit)
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| LoginHistoryError::DatabaseError(e.to_string()))?;
+
+        let logins = rows
+            .into_iter()
+            .map(|r| {
+                Ok(LoginRecord {
+                    user_id: *user_id,
+                    method: r
+                        .method
+                        .parse::<AuthMethodKind>()
+                        .map_err(|e| LoginHistoryError::DatabaseError(e.to_string()))?,
+                    logged_in_at: r.logged_in_at,
+                    // Written from an `IpAddr`, so anything else is dropped
+                    ip_address: r.ip_address.and_then(|ip| ip.parse().ok()),
+                    user_agent: r.user_agent,
+                })
+            })
+            .collect::<Result<Vec<_>, LoginHistoryError>>()?;
+
+        Ok(Some(LoginHistory {
+            last_login_at: user.last_login_at,
+            logins,
+        }))
+    }
+}
@@ -0,0 +1,27 @@
+use std::future::Future;
+use std::time::Instant;
+
+/// Latency of PostgreSQL queries, by query and outcome.
+pub const DB_QUERY_DURATION_SECONDS: &str = "db_query_duration_seconds";
+
+/// Await a database query, recording its latency.
+///
+/// # Arguments
+/// * `query` - Name of the query in metrics, `<table>.<operation>`
+/// * `future` - Query to run
+///
+/// # Returns
+/// Result of the query
+pub async fn timed<T, E>(
+    query: &'static str,
+    future: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    let start = Instant::now();
+    let result = future.await;
+
+    let outcome = if result.is_ok() { "success" } else { "error" };
+    metrics::histogram!(DB_QUERY_DURATION_SECONDS, "query" => query, "outcome" => outcome)
+        .record(start.elapsed().as_secs_f64());
+
+    result
+}
@@ -1,3 +1,26 @@
+pub mod account_link;
+pub mod audit;
+pub mod ceremony;
+pub mod health;
+pub mod idempotency;
+pub mod job;
+pub mod login_attempt;
+pub mod login_history;
+pub mod metrics;
+pub mod outbox;
+pub mod passkey;
+pub mod personal_token;
 pub mod user;
 
+pub use account_link::PostgresAccountLinkRepository;
+pub use audit::PostgresAuditLogger;
+pub use ceremony::InMemoryCeremonyStore;
+pub use health::PostgresHealthCheck;
+pub use idempotency::PostgresIdempotencyRepository;
+pub use job::PostgresJobRepository;
+pub use login_attempt::PostgresLoginAttemptRepository;
+pub use login_history::PostgresLoginHistoryRepository;
+pub use outbox::PostgresOutboxRepository;
+pub use passkey::PostgresPasskeyRepository;
+pub use personal_token::PostgresPersonalTokenRepository;
 pub use user::PostgresUserRepository;
@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use sqlx::PgConnection;
+use sqlx::PgPool;
+
+use crate::domain::outbox::errors::OutboxError;
+use crate::domain::outbox::models::OutboxMessage;
+use crate::domain::outbox::ports::OutboxRepository;
+use crate::domain::user::errors::UserError;
+use crate::domain::user::events::UserEvent;
+use crate::outbound::events::messages::UserEventMessage;
+
+pub struct PostgresOutboxRepository {
+    pool: PgPool,
+}
+
+impl PostgresOutboxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Store a user event in the outbox, as part of the transaction of its change.
+///
+/// The event is stored as the message published to Kafka, keyed by its user.
+pub(crate) async fn enqueue(conn: &mut PgConnection, event: UserEvent) -> Result<(), UserError> {
+    let key = event.user_id().to_string();
+    let event_type = event.event_type().to_string();
+    let payload = serde_json::to_value(UserEventMessage::from(event))
+        .map_err(|e| UserError::Unknown(format!("Failed to serialize event: {}", e)))?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO user_outbox (message_key, event_type, payload, created_at, next_attempt_at)
+        VALUES ($1, $2, $3, $4, $4)
+        "#,
+        key,
+        event_type,
+        payload,
+        Utc::now()
+    )
+    .execute(conn)
+    .await
+    .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl OutboxRepository for PostgresOutboxRepository {
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        claimed_until: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<OutboxMessage>, OutboxError> {
+        // Messages behind an unpublished one of the same key wait for it
+        let mut rows = sqlx::query!(
+            r#"
+            UPDATE user_outbox
+            SET next_attempt_at = $2
+            WHERE sequence IN (
+                SELECT pending.sequence
+                FROM user_outbox pending
+                WHERE pending.next_attempt_at <= $1
+                  AND NOT EXISTS (
+                      SELECT 1
+                      FROM user_outbox earlier
+                      WHERE earlier.message_key = pending.message_key
+                        AND earlier.sequence < pending.sequence
+                  )
+                ORDER BY pending.sequence
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING sequence, message_key, payload::TEXT AS "payload!", attempts
+            "#,
+            now,
+            claimed_until,
+            i64::from(limit)
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| OutboxError::DatabaseError(e.to_string()))?;
+
+        rows.sort_by_key(|r| r.sequence);
+        Ok(rows
+            .into_iter()
+            .map(|r| OutboxMessage {
+                sequence: r.sequence,
+                key: r.message_key,
+                payload: r.payload,
+                attempts: u32::try_from(r.attempts).unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    async fn remove(&self, sequence: i64) -> Result<(), OutboxError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM user_outbox
+            WHERE sequence = $1
+            "#,
+            sequence
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| OutboxError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn schedule_retry(
+        &self,
+        sequence: i64,
+        attempts: u32,
+        retry_at: DateTime<Utc>,
+        error: &str,
+    ) -> Result<(), OutboxError> {
+        sqlx::query!(
+            r#"
+            UPDATE user_outbox
+            SET attempts = $2, next_attempt_at = $3, last_error = $4
+            WHERE sequence = $1
+            "#,
+            sequence,
+            i32::try_from(attempts).unwrap_or(i32::MAX),
+            retry_at,
+            error
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| OutboxError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use webauthn_rs::prelude::Passkey;
+
+use crate::domain::passkey::errors::PasskeyError;
+use crate::domain::passkey::models::PasskeyCredential;
+use crate::domain::passkey::models::PasskeyId;
+use crate::domain::passkey::models::PasskeyName;
+use crate::domain::passkey::ports::PasskeyRepository;
+use crate::domain::user::models::UserId;
+
+pub struct PostgresPasskeyRepository {
+    pool: PgPool,
+}
+
+impl PostgresPasskeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn passkey_to_json(passkey: &Passkey) -> Result<serde_json::Value, PasskeyError> {
+    serde_json::to_value(passkey).map_err(|e| PasskeyError::DatabaseError(e.to_string()))
+}
+
+fn passkey_from_json(value: serde_json::Value) -> Result<Passkey, PasskeyError> {
+    serde_json::from_value(value).map_err(|e| PasskeyError::DatabaseError(e.to_string()))
+}
+
+#[async_trait]
+impl PasskeyRepository for PostgresPasskeyRepository {
+    async fn create(
+        &self,
+        credential: PasskeyCredential,
+    ) -> Result<PasskeyCredential, PasskeyError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO passkeys (id, user_id, credential_id, name, credential, created_at, last_used_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            credential.id.0,
+            credential.user_id.0,
+            credential.passkey.cred_id().as_slice(),
+            credential.name.as_str(),
+            passkey_to_json(&credential.passkey)?,
+            credential.created_at,
+            credential.last_used_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if let sqlx::Error::Database(db_err) = &e {
+                if db_err.constraint() == Some("passkeys_credential_id_key") {
+                    return PasskeyError::AlreadyRegistered;
+                }
+            }
+            PasskeyError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(credential)
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PasskeyCredential>, PasskeyError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, user_id, name, credential, created_at, last_used_at
+            FROM passkeys
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+            user_id.0,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| PasskeyError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|r| {
+                Ok(PasskeyCredential {
+                    id: PasskeyId(r.id),
+                    user_id: UserId(r.user_id),
+                    name: PasskeyName::new(r.name)?,
+                    passkey: passkey_from_json(r.credential)?,
+                    created_at: r.created_at,
+                    last_used_at: r.last_used_at,
+                })
+            })
+            .collect()
+    }
+
+    async fn update(&self, credential: &PasskeyCredential) -> Result<(), PasskeyError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE passkeys
+            SET credential = $2, last_used_at = $3
+            WHERE id = $1
+            "#,
+            credential.id.0,
+            passkey_to_json(&credential.passkey)?,
+            credential.last_used_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| PasskeyError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(PasskeyError::NotFound(credential.id.to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: &UserId, id: &PasskeyId) -> Result<bool, PasskeyError> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM passkeys
+            WHERE id = $1 AND user_id = $2
+            "#,
+            id.0,
+            user_id.0
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| PasskeyError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
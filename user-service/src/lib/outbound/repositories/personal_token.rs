@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use sqlx::PgPool;
+
+use crate::domain::personal_token::errors::PersonalTokenError;
+use crate::domain::personal_token::models::PersonalToken;
+use crate::domain::personal_token::models::PersonalTokenId;
+use crate::domain::personal_token::models::PersonalTokenName;
+use crate::domain::personal_token::models::PersonalTokenScopes;
+use crate::domain::personal_token::ports::PersonalTokenRepository;
+use crate::domain::user::models::UserId;
+
+pub struct PostgresPersonalTokenRepository {
+    pool: PgPool,
+}
+
+impl PostgresPersonalTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PersonalTokenRepository for PostgresPersonalTokenRepository {
+    async fn create(
+        &self,
+        token: PersonalToken,
+        token_hash: &str,
+    ) -> Result<PersonalToken, PersonalTokenError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO personal_tokens (id, user_id, name, scopes, token_hash, created_at, expires_at, last_used_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            token.id.0,
+            token.user_id.0,
+            token.name.as_str(),
+            token.scopes.as_slice(),
+            token_hash,
+            token.created_at,
+            token.expires_at,
+            token.last_used_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| PersonalTokenError::DatabaseError(e.to_string()))?;
+
+        Ok(token)
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PersonalToken>, PersonalTokenError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, user_id, name, scopes, created_at, expires_at, last_used_at
+            FROM personal_tokens
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+            user_id.0,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| PersonalTokenError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|r| {
+                Ok(PersonalToken {
+                    id: PersonalTokenId(r.id),
+                    user_id: UserId(r.user_id),
+                    name: PersonalTokenName::new(r.name)?,
+                    scopes: PersonalTokenScopes::new(r.scopes)?,
+                    created_at: r.created_at,
+                    expires_at: r.expires_at,
+                    last_used_at: r.last_used_at,
+                })
+            })
+            .collect()
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PersonalToken>, PersonalTokenError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, user_id, name, scopes, created_at, expires_at, last_used_at
+            FROM personal_tokens
+            WHERE token_hash = $1
+            "#,
+            token_hash,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| PersonalTokenError::DatabaseError(e.to_string()))?;
+
+        row.map(|r| {
+            Ok(PersonalToken {
+                id: PersonalTokenId(r.id),
+                user_id: UserId(r.user_id),
+                name: PersonalTokenName::new(r.name)?,
+                scopes: PersonalTokenScopes::new(r.scopes)?,
+                created_at: r.created_at,
+                expires_at: r.expires_at,
+                last_used_at: r.last_used_at,
+            })
+        })
+        .transpose()
+    }
+
+    async fn touch(
+        &self,
+        id: &PersonalTokenId,
+        at: DateTime<Utc>,
+    ) -> Result<(), PersonalTokenError> {
+        sqlx::query!(
+            r#"
+            UPDATE personal_tokens
+            SET last_used_at = $2
+            WHERE id = $1
+            "#,
+            id.0,
+            at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| PersonalTokenError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        user_id: &UserId,
+        id: &PersonalTokenId,
+    ) -> Result<bool, PersonalTokenError> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM personal_tokens
+            WHERE id = $1 AND user_id = $2
+            "#,
+            id.0,
+            user_id.0
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| PersonalTokenError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
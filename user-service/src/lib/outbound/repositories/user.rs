@@ -1,11 +1,21 @@
 use async_trait::async_trait;
 use sqlx::PgPool;
 
+use crate::domain::user::events::UserEvent;
+use crate::domain::user::models::AvatarUrl;
+use crate::domain::user::models::Bio;
+use crate::domain::user::models::DisplayName;
 use crate::domain::user::models::EmailAddress;
+use crate::domain::user::models::Role;
 use crate::domain::user::models::User;
+use crate::domain::user::models::UserCursor;
 use crate::domain::user::models::UserId;
+use crate::domain::user::models::UserSearchQuery;
+use crate::domain::user::models::UserStatus;
 use crate::domain::user::models::Username;
 use crate::domain::user::ports::UserRepository;
+use crate::outbound::repositories::metrics::timed;
+use crate::outbound::repositories::outbox;
 use crate::user::errors::UserError;
 
 pub struct PostgresUserRepository {
@@ -20,21 +30,31 @@ impl PostgresUserRepository {
 
 #[async_trait]
 impl UserRepository for PostgresUserRepository {
-    async fn create(&self, user: User) -> Result<User, UserError> {
-        sqlx::query!(
+    async fn create(&self, user: User, event: UserEvent) -> Result<User, UserError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        let query = sqlx::query!(
             r#"
-            INSERT INTO users (id, username, email, password_hash, created_at)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO users (id, username, email, password_hash, email_verified, display_name, avatar_url, bio, roles, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             "#,
             user.id.0,
             user.username.as_str(),
             user.email.as_str(),
             user.password_hash,
+            user.email_verified,
+            user.display_name.as_ref().map(DisplayName::as_str),
+            user.avatar_url.as_ref().map(AvatarUrl::as_str),
+            user.bio.as_ref().map(Bio::as_str),
+            &role_names(&user.roles),
             user.created_at
         )
-        .execute(&self.pool)
-        .await
-        .map_err(|e| {
+        .execute(&mut *tx);
+        timed("users.create", query).await.map_err(|e| {
             if let Some(db_err) = e.as_database_error() {
                 if db_err.is_unique_violation() {
                     if db_err.constraint() == Some("users_username_key") {
@@ -50,21 +70,27 @@ impl UserRepository for PostgresUserRepository {
             UserError::DatabaseError(e.to_string())
         })?;
 
+        outbox::enqueue(&mut tx, event).await?;
+        tx.commit()
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
         Ok(user)
     }
 
     async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, UserError> {
-        let row = sqlx::query!(
+        let query = sqlx::query!(
             r#"
-            SELECT id, username, email, password_hash, created_at
+            SELECT id, username, email, password_hash, email_verified, display_name, avatar_url, bio, roles, created_at
             FROM users
-            WHERE id = $1
+            WHERE id = $1 AND status = 'active'
             "#,
             id.0,
         )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+        .fetch_optional(&self.pool);
+        let row = timed("users.find_by_id", query)
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
 
         match row {
             Some(r) => Ok(Some(User {
@@ -72,6 +98,15 @@ impl UserRepository for PostgresUserRepository {
                 username: Username::new(r.username)?,
                 email: EmailAddress::new(r.email)?,
                 password_hash: r.password_hash,
+                email_verified: r.email_verified,
+                display_name: r
+                    .display_name
+                    .as_deref()
+                    .map(DisplayName::new)
+                    .transpose()?,
+                avatar_url: r.avatar_url.as_deref().map(AvatarUrl::new).transpose()?,
+                bio: r.bio.as_deref().map(Bio::new).transpose()?,
+                roles: parse_roles(&r.roles),
                 created_at: r.created_at,
             })),
             None => Ok(None),
@@ -79,17 +114,18 @@ impl UserRepository for PostgresUserRepository {
     }
 
     async fn find_by_username(&self, username: &Username) -> Result<Option<User>, UserError> {
-        let row = sqlx::query!(
+        let query = sqlx::query!(
             r#"
-            SELECT id, username, email, password_hash, created_at
+            SELECT id, username, email, password_hash, email_verified, display_name, avatar_url, bio, roles, created_at
             FROM users
-            WHERE username = $1
+            WHERE username = $1 AND status = 'active'
             "#,
             username.as_str(),
         )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+        .fetch_optional(&self.pool);
+        let row = timed("users.find_by_username", query)
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
 
         match row {
             Some(r) => Ok(Some(User {
@@ -97,6 +133,15 @@ impl UserRepository for PostgresUserRepository {
                 username: Username::new(r.username)?,
                 email: EmailAddress::new(r.email)?,
                 password_hash: r.password_hash,
+                email_verified: r.email_verified,
+                display_name: r
+                    .display_name
+                    .as_deref()
+                    .map(DisplayName::new)
+                    .transpose()?,
+                avatar_url: r.avatar_url.as_deref().map(AvatarUrl::new).transpose()?,
+                bio: r.bio.as_deref().map(Bio::new).transpose()?,
+                roles: parse_roles(&r.roles),
                 created_at: r.created_at,
             })),
             None => Ok(None),
@@ -104,17 +149,18 @@ impl UserRepository for PostgresUserRepository {
     }
 
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, UserError> {
-        let row = sqlx::query!(
+        let query = sqlx::query!(
             r#"
-            SELECT id, username, email, password_hash, created_at
+            SELECT id, username, email, password_hash, email_verified, display_name, avatar_url, bio, roles, created_at
             FROM users
-            WHERE email = $1
+            WHERE email = $1 AND status = 'active'
             "#,
             email,
         )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+        .fetch_optional(&self.pool);
+        let row = timed("users.find_by_email", query)
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
 
         match row {
             Some(r) => Ok(Some(User {
@@ -122,23 +168,45 @@ impl UserRepository for PostgresUserRepository {
                 username: Username::new(r.username)?,
                 email: EmailAddress::new(r.email)?,
                 password_hash: r.password_hash,
+                email_verified: r.email_verified,
+                display_name: r
+                    .display_name
+                    .as_deref()
+                    .map(DisplayName::new)
+                    .transpose()?,
+                avatar_url: r.avatar_url.as_deref().map(AvatarUrl::new).transpose()?,
+                bio: r.bio.as_deref().map(Bio::new).transpose()?,
+                roles: parse_roles(&r.roles),
                 created_at: r.created_at,
             })),
             None => Ok(None),
         }
     }
 
-    async fn list_all(&self) -> Result<Vec<User>, UserError> {
-        let rows = sqlx::query!(
+    async fn list_page(
+        &self,
+        status: UserStatus,
+        limit: u32,
+        after: Option<UserCursor>,
+    ) -> Result<Vec<User>, UserError> {
+        let query = sqlx::query!(
             r#"
-            SELECT id, username, email, password_hash, created_at
+            SELECT id, username, email, password_hash, email_verified, display_name, avatar_url, bio, roles, created_at
             FROM users
-            ORDER BY created_at DESC
+            WHERE status = $1
+                AND ($2::timestamptz IS NULL OR (created_at, id) < ($2, $3))
+            ORDER BY created_at DESC, id DESC
+            LIMIT $4
             "#,
+            status.as_str(),
+            after.map(|cursor| cursor.created_at),
+            after.map(|cursor| cursor.id.0),
+            i64::from(limit)
         )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+        .fetch_all(&self.pool);
+        let rows = timed("users.list_page", query)
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
 
         rows.into_iter()
             .map(|r| {
@@ -147,6 +215,56 @@ impl UserRepository for PostgresUserRepository {
                     username: Username::new(r.username)?,
                     email: EmailAddress::new(r.email)?,
                     password_hash: r.password_hash,
+                    email_verified: r.email_verified,
+                    display_name: r
+                        .display_name
+                        .as_deref()
+                        .map(DisplayName::new)
+                        .transpose()?,
+                    avatar_url: r.avatar_url.as_deref().map(AvatarUrl::new).transpose()?,
+                    bio: r.bio.as_deref().map(Bio::new).transpose()?,
+                    roles: parse_roles(&r.roles),
+                    created_at: r.created_at,
+                })
+            })
+            .collect()
+    }
+
+    async fn search(&self, query: &UserSearchQuery, limit: u32) -> Result<Vec<User>, UserError> {
+        let query = sqlx::query!(
+            r#"
+            SELECT id, username, email, password_hash, email_verified, display_name, avatar_url, bio, roles, created_at
+            FROM users
+            WHERE status = 'active'
+                AND (username ILIKE $1 OR username % $2)
+            ORDER BY (username ILIKE $1) DESC, similarity(username, $2) DESC, username
+            LIMIT $3
+            "#,
+            format!("{}%", escape_like(query.as_str())),
+            query.as_str(),
+            i64::from(limit)
+        )
+        .fetch_all(&self.pool);
+        let rows = timed("users.search", query)
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|r| {
+                Ok(User {
+                    id: UserId(r.id),
+                    username: Username::new(r.username)?,
+                    email: EmailAddress::new(r.email)?,
+                    password_hash: r.password_hash,
+                    email_verified: r.email_verified,
+                    display_name: r
+                        .display_name
+                        .as_deref()
+                        .map(DisplayName::new)
+                        .transpose()?,
+                    avatar_url: r.avatar_url.as_deref().map(AvatarUrl::new).transpose()?,
+                    bio: r.bio.as_deref().map(Bio::new).transpose()?,
+                    roles: parse_roles(&r.roles),
                     created_at: r.created_at,
                 })
             })
@@ -156,17 +274,18 @@ impl UserRepository for PostgresUserRepository {
     async fn find_by_ids(&self, ids: &[UserId]) -> Result<Vec<User>, UserError> {
         let uuids: Vec<_> = ids.iter().map(|id| id.0).collect();
 
-        let rows = sqlx::query!(
+        let query = sqlx::query!(
             r#"
-            SELECT id, username, email, password_hash, created_at
+            SELECT id, username, email, password_hash, email_verified, display_name, avatar_url, bio, roles, created_at
             FROM users
-            WHERE id = ANY($1)
+            WHERE id = ANY($1) AND status = 'active'
             "#,
             &uuids
         )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+        .fetch_all(&self.pool);
+        let rows = timed("users.find_by_ids", query)
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
 
         rows.into_iter()
             .map(|r| {
@@ -175,27 +294,47 @@ impl UserRepository for PostgresUserRepository {
                     username: Username::new(r.username)?,
                     email: EmailAddress::new(r.email)?,
                     password_hash: r.password_hash,
+                    email_verified: r.email_verified,
+                    display_name: r
+                        .display_name
+                        .as_deref()
+                        .map(DisplayName::new)
+                        .transpose()?,
+                    avatar_url: r.avatar_url.as_deref().map(AvatarUrl::new).transpose()?,
+                    bio: r.bio.as_deref().map(Bio::new).transpose()?,
+                    roles: parse_roles(&r.roles),
                     created_at: r.created_at,
                 })
             })
             .collect()
     }
 
-    async fn update(&self, user: User) -> Result<User, UserError> {
-        let result = sqlx::query!(
+    async fn update(&self, user: User, event: Option<UserEvent>) -> Result<User, UserError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        let query = sqlx::query!(
             r#"
             UPDATE users
-            SET username = $2, email = $3, password_hash = $4
-            WHERE id = $1
+            SET username = $2, email = $3, password_hash = $4, email_verified = $5,
+                display_name = $6, avatar_url = $7, bio = $8, roles = $9
+            WHERE id = $1 AND status = 'active'
             "#,
             user.id.0,
             user.username.as_str(),
             user.email.as_str(),
-            user.password_hash
+            user.password_hash,
+            user.email_verified,
+            user.display_name.as_ref().map(DisplayName::as_str),
+            user.avatar_url.as_ref().map(AvatarUrl::as_str),
+            user.bio.as_ref().map(Bio::as_str),
+            &role_names(&user.roles)
         )
-        .execute(&self.pool)
-        .await
-        .map_err(|e| {
+        .execute(&mut *tx);
+        let result = timed("users.update", query).await.map_err(|e| {
             //TODO: check with claude
             if let Some(db_err) = e.as_database_error() {
                 if db_err.is_unique_violation() {
@@ -216,25 +355,120 @@ impl UserRepository for PostgresUserRepository {
             return Err(UserError::NotFound(user.id.to_string()));
         }
 
+        if let Some(event) = event {
+            outbox::enqueue(&mut tx, event).await?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
         Ok(user)
     }
 
-    async fn delete(&self, id: &UserId) -> Result<(), UserError> {
-        let result = sqlx::query!(
+    async fn update_status(
+        &self,
+        id: &UserId,
+        from: UserStatus,
+        to: UserStatus,
+        event: UserEvent,
+    ) -> Result<(), UserError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        let query = sqlx::query!(
             r#"
-            DELETE FROM users
-            WHERE id = $1
+            UPDATE users
+            SET status = $3
+            WHERE id = $1 AND status = $2
+            "#,
+            id.0,
+            from.as_str(),
+            to.as_str()
+        )
+        .execute(&mut *tx);
+        let result = timed("users.update_status", query)
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected() > 0 {
+            outbox::enqueue(&mut tx, event).await?;
+            return tx
+                .commit()
+                .await
+                .map_err(|e| UserError::DatabaseError(e.to_string()));
+        }
+        drop(tx);
+
+        let query = sqlx::query_scalar!(
+            r#"
+            SELECT status FROM users WHERE id = $1 AND status <> 'deleted'
+            "#,
+            id.0
+        )
+        .fetch_optional(&self.pool);
+        let current = timed("users.update_status", query)
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        // The user exists but is not `from`, e.g. it already has status `to`
+        match current.as_deref().and_then(UserStatus::parse) {
+            Some(status) => Err(UserError::StatusUnchanged {
+                id: id.to_string(),
+                status,
+            }),
+            None => Err(UserError::NotFound(id.to_string())),
+        }
+    }
+
+    async fn delete(&self, id: &UserId, event: UserEvent) -> Result<(), UserError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
+        let query = sqlx::query!(
+            r#"
+            UPDATE users
+            SET status = 'deleted', deleted_at = NOW()
+            WHERE id = $1 AND status <> 'deleted'
             "#,
             id.0,
         )
-        .execute(&self.pool)
-        .await
-        .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+        .execute(&mut *tx);
+        let result = timed("users.delete", query)
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
 
         if result.rows_affected() == 0 {
             return Err(UserError::NotFound(id.to_string()));
         }
 
+        outbox::enqueue(&mut tx, event).await?;
+        tx.commit()
+            .await
+            .map_err(|e| UserError::DatabaseError(e.to_string()))?;
+
         Ok(())
     }
 }
+
+/// Parse the stored role names, skipping roles this version does not know.
+fn parse_roles(roles: &[String]) -> Vec<Role> {
+    roles.iter().filter_map(|role| Role::parse(role)).collect()
+}
+
+fn role_names(roles: &[Role]) -> Vec<String> {
+    roles.iter().map(|role| role.as_str().to_string()).collect()
+}
+
+/// Escape the LIKE wildcards of user input, so it only matches literally.
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
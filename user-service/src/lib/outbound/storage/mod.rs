@@ -0,0 +1,4 @@
+pub mod s3;
+
+pub use s3::S3ObjectStorage;
+pub use s3::S3Settings;
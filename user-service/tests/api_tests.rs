@@ -1,8 +1,18 @@
 mod common;
 
+use std::sync::Arc;
+
+use auth::Authenticator;
+use auth::Claims;
+use auth::OneTimeToken;
 use common::TestApp;
 use reqwest::StatusCode;
 use serde_json::json;
+use user_service::domain::job::models::JobKind;
+use user_service::domain::job::ports::JobServicePort;
+use user_service::domain::job::service::JobService;
+use user_service::domain::user::models::UserId;
+use user_service::outbound::repositories::job::PostgresJobRepository;
 
 #[tokio::test]
 async fn test_create_user_success() {
@@ -100,6 +110,52 @@ async fn test_create_user_duplicate_email() {
         .contains("already exists"));
 }
 
+#[tokio::test]
+async fn test_create_user_retried_with_idempotency_key() {
+    let app = TestApp::spawn().await;
+    let signup = json!({
+        "username": "nicola",
+        "email_address": "nicola@example.com",
+        "password": "pass_word!"
+    });
+
+    let first = app
+        .post("/api/users")
+        .header("Idempotency-Key", "signup-7d3f")
+        .json(&signup)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(first.status(), StatusCode::CREATED);
+    let first: serde_json::Value = first.json().await.expect("Failed to parse response");
+
+    // Retry after a lost response gets the same user instead of a conflict
+    let retry = app
+        .post("/api/users")
+        .header("Idempotency-Key", "signup-7d3f")
+        .json(&signup)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(retry.status(), StatusCode::CREATED);
+    let retry: serde_json::Value = retry.json().await.expect("Failed to parse response");
+    assert_eq!(retry["data"]["id"], first["data"]["id"]);
+
+    // The key cannot be reused for another account
+    let reused = app
+        .post("/api/users")
+        .header("Idempotency-Key", "signup-7d3f")
+        .json(&json!({
+            "username": "nicola2",
+            "email_address": "nicola2@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(reused.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
 #[tokio::test]
 async fn test_create_user_invalid_username() {
     let app = TestApp::spawn().await;
@@ -184,6 +240,62 @@ async fn test_authenticate_success() {
     assert_eq!(body["data"]["user"]["email"], "nicola@example.com");
 }
 
+#[tokio::test]
+async fn test_authenticate_by_email() {
+    let app = TestApp::spawn().await;
+
+    app.post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "email": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["user"]["username"], "nicola");
+
+    // Unknown accounts are indistinguishable from a wrong password
+    let wrong_password = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "identifier": "nicola@example.com",
+            "password": "Wrong_Password!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let unknown_email = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "identifier": "nobody@example.com",
+            "password": "Wrong_Password!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(wrong_password.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(unknown_email.status(), StatusCode::UNAUTHORIZED);
+    let wrong_password: serde_json::Value = wrong_password.json().await.unwrap();
+    let unknown_email: serde_json::Value = unknown_email.json().await.unwrap();
+    assert_eq!(wrong_password, unknown_email);
+}
+
 #[tokio::test]
 async fn test_authenticate_wrong_password() {
     let app = TestApp::spawn().await;
@@ -216,6 +328,59 @@ async fn test_authenticate_wrong_password() {
     assert!(body["data"]["message"].is_string());
 }
 
+#[tokio::test]
+async fn test_authenticate_locks_account_after_failed_attempts() {
+    let app = TestApp::spawn().await;
+
+    app.post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "Correct_Password!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    // The default limit is 5 failed logins in a row
+    for attempt in 1..=5 {
+        let response = app
+            .post("/api/auth/login")
+            .json(&json!({
+                "username": "nicola",
+                "password": "Wrong_Password!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        let expected = if attempt < 5 {
+            StatusCode::UNAUTHORIZED
+        } else {
+            StatusCode::LOCKED
+        };
+        assert_eq!(response.status(), expected);
+    }
+
+    // Locked accounts refuse even the correct password
+    let response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "nicola",
+            "password": "Correct_Password!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::LOCKED);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body["data"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("too many failed logins"));
+}
+
 #[tokio::test]
 async fn test_authenticate_nonexistent_user() {
     let app = TestApp::spawn().await;
@@ -237,10 +402,173 @@ async fn test_authenticate_nonexistent_user() {
 }
 
 #[tokio::test]
-async fn test_get_user_by_id() {
+async fn test_locked_and_unknown_accounts_answer_alike() {
+    let app = TestApp::spawn().await;
+
+    app.post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "Correct_Password!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let mut responses = Vec::new();
+    for username in ["nicola", "nonexistent"] {
+        let mut answers = Vec::new();
+        // One more than the default limit of 5 failed logins in a row
+        for _ in 0..6 {
+            let response = app
+                .post("/api/auth/login")
+                .json(&json!({
+                    "username": username,
+                    "password": "Wrong_Password!"
+                }))
+                .send()
+                .await
+                .expect("Failed to execute request");
+
+            let status = response.status();
+            let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+            answers.push((status, body));
+        }
+        responses.push(answers);
+    }
+
+    assert_eq!(responses[0], responses[1]);
+    assert_eq!(responses[1][5].0, StatusCode::LOCKED);
+}
+
+#[tokio::test]
+async fn test_logins_are_recorded_in_history() {
+    let app = TestApp::spawn().await;
+
+    let mut tokens = Vec::new();
+    let mut user_ids = Vec::new();
+    for username in ["alice", "bob"] {
+        let create_response = app
+            .post("/api/users")
+            .json(&json!({
+                "username": username,
+                "email_address": format!("{}@example.com", username),
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let create_body: serde_json::Value = create_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        user_ids.push(create_body["data"]["id"].as_str().unwrap().to_string());
+
+        let auth_response = app
+            .post("/api/auth/login")
+            .header("User-Agent", "history-test/1.0")
+            .json(&json!({
+                "username": username,
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let auth_body: serde_json::Value = auth_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        tokens.push(auth_body["data"]["token"].as_str().unwrap().to_string());
+    }
+    let alice_logins = format!("/api/users/{}/logins", user_ids[0]);
+
+    let response = app
+        .get_authenticated(&alice_logins, &tokens[0])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let logins = body["data"]["items"].as_array().unwrap();
+    assert_eq!(logins.len(), 1);
+    assert_eq!(logins[0]["method"], "password");
+    assert_eq!(logins[0]["user_agent"], "history-test/1.0");
+    assert_eq!(body["data"]["last_login_at"], logins[0]["logged_in_at"]);
+
+    // Only the owner and admins see the logins of an account
+    let response = app
+        .get_authenticated(&alice_logins, &tokens[1])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_user_data_export() {
+    let app = TestApp::spawn().await;
+
+    let mut tokens = Vec::new();
+    let mut user_ids = Vec::new();
+    for username in ["alice", "bob"] {
+        let create_response = app
+            .post("/api/users")
+            .json(&json!({
+                "username": username,
+                "email_address": format!("{}@example.com", username),
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let create_body: serde_json::Value = create_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        user_ids.push(create_body["data"]["id"].as_str().unwrap().to_string());
+
+        let auth_response = app
+            .post("/api/auth/login")
+            .json(&json!({
+                "username": username,
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let auth_body: serde_json::Value = auth_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        tokens.push(auth_body["data"]["token"].as_str().unwrap().to_string());
+    }
+    let alice_export = format!("/api/users/{}/export", user_ids[0]);
+
+    let response = app
+        .get_authenticated(&alice_export, &tokens[0])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["profile"]["username"], "alice");
+    assert_eq!(body["data"]["logins"]["items"].as_array().unwrap().len(), 1);
+    assert_eq!(body["data"]["auth_methods"][0]["kind"], "password");
+    assert!(body["data"]["profile"].get("password_hash").is_none());
+
+    // Only the owner and admins may export an account
+    let response = app
+        .get_authenticated(&alice_export, &tokens[1])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_logout_revokes_token() {
     let app = TestApp::spawn().await;
 
-    // Create a user
     let create_response = app
         .post("/api/users")
         .json(&json!({
@@ -251,14 +579,77 @@ async fn test_get_user_by_id() {
         .send()
         .await
         .expect("Failed to execute request");
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let user_id = create_body["data"]["id"].as_str().unwrap();
+
+    let login = || async {
+        let auth_response = app
+            .post("/api/auth/login")
+            .json(&json!({
+                "username": "nicola",
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let auth_body: serde_json::Value = auth_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        auth_body["data"]["token"].as_str().unwrap().to_string()
+    };
+    let token = login().await;
+    let other_token = login().await;
+
+    let response = app
+        .post_authenticated("/api/auth/logout", &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(response.headers()["clear-site-data"], "\"cookies\"");
+
+    // The logged out token is rejected, other logins are unaffected
+    let response = app
+        .get_authenticated(&format!("/api/users/{}", user_id), &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["code"], "token_revoked");
+
+    let response = app
+        .get_authenticated(&format!("/api/users/{}", user_id), &other_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_refresh_token() {
+    let app = TestApp::spawn().await;
 
+    let create_response = app
+        .post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
     let create_body: serde_json::Value = create_response
         .json()
         .await
         .expect("Failed to parse response");
     let user_id = create_body["data"]["id"].as_str().unwrap();
 
-    // Authenticate to get token
     let auth_response = app
         .post("/api/auth/login")
         .json(&json!({
@@ -268,34 +659,47 @@ async fn test_get_user_by_id() {
         .send()
         .await
         .expect("Failed to execute request");
-
     let auth_body: serde_json::Value = auth_response
         .json()
         .await
         .expect("Failed to parse response");
-    let token = auth_body["data"]["token"].as_str().unwrap();
+    let refresh_token = auth_body["data"]["refresh_token"].as_str().unwrap();
 
-    // Get user by ID
     let response = app
-        .get_authenticated(&format!("/api/users/{}", user_id), token)
+        .post("/api/auth/refresh")
+        .json(&json!({ "refresh_token": refresh_token }))
         .send()
         .await
         .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["user"]["id"], user_id);
+    assert_ne!(body["data"]["refresh_token"], refresh_token);
+    let token = body["data"]["token"].as_str().unwrap();
 
+    let response = app
+        .get_authenticated(&format!("/api/users/{}", user_id), token)
+        .send()
+        .await
+        .expect("Failed to execute request");
     assert_eq!(response.status(), StatusCode::OK);
 
-    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
-    assert_eq!(body["data"]["id"], user_id);
-    assert_eq!(body["data"]["username"], "nicola");
-    assert_eq!(body["data"]["email"], "nicola@example.com");
+    // Each refresh token works once
+    let response = app
+        .post("/api/auth/refresh")
+        .json(&json!({ "refresh_token": refresh_token }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 }
 
 #[tokio::test]
-async fn test_get_user_not_found() {
+async fn test_expired_access_token_is_refreshed() {
     let app = TestApp::spawn().await;
 
-    // Create a user and get token
-    app.post("/api/users")
+    let create_response = app
+        .post("/api/users")
         .json(&json!({
             "username": "nicola",
             "email_address": "nicola@example.com",
@@ -304,6 +708,12 @@ async fn test_get_user_not_found() {
         .send()
         .await
         .expect("Failed to execute request");
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let user_id = create_body["data"]["id"].as_str().unwrap();
+    let path = format!("/api/users/{}", user_id);
 
     let auth_response = app
         .post("/api/auth/login")
@@ -314,32 +724,44 @@ async fn test_get_user_not_found() {
         .send()
         .await
         .expect("Failed to execute request");
-
     let auth_body: serde_json::Value = auth_response
         .json()
         .await
         .expect("Failed to parse response");
     let token = auth_body["data"]["token"].as_str().unwrap();
+    let refresh_token = auth_body["data"]["refresh_token"].as_str().unwrap();
 
-    // Try to get non-existent user
-    let fake_uuid = uuid::Uuid::new_v4().to_string();
+    // Access tokens live for 24 hours in the test configuration
+    app.clock.advance(chrono::Duration::hours(25));
     let response = app
-        .get_authenticated(&format!("/api/users/{}", fake_uuid), token)
+        .get_authenticated(&path, token)
         .send()
         .await
         .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 
-    assert_eq!(response.status(), StatusCode::NOT_FOUND);
-
+    let response = app
+        .post("/api/auth/refresh")
+        .json(&json!({ "refresh_token": refresh_token }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
     let body: serde_json::Value = response.json().await.expect("Failed to parse response");
-    assert!(body["data"]["message"].is_string());
+    let token = body["data"]["token"].as_str().unwrap();
+
+    let response = app
+        .get_authenticated(&path, token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
 }
 
 #[tokio::test]
-async fn test_full_user_workflow() {
+async fn test_change_password() {
     let app = TestApp::spawn().await;
 
-    // 1. Create user
     let create_response = app
         .post("/api/users")
         .json(&json!({
@@ -350,17 +772,13 @@ async fn test_full_user_workflow() {
         .send()
         .await
         .expect("Failed to execute request");
-
-    assert_eq!(create_response.status(), StatusCode::CREATED);
-
     let create_body: serde_json::Value = create_response
         .json()
         .await
         .expect("Failed to parse response");
     let user_id = create_body["data"]["id"].as_str().unwrap().to_string();
 
-    // 2. Login
-    let login_response = app
+    let auth_response = app
         .post("/api/auth/login")
         .json(&json!({
             "username": "nicola",
@@ -369,18 +787,1251 @@ async fn test_full_user_workflow() {
         .send()
         .await
         .expect("Failed to execute request");
-
-    assert_eq!(login_response.status(), StatusCode::OK);
-
-    let login_body: serde_json::Value = login_response
+    let auth_body: serde_json::Value = auth_response
         .json()
         .await
         .expect("Failed to parse response");
-    let token = login_body["data"]["token"].as_str().unwrap().to_string();
+    let token = auth_body["data"]["token"].as_str().unwrap().to_string();
+    let refresh_token = auth_body["data"]["refresh_token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let path = format!("/api/users/{}/password", user_id);
 
-    // 3. Access protected endpoint - get user by ID
-    let user_response = app
-        .get_authenticated(&format!("/api/users/{}", user_id), &token)
+    // The generic update no longer changes passwords
+    let response = app
+        .patch_authenticated(&format!("/api/users/{}", user_id), &token)
+        .json(&json!({ "password": "new_pass_word!" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .post_authenticated(&path, &token)
+        .json(&json!({
+            "current_password": "wrong_password",
+            "new_password": "new_pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Tokens issued within the second of the change stay valid
+    app.clock.advance(chrono::Duration::seconds(1));
+    let response = app
+        .post_authenticated(&path, &token)
+        .json(&json!({
+            "current_password": "pass_word!",
+            "new_password": "new_pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // Every token issued before the change is revoked
+    let response = app
+        .get_authenticated(&format!("/api/users/{}", user_id), &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = app
+        .post("/api/auth/refresh")
+        .json(&json!({ "refresh_token": refresh_token }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    for (password, expected) in [
+        ("pass_word!", StatusCode::UNAUTHORIZED),
+        ("new_pass_word!", StatusCode::OK),
+    ] {
+        let response = app
+            .post("/api/auth/login")
+            .json(&json!({
+                "username": "nicola",
+                "password": password
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), expected);
+    }
+}
+
+#[tokio::test]
+async fn test_email_verification() {
+    let app = TestApp::spawn().await;
+
+    let mut user_ids = Vec::new();
+    for username in ["nicola", "marco"] {
+        let response = app
+            .post("/api/users")
+            .json(&json!({
+                "username": username,
+                "email_address": format!("{}@example.com", username),
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+        user_ids.push(body["data"]["id"].as_str().unwrap().to_string());
+    }
+
+    let auth_response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "nicola",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let auth_body: serde_json::Value = auth_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let token = auth_body["data"]["token"].as_str().unwrap().to_string();
+
+    // Only the owner can ask for a link
+    let response = app
+        .post_authenticated(&format!("/api/users/{}/verification", user_ids[1]), &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .post_authenticated(&format!("/api/users/{}/verification", user_ids[0]), &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body["data"]["expires_at"].is_string());
+
+    let response = app
+        .get("/api/auth/verify?token=not-a-token")
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // The mailed link cannot be read back, so sign the same token with the test secret
+    let link_token = Authenticator::new(b"test-secret-key-for-jwt-signing-at-least-32-bytes")
+        .issue_bound_one_time_token(
+            &user_ids[0],
+            OneTimeToken::EMAIL_VERIFICATION,
+            chrono::Duration::hours(1),
+            "nicola@example.com",
+        )
+        .expect("Failed to issue token");
+    let path = format!("/api/auth/verify?token={}", link_token);
+
+    let response = app
+        .get(&path)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["email_verified"], true);
+
+    let response = app
+        .get(&path)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .post_authenticated(&format!("/api/users/{}/verification", user_ids[0]), &token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_password_reset() {
+    let app = TestApp::spawn().await;
+
+    let create_response = app
+        .post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let user_id = create_body["data"]["id"].as_str().unwrap().to_string();
+
+    // Unknown addresses get the same answer
+    for email in ["nicola@example.com", "nobody@example.com"] {
+        let response = app
+            .post("/api/auth/password-reset/request")
+            .json(&json!({ "email": email }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+    }
+
+    let response = app
+        .post("/api/auth/password-reset/confirm")
+        .json(&json!({ "token": "not-a-token", "new_password": "new_pass_word!" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // The mailed link cannot be read back, so sign the same token with the test secret
+    let password_hash: String = sqlx::query_scalar("SELECT password_hash FROM users WHERE id = $1")
+        .bind(uuid::Uuid::parse_str(&user_id).unwrap())
+        .fetch_one(&app.db.pool)
+        .await
+        .expect("Failed to read password hash");
+    let reset_token = Authenticator::new(b"test-secret-key-for-jwt-signing-at-least-32-bytes")
+        .issue_bound_one_time_token(
+            &user_id,
+            OneTimeToken::PASSWORD_RESET,
+            chrono::Duration::minutes(30),
+            &password_hash,
+        )
+        .expect("Failed to issue token");
+
+    for expected in [StatusCode::NO_CONTENT, StatusCode::UNAUTHORIZED] {
+        let response = app
+            .post("/api/auth/password-reset/confirm")
+            .json(&json!({ "token": reset_token, "new_password": "new_pass_word!" }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), expected);
+    }
+
+    for (password, expected) in [
+        ("pass_word!", StatusCode::UNAUTHORIZED),
+        ("new_pass_word!", StatusCode::OK),
+    ] {
+        let response = app
+            .post("/api/auth/login")
+            .json(&json!({
+                "username": "nicola",
+                "password": password
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), expected);
+    }
+}
+
+#[tokio::test]
+async fn test_get_user_by_id() {
+    let app = TestApp::spawn().await;
+
+    // Create a user
+    let create_response = app
+        .post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let user_id = create_body["data"]["id"].as_str().unwrap();
+
+    // Authenticate to get token
+    let auth_response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "nicola",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let auth_body: serde_json::Value = auth_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let token = auth_body["data"]["token"].as_str().unwrap();
+
+    // Get user by ID
+    let response = app
+        .get_authenticated(&format!("/api/users/{}", user_id), token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["id"], user_id);
+    assert_eq!(body["data"]["username"], "nicola");
+    assert_eq!(body["data"]["email"], "nicola@example.com");
+}
+
+#[tokio::test]
+async fn test_update_user_profile() {
+    let app = TestApp::spawn().await;
+
+    let create_response = app
+        .post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let user_id = create_body["data"]["id"].as_str().unwrap();
+
+    let auth_response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "nicola",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let auth_body: serde_json::Value = auth_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let token = auth_body["data"]["token"].as_str().unwrap();
+
+    // Set the profile
+    let response = app
+        .patch_authenticated(&format!("/api/users/{}", user_id), token)
+        .json(&json!({
+            "display_name": "  Nicola D.  ",
+            "avatar_url": "https://cdn.example.com/nicola.png",
+            "bio": "Writes chat servers"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["display_name"], "Nicola D.");
+    assert_eq!(
+        body["data"]["avatar_url"],
+        "https://cdn.example.com/nicola.png"
+    );
+    assert_eq!(body["data"]["bio"], "Writes chat servers");
+
+    // Avatars must be served over HTTPS
+    let response = app
+        .patch_authenticated(&format!("/api/users/{}", user_id), token)
+        .json(&json!({"avatar_url": "http://cdn.example.com/nicola.png"}))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    // Blank values remove fields, omitted ones are kept
+    let response = app
+        .patch_authenticated(&format!("/api/users/{}", user_id), token)
+        .json(&json!({"bio": ""}))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .get_authenticated(&format!("/api/users/{}", user_id), token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["display_name"], "Nicola D.");
+    assert_eq!(
+        body["data"]["avatar_url"],
+        "https://cdn.example.com/nicola.png"
+    );
+    assert!(body["data"]["bio"].is_null());
+}
+
+#[tokio::test]
+async fn test_get_user_not_found() {
+    let app = TestApp::spawn().await;
+
+    // Create a user and get token
+    app.post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let auth_response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "nicola",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let auth_body: serde_json::Value = auth_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let token = auth_body["data"]["token"].as_str().unwrap();
+
+    // Try to get non-existent user
+    let fake_uuid = uuid::Uuid::new_v4().to_string();
+    let response = app
+        .get_authenticated(&format!("/api/users/{}", fake_uuid), token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body["data"]["message"].is_string());
+}
+
+#[tokio::test]
+async fn test_list_users_pages_with_cursor() {
+    let app = TestApp::spawn().await;
+
+    for username in ["alice", "bob", "carol"] {
+        app.post("/api/users")
+            .json(&json!({
+                "username": username,
+                "email_address": format!("{}@example.com", username),
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+    }
+
+    let auth_response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "alice",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let auth_body: serde_json::Value = auth_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let token = auth_body["data"]["token"].as_str().unwrap();
+
+    // First page, newest first
+    let response = app
+        .get_authenticated("/api/users?limit=2", token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let items = body["data"]["items"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["username"], "carol");
+    assert_eq!(items[1]["username"], "bob");
+    assert!(items.iter().all(|item| item.get("email").is_none()));
+    let cursor = body["data"]["next_cursor"].as_str().unwrap();
+
+    // Last page
+    let response = app
+        .get_authenticated(&format!("/api/users?limit=2&cursor={}", cursor), token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let items = body["data"]["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["username"], "alice");
+    assert!(body["data"]["next_cursor"].is_null());
+
+    // Malformed cursor
+    let response = app
+        .get_authenticated("/api/users?cursor=not-a-cursor", token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_search_users() {
+    let app = TestApp::spawn().await;
+
+    for username in ["nicola", "nicole", "bob_smith"] {
+        app.post("/api/users")
+            .json(&json!({
+                "username": username,
+                "email_address": format!("{}@example.com", username),
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+    }
+
+    let auth_response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "bob_smith",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let auth_body: serde_json::Value = auth_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let token = auth_body["data"]["token"].as_str().unwrap();
+
+    // Prefix of usernames
+    let response = app
+        .get_authenticated("/api/users/search?q=nico", token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let mut usernames: Vec<_> = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|user| user["username"].as_str().unwrap().to_string())
+        .collect();
+    usernames.sort();
+    assert_eq!(usernames, vec!["nicola", "nicole"]);
+
+    // Wildcards match literally
+    let response = app
+        .get_authenticated("/api/users/search?q=bob_", token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let users = body["data"].as_array().unwrap();
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0]["username"], "bob_smith");
+    assert!(users[0].get("email").is_none());
+
+    // Blank query
+    let response = app
+        .get_authenticated("/api/users/search?q=%20", token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_deactivate_and_reactivate_user() {
+    let app = TestApp::spawn().await;
+
+    let mut tokens = Vec::new();
+    let mut user_ids = Vec::new();
+    for username in ["alice", "bob"] {
+        let create_response = app
+            .post("/api/users")
+            .json(&json!({
+                "username": username,
+                "email_address": format!("{}@example.com", username),
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let create_body: serde_json::Value = create_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        user_ids.push(create_body["data"]["id"].as_str().unwrap().to_string());
+
+        let auth_response = app
+            .post("/api/auth/login")
+            .json(&json!({
+                "username": username,
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let auth_body: serde_json::Value = auth_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        tokens.push(auth_body["data"]["token"].as_str().unwrap().to_string());
+    }
+    let alice_path = format!("/api/users/{}", user_ids[0]);
+    let admin_token = app
+        .jwt_handler
+        .encode(
+            &Claims::new()
+                .with_subject(UserId::new())
+                .with_roles(["admin"])
+                .with_expiration(chrono::Utc::now().timestamp() + 3600),
+        )
+        .unwrap();
+
+    // Bob cannot deactivate Alice
+    let response = app
+        .post_authenticated(&format!("{}/deactivate", alice_path), &tokens[1])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Alice deactivates herself
+    let response = app
+        .post_authenticated(&format!("{}/deactivate", alice_path), &tokens[0])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .get_authenticated(&alice_path, &tokens[1])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "alice",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .post_authenticated(&format!("{}/deactivate", alice_path), &admin_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // Only admins reactivate
+    let response = app
+        .post_authenticated(&format!("{}/reactivate", alice_path), &tokens[1])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .post_authenticated(&format!("{}/reactivate", alice_path), &admin_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "alice",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_admin_manages_users() {
+    let app = TestApp::spawn().await;
+
+    let mut user_ids = Vec::new();
+    for username in ["alice", "bob"] {
+        let create_response = app
+            .post("/api/users")
+            .json(&json!({
+                "username": username,
+                "email_address": format!("{}@example.com", username),
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let create_body: serde_json::Value = create_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        user_ids.push(create_body["data"]["id"].as_str().unwrap().to_string());
+    }
+    sqlx::query("UPDATE users SET roles = '{admin}' WHERE username = 'alice'")
+        .execute(&app.db.pool)
+        .await
+        .expect("Failed to grant admin role");
+
+    let login = |username: &'static str| {
+        app.post("/api/auth/login").json(&json!({
+            "username": username,
+            "password": "pass_word!"
+        }))
+    };
+    let token = |body: serde_json::Value| body["data"]["token"].as_str().unwrap().to_string();
+    let admin_token = token(login("alice").send().await.unwrap().json().await.unwrap());
+    let bob_token = token(login("bob").send().await.unwrap().json().await.unwrap());
+    let bob_path = format!("/api/admin/users/{}", user_ids[1]);
+
+    // The role comes from the token issued at login
+    let response = app
+        .get_authenticated("/api/admin/users", &bob_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .get_authenticated("/api/admin/users", &admin_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let items = body["data"]["items"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1]["username"], "alice");
+    assert_eq!(items[1]["roles"], json!(["admin"]));
+
+    let response = app
+        .post_authenticated(&format!("{}/lock", bob_path), &admin_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let response = login("bob")
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .get_authenticated("/api/admin/users?status=locked", &admin_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["items"][0]["id"], user_ids[1].as_str());
+    assert_eq!(body["data"]["items"][0]["status"], "locked");
+
+    let response = app
+        .post_authenticated(&format!("{}/unlock", bob_path), &admin_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let response = login("bob")
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // A forced reset invalidates the current password
+    let response = app
+        .post_authenticated(&format!("{}/password-reset", bob_path), &admin_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let response = login("bob")
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_admin_searches_audit_log() {
+    let app = TestApp::spawn().await;
+
+    let mut user_ids = Vec::new();
+    for username in ["alice", "bob"] {
+        let create_response = app
+            .post("/api/users")
+            .json(&json!({
+                "username": username,
+                "email_address": format!("{}@example.com", username),
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let create_body: serde_json::Value = create_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        user_ids.push(create_body["data"]["id"].as_str().unwrap().to_string());
+    }
+    sqlx::query("UPDATE users SET roles = '{admin}' WHERE username = 'alice'")
+        .execute(&app.db.pool)
+        .await
+        .expect("Failed to grant admin role");
+
+    let response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "bob",
+            "password": "wrong_password"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let login_body: serde_json::Value = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "alice",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let admin_token = login_body["data"]["token"].as_str().unwrap().to_string();
+
+    let response = app
+        .post_authenticated(
+            &format!("/api/admin/users/{}/lock", user_ids[1]),
+            &admin_token,
+        )
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .get_authenticated(
+            &format!("/api/admin/audit-log?target_id={}", user_ids[1]),
+            &admin_token,
+        )
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let items = body["data"]["items"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["action"], "user_locked");
+    assert_eq!(items[0]["actor_id"], user_ids[0].as_str());
+    assert_eq!(items[1]["action"], "login_failed");
+    assert_eq!(items[1]["actor_id"], serde_json::Value::Null);
+
+    let response = app
+        .get_authenticated("/api/admin/audit-log?action=login", &admin_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let items = body["data"]["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["target_id"], user_ids[0].as_str());
+    assert_eq!(items[0]["detail"], "password");
+
+    let response = app
+        .get_authenticated("/api/admin/audit-log?action=unknown", &admin_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_personal_token_lifecycle() {
+    let app = TestApp::spawn().await;
+
+    app.post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    let login: serde_json::Value = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "nicola",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let access_token = login["data"]["token"].as_str().unwrap().to_string();
+
+    let response = app
+        .post_authenticated("/api/account/tokens", &access_token)
+        .json(&json!({
+            "name": "backup script",
+            "scopes": ["messages:read", "channels:read"],
+            "expires_in_days": 7
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let personal_token = body["data"]["token"].as_str().unwrap().to_string();
+    let token_id = body["data"]["id"].as_str().unwrap().to_string();
+    assert!(personal_token.starts_with("chat_pat_"));
+    assert_eq!(
+        body["data"]["scopes"],
+        json!(["channels:read", "messages:read"])
+    );
+
+    // Only a digest of the token is stored
+    let stored: String = sqlx::query_scalar("SELECT token_hash FROM personal_tokens")
+        .fetch_one(&app.db.pool)
+        .await
+        .expect("Failed to read token");
+    assert_ne!(stored, personal_token);
+
+    // Personal tokens cannot manage tokens themselves
+    let response = app
+        .get_authenticated("/api/account/tokens", &personal_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .get_authenticated("/api/account/tokens", &access_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"][0]["id"], token_id.as_str());
+    assert!(body["data"][0].get("token").is_none());
+
+    let response = app
+        .post_authenticated("/api/account/tokens", &access_token)
+        .json(&json!({
+            "name": "everything",
+            "scopes": ["users:delete"]
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let path = format!("/api/account/tokens/{}", token_id);
+    let response = app
+        .delete_authenticated(&path, &access_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let response = app
+        .delete_authenticated(&path, &access_token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_list_users_requires_authentication() {
+    let app = TestApp::spawn().await;
+
+    let response = app
+        .get("/api/users")
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_v1_is_deprecated() {
+    let app = TestApp::spawn().await;
+
+    for (path, username) in [("/api/v1/users", "nicola"), ("/api/users", "bernardo")] {
+        let response = app
+            .post(path)
+            .json(&json!({
+                "username": username,
+                "email_address": format!("{}@example.com", username),
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert!(response.headers()["deprecation"]
+            .to_str()
+            .unwrap()
+            .starts_with('@'));
+        assert_eq!(
+            response.headers()["link"],
+            "</api/v2>; rel=\"successor-version\""
+        );
+        let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+        assert_eq!(body["status_code"], 201);
+        assert_eq!(body["data"]["username"], username);
+    }
+}
+
+#[tokio::test]
+async fn test_v2_wraps_responses_in_envelope() {
+    let app = TestApp::spawn().await;
+
+    let create_response = app
+        .post("/api/v2/users")
+        .header("X-Request-Id", "req-123")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+    assert_eq!(create_response.headers()["x-request-id"], "req-123");
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(create_body["data"]["username"], "nicola");
+    assert_eq!(create_body["request_id"], "req-123");
+    assert!(create_body.get("status_code").is_none());
+
+    let auth_response = app
+        .post("/api/v2/auth/login")
+        .json(&json!({
+            "username": "nicola",
+            "password": "wrong_password"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(auth_response.status(), StatusCode::UNAUTHORIZED);
+    let auth_body: serde_json::Value = auth_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert!(auth_body["error"]["message"].is_string());
+    assert!(auth_body["request_id"].is_string());
+
+    let response = app
+        .get("/api/v2/users/00000000-0000-0000-0000-000000000000")
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["error"]["code"], "missing_token");
+}
+
+#[tokio::test]
+async fn test_users_cannot_change_other_accounts() {
+    let app = TestApp::spawn().await;
+
+    let mut tokens = Vec::new();
+    let mut user_ids = Vec::new();
+    for username in ["alice", "bob"] {
+        let create_response = app
+            .post("/api/users")
+            .json(&json!({
+                "username": username,
+                "email_address": format!("{}@example.com", username),
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let create_body: serde_json::Value = create_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        user_ids.push(create_body["data"]["id"].as_str().unwrap().to_string());
+
+        let auth_response = app
+            .post("/api/auth/login")
+            .json(&json!({
+                "username": username,
+                "password": "pass_word!"
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request");
+        let auth_body: serde_json::Value = auth_response
+            .json()
+            .await
+            .expect("Failed to parse response");
+        tokens.push(auth_body["data"]["token"].as_str().unwrap().to_string());
+    }
+    let alice_path = format!("/api/users/{}", user_ids[0]);
+    let admin_token = app
+        .jwt_handler
+        .encode(
+            &Claims::new()
+                .with_subject(UserId::new())
+                .with_roles(["admin"])
+                .with_expiration(chrono::Utc::now().timestamp() + 3600),
+        )
+        .unwrap();
+
+    // Bob can read Alice's profile but not change or delete it
+    let response = app
+        .patch_authenticated(&alice_path, &tokens[1])
+        .json(&json!({ "display_name": "Not Alice" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(
+        body["data"]["message"],
+        "Only the account owner or an admin can do this"
+    );
+
+    let response = app
+        .delete_authenticated(&alice_path, &tokens[1])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .get_authenticated(&alice_path, &tokens[1])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body["data"]["display_name"].is_null());
+
+    // Admins change any account
+    let response = app
+        .patch_authenticated(&alice_path, &admin_token)
+        .json(&json!({ "display_name": "Alice" }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Owners delete their own account
+    let response = app
+        .delete_authenticated(&alice_path, &tokens[0])
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_full_user_workflow() {
+    let app = TestApp::spawn().await;
+
+    // 1. Create user
+    let create_response = app
+        .post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let user_id = create_body["data"]["id"].as_str().unwrap().to_string();
+
+    // 2. Login
+    let login_response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "nicola",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(login_response.status(), StatusCode::OK);
+
+    let login_body: serde_json::Value = login_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let token = login_body["data"]["token"].as_str().unwrap().to_string();
+
+    // 3. Access protected endpoint - get user by ID
+    let user_response = app
+        .get_authenticated(&format!("/api/users/{}", user_id), &token)
         .send()
         .await
         .expect("Failed to execute request");
@@ -414,3 +2065,188 @@ async fn test_full_user_workflow() {
 
     assert_eq!(invalid_response.status(), StatusCode::UNAUTHORIZED);
 }
+
+#[tokio::test]
+async fn test_liveness_probe() {
+    let app = TestApp::spawn().await;
+
+    let response = app
+        .get("/healthz")
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["status"], "ok");
+}
+
+#[tokio::test]
+async fn test_readiness_probe() {
+    let app = TestApp::spawn().await;
+
+    let response = app
+        .get("/readyz")
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["status"], "ok");
+    assert_eq!(body["data"]["dependencies"][0]["name"], "postgresql");
+    assert_eq!(body["data"]["dependencies"][0]["status"], "ok");
+}
+
+#[tokio::test]
+async fn test_metrics_endpoint() {
+    let app = TestApp::spawn().await;
+
+    let response = app
+        .get("/metrics")
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/plain; version=0.0.4"
+    );
+}
+
+#[tokio::test]
+async fn test_openapi_contract_served() {
+    let app = TestApp::spawn().await;
+
+    let response = app
+        .get("/api/openapi.json")
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["info"]["title"], "User Service API");
+    assert!(body["paths"]["/api/users"].is_object());
+
+    let response = app
+        .get("/api/docs/")
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_get_version() {
+    let app = TestApp::spawn().await;
+
+    let response = app
+        .get("/internal/version")
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["service"], "user-service");
+    assert_eq!(body["data"]["version"], env!("CARGO_PKG_VERSION"));
+    assert!(body["data"]["git_sha"].is_string());
+    assert_eq!(body["data"]["config_digest"].as_str().unwrap().len(), 64);
+}
+
+#[tokio::test]
+async fn test_get_job() {
+    let app = TestApp::spawn().await;
+
+    // Create a user and get token
+    let create_response = app
+        .post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let create_body: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let user_id = UserId::from_string(create_body["data"]["id"].as_str().unwrap()).unwrap();
+
+    let auth_response = app
+        .post("/api/auth/login")
+        .json(&json!({
+            "username": "nicola",
+            "password": "pass_word!"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let auth_body: serde_json::Value = auth_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let token = auth_body["data"]["token"].as_str().unwrap();
+
+    // Start a job on behalf of the user
+    let job_service = JobService::new(Arc::new(PostgresJobRepository::new(app.db.pool.clone())));
+    let job = job_service
+        .create_job(JobKind::new("user_export"), &user_id)
+        .await
+        .expect("Failed to create job");
+
+    let response = app
+        .get_authenticated(&format!("/api/jobs/{}", job.id), token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["data"]["id"], job.id.to_string());
+    assert_eq!(body["data"]["kind"], "user_export");
+    assert_eq!(body["data"]["status"], "pending");
+    assert_eq!(body["data"]["progress"], 0);
+
+    // Unknown job
+    let response = app
+        .get_authenticated("/api/jobs/00000000-0000-0000-0000-000000000000", token)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_oversized_body_is_rejected() {
+    let app = TestApp::spawn().await;
+
+    let response = app
+        .post("/api/users")
+        .json(&json!({
+            "username": "nicola",
+            "email_address": "nicola@example.com",
+            "password": "x".repeat(2 * 1024 * 1024)
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["status_code"], 413);
+    assert!(body["data"]["message"].as_str().unwrap().contains("limit"));
+}